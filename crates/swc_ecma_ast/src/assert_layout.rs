@@ -1,7 +1,15 @@
-use crate::{AstNode, ExtraData, NodeData};
+use crate::{AstNode, Expr, ExtraData, Lit, NodeData, NodeId, Str};
 
 const _: () = {
     assert!(size_of::<AstNode>() == 16);
     assert!(size_of::<NodeData>() == 4);
     assert!(size_of::<ExtraData>() == 8);
 };
+
+// An enum of structs, even a nested one, stores its variant and then the id of
+// the struct, which [crate::Ast::validate] reads before decoding the enum.
+const _: () = {
+    let expr = Expr::Lit(Lit::Str(Str(NodeId::from_raw(7))));
+    let words: [u32; 2] = unsafe { std::mem::transmute(expr) };
+    assert!(words[1] == 7);
+};
@@ -2,7 +2,11 @@ use std::mem;
 
 use swc_experimental_ast_macros::ast;
 
-use crate::{Ast, ExtraData, node_id::ExtraDataCompact};
+use crate::{
+    Ast, ExtraData,
+    ast::{TsEnumDecl, TsInterfaceDecl, TsModuleDecl, TsTypeAliasDecl},
+    node_id::ExtraDataCompact,
+};
 
 #[ast]
 pub enum Decl {
@@ -10,10 +14,10 @@ pub enum Decl {
     Fn(FnDecl),
     Var(VarDecl),
     Using(UsingDecl),
-    // TsInterface(Box<TsInterfaceDecl>),
-    // TsTypeAlias(Box<TsTypeAliasDecl>),
-    // TsEnum(Box<TsEnumDecl>),
-    // TsModule(Box<TsModuleDecl>),
+    TsInterface(TsInterfaceDecl),
+    TsTypeAlias(TsTypeAliasDecl),
+    TsEnum(TsEnumDecl),
+    TsModule(TsModuleDecl),
 }
 
 #[ast]
@@ -59,7 +63,7 @@ impl ExtraDataCompact for VarDeclKind {
 pub struct VarDeclarator {
     name: Pat,
     init: Option<Expr>,
-    // pub definite: bool,
+    definite: bool,
 }
 
 #[ast]
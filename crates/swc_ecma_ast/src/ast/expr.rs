@@ -35,12 +35,12 @@ pub enum Expr {
     JSXEmpty(JSXEmptyExpr),
     JSXElement(JSXElement),
     JSXFragment(JSXFragment),
-    // TsTypeAssertion(TsTypeAssertion),
-    // TsConstAssertion(TsConstAssertion),
-    // TsNonNull(TsNonNullExpr),
-    // TsAs(TsAsExpr),
-    // TsInstantiation(TsInstantiation),
-    // TsSatisfies(TsSatisfiesExpr),
+    TsTypeAssertion(TsTypeAssertion),
+    TsConstAssertion(TsConstAssertion),
+    TsNonNull(TsNonNullExpr),
+    TsAs(TsAsExpr),
+    TsInstantiation(TsInstantiation),
+    TsSatisfies(TsSatisfiesExpr),
     PrivateName(PrivateName),
     OptChain(OptChainExpr),
     Invalid(Invalid),
@@ -145,16 +145,14 @@ pub struct CondExpr {
 pub struct CallExpr {
     callee: Callee,
     args: Vec<ExprOrSpread>,
-    // type_args: Option<Box<TsTypeParamInstantiation>>,
-    // pub type_params: Option<TsTypeParamInstantiation>,
+    type_args: Option<TsTypeParamInstantiation>,
 }
 
 #[ast]
 pub struct NewExpr {
     callee: Expr,
     args: Option<Vec<ExprOrSpread>>,
-    // type_args: Option<Box<TsTypeParamInstantiation>>,
-    // pub type_params: Option<TsTypeParamInstantiation>,
+    type_args: Option<TsTypeParamInstantiation>,
 }
 
 #[ast]
@@ -168,8 +166,8 @@ pub struct ArrowExpr {
     body: BlockStmtOrExpr,
     is_async: bool,
     is_generator: bool,
-    // type_params: Option<Box<TsTypeParamDecl>>,
-    // return_type: Option<Box<TsTypeAnn>>,
+    type_params: Option<TsTypeParamDecl>,
+    return_type: Option<TsTypeAnn>,
 }
 
 #[ast]
@@ -184,7 +182,7 @@ pub struct MetaPropExpr {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MetaPropKind {
     /// `new.target`
     NewTarget,
@@ -216,7 +214,7 @@ pub struct Tpl {
 #[ast]
 pub struct TaggedTpl {
     tag: Expr,
-    // type_params: Option<TsTypeParamInstantiation>,
+    type_params: Option<TsTypeParamInstantiation>,
     tpl: Tpl,
 }
 
@@ -282,11 +280,11 @@ pub enum SimpleAssignTarget {
     SuperProp(SuperPropExpr),
     Paren(ParenExpr),
     OptChain(OptChainExpr),
-    // TsAs(TsAsExpr),
-    // TsSatisfies(TsSatisfiesExpr),
-    // TsNonNull(TsNonNullExpr),
-    // TsTypeAssertion(TsTypeAssertion),
-    // TsInstantiation(TsInstantiation),
+    TsAs(TsAsExpr),
+    TsSatisfies(TsSatisfiesExpr),
+    TsNonNull(TsNonNullExpr),
+    TsTypeAssertion(TsTypeAssertion),
+    TsInstantiation(TsInstantiation),
     Invalid(Invalid),
 }
 
@@ -306,8 +304,7 @@ pub enum OptChainBase {
 pub struct OptCall {
     callee: Expr,
     args: Vec<ExprOrSpread>,
-    // type_args: Option<Box<TsTypeParamInstantiation>>,
-    // pub type_params: Option<TsTypeParamInstantiation>,
+    type_args: Option<TsTypeParamInstantiation>,
 }
 
 #[ast]
@@ -350,16 +347,16 @@ impl AssignTarget {
 impl SimpleAssignTarget {
     pub fn try_from_expr(ast: &mut Ast, e: Expr) -> Result<Self, Expr> {
         Ok(match e {
-            Expr::Ident(i) => SimpleAssignTarget::Ident(ast.binding_ident(i.span(ast), i)),
+            Expr::Ident(i) => SimpleAssignTarget::Ident(ast.binding_ident(i.span(ast), i, None)),
             Expr::Member(m) => SimpleAssignTarget::Member(m),
             Expr::SuperProp(s) => SimpleAssignTarget::SuperProp(s),
             Expr::OptChain(s) => SimpleAssignTarget::OptChain(s),
             Expr::Paren(s) => SimpleAssignTarget::Paren(s),
-            // Expr::TsAs(a) => SimpleAssignTarget::TsAs(a),
-            // Expr::TsSatisfies(s) => SimpleAssignTarget::TsSatisfies(s),
-            // Expr::TsNonNull(n) => SimpleAssignTarget::TsNonNull(n),
-            // Expr::TsTypeAssertion(a) => SimpleAssignTarget::TsTypeAssertion(a),
-            // Expr::TsInstantiation(a) => SimpleAssignTarget::TsInstantiation(a),
+            Expr::TsAs(a) => SimpleAssignTarget::TsAs(a),
+            Expr::TsSatisfies(s) => SimpleAssignTarget::TsSatisfies(s),
+            Expr::TsNonNull(n) => SimpleAssignTarget::TsNonNull(n),
+            Expr::TsTypeAssertion(a) => SimpleAssignTarget::TsTypeAssertion(a),
+            Expr::TsInstantiation(a) => SimpleAssignTarget::TsInstantiation(a),
             _ => return Err(e),
         })
     }
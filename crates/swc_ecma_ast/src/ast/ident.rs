@@ -22,12 +22,12 @@ pub struct PrivateName {
 #[ast]
 pub struct BindingIdent {
     id: Ident,
-    // pub type_ann: Option<Box<TsTypeAnn>>,
+    type_ann: Option<TsTypeAnn>,
 }
 
 impl Ident {
     pub fn into_binding(self, ast: &mut Ast) -> BindingIdent {
-        ast.binding_ident(self.span(ast), self)
+        ast.binding_ident(self.span(ast), self, None)
     }
 }
 
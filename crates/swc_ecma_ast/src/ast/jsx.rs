@@ -51,7 +51,7 @@ pub struct JSXOpeningElement {
     name: JSXElementName,
     attrs: Vec<JSXAttrOrSpread>,
     self_closing: bool,
-    // type_args: Option<Box<TsTypeParamInstantiation>>,
+    type_args: Option<TsTypeParamInstantiation>,
 }
 
 #[ast]
@@ -4,7 +4,10 @@ use swc_experimental_ast_macros::ast;
 
 use crate::{
     Ast, ExtraData,
-    ast::{ClassExpr, FnExpr, Ident, Str},
+    ast::{
+        ClassExpr, FnExpr, Ident, Str, TsExportAssignment, TsImportEqualsDecl, TsInterfaceDecl,
+        TsNamespaceExportDecl,
+    },
     node_id::ExtraDataCompact,
 };
 
@@ -16,9 +19,9 @@ pub enum ModuleDecl {
     ExportDefaultDecl(ExportDefaultDecl),
     ExportDefaultExpr(ExportDefaultExpr),
     ExportAll(ExportAll),
-    // TsImportEquals(TsImportEqualsDecl),
-    // TsExportAssignment(TsExportAssignment),
-    // TsNamespaceExport(TsNamespaceExportDecl),
+    TsImportEquals(TsImportEqualsDecl),
+    TsExportAssignment(TsExportAssignment),
+    TsNamespaceExport(TsNamespaceExportDecl),
 }
 
 #[ast]
@@ -125,7 +128,7 @@ pub struct ExportDefaultDecl {
 pub enum DefaultDecl {
     Class(ClassExpr),
     Fn(FnExpr),
-    // TsInterfaceDecl(TsInterfaceDecl),
+    TsInterfaceDecl(TsInterfaceDecl),
 }
 
 #[ast]
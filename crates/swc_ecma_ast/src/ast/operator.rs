@@ -115,7 +115,7 @@ impl BinaryOp {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Default)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Default)]
 pub enum AssignOp {
     /// `=`
     #[default]
@@ -167,7 +167,7 @@ impl ExtraDataCompact for AssignOp {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Default)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Default)]
 pub enum UpdateOp {
     /// `++`
     #[default]
@@ -187,7 +187,7 @@ impl ExtraDataCompact for UpdateOp {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Default)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, Default)]
 pub enum UnaryOp {
     /// `-`
     Minus,
@@ -30,7 +30,7 @@ pub struct AssignProp {
 #[ast]
 pub struct GetterProp {
     key: PropName,
-    // type_ann: Option<TsTypeAnn>,
+    type_ann: Option<TsTypeAnn>,
     body: Option<BlockStmt>,
 }
 #[ast]
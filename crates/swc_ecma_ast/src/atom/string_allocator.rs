@@ -20,24 +20,30 @@ impl StringAllocator {
     /// # Safety
     /// [StringAllocatorInner] uses [string_interner::backend::BucketBackend], which has stable string references
     #[allow(clippy::mut_from_ref)]
-    fn inner(&self) -> &mut StringAllocatorInner {
+    fn inner_mut(&self) -> &mut StringAllocatorInner {
         unsafe { &mut *self.0.get() }
     }
 
+    /// Reads don't create a `&mut`, so that a frozen allocator can be read
+    /// from several threads, see [crate::FrozenAst].
+    fn inner(&self) -> &StringAllocatorInner {
+        unsafe { &*self.0.get() }
+    }
+
     pub fn add_utf8(&self, s: &str) -> Utf8Ref {
-        self.inner().add_utf8(s)
+        self.inner_mut().add_utf8(s)
     }
 
     pub fn add_optional_utf8(&self, s: Option<&str>) -> OptionalUtf8Ref {
-        self.inner().add_optional_utf8(s)
+        self.inner_mut().add_optional_utf8(s)
     }
 
     pub fn add_wtf8(&self, s: &Wtf8) -> Wtf8Ref {
-        self.inner().add_wtf8(s)
+        self.inner_mut().add_wtf8(s)
     }
 
     pub fn add_optional_wtf8(&self, s: Option<&Wtf8>) -> OptionalWtf8Ref {
-        self.inner().add_optional_wtf8(s)
+        self.inner_mut().add_optional_wtf8(s)
     }
 
     pub fn get_utf8(&self, id: Utf8Ref) -> &str {
@@ -67,6 +73,38 @@ impl StringAllocator {
     pub fn empty_utf8_ref(&self) -> Utf8Ref {
         self.inner().empty_utf8_ref()
     }
+
+    /// Iterates all the UTF-8 strings in the order of their [Utf8Ref].
+    pub(crate) fn utf8_strings(&self) -> impl Iterator<Item = &str> {
+        self.inner().allocated_utf8.iter().map(|(_, s)| s)
+    }
+
+    /// Iterates all the WTF-8 strings in the order of their [Wtf8Ref].
+    pub(crate) fn wtf8_strings(&self) -> impl Iterator<Item = &Wtf8> {
+        self.inner().allocated_wtf8.iter()
+    }
+
+    #[inline]
+    pub(crate) fn utf8_len(&self) -> usize {
+        self.inner().allocated_utf8.len()
+    }
+
+    #[inline]
+    pub(crate) fn wtf8_len(&self) -> usize {
+        self.inner().allocated_wtf8.len()
+    }
+
+    /// Copies the strings into a new allocator, where they have the same ids.
+    pub(crate) fn copy_strings(&self) -> Self {
+        let copy = Self::default();
+        for s in self.utf8_strings() {
+            copy.add_utf8(s);
+        }
+        for s in self.wtf8_strings() {
+            copy.add_wtf8(s);
+        }
+        copy
+    }
 }
 
 /// A string allocator that can be used to allocate strings for the AST.
@@ -16,8 +16,12 @@ impl Wtf8Allocator {
     pub fn add(&mut self, s: &Wtf8) -> Wtf8Ref {
         const GAP: char = '\u{FFFD}';
 
+        // `push_char` reserves 4 bytes whatever the length of the char is, so
+        // the head would be reallocated under the previous strings otherwise.
+        const GAP_RESERVED: usize = 4;
+
         let cap = self.head.capacity();
-        if cap < self.head.len() + s.len() + GAP.len_utf8() {
+        if cap < self.head.len() + s.len() + GAP_RESERVED {
             let new_cap = (usize::max(cap, s.len()) + 1).next_power_of_two();
             let new_head = Wtf8Buf::with_capacity(new_cap);
             let old_head = core::mem::replace(&mut self.head, new_head);
@@ -43,6 +47,16 @@ impl Wtf8Allocator {
         //         references.
             unsafe { s.as_ref() })
     }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Wtf8> {
+        // SAFETY: See `resolve`.
+        self.spans.iter().map(|s| unsafe { s.as_ref() })
+    }
 }
 
 /// The reference to a wtf8 string in the string allocator.
@@ -145,6 +159,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_separator_fills_head() {
+        let mut allocator = Wtf8Allocator::default();
+
+        // The head has 16 bytes, of which the separator and the first string
+        // use 13, so the next separator fills it exactly.
+        let r1 = allocator.add(Wtf8::from_str("use strict"));
+        let r2 = allocator.add(Wtf8::from_str(""));
+        assert_eq!(allocator.resolve(r1), Some(Wtf8::from_str("use strict")));
+        assert_eq!(allocator.resolve(r2), Some(Wtf8::from_str("")));
+    }
+
     #[test]
     fn test_complex_lone_surrogates() {
         let mut allocator = Wtf8Allocator::default();
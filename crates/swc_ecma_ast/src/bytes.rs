@@ -0,0 +1,1055 @@
+//! A compact binary format of [Ast], so that parsed files can be cached on disk
+//! or sent to other processes.
+//!
+//! The arena can't be dumped as is: [ExtraData] and [NodeData] are unions with
+//! uninitialized bytes, and the layout of enums is up to the compiler. Instead,
+//! every field is encoded by its type from the generated schema. All integers
+//! are little-endian.
+//!
+//! ```text
+//! header:  magic [u8; 8], version u32, schema hash u64, checksum u64
+//! payload: nodes      u32 count, (kind u16, lo u32, hi u32, inline [u8; 3], data u32)*
+//!          extra data u32 count, u64*
+//!          bigints    u32 count, (len u32, signed little-endian bytes)*
+//!          utf8       u32 count, (len u32, bytes)*
+//!          wtf8       u32 count, (len u32, bytes)*
+//!          comments   u32 count, (node u32, placement u8, kind u8, lo u32, hi u32, text u32)*
+//! ```
+//!
+//! Node fields in [ExtraData] are encoded as the raw [NodeId], so that every
+//! field can be read in place. [crate::AstView] does so, e.g. over a file
+//! mapped into memory, while [Ast::from_bytes] copies the nodes and the
+//! strings into a new [Ast] which can be edited.
+
+use std::{fmt, rc::Rc};
+
+use oxc_index::{Idx, IndexVec};
+use swc_core::{
+    atoms::wtf8::Wtf8,
+    common::{BytePos, comments::CommentKind},
+};
+
+use crate::{
+    Ast, AstNode, BigIntId, BigIntValue, Comment, CommentPlacement, ExtraData, NodeData, NodeId,
+    OptionalNodeId, Span, StringAllocator, U24,
+    comments::AstComments,
+    node_id::{ExtraDataId, OptionalSubRange, SubRange},
+    nodes::Nodes,
+    schema::{FieldLocation, FieldType, NODE_SCHEMAS, NodeSchema, NodeType},
+};
+
+const MAGIC: [u8; 8] = *b"SWCAST\0\0";
+
+/// The version of the encoding. Changes of the AST definitions are detected
+/// by the schema hash instead.
+const FORMAT_VERSION: u32 = 2;
+
+const HEADER_LEN: usize = MAGIC.len() + 4 + 8 + 8;
+
+/// The error of [Ast::from_bytes].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AstDecodeError {
+    /// The bytes aren't an encoded AST.
+    InvalidMagic,
+    /// The bytes are encoded by an incompatible version of the format.
+    UnsupportedVersion(u32),
+    /// The bytes are encoded with different AST definitions.
+    SchemaMismatch,
+    /// The bytes are corrupted.
+    ChecksumMismatch,
+    /// The bytes are truncated.
+    UnexpectedEof,
+    /// The bytes don't describe a valid AST.
+    InvalidData(&'static str),
+}
+
+impl fmt::Display for AstDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "not an encoded AST"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {version}")
+            }
+            Self::SchemaMismatch => write!(f, "encoded with different AST definitions"),
+            Self::ChecksumMismatch => write!(f, "checksum mismatch"),
+            Self::UnexpectedEof => write!(f, "unexpected end of bytes"),
+            Self::InvalidData(reason) => write!(f, "invalid AST: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for AstDecodeError {}
+
+/// The error of [Ast::to_bytes].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AstEncodeError {
+    /// The arena doesn't describe a valid AST, e.g. a list overlaps the
+    /// fields of a node.
+    InvalidData(&'static str),
+}
+
+impl fmt::Display for AstEncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidData(reason) => write!(f, "invalid AST: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for AstEncodeError {}
+
+type Result<T> = std::result::Result<T, AstDecodeError>;
+
+impl Ast {
+    /// Encodes the AST into bytes, which can be decoded by [Ast::from_bytes].
+    ///
+    /// All the strings of the [StringAllocator] are written, including the ones
+    /// of other ASTs sharing it.
+    pub fn to_bytes(&self) -> std::result::Result<Vec<u8>, AstEncodeError> {
+        let mut payload = Vec::with_capacity(self.nodes.len() * 17 + self.extra_data.len() * 8);
+
+        put_u32(&mut payload, self.nodes.len() as u32);
+        for node in self.nodes.iter() {
+            let schema = node.kind.schema();
+            // Nodes without fields leave `NodeData` uninitialized.
+            let data = if schema.fields.is_empty() {
+                0
+            } else {
+                unsafe { node.data.inline_data }
+            };
+            put_u16(&mut payload, node.kind.schema_index() as u16);
+            put_u32(&mut payload, node.span.lo.0);
+            put_u32(&mut payload, node.span.hi.0);
+            payload.extend_from_slice(&node.inline_data.0);
+            put_u32(&mut payload, data);
+        }
+
+        let types = extra_data_types(
+            self.nodes.iter().map(|node| {
+                let schema = node.kind.schema();
+                let start = schema
+                    .has_extra_data()
+                    .then(|| unsafe { node.data.extra_data_start }.raw());
+                (schema, start)
+            }),
+            self.extra_data.len(),
+            |index| {
+                let range = unsafe { self.extra_data[ExtraDataId::from_usize(index)].sub_range };
+                (range.start.raw(), range.end.raw())
+            },
+        )
+        .map_err(AstEncodeError::InvalidData)?;
+        put_u32(&mut payload, self.extra_data.len() as u32);
+        for (data, ty) in self.extra_data.iter().zip(types) {
+            let value = match ty {
+                Some(ty) => unsafe { self.encode_extra_data(*data, ty) },
+                // Unreachable data, e.g. the old fields of an edited list.
+                None => 0,
+            };
+            put_u64(&mut payload, value);
+        }
+
+        put_u32(&mut payload, self.bigint.len() as u32);
+        for bigint in self.bigint.iter() {
+            put_bytes(&mut payload, &bigint.to_signed_bytes_le());
+        }
+
+        let utf8 = self.string_allocator.utf8_strings().collect::<Vec<_>>();
+        put_u32(&mut payload, utf8.len() as u32);
+        for s in utf8 {
+            put_bytes(&mut payload, s.as_bytes());
+        }
+
+        let wtf8 = self.string_allocator.wtf8_strings().collect::<Vec<_>>();
+        put_u32(&mut payload, wtf8.len() as u32);
+        for s in wtf8 {
+            put_bytes(&mut payload, s.as_bytes());
+        }
+
+        put_u32(&mut payload, self.comments.len() as u32);
+        for (node, placement, comment) in self.comments() {
+            put_u32(&mut payload, node.raw());
+            payload.push(placement as u8);
+            payload.push(match comment.kind {
+                CommentKind::Line => 0,
+                CommentKind::Block => 1,
+            });
+            put_u32(&mut payload, comment.span.lo.0);
+            put_u32(&mut payload, comment.span.hi.0);
+            put_u32(&mut payload, comment.text.index() as u32);
+        }
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+        bytes.extend_from_slice(&MAGIC);
+        put_u32(&mut bytes, FORMAT_VERSION);
+        put_u64(&mut bytes, schema_hash());
+        put_u64(&mut bytes, fnv1a(FNV_OFFSET_BASIS, &payload));
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    /// Decodes an AST encoded by [Ast::to_bytes] into a new [StringAllocator].
+    ///
+    /// The bytes are validated against the schema, so that the typed getters
+    /// are sound on the decoded AST. Node ids are kept, so side tables keyed
+    /// by [NodeId] stay valid.
+    ///
+    /// Use [crate::AstView] to read the bytes in place instead.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (payload, types) = Payload::read(bytes)?;
+
+        let mut nodes = Nodes::with_capacity(payload.node_count());
+        for index in 0..payload.node_count() {
+            nodes.push(payload.node(index));
+        }
+
+        let bigint = payload
+            .bigints
+            .iter()
+            .map(|bytes| BigIntValue::from_signed_bytes_le(bytes))
+            .collect();
+
+        let string_allocator = StringAllocator::default();
+        for (index, s) in payload.utf8.iter().enumerate() {
+            // The strings are unique, so they're interned with the same ids.
+            if string_allocator.add_utf8(s).index() != index {
+                return Err(AstDecodeError::InvalidData("duplicated UTF-8 string"));
+            }
+        }
+        for s in &payload.wtf8 {
+            string_allocator.add_wtf8(s);
+        }
+
+        let mut comments = AstComments::default();
+        for (node_id, placement, comment) in payload.comments() {
+            comments.push((node_id, placement), comment);
+        }
+
+        let mut ast = Ast {
+            nodes,
+            extra_data: IndexVec::with_capacity(types.len()),
+            bigint,
+            string_allocator: Rc::new(string_allocator),
+            comments,
+        };
+        for (index, ty) in types.into_iter().enumerate() {
+            let data = match ty {
+                // SAFETY: The payload is validated against the schema.
+                Some(ty) => unsafe { ast.decode_extra_data(ty, payload.extra_data(index)) },
+                None => ExtraData { other: 0 },
+            };
+            ast.extra_data.push(data);
+        }
+
+        Ok(ast)
+    }
+
+    /// # Safety
+    /// `data` must store `ty`.
+    unsafe fn encode_extra_data(&self, data: ExtraData, ty: FieldType) -> u64 {
+        unsafe {
+            match ty {
+                FieldType::Node(node_type) => (node_type.codec.read)(data, self).into_raw() as u64,
+                FieldType::OptionalNode(node_type) => {
+                    (node_type.optional_codec.unwrap().read)(data, self).into_raw() as u64
+                }
+                FieldType::List(_) => {
+                    let range = data.sub_range;
+                    range.start.raw() as u64 | (range.end.raw() as u64) << 32
+                }
+                FieldType::OptionalList(_) => {
+                    let range = data.optional_sub_range;
+                    range.start.raw() as u64 | (range.end.raw() as u64) << 32
+                }
+                FieldType::Enum(_) | FieldType::OptionalEnum(_) => data.other,
+                FieldType::Span => data.span.lo.0 as u64 | (data.span.hi.0 as u64) << 32,
+                FieldType::Bool => data.bool as u64,
+                FieldType::Number => data.number.to_bits(),
+                FieldType::Utf8 => data.utf8.index() as u64,
+                FieldType::OptionalUtf8 => data.optional_utf8.index() as u64,
+                FieldType::Wtf8 => data.wtf8.index() as u64,
+                FieldType::OptionalWtf8 => data.optional_wtf8.index() as u64,
+                FieldType::BigInt => data.bigint.index() as u64,
+            }
+        }
+    }
+
+    /// # Safety
+    /// `value` must be validated against `ty` by [Payload::read].
+    unsafe fn decode_extra_data(&self, ty: FieldType, value: u64) -> ExtraData {
+        let (low, high) = (value as u32, (value >> 32) as u32);
+        unsafe {
+            match ty {
+                FieldType::Node(node_type) => {
+                    (node_type.codec.write)(OptionalNodeId::from_raw(low), self)
+                }
+                FieldType::OptionalNode(node_type) => {
+                    (node_type.optional_codec.unwrap().write)(OptionalNodeId::from_raw(low), self)
+                }
+                FieldType::List(_) => ExtraData {
+                    sub_range: SubRange {
+                        start: ExtraDataId::from_raw(low),
+                        end: ExtraDataId::from_raw(high),
+                    },
+                },
+                FieldType::OptionalList(_) => ExtraData {
+                    optional_sub_range: OptionalSubRange {
+                        start: ExtraDataId::from_raw(low),
+                        end: ExtraDataId::from_raw(high),
+                    },
+                },
+                FieldType::Enum(_) | FieldType::OptionalEnum(_) => ExtraData { other: value },
+                FieldType::Span => ExtraData {
+                    span: Span {
+                        lo: BytePos(low),
+                        hi: BytePos(high),
+                    },
+                },
+                FieldType::Bool => ExtraData { bool: value == 1 },
+                FieldType::Number => ExtraData {
+                    number: f64::from_bits(value),
+                },
+                FieldType::Utf8 => ExtraData {
+                    utf8: Idx::from_usize(low as usize),
+                },
+                // `None` is `u32::MAX`, which `Idx::from_usize` rejects.
+                FieldType::OptionalUtf8 => ExtraData {
+                    optional_utf8: Idx::from_usize_unchecked(low as usize),
+                },
+                FieldType::Wtf8 => ExtraData {
+                    wtf8: Idx::from_usize(low as usize),
+                },
+                FieldType::OptionalWtf8 => ExtraData {
+                    optional_wtf8: Idx::from_usize_unchecked(low as usize),
+                },
+                FieldType::BigInt => ExtraData {
+                    bigint: BigIntId::from_raw(low),
+                },
+            }
+        }
+    }
+}
+
+const NODE_LEN: usize = 2 + 4 + 4 + 3 + 4;
+
+const COMMENT_LEN: usize = 4 + 1 + 1 + 4 + 4 + 4;
+
+/// The sections of encoded bytes, which are borrowed from the bytes and
+/// validated against the schema by [Payload::read].
+pub(crate) struct Payload<'a> {
+    nodes: &'a [u8],
+    extra_data: &'a [u8],
+    pub(crate) bigints: Vec<&'a [u8]>,
+    pub(crate) utf8: Vec<&'a str>,
+    pub(crate) wtf8: Vec<&'a Wtf8>,
+    comments: &'a [u8],
+}
+
+impl<'a> Payload<'a> {
+    /// Splits and validates the bytes. The type of every [ExtraData] is
+    /// returned too, which is `None` if no field refers to it.
+    pub(crate) fn read(bytes: &'a [u8]) -> Result<(Self, Vec<Option<FieldType>>)> {
+        let mut reader = Reader { bytes };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(AstDecodeError::InvalidMagic);
+        }
+        let version = reader.u32()?;
+        if version != FORMAT_VERSION {
+            return Err(AstDecodeError::UnsupportedVersion(version));
+        }
+        if reader.u64()? != schema_hash() {
+            return Err(AstDecodeError::SchemaMismatch);
+        }
+        let checksum = reader.u64()?;
+        if fnv1a(FNV_OFFSET_BASIS, reader.bytes) != checksum {
+            return Err(AstDecodeError::ChecksumMismatch);
+        }
+
+        let nodes = reader.records(NODE_LEN)?;
+        if nodes
+            .chunks_exact(NODE_LEN)
+            .any(|node| u16::from_le_bytes([node[0], node[1]]) as usize >= NODE_SCHEMAS.len())
+        {
+            return Err(AstDecodeError::InvalidData("unknown node kind"));
+        }
+        let extra_data = reader.records(8)?;
+
+        let bigint_count = reader.u32()? as usize;
+        let mut bigints = Vec::with_capacity(bigint_count.min(reader.bytes.len() / 4));
+        for _ in 0..bigint_count {
+            bigints.push(reader.bytes()?);
+        }
+
+        let utf8_count = reader.u32()? as usize;
+        let mut utf8 = Vec::with_capacity(utf8_count.min(reader.bytes.len() / 4));
+        for _ in 0..utf8_count {
+            let s = std::str::from_utf8(reader.bytes()?)
+                .map_err(|_| AstDecodeError::InvalidData("invalid UTF-8 string"))?;
+            utf8.push(s);
+        }
+        let wtf8_count = reader.u32()? as usize;
+        let mut wtf8 = Vec::with_capacity(wtf8_count.min(reader.bytes.len() / 4));
+        for _ in 0..wtf8_count {
+            let s = reader.bytes()?;
+            if !is_wtf8(s) {
+                return Err(AstDecodeError::InvalidData("invalid WTF-8 string"));
+            }
+            wtf8.push(unsafe { Wtf8::from_bytes_unchecked(s) });
+        }
+
+        let comments = reader.records(COMMENT_LEN)?;
+        if !reader.bytes.is_empty() {
+            return Err(AstDecodeError::InvalidData("trailing bytes"));
+        }
+
+        let payload = Payload {
+            nodes,
+            extra_data,
+            bigints,
+            utf8,
+            wtf8,
+            comments,
+        };
+        payload.validate_comments()?;
+        let types = payload.validate_fields()?;
+        Ok((payload, types))
+    }
+
+    #[inline]
+    pub(crate) fn node_count(&self) -> usize {
+        self.nodes.len() / NODE_LEN
+    }
+
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub(crate) fn node(&self, index: usize) -> AstNode {
+        let record = &self.nodes[index * NODE_LEN..(index + 1) * NODE_LEN];
+        let u32_at =
+            |offset: usize| u32::from_le_bytes(record[offset..offset + 4].try_into().unwrap());
+        AstNode {
+            span: Span {
+                lo: BytePos(u32_at(2)),
+                hi: BytePos(u32_at(6)),
+            },
+            kind: NODE_SCHEMAS[u16::from_le_bytes([record[0], record[1]]) as usize].kind,
+            inline_data: U24(record[10..13].try_into().unwrap()),
+            data: NodeData {
+                inline_data: u32_at(13),
+            },
+        }
+    }
+
+    #[inline]
+    pub(crate) fn extra_data_count(&self) -> usize {
+        self.extra_data.len() / 8
+    }
+
+    /// Returns the encoded value of an [ExtraData], see [Ast::to_bytes].
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub(crate) fn extra_data(&self, index: usize) -> u64 {
+        u64::from_le_bytes(
+            self.extra_data[index * 8..(index + 1) * 8]
+                .try_into()
+                .unwrap(),
+        )
+    }
+
+    /// Returns the comments in the order of [AstComments].
+    pub(crate) fn comments(
+        &self,
+    ) -> impl Iterator<Item = (NodeId, CommentPlacement, Comment)> + '_ {
+        self.comments
+            .chunks_exact(COMMENT_LEN)
+            // The comments are validated by `read`.
+            .map(|record| self.comment(record).unwrap())
+    }
+
+    fn comment(&self, record: &[u8]) -> Result<(NodeId, CommentPlacement, Comment)> {
+        let invalid = AstDecodeError::InvalidData;
+        let mut reader = Reader { bytes: record };
+        let node = reader.u32()?;
+        if node as usize >= self.node_count() {
+            return Err(invalid("comment of a node out of bounds"));
+        }
+        let placement = match reader.u8()? {
+            0 => CommentPlacement::Leading,
+            1 => CommentPlacement::Trailing,
+            _ => return Err(invalid("invalid comment placement")),
+        };
+        let kind = match reader.u8()? {
+            0 => CommentKind::Line,
+            1 => CommentKind::Block,
+            _ => return Err(invalid("invalid comment kind")),
+        };
+        let lo = BytePos(reader.u32()?);
+        let hi = BytePos(reader.u32()?);
+        let text = reader.u32()?;
+        if text as usize >= self.utf8.len() {
+            return Err(invalid("index out of bounds"));
+        }
+        let comment = Comment {
+            kind,
+            span: Span { lo, hi },
+            text: Idx::from_usize(text as usize),
+        };
+        Ok((NodeId::from_raw(node), placement, comment))
+    }
+
+    fn validate_comments(&self) -> Result<()> {
+        let mut last = None;
+        for record in self.comments.chunks_exact(COMMENT_LEN) {
+            let (node_id, placement, _) = self.comment(record)?;
+            let key = (node_id, placement);
+            // The lookups rely on the order.
+            if last.is_some_and(|last| last > key) {
+                return Err(AstDecodeError::InvalidData("unsorted comments"));
+            }
+            last = Some(key);
+        }
+        Ok(())
+    }
+
+    fn validate_fields(&self) -> Result<Vec<Option<FieldType>>> {
+        for index in 0..self.node_count() {
+            let node = self.node(index);
+            for field in node.kind.schema().fields {
+                if let FieldLocation::Inline { offset, size } = field.location {
+                    let raw = unsafe { FieldLocation::read_inline(&node, offset, size) };
+                    self.validate_inline(field.ty, raw)?;
+                }
+            }
+        }
+
+        let types = extra_data_types(
+            (0..self.node_count()).map(|index| {
+                let node = self.node(index);
+                let schema = node.kind.schema();
+                let start = schema
+                    .has_extra_data()
+                    .then(|| unsafe { node.data.extra_data_start }.raw());
+                (schema, start)
+            }),
+            self.extra_data_count(),
+            |index| {
+                let value = self.extra_data(index);
+                (value as u32, (value >> 32) as u32)
+            },
+        )
+        .map_err(AstDecodeError::InvalidData)?;
+        for (index, ty) in types.iter().enumerate() {
+            if let Some(ty) = ty {
+                self.validate_extra_data(*ty, self.extra_data(index))?;
+            }
+        }
+
+        Ok(types)
+    }
+
+    fn validate_extra_data(&self, ty: FieldType, value: u64) -> Result<()> {
+        let index = |value: u64, count: usize| -> Result<u32> {
+            if value < count as u64 {
+                Ok(value as u32)
+            } else {
+                Err(AstDecodeError::InvalidData("index out of bounds"))
+            }
+        };
+        let optional_index = |value: u64, count: usize| -> Result<()> {
+            if value != u32::MAX as u64 {
+                index(value, count)?;
+            }
+            Ok(())
+        };
+
+        match ty {
+            FieldType::Node(node_type) => {
+                self.validate_node(node_type, index(value, self.node_count())?)?
+            }
+            FieldType::OptionalNode(node_type) => {
+                if value != u32::MAX as u64 {
+                    self.validate_node(node_type, index(value, self.node_count())?)?;
+                }
+            }
+            // The bounds are checked by `extra_data_types`.
+            FieldType::List(_) | FieldType::OptionalList(_) => {}
+            FieldType::Enum(enum_type) => {
+                index(value, enum_type.variants.len())?;
+            }
+            FieldType::OptionalEnum(enum_type) => {
+                if value != u64::MAX {
+                    index(value, enum_type.variants.len())?;
+                }
+            }
+            FieldType::Span | FieldType::Number => {}
+            FieldType::Bool => {
+                index(value, 2)?;
+            }
+            FieldType::Utf8 => {
+                index(value, self.utf8.len())?;
+            }
+            FieldType::OptionalUtf8 => optional_index(value, self.utf8.len())?,
+            FieldType::Wtf8 => {
+                index(value, self.wtf8.len())?;
+            }
+            FieldType::OptionalWtf8 => optional_index(value, self.wtf8.len())?,
+            FieldType::BigInt => {
+                index(value, self.bigints.len())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_inline(&self, ty: FieldType, raw: u32) -> Result<()> {
+        let in_bounds = |value: u32, count: usize| (value as usize) < count;
+        let valid = match ty {
+            FieldType::Node(node_type) => {
+                in_bounds(raw, self.node_count()) && self.validate_node(node_type, raw).is_ok()
+            }
+            FieldType::OptionalNode(node_type) => {
+                raw == u32::MAX
+                    || (in_bounds(raw, self.node_count())
+                        && self.validate_node(node_type, raw).is_ok())
+            }
+            FieldType::Enum(enum_type) => in_bounds(raw, enum_type.variants.len()),
+            FieldType::BigInt => in_bounds(raw, self.bigints.len()),
+            _ => true,
+        };
+        match valid {
+            true => Ok(()),
+            false => Err(AstDecodeError::InvalidData("invalid inline field")),
+        }
+    }
+
+    fn validate_node(&self, node_type: &NodeType, node_id: u32) -> Result<()> {
+        let kind = self.node(node_id as usize).kind;
+        if !node_type.kinds.contains(&kind) {
+            return Err(AstDecodeError::InvalidData("unexpected node kind"));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the type of every [ExtraData], which is `None` if no field refers
+/// to it. `sub_range` reads the start and the end of a (optional) list.
+fn extra_data_types(
+    nodes: impl Iterator<Item = (&'static NodeSchema, Option<u32>)>,
+    len: usize,
+    sub_range: impl Fn(usize) -> (u32, u32),
+) -> std::result::Result<Vec<Option<FieldType>>, &'static str> {
+    let mut types = vec![None; len];
+    let assign = |types: &mut Vec<Option<FieldType>>, index: usize, ty: FieldType| {
+        let slot = types.get_mut(index).ok_or("extra data out of bounds")?;
+        match slot {
+            None => *slot = Some(ty),
+            Some(prev) if *prev == ty => {}
+            Some(_) => return Err("extra data of two types"),
+        }
+        Ok(())
+    };
+
+    for (schema, start) in nodes {
+        let Some(start) = start else {
+            continue;
+        };
+        for field in schema.fields {
+            let FieldLocation::ExtraData(offset) = field.location else {
+                continue;
+            };
+            let index = start as usize + offset as usize;
+            assign(&mut types, index, field.ty)?;
+
+            let (element, (start, end)) = match field.ty {
+                FieldType::List(element) => (element, sub_range(index)),
+                FieldType::OptionalList(element) => match sub_range(index) {
+                    (_, u32::MAX) => continue,
+                    range => (element, range),
+                },
+                _ => continue,
+            };
+            if start > end || end as usize > len {
+                return Err("invalid list");
+            }
+            for index in start..end {
+                assign(&mut types, index as usize, *element)?;
+            }
+        }
+    }
+
+    Ok(types)
+}
+
+/// A hash of the schema, so that ASTs are only decoded with the same
+/// definitions.
+fn schema_hash() -> u64 {
+    fn hash_type(hash: u64, ty: FieldType) -> u64 {
+        let tag = |tag: u8| fnv1a(hash, &[tag]);
+        match ty {
+            FieldType::Node(node_type) => fnv1a(tag(0), node_type.name.as_bytes()),
+            FieldType::OptionalNode(node_type) => fnv1a(tag(1), node_type.name.as_bytes()),
+            FieldType::List(element) => hash_type(tag(2), *element),
+            FieldType::OptionalList(element) => hash_type(tag(3), *element),
+            FieldType::Enum(enum_type) | FieldType::OptionalEnum(enum_type) => {
+                let hash = tag(if matches!(ty, FieldType::Enum(_)) {
+                    4
+                } else {
+                    5
+                });
+                enum_type
+                    .variants
+                    .iter()
+                    .fold(fnv1a(hash, enum_type.name.as_bytes()), |hash, variant| {
+                        fnv1a(hash, variant.as_bytes())
+                    })
+            }
+            FieldType::Span => tag(6),
+            FieldType::Bool => tag(7),
+            FieldType::Number => tag(8),
+            FieldType::Utf8 => tag(9),
+            FieldType::OptionalUtf8 => tag(10),
+            FieldType::Wtf8 => tag(11),
+            FieldType::OptionalWtf8 => tag(12),
+            FieldType::BigInt => tag(13),
+        }
+    }
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for schema in NODE_SCHEMAS {
+        hash = fnv1a(hash, schema.name.as_bytes());
+        for field in schema.fields {
+            hash = fnv1a(hash, field.name.as_bytes());
+            hash = hash_type(hash, field.ty);
+            hash = match field.location {
+                FieldLocation::Inline { offset, size } => fnv1a(hash, &[0, offset, size]),
+                FieldLocation::ExtraData(offset) => fnv1a(fnv1a(hash, &[1]), &offset.to_le_bytes()),
+            };
+        }
+    }
+    hash
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Returns `true` if `bytes` is well-formed WTF-8, i.e. UTF-8 which may
+/// contain unpaired surrogates.
+fn is_wtf8(mut bytes: &[u8]) -> bool {
+    let mut after_lead_surrogate = false;
+    loop {
+        match std::str::from_utf8(bytes) {
+            Ok(_) => return true,
+            Err(error) => {
+                let (valid, rest) = bytes.split_at(error.valid_up_to());
+                if !valid.is_empty() {
+                    after_lead_surrogate = false;
+                }
+                // A surrogate is encoded as `ED A0..=BF 80..=BF`.
+                let [0xED, second @ 0xA0..=0xBF, 0x80..=0xBF, ..] = *rest else {
+                    return false;
+                };
+                let is_lead = second < 0xB0;
+                // A pair of surrogates must be encoded as a supplementary
+                // code point.
+                if after_lead_surrogate && !is_lead {
+                    return false;
+                }
+                after_lead_surrogate = is_lead;
+                bytes = &rest[3..];
+            }
+        }
+    }
+}
+
+fn put_u16(buf: &mut Vec<u8>, value: u16) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(buf: &mut Vec<u8>, value: u32) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u64(buf: &mut Vec<u8>, value: u64) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    put_u32(buf, bytes.len() as u32);
+    buf.extend_from_slice(bytes);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(AstDecodeError::UnexpectedEof);
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    /// Reads a count, followed by as many records of `len` bytes.
+    fn records(&mut self, len: usize) -> Result<&'a [u8]> {
+        let count = self.u32()? as usize;
+        self.take(
+            count
+                .checked_mul(len)
+                .ok_or(AstDecodeError::UnexpectedEof)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::common::DUMMY_SP;
+
+    use super::*;
+    use crate::{
+        AstView, BinaryOp, Expr, FieldValue, GetSpan, Lit, NodeIdTrait, NodeKind, OptionalUtf8Ref,
+        Script, Stmt,
+    };
+
+    fn build() -> (Ast, Script) {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let sym = ast.add_utf8("a");
+        let left = ast.expr_ident(DUMMY_SP, sym, false);
+        let value = ast.add_bigint(BigIntValue::from(-42));
+        let raw = ast.add_optional_utf8(Some("-42n"));
+        let right = ast.expr_lit_big_int(DUMMY_SP, value, raw);
+        let bin = ast.expr_bin_expr(
+            Span::new(BytePos(1), BytePos(9)),
+            BinaryOp::Add,
+            left,
+            right,
+        );
+        let text = ast.add_utf8(" c ");
+        let comment = Comment {
+            kind: CommentKind::Block,
+            span: Span::new(BytePos(10), BytePos(17)),
+            text,
+        };
+        ast.add_trailing_comment(bin, comment);
+        let value = ast.add_wtf8(Wtf8::from_str("b"));
+        let str = ast.expr_lit_str(DUMMY_SP, value, OptionalUtf8Ref::none());
+        let stmts = [
+            ast.stmt_expr_stmt(DUMMY_SP, bin),
+            ast.stmt_expr_stmt(DUMMY_SP, str),
+        ];
+        let body = ast.add_typed_sub_range(stmts);
+        let script = ast.script(DUMMY_SP, body, OptionalUtf8Ref::none());
+        (ast, script)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let (ast, script) = build();
+        let bytes = ast.to_bytes().unwrap();
+        let decoded = Ast::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.to_bytes().unwrap(), bytes);
+
+        let stmts = script
+            .body(&decoded)
+            .iter()
+            .map(|id| decoded.get_node_in_sub_range(id))
+            .collect::<Vec<_>>();
+        let Stmt::Expr(bin) = stmts[0] else { panic!() };
+        let Expr::Bin(bin) = bin.expr(&decoded) else {
+            panic!()
+        };
+        assert_eq!(bin.span(&decoded), Span::new(BytePos(1), BytePos(9)));
+        assert_eq!(bin.op(&decoded), BinaryOp::Add);
+        let [comment] = decoded.trailing_comments(bin) else {
+            panic!()
+        };
+        assert_eq!(comment.kind, CommentKind::Block);
+        assert_eq!(decoded.get_utf8(comment.text), " c ");
+        let Expr::Ident(ident) = bin.left(&decoded) else {
+            panic!()
+        };
+        assert_eq!(decoded.get_utf8(ident.sym(&decoded)), "a");
+        let Expr::Lit(Lit::BigInt(big_int)) = bin.right(&decoded) else {
+            panic!()
+        };
+        assert_eq!(
+            decoded.get_big_int(big_int.value(&decoded)),
+            &BigIntValue::from(-42)
+        );
+        let Stmt::Expr(str) = stmts[1] else { panic!() };
+        let Expr::Lit(Lit::Str(str)) = str.expr(&decoded) else {
+            panic!()
+        };
+        assert_eq!(decoded.get_wtf8(str.value(&decoded)), Wtf8::from_str("b"));
+    }
+
+    #[test]
+    fn test_view() {
+        let (ast, script) = build();
+        let bytes = ast.to_bytes().unwrap();
+        let view = AstView::new(&bytes).unwrap();
+
+        assert_eq!(view.node_count(), ast.nodes.len());
+        for node_id in view.node_ids() {
+            assert_eq!(view.kind(node_id), ast.nodes.kind(node_id));
+            assert_eq!(view.span(node_id), ast.nodes.get(node_id).unwrap().span);
+            assert!(view.children(node_id).eq(ast.children(node_id)));
+        }
+
+        let Some(FieldValue::List(Some(body))) = view.field(script.node_id(), "body") else {
+            panic!()
+        };
+        let [FieldValue::Node(Some(bin)), FieldValue::Node(Some(str))] =
+            body.collect::<Vec<_>>()[..]
+        else {
+            panic!()
+        };
+        let Some(FieldValue::Node(Some(bin))) = view.field(bin, "expr") else {
+            panic!()
+        };
+        assert_eq!(view.field(bin, "op"), Some(FieldValue::Enum(Some("Add"))));
+        assert_eq!(view.field(bin, "unknown"), None);
+        let Some(FieldValue::Node(Some(ident))) = view.field(bin, "left") else {
+            panic!()
+        };
+        assert_eq!(view.field(ident, "sym"), Some(FieldValue::Utf8(Some("a"))));
+        let Some(FieldValue::Node(Some(big_int))) = view.field(bin, "right") else {
+            panic!()
+        };
+        assert_eq!(
+            view.field(big_int, "value"),
+            Some(FieldValue::BigInt(BigIntValue::from(-42)))
+        );
+        assert_eq!(
+            view.field(big_int, "raw"),
+            Some(FieldValue::Utf8(Some("-42n")))
+        );
+        let Some(FieldValue::Node(Some(str))) = view.field(str, "expr") else {
+            panic!()
+        };
+        assert_eq!(
+            view.field(str, "value"),
+            Some(FieldValue::Wtf8(Some(Wtf8::from_str("b"))))
+        );
+        assert_eq!(view.field(str, "raw"), Some(FieldValue::Utf8(None)));
+
+        let [(node_id, CommentPlacement::Trailing, comment)] =
+            view.comments().collect::<Vec<_>>()[..]
+        else {
+            panic!()
+        };
+        assert_eq!(node_id, bin);
+        assert_eq!(view.get_utf8(comment.text), " c ");
+    }
+
+    #[test]
+    fn test_encode_invalid_arena() {
+        let (mut ast, _) = build();
+        // The fields of the nodes point past the end of the extra data.
+        ast.extra_data.truncate(1);
+        assert_eq!(
+            ast.to_bytes(),
+            Err(AstEncodeError::InvalidData("extra data out of bounds"))
+        );
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let (ast, _) = build();
+        let bytes = ast.to_bytes().unwrap();
+
+        let mut invalid = bytes.clone();
+        invalid[0] = b'X';
+        assert_eq!(
+            Ast::from_bytes(&invalid).err(),
+            Some(AstDecodeError::InvalidMagic)
+        );
+
+        let mut invalid = bytes.clone();
+        invalid[MAGIC.len()..MAGIC.len() + 4].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+        assert_eq!(
+            Ast::from_bytes(&invalid).err(),
+            Some(AstDecodeError::UnsupportedVersion(FORMAT_VERSION + 1))
+        );
+
+        let mut invalid = bytes.clone();
+        invalid[MAGIC.len() + 4] ^= 1;
+        assert_eq!(
+            Ast::from_bytes(&invalid).err(),
+            Some(AstDecodeError::SchemaMismatch)
+        );
+
+        let mut invalid = bytes.clone();
+        *invalid.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            Ast::from_bytes(&invalid).err(),
+            Some(AstDecodeError::ChecksumMismatch)
+        );
+
+        assert_eq!(
+            Ast::from_bytes(&bytes[..HEADER_LEN - 1]).err(),
+            Some(AstDecodeError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn test_invalid_payload() {
+        let (ast, _) = build();
+        let bytes = ast.to_bytes().unwrap();
+        let with_payload = |payload: &[u8]| {
+            let mut bytes = bytes[..HEADER_LEN - 8].to_vec();
+            bytes.extend_from_slice(&fnv1a(FNV_OFFSET_BASIS, payload).to_le_bytes());
+            bytes.extend_from_slice(payload);
+            bytes
+        };
+
+        // The first node is the identifier, whose kind is replaced.
+        let kind = 4..6;
+        let mut payload = bytes[HEADER_LEN..].to_vec();
+        payload[kind.clone()].copy_from_slice(&u16::MAX.to_le_bytes());
+        assert_eq!(
+            Ast::from_bytes(&with_payload(&payload)).err(),
+            Some(AstDecodeError::InvalidData("unknown node kind"))
+        );
+
+        let script = (NodeKind::Script.schema_index() as u16).to_le_bytes();
+        payload[kind].copy_from_slice(&script);
+        assert!(matches!(
+            Ast::from_bytes(&with_payload(&payload)),
+            Err(AstDecodeError::InvalidData(_))
+        ));
+
+        let mut payload = bytes[HEADER_LEN..].to_vec();
+        payload.push(0);
+        assert_eq!(
+            Ast::from_bytes(&with_payload(&payload)).err(),
+            Some(AstDecodeError::InvalidData("trailing bytes"))
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Comments stored in the [Ast], attached to the nodes as leading or trailing
+//! comments.
+//!
+//! Unlike [swc_core::common::comments::Comments], which is keyed by
+//! [BytePos], the comments are keyed by [NodeId], so they travel with the AST
+//! when it's compacted or encoded into bytes.
+
+use std::collections::{BTreeMap, btree_map};
+
+use swc_core::common::{BytePos, comments::CommentKind};
+
+use crate::{Ast, NodeId, NodeIdTrait, Span, Utf8Ref};
+
+/// A comment, whose text doesn't include the `//` or `/* */` delimiters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comment {
+    pub kind: CommentKind,
+    pub span: Span,
+    pub text: Utf8Ref,
+}
+
+/// Whether a comment is printed before or after the node it's attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CommentPlacement {
+    Leading,
+    Trailing,
+}
+
+/// The comments of an [Ast], sorted by the node and the placement. Comments
+/// of the same node and placement are in source order.
+///
+/// The comments are grouped in a map rather than kept in a sorted [Vec], as
+/// transforms add and take the comments of one node at a time, which would
+/// shift the whole [Vec] every time.
+#[derive(Default, Clone)]
+pub(crate) struct AstComments {
+    entries: BTreeMap<(NodeId, CommentPlacement), Vec<Comment>>,
+    len: usize,
+}
+
+impl AstComments {
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    fn get(&self, key: (NodeId, CommentPlacement)) -> &[Comment] {
+        self.entries.get(&key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Adds a comment after the existing comments of the same node and
+    /// placement.
+    pub(crate) fn push(&mut self, key: (NodeId, CommentPlacement), comment: Comment) {
+        self.entries.entry(key).or_default().push(comment);
+        self.len += 1;
+    }
+
+    fn take(&mut self, key: (NodeId, CommentPlacement)) -> Vec<Comment> {
+        let comments = self.entries.remove(&key).unwrap_or_default();
+        self.len -= comments.len();
+        comments
+    }
+
+    /// Rebuilds the comments with new node ids, dropping the comments of the
+    /// nodes mapped to `None`.
+    pub(crate) fn remap(&mut self, mut f: impl FnMut(NodeId) -> Option<NodeId>) {
+        let entries = std::mem::take(&mut self.entries);
+        self.len = 0;
+        for ((node, placement), comments) in entries {
+            if let Some(node) = f(node) {
+                for comment in comments {
+                    self.push((node, placement), comment);
+                }
+            }
+        }
+    }
+
+    /// Drops the comments of the nodes from `nodes` on.
+    pub(crate) fn truncate(&mut self, nodes: usize) {
+        let dropped = self
+            .entries
+            .split_off(&(NodeId::from_raw(nodes as u32), CommentPlacement::Leading));
+        self.len -= dropped.values().map(Vec::len).sum::<usize>();
+    }
+}
+
+/// An iterator over the comments of an [Ast], see [Ast::comments].
+pub struct CommentsIter<'a> {
+    entries: btree_map::Iter<'a, (NodeId, CommentPlacement), Vec<Comment>>,
+    current: Option<(NodeId, CommentPlacement, std::slice::Iter<'a, Comment>)>,
+    len: usize,
+}
+
+impl<'a> Iterator for CommentsIter<'a> {
+    type Item = (NodeId, CommentPlacement, &'a Comment);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((node, placement, comments)) = &mut self.current
+                && let Some(comment) = comments.next()
+            {
+                self.len -= 1;
+                return Some((*node, *placement, comment));
+            }
+            let (&(node, placement), comments) = self.entries.next()?;
+            self.current = Some((node, placement, comments.iter()));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl ExactSizeIterator for CommentsIter<'_> {}
+
+impl Ast {
+    /// Returns the comments printed before `node`.
+    #[inline]
+    pub fn leading_comments<N: NodeIdTrait>(&self, node: N) -> &[Comment] {
+        self.comments
+            .get((node.node_id(), CommentPlacement::Leading))
+    }
+
+    /// Returns the comments printed after `node`.
+    #[inline]
+    pub fn trailing_comments<N: NodeIdTrait>(&self, node: N) -> &[Comment] {
+        self.comments
+            .get((node.node_id(), CommentPlacement::Trailing))
+    }
+
+    /// Adds a comment after the other leading comments of `node`.
+    pub fn add_leading_comment<N: NodeIdTrait>(&mut self, node: N, comment: Comment) {
+        self.comments
+            .push((node.node_id(), CommentPlacement::Leading), comment);
+    }
+
+    /// Adds a comment after the other trailing comments of `node`.
+    pub fn add_trailing_comment<N: NodeIdTrait>(&mut self, node: N, comment: Comment) {
+        self.comments
+            .push((node.node_id(), CommentPlacement::Trailing), comment);
+    }
+
+    /// Removes the leading comments of `node`, e.g. to move them to the node
+    /// replacing it.
+    pub fn take_leading_comments<N: NodeIdTrait>(&mut self, node: N) -> Vec<Comment> {
+        self.comments
+            .take((node.node_id(), CommentPlacement::Leading))
+    }
+
+    /// Removes the trailing comments of `node`.
+    pub fn take_trailing_comments<N: NodeIdTrait>(&mut self, node: N) -> Vec<Comment> {
+        self.comments
+            .take((node.node_id(), CommentPlacement::Trailing))
+    }
+
+    /// Returns all the comments, ordered by the node they're attached to.
+    pub fn comments(&self) -> CommentsIter<'_> {
+        CommentsIter {
+            entries: self.comments.entries.iter(),
+            current: None,
+            len: self.comments.len,
+        }
+    }
+
+    /// Attaches comments collected by their position, as the lexer does, to
+    /// the nodes reachable from `root`.
+    ///
+    /// A leading comment at `pos` is attached to the outermost node starting
+    /// at `pos`, and a trailing comment to the outermost node ending at `pos`.
+    /// Comments without such a node, e.g. the one in `f(/* a */)`, are
+    /// attached as trailing comments to the innermost node containing `pos`,
+    /// or to `root`.
+    pub fn attach_comments<N: NodeIdTrait>(
+        &mut self,
+        root: N,
+        comments: impl IntoIterator<Item = (CommentPlacement, BytePos, Comment)>,
+    ) {
+        let root = root.node_id();
+        let mut starts = Vec::new();
+        let mut ends = Vec::new();
+        let mut stack = vec![root];
+        while let Some(node_id) = stack.pop() {
+            let span = self.nodes.get(node_id).unwrap().span;
+            starts.push((span.lo, node_id));
+            ends.push((span.hi, node_id));
+            let len = stack.len();
+            stack.extend(self.children(node_id));
+            stack[len..].reverse();
+        }
+        // The sorts are stable, so the outermost node comes first among the
+        // nodes at the same position.
+        starts.sort_by_key(|(pos, _)| *pos);
+        ends.sort_by_key(|(pos, _)| *pos);
+
+        let find = |table: &[(BytePos, NodeId)], pos: BytePos| {
+            let index = table.partition_point(|(p, _)| *p < pos);
+            table
+                .get(index)
+                .filter(|(p, _)| *p == pos)
+                .map(|(_, node_id)| *node_id)
+        };
+        for (placement, pos, comment) in comments {
+            let table = match placement {
+                CommentPlacement::Leading => &starts,
+                CommentPlacement::Trailing => &ends,
+            };
+            let key = match find(table, pos) {
+                Some(node_id) => (node_id, placement),
+                None => (
+                    self.innermost_node_at(root, pos),
+                    CommentPlacement::Trailing,
+                ),
+            };
+            self.comments.push(key, comment);
+        }
+    }
+
+    fn innermost_node_at(&self, root: NodeId, pos: BytePos) -> NodeId {
+        let mut node_id = root;
+        while let Some(child) = self.children(node_id).find(|&child| {
+            let span = self.nodes.get(child).unwrap().span;
+            span.lo <= pos && pos <= span.hi
+        }) {
+            node_id = child;
+        }
+        node_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::DUMMY_SP;
+
+    use super::*;
+    use crate::{Expr, StringAllocator};
+
+    fn span(lo: u32, hi: u32) -> Span {
+        Span::new(BytePos(lo), BytePos(hi))
+    }
+
+    fn comment(ast: &mut Ast, text: &str) -> Comment {
+        Comment {
+            kind: CommentKind::Block,
+            span: DUMMY_SP,
+            text: ast.add_utf8(text),
+        }
+    }
+
+    fn texts<'a>(ast: &'a Ast, comments: &[Comment]) -> Vec<&'a str> {
+        comments.iter().map(|c| ast.get_utf8(c.text)).collect()
+    }
+
+    #[test]
+    fn test_attach_comments() {
+        // `/* a */ (b /* b */) /* c */`
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let sym = ast.add_utf8("b");
+        let b = ast.expr_ident(span(9, 10), sym, false);
+        let root = ast.expr_paren_expr(span(8, 19), b);
+        let Expr::Paren(paren) = root else {
+            unreachable!()
+        };
+
+        let comments = [
+            (
+                CommentPlacement::Leading,
+                BytePos(8),
+                comment(&mut ast, "a"),
+            ),
+            // Before `)`, where no node starts.
+            (
+                CommentPlacement::Leading,
+                BytePos(18),
+                comment(&mut ast, "b"),
+            ),
+            (
+                CommentPlacement::Trailing,
+                BytePos(19),
+                comment(&mut ast, "c"),
+            ),
+        ];
+        ast.attach_comments(root, comments);
+        assert_eq!(texts(&ast, ast.leading_comments(paren)), ["a"]);
+        assert_eq!(texts(&ast, ast.trailing_comments(paren)), ["b", "c"]);
+        assert!(ast.trailing_comments(b).is_empty());
+        assert_eq!(ast.comments().len(), 3);
+
+        let e = comment(&mut ast, "e");
+        ast.add_leading_comment(paren, e);
+        assert_eq!(texts(&ast, ast.leading_comments(paren)), ["a", "e"]);
+        let taken = ast.take_leading_comments(paren);
+        assert_eq!(texts(&ast, &taken), ["a", "e"]);
+        assert!(ast.leading_comments(paren).is_empty());
+        assert_eq!(texts(&ast, ast.trailing_comments(paren)), ["b", "c"]);
+
+        ast.add_leading_comment(b, e);
+        let all = ast.comments();
+        assert_eq!(all.len(), 3);
+        let all = all
+            .map(|(node, placement, c)| (node, placement, ast.get_utf8(c.text)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            all,
+            [
+                (b.node_id(), CommentPlacement::Leading, "e"),
+                (paren.node_id(), CommentPlacement::Trailing, "b"),
+                (paren.node_id(), CommentPlacement::Trailing, "c"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,329 @@
+//! Garbage collection of the arena.
+//!
+//! Transforms leave unreachable nodes behind, e.g. the `ParenExpr` replaced by
+//! its expression, and editing a list leaves its old elements in
+//! [Ast::extra_data]. [Ast::compact] copies the nodes reachable from a root
+//! into a new arena, using the generated schema to find the references.
+
+use oxc_index::IndexVec;
+
+use crate::{
+    Ast, ExtraData, NodeId, NodeIdTrait, OptionalNodeId,
+    node_id::{ExtraDataId, OptionalSubRange, SubRange},
+    schema::{FieldLocation, FieldType},
+};
+
+/// Maps the node ids before [Ast::compact] to the ones after it, so that side
+/// tables keyed by [NodeId] can be updated.
+pub struct NodeIdRemap {
+    new_ids: IndexVec<NodeId, OptionalNodeId>,
+    old_ids: IndexVec<NodeId, NodeId>,
+}
+
+impl NodeIdRemap {
+    /// Returns the new id of a node, or `None` if it was removed.
+    #[inline]
+    pub fn get(&self, old: NodeId) -> Option<NodeId> {
+        self.new_ids.get(old)?.to_option()
+    }
+
+    /// Returns the old id of a node.
+    #[inline]
+    pub fn old_id(&self, new: NodeId) -> NodeId {
+        self.old_ids[new]
+    }
+
+    /// The number of nodes after compaction.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.old_ids.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.old_ids.is_empty()
+    }
+
+    /// Rebuilds a table indexed by the old node ids, dropping the entries of
+    /// the removed nodes.
+    pub fn remap_table<T: Clone>(&self, table: &IndexVec<NodeId, T>) -> IndexVec<NodeId, T> {
+        self.old_ids.iter().map(|&old| table[old].clone()).collect()
+    }
+}
+
+impl Ast {
+    /// Removes the nodes and the extra data that are unreachable from `root`.
+    ///
+    /// The reachable nodes are renumbered in DFS pre-order, so `root` becomes
+    /// the first node and nodes are close to their children. Returns the
+    /// root with its new id and the mapping of the ids.
+    ///
+    /// BigInts and strings are kept as is, since the [crate::StringAllocator]
+    /// may be shared by other ASTs. The comments of the removed nodes are
+    /// dropped. Other tables keyed by node ids, e.g. the marks of the
+    /// hygiene, must be updated with the [NodeIdRemap].
+    pub fn compact<N: NodeIdTrait>(&mut self, root: N) -> (N, NodeIdRemap) {
+        let mut new_ids = IndexVec::with_capacity(self.nodes.len());
+        new_ids.resize(self.nodes.len(), OptionalNodeId::none());
+        let mut old_ids = IndexVec::<NodeId, NodeId>::new();
+
+        let mut stack = vec![root.node_id()];
+        let mut children = Vec::new();
+        while let Some(old) = stack.pop() {
+            // A node may be shared by several parents.
+            if new_ids[old].to_option().is_some() {
+                continue;
+            }
+            new_ids[old] = old_ids.push(old).into();
+
+            children.extend(self.children(old));
+            stack.extend(children.drain(..).rev());
+        }
+
+        let mut ast = Ast {
+            nodes: old_ids
+                .iter()
+                .map(|&old| self.nodes.get(old).unwrap())
+                .collect(),
+            extra_data: IndexVec::with_capacity(self.extra_data.len()),
+            bigint: std::mem::take(&mut self.bigint),
+            string_allocator: self.string_allocator.clone(),
+            comments: std::mem::take(&mut self.comments),
+        };
+        let remap = |old: OptionalNodeId| -> OptionalNodeId {
+            old.to_option()
+                .map_or(OptionalNodeId::none(), |old| new_ids[old])
+        };
+
+        for new in ast.nodes.indices() {
+            let mut node = ast.nodes.get(new).unwrap();
+            let schema = node.kind.schema();
+
+            for field in schema.fields {
+                let FieldLocation::Inline { offset, size } = field.location else {
+                    continue;
+                };
+                if let FieldType::Node(_) | FieldType::OptionalNode(_) = field.ty {
+                    unsafe {
+                        let old = OptionalNodeId::from_raw(FieldLocation::read_inline(
+                            &node, offset, size,
+                        ));
+                        FieldLocation::write_inline(&mut node, offset, size, remap(old).into_raw());
+                    }
+                }
+            }
+
+            if schema.has_extra_data() {
+                let old_start = unsafe { node.data.extra_data_start };
+                let new_start = ast.extra_data.next_idx();
+                let len = schema
+                    .fields
+                    .iter()
+                    .filter(|field| matches!(field.location, FieldLocation::ExtraData(_)))
+                    .count();
+                ast.extra_data
+                    .extend((0..len).map(|_| ExtraData { other: 0 }));
+
+                for field in schema.fields {
+                    let FieldLocation::ExtraData(offset) = field.location else {
+                        continue;
+                    };
+                    let data = self.extra_data[old_start + offset as usize];
+                    let data = unsafe {
+                        match field.ty {
+                            FieldType::List(element) => ExtraData {
+                                sub_range: self.copy_list(
+                                    &mut ast,
+                                    data.sub_range,
+                                    *element,
+                                    &remap,
+                                ),
+                            },
+                            FieldType::OptionalList(element) => {
+                                let range = data.optional_sub_range;
+                                match range.end.raw() == u32::MAX {
+                                    true => data,
+                                    false => {
+                                        let range = SubRange {
+                                            start: range.start,
+                                            end: range.end,
+                                        };
+                                        let range =
+                                            self.copy_list(&mut ast, range, *element, &remap);
+                                        ExtraData {
+                                            optional_sub_range: OptionalSubRange {
+                                                start: range.start,
+                                                end: range.end,
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                            ty => self.copy_extra_data(&ast, data, ty, &remap),
+                        }
+                    };
+                    ast.extra_data[new_start + offset as usize] = data;
+                }
+                node.data.extra_data_start = new_start;
+            }
+
+            ast.nodes.set(new, node);
+        }
+
+        ast.comments.remap(|old| new_ids[old].to_option());
+
+        *self = ast;
+        let root = unsafe { N::from_node_id_unchecked(NodeId::from_raw(0), self) };
+        (root, NodeIdRemap { new_ids, old_ids })
+    }
+
+    /// Copies the elements of a list to the end of the extra data of `ast`.
+    ///
+    /// # Safety
+    /// The elements must be of `element`.
+    unsafe fn copy_list(
+        &self,
+        ast: &mut Ast,
+        range: SubRange,
+        element: FieldType,
+        remap: &impl Fn(OptionalNodeId) -> OptionalNodeId,
+    ) -> SubRange {
+        let start = ast.extra_data.next_idx();
+        for index in range.start.index()..range.end.index() {
+            let data = self.extra_data[ExtraDataId::from_usize(index)];
+            let data = unsafe { self.copy_extra_data(ast, data, element, remap) };
+            ast.extra_data.push(data);
+        }
+        SubRange {
+            start,
+            end: ast.extra_data.next_idx(),
+        }
+    }
+
+    /// # Safety
+    /// `data` must store `ty`.
+    unsafe fn copy_extra_data(
+        &self,
+        ast: &Ast,
+        data: ExtraData,
+        ty: FieldType,
+        remap: &impl Fn(OptionalNodeId) -> OptionalNodeId,
+    ) -> ExtraData {
+        unsafe {
+            match ty {
+                FieldType::Node(node_type) => {
+                    let old = (node_type.codec.read)(data, self);
+                    (node_type.codec.write)(remap(old), ast)
+                }
+                FieldType::OptionalNode(node_type) => {
+                    let codec = node_type.optional_codec.unwrap();
+                    (codec.write)(remap((codec.read)(data, self)), ast)
+                }
+                _ => data,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::{DUMMY_SP, comments::CommentKind};
+
+    use super::*;
+    use crate::{ArrayLit, Comment, Expr, Ident, NodeKind, StringAllocator};
+
+    fn comment(ast: &mut Ast, text: &str) -> Comment {
+        Comment {
+            kind: CommentKind::Block,
+            span: DUMMY_SP,
+            text: ast.add_utf8(text),
+        }
+    }
+
+    fn ident(ast: &mut Ast, name: &str) -> Ident {
+        let sym = ast.add_utf8(name);
+        ast.ident(DUMMY_SP, sym, false)
+    }
+
+    /// Builds `[a, , b]` after an orphaned identifier, with a comment on `b`
+    /// and on the orphan.
+    fn build() -> (Ast, ArrayLit, Ident, Ident) {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let orphan = ident(&mut ast, "orphan");
+        let a = ident(&mut ast, "a");
+        let b = ident(&mut ast, "b");
+        let elems = [Some(a), None, Some(b)]
+            .map(|elem| elem.map(|elem| ast.expr_or_spread(DUMMY_SP, None, Expr::Ident(elem))));
+        let elems = ast.add_typed_sub_range(elems);
+        let array = ast.array_lit(DUMMY_SP, elems);
+
+        let text = comment(&mut ast, "b");
+        ast.add_leading_comment(b, text);
+        let text = comment(&mut ast, "orphan");
+        ast.add_leading_comment(orphan, text);
+        (ast, array, orphan, b)
+    }
+
+    fn elem_names(ast: &Ast, array: ArrayLit) -> Vec<Option<&str>> {
+        array
+            .elems(ast)
+            .iter()
+            .map(|at| {
+                ast.get_node_in_sub_range(at)
+                    .map(|elem| match elem.expr(ast) {
+                        Expr::Ident(ident) => ast.get_utf8(ident.sym(ast)),
+                        _ => unreachable!(),
+                    })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_compact() {
+        let (mut ast, array, orphan, b) = build();
+        assert_eq!(ast.node_count(), 6);
+
+        let (array, remap) = ast.compact(array);
+        assert_eq!(array.node_id().index(), 0);
+        assert_eq!(remap.len(), 5);
+        assert_eq!(ast.node_count(), 5);
+        assert_eq!(remap.get(orphan.node_id()), None);
+
+        // The hole of the array is kept.
+        assert_eq!(elem_names(&ast, array), [Some("a"), None, Some("b")]);
+        let elem = ast
+            .get_node_in_sub_range(array.elems(&ast).get(0).unwrap())
+            .unwrap();
+        assert_eq!(elem.spread(&ast), None);
+
+        // The comment of `b` moves to its new id, the one of the orphan is
+        // dropped.
+        let b = Ident::from_node_id(remap.get(b.node_id()).unwrap(), &ast);
+        assert_eq!(ast.leading_comments(b).len(), 1);
+        assert_eq!(ast.get_utf8(ast.leading_comments(b)[0].text), "b");
+        assert_eq!(ast.comments.len(), 1);
+    }
+
+    #[test]
+    fn test_remap() {
+        let (mut ast, array, _, _) = build();
+        let old_root = array.node_id();
+        let kinds = (0..ast.node_count())
+            .map(|index| ast.get_node(NodeId::from_usize(index)).unwrap().kind())
+            .collect::<IndexVec<NodeId, NodeKind>>();
+
+        let (array, remap) = ast.compact(array);
+        assert_eq!(remap.old_id(array.node_id()), old_root);
+        for new in (0..remap.len()).map(NodeId::from_usize) {
+            assert_eq!(remap.get(remap.old_id(new)), Some(new));
+        }
+
+        let kinds = remap.remap_table(&kinds);
+        assert_eq!(kinds.len(), ast.node_count());
+        for (new, kind) in kinds.iter_enumerated() {
+            assert_eq!(ast.get_node(new).unwrap().kind(), *kind);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::rc::Rc;
+
+use swc_core::common::Span;
+
+use crate::{
+    Accessibility, AssignOp, Ast, BigIntId, BinaryOp, ImportPhase, MetaPropKind, MethodKind,
+    OptionalUtf8Ref, OptionalWtf8Ref, TruePlusMinus, TsKeywordTypeKind, TsTypeOperatorOp,
+    TypedSubRange, UnaryOp, UpdateOp, Utf8Ref, VarDeclKind, Wtf8Ref, node_id::ExtraDataCompact,
+};
+
+/// Like [crate::CloneIn], but copies the node from one [Ast] into another.
+///
+/// Strings are interned into the string allocator of the destination, unless
+/// both ASTs share it, and bigints are copied.
+pub trait CloneInto: Sized {
+    type Cloned;
+
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned;
+}
+
+impl Ast {
+    /// Copies `node` of the AST `from` into this AST, and returns the copy.
+    ///
+    /// The comments of the nodes aren't copied.
+    #[inline]
+    pub fn import_subtree<T: CloneInto>(&mut self, from: &Ast, node: T) -> T::Cloned {
+        node.clone_into_ast(from, self)
+    }
+
+    #[inline]
+    fn shares_strings_with(&self, other: &Ast) -> bool {
+        Rc::ptr_eq(&self.string_allocator, &other.string_allocator)
+    }
+}
+
+macro_rules! impl_clone_into_trivial {
+    ($i:ident) => {
+        impl CloneInto for $i {
+            type Cloned = $i;
+
+            fn clone_into_ast(&self, _from: &Ast, _to: &mut Ast) -> Self::Cloned {
+                self.clone()
+            }
+        }
+    };
+}
+
+impl_clone_into_trivial!(Span);
+impl_clone_into_trivial!(bool);
+impl_clone_into_trivial!(f64);
+impl_clone_into_trivial!(UnaryOp);
+impl_clone_into_trivial!(UpdateOp);
+impl_clone_into_trivial!(BinaryOp);
+impl_clone_into_trivial!(AssignOp);
+impl_clone_into_trivial!(MetaPropKind);
+impl_clone_into_trivial!(ImportPhase);
+impl_clone_into_trivial!(VarDeclKind);
+impl_clone_into_trivial!(MethodKind);
+impl_clone_into_trivial!(Accessibility);
+impl_clone_into_trivial!(TruePlusMinus);
+impl_clone_into_trivial!(TsKeywordTypeKind);
+impl_clone_into_trivial!(TsTypeOperatorOp);
+
+macro_rules! impl_clone_into_string {
+    ($i:ident, $get:ident, $add:ident) => {
+        impl CloneInto for $i {
+            type Cloned = $i;
+
+            fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+                if from.shares_strings_with(to) {
+                    return *self;
+                }
+                to.$add(from.$get(*self))
+            }
+        }
+    };
+}
+
+impl_clone_into_string!(Utf8Ref, get_utf8, add_utf8);
+impl_clone_into_string!(OptionalUtf8Ref, get_optional_utf8, add_optional_utf8);
+impl_clone_into_string!(Wtf8Ref, get_wtf8, add_wtf8);
+impl_clone_into_string!(OptionalWtf8Ref, get_optional_wtf8, add_optional_wtf8);
+
+impl CloneInto for BigIntId {
+    type Cloned = BigIntId;
+
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        to.add_bigint(from.get_big_int(*self).clone())
+    }
+}
+
+impl<C, T: CloneInto<Cloned = C>> CloneInto for Option<T> {
+    type Cloned = Option<C>;
+
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        self.as_ref().map(|it| it.clone_into_ast(from, to))
+    }
+}
+
+impl<C: ExtraDataCompact, T: CloneInto<Cloned = C> + ExtraDataCompact> CloneInto
+    for TypedSubRange<T>
+{
+    type Cloned = TypedSubRange<C>;
+
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let mut ids = Vec::with_capacity(self.len());
+        for id in self.iter() {
+            let node = from.get_node_in_sub_range(id);
+            ids.push(node.clone_into_ast(from, to));
+        }
+        to.add_typed_sub_range(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::{atoms::wtf8::Wtf8, common::DUMMY_SP};
+
+    use super::*;
+    use crate::{EqIgnoreSpan, Expr, Lit, StringAllocator};
+
+    #[test]
+    fn test_import_subtree() {
+        let mut from = Ast::new(0, Rc::new(StringAllocator::default()));
+        let obj = from.add_utf8("console");
+        let obj = from.expr_ident(DUMMY_SP, obj, false);
+        let value = from.add_wtf8(Wtf8::from_str("hello"));
+        let str = from.expr_lit_str(DUMMY_SP, value, OptionalUtf8Ref::none());
+        let big_int = from.add_bigint(42.into());
+        let big_int = from.expr_lit_big_int(DUMMY_SP, big_int, OptionalUtf8Ref::none());
+        let elems = [obj, str, big_int].map(|expr| Some(from.expr_or_spread(DUMMY_SP, None, expr)));
+        let elems = from.add_typed_sub_range(elems);
+        let expr = from.expr_array_lit(DUMMY_SP, elems);
+
+        let mut to = Ast::new(0, Rc::new(StringAllocator::default()));
+        to.add_utf8("unrelated");
+        let imported = to.import_subtree(&from, expr);
+        assert!(expr.eq_ignore_span(&from, &imported, &to));
+
+        let Expr::Array(array) = imported else {
+            unreachable!()
+        };
+        let elems = array.elems(&to);
+        let elem = to.get_node_in_sub_range(elems.get(1).unwrap()).unwrap();
+        let Expr::Lit(Lit::Str(str)) = elem.expr(&to) else {
+            unreachable!()
+        };
+        assert_eq!(to.get_wtf8(str.value(&to)), Wtf8::from_str("hello"));
+    }
+}
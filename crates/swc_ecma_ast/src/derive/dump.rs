@@ -0,0 +1,176 @@
+use std::fmt::{Debug, Write};
+
+use swc_core::common::Span;
+
+use crate::{
+    Accessibility, AssignOp, Ast, BigIntId, BinaryOp, ImportPhase, MetaPropKind, MethodKind,
+    OptionalUtf8Ref, OptionalWtf8Ref, TruePlusMinus, TsKeywordTypeKind, TsTypeOperatorOp,
+    TypedSubRange, UnaryOp, UpdateOp, Utf8Ref, VarDeclKind, Wtf8Ref, node_id::ExtraDataCompact,
+};
+
+/// Prints a node as an indented tree with the kinds, spans and fields of the
+/// nodes, and the strings resolved.
+///
+/// ```text
+/// ExprStmt @ 0..2 {
+///     expr: Ident @ 0..1 {
+///         sym: "a",
+///         optional: false,
+///     },
+/// }
+/// ```
+pub trait Dump {
+    fn dump(&self, d: &mut Dumper);
+}
+
+impl Ast {
+    /// Returns the tree of `node`, see [Dump].
+    pub fn dump<N: Dump>(&self, node: N) -> String {
+        let mut d = Dumper {
+            ast: self,
+            out: String::new(),
+            indent: 0,
+        };
+        node.dump(&mut d);
+        d.out.push('\n');
+        d.out
+    }
+}
+
+pub struct Dumper<'a> {
+    ast: &'a Ast,
+    out: String,
+    indent: usize,
+}
+
+impl<'a> Dumper<'a> {
+    #[inline]
+    pub fn ast(&self) -> &'a Ast {
+        self.ast
+    }
+
+    /// Prints a struct node, whose fields are printed by `fields`.
+    pub fn node(&mut self, name: &str, span: Span, fields: impl FnOnce(&mut Self)) {
+        write!(self.out, "{name} @ {}..{}", span.lo.0, span.hi.0).unwrap();
+        let len = self.out.len();
+        self.out.push_str(" {\n");
+        let fields_start = self.out.len();
+        self.indent += 1;
+        fields(self);
+        self.indent -= 1;
+        if self.out.len() == fields_start {
+            self.out.truncate(len);
+        } else {
+            self.push_indent();
+            self.out.push('}');
+        }
+    }
+
+    pub fn field(&mut self, name: &str, value: &impl Dump) {
+        self.push_indent();
+        self.out.push_str(name);
+        self.out.push_str(": ");
+        value.dump(self);
+        self.out.push_str(",\n");
+    }
+
+    fn debug(&mut self, value: impl Debug) {
+        write!(self.out, "{value:?}").unwrap();
+    }
+
+    fn push_indent(&mut self) {
+        for _ in 0..self.indent {
+            self.out.push_str("    ");
+        }
+    }
+}
+
+macro_rules! impl_dump_debug {
+    ($i:ident) => {
+        impl Dump for $i {
+            #[inline]
+            fn dump(&self, d: &mut Dumper) {
+                d.debug(self)
+            }
+        }
+    };
+}
+
+impl_dump_debug!(bool);
+impl_dump_debug!(f64);
+impl_dump_debug!(UnaryOp);
+impl_dump_debug!(UpdateOp);
+impl_dump_debug!(BinaryOp);
+impl_dump_debug!(AssignOp);
+impl_dump_debug!(MetaPropKind);
+impl_dump_debug!(ImportPhase);
+impl_dump_debug!(VarDeclKind);
+impl_dump_debug!(MethodKind);
+impl_dump_debug!(Accessibility);
+impl_dump_debug!(TruePlusMinus);
+impl_dump_debug!(TsKeywordTypeKind);
+impl_dump_debug!(TsTypeOperatorOp);
+
+impl Dump for Span {
+    fn dump(&self, d: &mut Dumper) {
+        write!(d.out, "{}..{}", self.lo.0, self.hi.0).unwrap();
+    }
+}
+
+macro_rules! impl_dump_string {
+    ($i:ident, $get:ident, $optional:ident, $get_optional:ident) => {
+        impl Dump for $i {
+            #[inline]
+            fn dump(&self, d: &mut Dumper) {
+                d.debug(d.ast.$get(*self))
+            }
+        }
+
+        impl Dump for $optional {
+            fn dump(&self, d: &mut Dumper) {
+                match d.ast.$get_optional(*self) {
+                    Some(s) => d.debug(s),
+                    None => d.out.push_str("None"),
+                }
+            }
+        }
+    };
+}
+
+impl_dump_string!(Utf8Ref, get_utf8, OptionalUtf8Ref, get_optional_utf8);
+impl_dump_string!(Wtf8Ref, get_wtf8, OptionalWtf8Ref, get_optional_wtf8);
+
+impl Dump for BigIntId {
+    fn dump(&self, d: &mut Dumper) {
+        write!(d.out, "{}n", d.ast.get_big_int(*self)).unwrap();
+    }
+}
+
+impl<T: Dump> Dump for Option<T> {
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Some(it) => it.dump(d),
+            None => d.out.push_str("None"),
+        }
+    }
+}
+
+impl<T: Dump + ExtraDataCompact> Dump for TypedSubRange<T> {
+    fn dump(&self, d: &mut Dumper) {
+        if self.is_empty() {
+            d.out.push_str("[]");
+            return;
+        }
+
+        d.out.push_str("[\n");
+        d.indent += 1;
+        for id in self.iter() {
+            d.push_indent();
+            d.ast.get_node_in_sub_range(id).dump(d);
+            d.out.push_str(",\n");
+        }
+        d.indent -= 1;
+        d.push_indent();
+        d.out.push(']');
+    }
+}
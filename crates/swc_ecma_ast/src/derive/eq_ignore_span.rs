@@ -0,0 +1,205 @@
+use std::hash::{Hash, Hasher};
+
+use swc_core::common::Span;
+
+use crate::{
+    Accessibility, AssignOp, Ast, BigIntId, BinaryOp, ImportPhase, MetaPropKind, MethodKind,
+    OptionalUtf8Ref, OptionalWtf8Ref, TruePlusMinus, TsKeywordTypeKind, TsTypeOperatorOp,
+    TypedSubRange, UnaryOp, UpdateOp, Utf8Ref, VarDeclKind, Wtf8Ref, node_id::ExtraDataCompact,
+};
+
+/// Structural equality which ignores spans.
+///
+/// Typed nodes are ids, so `==` compares identity. This compares the fields
+/// recursively instead, and the strings and bigints by value, so the nodes
+/// may live in different [Ast]s.
+pub trait EqIgnoreSpan {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool;
+}
+
+/// Hashing consistent with [EqIgnoreSpan].
+pub trait HashIgnoreSpan {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H);
+}
+
+macro_rules! impl_ignore_span_trivial {
+    ($i:ident) => {
+        impl EqIgnoreSpan for $i {
+            #[inline]
+            fn eq_ignore_span(&self, _ast: &Ast, other: &Self, _other_ast: &Ast) -> bool {
+                self == other
+            }
+        }
+
+        impl HashIgnoreSpan for $i {
+            #[inline]
+            fn hash_ignore_span<H: Hasher>(&self, _ast: &Ast, state: &mut H) {
+                std::mem::discriminant(self).hash(state)
+            }
+        }
+    };
+}
+
+impl_ignore_span_trivial!(UnaryOp);
+impl_ignore_span_trivial!(UpdateOp);
+impl_ignore_span_trivial!(BinaryOp);
+impl_ignore_span_trivial!(AssignOp);
+impl_ignore_span_trivial!(MetaPropKind);
+impl_ignore_span_trivial!(ImportPhase);
+impl_ignore_span_trivial!(VarDeclKind);
+impl_ignore_span_trivial!(MethodKind);
+impl_ignore_span_trivial!(Accessibility);
+impl_ignore_span_trivial!(TruePlusMinus);
+impl_ignore_span_trivial!(TsKeywordTypeKind);
+impl_ignore_span_trivial!(TsTypeOperatorOp);
+
+impl EqIgnoreSpan for Span {
+    #[inline]
+    fn eq_ignore_span(&self, _ast: &Ast, _other: &Self, _other_ast: &Ast) -> bool {
+        true
+    }
+}
+
+impl HashIgnoreSpan for Span {
+    #[inline]
+    fn hash_ignore_span<H: Hasher>(&self, _ast: &Ast, _state: &mut H) {}
+}
+
+impl EqIgnoreSpan for bool {
+    #[inline]
+    fn eq_ignore_span(&self, _ast: &Ast, other: &Self, _other_ast: &Ast) -> bool {
+        self == other
+    }
+}
+
+impl HashIgnoreSpan for bool {
+    #[inline]
+    fn hash_ignore_span<H: Hasher>(&self, _ast: &Ast, state: &mut H) {
+        self.hash(state)
+    }
+}
+
+/// Numbers are compared by their bits, so `NaN` equals itself and `0` doesn't
+/// equal `-0`.
+impl EqIgnoreSpan for f64 {
+    #[inline]
+    fn eq_ignore_span(&self, _ast: &Ast, other: &Self, _other_ast: &Ast) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl HashIgnoreSpan for f64 {
+    #[inline]
+    fn hash_ignore_span<H: Hasher>(&self, _ast: &Ast, state: &mut H) {
+        self.to_bits().hash(state)
+    }
+}
+
+macro_rules! impl_ignore_span_by_value {
+    ($i:ident, $get:ident) => {
+        impl EqIgnoreSpan for $i {
+            #[inline]
+            fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+                ast.$get(*self) == other_ast.$get(*other)
+            }
+        }
+
+        impl HashIgnoreSpan for $i {
+            #[inline]
+            fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+                ast.$get(*self).hash(state)
+            }
+        }
+    };
+}
+
+impl_ignore_span_by_value!(Utf8Ref, get_utf8);
+impl_ignore_span_by_value!(OptionalUtf8Ref, get_optional_utf8);
+impl_ignore_span_by_value!(Wtf8Ref, get_wtf8);
+impl_ignore_span_by_value!(OptionalWtf8Ref, get_optional_wtf8);
+impl_ignore_span_by_value!(BigIntId, get_big_int);
+
+impl<T: EqIgnoreSpan> EqIgnoreSpan for Option<T> {
+    #[inline]
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: HashIgnoreSpan> HashIgnoreSpan for Option<T> {
+    #[inline]
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.is_some().hash(state);
+        if let Some(it) = self {
+            it.hash_ignore_span(ast, state);
+        }
+    }
+}
+
+impl<T: EqIgnoreSpan + ExtraDataCompact> EqIgnoreSpan for TypedSubRange<T> {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.len() == other.len()
+            && self.iter().zip(other.iter()).all(|(a, b)| {
+                let a = ast.get_node_in_sub_range(a);
+                let b = other_ast.get_node_in_sub_range(b);
+                a.eq_ignore_span(ast, &b, other_ast)
+            })
+    }
+}
+
+impl<T: HashIgnoreSpan + ExtraDataCompact> HashIgnoreSpan for TypedSubRange<T> {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.len().hash(state);
+        for id in self.iter() {
+            ast.get_node_in_sub_range(id).hash_ignore_span(ast, state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        hash::{DefaultHasher, Hasher},
+        rc::Rc,
+    };
+
+    use swc_core::common::{BytePos, DUMMY_SP};
+
+    use super::*;
+    use crate::{Expr, StringAllocator};
+
+    fn member(ast: &mut Ast, span: Span, obj: &str, prop: &str) -> Expr {
+        let obj = ast.add_utf8(obj);
+        let obj = ast.expr_ident(span, obj, false);
+        let prop = ast.add_utf8(prop);
+        let prop = ast.member_prop_ident_name(span, prop);
+        ast.expr_member_expr(span, obj, prop)
+    }
+
+    fn hash(expr: Expr, ast: &Ast) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        expr.hash_ignore_span(ast, &mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_eq_across_asts() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let a = member(&mut ast, DUMMY_SP, "console", "log");
+        let b = member(&mut ast, DUMMY_SP, "console", "error");
+
+        let mut other_ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        other_ast.add_utf8("unrelated");
+        let span = Span::new(BytePos(1), BytePos(12));
+        let c = member(&mut other_ast, span, "console", "log");
+
+        assert!(a.eq_ignore_span(&ast, &c, &other_ast));
+        assert_eq!(hash(a, &ast), hash(c, &other_ast));
+        assert!(!a.eq_ignore_span(&ast, &b, &ast));
+        assert!(!b.eq_ignore_span(&ast, &c, &other_ast));
+    }
+}
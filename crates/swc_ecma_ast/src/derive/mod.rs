@@ -1,5 +1,11 @@
 mod clone_in;
+mod clone_into;
+mod dump;
+mod eq_ignore_span;
 mod span;
 
 pub use clone_in::CloneIn;
+pub use clone_into::CloneInto;
+pub use dump::{Dump, Dumper};
+pub use eq_ignore_span::{EqIgnoreSpan, HashIgnoreSpan};
 pub use span::{GetSpan, SetSpan};
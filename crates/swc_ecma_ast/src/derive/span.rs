@@ -23,16 +23,14 @@ pub trait SetSpan {
 impl<T: NodeIdTrait> GetSpan for T {
     #[inline]
     fn span(&self, ast: &crate::Ast) -> crate::Span {
-        unsafe { ast.get_node_unchecked(self.node_id()).span }
+        unsafe { ast.nodes.span_unchecked(self.node_id()) }
     }
 }
 
 impl<T: NodeIdTrait> SetSpan for T {
     #[inline]
     fn set_span(&mut self, ast: &mut crate::Ast, span: crate::Span) {
-        unsafe {
-            ast.get_node_unchecked_mut(self.node_id()).span = span;
-        }
+        unsafe { ast.nodes.set_span_unchecked(self.node_id(), span) }
     }
 }
 
@@ -0,0 +1,89 @@
+use std::{ops::Deref, rc::Rc};
+
+use crate::Ast;
+
+/// A read-only [Ast], which can be sent to and shared with other threads.
+///
+/// [Ast] isn't `Send` nor `Sync`, as its [crate::StringAllocator] is shared
+/// by [Rc] and is mutated through shared references. A frozen AST owns its
+/// allocator, and only gives out `&Ast`, through which no string can be added.
+///
+/// ```ignore
+/// let modules = files
+///     .par_iter()
+///     .map(|file| parse(file).ast.freeze())
+///     .collect::<Vec<_>>();
+/// ```
+pub struct FrozenAst(Ast);
+
+// SAFETY: `Ast::freeze` makes the `Rc` of the string allocator unique, and
+// `&Ast` neither clones it nor adds strings. The nodes and the extra data are
+// plain data.
+unsafe impl Send for FrozenAst {}
+unsafe impl Sync for FrozenAst {}
+
+impl Ast {
+    /// Freezes the AST, so that it can be shared across threads.
+    ///
+    /// If the [crate::StringAllocator] is shared with other ASTs or with the
+    /// parser, its strings are copied into a new allocator. The ids of the
+    /// strings stay the same.
+    pub fn freeze(mut self) -> FrozenAst {
+        if Rc::get_mut(&mut self.string_allocator).is_none() {
+            self.string_allocator = Rc::new(self.string_allocator.copy_strings());
+        }
+        FrozenAst(self)
+    }
+}
+
+impl FrozenAst {
+    /// Returns the AST, so that it can be modified again.
+    pub fn into_inner(self) -> Ast {
+        self.0
+    }
+}
+
+impl Deref for FrozenAst {
+    type Target = Ast;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::{atoms::wtf8::Wtf8, common::DUMMY_SP};
+
+    use super::*;
+    use crate::{Expr, Lit, OptionalUtf8Ref, StringAllocator};
+
+    #[test]
+    fn test_share_across_threads() {
+        let string_allocator = Rc::new(StringAllocator::default());
+        let mut ast = Ast::new(0, string_allocator.clone());
+        let value = ast.add_wtf8(Wtf8::from_str("value"));
+        let expr = ast.expr_lit_str(DUMMY_SP, value, OptionalUtf8Ref::none());
+        let sym = ast.add_utf8("sym");
+
+        // The allocator is still shared, so the strings are copied.
+        let ast = ast.freeze();
+        string_allocator.add_utf8("added later");
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    let Expr::Lit(Lit::Str(str)) = expr else {
+                        unreachable!()
+                    };
+                    assert_eq!(ast.get_wtf8(str.value(&ast)), Wtf8::from_str("value"));
+                    assert_eq!(ast.get_utf8(sym), "sym");
+                });
+            }
+        });
+
+        let mut ast = ast.into_inner();
+        assert_eq!(ast.add_utf8("sym"), sym);
+    }
+}
@@ -1,4 +1,9 @@
-#![allow(unused, clippy::useless_conversion, clippy::identity_op)]
+#![allow(
+    unused,
+    clippy::useless_conversion,
+    clippy::identity_op,
+    clippy::too_many_arguments
+)]
 use crate::*;
 use swc_core::common::Span;
 impl Ast {
@@ -122,6 +127,40 @@ impl Ast {
         ))
     }
     #[inline]
+    pub fn module_item_module_decl_ts_import_equals_decl(
+        &mut self,
+        span: Span,
+        is_export: bool,
+        is_type_only: bool,
+        id: Ident,
+        module_ref: TsModuleRef,
+    ) -> ModuleItem {
+        ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(
+            self.ts_import_equals_decl(span, is_export, is_type_only, id, module_ref)
+                .into(),
+        ))
+    }
+    #[inline]
+    pub fn module_item_module_decl_ts_export_assignment(
+        &mut self,
+        span: Span,
+        expr: Expr,
+    ) -> ModuleItem {
+        ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(
+            self.ts_export_assignment(span, expr).into(),
+        ))
+    }
+    #[inline]
+    pub fn module_item_module_decl_ts_namespace_export_decl(
+        &mut self,
+        span: Span,
+        id: Ident,
+    ) -> ModuleItem {
+        ModuleItem::ModuleDecl(ModuleDecl::TsNamespaceExport(
+            self.ts_namespace_export_decl(span, id).into(),
+        ))
+    }
+    #[inline]
     pub fn module_item_stmt_block_stmt(
         &mut self,
         span: Span,
@@ -307,6 +346,64 @@ impl Ast {
         )))
     }
     #[inline]
+    pub fn module_item_stmt_decl_ts_interface_decl(
+        &mut self,
+        span: Span,
+        id: Ident,
+        declare: bool,
+        type_params: Option<TsTypeParamDecl>,
+        extends: TypedSubRange<TsExprWithTypeArgs>,
+        body: TsInterfaceBody,
+    ) -> ModuleItem {
+        ModuleItem::Stmt(Stmt::Decl(Decl::TsInterface(
+            self.ts_interface_decl(span, id, declare, type_params, extends, body)
+                .into(),
+        )))
+    }
+    #[inline]
+    pub fn module_item_stmt_decl_ts_type_alias_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        id: Ident,
+        type_params: Option<TsTypeParamDecl>,
+        type_ann: TsType,
+    ) -> ModuleItem {
+        ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(
+            self.ts_type_alias_decl(span, declare, id, type_params, type_ann)
+                .into(),
+        )))
+    }
+    #[inline]
+    pub fn module_item_stmt_decl_ts_enum_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        is_const: bool,
+        id: Ident,
+        members: TypedSubRange<TsEnumMember>,
+    ) -> ModuleItem {
+        ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(
+            self.ts_enum_decl(span, declare, is_const, id, members)
+                .into(),
+        )))
+    }
+    #[inline]
+    pub fn module_item_stmt_decl_ts_module_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        global: bool,
+        namespace: bool,
+        id: TsModuleName,
+        body: Option<TsNamespaceBody>,
+    ) -> ModuleItem {
+        ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(
+            self.ts_module_decl(span, declare, global, namespace, id, body)
+                .into(),
+        )))
+    }
+    #[inline]
     pub fn module_item_stmt_expr_stmt(&mut self, span: Span, expr: Expr) -> ModuleItem {
         ModuleItem::Stmt(Stmt::Expr(self.expr_stmt(span, expr).into()))
     }
@@ -362,6 +459,28 @@ impl Ast {
         ModuleDecl::ExportAll(self.export_all(span, src, type_only, with).into())
     }
     #[inline]
+    pub fn module_decl_ts_import_equals_decl(
+        &mut self,
+        span: Span,
+        is_export: bool,
+        is_type_only: bool,
+        id: Ident,
+        module_ref: TsModuleRef,
+    ) -> ModuleDecl {
+        ModuleDecl::TsImportEquals(
+            self.ts_import_equals_decl(span, is_export, is_type_only, id, module_ref)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn module_decl_ts_export_assignment(&mut self, span: Span, expr: Expr) -> ModuleDecl {
+        ModuleDecl::TsExportAssignment(self.ts_export_assignment(span, expr).into())
+    }
+    #[inline]
+    pub fn module_decl_ts_namespace_export_decl(&mut self, span: Span, id: Ident) -> ModuleDecl {
+        ModuleDecl::TsNamespaceExport(self.ts_namespace_export_decl(span, id).into())
+    }
+    #[inline]
     pub fn import_decl(
         &mut self,
         span: Span,
@@ -617,6 +736,21 @@ impl Ast {
         DefaultDecl::Fn(self.fn_expr(span, ident, function).into())
     }
     #[inline]
+    pub fn default_decl_ts_interface_decl(
+        &mut self,
+        span: Span,
+        id: Ident,
+        declare: bool,
+        type_params: Option<TsTypeParamDecl>,
+        extends: TypedSubRange<TsExprWithTypeArgs>,
+        body: TsInterfaceBody,
+    ) -> DefaultDecl {
+        DefaultDecl::TsInterfaceDecl(
+            self.ts_interface_decl(span, id, declare, type_params, extends, body)
+                .into(),
+        )
+    }
+    #[inline]
     pub fn export_default_expr(&mut self, span: Span, expr: Expr) -> ExportDefaultExpr {
         let _f0 = self.add_extra(expr.to_extra_data());
         ExportDefaultExpr(self.add_node(AstNode {
@@ -796,6 +930,64 @@ impl Ast {
         Stmt::Decl(Decl::Using(self.using_decl(span, is_await, decls).into()))
     }
     #[inline]
+    pub fn stmt_decl_ts_interface_decl(
+        &mut self,
+        span: Span,
+        id: Ident,
+        declare: bool,
+        type_params: Option<TsTypeParamDecl>,
+        extends: TypedSubRange<TsExprWithTypeArgs>,
+        body: TsInterfaceBody,
+    ) -> Stmt {
+        Stmt::Decl(Decl::TsInterface(
+            self.ts_interface_decl(span, id, declare, type_params, extends, body)
+                .into(),
+        ))
+    }
+    #[inline]
+    pub fn stmt_decl_ts_type_alias_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        id: Ident,
+        type_params: Option<TsTypeParamDecl>,
+        type_ann: TsType,
+    ) -> Stmt {
+        Stmt::Decl(Decl::TsTypeAlias(
+            self.ts_type_alias_decl(span, declare, id, type_params, type_ann)
+                .into(),
+        ))
+    }
+    #[inline]
+    pub fn stmt_decl_ts_enum_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        is_const: bool,
+        id: Ident,
+        members: TypedSubRange<TsEnumMember>,
+    ) -> Stmt {
+        Stmt::Decl(Decl::TsEnum(
+            self.ts_enum_decl(span, declare, is_const, id, members)
+                .into(),
+        ))
+    }
+    #[inline]
+    pub fn stmt_decl_ts_module_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        global: bool,
+        namespace: bool,
+        id: TsModuleName,
+        body: Option<TsNamespaceBody>,
+    ) -> Stmt {
+        Stmt::Decl(Decl::TsModule(
+            self.ts_module_decl(span, declare, global, namespace, id, body)
+                .into(),
+        ))
+    }
+    #[inline]
     pub fn stmt_expr_stmt(&mut self, span: Span, expr: Expr) -> Stmt {
         Stmt::Expr(self.expr_stmt(span, expr).into())
     }
@@ -1087,8 +1279,13 @@ impl Ast {
         ForHead::UsingDecl(self.using_decl(span, is_await, decls).into())
     }
     #[inline]
-    pub fn for_head_pat_binding_ident(&mut self, span: Span, id: Ident) -> ForHead {
-        ForHead::Pat(Pat::Ident(self.binding_ident(span, id).into()))
+    pub fn for_head_pat_binding_ident(
+        &mut self,
+        span: Span,
+        id: Ident,
+        type_ann: Option<TsTypeAnn>,
+    ) -> ForHead {
+        ForHead::Pat(Pat::Ident(self.binding_ident(span, id, type_ann).into()))
     }
     #[inline]
     pub fn for_head_pat_array_pat(
@@ -1096,12 +1293,23 @@ impl Ast {
         span: Span,
         elems: TypedSubRange<Option<Pat>>,
         optional: bool,
+        type_ann: Option<TsTypeAnn>,
     ) -> ForHead {
-        ForHead::Pat(Pat::Array(self.array_pat(span, elems, optional).into()))
+        ForHead::Pat(Pat::Array(
+            self.array_pat(span, elems, optional, type_ann).into(),
+        ))
     }
     #[inline]
-    pub fn for_head_pat_rest_pat(&mut self, span: Span, dot3_token: Span, arg: Pat) -> ForHead {
-        ForHead::Pat(Pat::Rest(self.rest_pat(span, dot3_token, arg).into()))
+    pub fn for_head_pat_rest_pat(
+        &mut self,
+        span: Span,
+        dot3_token: Span,
+        arg: Pat,
+        type_ann: Option<TsTypeAnn>,
+    ) -> ForHead {
+        ForHead::Pat(Pat::Rest(
+            self.rest_pat(span, dot3_token, arg, type_ann).into(),
+        ))
     }
     #[inline]
     pub fn for_head_pat_object_pat(
@@ -1109,8 +1317,11 @@ impl Ast {
         span: Span,
         props: TypedSubRange<ObjectPatProp>,
         optional: bool,
+        type_ann: Option<TsTypeAnn>,
     ) -> ForHead {
-        ForHead::Pat(Pat::Object(self.object_pat(span, props, optional).into()))
+        ForHead::Pat(Pat::Object(
+            self.object_pat(span, props, optional, type_ann).into(),
+        ))
     }
     #[inline]
     pub fn for_head_pat_assign_pat(&mut self, span: Span, left: Pat, right: Expr) -> ForHead {
@@ -1233,9 +1444,10 @@ impl Ast {
         span: Span,
         callee: Callee,
         args: TypedSubRange<ExprOrSpread>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> ForHead {
         ForHead::Pat(Pat::Expr(Expr::Call(
-            self.call_expr(span, callee, args).into(),
+            self.call_expr(span, callee, args, type_args).into(),
         )))
     }
     #[inline]
@@ -1244,9 +1456,10 @@ impl Ast {
         span: Span,
         callee: Expr,
         args: Option<TypedSubRange<ExprOrSpread>>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> ForHead {
         ForHead::Pat(Pat::Expr(Expr::New(
-            self.new_expr(span, callee, args).into(),
+            self.new_expr(span, callee, args, type_args).into(),
         )))
     }
     #[inline]
@@ -1327,9 +1540,15 @@ impl Ast {
         ForHead::Pat(Pat::Expr(Expr::Tpl(self.tpl(span, exprs, quasis).into())))
     }
     #[inline]
-    pub fn for_head_pat_expr_tagged_tpl(&mut self, span: Span, tag: Expr, tpl: Tpl) -> ForHead {
+    pub fn for_head_pat_expr_tagged_tpl(
+        &mut self,
+        span: Span,
+        tag: Expr,
+        type_params: Option<TsTypeParamInstantiation>,
+        tpl: Tpl,
+    ) -> ForHead {
         ForHead::Pat(Pat::Expr(Expr::TaggedTpl(
-            self.tagged_tpl(span, tag, tpl).into(),
+            self.tagged_tpl(span, tag, type_params, tpl).into(),
         )))
     }
     #[inline]
@@ -1340,10 +1559,20 @@ impl Ast {
         body: BlockStmtOrExpr,
         is_async: bool,
         is_generator: bool,
+        type_params: Option<TsTypeParamDecl>,
+        return_type: Option<TsTypeAnn>,
     ) -> ForHead {
         ForHead::Pat(Pat::Expr(Expr::Arrow(
-            self.arrow_expr(span, params, body, is_async, is_generator)
-                .into(),
+            self.arrow_expr(
+                span,
+                params,
+                body,
+                is_async,
+                is_generator,
+                type_params,
+                return_type,
+            )
+            .into(),
         )))
     }
     #[inline]
@@ -1433,6 +1662,62 @@ impl Ast {
         )))
     }
     #[inline]
+    pub fn for_head_pat_expr_ts_type_assertion(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> ForHead {
+        ForHead::Pat(Pat::Expr(Expr::TsTypeAssertion(
+            self.ts_type_assertion(span, expr, type_ann).into(),
+        )))
+    }
+    #[inline]
+    pub fn for_head_pat_expr_ts_const_assertion(&mut self, span: Span, expr: Expr) -> ForHead {
+        ForHead::Pat(Pat::Expr(Expr::TsConstAssertion(
+            self.ts_const_assertion(span, expr).into(),
+        )))
+    }
+    #[inline]
+    pub fn for_head_pat_expr_ts_non_null_expr(&mut self, span: Span, expr: Expr) -> ForHead {
+        ForHead::Pat(Pat::Expr(Expr::TsNonNull(
+            self.ts_non_null_expr(span, expr).into(),
+        )))
+    }
+    #[inline]
+    pub fn for_head_pat_expr_ts_as_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> ForHead {
+        ForHead::Pat(Pat::Expr(Expr::TsAs(
+            self.ts_as_expr(span, expr, type_ann).into(),
+        )))
+    }
+    #[inline]
+    pub fn for_head_pat_expr_ts_instantiation(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: TsTypeParamInstantiation,
+    ) -> ForHead {
+        ForHead::Pat(Pat::Expr(Expr::TsInstantiation(
+            self.ts_instantiation(span, expr, type_args).into(),
+        )))
+    }
+    #[inline]
+    pub fn for_head_pat_expr_ts_satisfies_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> ForHead {
+        ForHead::Pat(Pat::Expr(Expr::TsSatisfies(
+            self.ts_satisfies_expr(span, expr, type_ann).into(),
+        )))
+    }
+    #[inline]
     pub fn for_head_pat_expr_private_name(&mut self, span: Span, name: Utf8Ref) -> ForHead {
         ForHead::Pat(Pat::Expr(Expr::PrivateName(
             self.private_name(span, name).into(),
@@ -1567,8 +1852,11 @@ impl Ast {
         span: Span,
         callee: Callee,
         args: TypedSubRange<ExprOrSpread>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> VarDeclOrExpr {
-        VarDeclOrExpr::Expr(Expr::Call(self.call_expr(span, callee, args).into()))
+        VarDeclOrExpr::Expr(Expr::Call(
+            self.call_expr(span, callee, args, type_args).into(),
+        ))
     }
     #[inline]
     pub fn var_decl_or_expr_expr_new_expr(
@@ -1576,8 +1864,11 @@ impl Ast {
         span: Span,
         callee: Expr,
         args: Option<TypedSubRange<ExprOrSpread>>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> VarDeclOrExpr {
-        VarDeclOrExpr::Expr(Expr::New(self.new_expr(span, callee, args).into()))
+        VarDeclOrExpr::Expr(Expr::New(
+            self.new_expr(span, callee, args, type_args).into(),
+        ))
     }
     #[inline]
     pub fn var_decl_or_expr_expr_seq_expr(
@@ -1656,9 +1947,12 @@ impl Ast {
         &mut self,
         span: Span,
         tag: Expr,
+        type_params: Option<TsTypeParamInstantiation>,
         tpl: Tpl,
     ) -> VarDeclOrExpr {
-        VarDeclOrExpr::Expr(Expr::TaggedTpl(self.tagged_tpl(span, tag, tpl).into()))
+        VarDeclOrExpr::Expr(Expr::TaggedTpl(
+            self.tagged_tpl(span, tag, type_params, tpl).into(),
+        ))
     }
     #[inline]
     pub fn var_decl_or_expr_expr_arrow_expr(
@@ -1668,10 +1962,20 @@ impl Ast {
         body: BlockStmtOrExpr,
         is_async: bool,
         is_generator: bool,
+        type_params: Option<TsTypeParamDecl>,
+        return_type: Option<TsTypeAnn>,
     ) -> VarDeclOrExpr {
         VarDeclOrExpr::Expr(Expr::Arrow(
-            self.arrow_expr(span, params, body, is_async, is_generator)
-                .into(),
+            self.arrow_expr(
+                span,
+                params,
+                body,
+                is_async,
+                is_generator,
+                type_params,
+                return_type,
+            )
+            .into(),
         ))
     }
     #[inline]
@@ -1759,6 +2063,66 @@ impl Ast {
         ))
     }
     #[inline]
+    pub fn var_decl_or_expr_expr_ts_type_assertion(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> VarDeclOrExpr {
+        VarDeclOrExpr::Expr(Expr::TsTypeAssertion(
+            self.ts_type_assertion(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
+    pub fn var_decl_or_expr_expr_ts_const_assertion(
+        &mut self,
+        span: Span,
+        expr: Expr,
+    ) -> VarDeclOrExpr {
+        VarDeclOrExpr::Expr(Expr::TsConstAssertion(
+            self.ts_const_assertion(span, expr).into(),
+        ))
+    }
+    #[inline]
+    pub fn var_decl_or_expr_expr_ts_non_null_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+    ) -> VarDeclOrExpr {
+        VarDeclOrExpr::Expr(Expr::TsNonNull(self.ts_non_null_expr(span, expr).into()))
+    }
+    #[inline]
+    pub fn var_decl_or_expr_expr_ts_as_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> VarDeclOrExpr {
+        VarDeclOrExpr::Expr(Expr::TsAs(self.ts_as_expr(span, expr, type_ann).into()))
+    }
+    #[inline]
+    pub fn var_decl_or_expr_expr_ts_instantiation(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: TsTypeParamInstantiation,
+    ) -> VarDeclOrExpr {
+        VarDeclOrExpr::Expr(Expr::TsInstantiation(
+            self.ts_instantiation(span, expr, type_args).into(),
+        ))
+    }
+    #[inline]
+    pub fn var_decl_or_expr_expr_ts_satisfies_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> VarDeclOrExpr {
+        VarDeclOrExpr::Expr(Expr::TsSatisfies(
+            self.ts_satisfies_expr(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
     pub fn var_decl_or_expr_expr_private_name(
         &mut self,
         span: Span,
@@ -1821,6 +2185,64 @@ impl Ast {
         Decl::Using(self.using_decl(span, is_await, decls).into())
     }
     #[inline]
+    pub fn decl_ts_interface_decl(
+        &mut self,
+        span: Span,
+        id: Ident,
+        declare: bool,
+        type_params: Option<TsTypeParamDecl>,
+        extends: TypedSubRange<TsExprWithTypeArgs>,
+        body: TsInterfaceBody,
+    ) -> Decl {
+        Decl::TsInterface(
+            self.ts_interface_decl(span, id, declare, type_params, extends, body)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn decl_ts_type_alias_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        id: Ident,
+        type_params: Option<TsTypeParamDecl>,
+        type_ann: TsType,
+    ) -> Decl {
+        Decl::TsTypeAlias(
+            self.ts_type_alias_decl(span, declare, id, type_params, type_ann)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn decl_ts_enum_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        is_const: bool,
+        id: Ident,
+        members: TypedSubRange<TsEnumMember>,
+    ) -> Decl {
+        Decl::TsEnum(
+            self.ts_enum_decl(span, declare, is_const, id, members)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn decl_ts_module_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        global: bool,
+        namespace: bool,
+        id: TsModuleName,
+        body: Option<TsNamespaceBody>,
+    ) -> Decl {
+        Decl::TsModule(
+            self.ts_module_decl(span, declare, global, namespace, id, body)
+                .into(),
+        )
+    }
+    #[inline]
     pub fn fn_decl(
         &mut self,
         span: Span,
@@ -1879,13 +2301,19 @@ impl Ast {
         }))
     }
     #[inline]
-    pub fn var_declarator(&mut self, span: Span, name: Pat, init: Option<Expr>) -> VarDeclarator {
+    pub fn var_declarator(
+        &mut self,
+        span: Span,
+        name: Pat,
+        init: Option<Expr>,
+        definite: bool,
+    ) -> VarDeclarator {
         let _f0 = self.add_extra(name.to_extra_data());
         let _f1 = self.add_extra(init.to_extra_data());
         VarDeclarator(self.add_node(AstNode {
             span,
             kind: NodeKind::VarDeclarator,
-            inline_data: 0u32.into(),
+            inline_data: (0u32 | definite as u32).into(),
             data: NodeData {
                 extra_data_start: _f0,
             },
@@ -1969,8 +2397,9 @@ impl Ast {
         span: Span,
         callee: Callee,
         args: TypedSubRange<ExprOrSpread>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> Expr {
-        Expr::Call(self.call_expr(span, callee, args).into())
+        Expr::Call(self.call_expr(span, callee, args, type_args).into())
     }
     #[inline]
     pub fn expr_new_expr(
@@ -1978,8 +2407,9 @@ impl Ast {
         span: Span,
         callee: Expr,
         args: Option<TypedSubRange<ExprOrSpread>>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> Expr {
-        Expr::New(self.new_expr(span, callee, args).into())
+        Expr::New(self.new_expr(span, callee, args, type_args).into())
     }
     #[inline]
     pub fn expr_seq_expr(&mut self, span: Span, exprs: TypedSubRange<Expr>) -> Expr {
@@ -2023,8 +2453,14 @@ impl Ast {
         Expr::Tpl(self.tpl(span, exprs, quasis).into())
     }
     #[inline]
-    pub fn expr_tagged_tpl(&mut self, span: Span, tag: Expr, tpl: Tpl) -> Expr {
-        Expr::TaggedTpl(self.tagged_tpl(span, tag, tpl).into())
+    pub fn expr_tagged_tpl(
+        &mut self,
+        span: Span,
+        tag: Expr,
+        type_params: Option<TsTypeParamInstantiation>,
+        tpl: Tpl,
+    ) -> Expr {
+        Expr::TaggedTpl(self.tagged_tpl(span, tag, type_params, tpl).into())
     }
     #[inline]
     pub fn expr_arrow_expr(
@@ -2034,10 +2470,20 @@ impl Ast {
         body: BlockStmtOrExpr,
         is_async: bool,
         is_generator: bool,
+        type_params: Option<TsTypeParamDecl>,
+        return_type: Option<TsTypeAnn>,
     ) -> Expr {
         Expr::Arrow(
-            self.arrow_expr(span, params, body, is_async, is_generator)
-                .into(),
+            self.arrow_expr(
+                span,
+                params,
+                body,
+                is_async,
+                is_generator,
+                type_params,
+                return_type,
+            )
+            .into(),
         )
     }
     #[inline]
@@ -2093,7 +2539,36 @@ impl Ast {
         Expr::JSXFragment(self.jsx_fragment(span, opening, children, closing).into())
     }
     #[inline]
-    pub fn expr_private_name(&mut self, span: Span, name: Utf8Ref) -> Expr {
+    pub fn expr_ts_type_assertion(&mut self, span: Span, expr: Expr, type_ann: TsType) -> Expr {
+        Expr::TsTypeAssertion(self.ts_type_assertion(span, expr, type_ann).into())
+    }
+    #[inline]
+    pub fn expr_ts_const_assertion(&mut self, span: Span, expr: Expr) -> Expr {
+        Expr::TsConstAssertion(self.ts_const_assertion(span, expr).into())
+    }
+    #[inline]
+    pub fn expr_ts_non_null_expr(&mut self, span: Span, expr: Expr) -> Expr {
+        Expr::TsNonNull(self.ts_non_null_expr(span, expr).into())
+    }
+    #[inline]
+    pub fn expr_ts_as_expr(&mut self, span: Span, expr: Expr, type_ann: TsType) -> Expr {
+        Expr::TsAs(self.ts_as_expr(span, expr, type_ann).into())
+    }
+    #[inline]
+    pub fn expr_ts_instantiation(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: TsTypeParamInstantiation,
+    ) -> Expr {
+        Expr::TsInstantiation(self.ts_instantiation(span, expr, type_args).into())
+    }
+    #[inline]
+    pub fn expr_ts_satisfies_expr(&mut self, span: Span, expr: Expr, type_ann: TsType) -> Expr {
+        Expr::TsSatisfies(self.ts_satisfies_expr(span, expr, type_ann).into())
+    }
+    #[inline]
+    pub fn expr_private_name(&mut self, span: Span, name: Utf8Ref) -> Expr {
         Expr::PrivateName(self.private_name(span, name).into())
     }
     #[inline]
@@ -2182,9 +2657,12 @@ impl Ast {
         &mut self,
         span: Span,
         key: PropName,
+        type_ann: Option<TsTypeAnn>,
         body: Option<BlockStmt>,
     ) -> PropOrSpread {
-        PropOrSpread::Prop(Prop::Getter(self.getter_prop(span, key, body).into()))
+        PropOrSpread::Prop(Prop::Getter(
+            self.getter_prop(span, key, type_ann, body).into(),
+        ))
     }
     #[inline]
     pub fn prop_or_spread_prop_setter_prop(
@@ -2369,9 +2847,11 @@ impl Ast {
         span: Span,
         callee: Callee,
         args: TypedSubRange<ExprOrSpread>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> CallExpr {
         let _f0 = self.add_extra(callee.to_extra_data());
         let _f1 = self.add_extra(args.to_extra_data());
+        let _f2 = self.add_extra(type_args.to_extra_data());
         CallExpr(self.add_node(AstNode {
             span,
             kind: NodeKind::CallExpr,
@@ -2387,9 +2867,11 @@ impl Ast {
         span: Span,
         callee: Expr,
         args: Option<TypedSubRange<ExprOrSpread>>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> NewExpr {
         let _f0 = self.add_extra(callee.to_extra_data());
         let _f1 = self.add_extra(args.to_extra_data());
+        let _f2 = self.add_extra(type_args.to_extra_data());
         NewExpr(self.add_node(AstNode {
             span,
             kind: NodeKind::NewExpr,
@@ -2419,11 +2901,15 @@ impl Ast {
         body: BlockStmtOrExpr,
         is_async: bool,
         is_generator: bool,
+        type_params: Option<TsTypeParamDecl>,
+        return_type: Option<TsTypeAnn>,
     ) -> ArrowExpr {
         let _f0 = self.add_extra(params.to_extra_data());
         let _f1 = self.add_extra(body.to_extra_data());
         let _f2 = self.add_extra(is_async.to_extra_data());
         let _f3 = self.add_extra(is_generator.to_extra_data());
+        let _f4 = self.add_extra(type_params.to_extra_data());
+        let _f5 = self.add_extra(return_type.to_extra_data());
         ArrowExpr(self.add_node(AstNode {
             span,
             kind: NodeKind::ArrowExpr,
@@ -2487,9 +2973,16 @@ impl Ast {
         }))
     }
     #[inline]
-    pub fn tagged_tpl(&mut self, span: Span, tag: Expr, tpl: Tpl) -> TaggedTpl {
+    pub fn tagged_tpl(
+        &mut self,
+        span: Span,
+        tag: Expr,
+        type_params: Option<TsTypeParamInstantiation>,
+        tpl: Tpl,
+    ) -> TaggedTpl {
         let _f0 = self.add_extra(tag.to_extra_data());
-        let _f1 = self.add_extra(tpl.to_extra_data());
+        let _f1 = self.add_extra(type_params.to_extra_data());
+        let _f2 = self.add_extra(tpl.to_extra_data());
         TaggedTpl(self.add_node(AstNode {
             span,
             kind: NodeKind::TaggedTpl,
@@ -2632,8 +3125,11 @@ impl Ast {
         span: Span,
         callee: Callee,
         args: TypedSubRange<ExprOrSpread>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> Callee {
-        Callee::Expr(Expr::Call(self.call_expr(span, callee, args).into()))
+        Callee::Expr(Expr::Call(
+            self.call_expr(span, callee, args, type_args).into(),
+        ))
     }
     #[inline]
     pub fn callee_expr_new_expr(
@@ -2641,8 +3137,11 @@ impl Ast {
         span: Span,
         callee: Expr,
         args: Option<TypedSubRange<ExprOrSpread>>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> Callee {
-        Callee::Expr(Expr::New(self.new_expr(span, callee, args).into()))
+        Callee::Expr(Expr::New(
+            self.new_expr(span, callee, args, type_args).into(),
+        ))
     }
     #[inline]
     pub fn callee_expr_seq_expr(&mut self, span: Span, exprs: TypedSubRange<Expr>) -> Callee {
@@ -2703,8 +3202,16 @@ impl Ast {
         Callee::Expr(Expr::Tpl(self.tpl(span, exprs, quasis).into()))
     }
     #[inline]
-    pub fn callee_expr_tagged_tpl(&mut self, span: Span, tag: Expr, tpl: Tpl) -> Callee {
-        Callee::Expr(Expr::TaggedTpl(self.tagged_tpl(span, tag, tpl).into()))
+    pub fn callee_expr_tagged_tpl(
+        &mut self,
+        span: Span,
+        tag: Expr,
+        type_params: Option<TsTypeParamInstantiation>,
+        tpl: Tpl,
+    ) -> Callee {
+        Callee::Expr(Expr::TaggedTpl(
+            self.tagged_tpl(span, tag, type_params, tpl).into(),
+        ))
     }
     #[inline]
     pub fn callee_expr_arrow_expr(
@@ -2714,10 +3221,20 @@ impl Ast {
         body: BlockStmtOrExpr,
         is_async: bool,
         is_generator: bool,
+        type_params: Option<TsTypeParamDecl>,
+        return_type: Option<TsTypeAnn>,
     ) -> Callee {
         Callee::Expr(Expr::Arrow(
-            self.arrow_expr(span, params, body, is_async, is_generator)
-                .into(),
+            self.arrow_expr(
+                span,
+                params,
+                body,
+                is_async,
+                is_generator,
+                type_params,
+                return_type,
+            )
+            .into(),
         ))
     }
     #[inline]
@@ -2801,6 +3318,53 @@ impl Ast {
         ))
     }
     #[inline]
+    pub fn callee_expr_ts_type_assertion(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> Callee {
+        Callee::Expr(Expr::TsTypeAssertion(
+            self.ts_type_assertion(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
+    pub fn callee_expr_ts_const_assertion(&mut self, span: Span, expr: Expr) -> Callee {
+        Callee::Expr(Expr::TsConstAssertion(
+            self.ts_const_assertion(span, expr).into(),
+        ))
+    }
+    #[inline]
+    pub fn callee_expr_ts_non_null_expr(&mut self, span: Span, expr: Expr) -> Callee {
+        Callee::Expr(Expr::TsNonNull(self.ts_non_null_expr(span, expr).into()))
+    }
+    #[inline]
+    pub fn callee_expr_ts_as_expr(&mut self, span: Span, expr: Expr, type_ann: TsType) -> Callee {
+        Callee::Expr(Expr::TsAs(self.ts_as_expr(span, expr, type_ann).into()))
+    }
+    #[inline]
+    pub fn callee_expr_ts_instantiation(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: TsTypeParamInstantiation,
+    ) -> Callee {
+        Callee::Expr(Expr::TsInstantiation(
+            self.ts_instantiation(span, expr, type_args).into(),
+        ))
+    }
+    #[inline]
+    pub fn callee_expr_ts_satisfies_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> Callee {
+        Callee::Expr(Expr::TsSatisfies(
+            self.ts_satisfies_expr(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
     pub fn callee_expr_private_name(&mut self, span: Span, name: Utf8Ref) -> Callee {
         Callee::Expr(Expr::PrivateName(self.private_name(span, name).into()))
     }
@@ -2978,8 +3542,11 @@ impl Ast {
         span: Span,
         callee: Callee,
         args: TypedSubRange<ExprOrSpread>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> BlockStmtOrExpr {
-        BlockStmtOrExpr::Expr(Expr::Call(self.call_expr(span, callee, args).into()))
+        BlockStmtOrExpr::Expr(Expr::Call(
+            self.call_expr(span, callee, args, type_args).into(),
+        ))
     }
     #[inline]
     pub fn block_stmt_or_expr_expr_new_expr(
@@ -2987,8 +3554,11 @@ impl Ast {
         span: Span,
         callee: Expr,
         args: Option<TypedSubRange<ExprOrSpread>>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> BlockStmtOrExpr {
-        BlockStmtOrExpr::Expr(Expr::New(self.new_expr(span, callee, args).into()))
+        BlockStmtOrExpr::Expr(Expr::New(
+            self.new_expr(span, callee, args, type_args).into(),
+        ))
     }
     #[inline]
     pub fn block_stmt_or_expr_expr_seq_expr(
@@ -3067,9 +3637,12 @@ impl Ast {
         &mut self,
         span: Span,
         tag: Expr,
+        type_params: Option<TsTypeParamInstantiation>,
         tpl: Tpl,
     ) -> BlockStmtOrExpr {
-        BlockStmtOrExpr::Expr(Expr::TaggedTpl(self.tagged_tpl(span, tag, tpl).into()))
+        BlockStmtOrExpr::Expr(Expr::TaggedTpl(
+            self.tagged_tpl(span, tag, type_params, tpl).into(),
+        ))
     }
     #[inline]
     pub fn block_stmt_or_expr_expr_arrow_expr(
@@ -3079,10 +3652,20 @@ impl Ast {
         body: BlockStmtOrExpr,
         is_async: bool,
         is_generator: bool,
+        type_params: Option<TsTypeParamDecl>,
+        return_type: Option<TsTypeAnn>,
     ) -> BlockStmtOrExpr {
         BlockStmtOrExpr::Expr(Expr::Arrow(
-            self.arrow_expr(span, params, body, is_async, is_generator)
-                .into(),
+            self.arrow_expr(
+                span,
+                params,
+                body,
+                is_async,
+                is_generator,
+                type_params,
+                return_type,
+            )
+            .into(),
         ))
     }
     #[inline]
@@ -3174,6 +3757,66 @@ impl Ast {
         ))
     }
     #[inline]
+    pub fn block_stmt_or_expr_expr_ts_type_assertion(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> BlockStmtOrExpr {
+        BlockStmtOrExpr::Expr(Expr::TsTypeAssertion(
+            self.ts_type_assertion(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
+    pub fn block_stmt_or_expr_expr_ts_const_assertion(
+        &mut self,
+        span: Span,
+        expr: Expr,
+    ) -> BlockStmtOrExpr {
+        BlockStmtOrExpr::Expr(Expr::TsConstAssertion(
+            self.ts_const_assertion(span, expr).into(),
+        ))
+    }
+    #[inline]
+    pub fn block_stmt_or_expr_expr_ts_non_null_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+    ) -> BlockStmtOrExpr {
+        BlockStmtOrExpr::Expr(Expr::TsNonNull(self.ts_non_null_expr(span, expr).into()))
+    }
+    #[inline]
+    pub fn block_stmt_or_expr_expr_ts_as_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> BlockStmtOrExpr {
+        BlockStmtOrExpr::Expr(Expr::TsAs(self.ts_as_expr(span, expr, type_ann).into()))
+    }
+    #[inline]
+    pub fn block_stmt_or_expr_expr_ts_instantiation(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: TsTypeParamInstantiation,
+    ) -> BlockStmtOrExpr {
+        BlockStmtOrExpr::Expr(Expr::TsInstantiation(
+            self.ts_instantiation(span, expr, type_args).into(),
+        ))
+    }
+    #[inline]
+    pub fn block_stmt_or_expr_expr_ts_satisfies_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> BlockStmtOrExpr {
+        BlockStmtOrExpr::Expr(Expr::TsSatisfies(
+            self.ts_satisfies_expr(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
     pub fn block_stmt_or_expr_expr_private_name(
         &mut self,
         span: Span,
@@ -3201,9 +3844,10 @@ impl Ast {
         &mut self,
         span: Span,
         id: Ident,
+        type_ann: Option<TsTypeAnn>,
     ) -> AssignTarget {
         AssignTarget::Simple(SimpleAssignTarget::Ident(
-            self.binding_ident(span, id).into(),
+            self.binding_ident(span, id, type_ann).into(),
         ))
     }
     #[inline]
@@ -3250,6 +3894,60 @@ impl Ast {
         ))
     }
     #[inline]
+    pub fn assign_target_simple_assign_target_ts_as_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> AssignTarget {
+        AssignTarget::Simple(SimpleAssignTarget::TsAs(
+            self.ts_as_expr(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
+    pub fn assign_target_simple_assign_target_ts_satisfies_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> AssignTarget {
+        AssignTarget::Simple(SimpleAssignTarget::TsSatisfies(
+            self.ts_satisfies_expr(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
+    pub fn assign_target_simple_assign_target_ts_non_null_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+    ) -> AssignTarget {
+        AssignTarget::Simple(SimpleAssignTarget::TsNonNull(
+            self.ts_non_null_expr(span, expr).into(),
+        ))
+    }
+    #[inline]
+    pub fn assign_target_simple_assign_target_ts_type_assertion(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> AssignTarget {
+        AssignTarget::Simple(SimpleAssignTarget::TsTypeAssertion(
+            self.ts_type_assertion(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
+    pub fn assign_target_simple_assign_target_ts_instantiation(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: TsTypeParamInstantiation,
+    ) -> AssignTarget {
+        AssignTarget::Simple(SimpleAssignTarget::TsInstantiation(
+            self.ts_instantiation(span, expr, type_args).into(),
+        ))
+    }
+    #[inline]
     pub fn assign_target_simple_assign_target_invalid(&mut self, span: Span) -> AssignTarget {
         AssignTarget::Simple(SimpleAssignTarget::Invalid(self.invalid(span).into()))
     }
@@ -3259,9 +3957,10 @@ impl Ast {
         span: Span,
         elems: TypedSubRange<Option<Pat>>,
         optional: bool,
+        type_ann: Option<TsTypeAnn>,
     ) -> AssignTarget {
         AssignTarget::Pat(AssignTargetPat::Array(
-            self.array_pat(span, elems, optional).into(),
+            self.array_pat(span, elems, optional, type_ann).into(),
         ))
     }
     #[inline]
@@ -3270,9 +3969,10 @@ impl Ast {
         span: Span,
         props: TypedSubRange<ObjectPatProp>,
         optional: bool,
+        type_ann: Option<TsTypeAnn>,
     ) -> AssignTarget {
         AssignTarget::Pat(AssignTargetPat::Object(
-            self.object_pat(span, props, optional).into(),
+            self.object_pat(span, props, optional, type_ann).into(),
         ))
     }
     #[inline]
@@ -3285,8 +3985,9 @@ impl Ast {
         span: Span,
         elems: TypedSubRange<Option<Pat>>,
         optional: bool,
+        type_ann: Option<TsTypeAnn>,
     ) -> AssignTargetPat {
-        AssignTargetPat::Array(self.array_pat(span, elems, optional).into())
+        AssignTargetPat::Array(self.array_pat(span, elems, optional, type_ann).into())
     }
     #[inline]
     pub fn assign_target_pat_object_pat(
@@ -3294,8 +3995,9 @@ impl Ast {
         span: Span,
         props: TypedSubRange<ObjectPatProp>,
         optional: bool,
+        type_ann: Option<TsTypeAnn>,
     ) -> AssignTargetPat {
-        AssignTargetPat::Object(self.object_pat(span, props, optional).into())
+        AssignTargetPat::Object(self.object_pat(span, props, optional, type_ann).into())
     }
     #[inline]
     pub fn assign_target_pat_invalid(&mut self, span: Span) -> AssignTargetPat {
@@ -3306,8 +4008,9 @@ impl Ast {
         &mut self,
         span: Span,
         id: Ident,
+        type_ann: Option<TsTypeAnn>,
     ) -> SimpleAssignTarget {
-        SimpleAssignTarget::Ident(self.binding_ident(span, id).into())
+        SimpleAssignTarget::Ident(self.binding_ident(span, id, type_ann).into())
     }
     #[inline]
     pub fn simple_assign_target_member_expr(
@@ -3345,6 +4048,50 @@ impl Ast {
         SimpleAssignTarget::OptChain(self.opt_chain_expr(span, optional, base).into())
     }
     #[inline]
+    pub fn simple_assign_target_ts_as_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> SimpleAssignTarget {
+        SimpleAssignTarget::TsAs(self.ts_as_expr(span, expr, type_ann).into())
+    }
+    #[inline]
+    pub fn simple_assign_target_ts_satisfies_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> SimpleAssignTarget {
+        SimpleAssignTarget::TsSatisfies(self.ts_satisfies_expr(span, expr, type_ann).into())
+    }
+    #[inline]
+    pub fn simple_assign_target_ts_non_null_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+    ) -> SimpleAssignTarget {
+        SimpleAssignTarget::TsNonNull(self.ts_non_null_expr(span, expr).into())
+    }
+    #[inline]
+    pub fn simple_assign_target_ts_type_assertion(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> SimpleAssignTarget {
+        SimpleAssignTarget::TsTypeAssertion(self.ts_type_assertion(span, expr, type_ann).into())
+    }
+    #[inline]
+    pub fn simple_assign_target_ts_instantiation(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: TsTypeParamInstantiation,
+    ) -> SimpleAssignTarget {
+        SimpleAssignTarget::TsInstantiation(self.ts_instantiation(span, expr, type_args).into())
+    }
+    #[inline]
     pub fn simple_assign_target_invalid(&mut self, span: Span) -> SimpleAssignTarget {
         SimpleAssignTarget::Invalid(self.invalid(span).into())
     }
@@ -3380,8 +4127,9 @@ impl Ast {
         span: Span,
         callee: Expr,
         args: TypedSubRange<ExprOrSpread>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> OptChainBase {
-        OptChainBase::Call(self.opt_call(span, callee, args).into())
+        OptChainBase::Call(self.opt_call(span, callee, args, type_args).into())
     }
     #[inline]
     pub fn opt_call(
@@ -3389,9 +4137,11 @@ impl Ast {
         span: Span,
         callee: Expr,
         args: TypedSubRange<ExprOrSpread>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> OptCall {
         let _f0 = self.add_extra(callee.to_extra_data());
         let _f1 = self.add_extra(args.to_extra_data());
+        let _f2 = self.add_extra(type_args.to_extra_data());
         OptCall(self.add_node(AstNode {
             span,
             kind: NodeKind::OptCall,
@@ -3419,12 +4169,16 @@ impl Ast {
         body: Option<BlockStmt>,
         is_generator: bool,
         is_async: bool,
+        type_params: Option<TsTypeParamDecl>,
+        return_type: Option<TsTypeAnn>,
     ) -> Function {
         let _f0 = self.add_extra(params.to_extra_data());
         let _f1 = self.add_extra(decorators.to_extra_data());
         let _f2 = self.add_extra(body.to_extra_data());
         let _f3 = self.add_extra(is_generator.to_extra_data());
         let _f4 = self.add_extra(is_async.to_extra_data());
+        let _f5 = self.add_extra(type_params.to_extra_data());
+        let _f6 = self.add_extra(return_type.to_extra_data());
         Function(self.add_node(AstNode {
             span,
             kind: NodeKind::Function,
@@ -3448,6 +4202,28 @@ impl Ast {
         }))
     }
     #[inline]
+    pub fn param_or_ts_param_prop_ts_param_prop(
+        &mut self,
+        span: Span,
+        decorators: TypedSubRange<Decorator>,
+        accessibility: Option<Accessibility>,
+        is_override: bool,
+        readonly: bool,
+        param: TsParamPropParam,
+    ) -> ParamOrTsParamProp {
+        ParamOrTsParamProp::TsParamProp(
+            self.ts_param_prop(
+                span,
+                decorators,
+                accessibility,
+                is_override,
+                readonly,
+                param,
+            )
+            .into(),
+        )
+    }
+    #[inline]
     pub fn param_or_ts_param_prop_param(
         &mut self,
         span: Span,
@@ -3464,11 +4240,17 @@ impl Ast {
         body: TypedSubRange<ClassMember>,
         super_class: Option<Expr>,
         is_abstract: bool,
+        type_params: Option<TsTypeParamDecl>,
+        super_type_params: Option<TsTypeParamInstantiation>,
+        implements: TypedSubRange<TsExprWithTypeArgs>,
     ) -> Class {
         let _f0 = self.add_extra(decorators.to_extra_data());
         let _f1 = self.add_extra(body.to_extra_data());
         let _f2 = self.add_extra(super_class.to_extra_data());
         let _f3 = self.add_extra(is_abstract.to_extra_data());
+        let _f4 = self.add_extra(type_params.to_extra_data());
+        let _f5 = self.add_extra(super_type_params.to_extra_data());
+        let _f6 = self.add_extra(implements.to_extra_data());
         Class(self.add_node(AstNode {
             span,
             kind: NodeKind::Class,
@@ -3485,8 +4267,13 @@ impl Ast {
         key: PropName,
         params: TypedSubRange<ParamOrTsParamProp>,
         body: Option<BlockStmt>,
+        accessibility: Option<Accessibility>,
+        is_optional: bool,
     ) -> ClassMember {
-        ClassMember::Constructor(self.constructor(span, key, params, body).into())
+        ClassMember::Constructor(
+            self.constructor(span, key, params, body, accessibility, is_optional)
+                .into(),
+        )
     }
     #[inline]
     pub fn class_member_class_method(
@@ -3496,10 +4283,24 @@ impl Ast {
         function: Function,
         kind: MethodKind,
         is_static: bool,
+        accessibility: Option<Accessibility>,
+        is_abstract: bool,
+        is_optional: bool,
+        is_override: bool,
     ) -> ClassMember {
         ClassMember::Method(
-            self.class_method(span, key, function, kind, is_static)
-                .into(),
+            self.class_method(
+                span,
+                key,
+                function,
+                kind,
+                is_static,
+                accessibility,
+                is_abstract,
+                is_optional,
+                is_override,
+            )
+            .into(),
         )
     }
     #[inline]
@@ -3510,10 +4311,24 @@ impl Ast {
         function: Function,
         kind: MethodKind,
         is_static: bool,
+        accessibility: Option<Accessibility>,
+        is_abstract: bool,
+        is_optional: bool,
+        is_override: bool,
     ) -> ClassMember {
         ClassMember::PrivateMethod(
-            self.private_method(span, key, function, kind, is_static)
-                .into(),
+            self.private_method(
+                span,
+                key,
+                function,
+                kind,
+                is_static,
+                accessibility,
+                is_abstract,
+                is_optional,
+                is_override,
+            )
+            .into(),
         )
     }
     #[inline]
@@ -3522,12 +4337,34 @@ impl Ast {
         span: Span,
         key: PropName,
         value: Option<Expr>,
+        type_ann: Option<TsTypeAnn>,
         is_static: bool,
         decorators: TypedSubRange<Decorator>,
+        accessibility: Option<Accessibility>,
+        is_abstract: bool,
+        is_optional: bool,
+        is_override: bool,
+        readonly: bool,
+        declare: bool,
+        definite: bool,
     ) -> ClassMember {
         ClassMember::ClassProp(
-            self.class_prop(span, key, value, is_static, decorators)
-                .into(),
+            self.class_prop(
+                span,
+                key,
+                value,
+                type_ann,
+                is_static,
+                decorators,
+                accessibility,
+                is_abstract,
+                is_optional,
+                is_override,
+                readonly,
+                declare,
+                definite,
+            )
+            .into(),
         )
     }
     #[inline]
@@ -3536,12 +4373,30 @@ impl Ast {
         span: Span,
         key: PrivateName,
         value: Option<Expr>,
+        type_ann: Option<TsTypeAnn>,
         is_static: bool,
         decorators: TypedSubRange<Decorator>,
+        accessibility: Option<Accessibility>,
+        is_optional: bool,
+        is_override: bool,
+        readonly: bool,
+        definite: bool,
     ) -> ClassMember {
         ClassMember::PrivateProp(
-            self.private_prop(span, key, value, is_static, decorators)
-                .into(),
+            self.private_prop(
+                span,
+                key,
+                value,
+                type_ann,
+                is_static,
+                decorators,
+                accessibility,
+                is_optional,
+                is_override,
+                readonly,
+                definite,
+            )
+            .into(),
         )
     }
     #[inline]
@@ -3558,11 +4413,41 @@ impl Ast {
         span: Span,
         key: Key,
         value: Option<Expr>,
+        type_ann: Option<TsTypeAnn>,
         is_static: bool,
         decorators: TypedSubRange<Decorator>,
+        accessibility: Option<Accessibility>,
+        is_abstract: bool,
+        is_override: bool,
+        definite: bool,
     ) -> ClassMember {
         ClassMember::AutoAccessor(
-            self.auto_accessor(span, key, value, is_static, decorators)
+            self.auto_accessor(
+                span,
+                key,
+                value,
+                type_ann,
+                is_static,
+                decorators,
+                accessibility,
+                is_abstract,
+                is_override,
+                definite,
+            )
+            .into(),
+        )
+    }
+    #[inline]
+    pub fn class_member_ts_index_signature(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_ann: Option<TsTypeAnn>,
+        readonly: bool,
+        is_static: bool,
+    ) -> ClassMember {
+        ClassMember::TsIndexSignature(
+            self.ts_index_signature(span, params, type_ann, readonly, is_static)
                 .into(),
         )
     }
@@ -3572,13 +4457,29 @@ impl Ast {
         span: Span,
         key: PropName,
         value: Option<Expr>,
+        type_ann: Option<TsTypeAnn>,
         is_static: bool,
         decorators: TypedSubRange<Decorator>,
+        accessibility: Option<Accessibility>,
+        is_abstract: bool,
+        is_optional: bool,
+        is_override: bool,
+        readonly: bool,
+        declare: bool,
+        definite: bool,
     ) -> ClassProp {
         let _f0 = self.add_extra(key.to_extra_data());
         let _f1 = self.add_extra(value.to_extra_data());
-        let _f2 = self.add_extra(is_static.to_extra_data());
-        let _f3 = self.add_extra(decorators.to_extra_data());
+        let _f2 = self.add_extra(type_ann.to_extra_data());
+        let _f3 = self.add_extra(is_static.to_extra_data());
+        let _f4 = self.add_extra(decorators.to_extra_data());
+        let _f5 = self.add_extra(accessibility.to_extra_data());
+        let _f6 = self.add_extra(is_abstract.to_extra_data());
+        let _f7 = self.add_extra(is_optional.to_extra_data());
+        let _f8 = self.add_extra(is_override.to_extra_data());
+        let _f9 = self.add_extra(readonly.to_extra_data());
+        let _f10 = self.add_extra(declare.to_extra_data());
+        let _f11 = self.add_extra(definite.to_extra_data());
         ClassProp(self.add_node(AstNode {
             span,
             kind: NodeKind::ClassProp,
@@ -3594,13 +4495,25 @@ impl Ast {
         span: Span,
         key: PrivateName,
         value: Option<Expr>,
+        type_ann: Option<TsTypeAnn>,
         is_static: bool,
         decorators: TypedSubRange<Decorator>,
+        accessibility: Option<Accessibility>,
+        is_optional: bool,
+        is_override: bool,
+        readonly: bool,
+        definite: bool,
     ) -> PrivateProp {
         let _f0 = self.add_extra(key.to_extra_data());
         let _f1 = self.add_extra(value.to_extra_data());
-        let _f2 = self.add_extra(is_static.to_extra_data());
-        let _f3 = self.add_extra(decorators.to_extra_data());
+        let _f2 = self.add_extra(type_ann.to_extra_data());
+        let _f3 = self.add_extra(is_static.to_extra_data());
+        let _f4 = self.add_extra(decorators.to_extra_data());
+        let _f5 = self.add_extra(accessibility.to_extra_data());
+        let _f6 = self.add_extra(is_optional.to_extra_data());
+        let _f7 = self.add_extra(is_override.to_extra_data());
+        let _f8 = self.add_extra(readonly.to_extra_data());
+        let _f9 = self.add_extra(definite.to_extra_data());
         PrivateProp(self.add_node(AstNode {
             span,
             kind: NodeKind::PrivateProp,
@@ -3618,13 +4531,23 @@ impl Ast {
         function: Function,
         kind: MethodKind,
         is_static: bool,
+        accessibility: Option<Accessibility>,
+        is_abstract: bool,
+        is_optional: bool,
+        is_override: bool,
     ) -> ClassMethod {
         let _f0 = self.add_extra(key.to_extra_data());
         let _f1 = self.add_extra(function.to_extra_data());
+        let _f2 = self.add_extra(kind.to_extra_data());
+        let _f3 = self.add_extra(is_static.to_extra_data());
+        let _f4 = self.add_extra(accessibility.to_extra_data());
+        let _f5 = self.add_extra(is_abstract.to_extra_data());
+        let _f6 = self.add_extra(is_optional.to_extra_data());
+        let _f7 = self.add_extra(is_override.to_extra_data());
         ClassMethod(self.add_node(AstNode {
             span,
             kind: NodeKind::ClassMethod,
-            inline_data: (0u32 | kind as u32 | ((is_static as u32) << 8usize)).into(),
+            inline_data: 0u32.into(),
             data: NodeData {
                 extra_data_start: _f0,
             },
@@ -3638,13 +4561,23 @@ impl Ast {
         function: Function,
         kind: MethodKind,
         is_static: bool,
+        accessibility: Option<Accessibility>,
+        is_abstract: bool,
+        is_optional: bool,
+        is_override: bool,
     ) -> PrivateMethod {
         let _f0 = self.add_extra(key.to_extra_data());
         let _f1 = self.add_extra(function.to_extra_data());
+        let _f2 = self.add_extra(kind.to_extra_data());
+        let _f3 = self.add_extra(is_static.to_extra_data());
+        let _f4 = self.add_extra(accessibility.to_extra_data());
+        let _f5 = self.add_extra(is_abstract.to_extra_data());
+        let _f6 = self.add_extra(is_optional.to_extra_data());
+        let _f7 = self.add_extra(is_override.to_extra_data());
         PrivateMethod(self.add_node(AstNode {
             span,
             kind: NodeKind::PrivateMethod,
-            inline_data: (0u32 | kind as u32 | ((is_static as u32) << 8usize)).into(),
+            inline_data: 0u32.into(),
             data: NodeData {
                 extra_data_start: _f0,
             },
@@ -3657,10 +4590,14 @@ impl Ast {
         key: PropName,
         params: TypedSubRange<ParamOrTsParamProp>,
         body: Option<BlockStmt>,
+        accessibility: Option<Accessibility>,
+        is_optional: bool,
     ) -> Constructor {
         let _f0 = self.add_extra(key.to_extra_data());
         let _f1 = self.add_extra(params.to_extra_data());
         let _f2 = self.add_extra(body.to_extra_data());
+        let _f3 = self.add_extra(accessibility.to_extra_data());
+        let _f4 = self.add_extra(is_optional.to_extra_data());
         Constructor(self.add_node(AstNode {
             span,
             kind: NodeKind::Constructor,
@@ -3730,13 +4667,23 @@ impl Ast {
         span: Span,
         key: Key,
         value: Option<Expr>,
+        type_ann: Option<TsTypeAnn>,
         is_static: bool,
         decorators: TypedSubRange<Decorator>,
+        accessibility: Option<Accessibility>,
+        is_abstract: bool,
+        is_override: bool,
+        definite: bool,
     ) -> AutoAccessor {
         let _f0 = self.add_extra(key.to_extra_data());
         let _f1 = self.add_extra(value.to_extra_data());
-        let _f2 = self.add_extra(is_static.to_extra_data());
-        let _f3 = self.add_extra(decorators.to_extra_data());
+        let _f2 = self.add_extra(type_ann.to_extra_data());
+        let _f3 = self.add_extra(is_static.to_extra_data());
+        let _f4 = self.add_extra(decorators.to_extra_data());
+        let _f5 = self.add_extra(accessibility.to_extra_data());
+        let _f6 = self.add_extra(is_abstract.to_extra_data());
+        let _f7 = self.add_extra(is_override.to_extra_data());
+        let _f8 = self.add_extra(definite.to_extra_data());
         AutoAccessor(self.add_node(AstNode {
             span,
             kind: NodeKind::AutoAccessor,
@@ -3759,8 +4706,14 @@ impl Ast {
         Prop::Assign(self.assign_prop(span, key, value).into())
     }
     #[inline]
-    pub fn prop_getter_prop(&mut self, span: Span, key: PropName, body: Option<BlockStmt>) -> Prop {
-        Prop::Getter(self.getter_prop(span, key, body).into())
+    pub fn prop_getter_prop(
+        &mut self,
+        span: Span,
+        key: PropName,
+        type_ann: Option<TsTypeAnn>,
+        body: Option<BlockStmt>,
+    ) -> Prop {
+        Prop::Getter(self.getter_prop(span, key, type_ann, body).into())
     }
     #[inline]
     pub fn prop_setter_prop(
@@ -3808,10 +4761,12 @@ impl Ast {
         &mut self,
         span: Span,
         key: PropName,
+        type_ann: Option<TsTypeAnn>,
         body: Option<BlockStmt>,
     ) -> GetterProp {
         let _f0 = self.add_extra(key.to_extra_data());
-        let _f1 = self.add_extra(body.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
+        let _f2 = self.add_extra(body.to_extra_data());
         GetterProp(self.add_node(AstNode {
             span,
             kind: NodeKind::GetterProp,
@@ -3894,8 +4849,8 @@ impl Ast {
         }))
     }
     #[inline]
-    pub fn pat_binding_ident(&mut self, span: Span, id: Ident) -> Pat {
-        Pat::Ident(self.binding_ident(span, id).into())
+    pub fn pat_binding_ident(&mut self, span: Span, id: Ident, type_ann: Option<TsTypeAnn>) -> Pat {
+        Pat::Ident(self.binding_ident(span, id, type_ann).into())
     }
     #[inline]
     pub fn pat_array_pat(
@@ -3903,12 +4858,19 @@ impl Ast {
         span: Span,
         elems: TypedSubRange<Option<Pat>>,
         optional: bool,
+        type_ann: Option<TsTypeAnn>,
     ) -> Pat {
-        Pat::Array(self.array_pat(span, elems, optional).into())
+        Pat::Array(self.array_pat(span, elems, optional, type_ann).into())
     }
     #[inline]
-    pub fn pat_rest_pat(&mut self, span: Span, dot3_token: Span, arg: Pat) -> Pat {
-        Pat::Rest(self.rest_pat(span, dot3_token, arg).into())
+    pub fn pat_rest_pat(
+        &mut self,
+        span: Span,
+        dot3_token: Span,
+        arg: Pat,
+        type_ann: Option<TsTypeAnn>,
+    ) -> Pat {
+        Pat::Rest(self.rest_pat(span, dot3_token, arg, type_ann).into())
     }
     #[inline]
     pub fn pat_object_pat(
@@ -3916,8 +4878,9 @@ impl Ast {
         span: Span,
         props: TypedSubRange<ObjectPatProp>,
         optional: bool,
+        type_ann: Option<TsTypeAnn>,
     ) -> Pat {
-        Pat::Object(self.object_pat(span, props, optional).into())
+        Pat::Object(self.object_pat(span, props, optional, type_ann).into())
     }
     #[inline]
     pub fn pat_assign_pat(&mut self, span: Span, left: Pat, right: Expr) -> Pat {
@@ -4000,8 +4963,11 @@ impl Ast {
         span: Span,
         callee: Callee,
         args: TypedSubRange<ExprOrSpread>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> Pat {
-        Pat::Expr(Expr::Call(self.call_expr(span, callee, args).into()))
+        Pat::Expr(Expr::Call(
+            self.call_expr(span, callee, args, type_args).into(),
+        ))
     }
     #[inline]
     pub fn pat_expr_new_expr(
@@ -4009,8 +4975,11 @@ impl Ast {
         span: Span,
         callee: Expr,
         args: Option<TypedSubRange<ExprOrSpread>>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> Pat {
-        Pat::Expr(Expr::New(self.new_expr(span, callee, args).into()))
+        Pat::Expr(Expr::New(
+            self.new_expr(span, callee, args, type_args).into(),
+        ))
     }
     #[inline]
     pub fn pat_expr_seq_expr(&mut self, span: Span, exprs: TypedSubRange<Expr>) -> Pat {
@@ -4061,8 +5030,16 @@ impl Ast {
         Pat::Expr(Expr::Tpl(self.tpl(span, exprs, quasis).into()))
     }
     #[inline]
-    pub fn pat_expr_tagged_tpl(&mut self, span: Span, tag: Expr, tpl: Tpl) -> Pat {
-        Pat::Expr(Expr::TaggedTpl(self.tagged_tpl(span, tag, tpl).into()))
+    pub fn pat_expr_tagged_tpl(
+        &mut self,
+        span: Span,
+        tag: Expr,
+        type_params: Option<TsTypeParamInstantiation>,
+        tpl: Tpl,
+    ) -> Pat {
+        Pat::Expr(Expr::TaggedTpl(
+            self.tagged_tpl(span, tag, type_params, tpl).into(),
+        ))
     }
     #[inline]
     pub fn pat_expr_arrow_expr(
@@ -4072,10 +5049,20 @@ impl Ast {
         body: BlockStmtOrExpr,
         is_async: bool,
         is_generator: bool,
+        type_params: Option<TsTypeParamDecl>,
+        return_type: Option<TsTypeAnn>,
     ) -> Pat {
         Pat::Expr(Expr::Arrow(
-            self.arrow_expr(span, params, body, is_async, is_generator)
-                .into(),
+            self.arrow_expr(
+                span,
+                params,
+                body,
+                is_async,
+                is_generator,
+                type_params,
+                return_type,
+            )
+            .into(),
         ))
     }
     #[inline]
@@ -4144,6 +5131,43 @@ impl Ast {
         ))
     }
     #[inline]
+    pub fn pat_expr_ts_type_assertion(&mut self, span: Span, expr: Expr, type_ann: TsType) -> Pat {
+        Pat::Expr(Expr::TsTypeAssertion(
+            self.ts_type_assertion(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
+    pub fn pat_expr_ts_const_assertion(&mut self, span: Span, expr: Expr) -> Pat {
+        Pat::Expr(Expr::TsConstAssertion(
+            self.ts_const_assertion(span, expr).into(),
+        ))
+    }
+    #[inline]
+    pub fn pat_expr_ts_non_null_expr(&mut self, span: Span, expr: Expr) -> Pat {
+        Pat::Expr(Expr::TsNonNull(self.ts_non_null_expr(span, expr).into()))
+    }
+    #[inline]
+    pub fn pat_expr_ts_as_expr(&mut self, span: Span, expr: Expr, type_ann: TsType) -> Pat {
+        Pat::Expr(Expr::TsAs(self.ts_as_expr(span, expr, type_ann).into()))
+    }
+    #[inline]
+    pub fn pat_expr_ts_instantiation(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: TsTypeParamInstantiation,
+    ) -> Pat {
+        Pat::Expr(Expr::TsInstantiation(
+            self.ts_instantiation(span, expr, type_args).into(),
+        ))
+    }
+    #[inline]
+    pub fn pat_expr_ts_satisfies_expr(&mut self, span: Span, expr: Expr, type_ann: TsType) -> Pat {
+        Pat::Expr(Expr::TsSatisfies(
+            self.ts_satisfies_expr(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
     pub fn pat_expr_private_name(&mut self, span: Span, name: Utf8Ref) -> Pat {
         Pat::Expr(Expr::PrivateName(self.private_name(span, name).into()))
     }
@@ -4168,9 +5192,11 @@ impl Ast {
         span: Span,
         elems: TypedSubRange<Option<Pat>>,
         optional: bool,
+        type_ann: Option<TsTypeAnn>,
     ) -> ArrayPat {
         let _f0 = self.add_extra(elems.to_extra_data());
         let _f1 = self.add_extra(optional.to_extra_data());
+        let _f2 = self.add_extra(type_ann.to_extra_data());
         ArrayPat(self.add_node(AstNode {
             span,
             kind: NodeKind::ArrayPat,
@@ -4186,9 +5212,11 @@ impl Ast {
         span: Span,
         props: TypedSubRange<ObjectPatProp>,
         optional: bool,
+        type_ann: Option<TsTypeAnn>,
     ) -> ObjectPat {
         let _f0 = self.add_extra(props.to_extra_data());
         let _f1 = self.add_extra(optional.to_extra_data());
+        let _f2 = self.add_extra(type_ann.to_extra_data());
         ObjectPat(self.add_node(AstNode {
             span,
             kind: NodeKind::ObjectPat,
@@ -4212,9 +5240,16 @@ impl Ast {
         }))
     }
     #[inline]
-    pub fn rest_pat(&mut self, span: Span, dot3_token: Span, arg: Pat) -> RestPat {
+    pub fn rest_pat(
+        &mut self,
+        span: Span,
+        dot3_token: Span,
+        arg: Pat,
+        type_ann: Option<TsTypeAnn>,
+    ) -> RestPat {
         let _f0 = self.add_extra(dot3_token.to_extra_data());
         let _f1 = self.add_extra(arg.to_extra_data());
+        let _f2 = self.add_extra(type_ann.to_extra_data());
         RestPat(self.add_node(AstNode {
             span,
             kind: NodeKind::RestPat,
@@ -4248,8 +5283,9 @@ impl Ast {
         span: Span,
         dot3_token: Span,
         arg: Pat,
+        type_ann: Option<TsTypeAnn>,
     ) -> ObjectPatProp {
-        ObjectPatProp::Rest(self.rest_pat(span, dot3_token, arg).into())
+        ObjectPatProp::Rest(self.rest_pat(span, dot3_token, arg, type_ann).into())
     }
     #[inline]
     pub fn key_value_pat_prop(&mut self, span: Span, key: PropName, value: Pat) -> KeyValuePatProp {
@@ -4319,13 +5355,20 @@ impl Ast {
         }))
     }
     #[inline]
-    pub fn binding_ident(&mut self, span: Span, id: Ident) -> BindingIdent {
+    pub fn binding_ident(
+        &mut self,
+        span: Span,
+        id: Ident,
+        type_ann: Option<TsTypeAnn>,
+    ) -> BindingIdent {
+        let _f0 = self.add_extra(id.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
         BindingIdent(self.add_node(AstNode {
             span,
             kind: NodeKind::BindingIdent,
             inline_data: 0u32.into(),
             data: NodeData {
-                inline_data: id.node_id().index() as u32,
+                extra_data_start: _f0,
             },
         }))
     }
@@ -4598,8 +5641,11 @@ impl Ast {
         span: Span,
         callee: Callee,
         args: TypedSubRange<ExprOrSpread>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> JSXExpr {
-        JSXExpr::Expr(Expr::Call(self.call_expr(span, callee, args).into()))
+        JSXExpr::Expr(Expr::Call(
+            self.call_expr(span, callee, args, type_args).into(),
+        ))
     }
     #[inline]
     pub fn jsx_expr_expr_new_expr(
@@ -4607,8 +5653,11 @@ impl Ast {
         span: Span,
         callee: Expr,
         args: Option<TypedSubRange<ExprOrSpread>>,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> JSXExpr {
-        JSXExpr::Expr(Expr::New(self.new_expr(span, callee, args).into()))
+        JSXExpr::Expr(Expr::New(
+            self.new_expr(span, callee, args, type_args).into(),
+        ))
     }
     #[inline]
     pub fn jsx_expr_expr_seq_expr(&mut self, span: Span, exprs: TypedSubRange<Expr>) -> JSXExpr {
@@ -4669,21 +5718,39 @@ impl Ast {
         JSXExpr::Expr(Expr::Tpl(self.tpl(span, exprs, quasis).into()))
     }
     #[inline]
-    pub fn jsx_expr_expr_tagged_tpl(&mut self, span: Span, tag: Expr, tpl: Tpl) -> JSXExpr {
-        JSXExpr::Expr(Expr::TaggedTpl(self.tagged_tpl(span, tag, tpl).into()))
-    }
-    #[inline]
-    pub fn jsx_expr_expr_arrow_expr(
+    pub fn jsx_expr_expr_tagged_tpl(
         &mut self,
         span: Span,
-        params: TypedSubRange<Pat>,
+        tag: Expr,
+        type_params: Option<TsTypeParamInstantiation>,
+        tpl: Tpl,
+    ) -> JSXExpr {
+        JSXExpr::Expr(Expr::TaggedTpl(
+            self.tagged_tpl(span, tag, type_params, tpl).into(),
+        ))
+    }
+    #[inline]
+    pub fn jsx_expr_expr_arrow_expr(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<Pat>,
         body: BlockStmtOrExpr,
         is_async: bool,
         is_generator: bool,
+        type_params: Option<TsTypeParamDecl>,
+        return_type: Option<TsTypeAnn>,
     ) -> JSXExpr {
         JSXExpr::Expr(Expr::Arrow(
-            self.arrow_expr(span, params, body, is_async, is_generator)
-                .into(),
+            self.arrow_expr(
+                span,
+                params,
+                body,
+                is_async,
+                is_generator,
+                type_params,
+                return_type,
+            )
+            .into(),
         ))
     }
     #[inline]
@@ -4767,6 +5834,58 @@ impl Ast {
         ))
     }
     #[inline]
+    pub fn jsx_expr_expr_ts_type_assertion(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> JSXExpr {
+        JSXExpr::Expr(Expr::TsTypeAssertion(
+            self.ts_type_assertion(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
+    pub fn jsx_expr_expr_ts_const_assertion(&mut self, span: Span, expr: Expr) -> JSXExpr {
+        JSXExpr::Expr(Expr::TsConstAssertion(
+            self.ts_const_assertion(span, expr).into(),
+        ))
+    }
+    #[inline]
+    pub fn jsx_expr_expr_ts_non_null_expr(&mut self, span: Span, expr: Expr) -> JSXExpr {
+        JSXExpr::Expr(Expr::TsNonNull(self.ts_non_null_expr(span, expr).into()))
+    }
+    #[inline]
+    pub fn jsx_expr_expr_ts_as_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> JSXExpr {
+        JSXExpr::Expr(Expr::TsAs(self.ts_as_expr(span, expr, type_ann).into()))
+    }
+    #[inline]
+    pub fn jsx_expr_expr_ts_instantiation(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: TsTypeParamInstantiation,
+    ) -> JSXExpr {
+        JSXExpr::Expr(Expr::TsInstantiation(
+            self.ts_instantiation(span, expr, type_args).into(),
+        ))
+    }
+    #[inline]
+    pub fn jsx_expr_expr_ts_satisfies_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> JSXExpr {
+        JSXExpr::Expr(Expr::TsSatisfies(
+            self.ts_satisfies_expr(span, expr, type_ann).into(),
+        ))
+    }
+    #[inline]
     pub fn jsx_expr_expr_private_name(&mut self, span: Span, name: Utf8Ref) -> JSXExpr {
         JSXExpr::Expr(Expr::PrivateName(self.private_name(span, name).into()))
     }
@@ -4831,10 +5950,12 @@ impl Ast {
         name: JSXElementName,
         attrs: TypedSubRange<JSXAttrOrSpread>,
         self_closing: bool,
+        type_args: Option<TsTypeParamInstantiation>,
     ) -> JSXOpeningElement {
         let _f0 = self.add_extra(name.to_extra_data());
         let _f1 = self.add_extra(attrs.to_extra_data());
         let _f2 = self.add_extra(self_closing.to_extra_data());
+        let _f3 = self.add_extra(type_args.to_extra_data());
         JSXOpeningElement(self.add_node(AstNode {
             span,
             kind: NodeKind::JSXOpeningElement,
@@ -5054,4 +6175,1549 @@ impl Ast {
             data: NodeData { empty: () },
         }))
     }
+    #[inline]
+    pub fn ts_type_ann(&mut self, span: Span, type_ann: TsType) -> TsTypeAnn {
+        let _f0 = self.add_extra(type_ann.to_extra_data());
+        TsTypeAnn(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTypeAnn,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_type_param_decl(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsTypeParam>,
+    ) -> TsTypeParamDecl {
+        let _f0 = self.add_extra(params.to_extra_data());
+        TsTypeParamDecl(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTypeParamDecl,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_type_param(
+        &mut self,
+        span: Span,
+        name: Ident,
+        is_in: bool,
+        is_out: bool,
+        is_const: bool,
+        constraint: Option<TsType>,
+        default: Option<TsType>,
+    ) -> TsTypeParam {
+        let _f0 = self.add_extra(name.to_extra_data());
+        let _f1 = self.add_extra(constraint.to_extra_data());
+        let _f2 = self.add_extra(default.to_extra_data());
+        TsTypeParam(
+            self.add_node(AstNode {
+                span,
+                kind: NodeKind::TsTypeParam,
+                inline_data: (0u32
+                    | is_in as u32
+                    | ((is_out as u32) << 8usize)
+                    | ((is_const as u32) << 16usize))
+                    .into(),
+                data: NodeData {
+                    extra_data_start: _f0,
+                },
+            }),
+        )
+    }
+    #[inline]
+    pub fn ts_type_param_instantiation(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsType>,
+    ) -> TsTypeParamInstantiation {
+        let _f0 = self.add_extra(params.to_extra_data());
+        TsTypeParamInstantiation(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTypeParamInstantiation,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_param_prop(
+        &mut self,
+        span: Span,
+        decorators: TypedSubRange<Decorator>,
+        accessibility: Option<Accessibility>,
+        is_override: bool,
+        readonly: bool,
+        param: TsParamPropParam,
+    ) -> TsParamProp {
+        let _f0 = self.add_extra(decorators.to_extra_data());
+        let _f1 = self.add_extra(accessibility.to_extra_data());
+        let _f2 = self.add_extra(is_override.to_extra_data());
+        let _f3 = self.add_extra(readonly.to_extra_data());
+        let _f4 = self.add_extra(param.to_extra_data());
+        TsParamProp(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsParamProp,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_param_prop_param_binding_ident(
+        &mut self,
+        span: Span,
+        id: Ident,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsParamPropParam {
+        TsParamPropParam::Ident(self.binding_ident(span, id, type_ann).into())
+    }
+    #[inline]
+    pub fn ts_param_prop_param_assign_pat(
+        &mut self,
+        span: Span,
+        left: Pat,
+        right: Expr,
+    ) -> TsParamPropParam {
+        TsParamPropParam::Assign(self.assign_pat(span, left, right).into())
+    }
+    #[inline]
+    pub fn ts_qualified_name(
+        &mut self,
+        span: Span,
+        left: TsEntityName,
+        right: IdentName,
+    ) -> TsQualifiedName {
+        let _f0 = self.add_extra(left.to_extra_data());
+        let _f1 = self.add_extra(right.to_extra_data());
+        TsQualifiedName(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsQualifiedName,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_entity_name_ts_qualified_name(
+        &mut self,
+        span: Span,
+        left: TsEntityName,
+        right: IdentName,
+    ) -> TsEntityName {
+        TsEntityName::TsQualifiedName(self.ts_qualified_name(span, left, right).into())
+    }
+    #[inline]
+    pub fn ts_entity_name_ident(
+        &mut self,
+        span: Span,
+        sym: Utf8Ref,
+        optional: bool,
+    ) -> TsEntityName {
+        TsEntityName::Ident(self.ident(span, sym, optional).into())
+    }
+    #[inline]
+    pub fn ts_type_element_ts_call_signature_decl(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_ann: Option<TsTypeAnn>,
+        type_params: Option<TsTypeParamDecl>,
+    ) -> TsTypeElement {
+        TsTypeElement::TsCallSignatureDecl(
+            self.ts_call_signature_decl(span, params, type_ann, type_params)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_type_element_ts_construct_signature_decl(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_ann: Option<TsTypeAnn>,
+        type_params: Option<TsTypeParamDecl>,
+    ) -> TsTypeElement {
+        TsTypeElement::TsConstructSignatureDecl(
+            self.ts_construct_signature_decl(span, params, type_ann, type_params)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_type_element_ts_property_signature(
+        &mut self,
+        span: Span,
+        readonly: bool,
+        key: Expr,
+        computed: bool,
+        optional: bool,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsTypeElement {
+        TsTypeElement::TsPropertySignature(
+            self.ts_property_signature(span, readonly, key, computed, optional, type_ann)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_type_element_ts_getter_signature(
+        &mut self,
+        span: Span,
+        key: Expr,
+        computed: bool,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsTypeElement {
+        TsTypeElement::TsGetterSignature(
+            self.ts_getter_signature(span, key, computed, type_ann)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_type_element_ts_setter_signature(
+        &mut self,
+        span: Span,
+        key: Expr,
+        computed: bool,
+        param: TsFnParam,
+    ) -> TsTypeElement {
+        TsTypeElement::TsSetterSignature(
+            self.ts_setter_signature(span, key, computed, param).into(),
+        )
+    }
+    #[inline]
+    pub fn ts_type_element_ts_method_signature(
+        &mut self,
+        span: Span,
+        key: Expr,
+        computed: bool,
+        optional: bool,
+        params: TypedSubRange<TsFnParam>,
+        type_ann: Option<TsTypeAnn>,
+        type_params: Option<TsTypeParamDecl>,
+    ) -> TsTypeElement {
+        TsTypeElement::TsMethodSignature(
+            self.ts_method_signature(span, key, computed, optional, params, type_ann, type_params)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_type_element_ts_index_signature(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_ann: Option<TsTypeAnn>,
+        readonly: bool,
+        is_static: bool,
+    ) -> TsTypeElement {
+        TsTypeElement::TsIndexSignature(
+            self.ts_index_signature(span, params, type_ann, readonly, is_static)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_call_signature_decl(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_ann: Option<TsTypeAnn>,
+        type_params: Option<TsTypeParamDecl>,
+    ) -> TsCallSignatureDecl {
+        let _f0 = self.add_extra(params.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
+        let _f2 = self.add_extra(type_params.to_extra_data());
+        TsCallSignatureDecl(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsCallSignatureDecl,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_construct_signature_decl(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_ann: Option<TsTypeAnn>,
+        type_params: Option<TsTypeParamDecl>,
+    ) -> TsConstructSignatureDecl {
+        let _f0 = self.add_extra(params.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
+        let _f2 = self.add_extra(type_params.to_extra_data());
+        TsConstructSignatureDecl(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsConstructSignatureDecl,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_property_signature(
+        &mut self,
+        span: Span,
+        readonly: bool,
+        key: Expr,
+        computed: bool,
+        optional: bool,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsPropertySignature {
+        let _f0 = self.add_extra(key.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
+        TsPropertySignature(
+            self.add_node(AstNode {
+                span,
+                kind: NodeKind::TsPropertySignature,
+                inline_data: (0u32
+                    | readonly as u32
+                    | ((computed as u32) << 8usize)
+                    | ((optional as u32) << 16usize))
+                    .into(),
+                data: NodeData {
+                    extra_data_start: _f0,
+                },
+            }),
+        )
+    }
+    #[inline]
+    pub fn ts_getter_signature(
+        &mut self,
+        span: Span,
+        key: Expr,
+        computed: bool,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsGetterSignature {
+        let _f0 = self.add_extra(key.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
+        TsGetterSignature(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsGetterSignature,
+            inline_data: (0u32 | computed as u32).into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_setter_signature(
+        &mut self,
+        span: Span,
+        key: Expr,
+        computed: bool,
+        param: TsFnParam,
+    ) -> TsSetterSignature {
+        let _f0 = self.add_extra(key.to_extra_data());
+        let _f1 = self.add_extra(param.to_extra_data());
+        TsSetterSignature(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsSetterSignature,
+            inline_data: (0u32 | computed as u32).into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_method_signature(
+        &mut self,
+        span: Span,
+        key: Expr,
+        computed: bool,
+        optional: bool,
+        params: TypedSubRange<TsFnParam>,
+        type_ann: Option<TsTypeAnn>,
+        type_params: Option<TsTypeParamDecl>,
+    ) -> TsMethodSignature {
+        let _f0 = self.add_extra(key.to_extra_data());
+        let _f1 = self.add_extra(computed.to_extra_data());
+        let _f2 = self.add_extra(optional.to_extra_data());
+        let _f3 = self.add_extra(params.to_extra_data());
+        let _f4 = self.add_extra(type_ann.to_extra_data());
+        let _f5 = self.add_extra(type_params.to_extra_data());
+        TsMethodSignature(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsMethodSignature,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_index_signature(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_ann: Option<TsTypeAnn>,
+        readonly: bool,
+        is_static: bool,
+    ) -> TsIndexSignature {
+        let _f0 = self.add_extra(params.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
+        let _f2 = self.add_extra(readonly.to_extra_data());
+        let _f3 = self.add_extra(is_static.to_extra_data());
+        TsIndexSignature(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsIndexSignature,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_type_ts_keyword_type(&mut self, span: Span, kind: TsKeywordTypeKind) -> TsType {
+        TsType::TsKeywordType(self.ts_keyword_type(span, kind).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_this_type(&mut self, span: Span) -> TsType {
+        TsType::TsThisType(self.ts_this_type(span).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_fn_or_constructor_type_ts_fn_type(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_params: Option<TsTypeParamDecl>,
+        type_ann: TsTypeAnn,
+    ) -> TsType {
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(
+            self.ts_fn_type(span, params, type_params, type_ann).into(),
+        ))
+    }
+    #[inline]
+    pub fn ts_type_ts_fn_or_constructor_type_ts_constructor_type(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_params: Option<TsTypeParamDecl>,
+        type_ann: TsTypeAnn,
+        is_abstract: bool,
+    ) -> TsType {
+        TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType(
+            self.ts_constructor_type(span, params, type_params, type_ann, is_abstract)
+                .into(),
+        ))
+    }
+    #[inline]
+    pub fn ts_type_ts_type_ref(
+        &mut self,
+        span: Span,
+        type_name: TsEntityName,
+        type_params: Option<TsTypeParamInstantiation>,
+    ) -> TsType {
+        TsType::TsTypeRef(self.ts_type_ref(span, type_name, type_params).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_type_query(
+        &mut self,
+        span: Span,
+        expr_name: TsTypeQueryExpr,
+        type_args: Option<TsTypeParamInstantiation>,
+    ) -> TsType {
+        TsType::TsTypeQuery(self.ts_type_query(span, expr_name, type_args).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_type_lit(
+        &mut self,
+        span: Span,
+        members: TypedSubRange<TsTypeElement>,
+    ) -> TsType {
+        TsType::TsTypeLit(self.ts_type_lit(span, members).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_array_type(&mut self, span: Span, elem_type: TsType) -> TsType {
+        TsType::TsArrayType(self.ts_array_type(span, elem_type).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_tuple_type(
+        &mut self,
+        span: Span,
+        elem_types: TypedSubRange<TsTupleElement>,
+    ) -> TsType {
+        TsType::TsTupleType(self.ts_tuple_type(span, elem_types).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_optional_type(&mut self, span: Span, type_ann: TsType) -> TsType {
+        TsType::TsOptionalType(self.ts_optional_type(span, type_ann).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_rest_type(&mut self, span: Span, type_ann: TsType) -> TsType {
+        TsType::TsRestType(self.ts_rest_type(span, type_ann).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_union_or_intersection_type_ts_union_type(
+        &mut self,
+        span: Span,
+        types: TypedSubRange<TsType>,
+    ) -> TsType {
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
+            self.ts_union_type(span, types).into(),
+        ))
+    }
+    #[inline]
+    pub fn ts_type_ts_union_or_intersection_type_ts_intersection_type(
+        &mut self,
+        span: Span,
+        types: TypedSubRange<TsType>,
+    ) -> TsType {
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+            self.ts_intersection_type(span, types).into(),
+        ))
+    }
+    #[inline]
+    pub fn ts_type_ts_conditional_type(
+        &mut self,
+        span: Span,
+        check_type: TsType,
+        extends_type: TsType,
+        true_type: TsType,
+        false_type: TsType,
+    ) -> TsType {
+        TsType::TsConditionalType(
+            self.ts_conditional_type(span, check_type, extends_type, true_type, false_type)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_type_ts_infer_type(&mut self, span: Span, type_param: TsTypeParam) -> TsType {
+        TsType::TsInferType(self.ts_infer_type(span, type_param).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_parenthesized_type(&mut self, span: Span, type_ann: TsType) -> TsType {
+        TsType::TsParenthesizedType(self.ts_parenthesized_type(span, type_ann).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_type_operator(
+        &mut self,
+        span: Span,
+        op: TsTypeOperatorOp,
+        type_ann: TsType,
+    ) -> TsType {
+        TsType::TsTypeOperator(self.ts_type_operator(span, op, type_ann).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_indexed_access_type(
+        &mut self,
+        span: Span,
+        readonly: bool,
+        obj_type: TsType,
+        index_type: TsType,
+    ) -> TsType {
+        TsType::TsIndexedAccessType(
+            self.ts_indexed_access_type(span, readonly, obj_type, index_type)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_type_ts_mapped_type(
+        &mut self,
+        span: Span,
+        readonly: Option<TruePlusMinus>,
+        type_param: TsTypeParam,
+        name_type: Option<TsType>,
+        optional: Option<TruePlusMinus>,
+        type_ann: Option<TsType>,
+    ) -> TsType {
+        TsType::TsMappedType(
+            self.ts_mapped_type(span, readonly, type_param, name_type, optional, type_ann)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_type_ts_lit_type(&mut self, span: Span, lit: TsLit) -> TsType {
+        TsType::TsLitType(self.ts_lit_type(span, lit).into())
+    }
+    #[inline]
+    pub fn ts_type_ts_type_predicate(
+        &mut self,
+        span: Span,
+        asserts: bool,
+        param_name: TsThisTypeOrIdent,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsType {
+        TsType::TsTypePredicate(
+            self.ts_type_predicate(span, asserts, param_name, type_ann)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_type_ts_import_type(
+        &mut self,
+        span: Span,
+        arg: Str,
+        qualifier: Option<TsEntityName>,
+        type_args: Option<TsTypeParamInstantiation>,
+        attributes: Option<TsImportCallOptions>,
+    ) -> TsType {
+        TsType::TsImportType(
+            self.ts_import_type(span, arg, qualifier, type_args, attributes)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_fn_or_constructor_type_ts_fn_type(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_params: Option<TsTypeParamDecl>,
+        type_ann: TsTypeAnn,
+    ) -> TsFnOrConstructorType {
+        TsFnOrConstructorType::TsFnType(self.ts_fn_type(span, params, type_params, type_ann).into())
+    }
+    #[inline]
+    pub fn ts_fn_or_constructor_type_ts_constructor_type(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_params: Option<TsTypeParamDecl>,
+        type_ann: TsTypeAnn,
+        is_abstract: bool,
+    ) -> TsFnOrConstructorType {
+        TsFnOrConstructorType::TsConstructorType(
+            self.ts_constructor_type(span, params, type_params, type_ann, is_abstract)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_keyword_type(&mut self, span: Span, kind: TsKeywordTypeKind) -> TsKeywordType {
+        TsKeywordType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsKeywordType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                inline_data: kind as u32,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_this_type(&mut self, span: Span) -> TsThisType {
+        TsThisType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsThisType,
+            inline_data: 0u32.into(),
+            data: NodeData { empty: () },
+        }))
+    }
+    #[inline]
+    pub fn ts_fn_param_binding_ident(
+        &mut self,
+        span: Span,
+        id: Ident,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsFnParam {
+        TsFnParam::Ident(self.binding_ident(span, id, type_ann).into())
+    }
+    #[inline]
+    pub fn ts_fn_param_array_pat(
+        &mut self,
+        span: Span,
+        elems: TypedSubRange<Option<Pat>>,
+        optional: bool,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsFnParam {
+        TsFnParam::Array(self.array_pat(span, elems, optional, type_ann).into())
+    }
+    #[inline]
+    pub fn ts_fn_param_rest_pat(
+        &mut self,
+        span: Span,
+        dot3_token: Span,
+        arg: Pat,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsFnParam {
+        TsFnParam::Rest(self.rest_pat(span, dot3_token, arg, type_ann).into())
+    }
+    #[inline]
+    pub fn ts_fn_param_object_pat(
+        &mut self,
+        span: Span,
+        props: TypedSubRange<ObjectPatProp>,
+        optional: bool,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsFnParam {
+        TsFnParam::Object(self.object_pat(span, props, optional, type_ann).into())
+    }
+    #[inline]
+    pub fn ts_fn_type(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_params: Option<TsTypeParamDecl>,
+        type_ann: TsTypeAnn,
+    ) -> TsFnType {
+        let _f0 = self.add_extra(params.to_extra_data());
+        let _f1 = self.add_extra(type_params.to_extra_data());
+        let _f2 = self.add_extra(type_ann.to_extra_data());
+        TsFnType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsFnType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_constructor_type(
+        &mut self,
+        span: Span,
+        params: TypedSubRange<TsFnParam>,
+        type_params: Option<TsTypeParamDecl>,
+        type_ann: TsTypeAnn,
+        is_abstract: bool,
+    ) -> TsConstructorType {
+        let _f0 = self.add_extra(params.to_extra_data());
+        let _f1 = self.add_extra(type_params.to_extra_data());
+        let _f2 = self.add_extra(type_ann.to_extra_data());
+        let _f3 = self.add_extra(is_abstract.to_extra_data());
+        TsConstructorType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsConstructorType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_type_ref(
+        &mut self,
+        span: Span,
+        type_name: TsEntityName,
+        type_params: Option<TsTypeParamInstantiation>,
+    ) -> TsTypeRef {
+        let _f0 = self.add_extra(type_name.to_extra_data());
+        let _f1 = self.add_extra(type_params.to_extra_data());
+        TsTypeRef(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTypeRef,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_type_predicate(
+        &mut self,
+        span: Span,
+        asserts: bool,
+        param_name: TsThisTypeOrIdent,
+        type_ann: Option<TsTypeAnn>,
+    ) -> TsTypePredicate {
+        let _f0 = self.add_extra(param_name.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
+        TsTypePredicate(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTypePredicate,
+            inline_data: (0u32 | asserts as u32).into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_this_type_or_ident_ts_this_type(&mut self, span: Span) -> TsThisTypeOrIdent {
+        TsThisTypeOrIdent::TsThisType(self.ts_this_type(span).into())
+    }
+    #[inline]
+    pub fn ts_this_type_or_ident_ident(
+        &mut self,
+        span: Span,
+        sym: Utf8Ref,
+        optional: bool,
+    ) -> TsThisTypeOrIdent {
+        TsThisTypeOrIdent::Ident(self.ident(span, sym, optional).into())
+    }
+    #[inline]
+    pub fn ts_type_query(
+        &mut self,
+        span: Span,
+        expr_name: TsTypeQueryExpr,
+        type_args: Option<TsTypeParamInstantiation>,
+    ) -> TsTypeQuery {
+        let _f0 = self.add_extra(expr_name.to_extra_data());
+        let _f1 = self.add_extra(type_args.to_extra_data());
+        TsTypeQuery(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTypeQuery,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_type_query_expr_ts_entity_name_ts_qualified_name(
+        &mut self,
+        span: Span,
+        left: TsEntityName,
+        right: IdentName,
+    ) -> TsTypeQueryExpr {
+        TsTypeQueryExpr::TsEntityName(TsEntityName::TsQualifiedName(
+            self.ts_qualified_name(span, left, right).into(),
+        ))
+    }
+    #[inline]
+    pub fn ts_type_query_expr_ts_entity_name_ident(
+        &mut self,
+        span: Span,
+        sym: Utf8Ref,
+        optional: bool,
+    ) -> TsTypeQueryExpr {
+        TsTypeQueryExpr::TsEntityName(TsEntityName::Ident(self.ident(span, sym, optional).into()))
+    }
+    #[inline]
+    pub fn ts_type_query_expr_ts_import_type(
+        &mut self,
+        span: Span,
+        arg: Str,
+        qualifier: Option<TsEntityName>,
+        type_args: Option<TsTypeParamInstantiation>,
+        attributes: Option<TsImportCallOptions>,
+    ) -> TsTypeQueryExpr {
+        TsTypeQueryExpr::Import(
+            self.ts_import_type(span, arg, qualifier, type_args, attributes)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_import_call_options(&mut self, span: Span, with: ObjectLit) -> TsImportCallOptions {
+        TsImportCallOptions(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsImportCallOptions,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                inline_data: with.node_id().index() as u32,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_import_type(
+        &mut self,
+        span: Span,
+        arg: Str,
+        qualifier: Option<TsEntityName>,
+        type_args: Option<TsTypeParamInstantiation>,
+        attributes: Option<TsImportCallOptions>,
+    ) -> TsImportType {
+        let _f0 = self.add_extra(arg.to_extra_data());
+        let _f1 = self.add_extra(qualifier.to_extra_data());
+        let _f2 = self.add_extra(type_args.to_extra_data());
+        let _f3 = self.add_extra(attributes.to_extra_data());
+        TsImportType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsImportType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_type_lit(&mut self, span: Span, members: TypedSubRange<TsTypeElement>) -> TsTypeLit {
+        let _f0 = self.add_extra(members.to_extra_data());
+        TsTypeLit(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTypeLit,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_array_type(&mut self, span: Span, elem_type: TsType) -> TsArrayType {
+        let _f0 = self.add_extra(elem_type.to_extra_data());
+        TsArrayType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsArrayType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_tuple_type(
+        &mut self,
+        span: Span,
+        elem_types: TypedSubRange<TsTupleElement>,
+    ) -> TsTupleType {
+        let _f0 = self.add_extra(elem_types.to_extra_data());
+        TsTupleType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTupleType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_tuple_element(
+        &mut self,
+        span: Span,
+        label: Option<Pat>,
+        ty: TsType,
+    ) -> TsTupleElement {
+        let _f0 = self.add_extra(label.to_extra_data());
+        let _f1 = self.add_extra(ty.to_extra_data());
+        TsTupleElement(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTupleElement,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_optional_type(&mut self, span: Span, type_ann: TsType) -> TsOptionalType {
+        let _f0 = self.add_extra(type_ann.to_extra_data());
+        TsOptionalType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsOptionalType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_rest_type(&mut self, span: Span, type_ann: TsType) -> TsRestType {
+        let _f0 = self.add_extra(type_ann.to_extra_data());
+        TsRestType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsRestType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_union_or_intersection_type_ts_union_type(
+        &mut self,
+        span: Span,
+        types: TypedSubRange<TsType>,
+    ) -> TsUnionOrIntersectionType {
+        TsUnionOrIntersectionType::TsUnionType(self.ts_union_type(span, types).into())
+    }
+    #[inline]
+    pub fn ts_union_or_intersection_type_ts_intersection_type(
+        &mut self,
+        span: Span,
+        types: TypedSubRange<TsType>,
+    ) -> TsUnionOrIntersectionType {
+        TsUnionOrIntersectionType::TsIntersectionType(self.ts_intersection_type(span, types).into())
+    }
+    #[inline]
+    pub fn ts_union_type(&mut self, span: Span, types: TypedSubRange<TsType>) -> TsUnionType {
+        let _f0 = self.add_extra(types.to_extra_data());
+        TsUnionType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsUnionType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_intersection_type(
+        &mut self,
+        span: Span,
+        types: TypedSubRange<TsType>,
+    ) -> TsIntersectionType {
+        let _f0 = self.add_extra(types.to_extra_data());
+        TsIntersectionType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsIntersectionType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_conditional_type(
+        &mut self,
+        span: Span,
+        check_type: TsType,
+        extends_type: TsType,
+        true_type: TsType,
+        false_type: TsType,
+    ) -> TsConditionalType {
+        let _f0 = self.add_extra(check_type.to_extra_data());
+        let _f1 = self.add_extra(extends_type.to_extra_data());
+        let _f2 = self.add_extra(true_type.to_extra_data());
+        let _f3 = self.add_extra(false_type.to_extra_data());
+        TsConditionalType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsConditionalType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_infer_type(&mut self, span: Span, type_param: TsTypeParam) -> TsInferType {
+        TsInferType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsInferType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                inline_data: type_param.node_id().index() as u32,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_parenthesized_type(&mut self, span: Span, type_ann: TsType) -> TsParenthesizedType {
+        let _f0 = self.add_extra(type_ann.to_extra_data());
+        TsParenthesizedType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsParenthesizedType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_type_operator(
+        &mut self,
+        span: Span,
+        op: TsTypeOperatorOp,
+        type_ann: TsType,
+    ) -> TsTypeOperator {
+        let _f0 = self.add_extra(type_ann.to_extra_data());
+        TsTypeOperator(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTypeOperator,
+            inline_data: (0u32 | op as u32).into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_indexed_access_type(
+        &mut self,
+        span: Span,
+        readonly: bool,
+        obj_type: TsType,
+        index_type: TsType,
+    ) -> TsIndexedAccessType {
+        let _f0 = self.add_extra(obj_type.to_extra_data());
+        let _f1 = self.add_extra(index_type.to_extra_data());
+        TsIndexedAccessType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsIndexedAccessType,
+            inline_data: (0u32 | readonly as u32).into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_mapped_type(
+        &mut self,
+        span: Span,
+        readonly: Option<TruePlusMinus>,
+        type_param: TsTypeParam,
+        name_type: Option<TsType>,
+        optional: Option<TruePlusMinus>,
+        type_ann: Option<TsType>,
+    ) -> TsMappedType {
+        let _f0 = self.add_extra(readonly.to_extra_data());
+        let _f1 = self.add_extra(type_param.to_extra_data());
+        let _f2 = self.add_extra(name_type.to_extra_data());
+        let _f3 = self.add_extra(optional.to_extra_data());
+        let _f4 = self.add_extra(type_ann.to_extra_data());
+        TsMappedType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsMappedType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_lit_type(&mut self, span: Span, lit: TsLit) -> TsLitType {
+        let _f0 = self.add_extra(lit.to_extra_data());
+        TsLitType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsLitType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_lit_number(&mut self, span: Span, value: f64, raw: OptionalUtf8Ref) -> TsLit {
+        TsLit::Number(self.number(span, value, raw).into())
+    }
+    #[inline]
+    pub fn ts_lit_str(&mut self, span: Span, value: Wtf8Ref, raw: OptionalUtf8Ref) -> TsLit {
+        TsLit::Str(self.str(span, value, raw).into())
+    }
+    #[inline]
+    pub fn ts_lit_bool(&mut self, span: Span, value: bool) -> TsLit {
+        TsLit::Bool(self.bool(span, value).into())
+    }
+    #[inline]
+    pub fn ts_lit_big_int(&mut self, span: Span, value: BigIntId, raw: OptionalUtf8Ref) -> TsLit {
+        TsLit::BigInt(self.big_int(span, value, raw).into())
+    }
+    #[inline]
+    pub fn ts_lit_ts_tpl_lit_type(
+        &mut self,
+        span: Span,
+        types: TypedSubRange<TsType>,
+        quasis: TypedSubRange<TplElement>,
+    ) -> TsLit {
+        TsLit::Tpl(self.ts_tpl_lit_type(span, types, quasis).into())
+    }
+    #[inline]
+    pub fn ts_tpl_lit_type(
+        &mut self,
+        span: Span,
+        types: TypedSubRange<TsType>,
+        quasis: TypedSubRange<TplElement>,
+    ) -> TsTplLitType {
+        let _f0 = self.add_extra(types.to_extra_data());
+        let _f1 = self.add_extra(quasis.to_extra_data());
+        TsTplLitType(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTplLitType,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_interface_decl(
+        &mut self,
+        span: Span,
+        id: Ident,
+        declare: bool,
+        type_params: Option<TsTypeParamDecl>,
+        extends: TypedSubRange<TsExprWithTypeArgs>,
+        body: TsInterfaceBody,
+    ) -> TsInterfaceDecl {
+        let _f0 = self.add_extra(id.to_extra_data());
+        let _f1 = self.add_extra(declare.to_extra_data());
+        let _f2 = self.add_extra(type_params.to_extra_data());
+        let _f3 = self.add_extra(extends.to_extra_data());
+        let _f4 = self.add_extra(body.to_extra_data());
+        TsInterfaceDecl(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsInterfaceDecl,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_interface_body(
+        &mut self,
+        span: Span,
+        body: TypedSubRange<TsTypeElement>,
+    ) -> TsInterfaceBody {
+        let _f0 = self.add_extra(body.to_extra_data());
+        TsInterfaceBody(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsInterfaceBody,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_expr_with_type_args(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: Option<TsTypeParamInstantiation>,
+    ) -> TsExprWithTypeArgs {
+        let _f0 = self.add_extra(expr.to_extra_data());
+        let _f1 = self.add_extra(type_args.to_extra_data());
+        TsExprWithTypeArgs(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsExprWithTypeArgs,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_type_alias_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        id: Ident,
+        type_params: Option<TsTypeParamDecl>,
+        type_ann: TsType,
+    ) -> TsTypeAliasDecl {
+        let _f0 = self.add_extra(id.to_extra_data());
+        let _f1 = self.add_extra(type_params.to_extra_data());
+        let _f2 = self.add_extra(type_ann.to_extra_data());
+        TsTypeAliasDecl(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTypeAliasDecl,
+            inline_data: (0u32 | declare as u32).into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_enum_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        is_const: bool,
+        id: Ident,
+        members: TypedSubRange<TsEnumMember>,
+    ) -> TsEnumDecl {
+        let _f0 = self.add_extra(declare.to_extra_data());
+        let _f1 = self.add_extra(is_const.to_extra_data());
+        let _f2 = self.add_extra(id.to_extra_data());
+        let _f3 = self.add_extra(members.to_extra_data());
+        TsEnumDecl(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsEnumDecl,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_enum_member(
+        &mut self,
+        span: Span,
+        id: TsEnumMemberId,
+        init: Option<Expr>,
+    ) -> TsEnumMember {
+        let _f0 = self.add_extra(id.to_extra_data());
+        let _f1 = self.add_extra(init.to_extra_data());
+        TsEnumMember(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsEnumMember,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_enum_member_id_ident(
+        &mut self,
+        span: Span,
+        sym: Utf8Ref,
+        optional: bool,
+    ) -> TsEnumMemberId {
+        TsEnumMemberId::Ident(self.ident(span, sym, optional).into())
+    }
+    #[inline]
+    pub fn ts_enum_member_id_str(
+        &mut self,
+        span: Span,
+        value: Wtf8Ref,
+        raw: OptionalUtf8Ref,
+    ) -> TsEnumMemberId {
+        TsEnumMemberId::Str(self.str(span, value, raw).into())
+    }
+    #[inline]
+    pub fn ts_module_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        global: bool,
+        namespace: bool,
+        id: TsModuleName,
+        body: Option<TsNamespaceBody>,
+    ) -> TsModuleDecl {
+        let _f0 = self.add_extra(id.to_extra_data());
+        let _f1 = self.add_extra(body.to_extra_data());
+        TsModuleDecl(
+            self.add_node(AstNode {
+                span,
+                kind: NodeKind::TsModuleDecl,
+                inline_data: (0u32
+                    | declare as u32
+                    | ((global as u32) << 8usize)
+                    | ((namespace as u32) << 16usize))
+                    .into(),
+                data: NodeData {
+                    extra_data_start: _f0,
+                },
+            }),
+        )
+    }
+    #[inline]
+    pub fn ts_namespace_body_ts_module_block(
+        &mut self,
+        span: Span,
+        body: TypedSubRange<ModuleItem>,
+    ) -> TsNamespaceBody {
+        TsNamespaceBody::TsModuleBlock(self.ts_module_block(span, body).into())
+    }
+    #[inline]
+    pub fn ts_namespace_body_ts_namespace_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        global: bool,
+        id: Ident,
+        body: TsNamespaceBody,
+    ) -> TsNamespaceBody {
+        TsNamespaceBody::TsNamespaceDecl(
+            self.ts_namespace_decl(span, declare, global, id, body)
+                .into(),
+        )
+    }
+    #[inline]
+    pub fn ts_module_block(
+        &mut self,
+        span: Span,
+        body: TypedSubRange<ModuleItem>,
+    ) -> TsModuleBlock {
+        let _f0 = self.add_extra(body.to_extra_data());
+        TsModuleBlock(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsModuleBlock,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_namespace_decl(
+        &mut self,
+        span: Span,
+        declare: bool,
+        global: bool,
+        id: Ident,
+        body: TsNamespaceBody,
+    ) -> TsNamespaceDecl {
+        let _f0 = self.add_extra(id.to_extra_data());
+        let _f1 = self.add_extra(body.to_extra_data());
+        TsNamespaceDecl(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsNamespaceDecl,
+            inline_data: (0u32 | declare as u32 | ((global as u32) << 8usize)).into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_module_name_ident(
+        &mut self,
+        span: Span,
+        sym: Utf8Ref,
+        optional: bool,
+    ) -> TsModuleName {
+        TsModuleName::Ident(self.ident(span, sym, optional).into())
+    }
+    #[inline]
+    pub fn ts_module_name_str(
+        &mut self,
+        span: Span,
+        value: Wtf8Ref,
+        raw: OptionalUtf8Ref,
+    ) -> TsModuleName {
+        TsModuleName::Str(self.str(span, value, raw).into())
+    }
+    #[inline]
+    pub fn ts_import_equals_decl(
+        &mut self,
+        span: Span,
+        is_export: bool,
+        is_type_only: bool,
+        id: Ident,
+        module_ref: TsModuleRef,
+    ) -> TsImportEqualsDecl {
+        let _f0 = self.add_extra(id.to_extra_data());
+        let _f1 = self.add_extra(module_ref.to_extra_data());
+        TsImportEqualsDecl(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsImportEqualsDecl,
+            inline_data: (0u32 | is_export as u32 | ((is_type_only as u32) << 8usize)).into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_module_ref_ts_entity_name_ts_qualified_name(
+        &mut self,
+        span: Span,
+        left: TsEntityName,
+        right: IdentName,
+    ) -> TsModuleRef {
+        TsModuleRef::TsEntityName(TsEntityName::TsQualifiedName(
+            self.ts_qualified_name(span, left, right).into(),
+        ))
+    }
+    #[inline]
+    pub fn ts_module_ref_ts_entity_name_ident(
+        &mut self,
+        span: Span,
+        sym: Utf8Ref,
+        optional: bool,
+    ) -> TsModuleRef {
+        TsModuleRef::TsEntityName(TsEntityName::Ident(self.ident(span, sym, optional).into()))
+    }
+    #[inline]
+    pub fn ts_module_ref_ts_external_module_ref(&mut self, span: Span, expr: Str) -> TsModuleRef {
+        TsModuleRef::TsExternalModuleRef(self.ts_external_module_ref(span, expr).into())
+    }
+    #[inline]
+    pub fn ts_external_module_ref(&mut self, span: Span, expr: Str) -> TsExternalModuleRef {
+        TsExternalModuleRef(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsExternalModuleRef,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                inline_data: expr.node_id().index() as u32,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_export_assignment(&mut self, span: Span, expr: Expr) -> TsExportAssignment {
+        let _f0 = self.add_extra(expr.to_extra_data());
+        TsExportAssignment(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsExportAssignment,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_namespace_export_decl(&mut self, span: Span, id: Ident) -> TsNamespaceExportDecl {
+        TsNamespaceExportDecl(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsNamespaceExportDecl,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                inline_data: id.node_id().index() as u32,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_as_expr(&mut self, span: Span, expr: Expr, type_ann: TsType) -> TsAsExpr {
+        let _f0 = self.add_extra(expr.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
+        TsAsExpr(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsAsExpr,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_type_assertion(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> TsTypeAssertion {
+        let _f0 = self.add_extra(expr.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
+        TsTypeAssertion(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsTypeAssertion,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_non_null_expr(&mut self, span: Span, expr: Expr) -> TsNonNullExpr {
+        let _f0 = self.add_extra(expr.to_extra_data());
+        TsNonNullExpr(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsNonNullExpr,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_satisfies_expr(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_ann: TsType,
+    ) -> TsSatisfiesExpr {
+        let _f0 = self.add_extra(expr.to_extra_data());
+        let _f1 = self.add_extra(type_ann.to_extra_data());
+        TsSatisfiesExpr(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsSatisfiesExpr,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_const_assertion(&mut self, span: Span, expr: Expr) -> TsConstAssertion {
+        let _f0 = self.add_extra(expr.to_extra_data());
+        TsConstAssertion(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsConstAssertion,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
+    #[inline]
+    pub fn ts_instantiation(
+        &mut self,
+        span: Span,
+        expr: Expr,
+        type_args: TsTypeParamInstantiation,
+    ) -> TsInstantiation {
+        let _f0 = self.add_extra(expr.to_extra_data());
+        let _f1 = self.add_extra(type_args.to_extra_data());
+        TsInstantiation(self.add_node(AstNode {
+            span,
+            kind: NodeKind::TsInstantiation,
+            inline_data: 0u32.into(),
+            data: NodeData {
+                extra_data_start: _f0,
+            },
+        }))
+    }
 }
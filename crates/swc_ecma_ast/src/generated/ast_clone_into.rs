@@ -0,0 +1,2534 @@
+#![allow(unused)]
+use crate::{Ast, CloneInto, GetSpan};
+use crate::{ast::*, node_id::*};
+impl CloneInto for Program {
+    type Cloned = Program;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Module(it) => Self::Module(it.clone_into_ast(from, to)),
+            Self::Script(it) => Self::Script(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for Module {
+    type Cloned = Module;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let body = self.body(from).clone_into_ast(from, to);
+        let shebang = self.shebang(from).clone_into_ast(from, to);
+        to.module(span, body, shebang)
+    }
+}
+impl CloneInto for Script {
+    type Cloned = Script;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let body = self.body(from).clone_into_ast(from, to);
+        let shebang = self.shebang(from).clone_into_ast(from, to);
+        to.script(span, body, shebang)
+    }
+}
+impl CloneInto for ModuleItem {
+    type Cloned = ModuleItem;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::ModuleDecl(it) => Self::ModuleDecl(it.clone_into_ast(from, to)),
+            Self::Stmt(it) => Self::Stmt(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ModuleDecl {
+    type Cloned = ModuleDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Import(it) => Self::Import(it.clone_into_ast(from, to)),
+            Self::ExportDecl(it) => Self::ExportDecl(it.clone_into_ast(from, to)),
+            Self::ExportNamed(it) => Self::ExportNamed(it.clone_into_ast(from, to)),
+            Self::ExportDefaultDecl(it) => Self::ExportDefaultDecl(it.clone_into_ast(from, to)),
+            Self::ExportDefaultExpr(it) => Self::ExportDefaultExpr(it.clone_into_ast(from, to)),
+            Self::ExportAll(it) => Self::ExportAll(it.clone_into_ast(from, to)),
+            Self::TsImportEquals(it) => Self::TsImportEquals(it.clone_into_ast(from, to)),
+            Self::TsExportAssignment(it) => Self::TsExportAssignment(it.clone_into_ast(from, to)),
+            Self::TsNamespaceExport(it) => Self::TsNamespaceExport(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ImportDecl {
+    type Cloned = ImportDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let specifiers = self.specifiers(from).clone_into_ast(from, to);
+        let src = self.src(from).clone_into_ast(from, to);
+        let type_only = self.type_only(from).clone_into_ast(from, to);
+        let with = self.with(from).clone_into_ast(from, to);
+        let phase = self.phase(from).clone_into_ast(from, to);
+        to.import_decl(span, specifiers, src, type_only, with, phase)
+    }
+}
+impl CloneInto for ImportSpecifier {
+    type Cloned = ImportSpecifier;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Named(it) => Self::Named(it.clone_into_ast(from, to)),
+            Self::Default(it) => Self::Default(it.clone_into_ast(from, to)),
+            Self::Namespace(it) => Self::Namespace(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ImportNamedSpecifier {
+    type Cloned = ImportNamedSpecifier;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let local = self.local(from).clone_into_ast(from, to);
+        let imported = self.imported(from).clone_into_ast(from, to);
+        let is_type_only = self.is_type_only(from).clone_into_ast(from, to);
+        to.import_named_specifier(span, local, imported, is_type_only)
+    }
+}
+impl CloneInto for ImportDefaultSpecifier {
+    type Cloned = ImportDefaultSpecifier;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let local = self.local(from).clone_into_ast(from, to);
+        to.import_default_specifier(span, local)
+    }
+}
+impl CloneInto for ImportStarAsSpecifier {
+    type Cloned = ImportStarAsSpecifier;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let local = self.local(from).clone_into_ast(from, to);
+        to.import_star_as_specifier(span, local)
+    }
+}
+impl CloneInto for ExportDecl {
+    type Cloned = ExportDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let decl = self.decl(from).clone_into_ast(from, to);
+        to.export_decl(span, decl)
+    }
+}
+impl CloneInto for NamedExport {
+    type Cloned = NamedExport;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let specifiers = self.specifiers(from).clone_into_ast(from, to);
+        let src = self.src(from).clone_into_ast(from, to);
+        let type_only = self.type_only(from).clone_into_ast(from, to);
+        let with = self.with(from).clone_into_ast(from, to);
+        to.named_export(span, specifiers, src, type_only, with)
+    }
+}
+impl CloneInto for ExportSpecifier {
+    type Cloned = ExportSpecifier;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Namespace(it) => Self::Namespace(it.clone_into_ast(from, to)),
+            Self::Default(it) => Self::Default(it.clone_into_ast(from, to)),
+            Self::Named(it) => Self::Named(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ExportNamespaceSpecifier {
+    type Cloned = ExportNamespaceSpecifier;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let name = self.name(from).clone_into_ast(from, to);
+        to.export_namespace_specifier(span, name)
+    }
+}
+impl CloneInto for ModuleExportName {
+    type Cloned = ModuleExportName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::Str(it) => Self::Str(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ExportDefaultSpecifier {
+    type Cloned = ExportDefaultSpecifier;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let exported = self.exported(from).clone_into_ast(from, to);
+        to.export_default_specifier(span, exported)
+    }
+}
+impl CloneInto for ExportNamedSpecifier {
+    type Cloned = ExportNamedSpecifier;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let orig = self.orig(from).clone_into_ast(from, to);
+        let exported = self.exported(from).clone_into_ast(from, to);
+        let is_type_only = self.is_type_only(from).clone_into_ast(from, to);
+        to.export_named_specifier(span, orig, exported, is_type_only)
+    }
+}
+impl CloneInto for ExportDefaultDecl {
+    type Cloned = ExportDefaultDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let decl = self.decl(from).clone_into_ast(from, to);
+        to.export_default_decl(span, decl)
+    }
+}
+impl CloneInto for DefaultDecl {
+    type Cloned = DefaultDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Class(it) => Self::Class(it.clone_into_ast(from, to)),
+            Self::Fn(it) => Self::Fn(it.clone_into_ast(from, to)),
+            Self::TsInterfaceDecl(it) => Self::TsInterfaceDecl(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ExportDefaultExpr {
+    type Cloned = ExportDefaultExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.export_default_expr(span, expr)
+    }
+}
+impl CloneInto for ExportAll {
+    type Cloned = ExportAll;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let src = self.src(from).clone_into_ast(from, to);
+        let type_only = self.type_only(from).clone_into_ast(from, to);
+        let with = self.with(from).clone_into_ast(from, to);
+        to.export_all(span, src, type_only, with)
+    }
+}
+impl CloneInto for BlockStmt {
+    type Cloned = BlockStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let stmts = self.stmts(from).clone_into_ast(from, to);
+        to.block_stmt(span, stmts)
+    }
+}
+impl CloneInto for Stmt {
+    type Cloned = Stmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Block(it) => Self::Block(it.clone_into_ast(from, to)),
+            Self::Empty(it) => Self::Empty(it.clone_into_ast(from, to)),
+            Self::Debugger(it) => Self::Debugger(it.clone_into_ast(from, to)),
+            Self::With(it) => Self::With(it.clone_into_ast(from, to)),
+            Self::Return(it) => Self::Return(it.clone_into_ast(from, to)),
+            Self::Labeled(it) => Self::Labeled(it.clone_into_ast(from, to)),
+            Self::Break(it) => Self::Break(it.clone_into_ast(from, to)),
+            Self::Continue(it) => Self::Continue(it.clone_into_ast(from, to)),
+            Self::If(it) => Self::If(it.clone_into_ast(from, to)),
+            Self::Switch(it) => Self::Switch(it.clone_into_ast(from, to)),
+            Self::Throw(it) => Self::Throw(it.clone_into_ast(from, to)),
+            Self::Try(it) => Self::Try(it.clone_into_ast(from, to)),
+            Self::While(it) => Self::While(it.clone_into_ast(from, to)),
+            Self::DoWhile(it) => Self::DoWhile(it.clone_into_ast(from, to)),
+            Self::For(it) => Self::For(it.clone_into_ast(from, to)),
+            Self::ForIn(it) => Self::ForIn(it.clone_into_ast(from, to)),
+            Self::ForOf(it) => Self::ForOf(it.clone_into_ast(from, to)),
+            Self::Decl(it) => Self::Decl(it.clone_into_ast(from, to)),
+            Self::Expr(it) => Self::Expr(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ExprStmt {
+    type Cloned = ExprStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.expr_stmt(span, expr)
+    }
+}
+impl CloneInto for EmptyStmt {
+    type Cloned = EmptyStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.empty_stmt(span)
+    }
+}
+impl CloneInto for DebuggerStmt {
+    type Cloned = DebuggerStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.debugger_stmt(span)
+    }
+}
+impl CloneInto for WithStmt {
+    type Cloned = WithStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let obj = self.obj(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.with_stmt(span, obj, body)
+    }
+}
+impl CloneInto for ReturnStmt {
+    type Cloned = ReturnStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let arg = self.arg(from).clone_into_ast(from, to);
+        to.return_stmt(span, arg)
+    }
+}
+impl CloneInto for LabeledStmt {
+    type Cloned = LabeledStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let label = self.label(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.labeled_stmt(span, label, body)
+    }
+}
+impl CloneInto for BreakStmt {
+    type Cloned = BreakStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let label = self.label(from).clone_into_ast(from, to);
+        to.break_stmt(span, label)
+    }
+}
+impl CloneInto for ContinueStmt {
+    type Cloned = ContinueStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let label = self.label(from).clone_into_ast(from, to);
+        to.continue_stmt(span, label)
+    }
+}
+impl CloneInto for IfStmt {
+    type Cloned = IfStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let test = self.test(from).clone_into_ast(from, to);
+        let cons = self.cons(from).clone_into_ast(from, to);
+        let alt = self.alt(from).clone_into_ast(from, to);
+        to.if_stmt(span, test, cons, alt)
+    }
+}
+impl CloneInto for SwitchStmt {
+    type Cloned = SwitchStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let discriminant = self.discriminant(from).clone_into_ast(from, to);
+        let cases = self.cases(from).clone_into_ast(from, to);
+        to.switch_stmt(span, discriminant, cases)
+    }
+}
+impl CloneInto for ThrowStmt {
+    type Cloned = ThrowStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let arg = self.arg(from).clone_into_ast(from, to);
+        to.throw_stmt(span, arg)
+    }
+}
+impl CloneInto for TryStmt {
+    type Cloned = TryStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let block = self.block(from).clone_into_ast(from, to);
+        let handler = self.handler(from).clone_into_ast(from, to);
+        let finalizer = self.finalizer(from).clone_into_ast(from, to);
+        to.try_stmt(span, block, handler, finalizer)
+    }
+}
+impl CloneInto for WhileStmt {
+    type Cloned = WhileStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let test = self.test(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.while_stmt(span, test, body)
+    }
+}
+impl CloneInto for DoWhileStmt {
+    type Cloned = DoWhileStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let test = self.test(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.do_while_stmt(span, test, body)
+    }
+}
+impl CloneInto for ForStmt {
+    type Cloned = ForStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let init = self.init(from).clone_into_ast(from, to);
+        let test = self.test(from).clone_into_ast(from, to);
+        let update = self.update(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.for_stmt(span, init, test, update, body)
+    }
+}
+impl CloneInto for ForInStmt {
+    type Cloned = ForInStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let left = self.left(from).clone_into_ast(from, to);
+        let right = self.right(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.for_in_stmt(span, left, right, body)
+    }
+}
+impl CloneInto for ForOfStmt {
+    type Cloned = ForOfStmt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let is_await = self.is_await(from).clone_into_ast(from, to);
+        let left = self.left(from).clone_into_ast(from, to);
+        let right = self.right(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.for_of_stmt(span, is_await, left, right, body)
+    }
+}
+impl CloneInto for SwitchCase {
+    type Cloned = SwitchCase;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let test = self.test(from).clone_into_ast(from, to);
+        let cons = self.cons(from).clone_into_ast(from, to);
+        to.switch_case(span, test, cons)
+    }
+}
+impl CloneInto for CatchClause {
+    type Cloned = CatchClause;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let param = self.param(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.catch_clause(span, param, body)
+    }
+}
+impl CloneInto for ForHead {
+    type Cloned = ForHead;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::VarDecl(it) => Self::VarDecl(it.clone_into_ast(from, to)),
+            Self::UsingDecl(it) => Self::UsingDecl(it.clone_into_ast(from, to)),
+            Self::Pat(it) => Self::Pat(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for VarDeclOrExpr {
+    type Cloned = VarDeclOrExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::VarDecl(it) => Self::VarDecl(it.clone_into_ast(from, to)),
+            Self::Expr(it) => Self::Expr(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for Decl {
+    type Cloned = Decl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Class(it) => Self::Class(it.clone_into_ast(from, to)),
+            Self::Fn(it) => Self::Fn(it.clone_into_ast(from, to)),
+            Self::Var(it) => Self::Var(it.clone_into_ast(from, to)),
+            Self::Using(it) => Self::Using(it.clone_into_ast(from, to)),
+            Self::TsInterface(it) => Self::TsInterface(it.clone_into_ast(from, to)),
+            Self::TsTypeAlias(it) => Self::TsTypeAlias(it.clone_into_ast(from, to)),
+            Self::TsEnum(it) => Self::TsEnum(it.clone_into_ast(from, to)),
+            Self::TsModule(it) => Self::TsModule(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for FnDecl {
+    type Cloned = FnDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let ident = self.ident(from).clone_into_ast(from, to);
+        let declare = self.declare(from).clone_into_ast(from, to);
+        let function = self.function(from).clone_into_ast(from, to);
+        to.fn_decl(span, ident, declare, function)
+    }
+}
+impl CloneInto for ClassDecl {
+    type Cloned = ClassDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let ident = self.ident(from).clone_into_ast(from, to);
+        let declare = self.declare(from).clone_into_ast(from, to);
+        let class = self.class(from).clone_into_ast(from, to);
+        to.class_decl(span, ident, declare, class)
+    }
+}
+impl CloneInto for VarDecl {
+    type Cloned = VarDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let kind = self.kind(from).clone_into_ast(from, to);
+        let declare = self.declare(from).clone_into_ast(from, to);
+        let decls = self.decls(from).clone_into_ast(from, to);
+        to.var_decl(span, kind, declare, decls)
+    }
+}
+impl CloneInto for VarDeclarator {
+    type Cloned = VarDeclarator;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let name = self.name(from).clone_into_ast(from, to);
+        let init = self.init(from).clone_into_ast(from, to);
+        let definite = self.definite(from).clone_into_ast(from, to);
+        to.var_declarator(span, name, init, definite)
+    }
+}
+impl CloneInto for UsingDecl {
+    type Cloned = UsingDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let is_await = self.is_await(from).clone_into_ast(from, to);
+        let decls = self.decls(from).clone_into_ast(from, to);
+        to.using_decl(span, is_await, decls)
+    }
+}
+impl CloneInto for Expr {
+    type Cloned = Expr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::This(it) => Self::This(it.clone_into_ast(from, to)),
+            Self::Array(it) => Self::Array(it.clone_into_ast(from, to)),
+            Self::Object(it) => Self::Object(it.clone_into_ast(from, to)),
+            Self::Fn(it) => Self::Fn(it.clone_into_ast(from, to)),
+            Self::Unary(it) => Self::Unary(it.clone_into_ast(from, to)),
+            Self::Update(it) => Self::Update(it.clone_into_ast(from, to)),
+            Self::Bin(it) => Self::Bin(it.clone_into_ast(from, to)),
+            Self::Assign(it) => Self::Assign(it.clone_into_ast(from, to)),
+            Self::Member(it) => Self::Member(it.clone_into_ast(from, to)),
+            Self::SuperProp(it) => Self::SuperProp(it.clone_into_ast(from, to)),
+            Self::Cond(it) => Self::Cond(it.clone_into_ast(from, to)),
+            Self::Call(it) => Self::Call(it.clone_into_ast(from, to)),
+            Self::New(it) => Self::New(it.clone_into_ast(from, to)),
+            Self::Seq(it) => Self::Seq(it.clone_into_ast(from, to)),
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::Lit(it) => Self::Lit(it.clone_into_ast(from, to)),
+            Self::Tpl(it) => Self::Tpl(it.clone_into_ast(from, to)),
+            Self::TaggedTpl(it) => Self::TaggedTpl(it.clone_into_ast(from, to)),
+            Self::Arrow(it) => Self::Arrow(it.clone_into_ast(from, to)),
+            Self::Class(it) => Self::Class(it.clone_into_ast(from, to)),
+            Self::Yield(it) => Self::Yield(it.clone_into_ast(from, to)),
+            Self::MetaProp(it) => Self::MetaProp(it.clone_into_ast(from, to)),
+            Self::Await(it) => Self::Await(it.clone_into_ast(from, to)),
+            Self::Paren(it) => Self::Paren(it.clone_into_ast(from, to)),
+            Self::JSXMember(it) => Self::JSXMember(it.clone_into_ast(from, to)),
+            Self::JSXNamespacedName(it) => Self::JSXNamespacedName(it.clone_into_ast(from, to)),
+            Self::JSXEmpty(it) => Self::JSXEmpty(it.clone_into_ast(from, to)),
+            Self::JSXElement(it) => Self::JSXElement(it.clone_into_ast(from, to)),
+            Self::JSXFragment(it) => Self::JSXFragment(it.clone_into_ast(from, to)),
+            Self::TsTypeAssertion(it) => Self::TsTypeAssertion(it.clone_into_ast(from, to)),
+            Self::TsConstAssertion(it) => Self::TsConstAssertion(it.clone_into_ast(from, to)),
+            Self::TsNonNull(it) => Self::TsNonNull(it.clone_into_ast(from, to)),
+            Self::TsAs(it) => Self::TsAs(it.clone_into_ast(from, to)),
+            Self::TsInstantiation(it) => Self::TsInstantiation(it.clone_into_ast(from, to)),
+            Self::TsSatisfies(it) => Self::TsSatisfies(it.clone_into_ast(from, to)),
+            Self::PrivateName(it) => Self::PrivateName(it.clone_into_ast(from, to)),
+            Self::OptChain(it) => Self::OptChain(it.clone_into_ast(from, to)),
+            Self::Invalid(it) => Self::Invalid(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ThisExpr {
+    type Cloned = ThisExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.this_expr(span)
+    }
+}
+impl CloneInto for ArrayLit {
+    type Cloned = ArrayLit;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let elems = self.elems(from).clone_into_ast(from, to);
+        to.array_lit(span, elems)
+    }
+}
+impl CloneInto for ObjectLit {
+    type Cloned = ObjectLit;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let props = self.props(from).clone_into_ast(from, to);
+        to.object_lit(span, props)
+    }
+}
+impl CloneInto for PropOrSpread {
+    type Cloned = PropOrSpread;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::SpreadElement(it) => Self::SpreadElement(it.clone_into_ast(from, to)),
+            Self::Prop(it) => Self::Prop(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for SpreadElement {
+    type Cloned = SpreadElement;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let dot_3_token = self.dot_3_token(from).clone_into_ast(from, to);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.spread_element(span, dot_3_token, expr)
+    }
+}
+impl CloneInto for UnaryExpr {
+    type Cloned = UnaryExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let op = self.op(from).clone_into_ast(from, to);
+        let arg = self.arg(from).clone_into_ast(from, to);
+        to.unary_expr(span, op, arg)
+    }
+}
+impl CloneInto for UpdateExpr {
+    type Cloned = UpdateExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let op = self.op(from).clone_into_ast(from, to);
+        let prefix = self.prefix(from).clone_into_ast(from, to);
+        let arg = self.arg(from).clone_into_ast(from, to);
+        to.update_expr(span, op, prefix, arg)
+    }
+}
+impl CloneInto for BinExpr {
+    type Cloned = BinExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let op = self.op(from).clone_into_ast(from, to);
+        let left = self.left(from).clone_into_ast(from, to);
+        let right = self.right(from).clone_into_ast(from, to);
+        to.bin_expr(span, op, left, right)
+    }
+}
+impl CloneInto for FnExpr {
+    type Cloned = FnExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let ident = self.ident(from).clone_into_ast(from, to);
+        let function = self.function(from).clone_into_ast(from, to);
+        to.fn_expr(span, ident, function)
+    }
+}
+impl CloneInto for ClassExpr {
+    type Cloned = ClassExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let ident = self.ident(from).clone_into_ast(from, to);
+        let class = self.class(from).clone_into_ast(from, to);
+        to.class_expr(span, ident, class)
+    }
+}
+impl CloneInto for AssignExpr {
+    type Cloned = AssignExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let op = self.op(from).clone_into_ast(from, to);
+        let left = self.left(from).clone_into_ast(from, to);
+        let right = self.right(from).clone_into_ast(from, to);
+        to.assign_expr(span, op, left, right)
+    }
+}
+impl CloneInto for MemberExpr {
+    type Cloned = MemberExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let obj = self.obj(from).clone_into_ast(from, to);
+        let prop = self.prop(from).clone_into_ast(from, to);
+        to.member_expr(span, obj, prop)
+    }
+}
+impl CloneInto for MemberProp {
+    type Cloned = MemberProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::PrivateName(it) => Self::PrivateName(it.clone_into_ast(from, to)),
+            Self::Computed(it) => Self::Computed(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for SuperPropExpr {
+    type Cloned = SuperPropExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let obj = self.obj(from).clone_into_ast(from, to);
+        let prop = self.prop(from).clone_into_ast(from, to);
+        to.super_prop_expr(span, obj, prop)
+    }
+}
+impl CloneInto for SuperProp {
+    type Cloned = SuperProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::Computed(it) => Self::Computed(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for CondExpr {
+    type Cloned = CondExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let test = self.test(from).clone_into_ast(from, to);
+        let cons = self.cons(from).clone_into_ast(from, to);
+        let alt = self.alt(from).clone_into_ast(from, to);
+        to.cond_expr(span, test, cons, alt)
+    }
+}
+impl CloneInto for CallExpr {
+    type Cloned = CallExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let callee = self.callee(from).clone_into_ast(from, to);
+        let args = self.args(from).clone_into_ast(from, to);
+        let type_args = self.type_args(from).clone_into_ast(from, to);
+        to.call_expr(span, callee, args, type_args)
+    }
+}
+impl CloneInto for NewExpr {
+    type Cloned = NewExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let callee = self.callee(from).clone_into_ast(from, to);
+        let args = self.args(from).clone_into_ast(from, to);
+        let type_args = self.type_args(from).clone_into_ast(from, to);
+        to.new_expr(span, callee, args, type_args)
+    }
+}
+impl CloneInto for SeqExpr {
+    type Cloned = SeqExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let exprs = self.exprs(from).clone_into_ast(from, to);
+        to.seq_expr(span, exprs)
+    }
+}
+impl CloneInto for ArrowExpr {
+    type Cloned = ArrowExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let params = self.params(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        let is_async = self.is_async(from).clone_into_ast(from, to);
+        let is_generator = self.is_generator(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        let return_type = self.return_type(from).clone_into_ast(from, to);
+        to.arrow_expr(
+            span,
+            params,
+            body,
+            is_async,
+            is_generator,
+            type_params,
+            return_type,
+        )
+    }
+}
+impl CloneInto for YieldExpr {
+    type Cloned = YieldExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let arg = self.arg(from).clone_into_ast(from, to);
+        let delegate = self.delegate(from).clone_into_ast(from, to);
+        to.yield_expr(span, arg, delegate)
+    }
+}
+impl CloneInto for MetaPropExpr {
+    type Cloned = MetaPropExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let kind = self.kind(from).clone_into_ast(from, to);
+        to.meta_prop_expr(span, kind)
+    }
+}
+impl CloneInto for AwaitExpr {
+    type Cloned = AwaitExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let arg = self.arg(from).clone_into_ast(from, to);
+        to.await_expr(span, arg)
+    }
+}
+impl CloneInto for Tpl {
+    type Cloned = Tpl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let exprs = self.exprs(from).clone_into_ast(from, to);
+        let quasis = self.quasis(from).clone_into_ast(from, to);
+        to.tpl(span, exprs, quasis)
+    }
+}
+impl CloneInto for TaggedTpl {
+    type Cloned = TaggedTpl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let tag = self.tag(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        let tpl = self.tpl(from).clone_into_ast(from, to);
+        to.tagged_tpl(span, tag, type_params, tpl)
+    }
+}
+impl CloneInto for TplElement {
+    type Cloned = TplElement;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let tail = self.tail(from).clone_into_ast(from, to);
+        let cooked = self.cooked(from).clone_into_ast(from, to);
+        let raw = self.raw(from).clone_into_ast(from, to);
+        to.tpl_element(span, tail, cooked, raw)
+    }
+}
+impl CloneInto for ParenExpr {
+    type Cloned = ParenExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.paren_expr(span, expr)
+    }
+}
+impl CloneInto for Callee {
+    type Cloned = Callee;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Super(it) => Self::Super(it.clone_into_ast(from, to)),
+            Self::Import(it) => Self::Import(it.clone_into_ast(from, to)),
+            Self::Expr(it) => Self::Expr(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for Super {
+    type Cloned = Super;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.super_(span)
+    }
+}
+impl CloneInto for Import {
+    type Cloned = Import;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let phase = self.phase(from).clone_into_ast(from, to);
+        to.import(span, phase)
+    }
+}
+impl CloneInto for ExprOrSpread {
+    type Cloned = ExprOrSpread;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let spread = self.spread(from).clone_into_ast(from, to);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.expr_or_spread(span, spread, expr)
+    }
+}
+impl CloneInto for SpreadDot3Token {
+    type Cloned = SpreadDot3Token;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.spread_dot_3_token(span)
+    }
+}
+impl CloneInto for BlockStmtOrExpr {
+    type Cloned = BlockStmtOrExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::BlockStmt(it) => Self::BlockStmt(it.clone_into_ast(from, to)),
+            Self::Expr(it) => Self::Expr(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for AssignTarget {
+    type Cloned = AssignTarget;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Simple(it) => Self::Simple(it.clone_into_ast(from, to)),
+            Self::Pat(it) => Self::Pat(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for AssignTargetPat {
+    type Cloned = AssignTargetPat;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Array(it) => Self::Array(it.clone_into_ast(from, to)),
+            Self::Object(it) => Self::Object(it.clone_into_ast(from, to)),
+            Self::Invalid(it) => Self::Invalid(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for SimpleAssignTarget {
+    type Cloned = SimpleAssignTarget;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::Member(it) => Self::Member(it.clone_into_ast(from, to)),
+            Self::SuperProp(it) => Self::SuperProp(it.clone_into_ast(from, to)),
+            Self::Paren(it) => Self::Paren(it.clone_into_ast(from, to)),
+            Self::OptChain(it) => Self::OptChain(it.clone_into_ast(from, to)),
+            Self::TsAs(it) => Self::TsAs(it.clone_into_ast(from, to)),
+            Self::TsSatisfies(it) => Self::TsSatisfies(it.clone_into_ast(from, to)),
+            Self::TsNonNull(it) => Self::TsNonNull(it.clone_into_ast(from, to)),
+            Self::TsTypeAssertion(it) => Self::TsTypeAssertion(it.clone_into_ast(from, to)),
+            Self::TsInstantiation(it) => Self::TsInstantiation(it.clone_into_ast(from, to)),
+            Self::Invalid(it) => Self::Invalid(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for OptChainExpr {
+    type Cloned = OptChainExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let optional = self.optional(from).clone_into_ast(from, to);
+        let base = self.base(from).clone_into_ast(from, to);
+        to.opt_chain_expr(span, optional, base)
+    }
+}
+impl CloneInto for OptChainBase {
+    type Cloned = OptChainBase;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Member(it) => Self::Member(it.clone_into_ast(from, to)),
+            Self::Call(it) => Self::Call(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for OptCall {
+    type Cloned = OptCall;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let callee = self.callee(from).clone_into_ast(from, to);
+        let args = self.args(from).clone_into_ast(from, to);
+        let type_args = self.type_args(from).clone_into_ast(from, to);
+        to.opt_call(span, callee, args, type_args)
+    }
+}
+impl CloneInto for Invalid {
+    type Cloned = Invalid;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.invalid(span)
+    }
+}
+impl CloneInto for Function {
+    type Cloned = Function;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let params = self.params(from).clone_into_ast(from, to);
+        let decorators = self.decorators(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        let is_generator = self.is_generator(from).clone_into_ast(from, to);
+        let is_async = self.is_async(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        let return_type = self.return_type(from).clone_into_ast(from, to);
+        to.function(
+            span,
+            params,
+            decorators,
+            body,
+            is_generator,
+            is_async,
+            type_params,
+            return_type,
+        )
+    }
+}
+impl CloneInto for Param {
+    type Cloned = Param;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let decorators = self.decorators(from).clone_into_ast(from, to);
+        let pat = self.pat(from).clone_into_ast(from, to);
+        to.param(span, decorators, pat)
+    }
+}
+impl CloneInto for ParamOrTsParamProp {
+    type Cloned = ParamOrTsParamProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::TsParamProp(it) => Self::TsParamProp(it.clone_into_ast(from, to)),
+            Self::Param(it) => Self::Param(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for Class {
+    type Cloned = Class;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let decorators = self.decorators(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        let super_class = self.super_class(from).clone_into_ast(from, to);
+        let is_abstract = self.is_abstract(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        let super_type_params = self.super_type_params(from).clone_into_ast(from, to);
+        let implements = self.implements(from).clone_into_ast(from, to);
+        to.class(
+            span,
+            decorators,
+            body,
+            super_class,
+            is_abstract,
+            type_params,
+            super_type_params,
+            implements,
+        )
+    }
+}
+impl CloneInto for ClassMember {
+    type Cloned = ClassMember;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Constructor(it) => Self::Constructor(it.clone_into_ast(from, to)),
+            Self::Method(it) => Self::Method(it.clone_into_ast(from, to)),
+            Self::PrivateMethod(it) => Self::PrivateMethod(it.clone_into_ast(from, to)),
+            Self::ClassProp(it) => Self::ClassProp(it.clone_into_ast(from, to)),
+            Self::PrivateProp(it) => Self::PrivateProp(it.clone_into_ast(from, to)),
+            Self::Empty(it) => Self::Empty(it.clone_into_ast(from, to)),
+            Self::StaticBlock(it) => Self::StaticBlock(it.clone_into_ast(from, to)),
+            Self::AutoAccessor(it) => Self::AutoAccessor(it.clone_into_ast(from, to)),
+            Self::TsIndexSignature(it) => Self::TsIndexSignature(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ClassProp {
+    type Cloned = ClassProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let value = self.value(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        let is_static = self.is_static(from).clone_into_ast(from, to);
+        let decorators = self.decorators(from).clone_into_ast(from, to);
+        let accessibility = self.accessibility(from).clone_into_ast(from, to);
+        let is_abstract = self.is_abstract(from).clone_into_ast(from, to);
+        let is_optional = self.is_optional(from).clone_into_ast(from, to);
+        let is_override = self.is_override(from).clone_into_ast(from, to);
+        let readonly = self.readonly(from).clone_into_ast(from, to);
+        let declare = self.declare(from).clone_into_ast(from, to);
+        let definite = self.definite(from).clone_into_ast(from, to);
+        to.class_prop(
+            span,
+            key,
+            value,
+            type_ann,
+            is_static,
+            decorators,
+            accessibility,
+            is_abstract,
+            is_optional,
+            is_override,
+            readonly,
+            declare,
+            definite,
+        )
+    }
+}
+impl CloneInto for PrivateProp {
+    type Cloned = PrivateProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let value = self.value(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        let is_static = self.is_static(from).clone_into_ast(from, to);
+        let decorators = self.decorators(from).clone_into_ast(from, to);
+        let accessibility = self.accessibility(from).clone_into_ast(from, to);
+        let is_optional = self.is_optional(from).clone_into_ast(from, to);
+        let is_override = self.is_override(from).clone_into_ast(from, to);
+        let readonly = self.readonly(from).clone_into_ast(from, to);
+        let definite = self.definite(from).clone_into_ast(from, to);
+        to.private_prop(
+            span,
+            key,
+            value,
+            type_ann,
+            is_static,
+            decorators,
+            accessibility,
+            is_optional,
+            is_override,
+            readonly,
+            definite,
+        )
+    }
+}
+impl CloneInto for ClassMethod {
+    type Cloned = ClassMethod;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let function = self.function(from).clone_into_ast(from, to);
+        let kind = self.kind(from).clone_into_ast(from, to);
+        let is_static = self.is_static(from).clone_into_ast(from, to);
+        let accessibility = self.accessibility(from).clone_into_ast(from, to);
+        let is_abstract = self.is_abstract(from).clone_into_ast(from, to);
+        let is_optional = self.is_optional(from).clone_into_ast(from, to);
+        let is_override = self.is_override(from).clone_into_ast(from, to);
+        to.class_method(
+            span,
+            key,
+            function,
+            kind,
+            is_static,
+            accessibility,
+            is_abstract,
+            is_optional,
+            is_override,
+        )
+    }
+}
+impl CloneInto for PrivateMethod {
+    type Cloned = PrivateMethod;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let function = self.function(from).clone_into_ast(from, to);
+        let kind = self.kind(from).clone_into_ast(from, to);
+        let is_static = self.is_static(from).clone_into_ast(from, to);
+        let accessibility = self.accessibility(from).clone_into_ast(from, to);
+        let is_abstract = self.is_abstract(from).clone_into_ast(from, to);
+        let is_optional = self.is_optional(from).clone_into_ast(from, to);
+        let is_override = self.is_override(from).clone_into_ast(from, to);
+        to.private_method(
+            span,
+            key,
+            function,
+            kind,
+            is_static,
+            accessibility,
+            is_abstract,
+            is_optional,
+            is_override,
+        )
+    }
+}
+impl CloneInto for Constructor {
+    type Cloned = Constructor;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let params = self.params(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        let accessibility = self.accessibility(from).clone_into_ast(from, to);
+        let is_optional = self.is_optional(from).clone_into_ast(from, to);
+        to.constructor(span, key, params, body, accessibility, is_optional)
+    }
+}
+impl CloneInto for Decorator {
+    type Cloned = Decorator;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.decorator(span, expr)
+    }
+}
+impl CloneInto for StaticBlock {
+    type Cloned = StaticBlock;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.static_block(span, body)
+    }
+}
+impl CloneInto for Key {
+    type Cloned = Key;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Private(it) => Self::Private(it.clone_into_ast(from, to)),
+            Self::Public(it) => Self::Public(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for AutoAccessor {
+    type Cloned = AutoAccessor;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let value = self.value(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        let is_static = self.is_static(from).clone_into_ast(from, to);
+        let decorators = self.decorators(from).clone_into_ast(from, to);
+        let accessibility = self.accessibility(from).clone_into_ast(from, to);
+        let is_abstract = self.is_abstract(from).clone_into_ast(from, to);
+        let is_override = self.is_override(from).clone_into_ast(from, to);
+        let definite = self.definite(from).clone_into_ast(from, to);
+        to.auto_accessor(
+            span,
+            key,
+            value,
+            type_ann,
+            is_static,
+            decorators,
+            accessibility,
+            is_abstract,
+            is_override,
+            definite,
+        )
+    }
+}
+impl CloneInto for Prop {
+    type Cloned = Prop;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Shorthand(it) => Self::Shorthand(it.clone_into_ast(from, to)),
+            Self::KeyValue(it) => Self::KeyValue(it.clone_into_ast(from, to)),
+            Self::Assign(it) => Self::Assign(it.clone_into_ast(from, to)),
+            Self::Getter(it) => Self::Getter(it.clone_into_ast(from, to)),
+            Self::Setter(it) => Self::Setter(it.clone_into_ast(from, to)),
+            Self::Method(it) => Self::Method(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for KeyValueProp {
+    type Cloned = KeyValueProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let value = self.value(from).clone_into_ast(from, to);
+        to.key_value_prop(span, key, value)
+    }
+}
+impl CloneInto for AssignProp {
+    type Cloned = AssignProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let value = self.value(from).clone_into_ast(from, to);
+        to.assign_prop(span, key, value)
+    }
+}
+impl CloneInto for GetterProp {
+    type Cloned = GetterProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.getter_prop(span, key, type_ann, body)
+    }
+}
+impl CloneInto for SetterProp {
+    type Cloned = SetterProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let this_param = self.this_param(from).clone_into_ast(from, to);
+        let param = self.param(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.setter_prop(span, key, this_param, param, body)
+    }
+}
+impl CloneInto for MethodProp {
+    type Cloned = MethodProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let function = self.function(from).clone_into_ast(from, to);
+        to.method_prop(span, key, function)
+    }
+}
+impl CloneInto for PropName {
+    type Cloned = PropName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::Str(it) => Self::Str(it.clone_into_ast(from, to)),
+            Self::Num(it) => Self::Num(it.clone_into_ast(from, to)),
+            Self::Computed(it) => Self::Computed(it.clone_into_ast(from, to)),
+            Self::BigInt(it) => Self::BigInt(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ComputedPropName {
+    type Cloned = ComputedPropName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.computed_prop_name(span, expr)
+    }
+}
+impl CloneInto for Pat {
+    type Cloned = Pat;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::Array(it) => Self::Array(it.clone_into_ast(from, to)),
+            Self::Rest(it) => Self::Rest(it.clone_into_ast(from, to)),
+            Self::Object(it) => Self::Object(it.clone_into_ast(from, to)),
+            Self::Assign(it) => Self::Assign(it.clone_into_ast(from, to)),
+            Self::Invalid(it) => Self::Invalid(it.clone_into_ast(from, to)),
+            Self::Expr(it) => Self::Expr(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for ArrayPat {
+    type Cloned = ArrayPat;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let elems = self.elems(from).clone_into_ast(from, to);
+        let optional = self.optional(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.array_pat(span, elems, optional, type_ann)
+    }
+}
+impl CloneInto for ObjectPat {
+    type Cloned = ObjectPat;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let props = self.props(from).clone_into_ast(from, to);
+        let optional = self.optional(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.object_pat(span, props, optional, type_ann)
+    }
+}
+impl CloneInto for AssignPat {
+    type Cloned = AssignPat;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let left = self.left(from).clone_into_ast(from, to);
+        let right = self.right(from).clone_into_ast(from, to);
+        to.assign_pat(span, left, right)
+    }
+}
+impl CloneInto for RestPat {
+    type Cloned = RestPat;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let dot_3_token = self.dot_3_token(from).clone_into_ast(from, to);
+        let arg = self.arg(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.rest_pat(span, dot_3_token, arg, type_ann)
+    }
+}
+impl CloneInto for ObjectPatProp {
+    type Cloned = ObjectPatProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::KeyValue(it) => Self::KeyValue(it.clone_into_ast(from, to)),
+            Self::Assign(it) => Self::Assign(it.clone_into_ast(from, to)),
+            Self::Rest(it) => Self::Rest(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for KeyValuePatProp {
+    type Cloned = KeyValuePatProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let value = self.value(from).clone_into_ast(from, to);
+        to.key_value_pat_prop(span, key, value)
+    }
+}
+impl CloneInto for AssignPatProp {
+    type Cloned = AssignPatProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let value = self.value(from).clone_into_ast(from, to);
+        to.assign_pat_prop(span, key, value)
+    }
+}
+impl CloneInto for Ident {
+    type Cloned = Ident;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let sym = self.sym(from).clone_into_ast(from, to);
+        let optional = self.optional(from).clone_into_ast(from, to);
+        to.ident(span, sym, optional)
+    }
+}
+impl CloneInto for IdentName {
+    type Cloned = IdentName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let sym = self.sym(from).clone_into_ast(from, to);
+        to.ident_name(span, sym)
+    }
+}
+impl CloneInto for PrivateName {
+    type Cloned = PrivateName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let name = self.name(from).clone_into_ast(from, to);
+        to.private_name(span, name)
+    }
+}
+impl CloneInto for BindingIdent {
+    type Cloned = BindingIdent;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let id = self.id(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.binding_ident(span, id, type_ann)
+    }
+}
+impl CloneInto for Lit {
+    type Cloned = Lit;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Str(it) => Self::Str(it.clone_into_ast(from, to)),
+            Self::Bool(it) => Self::Bool(it.clone_into_ast(from, to)),
+            Self::Null(it) => Self::Null(it.clone_into_ast(from, to)),
+            Self::Num(it) => Self::Num(it.clone_into_ast(from, to)),
+            Self::BigInt(it) => Self::BigInt(it.clone_into_ast(from, to)),
+            Self::Regex(it) => Self::Regex(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for Str {
+    type Cloned = Str;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let value = self.value(from).clone_into_ast(from, to);
+        let raw = self.raw(from).clone_into_ast(from, to);
+        to.str(span, value, raw)
+    }
+}
+impl CloneInto for Bool {
+    type Cloned = Bool;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let value = self.value(from).clone_into_ast(from, to);
+        to.bool(span, value)
+    }
+}
+impl CloneInto for Null {
+    type Cloned = Null;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.null(span)
+    }
+}
+impl CloneInto for Number {
+    type Cloned = Number;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let value = self.value(from).clone_into_ast(from, to);
+        let raw = self.raw(from).clone_into_ast(from, to);
+        to.number(span, value, raw)
+    }
+}
+impl CloneInto for BigInt {
+    type Cloned = BigInt;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let value = self.value(from).clone_into_ast(from, to);
+        let raw = self.raw(from).clone_into_ast(from, to);
+        to.big_int(span, value, raw)
+    }
+}
+impl CloneInto for Regex {
+    type Cloned = Regex;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let exp = self.exp(from).clone_into_ast(from, to);
+        let flags = self.flags(from).clone_into_ast(from, to);
+        to.regex(span, exp, flags)
+    }
+}
+impl CloneInto for JSXObject {
+    type Cloned = JSXObject;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::JSXMemberExpr(it) => Self::JSXMemberExpr(it.clone_into_ast(from, to)),
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for JSXMemberExpr {
+    type Cloned = JSXMemberExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let obj = self.obj(from).clone_into_ast(from, to);
+        let prop = self.prop(from).clone_into_ast(from, to);
+        to.jsx_member_expr(span, obj, prop)
+    }
+}
+impl CloneInto for JSXNamespacedName {
+    type Cloned = JSXNamespacedName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let ns = self.ns(from).clone_into_ast(from, to);
+        let name = self.name(from).clone_into_ast(from, to);
+        to.jsx_namespaced_name(span, ns, name)
+    }
+}
+impl CloneInto for JSXEmptyExpr {
+    type Cloned = JSXEmptyExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.jsx_empty_expr(span)
+    }
+}
+impl CloneInto for JSXExprContainer {
+    type Cloned = JSXExprContainer;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.jsx_expr_container(span, expr)
+    }
+}
+impl CloneInto for JSXExpr {
+    type Cloned = JSXExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::JSXEmptyExpr(it) => Self::JSXEmptyExpr(it.clone_into_ast(from, to)),
+            Self::Expr(it) => Self::Expr(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for JSXSpreadChild {
+    type Cloned = JSXSpreadChild;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.jsx_spread_child(span, expr)
+    }
+}
+impl CloneInto for JSXElementName {
+    type Cloned = JSXElementName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::JSXMemberExpr(it) => Self::JSXMemberExpr(it.clone_into_ast(from, to)),
+            Self::JSXNamespacedName(it) => Self::JSXNamespacedName(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for JSXOpeningElement {
+    type Cloned = JSXOpeningElement;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let name = self.name(from).clone_into_ast(from, to);
+        let attrs = self.attrs(from).clone_into_ast(from, to);
+        let self_closing = self.self_closing(from).clone_into_ast(from, to);
+        let type_args = self.type_args(from).clone_into_ast(from, to);
+        to.jsx_opening_element(span, name, attrs, self_closing, type_args)
+    }
+}
+impl CloneInto for JSXAttrOrSpread {
+    type Cloned = JSXAttrOrSpread;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::JSXAttr(it) => Self::JSXAttr(it.clone_into_ast(from, to)),
+            Self::SpreadElement(it) => Self::SpreadElement(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for JSXClosingElement {
+    type Cloned = JSXClosingElement;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let name = self.name(from).clone_into_ast(from, to);
+        to.jsx_closing_element(span, name)
+    }
+}
+impl CloneInto for JSXAttr {
+    type Cloned = JSXAttr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let name = self.name(from).clone_into_ast(from, to);
+        let value = self.value(from).clone_into_ast(from, to);
+        to.jsx_attr(span, name, value)
+    }
+}
+impl CloneInto for JSXAttrName {
+    type Cloned = JSXAttrName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::JSXNamespacedName(it) => Self::JSXNamespacedName(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for JSXAttrValue {
+    type Cloned = JSXAttrValue;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Str(it) => Self::Str(it.clone_into_ast(from, to)),
+            Self::JSXExprContainer(it) => Self::JSXExprContainer(it.clone_into_ast(from, to)),
+            Self::JSXElement(it) => Self::JSXElement(it.clone_into_ast(from, to)),
+            Self::JSXFragment(it) => Self::JSXFragment(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for JSXText {
+    type Cloned = JSXText;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let value = self.value(from).clone_into_ast(from, to);
+        let raw = self.raw(from).clone_into_ast(from, to);
+        to.jsx_text(span, value, raw)
+    }
+}
+impl CloneInto for JSXElement {
+    type Cloned = JSXElement;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let opening = self.opening(from).clone_into_ast(from, to);
+        let children = self.children(from).clone_into_ast(from, to);
+        let closing = self.closing(from).clone_into_ast(from, to);
+        to.jsx_element(span, opening, children, closing)
+    }
+}
+impl CloneInto for JSXElementChild {
+    type Cloned = JSXElementChild;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::JSXText(it) => Self::JSXText(it.clone_into_ast(from, to)),
+            Self::JSXExprContainer(it) => Self::JSXExprContainer(it.clone_into_ast(from, to)),
+            Self::JSXSpreadChild(it) => Self::JSXSpreadChild(it.clone_into_ast(from, to)),
+            Self::JSXElement(it) => Self::JSXElement(it.clone_into_ast(from, to)),
+            Self::JSXFragment(it) => Self::JSXFragment(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for JSXFragment {
+    type Cloned = JSXFragment;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let opening = self.opening(from).clone_into_ast(from, to);
+        let children = self.children(from).clone_into_ast(from, to);
+        let closing = self.closing(from).clone_into_ast(from, to);
+        to.jsx_fragment(span, opening, children, closing)
+    }
+}
+impl CloneInto for JSXOpeningFragment {
+    type Cloned = JSXOpeningFragment;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.jsx_opening_fragment(span)
+    }
+}
+impl CloneInto for JSXClosingFragment {
+    type Cloned = JSXClosingFragment;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.jsx_closing_fragment(span)
+    }
+}
+impl CloneInto for TsTypeAnn {
+    type Cloned = TsTypeAnn;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_type_ann(span, type_ann)
+    }
+}
+impl CloneInto for TsTypeParamDecl {
+    type Cloned = TsTypeParamDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let params = self.params(from).clone_into_ast(from, to);
+        to.ts_type_param_decl(span, params)
+    }
+}
+impl CloneInto for TsTypeParam {
+    type Cloned = TsTypeParam;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let name = self.name(from).clone_into_ast(from, to);
+        let is_in = self.is_in(from).clone_into_ast(from, to);
+        let is_out = self.is_out(from).clone_into_ast(from, to);
+        let is_const = self.is_const(from).clone_into_ast(from, to);
+        let constraint = self.constraint(from).clone_into_ast(from, to);
+        let default = self.default(from).clone_into_ast(from, to);
+        to.ts_type_param(span, name, is_in, is_out, is_const, constraint, default)
+    }
+}
+impl CloneInto for TsTypeParamInstantiation {
+    type Cloned = TsTypeParamInstantiation;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let params = self.params(from).clone_into_ast(from, to);
+        to.ts_type_param_instantiation(span, params)
+    }
+}
+impl CloneInto for TsParamProp {
+    type Cloned = TsParamProp;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let decorators = self.decorators(from).clone_into_ast(from, to);
+        let accessibility = self.accessibility(from).clone_into_ast(from, to);
+        let is_override = self.is_override(from).clone_into_ast(from, to);
+        let readonly = self.readonly(from).clone_into_ast(from, to);
+        let param = self.param(from).clone_into_ast(from, to);
+        to.ts_param_prop(
+            span,
+            decorators,
+            accessibility,
+            is_override,
+            readonly,
+            param,
+        )
+    }
+}
+impl CloneInto for TsParamPropParam {
+    type Cloned = TsParamPropParam;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::Assign(it) => Self::Assign(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsQualifiedName {
+    type Cloned = TsQualifiedName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let left = self.left(from).clone_into_ast(from, to);
+        let right = self.right(from).clone_into_ast(from, to);
+        to.ts_qualified_name(span, left, right)
+    }
+}
+impl CloneInto for TsEntityName {
+    type Cloned = TsEntityName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::TsQualifiedName(it) => Self::TsQualifiedName(it.clone_into_ast(from, to)),
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsTypeElement {
+    type Cloned = TsTypeElement;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::TsCallSignatureDecl(it) => Self::TsCallSignatureDecl(it.clone_into_ast(from, to)),
+            Self::TsConstructSignatureDecl(it) => {
+                Self::TsConstructSignatureDecl(it.clone_into_ast(from, to))
+            }
+            Self::TsPropertySignature(it) => Self::TsPropertySignature(it.clone_into_ast(from, to)),
+            Self::TsGetterSignature(it) => Self::TsGetterSignature(it.clone_into_ast(from, to)),
+            Self::TsSetterSignature(it) => Self::TsSetterSignature(it.clone_into_ast(from, to)),
+            Self::TsMethodSignature(it) => Self::TsMethodSignature(it.clone_into_ast(from, to)),
+            Self::TsIndexSignature(it) => Self::TsIndexSignature(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsCallSignatureDecl {
+    type Cloned = TsCallSignatureDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let params = self.params(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        to.ts_call_signature_decl(span, params, type_ann, type_params)
+    }
+}
+impl CloneInto for TsConstructSignatureDecl {
+    type Cloned = TsConstructSignatureDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let params = self.params(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        to.ts_construct_signature_decl(span, params, type_ann, type_params)
+    }
+}
+impl CloneInto for TsPropertySignature {
+    type Cloned = TsPropertySignature;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let readonly = self.readonly(from).clone_into_ast(from, to);
+        let key = self.key(from).clone_into_ast(from, to);
+        let computed = self.computed(from).clone_into_ast(from, to);
+        let optional = self.optional(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_property_signature(span, readonly, key, computed, optional, type_ann)
+    }
+}
+impl CloneInto for TsGetterSignature {
+    type Cloned = TsGetterSignature;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let computed = self.computed(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_getter_signature(span, key, computed, type_ann)
+    }
+}
+impl CloneInto for TsSetterSignature {
+    type Cloned = TsSetterSignature;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let computed = self.computed(from).clone_into_ast(from, to);
+        let param = self.param(from).clone_into_ast(from, to);
+        to.ts_setter_signature(span, key, computed, param)
+    }
+}
+impl CloneInto for TsMethodSignature {
+    type Cloned = TsMethodSignature;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let key = self.key(from).clone_into_ast(from, to);
+        let computed = self.computed(from).clone_into_ast(from, to);
+        let optional = self.optional(from).clone_into_ast(from, to);
+        let params = self.params(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        to.ts_method_signature(span, key, computed, optional, params, type_ann, type_params)
+    }
+}
+impl CloneInto for TsIndexSignature {
+    type Cloned = TsIndexSignature;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let params = self.params(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        let readonly = self.readonly(from).clone_into_ast(from, to);
+        let is_static = self.is_static(from).clone_into_ast(from, to);
+        to.ts_index_signature(span, params, type_ann, readonly, is_static)
+    }
+}
+impl CloneInto for TsType {
+    type Cloned = TsType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::TsKeywordType(it) => Self::TsKeywordType(it.clone_into_ast(from, to)),
+            Self::TsThisType(it) => Self::TsThisType(it.clone_into_ast(from, to)),
+            Self::TsFnOrConstructorType(it) => {
+                Self::TsFnOrConstructorType(it.clone_into_ast(from, to))
+            }
+            Self::TsTypeRef(it) => Self::TsTypeRef(it.clone_into_ast(from, to)),
+            Self::TsTypeQuery(it) => Self::TsTypeQuery(it.clone_into_ast(from, to)),
+            Self::TsTypeLit(it) => Self::TsTypeLit(it.clone_into_ast(from, to)),
+            Self::TsArrayType(it) => Self::TsArrayType(it.clone_into_ast(from, to)),
+            Self::TsTupleType(it) => Self::TsTupleType(it.clone_into_ast(from, to)),
+            Self::TsOptionalType(it) => Self::TsOptionalType(it.clone_into_ast(from, to)),
+            Self::TsRestType(it) => Self::TsRestType(it.clone_into_ast(from, to)),
+            Self::TsUnionOrIntersectionType(it) => {
+                Self::TsUnionOrIntersectionType(it.clone_into_ast(from, to))
+            }
+            Self::TsConditionalType(it) => Self::TsConditionalType(it.clone_into_ast(from, to)),
+            Self::TsInferType(it) => Self::TsInferType(it.clone_into_ast(from, to)),
+            Self::TsParenthesizedType(it) => Self::TsParenthesizedType(it.clone_into_ast(from, to)),
+            Self::TsTypeOperator(it) => Self::TsTypeOperator(it.clone_into_ast(from, to)),
+            Self::TsIndexedAccessType(it) => Self::TsIndexedAccessType(it.clone_into_ast(from, to)),
+            Self::TsMappedType(it) => Self::TsMappedType(it.clone_into_ast(from, to)),
+            Self::TsLitType(it) => Self::TsLitType(it.clone_into_ast(from, to)),
+            Self::TsTypePredicate(it) => Self::TsTypePredicate(it.clone_into_ast(from, to)),
+            Self::TsImportType(it) => Self::TsImportType(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsFnOrConstructorType {
+    type Cloned = TsFnOrConstructorType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::TsFnType(it) => Self::TsFnType(it.clone_into_ast(from, to)),
+            Self::TsConstructorType(it) => Self::TsConstructorType(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsKeywordType {
+    type Cloned = TsKeywordType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let kind = self.kind(from).clone_into_ast(from, to);
+        to.ts_keyword_type(span, kind)
+    }
+}
+impl CloneInto for TsThisType {
+    type Cloned = TsThisType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        to.ts_this_type(span)
+    }
+}
+impl CloneInto for TsFnParam {
+    type Cloned = TsFnParam;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::Array(it) => Self::Array(it.clone_into_ast(from, to)),
+            Self::Rest(it) => Self::Rest(it.clone_into_ast(from, to)),
+            Self::Object(it) => Self::Object(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsFnType {
+    type Cloned = TsFnType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let params = self.params(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_fn_type(span, params, type_params, type_ann)
+    }
+}
+impl CloneInto for TsConstructorType {
+    type Cloned = TsConstructorType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let params = self.params(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        let is_abstract = self.is_abstract(from).clone_into_ast(from, to);
+        to.ts_constructor_type(span, params, type_params, type_ann, is_abstract)
+    }
+}
+impl CloneInto for TsTypeRef {
+    type Cloned = TsTypeRef;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let type_name = self.type_name(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        to.ts_type_ref(span, type_name, type_params)
+    }
+}
+impl CloneInto for TsTypePredicate {
+    type Cloned = TsTypePredicate;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let asserts = self.asserts(from).clone_into_ast(from, to);
+        let param_name = self.param_name(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_type_predicate(span, asserts, param_name, type_ann)
+    }
+}
+impl CloneInto for TsThisTypeOrIdent {
+    type Cloned = TsThisTypeOrIdent;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::TsThisType(it) => Self::TsThisType(it.clone_into_ast(from, to)),
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsTypeQuery {
+    type Cloned = TsTypeQuery;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr_name = self.expr_name(from).clone_into_ast(from, to);
+        let type_args = self.type_args(from).clone_into_ast(from, to);
+        to.ts_type_query(span, expr_name, type_args)
+    }
+}
+impl CloneInto for TsTypeQueryExpr {
+    type Cloned = TsTypeQueryExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::TsEntityName(it) => Self::TsEntityName(it.clone_into_ast(from, to)),
+            Self::Import(it) => Self::Import(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsImportCallOptions {
+    type Cloned = TsImportCallOptions;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let with = self.with(from).clone_into_ast(from, to);
+        to.ts_import_call_options(span, with)
+    }
+}
+impl CloneInto for TsImportType {
+    type Cloned = TsImportType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let arg = self.arg(from).clone_into_ast(from, to);
+        let qualifier = self.qualifier(from).clone_into_ast(from, to);
+        let type_args = self.type_args(from).clone_into_ast(from, to);
+        let attributes = self.attributes(from).clone_into_ast(from, to);
+        to.ts_import_type(span, arg, qualifier, type_args, attributes)
+    }
+}
+impl CloneInto for TsTypeLit {
+    type Cloned = TsTypeLit;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let members = self.members(from).clone_into_ast(from, to);
+        to.ts_type_lit(span, members)
+    }
+}
+impl CloneInto for TsArrayType {
+    type Cloned = TsArrayType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let elem_type = self.elem_type(from).clone_into_ast(from, to);
+        to.ts_array_type(span, elem_type)
+    }
+}
+impl CloneInto for TsTupleType {
+    type Cloned = TsTupleType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let elem_types = self.elem_types(from).clone_into_ast(from, to);
+        to.ts_tuple_type(span, elem_types)
+    }
+}
+impl CloneInto for TsTupleElement {
+    type Cloned = TsTupleElement;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let label = self.label(from).clone_into_ast(from, to);
+        let ty = self.ty(from).clone_into_ast(from, to);
+        to.ts_tuple_element(span, label, ty)
+    }
+}
+impl CloneInto for TsOptionalType {
+    type Cloned = TsOptionalType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_optional_type(span, type_ann)
+    }
+}
+impl CloneInto for TsRestType {
+    type Cloned = TsRestType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_rest_type(span, type_ann)
+    }
+}
+impl CloneInto for TsUnionOrIntersectionType {
+    type Cloned = TsUnionOrIntersectionType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::TsUnionType(it) => Self::TsUnionType(it.clone_into_ast(from, to)),
+            Self::TsIntersectionType(it) => Self::TsIntersectionType(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsUnionType {
+    type Cloned = TsUnionType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let types = self.types(from).clone_into_ast(from, to);
+        to.ts_union_type(span, types)
+    }
+}
+impl CloneInto for TsIntersectionType {
+    type Cloned = TsIntersectionType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let types = self.types(from).clone_into_ast(from, to);
+        to.ts_intersection_type(span, types)
+    }
+}
+impl CloneInto for TsConditionalType {
+    type Cloned = TsConditionalType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let check_type = self.check_type(from).clone_into_ast(from, to);
+        let extends_type = self.extends_type(from).clone_into_ast(from, to);
+        let true_type = self.true_type(from).clone_into_ast(from, to);
+        let false_type = self.false_type(from).clone_into_ast(from, to);
+        to.ts_conditional_type(span, check_type, extends_type, true_type, false_type)
+    }
+}
+impl CloneInto for TsInferType {
+    type Cloned = TsInferType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let type_param = self.type_param(from).clone_into_ast(from, to);
+        to.ts_infer_type(span, type_param)
+    }
+}
+impl CloneInto for TsParenthesizedType {
+    type Cloned = TsParenthesizedType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_parenthesized_type(span, type_ann)
+    }
+}
+impl CloneInto for TsTypeOperator {
+    type Cloned = TsTypeOperator;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let op = self.op(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_type_operator(span, op, type_ann)
+    }
+}
+impl CloneInto for TsIndexedAccessType {
+    type Cloned = TsIndexedAccessType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let readonly = self.readonly(from).clone_into_ast(from, to);
+        let obj_type = self.obj_type(from).clone_into_ast(from, to);
+        let index_type = self.index_type(from).clone_into_ast(from, to);
+        to.ts_indexed_access_type(span, readonly, obj_type, index_type)
+    }
+}
+impl CloneInto for TsMappedType {
+    type Cloned = TsMappedType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let readonly = self.readonly(from).clone_into_ast(from, to);
+        let type_param = self.type_param(from).clone_into_ast(from, to);
+        let name_type = self.name_type(from).clone_into_ast(from, to);
+        let optional = self.optional(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_mapped_type(span, readonly, type_param, name_type, optional, type_ann)
+    }
+}
+impl CloneInto for TsLitType {
+    type Cloned = TsLitType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let lit = self.lit(from).clone_into_ast(from, to);
+        to.ts_lit_type(span, lit)
+    }
+}
+impl CloneInto for TsLit {
+    type Cloned = TsLit;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Number(it) => Self::Number(it.clone_into_ast(from, to)),
+            Self::Str(it) => Self::Str(it.clone_into_ast(from, to)),
+            Self::Bool(it) => Self::Bool(it.clone_into_ast(from, to)),
+            Self::BigInt(it) => Self::BigInt(it.clone_into_ast(from, to)),
+            Self::Tpl(it) => Self::Tpl(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsTplLitType {
+    type Cloned = TsTplLitType;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let types = self.types(from).clone_into_ast(from, to);
+        let quasis = self.quasis(from).clone_into_ast(from, to);
+        to.ts_tpl_lit_type(span, types, quasis)
+    }
+}
+impl CloneInto for TsInterfaceDecl {
+    type Cloned = TsInterfaceDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let id = self.id(from).clone_into_ast(from, to);
+        let declare = self.declare(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        let extends = self.extends(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.ts_interface_decl(span, id, declare, type_params, extends, body)
+    }
+}
+impl CloneInto for TsInterfaceBody {
+    type Cloned = TsInterfaceBody;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.ts_interface_body(span, body)
+    }
+}
+impl CloneInto for TsExprWithTypeArgs {
+    type Cloned = TsExprWithTypeArgs;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        let type_args = self.type_args(from).clone_into_ast(from, to);
+        to.ts_expr_with_type_args(span, expr, type_args)
+    }
+}
+impl CloneInto for TsTypeAliasDecl {
+    type Cloned = TsTypeAliasDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let declare = self.declare(from).clone_into_ast(from, to);
+        let id = self.id(from).clone_into_ast(from, to);
+        let type_params = self.type_params(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_type_alias_decl(span, declare, id, type_params, type_ann)
+    }
+}
+impl CloneInto for TsEnumDecl {
+    type Cloned = TsEnumDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let declare = self.declare(from).clone_into_ast(from, to);
+        let is_const = self.is_const(from).clone_into_ast(from, to);
+        let id = self.id(from).clone_into_ast(from, to);
+        let members = self.members(from).clone_into_ast(from, to);
+        to.ts_enum_decl(span, declare, is_const, id, members)
+    }
+}
+impl CloneInto for TsEnumMember {
+    type Cloned = TsEnumMember;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let id = self.id(from).clone_into_ast(from, to);
+        let init = self.init(from).clone_into_ast(from, to);
+        to.ts_enum_member(span, id, init)
+    }
+}
+impl CloneInto for TsEnumMemberId {
+    type Cloned = TsEnumMemberId;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::Str(it) => Self::Str(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsModuleDecl {
+    type Cloned = TsModuleDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let declare = self.declare(from).clone_into_ast(from, to);
+        let global = self.global(from).clone_into_ast(from, to);
+        let namespace = self.namespace(from).clone_into_ast(from, to);
+        let id = self.id(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.ts_module_decl(span, declare, global, namespace, id, body)
+    }
+}
+impl CloneInto for TsNamespaceBody {
+    type Cloned = TsNamespaceBody;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::TsModuleBlock(it) => Self::TsModuleBlock(it.clone_into_ast(from, to)),
+            Self::TsNamespaceDecl(it) => Self::TsNamespaceDecl(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsModuleBlock {
+    type Cloned = TsModuleBlock;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.ts_module_block(span, body)
+    }
+}
+impl CloneInto for TsNamespaceDecl {
+    type Cloned = TsNamespaceDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let declare = self.declare(from).clone_into_ast(from, to);
+        let global = self.global(from).clone_into_ast(from, to);
+        let id = self.id(from).clone_into_ast(from, to);
+        let body = self.body(from).clone_into_ast(from, to);
+        to.ts_namespace_decl(span, declare, global, id, body)
+    }
+}
+impl CloneInto for TsModuleName {
+    type Cloned = TsModuleName;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::Ident(it) => Self::Ident(it.clone_into_ast(from, to)),
+            Self::Str(it) => Self::Str(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsImportEqualsDecl {
+    type Cloned = TsImportEqualsDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let is_export = self.is_export(from).clone_into_ast(from, to);
+        let is_type_only = self.is_type_only(from).clone_into_ast(from, to);
+        let id = self.id(from).clone_into_ast(from, to);
+        let module_ref = self.module_ref(from).clone_into_ast(from, to);
+        to.ts_import_equals_decl(span, is_export, is_type_only, id, module_ref)
+    }
+}
+impl CloneInto for TsModuleRef {
+    type Cloned = TsModuleRef;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        match self {
+            Self::TsEntityName(it) => Self::TsEntityName(it.clone_into_ast(from, to)),
+            Self::TsExternalModuleRef(it) => Self::TsExternalModuleRef(it.clone_into_ast(from, to)),
+        }
+    }
+}
+impl CloneInto for TsExternalModuleRef {
+    type Cloned = TsExternalModuleRef;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.ts_external_module_ref(span, expr)
+    }
+}
+impl CloneInto for TsExportAssignment {
+    type Cloned = TsExportAssignment;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.ts_export_assignment(span, expr)
+    }
+}
+impl CloneInto for TsNamespaceExportDecl {
+    type Cloned = TsNamespaceExportDecl;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let id = self.id(from).clone_into_ast(from, to);
+        to.ts_namespace_export_decl(span, id)
+    }
+}
+impl CloneInto for TsAsExpr {
+    type Cloned = TsAsExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_as_expr(span, expr, type_ann)
+    }
+}
+impl CloneInto for TsTypeAssertion {
+    type Cloned = TsTypeAssertion;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_type_assertion(span, expr, type_ann)
+    }
+}
+impl CloneInto for TsNonNullExpr {
+    type Cloned = TsNonNullExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.ts_non_null_expr(span, expr)
+    }
+}
+impl CloneInto for TsSatisfiesExpr {
+    type Cloned = TsSatisfiesExpr;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        let type_ann = self.type_ann(from).clone_into_ast(from, to);
+        to.ts_satisfies_expr(span, expr, type_ann)
+    }
+}
+impl CloneInto for TsConstAssertion {
+    type Cloned = TsConstAssertion;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        to.ts_const_assertion(span, expr)
+    }
+}
+impl CloneInto for TsInstantiation {
+    type Cloned = TsInstantiation;
+    #[inline]
+    fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+        let span = self.span(from);
+        let expr = self.expr(from).clone_into_ast(from, to);
+        let type_args = self.type_args(from).clone_into_ast(from, to);
+        to.ts_instantiation(span, expr, type_args)
+    }
+}
@@ -0,0 +1,2204 @@
+#![allow(unused)]
+use crate::{Ast, Dump, Dumper, GetSpan};
+use crate::{ast::*, node_id::*};
+impl Dump for Program {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Module(it) => it.dump(d),
+            Self::Script(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for Module {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Module", self.span(ast), |d| {
+            d.field("body", &self.body(ast));
+            d.field("shebang", &self.shebang(ast));
+        });
+    }
+}
+impl Dump for Script {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Script", self.span(ast), |d| {
+            d.field("body", &self.body(ast));
+            d.field("shebang", &self.shebang(ast));
+        });
+    }
+}
+impl Dump for ModuleItem {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::ModuleDecl(it) => it.dump(d),
+            Self::Stmt(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ModuleDecl {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Import(it) => it.dump(d),
+            Self::ExportDecl(it) => it.dump(d),
+            Self::ExportNamed(it) => it.dump(d),
+            Self::ExportDefaultDecl(it) => it.dump(d),
+            Self::ExportDefaultExpr(it) => it.dump(d),
+            Self::ExportAll(it) => it.dump(d),
+            Self::TsImportEquals(it) => it.dump(d),
+            Self::TsExportAssignment(it) => it.dump(d),
+            Self::TsNamespaceExport(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ImportDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ImportDecl", self.span(ast), |d| {
+            d.field("specifiers", &self.specifiers(ast));
+            d.field("src", &self.src(ast));
+            d.field("type_only", &self.type_only(ast));
+            d.field("with", &self.with(ast));
+            d.field("phase", &self.phase(ast));
+        });
+    }
+}
+impl Dump for ImportSpecifier {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Named(it) => it.dump(d),
+            Self::Default(it) => it.dump(d),
+            Self::Namespace(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ImportNamedSpecifier {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ImportNamedSpecifier", self.span(ast), |d| {
+            d.field("local", &self.local(ast));
+            d.field("imported", &self.imported(ast));
+            d.field("is_type_only", &self.is_type_only(ast));
+        });
+    }
+}
+impl Dump for ImportDefaultSpecifier {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ImportDefaultSpecifier", self.span(ast), |d| {
+            d.field("local", &self.local(ast));
+        });
+    }
+}
+impl Dump for ImportStarAsSpecifier {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ImportStarAsSpecifier", self.span(ast), |d| {
+            d.field("local", &self.local(ast));
+        });
+    }
+}
+impl Dump for ExportDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ExportDecl", self.span(ast), |d| {
+            d.field("decl", &self.decl(ast));
+        });
+    }
+}
+impl Dump for NamedExport {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("NamedExport", self.span(ast), |d| {
+            d.field("specifiers", &self.specifiers(ast));
+            d.field("src", &self.src(ast));
+            d.field("type_only", &self.type_only(ast));
+            d.field("with", &self.with(ast));
+        });
+    }
+}
+impl Dump for ExportSpecifier {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Namespace(it) => it.dump(d),
+            Self::Default(it) => it.dump(d),
+            Self::Named(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ExportNamespaceSpecifier {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ExportNamespaceSpecifier", self.span(ast), |d| {
+            d.field("name", &self.name(ast));
+        });
+    }
+}
+impl Dump for ModuleExportName {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::Str(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ExportDefaultSpecifier {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ExportDefaultSpecifier", self.span(ast), |d| {
+            d.field("exported", &self.exported(ast));
+        });
+    }
+}
+impl Dump for ExportNamedSpecifier {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ExportNamedSpecifier", self.span(ast), |d| {
+            d.field("orig", &self.orig(ast));
+            d.field("exported", &self.exported(ast));
+            d.field("is_type_only", &self.is_type_only(ast));
+        });
+    }
+}
+impl Dump for ExportDefaultDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ExportDefaultDecl", self.span(ast), |d| {
+            d.field("decl", &self.decl(ast));
+        });
+    }
+}
+impl Dump for DefaultDecl {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Class(it) => it.dump(d),
+            Self::Fn(it) => it.dump(d),
+            Self::TsInterfaceDecl(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ExportDefaultExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ExportDefaultExpr", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for ExportAll {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ExportAll", self.span(ast), |d| {
+            d.field("src", &self.src(ast));
+            d.field("type_only", &self.type_only(ast));
+            d.field("with", &self.with(ast));
+        });
+    }
+}
+impl Dump for BlockStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("BlockStmt", self.span(ast), |d| {
+            d.field("stmts", &self.stmts(ast));
+        });
+    }
+}
+impl Dump for Stmt {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Block(it) => it.dump(d),
+            Self::Empty(it) => it.dump(d),
+            Self::Debugger(it) => it.dump(d),
+            Self::With(it) => it.dump(d),
+            Self::Return(it) => it.dump(d),
+            Self::Labeled(it) => it.dump(d),
+            Self::Break(it) => it.dump(d),
+            Self::Continue(it) => it.dump(d),
+            Self::If(it) => it.dump(d),
+            Self::Switch(it) => it.dump(d),
+            Self::Throw(it) => it.dump(d),
+            Self::Try(it) => it.dump(d),
+            Self::While(it) => it.dump(d),
+            Self::DoWhile(it) => it.dump(d),
+            Self::For(it) => it.dump(d),
+            Self::ForIn(it) => it.dump(d),
+            Self::ForOf(it) => it.dump(d),
+            Self::Decl(it) => it.dump(d),
+            Self::Expr(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ExprStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ExprStmt", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for EmptyStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("EmptyStmt", self.span(ast), |d| {});
+    }
+}
+impl Dump for DebuggerStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("DebuggerStmt", self.span(ast), |d| {});
+    }
+}
+impl Dump for WithStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("WithStmt", self.span(ast), |d| {
+            d.field("obj", &self.obj(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for ReturnStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ReturnStmt", self.span(ast), |d| {
+            d.field("arg", &self.arg(ast));
+        });
+    }
+}
+impl Dump for LabeledStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("LabeledStmt", self.span(ast), |d| {
+            d.field("label", &self.label(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for BreakStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("BreakStmt", self.span(ast), |d| {
+            d.field("label", &self.label(ast));
+        });
+    }
+}
+impl Dump for ContinueStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ContinueStmt", self.span(ast), |d| {
+            d.field("label", &self.label(ast));
+        });
+    }
+}
+impl Dump for IfStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("IfStmt", self.span(ast), |d| {
+            d.field("test", &self.test(ast));
+            d.field("cons", &self.cons(ast));
+            d.field("alt", &self.alt(ast));
+        });
+    }
+}
+impl Dump for SwitchStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("SwitchStmt", self.span(ast), |d| {
+            d.field("discriminant", &self.discriminant(ast));
+            d.field("cases", &self.cases(ast));
+        });
+    }
+}
+impl Dump for ThrowStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ThrowStmt", self.span(ast), |d| {
+            d.field("arg", &self.arg(ast));
+        });
+    }
+}
+impl Dump for TryStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TryStmt", self.span(ast), |d| {
+            d.field("block", &self.block(ast));
+            d.field("handler", &self.handler(ast));
+            d.field("finalizer", &self.finalizer(ast));
+        });
+    }
+}
+impl Dump for WhileStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("WhileStmt", self.span(ast), |d| {
+            d.field("test", &self.test(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for DoWhileStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("DoWhileStmt", self.span(ast), |d| {
+            d.field("test", &self.test(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for ForStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ForStmt", self.span(ast), |d| {
+            d.field("init", &self.init(ast));
+            d.field("test", &self.test(ast));
+            d.field("update", &self.update(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for ForInStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ForInStmt", self.span(ast), |d| {
+            d.field("left", &self.left(ast));
+            d.field("right", &self.right(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for ForOfStmt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ForOfStmt", self.span(ast), |d| {
+            d.field("is_await", &self.is_await(ast));
+            d.field("left", &self.left(ast));
+            d.field("right", &self.right(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for SwitchCase {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("SwitchCase", self.span(ast), |d| {
+            d.field("test", &self.test(ast));
+            d.field("cons", &self.cons(ast));
+        });
+    }
+}
+impl Dump for CatchClause {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("CatchClause", self.span(ast), |d| {
+            d.field("param", &self.param(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for ForHead {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::VarDecl(it) => it.dump(d),
+            Self::UsingDecl(it) => it.dump(d),
+            Self::Pat(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for VarDeclOrExpr {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::VarDecl(it) => it.dump(d),
+            Self::Expr(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for Decl {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Class(it) => it.dump(d),
+            Self::Fn(it) => it.dump(d),
+            Self::Var(it) => it.dump(d),
+            Self::Using(it) => it.dump(d),
+            Self::TsInterface(it) => it.dump(d),
+            Self::TsTypeAlias(it) => it.dump(d),
+            Self::TsEnum(it) => it.dump(d),
+            Self::TsModule(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for FnDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("FnDecl", self.span(ast), |d| {
+            d.field("ident", &self.ident(ast));
+            d.field("declare", &self.declare(ast));
+            d.field("function", &self.function(ast));
+        });
+    }
+}
+impl Dump for ClassDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ClassDecl", self.span(ast), |d| {
+            d.field("ident", &self.ident(ast));
+            d.field("declare", &self.declare(ast));
+            d.field("class", &self.class(ast));
+        });
+    }
+}
+impl Dump for VarDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("VarDecl", self.span(ast), |d| {
+            d.field("kind", &self.kind(ast));
+            d.field("declare", &self.declare(ast));
+            d.field("decls", &self.decls(ast));
+        });
+    }
+}
+impl Dump for VarDeclarator {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("VarDeclarator", self.span(ast), |d| {
+            d.field("name", &self.name(ast));
+            d.field("init", &self.init(ast));
+            d.field("definite", &self.definite(ast));
+        });
+    }
+}
+impl Dump for UsingDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("UsingDecl", self.span(ast), |d| {
+            d.field("is_await", &self.is_await(ast));
+            d.field("decls", &self.decls(ast));
+        });
+    }
+}
+impl Dump for Expr {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::This(it) => it.dump(d),
+            Self::Array(it) => it.dump(d),
+            Self::Object(it) => it.dump(d),
+            Self::Fn(it) => it.dump(d),
+            Self::Unary(it) => it.dump(d),
+            Self::Update(it) => it.dump(d),
+            Self::Bin(it) => it.dump(d),
+            Self::Assign(it) => it.dump(d),
+            Self::Member(it) => it.dump(d),
+            Self::SuperProp(it) => it.dump(d),
+            Self::Cond(it) => it.dump(d),
+            Self::Call(it) => it.dump(d),
+            Self::New(it) => it.dump(d),
+            Self::Seq(it) => it.dump(d),
+            Self::Ident(it) => it.dump(d),
+            Self::Lit(it) => it.dump(d),
+            Self::Tpl(it) => it.dump(d),
+            Self::TaggedTpl(it) => it.dump(d),
+            Self::Arrow(it) => it.dump(d),
+            Self::Class(it) => it.dump(d),
+            Self::Yield(it) => it.dump(d),
+            Self::MetaProp(it) => it.dump(d),
+            Self::Await(it) => it.dump(d),
+            Self::Paren(it) => it.dump(d),
+            Self::JSXMember(it) => it.dump(d),
+            Self::JSXNamespacedName(it) => it.dump(d),
+            Self::JSXEmpty(it) => it.dump(d),
+            Self::JSXElement(it) => it.dump(d),
+            Self::JSXFragment(it) => it.dump(d),
+            Self::TsTypeAssertion(it) => it.dump(d),
+            Self::TsConstAssertion(it) => it.dump(d),
+            Self::TsNonNull(it) => it.dump(d),
+            Self::TsAs(it) => it.dump(d),
+            Self::TsInstantiation(it) => it.dump(d),
+            Self::TsSatisfies(it) => it.dump(d),
+            Self::PrivateName(it) => it.dump(d),
+            Self::OptChain(it) => it.dump(d),
+            Self::Invalid(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ThisExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ThisExpr", self.span(ast), |d| {});
+    }
+}
+impl Dump for ArrayLit {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ArrayLit", self.span(ast), |d| {
+            d.field("elems", &self.elems(ast));
+        });
+    }
+}
+impl Dump for ObjectLit {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ObjectLit", self.span(ast), |d| {
+            d.field("props", &self.props(ast));
+        });
+    }
+}
+impl Dump for PropOrSpread {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::SpreadElement(it) => it.dump(d),
+            Self::Prop(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for SpreadElement {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("SpreadElement", self.span(ast), |d| {
+            d.field("dot_3_token", &self.dot_3_token(ast));
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for UnaryExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("UnaryExpr", self.span(ast), |d| {
+            d.field("op", &self.op(ast));
+            d.field("arg", &self.arg(ast));
+        });
+    }
+}
+impl Dump for UpdateExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("UpdateExpr", self.span(ast), |d| {
+            d.field("op", &self.op(ast));
+            d.field("prefix", &self.prefix(ast));
+            d.field("arg", &self.arg(ast));
+        });
+    }
+}
+impl Dump for BinExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("BinExpr", self.span(ast), |d| {
+            d.field("op", &self.op(ast));
+            d.field("left", &self.left(ast));
+            d.field("right", &self.right(ast));
+        });
+    }
+}
+impl Dump for FnExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("FnExpr", self.span(ast), |d| {
+            d.field("ident", &self.ident(ast));
+            d.field("function", &self.function(ast));
+        });
+    }
+}
+impl Dump for ClassExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ClassExpr", self.span(ast), |d| {
+            d.field("ident", &self.ident(ast));
+            d.field("class", &self.class(ast));
+        });
+    }
+}
+impl Dump for AssignExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("AssignExpr", self.span(ast), |d| {
+            d.field("op", &self.op(ast));
+            d.field("left", &self.left(ast));
+            d.field("right", &self.right(ast));
+        });
+    }
+}
+impl Dump for MemberExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("MemberExpr", self.span(ast), |d| {
+            d.field("obj", &self.obj(ast));
+            d.field("prop", &self.prop(ast));
+        });
+    }
+}
+impl Dump for MemberProp {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::PrivateName(it) => it.dump(d),
+            Self::Computed(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for SuperPropExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("SuperPropExpr", self.span(ast), |d| {
+            d.field("obj", &self.obj(ast));
+            d.field("prop", &self.prop(ast));
+        });
+    }
+}
+impl Dump for SuperProp {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::Computed(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for CondExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("CondExpr", self.span(ast), |d| {
+            d.field("test", &self.test(ast));
+            d.field("cons", &self.cons(ast));
+            d.field("alt", &self.alt(ast));
+        });
+    }
+}
+impl Dump for CallExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("CallExpr", self.span(ast), |d| {
+            d.field("callee", &self.callee(ast));
+            d.field("args", &self.args(ast));
+            d.field("type_args", &self.type_args(ast));
+        });
+    }
+}
+impl Dump for NewExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("NewExpr", self.span(ast), |d| {
+            d.field("callee", &self.callee(ast));
+            d.field("args", &self.args(ast));
+            d.field("type_args", &self.type_args(ast));
+        });
+    }
+}
+impl Dump for SeqExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("SeqExpr", self.span(ast), |d| {
+            d.field("exprs", &self.exprs(ast));
+        });
+    }
+}
+impl Dump for ArrowExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ArrowExpr", self.span(ast), |d| {
+            d.field("params", &self.params(ast));
+            d.field("body", &self.body(ast));
+            d.field("is_async", &self.is_async(ast));
+            d.field("is_generator", &self.is_generator(ast));
+            d.field("type_params", &self.type_params(ast));
+            d.field("return_type", &self.return_type(ast));
+        });
+    }
+}
+impl Dump for YieldExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("YieldExpr", self.span(ast), |d| {
+            d.field("arg", &self.arg(ast));
+            d.field("delegate", &self.delegate(ast));
+        });
+    }
+}
+impl Dump for MetaPropExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("MetaPropExpr", self.span(ast), |d| {
+            d.field("kind", &self.kind(ast));
+        });
+    }
+}
+impl Dump for AwaitExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("AwaitExpr", self.span(ast), |d| {
+            d.field("arg", &self.arg(ast));
+        });
+    }
+}
+impl Dump for Tpl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Tpl", self.span(ast), |d| {
+            d.field("exprs", &self.exprs(ast));
+            d.field("quasis", &self.quasis(ast));
+        });
+    }
+}
+impl Dump for TaggedTpl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TaggedTpl", self.span(ast), |d| {
+            d.field("tag", &self.tag(ast));
+            d.field("type_params", &self.type_params(ast));
+            d.field("tpl", &self.tpl(ast));
+        });
+    }
+}
+impl Dump for TplElement {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TplElement", self.span(ast), |d| {
+            d.field("tail", &self.tail(ast));
+            d.field("cooked", &self.cooked(ast));
+            d.field("raw", &self.raw(ast));
+        });
+    }
+}
+impl Dump for ParenExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ParenExpr", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for Callee {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Super(it) => it.dump(d),
+            Self::Import(it) => it.dump(d),
+            Self::Expr(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for Super {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Super", self.span(ast), |d| {});
+    }
+}
+impl Dump for Import {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Import", self.span(ast), |d| {
+            d.field("phase", &self.phase(ast));
+        });
+    }
+}
+impl Dump for ExprOrSpread {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ExprOrSpread", self.span(ast), |d| {
+            d.field("spread", &self.spread(ast));
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for SpreadDot3Token {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("SpreadDot3Token", self.span(ast), |d| {});
+    }
+}
+impl Dump for BlockStmtOrExpr {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::BlockStmt(it) => it.dump(d),
+            Self::Expr(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for AssignTarget {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Simple(it) => it.dump(d),
+            Self::Pat(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for AssignTargetPat {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Array(it) => it.dump(d),
+            Self::Object(it) => it.dump(d),
+            Self::Invalid(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for SimpleAssignTarget {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::Member(it) => it.dump(d),
+            Self::SuperProp(it) => it.dump(d),
+            Self::Paren(it) => it.dump(d),
+            Self::OptChain(it) => it.dump(d),
+            Self::TsAs(it) => it.dump(d),
+            Self::TsSatisfies(it) => it.dump(d),
+            Self::TsNonNull(it) => it.dump(d),
+            Self::TsTypeAssertion(it) => it.dump(d),
+            Self::TsInstantiation(it) => it.dump(d),
+            Self::Invalid(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for OptChainExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("OptChainExpr", self.span(ast), |d| {
+            d.field("optional", &self.optional(ast));
+            d.field("base", &self.base(ast));
+        });
+    }
+}
+impl Dump for OptChainBase {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Member(it) => it.dump(d),
+            Self::Call(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for OptCall {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("OptCall", self.span(ast), |d| {
+            d.field("callee", &self.callee(ast));
+            d.field("args", &self.args(ast));
+            d.field("type_args", &self.type_args(ast));
+        });
+    }
+}
+impl Dump for Invalid {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Invalid", self.span(ast), |d| {});
+    }
+}
+impl Dump for Function {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Function", self.span(ast), |d| {
+            d.field("params", &self.params(ast));
+            d.field("decorators", &self.decorators(ast));
+            d.field("body", &self.body(ast));
+            d.field("is_generator", &self.is_generator(ast));
+            d.field("is_async", &self.is_async(ast));
+            d.field("type_params", &self.type_params(ast));
+            d.field("return_type", &self.return_type(ast));
+        });
+    }
+}
+impl Dump for Param {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Param", self.span(ast), |d| {
+            d.field("decorators", &self.decorators(ast));
+            d.field("pat", &self.pat(ast));
+        });
+    }
+}
+impl Dump for ParamOrTsParamProp {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::TsParamProp(it) => it.dump(d),
+            Self::Param(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for Class {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Class", self.span(ast), |d| {
+            d.field("decorators", &self.decorators(ast));
+            d.field("body", &self.body(ast));
+            d.field("super_class", &self.super_class(ast));
+            d.field("is_abstract", &self.is_abstract(ast));
+            d.field("type_params", &self.type_params(ast));
+            d.field("super_type_params", &self.super_type_params(ast));
+            d.field("implements", &self.implements(ast));
+        });
+    }
+}
+impl Dump for ClassMember {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Constructor(it) => it.dump(d),
+            Self::Method(it) => it.dump(d),
+            Self::PrivateMethod(it) => it.dump(d),
+            Self::ClassProp(it) => it.dump(d),
+            Self::PrivateProp(it) => it.dump(d),
+            Self::Empty(it) => it.dump(d),
+            Self::StaticBlock(it) => it.dump(d),
+            Self::AutoAccessor(it) => it.dump(d),
+            Self::TsIndexSignature(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ClassProp {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ClassProp", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("value", &self.value(ast));
+            d.field("type_ann", &self.type_ann(ast));
+            d.field("is_static", &self.is_static(ast));
+            d.field("decorators", &self.decorators(ast));
+            d.field("accessibility", &self.accessibility(ast));
+            d.field("is_abstract", &self.is_abstract(ast));
+            d.field("is_optional", &self.is_optional(ast));
+            d.field("is_override", &self.is_override(ast));
+            d.field("readonly", &self.readonly(ast));
+            d.field("declare", &self.declare(ast));
+            d.field("definite", &self.definite(ast));
+        });
+    }
+}
+impl Dump for PrivateProp {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("PrivateProp", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("value", &self.value(ast));
+            d.field("type_ann", &self.type_ann(ast));
+            d.field("is_static", &self.is_static(ast));
+            d.field("decorators", &self.decorators(ast));
+            d.field("accessibility", &self.accessibility(ast));
+            d.field("is_optional", &self.is_optional(ast));
+            d.field("is_override", &self.is_override(ast));
+            d.field("readonly", &self.readonly(ast));
+            d.field("definite", &self.definite(ast));
+        });
+    }
+}
+impl Dump for ClassMethod {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ClassMethod", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("function", &self.function(ast));
+            d.field("kind", &self.kind(ast));
+            d.field("is_static", &self.is_static(ast));
+            d.field("accessibility", &self.accessibility(ast));
+            d.field("is_abstract", &self.is_abstract(ast));
+            d.field("is_optional", &self.is_optional(ast));
+            d.field("is_override", &self.is_override(ast));
+        });
+    }
+}
+impl Dump for PrivateMethod {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("PrivateMethod", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("function", &self.function(ast));
+            d.field("kind", &self.kind(ast));
+            d.field("is_static", &self.is_static(ast));
+            d.field("accessibility", &self.accessibility(ast));
+            d.field("is_abstract", &self.is_abstract(ast));
+            d.field("is_optional", &self.is_optional(ast));
+            d.field("is_override", &self.is_override(ast));
+        });
+    }
+}
+impl Dump for Constructor {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Constructor", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("params", &self.params(ast));
+            d.field("body", &self.body(ast));
+            d.field("accessibility", &self.accessibility(ast));
+            d.field("is_optional", &self.is_optional(ast));
+        });
+    }
+}
+impl Dump for Decorator {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Decorator", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for StaticBlock {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("StaticBlock", self.span(ast), |d| {
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for Key {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Private(it) => it.dump(d),
+            Self::Public(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for AutoAccessor {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("AutoAccessor", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("value", &self.value(ast));
+            d.field("type_ann", &self.type_ann(ast));
+            d.field("is_static", &self.is_static(ast));
+            d.field("decorators", &self.decorators(ast));
+            d.field("accessibility", &self.accessibility(ast));
+            d.field("is_abstract", &self.is_abstract(ast));
+            d.field("is_override", &self.is_override(ast));
+            d.field("definite", &self.definite(ast));
+        });
+    }
+}
+impl Dump for Prop {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Shorthand(it) => it.dump(d),
+            Self::KeyValue(it) => it.dump(d),
+            Self::Assign(it) => it.dump(d),
+            Self::Getter(it) => it.dump(d),
+            Self::Setter(it) => it.dump(d),
+            Self::Method(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for KeyValueProp {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("KeyValueProp", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("value", &self.value(ast));
+        });
+    }
+}
+impl Dump for AssignProp {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("AssignProp", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("value", &self.value(ast));
+        });
+    }
+}
+impl Dump for GetterProp {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("GetterProp", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("type_ann", &self.type_ann(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for SetterProp {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("SetterProp", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("this_param", &self.this_param(ast));
+            d.field("param", &self.param(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for MethodProp {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("MethodProp", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("function", &self.function(ast));
+        });
+    }
+}
+impl Dump for PropName {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::Str(it) => it.dump(d),
+            Self::Num(it) => it.dump(d),
+            Self::Computed(it) => it.dump(d),
+            Self::BigInt(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ComputedPropName {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ComputedPropName", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for Pat {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::Array(it) => it.dump(d),
+            Self::Rest(it) => it.dump(d),
+            Self::Object(it) => it.dump(d),
+            Self::Assign(it) => it.dump(d),
+            Self::Invalid(it) => it.dump(d),
+            Self::Expr(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for ArrayPat {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ArrayPat", self.span(ast), |d| {
+            d.field("elems", &self.elems(ast));
+            d.field("optional", &self.optional(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for ObjectPat {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("ObjectPat", self.span(ast), |d| {
+            d.field("props", &self.props(ast));
+            d.field("optional", &self.optional(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for AssignPat {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("AssignPat", self.span(ast), |d| {
+            d.field("left", &self.left(ast));
+            d.field("right", &self.right(ast));
+        });
+    }
+}
+impl Dump for RestPat {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("RestPat", self.span(ast), |d| {
+            d.field("dot_3_token", &self.dot_3_token(ast));
+            d.field("arg", &self.arg(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for ObjectPatProp {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::KeyValue(it) => it.dump(d),
+            Self::Assign(it) => it.dump(d),
+            Self::Rest(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for KeyValuePatProp {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("KeyValuePatProp", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("value", &self.value(ast));
+        });
+    }
+}
+impl Dump for AssignPatProp {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("AssignPatProp", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("value", &self.value(ast));
+        });
+    }
+}
+impl Dump for Ident {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Ident", self.span(ast), |d| {
+            d.field("sym", &self.sym(ast));
+            d.field("optional", &self.optional(ast));
+        });
+    }
+}
+impl Dump for IdentName {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("IdentName", self.span(ast), |d| {
+            d.field("sym", &self.sym(ast));
+        });
+    }
+}
+impl Dump for PrivateName {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("PrivateName", self.span(ast), |d| {
+            d.field("name", &self.name(ast));
+        });
+    }
+}
+impl Dump for BindingIdent {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("BindingIdent", self.span(ast), |d| {
+            d.field("id", &self.id(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for Lit {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Str(it) => it.dump(d),
+            Self::Bool(it) => it.dump(d),
+            Self::Null(it) => it.dump(d),
+            Self::Num(it) => it.dump(d),
+            Self::BigInt(it) => it.dump(d),
+            Self::Regex(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for Str {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Str", self.span(ast), |d| {
+            d.field("value", &self.value(ast));
+            d.field("raw", &self.raw(ast));
+        });
+    }
+}
+impl Dump for Bool {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Bool", self.span(ast), |d| {
+            d.field("value", &self.value(ast));
+        });
+    }
+}
+impl Dump for Null {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Null", self.span(ast), |d| {});
+    }
+}
+impl Dump for Number {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Number", self.span(ast), |d| {
+            d.field("value", &self.value(ast));
+            d.field("raw", &self.raw(ast));
+        });
+    }
+}
+impl Dump for BigInt {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("BigInt", self.span(ast), |d| {
+            d.field("value", &self.value(ast));
+            d.field("raw", &self.raw(ast));
+        });
+    }
+}
+impl Dump for Regex {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("Regex", self.span(ast), |d| {
+            d.field("exp", &self.exp(ast));
+            d.field("flags", &self.flags(ast));
+        });
+    }
+}
+impl Dump for JSXObject {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::JSXMemberExpr(it) => it.dump(d),
+            Self::Ident(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for JSXMemberExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXMemberExpr", self.span(ast), |d| {
+            d.field("obj", &self.obj(ast));
+            d.field("prop", &self.prop(ast));
+        });
+    }
+}
+impl Dump for JSXNamespacedName {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXNamespacedName", self.span(ast), |d| {
+            d.field("ns", &self.ns(ast));
+            d.field("name", &self.name(ast));
+        });
+    }
+}
+impl Dump for JSXEmptyExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXEmptyExpr", self.span(ast), |d| {});
+    }
+}
+impl Dump for JSXExprContainer {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXExprContainer", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for JSXExpr {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::JSXEmptyExpr(it) => it.dump(d),
+            Self::Expr(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for JSXSpreadChild {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXSpreadChild", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for JSXElementName {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::JSXMemberExpr(it) => it.dump(d),
+            Self::JSXNamespacedName(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for JSXOpeningElement {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXOpeningElement", self.span(ast), |d| {
+            d.field("name", &self.name(ast));
+            d.field("attrs", &self.attrs(ast));
+            d.field("self_closing", &self.self_closing(ast));
+            d.field("type_args", &self.type_args(ast));
+        });
+    }
+}
+impl Dump for JSXAttrOrSpread {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::JSXAttr(it) => it.dump(d),
+            Self::SpreadElement(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for JSXClosingElement {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXClosingElement", self.span(ast), |d| {
+            d.field("name", &self.name(ast));
+        });
+    }
+}
+impl Dump for JSXAttr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXAttr", self.span(ast), |d| {
+            d.field("name", &self.name(ast));
+            d.field("value", &self.value(ast));
+        });
+    }
+}
+impl Dump for JSXAttrName {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::JSXNamespacedName(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for JSXAttrValue {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Str(it) => it.dump(d),
+            Self::JSXExprContainer(it) => it.dump(d),
+            Self::JSXElement(it) => it.dump(d),
+            Self::JSXFragment(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for JSXText {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXText", self.span(ast), |d| {
+            d.field("value", &self.value(ast));
+            d.field("raw", &self.raw(ast));
+        });
+    }
+}
+impl Dump for JSXElement {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXElement", self.span(ast), |d| {
+            d.field("opening", &self.opening(ast));
+            d.field("children", &self.children(ast));
+            d.field("closing", &self.closing(ast));
+        });
+    }
+}
+impl Dump for JSXElementChild {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::JSXText(it) => it.dump(d),
+            Self::JSXExprContainer(it) => it.dump(d),
+            Self::JSXSpreadChild(it) => it.dump(d),
+            Self::JSXElement(it) => it.dump(d),
+            Self::JSXFragment(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for JSXFragment {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXFragment", self.span(ast), |d| {
+            d.field("opening", &self.opening(ast));
+            d.field("children", &self.children(ast));
+            d.field("closing", &self.closing(ast));
+        });
+    }
+}
+impl Dump for JSXOpeningFragment {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXOpeningFragment", self.span(ast), |d| {});
+    }
+}
+impl Dump for JSXClosingFragment {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("JSXClosingFragment", self.span(ast), |d| {});
+    }
+}
+impl Dump for TsTypeAnn {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypeAnn", self.span(ast), |d| {
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsTypeParamDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypeParamDecl", self.span(ast), |d| {
+            d.field("params", &self.params(ast));
+        });
+    }
+}
+impl Dump for TsTypeParam {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypeParam", self.span(ast), |d| {
+            d.field("name", &self.name(ast));
+            d.field("is_in", &self.is_in(ast));
+            d.field("is_out", &self.is_out(ast));
+            d.field("is_const", &self.is_const(ast));
+            d.field("constraint", &self.constraint(ast));
+            d.field("default", &self.default(ast));
+        });
+    }
+}
+impl Dump for TsTypeParamInstantiation {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypeParamInstantiation", self.span(ast), |d| {
+            d.field("params", &self.params(ast));
+        });
+    }
+}
+impl Dump for TsParamProp {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsParamProp", self.span(ast), |d| {
+            d.field("decorators", &self.decorators(ast));
+            d.field("accessibility", &self.accessibility(ast));
+            d.field("is_override", &self.is_override(ast));
+            d.field("readonly", &self.readonly(ast));
+            d.field("param", &self.param(ast));
+        });
+    }
+}
+impl Dump for TsParamPropParam {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::Assign(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsQualifiedName {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsQualifiedName", self.span(ast), |d| {
+            d.field("left", &self.left(ast));
+            d.field("right", &self.right(ast));
+        });
+    }
+}
+impl Dump for TsEntityName {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::TsQualifiedName(it) => it.dump(d),
+            Self::Ident(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsTypeElement {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::TsCallSignatureDecl(it) => it.dump(d),
+            Self::TsConstructSignatureDecl(it) => it.dump(d),
+            Self::TsPropertySignature(it) => it.dump(d),
+            Self::TsGetterSignature(it) => it.dump(d),
+            Self::TsSetterSignature(it) => it.dump(d),
+            Self::TsMethodSignature(it) => it.dump(d),
+            Self::TsIndexSignature(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsCallSignatureDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsCallSignatureDecl", self.span(ast), |d| {
+            d.field("params", &self.params(ast));
+            d.field("type_ann", &self.type_ann(ast));
+            d.field("type_params", &self.type_params(ast));
+        });
+    }
+}
+impl Dump for TsConstructSignatureDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsConstructSignatureDecl", self.span(ast), |d| {
+            d.field("params", &self.params(ast));
+            d.field("type_ann", &self.type_ann(ast));
+            d.field("type_params", &self.type_params(ast));
+        });
+    }
+}
+impl Dump for TsPropertySignature {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsPropertySignature", self.span(ast), |d| {
+            d.field("readonly", &self.readonly(ast));
+            d.field("key", &self.key(ast));
+            d.field("computed", &self.computed(ast));
+            d.field("optional", &self.optional(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsGetterSignature {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsGetterSignature", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("computed", &self.computed(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsSetterSignature {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsSetterSignature", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("computed", &self.computed(ast));
+            d.field("param", &self.param(ast));
+        });
+    }
+}
+impl Dump for TsMethodSignature {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsMethodSignature", self.span(ast), |d| {
+            d.field("key", &self.key(ast));
+            d.field("computed", &self.computed(ast));
+            d.field("optional", &self.optional(ast));
+            d.field("params", &self.params(ast));
+            d.field("type_ann", &self.type_ann(ast));
+            d.field("type_params", &self.type_params(ast));
+        });
+    }
+}
+impl Dump for TsIndexSignature {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsIndexSignature", self.span(ast), |d| {
+            d.field("params", &self.params(ast));
+            d.field("type_ann", &self.type_ann(ast));
+            d.field("readonly", &self.readonly(ast));
+            d.field("is_static", &self.is_static(ast));
+        });
+    }
+}
+impl Dump for TsType {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::TsKeywordType(it) => it.dump(d),
+            Self::TsThisType(it) => it.dump(d),
+            Self::TsFnOrConstructorType(it) => it.dump(d),
+            Self::TsTypeRef(it) => it.dump(d),
+            Self::TsTypeQuery(it) => it.dump(d),
+            Self::TsTypeLit(it) => it.dump(d),
+            Self::TsArrayType(it) => it.dump(d),
+            Self::TsTupleType(it) => it.dump(d),
+            Self::TsOptionalType(it) => it.dump(d),
+            Self::TsRestType(it) => it.dump(d),
+            Self::TsUnionOrIntersectionType(it) => it.dump(d),
+            Self::TsConditionalType(it) => it.dump(d),
+            Self::TsInferType(it) => it.dump(d),
+            Self::TsParenthesizedType(it) => it.dump(d),
+            Self::TsTypeOperator(it) => it.dump(d),
+            Self::TsIndexedAccessType(it) => it.dump(d),
+            Self::TsMappedType(it) => it.dump(d),
+            Self::TsLitType(it) => it.dump(d),
+            Self::TsTypePredicate(it) => it.dump(d),
+            Self::TsImportType(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsFnOrConstructorType {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::TsFnType(it) => it.dump(d),
+            Self::TsConstructorType(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsKeywordType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsKeywordType", self.span(ast), |d| {
+            d.field("kind", &self.kind(ast));
+        });
+    }
+}
+impl Dump for TsThisType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsThisType", self.span(ast), |d| {});
+    }
+}
+impl Dump for TsFnParam {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::Array(it) => it.dump(d),
+            Self::Rest(it) => it.dump(d),
+            Self::Object(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsFnType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsFnType", self.span(ast), |d| {
+            d.field("params", &self.params(ast));
+            d.field("type_params", &self.type_params(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsConstructorType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsConstructorType", self.span(ast), |d| {
+            d.field("params", &self.params(ast));
+            d.field("type_params", &self.type_params(ast));
+            d.field("type_ann", &self.type_ann(ast));
+            d.field("is_abstract", &self.is_abstract(ast));
+        });
+    }
+}
+impl Dump for TsTypeRef {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypeRef", self.span(ast), |d| {
+            d.field("type_name", &self.type_name(ast));
+            d.field("type_params", &self.type_params(ast));
+        });
+    }
+}
+impl Dump for TsTypePredicate {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypePredicate", self.span(ast), |d| {
+            d.field("asserts", &self.asserts(ast));
+            d.field("param_name", &self.param_name(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsThisTypeOrIdent {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::TsThisType(it) => it.dump(d),
+            Self::Ident(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsTypeQuery {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypeQuery", self.span(ast), |d| {
+            d.field("expr_name", &self.expr_name(ast));
+            d.field("type_args", &self.type_args(ast));
+        });
+    }
+}
+impl Dump for TsTypeQueryExpr {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::TsEntityName(it) => it.dump(d),
+            Self::Import(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsImportCallOptions {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsImportCallOptions", self.span(ast), |d| {
+            d.field("with", &self.with(ast));
+        });
+    }
+}
+impl Dump for TsImportType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsImportType", self.span(ast), |d| {
+            d.field("arg", &self.arg(ast));
+            d.field("qualifier", &self.qualifier(ast));
+            d.field("type_args", &self.type_args(ast));
+            d.field("attributes", &self.attributes(ast));
+        });
+    }
+}
+impl Dump for TsTypeLit {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypeLit", self.span(ast), |d| {
+            d.field("members", &self.members(ast));
+        });
+    }
+}
+impl Dump for TsArrayType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsArrayType", self.span(ast), |d| {
+            d.field("elem_type", &self.elem_type(ast));
+        });
+    }
+}
+impl Dump for TsTupleType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTupleType", self.span(ast), |d| {
+            d.field("elem_types", &self.elem_types(ast));
+        });
+    }
+}
+impl Dump for TsTupleElement {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTupleElement", self.span(ast), |d| {
+            d.field("label", &self.label(ast));
+            d.field("ty", &self.ty(ast));
+        });
+    }
+}
+impl Dump for TsOptionalType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsOptionalType", self.span(ast), |d| {
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsRestType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsRestType", self.span(ast), |d| {
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsUnionOrIntersectionType {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::TsUnionType(it) => it.dump(d),
+            Self::TsIntersectionType(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsUnionType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsUnionType", self.span(ast), |d| {
+            d.field("types", &self.types(ast));
+        });
+    }
+}
+impl Dump for TsIntersectionType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsIntersectionType", self.span(ast), |d| {
+            d.field("types", &self.types(ast));
+        });
+    }
+}
+impl Dump for TsConditionalType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsConditionalType", self.span(ast), |d| {
+            d.field("check_type", &self.check_type(ast));
+            d.field("extends_type", &self.extends_type(ast));
+            d.field("true_type", &self.true_type(ast));
+            d.field("false_type", &self.false_type(ast));
+        });
+    }
+}
+impl Dump for TsInferType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsInferType", self.span(ast), |d| {
+            d.field("type_param", &self.type_param(ast));
+        });
+    }
+}
+impl Dump for TsParenthesizedType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsParenthesizedType", self.span(ast), |d| {
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsTypeOperator {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypeOperator", self.span(ast), |d| {
+            d.field("op", &self.op(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsIndexedAccessType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsIndexedAccessType", self.span(ast), |d| {
+            d.field("readonly", &self.readonly(ast));
+            d.field("obj_type", &self.obj_type(ast));
+            d.field("index_type", &self.index_type(ast));
+        });
+    }
+}
+impl Dump for TsMappedType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsMappedType", self.span(ast), |d| {
+            d.field("readonly", &self.readonly(ast));
+            d.field("type_param", &self.type_param(ast));
+            d.field("name_type", &self.name_type(ast));
+            d.field("optional", &self.optional(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsLitType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsLitType", self.span(ast), |d| {
+            d.field("lit", &self.lit(ast));
+        });
+    }
+}
+impl Dump for TsLit {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Number(it) => it.dump(d),
+            Self::Str(it) => it.dump(d),
+            Self::Bool(it) => it.dump(d),
+            Self::BigInt(it) => it.dump(d),
+            Self::Tpl(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsTplLitType {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTplLitType", self.span(ast), |d| {
+            d.field("types", &self.types(ast));
+            d.field("quasis", &self.quasis(ast));
+        });
+    }
+}
+impl Dump for TsInterfaceDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsInterfaceDecl", self.span(ast), |d| {
+            d.field("id", &self.id(ast));
+            d.field("declare", &self.declare(ast));
+            d.field("type_params", &self.type_params(ast));
+            d.field("extends", &self.extends(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for TsInterfaceBody {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsInterfaceBody", self.span(ast), |d| {
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for TsExprWithTypeArgs {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsExprWithTypeArgs", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+            d.field("type_args", &self.type_args(ast));
+        });
+    }
+}
+impl Dump for TsTypeAliasDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypeAliasDecl", self.span(ast), |d| {
+            d.field("declare", &self.declare(ast));
+            d.field("id", &self.id(ast));
+            d.field("type_params", &self.type_params(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsEnumDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsEnumDecl", self.span(ast), |d| {
+            d.field("declare", &self.declare(ast));
+            d.field("is_const", &self.is_const(ast));
+            d.field("id", &self.id(ast));
+            d.field("members", &self.members(ast));
+        });
+    }
+}
+impl Dump for TsEnumMember {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsEnumMember", self.span(ast), |d| {
+            d.field("id", &self.id(ast));
+            d.field("init", &self.init(ast));
+        });
+    }
+}
+impl Dump for TsEnumMemberId {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::Str(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsModuleDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsModuleDecl", self.span(ast), |d| {
+            d.field("declare", &self.declare(ast));
+            d.field("global", &self.global(ast));
+            d.field("namespace", &self.namespace(ast));
+            d.field("id", &self.id(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for TsNamespaceBody {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::TsModuleBlock(it) => it.dump(d),
+            Self::TsNamespaceDecl(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsModuleBlock {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsModuleBlock", self.span(ast), |d| {
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for TsNamespaceDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsNamespaceDecl", self.span(ast), |d| {
+            d.field("declare", &self.declare(ast));
+            d.field("global", &self.global(ast));
+            d.field("id", &self.id(ast));
+            d.field("body", &self.body(ast));
+        });
+    }
+}
+impl Dump for TsModuleName {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::Ident(it) => it.dump(d),
+            Self::Str(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsImportEqualsDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsImportEqualsDecl", self.span(ast), |d| {
+            d.field("is_export", &self.is_export(ast));
+            d.field("is_type_only", &self.is_type_only(ast));
+            d.field("id", &self.id(ast));
+            d.field("module_ref", &self.module_ref(ast));
+        });
+    }
+}
+impl Dump for TsModuleRef {
+    #[inline]
+    fn dump(&self, d: &mut Dumper) {
+        match self {
+            Self::TsEntityName(it) => it.dump(d),
+            Self::TsExternalModuleRef(it) => it.dump(d),
+        }
+    }
+}
+impl Dump for TsExternalModuleRef {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsExternalModuleRef", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for TsExportAssignment {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsExportAssignment", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for TsNamespaceExportDecl {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsNamespaceExportDecl", self.span(ast), |d| {
+            d.field("id", &self.id(ast));
+        });
+    }
+}
+impl Dump for TsAsExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsAsExpr", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsTypeAssertion {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsTypeAssertion", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsNonNullExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsNonNullExpr", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for TsSatisfiesExpr {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsSatisfiesExpr", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+            d.field("type_ann", &self.type_ann(ast));
+        });
+    }
+}
+impl Dump for TsConstAssertion {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsConstAssertion", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+        });
+    }
+}
+impl Dump for TsInstantiation {
+    fn dump(&self, d: &mut Dumper) {
+        let ast = d.ast();
+        d.node("TsInstantiation", self.span(ast), |d| {
+            d.field("expr", &self.expr(ast));
+            d.field("type_args", &self.type_args(ast));
+        });
+    }
+}
@@ -0,0 +1,4142 @@
+#![allow(unused)]
+use crate::{Ast, EqIgnoreSpan, HashIgnoreSpan};
+use crate::{ast::*, node_id::*};
+use std::hash::{Hash, Hasher};
+impl EqIgnoreSpan for Program {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Module(a), Self::Module(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Script(a), Self::Script(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for Program {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Module(it) => it.hash_ignore_span(ast, state),
+            Self::Script(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for Module {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.body(ast)
+            .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+            && self
+                .shebang(ast)
+                .eq_ignore_span(ast, &other.shebang(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Module {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.body(ast).hash_ignore_span(ast, state);
+        self.shebang(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Script {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.body(ast)
+            .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+            && self
+                .shebang(ast)
+                .eq_ignore_span(ast, &other.shebang(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Script {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.body(ast).hash_ignore_span(ast, state);
+        self.shebang(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ModuleItem {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::ModuleDecl(a), Self::ModuleDecl(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Stmt(a), Self::Stmt(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for ModuleItem {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::ModuleDecl(it) => it.hash_ignore_span(ast, state),
+            Self::Stmt(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ModuleDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Import(a), Self::Import(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::ExportDecl(a), Self::ExportDecl(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::ExportNamed(a), Self::ExportNamed(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::ExportDefaultDecl(a), Self::ExportDefaultDecl(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::ExportDefaultExpr(a), Self::ExportDefaultExpr(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::ExportAll(a), Self::ExportAll(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsImportEquals(a), Self::TsImportEquals(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsExportAssignment(a), Self::TsExportAssignment(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsNamespaceExport(a), Self::TsNamespaceExport(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for ModuleDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Import(it) => it.hash_ignore_span(ast, state),
+            Self::ExportDecl(it) => it.hash_ignore_span(ast, state),
+            Self::ExportNamed(it) => it.hash_ignore_span(ast, state),
+            Self::ExportDefaultDecl(it) => it.hash_ignore_span(ast, state),
+            Self::ExportDefaultExpr(it) => it.hash_ignore_span(ast, state),
+            Self::ExportAll(it) => it.hash_ignore_span(ast, state),
+            Self::TsImportEquals(it) => it.hash_ignore_span(ast, state),
+            Self::TsExportAssignment(it) => it.hash_ignore_span(ast, state),
+            Self::TsNamespaceExport(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ImportDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.specifiers(ast)
+            .eq_ignore_span(ast, &other.specifiers(other_ast), other_ast)
+            && self
+                .src(ast)
+                .eq_ignore_span(ast, &other.src(other_ast), other_ast)
+            && self
+                .type_only(ast)
+                .eq_ignore_span(ast, &other.type_only(other_ast), other_ast)
+            && self
+                .with(ast)
+                .eq_ignore_span(ast, &other.with(other_ast), other_ast)
+            && self
+                .phase(ast)
+                .eq_ignore_span(ast, &other.phase(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ImportDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.specifiers(ast).hash_ignore_span(ast, state);
+        self.src(ast).hash_ignore_span(ast, state);
+        self.type_only(ast).hash_ignore_span(ast, state);
+        self.with(ast).hash_ignore_span(ast, state);
+        self.phase(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ImportSpecifier {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Named(a), Self::Named(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Default(a), Self::Default(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Namespace(a), Self::Namespace(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for ImportSpecifier {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Named(it) => it.hash_ignore_span(ast, state),
+            Self::Default(it) => it.hash_ignore_span(ast, state),
+            Self::Namespace(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ImportNamedSpecifier {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.local(ast)
+            .eq_ignore_span(ast, &other.local(other_ast), other_ast)
+            && self
+                .imported(ast)
+                .eq_ignore_span(ast, &other.imported(other_ast), other_ast)
+            && self
+                .is_type_only(ast)
+                .eq_ignore_span(ast, &other.is_type_only(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ImportNamedSpecifier {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.local(ast).hash_ignore_span(ast, state);
+        self.imported(ast).hash_ignore_span(ast, state);
+        self.is_type_only(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ImportDefaultSpecifier {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.local(ast)
+            .eq_ignore_span(ast, &other.local(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ImportDefaultSpecifier {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.local(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ImportStarAsSpecifier {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.local(ast)
+            .eq_ignore_span(ast, &other.local(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ImportStarAsSpecifier {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.local(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ExportDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.decl(ast)
+            .eq_ignore_span(ast, &other.decl(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ExportDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.decl(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for NamedExport {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.specifiers(ast)
+            .eq_ignore_span(ast, &other.specifiers(other_ast), other_ast)
+            && self
+                .src(ast)
+                .eq_ignore_span(ast, &other.src(other_ast), other_ast)
+            && self
+                .type_only(ast)
+                .eq_ignore_span(ast, &other.type_only(other_ast), other_ast)
+            && self
+                .with(ast)
+                .eq_ignore_span(ast, &other.with(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for NamedExport {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.specifiers(ast).hash_ignore_span(ast, state);
+        self.src(ast).hash_ignore_span(ast, state);
+        self.type_only(ast).hash_ignore_span(ast, state);
+        self.with(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ExportSpecifier {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Namespace(a), Self::Namespace(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Default(a), Self::Default(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Named(a), Self::Named(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for ExportSpecifier {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Namespace(it) => it.hash_ignore_span(ast, state),
+            Self::Default(it) => it.hash_ignore_span(ast, state),
+            Self::Named(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ExportNamespaceSpecifier {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.name(ast)
+            .eq_ignore_span(ast, &other.name(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ExportNamespaceSpecifier {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.name(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ModuleExportName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Str(a), Self::Str(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for ModuleExportName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::Str(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ExportDefaultSpecifier {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.exported(ast)
+            .eq_ignore_span(ast, &other.exported(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ExportDefaultSpecifier {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.exported(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ExportNamedSpecifier {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.orig(ast)
+            .eq_ignore_span(ast, &other.orig(other_ast), other_ast)
+            && self
+                .exported(ast)
+                .eq_ignore_span(ast, &other.exported(other_ast), other_ast)
+            && self
+                .is_type_only(ast)
+                .eq_ignore_span(ast, &other.is_type_only(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ExportNamedSpecifier {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.orig(ast).hash_ignore_span(ast, state);
+        self.exported(ast).hash_ignore_span(ast, state);
+        self.is_type_only(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ExportDefaultDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.decl(ast)
+            .eq_ignore_span(ast, &other.decl(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ExportDefaultDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.decl(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for DefaultDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Class(a), Self::Class(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Fn(a), Self::Fn(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsInterfaceDecl(a), Self::TsInterfaceDecl(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for DefaultDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Class(it) => it.hash_ignore_span(ast, state),
+            Self::Fn(it) => it.hash_ignore_span(ast, state),
+            Self::TsInterfaceDecl(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ExportDefaultExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ExportDefaultExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ExportAll {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.src(ast)
+            .eq_ignore_span(ast, &other.src(other_ast), other_ast)
+            && self
+                .type_only(ast)
+                .eq_ignore_span(ast, &other.type_only(other_ast), other_ast)
+            && self
+                .with(ast)
+                .eq_ignore_span(ast, &other.with(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ExportAll {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.src(ast).hash_ignore_span(ast, state);
+        self.type_only(ast).hash_ignore_span(ast, state);
+        self.with(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for BlockStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.stmts(ast)
+            .eq_ignore_span(ast, &other.stmts(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for BlockStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.stmts(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Stmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Block(a), Self::Block(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Empty(a), Self::Empty(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Debugger(a), Self::Debugger(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::With(a), Self::With(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Return(a), Self::Return(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Labeled(a), Self::Labeled(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Break(a), Self::Break(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Continue(a), Self::Continue(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::If(a), Self::If(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Switch(a), Self::Switch(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Throw(a), Self::Throw(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Try(a), Self::Try(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::While(a), Self::While(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::DoWhile(a), Self::DoWhile(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::For(a), Self::For(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::ForIn(a), Self::ForIn(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::ForOf(a), Self::ForOf(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Decl(a), Self::Decl(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Expr(a), Self::Expr(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for Stmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Block(it) => it.hash_ignore_span(ast, state),
+            Self::Empty(it) => it.hash_ignore_span(ast, state),
+            Self::Debugger(it) => it.hash_ignore_span(ast, state),
+            Self::With(it) => it.hash_ignore_span(ast, state),
+            Self::Return(it) => it.hash_ignore_span(ast, state),
+            Self::Labeled(it) => it.hash_ignore_span(ast, state),
+            Self::Break(it) => it.hash_ignore_span(ast, state),
+            Self::Continue(it) => it.hash_ignore_span(ast, state),
+            Self::If(it) => it.hash_ignore_span(ast, state),
+            Self::Switch(it) => it.hash_ignore_span(ast, state),
+            Self::Throw(it) => it.hash_ignore_span(ast, state),
+            Self::Try(it) => it.hash_ignore_span(ast, state),
+            Self::While(it) => it.hash_ignore_span(ast, state),
+            Self::DoWhile(it) => it.hash_ignore_span(ast, state),
+            Self::For(it) => it.hash_ignore_span(ast, state),
+            Self::ForIn(it) => it.hash_ignore_span(ast, state),
+            Self::ForOf(it) => it.hash_ignore_span(ast, state),
+            Self::Decl(it) => it.hash_ignore_span(ast, state),
+            Self::Expr(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ExprStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ExprStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for EmptyStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for EmptyStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for DebuggerStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for DebuggerStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for WithStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.obj(ast)
+            .eq_ignore_span(ast, &other.obj(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for WithStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.obj(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ReturnStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.arg(ast)
+            .eq_ignore_span(ast, &other.arg(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ReturnStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.arg(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for LabeledStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.label(ast)
+            .eq_ignore_span(ast, &other.label(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for LabeledStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.label(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for BreakStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.label(ast)
+            .eq_ignore_span(ast, &other.label(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for BreakStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.label(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ContinueStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.label(ast)
+            .eq_ignore_span(ast, &other.label(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ContinueStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.label(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for IfStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.test(ast)
+            .eq_ignore_span(ast, &other.test(other_ast), other_ast)
+            && self
+                .cons(ast)
+                .eq_ignore_span(ast, &other.cons(other_ast), other_ast)
+            && self
+                .alt(ast)
+                .eq_ignore_span(ast, &other.alt(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for IfStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.test(ast).hash_ignore_span(ast, state);
+        self.cons(ast).hash_ignore_span(ast, state);
+        self.alt(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for SwitchStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.discriminant(ast)
+            .eq_ignore_span(ast, &other.discriminant(other_ast), other_ast)
+            && self
+                .cases(ast)
+                .eq_ignore_span(ast, &other.cases(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for SwitchStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.discriminant(ast).hash_ignore_span(ast, state);
+        self.cases(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ThrowStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.arg(ast)
+            .eq_ignore_span(ast, &other.arg(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ThrowStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.arg(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TryStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.block(ast)
+            .eq_ignore_span(ast, &other.block(other_ast), other_ast)
+            && self
+                .handler(ast)
+                .eq_ignore_span(ast, &other.handler(other_ast), other_ast)
+            && self
+                .finalizer(ast)
+                .eq_ignore_span(ast, &other.finalizer(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TryStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.block(ast).hash_ignore_span(ast, state);
+        self.handler(ast).hash_ignore_span(ast, state);
+        self.finalizer(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for WhileStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.test(ast)
+            .eq_ignore_span(ast, &other.test(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for WhileStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.test(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for DoWhileStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.test(ast)
+            .eq_ignore_span(ast, &other.test(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for DoWhileStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.test(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ForStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.init(ast)
+            .eq_ignore_span(ast, &other.init(other_ast), other_ast)
+            && self
+                .test(ast)
+                .eq_ignore_span(ast, &other.test(other_ast), other_ast)
+            && self
+                .update(ast)
+                .eq_ignore_span(ast, &other.update(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ForStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.init(ast).hash_ignore_span(ast, state);
+        self.test(ast).hash_ignore_span(ast, state);
+        self.update(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ForInStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.left(ast)
+            .eq_ignore_span(ast, &other.left(other_ast), other_ast)
+            && self
+                .right(ast)
+                .eq_ignore_span(ast, &other.right(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ForInStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.left(ast).hash_ignore_span(ast, state);
+        self.right(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ForOfStmt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.is_await(ast)
+            .eq_ignore_span(ast, &other.is_await(other_ast), other_ast)
+            && self
+                .left(ast)
+                .eq_ignore_span(ast, &other.left(other_ast), other_ast)
+            && self
+                .right(ast)
+                .eq_ignore_span(ast, &other.right(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ForOfStmt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.is_await(ast).hash_ignore_span(ast, state);
+        self.left(ast).hash_ignore_span(ast, state);
+        self.right(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for SwitchCase {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.test(ast)
+            .eq_ignore_span(ast, &other.test(other_ast), other_ast)
+            && self
+                .cons(ast)
+                .eq_ignore_span(ast, &other.cons(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for SwitchCase {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.test(ast).hash_ignore_span(ast, state);
+        self.cons(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for CatchClause {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.param(ast)
+            .eq_ignore_span(ast, &other.param(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for CatchClause {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.param(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ForHead {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::VarDecl(a), Self::VarDecl(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::UsingDecl(a), Self::UsingDecl(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Pat(a), Self::Pat(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for ForHead {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::VarDecl(it) => it.hash_ignore_span(ast, state),
+            Self::UsingDecl(it) => it.hash_ignore_span(ast, state),
+            Self::Pat(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for VarDeclOrExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::VarDecl(a), Self::VarDecl(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Expr(a), Self::Expr(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for VarDeclOrExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::VarDecl(it) => it.hash_ignore_span(ast, state),
+            Self::Expr(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for Decl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Class(a), Self::Class(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Fn(a), Self::Fn(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Var(a), Self::Var(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Using(a), Self::Using(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsInterface(a), Self::TsInterface(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsTypeAlias(a), Self::TsTypeAlias(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsEnum(a), Self::TsEnum(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsModule(a), Self::TsModule(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for Decl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Class(it) => it.hash_ignore_span(ast, state),
+            Self::Fn(it) => it.hash_ignore_span(ast, state),
+            Self::Var(it) => it.hash_ignore_span(ast, state),
+            Self::Using(it) => it.hash_ignore_span(ast, state),
+            Self::TsInterface(it) => it.hash_ignore_span(ast, state),
+            Self::TsTypeAlias(it) => it.hash_ignore_span(ast, state),
+            Self::TsEnum(it) => it.hash_ignore_span(ast, state),
+            Self::TsModule(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for FnDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.ident(ast)
+            .eq_ignore_span(ast, &other.ident(other_ast), other_ast)
+            && self
+                .declare(ast)
+                .eq_ignore_span(ast, &other.declare(other_ast), other_ast)
+            && self
+                .function(ast)
+                .eq_ignore_span(ast, &other.function(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for FnDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.ident(ast).hash_ignore_span(ast, state);
+        self.declare(ast).hash_ignore_span(ast, state);
+        self.function(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ClassDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.ident(ast)
+            .eq_ignore_span(ast, &other.ident(other_ast), other_ast)
+            && self
+                .declare(ast)
+                .eq_ignore_span(ast, &other.declare(other_ast), other_ast)
+            && self
+                .class(ast)
+                .eq_ignore_span(ast, &other.class(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ClassDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.ident(ast).hash_ignore_span(ast, state);
+        self.declare(ast).hash_ignore_span(ast, state);
+        self.class(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for VarDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.kind(ast)
+            .eq_ignore_span(ast, &other.kind(other_ast), other_ast)
+            && self
+                .declare(ast)
+                .eq_ignore_span(ast, &other.declare(other_ast), other_ast)
+            && self
+                .decls(ast)
+                .eq_ignore_span(ast, &other.decls(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for VarDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.kind(ast).hash_ignore_span(ast, state);
+        self.declare(ast).hash_ignore_span(ast, state);
+        self.decls(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for VarDeclarator {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.name(ast)
+            .eq_ignore_span(ast, &other.name(other_ast), other_ast)
+            && self
+                .init(ast)
+                .eq_ignore_span(ast, &other.init(other_ast), other_ast)
+            && self
+                .definite(ast)
+                .eq_ignore_span(ast, &other.definite(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for VarDeclarator {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.name(ast).hash_ignore_span(ast, state);
+        self.init(ast).hash_ignore_span(ast, state);
+        self.definite(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for UsingDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.is_await(ast)
+            .eq_ignore_span(ast, &other.is_await(other_ast), other_ast)
+            && self
+                .decls(ast)
+                .eq_ignore_span(ast, &other.decls(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for UsingDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.is_await(ast).hash_ignore_span(ast, state);
+        self.decls(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Expr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::This(a), Self::This(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Array(a), Self::Array(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Object(a), Self::Object(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Fn(a), Self::Fn(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Unary(a), Self::Unary(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Update(a), Self::Update(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Bin(a), Self::Bin(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Assign(a), Self::Assign(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Member(a), Self::Member(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::SuperProp(a), Self::SuperProp(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Cond(a), Self::Cond(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Call(a), Self::Call(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::New(a), Self::New(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Seq(a), Self::Seq(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Lit(a), Self::Lit(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Tpl(a), Self::Tpl(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TaggedTpl(a), Self::TaggedTpl(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Arrow(a), Self::Arrow(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Class(a), Self::Class(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Yield(a), Self::Yield(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::MetaProp(a), Self::MetaProp(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Await(a), Self::Await(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Paren(a), Self::Paren(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXMember(a), Self::JSXMember(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXNamespacedName(a), Self::JSXNamespacedName(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::JSXEmpty(a), Self::JSXEmpty(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXElement(a), Self::JSXElement(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsTypeAssertion(a), Self::TsTypeAssertion(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsConstAssertion(a), Self::TsConstAssertion(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsNonNull(a), Self::TsNonNull(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsAs(a), Self::TsAs(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsInstantiation(a), Self::TsInstantiation(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsSatisfies(a), Self::TsSatisfies(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::PrivateName(a), Self::PrivateName(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::OptChain(a), Self::OptChain(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Invalid(a), Self::Invalid(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for Expr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::This(it) => it.hash_ignore_span(ast, state),
+            Self::Array(it) => it.hash_ignore_span(ast, state),
+            Self::Object(it) => it.hash_ignore_span(ast, state),
+            Self::Fn(it) => it.hash_ignore_span(ast, state),
+            Self::Unary(it) => it.hash_ignore_span(ast, state),
+            Self::Update(it) => it.hash_ignore_span(ast, state),
+            Self::Bin(it) => it.hash_ignore_span(ast, state),
+            Self::Assign(it) => it.hash_ignore_span(ast, state),
+            Self::Member(it) => it.hash_ignore_span(ast, state),
+            Self::SuperProp(it) => it.hash_ignore_span(ast, state),
+            Self::Cond(it) => it.hash_ignore_span(ast, state),
+            Self::Call(it) => it.hash_ignore_span(ast, state),
+            Self::New(it) => it.hash_ignore_span(ast, state),
+            Self::Seq(it) => it.hash_ignore_span(ast, state),
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::Lit(it) => it.hash_ignore_span(ast, state),
+            Self::Tpl(it) => it.hash_ignore_span(ast, state),
+            Self::TaggedTpl(it) => it.hash_ignore_span(ast, state),
+            Self::Arrow(it) => it.hash_ignore_span(ast, state),
+            Self::Class(it) => it.hash_ignore_span(ast, state),
+            Self::Yield(it) => it.hash_ignore_span(ast, state),
+            Self::MetaProp(it) => it.hash_ignore_span(ast, state),
+            Self::Await(it) => it.hash_ignore_span(ast, state),
+            Self::Paren(it) => it.hash_ignore_span(ast, state),
+            Self::JSXMember(it) => it.hash_ignore_span(ast, state),
+            Self::JSXNamespacedName(it) => it.hash_ignore_span(ast, state),
+            Self::JSXEmpty(it) => it.hash_ignore_span(ast, state),
+            Self::JSXElement(it) => it.hash_ignore_span(ast, state),
+            Self::JSXFragment(it) => it.hash_ignore_span(ast, state),
+            Self::TsTypeAssertion(it) => it.hash_ignore_span(ast, state),
+            Self::TsConstAssertion(it) => it.hash_ignore_span(ast, state),
+            Self::TsNonNull(it) => it.hash_ignore_span(ast, state),
+            Self::TsAs(it) => it.hash_ignore_span(ast, state),
+            Self::TsInstantiation(it) => it.hash_ignore_span(ast, state),
+            Self::TsSatisfies(it) => it.hash_ignore_span(ast, state),
+            Self::PrivateName(it) => it.hash_ignore_span(ast, state),
+            Self::OptChain(it) => it.hash_ignore_span(ast, state),
+            Self::Invalid(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ThisExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for ThisExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for ArrayLit {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.elems(ast)
+            .eq_ignore_span(ast, &other.elems(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ArrayLit {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.elems(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ObjectLit {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.props(ast)
+            .eq_ignore_span(ast, &other.props(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ObjectLit {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.props(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for PropOrSpread {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::SpreadElement(a), Self::SpreadElement(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Prop(a), Self::Prop(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for PropOrSpread {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::SpreadElement(it) => it.hash_ignore_span(ast, state),
+            Self::Prop(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for SpreadElement {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.dot_3_token(ast)
+            .eq_ignore_span(ast, &other.dot_3_token(other_ast), other_ast)
+            && self
+                .expr(ast)
+                .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for SpreadElement {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.dot_3_token(ast).hash_ignore_span(ast, state);
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for UnaryExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.op(ast)
+            .eq_ignore_span(ast, &other.op(other_ast), other_ast)
+            && self
+                .arg(ast)
+                .eq_ignore_span(ast, &other.arg(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for UnaryExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.op(ast).hash_ignore_span(ast, state);
+        self.arg(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for UpdateExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.op(ast)
+            .eq_ignore_span(ast, &other.op(other_ast), other_ast)
+            && self
+                .prefix(ast)
+                .eq_ignore_span(ast, &other.prefix(other_ast), other_ast)
+            && self
+                .arg(ast)
+                .eq_ignore_span(ast, &other.arg(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for UpdateExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.op(ast).hash_ignore_span(ast, state);
+        self.prefix(ast).hash_ignore_span(ast, state);
+        self.arg(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for BinExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.op(ast)
+            .eq_ignore_span(ast, &other.op(other_ast), other_ast)
+            && self
+                .left(ast)
+                .eq_ignore_span(ast, &other.left(other_ast), other_ast)
+            && self
+                .right(ast)
+                .eq_ignore_span(ast, &other.right(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for BinExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.op(ast).hash_ignore_span(ast, state);
+        self.left(ast).hash_ignore_span(ast, state);
+        self.right(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for FnExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.ident(ast)
+            .eq_ignore_span(ast, &other.ident(other_ast), other_ast)
+            && self
+                .function(ast)
+                .eq_ignore_span(ast, &other.function(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for FnExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.ident(ast).hash_ignore_span(ast, state);
+        self.function(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ClassExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.ident(ast)
+            .eq_ignore_span(ast, &other.ident(other_ast), other_ast)
+            && self
+                .class(ast)
+                .eq_ignore_span(ast, &other.class(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ClassExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.ident(ast).hash_ignore_span(ast, state);
+        self.class(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for AssignExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.op(ast)
+            .eq_ignore_span(ast, &other.op(other_ast), other_ast)
+            && self
+                .left(ast)
+                .eq_ignore_span(ast, &other.left(other_ast), other_ast)
+            && self
+                .right(ast)
+                .eq_ignore_span(ast, &other.right(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for AssignExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.op(ast).hash_ignore_span(ast, state);
+        self.left(ast).hash_ignore_span(ast, state);
+        self.right(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for MemberExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.obj(ast)
+            .eq_ignore_span(ast, &other.obj(other_ast), other_ast)
+            && self
+                .prop(ast)
+                .eq_ignore_span(ast, &other.prop(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for MemberExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.obj(ast).hash_ignore_span(ast, state);
+        self.prop(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for MemberProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::PrivateName(a), Self::PrivateName(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Computed(a), Self::Computed(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for MemberProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::PrivateName(it) => it.hash_ignore_span(ast, state),
+            Self::Computed(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for SuperPropExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.obj(ast)
+            .eq_ignore_span(ast, &other.obj(other_ast), other_ast)
+            && self
+                .prop(ast)
+                .eq_ignore_span(ast, &other.prop(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for SuperPropExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.obj(ast).hash_ignore_span(ast, state);
+        self.prop(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for SuperProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Computed(a), Self::Computed(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for SuperProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::Computed(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for CondExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.test(ast)
+            .eq_ignore_span(ast, &other.test(other_ast), other_ast)
+            && self
+                .cons(ast)
+                .eq_ignore_span(ast, &other.cons(other_ast), other_ast)
+            && self
+                .alt(ast)
+                .eq_ignore_span(ast, &other.alt(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for CondExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.test(ast).hash_ignore_span(ast, state);
+        self.cons(ast).hash_ignore_span(ast, state);
+        self.alt(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for CallExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.callee(ast)
+            .eq_ignore_span(ast, &other.callee(other_ast), other_ast)
+            && self
+                .args(ast)
+                .eq_ignore_span(ast, &other.args(other_ast), other_ast)
+            && self
+                .type_args(ast)
+                .eq_ignore_span(ast, &other.type_args(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for CallExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.callee(ast).hash_ignore_span(ast, state);
+        self.args(ast).hash_ignore_span(ast, state);
+        self.type_args(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for NewExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.callee(ast)
+            .eq_ignore_span(ast, &other.callee(other_ast), other_ast)
+            && self
+                .args(ast)
+                .eq_ignore_span(ast, &other.args(other_ast), other_ast)
+            && self
+                .type_args(ast)
+                .eq_ignore_span(ast, &other.type_args(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for NewExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.callee(ast).hash_ignore_span(ast, state);
+        self.args(ast).hash_ignore_span(ast, state);
+        self.type_args(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for SeqExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.exprs(ast)
+            .eq_ignore_span(ast, &other.exprs(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for SeqExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.exprs(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ArrowExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.params(ast)
+            .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+            && self
+                .is_async(ast)
+                .eq_ignore_span(ast, &other.is_async(other_ast), other_ast)
+            && self
+                .is_generator(ast)
+                .eq_ignore_span(ast, &other.is_generator(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+            && self
+                .return_type(ast)
+                .eq_ignore_span(ast, &other.return_type(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ArrowExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.params(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+        self.is_async(ast).hash_ignore_span(ast, state);
+        self.is_generator(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+        self.return_type(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for YieldExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.arg(ast)
+            .eq_ignore_span(ast, &other.arg(other_ast), other_ast)
+            && self
+                .delegate(ast)
+                .eq_ignore_span(ast, &other.delegate(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for YieldExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.arg(ast).hash_ignore_span(ast, state);
+        self.delegate(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for MetaPropExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.kind(ast)
+            .eq_ignore_span(ast, &other.kind(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for MetaPropExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.kind(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for AwaitExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.arg(ast)
+            .eq_ignore_span(ast, &other.arg(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for AwaitExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.arg(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Tpl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.exprs(ast)
+            .eq_ignore_span(ast, &other.exprs(other_ast), other_ast)
+            && self
+                .quasis(ast)
+                .eq_ignore_span(ast, &other.quasis(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Tpl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.exprs(ast).hash_ignore_span(ast, state);
+        self.quasis(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TaggedTpl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.tag(ast)
+            .eq_ignore_span(ast, &other.tag(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+            && self
+                .tpl(ast)
+                .eq_ignore_span(ast, &other.tpl(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TaggedTpl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.tag(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+        self.tpl(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TplElement {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.tail(ast)
+            .eq_ignore_span(ast, &other.tail(other_ast), other_ast)
+            && self
+                .cooked(ast)
+                .eq_ignore_span(ast, &other.cooked(other_ast), other_ast)
+            && self
+                .raw(ast)
+                .eq_ignore_span(ast, &other.raw(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TplElement {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.tail(ast).hash_ignore_span(ast, state);
+        self.cooked(ast).hash_ignore_span(ast, state);
+        self.raw(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ParenExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ParenExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Callee {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Super(a), Self::Super(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Import(a), Self::Import(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Expr(a), Self::Expr(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for Callee {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Super(it) => it.hash_ignore_span(ast, state),
+            Self::Import(it) => it.hash_ignore_span(ast, state),
+            Self::Expr(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for Super {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for Super {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for Import {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.phase(ast)
+            .eq_ignore_span(ast, &other.phase(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Import {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.phase(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ExprOrSpread {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.spread(ast)
+            .eq_ignore_span(ast, &other.spread(other_ast), other_ast)
+            && self
+                .expr(ast)
+                .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ExprOrSpread {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.spread(ast).hash_ignore_span(ast, state);
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for SpreadDot3Token {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for SpreadDot3Token {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for BlockStmtOrExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::BlockStmt(a), Self::BlockStmt(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Expr(a), Self::Expr(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for BlockStmtOrExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::BlockStmt(it) => it.hash_ignore_span(ast, state),
+            Self::Expr(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for AssignTarget {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Simple(a), Self::Simple(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Pat(a), Self::Pat(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for AssignTarget {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Simple(it) => it.hash_ignore_span(ast, state),
+            Self::Pat(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for AssignTargetPat {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Array(a), Self::Array(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Object(a), Self::Object(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Invalid(a), Self::Invalid(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for AssignTargetPat {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Array(it) => it.hash_ignore_span(ast, state),
+            Self::Object(it) => it.hash_ignore_span(ast, state),
+            Self::Invalid(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for SimpleAssignTarget {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Member(a), Self::Member(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::SuperProp(a), Self::SuperProp(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Paren(a), Self::Paren(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::OptChain(a), Self::OptChain(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsAs(a), Self::TsAs(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsSatisfies(a), Self::TsSatisfies(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsNonNull(a), Self::TsNonNull(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsTypeAssertion(a), Self::TsTypeAssertion(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsInstantiation(a), Self::TsInstantiation(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::Invalid(a), Self::Invalid(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for SimpleAssignTarget {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::Member(it) => it.hash_ignore_span(ast, state),
+            Self::SuperProp(it) => it.hash_ignore_span(ast, state),
+            Self::Paren(it) => it.hash_ignore_span(ast, state),
+            Self::OptChain(it) => it.hash_ignore_span(ast, state),
+            Self::TsAs(it) => it.hash_ignore_span(ast, state),
+            Self::TsSatisfies(it) => it.hash_ignore_span(ast, state),
+            Self::TsNonNull(it) => it.hash_ignore_span(ast, state),
+            Self::TsTypeAssertion(it) => it.hash_ignore_span(ast, state),
+            Self::TsInstantiation(it) => it.hash_ignore_span(ast, state),
+            Self::Invalid(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for OptChainExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.optional(ast)
+            .eq_ignore_span(ast, &other.optional(other_ast), other_ast)
+            && self
+                .base(ast)
+                .eq_ignore_span(ast, &other.base(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for OptChainExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.optional(ast).hash_ignore_span(ast, state);
+        self.base(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for OptChainBase {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Member(a), Self::Member(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Call(a), Self::Call(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for OptChainBase {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Member(it) => it.hash_ignore_span(ast, state),
+            Self::Call(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for OptCall {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.callee(ast)
+            .eq_ignore_span(ast, &other.callee(other_ast), other_ast)
+            && self
+                .args(ast)
+                .eq_ignore_span(ast, &other.args(other_ast), other_ast)
+            && self
+                .type_args(ast)
+                .eq_ignore_span(ast, &other.type_args(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for OptCall {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.callee(ast).hash_ignore_span(ast, state);
+        self.args(ast).hash_ignore_span(ast, state);
+        self.type_args(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Invalid {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for Invalid {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for Function {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.params(ast)
+            .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+            && self
+                .decorators(ast)
+                .eq_ignore_span(ast, &other.decorators(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+            && self
+                .is_generator(ast)
+                .eq_ignore_span(ast, &other.is_generator(other_ast), other_ast)
+            && self
+                .is_async(ast)
+                .eq_ignore_span(ast, &other.is_async(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+            && self
+                .return_type(ast)
+                .eq_ignore_span(ast, &other.return_type(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Function {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.params(ast).hash_ignore_span(ast, state);
+        self.decorators(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+        self.is_generator(ast).hash_ignore_span(ast, state);
+        self.is_async(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+        self.return_type(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Param {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.decorators(ast)
+            .eq_ignore_span(ast, &other.decorators(other_ast), other_ast)
+            && self
+                .pat(ast)
+                .eq_ignore_span(ast, &other.pat(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Param {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.decorators(ast).hash_ignore_span(ast, state);
+        self.pat(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ParamOrTsParamProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::TsParamProp(a), Self::TsParamProp(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Param(a), Self::Param(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for ParamOrTsParamProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TsParamProp(it) => it.hash_ignore_span(ast, state),
+            Self::Param(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for Class {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.decorators(ast)
+            .eq_ignore_span(ast, &other.decorators(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+            && self
+                .super_class(ast)
+                .eq_ignore_span(ast, &other.super_class(other_ast), other_ast)
+            && self
+                .is_abstract(ast)
+                .eq_ignore_span(ast, &other.is_abstract(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+            && self.super_type_params(ast).eq_ignore_span(
+                ast,
+                &other.super_type_params(other_ast),
+                other_ast,
+            )
+            && self
+                .implements(ast)
+                .eq_ignore_span(ast, &other.implements(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Class {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.decorators(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+        self.super_class(ast).hash_ignore_span(ast, state);
+        self.is_abstract(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+        self.super_type_params(ast).hash_ignore_span(ast, state);
+        self.implements(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ClassMember {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Constructor(a), Self::Constructor(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Method(a), Self::Method(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::PrivateMethod(a), Self::PrivateMethod(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::ClassProp(a), Self::ClassProp(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::PrivateProp(a), Self::PrivateProp(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Empty(a), Self::Empty(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::StaticBlock(a), Self::StaticBlock(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::AutoAccessor(a), Self::AutoAccessor(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsIndexSignature(a), Self::TsIndexSignature(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for ClassMember {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Constructor(it) => it.hash_ignore_span(ast, state),
+            Self::Method(it) => it.hash_ignore_span(ast, state),
+            Self::PrivateMethod(it) => it.hash_ignore_span(ast, state),
+            Self::ClassProp(it) => it.hash_ignore_span(ast, state),
+            Self::PrivateProp(it) => it.hash_ignore_span(ast, state),
+            Self::Empty(it) => it.hash_ignore_span(ast, state),
+            Self::StaticBlock(it) => it.hash_ignore_span(ast, state),
+            Self::AutoAccessor(it) => it.hash_ignore_span(ast, state),
+            Self::TsIndexSignature(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ClassProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .value(ast)
+                .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+            && self
+                .is_static(ast)
+                .eq_ignore_span(ast, &other.is_static(other_ast), other_ast)
+            && self
+                .decorators(ast)
+                .eq_ignore_span(ast, &other.decorators(other_ast), other_ast)
+            && self.accessibility(ast).eq_ignore_span(
+                ast,
+                &other.accessibility(other_ast),
+                other_ast,
+            )
+            && self
+                .is_abstract(ast)
+                .eq_ignore_span(ast, &other.is_abstract(other_ast), other_ast)
+            && self
+                .is_optional(ast)
+                .eq_ignore_span(ast, &other.is_optional(other_ast), other_ast)
+            && self
+                .is_override(ast)
+                .eq_ignore_span(ast, &other.is_override(other_ast), other_ast)
+            && self
+                .readonly(ast)
+                .eq_ignore_span(ast, &other.readonly(other_ast), other_ast)
+            && self
+                .declare(ast)
+                .eq_ignore_span(ast, &other.declare(other_ast), other_ast)
+            && self
+                .definite(ast)
+                .eq_ignore_span(ast, &other.definite(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ClassProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.value(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+        self.is_static(ast).hash_ignore_span(ast, state);
+        self.decorators(ast).hash_ignore_span(ast, state);
+        self.accessibility(ast).hash_ignore_span(ast, state);
+        self.is_abstract(ast).hash_ignore_span(ast, state);
+        self.is_optional(ast).hash_ignore_span(ast, state);
+        self.is_override(ast).hash_ignore_span(ast, state);
+        self.readonly(ast).hash_ignore_span(ast, state);
+        self.declare(ast).hash_ignore_span(ast, state);
+        self.definite(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for PrivateProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .value(ast)
+                .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+            && self
+                .is_static(ast)
+                .eq_ignore_span(ast, &other.is_static(other_ast), other_ast)
+            && self
+                .decorators(ast)
+                .eq_ignore_span(ast, &other.decorators(other_ast), other_ast)
+            && self.accessibility(ast).eq_ignore_span(
+                ast,
+                &other.accessibility(other_ast),
+                other_ast,
+            )
+            && self
+                .is_optional(ast)
+                .eq_ignore_span(ast, &other.is_optional(other_ast), other_ast)
+            && self
+                .is_override(ast)
+                .eq_ignore_span(ast, &other.is_override(other_ast), other_ast)
+            && self
+                .readonly(ast)
+                .eq_ignore_span(ast, &other.readonly(other_ast), other_ast)
+            && self
+                .definite(ast)
+                .eq_ignore_span(ast, &other.definite(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for PrivateProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.value(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+        self.is_static(ast).hash_ignore_span(ast, state);
+        self.decorators(ast).hash_ignore_span(ast, state);
+        self.accessibility(ast).hash_ignore_span(ast, state);
+        self.is_optional(ast).hash_ignore_span(ast, state);
+        self.is_override(ast).hash_ignore_span(ast, state);
+        self.readonly(ast).hash_ignore_span(ast, state);
+        self.definite(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ClassMethod {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .function(ast)
+                .eq_ignore_span(ast, &other.function(other_ast), other_ast)
+            && self
+                .kind(ast)
+                .eq_ignore_span(ast, &other.kind(other_ast), other_ast)
+            && self
+                .is_static(ast)
+                .eq_ignore_span(ast, &other.is_static(other_ast), other_ast)
+            && self.accessibility(ast).eq_ignore_span(
+                ast,
+                &other.accessibility(other_ast),
+                other_ast,
+            )
+            && self
+                .is_abstract(ast)
+                .eq_ignore_span(ast, &other.is_abstract(other_ast), other_ast)
+            && self
+                .is_optional(ast)
+                .eq_ignore_span(ast, &other.is_optional(other_ast), other_ast)
+            && self
+                .is_override(ast)
+                .eq_ignore_span(ast, &other.is_override(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ClassMethod {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.function(ast).hash_ignore_span(ast, state);
+        self.kind(ast).hash_ignore_span(ast, state);
+        self.is_static(ast).hash_ignore_span(ast, state);
+        self.accessibility(ast).hash_ignore_span(ast, state);
+        self.is_abstract(ast).hash_ignore_span(ast, state);
+        self.is_optional(ast).hash_ignore_span(ast, state);
+        self.is_override(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for PrivateMethod {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .function(ast)
+                .eq_ignore_span(ast, &other.function(other_ast), other_ast)
+            && self
+                .kind(ast)
+                .eq_ignore_span(ast, &other.kind(other_ast), other_ast)
+            && self
+                .is_static(ast)
+                .eq_ignore_span(ast, &other.is_static(other_ast), other_ast)
+            && self.accessibility(ast).eq_ignore_span(
+                ast,
+                &other.accessibility(other_ast),
+                other_ast,
+            )
+            && self
+                .is_abstract(ast)
+                .eq_ignore_span(ast, &other.is_abstract(other_ast), other_ast)
+            && self
+                .is_optional(ast)
+                .eq_ignore_span(ast, &other.is_optional(other_ast), other_ast)
+            && self
+                .is_override(ast)
+                .eq_ignore_span(ast, &other.is_override(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for PrivateMethod {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.function(ast).hash_ignore_span(ast, state);
+        self.kind(ast).hash_ignore_span(ast, state);
+        self.is_static(ast).hash_ignore_span(ast, state);
+        self.accessibility(ast).hash_ignore_span(ast, state);
+        self.is_abstract(ast).hash_ignore_span(ast, state);
+        self.is_optional(ast).hash_ignore_span(ast, state);
+        self.is_override(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Constructor {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .params(ast)
+                .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+            && self.accessibility(ast).eq_ignore_span(
+                ast,
+                &other.accessibility(other_ast),
+                other_ast,
+            )
+            && self
+                .is_optional(ast)
+                .eq_ignore_span(ast, &other.is_optional(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Constructor {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.params(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+        self.accessibility(ast).hash_ignore_span(ast, state);
+        self.is_optional(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Decorator {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Decorator {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for StaticBlock {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.body(ast)
+            .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for StaticBlock {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Key {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Private(a), Self::Private(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Public(a), Self::Public(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for Key {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Private(it) => it.hash_ignore_span(ast, state),
+            Self::Public(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for AutoAccessor {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .value(ast)
+                .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+            && self
+                .is_static(ast)
+                .eq_ignore_span(ast, &other.is_static(other_ast), other_ast)
+            && self
+                .decorators(ast)
+                .eq_ignore_span(ast, &other.decorators(other_ast), other_ast)
+            && self.accessibility(ast).eq_ignore_span(
+                ast,
+                &other.accessibility(other_ast),
+                other_ast,
+            )
+            && self
+                .is_abstract(ast)
+                .eq_ignore_span(ast, &other.is_abstract(other_ast), other_ast)
+            && self
+                .is_override(ast)
+                .eq_ignore_span(ast, &other.is_override(other_ast), other_ast)
+            && self
+                .definite(ast)
+                .eq_ignore_span(ast, &other.definite(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for AutoAccessor {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.value(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+        self.is_static(ast).hash_ignore_span(ast, state);
+        self.decorators(ast).hash_ignore_span(ast, state);
+        self.accessibility(ast).hash_ignore_span(ast, state);
+        self.is_abstract(ast).hash_ignore_span(ast, state);
+        self.is_override(ast).hash_ignore_span(ast, state);
+        self.definite(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Prop {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Shorthand(a), Self::Shorthand(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::KeyValue(a), Self::KeyValue(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Assign(a), Self::Assign(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Getter(a), Self::Getter(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Setter(a), Self::Setter(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Method(a), Self::Method(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for Prop {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Shorthand(it) => it.hash_ignore_span(ast, state),
+            Self::KeyValue(it) => it.hash_ignore_span(ast, state),
+            Self::Assign(it) => it.hash_ignore_span(ast, state),
+            Self::Getter(it) => it.hash_ignore_span(ast, state),
+            Self::Setter(it) => it.hash_ignore_span(ast, state),
+            Self::Method(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for KeyValueProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .value(ast)
+                .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for KeyValueProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.value(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for AssignProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .value(ast)
+                .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for AssignProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.value(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for GetterProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for GetterProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for SetterProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .this_param(ast)
+                .eq_ignore_span(ast, &other.this_param(other_ast), other_ast)
+            && self
+                .param(ast)
+                .eq_ignore_span(ast, &other.param(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for SetterProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.this_param(ast).hash_ignore_span(ast, state);
+        self.param(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for MethodProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .function(ast)
+                .eq_ignore_span(ast, &other.function(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for MethodProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.function(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for PropName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Str(a), Self::Str(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Num(a), Self::Num(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Computed(a), Self::Computed(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::BigInt(a), Self::BigInt(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for PropName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::Str(it) => it.hash_ignore_span(ast, state),
+            Self::Num(it) => it.hash_ignore_span(ast, state),
+            Self::Computed(it) => it.hash_ignore_span(ast, state),
+            Self::BigInt(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ComputedPropName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ComputedPropName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Pat {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Array(a), Self::Array(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Rest(a), Self::Rest(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Object(a), Self::Object(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Assign(a), Self::Assign(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Invalid(a), Self::Invalid(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Expr(a), Self::Expr(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for Pat {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::Array(it) => it.hash_ignore_span(ast, state),
+            Self::Rest(it) => it.hash_ignore_span(ast, state),
+            Self::Object(it) => it.hash_ignore_span(ast, state),
+            Self::Assign(it) => it.hash_ignore_span(ast, state),
+            Self::Invalid(it) => it.hash_ignore_span(ast, state),
+            Self::Expr(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for ArrayPat {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.elems(ast)
+            .eq_ignore_span(ast, &other.elems(other_ast), other_ast)
+            && self
+                .optional(ast)
+                .eq_ignore_span(ast, &other.optional(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ArrayPat {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.elems(ast).hash_ignore_span(ast, state);
+        self.optional(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ObjectPat {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.props(ast)
+            .eq_ignore_span(ast, &other.props(other_ast), other_ast)
+            && self
+                .optional(ast)
+                .eq_ignore_span(ast, &other.optional(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for ObjectPat {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.props(ast).hash_ignore_span(ast, state);
+        self.optional(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for AssignPat {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.left(ast)
+            .eq_ignore_span(ast, &other.left(other_ast), other_ast)
+            && self
+                .right(ast)
+                .eq_ignore_span(ast, &other.right(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for AssignPat {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.left(ast).hash_ignore_span(ast, state);
+        self.right(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for RestPat {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.dot_3_token(ast)
+            .eq_ignore_span(ast, &other.dot_3_token(other_ast), other_ast)
+            && self
+                .arg(ast)
+                .eq_ignore_span(ast, &other.arg(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for RestPat {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.dot_3_token(ast).hash_ignore_span(ast, state);
+        self.arg(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for ObjectPatProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::KeyValue(a), Self::KeyValue(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Assign(a), Self::Assign(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Rest(a), Self::Rest(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for ObjectPatProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::KeyValue(it) => it.hash_ignore_span(ast, state),
+            Self::Assign(it) => it.hash_ignore_span(ast, state),
+            Self::Rest(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for KeyValuePatProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .value(ast)
+                .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for KeyValuePatProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.value(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for AssignPatProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .value(ast)
+                .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for AssignPatProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.value(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Ident {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.sym(ast)
+            .eq_ignore_span(ast, &other.sym(other_ast), other_ast)
+            && self
+                .optional(ast)
+                .eq_ignore_span(ast, &other.optional(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Ident {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.sym(ast).hash_ignore_span(ast, state);
+        self.optional(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for IdentName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.sym(ast)
+            .eq_ignore_span(ast, &other.sym(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for IdentName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.sym(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for PrivateName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.name(ast)
+            .eq_ignore_span(ast, &other.name(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for PrivateName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.name(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for BindingIdent {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.id(ast)
+            .eq_ignore_span(ast, &other.id(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for BindingIdent {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.id(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Lit {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Str(a), Self::Str(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Bool(a), Self::Bool(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Null(a), Self::Null(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Num(a), Self::Num(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::BigInt(a), Self::BigInt(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Regex(a), Self::Regex(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for Lit {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Str(it) => it.hash_ignore_span(ast, state),
+            Self::Bool(it) => it.hash_ignore_span(ast, state),
+            Self::Null(it) => it.hash_ignore_span(ast, state),
+            Self::Num(it) => it.hash_ignore_span(ast, state),
+            Self::BigInt(it) => it.hash_ignore_span(ast, state),
+            Self::Regex(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for Str {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.value(ast)
+            .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+            && self
+                .raw(ast)
+                .eq_ignore_span(ast, &other.raw(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Str {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.value(ast).hash_ignore_span(ast, state);
+        self.raw(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Bool {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.value(ast)
+            .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Bool {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.value(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Null {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for Null {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for Number {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.value(ast)
+            .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+            && self
+                .raw(ast)
+                .eq_ignore_span(ast, &other.raw(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Number {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.value(ast).hash_ignore_span(ast, state);
+        self.raw(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for BigInt {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.value(ast)
+            .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+            && self
+                .raw(ast)
+                .eq_ignore_span(ast, &other.raw(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for BigInt {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.value(ast).hash_ignore_span(ast, state);
+        self.raw(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for Regex {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.exp(ast)
+            .eq_ignore_span(ast, &other.exp(other_ast), other_ast)
+            && self
+                .flags(ast)
+                .eq_ignore_span(ast, &other.flags(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for Regex {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.exp(ast).hash_ignore_span(ast, state);
+        self.flags(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXObject {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::JSXMemberExpr(a), Self::JSXMemberExpr(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for JSXObject {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::JSXMemberExpr(it) => it.hash_ignore_span(ast, state),
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for JSXMemberExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.obj(ast)
+            .eq_ignore_span(ast, &other.obj(other_ast), other_ast)
+            && self
+                .prop(ast)
+                .eq_ignore_span(ast, &other.prop(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for JSXMemberExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.obj(ast).hash_ignore_span(ast, state);
+        self.prop(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXNamespacedName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.ns(ast)
+            .eq_ignore_span(ast, &other.ns(other_ast), other_ast)
+            && self
+                .name(ast)
+                .eq_ignore_span(ast, &other.name(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for JSXNamespacedName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.ns(ast).hash_ignore_span(ast, state);
+        self.name(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXEmptyExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for JSXEmptyExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for JSXExprContainer {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for JSXExprContainer {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::JSXEmptyExpr(a), Self::JSXEmptyExpr(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Expr(a), Self::Expr(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for JSXExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::JSXEmptyExpr(it) => it.hash_ignore_span(ast, state),
+            Self::Expr(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for JSXSpreadChild {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for JSXSpreadChild {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXElementName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXMemberExpr(a), Self::JSXMemberExpr(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXNamespacedName(a), Self::JSXNamespacedName(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for JSXElementName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::JSXMemberExpr(it) => it.hash_ignore_span(ast, state),
+            Self::JSXNamespacedName(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for JSXOpeningElement {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.name(ast)
+            .eq_ignore_span(ast, &other.name(other_ast), other_ast)
+            && self
+                .attrs(ast)
+                .eq_ignore_span(ast, &other.attrs(other_ast), other_ast)
+            && self
+                .self_closing(ast)
+                .eq_ignore_span(ast, &other.self_closing(other_ast), other_ast)
+            && self
+                .type_args(ast)
+                .eq_ignore_span(ast, &other.type_args(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for JSXOpeningElement {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.name(ast).hash_ignore_span(ast, state);
+        self.attrs(ast).hash_ignore_span(ast, state);
+        self.self_closing(ast).hash_ignore_span(ast, state);
+        self.type_args(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXAttrOrSpread {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::JSXAttr(a), Self::JSXAttr(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::SpreadElement(a), Self::SpreadElement(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for JSXAttrOrSpread {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::JSXAttr(it) => it.hash_ignore_span(ast, state),
+            Self::SpreadElement(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for JSXClosingElement {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.name(ast)
+            .eq_ignore_span(ast, &other.name(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for JSXClosingElement {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.name(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXAttr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.name(ast)
+            .eq_ignore_span(ast, &other.name(other_ast), other_ast)
+            && self
+                .value(ast)
+                .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for JSXAttr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.name(ast).hash_ignore_span(ast, state);
+        self.value(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXAttrName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXNamespacedName(a), Self::JSXNamespacedName(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for JSXAttrName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::JSXNamespacedName(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for JSXAttrValue {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Str(a), Self::Str(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXExprContainer(a), Self::JSXExprContainer(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::JSXElement(a), Self::JSXElement(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for JSXAttrValue {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Str(it) => it.hash_ignore_span(ast, state),
+            Self::JSXExprContainer(it) => it.hash_ignore_span(ast, state),
+            Self::JSXElement(it) => it.hash_ignore_span(ast, state),
+            Self::JSXFragment(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for JSXText {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.value(ast)
+            .eq_ignore_span(ast, &other.value(other_ast), other_ast)
+            && self
+                .raw(ast)
+                .eq_ignore_span(ast, &other.raw(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for JSXText {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.value(ast).hash_ignore_span(ast, state);
+        self.raw(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXElement {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.opening(ast)
+            .eq_ignore_span(ast, &other.opening(other_ast), other_ast)
+            && self
+                .children(ast)
+                .eq_ignore_span(ast, &other.children(other_ast), other_ast)
+            && self
+                .closing(ast)
+                .eq_ignore_span(ast, &other.closing(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for JSXElement {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.opening(ast).hash_ignore_span(ast, state);
+        self.children(ast).hash_ignore_span(ast, state);
+        self.closing(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXElementChild {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::JSXText(a), Self::JSXText(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXExprContainer(a), Self::JSXExprContainer(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::JSXSpreadChild(a), Self::JSXSpreadChild(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::JSXElement(a), Self::JSXElement(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::JSXFragment(a), Self::JSXFragment(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for JSXElementChild {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::JSXText(it) => it.hash_ignore_span(ast, state),
+            Self::JSXExprContainer(it) => it.hash_ignore_span(ast, state),
+            Self::JSXSpreadChild(it) => it.hash_ignore_span(ast, state),
+            Self::JSXElement(it) => it.hash_ignore_span(ast, state),
+            Self::JSXFragment(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for JSXFragment {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.opening(ast)
+            .eq_ignore_span(ast, &other.opening(other_ast), other_ast)
+            && self
+                .children(ast)
+                .eq_ignore_span(ast, &other.children(other_ast), other_ast)
+            && self
+                .closing(ast)
+                .eq_ignore_span(ast, &other.closing(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for JSXFragment {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.opening(ast).hash_ignore_span(ast, state);
+        self.children(ast).hash_ignore_span(ast, state);
+        self.closing(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for JSXOpeningFragment {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for JSXOpeningFragment {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for JSXClosingFragment {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for JSXClosingFragment {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for TsTypeAnn {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.type_ann(ast)
+            .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypeAnn {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTypeParamDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.params(ast)
+            .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypeParamDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.params(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTypeParam {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.name(ast)
+            .eq_ignore_span(ast, &other.name(other_ast), other_ast)
+            && self
+                .is_in(ast)
+                .eq_ignore_span(ast, &other.is_in(other_ast), other_ast)
+            && self
+                .is_out(ast)
+                .eq_ignore_span(ast, &other.is_out(other_ast), other_ast)
+            && self
+                .is_const(ast)
+                .eq_ignore_span(ast, &other.is_const(other_ast), other_ast)
+            && self
+                .constraint(ast)
+                .eq_ignore_span(ast, &other.constraint(other_ast), other_ast)
+            && self
+                .default(ast)
+                .eq_ignore_span(ast, &other.default(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypeParam {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.name(ast).hash_ignore_span(ast, state);
+        self.is_in(ast).hash_ignore_span(ast, state);
+        self.is_out(ast).hash_ignore_span(ast, state);
+        self.is_const(ast).hash_ignore_span(ast, state);
+        self.constraint(ast).hash_ignore_span(ast, state);
+        self.default(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTypeParamInstantiation {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.params(ast)
+            .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypeParamInstantiation {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.params(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsParamProp {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.decorators(ast)
+            .eq_ignore_span(ast, &other.decorators(other_ast), other_ast)
+            && self.accessibility(ast).eq_ignore_span(
+                ast,
+                &other.accessibility(other_ast),
+                other_ast,
+            )
+            && self
+                .is_override(ast)
+                .eq_ignore_span(ast, &other.is_override(other_ast), other_ast)
+            && self
+                .readonly(ast)
+                .eq_ignore_span(ast, &other.readonly(other_ast), other_ast)
+            && self
+                .param(ast)
+                .eq_ignore_span(ast, &other.param(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsParamProp {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.decorators(ast).hash_ignore_span(ast, state);
+        self.accessibility(ast).hash_ignore_span(ast, state);
+        self.is_override(ast).hash_ignore_span(ast, state);
+        self.readonly(ast).hash_ignore_span(ast, state);
+        self.param(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsParamPropParam {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Assign(a), Self::Assign(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsParamPropParam {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::Assign(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsQualifiedName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.left(ast)
+            .eq_ignore_span(ast, &other.left(other_ast), other_ast)
+            && self
+                .right(ast)
+                .eq_ignore_span(ast, &other.right(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsQualifiedName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.left(ast).hash_ignore_span(ast, state);
+        self.right(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsEntityName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::TsQualifiedName(a), Self::TsQualifiedName(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsEntityName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TsQualifiedName(it) => it.hash_ignore_span(ast, state),
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsTypeElement {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::TsCallSignatureDecl(a), Self::TsCallSignatureDecl(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsConstructSignatureDecl(a), Self::TsConstructSignatureDecl(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsPropertySignature(a), Self::TsPropertySignature(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsGetterSignature(a), Self::TsGetterSignature(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsSetterSignature(a), Self::TsSetterSignature(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsMethodSignature(a), Self::TsMethodSignature(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsIndexSignature(a), Self::TsIndexSignature(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsTypeElement {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TsCallSignatureDecl(it) => it.hash_ignore_span(ast, state),
+            Self::TsConstructSignatureDecl(it) => it.hash_ignore_span(ast, state),
+            Self::TsPropertySignature(it) => it.hash_ignore_span(ast, state),
+            Self::TsGetterSignature(it) => it.hash_ignore_span(ast, state),
+            Self::TsSetterSignature(it) => it.hash_ignore_span(ast, state),
+            Self::TsMethodSignature(it) => it.hash_ignore_span(ast, state),
+            Self::TsIndexSignature(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsCallSignatureDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.params(ast)
+            .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsCallSignatureDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.params(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsConstructSignatureDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.params(ast)
+            .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsConstructSignatureDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.params(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsPropertySignature {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.readonly(ast)
+            .eq_ignore_span(ast, &other.readonly(other_ast), other_ast)
+            && self
+                .key(ast)
+                .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .computed(ast)
+                .eq_ignore_span(ast, &other.computed(other_ast), other_ast)
+            && self
+                .optional(ast)
+                .eq_ignore_span(ast, &other.optional(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsPropertySignature {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.readonly(ast).hash_ignore_span(ast, state);
+        self.key(ast).hash_ignore_span(ast, state);
+        self.computed(ast).hash_ignore_span(ast, state);
+        self.optional(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsGetterSignature {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .computed(ast)
+                .eq_ignore_span(ast, &other.computed(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsGetterSignature {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.computed(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsSetterSignature {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .computed(ast)
+                .eq_ignore_span(ast, &other.computed(other_ast), other_ast)
+            && self
+                .param(ast)
+                .eq_ignore_span(ast, &other.param(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsSetterSignature {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.computed(ast).hash_ignore_span(ast, state);
+        self.param(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsMethodSignature {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.key(ast)
+            .eq_ignore_span(ast, &other.key(other_ast), other_ast)
+            && self
+                .computed(ast)
+                .eq_ignore_span(ast, &other.computed(other_ast), other_ast)
+            && self
+                .optional(ast)
+                .eq_ignore_span(ast, &other.optional(other_ast), other_ast)
+            && self
+                .params(ast)
+                .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsMethodSignature {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.key(ast).hash_ignore_span(ast, state);
+        self.computed(ast).hash_ignore_span(ast, state);
+        self.optional(ast).hash_ignore_span(ast, state);
+        self.params(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsIndexSignature {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.params(ast)
+            .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+            && self
+                .readonly(ast)
+                .eq_ignore_span(ast, &other.readonly(other_ast), other_ast)
+            && self
+                .is_static(ast)
+                .eq_ignore_span(ast, &other.is_static(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsIndexSignature {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.params(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+        self.readonly(ast).hash_ignore_span(ast, state);
+        self.is_static(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::TsKeywordType(a), Self::TsKeywordType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsThisType(a), Self::TsThisType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsFnOrConstructorType(a), Self::TsFnOrConstructorType(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsTypeRef(a), Self::TsTypeRef(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsTypeQuery(a), Self::TsTypeQuery(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsTypeLit(a), Self::TsTypeLit(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsArrayType(a), Self::TsArrayType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsTupleType(a), Self::TsTupleType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsOptionalType(a), Self::TsOptionalType(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsRestType(a), Self::TsRestType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsUnionOrIntersectionType(a), Self::TsUnionOrIntersectionType(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsConditionalType(a), Self::TsConditionalType(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsInferType(a), Self::TsInferType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsParenthesizedType(a), Self::TsParenthesizedType(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsTypeOperator(a), Self::TsTypeOperator(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsIndexedAccessType(a), Self::TsIndexedAccessType(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsMappedType(a), Self::TsMappedType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsLitType(a), Self::TsLitType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsTypePredicate(a), Self::TsTypePredicate(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            (Self::TsImportType(a), Self::TsImportType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TsKeywordType(it) => it.hash_ignore_span(ast, state),
+            Self::TsThisType(it) => it.hash_ignore_span(ast, state),
+            Self::TsFnOrConstructorType(it) => it.hash_ignore_span(ast, state),
+            Self::TsTypeRef(it) => it.hash_ignore_span(ast, state),
+            Self::TsTypeQuery(it) => it.hash_ignore_span(ast, state),
+            Self::TsTypeLit(it) => it.hash_ignore_span(ast, state),
+            Self::TsArrayType(it) => it.hash_ignore_span(ast, state),
+            Self::TsTupleType(it) => it.hash_ignore_span(ast, state),
+            Self::TsOptionalType(it) => it.hash_ignore_span(ast, state),
+            Self::TsRestType(it) => it.hash_ignore_span(ast, state),
+            Self::TsUnionOrIntersectionType(it) => it.hash_ignore_span(ast, state),
+            Self::TsConditionalType(it) => it.hash_ignore_span(ast, state),
+            Self::TsInferType(it) => it.hash_ignore_span(ast, state),
+            Self::TsParenthesizedType(it) => it.hash_ignore_span(ast, state),
+            Self::TsTypeOperator(it) => it.hash_ignore_span(ast, state),
+            Self::TsIndexedAccessType(it) => it.hash_ignore_span(ast, state),
+            Self::TsMappedType(it) => it.hash_ignore_span(ast, state),
+            Self::TsLitType(it) => it.hash_ignore_span(ast, state),
+            Self::TsTypePredicate(it) => it.hash_ignore_span(ast, state),
+            Self::TsImportType(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsFnOrConstructorType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::TsFnType(a), Self::TsFnType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsConstructorType(a), Self::TsConstructorType(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsFnOrConstructorType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TsFnType(it) => it.hash_ignore_span(ast, state),
+            Self::TsConstructorType(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsKeywordType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.kind(ast)
+            .eq_ignore_span(ast, &other.kind(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsKeywordType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.kind(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsThisType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        true
+    }
+}
+impl HashIgnoreSpan for TsThisType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {}
+}
+impl EqIgnoreSpan for TsFnParam {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Array(a), Self::Array(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Rest(a), Self::Rest(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Object(a), Self::Object(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsFnParam {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::Array(it) => it.hash_ignore_span(ast, state),
+            Self::Rest(it) => it.hash_ignore_span(ast, state),
+            Self::Object(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsFnType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.params(ast)
+            .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsFnType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.params(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsConstructorType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.params(ast)
+            .eq_ignore_span(ast, &other.params(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+            && self
+                .is_abstract(ast)
+                .eq_ignore_span(ast, &other.is_abstract(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsConstructorType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.params(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+        self.is_abstract(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTypeRef {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.type_name(ast)
+            .eq_ignore_span(ast, &other.type_name(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypeRef {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.type_name(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTypePredicate {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.asserts(ast)
+            .eq_ignore_span(ast, &other.asserts(other_ast), other_ast)
+            && self
+                .param_name(ast)
+                .eq_ignore_span(ast, &other.param_name(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypePredicate {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.asserts(ast).hash_ignore_span(ast, state);
+        self.param_name(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsThisTypeOrIdent {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::TsThisType(a), Self::TsThisType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsThisTypeOrIdent {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TsThisType(it) => it.hash_ignore_span(ast, state),
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsTypeQuery {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr_name(ast)
+            .eq_ignore_span(ast, &other.expr_name(other_ast), other_ast)
+            && self
+                .type_args(ast)
+                .eq_ignore_span(ast, &other.type_args(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypeQuery {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr_name(ast).hash_ignore_span(ast, state);
+        self.type_args(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTypeQueryExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::TsEntityName(a), Self::TsEntityName(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Import(a), Self::Import(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsTypeQueryExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TsEntityName(it) => it.hash_ignore_span(ast, state),
+            Self::Import(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsImportCallOptions {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.with(ast)
+            .eq_ignore_span(ast, &other.with(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsImportCallOptions {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.with(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsImportType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.arg(ast)
+            .eq_ignore_span(ast, &other.arg(other_ast), other_ast)
+            && self
+                .qualifier(ast)
+                .eq_ignore_span(ast, &other.qualifier(other_ast), other_ast)
+            && self
+                .type_args(ast)
+                .eq_ignore_span(ast, &other.type_args(other_ast), other_ast)
+            && self
+                .attributes(ast)
+                .eq_ignore_span(ast, &other.attributes(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsImportType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.arg(ast).hash_ignore_span(ast, state);
+        self.qualifier(ast).hash_ignore_span(ast, state);
+        self.type_args(ast).hash_ignore_span(ast, state);
+        self.attributes(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTypeLit {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.members(ast)
+            .eq_ignore_span(ast, &other.members(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypeLit {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.members(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsArrayType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.elem_type(ast)
+            .eq_ignore_span(ast, &other.elem_type(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsArrayType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.elem_type(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTupleType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.elem_types(ast)
+            .eq_ignore_span(ast, &other.elem_types(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTupleType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.elem_types(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTupleElement {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.label(ast)
+            .eq_ignore_span(ast, &other.label(other_ast), other_ast)
+            && self
+                .ty(ast)
+                .eq_ignore_span(ast, &other.ty(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTupleElement {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.label(ast).hash_ignore_span(ast, state);
+        self.ty(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsOptionalType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.type_ann(ast)
+            .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsOptionalType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsRestType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.type_ann(ast)
+            .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsRestType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsUnionOrIntersectionType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::TsUnionType(a), Self::TsUnionType(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsIntersectionType(a), Self::TsIntersectionType(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsUnionOrIntersectionType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TsUnionType(it) => it.hash_ignore_span(ast, state),
+            Self::TsIntersectionType(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsUnionType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.types(ast)
+            .eq_ignore_span(ast, &other.types(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsUnionType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.types(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsIntersectionType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.types(ast)
+            .eq_ignore_span(ast, &other.types(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsIntersectionType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.types(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsConditionalType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.check_type(ast)
+            .eq_ignore_span(ast, &other.check_type(other_ast), other_ast)
+            && self
+                .extends_type(ast)
+                .eq_ignore_span(ast, &other.extends_type(other_ast), other_ast)
+            && self
+                .true_type(ast)
+                .eq_ignore_span(ast, &other.true_type(other_ast), other_ast)
+            && self
+                .false_type(ast)
+                .eq_ignore_span(ast, &other.false_type(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsConditionalType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.check_type(ast).hash_ignore_span(ast, state);
+        self.extends_type(ast).hash_ignore_span(ast, state);
+        self.true_type(ast).hash_ignore_span(ast, state);
+        self.false_type(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsInferType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.type_param(ast)
+            .eq_ignore_span(ast, &other.type_param(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsInferType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.type_param(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsParenthesizedType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.type_ann(ast)
+            .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsParenthesizedType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTypeOperator {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.op(ast)
+            .eq_ignore_span(ast, &other.op(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypeOperator {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.op(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsIndexedAccessType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.readonly(ast)
+            .eq_ignore_span(ast, &other.readonly(other_ast), other_ast)
+            && self
+                .obj_type(ast)
+                .eq_ignore_span(ast, &other.obj_type(other_ast), other_ast)
+            && self
+                .index_type(ast)
+                .eq_ignore_span(ast, &other.index_type(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsIndexedAccessType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.readonly(ast).hash_ignore_span(ast, state);
+        self.obj_type(ast).hash_ignore_span(ast, state);
+        self.index_type(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsMappedType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.readonly(ast)
+            .eq_ignore_span(ast, &other.readonly(other_ast), other_ast)
+            && self
+                .type_param(ast)
+                .eq_ignore_span(ast, &other.type_param(other_ast), other_ast)
+            && self
+                .name_type(ast)
+                .eq_ignore_span(ast, &other.name_type(other_ast), other_ast)
+            && self
+                .optional(ast)
+                .eq_ignore_span(ast, &other.optional(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsMappedType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.readonly(ast).hash_ignore_span(ast, state);
+        self.type_param(ast).hash_ignore_span(ast, state);
+        self.name_type(ast).hash_ignore_span(ast, state);
+        self.optional(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsLitType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.lit(ast)
+            .eq_ignore_span(ast, &other.lit(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsLitType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.lit(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsLit {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Str(a), Self::Str(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Bool(a), Self::Bool(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::BigInt(a), Self::BigInt(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Tpl(a), Self::Tpl(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsLit {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Number(it) => it.hash_ignore_span(ast, state),
+            Self::Str(it) => it.hash_ignore_span(ast, state),
+            Self::Bool(it) => it.hash_ignore_span(ast, state),
+            Self::BigInt(it) => it.hash_ignore_span(ast, state),
+            Self::Tpl(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsTplLitType {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.types(ast)
+            .eq_ignore_span(ast, &other.types(other_ast), other_ast)
+            && self
+                .quasis(ast)
+                .eq_ignore_span(ast, &other.quasis(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTplLitType {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.types(ast).hash_ignore_span(ast, state);
+        self.quasis(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsInterfaceDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.id(ast)
+            .eq_ignore_span(ast, &other.id(other_ast), other_ast)
+            && self
+                .declare(ast)
+                .eq_ignore_span(ast, &other.declare(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+            && self
+                .extends(ast)
+                .eq_ignore_span(ast, &other.extends(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsInterfaceDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.id(ast).hash_ignore_span(ast, state);
+        self.declare(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+        self.extends(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsInterfaceBody {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.body(ast)
+            .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsInterfaceBody {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsExprWithTypeArgs {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+            && self
+                .type_args(ast)
+                .eq_ignore_span(ast, &other.type_args(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsExprWithTypeArgs {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+        self.type_args(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTypeAliasDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.declare(ast)
+            .eq_ignore_span(ast, &other.declare(other_ast), other_ast)
+            && self
+                .id(ast)
+                .eq_ignore_span(ast, &other.id(other_ast), other_ast)
+            && self
+                .type_params(ast)
+                .eq_ignore_span(ast, &other.type_params(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypeAliasDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.declare(ast).hash_ignore_span(ast, state);
+        self.id(ast).hash_ignore_span(ast, state);
+        self.type_params(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsEnumDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.declare(ast)
+            .eq_ignore_span(ast, &other.declare(other_ast), other_ast)
+            && self
+                .is_const(ast)
+                .eq_ignore_span(ast, &other.is_const(other_ast), other_ast)
+            && self
+                .id(ast)
+                .eq_ignore_span(ast, &other.id(other_ast), other_ast)
+            && self
+                .members(ast)
+                .eq_ignore_span(ast, &other.members(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsEnumDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.declare(ast).hash_ignore_span(ast, state);
+        self.is_const(ast).hash_ignore_span(ast, state);
+        self.id(ast).hash_ignore_span(ast, state);
+        self.members(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsEnumMember {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.id(ast)
+            .eq_ignore_span(ast, &other.id(other_ast), other_ast)
+            && self
+                .init(ast)
+                .eq_ignore_span(ast, &other.init(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsEnumMember {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.id(ast).hash_ignore_span(ast, state);
+        self.init(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsEnumMemberId {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Str(a), Self::Str(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsEnumMemberId {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::Str(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsModuleDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.declare(ast)
+            .eq_ignore_span(ast, &other.declare(other_ast), other_ast)
+            && self
+                .global(ast)
+                .eq_ignore_span(ast, &other.global(other_ast), other_ast)
+            && self
+                .namespace(ast)
+                .eq_ignore_span(ast, &other.namespace(other_ast), other_ast)
+            && self
+                .id(ast)
+                .eq_ignore_span(ast, &other.id(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsModuleDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.declare(ast).hash_ignore_span(ast, state);
+        self.global(ast).hash_ignore_span(ast, state);
+        self.namespace(ast).hash_ignore_span(ast, state);
+        self.id(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsNamespaceBody {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::TsModuleBlock(a), Self::TsModuleBlock(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsNamespaceDecl(a), Self::TsNamespaceDecl(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsNamespaceBody {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TsModuleBlock(it) => it.hash_ignore_span(ast, state),
+            Self::TsNamespaceDecl(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsModuleBlock {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.body(ast)
+            .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsModuleBlock {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsNamespaceDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.declare(ast)
+            .eq_ignore_span(ast, &other.declare(other_ast), other_ast)
+            && self
+                .global(ast)
+                .eq_ignore_span(ast, &other.global(other_ast), other_ast)
+            && self
+                .id(ast)
+                .eq_ignore_span(ast, &other.id(other_ast), other_ast)
+            && self
+                .body(ast)
+                .eq_ignore_span(ast, &other.body(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsNamespaceDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.declare(ast).hash_ignore_span(ast, state);
+        self.global(ast).hash_ignore_span(ast, state);
+        self.id(ast).hash_ignore_span(ast, state);
+        self.body(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsModuleName {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::Ident(a), Self::Ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::Str(a), Self::Str(b)) => a.eq_ignore_span(ast, b, other_ast),
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsModuleName {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Ident(it) => it.hash_ignore_span(ast, state),
+            Self::Str(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsImportEqualsDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.is_export(ast)
+            .eq_ignore_span(ast, &other.is_export(other_ast), other_ast)
+            && self
+                .is_type_only(ast)
+                .eq_ignore_span(ast, &other.is_type_only(other_ast), other_ast)
+            && self
+                .id(ast)
+                .eq_ignore_span(ast, &other.id(other_ast), other_ast)
+            && self
+                .module_ref(ast)
+                .eq_ignore_span(ast, &other.module_ref(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsImportEqualsDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.is_export(ast).hash_ignore_span(ast, state);
+        self.is_type_only(ast).hash_ignore_span(ast, state);
+        self.id(ast).hash_ignore_span(ast, state);
+        self.module_ref(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsModuleRef {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        match (self, other) {
+            (Self::TsEntityName(a), Self::TsEntityName(b)) => a.eq_ignore_span(ast, b, other_ast),
+            (Self::TsExternalModuleRef(a), Self::TsExternalModuleRef(b)) => {
+                a.eq_ignore_span(ast, b, other_ast)
+            }
+            _ => false,
+        }
+    }
+}
+impl HashIgnoreSpan for TsModuleRef {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::TsEntityName(it) => it.hash_ignore_span(ast, state),
+            Self::TsExternalModuleRef(it) => it.hash_ignore_span(ast, state),
+        }
+    }
+}
+impl EqIgnoreSpan for TsExternalModuleRef {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsExternalModuleRef {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsExportAssignment {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsExportAssignment {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsNamespaceExportDecl {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.id(ast)
+            .eq_ignore_span(ast, &other.id(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsNamespaceExportDecl {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.id(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsAsExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsAsExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsTypeAssertion {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsTypeAssertion {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsNonNullExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsNonNullExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsSatisfiesExpr {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+            && self
+                .type_ann(ast)
+                .eq_ignore_span(ast, &other.type_ann(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsSatisfiesExpr {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+        self.type_ann(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsConstAssertion {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsConstAssertion {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+    }
+}
+impl EqIgnoreSpan for TsInstantiation {
+    fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+        self.expr(ast)
+            .eq_ignore_span(ast, &other.expr(other_ast), other_ast)
+            && self
+                .type_args(ast)
+                .eq_ignore_span(ast, &other.type_args(other_ast), other_ast)
+    }
+}
+impl HashIgnoreSpan for TsInstantiation {
+    fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+        self.expr(ast).hash_ignore_span(ast, state);
+        self.type_args(ast).hash_ignore_span(ast, state);
+    }
+}
@@ -11,7 +11,7 @@ impl NodeIdTrait for Program {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::Module => Program::Module({ Module::from_node_id(id, ast) }),
             NodeKind::Script => Program::Script({ Script::from_node_id(id, ast) }),
             _ => unreachable!(),
@@ -21,6 +21,10 @@ impl NodeIdTrait for Program {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::Module | NodeKind::Script)
+    }
 }
 impl NodeIdTrait for Module {
     #[inline]
@@ -29,13 +33,20 @@ impl NodeIdTrait for Module {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Module);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Module);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Module
+    }
+}
+impl StructNode for Module {
+    const KIND: NodeKind = NodeKind::Module;
 }
 impl NodeIdTrait for Script {
     #[inline]
@@ -44,13 +55,20 @@ impl NodeIdTrait for Script {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Script);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Script);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Script
+    }
+}
+impl StructNode for Script {
+    const KIND: NodeKind = NodeKind::Script;
 }
 impl NodeIdTrait for ModuleItem {
     #[inline]
@@ -62,7 +80,7 @@ impl NodeIdTrait for ModuleItem {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::BlockStmt => {
                 ModuleItem::Stmt(Stmt::Block({ BlockStmt::from_node_id(id, ast) }))
             }
@@ -127,6 +145,31 @@ impl NodeIdTrait for ModuleItem {
                 ModuleItem::Stmt(Stmt::Throw({ ThrowStmt::from_node_id(id, ast) }))
             }
             NodeKind::TryStmt => ModuleItem::Stmt(Stmt::Try({ TryStmt::from_node_id(id, ast) })),
+            NodeKind::TsEnumDecl => ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum({
+                TsEnumDecl::from_node_id(id, ast)
+            }))),
+            NodeKind::TsExportAssignment => {
+                ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment({
+                    TsExportAssignment::from_node_id(id, ast)
+                }))
+            }
+            NodeKind::TsImportEqualsDecl => ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals({
+                TsImportEqualsDecl::from_node_id(id, ast)
+            })),
+            NodeKind::TsInterfaceDecl => ModuleItem::Stmt(Stmt::Decl(Decl::TsInterface({
+                TsInterfaceDecl::from_node_id(id, ast)
+            }))),
+            NodeKind::TsModuleDecl => ModuleItem::Stmt(Stmt::Decl(Decl::TsModule({
+                TsModuleDecl::from_node_id(id, ast)
+            }))),
+            NodeKind::TsNamespaceExportDecl => {
+                ModuleItem::ModuleDecl(ModuleDecl::TsNamespaceExport({
+                    TsNamespaceExportDecl::from_node_id(id, ast)
+                }))
+            }
+            NodeKind::TsTypeAliasDecl => ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias({
+                TsTypeAliasDecl::from_node_id(id, ast)
+            }))),
             NodeKind::UsingDecl => ModuleItem::Stmt(Stmt::Decl(Decl::Using({
                 UsingDecl::from_node_id(id, ast)
             }))),
@@ -144,6 +187,47 @@ impl NodeIdTrait for ModuleItem {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::BlockStmt
+                | NodeKind::BreakStmt
+                | NodeKind::ClassDecl
+                | NodeKind::ContinueStmt
+                | NodeKind::DebuggerStmt
+                | NodeKind::DoWhileStmt
+                | NodeKind::EmptyStmt
+                | NodeKind::ExportAll
+                | NodeKind::ExportDecl
+                | NodeKind::ExportDefaultDecl
+                | NodeKind::ExportDefaultExpr
+                | NodeKind::ExprStmt
+                | NodeKind::FnDecl
+                | NodeKind::ForInStmt
+                | NodeKind::ForOfStmt
+                | NodeKind::ForStmt
+                | NodeKind::IfStmt
+                | NodeKind::ImportDecl
+                | NodeKind::LabeledStmt
+                | NodeKind::NamedExport
+                | NodeKind::ReturnStmt
+                | NodeKind::SwitchStmt
+                | NodeKind::ThrowStmt
+                | NodeKind::TryStmt
+                | NodeKind::TsEnumDecl
+                | NodeKind::TsExportAssignment
+                | NodeKind::TsImportEqualsDecl
+                | NodeKind::TsInterfaceDecl
+                | NodeKind::TsModuleDecl
+                | NodeKind::TsNamespaceExportDecl
+                | NodeKind::TsTypeAliasDecl
+                | NodeKind::UsingDecl
+                | NodeKind::VarDecl
+                | NodeKind::WhileStmt
+                | NodeKind::WithStmt
+        )
+    }
 }
 impl NodeIdTrait for ModuleDecl {
     #[inline]
@@ -155,11 +239,14 @@ impl NodeIdTrait for ModuleDecl {
             Self::ExportDefaultDecl(it) => it.node_id(),
             Self::ExportDefaultExpr(it) => it.node_id(),
             Self::ExportAll(it) => it.node_id(),
+            Self::TsImportEquals(it) => it.node_id(),
+            Self::TsExportAssignment(it) => it.node_id(),
+            Self::TsNamespaceExport(it) => it.node_id(),
         }
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ExportAll => ModuleDecl::ExportAll({ ExportAll::from_node_id(id, ast) }),
             NodeKind::ExportDecl => ModuleDecl::ExportDecl({ ExportDecl::from_node_id(id, ast) }),
             NodeKind::ExportDefaultDecl => {
@@ -172,6 +259,15 @@ impl NodeIdTrait for ModuleDecl {
             NodeKind::NamedExport => {
                 ModuleDecl::ExportNamed({ NamedExport::from_node_id(id, ast) })
             }
+            NodeKind::TsExportAssignment => {
+                ModuleDecl::TsExportAssignment({ TsExportAssignment::from_node_id(id, ast) })
+            }
+            NodeKind::TsImportEqualsDecl => {
+                ModuleDecl::TsImportEquals({ TsImportEqualsDecl::from_node_id(id, ast) })
+            }
+            NodeKind::TsNamespaceExportDecl => {
+                ModuleDecl::TsNamespaceExport({ TsNamespaceExportDecl::from_node_id(id, ast) })
+            }
             _ => unreachable!(),
         }
     }
@@ -179,6 +275,21 @@ impl NodeIdTrait for ModuleDecl {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ExportAll
+                | NodeKind::ExportDecl
+                | NodeKind::ExportDefaultDecl
+                | NodeKind::ExportDefaultExpr
+                | NodeKind::ImportDecl
+                | NodeKind::NamedExport
+                | NodeKind::TsExportAssignment
+                | NodeKind::TsImportEqualsDecl
+                | NodeKind::TsNamespaceExportDecl
+        )
+    }
 }
 impl NodeIdTrait for ImportDecl {
     #[inline]
@@ -187,13 +298,20 @@ impl NodeIdTrait for ImportDecl {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ImportDecl);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ImportDecl);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ImportDecl
+    }
+}
+impl StructNode for ImportDecl {
+    const KIND: NodeKind = NodeKind::ImportDecl;
 }
 impl NodeIdTrait for ImportSpecifier {
     #[inline]
@@ -206,7 +324,7 @@ impl NodeIdTrait for ImportSpecifier {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ImportDefaultSpecifier => {
                 ImportSpecifier::Default({ ImportDefaultSpecifier::from_node_id(id, ast) })
             }
@@ -223,6 +341,15 @@ impl NodeIdTrait for ImportSpecifier {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ImportDefaultSpecifier
+                | NodeKind::ImportNamedSpecifier
+                | NodeKind::ImportStarAsSpecifier
+        )
+    }
 }
 impl NodeIdTrait for ImportNamedSpecifier {
     #[inline]
@@ -231,13 +358,20 @@ impl NodeIdTrait for ImportNamedSpecifier {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ImportNamedSpecifier);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ImportNamedSpecifier);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ImportNamedSpecifier
+    }
+}
+impl StructNode for ImportNamedSpecifier {
+    const KIND: NodeKind = NodeKind::ImportNamedSpecifier;
 }
 impl NodeIdTrait for ImportDefaultSpecifier {
     #[inline]
@@ -246,13 +380,20 @@ impl NodeIdTrait for ImportDefaultSpecifier {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ImportDefaultSpecifier);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ImportDefaultSpecifier);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ImportDefaultSpecifier
+    }
+}
+impl StructNode for ImportDefaultSpecifier {
+    const KIND: NodeKind = NodeKind::ImportDefaultSpecifier;
 }
 impl NodeIdTrait for ImportStarAsSpecifier {
     #[inline]
@@ -261,13 +402,20 @@ impl NodeIdTrait for ImportStarAsSpecifier {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ImportStarAsSpecifier);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ImportStarAsSpecifier);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ImportStarAsSpecifier
+    }
+}
+impl StructNode for ImportStarAsSpecifier {
+    const KIND: NodeKind = NodeKind::ImportStarAsSpecifier;
 }
 impl NodeIdTrait for ExportDecl {
     #[inline]
@@ -276,13 +424,20 @@ impl NodeIdTrait for ExportDecl {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ExportDecl);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ExportDecl);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ExportDecl
+    }
+}
+impl StructNode for ExportDecl {
+    const KIND: NodeKind = NodeKind::ExportDecl;
 }
 impl NodeIdTrait for NamedExport {
     #[inline]
@@ -291,13 +446,20 @@ impl NodeIdTrait for NamedExport {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::NamedExport);
+        assert!(ast.nodes.kind(node_id) == NodeKind::NamedExport);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::NamedExport
+    }
+}
+impl StructNode for NamedExport {
+    const KIND: NodeKind = NodeKind::NamedExport;
 }
 impl NodeIdTrait for ExportSpecifier {
     #[inline]
@@ -310,7 +472,7 @@ impl NodeIdTrait for ExportSpecifier {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ExportDefaultSpecifier => {
                 ExportSpecifier::Default({ ExportDefaultSpecifier::from_node_id(id, ast) })
             }
@@ -327,6 +489,15 @@ impl NodeIdTrait for ExportSpecifier {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ExportDefaultSpecifier
+                | NodeKind::ExportNamedSpecifier
+                | NodeKind::ExportNamespaceSpecifier
+        )
+    }
 }
 impl NodeIdTrait for ExportNamespaceSpecifier {
     #[inline]
@@ -335,13 +506,20 @@ impl NodeIdTrait for ExportNamespaceSpecifier {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ExportNamespaceSpecifier);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ExportNamespaceSpecifier);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ExportNamespaceSpecifier
+    }
+}
+impl StructNode for ExportNamespaceSpecifier {
+    const KIND: NodeKind = NodeKind::ExportNamespaceSpecifier;
 }
 impl NodeIdTrait for ModuleExportName {
     #[inline]
@@ -353,7 +531,7 @@ impl NodeIdTrait for ModuleExportName {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::Ident => ModuleExportName::Ident({ Ident::from_node_id(id, ast) }),
             NodeKind::Str => ModuleExportName::Str({ Str::from_node_id(id, ast) }),
             _ => unreachable!(),
@@ -363,6 +541,10 @@ impl NodeIdTrait for ModuleExportName {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::Ident | NodeKind::Str)
+    }
 }
 impl NodeIdTrait for ExportDefaultSpecifier {
     #[inline]
@@ -371,13 +553,20 @@ impl NodeIdTrait for ExportDefaultSpecifier {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ExportDefaultSpecifier);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ExportDefaultSpecifier);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ExportDefaultSpecifier
+    }
+}
+impl StructNode for ExportDefaultSpecifier {
+    const KIND: NodeKind = NodeKind::ExportDefaultSpecifier;
 }
 impl NodeIdTrait for ExportNamedSpecifier {
     #[inline]
@@ -386,13 +575,20 @@ impl NodeIdTrait for ExportNamedSpecifier {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ExportNamedSpecifier);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ExportNamedSpecifier);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ExportNamedSpecifier
+    }
+}
+impl StructNode for ExportNamedSpecifier {
+    const KIND: NodeKind = NodeKind::ExportNamedSpecifier;
 }
 impl NodeIdTrait for ExportDefaultDecl {
     #[inline]
@@ -401,13 +597,20 @@ impl NodeIdTrait for ExportDefaultDecl {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ExportDefaultDecl);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ExportDefaultDecl);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ExportDefaultDecl
+    }
+}
+impl StructNode for ExportDefaultDecl {
+    const KIND: NodeKind = NodeKind::ExportDefaultDecl;
 }
 impl NodeIdTrait for DefaultDecl {
     #[inline]
@@ -415,13 +618,17 @@ impl NodeIdTrait for DefaultDecl {
         match self {
             Self::Class(it) => it.node_id(),
             Self::Fn(it) => it.node_id(),
+            Self::TsInterfaceDecl(it) => it.node_id(),
         }
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ClassExpr => DefaultDecl::Class({ ClassExpr::from_node_id(id, ast) }),
             NodeKind::FnExpr => DefaultDecl::Fn({ FnExpr::from_node_id(id, ast) }),
+            NodeKind::TsInterfaceDecl => {
+                DefaultDecl::TsInterfaceDecl({ TsInterfaceDecl::from_node_id(id, ast) })
+            }
             _ => unreachable!(),
         }
     }
@@ -429,6 +636,13 @@ impl NodeIdTrait for DefaultDecl {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ClassExpr | NodeKind::FnExpr | NodeKind::TsInterfaceDecl
+        )
+    }
 }
 impl NodeIdTrait for ExportDefaultExpr {
     #[inline]
@@ -437,13 +651,20 @@ impl NodeIdTrait for ExportDefaultExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ExportDefaultExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ExportDefaultExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ExportDefaultExpr
+    }
+}
+impl StructNode for ExportDefaultExpr {
+    const KIND: NodeKind = NodeKind::ExportDefaultExpr;
 }
 impl NodeIdTrait for ExportAll {
     #[inline]
@@ -452,13 +673,20 @@ impl NodeIdTrait for ExportAll {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ExportAll);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ExportAll);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ExportAll
+    }
+}
+impl StructNode for ExportAll {
+    const KIND: NodeKind = NodeKind::ExportAll;
 }
 impl NodeIdTrait for BlockStmt {
     #[inline]
@@ -467,13 +695,20 @@ impl NodeIdTrait for BlockStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::BlockStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::BlockStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::BlockStmt
+    }
+}
+impl StructNode for BlockStmt {
+    const KIND: NodeKind = NodeKind::BlockStmt;
 }
 impl NodeIdTrait for Stmt {
     #[inline]
@@ -502,7 +737,7 @@ impl NodeIdTrait for Stmt {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::BlockStmt => Stmt::Block({ BlockStmt::from_node_id(id, ast) }),
             NodeKind::BreakStmt => Stmt::Break({ BreakStmt::from_node_id(id, ast) }),
             NodeKind::ClassDecl => Stmt::Decl(Decl::Class({ ClassDecl::from_node_id(id, ast) })),
@@ -521,6 +756,16 @@ impl NodeIdTrait for Stmt {
             NodeKind::SwitchStmt => Stmt::Switch({ SwitchStmt::from_node_id(id, ast) }),
             NodeKind::ThrowStmt => Stmt::Throw({ ThrowStmt::from_node_id(id, ast) }),
             NodeKind::TryStmt => Stmt::Try({ TryStmt::from_node_id(id, ast) }),
+            NodeKind::TsEnumDecl => Stmt::Decl(Decl::TsEnum({ TsEnumDecl::from_node_id(id, ast) })),
+            NodeKind::TsInterfaceDecl => Stmt::Decl(Decl::TsInterface({
+                TsInterfaceDecl::from_node_id(id, ast)
+            })),
+            NodeKind::TsModuleDecl => {
+                Stmt::Decl(Decl::TsModule({ TsModuleDecl::from_node_id(id, ast) }))
+            }
+            NodeKind::TsTypeAliasDecl => Stmt::Decl(Decl::TsTypeAlias({
+                TsTypeAliasDecl::from_node_id(id, ast)
+            })),
             NodeKind::UsingDecl => Stmt::Decl(Decl::Using({ UsingDecl::from_node_id(id, ast) })),
             NodeKind::VarDecl => Stmt::Decl(Decl::Var({ VarDecl::from_node_id(id, ast) })),
             NodeKind::WhileStmt => Stmt::While({ WhileStmt::from_node_id(id, ast) }),
@@ -532,6 +777,38 @@ impl NodeIdTrait for Stmt {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::BlockStmt
+                | NodeKind::BreakStmt
+                | NodeKind::ClassDecl
+                | NodeKind::ContinueStmt
+                | NodeKind::DebuggerStmt
+                | NodeKind::DoWhileStmt
+                | NodeKind::EmptyStmt
+                | NodeKind::ExprStmt
+                | NodeKind::FnDecl
+                | NodeKind::ForInStmt
+                | NodeKind::ForOfStmt
+                | NodeKind::ForStmt
+                | NodeKind::IfStmt
+                | NodeKind::LabeledStmt
+                | NodeKind::ReturnStmt
+                | NodeKind::SwitchStmt
+                | NodeKind::ThrowStmt
+                | NodeKind::TryStmt
+                | NodeKind::TsEnumDecl
+                | NodeKind::TsInterfaceDecl
+                | NodeKind::TsModuleDecl
+                | NodeKind::TsTypeAliasDecl
+                | NodeKind::UsingDecl
+                | NodeKind::VarDecl
+                | NodeKind::WhileStmt
+                | NodeKind::WithStmt
+        )
+    }
 }
 impl NodeIdTrait for ExprStmt {
     #[inline]
@@ -540,13 +817,20 @@ impl NodeIdTrait for ExprStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ExprStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ExprStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ExprStmt
+    }
+}
+impl StructNode for ExprStmt {
+    const KIND: NodeKind = NodeKind::ExprStmt;
 }
 impl NodeIdTrait for EmptyStmt {
     #[inline]
@@ -555,13 +839,20 @@ impl NodeIdTrait for EmptyStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::EmptyStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::EmptyStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::EmptyStmt
+    }
+}
+impl StructNode for EmptyStmt {
+    const KIND: NodeKind = NodeKind::EmptyStmt;
 }
 impl NodeIdTrait for DebuggerStmt {
     #[inline]
@@ -570,13 +861,20 @@ impl NodeIdTrait for DebuggerStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::DebuggerStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::DebuggerStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::DebuggerStmt
+    }
+}
+impl StructNode for DebuggerStmt {
+    const KIND: NodeKind = NodeKind::DebuggerStmt;
 }
 impl NodeIdTrait for WithStmt {
     #[inline]
@@ -585,13 +883,20 @@ impl NodeIdTrait for WithStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::WithStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::WithStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::WithStmt
+    }
+}
+impl StructNode for WithStmt {
+    const KIND: NodeKind = NodeKind::WithStmt;
 }
 impl NodeIdTrait for ReturnStmt {
     #[inline]
@@ -600,13 +905,20 @@ impl NodeIdTrait for ReturnStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ReturnStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ReturnStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ReturnStmt
+    }
+}
+impl StructNode for ReturnStmt {
+    const KIND: NodeKind = NodeKind::ReturnStmt;
 }
 impl NodeIdTrait for LabeledStmt {
     #[inline]
@@ -615,13 +927,20 @@ impl NodeIdTrait for LabeledStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::LabeledStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::LabeledStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::LabeledStmt
+    }
+}
+impl StructNode for LabeledStmt {
+    const KIND: NodeKind = NodeKind::LabeledStmt;
 }
 impl NodeIdTrait for BreakStmt {
     #[inline]
@@ -630,13 +949,20 @@ impl NodeIdTrait for BreakStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::BreakStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::BreakStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::BreakStmt
+    }
+}
+impl StructNode for BreakStmt {
+    const KIND: NodeKind = NodeKind::BreakStmt;
 }
 impl NodeIdTrait for ContinueStmt {
     #[inline]
@@ -645,13 +971,20 @@ impl NodeIdTrait for ContinueStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ContinueStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ContinueStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ContinueStmt
+    }
+}
+impl StructNode for ContinueStmt {
+    const KIND: NodeKind = NodeKind::ContinueStmt;
 }
 impl NodeIdTrait for IfStmt {
     #[inline]
@@ -660,13 +993,20 @@ impl NodeIdTrait for IfStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::IfStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::IfStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::IfStmt
+    }
+}
+impl StructNode for IfStmt {
+    const KIND: NodeKind = NodeKind::IfStmt;
 }
 impl NodeIdTrait for SwitchStmt {
     #[inline]
@@ -675,13 +1015,20 @@ impl NodeIdTrait for SwitchStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::SwitchStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::SwitchStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::SwitchStmt
+    }
+}
+impl StructNode for SwitchStmt {
+    const KIND: NodeKind = NodeKind::SwitchStmt;
 }
 impl NodeIdTrait for ThrowStmt {
     #[inline]
@@ -690,13 +1037,20 @@ impl NodeIdTrait for ThrowStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ThrowStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ThrowStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ThrowStmt
+    }
+}
+impl StructNode for ThrowStmt {
+    const KIND: NodeKind = NodeKind::ThrowStmt;
 }
 impl NodeIdTrait for TryStmt {
     #[inline]
@@ -705,13 +1059,20 @@ impl NodeIdTrait for TryStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::TryStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::TryStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TryStmt
+    }
+}
+impl StructNode for TryStmt {
+    const KIND: NodeKind = NodeKind::TryStmt;
 }
 impl NodeIdTrait for WhileStmt {
     #[inline]
@@ -720,13 +1081,20 @@ impl NodeIdTrait for WhileStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::WhileStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::WhileStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::WhileStmt
+    }
+}
+impl StructNode for WhileStmt {
+    const KIND: NodeKind = NodeKind::WhileStmt;
 }
 impl NodeIdTrait for DoWhileStmt {
     #[inline]
@@ -735,13 +1103,20 @@ impl NodeIdTrait for DoWhileStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::DoWhileStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::DoWhileStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::DoWhileStmt
+    }
+}
+impl StructNode for DoWhileStmt {
+    const KIND: NodeKind = NodeKind::DoWhileStmt;
 }
 impl NodeIdTrait for ForStmt {
     #[inline]
@@ -750,13 +1125,20 @@ impl NodeIdTrait for ForStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ForStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ForStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ForStmt
+    }
+}
+impl StructNode for ForStmt {
+    const KIND: NodeKind = NodeKind::ForStmt;
 }
 impl NodeIdTrait for ForInStmt {
     #[inline]
@@ -765,13 +1147,20 @@ impl NodeIdTrait for ForInStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ForInStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ForInStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ForInStmt
+    }
+}
+impl StructNode for ForInStmt {
+    const KIND: NodeKind = NodeKind::ForInStmt;
 }
 impl NodeIdTrait for ForOfStmt {
     #[inline]
@@ -780,13 +1169,20 @@ impl NodeIdTrait for ForOfStmt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ForOfStmt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ForOfStmt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ForOfStmt
+    }
+}
+impl StructNode for ForOfStmt {
+    const KIND: NodeKind = NodeKind::ForOfStmt;
 }
 impl NodeIdTrait for SwitchCase {
     #[inline]
@@ -795,13 +1191,20 @@ impl NodeIdTrait for SwitchCase {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::SwitchCase);
+        assert!(ast.nodes.kind(node_id) == NodeKind::SwitchCase);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::SwitchCase
+    }
+}
+impl StructNode for SwitchCase {
+    const KIND: NodeKind = NodeKind::SwitchCase;
 }
 impl NodeIdTrait for CatchClause {
     #[inline]
@@ -810,13 +1213,20 @@ impl NodeIdTrait for CatchClause {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::CatchClause);
+        assert!(ast.nodes.kind(node_id) == NodeKind::CatchClause);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::CatchClause
+    }
+}
+impl StructNode for CatchClause {
+    const KIND: NodeKind = NodeKind::CatchClause;
 }
 impl NodeIdTrait for ForHead {
     #[inline]
@@ -829,7 +1239,7 @@ impl NodeIdTrait for ForHead {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ArrayLit => {
                 ForHead::Pat(Pat::Expr(Expr::Array({ ArrayLit::from_node_id(id, ast) })))
             }
@@ -935,6 +1345,24 @@ impl NodeIdTrait for ForHead {
                 ForHead::Pat(Pat::Expr(Expr::This({ ThisExpr::from_node_id(id, ast) })))
             }
             NodeKind::Tpl => ForHead::Pat(Pat::Expr(Expr::Tpl({ Tpl::from_node_id(id, ast) }))),
+            NodeKind::TsAsExpr => {
+                ForHead::Pat(Pat::Expr(Expr::TsAs({ TsAsExpr::from_node_id(id, ast) })))
+            }
+            NodeKind::TsConstAssertion => ForHead::Pat(Pat::Expr(Expr::TsConstAssertion({
+                TsConstAssertion::from_node_id(id, ast)
+            }))),
+            NodeKind::TsInstantiation => ForHead::Pat(Pat::Expr(Expr::TsInstantiation({
+                TsInstantiation::from_node_id(id, ast)
+            }))),
+            NodeKind::TsNonNullExpr => ForHead::Pat(Pat::Expr(Expr::TsNonNull({
+                TsNonNullExpr::from_node_id(id, ast)
+            }))),
+            NodeKind::TsSatisfiesExpr => ForHead::Pat(Pat::Expr(Expr::TsSatisfies({
+                TsSatisfiesExpr::from_node_id(id, ast)
+            }))),
+            NodeKind::TsTypeAssertion => ForHead::Pat(Pat::Expr(Expr::TsTypeAssertion({
+                TsTypeAssertion::from_node_id(id, ast)
+            }))),
             NodeKind::UnaryExpr => {
                 ForHead::Pat(Pat::Expr(Expr::Unary({ UnaryExpr::from_node_id(id, ast) })))
             }
@@ -953,6 +1381,62 @@ impl NodeIdTrait for ForHead {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ArrayLit
+                | NodeKind::ArrayPat
+                | NodeKind::ArrowExpr
+                | NodeKind::AssignExpr
+                | NodeKind::AssignPat
+                | NodeKind::AwaitExpr
+                | NodeKind::BigInt
+                | NodeKind::BinExpr
+                | NodeKind::BindingIdent
+                | NodeKind::Bool
+                | NodeKind::CallExpr
+                | NodeKind::ClassExpr
+                | NodeKind::CondExpr
+                | NodeKind::FnExpr
+                | NodeKind::Ident
+                | NodeKind::Invalid
+                | NodeKind::JSXElement
+                | NodeKind::JSXEmptyExpr
+                | NodeKind::JSXFragment
+                | NodeKind::JSXMemberExpr
+                | NodeKind::JSXNamespacedName
+                | NodeKind::MemberExpr
+                | NodeKind::MetaPropExpr
+                | NodeKind::NewExpr
+                | NodeKind::Null
+                | NodeKind::Number
+                | NodeKind::ObjectLit
+                | NodeKind::ObjectPat
+                | NodeKind::OptChainExpr
+                | NodeKind::ParenExpr
+                | NodeKind::PrivateName
+                | NodeKind::Regex
+                | NodeKind::RestPat
+                | NodeKind::SeqExpr
+                | NodeKind::Str
+                | NodeKind::SuperPropExpr
+                | NodeKind::TaggedTpl
+                | NodeKind::ThisExpr
+                | NodeKind::Tpl
+                | NodeKind::TsAsExpr
+                | NodeKind::TsConstAssertion
+                | NodeKind::TsInstantiation
+                | NodeKind::TsNonNullExpr
+                | NodeKind::TsSatisfiesExpr
+                | NodeKind::TsTypeAssertion
+                | NodeKind::UnaryExpr
+                | NodeKind::UpdateExpr
+                | NodeKind::UsingDecl
+                | NodeKind::VarDecl
+                | NodeKind::YieldExpr
+        )
+    }
 }
 impl NodeIdTrait for VarDeclOrExpr {
     #[inline]
@@ -964,7 +1448,7 @@ impl NodeIdTrait for VarDeclOrExpr {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ArrayLit => {
                 VarDeclOrExpr::Expr(Expr::Array({ ArrayLit::from_node_id(id, ast) }))
             }
@@ -1055,6 +1539,24 @@ impl NodeIdTrait for VarDeclOrExpr {
                 VarDeclOrExpr::Expr(Expr::This({ ThisExpr::from_node_id(id, ast) }))
             }
             NodeKind::Tpl => VarDeclOrExpr::Expr(Expr::Tpl({ Tpl::from_node_id(id, ast) })),
+            NodeKind::TsAsExpr => {
+                VarDeclOrExpr::Expr(Expr::TsAs({ TsAsExpr::from_node_id(id, ast) }))
+            }
+            NodeKind::TsConstAssertion => VarDeclOrExpr::Expr(Expr::TsConstAssertion({
+                TsConstAssertion::from_node_id(id, ast)
+            })),
+            NodeKind::TsInstantiation => VarDeclOrExpr::Expr(Expr::TsInstantiation({
+                TsInstantiation::from_node_id(id, ast)
+            })),
+            NodeKind::TsNonNullExpr => {
+                VarDeclOrExpr::Expr(Expr::TsNonNull({ TsNonNullExpr::from_node_id(id, ast) }))
+            }
+            NodeKind::TsSatisfiesExpr => VarDeclOrExpr::Expr(Expr::TsSatisfies({
+                TsSatisfiesExpr::from_node_id(id, ast)
+            })),
+            NodeKind::TsTypeAssertion => VarDeclOrExpr::Expr(Expr::TsTypeAssertion({
+                TsTypeAssertion::from_node_id(id, ast)
+            })),
             NodeKind::UnaryExpr => {
                 VarDeclOrExpr::Expr(Expr::Unary({ UnaryExpr::from_node_id(id, ast) }))
             }
@@ -1072,6 +1574,56 @@ impl NodeIdTrait for VarDeclOrExpr {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ArrayLit
+                | NodeKind::ArrowExpr
+                | NodeKind::AssignExpr
+                | NodeKind::AwaitExpr
+                | NodeKind::BigInt
+                | NodeKind::BinExpr
+                | NodeKind::Bool
+                | NodeKind::CallExpr
+                | NodeKind::ClassExpr
+                | NodeKind::CondExpr
+                | NodeKind::FnExpr
+                | NodeKind::Ident
+                | NodeKind::Invalid
+                | NodeKind::JSXElement
+                | NodeKind::JSXEmptyExpr
+                | NodeKind::JSXFragment
+                | NodeKind::JSXMemberExpr
+                | NodeKind::JSXNamespacedName
+                | NodeKind::MemberExpr
+                | NodeKind::MetaPropExpr
+                | NodeKind::NewExpr
+                | NodeKind::Null
+                | NodeKind::Number
+                | NodeKind::ObjectLit
+                | NodeKind::OptChainExpr
+                | NodeKind::ParenExpr
+                | NodeKind::PrivateName
+                | NodeKind::Regex
+                | NodeKind::SeqExpr
+                | NodeKind::Str
+                | NodeKind::SuperPropExpr
+                | NodeKind::TaggedTpl
+                | NodeKind::ThisExpr
+                | NodeKind::Tpl
+                | NodeKind::TsAsExpr
+                | NodeKind::TsConstAssertion
+                | NodeKind::TsInstantiation
+                | NodeKind::TsNonNullExpr
+                | NodeKind::TsSatisfiesExpr
+                | NodeKind::TsTypeAssertion
+                | NodeKind::UnaryExpr
+                | NodeKind::UpdateExpr
+                | NodeKind::VarDecl
+                | NodeKind::YieldExpr
+        )
+    }
 }
 impl NodeIdTrait for Decl {
     #[inline]
@@ -1081,13 +1633,25 @@ impl NodeIdTrait for Decl {
             Self::Fn(it) => it.node_id(),
             Self::Var(it) => it.node_id(),
             Self::Using(it) => it.node_id(),
+            Self::TsInterface(it) => it.node_id(),
+            Self::TsTypeAlias(it) => it.node_id(),
+            Self::TsEnum(it) => it.node_id(),
+            Self::TsModule(it) => it.node_id(),
         }
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ClassDecl => Decl::Class({ ClassDecl::from_node_id(id, ast) }),
             NodeKind::FnDecl => Decl::Fn({ FnDecl::from_node_id(id, ast) }),
+            NodeKind::TsEnumDecl => Decl::TsEnum({ TsEnumDecl::from_node_id(id, ast) }),
+            NodeKind::TsInterfaceDecl => {
+                Decl::TsInterface({ TsInterfaceDecl::from_node_id(id, ast) })
+            }
+            NodeKind::TsModuleDecl => Decl::TsModule({ TsModuleDecl::from_node_id(id, ast) }),
+            NodeKind::TsTypeAliasDecl => {
+                Decl::TsTypeAlias({ TsTypeAliasDecl::from_node_id(id, ast) })
+            }
             NodeKind::UsingDecl => Decl::Using({ UsingDecl::from_node_id(id, ast) }),
             NodeKind::VarDecl => Decl::Var({ VarDecl::from_node_id(id, ast) }),
             _ => unreachable!(),
@@ -1097,6 +1661,20 @@ impl NodeIdTrait for Decl {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ClassDecl
+                | NodeKind::FnDecl
+                | NodeKind::TsEnumDecl
+                | NodeKind::TsInterfaceDecl
+                | NodeKind::TsModuleDecl
+                | NodeKind::TsTypeAliasDecl
+                | NodeKind::UsingDecl
+                | NodeKind::VarDecl
+        )
+    }
 }
 impl NodeIdTrait for FnDecl {
     #[inline]
@@ -1105,13 +1683,20 @@ impl NodeIdTrait for FnDecl {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::FnDecl);
+        assert!(ast.nodes.kind(node_id) == NodeKind::FnDecl);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::FnDecl
+    }
+}
+impl StructNode for FnDecl {
+    const KIND: NodeKind = NodeKind::FnDecl;
 }
 impl NodeIdTrait for ClassDecl {
     #[inline]
@@ -1120,13 +1705,20 @@ impl NodeIdTrait for ClassDecl {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ClassDecl);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ClassDecl);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ClassDecl
+    }
+}
+impl StructNode for ClassDecl {
+    const KIND: NodeKind = NodeKind::ClassDecl;
 }
 impl NodeIdTrait for VarDecl {
     #[inline]
@@ -1135,13 +1727,20 @@ impl NodeIdTrait for VarDecl {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::VarDecl);
+        assert!(ast.nodes.kind(node_id) == NodeKind::VarDecl);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::VarDecl
+    }
+}
+impl StructNode for VarDecl {
+    const KIND: NodeKind = NodeKind::VarDecl;
 }
 impl NodeIdTrait for VarDeclarator {
     #[inline]
@@ -1150,13 +1749,20 @@ impl NodeIdTrait for VarDeclarator {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::VarDeclarator);
+        assert!(ast.nodes.kind(node_id) == NodeKind::VarDeclarator);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::VarDeclarator
+    }
+}
+impl StructNode for VarDeclarator {
+    const KIND: NodeKind = NodeKind::VarDeclarator;
 }
 impl NodeIdTrait for UsingDecl {
     #[inline]
@@ -1165,13 +1771,20 @@ impl NodeIdTrait for UsingDecl {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::UsingDecl);
+        assert!(ast.nodes.kind(node_id) == NodeKind::UsingDecl);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::UsingDecl
+    }
+}
+impl StructNode for UsingDecl {
+    const KIND: NodeKind = NodeKind::UsingDecl;
 }
 impl NodeIdTrait for Expr {
     #[inline]
@@ -1206,6 +1819,12 @@ impl NodeIdTrait for Expr {
             Self::JSXEmpty(it) => it.node_id(),
             Self::JSXElement(it) => it.node_id(),
             Self::JSXFragment(it) => it.node_id(),
+            Self::TsTypeAssertion(it) => it.node_id(),
+            Self::TsConstAssertion(it) => it.node_id(),
+            Self::TsNonNull(it) => it.node_id(),
+            Self::TsAs(it) => it.node_id(),
+            Self::TsInstantiation(it) => it.node_id(),
+            Self::TsSatisfies(it) => it.node_id(),
             Self::PrivateName(it) => it.node_id(),
             Self::OptChain(it) => it.node_id(),
             Self::Invalid(it) => it.node_id(),
@@ -1213,7 +1832,7 @@ impl NodeIdTrait for Expr {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ArrayLit => Expr::Array({ ArrayLit::from_node_id(id, ast) }),
             NodeKind::ArrowExpr => Expr::Arrow({ ArrowExpr::from_node_id(id, ast) }),
             NodeKind::AssignExpr => Expr::Assign({ AssignExpr::from_node_id(id, ast) }),
@@ -1250,6 +1869,20 @@ impl NodeIdTrait for Expr {
             NodeKind::TaggedTpl => Expr::TaggedTpl({ TaggedTpl::from_node_id(id, ast) }),
             NodeKind::ThisExpr => Expr::This({ ThisExpr::from_node_id(id, ast) }),
             NodeKind::Tpl => Expr::Tpl({ Tpl::from_node_id(id, ast) }),
+            NodeKind::TsAsExpr => Expr::TsAs({ TsAsExpr::from_node_id(id, ast) }),
+            NodeKind::TsConstAssertion => {
+                Expr::TsConstAssertion({ TsConstAssertion::from_node_id(id, ast) })
+            }
+            NodeKind::TsInstantiation => {
+                Expr::TsInstantiation({ TsInstantiation::from_node_id(id, ast) })
+            }
+            NodeKind::TsNonNullExpr => Expr::TsNonNull({ TsNonNullExpr::from_node_id(id, ast) }),
+            NodeKind::TsSatisfiesExpr => {
+                Expr::TsSatisfies({ TsSatisfiesExpr::from_node_id(id, ast) })
+            }
+            NodeKind::TsTypeAssertion => {
+                Expr::TsTypeAssertion({ TsTypeAssertion::from_node_id(id, ast) })
+            }
             NodeKind::UnaryExpr => Expr::Unary({ UnaryExpr::from_node_id(id, ast) }),
             NodeKind::UpdateExpr => Expr::Update({ UpdateExpr::from_node_id(id, ast) }),
             NodeKind::YieldExpr => Expr::Yield({ YieldExpr::from_node_id(id, ast) }),
@@ -1260,6 +1893,55 @@ impl NodeIdTrait for Expr {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ArrayLit
+                | NodeKind::ArrowExpr
+                | NodeKind::AssignExpr
+                | NodeKind::AwaitExpr
+                | NodeKind::BigInt
+                | NodeKind::BinExpr
+                | NodeKind::Bool
+                | NodeKind::CallExpr
+                | NodeKind::ClassExpr
+                | NodeKind::CondExpr
+                | NodeKind::FnExpr
+                | NodeKind::Ident
+                | NodeKind::Invalid
+                | NodeKind::JSXElement
+                | NodeKind::JSXEmptyExpr
+                | NodeKind::JSXFragment
+                | NodeKind::JSXMemberExpr
+                | NodeKind::JSXNamespacedName
+                | NodeKind::MemberExpr
+                | NodeKind::MetaPropExpr
+                | NodeKind::NewExpr
+                | NodeKind::Null
+                | NodeKind::Number
+                | NodeKind::ObjectLit
+                | NodeKind::OptChainExpr
+                | NodeKind::ParenExpr
+                | NodeKind::PrivateName
+                | NodeKind::Regex
+                | NodeKind::SeqExpr
+                | NodeKind::Str
+                | NodeKind::SuperPropExpr
+                | NodeKind::TaggedTpl
+                | NodeKind::ThisExpr
+                | NodeKind::Tpl
+                | NodeKind::TsAsExpr
+                | NodeKind::TsConstAssertion
+                | NodeKind::TsInstantiation
+                | NodeKind::TsNonNullExpr
+                | NodeKind::TsSatisfiesExpr
+                | NodeKind::TsTypeAssertion
+                | NodeKind::UnaryExpr
+                | NodeKind::UpdateExpr
+                | NodeKind::YieldExpr
+        )
+    }
 }
 impl NodeIdTrait for ThisExpr {
     #[inline]
@@ -1268,13 +1950,20 @@ impl NodeIdTrait for ThisExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ThisExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ThisExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ThisExpr
+    }
+}
+impl StructNode for ThisExpr {
+    const KIND: NodeKind = NodeKind::ThisExpr;
 }
 impl NodeIdTrait for ArrayLit {
     #[inline]
@@ -1283,13 +1972,20 @@ impl NodeIdTrait for ArrayLit {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ArrayLit);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ArrayLit);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ArrayLit
+    }
+}
+impl StructNode for ArrayLit {
+    const KIND: NodeKind = NodeKind::ArrayLit;
 }
 impl NodeIdTrait for ObjectLit {
     #[inline]
@@ -1298,13 +1994,20 @@ impl NodeIdTrait for ObjectLit {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ObjectLit);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ObjectLit);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ObjectLit
+    }
+}
+impl StructNode for ObjectLit {
+    const KIND: NodeKind = NodeKind::ObjectLit;
 }
 impl NodeIdTrait for PropOrSpread {
     #[inline]
@@ -1316,7 +2019,7 @@ impl NodeIdTrait for PropOrSpread {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::AssignProp => {
                 PropOrSpread::Prop(Prop::Assign({ AssignProp::from_node_id(id, ast) }))
             }
@@ -1345,6 +2048,19 @@ impl NodeIdTrait for PropOrSpread {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::AssignProp
+                | NodeKind::GetterProp
+                | NodeKind::Ident
+                | NodeKind::KeyValueProp
+                | NodeKind::MethodProp
+                | NodeKind::SetterProp
+                | NodeKind::SpreadElement
+        )
+    }
 }
 impl NodeIdTrait for SpreadElement {
     #[inline]
@@ -1353,13 +2069,20 @@ impl NodeIdTrait for SpreadElement {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::SpreadElement);
+        assert!(ast.nodes.kind(node_id) == NodeKind::SpreadElement);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::SpreadElement
+    }
+}
+impl StructNode for SpreadElement {
+    const KIND: NodeKind = NodeKind::SpreadElement;
 }
 impl NodeIdTrait for UnaryExpr {
     #[inline]
@@ -1368,13 +2091,20 @@ impl NodeIdTrait for UnaryExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::UnaryExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::UnaryExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::UnaryExpr
+    }
+}
+impl StructNode for UnaryExpr {
+    const KIND: NodeKind = NodeKind::UnaryExpr;
 }
 impl NodeIdTrait for UpdateExpr {
     #[inline]
@@ -1383,13 +2113,20 @@ impl NodeIdTrait for UpdateExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::UpdateExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::UpdateExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::UpdateExpr
+    }
+}
+impl StructNode for UpdateExpr {
+    const KIND: NodeKind = NodeKind::UpdateExpr;
 }
 impl NodeIdTrait for BinExpr {
     #[inline]
@@ -1398,13 +2135,20 @@ impl NodeIdTrait for BinExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::BinExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::BinExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::BinExpr
+    }
+}
+impl StructNode for BinExpr {
+    const KIND: NodeKind = NodeKind::BinExpr;
 }
 impl NodeIdTrait for FnExpr {
     #[inline]
@@ -1413,13 +2157,20 @@ impl NodeIdTrait for FnExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::FnExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::FnExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::FnExpr
+    }
+}
+impl StructNode for FnExpr {
+    const KIND: NodeKind = NodeKind::FnExpr;
 }
 impl NodeIdTrait for ClassExpr {
     #[inline]
@@ -1428,13 +2179,20 @@ impl NodeIdTrait for ClassExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ClassExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ClassExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ClassExpr
+    }
+}
+impl StructNode for ClassExpr {
+    const KIND: NodeKind = NodeKind::ClassExpr;
 }
 impl NodeIdTrait for AssignExpr {
     #[inline]
@@ -1443,13 +2201,20 @@ impl NodeIdTrait for AssignExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::AssignExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::AssignExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::AssignExpr
+    }
+}
+impl StructNode for AssignExpr {
+    const KIND: NodeKind = NodeKind::AssignExpr;
 }
 impl NodeIdTrait for MemberExpr {
     #[inline]
@@ -1458,13 +2223,20 @@ impl NodeIdTrait for MemberExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::MemberExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::MemberExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::MemberExpr
+    }
+}
+impl StructNode for MemberExpr {
+    const KIND: NodeKind = NodeKind::MemberExpr;
 }
 impl NodeIdTrait for MemberProp {
     #[inline]
@@ -1477,7 +2249,7 @@ impl NodeIdTrait for MemberProp {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ComputedPropName => {
                 MemberProp::Computed({ ComputedPropName::from_node_id(id, ast) })
             }
@@ -1492,6 +2264,13 @@ impl NodeIdTrait for MemberProp {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ComputedPropName | NodeKind::IdentName | NodeKind::PrivateName
+        )
+    }
 }
 impl NodeIdTrait for SuperPropExpr {
     #[inline]
@@ -1500,13 +2279,20 @@ impl NodeIdTrait for SuperPropExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::SuperPropExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::SuperPropExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::SuperPropExpr
+    }
+}
+impl StructNode for SuperPropExpr {
+    const KIND: NodeKind = NodeKind::SuperPropExpr;
 }
 impl NodeIdTrait for SuperProp {
     #[inline]
@@ -1518,7 +2304,7 @@ impl NodeIdTrait for SuperProp {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ComputedPropName => {
                 SuperProp::Computed({ ComputedPropName::from_node_id(id, ast) })
             }
@@ -1530,6 +2316,10 @@ impl NodeIdTrait for SuperProp {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::ComputedPropName | NodeKind::IdentName)
+    }
 }
 impl NodeIdTrait for CondExpr {
     #[inline]
@@ -1538,13 +2328,20 @@ impl NodeIdTrait for CondExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::CondExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::CondExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::CondExpr
+    }
+}
+impl StructNode for CondExpr {
+    const KIND: NodeKind = NodeKind::CondExpr;
 }
 impl NodeIdTrait for CallExpr {
     #[inline]
@@ -1553,13 +2350,20 @@ impl NodeIdTrait for CallExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::CallExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::CallExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::CallExpr
+    }
+}
+impl StructNode for CallExpr {
+    const KIND: NodeKind = NodeKind::CallExpr;
 }
 impl NodeIdTrait for NewExpr {
     #[inline]
@@ -1568,13 +2372,20 @@ impl NodeIdTrait for NewExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::NewExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::NewExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::NewExpr
+    }
+}
+impl StructNode for NewExpr {
+    const KIND: NodeKind = NodeKind::NewExpr;
 }
 impl NodeIdTrait for SeqExpr {
     #[inline]
@@ -1583,13 +2394,20 @@ impl NodeIdTrait for SeqExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::SeqExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::SeqExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::SeqExpr
+    }
+}
+impl StructNode for SeqExpr {
+    const KIND: NodeKind = NodeKind::SeqExpr;
 }
 impl NodeIdTrait for ArrowExpr {
     #[inline]
@@ -1598,13 +2416,20 @@ impl NodeIdTrait for ArrowExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ArrowExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ArrowExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ArrowExpr
+    }
+}
+impl StructNode for ArrowExpr {
+    const KIND: NodeKind = NodeKind::ArrowExpr;
 }
 impl NodeIdTrait for YieldExpr {
     #[inline]
@@ -1613,13 +2438,20 @@ impl NodeIdTrait for YieldExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::YieldExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::YieldExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::YieldExpr
+    }
+}
+impl StructNode for YieldExpr {
+    const KIND: NodeKind = NodeKind::YieldExpr;
 }
 impl NodeIdTrait for MetaPropExpr {
     #[inline]
@@ -1628,13 +2460,20 @@ impl NodeIdTrait for MetaPropExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::MetaPropExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::MetaPropExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::MetaPropExpr
+    }
+}
+impl StructNode for MetaPropExpr {
+    const KIND: NodeKind = NodeKind::MetaPropExpr;
 }
 impl NodeIdTrait for AwaitExpr {
     #[inline]
@@ -1643,13 +2482,20 @@ impl NodeIdTrait for AwaitExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::AwaitExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::AwaitExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::AwaitExpr
+    }
+}
+impl StructNode for AwaitExpr {
+    const KIND: NodeKind = NodeKind::AwaitExpr;
 }
 impl NodeIdTrait for Tpl {
     #[inline]
@@ -1658,13 +2504,20 @@ impl NodeIdTrait for Tpl {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Tpl);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Tpl);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Tpl
+    }
+}
+impl StructNode for Tpl {
+    const KIND: NodeKind = NodeKind::Tpl;
 }
 impl NodeIdTrait for TaggedTpl {
     #[inline]
@@ -1673,13 +2526,20 @@ impl NodeIdTrait for TaggedTpl {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::TaggedTpl);
+        assert!(ast.nodes.kind(node_id) == NodeKind::TaggedTpl);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TaggedTpl
+    }
+}
+impl StructNode for TaggedTpl {
+    const KIND: NodeKind = NodeKind::TaggedTpl;
 }
 impl NodeIdTrait for TplElement {
     #[inline]
@@ -1688,13 +2548,20 @@ impl NodeIdTrait for TplElement {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::TplElement);
+        assert!(ast.nodes.kind(node_id) == NodeKind::TplElement);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TplElement
+    }
+}
+impl StructNode for TplElement {
+    const KIND: NodeKind = NodeKind::TplElement;
 }
 impl NodeIdTrait for ParenExpr {
     #[inline]
@@ -1703,13 +2570,20 @@ impl NodeIdTrait for ParenExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ParenExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ParenExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ParenExpr
+    }
+}
+impl StructNode for ParenExpr {
+    const KIND: NodeKind = NodeKind::ParenExpr;
 }
 impl NodeIdTrait for Callee {
     #[inline]
@@ -1722,7 +2596,7 @@ impl NodeIdTrait for Callee {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ArrayLit => Callee::Expr(Expr::Array({ ArrayLit::from_node_id(id, ast) })),
             NodeKind::ArrowExpr => Callee::Expr(Expr::Arrow({ ArrowExpr::from_node_id(id, ast) })),
             NodeKind::AssignExpr => {
@@ -1789,6 +2663,22 @@ impl NodeIdTrait for Callee {
             }
             NodeKind::ThisExpr => Callee::Expr(Expr::This({ ThisExpr::from_node_id(id, ast) })),
             NodeKind::Tpl => Callee::Expr(Expr::Tpl({ Tpl::from_node_id(id, ast) })),
+            NodeKind::TsAsExpr => Callee::Expr(Expr::TsAs({ TsAsExpr::from_node_id(id, ast) })),
+            NodeKind::TsConstAssertion => Callee::Expr(Expr::TsConstAssertion({
+                TsConstAssertion::from_node_id(id, ast)
+            })),
+            NodeKind::TsInstantiation => Callee::Expr(Expr::TsInstantiation({
+                TsInstantiation::from_node_id(id, ast)
+            })),
+            NodeKind::TsNonNullExpr => {
+                Callee::Expr(Expr::TsNonNull({ TsNonNullExpr::from_node_id(id, ast) }))
+            }
+            NodeKind::TsSatisfiesExpr => Callee::Expr(Expr::TsSatisfies({
+                TsSatisfiesExpr::from_node_id(id, ast)
+            })),
+            NodeKind::TsTypeAssertion => Callee::Expr(Expr::TsTypeAssertion({
+                TsTypeAssertion::from_node_id(id, ast)
+            })),
             NodeKind::UnaryExpr => Callee::Expr(Expr::Unary({ UnaryExpr::from_node_id(id, ast) })),
             NodeKind::UpdateExpr => {
                 Callee::Expr(Expr::Update({ UpdateExpr::from_node_id(id, ast) }))
@@ -1801,6 +2691,57 @@ impl NodeIdTrait for Callee {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ArrayLit
+                | NodeKind::ArrowExpr
+                | NodeKind::AssignExpr
+                | NodeKind::AwaitExpr
+                | NodeKind::BigInt
+                | NodeKind::BinExpr
+                | NodeKind::Bool
+                | NodeKind::CallExpr
+                | NodeKind::ClassExpr
+                | NodeKind::CondExpr
+                | NodeKind::FnExpr
+                | NodeKind::Ident
+                | NodeKind::Import
+                | NodeKind::Invalid
+                | NodeKind::JSXElement
+                | NodeKind::JSXEmptyExpr
+                | NodeKind::JSXFragment
+                | NodeKind::JSXMemberExpr
+                | NodeKind::JSXNamespacedName
+                | NodeKind::MemberExpr
+                | NodeKind::MetaPropExpr
+                | NodeKind::NewExpr
+                | NodeKind::Null
+                | NodeKind::Number
+                | NodeKind::ObjectLit
+                | NodeKind::OptChainExpr
+                | NodeKind::ParenExpr
+                | NodeKind::PrivateName
+                | NodeKind::Regex
+                | NodeKind::SeqExpr
+                | NodeKind::Str
+                | NodeKind::Super
+                | NodeKind::SuperPropExpr
+                | NodeKind::TaggedTpl
+                | NodeKind::ThisExpr
+                | NodeKind::Tpl
+                | NodeKind::TsAsExpr
+                | NodeKind::TsConstAssertion
+                | NodeKind::TsInstantiation
+                | NodeKind::TsNonNullExpr
+                | NodeKind::TsSatisfiesExpr
+                | NodeKind::TsTypeAssertion
+                | NodeKind::UnaryExpr
+                | NodeKind::UpdateExpr
+                | NodeKind::YieldExpr
+        )
+    }
 }
 impl NodeIdTrait for Super {
     #[inline]
@@ -1809,13 +2750,20 @@ impl NodeIdTrait for Super {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Super);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Super);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Super
+    }
+}
+impl StructNode for Super {
+    const KIND: NodeKind = NodeKind::Super;
 }
 impl NodeIdTrait for Import {
     #[inline]
@@ -1824,13 +2772,20 @@ impl NodeIdTrait for Import {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Import);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Import);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Import
+    }
+}
+impl StructNode for Import {
+    const KIND: NodeKind = NodeKind::Import;
 }
 impl NodeIdTrait for ExprOrSpread {
     #[inline]
@@ -1839,13 +2794,20 @@ impl NodeIdTrait for ExprOrSpread {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ExprOrSpread);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ExprOrSpread);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ExprOrSpread
+    }
+}
+impl StructNode for ExprOrSpread {
+    const KIND: NodeKind = NodeKind::ExprOrSpread;
 }
 impl NodeIdTrait for SpreadDot3Token {
     #[inline]
@@ -1854,13 +2816,20 @@ impl NodeIdTrait for SpreadDot3Token {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::SpreadDot3Token);
+        assert!(ast.nodes.kind(node_id) == NodeKind::SpreadDot3Token);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::SpreadDot3Token
+    }
+}
+impl StructNode for SpreadDot3Token {
+    const KIND: NodeKind = NodeKind::SpreadDot3Token;
 }
 impl NodeIdTrait for BlockStmtOrExpr {
     #[inline]
@@ -1872,7 +2841,7 @@ impl NodeIdTrait for BlockStmtOrExpr {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ArrayLit => {
                 BlockStmtOrExpr::Expr(Expr::Array({ ArrayLit::from_node_id(id, ast) }))
             }
@@ -1970,6 +2939,24 @@ impl NodeIdTrait for BlockStmtOrExpr {
                 BlockStmtOrExpr::Expr(Expr::This({ ThisExpr::from_node_id(id, ast) }))
             }
             NodeKind::Tpl => BlockStmtOrExpr::Expr(Expr::Tpl({ Tpl::from_node_id(id, ast) })),
+            NodeKind::TsAsExpr => {
+                BlockStmtOrExpr::Expr(Expr::TsAs({ TsAsExpr::from_node_id(id, ast) }))
+            }
+            NodeKind::TsConstAssertion => BlockStmtOrExpr::Expr(Expr::TsConstAssertion({
+                TsConstAssertion::from_node_id(id, ast)
+            })),
+            NodeKind::TsInstantiation => BlockStmtOrExpr::Expr(Expr::TsInstantiation({
+                TsInstantiation::from_node_id(id, ast)
+            })),
+            NodeKind::TsNonNullExpr => {
+                BlockStmtOrExpr::Expr(Expr::TsNonNull({ TsNonNullExpr::from_node_id(id, ast) }))
+            }
+            NodeKind::TsSatisfiesExpr => BlockStmtOrExpr::Expr(Expr::TsSatisfies({
+                TsSatisfiesExpr::from_node_id(id, ast)
+            })),
+            NodeKind::TsTypeAssertion => BlockStmtOrExpr::Expr(Expr::TsTypeAssertion({
+                TsTypeAssertion::from_node_id(id, ast)
+            })),
             NodeKind::UnaryExpr => {
                 BlockStmtOrExpr::Expr(Expr::Unary({ UnaryExpr::from_node_id(id, ast) }))
             }
@@ -1986,6 +2973,56 @@ impl NodeIdTrait for BlockStmtOrExpr {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ArrayLit
+                | NodeKind::ArrowExpr
+                | NodeKind::AssignExpr
+                | NodeKind::AwaitExpr
+                | NodeKind::BigInt
+                | NodeKind::BinExpr
+                | NodeKind::BlockStmt
+                | NodeKind::Bool
+                | NodeKind::CallExpr
+                | NodeKind::ClassExpr
+                | NodeKind::CondExpr
+                | NodeKind::FnExpr
+                | NodeKind::Ident
+                | NodeKind::Invalid
+                | NodeKind::JSXElement
+                | NodeKind::JSXEmptyExpr
+                | NodeKind::JSXFragment
+                | NodeKind::JSXMemberExpr
+                | NodeKind::JSXNamespacedName
+                | NodeKind::MemberExpr
+                | NodeKind::MetaPropExpr
+                | NodeKind::NewExpr
+                | NodeKind::Null
+                | NodeKind::Number
+                | NodeKind::ObjectLit
+                | NodeKind::OptChainExpr
+                | NodeKind::ParenExpr
+                | NodeKind::PrivateName
+                | NodeKind::Regex
+                | NodeKind::SeqExpr
+                | NodeKind::Str
+                | NodeKind::SuperPropExpr
+                | NodeKind::TaggedTpl
+                | NodeKind::ThisExpr
+                | NodeKind::Tpl
+                | NodeKind::TsAsExpr
+                | NodeKind::TsConstAssertion
+                | NodeKind::TsInstantiation
+                | NodeKind::TsNonNullExpr
+                | NodeKind::TsSatisfiesExpr
+                | NodeKind::TsTypeAssertion
+                | NodeKind::UnaryExpr
+                | NodeKind::UpdateExpr
+                | NodeKind::YieldExpr
+        )
+    }
 }
 impl NodeIdTrait for AssignTarget {
     #[inline]
@@ -1997,7 +3034,7 @@ impl NodeIdTrait for AssignTarget {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ArrayPat => {
                 AssignTarget::Pat(AssignTargetPat::Array({ ArrayPat::from_node_id(id, ast) }))
             }
@@ -2022,6 +3059,25 @@ impl NodeIdTrait for AssignTarget {
             NodeKind::SuperPropExpr => AssignTarget::Simple(SimpleAssignTarget::SuperProp({
                 SuperPropExpr::from_node_id(id, ast)
             })),
+            NodeKind::TsAsExpr => AssignTarget::Simple(SimpleAssignTarget::TsAs({
+                TsAsExpr::from_node_id(id, ast)
+            })),
+            NodeKind::TsInstantiation => {
+                AssignTarget::Simple(SimpleAssignTarget::TsInstantiation({
+                    TsInstantiation::from_node_id(id, ast)
+                }))
+            }
+            NodeKind::TsNonNullExpr => AssignTarget::Simple(SimpleAssignTarget::TsNonNull({
+                TsNonNullExpr::from_node_id(id, ast)
+            })),
+            NodeKind::TsSatisfiesExpr => AssignTarget::Simple(SimpleAssignTarget::TsSatisfies({
+                TsSatisfiesExpr::from_node_id(id, ast)
+            })),
+            NodeKind::TsTypeAssertion => {
+                AssignTarget::Simple(SimpleAssignTarget::TsTypeAssertion({
+                    TsTypeAssertion::from_node_id(id, ast)
+                }))
+            }
             _ => unreachable!(),
         }
     }
@@ -2029,6 +3085,25 @@ impl NodeIdTrait for AssignTarget {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ArrayPat
+                | NodeKind::BindingIdent
+                | NodeKind::Invalid
+                | NodeKind::MemberExpr
+                | NodeKind::ObjectPat
+                | NodeKind::OptChainExpr
+                | NodeKind::ParenExpr
+                | NodeKind::SuperPropExpr
+                | NodeKind::TsAsExpr
+                | NodeKind::TsInstantiation
+                | NodeKind::TsNonNullExpr
+                | NodeKind::TsSatisfiesExpr
+                | NodeKind::TsTypeAssertion
+        )
+    }
 }
 impl NodeIdTrait for AssignTargetPat {
     #[inline]
@@ -2041,7 +3116,7 @@ impl NodeIdTrait for AssignTargetPat {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ArrayPat => AssignTargetPat::Array({ ArrayPat::from_node_id(id, ast) }),
             NodeKind::Invalid => AssignTargetPat::Invalid({ Invalid::from_node_id(id, ast) }),
             NodeKind::ObjectPat => AssignTargetPat::Object({ ObjectPat::from_node_id(id, ast) }),
@@ -2052,6 +3127,13 @@ impl NodeIdTrait for AssignTargetPat {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ArrayPat | NodeKind::Invalid | NodeKind::ObjectPat
+        )
+    }
 }
 impl NodeIdTrait for SimpleAssignTarget {
     #[inline]
@@ -2062,12 +3144,17 @@ impl NodeIdTrait for SimpleAssignTarget {
             Self::SuperProp(it) => it.node_id(),
             Self::Paren(it) => it.node_id(),
             Self::OptChain(it) => it.node_id(),
+            Self::TsAs(it) => it.node_id(),
+            Self::TsSatisfies(it) => it.node_id(),
+            Self::TsNonNull(it) => it.node_id(),
+            Self::TsTypeAssertion(it) => it.node_id(),
+            Self::TsInstantiation(it) => it.node_id(),
             Self::Invalid(it) => it.node_id(),
         }
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::BindingIdent => {
                 SimpleAssignTarget::Ident({ BindingIdent::from_node_id(id, ast) })
             }
@@ -2082,6 +3169,19 @@ impl NodeIdTrait for SimpleAssignTarget {
             NodeKind::SuperPropExpr => {
                 SimpleAssignTarget::SuperProp({ SuperPropExpr::from_node_id(id, ast) })
             }
+            NodeKind::TsAsExpr => SimpleAssignTarget::TsAs({ TsAsExpr::from_node_id(id, ast) }),
+            NodeKind::TsInstantiation => {
+                SimpleAssignTarget::TsInstantiation({ TsInstantiation::from_node_id(id, ast) })
+            }
+            NodeKind::TsNonNullExpr => {
+                SimpleAssignTarget::TsNonNull({ TsNonNullExpr::from_node_id(id, ast) })
+            }
+            NodeKind::TsSatisfiesExpr => {
+                SimpleAssignTarget::TsSatisfies({ TsSatisfiesExpr::from_node_id(id, ast) })
+            }
+            NodeKind::TsTypeAssertion => {
+                SimpleAssignTarget::TsTypeAssertion({ TsTypeAssertion::from_node_id(id, ast) })
+            }
             _ => unreachable!(),
         }
     }
@@ -2089,6 +3189,23 @@ impl NodeIdTrait for SimpleAssignTarget {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::BindingIdent
+                | NodeKind::Invalid
+                | NodeKind::MemberExpr
+                | NodeKind::OptChainExpr
+                | NodeKind::ParenExpr
+                | NodeKind::SuperPropExpr
+                | NodeKind::TsAsExpr
+                | NodeKind::TsInstantiation
+                | NodeKind::TsNonNullExpr
+                | NodeKind::TsSatisfiesExpr
+                | NodeKind::TsTypeAssertion
+        )
+    }
 }
 impl NodeIdTrait for OptChainExpr {
     #[inline]
@@ -2097,13 +3214,20 @@ impl NodeIdTrait for OptChainExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::OptChainExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::OptChainExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::OptChainExpr
+    }
+}
+impl StructNode for OptChainExpr {
+    const KIND: NodeKind = NodeKind::OptChainExpr;
 }
 impl NodeIdTrait for OptChainBase {
     #[inline]
@@ -2115,7 +3239,7 @@ impl NodeIdTrait for OptChainBase {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::MemberExpr => OptChainBase::Member({ MemberExpr::from_node_id(id, ast) }),
             NodeKind::OptCall => OptChainBase::Call({ OptCall::from_node_id(id, ast) }),
             _ => unreachable!(),
@@ -2125,6 +3249,10 @@ impl NodeIdTrait for OptChainBase {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::MemberExpr | NodeKind::OptCall)
+    }
 }
 impl NodeIdTrait for OptCall {
     #[inline]
@@ -2133,13 +3261,20 @@ impl NodeIdTrait for OptCall {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::OptCall);
+        assert!(ast.nodes.kind(node_id) == NodeKind::OptCall);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::OptCall
+    }
+}
+impl StructNode for OptCall {
+    const KIND: NodeKind = NodeKind::OptCall;
 }
 impl NodeIdTrait for Invalid {
     #[inline]
@@ -2148,13 +3283,20 @@ impl NodeIdTrait for Invalid {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Invalid);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Invalid);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Invalid
+    }
+}
+impl StructNode for Invalid {
+    const KIND: NodeKind = NodeKind::Invalid;
 }
 impl NodeIdTrait for Function {
     #[inline]
@@ -2163,13 +3305,20 @@ impl NodeIdTrait for Function {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Function);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Function);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Function
+    }
+}
+impl StructNode for Function {
+    const KIND: NodeKind = NodeKind::Function;
 }
 impl NodeIdTrait for Param {
     #[inline]
@@ -2178,25 +3327,36 @@ impl NodeIdTrait for Param {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Param);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Param);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Param
+    }
+}
+impl StructNode for Param {
+    const KIND: NodeKind = NodeKind::Param;
 }
 impl NodeIdTrait for ParamOrTsParamProp {
     #[inline]
     fn node_id(&self) -> NodeId {
         match self {
+            Self::TsParamProp(it) => it.node_id(),
             Self::Param(it) => it.node_id(),
         }
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::Param => ParamOrTsParamProp::Param({ Param::from_node_id(id, ast) }),
+            NodeKind::TsParamProp => {
+                ParamOrTsParamProp::TsParamProp({ TsParamProp::from_node_id(id, ast) })
+            }
             _ => unreachable!(),
         }
     }
@@ -2204,6 +3364,10 @@ impl NodeIdTrait for ParamOrTsParamProp {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::Param | NodeKind::TsParamProp)
+    }
 }
 impl NodeIdTrait for Class {
     #[inline]
@@ -2212,13 +3376,20 @@ impl NodeIdTrait for Class {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Class);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Class);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Class
+    }
+}
+impl StructNode for Class {
+    const KIND: NodeKind = NodeKind::Class;
 }
 impl NodeIdTrait for ClassMember {
     #[inline]
@@ -2232,11 +3403,12 @@ impl NodeIdTrait for ClassMember {
             Self::Empty(it) => it.node_id(),
             Self::StaticBlock(it) => it.node_id(),
             Self::AutoAccessor(it) => it.node_id(),
+            Self::TsIndexSignature(it) => it.node_id(),
         }
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::AutoAccessor => {
                 ClassMember::AutoAccessor({ AutoAccessor::from_node_id(id, ast) })
             }
@@ -2255,6 +3427,9 @@ impl NodeIdTrait for ClassMember {
             NodeKind::StaticBlock => {
                 ClassMember::StaticBlock({ StaticBlock::from_node_id(id, ast) })
             }
+            NodeKind::TsIndexSignature => {
+                ClassMember::TsIndexSignature({ TsIndexSignature::from_node_id(id, ast) })
+            }
             _ => unreachable!(),
         }
     }
@@ -2262,6 +3437,21 @@ impl NodeIdTrait for ClassMember {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::AutoAccessor
+                | NodeKind::ClassMethod
+                | NodeKind::ClassProp
+                | NodeKind::Constructor
+                | NodeKind::EmptyStmt
+                | NodeKind::PrivateMethod
+                | NodeKind::PrivateProp
+                | NodeKind::StaticBlock
+                | NodeKind::TsIndexSignature
+        )
+    }
 }
 impl NodeIdTrait for ClassProp {
     #[inline]
@@ -2270,13 +3460,20 @@ impl NodeIdTrait for ClassProp {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ClassProp);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ClassProp);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ClassProp
+    }
+}
+impl StructNode for ClassProp {
+    const KIND: NodeKind = NodeKind::ClassProp;
 }
 impl NodeIdTrait for PrivateProp {
     #[inline]
@@ -2285,13 +3482,20 @@ impl NodeIdTrait for PrivateProp {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::PrivateProp);
+        assert!(ast.nodes.kind(node_id) == NodeKind::PrivateProp);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::PrivateProp
+    }
+}
+impl StructNode for PrivateProp {
+    const KIND: NodeKind = NodeKind::PrivateProp;
 }
 impl NodeIdTrait for ClassMethod {
     #[inline]
@@ -2300,13 +3504,20 @@ impl NodeIdTrait for ClassMethod {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ClassMethod);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ClassMethod);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ClassMethod
+    }
+}
+impl StructNode for ClassMethod {
+    const KIND: NodeKind = NodeKind::ClassMethod;
 }
 impl NodeIdTrait for PrivateMethod {
     #[inline]
@@ -2315,13 +3526,20 @@ impl NodeIdTrait for PrivateMethod {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::PrivateMethod);
+        assert!(ast.nodes.kind(node_id) == NodeKind::PrivateMethod);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::PrivateMethod
+    }
+}
+impl StructNode for PrivateMethod {
+    const KIND: NodeKind = NodeKind::PrivateMethod;
 }
 impl NodeIdTrait for Constructor {
     #[inline]
@@ -2330,13 +3548,20 @@ impl NodeIdTrait for Constructor {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Constructor);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Constructor);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Constructor
+    }
+}
+impl StructNode for Constructor {
+    const KIND: NodeKind = NodeKind::Constructor;
 }
 impl NodeIdTrait for Decorator {
     #[inline]
@@ -2345,13 +3570,20 @@ impl NodeIdTrait for Decorator {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Decorator);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Decorator);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Decorator
+    }
+}
+impl StructNode for Decorator {
+    const KIND: NodeKind = NodeKind::Decorator;
 }
 impl NodeIdTrait for StaticBlock {
     #[inline]
@@ -2360,13 +3592,20 @@ impl NodeIdTrait for StaticBlock {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::StaticBlock);
+        assert!(ast.nodes.kind(node_id) == NodeKind::StaticBlock);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::StaticBlock
+    }
+}
+impl StructNode for StaticBlock {
+    const KIND: NodeKind = NodeKind::StaticBlock;
 }
 impl NodeIdTrait for Key {
     #[inline]
@@ -2378,7 +3617,7 @@ impl NodeIdTrait for Key {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::BigInt => Key::Public(PropName::BigInt({ BigInt::from_node_id(id, ast) })),
             NodeKind::ComputedPropName => Key::Public(PropName::Computed({
                 ComputedPropName::from_node_id(id, ast)
@@ -2396,6 +3635,18 @@ impl NodeIdTrait for Key {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::BigInt
+                | NodeKind::ComputedPropName
+                | NodeKind::IdentName
+                | NodeKind::Number
+                | NodeKind::PrivateName
+                | NodeKind::Str
+        )
+    }
 }
 impl NodeIdTrait for AutoAccessor {
     #[inline]
@@ -2404,13 +3655,20 @@ impl NodeIdTrait for AutoAccessor {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::AutoAccessor);
+        assert!(ast.nodes.kind(node_id) == NodeKind::AutoAccessor);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::AutoAccessor
+    }
+}
+impl StructNode for AutoAccessor {
+    const KIND: NodeKind = NodeKind::AutoAccessor;
 }
 impl NodeIdTrait for Prop {
     #[inline]
@@ -2426,7 +3684,7 @@ impl NodeIdTrait for Prop {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::AssignProp => Prop::Assign({ AssignProp::from_node_id(id, ast) }),
             NodeKind::GetterProp => Prop::Getter({ GetterProp::from_node_id(id, ast) }),
             NodeKind::Ident => Prop::Shorthand({ Ident::from_node_id(id, ast) }),
@@ -2440,6 +3698,18 @@ impl NodeIdTrait for Prop {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::AssignProp
+                | NodeKind::GetterProp
+                | NodeKind::Ident
+                | NodeKind::KeyValueProp
+                | NodeKind::MethodProp
+                | NodeKind::SetterProp
+        )
+    }
 }
 impl NodeIdTrait for KeyValueProp {
     #[inline]
@@ -2448,13 +3718,20 @@ impl NodeIdTrait for KeyValueProp {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::KeyValueProp);
+        assert!(ast.nodes.kind(node_id) == NodeKind::KeyValueProp);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::KeyValueProp
+    }
+}
+impl StructNode for KeyValueProp {
+    const KIND: NodeKind = NodeKind::KeyValueProp;
 }
 impl NodeIdTrait for AssignProp {
     #[inline]
@@ -2463,13 +3740,20 @@ impl NodeIdTrait for AssignProp {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::AssignProp);
+        assert!(ast.nodes.kind(node_id) == NodeKind::AssignProp);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::AssignProp
+    }
+}
+impl StructNode for AssignProp {
+    const KIND: NodeKind = NodeKind::AssignProp;
 }
 impl NodeIdTrait for GetterProp {
     #[inline]
@@ -2478,13 +3762,20 @@ impl NodeIdTrait for GetterProp {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::GetterProp);
+        assert!(ast.nodes.kind(node_id) == NodeKind::GetterProp);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::GetterProp
+    }
+}
+impl StructNode for GetterProp {
+    const KIND: NodeKind = NodeKind::GetterProp;
 }
 impl NodeIdTrait for SetterProp {
     #[inline]
@@ -2493,13 +3784,20 @@ impl NodeIdTrait for SetterProp {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::SetterProp);
+        assert!(ast.nodes.kind(node_id) == NodeKind::SetterProp);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::SetterProp
+    }
+}
+impl StructNode for SetterProp {
+    const KIND: NodeKind = NodeKind::SetterProp;
 }
 impl NodeIdTrait for MethodProp {
     #[inline]
@@ -2508,13 +3806,20 @@ impl NodeIdTrait for MethodProp {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::MethodProp);
+        assert!(ast.nodes.kind(node_id) == NodeKind::MethodProp);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::MethodProp
+    }
+}
+impl StructNode for MethodProp {
+    const KIND: NodeKind = NodeKind::MethodProp;
 }
 impl NodeIdTrait for PropName {
     #[inline]
@@ -2529,7 +3834,7 @@ impl NodeIdTrait for PropName {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::BigInt => PropName::BigInt({ BigInt::from_node_id(id, ast) }),
             NodeKind::ComputedPropName => {
                 PropName::Computed({ ComputedPropName::from_node_id(id, ast) })
@@ -2544,6 +3849,17 @@ impl NodeIdTrait for PropName {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::BigInt
+                | NodeKind::ComputedPropName
+                | NodeKind::IdentName
+                | NodeKind::Number
+                | NodeKind::Str
+        )
+    }
 }
 impl NodeIdTrait for ComputedPropName {
     #[inline]
@@ -2552,13 +3868,20 @@ impl NodeIdTrait for ComputedPropName {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ComputedPropName);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ComputedPropName);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ComputedPropName
+    }
+}
+impl StructNode for ComputedPropName {
+    const KIND: NodeKind = NodeKind::ComputedPropName;
 }
 impl NodeIdTrait for Pat {
     #[inline]
@@ -2575,7 +3898,7 @@ impl NodeIdTrait for Pat {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ArrayLit => Pat::Expr(Expr::Array({ ArrayLit::from_node_id(id, ast) })),
             NodeKind::ArrayPat => Pat::Array({ ArrayPat::from_node_id(id, ast) }),
             NodeKind::ArrowExpr => Pat::Expr(Expr::Arrow({ ArrowExpr::from_node_id(id, ast) })),
@@ -2635,6 +3958,22 @@ impl NodeIdTrait for Pat {
             NodeKind::TaggedTpl => Pat::Expr(Expr::TaggedTpl({ TaggedTpl::from_node_id(id, ast) })),
             NodeKind::ThisExpr => Pat::Expr(Expr::This({ ThisExpr::from_node_id(id, ast) })),
             NodeKind::Tpl => Pat::Expr(Expr::Tpl({ Tpl::from_node_id(id, ast) })),
+            NodeKind::TsAsExpr => Pat::Expr(Expr::TsAs({ TsAsExpr::from_node_id(id, ast) })),
+            NodeKind::TsConstAssertion => Pat::Expr(Expr::TsConstAssertion({
+                TsConstAssertion::from_node_id(id, ast)
+            })),
+            NodeKind::TsInstantiation => Pat::Expr(Expr::TsInstantiation({
+                TsInstantiation::from_node_id(id, ast)
+            })),
+            NodeKind::TsNonNullExpr => {
+                Pat::Expr(Expr::TsNonNull({ TsNonNullExpr::from_node_id(id, ast) }))
+            }
+            NodeKind::TsSatisfiesExpr => Pat::Expr(Expr::TsSatisfies({
+                TsSatisfiesExpr::from_node_id(id, ast)
+            })),
+            NodeKind::TsTypeAssertion => Pat::Expr(Expr::TsTypeAssertion({
+                TsTypeAssertion::from_node_id(id, ast)
+            })),
             NodeKind::UnaryExpr => Pat::Expr(Expr::Unary({ UnaryExpr::from_node_id(id, ast) })),
             NodeKind::UpdateExpr => Pat::Expr(Expr::Update({ UpdateExpr::from_node_id(id, ast) })),
             NodeKind::YieldExpr => Pat::Expr(Expr::Yield({ YieldExpr::from_node_id(id, ast) })),
@@ -2645,6 +3984,60 @@ impl NodeIdTrait for Pat {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ArrayLit
+                | NodeKind::ArrayPat
+                | NodeKind::ArrowExpr
+                | NodeKind::AssignExpr
+                | NodeKind::AssignPat
+                | NodeKind::AwaitExpr
+                | NodeKind::BigInt
+                | NodeKind::BinExpr
+                | NodeKind::BindingIdent
+                | NodeKind::Bool
+                | NodeKind::CallExpr
+                | NodeKind::ClassExpr
+                | NodeKind::CondExpr
+                | NodeKind::FnExpr
+                | NodeKind::Ident
+                | NodeKind::Invalid
+                | NodeKind::JSXElement
+                | NodeKind::JSXEmptyExpr
+                | NodeKind::JSXFragment
+                | NodeKind::JSXMemberExpr
+                | NodeKind::JSXNamespacedName
+                | NodeKind::MemberExpr
+                | NodeKind::MetaPropExpr
+                | NodeKind::NewExpr
+                | NodeKind::Null
+                | NodeKind::Number
+                | NodeKind::ObjectLit
+                | NodeKind::ObjectPat
+                | NodeKind::OptChainExpr
+                | NodeKind::ParenExpr
+                | NodeKind::PrivateName
+                | NodeKind::Regex
+                | NodeKind::RestPat
+                | NodeKind::SeqExpr
+                | NodeKind::Str
+                | NodeKind::SuperPropExpr
+                | NodeKind::TaggedTpl
+                | NodeKind::ThisExpr
+                | NodeKind::Tpl
+                | NodeKind::TsAsExpr
+                | NodeKind::TsConstAssertion
+                | NodeKind::TsInstantiation
+                | NodeKind::TsNonNullExpr
+                | NodeKind::TsSatisfiesExpr
+                | NodeKind::TsTypeAssertion
+                | NodeKind::UnaryExpr
+                | NodeKind::UpdateExpr
+                | NodeKind::YieldExpr
+        )
+    }
 }
 impl NodeIdTrait for ArrayPat {
     #[inline]
@@ -2653,13 +4046,20 @@ impl NodeIdTrait for ArrayPat {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ArrayPat);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ArrayPat);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ArrayPat
+    }
+}
+impl StructNode for ArrayPat {
+    const KIND: NodeKind = NodeKind::ArrayPat;
 }
 impl NodeIdTrait for ObjectPat {
     #[inline]
@@ -2668,13 +4068,20 @@ impl NodeIdTrait for ObjectPat {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::ObjectPat);
+        assert!(ast.nodes.kind(node_id) == NodeKind::ObjectPat);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::ObjectPat
+    }
+}
+impl StructNode for ObjectPat {
+    const KIND: NodeKind = NodeKind::ObjectPat;
 }
 impl NodeIdTrait for AssignPat {
     #[inline]
@@ -2683,13 +4090,20 @@ impl NodeIdTrait for AssignPat {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::AssignPat);
+        assert!(ast.nodes.kind(node_id) == NodeKind::AssignPat);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::AssignPat
+    }
+}
+impl StructNode for AssignPat {
+    const KIND: NodeKind = NodeKind::AssignPat;
 }
 impl NodeIdTrait for RestPat {
     #[inline]
@@ -2698,13 +4112,20 @@ impl NodeIdTrait for RestPat {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::RestPat);
+        assert!(ast.nodes.kind(node_id) == NodeKind::RestPat);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::RestPat
+    }
+}
+impl StructNode for RestPat {
+    const KIND: NodeKind = NodeKind::RestPat;
 }
 impl NodeIdTrait for ObjectPatProp {
     #[inline]
@@ -2717,7 +4138,7 @@ impl NodeIdTrait for ObjectPatProp {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::AssignPatProp => {
                 ObjectPatProp::Assign({ AssignPatProp::from_node_id(id, ast) })
             }
@@ -2732,6 +4153,13 @@ impl NodeIdTrait for ObjectPatProp {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::AssignPatProp | NodeKind::KeyValuePatProp | NodeKind::RestPat
+        )
+    }
 }
 impl NodeIdTrait for KeyValuePatProp {
     #[inline]
@@ -2740,13 +4168,20 @@ impl NodeIdTrait for KeyValuePatProp {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::KeyValuePatProp);
+        assert!(ast.nodes.kind(node_id) == NodeKind::KeyValuePatProp);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::KeyValuePatProp
+    }
+}
+impl StructNode for KeyValuePatProp {
+    const KIND: NodeKind = NodeKind::KeyValuePatProp;
 }
 impl NodeIdTrait for AssignPatProp {
     #[inline]
@@ -2755,13 +4190,20 @@ impl NodeIdTrait for AssignPatProp {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::AssignPatProp);
+        assert!(ast.nodes.kind(node_id) == NodeKind::AssignPatProp);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::AssignPatProp
+    }
+}
+impl StructNode for AssignPatProp {
+    const KIND: NodeKind = NodeKind::AssignPatProp;
 }
 impl NodeIdTrait for Ident {
     #[inline]
@@ -2770,13 +4212,20 @@ impl NodeIdTrait for Ident {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Ident);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Ident);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Ident
+    }
+}
+impl StructNode for Ident {
+    const KIND: NodeKind = NodeKind::Ident;
 }
 impl NodeIdTrait for IdentName {
     #[inline]
@@ -2785,13 +4234,20 @@ impl NodeIdTrait for IdentName {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::IdentName);
+        assert!(ast.nodes.kind(node_id) == NodeKind::IdentName);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::IdentName
+    }
+}
+impl StructNode for IdentName {
+    const KIND: NodeKind = NodeKind::IdentName;
 }
 impl NodeIdTrait for PrivateName {
     #[inline]
@@ -2800,13 +4256,20 @@ impl NodeIdTrait for PrivateName {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::PrivateName);
+        assert!(ast.nodes.kind(node_id) == NodeKind::PrivateName);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::PrivateName
+    }
+}
+impl StructNode for PrivateName {
+    const KIND: NodeKind = NodeKind::PrivateName;
 }
 impl NodeIdTrait for BindingIdent {
     #[inline]
@@ -2815,13 +4278,20 @@ impl NodeIdTrait for BindingIdent {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::BindingIdent);
+        assert!(ast.nodes.kind(node_id) == NodeKind::BindingIdent);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::BindingIdent
+    }
+}
+impl StructNode for BindingIdent {
+    const KIND: NodeKind = NodeKind::BindingIdent;
 }
 impl NodeIdTrait for Lit {
     #[inline]
@@ -2837,7 +4307,7 @@ impl NodeIdTrait for Lit {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::BigInt => Lit::BigInt({ BigInt::from_node_id(id, ast) }),
             NodeKind::Bool => Lit::Bool({ Bool::from_node_id(id, ast) }),
             NodeKind::Null => Lit::Null({ Null::from_node_id(id, ast) }),
@@ -2851,6 +4321,18 @@ impl NodeIdTrait for Lit {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::BigInt
+                | NodeKind::Bool
+                | NodeKind::Null
+                | NodeKind::Number
+                | NodeKind::Regex
+                | NodeKind::Str
+        )
+    }
 }
 impl NodeIdTrait for Str {
     #[inline]
@@ -2859,13 +4341,20 @@ impl NodeIdTrait for Str {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Str);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Str);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Str
+    }
+}
+impl StructNode for Str {
+    const KIND: NodeKind = NodeKind::Str;
 }
 impl NodeIdTrait for Bool {
     #[inline]
@@ -2874,13 +4363,20 @@ impl NodeIdTrait for Bool {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Bool);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Bool);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Bool
+    }
+}
+impl StructNode for Bool {
+    const KIND: NodeKind = NodeKind::Bool;
 }
 impl NodeIdTrait for Null {
     #[inline]
@@ -2889,13 +4385,20 @@ impl NodeIdTrait for Null {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Null);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Null);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Null
+    }
+}
+impl StructNode for Null {
+    const KIND: NodeKind = NodeKind::Null;
 }
 impl NodeIdTrait for Number {
     #[inline]
@@ -2904,13 +4407,20 @@ impl NodeIdTrait for Number {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Number);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Number);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Number
+    }
+}
+impl StructNode for Number {
+    const KIND: NodeKind = NodeKind::Number;
 }
 impl NodeIdTrait for BigInt {
     #[inline]
@@ -2919,13 +4429,20 @@ impl NodeIdTrait for BigInt {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::BigInt);
+        assert!(ast.nodes.kind(node_id) == NodeKind::BigInt);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::BigInt
+    }
+}
+impl StructNode for BigInt {
+    const KIND: NodeKind = NodeKind::BigInt;
 }
 impl NodeIdTrait for Regex {
     #[inline]
@@ -2934,13 +4451,20 @@ impl NodeIdTrait for Regex {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::Regex);
+        assert!(ast.nodes.kind(node_id) == NodeKind::Regex);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::Regex
+    }
+}
+impl StructNode for Regex {
+    const KIND: NodeKind = NodeKind::Regex;
 }
 impl NodeIdTrait for JSXObject {
     #[inline]
@@ -2952,7 +4476,7 @@ impl NodeIdTrait for JSXObject {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::Ident => JSXObject::Ident({ Ident::from_node_id(id, ast) }),
             NodeKind::JSXMemberExpr => {
                 JSXObject::JSXMemberExpr({ JSXMemberExpr::from_node_id(id, ast) })
@@ -2964,6 +4488,10 @@ impl NodeIdTrait for JSXObject {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::Ident | NodeKind::JSXMemberExpr)
+    }
 }
 impl NodeIdTrait for JSXMemberExpr {
     #[inline]
@@ -2972,13 +4500,20 @@ impl NodeIdTrait for JSXMemberExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXMemberExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXMemberExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXMemberExpr
+    }
+}
+impl StructNode for JSXMemberExpr {
+    const KIND: NodeKind = NodeKind::JSXMemberExpr;
 }
 impl NodeIdTrait for JSXNamespacedName {
     #[inline]
@@ -2987,13 +4522,20 @@ impl NodeIdTrait for JSXNamespacedName {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXNamespacedName);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXNamespacedName);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXNamespacedName
+    }
+}
+impl StructNode for JSXNamespacedName {
+    const KIND: NodeKind = NodeKind::JSXNamespacedName;
 }
 impl NodeIdTrait for JSXEmptyExpr {
     #[inline]
@@ -3002,13 +4544,20 @@ impl NodeIdTrait for JSXEmptyExpr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXEmptyExpr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXEmptyExpr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXEmptyExpr
+    }
+}
+impl StructNode for JSXEmptyExpr {
+    const KIND: NodeKind = NodeKind::JSXEmptyExpr;
 }
 impl NodeIdTrait for JSXExprContainer {
     #[inline]
@@ -3017,13 +4566,20 @@ impl NodeIdTrait for JSXExprContainer {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXExprContainer);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXExprContainer);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXExprContainer
+    }
+}
+impl StructNode for JSXExprContainer {
+    const KIND: NodeKind = NodeKind::JSXExprContainer;
 }
 impl NodeIdTrait for JSXExpr {
     #[inline]
@@ -3035,7 +4591,7 @@ impl NodeIdTrait for JSXExpr {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::ArrayLit => JSXExpr::Expr(Expr::Array({ ArrayLit::from_node_id(id, ast) })),
             NodeKind::ArrowExpr => JSXExpr::Expr(Expr::Arrow({ ArrowExpr::from_node_id(id, ast) })),
             NodeKind::AssignExpr => {
@@ -3102,6 +4658,22 @@ impl NodeIdTrait for JSXExpr {
             }
             NodeKind::ThisExpr => JSXExpr::Expr(Expr::This({ ThisExpr::from_node_id(id, ast) })),
             NodeKind::Tpl => JSXExpr::Expr(Expr::Tpl({ Tpl::from_node_id(id, ast) })),
+            NodeKind::TsAsExpr => JSXExpr::Expr(Expr::TsAs({ TsAsExpr::from_node_id(id, ast) })),
+            NodeKind::TsConstAssertion => JSXExpr::Expr(Expr::TsConstAssertion({
+                TsConstAssertion::from_node_id(id, ast)
+            })),
+            NodeKind::TsInstantiation => JSXExpr::Expr(Expr::TsInstantiation({
+                TsInstantiation::from_node_id(id, ast)
+            })),
+            NodeKind::TsNonNullExpr => {
+                JSXExpr::Expr(Expr::TsNonNull({ TsNonNullExpr::from_node_id(id, ast) }))
+            }
+            NodeKind::TsSatisfiesExpr => JSXExpr::Expr(Expr::TsSatisfies({
+                TsSatisfiesExpr::from_node_id(id, ast)
+            })),
+            NodeKind::TsTypeAssertion => JSXExpr::Expr(Expr::TsTypeAssertion({
+                TsTypeAssertion::from_node_id(id, ast)
+            })),
             NodeKind::UnaryExpr => JSXExpr::Expr(Expr::Unary({ UnaryExpr::from_node_id(id, ast) })),
             NodeKind::UpdateExpr => {
                 JSXExpr::Expr(Expr::Update({ UpdateExpr::from_node_id(id, ast) }))
@@ -3114,6 +4686,55 @@ impl NodeIdTrait for JSXExpr {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ArrayLit
+                | NodeKind::ArrowExpr
+                | NodeKind::AssignExpr
+                | NodeKind::AwaitExpr
+                | NodeKind::BigInt
+                | NodeKind::BinExpr
+                | NodeKind::Bool
+                | NodeKind::CallExpr
+                | NodeKind::ClassExpr
+                | NodeKind::CondExpr
+                | NodeKind::FnExpr
+                | NodeKind::Ident
+                | NodeKind::Invalid
+                | NodeKind::JSXElement
+                | NodeKind::JSXEmptyExpr
+                | NodeKind::JSXFragment
+                | NodeKind::JSXMemberExpr
+                | NodeKind::JSXNamespacedName
+                | NodeKind::MemberExpr
+                | NodeKind::MetaPropExpr
+                | NodeKind::NewExpr
+                | NodeKind::Null
+                | NodeKind::Number
+                | NodeKind::ObjectLit
+                | NodeKind::OptChainExpr
+                | NodeKind::ParenExpr
+                | NodeKind::PrivateName
+                | NodeKind::Regex
+                | NodeKind::SeqExpr
+                | NodeKind::Str
+                | NodeKind::SuperPropExpr
+                | NodeKind::TaggedTpl
+                | NodeKind::ThisExpr
+                | NodeKind::Tpl
+                | NodeKind::TsAsExpr
+                | NodeKind::TsConstAssertion
+                | NodeKind::TsInstantiation
+                | NodeKind::TsNonNullExpr
+                | NodeKind::TsSatisfiesExpr
+                | NodeKind::TsTypeAssertion
+                | NodeKind::UnaryExpr
+                | NodeKind::UpdateExpr
+                | NodeKind::YieldExpr
+        )
+    }
 }
 impl NodeIdTrait for JSXSpreadChild {
     #[inline]
@@ -3122,13 +4743,20 @@ impl NodeIdTrait for JSXSpreadChild {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXSpreadChild);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXSpreadChild);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXSpreadChild
+    }
+}
+impl StructNode for JSXSpreadChild {
+    const KIND: NodeKind = NodeKind::JSXSpreadChild;
 }
 impl NodeIdTrait for JSXElementName {
     #[inline]
@@ -3141,7 +4769,7 @@ impl NodeIdTrait for JSXElementName {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::Ident => JSXElementName::Ident({ Ident::from_node_id(id, ast) }),
             NodeKind::JSXMemberExpr => {
                 JSXElementName::JSXMemberExpr({ JSXMemberExpr::from_node_id(id, ast) })
@@ -3156,6 +4784,13 @@ impl NodeIdTrait for JSXElementName {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::Ident | NodeKind::JSXMemberExpr | NodeKind::JSXNamespacedName
+        )
+    }
 }
 impl NodeIdTrait for JSXOpeningElement {
     #[inline]
@@ -3164,13 +4799,20 @@ impl NodeIdTrait for JSXOpeningElement {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXOpeningElement);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXOpeningElement);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXOpeningElement
+    }
+}
+impl StructNode for JSXOpeningElement {
+    const KIND: NodeKind = NodeKind::JSXOpeningElement;
 }
 impl NodeIdTrait for JSXAttrOrSpread {
     #[inline]
@@ -3182,7 +4824,7 @@ impl NodeIdTrait for JSXAttrOrSpread {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::JSXAttr => JSXAttrOrSpread::JSXAttr({ JSXAttr::from_node_id(id, ast) }),
             NodeKind::SpreadElement => {
                 JSXAttrOrSpread::SpreadElement({ SpreadElement::from_node_id(id, ast) })
@@ -3194,6 +4836,10 @@ impl NodeIdTrait for JSXAttrOrSpread {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::JSXAttr | NodeKind::SpreadElement)
+    }
 }
 impl NodeIdTrait for JSXClosingElement {
     #[inline]
@@ -3202,13 +4848,20 @@ impl NodeIdTrait for JSXClosingElement {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXClosingElement);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXClosingElement);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXClosingElement
+    }
+}
+impl StructNode for JSXClosingElement {
+    const KIND: NodeKind = NodeKind::JSXClosingElement;
 }
 impl NodeIdTrait for JSXAttr {
     #[inline]
@@ -3217,13 +4870,20 @@ impl NodeIdTrait for JSXAttr {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXAttr);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXAttr);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXAttr
+    }
+}
+impl StructNode for JSXAttr {
+    const KIND: NodeKind = NodeKind::JSXAttr;
 }
 impl NodeIdTrait for JSXAttrName {
     #[inline]
@@ -3235,7 +4895,7 @@ impl NodeIdTrait for JSXAttrName {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::IdentName => JSXAttrName::Ident({ IdentName::from_node_id(id, ast) }),
             NodeKind::JSXNamespacedName => {
                 JSXAttrName::JSXNamespacedName({ JSXNamespacedName::from_node_id(id, ast) })
@@ -3247,6 +4907,10 @@ impl NodeIdTrait for JSXAttrName {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::IdentName | NodeKind::JSXNamespacedName)
+    }
 }
 impl NodeIdTrait for JSXAttrValue {
     #[inline]
@@ -3260,7 +4924,7 @@ impl NodeIdTrait for JSXAttrValue {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::JSXElement => JSXAttrValue::JSXElement({ JSXElement::from_node_id(id, ast) }),
             NodeKind::JSXExprContainer => {
                 JSXAttrValue::JSXExprContainer({ JSXExprContainer::from_node_id(id, ast) })
@@ -3276,6 +4940,16 @@ impl NodeIdTrait for JSXAttrValue {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::JSXElement
+                | NodeKind::JSXExprContainer
+                | NodeKind::JSXFragment
+                | NodeKind::Str
+        )
+    }
 }
 impl NodeIdTrait for JSXText {
     #[inline]
@@ -3284,13 +4958,20 @@ impl NodeIdTrait for JSXText {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXText);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXText);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXText
+    }
+}
+impl StructNode for JSXText {
+    const KIND: NodeKind = NodeKind::JSXText;
 }
 impl NodeIdTrait for JSXElement {
     #[inline]
@@ -3299,13 +4980,20 @@ impl NodeIdTrait for JSXElement {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXElement);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXElement);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXElement
+    }
+}
+impl StructNode for JSXElement {
+    const KIND: NodeKind = NodeKind::JSXElement;
 }
 impl NodeIdTrait for JSXElementChild {
     #[inline]
@@ -3320,7 +5008,7 @@ impl NodeIdTrait for JSXElementChild {
     }
     #[inline]
     fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-        match &ast.nodes[id].kind {
+        match ast.nodes.kind(id) {
             NodeKind::JSXElement => {
                 JSXElementChild::JSXElement({ JSXElement::from_node_id(id, ast) })
             }
@@ -3341,6 +5029,17 @@ impl NodeIdTrait for JSXElementChild {
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
         Self::from_node_id(id, ast)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::JSXElement
+                | NodeKind::JSXExprContainer
+                | NodeKind::JSXFragment
+                | NodeKind::JSXSpreadChild
+                | NodeKind::JSXText
+        )
+    }
 }
 impl NodeIdTrait for JSXFragment {
     #[inline]
@@ -3349,13 +5048,20 @@ impl NodeIdTrait for JSXFragment {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXFragment);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXFragment);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXFragment
+    }
+}
+impl StructNode for JSXFragment {
+    const KIND: NodeKind = NodeKind::JSXFragment;
 }
 impl NodeIdTrait for JSXOpeningFragment {
     #[inline]
@@ -3364,13 +5070,20 @@ impl NodeIdTrait for JSXOpeningFragment {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXOpeningFragment);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXOpeningFragment);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXOpeningFragment
+    }
+}
+impl StructNode for JSXOpeningFragment {
+    const KIND: NodeKind = NodeKind::JSXOpeningFragment;
 }
 impl NodeIdTrait for JSXClosingFragment {
     #[inline]
@@ -3379,11 +5092,1807 @@ impl NodeIdTrait for JSXClosingFragment {
     }
     #[inline]
     fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-        assert!(ast.nodes[node_id].kind == NodeKind::JSXClosingFragment);
+        assert!(ast.nodes.kind(node_id) == NodeKind::JSXClosingFragment);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::JSXClosingFragment
+    }
+}
+impl StructNode for JSXClosingFragment {
+    const KIND: NodeKind = NodeKind::JSXClosingFragment;
+}
+impl NodeIdTrait for TsTypeAnn {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypeAnn);
         Self(node_id)
     }
     #[inline]
     unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
         Self(node_id)
     }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypeAnn
+    }
+}
+impl StructNode for TsTypeAnn {
+    const KIND: NodeKind = NodeKind::TsTypeAnn;
+}
+impl NodeIdTrait for TsTypeParamDecl {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypeParamDecl);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypeParamDecl
+    }
+}
+impl StructNode for TsTypeParamDecl {
+    const KIND: NodeKind = NodeKind::TsTypeParamDecl;
+}
+impl NodeIdTrait for TsTypeParam {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypeParam);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypeParam
+    }
+}
+impl StructNode for TsTypeParam {
+    const KIND: NodeKind = NodeKind::TsTypeParam;
+}
+impl NodeIdTrait for TsTypeParamInstantiation {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypeParamInstantiation);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypeParamInstantiation
+    }
+}
+impl StructNode for TsTypeParamInstantiation {
+    const KIND: NodeKind = NodeKind::TsTypeParamInstantiation;
+}
+impl NodeIdTrait for TsParamProp {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsParamProp);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsParamProp
+    }
+}
+impl StructNode for TsParamProp {
+    const KIND: NodeKind = NodeKind::TsParamProp;
+}
+impl NodeIdTrait for TsParamPropParam {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::Ident(it) => it.node_id(),
+            Self::Assign(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::AssignPat => TsParamPropParam::Assign({ AssignPat::from_node_id(id, ast) }),
+            NodeKind::BindingIdent => {
+                TsParamPropParam::Ident({ BindingIdent::from_node_id(id, ast) })
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::AssignPat | NodeKind::BindingIdent)
+    }
+}
+impl NodeIdTrait for TsQualifiedName {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsQualifiedName);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsQualifiedName
+    }
+}
+impl StructNode for TsQualifiedName {
+    const KIND: NodeKind = NodeKind::TsQualifiedName;
+}
+impl NodeIdTrait for TsEntityName {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::TsQualifiedName(it) => it.node_id(),
+            Self::Ident(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::Ident => TsEntityName::Ident({ Ident::from_node_id(id, ast) }),
+            NodeKind::TsQualifiedName => {
+                TsEntityName::TsQualifiedName({ TsQualifiedName::from_node_id(id, ast) })
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::Ident | NodeKind::TsQualifiedName)
+    }
+}
+impl NodeIdTrait for TsTypeElement {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::TsCallSignatureDecl(it) => it.node_id(),
+            Self::TsConstructSignatureDecl(it) => it.node_id(),
+            Self::TsPropertySignature(it) => it.node_id(),
+            Self::TsGetterSignature(it) => it.node_id(),
+            Self::TsSetterSignature(it) => it.node_id(),
+            Self::TsMethodSignature(it) => it.node_id(),
+            Self::TsIndexSignature(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::TsCallSignatureDecl => {
+                TsTypeElement::TsCallSignatureDecl({ TsCallSignatureDecl::from_node_id(id, ast) })
+            }
+            NodeKind::TsConstructSignatureDecl => TsTypeElement::TsConstructSignatureDecl({
+                TsConstructSignatureDecl::from_node_id(id, ast)
+            }),
+            NodeKind::TsGetterSignature => {
+                TsTypeElement::TsGetterSignature({ TsGetterSignature::from_node_id(id, ast) })
+            }
+            NodeKind::TsIndexSignature => {
+                TsTypeElement::TsIndexSignature({ TsIndexSignature::from_node_id(id, ast) })
+            }
+            NodeKind::TsMethodSignature => {
+                TsTypeElement::TsMethodSignature({ TsMethodSignature::from_node_id(id, ast) })
+            }
+            NodeKind::TsPropertySignature => {
+                TsTypeElement::TsPropertySignature({ TsPropertySignature::from_node_id(id, ast) })
+            }
+            NodeKind::TsSetterSignature => {
+                TsTypeElement::TsSetterSignature({ TsSetterSignature::from_node_id(id, ast) })
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::TsCallSignatureDecl
+                | NodeKind::TsConstructSignatureDecl
+                | NodeKind::TsGetterSignature
+                | NodeKind::TsIndexSignature
+                | NodeKind::TsMethodSignature
+                | NodeKind::TsPropertySignature
+                | NodeKind::TsSetterSignature
+        )
+    }
+}
+impl NodeIdTrait for TsCallSignatureDecl {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsCallSignatureDecl);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsCallSignatureDecl
+    }
+}
+impl StructNode for TsCallSignatureDecl {
+    const KIND: NodeKind = NodeKind::TsCallSignatureDecl;
+}
+impl NodeIdTrait for TsConstructSignatureDecl {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsConstructSignatureDecl);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsConstructSignatureDecl
+    }
+}
+impl StructNode for TsConstructSignatureDecl {
+    const KIND: NodeKind = NodeKind::TsConstructSignatureDecl;
+}
+impl NodeIdTrait for TsPropertySignature {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsPropertySignature);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsPropertySignature
+    }
+}
+impl StructNode for TsPropertySignature {
+    const KIND: NodeKind = NodeKind::TsPropertySignature;
+}
+impl NodeIdTrait for TsGetterSignature {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsGetterSignature);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsGetterSignature
+    }
+}
+impl StructNode for TsGetterSignature {
+    const KIND: NodeKind = NodeKind::TsGetterSignature;
+}
+impl NodeIdTrait for TsSetterSignature {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsSetterSignature);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsSetterSignature
+    }
+}
+impl StructNode for TsSetterSignature {
+    const KIND: NodeKind = NodeKind::TsSetterSignature;
+}
+impl NodeIdTrait for TsMethodSignature {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsMethodSignature);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsMethodSignature
+    }
+}
+impl StructNode for TsMethodSignature {
+    const KIND: NodeKind = NodeKind::TsMethodSignature;
+}
+impl NodeIdTrait for TsIndexSignature {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsIndexSignature);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsIndexSignature
+    }
+}
+impl StructNode for TsIndexSignature {
+    const KIND: NodeKind = NodeKind::TsIndexSignature;
+}
+impl NodeIdTrait for TsType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::TsKeywordType(it) => it.node_id(),
+            Self::TsThisType(it) => it.node_id(),
+            Self::TsFnOrConstructorType(it) => it.node_id(),
+            Self::TsTypeRef(it) => it.node_id(),
+            Self::TsTypeQuery(it) => it.node_id(),
+            Self::TsTypeLit(it) => it.node_id(),
+            Self::TsArrayType(it) => it.node_id(),
+            Self::TsTupleType(it) => it.node_id(),
+            Self::TsOptionalType(it) => it.node_id(),
+            Self::TsRestType(it) => it.node_id(),
+            Self::TsUnionOrIntersectionType(it) => it.node_id(),
+            Self::TsConditionalType(it) => it.node_id(),
+            Self::TsInferType(it) => it.node_id(),
+            Self::TsParenthesizedType(it) => it.node_id(),
+            Self::TsTypeOperator(it) => it.node_id(),
+            Self::TsIndexedAccessType(it) => it.node_id(),
+            Self::TsMappedType(it) => it.node_id(),
+            Self::TsLitType(it) => it.node_id(),
+            Self::TsTypePredicate(it) => it.node_id(),
+            Self::TsImportType(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::TsArrayType => TsType::TsArrayType({ TsArrayType::from_node_id(id, ast) }),
+            NodeKind::TsConditionalType => {
+                TsType::TsConditionalType({ TsConditionalType::from_node_id(id, ast) })
+            }
+            NodeKind::TsConstructorType => {
+                TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsConstructorType({
+                    TsConstructorType::from_node_id(id, ast)
+                }))
+            }
+            NodeKind::TsFnType => TsType::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType({
+                TsFnType::from_node_id(id, ast)
+            })),
+            NodeKind::TsImportType => TsType::TsImportType({ TsImportType::from_node_id(id, ast) }),
+            NodeKind::TsIndexedAccessType => {
+                TsType::TsIndexedAccessType({ TsIndexedAccessType::from_node_id(id, ast) })
+            }
+            NodeKind::TsInferType => TsType::TsInferType({ TsInferType::from_node_id(id, ast) }),
+            NodeKind::TsIntersectionType => {
+                TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType({
+                    TsIntersectionType::from_node_id(id, ast)
+                }))
+            }
+            NodeKind::TsKeywordType => {
+                TsType::TsKeywordType({ TsKeywordType::from_node_id(id, ast) })
+            }
+            NodeKind::TsLitType => TsType::TsLitType({ TsLitType::from_node_id(id, ast) }),
+            NodeKind::TsMappedType => TsType::TsMappedType({ TsMappedType::from_node_id(id, ast) }),
+            NodeKind::TsOptionalType => {
+                TsType::TsOptionalType({ TsOptionalType::from_node_id(id, ast) })
+            }
+            NodeKind::TsParenthesizedType => {
+                TsType::TsParenthesizedType({ TsParenthesizedType::from_node_id(id, ast) })
+            }
+            NodeKind::TsRestType => TsType::TsRestType({ TsRestType::from_node_id(id, ast) }),
+            NodeKind::TsThisType => TsType::TsThisType({ TsThisType::from_node_id(id, ast) }),
+            NodeKind::TsTupleType => TsType::TsTupleType({ TsTupleType::from_node_id(id, ast) }),
+            NodeKind::TsTypeLit => TsType::TsTypeLit({ TsTypeLit::from_node_id(id, ast) }),
+            NodeKind::TsTypeOperator => {
+                TsType::TsTypeOperator({ TsTypeOperator::from_node_id(id, ast) })
+            }
+            NodeKind::TsTypePredicate => {
+                TsType::TsTypePredicate({ TsTypePredicate::from_node_id(id, ast) })
+            }
+            NodeKind::TsTypeQuery => TsType::TsTypeQuery({ TsTypeQuery::from_node_id(id, ast) }),
+            NodeKind::TsTypeRef => TsType::TsTypeRef({ TsTypeRef::from_node_id(id, ast) }),
+            NodeKind::TsUnionType => {
+                TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType({
+                    TsUnionType::from_node_id(id, ast)
+                }))
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::TsArrayType
+                | NodeKind::TsConditionalType
+                | NodeKind::TsConstructorType
+                | NodeKind::TsFnType
+                | NodeKind::TsImportType
+                | NodeKind::TsIndexedAccessType
+                | NodeKind::TsInferType
+                | NodeKind::TsIntersectionType
+                | NodeKind::TsKeywordType
+                | NodeKind::TsLitType
+                | NodeKind::TsMappedType
+                | NodeKind::TsOptionalType
+                | NodeKind::TsParenthesizedType
+                | NodeKind::TsRestType
+                | NodeKind::TsThisType
+                | NodeKind::TsTupleType
+                | NodeKind::TsTypeLit
+                | NodeKind::TsTypeOperator
+                | NodeKind::TsTypePredicate
+                | NodeKind::TsTypeQuery
+                | NodeKind::TsTypeRef
+                | NodeKind::TsUnionType
+        )
+    }
+}
+impl NodeIdTrait for TsFnOrConstructorType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::TsFnType(it) => it.node_id(),
+            Self::TsConstructorType(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::TsConstructorType => TsFnOrConstructorType::TsConstructorType({
+                TsConstructorType::from_node_id(id, ast)
+            }),
+            NodeKind::TsFnType => {
+                TsFnOrConstructorType::TsFnType({ TsFnType::from_node_id(id, ast) })
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::TsConstructorType | NodeKind::TsFnType)
+    }
+}
+impl NodeIdTrait for TsKeywordType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsKeywordType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsKeywordType
+    }
+}
+impl StructNode for TsKeywordType {
+    const KIND: NodeKind = NodeKind::TsKeywordType;
+}
+impl NodeIdTrait for TsThisType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsThisType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsThisType
+    }
+}
+impl StructNode for TsThisType {
+    const KIND: NodeKind = NodeKind::TsThisType;
+}
+impl NodeIdTrait for TsFnParam {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::Ident(it) => it.node_id(),
+            Self::Array(it) => it.node_id(),
+            Self::Rest(it) => it.node_id(),
+            Self::Object(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::ArrayPat => TsFnParam::Array({ ArrayPat::from_node_id(id, ast) }),
+            NodeKind::BindingIdent => TsFnParam::Ident({ BindingIdent::from_node_id(id, ast) }),
+            NodeKind::ObjectPat => TsFnParam::Object({ ObjectPat::from_node_id(id, ast) }),
+            NodeKind::RestPat => TsFnParam::Rest({ RestPat::from_node_id(id, ast) }),
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::ArrayPat | NodeKind::BindingIdent | NodeKind::ObjectPat | NodeKind::RestPat
+        )
+    }
+}
+impl NodeIdTrait for TsFnType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsFnType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsFnType
+    }
+}
+impl StructNode for TsFnType {
+    const KIND: NodeKind = NodeKind::TsFnType;
+}
+impl NodeIdTrait for TsConstructorType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsConstructorType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsConstructorType
+    }
+}
+impl StructNode for TsConstructorType {
+    const KIND: NodeKind = NodeKind::TsConstructorType;
+}
+impl NodeIdTrait for TsTypeRef {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypeRef);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypeRef
+    }
+}
+impl StructNode for TsTypeRef {
+    const KIND: NodeKind = NodeKind::TsTypeRef;
+}
+impl NodeIdTrait for TsTypePredicate {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypePredicate);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypePredicate
+    }
+}
+impl StructNode for TsTypePredicate {
+    const KIND: NodeKind = NodeKind::TsTypePredicate;
+}
+impl NodeIdTrait for TsThisTypeOrIdent {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::TsThisType(it) => it.node_id(),
+            Self::Ident(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::Ident => TsThisTypeOrIdent::Ident({ Ident::from_node_id(id, ast) }),
+            NodeKind::TsThisType => {
+                TsThisTypeOrIdent::TsThisType({ TsThisType::from_node_id(id, ast) })
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::Ident | NodeKind::TsThisType)
+    }
+}
+impl NodeIdTrait for TsTypeQuery {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypeQuery);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypeQuery
+    }
+}
+impl StructNode for TsTypeQuery {
+    const KIND: NodeKind = NodeKind::TsTypeQuery;
+}
+impl NodeIdTrait for TsTypeQueryExpr {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::TsEntityName(it) => it.node_id(),
+            Self::Import(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::Ident => {
+                TsTypeQueryExpr::TsEntityName(TsEntityName::Ident({ Ident::from_node_id(id, ast) }))
+            }
+            NodeKind::TsImportType => {
+                TsTypeQueryExpr::Import({ TsImportType::from_node_id(id, ast) })
+            }
+            NodeKind::TsQualifiedName => {
+                TsTypeQueryExpr::TsEntityName(TsEntityName::TsQualifiedName({
+                    TsQualifiedName::from_node_id(id, ast)
+                }))
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::Ident | NodeKind::TsImportType | NodeKind::TsQualifiedName
+        )
+    }
+}
+impl NodeIdTrait for TsImportCallOptions {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsImportCallOptions);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsImportCallOptions
+    }
+}
+impl StructNode for TsImportCallOptions {
+    const KIND: NodeKind = NodeKind::TsImportCallOptions;
+}
+impl NodeIdTrait for TsImportType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsImportType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsImportType
+    }
+}
+impl StructNode for TsImportType {
+    const KIND: NodeKind = NodeKind::TsImportType;
+}
+impl NodeIdTrait for TsTypeLit {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypeLit);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypeLit
+    }
+}
+impl StructNode for TsTypeLit {
+    const KIND: NodeKind = NodeKind::TsTypeLit;
+}
+impl NodeIdTrait for TsArrayType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsArrayType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsArrayType
+    }
+}
+impl StructNode for TsArrayType {
+    const KIND: NodeKind = NodeKind::TsArrayType;
+}
+impl NodeIdTrait for TsTupleType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTupleType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTupleType
+    }
+}
+impl StructNode for TsTupleType {
+    const KIND: NodeKind = NodeKind::TsTupleType;
+}
+impl NodeIdTrait for TsTupleElement {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTupleElement);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTupleElement
+    }
+}
+impl StructNode for TsTupleElement {
+    const KIND: NodeKind = NodeKind::TsTupleElement;
+}
+impl NodeIdTrait for TsOptionalType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsOptionalType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsOptionalType
+    }
+}
+impl StructNode for TsOptionalType {
+    const KIND: NodeKind = NodeKind::TsOptionalType;
+}
+impl NodeIdTrait for TsRestType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsRestType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsRestType
+    }
+}
+impl StructNode for TsRestType {
+    const KIND: NodeKind = NodeKind::TsRestType;
+}
+impl NodeIdTrait for TsUnionOrIntersectionType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::TsUnionType(it) => it.node_id(),
+            Self::TsIntersectionType(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::TsIntersectionType => TsUnionOrIntersectionType::TsIntersectionType({
+                TsIntersectionType::from_node_id(id, ast)
+            }),
+            NodeKind::TsUnionType => {
+                TsUnionOrIntersectionType::TsUnionType({ TsUnionType::from_node_id(id, ast) })
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::TsIntersectionType | NodeKind::TsUnionType)
+    }
+}
+impl NodeIdTrait for TsUnionType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsUnionType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsUnionType
+    }
+}
+impl StructNode for TsUnionType {
+    const KIND: NodeKind = NodeKind::TsUnionType;
+}
+impl NodeIdTrait for TsIntersectionType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsIntersectionType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsIntersectionType
+    }
+}
+impl StructNode for TsIntersectionType {
+    const KIND: NodeKind = NodeKind::TsIntersectionType;
+}
+impl NodeIdTrait for TsConditionalType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsConditionalType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsConditionalType
+    }
+}
+impl StructNode for TsConditionalType {
+    const KIND: NodeKind = NodeKind::TsConditionalType;
+}
+impl NodeIdTrait for TsInferType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsInferType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsInferType
+    }
+}
+impl StructNode for TsInferType {
+    const KIND: NodeKind = NodeKind::TsInferType;
+}
+impl NodeIdTrait for TsParenthesizedType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsParenthesizedType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsParenthesizedType
+    }
+}
+impl StructNode for TsParenthesizedType {
+    const KIND: NodeKind = NodeKind::TsParenthesizedType;
+}
+impl NodeIdTrait for TsTypeOperator {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypeOperator);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypeOperator
+    }
+}
+impl StructNode for TsTypeOperator {
+    const KIND: NodeKind = NodeKind::TsTypeOperator;
+}
+impl NodeIdTrait for TsIndexedAccessType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsIndexedAccessType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsIndexedAccessType
+    }
+}
+impl StructNode for TsIndexedAccessType {
+    const KIND: NodeKind = NodeKind::TsIndexedAccessType;
+}
+impl NodeIdTrait for TsMappedType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsMappedType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsMappedType
+    }
+}
+impl StructNode for TsMappedType {
+    const KIND: NodeKind = NodeKind::TsMappedType;
+}
+impl NodeIdTrait for TsLitType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsLitType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsLitType
+    }
+}
+impl StructNode for TsLitType {
+    const KIND: NodeKind = NodeKind::TsLitType;
+}
+impl NodeIdTrait for TsLit {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::Number(it) => it.node_id(),
+            Self::Str(it) => it.node_id(),
+            Self::Bool(it) => it.node_id(),
+            Self::BigInt(it) => it.node_id(),
+            Self::Tpl(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::BigInt => TsLit::BigInt({ BigInt::from_node_id(id, ast) }),
+            NodeKind::Bool => TsLit::Bool({ Bool::from_node_id(id, ast) }),
+            NodeKind::Number => TsLit::Number({ Number::from_node_id(id, ast) }),
+            NodeKind::Str => TsLit::Str({ Str::from_node_id(id, ast) }),
+            NodeKind::TsTplLitType => TsLit::Tpl({ TsTplLitType::from_node_id(id, ast) }),
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::BigInt
+                | NodeKind::Bool
+                | NodeKind::Number
+                | NodeKind::Str
+                | NodeKind::TsTplLitType
+        )
+    }
+}
+impl NodeIdTrait for TsTplLitType {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTplLitType);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTplLitType
+    }
+}
+impl StructNode for TsTplLitType {
+    const KIND: NodeKind = NodeKind::TsTplLitType;
+}
+impl NodeIdTrait for TsInterfaceDecl {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsInterfaceDecl);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsInterfaceDecl
+    }
+}
+impl StructNode for TsInterfaceDecl {
+    const KIND: NodeKind = NodeKind::TsInterfaceDecl;
+}
+impl NodeIdTrait for TsInterfaceBody {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsInterfaceBody);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsInterfaceBody
+    }
+}
+impl StructNode for TsInterfaceBody {
+    const KIND: NodeKind = NodeKind::TsInterfaceBody;
+}
+impl NodeIdTrait for TsExprWithTypeArgs {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsExprWithTypeArgs);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsExprWithTypeArgs
+    }
+}
+impl StructNode for TsExprWithTypeArgs {
+    const KIND: NodeKind = NodeKind::TsExprWithTypeArgs;
+}
+impl NodeIdTrait for TsTypeAliasDecl {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypeAliasDecl);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypeAliasDecl
+    }
+}
+impl StructNode for TsTypeAliasDecl {
+    const KIND: NodeKind = NodeKind::TsTypeAliasDecl;
+}
+impl NodeIdTrait for TsEnumDecl {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsEnumDecl);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsEnumDecl
+    }
+}
+impl StructNode for TsEnumDecl {
+    const KIND: NodeKind = NodeKind::TsEnumDecl;
+}
+impl NodeIdTrait for TsEnumMember {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsEnumMember);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsEnumMember
+    }
+}
+impl StructNode for TsEnumMember {
+    const KIND: NodeKind = NodeKind::TsEnumMember;
+}
+impl NodeIdTrait for TsEnumMemberId {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::Ident(it) => it.node_id(),
+            Self::Str(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::Ident => TsEnumMemberId::Ident({ Ident::from_node_id(id, ast) }),
+            NodeKind::Str => TsEnumMemberId::Str({ Str::from_node_id(id, ast) }),
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::Ident | NodeKind::Str)
+    }
+}
+impl NodeIdTrait for TsModuleDecl {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsModuleDecl);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsModuleDecl
+    }
+}
+impl StructNode for TsModuleDecl {
+    const KIND: NodeKind = NodeKind::TsModuleDecl;
+}
+impl NodeIdTrait for TsNamespaceBody {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::TsModuleBlock(it) => it.node_id(),
+            Self::TsNamespaceDecl(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::TsModuleBlock => {
+                TsNamespaceBody::TsModuleBlock({ TsModuleBlock::from_node_id(id, ast) })
+            }
+            NodeKind::TsNamespaceDecl => {
+                TsNamespaceBody::TsNamespaceDecl({ TsNamespaceDecl::from_node_id(id, ast) })
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::TsModuleBlock | NodeKind::TsNamespaceDecl)
+    }
+}
+impl NodeIdTrait for TsModuleBlock {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsModuleBlock);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsModuleBlock
+    }
+}
+impl StructNode for TsModuleBlock {
+    const KIND: NodeKind = NodeKind::TsModuleBlock;
+}
+impl NodeIdTrait for TsNamespaceDecl {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsNamespaceDecl);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsNamespaceDecl
+    }
+}
+impl StructNode for TsNamespaceDecl {
+    const KIND: NodeKind = NodeKind::TsNamespaceDecl;
+}
+impl NodeIdTrait for TsModuleName {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::Ident(it) => it.node_id(),
+            Self::Str(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::Ident => TsModuleName::Ident({ Ident::from_node_id(id, ast) }),
+            NodeKind::Str => TsModuleName::Str({ Str::from_node_id(id, ast) }),
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(kind, NodeKind::Ident | NodeKind::Str)
+    }
+}
+impl NodeIdTrait for TsImportEqualsDecl {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsImportEqualsDecl);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsImportEqualsDecl
+    }
+}
+impl StructNode for TsImportEqualsDecl {
+    const KIND: NodeKind = NodeKind::TsImportEqualsDecl;
+}
+impl NodeIdTrait for TsModuleRef {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        match self {
+            Self::TsEntityName(it) => it.node_id(),
+            Self::TsExternalModuleRef(it) => it.node_id(),
+        }
+    }
+    #[inline]
+    fn from_node_id(id: NodeId, ast: &Ast) -> Self {
+        match ast.nodes.kind(id) {
+            NodeKind::Ident => {
+                TsModuleRef::TsEntityName(TsEntityName::Ident({ Ident::from_node_id(id, ast) }))
+            }
+            NodeKind::TsExternalModuleRef => {
+                TsModuleRef::TsExternalModuleRef({ TsExternalModuleRef::from_node_id(id, ast) })
+            }
+            NodeKind::TsQualifiedName => {
+                TsModuleRef::TsEntityName(TsEntityName::TsQualifiedName({
+                    TsQualifiedName::from_node_id(id, ast)
+                }))
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
+        Self::from_node_id(id, ast)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        matches!(
+            kind,
+            NodeKind::Ident | NodeKind::TsExternalModuleRef | NodeKind::TsQualifiedName
+        )
+    }
+}
+impl NodeIdTrait for TsExternalModuleRef {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsExternalModuleRef);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsExternalModuleRef
+    }
+}
+impl StructNode for TsExternalModuleRef {
+    const KIND: NodeKind = NodeKind::TsExternalModuleRef;
+}
+impl NodeIdTrait for TsExportAssignment {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsExportAssignment);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsExportAssignment
+    }
+}
+impl StructNode for TsExportAssignment {
+    const KIND: NodeKind = NodeKind::TsExportAssignment;
+}
+impl NodeIdTrait for TsNamespaceExportDecl {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsNamespaceExportDecl);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsNamespaceExportDecl
+    }
+}
+impl StructNode for TsNamespaceExportDecl {
+    const KIND: NodeKind = NodeKind::TsNamespaceExportDecl;
+}
+impl NodeIdTrait for TsAsExpr {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsAsExpr);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsAsExpr
+    }
+}
+impl StructNode for TsAsExpr {
+    const KIND: NodeKind = NodeKind::TsAsExpr;
+}
+impl NodeIdTrait for TsTypeAssertion {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsTypeAssertion);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsTypeAssertion
+    }
+}
+impl StructNode for TsTypeAssertion {
+    const KIND: NodeKind = NodeKind::TsTypeAssertion;
+}
+impl NodeIdTrait for TsNonNullExpr {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsNonNullExpr);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsNonNullExpr
+    }
+}
+impl StructNode for TsNonNullExpr {
+    const KIND: NodeKind = NodeKind::TsNonNullExpr;
+}
+impl NodeIdTrait for TsSatisfiesExpr {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsSatisfiesExpr);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsSatisfiesExpr
+    }
+}
+impl StructNode for TsSatisfiesExpr {
+    const KIND: NodeKind = NodeKind::TsSatisfiesExpr;
+}
+impl NodeIdTrait for TsConstAssertion {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsConstAssertion);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsConstAssertion
+    }
+}
+impl StructNode for TsConstAssertion {
+    const KIND: NodeKind = NodeKind::TsConstAssertion;
+}
+impl NodeIdTrait for TsInstantiation {
+    #[inline]
+    fn node_id(&self) -> NodeId {
+        self.0
+    }
+    #[inline]
+    fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
+        assert!(ast.nodes.kind(node_id) == NodeKind::TsInstantiation);
+        Self(node_id)
+    }
+    #[inline]
+    unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
+        Self(node_id)
+    }
+    #[inline]
+    fn is_kind(kind: NodeKind) -> bool {
+        kind == NodeKind::TsInstantiation
+    }
+}
+impl StructNode for TsInstantiation {
+    const KIND: NodeKind = NodeKind::TsInstantiation;
 }
@@ -35,8 +35,8 @@ impl Module {
     pub fn body(&self, ast: &crate::Ast) -> TypedSubRange<ModuleItem> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -54,8 +54,8 @@ impl Module {
     pub fn shebang(&self, ast: &crate::Ast) -> OptionalUtf8Ref {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -73,8 +73,8 @@ impl Module {
     pub fn set_body(&self, ast: &mut crate::Ast, body: TypedSubRange<ModuleItem>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -91,8 +91,8 @@ impl Module {
     pub fn set_shebang(&self, ast: &mut crate::Ast, shebang: OptionalUtf8Ref) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -111,8 +111,8 @@ impl Script {
     pub fn body(&self, ast: &crate::Ast) -> TypedSubRange<Stmt> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -130,8 +130,8 @@ impl Script {
     pub fn shebang(&self, ast: &crate::Ast) -> OptionalUtf8Ref {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -149,8 +149,8 @@ impl Script {
     pub fn set_body(&self, ast: &mut crate::Ast, body: TypedSubRange<Stmt>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -167,8 +167,8 @@ impl Script {
     pub fn set_shebang(&self, ast: &mut crate::Ast, shebang: OptionalUtf8Ref) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -232,6 +232,18 @@ impl ModuleDecl {
         matches!(self, Self::ExportAll(_))
     }
     #[inline]
+    pub fn is_ts_import_equals(&self) -> bool {
+        matches!(self, Self::TsImportEquals(_))
+    }
+    #[inline]
+    pub fn is_ts_export_assignment(&self) -> bool {
+        matches!(self, Self::TsExportAssignment(_))
+    }
+    #[inline]
+    pub fn is_ts_namespace_export(&self) -> bool {
+        matches!(self, Self::TsNamespaceExport(_))
+    }
+    #[inline]
     pub fn as_import(self) -> Option<ImportDecl> {
         match self {
             Self::Import(it) => Some(it),
@@ -273,14 +285,35 @@ impl ModuleDecl {
             _ => None,
         }
     }
+    #[inline]
+    pub fn as_ts_import_equals(self) -> Option<TsImportEqualsDecl> {
+        match self {
+            Self::TsImportEquals(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_export_assignment(self) -> Option<TsExportAssignment> {
+        match self {
+            Self::TsExportAssignment(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_namespace_export(self) -> Option<TsNamespaceExportDecl> {
+        match self {
+            Self::TsNamespaceExport(it) => Some(it),
+            _ => None,
+        }
+    }
 }
 impl ImportDecl {
     #[inline]
     pub fn specifiers(&self, ast: &crate::Ast) -> TypedSubRange<ImportSpecifier> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -298,8 +331,8 @@ impl ImportDecl {
     pub fn src(&self, ast: &crate::Ast) -> Str {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -317,8 +350,8 @@ impl ImportDecl {
     pub fn type_only(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -336,8 +369,8 @@ impl ImportDecl {
     pub fn with(&self, ast: &crate::Ast) -> Option<ObjectLit> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -355,8 +388,8 @@ impl ImportDecl {
     pub fn phase(&self, ast: &crate::Ast) -> ImportPhase {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(4usize),
@@ -374,8 +407,8 @@ impl ImportDecl {
     pub fn set_specifiers(&self, ast: &mut crate::Ast, specifiers: TypedSubRange<ImportSpecifier>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -392,8 +425,8 @@ impl ImportDecl {
     pub fn set_src(&self, ast: &mut crate::Ast, src: Str) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -410,8 +443,8 @@ impl ImportDecl {
     pub fn set_type_only(&self, ast: &mut crate::Ast, type_only: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -428,8 +461,8 @@ impl ImportDecl {
     pub fn set_with(&self, ast: &mut crate::Ast, with: Option<ObjectLit>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -446,8 +479,8 @@ impl ImportDecl {
     pub fn set_phase(&self, ast: &mut crate::Ast, phase: ImportPhase) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(4usize),
@@ -499,16 +532,15 @@ impl ImportSpecifier {
 impl ImportNamedSpecifier {
     #[inline]
     pub fn is_type_only(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         raw != 0
     }
     #[inline]
     pub fn local(&self, ast: &crate::Ast) -> Ident {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -526,8 +558,8 @@ impl ImportNamedSpecifier {
     pub fn imported(&self, ast: &crate::Ast) -> Option<ModuleExportName> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -543,17 +575,21 @@ impl ImportNamedSpecifier {
     }
     #[inline]
     pub fn set_is_type_only(&self, ast: &mut crate::Ast, is_type_only: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = is_type_only as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_local(&self, ast: &mut crate::Ast, local: Ident) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -570,8 +606,8 @@ impl ImportNamedSpecifier {
     pub fn set_imported(&self, ast: &mut crate::Ast, imported: Option<ModuleExportName>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -588,27 +624,25 @@ impl ImportNamedSpecifier {
 impl ImportDefaultSpecifier {
     #[inline]
     pub fn local(&self, ast: &crate::Ast) -> Ident {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = unsafe { node.data.inline_data };
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
         unsafe { Ident::from_node_id_unchecked(crate::NodeId::from_raw_unchecked(raw), ast) }
     }
     #[inline]
     pub fn set_local(&self, ast: &mut crate::Ast, local: Ident) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        node.data.inline_data = local.node_id().index() as u32;
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data =
+            local.node_id().index() as u32;
     }
 }
 impl ImportStarAsSpecifier {
     #[inline]
     pub fn local(&self, ast: &crate::Ast) -> Ident {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = unsafe { node.data.inline_data };
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
         unsafe { Ident::from_node_id_unchecked(crate::NodeId::from_raw_unchecked(raw), ast) }
     }
     #[inline]
     pub fn set_local(&self, ast: &mut crate::Ast, local: Ident) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        node.data.inline_data = local.node_id().index() as u32;
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data =
+            local.node_id().index() as u32;
     }
 }
 impl ExportDecl {
@@ -616,8 +650,8 @@ impl ExportDecl {
     pub fn decl(&self, ast: &crate::Ast) -> Decl {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -635,8 +669,8 @@ impl ExportDecl {
     pub fn set_decl(&self, ast: &mut crate::Ast, decl: Decl) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -655,8 +689,8 @@ impl NamedExport {
     pub fn specifiers(&self, ast: &crate::Ast) -> TypedSubRange<ExportSpecifier> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -674,8 +708,8 @@ impl NamedExport {
     pub fn src(&self, ast: &crate::Ast) -> Option<Str> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -693,8 +727,8 @@ impl NamedExport {
     pub fn type_only(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -712,8 +746,8 @@ impl NamedExport {
     pub fn with(&self, ast: &crate::Ast) -> Option<ObjectLit> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -731,8 +765,8 @@ impl NamedExport {
     pub fn set_specifiers(&self, ast: &mut crate::Ast, specifiers: TypedSubRange<ExportSpecifier>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -749,8 +783,8 @@ impl NamedExport {
     pub fn set_src(&self, ast: &mut crate::Ast, src: Option<Str>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -767,8 +801,8 @@ impl NamedExport {
     pub fn set_type_only(&self, ast: &mut crate::Ast, type_only: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -785,8 +819,8 @@ impl NamedExport {
     pub fn set_with(&self, ast: &mut crate::Ast, with: Option<ObjectLit>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -840,8 +874,8 @@ impl ExportNamespaceSpecifier {
     pub fn name(&self, ast: &crate::Ast) -> ModuleExportName {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -859,8 +893,8 @@ impl ExportNamespaceSpecifier {
     pub fn set_name(&self, ast: &mut crate::Ast, name: ModuleExportName) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -901,29 +935,27 @@ impl ModuleExportName {
 impl ExportDefaultSpecifier {
     #[inline]
     pub fn exported(&self, ast: &crate::Ast) -> Ident {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = unsafe { node.data.inline_data };
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
         unsafe { Ident::from_node_id_unchecked(crate::NodeId::from_raw_unchecked(raw), ast) }
     }
     #[inline]
     pub fn set_exported(&self, ast: &mut crate::Ast, exported: Ident) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        node.data.inline_data = exported.node_id().index() as u32;
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data =
+            exported.node_id().index() as u32;
     }
 }
 impl ExportNamedSpecifier {
     #[inline]
     pub fn is_type_only(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         raw != 0
     }
     #[inline]
     pub fn orig(&self, ast: &crate::Ast) -> ModuleExportName {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -941,8 +973,8 @@ impl ExportNamedSpecifier {
     pub fn exported(&self, ast: &crate::Ast) -> Option<ModuleExportName> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -958,17 +990,21 @@ impl ExportNamedSpecifier {
     }
     #[inline]
     pub fn set_is_type_only(&self, ast: &mut crate::Ast, is_type_only: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = is_type_only as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_orig(&self, ast: &mut crate::Ast, orig: ModuleExportName) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -985,8 +1021,8 @@ impl ExportNamedSpecifier {
     pub fn set_exported(&self, ast: &mut crate::Ast, exported: Option<ModuleExportName>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1005,8 +1041,8 @@ impl ExportDefaultDecl {
     pub fn decl(&self, ast: &crate::Ast) -> DefaultDecl {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1024,8 +1060,8 @@ impl ExportDefaultDecl {
     pub fn set_decl(&self, ast: &mut crate::Ast, decl: DefaultDecl) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1049,6 +1085,10 @@ impl DefaultDecl {
         matches!(self, Self::Fn(_))
     }
     #[inline]
+    pub fn is_ts_interface_decl(&self) -> bool {
+        matches!(self, Self::TsInterfaceDecl(_))
+    }
+    #[inline]
     pub fn as_class(self) -> Option<ClassExpr> {
         match self {
             Self::Class(it) => Some(it),
@@ -1062,14 +1102,21 @@ impl DefaultDecl {
             _ => None,
         }
     }
+    #[inline]
+    pub fn as_ts_interface_decl(self) -> Option<TsInterfaceDecl> {
+        match self {
+            Self::TsInterfaceDecl(it) => Some(it),
+            _ => None,
+        }
+    }
 }
 impl ExportDefaultExpr {
     #[inline]
     pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1087,8 +1134,8 @@ impl ExportDefaultExpr {
     pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1105,16 +1152,15 @@ impl ExportDefaultExpr {
 impl ExportAll {
     #[inline]
     pub fn type_only(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         raw != 0
     }
     #[inline]
     pub fn src(&self, ast: &crate::Ast) -> Str {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1132,8 +1178,8 @@ impl ExportAll {
     pub fn with(&self, ast: &crate::Ast) -> Option<ObjectLit> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1149,17 +1195,21 @@ impl ExportAll {
     }
     #[inline]
     pub fn set_type_only(&self, ast: &mut crate::Ast, type_only: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = type_only as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_src(&self, ast: &mut crate::Ast, src: Str) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1176,8 +1226,8 @@ impl ExportAll {
     pub fn set_with(&self, ast: &mut crate::Ast, with: Option<ObjectLit>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1196,8 +1246,8 @@ impl BlockStmt {
     pub fn stmts(&self, ast: &crate::Ast) -> TypedSubRange<Stmt> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1215,8 +1265,8 @@ impl BlockStmt {
     pub fn set_stmts(&self, ast: &mut crate::Ast, stmts: TypedSubRange<Stmt>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1446,8 +1496,8 @@ impl ExprStmt {
     pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1465,8 +1515,8 @@ impl ExprStmt {
     pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1487,8 +1537,8 @@ impl WithStmt {
     pub fn obj(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1506,8 +1556,8 @@ impl WithStmt {
     pub fn body(&self, ast: &crate::Ast) -> Stmt {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1525,8 +1575,8 @@ impl WithStmt {
     pub fn set_obj(&self, ast: &mut crate::Ast, obj: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1543,8 +1593,8 @@ impl WithStmt {
     pub fn set_body(&self, ast: &mut crate::Ast, body: Stmt) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1563,8 +1613,8 @@ impl ReturnStmt {
     pub fn arg(&self, ast: &crate::Ast) -> Option<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1582,8 +1632,8 @@ impl ReturnStmt {
     pub fn set_arg(&self, ast: &mut crate::Ast, arg: Option<Expr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1602,8 +1652,8 @@ impl LabeledStmt {
     pub fn label(&self, ast: &crate::Ast) -> Ident {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1621,8 +1671,8 @@ impl LabeledStmt {
     pub fn body(&self, ast: &crate::Ast) -> Stmt {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1640,8 +1690,8 @@ impl LabeledStmt {
     pub fn set_label(&self, ast: &mut crate::Ast, label: Ident) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1658,8 +1708,8 @@ impl LabeledStmt {
     pub fn set_body(&self, ast: &mut crate::Ast, body: Stmt) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1676,29 +1726,27 @@ impl LabeledStmt {
 impl BreakStmt {
     #[inline]
     pub fn label(&self, ast: &crate::Ast) -> Option<Ident> {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = unsafe { node.data.inline_data };
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
         let opt = crate::OptionalNodeId::from_raw(raw);
         opt.map(|id| unsafe { Ident::from_node_id_unchecked(id, ast) })
     }
     #[inline]
     pub fn set_label(&self, ast: &mut crate::Ast, label: Option<Ident>) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        node.data.inline_data = crate::OptionalNodeId::from(label.map(|n| n.node_id())).into_raw();
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data =
+            crate::OptionalNodeId::from(label.map(|n| n.node_id())).into_raw();
     }
 }
 impl ContinueStmt {
     #[inline]
     pub fn label(&self, ast: &crate::Ast) -> Option<Ident> {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = unsafe { node.data.inline_data };
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
         let opt = crate::OptionalNodeId::from_raw(raw);
         opt.map(|id| unsafe { Ident::from_node_id_unchecked(id, ast) })
     }
     #[inline]
     pub fn set_label(&self, ast: &mut crate::Ast, label: Option<Ident>) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        node.data.inline_data = crate::OptionalNodeId::from(label.map(|n| n.node_id())).into_raw();
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data =
+            crate::OptionalNodeId::from(label.map(|n| n.node_id())).into_raw();
     }
 }
 impl IfStmt {
@@ -1706,8 +1754,8 @@ impl IfStmt {
     pub fn test(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1725,8 +1773,8 @@ impl IfStmt {
     pub fn cons(&self, ast: &crate::Ast) -> Stmt {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1744,8 +1792,8 @@ impl IfStmt {
     pub fn alt(&self, ast: &crate::Ast) -> Option<Stmt> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -1763,8 +1811,8 @@ impl IfStmt {
     pub fn set_test(&self, ast: &mut crate::Ast, test: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1781,8 +1829,8 @@ impl IfStmt {
     pub fn set_cons(&self, ast: &mut crate::Ast, cons: Stmt) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1799,8 +1847,8 @@ impl IfStmt {
     pub fn set_alt(&self, ast: &mut crate::Ast, alt: Option<Stmt>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -1819,8 +1867,8 @@ impl SwitchStmt {
     pub fn discriminant(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1838,8 +1886,8 @@ impl SwitchStmt {
     pub fn cases(&self, ast: &crate::Ast) -> TypedSubRange<SwitchCase> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1857,8 +1905,8 @@ impl SwitchStmt {
     pub fn set_discriminant(&self, ast: &mut crate::Ast, discriminant: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1875,8 +1923,8 @@ impl SwitchStmt {
     pub fn set_cases(&self, ast: &mut crate::Ast, cases: TypedSubRange<SwitchCase>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1895,8 +1943,8 @@ impl ThrowStmt {
     pub fn arg(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1914,8 +1962,8 @@ impl ThrowStmt {
     pub fn set_arg(&self, ast: &mut crate::Ast, arg: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1934,8 +1982,8 @@ impl TryStmt {
     pub fn block(&self, ast: &crate::Ast) -> BlockStmt {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -1953,8 +2001,8 @@ impl TryStmt {
     pub fn handler(&self, ast: &crate::Ast) -> Option<CatchClause> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -1972,8 +2020,8 @@ impl TryStmt {
     pub fn finalizer(&self, ast: &crate::Ast) -> Option<BlockStmt> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -1991,8 +2039,8 @@ impl TryStmt {
     pub fn set_block(&self, ast: &mut crate::Ast, block: BlockStmt) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2009,8 +2057,8 @@ impl TryStmt {
     pub fn set_handler(&self, ast: &mut crate::Ast, handler: Option<CatchClause>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2027,8 +2075,8 @@ impl TryStmt {
     pub fn set_finalizer(&self, ast: &mut crate::Ast, finalizer: Option<BlockStmt>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -2047,8 +2095,8 @@ impl WhileStmt {
     pub fn test(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2066,8 +2114,8 @@ impl WhileStmt {
     pub fn body(&self, ast: &crate::Ast) -> Stmt {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2085,8 +2133,8 @@ impl WhileStmt {
     pub fn set_test(&self, ast: &mut crate::Ast, test: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2103,8 +2151,8 @@ impl WhileStmt {
     pub fn set_body(&self, ast: &mut crate::Ast, body: Stmt) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2123,8 +2171,8 @@ impl DoWhileStmt {
     pub fn test(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2142,8 +2190,8 @@ impl DoWhileStmt {
     pub fn body(&self, ast: &crate::Ast) -> Stmt {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2161,8 +2209,8 @@ impl DoWhileStmt {
     pub fn set_test(&self, ast: &mut crate::Ast, test: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2179,8 +2227,8 @@ impl DoWhileStmt {
     pub fn set_body(&self, ast: &mut crate::Ast, body: Stmt) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2199,8 +2247,8 @@ impl ForStmt {
     pub fn init(&self, ast: &crate::Ast) -> Option<VarDeclOrExpr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2218,8 +2266,8 @@ impl ForStmt {
     pub fn test(&self, ast: &crate::Ast) -> Option<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2237,8 +2285,8 @@ impl ForStmt {
     pub fn update(&self, ast: &crate::Ast) -> Option<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -2256,8 +2304,8 @@ impl ForStmt {
     pub fn body(&self, ast: &crate::Ast) -> Stmt {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -2275,8 +2323,8 @@ impl ForStmt {
     pub fn set_init(&self, ast: &mut crate::Ast, init: Option<VarDeclOrExpr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2293,8 +2341,8 @@ impl ForStmt {
     pub fn set_test(&self, ast: &mut crate::Ast, test: Option<Expr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2311,8 +2359,8 @@ impl ForStmt {
     pub fn set_update(&self, ast: &mut crate::Ast, update: Option<Expr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -2329,8 +2377,8 @@ impl ForStmt {
     pub fn set_body(&self, ast: &mut crate::Ast, body: Stmt) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -2349,8 +2397,8 @@ impl ForInStmt {
     pub fn left(&self, ast: &crate::Ast) -> ForHead {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2368,8 +2416,8 @@ impl ForInStmt {
     pub fn right(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2387,8 +2435,8 @@ impl ForInStmt {
     pub fn body(&self, ast: &crate::Ast) -> Stmt {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -2406,8 +2454,8 @@ impl ForInStmt {
     pub fn set_left(&self, ast: &mut crate::Ast, left: ForHead) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2424,8 +2472,8 @@ impl ForInStmt {
     pub fn set_right(&self, ast: &mut crate::Ast, right: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2442,8 +2490,8 @@ impl ForInStmt {
     pub fn set_body(&self, ast: &mut crate::Ast, body: Stmt) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -2460,16 +2508,15 @@ impl ForInStmt {
 impl ForOfStmt {
     #[inline]
     pub fn is_await(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         raw != 0
     }
     #[inline]
     pub fn left(&self, ast: &crate::Ast) -> ForHead {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2487,8 +2534,8 @@ impl ForOfStmt {
     pub fn right(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2506,8 +2553,8 @@ impl ForOfStmt {
     pub fn body(&self, ast: &crate::Ast) -> Stmt {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -2523,17 +2570,21 @@ impl ForOfStmt {
     }
     #[inline]
     pub fn set_is_await(&self, ast: &mut crate::Ast, is_await: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = is_await as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_left(&self, ast: &mut crate::Ast, left: ForHead) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2550,8 +2601,8 @@ impl ForOfStmt {
     pub fn set_right(&self, ast: &mut crate::Ast, right: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2568,8 +2619,8 @@ impl ForOfStmt {
     pub fn set_body(&self, ast: &mut crate::Ast, body: Stmt) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -2588,8 +2639,8 @@ impl SwitchCase {
     pub fn test(&self, ast: &crate::Ast) -> Option<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2607,8 +2658,8 @@ impl SwitchCase {
     pub fn cons(&self, ast: &crate::Ast) -> TypedSubRange<Stmt> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2626,8 +2677,8 @@ impl SwitchCase {
     pub fn set_test(&self, ast: &mut crate::Ast, test: Option<Expr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2644,8 +2695,8 @@ impl SwitchCase {
     pub fn set_cons(&self, ast: &mut crate::Ast, cons: TypedSubRange<Stmt>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2664,8 +2715,8 @@ impl CatchClause {
     pub fn param(&self, ast: &crate::Ast) -> Option<Pat> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2683,8 +2734,8 @@ impl CatchClause {
     pub fn body(&self, ast: &crate::Ast) -> BlockStmt {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2702,8 +2753,8 @@ impl CatchClause {
     pub fn set_param(&self, ast: &mut crate::Ast, param: Option<Pat>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2720,8 +2771,8 @@ impl CatchClause {
     pub fn set_body(&self, ast: &mut crate::Ast, body: BlockStmt) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2812,6 +2863,22 @@ impl Decl {
         matches!(self, Self::Using(_))
     }
     #[inline]
+    pub fn is_ts_interface(&self) -> bool {
+        matches!(self, Self::TsInterface(_))
+    }
+    #[inline]
+    pub fn is_ts_type_alias(&self) -> bool {
+        matches!(self, Self::TsTypeAlias(_))
+    }
+    #[inline]
+    pub fn is_ts_enum(&self) -> bool {
+        matches!(self, Self::TsEnum(_))
+    }
+    #[inline]
+    pub fn is_ts_module(&self) -> bool {
+        matches!(self, Self::TsModule(_))
+    }
+    #[inline]
     pub fn as_class(self) -> Option<ClassDecl> {
         match self {
             Self::Class(it) => Some(it),
@@ -2839,20 +2906,47 @@ impl Decl {
             _ => None,
         }
     }
+    #[inline]
+    pub fn as_ts_interface(self) -> Option<TsInterfaceDecl> {
+        match self {
+            Self::TsInterface(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_type_alias(self) -> Option<TsTypeAliasDecl> {
+        match self {
+            Self::TsTypeAlias(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_enum(self) -> Option<TsEnumDecl> {
+        match self {
+            Self::TsEnum(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_module(self) -> Option<TsModuleDecl> {
+        match self {
+            Self::TsModule(it) => Some(it),
+            _ => None,
+        }
+    }
 }
 impl FnDecl {
     #[inline]
     pub fn declare(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         raw != 0
     }
     #[inline]
     pub fn ident(&self, ast: &crate::Ast) -> Ident {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2870,8 +2964,8 @@ impl FnDecl {
     pub fn function(&self, ast: &crate::Ast) -> Function {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2887,17 +2981,21 @@ impl FnDecl {
     }
     #[inline]
     pub fn set_declare(&self, ast: &mut crate::Ast, declare: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = declare as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_ident(&self, ast: &mut crate::Ast, ident: Ident) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2914,8 +3012,8 @@ impl FnDecl {
     pub fn set_function(&self, ast: &mut crate::Ast, function: Function) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2932,16 +3030,15 @@ impl FnDecl {
 impl ClassDecl {
     #[inline]
     pub fn declare(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         raw != 0
     }
     #[inline]
     pub fn ident(&self, ast: &crate::Ast) -> Ident {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -2959,8 +3056,8 @@ impl ClassDecl {
     pub fn class(&self, ast: &crate::Ast) -> Class {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -2976,17 +3073,21 @@ impl ClassDecl {
     }
     #[inline]
     pub fn set_declare(&self, ast: &mut crate::Ast, declare: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = declare as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_ident(&self, ast: &mut crate::Ast, ident: Ident) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3003,8 +3104,8 @@ impl ClassDecl {
     pub fn set_class(&self, ast: &mut crate::Ast, class: Class) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -3023,8 +3124,8 @@ impl VarDecl {
     pub fn kind(&self, ast: &crate::Ast) -> VarDeclKind {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3042,8 +3143,8 @@ impl VarDecl {
     pub fn declare(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -3061,8 +3162,8 @@ impl VarDecl {
     pub fn decls(&self, ast: &crate::Ast) -> TypedSubRange<VarDeclarator> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -3080,8 +3181,8 @@ impl VarDecl {
     pub fn set_kind(&self, ast: &mut crate::Ast, kind: VarDeclKind) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3098,8 +3199,8 @@ impl VarDecl {
     pub fn set_declare(&self, ast: &mut crate::Ast, declare: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -3116,8 +3217,8 @@ impl VarDecl {
     pub fn set_decls(&self, ast: &mut crate::Ast, decls: TypedSubRange<VarDeclarator>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -3132,12 +3233,17 @@ impl VarDecl {
     }
 }
 impl VarDeclarator {
+    #[inline]
+    pub fn definite(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
+    }
     #[inline]
     pub fn name(&self, ast: &crate::Ast) -> Pat {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3155,8 +3261,8 @@ impl VarDeclarator {
     pub fn init(&self, ast: &crate::Ast) -> Option<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -3171,11 +3277,22 @@ impl VarDeclarator {
         }
     }
     #[inline]
+    pub fn set_definite(&self, ast: &mut crate::Ast, definite: bool) {
+        let field_val: u32 = definite as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
     pub fn set_name(&self, ast: &mut crate::Ast, name: Pat) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3192,8 +3309,8 @@ impl VarDeclarator {
     pub fn set_init(&self, ast: &mut crate::Ast, init: Option<Expr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -3212,8 +3329,8 @@ impl UsingDecl {
     pub fn is_await(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3231,8 +3348,8 @@ impl UsingDecl {
     pub fn decls(&self, ast: &crate::Ast) -> TypedSubRange<VarDeclarator> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -3250,8 +3367,8 @@ impl UsingDecl {
     pub fn set_is_await(&self, ast: &mut crate::Ast, is_await: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3268,8 +3385,8 @@ impl UsingDecl {
     pub fn set_decls(&self, ast: &mut crate::Ast, decls: TypedSubRange<VarDeclarator>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -3401,6 +3518,30 @@ impl Expr {
         matches!(self, Self::JSXFragment(_))
     }
     #[inline]
+    pub fn is_ts_type_assertion(&self) -> bool {
+        matches!(self, Self::TsTypeAssertion(_))
+    }
+    #[inline]
+    pub fn is_ts_const_assertion(&self) -> bool {
+        matches!(self, Self::TsConstAssertion(_))
+    }
+    #[inline]
+    pub fn is_ts_non_null(&self) -> bool {
+        matches!(self, Self::TsNonNull(_))
+    }
+    #[inline]
+    pub fn is_ts_as(&self) -> bool {
+        matches!(self, Self::TsAs(_))
+    }
+    #[inline]
+    pub fn is_ts_instantiation(&self) -> bool {
+        matches!(self, Self::TsInstantiation(_))
+    }
+    #[inline]
+    pub fn is_ts_satisfies(&self) -> bool {
+        matches!(self, Self::TsSatisfies(_))
+    }
+    #[inline]
     pub fn is_private_name(&self) -> bool {
         matches!(self, Self::PrivateName(_))
     }
@@ -3616,6 +3757,48 @@ impl Expr {
         }
     }
     #[inline]
+    pub fn as_ts_type_assertion(self) -> Option<TsTypeAssertion> {
+        match self {
+            Self::TsTypeAssertion(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_const_assertion(self) -> Option<TsConstAssertion> {
+        match self {
+            Self::TsConstAssertion(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_non_null(self) -> Option<TsNonNullExpr> {
+        match self {
+            Self::TsNonNull(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_as(self) -> Option<TsAsExpr> {
+        match self {
+            Self::TsAs(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_instantiation(self) -> Option<TsInstantiation> {
+        match self {
+            Self::TsInstantiation(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_satisfies(self) -> Option<TsSatisfiesExpr> {
+        match self {
+            Self::TsSatisfies(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
     pub fn as_private_name(self) -> Option<PrivateName> {
         match self {
             Self::PrivateName(it) => Some(it),
@@ -3643,8 +3826,8 @@ impl ArrayLit {
     pub fn elems(&self, ast: &crate::Ast) -> TypedSubRange<Option<ExprOrSpread>> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3662,8 +3845,8 @@ impl ArrayLit {
     pub fn set_elems(&self, ast: &mut crate::Ast, elems: TypedSubRange<Option<ExprOrSpread>>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3682,8 +3865,8 @@ impl ObjectLit {
     pub fn props(&self, ast: &crate::Ast) -> TypedSubRange<PropOrSpread> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3701,8 +3884,8 @@ impl ObjectLit {
     pub fn set_props(&self, ast: &mut crate::Ast, props: TypedSubRange<PropOrSpread>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3745,8 +3928,8 @@ impl SpreadElement {
     pub fn dot_3_token(&self, ast: &crate::Ast) -> Span {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3764,8 +3947,8 @@ impl SpreadElement {
     pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -3783,8 +3966,8 @@ impl SpreadElement {
     pub fn set_dot3_token(&self, ast: &mut crate::Ast, dot3_token: Span) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3801,8 +3984,8 @@ impl SpreadElement {
     pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -3819,16 +4002,15 @@ impl SpreadElement {
 impl UnaryExpr {
     #[inline]
     pub fn op(&self, ast: &crate::Ast) -> UnaryOp {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         unsafe { std::mem::transmute::<u8, UnaryOp>(raw as u8) }
     }
     #[inline]
     pub fn arg(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3844,17 +4026,21 @@ impl UnaryExpr {
     }
     #[inline]
     pub fn set_op(&self, ast: &mut crate::Ast, op: UnaryOp) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = op as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_arg(&self, ast: &mut crate::Ast, arg: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3871,22 +4057,21 @@ impl UnaryExpr {
 impl UpdateExpr {
     #[inline]
     pub fn op(&self, ast: &crate::Ast) -> UpdateOp {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         unsafe { std::mem::transmute::<u8, UpdateOp>(raw as u8) }
     }
     #[inline]
     pub fn prefix(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = (u32::from(node.inline_data) >> 8usize) & 255u32;
+        let raw =
+            (u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) >> 8usize) & 255u32;
         raw != 0
     }
     #[inline]
     pub fn arg(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3902,24 +4087,32 @@ impl UpdateExpr {
     }
     #[inline]
     pub fn set_op(&self, ast: &mut crate::Ast, op: UpdateOp) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = op as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_prefix(&self, ast: &mut crate::Ast, prefix: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = prefix as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16711935u32) | ((field_val & 255u32) << 8usize)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16711935u32) | ((field_val & 255u32) << 8usize)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_arg(&self, ast: &mut crate::Ast, arg: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3936,16 +4129,15 @@ impl UpdateExpr {
 impl BinExpr {
     #[inline]
     pub fn op(&self, ast: &crate::Ast) -> BinaryOp {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         unsafe { std::mem::transmute::<u8, BinaryOp>(raw as u8) }
     }
     #[inline]
     pub fn left(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -3963,8 +4155,8 @@ impl BinExpr {
     pub fn right(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -3980,17 +4172,21 @@ impl BinExpr {
     }
     #[inline]
     pub fn set_op(&self, ast: &mut crate::Ast, op: BinaryOp) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = op as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_left(&self, ast: &mut crate::Ast, left: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4007,8 +4203,8 @@ impl BinExpr {
     pub fn set_right(&self, ast: &mut crate::Ast, right: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4027,8 +4223,8 @@ impl FnExpr {
     pub fn ident(&self, ast: &crate::Ast) -> Option<Ident> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4046,8 +4242,8 @@ impl FnExpr {
     pub fn function(&self, ast: &crate::Ast) -> Function {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4065,8 +4261,8 @@ impl FnExpr {
     pub fn set_ident(&self, ast: &mut crate::Ast, ident: Option<Ident>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4083,8 +4279,8 @@ impl FnExpr {
     pub fn set_function(&self, ast: &mut crate::Ast, function: Function) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4103,8 +4299,8 @@ impl ClassExpr {
     pub fn ident(&self, ast: &crate::Ast) -> Option<Ident> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4122,8 +4318,8 @@ impl ClassExpr {
     pub fn class(&self, ast: &crate::Ast) -> Class {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4141,8 +4337,8 @@ impl ClassExpr {
     pub fn set_ident(&self, ast: &mut crate::Ast, ident: Option<Ident>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4159,8 +4355,8 @@ impl ClassExpr {
     pub fn set_class(&self, ast: &mut crate::Ast, class: Class) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4177,16 +4373,15 @@ impl ClassExpr {
 impl AssignExpr {
     #[inline]
     pub fn op(&self, ast: &crate::Ast) -> AssignOp {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         unsafe { std::mem::transmute::<u8, AssignOp>(raw as u8) }
     }
     #[inline]
     pub fn left(&self, ast: &crate::Ast) -> AssignTarget {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4204,8 +4399,8 @@ impl AssignExpr {
     pub fn right(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4221,17 +4416,21 @@ impl AssignExpr {
     }
     #[inline]
     pub fn set_op(&self, ast: &mut crate::Ast, op: AssignOp) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = op as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_left(&self, ast: &mut crate::Ast, left: AssignTarget) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4248,8 +4447,8 @@ impl AssignExpr {
     pub fn set_right(&self, ast: &mut crate::Ast, right: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4268,8 +4467,8 @@ impl MemberExpr {
     pub fn obj(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4287,8 +4486,8 @@ impl MemberExpr {
     pub fn prop(&self, ast: &crate::Ast) -> MemberProp {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4306,8 +4505,8 @@ impl MemberExpr {
     pub fn set_obj(&self, ast: &mut crate::Ast, obj: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4324,8 +4523,8 @@ impl MemberExpr {
     pub fn set_prop(&self, ast: &mut crate::Ast, prop: MemberProp) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4379,8 +4578,8 @@ impl SuperPropExpr {
     pub fn obj(&self, ast: &crate::Ast) -> Super {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4398,8 +4597,8 @@ impl SuperPropExpr {
     pub fn prop(&self, ast: &crate::Ast) -> SuperProp {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4417,8 +4616,8 @@ impl SuperPropExpr {
     pub fn set_obj(&self, ast: &mut crate::Ast, obj: Super) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4435,8 +4634,8 @@ impl SuperPropExpr {
     pub fn set_prop(&self, ast: &mut crate::Ast, prop: SuperProp) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4479,8 +4678,8 @@ impl CondExpr {
     pub fn test(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4498,8 +4697,8 @@ impl CondExpr {
     pub fn cons(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4517,8 +4716,8 @@ impl CondExpr {
     pub fn alt(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -4536,8 +4735,8 @@ impl CondExpr {
     pub fn set_test(&self, ast: &mut crate::Ast, test: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4554,8 +4753,8 @@ impl CondExpr {
     pub fn set_cons(&self, ast: &mut crate::Ast, cons: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4572,8 +4771,8 @@ impl CondExpr {
     pub fn set_alt(&self, ast: &mut crate::Ast, alt: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -4592,8 +4791,8 @@ impl CallExpr {
     pub fn callee(&self, ast: &crate::Ast) -> Callee {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4611,8 +4810,8 @@ impl CallExpr {
     pub fn args(&self, ast: &crate::Ast) -> TypedSubRange<ExprOrSpread> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4627,11 +4826,30 @@ impl CallExpr {
         }
     }
     #[inline]
+    pub fn type_args(&self, ast: &crate::Ast) -> Option<TsTypeParamInstantiation> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
     pub fn set_callee(&self, ast: &mut crate::Ast, callee: Callee) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4648,8 +4866,8 @@ impl CallExpr {
     pub fn set_args(&self, ast: &mut crate::Ast, args: TypedSubRange<ExprOrSpread>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4662,14 +4880,32 @@ impl CallExpr {
                 .get_unchecked_mut(offset.index()) = args.to_extra_data();
         };
     }
+    #[inline]
+    pub fn set_type_args(&self, ast: &mut crate::Ast, type_args: Option<TsTypeParamInstantiation>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_args.to_extra_data();
+        };
+    }
 }
 impl NewExpr {
     #[inline]
     pub fn callee(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4687,8 +4923,8 @@ impl NewExpr {
     pub fn args(&self, ast: &crate::Ast) -> Option<TypedSubRange<ExprOrSpread>> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4703,11 +4939,30 @@ impl NewExpr {
         }
     }
     #[inline]
+    pub fn type_args(&self, ast: &crate::Ast) -> Option<TsTypeParamInstantiation> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
     pub fn set_callee(&self, ast: &mut crate::Ast, callee: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4724,8 +4979,8 @@ impl NewExpr {
     pub fn set_args(&self, ast: &mut crate::Ast, args: Option<TypedSubRange<ExprOrSpread>>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4738,14 +4993,32 @@ impl NewExpr {
                 .get_unchecked_mut(offset.index()) = args.to_extra_data();
         };
     }
+    #[inline]
+    pub fn set_type_args(&self, ast: &mut crate::Ast, type_args: Option<TsTypeParamInstantiation>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_args.to_extra_data();
+        };
+    }
 }
 impl SeqExpr {
     #[inline]
     pub fn exprs(&self, ast: &crate::Ast) -> TypedSubRange<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4763,8 +5036,8 @@ impl SeqExpr {
     pub fn set_exprs(&self, ast: &mut crate::Ast, exprs: TypedSubRange<Expr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4783,8 +5056,8 @@ impl ArrowExpr {
     pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<Pat> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4802,8 +5075,8 @@ impl ArrowExpr {
     pub fn body(&self, ast: &crate::Ast) -> BlockStmtOrExpr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4821,8 +5094,8 @@ impl ArrowExpr {
     pub fn is_async(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -4840,8 +5113,8 @@ impl ArrowExpr {
     pub fn is_generator(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -4856,11 +5129,49 @@ impl ArrowExpr {
         }
     }
     #[inline]
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamDecl> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn return_type(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(5usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
     pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<Pat>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -4877,8 +5188,8 @@ impl ArrowExpr {
     pub fn set_body(&self, ast: &mut crate::Ast, body: BlockStmtOrExpr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -4895,8 +5206,8 @@ impl ArrowExpr {
     pub fn set_is_async(&self, ast: &mut crate::Ast, is_async: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -4913,8 +5224,8 @@ impl ArrowExpr {
     pub fn set_is_generator(&self, ast: &mut crate::Ast, is_generator: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -4927,70 +5238,107 @@ impl ArrowExpr {
                 .get_unchecked_mut(offset.index()) = is_generator.to_extra_data();
         };
     }
-}
-impl YieldExpr {
-    #[inline]
-    pub fn delegate(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
-        raw != 0
-    }
     #[inline]
-    pub fn arg(&self, ast: &crate::Ast) -> Option<Expr> {
+    pub fn set_type_params(&self, ast: &mut crate::Ast, type_params: Option<TsTypeParamDecl>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
-    }
-    #[inline]
-    pub fn set_delegate(&self, ast: &mut crate::Ast, delegate: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        let field_val: u32 = delegate as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
     }
     #[inline]
-    pub fn set_arg(&self, ast: &mut crate::Ast, arg: Option<Expr>) {
+    pub fn set_return_type(&self, ast: &mut crate::Ast, return_type: Option<TsTypeAnn>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(5usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = arg.to_extra_data();
+                .get_unchecked_mut(offset.index()) = return_type.to_extra_data();
         };
     }
 }
-impl MetaPropExpr {
+impl YieldExpr {
+    #[inline]
+    pub fn delegate(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn arg(&self, ast: &crate::Ast) -> Option<Expr> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_delegate(&self, ast: &mut crate::Ast, delegate: bool) {
+        let field_val: u32 = delegate as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_arg(&self, ast: &mut crate::Ast, arg: Option<Expr>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = arg.to_extra_data();
+        };
+    }
+}
+impl MetaPropExpr {
     #[inline]
     pub fn kind(&self, ast: &crate::Ast) -> MetaPropKind {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = unsafe { node.data.inline_data };
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
         unsafe { std::mem::transmute::<u8, MetaPropKind>(raw as u8) }
     }
     #[inline]
     pub fn set_kind(&self, ast: &mut crate::Ast, kind: MetaPropKind) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        node.data.inline_data = kind as u32;
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data = kind as u32;
     }
 }
 impl AwaitExpr {
@@ -4998,8 +5346,8 @@ impl AwaitExpr {
     pub fn arg(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5017,8 +5365,8 @@ impl AwaitExpr {
     pub fn set_arg(&self, ast: &mut crate::Ast, arg: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5037,8 +5385,8 @@ impl Tpl {
     pub fn exprs(&self, ast: &crate::Ast) -> TypedSubRange<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5056,8 +5404,8 @@ impl Tpl {
     pub fn quasis(&self, ast: &crate::Ast) -> TypedSubRange<TplElement> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5075,8 +5423,8 @@ impl Tpl {
     pub fn set_exprs(&self, ast: &mut crate::Ast, exprs: TypedSubRange<Expr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5093,8 +5441,8 @@ impl Tpl {
     pub fn set_quasis(&self, ast: &mut crate::Ast, quasis: TypedSubRange<TplElement>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5113,8 +5461,8 @@ impl TaggedTpl {
     pub fn tag(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5129,11 +5477,11 @@ impl TaggedTpl {
         }
     }
     #[inline]
-    pub fn tpl(&self, ast: &crate::Ast) -> Tpl {
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamInstantiation> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5148,11 +5496,30 @@ impl TaggedTpl {
         }
     }
     #[inline]
+    pub fn tpl(&self, ast: &crate::Ast) -> Tpl {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
     pub fn set_tag(&self, ast: &mut crate::Ast, tag: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5166,17 +5533,39 @@ impl TaggedTpl {
         };
     }
     #[inline]
-    pub fn set_tpl(&self, ast: &mut crate::Ast, tpl: Tpl) {
+    pub fn set_type_params(
+        &self,
+        ast: &mut crate::Ast,
+        type_params: Option<TsTypeParamInstantiation>,
+    ) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_tpl(&self, ast: &mut crate::Ast, tpl: Tpl) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
@@ -5187,16 +5576,15 @@ impl TaggedTpl {
 impl TplElement {
     #[inline]
     pub fn tail(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         raw != 0
     }
     #[inline]
     pub fn cooked(&self, ast: &crate::Ast) -> OptionalWtf8Ref {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5214,8 +5602,8 @@ impl TplElement {
     pub fn raw(&self, ast: &crate::Ast) -> Utf8Ref {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5231,17 +5619,21 @@ impl TplElement {
     }
     #[inline]
     pub fn set_tail(&self, ast: &mut crate::Ast, tail: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = tail as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_cooked(&self, ast: &mut crate::Ast, cooked: OptionalWtf8Ref) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5258,8 +5650,8 @@ impl TplElement {
     pub fn set_raw(&self, ast: &mut crate::Ast, raw: Utf8Ref) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5278,8 +5670,8 @@ impl ParenExpr {
     pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5297,8 +5689,8 @@ impl ParenExpr {
     pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5351,14 +5743,12 @@ impl Super {}
 impl Import {
     #[inline]
     pub fn phase(&self, ast: &crate::Ast) -> ImportPhase {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = unsafe { node.data.inline_data };
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
         unsafe { std::mem::transmute::<u8, ImportPhase>(raw as u8) }
     }
     #[inline]
     pub fn set_phase(&self, ast: &mut crate::Ast, phase: ImportPhase) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        node.data.inline_data = phase as u32;
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data = phase as u32;
     }
 }
 impl ExprOrSpread {
@@ -5366,8 +5756,8 @@ impl ExprOrSpread {
     pub fn spread(&self, ast: &crate::Ast) -> Option<SpreadDot3Token> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5385,8 +5775,8 @@ impl ExprOrSpread {
     pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5404,8 +5794,8 @@ impl ExprOrSpread {
     pub fn set_spread(&self, ast: &mut crate::Ast, spread: Option<SpreadDot3Token>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5422,8 +5812,8 @@ impl ExprOrSpread {
     pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5543,6 +5933,26 @@ impl SimpleAssignTarget {
         matches!(self, Self::OptChain(_))
     }
     #[inline]
+    pub fn is_ts_as(&self) -> bool {
+        matches!(self, Self::TsAs(_))
+    }
+    #[inline]
+    pub fn is_ts_satisfies(&self) -> bool {
+        matches!(self, Self::TsSatisfies(_))
+    }
+    #[inline]
+    pub fn is_ts_non_null(&self) -> bool {
+        matches!(self, Self::TsNonNull(_))
+    }
+    #[inline]
+    pub fn is_ts_type_assertion(&self) -> bool {
+        matches!(self, Self::TsTypeAssertion(_))
+    }
+    #[inline]
+    pub fn is_ts_instantiation(&self) -> bool {
+        matches!(self, Self::TsInstantiation(_))
+    }
+    #[inline]
     pub fn is_invalid(&self) -> bool {
         matches!(self, Self::Invalid(_))
     }
@@ -5582,6 +5992,41 @@ impl SimpleAssignTarget {
         }
     }
     #[inline]
+    pub fn as_ts_as(self) -> Option<TsAsExpr> {
+        match self {
+            Self::TsAs(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_satisfies(self) -> Option<TsSatisfiesExpr> {
+        match self {
+            Self::TsSatisfies(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_non_null(self) -> Option<TsNonNullExpr> {
+        match self {
+            Self::TsNonNull(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_type_assertion(self) -> Option<TsTypeAssertion> {
+        match self {
+            Self::TsTypeAssertion(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_instantiation(self) -> Option<TsInstantiation> {
+        match self {
+            Self::TsInstantiation(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
     pub fn as_invalid(self) -> Option<Invalid> {
         match self {
             Self::Invalid(it) => Some(it),
@@ -5592,16 +6037,15 @@ impl SimpleAssignTarget {
 impl OptChainExpr {
     #[inline]
     pub fn optional(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
         raw != 0
     }
     #[inline]
     pub fn base(&self, ast: &crate::Ast) -> OptChainBase {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5617,17 +6061,21 @@ impl OptChainExpr {
     }
     #[inline]
     pub fn set_optional(&self, ast: &mut crate::Ast, optional: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
         let field_val: u32 = optional as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
     pub fn set_base(&self, ast: &mut crate::Ast, base: OptChainBase) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5670,8 +6118,8 @@ impl OptCall {
     pub fn callee(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5689,8 +6137,8 @@ impl OptCall {
     pub fn args(&self, ast: &crate::Ast) -> TypedSubRange<ExprOrSpread> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5705,11 +6153,30 @@ impl OptCall {
         }
     }
     #[inline]
+    pub fn type_args(&self, ast: &crate::Ast) -> Option<TsTypeParamInstantiation> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
     pub fn set_callee(&self, ast: &mut crate::Ast, callee: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5726,8 +6193,8 @@ impl OptCall {
     pub fn set_args(&self, ast: &mut crate::Ast, args: TypedSubRange<ExprOrSpread>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5740,6 +6207,24 @@ impl OptCall {
                 .get_unchecked_mut(offset.index()) = args.to_extra_data();
         };
     }
+    #[inline]
+    pub fn set_type_args(&self, ast: &mut crate::Ast, type_args: Option<TsTypeParamInstantiation>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_args.to_extra_data();
+        };
+    }
 }
 impl Invalid {}
 impl Function {
@@ -5747,8 +6232,8 @@ impl Function {
     pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<Param> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5766,8 +6251,8 @@ impl Function {
     pub fn decorators(&self, ast: &crate::Ast) -> TypedSubRange<Decorator> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5785,8 +6270,8 @@ impl Function {
     pub fn body(&self, ast: &crate::Ast) -> Option<BlockStmt> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -5804,8 +6289,8 @@ impl Function {
     pub fn is_generator(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -5823,8 +6308,8 @@ impl Function {
     pub fn is_async(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(4usize),
@@ -5839,11 +6324,49 @@ impl Function {
         }
     }
     #[inline]
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamDecl> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(5usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn return_type(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(6usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
     pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<Param>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5860,8 +6383,8 @@ impl Function {
     pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5878,8 +6401,8 @@ impl Function {
     pub fn set_body(&self, ast: &mut crate::Ast, body: Option<BlockStmt>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -5896,8 +6419,8 @@ impl Function {
     pub fn set_is_generator(&self, ast: &mut crate::Ast, is_generator: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -5914,8 +6437,8 @@ impl Function {
     pub fn set_is_async(&self, ast: &mut crate::Ast, is_async: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(4usize),
@@ -5928,14 +6451,50 @@ impl Function {
                 .get_unchecked_mut(offset.index()) = is_async.to_extra_data();
         };
     }
+    #[inline]
+    pub fn set_type_params(&self, ast: &mut crate::Ast, type_params: Option<TsTypeParamDecl>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(5usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_return_type(&self, ast: &mut crate::Ast, return_type: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(6usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = return_type.to_extra_data();
+        };
+    }
 }
 impl Param {
     #[inline]
     pub fn decorators(&self, ast: &crate::Ast) -> TypedSubRange<Decorator> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5953,8 +6512,8 @@ impl Param {
     pub fn pat(&self, ast: &crate::Ast) -> Pat {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -5972,8 +6531,8 @@ impl Param {
     pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -5990,8 +6549,8 @@ impl Param {
     pub fn set_pat(&self, ast: &mut crate::Ast, pat: Pat) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -6006,11 +6565,22 @@ impl Param {
     }
 }
 impl ParamOrTsParamProp {
+    #[inline]
+    pub fn is_ts_param_prop(&self) -> bool {
+        matches!(self, Self::TsParamProp(_))
+    }
     #[inline]
     pub fn is_param(&self) -> bool {
         matches!(self, Self::Param(_))
     }
     #[inline]
+    pub fn as_ts_param_prop(self) -> Option<TsParamProp> {
+        match self {
+            Self::TsParamProp(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
     pub fn as_param(self) -> Option<Param> {
         match self {
             Self::Param(it) => Some(it),
@@ -6023,8 +6593,8 @@ impl Class {
     pub fn decorators(&self, ast: &crate::Ast) -> TypedSubRange<Decorator> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -6042,8 +6612,8 @@ impl Class {
     pub fn body(&self, ast: &crate::Ast) -> TypedSubRange<ClassMember> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -6061,8 +6631,8 @@ impl Class {
     pub fn super_class(&self, ast: &crate::Ast) -> Option<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -6080,8 +6650,8 @@ impl Class {
     pub fn is_abstract(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -6096,79 +6666,198 @@ impl Class {
         }
     }
     #[inline]
-    pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamDecl> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = decorators.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn set_body(&self, ast: &mut crate::Ast, body: TypedSubRange<ClassMember>) {
+    pub fn super_type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamInstantiation> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(5usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = body.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn set_super_class(&self, ast: &mut crate::Ast, super_class: Option<Expr>) {
+    pub fn implements(&self, ast: &crate::Ast) -> TypedSubRange<TsExprWithTypeArgs> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(2usize),
+                    .wrapping_add(6usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = super_class.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn set_is_abstract(&self, ast: &mut crate::Ast, is_abstract: bool) {
+    pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(3usize),
+                    .wrapping_add(0usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = is_abstract.to_extra_data();
+                .get_unchecked_mut(offset.index()) = decorators.to_extra_data();
         };
     }
-}
-impl ClassMember {
+    #[inline]
+    pub fn set_body(&self, ast: &mut crate::Ast, body: TypedSubRange<ClassMember>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = body.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_super_class(&self, ast: &mut crate::Ast, super_class: Option<Expr>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = super_class.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_abstract(&self, ast: &mut crate::Ast, is_abstract: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_abstract.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_params(&self, ast: &mut crate::Ast, type_params: Option<TsTypeParamDecl>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_super_type_params(
+        &self,
+        ast: &mut crate::Ast,
+        super_type_params: Option<TsTypeParamInstantiation>,
+    ) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(5usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = super_type_params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_implements(
+        &self,
+        ast: &mut crate::Ast,
+        implements: TypedSubRange<TsExprWithTypeArgs>,
+    ) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(6usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = implements.to_extra_data();
+        };
+    }
+}
+impl ClassMember {
     #[inline]
     pub fn is_constructor(&self) -> bool {
         matches!(self, Self::Constructor(_))
@@ -6202,6 +6891,10 @@ impl ClassMember {
         matches!(self, Self::AutoAccessor(_))
     }
     #[inline]
+    pub fn is_ts_index_signature(&self) -> bool {
+        matches!(self, Self::TsIndexSignature(_))
+    }
+    #[inline]
     pub fn as_constructor(self) -> Option<Constructor> {
         match self {
             Self::Constructor(it) => Some(it),
@@ -6257,14 +6950,21 @@ impl ClassMember {
             _ => None,
         }
     }
+    #[inline]
+    pub fn as_ts_index_signature(self) -> Option<TsIndexSignature> {
+        match self {
+            Self::TsIndexSignature(it) => Some(it),
+            _ => None,
+        }
+    }
 }
 impl ClassProp {
     #[inline]
     pub fn key(&self, ast: &crate::Ast) -> PropName {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -6282,8 +6982,8 @@ impl ClassProp {
     pub fn value(&self, ast: &crate::Ast) -> Option<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -6298,11 +6998,11 @@ impl ClassProp {
         }
     }
     #[inline]
-    pub fn is_static(&self, ast: &crate::Ast) -> bool {
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -6317,11 +7017,11 @@ impl ClassProp {
         }
     }
     #[inline]
-    pub fn decorators(&self, ast: &crate::Ast) -> TypedSubRange<Decorator> {
+    pub fn is_static(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -6336,88 +7036,90 @@ impl ClassProp {
         }
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+    pub fn decorators(&self, ast: &crate::Ast) -> TypedSubRange<Decorator> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = key.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: Option<Expr>) {
+    pub fn accessibility(&self, ast: &crate::Ast) -> Option<Accessibility> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(5usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = value.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
+    pub fn is_abstract(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(2usize),
+                    .wrapping_add(6usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = is_static.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
+    pub fn is_optional(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(3usize),
+                    .wrapping_add(7usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = decorators.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
-}
-impl PrivateProp {
     #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> PrivateName {
+    pub fn is_override(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(8usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -6429,14 +7131,14 @@ impl PrivateProp {
         }
     }
     #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> Option<Expr> {
+    pub fn readonly(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(9usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -6448,14 +7150,14 @@ impl PrivateProp {
         }
     }
     #[inline]
-    pub fn is_static(&self, ast: &crate::Ast) -> bool {
+    pub fn declare(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(2usize),
+                    .wrapping_add(10usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -6467,14 +7169,14 @@ impl PrivateProp {
         }
     }
     #[inline]
-    pub fn decorators(&self, ast: &crate::Ast) -> TypedSubRange<Decorator> {
+    pub fn definite(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(3usize),
+                    .wrapping_add(11usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -6486,11 +7188,11 @@ impl PrivateProp {
         }
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: PrivateName) {
+    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -6507,8 +7209,8 @@ impl PrivateProp {
     pub fn set_value(&self, ast: &mut crate::Ast, value: Option<Expr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -6522,11 +7224,11 @@ impl PrivateProp {
         };
     }
     #[inline]
-    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -6536,15 +7238,15 @@ impl PrivateProp {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = is_static.to_extra_data();
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
+    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -6554,221 +7256,161 @@ impl PrivateProp {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = decorators.to_extra_data();
+                .get_unchecked_mut(offset.index()) = is_static.to_extra_data();
         };
     }
-}
-impl ClassMethod {
-    #[inline]
-    pub fn kind(&self, ast: &crate::Ast) -> MethodKind {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
-        unsafe { std::mem::transmute::<u8, MethodKind>(raw as u8) }
-    }
-    #[inline]
-    pub fn is_static(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = (u32::from(node.inline_data) >> 8usize) & 255u32;
-        raw != 0
-    }
     #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> PropName {
+    pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = decorators.to_extra_data();
+        };
     }
     #[inline]
-    pub fn function(&self, ast: &crate::Ast) -> Function {
+    pub fn set_accessibility(&self, ast: &mut crate::Ast, accessibility: Option<Accessibility>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(5usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
-    }
-    #[inline]
-    pub fn set_kind(&self, ast: &mut crate::Ast, kind: MethodKind) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        let field_val: u32 = kind as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
-    }
-    #[inline]
-    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        let field_val: u32 = is_static as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16711935u32) | ((field_val & 255u32) << 8usize)).into();
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = accessibility.to_extra_data();
+        };
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+    pub fn set_is_abstract(&self, ast: &mut crate::Ast, is_abstract: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(6usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+                .get_unchecked_mut(offset.index()) = is_abstract.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_function(&self, ast: &mut crate::Ast, function: Function) {
+    pub fn set_is_optional(&self, ast: &mut crate::Ast, is_optional: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(7usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = function.to_extra_data();
+                .get_unchecked_mut(offset.index()) = is_optional.to_extra_data();
         };
     }
-}
-impl PrivateMethod {
-    #[inline]
-    pub fn kind(&self, ast: &crate::Ast) -> MethodKind {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
-        unsafe { std::mem::transmute::<u8, MethodKind>(raw as u8) }
-    }
-    #[inline]
-    pub fn is_static(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = (u32::from(node.inline_data) >> 8usize) & 255u32;
-        raw != 0
-    }
     #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> PrivateName {
+    pub fn set_is_override(&self, ast: &mut crate::Ast, is_override: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(8usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_override.to_extra_data();
+        };
     }
     #[inline]
-    pub fn function(&self, ast: &crate::Ast) -> Function {
+    pub fn set_readonly(&self, ast: &mut crate::Ast, readonly: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(9usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
-    }
-    #[inline]
-    pub fn set_kind(&self, ast: &mut crate::Ast, kind: MethodKind) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        let field_val: u32 = kind as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
-    }
-    #[inline]
-    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        let field_val: u32 = is_static as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16711935u32) | ((field_val & 255u32) << 8usize)).into();
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = readonly.to_extra_data();
+        };
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: PrivateName) {
+    pub fn set_declare(&self, ast: &mut crate::Ast, declare: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(10usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+                .get_unchecked_mut(offset.index()) = declare.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_function(&self, ast: &mut crate::Ast, function: Function) {
+    pub fn set_definite(&self, ast: &mut crate::Ast, definite: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(11usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = function.to_extra_data();
+                .get_unchecked_mut(offset.index()) = definite.to_extra_data();
         };
     }
 }
-impl Constructor {
+impl PrivateProp {
     #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> PropName {
+    pub fn key(&self, ast: &crate::Ast) -> PrivateName {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -6783,11 +7425,11 @@ impl Constructor {
         }
     }
     #[inline]
-    pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<ParamOrTsParamProp> {
+    pub fn value(&self, ast: &crate::Ast) -> Option<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -6802,11 +7444,11 @@ impl Constructor {
         }
     }
     #[inline]
-    pub fn body(&self, ast: &crate::Ast) -> Option<BlockStmt> {
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -6821,70 +7463,33 @@ impl Constructor {
         }
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
-        let offset = unsafe {
-            ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
-                    .extra_data_start
-                    .index()
-                    .wrapping_add(0usize),
-            )
-        };
-        debug_assert!(offset < ast.extra_data.len());
-        unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = key.to_extra_data();
-        };
-    }
-    #[inline]
-    pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<ParamOrTsParamProp>) {
+    pub fn is_static(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(3usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = params.to_extra_data();
-        };
-    }
-    #[inline]
-    pub fn set_body(&self, ast: &mut crate::Ast, body: Option<BlockStmt>) {
-        let offset = unsafe {
-            ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
-                    .extra_data_start
-                    .index()
-                    .wrapping_add(2usize),
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
             )
-        };
-        debug_assert!(offset < ast.extra_data.len());
-        unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = body.to_extra_data();
-        };
+        }
     }
-}
-impl Decorator {
     #[inline]
-    pub fn expr(&self, ast: &crate::Ast) -> Expr {
+    pub fn decorators(&self, ast: &crate::Ast) -> TypedSubRange<Decorator> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -6896,71 +7501,33 @@ impl Decorator {
         }
     }
     #[inline]
-    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
+    pub fn accessibility(&self, ast: &crate::Ast) -> Option<Accessibility> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(5usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
-        };
-    }
-}
-impl StaticBlock {
-    #[inline]
-    pub fn body(&self, ast: &crate::Ast) -> BlockStmt {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = unsafe { node.data.inline_data };
-        unsafe { BlockStmt::from_node_id_unchecked(crate::NodeId::from_raw_unchecked(raw), ast) }
-    }
-    #[inline]
-    pub fn set_body(&self, ast: &mut crate::Ast, body: BlockStmt) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        node.data.inline_data = body.node_id().index() as u32;
-    }
-}
-impl Key {
-    #[inline]
-    pub fn is_private(&self) -> bool {
-        matches!(self, Self::Private(_))
-    }
-    #[inline]
-    pub fn is_public(&self) -> bool {
-        matches!(self, Self::Public(_))
-    }
-    #[inline]
-    pub fn as_private(self) -> Option<PrivateName> {
-        match self {
-            Self::Private(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_public(self) -> Option<PropName> {
-        match self {
-            Self::Public(it) => Some(it),
-            _ => None,
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
-}
-impl AutoAccessor {
     #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> Key {
+    pub fn is_optional(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(6usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -6972,14 +7539,14 @@ impl AutoAccessor {
         }
     }
     #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> Option<Expr> {
+    pub fn is_override(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(7usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -6991,14 +7558,14 @@ impl AutoAccessor {
         }
     }
     #[inline]
-    pub fn is_static(&self, ast: &crate::Ast) -> bool {
+    pub fn readonly(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(2usize),
+                    .wrapping_add(8usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -7010,14 +7577,14 @@ impl AutoAccessor {
         }
     }
     #[inline]
-    pub fn decorators(&self, ast: &crate::Ast) -> TypedSubRange<Decorator> {
+    pub fn definite(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(3usize),
+                    .wrapping_add(9usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -7029,11 +7596,11 @@ impl AutoAccessor {
         }
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: Key) {
+    pub fn set_key(&self, ast: &mut crate::Ast, key: PrivateName) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -7050,8 +7617,8 @@ impl AutoAccessor {
     pub fn set_value(&self, ast: &mut crate::Ast, value: Option<Expr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -7065,11 +7632,11 @@ impl AutoAccessor {
         };
     }
     #[inline]
-    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -7079,15 +7646,15 @@ impl AutoAccessor {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = is_static.to_extra_data();
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
+    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -7097,237 +7664,125 @@ impl AutoAccessor {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = decorators.to_extra_data();
+                .get_unchecked_mut(offset.index()) = is_static.to_extra_data();
         };
     }
-}
-impl Prop {
-    #[inline]
-    pub fn is_shorthand(&self) -> bool {
-        matches!(self, Self::Shorthand(_))
-    }
-    #[inline]
-    pub fn is_key_value(&self) -> bool {
-        matches!(self, Self::KeyValue(_))
-    }
-    #[inline]
-    pub fn is_assign(&self) -> bool {
-        matches!(self, Self::Assign(_))
-    }
-    #[inline]
-    pub fn is_getter(&self) -> bool {
-        matches!(self, Self::Getter(_))
-    }
-    #[inline]
-    pub fn is_setter(&self) -> bool {
-        matches!(self, Self::Setter(_))
-    }
-    #[inline]
-    pub fn is_method(&self) -> bool {
-        matches!(self, Self::Method(_))
-    }
-    #[inline]
-    pub fn as_shorthand(self) -> Option<Ident> {
-        match self {
-            Self::Shorthand(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_key_value(self) -> Option<KeyValueProp> {
-        match self {
-            Self::KeyValue(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_assign(self) -> Option<AssignProp> {
-        match self {
-            Self::Assign(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_getter(self) -> Option<GetterProp> {
-        match self {
-            Self::Getter(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_setter(self) -> Option<SetterProp> {
-        match self {
-            Self::Setter(it) => Some(it),
-            _ => None,
-        }
-    }
     #[inline]
-    pub fn as_method(self) -> Option<MethodProp> {
-        match self {
-            Self::Method(it) => Some(it),
-            _ => None,
-        }
-    }
-}
-impl KeyValueProp {
-    #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> PropName {
+    pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
-    }
-    #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> Expr {
-        let offset = unsafe {
-            ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
-                    .extra_data_start
-                    .index()
-                    .wrapping_add(1usize),
-            )
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = decorators.to_extra_data();
         };
-        debug_assert!(offset < ast.extra_data.len());
-        unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+    pub fn set_accessibility(&self, ast: &mut crate::Ast, accessibility: Option<Accessibility>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(5usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+                .get_unchecked_mut(offset.index()) = accessibility.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: Expr) {
+    pub fn set_is_optional(&self, ast: &mut crate::Ast, is_optional: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(6usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+                .get_unchecked_mut(offset.index()) = is_optional.to_extra_data();
         };
     }
-}
-impl AssignProp {
     #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> Ident {
+    pub fn set_is_override(&self, ast: &mut crate::Ast, is_override: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(7usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
-    }
-    #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> Expr {
-        let offset = unsafe {
-            ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
-                    .extra_data_start
-                    .index()
-                    .wrapping_add(1usize),
-            )
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_override.to_extra_data();
         };
-        debug_assert!(offset < ast.extra_data.len());
-        unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: Ident) {
+    pub fn set_readonly(&self, ast: &mut crate::Ast, readonly: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(8usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+                .get_unchecked_mut(offset.index()) = readonly.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: Expr) {
+    pub fn set_definite(&self, ast: &mut crate::Ast, definite: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(9usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+                .get_unchecked_mut(offset.index()) = definite.to_extra_data();
         };
     }
 }
-impl GetterProp {
+impl ClassMethod {
     #[inline]
     pub fn key(&self, ast: &crate::Ast) -> PropName {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -7342,11 +7797,11 @@ impl GetterProp {
         }
     }
     #[inline]
-    pub fn body(&self, ast: &crate::Ast) -> Option<BlockStmt> {
+    pub fn function(&self, ast: &crate::Ast) -> Function {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -7361,52 +7816,52 @@ impl GetterProp {
         }
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+    pub fn kind(&self, ast: &crate::Ast) -> MethodKind {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(2usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = key.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn set_body(&self, ast: &mut crate::Ast, body: Option<BlockStmt>) {
+    pub fn is_static(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(3usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = body.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
-}
-impl SetterProp {
     #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> PropName {
+    pub fn accessibility(&self, ast: &crate::Ast) -> Option<Accessibility> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -7418,14 +7873,14 @@ impl SetterProp {
         }
     }
     #[inline]
-    pub fn this_param(&self, ast: &crate::Ast) -> Option<Pat> {
+    pub fn is_abstract(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(5usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -7437,14 +7892,14 @@ impl SetterProp {
         }
     }
     #[inline]
-    pub fn param(&self, ast: &crate::Ast) -> Pat {
+    pub fn is_optional(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(2usize),
+                    .wrapping_add(6usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -7456,14 +7911,14 @@ impl SetterProp {
         }
     }
     #[inline]
-    pub fn body(&self, ast: &crate::Ast) -> Option<BlockStmt> {
+    pub fn is_override(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(3usize),
+                    .wrapping_add(7usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -7478,8 +7933,8 @@ impl SetterProp {
     pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -7493,11 +7948,11 @@ impl SetterProp {
         };
     }
     #[inline]
-    pub fn set_this_param(&self, ast: &mut crate::Ast, this_param: Option<Pat>) {
+    pub fn set_function(&self, ast: &mut crate::Ast, function: Function) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -7507,15 +7962,15 @@ impl SetterProp {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = this_param.to_extra_data();
+                .get_unchecked_mut(offset.index()) = function.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_param(&self, ast: &mut crate::Ast, param: Pat) {
+    pub fn set_kind(&self, ast: &mut crate::Ast, kind: MethodKind) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(2usize),
@@ -7525,15 +7980,15 @@ impl SetterProp {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = param.to_extra_data();
+                .get_unchecked_mut(offset.index()) = kind.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_body(&self, ast: &mut crate::Ast, body: Option<BlockStmt>) {
+    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(3usize),
@@ -7543,153 +7998,225 @@ impl SetterProp {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = body.to_extra_data();
+                .get_unchecked_mut(offset.index()) = is_static.to_extra_data();
         };
     }
-}
-impl MethodProp {
     #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> PropName {
+    pub fn set_accessibility(&self, ast: &mut crate::Ast, accessibility: Option<Accessibility>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = accessibility.to_extra_data();
+        };
     }
     #[inline]
-    pub fn function(&self, ast: &crate::Ast) -> Function {
+    pub fn set_is_abstract(&self, ast: &mut crate::Ast, is_abstract: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(5usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_abstract.to_extra_data();
+        };
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+    pub fn set_is_optional(&self, ast: &mut crate::Ast, is_optional: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(6usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+                .get_unchecked_mut(offset.index()) = is_optional.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_function(&self, ast: &mut crate::Ast, function: Function) {
+    pub fn set_is_override(&self, ast: &mut crate::Ast, is_override: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(7usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = function.to_extra_data();
+                .get_unchecked_mut(offset.index()) = is_override.to_extra_data();
         };
     }
 }
-impl PropName {
-    #[inline]
-    pub fn is_ident(&self) -> bool {
-        matches!(self, Self::Ident(_))
-    }
-    #[inline]
-    pub fn is_str(&self) -> bool {
-        matches!(self, Self::Str(_))
-    }
-    #[inline]
-    pub fn is_num(&self) -> bool {
-        matches!(self, Self::Num(_))
-    }
+impl PrivateMethod {
     #[inline]
-    pub fn is_computed(&self) -> bool {
-        matches!(self, Self::Computed(_))
+    pub fn key(&self, ast: &crate::Ast) -> PrivateName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn is_big_int(&self) -> bool {
-        matches!(self, Self::BigInt(_))
+    pub fn function(&self, ast: &crate::Ast) -> Function {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn as_ident(self) -> Option<IdentName> {
-        match self {
-            Self::Ident(it) => Some(it),
-            _ => None,
+    pub fn kind(&self, ast: &crate::Ast) -> MethodKind {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
     #[inline]
-    pub fn as_str(self) -> Option<Str> {
-        match self {
-            Self::Str(it) => Some(it),
-            _ => None,
+    pub fn is_static(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
     #[inline]
-    pub fn as_num(self) -> Option<Number> {
-        match self {
-            Self::Num(it) => Some(it),
-            _ => None,
+    pub fn accessibility(&self, ast: &crate::Ast) -> Option<Accessibility> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
     #[inline]
-    pub fn as_computed(self) -> Option<ComputedPropName> {
-        match self {
-            Self::Computed(it) => Some(it),
-            _ => None,
+    pub fn is_abstract(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(5usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
     #[inline]
-    pub fn as_big_int(self) -> Option<BigInt> {
-        match self {
-            Self::BigInt(it) => Some(it),
-            _ => None,
+    pub fn is_optional(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(6usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
-}
-impl ComputedPropName {
     #[inline]
-    pub fn expr(&self, ast: &crate::Ast) -> Expr {
+    pub fn is_override(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(7usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -7701,11 +8228,11 @@ impl ComputedPropName {
         }
     }
     #[inline]
-    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
+    pub fn set_key(&self, ast: &mut crate::Ast, key: PrivateName) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -7715,96 +8242,6940 @@ impl ComputedPropName {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
         };
     }
-}
-impl Pat {
-    #[inline]
-    pub fn is_ident(&self) -> bool {
-        matches!(self, Self::Ident(_))
-    }
-    #[inline]
-    pub fn is_array(&self) -> bool {
-        matches!(self, Self::Array(_))
-    }
-    #[inline]
-    pub fn is_rest(&self) -> bool {
-        matches!(self, Self::Rest(_))
-    }
     #[inline]
-    pub fn is_object(&self) -> bool {
-        matches!(self, Self::Object(_))
+    pub fn set_function(&self, ast: &mut crate::Ast, function: Function) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = function.to_extra_data();
+        };
     }
     #[inline]
-    pub fn is_assign(&self) -> bool {
-        matches!(self, Self::Assign(_))
-    }
+    pub fn set_kind(&self, ast: &mut crate::Ast, kind: MethodKind) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = kind.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_static.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_accessibility(&self, ast: &mut crate::Ast, accessibility: Option<Accessibility>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = accessibility.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_abstract(&self, ast: &mut crate::Ast, is_abstract: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(5usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_abstract.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_optional(&self, ast: &mut crate::Ast, is_optional: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(6usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_optional.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_override(&self, ast: &mut crate::Ast, is_override: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(7usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_override.to_extra_data();
+        };
+    }
+}
+impl Constructor {
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> PropName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<ParamOrTsParamProp> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn body(&self, ast: &crate::Ast) -> Option<BlockStmt> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn accessibility(&self, ast: &crate::Ast) -> Option<Accessibility> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn is_optional(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<ParamOrTsParamProp>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_body(&self, ast: &mut crate::Ast, body: Option<BlockStmt>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = body.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_accessibility(&self, ast: &mut crate::Ast, accessibility: Option<Accessibility>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = accessibility.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_optional(&self, ast: &mut crate::Ast, is_optional: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_optional.to_extra_data();
+        };
+    }
+}
+impl Decorator {
+    #[inline]
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
+        };
+    }
+}
+impl StaticBlock {
+    #[inline]
+    pub fn body(&self, ast: &crate::Ast) -> BlockStmt {
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
+        unsafe { BlockStmt::from_node_id_unchecked(crate::NodeId::from_raw_unchecked(raw), ast) }
+    }
+    #[inline]
+    pub fn set_body(&self, ast: &mut crate::Ast, body: BlockStmt) {
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data = body.node_id().index() as u32;
+    }
+}
+impl Key {
+    #[inline]
+    pub fn is_private(&self) -> bool {
+        matches!(self, Self::Private(_))
+    }
+    #[inline]
+    pub fn is_public(&self) -> bool {
+        matches!(self, Self::Public(_))
+    }
+    #[inline]
+    pub fn as_private(self) -> Option<PrivateName> {
+        match self {
+            Self::Private(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_public(self) -> Option<PropName> {
+        match self {
+            Self::Public(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl AutoAccessor {
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> Key {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> Option<Expr> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn is_static(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn decorators(&self, ast: &crate::Ast) -> TypedSubRange<Decorator> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn accessibility(&self, ast: &crate::Ast) -> Option<Accessibility> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(5usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn is_abstract(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(6usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn is_override(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(7usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn definite(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(8usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: Key) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: Option<Expr>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_static.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = decorators.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_accessibility(&self, ast: &mut crate::Ast, accessibility: Option<Accessibility>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(5usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = accessibility.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_abstract(&self, ast: &mut crate::Ast, is_abstract: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(6usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_abstract.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_override(&self, ast: &mut crate::Ast, is_override: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(7usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_override.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_definite(&self, ast: &mut crate::Ast, definite: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(8usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = definite.to_extra_data();
+        };
+    }
+}
+impl Prop {
+    #[inline]
+    pub fn is_shorthand(&self) -> bool {
+        matches!(self, Self::Shorthand(_))
+    }
+    #[inline]
+    pub fn is_key_value(&self) -> bool {
+        matches!(self, Self::KeyValue(_))
+    }
+    #[inline]
+    pub fn is_assign(&self) -> bool {
+        matches!(self, Self::Assign(_))
+    }
+    #[inline]
+    pub fn is_getter(&self) -> bool {
+        matches!(self, Self::Getter(_))
+    }
+    #[inline]
+    pub fn is_setter(&self) -> bool {
+        matches!(self, Self::Setter(_))
+    }
+    #[inline]
+    pub fn is_method(&self) -> bool {
+        matches!(self, Self::Method(_))
+    }
+    #[inline]
+    pub fn as_shorthand(self) -> Option<Ident> {
+        match self {
+            Self::Shorthand(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_key_value(self) -> Option<KeyValueProp> {
+        match self {
+            Self::KeyValue(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_assign(self) -> Option<AssignProp> {
+        match self {
+            Self::Assign(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_getter(self) -> Option<GetterProp> {
+        match self {
+            Self::Getter(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_setter(self) -> Option<SetterProp> {
+        match self {
+            Self::Setter(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_method(self) -> Option<MethodProp> {
+        match self {
+            Self::Method(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl KeyValueProp {
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> PropName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> Expr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: Expr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+        };
+    }
+}
+impl AssignProp {
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> Ident {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> Expr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: Ident) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: Expr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+        };
+    }
+}
+impl GetterProp {
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> PropName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn body(&self, ast: &crate::Ast) -> Option<BlockStmt> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_body(&self, ast: &mut crate::Ast, body: Option<BlockStmt>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = body.to_extra_data();
+        };
+    }
+}
+impl SetterProp {
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> PropName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn this_param(&self, ast: &crate::Ast) -> Option<Pat> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn param(&self, ast: &crate::Ast) -> Pat {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn body(&self, ast: &crate::Ast) -> Option<BlockStmt> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_this_param(&self, ast: &mut crate::Ast, this_param: Option<Pat>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = this_param.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_param(&self, ast: &mut crate::Ast, param: Pat) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = param.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_body(&self, ast: &mut crate::Ast, body: Option<BlockStmt>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = body.to_extra_data();
+        };
+    }
+}
+impl MethodProp {
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> PropName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn function(&self, ast: &crate::Ast) -> Function {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_function(&self, ast: &mut crate::Ast, function: Function) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = function.to_extra_data();
+        };
+    }
+}
+impl PropName {
+    #[inline]
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::Ident(_))
+    }
+    #[inline]
+    pub fn is_str(&self) -> bool {
+        matches!(self, Self::Str(_))
+    }
+    #[inline]
+    pub fn is_num(&self) -> bool {
+        matches!(self, Self::Num(_))
+    }
+    #[inline]
+    pub fn is_computed(&self) -> bool {
+        matches!(self, Self::Computed(_))
+    }
+    #[inline]
+    pub fn is_big_int(&self) -> bool {
+        matches!(self, Self::BigInt(_))
+    }
+    #[inline]
+    pub fn as_ident(self) -> Option<IdentName> {
+        match self {
+            Self::Ident(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_str(self) -> Option<Str> {
+        match self {
+            Self::Str(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_num(self) -> Option<Number> {
+        match self {
+            Self::Num(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_computed(self) -> Option<ComputedPropName> {
+        match self {
+            Self::Computed(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_big_int(self) -> Option<BigInt> {
+        match self {
+            Self::BigInt(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl ComputedPropName {
+    #[inline]
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
+        };
+    }
+}
+impl Pat {
+    #[inline]
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::Ident(_))
+    }
+    #[inline]
+    pub fn is_array(&self) -> bool {
+        matches!(self, Self::Array(_))
+    }
+    #[inline]
+    pub fn is_rest(&self) -> bool {
+        matches!(self, Self::Rest(_))
+    }
+    #[inline]
+    pub fn is_object(&self) -> bool {
+        matches!(self, Self::Object(_))
+    }
+    #[inline]
+    pub fn is_assign(&self) -> bool {
+        matches!(self, Self::Assign(_))
+    }
+    #[inline]
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Self::Invalid(_))
+    }
+    #[inline]
+    pub fn is_expr(&self) -> bool {
+        matches!(self, Self::Expr(_))
+    }
+    #[inline]
+    pub fn as_ident(self) -> Option<BindingIdent> {
+        match self {
+            Self::Ident(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_array(self) -> Option<ArrayPat> {
+        match self {
+            Self::Array(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_rest(self) -> Option<RestPat> {
+        match self {
+            Self::Rest(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_object(self) -> Option<ObjectPat> {
+        match self {
+            Self::Object(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_assign(self) -> Option<AssignPat> {
+        match self {
+            Self::Assign(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_invalid(self) -> Option<Invalid> {
+        match self {
+            Self::Invalid(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_expr(self) -> Option<Expr> {
+        match self {
+            Self::Expr(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl ArrayPat {
+    #[inline]
+    pub fn elems(&self, ast: &crate::Ast) -> TypedSubRange<Option<Pat>> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn optional(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_elems(&self, ast: &mut crate::Ast, elems: TypedSubRange<Option<Pat>>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = elems.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_optional(&self, ast: &mut crate::Ast, optional: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = optional.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl ObjectPat {
+    #[inline]
+    pub fn props(&self, ast: &crate::Ast) -> TypedSubRange<ObjectPatProp> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn optional(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_props(&self, ast: &mut crate::Ast, props: TypedSubRange<ObjectPatProp>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = props.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_optional(&self, ast: &mut crate::Ast, optional: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = optional.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl AssignPat {
+    #[inline]
+    pub fn left(&self, ast: &crate::Ast) -> Pat {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn right(&self, ast: &crate::Ast) -> Expr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_left(&self, ast: &mut crate::Ast, left: Pat) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = left.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_right(&self, ast: &mut crate::Ast, right: Expr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = right.to_extra_data();
+        };
+    }
+}
+impl RestPat {
+    #[inline]
+    pub fn dot_3_token(&self, ast: &crate::Ast) -> Span {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn arg(&self, ast: &crate::Ast) -> Pat {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_dot3_token(&self, ast: &mut crate::Ast, dot3_token: Span) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = dot3_token.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_arg(&self, ast: &mut crate::Ast, arg: Pat) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = arg.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl ObjectPatProp {
+    #[inline]
+    pub fn is_key_value(&self) -> bool {
+        matches!(self, Self::KeyValue(_))
+    }
+    #[inline]
+    pub fn is_assign(&self) -> bool {
+        matches!(self, Self::Assign(_))
+    }
+    #[inline]
+    pub fn is_rest(&self) -> bool {
+        matches!(self, Self::Rest(_))
+    }
+    #[inline]
+    pub fn as_key_value(self) -> Option<KeyValuePatProp> {
+        match self {
+            Self::KeyValue(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_assign(self) -> Option<AssignPatProp> {
+        match self {
+            Self::Assign(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_rest(self) -> Option<RestPat> {
+        match self {
+            Self::Rest(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl KeyValuePatProp {
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> PropName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> Pat {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: Pat) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+        };
+    }
+}
+impl AssignPatProp {
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> BindingIdent {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> Option<Expr> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: BindingIdent) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: Option<Expr>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+        };
+    }
+}
+impl Ident {
+    #[inline]
+    pub fn optional(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn sym(&self, ast: &crate::Ast) -> Utf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_optional(&self, ast: &mut crate::Ast, optional: bool) {
+        let field_val: u32 = optional as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_sym(&self, ast: &mut crate::Ast, sym: Utf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = sym.to_extra_data();
+        };
+    }
+}
+impl IdentName {
+    #[inline]
+    pub fn sym(&self, ast: &crate::Ast) -> Utf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_sym(&self, ast: &mut crate::Ast, sym: Utf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = sym.to_extra_data();
+        };
+    }
+}
+impl PrivateName {
+    #[inline]
+    pub fn name(&self, ast: &crate::Ast) -> Utf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_name(&self, ast: &mut crate::Ast, name: Utf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = name.to_extra_data();
+        };
+    }
+}
+impl BindingIdent {
+    #[inline]
+    pub fn id(&self, ast: &crate::Ast) -> Ident {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_id(&self, ast: &mut crate::Ast, id: Ident) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = id.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl Lit {
+    #[inline]
+    pub fn is_str(&self) -> bool {
+        matches!(self, Self::Str(_))
+    }
+    #[inline]
+    pub fn is_bool(&self) -> bool {
+        matches!(self, Self::Bool(_))
+    }
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null(_))
+    }
+    #[inline]
+    pub fn is_num(&self) -> bool {
+        matches!(self, Self::Num(_))
+    }
+    #[inline]
+    pub fn is_big_int(&self) -> bool {
+        matches!(self, Self::BigInt(_))
+    }
+    #[inline]
+    pub fn is_regex(&self) -> bool {
+        matches!(self, Self::Regex(_))
+    }
+    #[inline]
+    pub fn as_str(self) -> Option<Str> {
+        match self {
+            Self::Str(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_bool(self) -> Option<Bool> {
+        match self {
+            Self::Bool(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_null(self) -> Option<Null> {
+        match self {
+            Self::Null(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_num(self) -> Option<Number> {
+        match self {
+            Self::Num(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_big_int(self) -> Option<BigInt> {
+        match self {
+            Self::BigInt(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_regex(self) -> Option<Regex> {
+        match self {
+            Self::Regex(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl Str {
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> Wtf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn raw(&self, ast: &crate::Ast) -> OptionalUtf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: Wtf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_raw(&self, ast: &mut crate::Ast, raw: OptionalUtf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = raw.to_extra_data();
+        };
+    }
+}
+impl Bool {
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> bool {
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
+        raw != 0
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: bool) {
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data = value as u32;
+    }
+}
+impl Null {}
+impl Number {
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> f64 {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn raw(&self, ast: &crate::Ast) -> OptionalUtf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: f64) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_raw(&self, ast: &mut crate::Ast, raw: OptionalUtf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = raw.to_extra_data();
+        };
+    }
+}
+impl BigInt {
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> BigIntId {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn raw(&self, ast: &crate::Ast) -> OptionalUtf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: BigIntId) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_raw(&self, ast: &mut crate::Ast, raw: OptionalUtf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = raw.to_extra_data();
+        };
+    }
+}
+impl Regex {
+    #[inline]
+    pub fn exp(&self, ast: &crate::Ast) -> Utf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn flags(&self, ast: &crate::Ast) -> Utf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_exp(&self, ast: &mut crate::Ast, exp: Utf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = exp.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_flags(&self, ast: &mut crate::Ast, flags: Utf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = flags.to_extra_data();
+        };
+    }
+}
+impl JSXObject {
+    #[inline]
+    pub fn is_jsx_member_expr(&self) -> bool {
+        matches!(self, Self::JSXMemberExpr(_))
+    }
+    #[inline]
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::Ident(_))
+    }
+    #[inline]
+    pub fn as_jsx_member_expr(self) -> Option<JSXMemberExpr> {
+        match self {
+            Self::JSXMemberExpr(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ident(self) -> Option<Ident> {
+        match self {
+            Self::Ident(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl JSXMemberExpr {
+    #[inline]
+    pub fn obj(&self, ast: &crate::Ast) -> JSXObject {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn prop(&self, ast: &crate::Ast) -> IdentName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_obj(&self, ast: &mut crate::Ast, obj: JSXObject) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = obj.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_prop(&self, ast: &mut crate::Ast, prop: IdentName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = prop.to_extra_data();
+        };
+    }
+}
+impl JSXNamespacedName {
+    #[inline]
+    pub fn ns(&self, ast: &crate::Ast) -> IdentName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn name(&self, ast: &crate::Ast) -> IdentName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_ns(&self, ast: &mut crate::Ast, ns: IdentName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = ns.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_name(&self, ast: &mut crate::Ast, name: IdentName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = name.to_extra_data();
+        };
+    }
+}
+impl JSXEmptyExpr {}
+impl JSXExprContainer {
+    #[inline]
+    pub fn expr(&self, ast: &crate::Ast) -> JSXExpr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: JSXExpr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
+        };
+    }
+}
+impl JSXExpr {
+    #[inline]
+    pub fn is_jsx_empty_expr(&self) -> bool {
+        matches!(self, Self::JSXEmptyExpr(_))
+    }
+    #[inline]
+    pub fn is_expr(&self) -> bool {
+        matches!(self, Self::Expr(_))
+    }
+    #[inline]
+    pub fn as_jsx_empty_expr(self) -> Option<JSXEmptyExpr> {
+        match self {
+            Self::JSXEmptyExpr(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_expr(self) -> Option<Expr> {
+        match self {
+            Self::Expr(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl JSXSpreadChild {
+    #[inline]
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
+        };
+    }
+}
+impl JSXElementName {
+    #[inline]
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::Ident(_))
+    }
+    #[inline]
+    pub fn is_jsx_member_expr(&self) -> bool {
+        matches!(self, Self::JSXMemberExpr(_))
+    }
+    #[inline]
+    pub fn is_jsx_namespaced_name(&self) -> bool {
+        matches!(self, Self::JSXNamespacedName(_))
+    }
+    #[inline]
+    pub fn as_ident(self) -> Option<Ident> {
+        match self {
+            Self::Ident(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_jsx_member_expr(self) -> Option<JSXMemberExpr> {
+        match self {
+            Self::JSXMemberExpr(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_jsx_namespaced_name(self) -> Option<JSXNamespacedName> {
+        match self {
+            Self::JSXNamespacedName(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl JSXOpeningElement {
+    #[inline]
+    pub fn name(&self, ast: &crate::Ast) -> JSXElementName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn attrs(&self, ast: &crate::Ast) -> TypedSubRange<JSXAttrOrSpread> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn self_closing(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_args(&self, ast: &crate::Ast) -> Option<TsTypeParamInstantiation> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_name(&self, ast: &mut crate::Ast, name: JSXElementName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = name.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_attrs(&self, ast: &mut crate::Ast, attrs: TypedSubRange<JSXAttrOrSpread>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = attrs.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_self_closing(&self, ast: &mut crate::Ast, self_closing: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = self_closing.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_args(&self, ast: &mut crate::Ast, type_args: Option<TsTypeParamInstantiation>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_args.to_extra_data();
+        };
+    }
+}
+impl JSXAttrOrSpread {
+    #[inline]
+    pub fn is_jsx_attr(&self) -> bool {
+        matches!(self, Self::JSXAttr(_))
+    }
+    #[inline]
+    pub fn is_spread_element(&self) -> bool {
+        matches!(self, Self::SpreadElement(_))
+    }
+    #[inline]
+    pub fn as_jsx_attr(self) -> Option<JSXAttr> {
+        match self {
+            Self::JSXAttr(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_spread_element(self) -> Option<SpreadElement> {
+        match self {
+            Self::SpreadElement(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl JSXClosingElement {
+    #[inline]
+    pub fn name(&self, ast: &crate::Ast) -> JSXElementName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_name(&self, ast: &mut crate::Ast, name: JSXElementName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = name.to_extra_data();
+        };
+    }
+}
+impl JSXAttr {
+    #[inline]
+    pub fn name(&self, ast: &crate::Ast) -> JSXAttrName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> Option<JSXAttrValue> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_name(&self, ast: &mut crate::Ast, name: JSXAttrName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = name.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: Option<JSXAttrValue>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+        };
+    }
+}
+impl JSXAttrName {
+    #[inline]
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::Ident(_))
+    }
+    #[inline]
+    pub fn is_jsx_namespaced_name(&self) -> bool {
+        matches!(self, Self::JSXNamespacedName(_))
+    }
+    #[inline]
+    pub fn as_ident(self) -> Option<IdentName> {
+        match self {
+            Self::Ident(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_jsx_namespaced_name(self) -> Option<JSXNamespacedName> {
+        match self {
+            Self::JSXNamespacedName(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl JSXAttrValue {
+    #[inline]
+    pub fn is_str(&self) -> bool {
+        matches!(self, Self::Str(_))
+    }
+    #[inline]
+    pub fn is_jsx_expr_container(&self) -> bool {
+        matches!(self, Self::JSXExprContainer(_))
+    }
+    #[inline]
+    pub fn is_jsx_element(&self) -> bool {
+        matches!(self, Self::JSXElement(_))
+    }
+    #[inline]
+    pub fn is_jsx_fragment(&self) -> bool {
+        matches!(self, Self::JSXFragment(_))
+    }
+    #[inline]
+    pub fn as_str(self) -> Option<Str> {
+        match self {
+            Self::Str(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_jsx_expr_container(self) -> Option<JSXExprContainer> {
+        match self {
+            Self::JSXExprContainer(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_jsx_element(self) -> Option<JSXElement> {
+        match self {
+            Self::JSXElement(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_jsx_fragment(self) -> Option<JSXFragment> {
+        match self {
+            Self::JSXFragment(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl JSXText {
+    #[inline]
+    pub fn value(&self, ast: &crate::Ast) -> Utf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn raw(&self, ast: &crate::Ast) -> Utf8Ref {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_value(&self, ast: &mut crate::Ast, value: Utf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_raw(&self, ast: &mut crate::Ast, raw: Utf8Ref) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = raw.to_extra_data();
+        };
+    }
+}
+impl JSXElement {
+    #[inline]
+    pub fn opening(&self, ast: &crate::Ast) -> JSXOpeningElement {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn children(&self, ast: &crate::Ast) -> TypedSubRange<JSXElementChild> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn closing(&self, ast: &crate::Ast) -> Option<JSXClosingElement> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_opening(&self, ast: &mut crate::Ast, opening: JSXOpeningElement) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = opening.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_children(&self, ast: &mut crate::Ast, children: TypedSubRange<JSXElementChild>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = children.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_closing(&self, ast: &mut crate::Ast, closing: Option<JSXClosingElement>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = closing.to_extra_data();
+        };
+    }
+}
+impl JSXElementChild {
+    #[inline]
+    pub fn is_jsx_text(&self) -> bool {
+        matches!(self, Self::JSXText(_))
+    }
+    #[inline]
+    pub fn is_jsx_expr_container(&self) -> bool {
+        matches!(self, Self::JSXExprContainer(_))
+    }
+    #[inline]
+    pub fn is_jsx_spread_child(&self) -> bool {
+        matches!(self, Self::JSXSpreadChild(_))
+    }
+    #[inline]
+    pub fn is_jsx_element(&self) -> bool {
+        matches!(self, Self::JSXElement(_))
+    }
+    #[inline]
+    pub fn is_jsx_fragment(&self) -> bool {
+        matches!(self, Self::JSXFragment(_))
+    }
+    #[inline]
+    pub fn as_jsx_text(self) -> Option<JSXText> {
+        match self {
+            Self::JSXText(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_jsx_expr_container(self) -> Option<JSXExprContainer> {
+        match self {
+            Self::JSXExprContainer(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_jsx_spread_child(self) -> Option<JSXSpreadChild> {
+        match self {
+            Self::JSXSpreadChild(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_jsx_element(self) -> Option<JSXElement> {
+        match self {
+            Self::JSXElement(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_jsx_fragment(self) -> Option<JSXFragment> {
+        match self {
+            Self::JSXFragment(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl JSXFragment {
+    #[inline]
+    pub fn opening(&self, ast: &crate::Ast) -> JSXOpeningFragment {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn children(&self, ast: &crate::Ast) -> TypedSubRange<JSXElementChild> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn closing(&self, ast: &crate::Ast) -> JSXClosingFragment {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_opening(&self, ast: &mut crate::Ast, opening: JSXOpeningFragment) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = opening.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_children(&self, ast: &mut crate::Ast, children: TypedSubRange<JSXElementChild>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = children.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_closing(&self, ast: &mut crate::Ast, closing: JSXClosingFragment) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = closing.to_extra_data();
+        };
+    }
+}
+impl JSXOpeningFragment {}
+impl JSXClosingFragment {}
+impl TsTypeAnn {
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl TsTypeParamDecl {
+    #[inline]
+    pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<TsTypeParam> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<TsTypeParam>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = params.to_extra_data();
+        };
+    }
+}
+impl TsTypeParam {
+    #[inline]
+    pub fn is_in(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn is_out(&self, ast: &crate::Ast) -> bool {
+        let raw =
+            (u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) >> 8usize) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn is_const(&self, ast: &crate::Ast) -> bool {
+        let raw =
+            (u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) >> 16usize) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn name(&self, ast: &crate::Ast) -> Ident {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn constraint(&self, ast: &crate::Ast) -> Option<TsType> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn default(&self, ast: &crate::Ast) -> Option<TsType> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_is_in(&self, ast: &mut crate::Ast, is_in: bool) {
+        let field_val: u32 = is_in as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_is_out(&self, ast: &mut crate::Ast, is_out: bool) {
+        let field_val: u32 = is_out as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16711935u32) | ((field_val & 255u32) << 8usize)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_is_const(&self, ast: &mut crate::Ast, is_const: bool) {
+        let field_val: u32 = is_const as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 65535u32) | ((field_val & 255u32) << 16usize)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_name(&self, ast: &mut crate::Ast, name: Ident) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = name.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_constraint(&self, ast: &mut crate::Ast, constraint: Option<TsType>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = constraint.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_default(&self, ast: &mut crate::Ast, default: Option<TsType>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = default.to_extra_data();
+        };
+    }
+}
+impl TsTypeParamInstantiation {
+    #[inline]
+    pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<TsType> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<TsType>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = params.to_extra_data();
+        };
+    }
+}
+impl TsParamProp {
+    #[inline]
+    pub fn decorators(&self, ast: &crate::Ast) -> TypedSubRange<Decorator> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn accessibility(&self, ast: &crate::Ast) -> Option<Accessibility> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn is_override(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn readonly(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn param(&self, ast: &crate::Ast) -> TsParamPropParam {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_decorators(&self, ast: &mut crate::Ast, decorators: TypedSubRange<Decorator>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = decorators.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_accessibility(&self, ast: &mut crate::Ast, accessibility: Option<Accessibility>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = accessibility.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_override(&self, ast: &mut crate::Ast, is_override: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_override.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_readonly(&self, ast: &mut crate::Ast, readonly: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = readonly.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_param(&self, ast: &mut crate::Ast, param: TsParamPropParam) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = param.to_extra_data();
+        };
+    }
+}
+impl TsParamPropParam {
+    #[inline]
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::Ident(_))
+    }
+    #[inline]
+    pub fn is_assign(&self) -> bool {
+        matches!(self, Self::Assign(_))
+    }
+    #[inline]
+    pub fn as_ident(self) -> Option<BindingIdent> {
+        match self {
+            Self::Ident(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_assign(self) -> Option<AssignPat> {
+        match self {
+            Self::Assign(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl TsQualifiedName {
+    #[inline]
+    pub fn left(&self, ast: &crate::Ast) -> TsEntityName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn right(&self, ast: &crate::Ast) -> IdentName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_left(&self, ast: &mut crate::Ast, left: TsEntityName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = left.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_right(&self, ast: &mut crate::Ast, right: IdentName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = right.to_extra_data();
+        };
+    }
+}
+impl TsEntityName {
+    #[inline]
+    pub fn is_ts_qualified_name(&self) -> bool {
+        matches!(self, Self::TsQualifiedName(_))
+    }
+    #[inline]
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::Ident(_))
+    }
+    #[inline]
+    pub fn as_ts_qualified_name(self) -> Option<TsQualifiedName> {
+        match self {
+            Self::TsQualifiedName(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ident(self) -> Option<Ident> {
+        match self {
+            Self::Ident(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl TsTypeElement {
+    #[inline]
+    pub fn is_ts_call_signature_decl(&self) -> bool {
+        matches!(self, Self::TsCallSignatureDecl(_))
+    }
+    #[inline]
+    pub fn is_ts_construct_signature_decl(&self) -> bool {
+        matches!(self, Self::TsConstructSignatureDecl(_))
+    }
+    #[inline]
+    pub fn is_ts_property_signature(&self) -> bool {
+        matches!(self, Self::TsPropertySignature(_))
+    }
+    #[inline]
+    pub fn is_ts_getter_signature(&self) -> bool {
+        matches!(self, Self::TsGetterSignature(_))
+    }
+    #[inline]
+    pub fn is_ts_setter_signature(&self) -> bool {
+        matches!(self, Self::TsSetterSignature(_))
+    }
+    #[inline]
+    pub fn is_ts_method_signature(&self) -> bool {
+        matches!(self, Self::TsMethodSignature(_))
+    }
+    #[inline]
+    pub fn is_ts_index_signature(&self) -> bool {
+        matches!(self, Self::TsIndexSignature(_))
+    }
+    #[inline]
+    pub fn as_ts_call_signature_decl(self) -> Option<TsCallSignatureDecl> {
+        match self {
+            Self::TsCallSignatureDecl(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_construct_signature_decl(self) -> Option<TsConstructSignatureDecl> {
+        match self {
+            Self::TsConstructSignatureDecl(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_property_signature(self) -> Option<TsPropertySignature> {
+        match self {
+            Self::TsPropertySignature(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_getter_signature(self) -> Option<TsGetterSignature> {
+        match self {
+            Self::TsGetterSignature(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_setter_signature(self) -> Option<TsSetterSignature> {
+        match self {
+            Self::TsSetterSignature(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_method_signature(self) -> Option<TsMethodSignature> {
+        match self {
+            Self::TsMethodSignature(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_index_signature(self) -> Option<TsIndexSignature> {
+        match self {
+            Self::TsIndexSignature(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl TsCallSignatureDecl {
+    #[inline]
+    pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<TsFnParam> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamDecl> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<TsFnParam>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_params(&self, ast: &mut crate::Ast, type_params: Option<TsTypeParamDecl>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
+    }
+}
+impl TsConstructSignatureDecl {
+    #[inline]
+    pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<TsFnParam> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamDecl> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<TsFnParam>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_params(&self, ast: &mut crate::Ast, type_params: Option<TsTypeParamDecl>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
+    }
+}
+impl TsPropertySignature {
+    #[inline]
+    pub fn readonly(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn computed(&self, ast: &crate::Ast) -> bool {
+        let raw =
+            (u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) >> 8usize) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn optional(&self, ast: &crate::Ast) -> bool {
+        let raw =
+            (u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) >> 16usize) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> Expr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_readonly(&self, ast: &mut crate::Ast, readonly: bool) {
+        let field_val: u32 = readonly as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_computed(&self, ast: &mut crate::Ast, computed: bool) {
+        let field_val: u32 = computed as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16711935u32) | ((field_val & 255u32) << 8usize)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_optional(&self, ast: &mut crate::Ast, optional: bool) {
+        let field_val: u32 = optional as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 65535u32) | ((field_val & 255u32) << 16usize)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: Expr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl TsGetterSignature {
+    #[inline]
+    pub fn computed(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> Expr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_computed(&self, ast: &mut crate::Ast, computed: bool) {
+        let field_val: u32 = computed as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: Expr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl TsSetterSignature {
+    #[inline]
+    pub fn computed(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> Expr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn param(&self, ast: &crate::Ast) -> TsFnParam {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_computed(&self, ast: &mut crate::Ast, computed: bool) {
+        let field_val: u32 = computed as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: Expr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_param(&self, ast: &mut crate::Ast, param: TsFnParam) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = param.to_extra_data();
+        };
+    }
+}
+impl TsMethodSignature {
+    #[inline]
+    pub fn key(&self, ast: &crate::Ast) -> Expr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn computed(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn optional(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<TsFnParam> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamDecl> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(5usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_key(&self, ast: &mut crate::Ast, key: Expr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_computed(&self, ast: &mut crate::Ast, computed: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = computed.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_optional(&self, ast: &mut crate::Ast, optional: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = optional.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<TsFnParam>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_params(&self, ast: &mut crate::Ast, type_params: Option<TsTypeParamDecl>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(5usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
+    }
+}
+impl TsIndexSignature {
+    #[inline]
+    pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<TsFnParam> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn readonly(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn is_static(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<TsFnParam>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_readonly(&self, ast: &mut crate::Ast, readonly: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = readonly.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_static(&self, ast: &mut crate::Ast, is_static: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_static.to_extra_data();
+        };
+    }
+}
+impl TsType {
+    #[inline]
+    pub fn is_ts_keyword_type(&self) -> bool {
+        matches!(self, Self::TsKeywordType(_))
+    }
+    #[inline]
+    pub fn is_ts_this_type(&self) -> bool {
+        matches!(self, Self::TsThisType(_))
+    }
+    #[inline]
+    pub fn is_ts_fn_or_constructor_type(&self) -> bool {
+        matches!(self, Self::TsFnOrConstructorType(_))
+    }
+    #[inline]
+    pub fn is_ts_type_ref(&self) -> bool {
+        matches!(self, Self::TsTypeRef(_))
+    }
+    #[inline]
+    pub fn is_ts_type_query(&self) -> bool {
+        matches!(self, Self::TsTypeQuery(_))
+    }
+    #[inline]
+    pub fn is_ts_type_lit(&self) -> bool {
+        matches!(self, Self::TsTypeLit(_))
+    }
+    #[inline]
+    pub fn is_ts_array_type(&self) -> bool {
+        matches!(self, Self::TsArrayType(_))
+    }
+    #[inline]
+    pub fn is_ts_tuple_type(&self) -> bool {
+        matches!(self, Self::TsTupleType(_))
+    }
+    #[inline]
+    pub fn is_ts_optional_type(&self) -> bool {
+        matches!(self, Self::TsOptionalType(_))
+    }
+    #[inline]
+    pub fn is_ts_rest_type(&self) -> bool {
+        matches!(self, Self::TsRestType(_))
+    }
+    #[inline]
+    pub fn is_ts_union_or_intersection_type(&self) -> bool {
+        matches!(self, Self::TsUnionOrIntersectionType(_))
+    }
+    #[inline]
+    pub fn is_ts_conditional_type(&self) -> bool {
+        matches!(self, Self::TsConditionalType(_))
+    }
+    #[inline]
+    pub fn is_ts_infer_type(&self) -> bool {
+        matches!(self, Self::TsInferType(_))
+    }
+    #[inline]
+    pub fn is_ts_parenthesized_type(&self) -> bool {
+        matches!(self, Self::TsParenthesizedType(_))
+    }
+    #[inline]
+    pub fn is_ts_type_operator(&self) -> bool {
+        matches!(self, Self::TsTypeOperator(_))
+    }
+    #[inline]
+    pub fn is_ts_indexed_access_type(&self) -> bool {
+        matches!(self, Self::TsIndexedAccessType(_))
+    }
+    #[inline]
+    pub fn is_ts_mapped_type(&self) -> bool {
+        matches!(self, Self::TsMappedType(_))
+    }
+    #[inline]
+    pub fn is_ts_lit_type(&self) -> bool {
+        matches!(self, Self::TsLitType(_))
+    }
+    #[inline]
+    pub fn is_ts_type_predicate(&self) -> bool {
+        matches!(self, Self::TsTypePredicate(_))
+    }
+    #[inline]
+    pub fn is_ts_import_type(&self) -> bool {
+        matches!(self, Self::TsImportType(_))
+    }
+    #[inline]
+    pub fn as_ts_keyword_type(self) -> Option<TsKeywordType> {
+        match self {
+            Self::TsKeywordType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_this_type(self) -> Option<TsThisType> {
+        match self {
+            Self::TsThisType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_fn_or_constructor_type(self) -> Option<TsFnOrConstructorType> {
+        match self {
+            Self::TsFnOrConstructorType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_type_ref(self) -> Option<TsTypeRef> {
+        match self {
+            Self::TsTypeRef(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_type_query(self) -> Option<TsTypeQuery> {
+        match self {
+            Self::TsTypeQuery(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_type_lit(self) -> Option<TsTypeLit> {
+        match self {
+            Self::TsTypeLit(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_array_type(self) -> Option<TsArrayType> {
+        match self {
+            Self::TsArrayType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_tuple_type(self) -> Option<TsTupleType> {
+        match self {
+            Self::TsTupleType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_optional_type(self) -> Option<TsOptionalType> {
+        match self {
+            Self::TsOptionalType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_rest_type(self) -> Option<TsRestType> {
+        match self {
+            Self::TsRestType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_union_or_intersection_type(self) -> Option<TsUnionOrIntersectionType> {
+        match self {
+            Self::TsUnionOrIntersectionType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_conditional_type(self) -> Option<TsConditionalType> {
+        match self {
+            Self::TsConditionalType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_infer_type(self) -> Option<TsInferType> {
+        match self {
+            Self::TsInferType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_parenthesized_type(self) -> Option<TsParenthesizedType> {
+        match self {
+            Self::TsParenthesizedType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_type_operator(self) -> Option<TsTypeOperator> {
+        match self {
+            Self::TsTypeOperator(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_indexed_access_type(self) -> Option<TsIndexedAccessType> {
+        match self {
+            Self::TsIndexedAccessType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_mapped_type(self) -> Option<TsMappedType> {
+        match self {
+            Self::TsMappedType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_lit_type(self) -> Option<TsLitType> {
+        match self {
+            Self::TsLitType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_type_predicate(self) -> Option<TsTypePredicate> {
+        match self {
+            Self::TsTypePredicate(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_import_type(self) -> Option<TsImportType> {
+        match self {
+            Self::TsImportType(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl TsFnOrConstructorType {
+    #[inline]
+    pub fn is_ts_fn_type(&self) -> bool {
+        matches!(self, Self::TsFnType(_))
+    }
+    #[inline]
+    pub fn is_ts_constructor_type(&self) -> bool {
+        matches!(self, Self::TsConstructorType(_))
+    }
+    #[inline]
+    pub fn as_ts_fn_type(self) -> Option<TsFnType> {
+        match self {
+            Self::TsFnType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_constructor_type(self) -> Option<TsConstructorType> {
+        match self {
+            Self::TsConstructorType(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl TsKeywordType {
+    #[inline]
+    pub fn kind(&self, ast: &crate::Ast) -> TsKeywordTypeKind {
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
+        unsafe { std::mem::transmute::<u8, TsKeywordTypeKind>(raw as u8) }
+    }
+    #[inline]
+    pub fn set_kind(&self, ast: &mut crate::Ast, kind: TsKeywordTypeKind) {
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data = kind as u32;
+    }
+}
+impl TsThisType {}
+impl TsFnParam {
+    #[inline]
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::Ident(_))
+    }
+    #[inline]
+    pub fn is_array(&self) -> bool {
+        matches!(self, Self::Array(_))
+    }
+    #[inline]
+    pub fn is_rest(&self) -> bool {
+        matches!(self, Self::Rest(_))
+    }
+    #[inline]
+    pub fn is_object(&self) -> bool {
+        matches!(self, Self::Object(_))
+    }
+    #[inline]
+    pub fn as_ident(self) -> Option<BindingIdent> {
+        match self {
+            Self::Ident(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_array(self) -> Option<ArrayPat> {
+        match self {
+            Self::Array(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_rest(self) -> Option<RestPat> {
+        match self {
+            Self::Rest(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_object(self) -> Option<ObjectPat> {
+        match self {
+            Self::Object(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl TsFnType {
+    #[inline]
+    pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<TsFnParam> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamDecl> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsTypeAnn {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<TsFnParam>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_params(&self, ast: &mut crate::Ast, type_params: Option<TsTypeParamDecl>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsTypeAnn) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl TsConstructorType {
+    #[inline]
+    pub fn params(&self, ast: &crate::Ast) -> TypedSubRange<TsFnParam> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamDecl> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsTypeAnn {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn is_abstract(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_params(&self, ast: &mut crate::Ast, params: TypedSubRange<TsFnParam>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_params(&self, ast: &mut crate::Ast, type_params: Option<TsTypeParamDecl>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsTypeAnn) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_is_abstract(&self, ast: &mut crate::Ast, is_abstract: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_abstract.to_extra_data();
+        };
+    }
+}
+impl TsTypeRef {
+    #[inline]
+    pub fn type_name(&self, ast: &crate::Ast) -> TsEntityName {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamInstantiation> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_type_name(&self, ast: &mut crate::Ast, type_name: TsEntityName) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_name.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_params(
+        &self,
+        ast: &mut crate::Ast,
+        type_params: Option<TsTypeParamInstantiation>,
+    ) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
+    }
+}
+impl TsTypePredicate {
+    #[inline]
+    pub fn asserts(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn param_name(&self, ast: &crate::Ast) -> TsThisTypeOrIdent {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsTypeAnn> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_asserts(&self, ast: &mut crate::Ast, asserts: bool) {
+        let field_val: u32 = asserts as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_param_name(&self, ast: &mut crate::Ast, param_name: TsThisTypeOrIdent) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = param_name.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsTypeAnn>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl TsThisTypeOrIdent {
+    #[inline]
+    pub fn is_ts_this_type(&self) -> bool {
+        matches!(self, Self::TsThisType(_))
+    }
+    #[inline]
+    pub fn is_ident(&self) -> bool {
+        matches!(self, Self::Ident(_))
+    }
+    #[inline]
+    pub fn as_ts_this_type(self) -> Option<TsThisType> {
+        match self {
+            Self::TsThisType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ident(self) -> Option<Ident> {
+        match self {
+            Self::Ident(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl TsTypeQuery {
+    #[inline]
+    pub fn expr_name(&self, ast: &crate::Ast) -> TsTypeQueryExpr {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_args(&self, ast: &crate::Ast) -> Option<TsTypeParamInstantiation> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_expr_name(&self, ast: &mut crate::Ast, expr_name: TsTypeQueryExpr) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = expr_name.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_args(&self, ast: &mut crate::Ast, type_args: Option<TsTypeParamInstantiation>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_args.to_extra_data();
+        };
+    }
+}
+impl TsTypeQueryExpr {
+    #[inline]
+    pub fn is_ts_entity_name(&self) -> bool {
+        matches!(self, Self::TsEntityName(_))
+    }
+    #[inline]
+    pub fn is_import(&self) -> bool {
+        matches!(self, Self::Import(_))
+    }
+    #[inline]
+    pub fn as_ts_entity_name(self) -> Option<TsEntityName> {
+        match self {
+            Self::TsEntityName(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_import(self) -> Option<TsImportType> {
+        match self {
+            Self::Import(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl TsImportCallOptions {
+    #[inline]
+    pub fn with(&self, ast: &crate::Ast) -> ObjectLit {
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
+        unsafe { ObjectLit::from_node_id_unchecked(crate::NodeId::from_raw_unchecked(raw), ast) }
+    }
+    #[inline]
+    pub fn set_with(&self, ast: &mut crate::Ast, with: ObjectLit) {
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data = with.node_id().index() as u32;
+    }
+}
+impl TsImportType {
+    #[inline]
+    pub fn arg(&self, ast: &crate::Ast) -> Str {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn qualifier(&self, ast: &crate::Ast) -> Option<TsEntityName> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_args(&self, ast: &crate::Ast) -> Option<TsTypeParamInstantiation> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn attributes(&self, ast: &crate::Ast) -> Option<TsImportCallOptions> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_arg(&self, ast: &mut crate::Ast, arg: Str) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = arg.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_qualifier(&self, ast: &mut crate::Ast, qualifier: Option<TsEntityName>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = qualifier.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_args(&self, ast: &mut crate::Ast, type_args: Option<TsTypeParamInstantiation>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_args.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_attributes(&self, ast: &mut crate::Ast, attributes: Option<TsImportCallOptions>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = attributes.to_extra_data();
+        };
+    }
+}
+impl TsTypeLit {
+    #[inline]
+    pub fn members(&self, ast: &crate::Ast) -> TypedSubRange<TsTypeElement> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_members(&self, ast: &mut crate::Ast, members: TypedSubRange<TsTypeElement>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = members.to_extra_data();
+        };
+    }
+}
+impl TsArrayType {
+    #[inline]
+    pub fn elem_type(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_elem_type(&self, ast: &mut crate::Ast, elem_type: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = elem_type.to_extra_data();
+        };
+    }
+}
+impl TsTupleType {
+    #[inline]
+    pub fn elem_types(&self, ast: &crate::Ast) -> TypedSubRange<TsTupleElement> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_elem_types(&self, ast: &mut crate::Ast, elem_types: TypedSubRange<TsTupleElement>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = elem_types.to_extra_data();
+        };
+    }
+}
+impl TsTupleElement {
+    #[inline]
+    pub fn label(&self, ast: &crate::Ast) -> Option<Pat> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn ty(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_label(&self, ast: &mut crate::Ast, label: Option<Pat>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = label.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_ty(&self, ast: &mut crate::Ast, ty: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = ty.to_extra_data();
+        };
+    }
+}
+impl TsOptionalType {
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl TsRestType {
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl TsUnionOrIntersectionType {
+    #[inline]
+    pub fn is_ts_union_type(&self) -> bool {
+        matches!(self, Self::TsUnionType(_))
+    }
+    #[inline]
+    pub fn is_ts_intersection_type(&self) -> bool {
+        matches!(self, Self::TsIntersectionType(_))
+    }
+    #[inline]
+    pub fn as_ts_union_type(self) -> Option<TsUnionType> {
+        match self {
+            Self::TsUnionType(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_ts_intersection_type(self) -> Option<TsIntersectionType> {
+        match self {
+            Self::TsIntersectionType(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl TsUnionType {
+    #[inline]
+    pub fn types(&self, ast: &crate::Ast) -> TypedSubRange<TsType> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
     #[inline]
-    pub fn is_invalid(&self) -> bool {
-        matches!(self, Self::Invalid(_))
+    pub fn set_types(&self, ast: &mut crate::Ast, types: TypedSubRange<TsType>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = types.to_extra_data();
+        };
     }
+}
+impl TsIntersectionType {
     #[inline]
-    pub fn is_expr(&self) -> bool {
-        matches!(self, Self::Expr(_))
+    pub fn types(&self, ast: &crate::Ast) -> TypedSubRange<TsType> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn as_ident(self) -> Option<BindingIdent> {
-        match self {
-            Self::Ident(it) => Some(it),
-            _ => None,
+    pub fn set_types(&self, ast: &mut crate::Ast, types: TypedSubRange<TsType>) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = types.to_extra_data();
+        };
+    }
+}
+impl TsConditionalType {
+    #[inline]
+    pub fn check_type(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
     #[inline]
-    pub fn as_array(self) -> Option<ArrayPat> {
-        match self {
-            Self::Array(it) => Some(it),
-            _ => None,
+    pub fn extends_type(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
     #[inline]
-    pub fn as_rest(self) -> Option<RestPat> {
-        match self {
-            Self::Rest(it) => Some(it),
-            _ => None,
+    pub fn true_type(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
     #[inline]
-    pub fn as_object(self) -> Option<ObjectPat> {
-        match self {
-            Self::Object(it) => Some(it),
-            _ => None,
+    pub fn false_type(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
     #[inline]
-    pub fn as_assign(self) -> Option<AssignPat> {
-        match self {
-            Self::Assign(it) => Some(it),
-            _ => None,
+    pub fn set_check_type(&self, ast: &mut crate::Ast, check_type: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = check_type.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_extends_type(&self, ast: &mut crate::Ast, extends_type: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = extends_type.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_true_type(&self, ast: &mut crate::Ast, true_type: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = true_type.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_false_type(&self, ast: &mut crate::Ast, false_type: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = false_type.to_extra_data();
+        };
+    }
+}
+impl TsInferType {
+    #[inline]
+    pub fn type_param(&self, ast: &crate::Ast) -> TsTypeParam {
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
+        unsafe { TsTypeParam::from_node_id_unchecked(crate::NodeId::from_raw_unchecked(raw), ast) }
+    }
+    #[inline]
+    pub fn set_type_param(&self, ast: &mut crate::Ast, type_param: TsTypeParam) {
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data =
+            type_param.node_id().index() as u32;
+    }
+}
+impl TsParenthesizedType {
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
     #[inline]
-    pub fn as_invalid(self) -> Option<Invalid> {
-        match self {
-            Self::Invalid(it) => Some(it),
-            _ => None,
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl TsTypeOperator {
+    #[inline]
+    pub fn op(&self, ast: &crate::Ast) -> TsTypeOperatorOp {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        unsafe { std::mem::transmute::<u8, TsTypeOperatorOp>(raw as u8) }
+    }
+    #[inline]
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsType {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
     #[inline]
-    pub fn as_expr(self) -> Option<Expr> {
-        match self {
-            Self::Expr(it) => Some(it),
-            _ => None,
-        }
+    pub fn set_op(&self, ast: &mut crate::Ast, op: TsTypeOperatorOp) {
+        let field_val: u32 = op as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
+    }
+}
+impl TsIndexedAccessType {
+    #[inline]
+    pub fn readonly(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
     }
-}
-impl ArrayPat {
     #[inline]
-    pub fn elems(&self, ast: &crate::Ast) -> TypedSubRange<Option<Pat>> {
+    pub fn obj_type(&self, ast: &crate::Ast) -> TsType {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -7819,11 +15190,11 @@ impl ArrayPat {
         }
     }
     #[inline]
-    pub fn optional(&self, ast: &crate::Ast) -> bool {
+    pub fn index_type(&self, ast: &crate::Ast) -> TsType {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -7838,11 +15209,22 @@ impl ArrayPat {
         }
     }
     #[inline]
-    pub fn set_elems(&self, ast: &mut crate::Ast, elems: TypedSubRange<Option<Pat>>) {
+    pub fn set_readonly(&self, ast: &mut crate::Ast, readonly: bool) {
+        let field_val: u32 = readonly as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_obj_type(&self, ast: &mut crate::Ast, obj_type: TsType) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -7852,15 +15234,15 @@ impl ArrayPat {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = elems.to_extra_data();
+                .get_unchecked_mut(offset.index()) = obj_type.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_optional(&self, ast: &mut crate::Ast, optional: bool) {
+    pub fn set_index_type(&self, ast: &mut crate::Ast, index_type: TsType) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -7870,17 +15252,17 @@ impl ArrayPat {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = optional.to_extra_data();
+                .get_unchecked_mut(offset.index()) = index_type.to_extra_data();
         };
     }
 }
-impl ObjectPat {
+impl TsMappedType {
     #[inline]
-    pub fn props(&self, ast: &crate::Ast) -> TypedSubRange<ObjectPatProp> {
+    pub fn readonly(&self, ast: &crate::Ast) -> Option<TruePlusMinus> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -7895,11 +15277,11 @@ impl ObjectPat {
         }
     }
     #[inline]
-    pub fn optional(&self, ast: &crate::Ast) -> bool {
+    pub fn type_param(&self, ast: &crate::Ast) -> TsTypeParam {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -7914,52 +15296,33 @@ impl ObjectPat {
         }
     }
     #[inline]
-    pub fn set_props(&self, ast: &mut crate::Ast, props: TypedSubRange<ObjectPatProp>) {
+    pub fn name_type(&self, ast: &crate::Ast) -> Option<TsType> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(2usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = props.to_extra_data();
-        };
-    }
-    #[inline]
-    pub fn set_optional(&self, ast: &mut crate::Ast, optional: bool) {
-        let offset = unsafe {
-            ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
-                    .extra_data_start
-                    .index()
-                    .wrapping_add(1usize),
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
             )
-        };
-        debug_assert!(offset < ast.extra_data.len());
-        unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = optional.to_extra_data();
-        };
+        }
     }
-}
-impl AssignPat {
     #[inline]
-    pub fn left(&self, ast: &crate::Ast) -> Pat {
+    pub fn optional(&self, ast: &crate::Ast) -> Option<TruePlusMinus> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(3usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -7971,14 +15334,14 @@ impl AssignPat {
         }
     }
     #[inline]
-    pub fn right(&self, ast: &crate::Ast) -> Expr {
+    pub fn type_ann(&self, ast: &crate::Ast) -> Option<TsType> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -7990,11 +15353,11 @@ impl AssignPat {
         }
     }
     #[inline]
-    pub fn set_left(&self, ast: &mut crate::Ast, left: Pat) {
+    pub fn set_readonly(&self, ast: &mut crate::Ast, readonly: Option<TruePlusMinus>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8004,15 +15367,15 @@ impl AssignPat {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = left.to_extra_data();
+                .get_unchecked_mut(offset.index()) = readonly.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_right(&self, ast: &mut crate::Ast, right: Expr) {
+    pub fn set_type_param(&self, ast: &mut crate::Ast, type_param: TsTypeParam) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -8022,128 +15385,167 @@ impl AssignPat {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = right.to_extra_data();
+                .get_unchecked_mut(offset.index()) = type_param.to_extra_data();
         };
     }
-}
-impl RestPat {
     #[inline]
-    pub fn dot_3_token(&self, ast: &crate::Ast) -> Span {
+    pub fn set_name_type(&self, ast: &mut crate::Ast, name_type: Option<TsType>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(2usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = name_type.to_extra_data();
+        };
     }
     #[inline]
-    pub fn arg(&self, ast: &crate::Ast) -> Pat {
+    pub fn set_optional(&self, ast: &mut crate::Ast, optional: Option<TruePlusMinus>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(3usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = optional.to_extra_data();
+        };
     }
     #[inline]
-    pub fn set_dot3_token(&self, ast: &mut crate::Ast, dot3_token: Span) {
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: Option<TsType>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = dot3_token.to_extra_data();
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
         };
     }
+}
+impl TsLitType {
     #[inline]
-    pub fn set_arg(&self, ast: &mut crate::Ast, arg: Pat) {
+    pub fn lit(&self, ast: &crate::Ast) -> TsLit {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_lit(&self, ast: &mut crate::Ast, lit: TsLit) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = arg.to_extra_data();
+                .get_unchecked_mut(offset.index()) = lit.to_extra_data();
         };
     }
 }
-impl ObjectPatProp {
+impl TsLit {
     #[inline]
-    pub fn is_key_value(&self) -> bool {
-        matches!(self, Self::KeyValue(_))
+    pub fn is_number(&self) -> bool {
+        matches!(self, Self::Number(_))
     }
     #[inline]
-    pub fn is_assign(&self) -> bool {
-        matches!(self, Self::Assign(_))
+    pub fn is_str(&self) -> bool {
+        matches!(self, Self::Str(_))
     }
     #[inline]
-    pub fn is_rest(&self) -> bool {
-        matches!(self, Self::Rest(_))
+    pub fn is_bool(&self) -> bool {
+        matches!(self, Self::Bool(_))
     }
     #[inline]
-    pub fn as_key_value(self) -> Option<KeyValuePatProp> {
+    pub fn is_big_int(&self) -> bool {
+        matches!(self, Self::BigInt(_))
+    }
+    #[inline]
+    pub fn is_tpl(&self) -> bool {
+        matches!(self, Self::Tpl(_))
+    }
+    #[inline]
+    pub fn as_number(self) -> Option<Number> {
         match self {
-            Self::KeyValue(it) => Some(it),
+            Self::Number(it) => Some(it),
             _ => None,
         }
     }
     #[inline]
-    pub fn as_assign(self) -> Option<AssignPatProp> {
+    pub fn as_str(self) -> Option<Str> {
         match self {
-            Self::Assign(it) => Some(it),
+            Self::Str(it) => Some(it),
             _ => None,
         }
     }
     #[inline]
-    pub fn as_rest(self) -> Option<RestPat> {
+    pub fn as_bool(self) -> Option<Bool> {
         match self {
-            Self::Rest(it) => Some(it),
+            Self::Bool(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_big_int(self) -> Option<BigInt> {
+        match self {
+            Self::BigInt(it) => Some(it),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_tpl(self) -> Option<TsTplLitType> {
+        match self {
+            Self::Tpl(it) => Some(it),
             _ => None,
         }
     }
 }
-impl KeyValuePatProp {
+impl TsTplLitType {
     #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> PropName {
+    pub fn types(&self, ast: &crate::Ast) -> TypedSubRange<TsType> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8158,11 +15560,11 @@ impl KeyValuePatProp {
         }
     }
     #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> Pat {
+    pub fn quasis(&self, ast: &crate::Ast) -> TypedSubRange<TplElement> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -8177,11 +15579,11 @@ impl KeyValuePatProp {
         }
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: PropName) {
+    pub fn set_types(&self, ast: &mut crate::Ast, types: TypedSubRange<TsType>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8191,15 +15593,15 @@ impl KeyValuePatProp {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+                .get_unchecked_mut(offset.index()) = types.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: Pat) {
+    pub fn set_quasis(&self, ast: &mut crate::Ast, quasis: TypedSubRange<TplElement>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -8209,17 +15611,17 @@ impl KeyValuePatProp {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+                .get_unchecked_mut(offset.index()) = quasis.to_extra_data();
         };
     }
 }
-impl AssignPatProp {
+impl TsInterfaceDecl {
     #[inline]
-    pub fn key(&self, ast: &crate::Ast) -> BindingIdent {
+    pub fn id(&self, ast: &crate::Ast) -> Ident {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8234,74 +15636,179 @@ impl AssignPatProp {
         }
     }
     #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> Option<Expr> {
+    pub fn declare(&self, ast: &crate::Ast) -> bool {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamDecl> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn extends(&self, ast: &crate::Ast) -> TypedSubRange<TsExprWithTypeArgs> {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(3usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn body(&self, ast: &crate::Ast) -> TsInterfaceBody {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(4usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
+    }
+    #[inline]
+    pub fn set_id(&self, ast: &mut crate::Ast, id: Ident) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = id.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_declare(&self, ast: &mut crate::Ast, declare: bool) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(1usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = declare.to_extra_data();
+        };
+    }
+    #[inline]
+    pub fn set_type_params(&self, ast: &mut crate::Ast, type_params: Option<TsTypeParamDecl>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(2usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
+        };
     }
     #[inline]
-    pub fn set_key(&self, ast: &mut crate::Ast, key: BindingIdent) {
+    pub fn set_extends(&self, ast: &mut crate::Ast, extends: TypedSubRange<TsExprWithTypeArgs>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(3usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = key.to_extra_data();
+                .get_unchecked_mut(offset.index()) = extends.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: Option<Expr>) {
+    pub fn set_body(&self, ast: &mut crate::Ast, body: TsInterfaceBody) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(4usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+                .get_unchecked_mut(offset.index()) = body.to_extra_data();
         };
     }
 }
-impl Ident {
-    #[inline]
-    pub fn optional(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = u32::from(node.inline_data) & 255u32;
-        raw != 0
-    }
+impl TsInterfaceBody {
     #[inline]
-    pub fn sym(&self, ast: &crate::Ast) -> Utf8Ref {
+    pub fn body(&self, ast: &crate::Ast) -> TypedSubRange<TsTypeElement> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8316,18 +15823,11 @@ impl Ident {
         }
     }
     #[inline]
-    pub fn set_optional(&self, ast: &mut crate::Ast, optional: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        let field_val: u32 = optional as u32;
-        let old = u32::from(node.inline_data);
-        node.inline_data = ((old & 16776960u32) | (field_val & 255u32)).into();
-    }
-    #[inline]
-    pub fn set_sym(&self, ast: &mut crate::Ast, sym: Utf8Ref) {
+    pub fn set_body(&self, ast: &mut crate::Ast, body: TypedSubRange<TsTypeElement>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8337,17 +15837,17 @@ impl Ident {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = sym.to_extra_data();
+                .get_unchecked_mut(offset.index()) = body.to_extra_data();
         };
     }
 }
-impl IdentName {
+impl TsExprWithTypeArgs {
     #[inline]
-    pub fn sym(&self, ast: &crate::Ast) -> Utf8Ref {
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8362,31 +15862,30 @@ impl IdentName {
         }
     }
     #[inline]
-    pub fn set_sym(&self, ast: &mut crate::Ast, sym: Utf8Ref) {
+    pub fn type_args(&self, ast: &crate::Ast) -> Option<TsTypeParamInstantiation> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = sym.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
-}
-impl PrivateName {
     #[inline]
-    pub fn name(&self, ast: &crate::Ast) -> Utf8Ref {
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8394,122 +15893,64 @@ impl PrivateName {
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
+        };
     }
     #[inline]
-    pub fn set_name(&self, ast: &mut crate::Ast, name: Utf8Ref) {
+    pub fn set_type_args(&self, ast: &mut crate::Ast, type_args: Option<TsTypeParamInstantiation>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = name.to_extra_data();
+                .get_unchecked_mut(offset.index()) = type_args.to_extra_data();
         };
     }
 }
-impl BindingIdent {
-    #[inline]
-    pub fn id(&self, ast: &crate::Ast) -> Ident {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = unsafe { node.data.inline_data };
-        unsafe { Ident::from_node_id_unchecked(crate::NodeId::from_raw_unchecked(raw), ast) }
-    }
-    #[inline]
-    pub fn set_id(&self, ast: &mut crate::Ast, id: Ident) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        node.data.inline_data = id.node_id().index() as u32;
-    }
-}
-impl Lit {
-    #[inline]
-    pub fn is_str(&self) -> bool {
-        matches!(self, Self::Str(_))
-    }
-    #[inline]
-    pub fn is_bool(&self) -> bool {
-        matches!(self, Self::Bool(_))
-    }
-    #[inline]
-    pub fn is_null(&self) -> bool {
-        matches!(self, Self::Null(_))
-    }
-    #[inline]
-    pub fn is_num(&self) -> bool {
-        matches!(self, Self::Num(_))
-    }
-    #[inline]
-    pub fn is_big_int(&self) -> bool {
-        matches!(self, Self::BigInt(_))
-    }
-    #[inline]
-    pub fn is_regex(&self) -> bool {
-        matches!(self, Self::Regex(_))
-    }
-    #[inline]
-    pub fn as_str(self) -> Option<Str> {
-        match self {
-            Self::Str(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_bool(self) -> Option<Bool> {
-        match self {
-            Self::Bool(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_null(self) -> Option<Null> {
-        match self {
-            Self::Null(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_num(self) -> Option<Number> {
-        match self {
-            Self::Num(it) => Some(it),
-            _ => None,
-        }
-    }
+impl TsTypeAliasDecl {
     #[inline]
-    pub fn as_big_int(self) -> Option<BigInt> {
-        match self {
-            Self::BigInt(it) => Some(it),
-            _ => None,
-        }
+    pub fn declare(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
     }
     #[inline]
-    pub fn as_regex(self) -> Option<Regex> {
-        match self {
-            Self::Regex(it) => Some(it),
-            _ => None,
+    pub fn id(&self, ast: &crate::Ast) -> Ident {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(0usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
         }
     }
-}
-impl Str {
     #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> Wtf8Ref {
+    pub fn type_params(&self, ast: &crate::Ast) -> Option<TsTypeParamDecl> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -8521,14 +15962,14 @@ impl Str {
         }
     }
     #[inline]
-    pub fn raw(&self, ast: &crate::Ast) -> OptionalUtf8Ref {
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsType {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(2usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -8540,11 +15981,22 @@ impl Str {
         }
     }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: Wtf8Ref) {
+    pub fn set_declare(&self, ast: &mut crate::Ast, declare: bool) {
+        let field_val: u32 = declare as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_id(&self, ast: &mut crate::Ast, id: Ident) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8554,15 +16006,15 @@ impl Str {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+                .get_unchecked_mut(offset.index()) = id.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_raw(&self, ast: &mut crate::Ast, raw: OptionalUtf8Ref) {
+    pub fn set_type_params(&self, ast: &mut crate::Ast, type_params: Option<TsTypeParamDecl>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -8572,31 +16024,35 @@ impl Str {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = raw.to_extra_data();
+                .get_unchecked_mut(offset.index()) = type_params.to_extra_data();
         };
     }
-}
-impl Bool {
-    #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> bool {
-        let node = unsafe { ast.get_node_unchecked(self.0) };
-        let raw = unsafe { node.data.inline_data };
-        raw != 0
-    }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: bool) {
-        let node = unsafe { ast.get_node_unchecked_mut(self.0) };
-        node.data.inline_data = value as u32;
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsType) {
+        let offset = unsafe {
+            ExtraDataId::from_usize_unchecked(
+                ast.nodes
+                    .data_unchecked(self.0)
+                    .extra_data_start
+                    .index()
+                    .wrapping_add(2usize),
+            )
+        };
+        debug_assert!(offset < ast.extra_data.len());
+        unsafe {
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
+        };
     }
 }
-impl Null {}
-impl Number {
+impl TsEnumDecl {
     #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> f64 {
+    pub fn declare(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8611,11 +16067,11 @@ impl Number {
         }
     }
     #[inline]
-    pub fn raw(&self, ast: &crate::Ast) -> OptionalUtf8Ref {
+    pub fn is_const(&self, ast: &crate::Ast) -> bool {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -8630,49 +16086,49 @@ impl Number {
         }
     }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: f64) {
+    pub fn id(&self, ast: &crate::Ast) -> Ident {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(2usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = value.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn set_raw(&self, ast: &mut crate::Ast, raw: OptionalUtf8Ref) {
+    pub fn members(&self, ast: &crate::Ast) -> TypedSubRange<TsEnumMember> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(3usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = raw.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
-}
-impl BigInt {
     #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> BigIntId {
+    pub fn set_declare(&self, ast: &mut crate::Ast, declare: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8680,18 +16136,17 @@ impl BigInt {
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = declare.to_extra_data();
+        };
     }
     #[inline]
-    pub fn raw(&self, ast: &crate::Ast) -> OptionalUtf8Ref {
+    pub fn set_is_const(&self, ast: &mut crate::Ast, is_const: bool) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -8699,56 +16154,55 @@ impl BigInt {
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = is_const.to_extra_data();
+        };
     }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: BigIntId) {
+    pub fn set_id(&self, ast: &mut crate::Ast, id: Ident) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(2usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+                .get_unchecked_mut(offset.index()) = id.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_raw(&self, ast: &mut crate::Ast, raw: OptionalUtf8Ref) {
+    pub fn set_members(&self, ast: &mut crate::Ast, members: TypedSubRange<TsEnumMember>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(3usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = raw.to_extra_data();
+                .get_unchecked_mut(offset.index()) = members.to_extra_data();
         };
     }
 }
-impl Regex {
+impl TsEnumMember {
     #[inline]
-    pub fn exp(&self, ast: &crate::Ast) -> Utf8Ref {
+    pub fn id(&self, ast: &crate::Ast) -> TsEnumMemberId {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8763,11 +16217,11 @@ impl Regex {
         }
     }
     #[inline]
-    pub fn flags(&self, ast: &crate::Ast) -> Utf8Ref {
+    pub fn init(&self, ast: &crate::Ast) -> Option<Expr> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -8782,11 +16236,11 @@ impl Regex {
         }
     }
     #[inline]
-    pub fn set_exp(&self, ast: &mut crate::Ast, exp: Utf8Ref) {
+    pub fn set_id(&self, ast: &mut crate::Ast, id: TsEnumMemberId) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8796,15 +16250,15 @@ impl Regex {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = exp.to_extra_data();
+                .get_unchecked_mut(offset.index()) = id.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_flags(&self, ast: &mut crate::Ast, flags: Utf8Ref) {
+    pub fn set_init(&self, ast: &mut crate::Ast, init: Option<Expr>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -8814,41 +16268,58 @@ impl Regex {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = flags.to_extra_data();
+                .get_unchecked_mut(offset.index()) = init.to_extra_data();
         };
     }
 }
-impl JSXObject {
-    #[inline]
-    pub fn is_jsx_member_expr(&self) -> bool {
-        matches!(self, Self::JSXMemberExpr(_))
-    }
+impl TsEnumMemberId {
     #[inline]
     pub fn is_ident(&self) -> bool {
         matches!(self, Self::Ident(_))
     }
     #[inline]
-    pub fn as_jsx_member_expr(self) -> Option<JSXMemberExpr> {
+    pub fn is_str(&self) -> bool {
+        matches!(self, Self::Str(_))
+    }
+    #[inline]
+    pub fn as_ident(self) -> Option<Ident> {
         match self {
-            Self::JSXMemberExpr(it) => Some(it),
+            Self::Ident(it) => Some(it),
             _ => None,
         }
     }
     #[inline]
-    pub fn as_ident(self) -> Option<Ident> {
+    pub fn as_str(self) -> Option<Str> {
         match self {
-            Self::Ident(it) => Some(it),
+            Self::Str(it) => Some(it),
             _ => None,
         }
     }
 }
-impl JSXMemberExpr {
+impl TsModuleDecl {
     #[inline]
-    pub fn obj(&self, ast: &crate::Ast) -> JSXObject {
+    pub fn declare(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn global(&self, ast: &crate::Ast) -> bool {
+        let raw =
+            (u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) >> 8usize) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn namespace(&self, ast: &crate::Ast) -> bool {
+        let raw =
+            (u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) >> 16usize) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn id(&self, ast: &crate::Ast) -> TsModuleName {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8863,11 +16334,11 @@ impl JSXMemberExpr {
         }
     }
     #[inline]
-    pub fn prop(&self, ast: &crate::Ast) -> IdentName {
+    pub fn body(&self, ast: &crate::Ast) -> Option<TsNamespaceBody> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -8882,11 +16353,44 @@ impl JSXMemberExpr {
         }
     }
     #[inline]
-    pub fn set_obj(&self, ast: &mut crate::Ast, obj: JSXObject) {
+    pub fn set_declare(&self, ast: &mut crate::Ast, declare: bool) {
+        let field_val: u32 = declare as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_global(&self, ast: &mut crate::Ast, global: bool) {
+        let field_val: u32 = global as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16711935u32) | ((field_val & 255u32) << 8usize)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_namespace(&self, ast: &mut crate::Ast, namespace: bool) {
+        let field_val: u32 = namespace as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 65535u32) | ((field_val & 255u32) << 16usize)).into(),
+            )
+        };
+    }
+    #[inline]
+    pub fn set_id(&self, ast: &mut crate::Ast, id: TsModuleName) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8896,15 +16400,15 @@ impl JSXMemberExpr {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = obj.to_extra_data();
+                .get_unchecked_mut(offset.index()) = id.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_prop(&self, ast: &mut crate::Ast, prop: IdentName) {
+    pub fn set_body(&self, ast: &mut crate::Ast, body: Option<TsNamespaceBody>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -8914,39 +16418,44 @@ impl JSXMemberExpr {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = prop.to_extra_data();
+                .get_unchecked_mut(offset.index()) = body.to_extra_data();
         };
     }
 }
-impl JSXNamespacedName {
+impl TsNamespaceBody {
     #[inline]
-    pub fn ns(&self, ast: &crate::Ast) -> IdentName {
-        let offset = unsafe {
-            ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
-                    .extra_data_start
-                    .index()
-                    .wrapping_add(0usize),
-            )
-        };
-        debug_assert!(offset < ast.extra_data.len());
-        unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
+    pub fn is_ts_module_block(&self) -> bool {
+        matches!(self, Self::TsModuleBlock(_))
+    }
+    #[inline]
+    pub fn is_ts_namespace_decl(&self) -> bool {
+        matches!(self, Self::TsNamespaceDecl(_))
+    }
+    #[inline]
+    pub fn as_ts_module_block(self) -> Option<TsModuleBlock> {
+        match self {
+            Self::TsModuleBlock(it) => Some(it),
+            _ => None,
         }
     }
     #[inline]
-    pub fn name(&self, ast: &crate::Ast) -> IdentName {
+    pub fn as_ts_namespace_decl(self) -> Option<TsNamespaceDecl> {
+        match self {
+            Self::TsNamespaceDecl(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+impl TsModuleBlock {
+    #[inline]
+    pub fn body(&self, ast: &crate::Ast) -> TypedSubRange<ModuleItem> {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(0usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -8958,11 +16467,11 @@ impl JSXNamespacedName {
         }
     }
     #[inline]
-    pub fn set_ns(&self, ast: &mut crate::Ast, ns: IdentName) {
+    pub fn set_body(&self, ast: &mut crate::Ast, body: TypedSubRange<ModuleItem>) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -8972,36 +16481,28 @@ impl JSXNamespacedName {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = ns.to_extra_data();
+                .get_unchecked_mut(offset.index()) = body.to_extra_data();
         };
     }
+}
+impl TsNamespaceDecl {
     #[inline]
-    pub fn set_name(&self, ast: &mut crate::Ast, name: IdentName) {
-        let offset = unsafe {
-            ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
-                    .extra_data_start
-                    .index()
-                    .wrapping_add(1usize),
-            )
-        };
-        debug_assert!(offset < ast.extra_data.len());
-        unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = name.to_extra_data();
-        };
+    pub fn declare(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
     }
-}
-impl JSXEmptyExpr {}
-impl JSXExprContainer {
     #[inline]
-    pub fn expr(&self, ast: &crate::Ast) -> JSXExpr {
+    pub fn global(&self, ast: &crate::Ast) -> bool {
+        let raw =
+            (u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) >> 8usize) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn id(&self, ast: &crate::Ast) -> Ident {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9016,55 +16517,52 @@ impl JSXExprContainer {
         }
     }
     #[inline]
-    pub fn set_expr(&self, ast: &mut crate::Ast, expr: JSXExpr) {
+    pub fn body(&self, ast: &crate::Ast) -> TsNamespaceBody {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
-        };
-    }
-}
-impl JSXExpr {
-    #[inline]
-    pub fn is_jsx_empty_expr(&self) -> bool {
-        matches!(self, Self::JSXEmptyExpr(_))
-    }
-    #[inline]
-    pub fn is_expr(&self) -> bool {
-        matches!(self, Self::Expr(_))
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn as_jsx_empty_expr(self) -> Option<JSXEmptyExpr> {
-        match self {
-            Self::JSXEmptyExpr(it) => Some(it),
-            _ => None,
-        }
+    pub fn set_declare(&self, ast: &mut crate::Ast, declare: bool) {
+        let field_val: u32 = declare as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
+            )
+        };
     }
     #[inline]
-    pub fn as_expr(self) -> Option<Expr> {
-        match self {
-            Self::Expr(it) => Some(it),
-            _ => None,
-        }
+    pub fn set_global(&self, ast: &mut crate::Ast, global: bool) {
+        let field_val: u32 = global as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
+        unsafe {
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16711935u32) | ((field_val & 255u32) << 8usize)).into(),
+            )
+        };
     }
-}
-impl JSXSpreadChild {
     #[inline]
-    pub fn expr(&self, ast: &crate::Ast) -> Expr {
+    pub fn set_id(&self, ast: &mut crate::Ast, id: Ident) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9072,43 +16570,38 @@ impl JSXSpreadChild {
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = id.to_extra_data();
+        };
     }
     #[inline]
-    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
+    pub fn set_body(&self, ast: &mut crate::Ast, body: TsNamespaceBody) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
+                .get_unchecked_mut(offset.index()) = body.to_extra_data();
         };
     }
 }
-impl JSXElementName {
+impl TsModuleName {
     #[inline]
     pub fn is_ident(&self) -> bool {
         matches!(self, Self::Ident(_))
     }
     #[inline]
-    pub fn is_jsx_member_expr(&self) -> bool {
-        matches!(self, Self::JSXMemberExpr(_))
-    }
-    #[inline]
-    pub fn is_jsx_namespaced_name(&self) -> bool {
-        matches!(self, Self::JSXNamespacedName(_))
+    pub fn is_str(&self) -> bool {
+        matches!(self, Self::Str(_))
     }
     #[inline]
     pub fn as_ident(self) -> Option<Ident> {
@@ -9118,27 +16611,31 @@ impl JSXElementName {
         }
     }
     #[inline]
-    pub fn as_jsx_member_expr(self) -> Option<JSXMemberExpr> {
+    pub fn as_str(self) -> Option<Str> {
         match self {
-            Self::JSXMemberExpr(it) => Some(it),
+            Self::Str(it) => Some(it),
             _ => None,
         }
     }
+}
+impl TsImportEqualsDecl {
     #[inline]
-    pub fn as_jsx_namespaced_name(self) -> Option<JSXNamespacedName> {
-        match self {
-            Self::JSXNamespacedName(it) => Some(it),
-            _ => None,
-        }
+    pub fn is_export(&self, ast: &crate::Ast) -> bool {
+        let raw = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) & 255u32;
+        raw != 0
     }
-}
-impl JSXOpeningElement {
     #[inline]
-    pub fn name(&self, ast: &crate::Ast) -> JSXElementName {
+    pub fn is_type_only(&self, ast: &crate::Ast) -> bool {
+        let raw =
+            (u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) }) >> 8usize) & 255u32;
+        raw != 0
+    }
+    #[inline]
+    pub fn id(&self, ast: &crate::Ast) -> Ident {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9153,11 +16650,11 @@ impl JSXOpeningElement {
         }
     }
     #[inline]
-    pub fn attrs(&self, ast: &crate::Ast) -> TypedSubRange<JSXAttrOrSpread> {
+    pub fn module_ref(&self, ast: &crate::Ast) -> TsModuleRef {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -9172,110 +16669,106 @@ impl JSXOpeningElement {
         }
     }
     #[inline]
-    pub fn self_closing(&self, ast: &crate::Ast) -> bool {
-        let offset = unsafe {
-            ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
-                    .extra_data_start
-                    .index()
-                    .wrapping_add(2usize),
-            )
-        };
-        debug_assert!(offset < ast.extra_data.len());
+    pub fn set_is_export(&self, ast: &mut crate::Ast, is_export: bool) {
+        let field_val: u32 = is_export as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16776960u32) | (field_val & 255u32)).into(),
             )
-        }
+        };
     }
     #[inline]
-    pub fn set_name(&self, ast: &mut crate::Ast, name: JSXElementName) {
-        let offset = unsafe {
-            ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
-                    .extra_data_start
-                    .index()
-                    .wrapping_add(0usize),
-            )
-        };
-        debug_assert!(offset < ast.extra_data.len());
+    pub fn set_is_type_only(&self, ast: &mut crate::Ast, is_type_only: bool) {
+        let field_val: u32 = is_type_only as u32;
+        let old = u32::from(unsafe { ast.nodes.inline_data_unchecked(self.0) });
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = name.to_extra_data();
+            ast.nodes.set_inline_data_unchecked(
+                self.0,
+                ((old & 16711935u32) | ((field_val & 255u32) << 8usize)).into(),
+            )
         };
     }
     #[inline]
-    pub fn set_attrs(&self, ast: &mut crate::Ast, attrs: TypedSubRange<JSXAttrOrSpread>) {
+    pub fn set_id(&self, ast: &mut crate::Ast, id: Ident) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(0usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = attrs.to_extra_data();
+                .get_unchecked_mut(offset.index()) = id.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_self_closing(&self, ast: &mut crate::Ast, self_closing: bool) {
+    pub fn set_module_ref(&self, ast: &mut crate::Ast, module_ref: TsModuleRef) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(2usize),
+                    .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = self_closing.to_extra_data();
+                .get_unchecked_mut(offset.index()) = module_ref.to_extra_data();
         };
     }
 }
-impl JSXAttrOrSpread {
+impl TsModuleRef {
     #[inline]
-    pub fn is_jsx_attr(&self) -> bool {
-        matches!(self, Self::JSXAttr(_))
+    pub fn is_ts_entity_name(&self) -> bool {
+        matches!(self, Self::TsEntityName(_))
     }
     #[inline]
-    pub fn is_spread_element(&self) -> bool {
-        matches!(self, Self::SpreadElement(_))
+    pub fn is_ts_external_module_ref(&self) -> bool {
+        matches!(self, Self::TsExternalModuleRef(_))
     }
     #[inline]
-    pub fn as_jsx_attr(self) -> Option<JSXAttr> {
+    pub fn as_ts_entity_name(self) -> Option<TsEntityName> {
         match self {
-            Self::JSXAttr(it) => Some(it),
+            Self::TsEntityName(it) => Some(it),
             _ => None,
         }
     }
     #[inline]
-    pub fn as_spread_element(self) -> Option<SpreadElement> {
+    pub fn as_ts_external_module_ref(self) -> Option<TsExternalModuleRef> {
         match self {
-            Self::SpreadElement(it) => Some(it),
+            Self::TsExternalModuleRef(it) => Some(it),
             _ => None,
         }
     }
 }
-impl JSXClosingElement {
+impl TsExternalModuleRef {
     #[inline]
-    pub fn name(&self, ast: &crate::Ast) -> JSXElementName {
+    pub fn expr(&self, ast: &crate::Ast) -> Str {
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
+        unsafe { Str::from_node_id_unchecked(crate::NodeId::from_raw_unchecked(raw), ast) }
+    }
+    #[inline]
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Str) {
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data = expr.node_id().index() as u32;
+    }
+}
+impl TsExportAssignment {
+    #[inline]
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9290,11 +16783,11 @@ impl JSXClosingElement {
         }
     }
     #[inline]
-    pub fn set_name(&self, ast: &mut crate::Ast, name: JSXElementName) {
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9304,17 +16797,28 @@ impl JSXClosingElement {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = name.to_extra_data();
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
         };
     }
 }
-impl JSXAttr {
+impl TsNamespaceExportDecl {
     #[inline]
-    pub fn name(&self, ast: &crate::Ast) -> JSXAttrName {
+    pub fn id(&self, ast: &crate::Ast) -> Ident {
+        let raw = unsafe { ast.nodes.data_unchecked(self.0).inline_data };
+        unsafe { Ident::from_node_id_unchecked(crate::NodeId::from_raw_unchecked(raw), ast) }
+    }
+    #[inline]
+    pub fn set_id(&self, ast: &mut crate::Ast, id: Ident) {
+        unsafe { ast.nodes.data_unchecked_mut(self.0) }.inline_data = id.node_id().index() as u32;
+    }
+}
+impl TsAsExpr {
+    #[inline]
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9329,11 +16833,11 @@ impl JSXAttr {
         }
     }
     #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> Option<JSXAttrValue> {
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsType {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -9348,11 +16852,11 @@ impl JSXAttr {
         }
     }
     #[inline]
-    pub fn set_name(&self, ast: &mut crate::Ast, name: JSXAttrName) {
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9362,15 +16866,15 @@ impl JSXAttr {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = name.to_extra_data();
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: Option<JSXAttrValue>) {
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsType) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -9380,87 +16884,17 @@ impl JSXAttr {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
         };
     }
 }
-impl JSXAttrName {
-    #[inline]
-    pub fn is_ident(&self) -> bool {
-        matches!(self, Self::Ident(_))
-    }
-    #[inline]
-    pub fn is_jsx_namespaced_name(&self) -> bool {
-        matches!(self, Self::JSXNamespacedName(_))
-    }
-    #[inline]
-    pub fn as_ident(self) -> Option<IdentName> {
-        match self {
-            Self::Ident(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_jsx_namespaced_name(self) -> Option<JSXNamespacedName> {
-        match self {
-            Self::JSXNamespacedName(it) => Some(it),
-            _ => None,
-        }
-    }
-}
-impl JSXAttrValue {
-    #[inline]
-    pub fn is_str(&self) -> bool {
-        matches!(self, Self::Str(_))
-    }
-    #[inline]
-    pub fn is_jsx_expr_container(&self) -> bool {
-        matches!(self, Self::JSXExprContainer(_))
-    }
-    #[inline]
-    pub fn is_jsx_element(&self) -> bool {
-        matches!(self, Self::JSXElement(_))
-    }
-    #[inline]
-    pub fn is_jsx_fragment(&self) -> bool {
-        matches!(self, Self::JSXFragment(_))
-    }
-    #[inline]
-    pub fn as_str(self) -> Option<Str> {
-        match self {
-            Self::Str(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_jsx_expr_container(self) -> Option<JSXExprContainer> {
-        match self {
-            Self::JSXExprContainer(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_jsx_element(self) -> Option<JSXElement> {
-        match self {
-            Self::JSXElement(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_jsx_fragment(self) -> Option<JSXFragment> {
-        match self {
-            Self::JSXFragment(it) => Some(it),
-            _ => None,
-        }
-    }
-}
-impl JSXText {
+impl TsTypeAssertion {
     #[inline]
-    pub fn value(&self, ast: &crate::Ast) -> Utf8Ref {
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9475,11 +16909,11 @@ impl JSXText {
         }
     }
     #[inline]
-    pub fn raw(&self, ast: &crate::Ast) -> Utf8Ref {
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsType {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -9494,11 +16928,11 @@ impl JSXText {
         }
     }
     #[inline]
-    pub fn set_value(&self, ast: &mut crate::Ast, value: Utf8Ref) {
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9508,15 +16942,15 @@ impl JSXText {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = value.to_extra_data();
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_raw(&self, ast: &mut crate::Ast, raw: Utf8Ref) {
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsType) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(1usize),
@@ -9526,17 +16960,17 @@ impl JSXText {
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = raw.to_extra_data();
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
         };
     }
 }
-impl JSXElement {
+impl TsNonNullExpr {
     #[inline]
-    pub fn opening(&self, ast: &crate::Ast) -> JSXOpeningElement {
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9551,33 +16985,34 @@ impl JSXElement {
         }
     }
     #[inline]
-    pub fn children(&self, ast: &crate::Ast) -> TypedSubRange<JSXElementChild> {
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(0usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
+        };
     }
+}
+impl TsSatisfiesExpr {
     #[inline]
-    pub fn closing(&self, ast: &crate::Ast) -> Option<JSXClosingElement> {
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(2usize),
+                    .wrapping_add(0usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -9589,124 +17024,68 @@ impl JSXElement {
         }
     }
     #[inline]
-    pub fn set_opening(&self, ast: &mut crate::Ast, opening: JSXOpeningElement) {
+    pub fn type_ann(&self, ast: &crate::Ast) -> TsType {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = opening.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn set_children(&self, ast: &mut crate::Ast, children: TypedSubRange<JSXElementChild>) {
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(0usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = children.to_extra_data();
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_closing(&self, ast: &mut crate::Ast, closing: Option<JSXClosingElement>) {
+    pub fn set_type_ann(&self, ast: &mut crate::Ast, type_ann: TsType) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(2usize),
+                    .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = closing.to_extra_data();
+                .get_unchecked_mut(offset.index()) = type_ann.to_extra_data();
         };
     }
 }
-impl JSXElementChild {
-    #[inline]
-    pub fn is_jsx_text(&self) -> bool {
-        matches!(self, Self::JSXText(_))
-    }
-    #[inline]
-    pub fn is_jsx_expr_container(&self) -> bool {
-        matches!(self, Self::JSXExprContainer(_))
-    }
-    #[inline]
-    pub fn is_jsx_spread_child(&self) -> bool {
-        matches!(self, Self::JSXSpreadChild(_))
-    }
-    #[inline]
-    pub fn is_jsx_element(&self) -> bool {
-        matches!(self, Self::JSXElement(_))
-    }
-    #[inline]
-    pub fn is_jsx_fragment(&self) -> bool {
-        matches!(self, Self::JSXFragment(_))
-    }
-    #[inline]
-    pub fn as_jsx_text(self) -> Option<JSXText> {
-        match self {
-            Self::JSXText(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_jsx_expr_container(self) -> Option<JSXExprContainer> {
-        match self {
-            Self::JSXExprContainer(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_jsx_spread_child(self) -> Option<JSXSpreadChild> {
-        match self {
-            Self::JSXSpreadChild(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_jsx_element(self) -> Option<JSXElement> {
-        match self {
-            Self::JSXElement(it) => Some(it),
-            _ => None,
-        }
-    }
-    #[inline]
-    pub fn as_jsx_fragment(self) -> Option<JSXFragment> {
-        match self {
-            Self::JSXFragment(it) => Some(it),
-            _ => None,
-        }
-    }
-}
-impl JSXFragment {
+impl TsConstAssertion {
     #[inline]
-    pub fn opening(&self, ast: &crate::Ast) -> JSXOpeningFragment {
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
                     .wrapping_add(0usize),
@@ -9721,33 +17100,34 @@ impl JSXFragment {
         }
     }
     #[inline]
-    pub fn children(&self, ast: &crate::Ast) -> TypedSubRange<JSXElementChild> {
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(0usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            ExtraDataCompact::from_extra_data(
-                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
-                ast,
-            )
-        }
+            *ast.extra_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
+        };
     }
+}
+impl TsInstantiation {
     #[inline]
-    pub fn closing(&self, ast: &crate::Ast) -> JSXClosingFragment {
+    pub fn expr(&self, ast: &crate::Ast) -> Expr {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(2usize),
+                    .wrapping_add(0usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
@@ -9759,59 +17139,58 @@ impl JSXFragment {
         }
     }
     #[inline]
-    pub fn set_opening(&self, ast: &mut crate::Ast, opening: JSXOpeningFragment) {
+    pub fn type_args(&self, ast: &crate::Ast) -> TsTypeParamInstantiation {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(0usize),
+                    .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
-            *ast.extra_data
-                .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = opening.to_extra_data();
-        };
+            ExtraDataCompact::from_extra_data(
+                *ast.extra_data.as_raw_slice().get_unchecked(offset.index()),
+                ast,
+            )
+        }
     }
     #[inline]
-    pub fn set_children(&self, ast: &mut crate::Ast, children: TypedSubRange<JSXElementChild>) {
+    pub fn set_expr(&self, ast: &mut crate::Ast, expr: Expr) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(1usize),
+                    .wrapping_add(0usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = children.to_extra_data();
+                .get_unchecked_mut(offset.index()) = expr.to_extra_data();
         };
     }
     #[inline]
-    pub fn set_closing(&self, ast: &mut crate::Ast, closing: JSXClosingFragment) {
+    pub fn set_type_args(&self, ast: &mut crate::Ast, type_args: TsTypeParamInstantiation) {
         let offset = unsafe {
             ExtraDataId::from_usize_unchecked(
-                ast.get_node_unchecked(self.0)
-                    .data
+                ast.nodes
+                    .data_unchecked(self.0)
                     .extra_data_start
                     .index()
-                    .wrapping_add(2usize),
+                    .wrapping_add(1usize),
             )
         };
         debug_assert!(offset < ast.extra_data.len());
         unsafe {
             *ast.extra_data
                 .as_raw_slice_mut()
-                .get_unchecked_mut(offset.index()) = closing.to_extra_data();
+                .get_unchecked_mut(offset.index()) = type_args.to_extra_data();
         };
     }
 }
-impl JSXOpeningFragment {}
-impl JSXClosingFragment {}
@@ -0,0 +1,5317 @@
+#![allow(dead_code, unreachable_patterns)]
+use crate::{schema::*, *};
+const PROGRAM: NodeType = NodeType {
+    name: "Program",
+    kinds: &[NodeKind::Module, NodeKind::Script],
+    codec: NodeCodec::new::<Program>(),
+    optional_codec: None,
+};
+const MODULE: NodeType = NodeType {
+    name: "Module",
+    kinds: &[NodeKind::Module],
+    codec: NodeCodec::new::<Module>(),
+    optional_codec: None,
+};
+const SCRIPT: NodeType = NodeType {
+    name: "Script",
+    kinds: &[NodeKind::Script],
+    codec: NodeCodec::new::<Script>(),
+    optional_codec: None,
+};
+const MODULE_ITEM: NodeType = NodeType {
+    name: "ModuleItem",
+    kinds: &[
+        NodeKind::BlockStmt,
+        NodeKind::BreakStmt,
+        NodeKind::ClassDecl,
+        NodeKind::ContinueStmt,
+        NodeKind::DebuggerStmt,
+        NodeKind::DoWhileStmt,
+        NodeKind::EmptyStmt,
+        NodeKind::ExportAll,
+        NodeKind::ExportDecl,
+        NodeKind::ExportDefaultDecl,
+        NodeKind::ExportDefaultExpr,
+        NodeKind::ExprStmt,
+        NodeKind::FnDecl,
+        NodeKind::ForInStmt,
+        NodeKind::ForOfStmt,
+        NodeKind::ForStmt,
+        NodeKind::IfStmt,
+        NodeKind::ImportDecl,
+        NodeKind::LabeledStmt,
+        NodeKind::NamedExport,
+        NodeKind::ReturnStmt,
+        NodeKind::SwitchStmt,
+        NodeKind::ThrowStmt,
+        NodeKind::TryStmt,
+        NodeKind::TsEnumDecl,
+        NodeKind::TsExportAssignment,
+        NodeKind::TsImportEqualsDecl,
+        NodeKind::TsInterfaceDecl,
+        NodeKind::TsModuleDecl,
+        NodeKind::TsNamespaceExportDecl,
+        NodeKind::TsTypeAliasDecl,
+        NodeKind::UsingDecl,
+        NodeKind::VarDecl,
+        NodeKind::WhileStmt,
+        NodeKind::WithStmt,
+    ],
+    codec: NodeCodec::new::<ModuleItem>(),
+    optional_codec: None,
+};
+const MODULE_DECL: NodeType = NodeType {
+    name: "ModuleDecl",
+    kinds: &[
+        NodeKind::ExportAll,
+        NodeKind::ExportDecl,
+        NodeKind::ExportDefaultDecl,
+        NodeKind::ExportDefaultExpr,
+        NodeKind::ImportDecl,
+        NodeKind::NamedExport,
+        NodeKind::TsExportAssignment,
+        NodeKind::TsImportEqualsDecl,
+        NodeKind::TsNamespaceExportDecl,
+    ],
+    codec: NodeCodec::new::<ModuleDecl>(),
+    optional_codec: None,
+};
+const IMPORT_DECL: NodeType = NodeType {
+    name: "ImportDecl",
+    kinds: &[NodeKind::ImportDecl],
+    codec: NodeCodec::new::<ImportDecl>(),
+    optional_codec: None,
+};
+const IMPORT_SPECIFIER: NodeType = NodeType {
+    name: "ImportSpecifier",
+    kinds: &[
+        NodeKind::ImportDefaultSpecifier,
+        NodeKind::ImportNamedSpecifier,
+        NodeKind::ImportStarAsSpecifier,
+    ],
+    codec: NodeCodec::new::<ImportSpecifier>(),
+    optional_codec: None,
+};
+const IMPORT_NAMED_SPECIFIER: NodeType = NodeType {
+    name: "ImportNamedSpecifier",
+    kinds: &[NodeKind::ImportNamedSpecifier],
+    codec: NodeCodec::new::<ImportNamedSpecifier>(),
+    optional_codec: None,
+};
+const IMPORT_DEFAULT_SPECIFIER: NodeType = NodeType {
+    name: "ImportDefaultSpecifier",
+    kinds: &[NodeKind::ImportDefaultSpecifier],
+    codec: NodeCodec::new::<ImportDefaultSpecifier>(),
+    optional_codec: None,
+};
+const IMPORT_STAR_AS_SPECIFIER: NodeType = NodeType {
+    name: "ImportStarAsSpecifier",
+    kinds: &[NodeKind::ImportStarAsSpecifier],
+    codec: NodeCodec::new::<ImportStarAsSpecifier>(),
+    optional_codec: None,
+};
+const EXPORT_DECL: NodeType = NodeType {
+    name: "ExportDecl",
+    kinds: &[NodeKind::ExportDecl],
+    codec: NodeCodec::new::<ExportDecl>(),
+    optional_codec: None,
+};
+const NAMED_EXPORT: NodeType = NodeType {
+    name: "NamedExport",
+    kinds: &[NodeKind::NamedExport],
+    codec: NodeCodec::new::<NamedExport>(),
+    optional_codec: None,
+};
+const EXPORT_SPECIFIER: NodeType = NodeType {
+    name: "ExportSpecifier",
+    kinds: &[
+        NodeKind::ExportDefaultSpecifier,
+        NodeKind::ExportNamedSpecifier,
+        NodeKind::ExportNamespaceSpecifier,
+    ],
+    codec: NodeCodec::new::<ExportSpecifier>(),
+    optional_codec: None,
+};
+const EXPORT_NAMESPACE_SPECIFIER: NodeType = NodeType {
+    name: "ExportNamespaceSpecifier",
+    kinds: &[NodeKind::ExportNamespaceSpecifier],
+    codec: NodeCodec::new::<ExportNamespaceSpecifier>(),
+    optional_codec: None,
+};
+const MODULE_EXPORT_NAME: NodeType = NodeType {
+    name: "ModuleExportName",
+    kinds: &[NodeKind::Ident, NodeKind::Str],
+    codec: NodeCodec::new::<ModuleExportName>(),
+    optional_codec: Some(NodeCodec::new_optional::<ModuleExportName>()),
+};
+const EXPORT_DEFAULT_SPECIFIER: NodeType = NodeType {
+    name: "ExportDefaultSpecifier",
+    kinds: &[NodeKind::ExportDefaultSpecifier],
+    codec: NodeCodec::new::<ExportDefaultSpecifier>(),
+    optional_codec: None,
+};
+const EXPORT_NAMED_SPECIFIER: NodeType = NodeType {
+    name: "ExportNamedSpecifier",
+    kinds: &[NodeKind::ExportNamedSpecifier],
+    codec: NodeCodec::new::<ExportNamedSpecifier>(),
+    optional_codec: None,
+};
+const EXPORT_DEFAULT_DECL: NodeType = NodeType {
+    name: "ExportDefaultDecl",
+    kinds: &[NodeKind::ExportDefaultDecl],
+    codec: NodeCodec::new::<ExportDefaultDecl>(),
+    optional_codec: None,
+};
+const DEFAULT_DECL: NodeType = NodeType {
+    name: "DefaultDecl",
+    kinds: &[
+        NodeKind::ClassExpr,
+        NodeKind::FnExpr,
+        NodeKind::TsInterfaceDecl,
+    ],
+    codec: NodeCodec::new::<DefaultDecl>(),
+    optional_codec: None,
+};
+const EXPORT_DEFAULT_EXPR: NodeType = NodeType {
+    name: "ExportDefaultExpr",
+    kinds: &[NodeKind::ExportDefaultExpr],
+    codec: NodeCodec::new::<ExportDefaultExpr>(),
+    optional_codec: None,
+};
+const EXPORT_ALL: NodeType = NodeType {
+    name: "ExportAll",
+    kinds: &[NodeKind::ExportAll],
+    codec: NodeCodec::new::<ExportAll>(),
+    optional_codec: None,
+};
+const BLOCK_STMT: NodeType = NodeType {
+    name: "BlockStmt",
+    kinds: &[NodeKind::BlockStmt],
+    codec: NodeCodec::new::<BlockStmt>(),
+    optional_codec: Some(NodeCodec::new_optional::<BlockStmt>()),
+};
+const STMT: NodeType = NodeType {
+    name: "Stmt",
+    kinds: &[
+        NodeKind::BlockStmt,
+        NodeKind::BreakStmt,
+        NodeKind::ClassDecl,
+        NodeKind::ContinueStmt,
+        NodeKind::DebuggerStmt,
+        NodeKind::DoWhileStmt,
+        NodeKind::EmptyStmt,
+        NodeKind::ExprStmt,
+        NodeKind::FnDecl,
+        NodeKind::ForInStmt,
+        NodeKind::ForOfStmt,
+        NodeKind::ForStmt,
+        NodeKind::IfStmt,
+        NodeKind::LabeledStmt,
+        NodeKind::ReturnStmt,
+        NodeKind::SwitchStmt,
+        NodeKind::ThrowStmt,
+        NodeKind::TryStmt,
+        NodeKind::TsEnumDecl,
+        NodeKind::TsInterfaceDecl,
+        NodeKind::TsModuleDecl,
+        NodeKind::TsTypeAliasDecl,
+        NodeKind::UsingDecl,
+        NodeKind::VarDecl,
+        NodeKind::WhileStmt,
+        NodeKind::WithStmt,
+    ],
+    codec: NodeCodec::new::<Stmt>(),
+    optional_codec: Some(NodeCodec::new_optional::<Stmt>()),
+};
+const EXPR_STMT: NodeType = NodeType {
+    name: "ExprStmt",
+    kinds: &[NodeKind::ExprStmt],
+    codec: NodeCodec::new::<ExprStmt>(),
+    optional_codec: None,
+};
+const EMPTY_STMT: NodeType = NodeType {
+    name: "EmptyStmt",
+    kinds: &[NodeKind::EmptyStmt],
+    codec: NodeCodec::new::<EmptyStmt>(),
+    optional_codec: None,
+};
+const DEBUGGER_STMT: NodeType = NodeType {
+    name: "DebuggerStmt",
+    kinds: &[NodeKind::DebuggerStmt],
+    codec: NodeCodec::new::<DebuggerStmt>(),
+    optional_codec: None,
+};
+const WITH_STMT: NodeType = NodeType {
+    name: "WithStmt",
+    kinds: &[NodeKind::WithStmt],
+    codec: NodeCodec::new::<WithStmt>(),
+    optional_codec: None,
+};
+const RETURN_STMT: NodeType = NodeType {
+    name: "ReturnStmt",
+    kinds: &[NodeKind::ReturnStmt],
+    codec: NodeCodec::new::<ReturnStmt>(),
+    optional_codec: None,
+};
+const LABELED_STMT: NodeType = NodeType {
+    name: "LabeledStmt",
+    kinds: &[NodeKind::LabeledStmt],
+    codec: NodeCodec::new::<LabeledStmt>(),
+    optional_codec: None,
+};
+const BREAK_STMT: NodeType = NodeType {
+    name: "BreakStmt",
+    kinds: &[NodeKind::BreakStmt],
+    codec: NodeCodec::new::<BreakStmt>(),
+    optional_codec: None,
+};
+const CONTINUE_STMT: NodeType = NodeType {
+    name: "ContinueStmt",
+    kinds: &[NodeKind::ContinueStmt],
+    codec: NodeCodec::new::<ContinueStmt>(),
+    optional_codec: None,
+};
+const IF_STMT: NodeType = NodeType {
+    name: "IfStmt",
+    kinds: &[NodeKind::IfStmt],
+    codec: NodeCodec::new::<IfStmt>(),
+    optional_codec: None,
+};
+const SWITCH_STMT: NodeType = NodeType {
+    name: "SwitchStmt",
+    kinds: &[NodeKind::SwitchStmt],
+    codec: NodeCodec::new::<SwitchStmt>(),
+    optional_codec: None,
+};
+const THROW_STMT: NodeType = NodeType {
+    name: "ThrowStmt",
+    kinds: &[NodeKind::ThrowStmt],
+    codec: NodeCodec::new::<ThrowStmt>(),
+    optional_codec: None,
+};
+const TRY_STMT: NodeType = NodeType {
+    name: "TryStmt",
+    kinds: &[NodeKind::TryStmt],
+    codec: NodeCodec::new::<TryStmt>(),
+    optional_codec: None,
+};
+const WHILE_STMT: NodeType = NodeType {
+    name: "WhileStmt",
+    kinds: &[NodeKind::WhileStmt],
+    codec: NodeCodec::new::<WhileStmt>(),
+    optional_codec: None,
+};
+const DO_WHILE_STMT: NodeType = NodeType {
+    name: "DoWhileStmt",
+    kinds: &[NodeKind::DoWhileStmt],
+    codec: NodeCodec::new::<DoWhileStmt>(),
+    optional_codec: None,
+};
+const FOR_STMT: NodeType = NodeType {
+    name: "ForStmt",
+    kinds: &[NodeKind::ForStmt],
+    codec: NodeCodec::new::<ForStmt>(),
+    optional_codec: None,
+};
+const FOR_IN_STMT: NodeType = NodeType {
+    name: "ForInStmt",
+    kinds: &[NodeKind::ForInStmt],
+    codec: NodeCodec::new::<ForInStmt>(),
+    optional_codec: None,
+};
+const FOR_OF_STMT: NodeType = NodeType {
+    name: "ForOfStmt",
+    kinds: &[NodeKind::ForOfStmt],
+    codec: NodeCodec::new::<ForOfStmt>(),
+    optional_codec: None,
+};
+const SWITCH_CASE: NodeType = NodeType {
+    name: "SwitchCase",
+    kinds: &[NodeKind::SwitchCase],
+    codec: NodeCodec::new::<SwitchCase>(),
+    optional_codec: None,
+};
+const CATCH_CLAUSE: NodeType = NodeType {
+    name: "CatchClause",
+    kinds: &[NodeKind::CatchClause],
+    codec: NodeCodec::new::<CatchClause>(),
+    optional_codec: Some(NodeCodec::new_optional::<CatchClause>()),
+};
+const FOR_HEAD: NodeType = NodeType {
+    name: "ForHead",
+    kinds: &[
+        NodeKind::ArrayLit,
+        NodeKind::ArrayPat,
+        NodeKind::ArrowExpr,
+        NodeKind::AssignExpr,
+        NodeKind::AssignPat,
+        NodeKind::AwaitExpr,
+        NodeKind::BigInt,
+        NodeKind::BinExpr,
+        NodeKind::BindingIdent,
+        NodeKind::Bool,
+        NodeKind::CallExpr,
+        NodeKind::ClassExpr,
+        NodeKind::CondExpr,
+        NodeKind::FnExpr,
+        NodeKind::Ident,
+        NodeKind::Invalid,
+        NodeKind::JSXElement,
+        NodeKind::JSXEmptyExpr,
+        NodeKind::JSXFragment,
+        NodeKind::JSXMemberExpr,
+        NodeKind::JSXNamespacedName,
+        NodeKind::MemberExpr,
+        NodeKind::MetaPropExpr,
+        NodeKind::NewExpr,
+        NodeKind::Null,
+        NodeKind::Number,
+        NodeKind::ObjectLit,
+        NodeKind::ObjectPat,
+        NodeKind::OptChainExpr,
+        NodeKind::ParenExpr,
+        NodeKind::PrivateName,
+        NodeKind::Regex,
+        NodeKind::RestPat,
+        NodeKind::SeqExpr,
+        NodeKind::Str,
+        NodeKind::SuperPropExpr,
+        NodeKind::TaggedTpl,
+        NodeKind::ThisExpr,
+        NodeKind::Tpl,
+        NodeKind::TsAsExpr,
+        NodeKind::TsConstAssertion,
+        NodeKind::TsInstantiation,
+        NodeKind::TsNonNullExpr,
+        NodeKind::TsSatisfiesExpr,
+        NodeKind::TsTypeAssertion,
+        NodeKind::UnaryExpr,
+        NodeKind::UpdateExpr,
+        NodeKind::UsingDecl,
+        NodeKind::VarDecl,
+        NodeKind::YieldExpr,
+    ],
+    codec: NodeCodec::new::<ForHead>(),
+    optional_codec: None,
+};
+const VAR_DECL_OR_EXPR: NodeType = NodeType {
+    name: "VarDeclOrExpr",
+    kinds: &[
+        NodeKind::ArrayLit,
+        NodeKind::ArrowExpr,
+        NodeKind::AssignExpr,
+        NodeKind::AwaitExpr,
+        NodeKind::BigInt,
+        NodeKind::BinExpr,
+        NodeKind::Bool,
+        NodeKind::CallExpr,
+        NodeKind::ClassExpr,
+        NodeKind::CondExpr,
+        NodeKind::FnExpr,
+        NodeKind::Ident,
+        NodeKind::Invalid,
+        NodeKind::JSXElement,
+        NodeKind::JSXEmptyExpr,
+        NodeKind::JSXFragment,
+        NodeKind::JSXMemberExpr,
+        NodeKind::JSXNamespacedName,
+        NodeKind::MemberExpr,
+        NodeKind::MetaPropExpr,
+        NodeKind::NewExpr,
+        NodeKind::Null,
+        NodeKind::Number,
+        NodeKind::ObjectLit,
+        NodeKind::OptChainExpr,
+        NodeKind::ParenExpr,
+        NodeKind::PrivateName,
+        NodeKind::Regex,
+        NodeKind::SeqExpr,
+        NodeKind::Str,
+        NodeKind::SuperPropExpr,
+        NodeKind::TaggedTpl,
+        NodeKind::ThisExpr,
+        NodeKind::Tpl,
+        NodeKind::TsAsExpr,
+        NodeKind::TsConstAssertion,
+        NodeKind::TsInstantiation,
+        NodeKind::TsNonNullExpr,
+        NodeKind::TsSatisfiesExpr,
+        NodeKind::TsTypeAssertion,
+        NodeKind::UnaryExpr,
+        NodeKind::UpdateExpr,
+        NodeKind::VarDecl,
+        NodeKind::YieldExpr,
+    ],
+    codec: NodeCodec::new::<VarDeclOrExpr>(),
+    optional_codec: Some(NodeCodec::new_optional::<VarDeclOrExpr>()),
+};
+const DECL: NodeType = NodeType {
+    name: "Decl",
+    kinds: &[
+        NodeKind::ClassDecl,
+        NodeKind::FnDecl,
+        NodeKind::TsEnumDecl,
+        NodeKind::TsInterfaceDecl,
+        NodeKind::TsModuleDecl,
+        NodeKind::TsTypeAliasDecl,
+        NodeKind::UsingDecl,
+        NodeKind::VarDecl,
+    ],
+    codec: NodeCodec::new::<Decl>(),
+    optional_codec: None,
+};
+const FN_DECL: NodeType = NodeType {
+    name: "FnDecl",
+    kinds: &[NodeKind::FnDecl],
+    codec: NodeCodec::new::<FnDecl>(),
+    optional_codec: None,
+};
+const CLASS_DECL: NodeType = NodeType {
+    name: "ClassDecl",
+    kinds: &[NodeKind::ClassDecl],
+    codec: NodeCodec::new::<ClassDecl>(),
+    optional_codec: None,
+};
+const VAR_DECL: NodeType = NodeType {
+    name: "VarDecl",
+    kinds: &[NodeKind::VarDecl],
+    codec: NodeCodec::new::<VarDecl>(),
+    optional_codec: None,
+};
+const VAR_DECLARATOR: NodeType = NodeType {
+    name: "VarDeclarator",
+    kinds: &[NodeKind::VarDeclarator],
+    codec: NodeCodec::new::<VarDeclarator>(),
+    optional_codec: None,
+};
+const USING_DECL: NodeType = NodeType {
+    name: "UsingDecl",
+    kinds: &[NodeKind::UsingDecl],
+    codec: NodeCodec::new::<UsingDecl>(),
+    optional_codec: None,
+};
+const EXPR: NodeType = NodeType {
+    name: "Expr",
+    kinds: &[
+        NodeKind::ArrayLit,
+        NodeKind::ArrowExpr,
+        NodeKind::AssignExpr,
+        NodeKind::AwaitExpr,
+        NodeKind::BigInt,
+        NodeKind::BinExpr,
+        NodeKind::Bool,
+        NodeKind::CallExpr,
+        NodeKind::ClassExpr,
+        NodeKind::CondExpr,
+        NodeKind::FnExpr,
+        NodeKind::Ident,
+        NodeKind::Invalid,
+        NodeKind::JSXElement,
+        NodeKind::JSXEmptyExpr,
+        NodeKind::JSXFragment,
+        NodeKind::JSXMemberExpr,
+        NodeKind::JSXNamespacedName,
+        NodeKind::MemberExpr,
+        NodeKind::MetaPropExpr,
+        NodeKind::NewExpr,
+        NodeKind::Null,
+        NodeKind::Number,
+        NodeKind::ObjectLit,
+        NodeKind::OptChainExpr,
+        NodeKind::ParenExpr,
+        NodeKind::PrivateName,
+        NodeKind::Regex,
+        NodeKind::SeqExpr,
+        NodeKind::Str,
+        NodeKind::SuperPropExpr,
+        NodeKind::TaggedTpl,
+        NodeKind::ThisExpr,
+        NodeKind::Tpl,
+        NodeKind::TsAsExpr,
+        NodeKind::TsConstAssertion,
+        NodeKind::TsInstantiation,
+        NodeKind::TsNonNullExpr,
+        NodeKind::TsSatisfiesExpr,
+        NodeKind::TsTypeAssertion,
+        NodeKind::UnaryExpr,
+        NodeKind::UpdateExpr,
+        NodeKind::YieldExpr,
+    ],
+    codec: NodeCodec::new::<Expr>(),
+    optional_codec: Some(NodeCodec::new_optional::<Expr>()),
+};
+const THIS_EXPR: NodeType = NodeType {
+    name: "ThisExpr",
+    kinds: &[NodeKind::ThisExpr],
+    codec: NodeCodec::new::<ThisExpr>(),
+    optional_codec: None,
+};
+const ARRAY_LIT: NodeType = NodeType {
+    name: "ArrayLit",
+    kinds: &[NodeKind::ArrayLit],
+    codec: NodeCodec::new::<ArrayLit>(),
+    optional_codec: None,
+};
+const OBJECT_LIT: NodeType = NodeType {
+    name: "ObjectLit",
+    kinds: &[NodeKind::ObjectLit],
+    codec: NodeCodec::new::<ObjectLit>(),
+    optional_codec: Some(NodeCodec::new_optional::<ObjectLit>()),
+};
+const PROP_OR_SPREAD: NodeType = NodeType {
+    name: "PropOrSpread",
+    kinds: &[
+        NodeKind::AssignProp,
+        NodeKind::GetterProp,
+        NodeKind::Ident,
+        NodeKind::KeyValueProp,
+        NodeKind::MethodProp,
+        NodeKind::SetterProp,
+        NodeKind::SpreadElement,
+    ],
+    codec: NodeCodec::new::<PropOrSpread>(),
+    optional_codec: None,
+};
+const SPREAD_ELEMENT: NodeType = NodeType {
+    name: "SpreadElement",
+    kinds: &[NodeKind::SpreadElement],
+    codec: NodeCodec::new::<SpreadElement>(),
+    optional_codec: None,
+};
+const UNARY_EXPR: NodeType = NodeType {
+    name: "UnaryExpr",
+    kinds: &[NodeKind::UnaryExpr],
+    codec: NodeCodec::new::<UnaryExpr>(),
+    optional_codec: None,
+};
+const UPDATE_EXPR: NodeType = NodeType {
+    name: "UpdateExpr",
+    kinds: &[NodeKind::UpdateExpr],
+    codec: NodeCodec::new::<UpdateExpr>(),
+    optional_codec: None,
+};
+const BIN_EXPR: NodeType = NodeType {
+    name: "BinExpr",
+    kinds: &[NodeKind::BinExpr],
+    codec: NodeCodec::new::<BinExpr>(),
+    optional_codec: None,
+};
+const FN_EXPR: NodeType = NodeType {
+    name: "FnExpr",
+    kinds: &[NodeKind::FnExpr],
+    codec: NodeCodec::new::<FnExpr>(),
+    optional_codec: None,
+};
+const CLASS_EXPR: NodeType = NodeType {
+    name: "ClassExpr",
+    kinds: &[NodeKind::ClassExpr],
+    codec: NodeCodec::new::<ClassExpr>(),
+    optional_codec: None,
+};
+const ASSIGN_EXPR: NodeType = NodeType {
+    name: "AssignExpr",
+    kinds: &[NodeKind::AssignExpr],
+    codec: NodeCodec::new::<AssignExpr>(),
+    optional_codec: None,
+};
+const MEMBER_EXPR: NodeType = NodeType {
+    name: "MemberExpr",
+    kinds: &[NodeKind::MemberExpr],
+    codec: NodeCodec::new::<MemberExpr>(),
+    optional_codec: None,
+};
+const MEMBER_PROP: NodeType = NodeType {
+    name: "MemberProp",
+    kinds: &[
+        NodeKind::ComputedPropName,
+        NodeKind::IdentName,
+        NodeKind::PrivateName,
+    ],
+    codec: NodeCodec::new::<MemberProp>(),
+    optional_codec: None,
+};
+const SUPER_PROP_EXPR: NodeType = NodeType {
+    name: "SuperPropExpr",
+    kinds: &[NodeKind::SuperPropExpr],
+    codec: NodeCodec::new::<SuperPropExpr>(),
+    optional_codec: None,
+};
+const SUPER_PROP: NodeType = NodeType {
+    name: "SuperProp",
+    kinds: &[NodeKind::ComputedPropName, NodeKind::IdentName],
+    codec: NodeCodec::new::<SuperProp>(),
+    optional_codec: None,
+};
+const COND_EXPR: NodeType = NodeType {
+    name: "CondExpr",
+    kinds: &[NodeKind::CondExpr],
+    codec: NodeCodec::new::<CondExpr>(),
+    optional_codec: None,
+};
+const CALL_EXPR: NodeType = NodeType {
+    name: "CallExpr",
+    kinds: &[NodeKind::CallExpr],
+    codec: NodeCodec::new::<CallExpr>(),
+    optional_codec: None,
+};
+const NEW_EXPR: NodeType = NodeType {
+    name: "NewExpr",
+    kinds: &[NodeKind::NewExpr],
+    codec: NodeCodec::new::<NewExpr>(),
+    optional_codec: None,
+};
+const SEQ_EXPR: NodeType = NodeType {
+    name: "SeqExpr",
+    kinds: &[NodeKind::SeqExpr],
+    codec: NodeCodec::new::<SeqExpr>(),
+    optional_codec: None,
+};
+const ARROW_EXPR: NodeType = NodeType {
+    name: "ArrowExpr",
+    kinds: &[NodeKind::ArrowExpr],
+    codec: NodeCodec::new::<ArrowExpr>(),
+    optional_codec: None,
+};
+const YIELD_EXPR: NodeType = NodeType {
+    name: "YieldExpr",
+    kinds: &[NodeKind::YieldExpr],
+    codec: NodeCodec::new::<YieldExpr>(),
+    optional_codec: None,
+};
+const META_PROP_EXPR: NodeType = NodeType {
+    name: "MetaPropExpr",
+    kinds: &[NodeKind::MetaPropExpr],
+    codec: NodeCodec::new::<MetaPropExpr>(),
+    optional_codec: None,
+};
+const AWAIT_EXPR: NodeType = NodeType {
+    name: "AwaitExpr",
+    kinds: &[NodeKind::AwaitExpr],
+    codec: NodeCodec::new::<AwaitExpr>(),
+    optional_codec: None,
+};
+const TPL: NodeType = NodeType {
+    name: "Tpl",
+    kinds: &[NodeKind::Tpl],
+    codec: NodeCodec::new::<Tpl>(),
+    optional_codec: None,
+};
+const TAGGED_TPL: NodeType = NodeType {
+    name: "TaggedTpl",
+    kinds: &[NodeKind::TaggedTpl],
+    codec: NodeCodec::new::<TaggedTpl>(),
+    optional_codec: None,
+};
+const TPL_ELEMENT: NodeType = NodeType {
+    name: "TplElement",
+    kinds: &[NodeKind::TplElement],
+    codec: NodeCodec::new::<TplElement>(),
+    optional_codec: None,
+};
+const PAREN_EXPR: NodeType = NodeType {
+    name: "ParenExpr",
+    kinds: &[NodeKind::ParenExpr],
+    codec: NodeCodec::new::<ParenExpr>(),
+    optional_codec: None,
+};
+const CALLEE: NodeType = NodeType {
+    name: "Callee",
+    kinds: &[
+        NodeKind::ArrayLit,
+        NodeKind::ArrowExpr,
+        NodeKind::AssignExpr,
+        NodeKind::AwaitExpr,
+        NodeKind::BigInt,
+        NodeKind::BinExpr,
+        NodeKind::Bool,
+        NodeKind::CallExpr,
+        NodeKind::ClassExpr,
+        NodeKind::CondExpr,
+        NodeKind::FnExpr,
+        NodeKind::Ident,
+        NodeKind::Import,
+        NodeKind::Invalid,
+        NodeKind::JSXElement,
+        NodeKind::JSXEmptyExpr,
+        NodeKind::JSXFragment,
+        NodeKind::JSXMemberExpr,
+        NodeKind::JSXNamespacedName,
+        NodeKind::MemberExpr,
+        NodeKind::MetaPropExpr,
+        NodeKind::NewExpr,
+        NodeKind::Null,
+        NodeKind::Number,
+        NodeKind::ObjectLit,
+        NodeKind::OptChainExpr,
+        NodeKind::ParenExpr,
+        NodeKind::PrivateName,
+        NodeKind::Regex,
+        NodeKind::SeqExpr,
+        NodeKind::Str,
+        NodeKind::Super,
+        NodeKind::SuperPropExpr,
+        NodeKind::TaggedTpl,
+        NodeKind::ThisExpr,
+        NodeKind::Tpl,
+        NodeKind::TsAsExpr,
+        NodeKind::TsConstAssertion,
+        NodeKind::TsInstantiation,
+        NodeKind::TsNonNullExpr,
+        NodeKind::TsSatisfiesExpr,
+        NodeKind::TsTypeAssertion,
+        NodeKind::UnaryExpr,
+        NodeKind::UpdateExpr,
+        NodeKind::YieldExpr,
+    ],
+    codec: NodeCodec::new::<Callee>(),
+    optional_codec: None,
+};
+const SUPER: NodeType = NodeType {
+    name: "Super",
+    kinds: &[NodeKind::Super],
+    codec: NodeCodec::new::<Super>(),
+    optional_codec: None,
+};
+const IMPORT: NodeType = NodeType {
+    name: "Import",
+    kinds: &[NodeKind::Import],
+    codec: NodeCodec::new::<Import>(),
+    optional_codec: None,
+};
+const EXPR_OR_SPREAD: NodeType = NodeType {
+    name: "ExprOrSpread",
+    kinds: &[NodeKind::ExprOrSpread],
+    codec: NodeCodec::new::<ExprOrSpread>(),
+    optional_codec: Some(NodeCodec::new_optional::<ExprOrSpread>()),
+};
+const SPREAD_DOT_3_TOKEN: NodeType = NodeType {
+    name: "SpreadDot3Token",
+    kinds: &[NodeKind::SpreadDot3Token],
+    codec: NodeCodec::new::<SpreadDot3Token>(),
+    optional_codec: Some(NodeCodec::new_optional::<SpreadDot3Token>()),
+};
+const BLOCK_STMT_OR_EXPR: NodeType = NodeType {
+    name: "BlockStmtOrExpr",
+    kinds: &[
+        NodeKind::ArrayLit,
+        NodeKind::ArrowExpr,
+        NodeKind::AssignExpr,
+        NodeKind::AwaitExpr,
+        NodeKind::BigInt,
+        NodeKind::BinExpr,
+        NodeKind::BlockStmt,
+        NodeKind::Bool,
+        NodeKind::CallExpr,
+        NodeKind::ClassExpr,
+        NodeKind::CondExpr,
+        NodeKind::FnExpr,
+        NodeKind::Ident,
+        NodeKind::Invalid,
+        NodeKind::JSXElement,
+        NodeKind::JSXEmptyExpr,
+        NodeKind::JSXFragment,
+        NodeKind::JSXMemberExpr,
+        NodeKind::JSXNamespacedName,
+        NodeKind::MemberExpr,
+        NodeKind::MetaPropExpr,
+        NodeKind::NewExpr,
+        NodeKind::Null,
+        NodeKind::Number,
+        NodeKind::ObjectLit,
+        NodeKind::OptChainExpr,
+        NodeKind::ParenExpr,
+        NodeKind::PrivateName,
+        NodeKind::Regex,
+        NodeKind::SeqExpr,
+        NodeKind::Str,
+        NodeKind::SuperPropExpr,
+        NodeKind::TaggedTpl,
+        NodeKind::ThisExpr,
+        NodeKind::Tpl,
+        NodeKind::TsAsExpr,
+        NodeKind::TsConstAssertion,
+        NodeKind::TsInstantiation,
+        NodeKind::TsNonNullExpr,
+        NodeKind::TsSatisfiesExpr,
+        NodeKind::TsTypeAssertion,
+        NodeKind::UnaryExpr,
+        NodeKind::UpdateExpr,
+        NodeKind::YieldExpr,
+    ],
+    codec: NodeCodec::new::<BlockStmtOrExpr>(),
+    optional_codec: None,
+};
+const ASSIGN_TARGET: NodeType = NodeType {
+    name: "AssignTarget",
+    kinds: &[
+        NodeKind::ArrayPat,
+        NodeKind::BindingIdent,
+        NodeKind::Invalid,
+        NodeKind::MemberExpr,
+        NodeKind::ObjectPat,
+        NodeKind::OptChainExpr,
+        NodeKind::ParenExpr,
+        NodeKind::SuperPropExpr,
+        NodeKind::TsAsExpr,
+        NodeKind::TsInstantiation,
+        NodeKind::TsNonNullExpr,
+        NodeKind::TsSatisfiesExpr,
+        NodeKind::TsTypeAssertion,
+    ],
+    codec: NodeCodec::new::<AssignTarget>(),
+    optional_codec: None,
+};
+const ASSIGN_TARGET_PAT: NodeType = NodeType {
+    name: "AssignTargetPat",
+    kinds: &[NodeKind::ArrayPat, NodeKind::Invalid, NodeKind::ObjectPat],
+    codec: NodeCodec::new::<AssignTargetPat>(),
+    optional_codec: None,
+};
+const SIMPLE_ASSIGN_TARGET: NodeType = NodeType {
+    name: "SimpleAssignTarget",
+    kinds: &[
+        NodeKind::BindingIdent,
+        NodeKind::Invalid,
+        NodeKind::MemberExpr,
+        NodeKind::OptChainExpr,
+        NodeKind::ParenExpr,
+        NodeKind::SuperPropExpr,
+        NodeKind::TsAsExpr,
+        NodeKind::TsInstantiation,
+        NodeKind::TsNonNullExpr,
+        NodeKind::TsSatisfiesExpr,
+        NodeKind::TsTypeAssertion,
+    ],
+    codec: NodeCodec::new::<SimpleAssignTarget>(),
+    optional_codec: None,
+};
+const OPT_CHAIN_EXPR: NodeType = NodeType {
+    name: "OptChainExpr",
+    kinds: &[NodeKind::OptChainExpr],
+    codec: NodeCodec::new::<OptChainExpr>(),
+    optional_codec: None,
+};
+const OPT_CHAIN_BASE: NodeType = NodeType {
+    name: "OptChainBase",
+    kinds: &[NodeKind::MemberExpr, NodeKind::OptCall],
+    codec: NodeCodec::new::<OptChainBase>(),
+    optional_codec: None,
+};
+const OPT_CALL: NodeType = NodeType {
+    name: "OptCall",
+    kinds: &[NodeKind::OptCall],
+    codec: NodeCodec::new::<OptCall>(),
+    optional_codec: None,
+};
+const INVALID: NodeType = NodeType {
+    name: "Invalid",
+    kinds: &[NodeKind::Invalid],
+    codec: NodeCodec::new::<Invalid>(),
+    optional_codec: None,
+};
+const FUNCTION: NodeType = NodeType {
+    name: "Function",
+    kinds: &[NodeKind::Function],
+    codec: NodeCodec::new::<Function>(),
+    optional_codec: None,
+};
+const PARAM: NodeType = NodeType {
+    name: "Param",
+    kinds: &[NodeKind::Param],
+    codec: NodeCodec::new::<Param>(),
+    optional_codec: None,
+};
+const PARAM_OR_TS_PARAM_PROP: NodeType = NodeType {
+    name: "ParamOrTsParamProp",
+    kinds: &[NodeKind::Param, NodeKind::TsParamProp],
+    codec: NodeCodec::new::<ParamOrTsParamProp>(),
+    optional_codec: None,
+};
+const CLASS: NodeType = NodeType {
+    name: "Class",
+    kinds: &[NodeKind::Class],
+    codec: NodeCodec::new::<Class>(),
+    optional_codec: None,
+};
+const CLASS_MEMBER: NodeType = NodeType {
+    name: "ClassMember",
+    kinds: &[
+        NodeKind::AutoAccessor,
+        NodeKind::ClassMethod,
+        NodeKind::ClassProp,
+        NodeKind::Constructor,
+        NodeKind::EmptyStmt,
+        NodeKind::PrivateMethod,
+        NodeKind::PrivateProp,
+        NodeKind::StaticBlock,
+        NodeKind::TsIndexSignature,
+    ],
+    codec: NodeCodec::new::<ClassMember>(),
+    optional_codec: None,
+};
+const CLASS_PROP: NodeType = NodeType {
+    name: "ClassProp",
+    kinds: &[NodeKind::ClassProp],
+    codec: NodeCodec::new::<ClassProp>(),
+    optional_codec: None,
+};
+const PRIVATE_PROP: NodeType = NodeType {
+    name: "PrivateProp",
+    kinds: &[NodeKind::PrivateProp],
+    codec: NodeCodec::new::<PrivateProp>(),
+    optional_codec: None,
+};
+const CLASS_METHOD: NodeType = NodeType {
+    name: "ClassMethod",
+    kinds: &[NodeKind::ClassMethod],
+    codec: NodeCodec::new::<ClassMethod>(),
+    optional_codec: None,
+};
+const PRIVATE_METHOD: NodeType = NodeType {
+    name: "PrivateMethod",
+    kinds: &[NodeKind::PrivateMethod],
+    codec: NodeCodec::new::<PrivateMethod>(),
+    optional_codec: None,
+};
+const CONSTRUCTOR: NodeType = NodeType {
+    name: "Constructor",
+    kinds: &[NodeKind::Constructor],
+    codec: NodeCodec::new::<Constructor>(),
+    optional_codec: None,
+};
+const DECORATOR: NodeType = NodeType {
+    name: "Decorator",
+    kinds: &[NodeKind::Decorator],
+    codec: NodeCodec::new::<Decorator>(),
+    optional_codec: None,
+};
+const STATIC_BLOCK: NodeType = NodeType {
+    name: "StaticBlock",
+    kinds: &[NodeKind::StaticBlock],
+    codec: NodeCodec::new::<StaticBlock>(),
+    optional_codec: None,
+};
+const KEY: NodeType = NodeType {
+    name: "Key",
+    kinds: &[
+        NodeKind::BigInt,
+        NodeKind::ComputedPropName,
+        NodeKind::IdentName,
+        NodeKind::Number,
+        NodeKind::PrivateName,
+        NodeKind::Str,
+    ],
+    codec: NodeCodec::new::<Key>(),
+    optional_codec: None,
+};
+const AUTO_ACCESSOR: NodeType = NodeType {
+    name: "AutoAccessor",
+    kinds: &[NodeKind::AutoAccessor],
+    codec: NodeCodec::new::<AutoAccessor>(),
+    optional_codec: None,
+};
+const PROP: NodeType = NodeType {
+    name: "Prop",
+    kinds: &[
+        NodeKind::AssignProp,
+        NodeKind::GetterProp,
+        NodeKind::Ident,
+        NodeKind::KeyValueProp,
+        NodeKind::MethodProp,
+        NodeKind::SetterProp,
+    ],
+    codec: NodeCodec::new::<Prop>(),
+    optional_codec: None,
+};
+const KEY_VALUE_PROP: NodeType = NodeType {
+    name: "KeyValueProp",
+    kinds: &[NodeKind::KeyValueProp],
+    codec: NodeCodec::new::<KeyValueProp>(),
+    optional_codec: None,
+};
+const ASSIGN_PROP: NodeType = NodeType {
+    name: "AssignProp",
+    kinds: &[NodeKind::AssignProp],
+    codec: NodeCodec::new::<AssignProp>(),
+    optional_codec: None,
+};
+const GETTER_PROP: NodeType = NodeType {
+    name: "GetterProp",
+    kinds: &[NodeKind::GetterProp],
+    codec: NodeCodec::new::<GetterProp>(),
+    optional_codec: None,
+};
+const SETTER_PROP: NodeType = NodeType {
+    name: "SetterProp",
+    kinds: &[NodeKind::SetterProp],
+    codec: NodeCodec::new::<SetterProp>(),
+    optional_codec: None,
+};
+const METHOD_PROP: NodeType = NodeType {
+    name: "MethodProp",
+    kinds: &[NodeKind::MethodProp],
+    codec: NodeCodec::new::<MethodProp>(),
+    optional_codec: None,
+};
+const PROP_NAME: NodeType = NodeType {
+    name: "PropName",
+    kinds: &[
+        NodeKind::BigInt,
+        NodeKind::ComputedPropName,
+        NodeKind::IdentName,
+        NodeKind::Number,
+        NodeKind::Str,
+    ],
+    codec: NodeCodec::new::<PropName>(),
+    optional_codec: None,
+};
+const COMPUTED_PROP_NAME: NodeType = NodeType {
+    name: "ComputedPropName",
+    kinds: &[NodeKind::ComputedPropName],
+    codec: NodeCodec::new::<ComputedPropName>(),
+    optional_codec: None,
+};
+const PAT: NodeType = NodeType {
+    name: "Pat",
+    kinds: &[
+        NodeKind::ArrayLit,
+        NodeKind::ArrayPat,
+        NodeKind::ArrowExpr,
+        NodeKind::AssignExpr,
+        NodeKind::AssignPat,
+        NodeKind::AwaitExpr,
+        NodeKind::BigInt,
+        NodeKind::BinExpr,
+        NodeKind::BindingIdent,
+        NodeKind::Bool,
+        NodeKind::CallExpr,
+        NodeKind::ClassExpr,
+        NodeKind::CondExpr,
+        NodeKind::FnExpr,
+        NodeKind::Ident,
+        NodeKind::Invalid,
+        NodeKind::JSXElement,
+        NodeKind::JSXEmptyExpr,
+        NodeKind::JSXFragment,
+        NodeKind::JSXMemberExpr,
+        NodeKind::JSXNamespacedName,
+        NodeKind::MemberExpr,
+        NodeKind::MetaPropExpr,
+        NodeKind::NewExpr,
+        NodeKind::Null,
+        NodeKind::Number,
+        NodeKind::ObjectLit,
+        NodeKind::ObjectPat,
+        NodeKind::OptChainExpr,
+        NodeKind::ParenExpr,
+        NodeKind::PrivateName,
+        NodeKind::Regex,
+        NodeKind::RestPat,
+        NodeKind::SeqExpr,
+        NodeKind::Str,
+        NodeKind::SuperPropExpr,
+        NodeKind::TaggedTpl,
+        NodeKind::ThisExpr,
+        NodeKind::Tpl,
+        NodeKind::TsAsExpr,
+        NodeKind::TsConstAssertion,
+        NodeKind::TsInstantiation,
+        NodeKind::TsNonNullExpr,
+        NodeKind::TsSatisfiesExpr,
+        NodeKind::TsTypeAssertion,
+        NodeKind::UnaryExpr,
+        NodeKind::UpdateExpr,
+        NodeKind::YieldExpr,
+    ],
+    codec: NodeCodec::new::<Pat>(),
+    optional_codec: Some(NodeCodec::new_optional::<Pat>()),
+};
+const ARRAY_PAT: NodeType = NodeType {
+    name: "ArrayPat",
+    kinds: &[NodeKind::ArrayPat],
+    codec: NodeCodec::new::<ArrayPat>(),
+    optional_codec: None,
+};
+const OBJECT_PAT: NodeType = NodeType {
+    name: "ObjectPat",
+    kinds: &[NodeKind::ObjectPat],
+    codec: NodeCodec::new::<ObjectPat>(),
+    optional_codec: None,
+};
+const ASSIGN_PAT: NodeType = NodeType {
+    name: "AssignPat",
+    kinds: &[NodeKind::AssignPat],
+    codec: NodeCodec::new::<AssignPat>(),
+    optional_codec: None,
+};
+const REST_PAT: NodeType = NodeType {
+    name: "RestPat",
+    kinds: &[NodeKind::RestPat],
+    codec: NodeCodec::new::<RestPat>(),
+    optional_codec: None,
+};
+const OBJECT_PAT_PROP: NodeType = NodeType {
+    name: "ObjectPatProp",
+    kinds: &[
+        NodeKind::AssignPatProp,
+        NodeKind::KeyValuePatProp,
+        NodeKind::RestPat,
+    ],
+    codec: NodeCodec::new::<ObjectPatProp>(),
+    optional_codec: None,
+};
+const KEY_VALUE_PAT_PROP: NodeType = NodeType {
+    name: "KeyValuePatProp",
+    kinds: &[NodeKind::KeyValuePatProp],
+    codec: NodeCodec::new::<KeyValuePatProp>(),
+    optional_codec: None,
+};
+const ASSIGN_PAT_PROP: NodeType = NodeType {
+    name: "AssignPatProp",
+    kinds: &[NodeKind::AssignPatProp],
+    codec: NodeCodec::new::<AssignPatProp>(),
+    optional_codec: None,
+};
+const IDENT: NodeType = NodeType {
+    name: "Ident",
+    kinds: &[NodeKind::Ident],
+    codec: NodeCodec::new::<Ident>(),
+    optional_codec: Some(NodeCodec::new_optional::<Ident>()),
+};
+const IDENT_NAME: NodeType = NodeType {
+    name: "IdentName",
+    kinds: &[NodeKind::IdentName],
+    codec: NodeCodec::new::<IdentName>(),
+    optional_codec: None,
+};
+const PRIVATE_NAME: NodeType = NodeType {
+    name: "PrivateName",
+    kinds: &[NodeKind::PrivateName],
+    codec: NodeCodec::new::<PrivateName>(),
+    optional_codec: None,
+};
+const BINDING_IDENT: NodeType = NodeType {
+    name: "BindingIdent",
+    kinds: &[NodeKind::BindingIdent],
+    codec: NodeCodec::new::<BindingIdent>(),
+    optional_codec: None,
+};
+const LIT: NodeType = NodeType {
+    name: "Lit",
+    kinds: &[
+        NodeKind::BigInt,
+        NodeKind::Bool,
+        NodeKind::Null,
+        NodeKind::Number,
+        NodeKind::Regex,
+        NodeKind::Str,
+    ],
+    codec: NodeCodec::new::<Lit>(),
+    optional_codec: None,
+};
+const STR: NodeType = NodeType {
+    name: "Str",
+    kinds: &[NodeKind::Str],
+    codec: NodeCodec::new::<Str>(),
+    optional_codec: Some(NodeCodec::new_optional::<Str>()),
+};
+const BOOL: NodeType = NodeType {
+    name: "Bool",
+    kinds: &[NodeKind::Bool],
+    codec: NodeCodec::new::<Bool>(),
+    optional_codec: None,
+};
+const NULL: NodeType = NodeType {
+    name: "Null",
+    kinds: &[NodeKind::Null],
+    codec: NodeCodec::new::<Null>(),
+    optional_codec: None,
+};
+const NUMBER: NodeType = NodeType {
+    name: "Number",
+    kinds: &[NodeKind::Number],
+    codec: NodeCodec::new::<Number>(),
+    optional_codec: None,
+};
+const BIG_INT: NodeType = NodeType {
+    name: "BigInt",
+    kinds: &[NodeKind::BigInt],
+    codec: NodeCodec::new::<BigInt>(),
+    optional_codec: None,
+};
+const REGEX: NodeType = NodeType {
+    name: "Regex",
+    kinds: &[NodeKind::Regex],
+    codec: NodeCodec::new::<Regex>(),
+    optional_codec: None,
+};
+const JSX_OBJECT: NodeType = NodeType {
+    name: "JSXObject",
+    kinds: &[NodeKind::Ident, NodeKind::JSXMemberExpr],
+    codec: NodeCodec::new::<JSXObject>(),
+    optional_codec: None,
+};
+const JSX_MEMBER_EXPR: NodeType = NodeType {
+    name: "JSXMemberExpr",
+    kinds: &[NodeKind::JSXMemberExpr],
+    codec: NodeCodec::new::<JSXMemberExpr>(),
+    optional_codec: None,
+};
+const JSX_NAMESPACED_NAME: NodeType = NodeType {
+    name: "JSXNamespacedName",
+    kinds: &[NodeKind::JSXNamespacedName],
+    codec: NodeCodec::new::<JSXNamespacedName>(),
+    optional_codec: None,
+};
+const JSX_EMPTY_EXPR: NodeType = NodeType {
+    name: "JSXEmptyExpr",
+    kinds: &[NodeKind::JSXEmptyExpr],
+    codec: NodeCodec::new::<JSXEmptyExpr>(),
+    optional_codec: None,
+};
+const JSX_EXPR_CONTAINER: NodeType = NodeType {
+    name: "JSXExprContainer",
+    kinds: &[NodeKind::JSXExprContainer],
+    codec: NodeCodec::new::<JSXExprContainer>(),
+    optional_codec: None,
+};
+const JSX_EXPR: NodeType = NodeType {
+    name: "JSXExpr",
+    kinds: &[
+        NodeKind::ArrayLit,
+        NodeKind::ArrowExpr,
+        NodeKind::AssignExpr,
+        NodeKind::AwaitExpr,
+        NodeKind::BigInt,
+        NodeKind::BinExpr,
+        NodeKind::Bool,
+        NodeKind::CallExpr,
+        NodeKind::ClassExpr,
+        NodeKind::CondExpr,
+        NodeKind::FnExpr,
+        NodeKind::Ident,
+        NodeKind::Invalid,
+        NodeKind::JSXElement,
+        NodeKind::JSXEmptyExpr,
+        NodeKind::JSXFragment,
+        NodeKind::JSXMemberExpr,
+        NodeKind::JSXNamespacedName,
+        NodeKind::MemberExpr,
+        NodeKind::MetaPropExpr,
+        NodeKind::NewExpr,
+        NodeKind::Null,
+        NodeKind::Number,
+        NodeKind::ObjectLit,
+        NodeKind::OptChainExpr,
+        NodeKind::ParenExpr,
+        NodeKind::PrivateName,
+        NodeKind::Regex,
+        NodeKind::SeqExpr,
+        NodeKind::Str,
+        NodeKind::SuperPropExpr,
+        NodeKind::TaggedTpl,
+        NodeKind::ThisExpr,
+        NodeKind::Tpl,
+        NodeKind::TsAsExpr,
+        NodeKind::TsConstAssertion,
+        NodeKind::TsInstantiation,
+        NodeKind::TsNonNullExpr,
+        NodeKind::TsSatisfiesExpr,
+        NodeKind::TsTypeAssertion,
+        NodeKind::UnaryExpr,
+        NodeKind::UpdateExpr,
+        NodeKind::YieldExpr,
+    ],
+    codec: NodeCodec::new::<JSXExpr>(),
+    optional_codec: None,
+};
+const JSX_SPREAD_CHILD: NodeType = NodeType {
+    name: "JSXSpreadChild",
+    kinds: &[NodeKind::JSXSpreadChild],
+    codec: NodeCodec::new::<JSXSpreadChild>(),
+    optional_codec: None,
+};
+const JSX_ELEMENT_NAME: NodeType = NodeType {
+    name: "JSXElementName",
+    kinds: &[
+        NodeKind::Ident,
+        NodeKind::JSXMemberExpr,
+        NodeKind::JSXNamespacedName,
+    ],
+    codec: NodeCodec::new::<JSXElementName>(),
+    optional_codec: None,
+};
+const JSX_OPENING_ELEMENT: NodeType = NodeType {
+    name: "JSXOpeningElement",
+    kinds: &[NodeKind::JSXOpeningElement],
+    codec: NodeCodec::new::<JSXOpeningElement>(),
+    optional_codec: None,
+};
+const JSX_ATTR_OR_SPREAD: NodeType = NodeType {
+    name: "JSXAttrOrSpread",
+    kinds: &[NodeKind::JSXAttr, NodeKind::SpreadElement],
+    codec: NodeCodec::new::<JSXAttrOrSpread>(),
+    optional_codec: None,
+};
+const JSX_CLOSING_ELEMENT: NodeType = NodeType {
+    name: "JSXClosingElement",
+    kinds: &[NodeKind::JSXClosingElement],
+    codec: NodeCodec::new::<JSXClosingElement>(),
+    optional_codec: Some(NodeCodec::new_optional::<JSXClosingElement>()),
+};
+const JSX_ATTR: NodeType = NodeType {
+    name: "JSXAttr",
+    kinds: &[NodeKind::JSXAttr],
+    codec: NodeCodec::new::<JSXAttr>(),
+    optional_codec: None,
+};
+const JSX_ATTR_NAME: NodeType = NodeType {
+    name: "JSXAttrName",
+    kinds: &[NodeKind::IdentName, NodeKind::JSXNamespacedName],
+    codec: NodeCodec::new::<JSXAttrName>(),
+    optional_codec: None,
+};
+const JSX_ATTR_VALUE: NodeType = NodeType {
+    name: "JSXAttrValue",
+    kinds: &[
+        NodeKind::JSXElement,
+        NodeKind::JSXExprContainer,
+        NodeKind::JSXFragment,
+        NodeKind::Str,
+    ],
+    codec: NodeCodec::new::<JSXAttrValue>(),
+    optional_codec: Some(NodeCodec::new_optional::<JSXAttrValue>()),
+};
+const JSX_TEXT: NodeType = NodeType {
+    name: "JSXText",
+    kinds: &[NodeKind::JSXText],
+    codec: NodeCodec::new::<JSXText>(),
+    optional_codec: None,
+};
+const JSX_ELEMENT: NodeType = NodeType {
+    name: "JSXElement",
+    kinds: &[NodeKind::JSXElement],
+    codec: NodeCodec::new::<JSXElement>(),
+    optional_codec: None,
+};
+const JSX_ELEMENT_CHILD: NodeType = NodeType {
+    name: "JSXElementChild",
+    kinds: &[
+        NodeKind::JSXElement,
+        NodeKind::JSXExprContainer,
+        NodeKind::JSXFragment,
+        NodeKind::JSXSpreadChild,
+        NodeKind::JSXText,
+    ],
+    codec: NodeCodec::new::<JSXElementChild>(),
+    optional_codec: None,
+};
+const JSX_FRAGMENT: NodeType = NodeType {
+    name: "JSXFragment",
+    kinds: &[NodeKind::JSXFragment],
+    codec: NodeCodec::new::<JSXFragment>(),
+    optional_codec: None,
+};
+const JSX_OPENING_FRAGMENT: NodeType = NodeType {
+    name: "JSXOpeningFragment",
+    kinds: &[NodeKind::JSXOpeningFragment],
+    codec: NodeCodec::new::<JSXOpeningFragment>(),
+    optional_codec: None,
+};
+const JSX_CLOSING_FRAGMENT: NodeType = NodeType {
+    name: "JSXClosingFragment",
+    kinds: &[NodeKind::JSXClosingFragment],
+    codec: NodeCodec::new::<JSXClosingFragment>(),
+    optional_codec: None,
+};
+const TS_TYPE_ANN: NodeType = NodeType {
+    name: "TsTypeAnn",
+    kinds: &[NodeKind::TsTypeAnn],
+    codec: NodeCodec::new::<TsTypeAnn>(),
+    optional_codec: Some(NodeCodec::new_optional::<TsTypeAnn>()),
+};
+const TS_TYPE_PARAM_DECL: NodeType = NodeType {
+    name: "TsTypeParamDecl",
+    kinds: &[NodeKind::TsTypeParamDecl],
+    codec: NodeCodec::new::<TsTypeParamDecl>(),
+    optional_codec: Some(NodeCodec::new_optional::<TsTypeParamDecl>()),
+};
+const TS_TYPE_PARAM: NodeType = NodeType {
+    name: "TsTypeParam",
+    kinds: &[NodeKind::TsTypeParam],
+    codec: NodeCodec::new::<TsTypeParam>(),
+    optional_codec: None,
+};
+const TS_TYPE_PARAM_INSTANTIATION: NodeType = NodeType {
+    name: "TsTypeParamInstantiation",
+    kinds: &[NodeKind::TsTypeParamInstantiation],
+    codec: NodeCodec::new::<TsTypeParamInstantiation>(),
+    optional_codec: Some(NodeCodec::new_optional::<TsTypeParamInstantiation>()),
+};
+const TS_PARAM_PROP: NodeType = NodeType {
+    name: "TsParamProp",
+    kinds: &[NodeKind::TsParamProp],
+    codec: NodeCodec::new::<TsParamProp>(),
+    optional_codec: None,
+};
+const TS_PARAM_PROP_PARAM: NodeType = NodeType {
+    name: "TsParamPropParam",
+    kinds: &[NodeKind::AssignPat, NodeKind::BindingIdent],
+    codec: NodeCodec::new::<TsParamPropParam>(),
+    optional_codec: None,
+};
+const TS_QUALIFIED_NAME: NodeType = NodeType {
+    name: "TsQualifiedName",
+    kinds: &[NodeKind::TsQualifiedName],
+    codec: NodeCodec::new::<TsQualifiedName>(),
+    optional_codec: None,
+};
+const TS_ENTITY_NAME: NodeType = NodeType {
+    name: "TsEntityName",
+    kinds: &[NodeKind::Ident, NodeKind::TsQualifiedName],
+    codec: NodeCodec::new::<TsEntityName>(),
+    optional_codec: Some(NodeCodec::new_optional::<TsEntityName>()),
+};
+const TS_TYPE_ELEMENT: NodeType = NodeType {
+    name: "TsTypeElement",
+    kinds: &[
+        NodeKind::TsCallSignatureDecl,
+        NodeKind::TsConstructSignatureDecl,
+        NodeKind::TsGetterSignature,
+        NodeKind::TsIndexSignature,
+        NodeKind::TsMethodSignature,
+        NodeKind::TsPropertySignature,
+        NodeKind::TsSetterSignature,
+    ],
+    codec: NodeCodec::new::<TsTypeElement>(),
+    optional_codec: None,
+};
+const TS_CALL_SIGNATURE_DECL: NodeType = NodeType {
+    name: "TsCallSignatureDecl",
+    kinds: &[NodeKind::TsCallSignatureDecl],
+    codec: NodeCodec::new::<TsCallSignatureDecl>(),
+    optional_codec: None,
+};
+const TS_CONSTRUCT_SIGNATURE_DECL: NodeType = NodeType {
+    name: "TsConstructSignatureDecl",
+    kinds: &[NodeKind::TsConstructSignatureDecl],
+    codec: NodeCodec::new::<TsConstructSignatureDecl>(),
+    optional_codec: None,
+};
+const TS_PROPERTY_SIGNATURE: NodeType = NodeType {
+    name: "TsPropertySignature",
+    kinds: &[NodeKind::TsPropertySignature],
+    codec: NodeCodec::new::<TsPropertySignature>(),
+    optional_codec: None,
+};
+const TS_GETTER_SIGNATURE: NodeType = NodeType {
+    name: "TsGetterSignature",
+    kinds: &[NodeKind::TsGetterSignature],
+    codec: NodeCodec::new::<TsGetterSignature>(),
+    optional_codec: None,
+};
+const TS_SETTER_SIGNATURE: NodeType = NodeType {
+    name: "TsSetterSignature",
+    kinds: &[NodeKind::TsSetterSignature],
+    codec: NodeCodec::new::<TsSetterSignature>(),
+    optional_codec: None,
+};
+const TS_METHOD_SIGNATURE: NodeType = NodeType {
+    name: "TsMethodSignature",
+    kinds: &[NodeKind::TsMethodSignature],
+    codec: NodeCodec::new::<TsMethodSignature>(),
+    optional_codec: None,
+};
+const TS_INDEX_SIGNATURE: NodeType = NodeType {
+    name: "TsIndexSignature",
+    kinds: &[NodeKind::TsIndexSignature],
+    codec: NodeCodec::new::<TsIndexSignature>(),
+    optional_codec: None,
+};
+const TS_TYPE: NodeType = NodeType {
+    name: "TsType",
+    kinds: &[
+        NodeKind::TsArrayType,
+        NodeKind::TsConditionalType,
+        NodeKind::TsConstructorType,
+        NodeKind::TsFnType,
+        NodeKind::TsImportType,
+        NodeKind::TsIndexedAccessType,
+        NodeKind::TsInferType,
+        NodeKind::TsIntersectionType,
+        NodeKind::TsKeywordType,
+        NodeKind::TsLitType,
+        NodeKind::TsMappedType,
+        NodeKind::TsOptionalType,
+        NodeKind::TsParenthesizedType,
+        NodeKind::TsRestType,
+        NodeKind::TsThisType,
+        NodeKind::TsTupleType,
+        NodeKind::TsTypeLit,
+        NodeKind::TsTypeOperator,
+        NodeKind::TsTypePredicate,
+        NodeKind::TsTypeQuery,
+        NodeKind::TsTypeRef,
+        NodeKind::TsUnionType,
+    ],
+    codec: NodeCodec::new::<TsType>(),
+    optional_codec: Some(NodeCodec::new_optional::<TsType>()),
+};
+const TS_FN_OR_CONSTRUCTOR_TYPE: NodeType = NodeType {
+    name: "TsFnOrConstructorType",
+    kinds: &[NodeKind::TsConstructorType, NodeKind::TsFnType],
+    codec: NodeCodec::new::<TsFnOrConstructorType>(),
+    optional_codec: None,
+};
+const TS_KEYWORD_TYPE: NodeType = NodeType {
+    name: "TsKeywordType",
+    kinds: &[NodeKind::TsKeywordType],
+    codec: NodeCodec::new::<TsKeywordType>(),
+    optional_codec: None,
+};
+const TS_THIS_TYPE: NodeType = NodeType {
+    name: "TsThisType",
+    kinds: &[NodeKind::TsThisType],
+    codec: NodeCodec::new::<TsThisType>(),
+    optional_codec: None,
+};
+const TS_FN_PARAM: NodeType = NodeType {
+    name: "TsFnParam",
+    kinds: &[
+        NodeKind::ArrayPat,
+        NodeKind::BindingIdent,
+        NodeKind::ObjectPat,
+        NodeKind::RestPat,
+    ],
+    codec: NodeCodec::new::<TsFnParam>(),
+    optional_codec: None,
+};
+const TS_FN_TYPE: NodeType = NodeType {
+    name: "TsFnType",
+    kinds: &[NodeKind::TsFnType],
+    codec: NodeCodec::new::<TsFnType>(),
+    optional_codec: None,
+};
+const TS_CONSTRUCTOR_TYPE: NodeType = NodeType {
+    name: "TsConstructorType",
+    kinds: &[NodeKind::TsConstructorType],
+    codec: NodeCodec::new::<TsConstructorType>(),
+    optional_codec: None,
+};
+const TS_TYPE_REF: NodeType = NodeType {
+    name: "TsTypeRef",
+    kinds: &[NodeKind::TsTypeRef],
+    codec: NodeCodec::new::<TsTypeRef>(),
+    optional_codec: None,
+};
+const TS_TYPE_PREDICATE: NodeType = NodeType {
+    name: "TsTypePredicate",
+    kinds: &[NodeKind::TsTypePredicate],
+    codec: NodeCodec::new::<TsTypePredicate>(),
+    optional_codec: None,
+};
+const TS_THIS_TYPE_OR_IDENT: NodeType = NodeType {
+    name: "TsThisTypeOrIdent",
+    kinds: &[NodeKind::Ident, NodeKind::TsThisType],
+    codec: NodeCodec::new::<TsThisTypeOrIdent>(),
+    optional_codec: None,
+};
+const TS_TYPE_QUERY: NodeType = NodeType {
+    name: "TsTypeQuery",
+    kinds: &[NodeKind::TsTypeQuery],
+    codec: NodeCodec::new::<TsTypeQuery>(),
+    optional_codec: None,
+};
+const TS_TYPE_QUERY_EXPR: NodeType = NodeType {
+    name: "TsTypeQueryExpr",
+    kinds: &[
+        NodeKind::Ident,
+        NodeKind::TsImportType,
+        NodeKind::TsQualifiedName,
+    ],
+    codec: NodeCodec::new::<TsTypeQueryExpr>(),
+    optional_codec: None,
+};
+const TS_IMPORT_CALL_OPTIONS: NodeType = NodeType {
+    name: "TsImportCallOptions",
+    kinds: &[NodeKind::TsImportCallOptions],
+    codec: NodeCodec::new::<TsImportCallOptions>(),
+    optional_codec: Some(NodeCodec::new_optional::<TsImportCallOptions>()),
+};
+const TS_IMPORT_TYPE: NodeType = NodeType {
+    name: "TsImportType",
+    kinds: &[NodeKind::TsImportType],
+    codec: NodeCodec::new::<TsImportType>(),
+    optional_codec: None,
+};
+const TS_TYPE_LIT: NodeType = NodeType {
+    name: "TsTypeLit",
+    kinds: &[NodeKind::TsTypeLit],
+    codec: NodeCodec::new::<TsTypeLit>(),
+    optional_codec: None,
+};
+const TS_ARRAY_TYPE: NodeType = NodeType {
+    name: "TsArrayType",
+    kinds: &[NodeKind::TsArrayType],
+    codec: NodeCodec::new::<TsArrayType>(),
+    optional_codec: None,
+};
+const TS_TUPLE_TYPE: NodeType = NodeType {
+    name: "TsTupleType",
+    kinds: &[NodeKind::TsTupleType],
+    codec: NodeCodec::new::<TsTupleType>(),
+    optional_codec: None,
+};
+const TS_TUPLE_ELEMENT: NodeType = NodeType {
+    name: "TsTupleElement",
+    kinds: &[NodeKind::TsTupleElement],
+    codec: NodeCodec::new::<TsTupleElement>(),
+    optional_codec: None,
+};
+const TS_OPTIONAL_TYPE: NodeType = NodeType {
+    name: "TsOptionalType",
+    kinds: &[NodeKind::TsOptionalType],
+    codec: NodeCodec::new::<TsOptionalType>(),
+    optional_codec: None,
+};
+const TS_REST_TYPE: NodeType = NodeType {
+    name: "TsRestType",
+    kinds: &[NodeKind::TsRestType],
+    codec: NodeCodec::new::<TsRestType>(),
+    optional_codec: None,
+};
+const TS_UNION_OR_INTERSECTION_TYPE: NodeType = NodeType {
+    name: "TsUnionOrIntersectionType",
+    kinds: &[NodeKind::TsIntersectionType, NodeKind::TsUnionType],
+    codec: NodeCodec::new::<TsUnionOrIntersectionType>(),
+    optional_codec: None,
+};
+const TS_UNION_TYPE: NodeType = NodeType {
+    name: "TsUnionType",
+    kinds: &[NodeKind::TsUnionType],
+    codec: NodeCodec::new::<TsUnionType>(),
+    optional_codec: None,
+};
+const TS_INTERSECTION_TYPE: NodeType = NodeType {
+    name: "TsIntersectionType",
+    kinds: &[NodeKind::TsIntersectionType],
+    codec: NodeCodec::new::<TsIntersectionType>(),
+    optional_codec: None,
+};
+const TS_CONDITIONAL_TYPE: NodeType = NodeType {
+    name: "TsConditionalType",
+    kinds: &[NodeKind::TsConditionalType],
+    codec: NodeCodec::new::<TsConditionalType>(),
+    optional_codec: None,
+};
+const TS_INFER_TYPE: NodeType = NodeType {
+    name: "TsInferType",
+    kinds: &[NodeKind::TsInferType],
+    codec: NodeCodec::new::<TsInferType>(),
+    optional_codec: None,
+};
+const TS_PARENTHESIZED_TYPE: NodeType = NodeType {
+    name: "TsParenthesizedType",
+    kinds: &[NodeKind::TsParenthesizedType],
+    codec: NodeCodec::new::<TsParenthesizedType>(),
+    optional_codec: None,
+};
+const TS_TYPE_OPERATOR: NodeType = NodeType {
+    name: "TsTypeOperator",
+    kinds: &[NodeKind::TsTypeOperator],
+    codec: NodeCodec::new::<TsTypeOperator>(),
+    optional_codec: None,
+};
+const TS_INDEXED_ACCESS_TYPE: NodeType = NodeType {
+    name: "TsIndexedAccessType",
+    kinds: &[NodeKind::TsIndexedAccessType],
+    codec: NodeCodec::new::<TsIndexedAccessType>(),
+    optional_codec: None,
+};
+const TS_MAPPED_TYPE: NodeType = NodeType {
+    name: "TsMappedType",
+    kinds: &[NodeKind::TsMappedType],
+    codec: NodeCodec::new::<TsMappedType>(),
+    optional_codec: None,
+};
+const TS_LIT_TYPE: NodeType = NodeType {
+    name: "TsLitType",
+    kinds: &[NodeKind::TsLitType],
+    codec: NodeCodec::new::<TsLitType>(),
+    optional_codec: None,
+};
+const TS_LIT: NodeType = NodeType {
+    name: "TsLit",
+    kinds: &[
+        NodeKind::BigInt,
+        NodeKind::Bool,
+        NodeKind::Number,
+        NodeKind::Str,
+        NodeKind::TsTplLitType,
+    ],
+    codec: NodeCodec::new::<TsLit>(),
+    optional_codec: None,
+};
+const TS_TPL_LIT_TYPE: NodeType = NodeType {
+    name: "TsTplLitType",
+    kinds: &[NodeKind::TsTplLitType],
+    codec: NodeCodec::new::<TsTplLitType>(),
+    optional_codec: None,
+};
+const TS_INTERFACE_DECL: NodeType = NodeType {
+    name: "TsInterfaceDecl",
+    kinds: &[NodeKind::TsInterfaceDecl],
+    codec: NodeCodec::new::<TsInterfaceDecl>(),
+    optional_codec: None,
+};
+const TS_INTERFACE_BODY: NodeType = NodeType {
+    name: "TsInterfaceBody",
+    kinds: &[NodeKind::TsInterfaceBody],
+    codec: NodeCodec::new::<TsInterfaceBody>(),
+    optional_codec: None,
+};
+const TS_EXPR_WITH_TYPE_ARGS: NodeType = NodeType {
+    name: "TsExprWithTypeArgs",
+    kinds: &[NodeKind::TsExprWithTypeArgs],
+    codec: NodeCodec::new::<TsExprWithTypeArgs>(),
+    optional_codec: None,
+};
+const TS_TYPE_ALIAS_DECL: NodeType = NodeType {
+    name: "TsTypeAliasDecl",
+    kinds: &[NodeKind::TsTypeAliasDecl],
+    codec: NodeCodec::new::<TsTypeAliasDecl>(),
+    optional_codec: None,
+};
+const TS_ENUM_DECL: NodeType = NodeType {
+    name: "TsEnumDecl",
+    kinds: &[NodeKind::TsEnumDecl],
+    codec: NodeCodec::new::<TsEnumDecl>(),
+    optional_codec: None,
+};
+const TS_ENUM_MEMBER: NodeType = NodeType {
+    name: "TsEnumMember",
+    kinds: &[NodeKind::TsEnumMember],
+    codec: NodeCodec::new::<TsEnumMember>(),
+    optional_codec: None,
+};
+const TS_ENUM_MEMBER_ID: NodeType = NodeType {
+    name: "TsEnumMemberId",
+    kinds: &[NodeKind::Ident, NodeKind::Str],
+    codec: NodeCodec::new::<TsEnumMemberId>(),
+    optional_codec: None,
+};
+const TS_MODULE_DECL: NodeType = NodeType {
+    name: "TsModuleDecl",
+    kinds: &[NodeKind::TsModuleDecl],
+    codec: NodeCodec::new::<TsModuleDecl>(),
+    optional_codec: None,
+};
+const TS_NAMESPACE_BODY: NodeType = NodeType {
+    name: "TsNamespaceBody",
+    kinds: &[NodeKind::TsModuleBlock, NodeKind::TsNamespaceDecl],
+    codec: NodeCodec::new::<TsNamespaceBody>(),
+    optional_codec: Some(NodeCodec::new_optional::<TsNamespaceBody>()),
+};
+const TS_MODULE_BLOCK: NodeType = NodeType {
+    name: "TsModuleBlock",
+    kinds: &[NodeKind::TsModuleBlock],
+    codec: NodeCodec::new::<TsModuleBlock>(),
+    optional_codec: None,
+};
+const TS_NAMESPACE_DECL: NodeType = NodeType {
+    name: "TsNamespaceDecl",
+    kinds: &[NodeKind::TsNamespaceDecl],
+    codec: NodeCodec::new::<TsNamespaceDecl>(),
+    optional_codec: None,
+};
+const TS_MODULE_NAME: NodeType = NodeType {
+    name: "TsModuleName",
+    kinds: &[NodeKind::Ident, NodeKind::Str],
+    codec: NodeCodec::new::<TsModuleName>(),
+    optional_codec: None,
+};
+const TS_IMPORT_EQUALS_DECL: NodeType = NodeType {
+    name: "TsImportEqualsDecl",
+    kinds: &[NodeKind::TsImportEqualsDecl],
+    codec: NodeCodec::new::<TsImportEqualsDecl>(),
+    optional_codec: None,
+};
+const TS_MODULE_REF: NodeType = NodeType {
+    name: "TsModuleRef",
+    kinds: &[
+        NodeKind::Ident,
+        NodeKind::TsExternalModuleRef,
+        NodeKind::TsQualifiedName,
+    ],
+    codec: NodeCodec::new::<TsModuleRef>(),
+    optional_codec: None,
+};
+const TS_EXTERNAL_MODULE_REF: NodeType = NodeType {
+    name: "TsExternalModuleRef",
+    kinds: &[NodeKind::TsExternalModuleRef],
+    codec: NodeCodec::new::<TsExternalModuleRef>(),
+    optional_codec: None,
+};
+const TS_EXPORT_ASSIGNMENT: NodeType = NodeType {
+    name: "TsExportAssignment",
+    kinds: &[NodeKind::TsExportAssignment],
+    codec: NodeCodec::new::<TsExportAssignment>(),
+    optional_codec: None,
+};
+const TS_NAMESPACE_EXPORT_DECL: NodeType = NodeType {
+    name: "TsNamespaceExportDecl",
+    kinds: &[NodeKind::TsNamespaceExportDecl],
+    codec: NodeCodec::new::<TsNamespaceExportDecl>(),
+    optional_codec: None,
+};
+const TS_AS_EXPR: NodeType = NodeType {
+    name: "TsAsExpr",
+    kinds: &[NodeKind::TsAsExpr],
+    codec: NodeCodec::new::<TsAsExpr>(),
+    optional_codec: None,
+};
+const TS_TYPE_ASSERTION: NodeType = NodeType {
+    name: "TsTypeAssertion",
+    kinds: &[NodeKind::TsTypeAssertion],
+    codec: NodeCodec::new::<TsTypeAssertion>(),
+    optional_codec: None,
+};
+const TS_NON_NULL_EXPR: NodeType = NodeType {
+    name: "TsNonNullExpr",
+    kinds: &[NodeKind::TsNonNullExpr],
+    codec: NodeCodec::new::<TsNonNullExpr>(),
+    optional_codec: None,
+};
+const TS_SATISFIES_EXPR: NodeType = NodeType {
+    name: "TsSatisfiesExpr",
+    kinds: &[NodeKind::TsSatisfiesExpr],
+    codec: NodeCodec::new::<TsSatisfiesExpr>(),
+    optional_codec: None,
+};
+const TS_CONST_ASSERTION: NodeType = NodeType {
+    name: "TsConstAssertion",
+    kinds: &[NodeKind::TsConstAssertion],
+    codec: NodeCodec::new::<TsConstAssertion>(),
+    optional_codec: None,
+};
+const TS_INSTANTIATION: NodeType = NodeType {
+    name: "TsInstantiation",
+    kinds: &[NodeKind::TsInstantiation],
+    codec: NodeCodec::new::<TsInstantiation>(),
+    optional_codec: None,
+};
+const ACCESSIBILITY: EnumType = EnumType {
+    name: "Accessibility",
+    variants: &["Public", "Protected", "Private"],
+};
+const ASSIGN_OP: EnumType = EnumType {
+    name: "AssignOp",
+    variants: &[
+        "Assign",
+        "AddAssign",
+        "SubAssign",
+        "MulAssign",
+        "DivAssign",
+        "ModAssign",
+        "LShiftAssign",
+        "RShiftAssign",
+        "ZeroFillRShiftAssign",
+        "BitOrAssign",
+        "BitXorAssign",
+        "BitAndAssign",
+        "ExpAssign",
+        "AndAssign",
+        "OrAssign",
+        "NullishAssign",
+    ],
+};
+const BINARY_OP: EnumType = EnumType {
+    name: "BinaryOp",
+    variants: &[
+        "EqEq",
+        "NotEq",
+        "EqEqEq",
+        "NotEqEq",
+        "Lt",
+        "LtEq",
+        "Gt",
+        "GtEq",
+        "LShift",
+        "RShift",
+        "ZeroFillRShift",
+        "Add",
+        "Sub",
+        "Mul",
+        "Div",
+        "Mod",
+        "BitOr",
+        "BitXor",
+        "BitAnd",
+        "LogicalOr",
+        "LogicalAnd",
+        "In",
+        "InstanceOf",
+        "Exp",
+        "NullishCoalescing",
+    ],
+};
+const IMPORT_PHASE: EnumType = EnumType {
+    name: "ImportPhase",
+    variants: &["Evaluation", "Source", "Defer"],
+};
+const META_PROP_KIND: EnumType = EnumType {
+    name: "MetaPropKind",
+    variants: &["NewTarget", "ImportMeta"],
+};
+const METHOD_KIND: EnumType = EnumType {
+    name: "MethodKind",
+    variants: &["Method", "Getter", "Setter"],
+};
+const TRUE_PLUS_MINUS: EnumType = EnumType {
+    name: "TruePlusMinus",
+    variants: &["True", "Plus", "Minus"],
+};
+const TS_KEYWORD_TYPE_KIND: EnumType = EnumType {
+    name: "TsKeywordTypeKind",
+    variants: &[
+        "TsAnyKeyword",
+        "TsUnknownKeyword",
+        "TsNumberKeyword",
+        "TsObjectKeyword",
+        "TsBooleanKeyword",
+        "TsBigIntKeyword",
+        "TsStringKeyword",
+        "TsSymbolKeyword",
+        "TsVoidKeyword",
+        "TsUndefinedKeyword",
+        "TsNullKeyword",
+        "TsNeverKeyword",
+        "TsIntrinsicKeyword",
+    ],
+};
+const TS_TYPE_OPERATOR_OP: EnumType = EnumType {
+    name: "TsTypeOperatorOp",
+    variants: &["KeyOf", "Unique", "ReadOnly"],
+};
+const UNARY_OP: EnumType = EnumType {
+    name: "UnaryOp",
+    variants: &["Minus", "Plus", "Bang", "Tilde", "TypeOf", "Void", "Delete"],
+};
+const UPDATE_OP: EnumType = EnumType {
+    name: "UpdateOp",
+    variants: &["PlusPlus", "MinusMinus"],
+};
+const VAR_DECL_KIND: EnumType = EnumType {
+    name: "VarDeclKind",
+    variants: &["Var", "Let", "Const"],
+};
+pub(crate) static NODE_SCHEMAS: &[NodeSchema] = &[
+    NodeSchema {
+        kind: NodeKind::Module,
+        name: "Module",
+        fields: &[
+            FieldSchema {
+                name: "body",
+                ty: FieldType::List(&FieldType::Node(&MODULE_ITEM)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "shebang",
+                ty: FieldType::OptionalUtf8,
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::Script,
+        name: "Script",
+        fields: &[
+            FieldSchema {
+                name: "body",
+                ty: FieldType::List(&FieldType::Node(&STMT)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "shebang",
+                ty: FieldType::OptionalUtf8,
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ImportDecl,
+        name: "ImportDecl",
+        fields: &[
+            FieldSchema {
+                name: "specifiers",
+                ty: FieldType::List(&FieldType::Node(&IMPORT_SPECIFIER)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "src",
+                ty: FieldType::Node(&STR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_only",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "with",
+                ty: FieldType::OptionalNode(&OBJECT_LIT),
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "phase",
+                ty: FieldType::Enum(&IMPORT_PHASE),
+                location: FieldLocation::ExtraData(4u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ImportNamedSpecifier,
+        name: "ImportNamedSpecifier",
+        fields: &[
+            FieldSchema {
+                name: "local",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "imported",
+                ty: FieldType::OptionalNode(&MODULE_EXPORT_NAME),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "is_type_only",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ImportDefaultSpecifier,
+        name: "ImportDefaultSpecifier",
+        fields: &[FieldSchema {
+            name: "local",
+            ty: FieldType::Node(&IDENT),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 4u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ImportStarAsSpecifier,
+        name: "ImportStarAsSpecifier",
+        fields: &[FieldSchema {
+            name: "local",
+            ty: FieldType::Node(&IDENT),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 4u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ExportDecl,
+        name: "ExportDecl",
+        fields: &[FieldSchema {
+            name: "decl",
+            ty: FieldType::Node(&DECL),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::NamedExport,
+        name: "NamedExport",
+        fields: &[
+            FieldSchema {
+                name: "specifiers",
+                ty: FieldType::List(&FieldType::Node(&EXPORT_SPECIFIER)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "src",
+                ty: FieldType::OptionalNode(&STR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_only",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "with",
+                ty: FieldType::OptionalNode(&OBJECT_LIT),
+                location: FieldLocation::ExtraData(3u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ExportNamespaceSpecifier,
+        name: "ExportNamespaceSpecifier",
+        fields: &[FieldSchema {
+            name: "name",
+            ty: FieldType::Node(&MODULE_EXPORT_NAME),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ExportDefaultSpecifier,
+        name: "ExportDefaultSpecifier",
+        fields: &[FieldSchema {
+            name: "exported",
+            ty: FieldType::Node(&IDENT),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 4u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ExportNamedSpecifier,
+        name: "ExportNamedSpecifier",
+        fields: &[
+            FieldSchema {
+                name: "orig",
+                ty: FieldType::Node(&MODULE_EXPORT_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "exported",
+                ty: FieldType::OptionalNode(&MODULE_EXPORT_NAME),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "is_type_only",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ExportDefaultDecl,
+        name: "ExportDefaultDecl",
+        fields: &[FieldSchema {
+            name: "decl",
+            ty: FieldType::Node(&DEFAULT_DECL),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ExportDefaultExpr,
+        name: "ExportDefaultExpr",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ExportAll,
+        name: "ExportAll",
+        fields: &[
+            FieldSchema {
+                name: "src",
+                ty: FieldType::Node(&STR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_only",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "with",
+                ty: FieldType::OptionalNode(&OBJECT_LIT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::BlockStmt,
+        name: "BlockStmt",
+        fields: &[FieldSchema {
+            name: "stmts",
+            ty: FieldType::List(&FieldType::Node(&STMT)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ExprStmt,
+        name: "ExprStmt",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::EmptyStmt,
+        name: "EmptyStmt",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::DebuggerStmt,
+        name: "DebuggerStmt",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::WithStmt,
+        name: "WithStmt",
+        fields: &[
+            FieldSchema {
+                name: "obj",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&STMT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ReturnStmt,
+        name: "ReturnStmt",
+        fields: &[FieldSchema {
+            name: "arg",
+            ty: FieldType::OptionalNode(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::LabeledStmt,
+        name: "LabeledStmt",
+        fields: &[
+            FieldSchema {
+                name: "label",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&STMT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::BreakStmt,
+        name: "BreakStmt",
+        fields: &[FieldSchema {
+            name: "label",
+            ty: FieldType::OptionalNode(&IDENT),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 4u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ContinueStmt,
+        name: "ContinueStmt",
+        fields: &[FieldSchema {
+            name: "label",
+            ty: FieldType::OptionalNode(&IDENT),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 4u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::IfStmt,
+        name: "IfStmt",
+        fields: &[
+            FieldSchema {
+                name: "test",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "cons",
+                ty: FieldType::Node(&STMT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "alt",
+                ty: FieldType::OptionalNode(&STMT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::SwitchStmt,
+        name: "SwitchStmt",
+        fields: &[
+            FieldSchema {
+                name: "discriminant",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "cases",
+                ty: FieldType::List(&FieldType::Node(&SWITCH_CASE)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ThrowStmt,
+        name: "ThrowStmt",
+        fields: &[FieldSchema {
+            name: "arg",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TryStmt,
+        name: "TryStmt",
+        fields: &[
+            FieldSchema {
+                name: "block",
+                ty: FieldType::Node(&BLOCK_STMT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "handler",
+                ty: FieldType::OptionalNode(&CATCH_CLAUSE),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "finalizer",
+                ty: FieldType::OptionalNode(&BLOCK_STMT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::WhileStmt,
+        name: "WhileStmt",
+        fields: &[
+            FieldSchema {
+                name: "test",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&STMT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::DoWhileStmt,
+        name: "DoWhileStmt",
+        fields: &[
+            FieldSchema {
+                name: "test",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&STMT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ForStmt,
+        name: "ForStmt",
+        fields: &[
+            FieldSchema {
+                name: "init",
+                ty: FieldType::OptionalNode(&VAR_DECL_OR_EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "test",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "update",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&STMT),
+                location: FieldLocation::ExtraData(3u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ForInStmt,
+        name: "ForInStmt",
+        fields: &[
+            FieldSchema {
+                name: "left",
+                ty: FieldType::Node(&FOR_HEAD),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "right",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&STMT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ForOfStmt,
+        name: "ForOfStmt",
+        fields: &[
+            FieldSchema {
+                name: "is_await",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "left",
+                ty: FieldType::Node(&FOR_HEAD),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "right",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&STMT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::SwitchCase,
+        name: "SwitchCase",
+        fields: &[
+            FieldSchema {
+                name: "test",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "cons",
+                ty: FieldType::List(&FieldType::Node(&STMT)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::CatchClause,
+        name: "CatchClause",
+        fields: &[
+            FieldSchema {
+                name: "param",
+                ty: FieldType::OptionalNode(&PAT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&BLOCK_STMT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::FnDecl,
+        name: "FnDecl",
+        fields: &[
+            FieldSchema {
+                name: "ident",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "declare",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "function",
+                ty: FieldType::Node(&FUNCTION),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ClassDecl,
+        name: "ClassDecl",
+        fields: &[
+            FieldSchema {
+                name: "ident",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "declare",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "class",
+                ty: FieldType::Node(&CLASS),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::VarDecl,
+        name: "VarDecl",
+        fields: &[
+            FieldSchema {
+                name: "kind",
+                ty: FieldType::Enum(&VAR_DECL_KIND),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "declare",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "decls",
+                ty: FieldType::List(&FieldType::Node(&VAR_DECLARATOR)),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::VarDeclarator,
+        name: "VarDeclarator",
+        fields: &[
+            FieldSchema {
+                name: "name",
+                ty: FieldType::Node(&PAT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "init",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "definite",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::UsingDecl,
+        name: "UsingDecl",
+        fields: &[
+            FieldSchema {
+                name: "is_await",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "decls",
+                ty: FieldType::List(&FieldType::Node(&VAR_DECLARATOR)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ThisExpr,
+        name: "ThisExpr",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::ArrayLit,
+        name: "ArrayLit",
+        fields: &[FieldSchema {
+            name: "elems",
+            ty: FieldType::List(&FieldType::OptionalNode(&EXPR_OR_SPREAD)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ObjectLit,
+        name: "ObjectLit",
+        fields: &[FieldSchema {
+            name: "props",
+            ty: FieldType::List(&FieldType::Node(&PROP_OR_SPREAD)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::SpreadElement,
+        name: "SpreadElement",
+        fields: &[
+            FieldSchema {
+                name: "dot3_token",
+                ty: FieldType::Span,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "expr",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::UnaryExpr,
+        name: "UnaryExpr",
+        fields: &[
+            FieldSchema {
+                name: "op",
+                ty: FieldType::Enum(&UNARY_OP),
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "arg",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::UpdateExpr,
+        name: "UpdateExpr",
+        fields: &[
+            FieldSchema {
+                name: "op",
+                ty: FieldType::Enum(&UPDATE_OP),
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "prefix",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 5u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "arg",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::BinExpr,
+        name: "BinExpr",
+        fields: &[
+            FieldSchema {
+                name: "op",
+                ty: FieldType::Enum(&BINARY_OP),
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "left",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "right",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::FnExpr,
+        name: "FnExpr",
+        fields: &[
+            FieldSchema {
+                name: "ident",
+                ty: FieldType::OptionalNode(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "function",
+                ty: FieldType::Node(&FUNCTION),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ClassExpr,
+        name: "ClassExpr",
+        fields: &[
+            FieldSchema {
+                name: "ident",
+                ty: FieldType::OptionalNode(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "class",
+                ty: FieldType::Node(&CLASS),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::AssignExpr,
+        name: "AssignExpr",
+        fields: &[
+            FieldSchema {
+                name: "op",
+                ty: FieldType::Enum(&ASSIGN_OP),
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "left",
+                ty: FieldType::Node(&ASSIGN_TARGET),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "right",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::MemberExpr,
+        name: "MemberExpr",
+        fields: &[
+            FieldSchema {
+                name: "obj",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "prop",
+                ty: FieldType::Node(&MEMBER_PROP),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::SuperPropExpr,
+        name: "SuperPropExpr",
+        fields: &[
+            FieldSchema {
+                name: "obj",
+                ty: FieldType::Node(&SUPER),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "prop",
+                ty: FieldType::Node(&SUPER_PROP),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::CondExpr,
+        name: "CondExpr",
+        fields: &[
+            FieldSchema {
+                name: "test",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "cons",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "alt",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::CallExpr,
+        name: "CallExpr",
+        fields: &[
+            FieldSchema {
+                name: "callee",
+                ty: FieldType::Node(&CALLEE),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "args",
+                ty: FieldType::List(&FieldType::Node(&EXPR_OR_SPREAD)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_args",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::NewExpr,
+        name: "NewExpr",
+        fields: &[
+            FieldSchema {
+                name: "callee",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "args",
+                ty: FieldType::OptionalList(&FieldType::Node(&EXPR_OR_SPREAD)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_args",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::SeqExpr,
+        name: "SeqExpr",
+        fields: &[FieldSchema {
+            name: "exprs",
+            ty: FieldType::List(&FieldType::Node(&EXPR)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ArrowExpr,
+        name: "ArrowExpr",
+        fields: &[
+            FieldSchema {
+                name: "params",
+                ty: FieldType::List(&FieldType::Node(&PAT)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&BLOCK_STMT_OR_EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "is_async",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "is_generator",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_DECL),
+                location: FieldLocation::ExtraData(4u32),
+            },
+            FieldSchema {
+                name: "return_type",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(5u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::YieldExpr,
+        name: "YieldExpr",
+        fields: &[
+            FieldSchema {
+                name: "arg",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "delegate",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::MetaPropExpr,
+        name: "MetaPropExpr",
+        fields: &[FieldSchema {
+            name: "kind",
+            ty: FieldType::Enum(&META_PROP_KIND),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 1u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::AwaitExpr,
+        name: "AwaitExpr",
+        fields: &[FieldSchema {
+            name: "arg",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::Tpl,
+        name: "Tpl",
+        fields: &[
+            FieldSchema {
+                name: "exprs",
+                ty: FieldType::List(&FieldType::Node(&EXPR)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "quasis",
+                ty: FieldType::List(&FieldType::Node(&TPL_ELEMENT)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TaggedTpl,
+        name: "TaggedTpl",
+        fields: &[
+            FieldSchema {
+                name: "tag",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "tpl",
+                ty: FieldType::Node(&TPL),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TplElement,
+        name: "TplElement",
+        fields: &[
+            FieldSchema {
+                name: "tail",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "cooked",
+                ty: FieldType::OptionalWtf8,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "raw",
+                ty: FieldType::Utf8,
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ParenExpr,
+        name: "ParenExpr",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::Super,
+        name: "Super",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::Import,
+        name: "Import",
+        fields: &[FieldSchema {
+            name: "phase",
+            ty: FieldType::Enum(&IMPORT_PHASE),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 1u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ExprOrSpread,
+        name: "ExprOrSpread",
+        fields: &[
+            FieldSchema {
+                name: "spread",
+                ty: FieldType::OptionalNode(&SPREAD_DOT_3_TOKEN),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "expr",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::SpreadDot3Token,
+        name: "SpreadDot3Token",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::OptChainExpr,
+        name: "OptChainExpr",
+        fields: &[
+            FieldSchema {
+                name: "optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "base",
+                ty: FieldType::Node(&OPT_CHAIN_BASE),
+                location: FieldLocation::ExtraData(0u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::OptCall,
+        name: "OptCall",
+        fields: &[
+            FieldSchema {
+                name: "callee",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "args",
+                ty: FieldType::List(&FieldType::Node(&EXPR_OR_SPREAD)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_args",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::Invalid,
+        name: "Invalid",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::Function,
+        name: "Function",
+        fields: &[
+            FieldSchema {
+                name: "params",
+                ty: FieldType::List(&FieldType::Node(&PARAM)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "decorators",
+                ty: FieldType::List(&FieldType::Node(&DECORATOR)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::OptionalNode(&BLOCK_STMT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "is_generator",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "is_async",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(4u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_DECL),
+                location: FieldLocation::ExtraData(5u32),
+            },
+            FieldSchema {
+                name: "return_type",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(6u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::Param,
+        name: "Param",
+        fields: &[
+            FieldSchema {
+                name: "decorators",
+                ty: FieldType::List(&FieldType::Node(&DECORATOR)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "pat",
+                ty: FieldType::Node(&PAT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::Class,
+        name: "Class",
+        fields: &[
+            FieldSchema {
+                name: "decorators",
+                ty: FieldType::List(&FieldType::Node(&DECORATOR)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::List(&FieldType::Node(&CLASS_MEMBER)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "super_class",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "is_abstract",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_DECL),
+                location: FieldLocation::ExtraData(4u32),
+            },
+            FieldSchema {
+                name: "super_type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(5u32),
+            },
+            FieldSchema {
+                name: "implements",
+                ty: FieldType::List(&FieldType::Node(&TS_EXPR_WITH_TYPE_ARGS)),
+                location: FieldLocation::ExtraData(6u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ClassProp,
+        name: "ClassProp",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&PROP_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "value",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "is_static",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "decorators",
+                ty: FieldType::List(&FieldType::Node(&DECORATOR)),
+                location: FieldLocation::ExtraData(4u32),
+            },
+            FieldSchema {
+                name: "accessibility",
+                ty: FieldType::OptionalEnum(&ACCESSIBILITY),
+                location: FieldLocation::ExtraData(5u32),
+            },
+            FieldSchema {
+                name: "is_abstract",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(6u32),
+            },
+            FieldSchema {
+                name: "is_optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(7u32),
+            },
+            FieldSchema {
+                name: "is_override",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(8u32),
+            },
+            FieldSchema {
+                name: "readonly",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(9u32),
+            },
+            FieldSchema {
+                name: "declare",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(10u32),
+            },
+            FieldSchema {
+                name: "definite",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(11u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::PrivateProp,
+        name: "PrivateProp",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&PRIVATE_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "value",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "is_static",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "decorators",
+                ty: FieldType::List(&FieldType::Node(&DECORATOR)),
+                location: FieldLocation::ExtraData(4u32),
+            },
+            FieldSchema {
+                name: "accessibility",
+                ty: FieldType::OptionalEnum(&ACCESSIBILITY),
+                location: FieldLocation::ExtraData(5u32),
+            },
+            FieldSchema {
+                name: "is_optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(6u32),
+            },
+            FieldSchema {
+                name: "is_override",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(7u32),
+            },
+            FieldSchema {
+                name: "readonly",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(8u32),
+            },
+            FieldSchema {
+                name: "definite",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(9u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ClassMethod,
+        name: "ClassMethod",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&PROP_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "function",
+                ty: FieldType::Node(&FUNCTION),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "kind",
+                ty: FieldType::Enum(&METHOD_KIND),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "is_static",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "accessibility",
+                ty: FieldType::OptionalEnum(&ACCESSIBILITY),
+                location: FieldLocation::ExtraData(4u32),
+            },
+            FieldSchema {
+                name: "is_abstract",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(5u32),
+            },
+            FieldSchema {
+                name: "is_optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(6u32),
+            },
+            FieldSchema {
+                name: "is_override",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(7u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::PrivateMethod,
+        name: "PrivateMethod",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&PRIVATE_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "function",
+                ty: FieldType::Node(&FUNCTION),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "kind",
+                ty: FieldType::Enum(&METHOD_KIND),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "is_static",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "accessibility",
+                ty: FieldType::OptionalEnum(&ACCESSIBILITY),
+                location: FieldLocation::ExtraData(4u32),
+            },
+            FieldSchema {
+                name: "is_abstract",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(5u32),
+            },
+            FieldSchema {
+                name: "is_optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(6u32),
+            },
+            FieldSchema {
+                name: "is_override",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(7u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::Constructor,
+        name: "Constructor",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&PROP_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "params",
+                ty: FieldType::List(&FieldType::Node(&PARAM_OR_TS_PARAM_PROP)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::OptionalNode(&BLOCK_STMT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "accessibility",
+                ty: FieldType::OptionalEnum(&ACCESSIBILITY),
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "is_optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(4u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::Decorator,
+        name: "Decorator",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::StaticBlock,
+        name: "StaticBlock",
+        fields: &[FieldSchema {
+            name: "body",
+            ty: FieldType::Node(&BLOCK_STMT),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 4u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::AutoAccessor,
+        name: "AutoAccessor",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&KEY),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "value",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "is_static",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "decorators",
+                ty: FieldType::List(&FieldType::Node(&DECORATOR)),
+                location: FieldLocation::ExtraData(4u32),
+            },
+            FieldSchema {
+                name: "accessibility",
+                ty: FieldType::OptionalEnum(&ACCESSIBILITY),
+                location: FieldLocation::ExtraData(5u32),
+            },
+            FieldSchema {
+                name: "is_abstract",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(6u32),
+            },
+            FieldSchema {
+                name: "is_override",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(7u32),
+            },
+            FieldSchema {
+                name: "definite",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(8u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::KeyValueProp,
+        name: "KeyValueProp",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&PROP_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "value",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::AssignProp,
+        name: "AssignProp",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "value",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::GetterProp,
+        name: "GetterProp",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&PROP_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::OptionalNode(&BLOCK_STMT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::SetterProp,
+        name: "SetterProp",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&PROP_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "this_param",
+                ty: FieldType::OptionalNode(&PAT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "param",
+                ty: FieldType::Node(&PAT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::OptionalNode(&BLOCK_STMT),
+                location: FieldLocation::ExtraData(3u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::MethodProp,
+        name: "MethodProp",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&PROP_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "function",
+                ty: FieldType::Node(&FUNCTION),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ComputedPropName,
+        name: "ComputedPropName",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::ArrayPat,
+        name: "ArrayPat",
+        fields: &[
+            FieldSchema {
+                name: "elems",
+                ty: FieldType::List(&FieldType::OptionalNode(&PAT)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::ObjectPat,
+        name: "ObjectPat",
+        fields: &[
+            FieldSchema {
+                name: "props",
+                ty: FieldType::List(&FieldType::Node(&OBJECT_PAT_PROP)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::AssignPat,
+        name: "AssignPat",
+        fields: &[
+            FieldSchema {
+                name: "left",
+                ty: FieldType::Node(&PAT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "right",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::RestPat,
+        name: "RestPat",
+        fields: &[
+            FieldSchema {
+                name: "dot3_token",
+                ty: FieldType::Span,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "arg",
+                ty: FieldType::Node(&PAT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::KeyValuePatProp,
+        name: "KeyValuePatProp",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&PROP_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "value",
+                ty: FieldType::Node(&PAT),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::AssignPatProp,
+        name: "AssignPatProp",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&BINDING_IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "value",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::Ident,
+        name: "Ident",
+        fields: &[
+            FieldSchema {
+                name: "sym",
+                ty: FieldType::Utf8,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::IdentName,
+        name: "IdentName",
+        fields: &[FieldSchema {
+            name: "sym",
+            ty: FieldType::Utf8,
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::PrivateName,
+        name: "PrivateName",
+        fields: &[FieldSchema {
+            name: "name",
+            ty: FieldType::Utf8,
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::BindingIdent,
+        name: "BindingIdent",
+        fields: &[
+            FieldSchema {
+                name: "id",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::Str,
+        name: "Str",
+        fields: &[
+            FieldSchema {
+                name: "value",
+                ty: FieldType::Wtf8,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "raw",
+                ty: FieldType::OptionalUtf8,
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::Bool,
+        name: "Bool",
+        fields: &[FieldSchema {
+            name: "value",
+            ty: FieldType::Bool,
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 1u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::Null,
+        name: "Null",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::Number,
+        name: "Number",
+        fields: &[
+            FieldSchema {
+                name: "value",
+                ty: FieldType::Number,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "raw",
+                ty: FieldType::OptionalUtf8,
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::BigInt,
+        name: "BigInt",
+        fields: &[
+            FieldSchema {
+                name: "value",
+                ty: FieldType::BigInt,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "raw",
+                ty: FieldType::OptionalUtf8,
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::Regex,
+        name: "Regex",
+        fields: &[
+            FieldSchema {
+                name: "exp",
+                ty: FieldType::Utf8,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "flags",
+                ty: FieldType::Utf8,
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXMemberExpr,
+        name: "JSXMemberExpr",
+        fields: &[
+            FieldSchema {
+                name: "obj",
+                ty: FieldType::Node(&JSX_OBJECT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "prop",
+                ty: FieldType::Node(&IDENT_NAME),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXNamespacedName,
+        name: "JSXNamespacedName",
+        fields: &[
+            FieldSchema {
+                name: "ns",
+                ty: FieldType::Node(&IDENT_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "name",
+                ty: FieldType::Node(&IDENT_NAME),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXEmptyExpr,
+        name: "JSXEmptyExpr",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXExprContainer,
+        name: "JSXExprContainer",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&JSX_EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXSpreadChild,
+        name: "JSXSpreadChild",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXOpeningElement,
+        name: "JSXOpeningElement",
+        fields: &[
+            FieldSchema {
+                name: "name",
+                ty: FieldType::Node(&JSX_ELEMENT_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "attrs",
+                ty: FieldType::List(&FieldType::Node(&JSX_ATTR_OR_SPREAD)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "self_closing",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "type_args",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(3u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXClosingElement,
+        name: "JSXClosingElement",
+        fields: &[FieldSchema {
+            name: "name",
+            ty: FieldType::Node(&JSX_ELEMENT_NAME),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXAttr,
+        name: "JSXAttr",
+        fields: &[
+            FieldSchema {
+                name: "name",
+                ty: FieldType::Node(&JSX_ATTR_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "value",
+                ty: FieldType::OptionalNode(&JSX_ATTR_VALUE),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXText,
+        name: "JSXText",
+        fields: &[
+            FieldSchema {
+                name: "value",
+                ty: FieldType::Utf8,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "raw",
+                ty: FieldType::Utf8,
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXElement,
+        name: "JSXElement",
+        fields: &[
+            FieldSchema {
+                name: "opening",
+                ty: FieldType::Node(&JSX_OPENING_ELEMENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "children",
+                ty: FieldType::List(&FieldType::Node(&JSX_ELEMENT_CHILD)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "closing",
+                ty: FieldType::OptionalNode(&JSX_CLOSING_ELEMENT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXFragment,
+        name: "JSXFragment",
+        fields: &[
+            FieldSchema {
+                name: "opening",
+                ty: FieldType::Node(&JSX_OPENING_FRAGMENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "children",
+                ty: FieldType::List(&FieldType::Node(&JSX_ELEMENT_CHILD)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "closing",
+                ty: FieldType::Node(&JSX_CLOSING_FRAGMENT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXOpeningFragment,
+        name: "JSXOpeningFragment",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::JSXClosingFragment,
+        name: "JSXClosingFragment",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypeAnn,
+        name: "TsTypeAnn",
+        fields: &[FieldSchema {
+            name: "type_ann",
+            ty: FieldType::Node(&TS_TYPE),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypeParamDecl,
+        name: "TsTypeParamDecl",
+        fields: &[FieldSchema {
+            name: "params",
+            ty: FieldType::List(&FieldType::Node(&TS_TYPE_PARAM)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypeParam,
+        name: "TsTypeParam",
+        fields: &[
+            FieldSchema {
+                name: "name",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "is_in",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "is_out",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 5u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "is_const",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 6u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "constraint",
+                ty: FieldType::OptionalNode(&TS_TYPE),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "default",
+                ty: FieldType::OptionalNode(&TS_TYPE),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypeParamInstantiation,
+        name: "TsTypeParamInstantiation",
+        fields: &[FieldSchema {
+            name: "params",
+            ty: FieldType::List(&FieldType::Node(&TS_TYPE)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsParamProp,
+        name: "TsParamProp",
+        fields: &[
+            FieldSchema {
+                name: "decorators",
+                ty: FieldType::List(&FieldType::Node(&DECORATOR)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "accessibility",
+                ty: FieldType::OptionalEnum(&ACCESSIBILITY),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "is_override",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "readonly",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "param",
+                ty: FieldType::Node(&TS_PARAM_PROP_PARAM),
+                location: FieldLocation::ExtraData(4u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsQualifiedName,
+        name: "TsQualifiedName",
+        fields: &[
+            FieldSchema {
+                name: "left",
+                ty: FieldType::Node(&TS_ENTITY_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "right",
+                ty: FieldType::Node(&IDENT_NAME),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsCallSignatureDecl,
+        name: "TsCallSignatureDecl",
+        fields: &[
+            FieldSchema {
+                name: "params",
+                ty: FieldType::List(&FieldType::Node(&TS_FN_PARAM)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_DECL),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsConstructSignatureDecl,
+        name: "TsConstructSignatureDecl",
+        fields: &[
+            FieldSchema {
+                name: "params",
+                ty: FieldType::List(&FieldType::Node(&TS_FN_PARAM)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_DECL),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsPropertySignature,
+        name: "TsPropertySignature",
+        fields: &[
+            FieldSchema {
+                name: "readonly",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "computed",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 5u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 6u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsGetterSignature,
+        name: "TsGetterSignature",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "computed",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsSetterSignature,
+        name: "TsSetterSignature",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "computed",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "param",
+                ty: FieldType::Node(&TS_FN_PARAM),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsMethodSignature,
+        name: "TsMethodSignature",
+        fields: &[
+            FieldSchema {
+                name: "key",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "computed",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "optional",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "params",
+                ty: FieldType::List(&FieldType::Node(&TS_FN_PARAM)),
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(4u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_DECL),
+                location: FieldLocation::ExtraData(5u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsIndexSignature,
+        name: "TsIndexSignature",
+        fields: &[
+            FieldSchema {
+                name: "params",
+                ty: FieldType::List(&FieldType::Node(&TS_FN_PARAM)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "readonly",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "is_static",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsKeywordType,
+        name: "TsKeywordType",
+        fields: &[FieldSchema {
+            name: "kind",
+            ty: FieldType::Enum(&TS_KEYWORD_TYPE_KIND),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 1u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsThisType,
+        name: "TsThisType",
+        fields: &[],
+    },
+    NodeSchema {
+        kind: NodeKind::TsFnType,
+        name: "TsFnType",
+        fields: &[
+            FieldSchema {
+                name: "params",
+                ty: FieldType::List(&FieldType::Node(&TS_FN_PARAM)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_DECL),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::Node(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsConstructorType,
+        name: "TsConstructorType",
+        fields: &[
+            FieldSchema {
+                name: "params",
+                ty: FieldType::List(&FieldType::Node(&TS_FN_PARAM)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_DECL),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::Node(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "is_abstract",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(3u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypeRef,
+        name: "TsTypeRef",
+        fields: &[
+            FieldSchema {
+                name: "type_name",
+                ty: FieldType::Node(&TS_ENTITY_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypePredicate,
+        name: "TsTypePredicate",
+        fields: &[
+            FieldSchema {
+                name: "asserts",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "param_name",
+                ty: FieldType::Node(&TS_THIS_TYPE_OR_IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE_ANN),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypeQuery,
+        name: "TsTypeQuery",
+        fields: &[
+            FieldSchema {
+                name: "expr_name",
+                ty: FieldType::Node(&TS_TYPE_QUERY_EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_args",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsImportCallOptions,
+        name: "TsImportCallOptions",
+        fields: &[FieldSchema {
+            name: "with",
+            ty: FieldType::Node(&OBJECT_LIT),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 4u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsImportType,
+        name: "TsImportType",
+        fields: &[
+            FieldSchema {
+                name: "arg",
+                ty: FieldType::Node(&STR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "qualifier",
+                ty: FieldType::OptionalNode(&TS_ENTITY_NAME),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_args",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "attributes",
+                ty: FieldType::OptionalNode(&TS_IMPORT_CALL_OPTIONS),
+                location: FieldLocation::ExtraData(3u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypeLit,
+        name: "TsTypeLit",
+        fields: &[FieldSchema {
+            name: "members",
+            ty: FieldType::List(&FieldType::Node(&TS_TYPE_ELEMENT)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsArrayType,
+        name: "TsArrayType",
+        fields: &[FieldSchema {
+            name: "elem_type",
+            ty: FieldType::Node(&TS_TYPE),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTupleType,
+        name: "TsTupleType",
+        fields: &[FieldSchema {
+            name: "elem_types",
+            ty: FieldType::List(&FieldType::Node(&TS_TUPLE_ELEMENT)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTupleElement,
+        name: "TsTupleElement",
+        fields: &[
+            FieldSchema {
+                name: "label",
+                ty: FieldType::OptionalNode(&PAT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "ty",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsOptionalType,
+        name: "TsOptionalType",
+        fields: &[FieldSchema {
+            name: "type_ann",
+            ty: FieldType::Node(&TS_TYPE),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsRestType,
+        name: "TsRestType",
+        fields: &[FieldSchema {
+            name: "type_ann",
+            ty: FieldType::Node(&TS_TYPE),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsUnionType,
+        name: "TsUnionType",
+        fields: &[FieldSchema {
+            name: "types",
+            ty: FieldType::List(&FieldType::Node(&TS_TYPE)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsIntersectionType,
+        name: "TsIntersectionType",
+        fields: &[FieldSchema {
+            name: "types",
+            ty: FieldType::List(&FieldType::Node(&TS_TYPE)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsConditionalType,
+        name: "TsConditionalType",
+        fields: &[
+            FieldSchema {
+                name: "check_type",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "extends_type",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "true_type",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "false_type",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(3u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsInferType,
+        name: "TsInferType",
+        fields: &[FieldSchema {
+            name: "type_param",
+            ty: FieldType::Node(&TS_TYPE_PARAM),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 4u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsParenthesizedType,
+        name: "TsParenthesizedType",
+        fields: &[FieldSchema {
+            name: "type_ann",
+            ty: FieldType::Node(&TS_TYPE),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypeOperator,
+        name: "TsTypeOperator",
+        fields: &[
+            FieldSchema {
+                name: "op",
+                ty: FieldType::Enum(&TS_TYPE_OPERATOR_OP),
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(0u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsIndexedAccessType,
+        name: "TsIndexedAccessType",
+        fields: &[
+            FieldSchema {
+                name: "readonly",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "obj_type",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "index_type",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsMappedType,
+        name: "TsMappedType",
+        fields: &[
+            FieldSchema {
+                name: "readonly",
+                ty: FieldType::OptionalEnum(&TRUE_PLUS_MINUS),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_param",
+                ty: FieldType::Node(&TS_TYPE_PARAM),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "name_type",
+                ty: FieldType::OptionalNode(&TS_TYPE),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "optional",
+                ty: FieldType::OptionalEnum(&TRUE_PLUS_MINUS),
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::OptionalNode(&TS_TYPE),
+                location: FieldLocation::ExtraData(4u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsLitType,
+        name: "TsLitType",
+        fields: &[FieldSchema {
+            name: "lit",
+            ty: FieldType::Node(&TS_LIT),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTplLitType,
+        name: "TsTplLitType",
+        fields: &[
+            FieldSchema {
+                name: "types",
+                ty: FieldType::List(&FieldType::Node(&TS_TYPE)),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "quasis",
+                ty: FieldType::List(&FieldType::Node(&TPL_ELEMENT)),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsInterfaceDecl,
+        name: "TsInterfaceDecl",
+        fields: &[
+            FieldSchema {
+                name: "id",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "declare",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_DECL),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "extends",
+                ty: FieldType::List(&FieldType::Node(&TS_EXPR_WITH_TYPE_ARGS)),
+                location: FieldLocation::ExtraData(3u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&TS_INTERFACE_BODY),
+                location: FieldLocation::ExtraData(4u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsInterfaceBody,
+        name: "TsInterfaceBody",
+        fields: &[FieldSchema {
+            name: "body",
+            ty: FieldType::List(&FieldType::Node(&TS_TYPE_ELEMENT)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsExprWithTypeArgs,
+        name: "TsExprWithTypeArgs",
+        fields: &[
+            FieldSchema {
+                name: "expr",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_args",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypeAliasDecl,
+        name: "TsTypeAliasDecl",
+        fields: &[
+            FieldSchema {
+                name: "declare",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "id",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_params",
+                ty: FieldType::OptionalNode(&TS_TYPE_PARAM_DECL),
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(2u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsEnumDecl,
+        name: "TsEnumDecl",
+        fields: &[
+            FieldSchema {
+                name: "declare",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "is_const",
+                ty: FieldType::Bool,
+                location: FieldLocation::ExtraData(1u32),
+            },
+            FieldSchema {
+                name: "id",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(2u32),
+            },
+            FieldSchema {
+                name: "members",
+                ty: FieldType::List(&FieldType::Node(&TS_ENUM_MEMBER)),
+                location: FieldLocation::ExtraData(3u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsEnumMember,
+        name: "TsEnumMember",
+        fields: &[
+            FieldSchema {
+                name: "id",
+                ty: FieldType::Node(&TS_ENUM_MEMBER_ID),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "init",
+                ty: FieldType::OptionalNode(&EXPR),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsModuleDecl,
+        name: "TsModuleDecl",
+        fields: &[
+            FieldSchema {
+                name: "declare",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "global",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 5u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "namespace",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 6u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "id",
+                ty: FieldType::Node(&TS_MODULE_NAME),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::OptionalNode(&TS_NAMESPACE_BODY),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsModuleBlock,
+        name: "TsModuleBlock",
+        fields: &[FieldSchema {
+            name: "body",
+            ty: FieldType::List(&FieldType::Node(&MODULE_ITEM)),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsNamespaceDecl,
+        name: "TsNamespaceDecl",
+        fields: &[
+            FieldSchema {
+                name: "declare",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "global",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 5u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "id",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "body",
+                ty: FieldType::Node(&TS_NAMESPACE_BODY),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsImportEqualsDecl,
+        name: "TsImportEqualsDecl",
+        fields: &[
+            FieldSchema {
+                name: "is_export",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 4u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "is_type_only",
+                ty: FieldType::Bool,
+                location: FieldLocation::Inline {
+                    offset: 5u8,
+                    size: 1u8,
+                },
+            },
+            FieldSchema {
+                name: "id",
+                ty: FieldType::Node(&IDENT),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "module_ref",
+                ty: FieldType::Node(&TS_MODULE_REF),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsExternalModuleRef,
+        name: "TsExternalModuleRef",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&STR),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 4u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsExportAssignment,
+        name: "TsExportAssignment",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsNamespaceExportDecl,
+        name: "TsNamespaceExportDecl",
+        fields: &[FieldSchema {
+            name: "id",
+            ty: FieldType::Node(&IDENT),
+            location: FieldLocation::Inline {
+                offset: 0u8,
+                size: 4u8,
+            },
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsAsExpr,
+        name: "TsAsExpr",
+        fields: &[
+            FieldSchema {
+                name: "expr",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsTypeAssertion,
+        name: "TsTypeAssertion",
+        fields: &[
+            FieldSchema {
+                name: "expr",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsNonNullExpr,
+        name: "TsNonNullExpr",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsSatisfiesExpr,
+        name: "TsSatisfiesExpr",
+        fields: &[
+            FieldSchema {
+                name: "expr",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_ann",
+                ty: FieldType::Node(&TS_TYPE),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+    NodeSchema {
+        kind: NodeKind::TsConstAssertion,
+        name: "TsConstAssertion",
+        fields: &[FieldSchema {
+            name: "expr",
+            ty: FieldType::Node(&EXPR),
+            location: FieldLocation::ExtraData(0u32),
+        }],
+    },
+    NodeSchema {
+        kind: NodeKind::TsInstantiation,
+        name: "TsInstantiation",
+        fields: &[
+            FieldSchema {
+                name: "expr",
+                ty: FieldType::Node(&EXPR),
+                location: FieldLocation::ExtraData(0u32),
+            },
+            FieldSchema {
+                name: "type_args",
+                ty: FieldType::Node(&TS_TYPE_PARAM_INSTANTIATION),
+                location: FieldLocation::ExtraData(1u32),
+            },
+        ],
+    },
+];
+impl NodeKind {
+    /// Returns the index of the schema of the kind in [NODE_SCHEMAS].
+    pub(crate) fn schema_index(self) -> usize {
+        match self {
+            NodeKind::Module => 0usize,
+            NodeKind::Script => 1usize,
+            NodeKind::ImportDecl => 2usize,
+            NodeKind::ImportNamedSpecifier => 3usize,
+            NodeKind::ImportDefaultSpecifier => 4usize,
+            NodeKind::ImportStarAsSpecifier => 5usize,
+            NodeKind::ExportDecl => 6usize,
+            NodeKind::NamedExport => 7usize,
+            NodeKind::ExportNamespaceSpecifier => 8usize,
+            NodeKind::ExportDefaultSpecifier => 9usize,
+            NodeKind::ExportNamedSpecifier => 10usize,
+            NodeKind::ExportDefaultDecl => 11usize,
+            NodeKind::ExportDefaultExpr => 12usize,
+            NodeKind::ExportAll => 13usize,
+            NodeKind::BlockStmt => 14usize,
+            NodeKind::ExprStmt => 15usize,
+            NodeKind::EmptyStmt => 16usize,
+            NodeKind::DebuggerStmt => 17usize,
+            NodeKind::WithStmt => 18usize,
+            NodeKind::ReturnStmt => 19usize,
+            NodeKind::LabeledStmt => 20usize,
+            NodeKind::BreakStmt => 21usize,
+            NodeKind::ContinueStmt => 22usize,
+            NodeKind::IfStmt => 23usize,
+            NodeKind::SwitchStmt => 24usize,
+            NodeKind::ThrowStmt => 25usize,
+            NodeKind::TryStmt => 26usize,
+            NodeKind::WhileStmt => 27usize,
+            NodeKind::DoWhileStmt => 28usize,
+            NodeKind::ForStmt => 29usize,
+            NodeKind::ForInStmt => 30usize,
+            NodeKind::ForOfStmt => 31usize,
+            NodeKind::SwitchCase => 32usize,
+            NodeKind::CatchClause => 33usize,
+            NodeKind::FnDecl => 34usize,
+            NodeKind::ClassDecl => 35usize,
+            NodeKind::VarDecl => 36usize,
+            NodeKind::VarDeclarator => 37usize,
+            NodeKind::UsingDecl => 38usize,
+            NodeKind::ThisExpr => 39usize,
+            NodeKind::ArrayLit => 40usize,
+            NodeKind::ObjectLit => 41usize,
+            NodeKind::SpreadElement => 42usize,
+            NodeKind::UnaryExpr => 43usize,
+            NodeKind::UpdateExpr => 44usize,
+            NodeKind::BinExpr => 45usize,
+            NodeKind::FnExpr => 46usize,
+            NodeKind::ClassExpr => 47usize,
+            NodeKind::AssignExpr => 48usize,
+            NodeKind::MemberExpr => 49usize,
+            NodeKind::SuperPropExpr => 50usize,
+            NodeKind::CondExpr => 51usize,
+            NodeKind::CallExpr => 52usize,
+            NodeKind::NewExpr => 53usize,
+            NodeKind::SeqExpr => 54usize,
+            NodeKind::ArrowExpr => 55usize,
+            NodeKind::YieldExpr => 56usize,
+            NodeKind::MetaPropExpr => 57usize,
+            NodeKind::AwaitExpr => 58usize,
+            NodeKind::Tpl => 59usize,
+            NodeKind::TaggedTpl => 60usize,
+            NodeKind::TplElement => 61usize,
+            NodeKind::ParenExpr => 62usize,
+            NodeKind::Super => 63usize,
+            NodeKind::Import => 64usize,
+            NodeKind::ExprOrSpread => 65usize,
+            NodeKind::SpreadDot3Token => 66usize,
+            NodeKind::OptChainExpr => 67usize,
+            NodeKind::OptCall => 68usize,
+            NodeKind::Invalid => 69usize,
+            NodeKind::Function => 70usize,
+            NodeKind::Param => 71usize,
+            NodeKind::Class => 72usize,
+            NodeKind::ClassProp => 73usize,
+            NodeKind::PrivateProp => 74usize,
+            NodeKind::ClassMethod => 75usize,
+            NodeKind::PrivateMethod => 76usize,
+            NodeKind::Constructor => 77usize,
+            NodeKind::Decorator => 78usize,
+            NodeKind::StaticBlock => 79usize,
+            NodeKind::AutoAccessor => 80usize,
+            NodeKind::KeyValueProp => 81usize,
+            NodeKind::AssignProp => 82usize,
+            NodeKind::GetterProp => 83usize,
+            NodeKind::SetterProp => 84usize,
+            NodeKind::MethodProp => 85usize,
+            NodeKind::ComputedPropName => 86usize,
+            NodeKind::ArrayPat => 87usize,
+            NodeKind::ObjectPat => 88usize,
+            NodeKind::AssignPat => 89usize,
+            NodeKind::RestPat => 90usize,
+            NodeKind::KeyValuePatProp => 91usize,
+            NodeKind::AssignPatProp => 92usize,
+            NodeKind::Ident => 93usize,
+            NodeKind::IdentName => 94usize,
+            NodeKind::PrivateName => 95usize,
+            NodeKind::BindingIdent => 96usize,
+            NodeKind::Str => 97usize,
+            NodeKind::Bool => 98usize,
+            NodeKind::Null => 99usize,
+            NodeKind::Number => 100usize,
+            NodeKind::BigInt => 101usize,
+            NodeKind::Regex => 102usize,
+            NodeKind::JSXMemberExpr => 103usize,
+            NodeKind::JSXNamespacedName => 104usize,
+            NodeKind::JSXEmptyExpr => 105usize,
+            NodeKind::JSXExprContainer => 106usize,
+            NodeKind::JSXSpreadChild => 107usize,
+            NodeKind::JSXOpeningElement => 108usize,
+            NodeKind::JSXClosingElement => 109usize,
+            NodeKind::JSXAttr => 110usize,
+            NodeKind::JSXText => 111usize,
+            NodeKind::JSXElement => 112usize,
+            NodeKind::JSXFragment => 113usize,
+            NodeKind::JSXOpeningFragment => 114usize,
+            NodeKind::JSXClosingFragment => 115usize,
+            NodeKind::TsTypeAnn => 116usize,
+            NodeKind::TsTypeParamDecl => 117usize,
+            NodeKind::TsTypeParam => 118usize,
+            NodeKind::TsTypeParamInstantiation => 119usize,
+            NodeKind::TsParamProp => 120usize,
+            NodeKind::TsQualifiedName => 121usize,
+            NodeKind::TsCallSignatureDecl => 122usize,
+            NodeKind::TsConstructSignatureDecl => 123usize,
+            NodeKind::TsPropertySignature => 124usize,
+            NodeKind::TsGetterSignature => 125usize,
+            NodeKind::TsSetterSignature => 126usize,
+            NodeKind::TsMethodSignature => 127usize,
+            NodeKind::TsIndexSignature => 128usize,
+            NodeKind::TsKeywordType => 129usize,
+            NodeKind::TsThisType => 130usize,
+            NodeKind::TsFnType => 131usize,
+            NodeKind::TsConstructorType => 132usize,
+            NodeKind::TsTypeRef => 133usize,
+            NodeKind::TsTypePredicate => 134usize,
+            NodeKind::TsTypeQuery => 135usize,
+            NodeKind::TsImportCallOptions => 136usize,
+            NodeKind::TsImportType => 137usize,
+            NodeKind::TsTypeLit => 138usize,
+            NodeKind::TsArrayType => 139usize,
+            NodeKind::TsTupleType => 140usize,
+            NodeKind::TsTupleElement => 141usize,
+            NodeKind::TsOptionalType => 142usize,
+            NodeKind::TsRestType => 143usize,
+            NodeKind::TsUnionType => 144usize,
+            NodeKind::TsIntersectionType => 145usize,
+            NodeKind::TsConditionalType => 146usize,
+            NodeKind::TsInferType => 147usize,
+            NodeKind::TsParenthesizedType => 148usize,
+            NodeKind::TsTypeOperator => 149usize,
+            NodeKind::TsIndexedAccessType => 150usize,
+            NodeKind::TsMappedType => 151usize,
+            NodeKind::TsLitType => 152usize,
+            NodeKind::TsTplLitType => 153usize,
+            NodeKind::TsInterfaceDecl => 154usize,
+            NodeKind::TsInterfaceBody => 155usize,
+            NodeKind::TsExprWithTypeArgs => 156usize,
+            NodeKind::TsTypeAliasDecl => 157usize,
+            NodeKind::TsEnumDecl => 158usize,
+            NodeKind::TsEnumMember => 159usize,
+            NodeKind::TsModuleDecl => 160usize,
+            NodeKind::TsModuleBlock => 161usize,
+            NodeKind::TsNamespaceDecl => 162usize,
+            NodeKind::TsImportEqualsDecl => 163usize,
+            NodeKind::TsExternalModuleRef => 164usize,
+            NodeKind::TsExportAssignment => 165usize,
+            NodeKind::TsNamespaceExportDecl => 166usize,
+            NodeKind::TsAsExpr => 167usize,
+            NodeKind::TsTypeAssertion => 168usize,
+            NodeKind::TsNonNullExpr => 169usize,
+            NodeKind::TsSatisfiesExpr => 170usize,
+            NodeKind::TsConstAssertion => 171usize,
+            NodeKind::TsInstantiation => 172usize,
+            _ => unreachable!("{self:?} has no schema"),
+        }
+    }
+}
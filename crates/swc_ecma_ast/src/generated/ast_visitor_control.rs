@@ -0,0 +1,6537 @@
+#![allow(unused, clippy::useless_conversion, clippy::single_match)]
+use crate::*;
+use std::ops::ControlFlow;
+use swc_core::common::Span;
+pub trait VisitControl {
+    type Break;
+    fn ast(&self) -> &Ast;
+    #[inline]
+    fn enter_node(&mut self, node_id: NodeId) -> VisitFlow<Self::Break> {
+        VisitFlow::Continue
+    }
+    #[inline]
+    fn leave_node(&mut self, node_id: NodeId) -> ControlFlow<Self::Break> {
+        ControlFlow::Continue(())
+    }
+    #[inline]
+    fn visit_program(&mut self, node: Program) -> ControlFlow<Self::Break> {
+        <Program as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_module(&mut self, node: Module) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Module as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_script(&mut self, node: Script) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Script as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_module_item(&mut self, node: ModuleItem) -> ControlFlow<Self::Break> {
+        <ModuleItem as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_module_decl(&mut self, node: ModuleDecl) -> ControlFlow<Self::Break> {
+        <ModuleDecl as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_import_decl(&mut self, node: ImportDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ImportDecl as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_import_specifier(&mut self, node: ImportSpecifier) -> ControlFlow<Self::Break> {
+        <ImportSpecifier as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_import_named_specifier(
+        &mut self,
+        node: ImportNamedSpecifier,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ImportNamedSpecifier as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_import_default_specifier(
+        &mut self,
+        node: ImportDefaultSpecifier,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ImportDefaultSpecifier as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_import_star_as_specifier(
+        &mut self,
+        node: ImportStarAsSpecifier,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ImportStarAsSpecifier as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_export_decl(&mut self, node: ExportDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ExportDecl as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_named_export(&mut self, node: NamedExport) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <NamedExport as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_export_specifier(&mut self, node: ExportSpecifier) -> ControlFlow<Self::Break> {
+        <ExportSpecifier as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_export_namespace_specifier(
+        &mut self,
+        node: ExportNamespaceSpecifier,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ExportNamespaceSpecifier as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_module_export_name(&mut self, node: ModuleExportName) -> ControlFlow<Self::Break> {
+        <ModuleExportName as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_export_default_specifier(
+        &mut self,
+        node: ExportDefaultSpecifier,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ExportDefaultSpecifier as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_export_named_specifier(
+        &mut self,
+        node: ExportNamedSpecifier,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ExportNamedSpecifier as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_export_default_decl(&mut self, node: ExportDefaultDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ExportDefaultDecl as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_default_decl(&mut self, node: DefaultDecl) -> ControlFlow<Self::Break> {
+        <DefaultDecl as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_export_default_expr(&mut self, node: ExportDefaultExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ExportDefaultExpr as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_export_all(&mut self, node: ExportAll) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ExportAll as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_block_stmt(&mut self, node: BlockStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <BlockStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_stmt(&mut self, node: Stmt) -> ControlFlow<Self::Break> {
+        <Stmt as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_expr_stmt(&mut self, node: ExprStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ExprStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_empty_stmt(&mut self, node: EmptyStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <EmptyStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_debugger_stmt(&mut self, node: DebuggerStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <DebuggerStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_with_stmt(&mut self, node: WithStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <WithStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_return_stmt(&mut self, node: ReturnStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ReturnStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_labeled_stmt(&mut self, node: LabeledStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <LabeledStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_break_stmt(&mut self, node: BreakStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <BreakStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_continue_stmt(&mut self, node: ContinueStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ContinueStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_if_stmt(&mut self, node: IfStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <IfStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_switch_stmt(&mut self, node: SwitchStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <SwitchStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_throw_stmt(&mut self, node: ThrowStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ThrowStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_try_stmt(&mut self, node: TryStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TryStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_while_stmt(&mut self, node: WhileStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <WhileStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_do_while_stmt(&mut self, node: DoWhileStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <DoWhileStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_for_stmt(&mut self, node: ForStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ForStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_for_in_stmt(&mut self, node: ForInStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ForInStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_for_of_stmt(&mut self, node: ForOfStmt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ForOfStmt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_switch_case(&mut self, node: SwitchCase) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <SwitchCase as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_catch_clause(&mut self, node: CatchClause) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <CatchClause as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_for_head(&mut self, node: ForHead) -> ControlFlow<Self::Break> {
+        <ForHead as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_var_decl_or_expr(&mut self, node: VarDeclOrExpr) -> ControlFlow<Self::Break> {
+        <VarDeclOrExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_decl(&mut self, node: Decl) -> ControlFlow<Self::Break> {
+        <Decl as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_fn_decl(&mut self, node: FnDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <FnDecl as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_class_decl(&mut self, node: ClassDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ClassDecl as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_var_decl(&mut self, node: VarDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <VarDecl as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_var_declarator(&mut self, node: VarDeclarator) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <VarDeclarator as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_using_decl(&mut self, node: UsingDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <UsingDecl as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_expr(&mut self, node: Expr) -> ControlFlow<Self::Break> {
+        <Expr as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_this_expr(&mut self, node: ThisExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ThisExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_array_lit(&mut self, node: ArrayLit) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ArrayLit as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_object_lit(&mut self, node: ObjectLit) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ObjectLit as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_prop_or_spread(&mut self, node: PropOrSpread) -> ControlFlow<Self::Break> {
+        <PropOrSpread as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_spread_element(&mut self, node: SpreadElement) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <SpreadElement as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_unary_expr(&mut self, node: UnaryExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <UnaryExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_update_expr(&mut self, node: UpdateExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <UpdateExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_bin_expr(&mut self, node: BinExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <BinExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_fn_expr(&mut self, node: FnExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <FnExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_class_expr(&mut self, node: ClassExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ClassExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_assign_expr(&mut self, node: AssignExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <AssignExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_member_expr(&mut self, node: MemberExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <MemberExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_member_prop(&mut self, node: MemberProp) -> ControlFlow<Self::Break> {
+        <MemberProp as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_super_prop_expr(&mut self, node: SuperPropExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <SuperPropExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_super_prop(&mut self, node: SuperProp) -> ControlFlow<Self::Break> {
+        <SuperProp as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_cond_expr(&mut self, node: CondExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <CondExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_call_expr(&mut self, node: CallExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <CallExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_new_expr(&mut self, node: NewExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <NewExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_seq_expr(&mut self, node: SeqExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <SeqExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_arrow_expr(&mut self, node: ArrowExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ArrowExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_yield_expr(&mut self, node: YieldExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <YieldExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_meta_prop_expr(&mut self, node: MetaPropExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <MetaPropExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_await_expr(&mut self, node: AwaitExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <AwaitExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_tpl(&mut self, node: Tpl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Tpl as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_tagged_tpl(&mut self, node: TaggedTpl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TaggedTpl as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_tpl_element(&mut self, node: TplElement) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TplElement as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_paren_expr(&mut self, node: ParenExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ParenExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_callee(&mut self, node: Callee) -> ControlFlow<Self::Break> {
+        <Callee as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_super(&mut self, node: Super) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Super as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_import(&mut self, node: Import) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Import as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_expr_or_spread(&mut self, node: ExprOrSpread) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ExprOrSpread as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_spread_dot_3_token(&mut self, node: SpreadDot3Token) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <SpreadDot3Token as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_block_stmt_or_expr(&mut self, node: BlockStmtOrExpr) -> ControlFlow<Self::Break> {
+        <BlockStmtOrExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_assign_target(&mut self, node: AssignTarget) -> ControlFlow<Self::Break> {
+        <AssignTarget as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_assign_target_pat(&mut self, node: AssignTargetPat) -> ControlFlow<Self::Break> {
+        <AssignTargetPat as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_simple_assign_target(&mut self, node: SimpleAssignTarget) -> ControlFlow<Self::Break> {
+        <SimpleAssignTarget as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_chain_expr(&mut self, node: OptChainExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <OptChainExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_opt_chain_base(&mut self, node: OptChainBase) -> ControlFlow<Self::Break> {
+        <OptChainBase as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_call(&mut self, node: OptCall) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <OptCall as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_invalid(&mut self, node: Invalid) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Invalid as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_function(&mut self, node: Function) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Function as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_param(&mut self, node: Param) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Param as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_param_or_ts_param_prop(
+        &mut self,
+        node: ParamOrTsParamProp,
+    ) -> ControlFlow<Self::Break> {
+        <ParamOrTsParamProp as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_class(&mut self, node: Class) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Class as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_class_member(&mut self, node: ClassMember) -> ControlFlow<Self::Break> {
+        <ClassMember as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_class_prop(&mut self, node: ClassProp) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ClassProp as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_private_prop(&mut self, node: PrivateProp) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <PrivateProp as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_class_method(&mut self, node: ClassMethod) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ClassMethod as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_private_method(&mut self, node: PrivateMethod) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <PrivateMethod as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_constructor(&mut self, node: Constructor) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Constructor as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_decorator(&mut self, node: Decorator) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Decorator as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_static_block(&mut self, node: StaticBlock) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <StaticBlock as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_key(&mut self, node: Key) -> ControlFlow<Self::Break> {
+        <Key as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_auto_accessor(&mut self, node: AutoAccessor) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <AutoAccessor as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_prop(&mut self, node: Prop) -> ControlFlow<Self::Break> {
+        <Prop as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_key_value_prop(&mut self, node: KeyValueProp) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <KeyValueProp as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_assign_prop(&mut self, node: AssignProp) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <AssignProp as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_getter_prop(&mut self, node: GetterProp) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <GetterProp as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_setter_prop(&mut self, node: SetterProp) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <SetterProp as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_method_prop(&mut self, node: MethodProp) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <MethodProp as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_prop_name(&mut self, node: PropName) -> ControlFlow<Self::Break> {
+        <PropName as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_computed_prop_name(&mut self, node: ComputedPropName) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ComputedPropName as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_pat(&mut self, node: Pat) -> ControlFlow<Self::Break> {
+        <Pat as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_array_pat(&mut self, node: ArrayPat) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ArrayPat as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_object_pat(&mut self, node: ObjectPat) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <ObjectPat as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_assign_pat(&mut self, node: AssignPat) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <AssignPat as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_rest_pat(&mut self, node: RestPat) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <RestPat as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_object_pat_prop(&mut self, node: ObjectPatProp) -> ControlFlow<Self::Break> {
+        <ObjectPatProp as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_key_value_pat_prop(&mut self, node: KeyValuePatProp) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <KeyValuePatProp as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_assign_pat_prop(&mut self, node: AssignPatProp) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <AssignPatProp as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ident(&mut self, node: Ident) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Ident as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ident_name(&mut self, node: IdentName) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <IdentName as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_private_name(&mut self, node: PrivateName) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <PrivateName as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_binding_ident(&mut self, node: BindingIdent) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <BindingIdent as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_lit(&mut self, node: Lit) -> ControlFlow<Self::Break> {
+        <Lit as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_str(&mut self, node: Str) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Str as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_bool(&mut self, node: Bool) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Bool as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_null(&mut self, node: Null) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Null as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_number(&mut self, node: Number) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Number as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_big_int(&mut self, node: BigInt) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <BigInt as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_regex(&mut self, node: Regex) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <Regex as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_object(&mut self, node: JSXObject) -> ControlFlow<Self::Break> {
+        <JSXObject as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_jsx_member_expr(&mut self, node: JSXMemberExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXMemberExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_namespaced_name(&mut self, node: JSXNamespacedName) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXNamespacedName as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_empty_expr(&mut self, node: JSXEmptyExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXEmptyExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_expr_container(&mut self, node: JSXExprContainer) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXExprContainer as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_expr(&mut self, node: JSXExpr) -> ControlFlow<Self::Break> {
+        <JSXExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_jsx_spread_child(&mut self, node: JSXSpreadChild) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXSpreadChild as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_element_name(&mut self, node: JSXElementName) -> ControlFlow<Self::Break> {
+        <JSXElementName as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_jsx_opening_element(&mut self, node: JSXOpeningElement) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXOpeningElement as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_attr_or_spread(&mut self, node: JSXAttrOrSpread) -> ControlFlow<Self::Break> {
+        <JSXAttrOrSpread as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_jsx_closing_element(&mut self, node: JSXClosingElement) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXClosingElement as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_attr(&mut self, node: JSXAttr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXAttr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_attr_name(&mut self, node: JSXAttrName) -> ControlFlow<Self::Break> {
+        <JSXAttrName as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_jsx_attr_value(&mut self, node: JSXAttrValue) -> ControlFlow<Self::Break> {
+        <JSXAttrValue as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_jsx_text(&mut self, node: JSXText) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXText as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_element(&mut self, node: JSXElement) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXElement as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_element_child(&mut self, node: JSXElementChild) -> ControlFlow<Self::Break> {
+        <JSXElementChild as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_jsx_fragment(&mut self, node: JSXFragment) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXFragment as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_opening_fragment(&mut self, node: JSXOpeningFragment) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXOpeningFragment as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_jsx_closing_fragment(&mut self, node: JSXClosingFragment) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <JSXClosingFragment as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_ann(&mut self, node: TsTypeAnn) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypeAnn as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_param_decl(&mut self, node: TsTypeParamDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypeParamDecl as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_param(&mut self, node: TsTypeParam) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypeParam as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_param_instantiation(
+        &mut self,
+        node: TsTypeParamInstantiation,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypeParamInstantiation as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_param_prop(&mut self, node: TsParamProp) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsParamProp as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_param_prop_param(&mut self, node: TsParamPropParam) -> ControlFlow<Self::Break> {
+        <TsParamPropParam as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_qualified_name(&mut self, node: TsQualifiedName) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsQualifiedName as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_entity_name(&mut self, node: TsEntityName) -> ControlFlow<Self::Break> {
+        <TsEntityName as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_type_element(&mut self, node: TsTypeElement) -> ControlFlow<Self::Break> {
+        <TsTypeElement as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_call_signature_decl(
+        &mut self,
+        node: TsCallSignatureDecl,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsCallSignatureDecl as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_construct_signature_decl(
+        &mut self,
+        node: TsConstructSignatureDecl,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsConstructSignatureDecl as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_property_signature(
+        &mut self,
+        node: TsPropertySignature,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsPropertySignature as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_getter_signature(&mut self, node: TsGetterSignature) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsGetterSignature as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_setter_signature(&mut self, node: TsSetterSignature) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsSetterSignature as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_method_signature(&mut self, node: TsMethodSignature) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsMethodSignature as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_index_signature(&mut self, node: TsIndexSignature) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsIndexSignature as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type(&mut self, node: TsType) -> ControlFlow<Self::Break> {
+        <TsType as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_fn_or_constructor_type(
+        &mut self,
+        node: TsFnOrConstructorType,
+    ) -> ControlFlow<Self::Break> {
+        <TsFnOrConstructorType as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_keyword_type(&mut self, node: TsKeywordType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsKeywordType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_this_type(&mut self, node: TsThisType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsThisType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_fn_param(&mut self, node: TsFnParam) -> ControlFlow<Self::Break> {
+        <TsFnParam as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_fn_type(&mut self, node: TsFnType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsFnType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_constructor_type(&mut self, node: TsConstructorType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsConstructorType as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_ref(&mut self, node: TsTypeRef) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypeRef as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_predicate(&mut self, node: TsTypePredicate) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypePredicate as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_this_type_or_ident(&mut self, node: TsThisTypeOrIdent) -> ControlFlow<Self::Break> {
+        <TsThisTypeOrIdent as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_type_query(&mut self, node: TsTypeQuery) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypeQuery as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_query_expr(&mut self, node: TsTypeQueryExpr) -> ControlFlow<Self::Break> {
+        <TsTypeQueryExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_import_call_options(
+        &mut self,
+        node: TsImportCallOptions,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsImportCallOptions as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_import_type(&mut self, node: TsImportType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsImportType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_lit(&mut self, node: TsTypeLit) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypeLit as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_array_type(&mut self, node: TsArrayType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsArrayType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_tuple_type(&mut self, node: TsTupleType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTupleType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_tuple_element(&mut self, node: TsTupleElement) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTupleElement as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_optional_type(&mut self, node: TsOptionalType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsOptionalType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_rest_type(&mut self, node: TsRestType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsRestType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_union_or_intersection_type(
+        &mut self,
+        node: TsUnionOrIntersectionType,
+    ) -> ControlFlow<Self::Break> {
+        <TsUnionOrIntersectionType as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_ts_union_type(&mut self, node: TsUnionType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsUnionType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_intersection_type(&mut self, node: TsIntersectionType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsIntersectionType as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_conditional_type(&mut self, node: TsConditionalType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsConditionalType as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_infer_type(&mut self, node: TsInferType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsInferType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_parenthesized_type(
+        &mut self,
+        node: TsParenthesizedType,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsParenthesizedType as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_operator(&mut self, node: TsTypeOperator) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypeOperator as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_indexed_access_type(
+        &mut self,
+        node: TsIndexedAccessType,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsIndexedAccessType as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_mapped_type(&mut self, node: TsMappedType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsMappedType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_lit_type(&mut self, node: TsLitType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsLitType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_lit(&mut self, node: TsLit) -> ControlFlow<Self::Break> {
+        <TsLit as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_tpl_lit_type(&mut self, node: TsTplLitType) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTplLitType as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_interface_decl(&mut self, node: TsInterfaceDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsInterfaceDecl as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_interface_body(&mut self, node: TsInterfaceBody) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsInterfaceBody as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_expr_with_type_args(
+        &mut self,
+        node: TsExprWithTypeArgs,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsExprWithTypeArgs as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypeAliasDecl as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_enum_decl(&mut self, node: TsEnumDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsEnumDecl as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_enum_member(&mut self, node: TsEnumMember) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsEnumMember as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_enum_member_id(&mut self, node: TsEnumMemberId) -> ControlFlow<Self::Break> {
+        <TsEnumMemberId as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_module_decl(&mut self, node: TsModuleDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsModuleDecl as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_namespace_body(&mut self, node: TsNamespaceBody) -> ControlFlow<Self::Break> {
+        <TsNamespaceBody as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_module_block(&mut self, node: TsModuleBlock) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsModuleBlock as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_namespace_decl(&mut self, node: TsNamespaceDecl) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsNamespaceDecl as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_module_name(&mut self, node: TsModuleName) -> ControlFlow<Self::Break> {
+        <TsModuleName as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_import_equals_decl(
+        &mut self,
+        node: TsImportEqualsDecl,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsImportEqualsDecl as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_module_ref(&mut self, node: TsModuleRef) -> ControlFlow<Self::Break> {
+        <TsModuleRef as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_external_module_ref(
+        &mut self,
+        node: TsExternalModuleRef,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsExternalModuleRef as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_export_assignment(&mut self, node: TsExportAssignment) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsExportAssignment as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_namespace_export_decl(
+        &mut self,
+        node: TsNamespaceExportDecl,
+    ) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsNamespaceExportDecl as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_as_expr(&mut self, node: TsAsExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsAsExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_type_assertion(&mut self, node: TsTypeAssertion) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsTypeAssertion as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_non_null_expr(&mut self, node: TsNonNullExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsNonNullExpr as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsSatisfiesExpr as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_const_assertion(&mut self, node: TsConstAssertion) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsConstAssertion as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_ts_instantiation(&mut self, node: TsInstantiation) -> ControlFlow<Self::Break> {
+        match self.enter_node(node.node_id()) {
+            VisitFlow::Continue => {
+                <TsInstantiation as VisitControlWith<Self>>::visit_children_with_control(
+                    node, self,
+                )?
+            }
+            VisitFlow::SkipChildren => {}
+            VisitFlow::Break(value) => return ControlFlow::Break(value),
+        }
+        self.leave_node(node.node_id())
+    }
+    #[inline]
+    fn visit_module_items(&mut self, node: TypedSubRange<ModuleItem>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<ModuleItem> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_stmts(&mut self, node: TypedSubRange<Stmt>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<Stmt> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_import_specifiers(
+        &mut self,
+        node: TypedSubRange<ImportSpecifier>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<ImportSpecifier> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_object_lit(&mut self, node: Option<ObjectLit>) -> ControlFlow<Self::Break> {
+        <Option<ObjectLit> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_module_export_name(
+        &mut self,
+        node: Option<ModuleExportName>,
+    ) -> ControlFlow<Self::Break> {
+        <Option<ModuleExportName> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_export_specifiers(
+        &mut self,
+        node: TypedSubRange<ExportSpecifier>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<ExportSpecifier> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_str(&mut self, node: Option<Str>) -> ControlFlow<Self::Break> {
+        <Option<Str> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_expr(&mut self, node: Option<Expr>) -> ControlFlow<Self::Break> {
+        <Option<Expr> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_ident(&mut self, node: Option<Ident>) -> ControlFlow<Self::Break> {
+        <Option<Ident> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_stmt(&mut self, node: Option<Stmt>) -> ControlFlow<Self::Break> {
+        <Option<Stmt> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_switch_cases(&mut self, node: TypedSubRange<SwitchCase>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<SwitchCase> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_catch_clause(&mut self, node: Option<CatchClause>) -> ControlFlow<Self::Break> {
+        <Option<CatchClause> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_block_stmt(&mut self, node: Option<BlockStmt>) -> ControlFlow<Self::Break> {
+        <Option<BlockStmt> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_var_decl_or_expr(
+        &mut self,
+        node: Option<VarDeclOrExpr>,
+    ) -> ControlFlow<Self::Break> {
+        <Option<VarDeclOrExpr> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_pat(&mut self, node: Option<Pat>) -> ControlFlow<Self::Break> {
+        <Option<Pat> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_var_declarators(
+        &mut self,
+        node: TypedSubRange<VarDeclarator>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<VarDeclarator> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_expr_or_spread(&mut self, node: Option<ExprOrSpread>) -> ControlFlow<Self::Break> {
+        <Option<ExprOrSpread> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_vec_expr_or_spreads(
+        &mut self,
+        node: TypedSubRange<Option<ExprOrSpread>>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<Option<ExprOrSpread>> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_prop_or_spreads(
+        &mut self,
+        node: TypedSubRange<PropOrSpread>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<PropOrSpread> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_expr_or_spreads(
+        &mut self,
+        node: TypedSubRange<ExprOrSpread>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<ExprOrSpread> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_ts_type_param_instantiation(
+        &mut self,
+        node: Option<TsTypeParamInstantiation>,
+    ) -> ControlFlow<Self::Break> {
+        <Option<TsTypeParamInstantiation> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_expr_or_spreads(
+        &mut self,
+        node: Option<TypedSubRange<ExprOrSpread>>,
+    ) -> ControlFlow<Self::Break> {
+        <Option<TypedSubRange<ExprOrSpread>> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_exprs(&mut self, node: TypedSubRange<Expr>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<Expr> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_pats(&mut self, node: TypedSubRange<Pat>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<Pat> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_ts_type_param_decl(
+        &mut self,
+        node: Option<TsTypeParamDecl>,
+    ) -> ControlFlow<Self::Break> {
+        <Option<TsTypeParamDecl> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_ts_type_ann(&mut self, node: Option<TsTypeAnn>) -> ControlFlow<Self::Break> {
+        <Option<TsTypeAnn> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_tpl_elements(&mut self, node: TypedSubRange<TplElement>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<TplElement> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_spread_dot_3_token(
+        &mut self,
+        node: Option<SpreadDot3Token>,
+    ) -> ControlFlow<Self::Break> {
+        <Option<SpreadDot3Token> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_params(&mut self, node: TypedSubRange<Param>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<Param> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_decorators(&mut self, node: TypedSubRange<Decorator>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<Decorator> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_class_members(
+        &mut self,
+        node: TypedSubRange<ClassMember>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<ClassMember> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_ts_expr_with_type_argss(
+        &mut self,
+        node: TypedSubRange<TsExprWithTypeArgs>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<TsExprWithTypeArgs> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_accessibility(&mut self, node: Option<Accessibility>) -> ControlFlow<Self::Break> {
+        <Option<Accessibility> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_param_or_ts_param_props(
+        &mut self,
+        node: TypedSubRange<ParamOrTsParamProp>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<ParamOrTsParamProp> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_vec_pats(&mut self, node: TypedSubRange<Option<Pat>>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<Option<Pat>> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_object_pat_props(
+        &mut self,
+        node: TypedSubRange<ObjectPatProp>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<ObjectPatProp> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_jsx_attr_or_spreads(
+        &mut self,
+        node: TypedSubRange<JSXAttrOrSpread>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<JSXAttrOrSpread> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_jsx_attr_value(&mut self, node: Option<JSXAttrValue>) -> ControlFlow<Self::Break> {
+        <Option<JSXAttrValue> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_jsx_element_childs(
+        &mut self,
+        node: TypedSubRange<JSXElementChild>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<JSXElementChild> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_jsx_closing_element(
+        &mut self,
+        node: Option<JSXClosingElement>,
+    ) -> ControlFlow<Self::Break> {
+        <Option<JSXClosingElement> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_ts_type_params(
+        &mut self,
+        node: TypedSubRange<TsTypeParam>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<TsTypeParam> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_ts_type(&mut self, node: Option<TsType>) -> ControlFlow<Self::Break> {
+        <Option<TsType> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_types(&mut self, node: TypedSubRange<TsType>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<TsType> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_fn_params(&mut self, node: TypedSubRange<TsFnParam>) -> ControlFlow<Self::Break> {
+        <TypedSubRange<TsFnParam> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_ts_entity_name(&mut self, node: Option<TsEntityName>) -> ControlFlow<Self::Break> {
+        <Option<TsEntityName> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_opt_ts_import_call_options(
+        &mut self,
+        node: Option<TsImportCallOptions>,
+    ) -> ControlFlow<Self::Break> {
+        <Option<TsImportCallOptions> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_ts_type_elements(
+        &mut self,
+        node: TypedSubRange<TsTypeElement>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<TsTypeElement> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_ts_tuple_elements(
+        &mut self,
+        node: TypedSubRange<TsTupleElement>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<TsTupleElement> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_true_plus_minus(
+        &mut self,
+        node: Option<TruePlusMinus>,
+    ) -> ControlFlow<Self::Break> {
+        <Option<TruePlusMinus> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+    #[inline]
+    fn visit_ts_enum_members(
+        &mut self,
+        node: TypedSubRange<TsEnumMember>,
+    ) -> ControlFlow<Self::Break> {
+        <TypedSubRange<TsEnumMember> as VisitControlWith<Self>>::visit_children_with_control(
+            node, self,
+        )
+    }
+    #[inline]
+    fn visit_opt_ts_namespace_body(
+        &mut self,
+        node: Option<TsNamespaceBody>,
+    ) -> ControlFlow<Self::Break> {
+        <Option<TsNamespaceBody> as VisitControlWith<Self>>::visit_children_with_control(node, self)
+    }
+}
+pub trait VisitControlWith<V: ?Sized + VisitControl> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break>;
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break>;
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Program {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_program(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Module(it) => <Module as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Script(it) => <Script as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Module {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_module(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.body(visitor.ast());
+        <TypedSubRange<ModuleItem> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.shebang(visitor.ast());
+        <OptionalUtf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Script {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_script(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.body(visitor.ast());
+        <TypedSubRange<Stmt> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.shebang(visitor.ast());
+        <OptionalUtf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ModuleItem {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_module_item(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::ModuleDecl(it) => {
+                <ModuleDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Stmt(it) => <Stmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ModuleDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_module_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Import(it) => {
+                <ImportDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::ExportDecl(it) => {
+                <ExportDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::ExportNamed(it) => {
+                <NamedExport as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::ExportDefaultDecl(it) => {
+                <ExportDefaultDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::ExportDefaultExpr(it) => {
+                <ExportDefaultExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::ExportAll(it) => {
+                <ExportAll as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsImportEquals(it) => {
+                <TsImportEqualsDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsExportAssignment(it) => {
+                <TsExportAssignment as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsNamespaceExport(it) => {
+                <TsNamespaceExportDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ImportDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_import_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.specifiers(visitor.ast());
+        <TypedSubRange<ImportSpecifier> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.src(visitor.ast());
+        <Str as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_only(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.with(visitor.ast());
+        <Option<ObjectLit> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.phase(visitor.ast());
+        <ImportPhase as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ImportSpecifier {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_import_specifier(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Named(it) => {
+                <ImportNamedSpecifier as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Default(it) => {
+                <ImportDefaultSpecifier as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Namespace(it) => {
+                <ImportStarAsSpecifier as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ImportNamedSpecifier {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_import_named_specifier(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.local(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.imported(visitor.ast());
+        <Option<ModuleExportName> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.is_type_only(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ImportDefaultSpecifier {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_import_default_specifier(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.local(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ImportStarAsSpecifier {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_import_star_as_specifier(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.local(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ExportDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_export_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.decl(visitor.ast());
+        <Decl as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for NamedExport {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_named_export(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.specifiers(visitor.ast());
+        <TypedSubRange<ExportSpecifier> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.src(visitor.ast());
+        <Option<Str> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_only(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.with(visitor.ast());
+        <Option<ObjectLit> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ExportSpecifier {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_export_specifier(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Namespace(it) => {
+                <ExportNamespaceSpecifier as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Default(it) => {
+                <ExportDefaultSpecifier as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Named(it) => {
+                <ExportNamedSpecifier as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ExportNamespaceSpecifier {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_export_namespace_specifier(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.name(visitor.ast());
+        <ModuleExportName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ModuleExportName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_module_export_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => <Ident as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Str(it) => <Str as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ExportDefaultSpecifier {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_export_default_specifier(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.exported(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ExportNamedSpecifier {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_export_named_specifier(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.orig(visitor.ast());
+        <ModuleExportName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.exported(visitor.ast());
+        <Option<ModuleExportName> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.is_type_only(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ExportDefaultDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_export_default_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.decl(visitor.ast());
+        <DefaultDecl as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for DefaultDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_default_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Class(it) => <ClassExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Fn(it) => <FnExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::TsInterfaceDecl(it) => {
+                <TsInterfaceDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ExportDefaultExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_export_default_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ExportAll {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_export_all(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.src(visitor.ast());
+        <Str as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_only(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.with(visitor.ast());
+        <Option<ObjectLit> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for BlockStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_block_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.stmts(visitor.ast());
+        <TypedSubRange<Stmt> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Stmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Block(it) => <BlockStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Empty(it) => <EmptyStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Debugger(it) => {
+                <DebuggerStmt as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::With(it) => <WithStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Return(it) => {
+                <ReturnStmt as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Labeled(it) => {
+                <LabeledStmt as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Break(it) => <BreakStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Continue(it) => {
+                <ContinueStmt as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::If(it) => <IfStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Switch(it) => {
+                <SwitchStmt as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Throw(it) => <ThrowStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Try(it) => <TryStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::While(it) => <WhileStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::DoWhile(it) => {
+                <DoWhileStmt as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::For(it) => <ForStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::ForIn(it) => <ForInStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::ForOf(it) => <ForOfStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Decl(it) => <Decl as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Expr(it) => <ExprStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ExprStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_expr_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for EmptyStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_empty_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for DebuggerStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_debugger_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for WithStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_with_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.obj(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ReturnStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_return_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.arg(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for LabeledStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_labeled_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.label(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for BreakStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_break_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.label(visitor.ast());
+        <Option<Ident> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ContinueStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_continue_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.label(visitor.ast());
+        <Option<Ident> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for IfStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_if_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.test(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.cons(visitor.ast());
+        <Stmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.alt(visitor.ast());
+        <Option<Stmt> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for SwitchStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_switch_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.discriminant(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.cases(visitor.ast());
+        <TypedSubRange<SwitchCase> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ThrowStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_throw_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.arg(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TryStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_try_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.block(visitor.ast());
+        <BlockStmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.handler(visitor.ast());
+        <Option<CatchClause> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.finalizer(visitor.ast());
+        <Option<BlockStmt> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for WhileStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_while_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.test(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for DoWhileStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_do_while_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.test(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ForStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_for_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.init(visitor.ast());
+        <Option<VarDeclOrExpr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.test(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.update(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ForInStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_for_in_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.left(visitor.ast());
+        <ForHead as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.right(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ForOfStmt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_for_of_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.is_await(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.left(visitor.ast());
+        <ForHead as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.right(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for SwitchCase {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_switch_case(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.test(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.cons(visitor.ast());
+        <TypedSubRange<Stmt> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for CatchClause {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_catch_clause(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.param(visitor.ast());
+        <Option<Pat> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <BlockStmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ForHead {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_for_head(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::VarDecl(it) => <VarDecl as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::UsingDecl(it) => {
+                <UsingDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Pat(it) => <Pat as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for VarDeclOrExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_var_decl_or_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::VarDecl(it) => <VarDecl as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Expr(it) => <Expr as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Decl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Class(it) => <ClassDecl as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Fn(it) => <FnDecl as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Var(it) => <VarDecl as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Using(it) => <UsingDecl as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::TsInterface(it) => {
+                <TsInterfaceDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsTypeAlias(it) => {
+                <TsTypeAliasDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsEnum(it) => {
+                <TsEnumDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsModule(it) => {
+                <TsModuleDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for FnDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_fn_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.ident(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.function(visitor.ast());
+        <Function as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ClassDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_class_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.ident(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.class(visitor.ast());
+        <Class as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for VarDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_var_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.kind(visitor.ast());
+        <VarDeclKind as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.decls(visitor.ast());
+        <TypedSubRange<VarDeclarator> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for VarDeclarator {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_var_declarator(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.name(visitor.ast());
+        <Pat as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.init(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.definite(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for UsingDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_using_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.is_await(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.decls(visitor.ast());
+        <TypedSubRange<VarDeclarator> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Expr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::This(it) => <ThisExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Array(it) => <ArrayLit as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Object(it) => <ObjectLit as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Fn(it) => <FnExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Unary(it) => <UnaryExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Update(it) => {
+                <UpdateExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Bin(it) => <BinExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Assign(it) => {
+                <AssignExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Member(it) => {
+                <MemberExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::SuperProp(it) => {
+                <SuperPropExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Cond(it) => <CondExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Call(it) => <CallExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::New(it) => <NewExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Seq(it) => <SeqExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Ident(it) => <Ident as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Lit(it) => <Lit as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Tpl(it) => <Tpl as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::TaggedTpl(it) => {
+                <TaggedTpl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Arrow(it) => <ArrowExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Class(it) => <ClassExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Yield(it) => <YieldExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::MetaProp(it) => {
+                <MetaPropExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Await(it) => <AwaitExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Paren(it) => <ParenExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::JSXMember(it) => {
+                <JSXMemberExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::JSXNamespacedName(it) => {
+                <JSXNamespacedName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::JSXEmpty(it) => {
+                <JSXEmptyExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::JSXElement(it) => {
+                <JSXElement as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::JSXFragment(it) => {
+                <JSXFragment as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsTypeAssertion(it) => {
+                <TsTypeAssertion as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsConstAssertion(it) => {
+                <TsConstAssertion as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsNonNull(it) => {
+                <TsNonNullExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsAs(it) => <TsAsExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::TsInstantiation(it) => {
+                <TsInstantiation as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsSatisfies(it) => {
+                <TsSatisfiesExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::PrivateName(it) => {
+                <PrivateName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::OptChain(it) => {
+                <OptChainExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Invalid(it) => <Invalid as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ThisExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_this_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ArrayLit {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_array_lit(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.elems(visitor.ast());
+        <TypedSubRange<Option<ExprOrSpread>> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ObjectLit {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_object_lit(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.props(visitor.ast());
+        <TypedSubRange<PropOrSpread> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for PropOrSpread {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_prop_or_spread(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::SpreadElement(it) => {
+                <SpreadElement as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Prop(it) => <Prop as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for SpreadElement {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_spread_element(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.dot_3_token(visitor.ast());
+        <Span as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for UnaryExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_unary_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.op(visitor.ast());
+        <UnaryOp as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.arg(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for UpdateExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_update_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.op(visitor.ast());
+        <UpdateOp as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.prefix(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.arg(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for BinExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_bin_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.op(visitor.ast());
+        <BinaryOp as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.left(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.right(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for FnExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_fn_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.ident(visitor.ast());
+        <Option<Ident> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.function(visitor.ast());
+        <Function as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ClassExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_class_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.ident(visitor.ast());
+        <Option<Ident> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.class(visitor.ast());
+        <Class as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for AssignExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_assign_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.op(visitor.ast());
+        <AssignOp as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.left(visitor.ast());
+        <AssignTarget as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.right(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for MemberExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_member_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.obj(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.prop(visitor.ast());
+        <MemberProp as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for MemberProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_member_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => <IdentName as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::PrivateName(it) => {
+                <PrivateName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Computed(it) => {
+                <ComputedPropName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for SuperPropExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_super_prop_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.obj(visitor.ast());
+        <Super as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.prop(visitor.ast());
+        <SuperProp as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for SuperProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_super_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => <IdentName as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Computed(it) => {
+                <ComputedPropName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for CondExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_cond_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.test(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.cons(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.alt(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for CallExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_call_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.callee(visitor.ast());
+        <Callee as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.args(visitor.ast());
+        <TypedSubRange<ExprOrSpread> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for NewExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_new_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.callee(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.args(visitor.ast());
+        <Option<TypedSubRange<ExprOrSpread>> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for SeqExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_seq_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.exprs(visitor.ast());
+        <TypedSubRange<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ArrowExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_arrow_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<Pat> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <BlockStmtOrExpr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_async(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_generator(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.return_type(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for YieldExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_yield_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.arg(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.delegate(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for MetaPropExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_meta_prop_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.kind(visitor.ast());
+        <MetaPropKind as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for AwaitExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_await_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.arg(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Tpl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_tpl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.exprs(visitor.ast());
+        <TypedSubRange<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.quasis(visitor.ast());
+        <TypedSubRange<TplElement> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TaggedTpl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_tagged_tpl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.tag(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.tpl(visitor.ast());
+        <Tpl as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TplElement {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_tpl_element(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.tail(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.cooked(visitor.ast());
+        <OptionalWtf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.raw(visitor.ast());
+        <Utf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ParenExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_paren_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Callee {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_callee(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Super(it) => <Super as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Import(it) => <Import as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Expr(it) => <Expr as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Super {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_super(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Import {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_import(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.phase(visitor.ast());
+        <ImportPhase as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ExprOrSpread {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_expr_or_spread(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.spread(visitor.ast());
+        <Option<SpreadDot3Token> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for SpreadDot3Token {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_spread_dot_3_token(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for BlockStmtOrExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_block_stmt_or_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::BlockStmt(it) => {
+                <BlockStmt as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Expr(it) => <Expr as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for AssignTarget {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_assign_target(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Simple(it) => {
+                <SimpleAssignTarget as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Pat(it) => {
+                <AssignTargetPat as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for AssignTargetPat {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_assign_target_pat(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Array(it) => <ArrayPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Object(it) => <ObjectPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Invalid(it) => <Invalid as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for SimpleAssignTarget {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_simple_assign_target(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => {
+                <BindingIdent as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Member(it) => {
+                <MemberExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::SuperProp(it) => {
+                <SuperPropExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Paren(it) => <ParenExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::OptChain(it) => {
+                <OptChainExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsAs(it) => <TsAsExpr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::TsSatisfies(it) => {
+                <TsSatisfiesExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsNonNull(it) => {
+                <TsNonNullExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsTypeAssertion(it) => {
+                <TsTypeAssertion as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsInstantiation(it) => {
+                <TsInstantiation as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Invalid(it) => <Invalid as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for OptChainExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_chain_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.base(visitor.ast());
+        <OptChainBase as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for OptChainBase {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_chain_base(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Member(it) => {
+                <MemberExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Call(it) => <OptCall as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for OptCall {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_call(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.callee(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.args(visitor.ast());
+        <TypedSubRange<ExprOrSpread> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Invalid {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_invalid(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Function {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_function(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<Param> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.body(visitor.ast());
+        <Option<BlockStmt> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_generator(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_async(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.return_type(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Param {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_param(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.pat(visitor.ast());
+        <Pat as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ParamOrTsParamProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_param_or_ts_param_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::TsParamProp(it) => {
+                <TsParamProp as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Param(it) => <Param as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Class {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_class(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.body(visitor.ast());
+        <TypedSubRange<ClassMember> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.super_class(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.super_type_params(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.implements(visitor.ast());
+        <TypedSubRange<TsExprWithTypeArgs> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ClassMember {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_class_member(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Constructor(it) => {
+                <Constructor as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Method(it) => {
+                <ClassMethod as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::PrivateMethod(it) => {
+                <PrivateMethod as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::ClassProp(it) => {
+                <ClassProp as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::PrivateProp(it) => {
+                <PrivateProp as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Empty(it) => <EmptyStmt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::StaticBlock(it) => {
+                <StaticBlock as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::AutoAccessor(it) => {
+                <AutoAccessor as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsIndexSignature(it) => {
+                <TsIndexSignature as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ClassProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_class_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.value(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.definite(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for PrivateProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_private_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <PrivateName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.value(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.definite(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ClassMethod {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_class_method(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.function(visitor.ast());
+        <Function as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.kind(visitor.ast());
+        <MethodKind as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for PrivateMethod {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_private_method(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <PrivateName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.function(visitor.ast());
+        <Function as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.kind(visitor.ast());
+        <MethodKind as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Constructor {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_constructor(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<ParamOrTsParamProp> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.body(visitor.ast());
+        <Option<BlockStmt> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Decorator {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_decorator(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for StaticBlock {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_static_block(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.body(visitor.ast());
+        <BlockStmt as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Key {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_key(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Private(it) => {
+                <PrivateName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Public(it) => <PropName as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for AutoAccessor {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_auto_accessor(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <Key as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.value(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.definite(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Prop {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Shorthand(it) => <Ident as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::KeyValue(it) => {
+                <KeyValueProp as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Assign(it) => {
+                <AssignProp as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Getter(it) => {
+                <GetterProp as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Setter(it) => {
+                <SetterProp as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Method(it) => {
+                <MethodProp as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for KeyValueProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_key_value_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.value(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for AssignProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_assign_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.value(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for GetterProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_getter_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <Option<BlockStmt> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for SetterProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_setter_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.this_param(visitor.ast());
+        <Option<Pat> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.param(visitor.ast());
+        <Pat as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <Option<BlockStmt> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for MethodProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_method_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.function(visitor.ast());
+        <Function as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for PropName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_prop_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => <IdentName as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Str(it) => <Str as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Num(it) => <Number as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Computed(it) => {
+                <ComputedPropName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::BigInt(it) => <BigInt as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ComputedPropName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_computed_prop_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Pat {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_pat(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => {
+                <BindingIdent as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Array(it) => <ArrayPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Rest(it) => <RestPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Object(it) => <ObjectPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Assign(it) => <AssignPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Invalid(it) => <Invalid as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Expr(it) => <Expr as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ArrayPat {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_array_pat(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.elems(visitor.ast());
+        <TypedSubRange<Option<Pat>> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ObjectPat {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_object_pat(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.props(visitor.ast());
+        <TypedSubRange<ObjectPatProp> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for AssignPat {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_assign_pat(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.left(visitor.ast());
+        <Pat as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.right(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for RestPat {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_rest_pat(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.dot_3_token(visitor.ast());
+        <Span as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.arg(visitor.ast());
+        <Pat as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for ObjectPatProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_object_pat_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::KeyValue(it) => {
+                <KeyValuePatProp as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Assign(it) => {
+                <AssignPatProp as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Rest(it) => <RestPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for KeyValuePatProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_key_value_pat_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.value(visitor.ast());
+        <Pat as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for AssignPatProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_assign_pat_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <BindingIdent as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.value(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Ident {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ident(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.sym(visitor.ast());
+        <Utf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for IdentName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ident_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.sym(visitor.ast());
+        <Utf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for PrivateName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_private_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.name(visitor.ast());
+        <Utf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for BindingIdent {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_binding_ident(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Lit {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_lit(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Str(it) => <Str as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Bool(it) => <Bool as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Null(it) => <Null as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Num(it) => <Number as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::BigInt(it) => <BigInt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Regex(it) => <Regex as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Str {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_str(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.value(visitor.ast());
+        <Wtf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.raw(visitor.ast());
+        <OptionalUtf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Bool {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_bool(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.value(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Null {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_null(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Number {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_number(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.value(visitor.ast());
+        <f64 as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.raw(visitor.ast());
+        <OptionalUtf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for BigInt {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_big_int(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.value(visitor.ast());
+        <BigIntId as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.raw(visitor.ast());
+        <OptionalUtf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Regex {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_regex(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.exp(visitor.ast());
+        <Utf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.flags(visitor.ast());
+        <Utf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXObject {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_object(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::JSXMemberExpr(it) => {
+                <JSXMemberExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Ident(it) => <Ident as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXMemberExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_member_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.obj(visitor.ast());
+        <JSXObject as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.prop(visitor.ast());
+        <IdentName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXNamespacedName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_namespaced_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.ns(visitor.ast());
+        <IdentName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.name(visitor.ast());
+        <IdentName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXEmptyExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_empty_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXExprContainer {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_expr_container(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <JSXExpr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::JSXEmptyExpr(it) => {
+                <JSXEmptyExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Expr(it) => <Expr as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXSpreadChild {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_spread_child(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXElementName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_element_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => <Ident as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::JSXMemberExpr(it) => {
+                <JSXMemberExpr as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::JSXNamespacedName(it) => {
+                <JSXNamespacedName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXOpeningElement {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_opening_element(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.name(visitor.ast());
+        <JSXElementName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.attrs(visitor.ast());
+        <TypedSubRange<JSXAttrOrSpread> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.self_closing(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXAttrOrSpread {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_attr_or_spread(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::JSXAttr(it) => <JSXAttr as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::SpreadElement(it) => {
+                <SpreadElement as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXClosingElement {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_closing_element(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.name(visitor.ast());
+        <JSXElementName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXAttr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_attr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.name(visitor.ast());
+        <JSXAttrName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.value(visitor.ast());
+        <Option<JSXAttrValue> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXAttrName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_attr_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => <IdentName as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::JSXNamespacedName(it) => {
+                <JSXNamespacedName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXAttrValue {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_attr_value(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Str(it) => <Str as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::JSXExprContainer(it) => {
+                <JSXExprContainer as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::JSXElement(it) => {
+                <JSXElement as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::JSXFragment(it) => {
+                <JSXFragment as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXText {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_text(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.value(visitor.ast());
+        <Utf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.raw(visitor.ast());
+        <Utf8Ref as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXElement {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_element(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.opening(visitor.ast());
+        <JSXOpeningElement as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.children(visitor.ast());
+        <TypedSubRange<JSXElementChild> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.closing(visitor.ast());
+        <Option<JSXClosingElement> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXElementChild {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_element_child(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::JSXText(it) => <JSXText as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::JSXExprContainer(it) => {
+                <JSXExprContainer as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::JSXSpreadChild(it) => {
+                <JSXSpreadChild as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::JSXElement(it) => {
+                <JSXElement as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::JSXFragment(it) => {
+                <JSXFragment as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXFragment {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_fragment(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.opening(visitor.ast());
+        <JSXOpeningFragment as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.children(visitor.ast());
+        <TypedSubRange<JSXElementChild> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.closing(visitor.ast());
+        <JSXClosingFragment as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXOpeningFragment {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_opening_fragment(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for JSXClosingFragment {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_closing_fragment(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeAnn {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_ann(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeParamDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_param_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsTypeParam> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeParam {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_param(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.name(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_in(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_out(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_const(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.constraint(visitor.ast());
+        <Option<TsType> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.default(visitor.ast());
+        <Option<TsType> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeParamInstantiation {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_param_instantiation(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsType> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsParamProp {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_param_prop(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.param(visitor.ast());
+        <TsParamPropParam as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsParamPropParam {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_param_prop_param(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => {
+                <BindingIdent as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Assign(it) => <AssignPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsQualifiedName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_qualified_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.left(visitor.ast());
+        <TsEntityName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.right(visitor.ast());
+        <IdentName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsEntityName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_entity_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::TsQualifiedName(it) => {
+                <TsQualifiedName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Ident(it) => <Ident as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeElement {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_element(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::TsCallSignatureDecl(it) => {
+                <TsCallSignatureDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsConstructSignatureDecl(it) => {
+                <TsConstructSignatureDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsPropertySignature(it) => {
+                <TsPropertySignature as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsGetterSignature(it) => {
+                <TsGetterSignature as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsSetterSignature(it) => {
+                <TsSetterSignature as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsMethodSignature(it) => {
+                <TsMethodSignature as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsIndexSignature(it) => {
+                <TsIndexSignature as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsCallSignatureDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_call_signature_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsConstructSignatureDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_construct_signature_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsPropertySignature {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_property_signature(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.key(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.computed(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsGetterSignature {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_getter_signature(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.computed(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsSetterSignature {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_setter_signature(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.computed(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.param(visitor.ast());
+        <TsFnParam as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsMethodSignature {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_method_signature(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.key(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.computed(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsIndexSignature {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_index_signature(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::TsKeywordType(it) => {
+                <TsKeywordType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsThisType(it) => {
+                <TsThisType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsFnOrConstructorType(it) => {
+                <TsFnOrConstructorType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsTypeRef(it) => {
+                <TsTypeRef as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsTypeQuery(it) => {
+                <TsTypeQuery as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsTypeLit(it) => {
+                <TsTypeLit as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsArrayType(it) => {
+                <TsArrayType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsTupleType(it) => {
+                <TsTupleType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsOptionalType(it) => {
+                <TsOptionalType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsRestType(it) => {
+                <TsRestType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsUnionOrIntersectionType(it) => {
+                <TsUnionOrIntersectionType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsConditionalType(it) => {
+                <TsConditionalType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsInferType(it) => {
+                <TsInferType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsParenthesizedType(it) => {
+                <TsParenthesizedType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsTypeOperator(it) => {
+                <TsTypeOperator as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsIndexedAccessType(it) => {
+                <TsIndexedAccessType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsMappedType(it) => {
+                <TsMappedType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsLitType(it) => {
+                <TsLitType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsTypePredicate(it) => {
+                <TsTypePredicate as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsImportType(it) => {
+                <TsImportType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsFnOrConstructorType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_fn_or_constructor_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::TsFnType(it) => {
+                <TsFnType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsConstructorType(it) => {
+                <TsConstructorType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsKeywordType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_keyword_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.kind(visitor.ast());
+        <TsKeywordTypeKind as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsThisType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_this_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsFnParam {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_fn_param(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => {
+                <BindingIdent as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Array(it) => <ArrayPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Rest(it) => <RestPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Object(it) => <ObjectPat as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsFnType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_fn_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <TsTypeAnn as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsConstructorType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_constructor_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <TsTypeAnn as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeRef {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_ref(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.type_name(visitor.ast());
+        <TsEntityName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypePredicate {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_predicate(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.asserts(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.param_name(visitor.ast());
+        <TsThisTypeOrIdent as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsThisTypeOrIdent {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_this_type_or_ident(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::TsThisType(it) => {
+                <TsThisType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Ident(it) => <Ident as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeQuery {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_query(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr_name(visitor.ast());
+        <TsTypeQueryExpr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeQueryExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_query_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::TsEntityName(it) => {
+                <TsEntityName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::Import(it) => {
+                <TsImportType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsImportCallOptions {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_import_call_options(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.with(visitor.ast());
+        <ObjectLit as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsImportType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_import_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.arg(visitor.ast());
+        <Str as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.qualifier(visitor.ast());
+        <Option<TsEntityName> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.attributes(visitor.ast());
+        <Option<TsImportCallOptions> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeLit {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_lit(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.members(visitor.ast());
+        <TypedSubRange<TsTypeElement> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsArrayType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_array_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.elem_type(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTupleType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_tuple_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.elem_types(visitor.ast());
+        <TypedSubRange<TsTupleElement> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTupleElement {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_tuple_element(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.label(visitor.ast());
+        <Option<Pat> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.ty(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsOptionalType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_optional_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsRestType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_rest_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsUnionOrIntersectionType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_union_or_intersection_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::TsUnionType(it) => {
+                <TsUnionType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsIntersectionType(it) => {
+                <TsIntersectionType as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsUnionType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_union_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.types(visitor.ast());
+        <TypedSubRange<TsType> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsIntersectionType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_intersection_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.types(visitor.ast());
+        <TypedSubRange<TsType> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsConditionalType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_conditional_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.check_type(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.extends_type(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.true_type(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.false_type(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsInferType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_infer_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.type_param(visitor.ast());
+        <TsTypeParam as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsParenthesizedType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_parenthesized_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeOperator {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_operator(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.op(visitor.ast());
+        <TsTypeOperatorOp as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsIndexedAccessType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_indexed_access_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.obj_type(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.index_type(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsMappedType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_mapped_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.readonly(visitor.ast());
+        <Option<TruePlusMinus> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_param(visitor.ast());
+        <TsTypeParam as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.name_type(visitor.ast());
+        <Option<TsType> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.optional(visitor.ast());
+        <Option<TruePlusMinus> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsType> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsLitType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_lit_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.lit(visitor.ast());
+        <TsLit as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsLit {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_lit(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Number(it) => <Number as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Str(it) => <Str as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Bool(it) => <Bool as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::BigInt(it) => <BigInt as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Tpl(it) => <TsTplLitType as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTplLitType {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_tpl_lit_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.types(visitor.ast());
+        <TypedSubRange<TsType> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.quasis(visitor.ast());
+        <TypedSubRange<TplElement> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsInterfaceDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_interface_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.extends(visitor.ast());
+        <TypedSubRange<TsExprWithTypeArgs> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        let field_value = self.body(visitor.ast());
+        <TsInterfaceBody as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsInterfaceBody {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_interface_body(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.body(visitor.ast());
+        <TypedSubRange<TsTypeElement> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsExprWithTypeArgs {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_expr_with_type_args(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeAliasDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_alias_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsEnumDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_enum_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_const(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.members(visitor.ast());
+        <TypedSubRange<TsEnumMember> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsEnumMember {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_enum_member(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.id(visitor.ast());
+        <TsEnumMemberId as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.init(visitor.ast());
+        <Option<Expr> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsEnumMemberId {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_enum_member_id(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => <Ident as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Str(it) => <Str as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsModuleDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_module_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.global(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.namespace(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.id(visitor.ast());
+        <TsModuleName as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <Option<TsNamespaceBody> as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsNamespaceBody {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_namespace_body(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::TsModuleBlock(it) => {
+                <TsModuleBlock as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsNamespaceDecl(it) => {
+                <TsNamespaceDecl as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsModuleBlock {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_module_block(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.body(visitor.ast());
+        <TypedSubRange<ModuleItem> as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsNamespaceDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_namespace_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.global(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.body(visitor.ast());
+        <TsNamespaceBody as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsModuleName {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_module_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::Ident(it) => <Ident as VisitControlWith<V>>::visit_with_control(it, visitor),
+            Self::Str(it) => <Str as VisitControlWith<V>>::visit_with_control(it, visitor),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsImportEqualsDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_import_equals_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.is_export(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.is_type_only(visitor.ast());
+        <bool as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.module_ref(visitor.ast());
+        <TsModuleRef as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsModuleRef {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_module_ref(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Self::TsEntityName(it) => {
+                <TsEntityName as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+            Self::TsExternalModuleRef(it) => {
+                <TsExternalModuleRef as VisitControlWith<V>>::visit_with_control(it, visitor)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsExternalModuleRef {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_external_module_ref(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Str as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsExportAssignment {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_export_assignment(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsNamespaceExportDecl {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_namespace_export_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsAsExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_as_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsTypeAssertion {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_assertion(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsNonNullExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_non_null_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsSatisfiesExpr {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_satisfies_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsConstAssertion {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_const_assertion(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TsInstantiation {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_instantiation(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+        let field_value = self.type_args(visitor.ast());
+        <TsTypeParamInstantiation as VisitControlWith<V>>::visit_with_control(
+            field_value,
+            visitor,
+        )?;
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<ModuleItem> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_module_items(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<Stmt> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_stmts(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<ImportSpecifier> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_import_specifiers(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<ObjectLit> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_object_lit(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<ModuleExportName> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_module_export_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<ExportSpecifier> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_export_specifiers(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<Str> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_str(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<Expr> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<Ident> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_ident(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<Stmt> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<SwitchCase> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_switch_cases(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<CatchClause> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_catch_clause(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<BlockStmt> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_block_stmt(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<VarDeclOrExpr> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_var_decl_or_expr(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<Pat> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_pat(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<VarDeclarator> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_var_declarators(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<ExprOrSpread> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_expr_or_spread(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<Option<ExprOrSpread>> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_vec_expr_or_spreads(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<PropOrSpread> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_prop_or_spreads(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<ExprOrSpread> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_expr_or_spreads(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<TsTypeParamInstantiation> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_ts_type_param_instantiation(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<TypedSubRange<ExprOrSpread>> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_expr_or_spreads(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<Expr> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_exprs(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<Pat> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_pats(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<TsTypeParamDecl> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_ts_type_param_decl(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<TsTypeAnn> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_ts_type_ann(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<TplElement> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_tpl_elements(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<SpreadDot3Token> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_spread_dot_3_token(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<Param> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_params(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<Decorator> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_decorators(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<ClassMember> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_class_members(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<TsExprWithTypeArgs> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_expr_with_type_argss(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<Accessibility> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_accessibility(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<ParamOrTsParamProp> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_param_or_ts_param_props(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<Option<Pat>> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_vec_pats(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<ObjectPatProp> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_object_pat_props(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<JSXAttrOrSpread> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_attr_or_spreads(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<JSXAttrValue> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_jsx_attr_value(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<JSXElementChild> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_jsx_element_childs(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<JSXClosingElement> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_jsx_closing_element(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<TsTypeParam> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_params(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<TsType> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_ts_type(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<TsType> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_types(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<TsFnParam> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_fn_params(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<TsEntityName> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_ts_entity_name(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<TsImportCallOptions> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_ts_import_call_options(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<TsTypeElement> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_type_elements(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<TsTupleElement> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_tuple_elements(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<TruePlusMinus> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_true_plus_minus(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for TypedSubRange<TsEnumMember> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_ts_enum_members(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        for child_idx in self.iter() {
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_control(visitor)?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+impl<V: ?Sized + VisitControl> VisitControlWith<V> for Option<TsNamespaceBody> {
+    fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        <V as VisitControl>::visit_opt_ts_namespace_body(visitor, self)
+    }
+    fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+        match self {
+            Some(it) => it.visit_with_control(visitor),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
@@ -0,0 +1,8959 @@
+#![allow(unused, clippy::useless_conversion, clippy::single_match)]
+use crate::*;
+pub trait VisitHooks {
+    #[inline]
+    fn enter_node(&mut self, node_id: NodeId, ast: &Ast) {}
+    #[inline]
+    fn leave_node(&mut self, node_id: NodeId, ast: &Ast) {}
+    #[inline]
+    fn enter_module(&mut self, node: Module, ast: &Ast) {}
+    #[inline]
+    fn leave_module(&mut self, node: Module, ast: &Ast) {}
+    #[inline]
+    fn enter_script(&mut self, node: Script, ast: &Ast) {}
+    #[inline]
+    fn leave_script(&mut self, node: Script, ast: &Ast) {}
+    #[inline]
+    fn enter_import_decl(&mut self, node: ImportDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_import_decl(&mut self, node: ImportDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_import_named_specifier(&mut self, node: ImportNamedSpecifier, ast: &Ast) {}
+    #[inline]
+    fn leave_import_named_specifier(&mut self, node: ImportNamedSpecifier, ast: &Ast) {}
+    #[inline]
+    fn enter_import_default_specifier(&mut self, node: ImportDefaultSpecifier, ast: &Ast) {}
+    #[inline]
+    fn leave_import_default_specifier(&mut self, node: ImportDefaultSpecifier, ast: &Ast) {}
+    #[inline]
+    fn enter_import_star_as_specifier(&mut self, node: ImportStarAsSpecifier, ast: &Ast) {}
+    #[inline]
+    fn leave_import_star_as_specifier(&mut self, node: ImportStarAsSpecifier, ast: &Ast) {}
+    #[inline]
+    fn enter_export_decl(&mut self, node: ExportDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_export_decl(&mut self, node: ExportDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_named_export(&mut self, node: NamedExport, ast: &Ast) {}
+    #[inline]
+    fn leave_named_export(&mut self, node: NamedExport, ast: &Ast) {}
+    #[inline]
+    fn enter_export_namespace_specifier(&mut self, node: ExportNamespaceSpecifier, ast: &Ast) {}
+    #[inline]
+    fn leave_export_namespace_specifier(&mut self, node: ExportNamespaceSpecifier, ast: &Ast) {}
+    #[inline]
+    fn enter_export_default_specifier(&mut self, node: ExportDefaultSpecifier, ast: &Ast) {}
+    #[inline]
+    fn leave_export_default_specifier(&mut self, node: ExportDefaultSpecifier, ast: &Ast) {}
+    #[inline]
+    fn enter_export_named_specifier(&mut self, node: ExportNamedSpecifier, ast: &Ast) {}
+    #[inline]
+    fn leave_export_named_specifier(&mut self, node: ExportNamedSpecifier, ast: &Ast) {}
+    #[inline]
+    fn enter_export_default_decl(&mut self, node: ExportDefaultDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_export_default_decl(&mut self, node: ExportDefaultDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_export_default_expr(&mut self, node: ExportDefaultExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_export_default_expr(&mut self, node: ExportDefaultExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_export_all(&mut self, node: ExportAll, ast: &Ast) {}
+    #[inline]
+    fn leave_export_all(&mut self, node: ExportAll, ast: &Ast) {}
+    #[inline]
+    fn enter_block_stmt(&mut self, node: BlockStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_block_stmt(&mut self, node: BlockStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_expr_stmt(&mut self, node: ExprStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_expr_stmt(&mut self, node: ExprStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_empty_stmt(&mut self, node: EmptyStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_empty_stmt(&mut self, node: EmptyStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_debugger_stmt(&mut self, node: DebuggerStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_debugger_stmt(&mut self, node: DebuggerStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_with_stmt(&mut self, node: WithStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_with_stmt(&mut self, node: WithStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_return_stmt(&mut self, node: ReturnStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_return_stmt(&mut self, node: ReturnStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_labeled_stmt(&mut self, node: LabeledStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_labeled_stmt(&mut self, node: LabeledStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_break_stmt(&mut self, node: BreakStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_break_stmt(&mut self, node: BreakStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_continue_stmt(&mut self, node: ContinueStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_continue_stmt(&mut self, node: ContinueStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_if_stmt(&mut self, node: IfStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_if_stmt(&mut self, node: IfStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_switch_stmt(&mut self, node: SwitchStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_switch_stmt(&mut self, node: SwitchStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_throw_stmt(&mut self, node: ThrowStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_throw_stmt(&mut self, node: ThrowStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_try_stmt(&mut self, node: TryStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_try_stmt(&mut self, node: TryStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_while_stmt(&mut self, node: WhileStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_while_stmt(&mut self, node: WhileStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_do_while_stmt(&mut self, node: DoWhileStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_do_while_stmt(&mut self, node: DoWhileStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_for_stmt(&mut self, node: ForStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_for_stmt(&mut self, node: ForStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_for_in_stmt(&mut self, node: ForInStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_for_in_stmt(&mut self, node: ForInStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_for_of_stmt(&mut self, node: ForOfStmt, ast: &Ast) {}
+    #[inline]
+    fn leave_for_of_stmt(&mut self, node: ForOfStmt, ast: &Ast) {}
+    #[inline]
+    fn enter_switch_case(&mut self, node: SwitchCase, ast: &Ast) {}
+    #[inline]
+    fn leave_switch_case(&mut self, node: SwitchCase, ast: &Ast) {}
+    #[inline]
+    fn enter_catch_clause(&mut self, node: CatchClause, ast: &Ast) {}
+    #[inline]
+    fn leave_catch_clause(&mut self, node: CatchClause, ast: &Ast) {}
+    #[inline]
+    fn enter_fn_decl(&mut self, node: FnDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_fn_decl(&mut self, node: FnDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_class_decl(&mut self, node: ClassDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_class_decl(&mut self, node: ClassDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_var_decl(&mut self, node: VarDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_var_decl(&mut self, node: VarDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_var_declarator(&mut self, node: VarDeclarator, ast: &Ast) {}
+    #[inline]
+    fn leave_var_declarator(&mut self, node: VarDeclarator, ast: &Ast) {}
+    #[inline]
+    fn enter_using_decl(&mut self, node: UsingDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_using_decl(&mut self, node: UsingDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_this_expr(&mut self, node: ThisExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_this_expr(&mut self, node: ThisExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_array_lit(&mut self, node: ArrayLit, ast: &Ast) {}
+    #[inline]
+    fn leave_array_lit(&mut self, node: ArrayLit, ast: &Ast) {}
+    #[inline]
+    fn enter_object_lit(&mut self, node: ObjectLit, ast: &Ast) {}
+    #[inline]
+    fn leave_object_lit(&mut self, node: ObjectLit, ast: &Ast) {}
+    #[inline]
+    fn enter_spread_element(&mut self, node: SpreadElement, ast: &Ast) {}
+    #[inline]
+    fn leave_spread_element(&mut self, node: SpreadElement, ast: &Ast) {}
+    #[inline]
+    fn enter_unary_expr(&mut self, node: UnaryExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_unary_expr(&mut self, node: UnaryExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_update_expr(&mut self, node: UpdateExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_update_expr(&mut self, node: UpdateExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_bin_expr(&mut self, node: BinExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_bin_expr(&mut self, node: BinExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_fn_expr(&mut self, node: FnExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_fn_expr(&mut self, node: FnExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_class_expr(&mut self, node: ClassExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_class_expr(&mut self, node: ClassExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_assign_expr(&mut self, node: AssignExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_assign_expr(&mut self, node: AssignExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_member_expr(&mut self, node: MemberExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_member_expr(&mut self, node: MemberExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_super_prop_expr(&mut self, node: SuperPropExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_super_prop_expr(&mut self, node: SuperPropExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_cond_expr(&mut self, node: CondExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_cond_expr(&mut self, node: CondExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_call_expr(&mut self, node: CallExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_call_expr(&mut self, node: CallExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_new_expr(&mut self, node: NewExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_new_expr(&mut self, node: NewExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_seq_expr(&mut self, node: SeqExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_seq_expr(&mut self, node: SeqExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_arrow_expr(&mut self, node: ArrowExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_arrow_expr(&mut self, node: ArrowExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_yield_expr(&mut self, node: YieldExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_yield_expr(&mut self, node: YieldExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_meta_prop_expr(&mut self, node: MetaPropExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_meta_prop_expr(&mut self, node: MetaPropExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_await_expr(&mut self, node: AwaitExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_await_expr(&mut self, node: AwaitExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_tpl(&mut self, node: Tpl, ast: &Ast) {}
+    #[inline]
+    fn leave_tpl(&mut self, node: Tpl, ast: &Ast) {}
+    #[inline]
+    fn enter_tagged_tpl(&mut self, node: TaggedTpl, ast: &Ast) {}
+    #[inline]
+    fn leave_tagged_tpl(&mut self, node: TaggedTpl, ast: &Ast) {}
+    #[inline]
+    fn enter_tpl_element(&mut self, node: TplElement, ast: &Ast) {}
+    #[inline]
+    fn leave_tpl_element(&mut self, node: TplElement, ast: &Ast) {}
+    #[inline]
+    fn enter_paren_expr(&mut self, node: ParenExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_paren_expr(&mut self, node: ParenExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_super(&mut self, node: Super, ast: &Ast) {}
+    #[inline]
+    fn leave_super(&mut self, node: Super, ast: &Ast) {}
+    #[inline]
+    fn enter_import(&mut self, node: Import, ast: &Ast) {}
+    #[inline]
+    fn leave_import(&mut self, node: Import, ast: &Ast) {}
+    #[inline]
+    fn enter_expr_or_spread(&mut self, node: ExprOrSpread, ast: &Ast) {}
+    #[inline]
+    fn leave_expr_or_spread(&mut self, node: ExprOrSpread, ast: &Ast) {}
+    #[inline]
+    fn enter_spread_dot_3_token(&mut self, node: SpreadDot3Token, ast: &Ast) {}
+    #[inline]
+    fn leave_spread_dot_3_token(&mut self, node: SpreadDot3Token, ast: &Ast) {}
+    #[inline]
+    fn enter_opt_chain_expr(&mut self, node: OptChainExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_opt_chain_expr(&mut self, node: OptChainExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_opt_call(&mut self, node: OptCall, ast: &Ast) {}
+    #[inline]
+    fn leave_opt_call(&mut self, node: OptCall, ast: &Ast) {}
+    #[inline]
+    fn enter_invalid(&mut self, node: Invalid, ast: &Ast) {}
+    #[inline]
+    fn leave_invalid(&mut self, node: Invalid, ast: &Ast) {}
+    #[inline]
+    fn enter_function(&mut self, node: Function, ast: &Ast) {}
+    #[inline]
+    fn leave_function(&mut self, node: Function, ast: &Ast) {}
+    #[inline]
+    fn enter_param(&mut self, node: Param, ast: &Ast) {}
+    #[inline]
+    fn leave_param(&mut self, node: Param, ast: &Ast) {}
+    #[inline]
+    fn enter_class(&mut self, node: Class, ast: &Ast) {}
+    #[inline]
+    fn leave_class(&mut self, node: Class, ast: &Ast) {}
+    #[inline]
+    fn enter_class_prop(&mut self, node: ClassProp, ast: &Ast) {}
+    #[inline]
+    fn leave_class_prop(&mut self, node: ClassProp, ast: &Ast) {}
+    #[inline]
+    fn enter_private_prop(&mut self, node: PrivateProp, ast: &Ast) {}
+    #[inline]
+    fn leave_private_prop(&mut self, node: PrivateProp, ast: &Ast) {}
+    #[inline]
+    fn enter_class_method(&mut self, node: ClassMethod, ast: &Ast) {}
+    #[inline]
+    fn leave_class_method(&mut self, node: ClassMethod, ast: &Ast) {}
+    #[inline]
+    fn enter_private_method(&mut self, node: PrivateMethod, ast: &Ast) {}
+    #[inline]
+    fn leave_private_method(&mut self, node: PrivateMethod, ast: &Ast) {}
+    #[inline]
+    fn enter_constructor(&mut self, node: Constructor, ast: &Ast) {}
+    #[inline]
+    fn leave_constructor(&mut self, node: Constructor, ast: &Ast) {}
+    #[inline]
+    fn enter_decorator(&mut self, node: Decorator, ast: &Ast) {}
+    #[inline]
+    fn leave_decorator(&mut self, node: Decorator, ast: &Ast) {}
+    #[inline]
+    fn enter_static_block(&mut self, node: StaticBlock, ast: &Ast) {}
+    #[inline]
+    fn leave_static_block(&mut self, node: StaticBlock, ast: &Ast) {}
+    #[inline]
+    fn enter_auto_accessor(&mut self, node: AutoAccessor, ast: &Ast) {}
+    #[inline]
+    fn leave_auto_accessor(&mut self, node: AutoAccessor, ast: &Ast) {}
+    #[inline]
+    fn enter_key_value_prop(&mut self, node: KeyValueProp, ast: &Ast) {}
+    #[inline]
+    fn leave_key_value_prop(&mut self, node: KeyValueProp, ast: &Ast) {}
+    #[inline]
+    fn enter_assign_prop(&mut self, node: AssignProp, ast: &Ast) {}
+    #[inline]
+    fn leave_assign_prop(&mut self, node: AssignProp, ast: &Ast) {}
+    #[inline]
+    fn enter_getter_prop(&mut self, node: GetterProp, ast: &Ast) {}
+    #[inline]
+    fn leave_getter_prop(&mut self, node: GetterProp, ast: &Ast) {}
+    #[inline]
+    fn enter_setter_prop(&mut self, node: SetterProp, ast: &Ast) {}
+    #[inline]
+    fn leave_setter_prop(&mut self, node: SetterProp, ast: &Ast) {}
+    #[inline]
+    fn enter_method_prop(&mut self, node: MethodProp, ast: &Ast) {}
+    #[inline]
+    fn leave_method_prop(&mut self, node: MethodProp, ast: &Ast) {}
+    #[inline]
+    fn enter_computed_prop_name(&mut self, node: ComputedPropName, ast: &Ast) {}
+    #[inline]
+    fn leave_computed_prop_name(&mut self, node: ComputedPropName, ast: &Ast) {}
+    #[inline]
+    fn enter_array_pat(&mut self, node: ArrayPat, ast: &Ast) {}
+    #[inline]
+    fn leave_array_pat(&mut self, node: ArrayPat, ast: &Ast) {}
+    #[inline]
+    fn enter_object_pat(&mut self, node: ObjectPat, ast: &Ast) {}
+    #[inline]
+    fn leave_object_pat(&mut self, node: ObjectPat, ast: &Ast) {}
+    #[inline]
+    fn enter_assign_pat(&mut self, node: AssignPat, ast: &Ast) {}
+    #[inline]
+    fn leave_assign_pat(&mut self, node: AssignPat, ast: &Ast) {}
+    #[inline]
+    fn enter_rest_pat(&mut self, node: RestPat, ast: &Ast) {}
+    #[inline]
+    fn leave_rest_pat(&mut self, node: RestPat, ast: &Ast) {}
+    #[inline]
+    fn enter_key_value_pat_prop(&mut self, node: KeyValuePatProp, ast: &Ast) {}
+    #[inline]
+    fn leave_key_value_pat_prop(&mut self, node: KeyValuePatProp, ast: &Ast) {}
+    #[inline]
+    fn enter_assign_pat_prop(&mut self, node: AssignPatProp, ast: &Ast) {}
+    #[inline]
+    fn leave_assign_pat_prop(&mut self, node: AssignPatProp, ast: &Ast) {}
+    #[inline]
+    fn enter_ident(&mut self, node: Ident, ast: &Ast) {}
+    #[inline]
+    fn leave_ident(&mut self, node: Ident, ast: &Ast) {}
+    #[inline]
+    fn enter_ident_name(&mut self, node: IdentName, ast: &Ast) {}
+    #[inline]
+    fn leave_ident_name(&mut self, node: IdentName, ast: &Ast) {}
+    #[inline]
+    fn enter_private_name(&mut self, node: PrivateName, ast: &Ast) {}
+    #[inline]
+    fn leave_private_name(&mut self, node: PrivateName, ast: &Ast) {}
+    #[inline]
+    fn enter_binding_ident(&mut self, node: BindingIdent, ast: &Ast) {}
+    #[inline]
+    fn leave_binding_ident(&mut self, node: BindingIdent, ast: &Ast) {}
+    #[inline]
+    fn enter_str(&mut self, node: Str, ast: &Ast) {}
+    #[inline]
+    fn leave_str(&mut self, node: Str, ast: &Ast) {}
+    #[inline]
+    fn enter_bool(&mut self, node: Bool, ast: &Ast) {}
+    #[inline]
+    fn leave_bool(&mut self, node: Bool, ast: &Ast) {}
+    #[inline]
+    fn enter_null(&mut self, node: Null, ast: &Ast) {}
+    #[inline]
+    fn leave_null(&mut self, node: Null, ast: &Ast) {}
+    #[inline]
+    fn enter_number(&mut self, node: Number, ast: &Ast) {}
+    #[inline]
+    fn leave_number(&mut self, node: Number, ast: &Ast) {}
+    #[inline]
+    fn enter_big_int(&mut self, node: BigInt, ast: &Ast) {}
+    #[inline]
+    fn leave_big_int(&mut self, node: BigInt, ast: &Ast) {}
+    #[inline]
+    fn enter_regex(&mut self, node: Regex, ast: &Ast) {}
+    #[inline]
+    fn leave_regex(&mut self, node: Regex, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_member_expr(&mut self, node: JSXMemberExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_member_expr(&mut self, node: JSXMemberExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_namespaced_name(&mut self, node: JSXNamespacedName, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_namespaced_name(&mut self, node: JSXNamespacedName, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_empty_expr(&mut self, node: JSXEmptyExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_empty_expr(&mut self, node: JSXEmptyExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_expr_container(&mut self, node: JSXExprContainer, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_expr_container(&mut self, node: JSXExprContainer, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_spread_child(&mut self, node: JSXSpreadChild, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_spread_child(&mut self, node: JSXSpreadChild, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_opening_element(&mut self, node: JSXOpeningElement, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_opening_element(&mut self, node: JSXOpeningElement, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_closing_element(&mut self, node: JSXClosingElement, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_closing_element(&mut self, node: JSXClosingElement, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_attr(&mut self, node: JSXAttr, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_attr(&mut self, node: JSXAttr, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_text(&mut self, node: JSXText, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_text(&mut self, node: JSXText, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_element(&mut self, node: JSXElement, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_element(&mut self, node: JSXElement, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_fragment(&mut self, node: JSXFragment, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_fragment(&mut self, node: JSXFragment, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_opening_fragment(&mut self, node: JSXOpeningFragment, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_opening_fragment(&mut self, node: JSXOpeningFragment, ast: &Ast) {}
+    #[inline]
+    fn enter_jsx_closing_fragment(&mut self, node: JSXClosingFragment, ast: &Ast) {}
+    #[inline]
+    fn leave_jsx_closing_fragment(&mut self, node: JSXClosingFragment, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_ann(&mut self, node: TsTypeAnn, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_ann(&mut self, node: TsTypeAnn, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_param_decl(&mut self, node: TsTypeParamDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_param_decl(&mut self, node: TsTypeParamDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_param(&mut self, node: TsTypeParam, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_param(&mut self, node: TsTypeParam, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_param_instantiation(&mut self, node: TsTypeParamInstantiation, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_param_instantiation(&mut self, node: TsTypeParamInstantiation, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_param_prop(&mut self, node: TsParamProp, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_param_prop(&mut self, node: TsParamProp, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_qualified_name(&mut self, node: TsQualifiedName, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_qualified_name(&mut self, node: TsQualifiedName, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_call_signature_decl(&mut self, node: TsCallSignatureDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_call_signature_decl(&mut self, node: TsCallSignatureDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_construct_signature_decl(&mut self, node: TsConstructSignatureDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_construct_signature_decl(&mut self, node: TsConstructSignatureDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_property_signature(&mut self, node: TsPropertySignature, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_property_signature(&mut self, node: TsPropertySignature, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_getter_signature(&mut self, node: TsGetterSignature, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_getter_signature(&mut self, node: TsGetterSignature, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_setter_signature(&mut self, node: TsSetterSignature, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_setter_signature(&mut self, node: TsSetterSignature, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_method_signature(&mut self, node: TsMethodSignature, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_method_signature(&mut self, node: TsMethodSignature, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_index_signature(&mut self, node: TsIndexSignature, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_index_signature(&mut self, node: TsIndexSignature, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_keyword_type(&mut self, node: TsKeywordType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_keyword_type(&mut self, node: TsKeywordType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_this_type(&mut self, node: TsThisType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_this_type(&mut self, node: TsThisType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_fn_type(&mut self, node: TsFnType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_fn_type(&mut self, node: TsFnType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_constructor_type(&mut self, node: TsConstructorType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_constructor_type(&mut self, node: TsConstructorType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_ref(&mut self, node: TsTypeRef, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_ref(&mut self, node: TsTypeRef, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_predicate(&mut self, node: TsTypePredicate, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_predicate(&mut self, node: TsTypePredicate, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_query(&mut self, node: TsTypeQuery, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_query(&mut self, node: TsTypeQuery, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_import_call_options(&mut self, node: TsImportCallOptions, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_import_call_options(&mut self, node: TsImportCallOptions, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_import_type(&mut self, node: TsImportType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_import_type(&mut self, node: TsImportType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_lit(&mut self, node: TsTypeLit, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_lit(&mut self, node: TsTypeLit, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_array_type(&mut self, node: TsArrayType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_array_type(&mut self, node: TsArrayType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_tuple_type(&mut self, node: TsTupleType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_tuple_type(&mut self, node: TsTupleType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_tuple_element(&mut self, node: TsTupleElement, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_tuple_element(&mut self, node: TsTupleElement, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_optional_type(&mut self, node: TsOptionalType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_optional_type(&mut self, node: TsOptionalType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_rest_type(&mut self, node: TsRestType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_rest_type(&mut self, node: TsRestType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_union_type(&mut self, node: TsUnionType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_union_type(&mut self, node: TsUnionType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_intersection_type(&mut self, node: TsIntersectionType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_intersection_type(&mut self, node: TsIntersectionType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_conditional_type(&mut self, node: TsConditionalType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_conditional_type(&mut self, node: TsConditionalType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_infer_type(&mut self, node: TsInferType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_infer_type(&mut self, node: TsInferType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_parenthesized_type(&mut self, node: TsParenthesizedType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_parenthesized_type(&mut self, node: TsParenthesizedType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_operator(&mut self, node: TsTypeOperator, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_operator(&mut self, node: TsTypeOperator, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_indexed_access_type(&mut self, node: TsIndexedAccessType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_indexed_access_type(&mut self, node: TsIndexedAccessType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_mapped_type(&mut self, node: TsMappedType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_mapped_type(&mut self, node: TsMappedType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_lit_type(&mut self, node: TsLitType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_lit_type(&mut self, node: TsLitType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_tpl_lit_type(&mut self, node: TsTplLitType, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_tpl_lit_type(&mut self, node: TsTplLitType, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_interface_decl(&mut self, node: TsInterfaceDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_interface_decl(&mut self, node: TsInterfaceDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_interface_body(&mut self, node: TsInterfaceBody, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_interface_body(&mut self, node: TsInterfaceBody, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_expr_with_type_args(&mut self, node: TsExprWithTypeArgs, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_expr_with_type_args(&mut self, node: TsExprWithTypeArgs, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_enum_decl(&mut self, node: TsEnumDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_enum_decl(&mut self, node: TsEnumDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_enum_member(&mut self, node: TsEnumMember, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_enum_member(&mut self, node: TsEnumMember, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_module_decl(&mut self, node: TsModuleDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_module_decl(&mut self, node: TsModuleDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_module_block(&mut self, node: TsModuleBlock, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_module_block(&mut self, node: TsModuleBlock, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_namespace_decl(&mut self, node: TsNamespaceDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_namespace_decl(&mut self, node: TsNamespaceDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_import_equals_decl(&mut self, node: TsImportEqualsDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_import_equals_decl(&mut self, node: TsImportEqualsDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_external_module_ref(&mut self, node: TsExternalModuleRef, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_external_module_ref(&mut self, node: TsExternalModuleRef, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_export_assignment(&mut self, node: TsExportAssignment, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_export_assignment(&mut self, node: TsExportAssignment, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_namespace_export_decl(&mut self, node: TsNamespaceExportDecl, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_namespace_export_decl(&mut self, node: TsNamespaceExportDecl, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_as_expr(&mut self, node: TsAsExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_as_expr(&mut self, node: TsAsExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_type_assertion(&mut self, node: TsTypeAssertion, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_type_assertion(&mut self, node: TsTypeAssertion, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_non_null_expr(&mut self, node: TsNonNullExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_non_null_expr(&mut self, node: TsNonNullExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_const_assertion(&mut self, node: TsConstAssertion, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_const_assertion(&mut self, node: TsConstAssertion, ast: &Ast) {}
+    #[inline]
+    fn enter_ts_instantiation(&mut self, node: TsInstantiation, ast: &Ast) {}
+    #[inline]
+    fn leave_ts_instantiation(&mut self, node: TsInstantiation, ast: &Ast) {}
+}
+impl<V: ?Sized + VisitHooks> VisitHooks for &mut V {
+    #[inline]
+    fn enter_node(&mut self, node: NodeId, ast: &Ast) {
+        (**self).enter_node(node, ast)
+    }
+    #[inline]
+    fn leave_node(&mut self, node: NodeId, ast: &Ast) {
+        (**self).leave_node(node, ast)
+    }
+    #[inline]
+    fn enter_module(&mut self, node: Module, ast: &Ast) {
+        (**self).enter_module(node, ast)
+    }
+    #[inline]
+    fn leave_module(&mut self, node: Module, ast: &Ast) {
+        (**self).leave_module(node, ast)
+    }
+    #[inline]
+    fn enter_script(&mut self, node: Script, ast: &Ast) {
+        (**self).enter_script(node, ast)
+    }
+    #[inline]
+    fn leave_script(&mut self, node: Script, ast: &Ast) {
+        (**self).leave_script(node, ast)
+    }
+    #[inline]
+    fn enter_import_decl(&mut self, node: ImportDecl, ast: &Ast) {
+        (**self).enter_import_decl(node, ast)
+    }
+    #[inline]
+    fn leave_import_decl(&mut self, node: ImportDecl, ast: &Ast) {
+        (**self).leave_import_decl(node, ast)
+    }
+    #[inline]
+    fn enter_import_named_specifier(&mut self, node: ImportNamedSpecifier, ast: &Ast) {
+        (**self).enter_import_named_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_import_named_specifier(&mut self, node: ImportNamedSpecifier, ast: &Ast) {
+        (**self).leave_import_named_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_import_default_specifier(&mut self, node: ImportDefaultSpecifier, ast: &Ast) {
+        (**self).enter_import_default_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_import_default_specifier(&mut self, node: ImportDefaultSpecifier, ast: &Ast) {
+        (**self).leave_import_default_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_import_star_as_specifier(&mut self, node: ImportStarAsSpecifier, ast: &Ast) {
+        (**self).enter_import_star_as_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_import_star_as_specifier(&mut self, node: ImportStarAsSpecifier, ast: &Ast) {
+        (**self).leave_import_star_as_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_export_decl(&mut self, node: ExportDecl, ast: &Ast) {
+        (**self).enter_export_decl(node, ast)
+    }
+    #[inline]
+    fn leave_export_decl(&mut self, node: ExportDecl, ast: &Ast) {
+        (**self).leave_export_decl(node, ast)
+    }
+    #[inline]
+    fn enter_named_export(&mut self, node: NamedExport, ast: &Ast) {
+        (**self).enter_named_export(node, ast)
+    }
+    #[inline]
+    fn leave_named_export(&mut self, node: NamedExport, ast: &Ast) {
+        (**self).leave_named_export(node, ast)
+    }
+    #[inline]
+    fn enter_export_namespace_specifier(&mut self, node: ExportNamespaceSpecifier, ast: &Ast) {
+        (**self).enter_export_namespace_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_export_namespace_specifier(&mut self, node: ExportNamespaceSpecifier, ast: &Ast) {
+        (**self).leave_export_namespace_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_export_default_specifier(&mut self, node: ExportDefaultSpecifier, ast: &Ast) {
+        (**self).enter_export_default_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_export_default_specifier(&mut self, node: ExportDefaultSpecifier, ast: &Ast) {
+        (**self).leave_export_default_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_export_named_specifier(&mut self, node: ExportNamedSpecifier, ast: &Ast) {
+        (**self).enter_export_named_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_export_named_specifier(&mut self, node: ExportNamedSpecifier, ast: &Ast) {
+        (**self).leave_export_named_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_export_default_decl(&mut self, node: ExportDefaultDecl, ast: &Ast) {
+        (**self).enter_export_default_decl(node, ast)
+    }
+    #[inline]
+    fn leave_export_default_decl(&mut self, node: ExportDefaultDecl, ast: &Ast) {
+        (**self).leave_export_default_decl(node, ast)
+    }
+    #[inline]
+    fn enter_export_default_expr(&mut self, node: ExportDefaultExpr, ast: &Ast) {
+        (**self).enter_export_default_expr(node, ast)
+    }
+    #[inline]
+    fn leave_export_default_expr(&mut self, node: ExportDefaultExpr, ast: &Ast) {
+        (**self).leave_export_default_expr(node, ast)
+    }
+    #[inline]
+    fn enter_export_all(&mut self, node: ExportAll, ast: &Ast) {
+        (**self).enter_export_all(node, ast)
+    }
+    #[inline]
+    fn leave_export_all(&mut self, node: ExportAll, ast: &Ast) {
+        (**self).leave_export_all(node, ast)
+    }
+    #[inline]
+    fn enter_block_stmt(&mut self, node: BlockStmt, ast: &Ast) {
+        (**self).enter_block_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_block_stmt(&mut self, node: BlockStmt, ast: &Ast) {
+        (**self).leave_block_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_expr_stmt(&mut self, node: ExprStmt, ast: &Ast) {
+        (**self).enter_expr_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_expr_stmt(&mut self, node: ExprStmt, ast: &Ast) {
+        (**self).leave_expr_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_empty_stmt(&mut self, node: EmptyStmt, ast: &Ast) {
+        (**self).enter_empty_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_empty_stmt(&mut self, node: EmptyStmt, ast: &Ast) {
+        (**self).leave_empty_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_debugger_stmt(&mut self, node: DebuggerStmt, ast: &Ast) {
+        (**self).enter_debugger_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_debugger_stmt(&mut self, node: DebuggerStmt, ast: &Ast) {
+        (**self).leave_debugger_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_with_stmt(&mut self, node: WithStmt, ast: &Ast) {
+        (**self).enter_with_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_with_stmt(&mut self, node: WithStmt, ast: &Ast) {
+        (**self).leave_with_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_return_stmt(&mut self, node: ReturnStmt, ast: &Ast) {
+        (**self).enter_return_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_return_stmt(&mut self, node: ReturnStmt, ast: &Ast) {
+        (**self).leave_return_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_labeled_stmt(&mut self, node: LabeledStmt, ast: &Ast) {
+        (**self).enter_labeled_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_labeled_stmt(&mut self, node: LabeledStmt, ast: &Ast) {
+        (**self).leave_labeled_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_break_stmt(&mut self, node: BreakStmt, ast: &Ast) {
+        (**self).enter_break_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_break_stmt(&mut self, node: BreakStmt, ast: &Ast) {
+        (**self).leave_break_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_continue_stmt(&mut self, node: ContinueStmt, ast: &Ast) {
+        (**self).enter_continue_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_continue_stmt(&mut self, node: ContinueStmt, ast: &Ast) {
+        (**self).leave_continue_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_if_stmt(&mut self, node: IfStmt, ast: &Ast) {
+        (**self).enter_if_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_if_stmt(&mut self, node: IfStmt, ast: &Ast) {
+        (**self).leave_if_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_switch_stmt(&mut self, node: SwitchStmt, ast: &Ast) {
+        (**self).enter_switch_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_switch_stmt(&mut self, node: SwitchStmt, ast: &Ast) {
+        (**self).leave_switch_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_throw_stmt(&mut self, node: ThrowStmt, ast: &Ast) {
+        (**self).enter_throw_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_throw_stmt(&mut self, node: ThrowStmt, ast: &Ast) {
+        (**self).leave_throw_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_try_stmt(&mut self, node: TryStmt, ast: &Ast) {
+        (**self).enter_try_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_try_stmt(&mut self, node: TryStmt, ast: &Ast) {
+        (**self).leave_try_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_while_stmt(&mut self, node: WhileStmt, ast: &Ast) {
+        (**self).enter_while_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_while_stmt(&mut self, node: WhileStmt, ast: &Ast) {
+        (**self).leave_while_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_do_while_stmt(&mut self, node: DoWhileStmt, ast: &Ast) {
+        (**self).enter_do_while_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_do_while_stmt(&mut self, node: DoWhileStmt, ast: &Ast) {
+        (**self).leave_do_while_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_for_stmt(&mut self, node: ForStmt, ast: &Ast) {
+        (**self).enter_for_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_for_stmt(&mut self, node: ForStmt, ast: &Ast) {
+        (**self).leave_for_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_for_in_stmt(&mut self, node: ForInStmt, ast: &Ast) {
+        (**self).enter_for_in_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_for_in_stmt(&mut self, node: ForInStmt, ast: &Ast) {
+        (**self).leave_for_in_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_for_of_stmt(&mut self, node: ForOfStmt, ast: &Ast) {
+        (**self).enter_for_of_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_for_of_stmt(&mut self, node: ForOfStmt, ast: &Ast) {
+        (**self).leave_for_of_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_switch_case(&mut self, node: SwitchCase, ast: &Ast) {
+        (**self).enter_switch_case(node, ast)
+    }
+    #[inline]
+    fn leave_switch_case(&mut self, node: SwitchCase, ast: &Ast) {
+        (**self).leave_switch_case(node, ast)
+    }
+    #[inline]
+    fn enter_catch_clause(&mut self, node: CatchClause, ast: &Ast) {
+        (**self).enter_catch_clause(node, ast)
+    }
+    #[inline]
+    fn leave_catch_clause(&mut self, node: CatchClause, ast: &Ast) {
+        (**self).leave_catch_clause(node, ast)
+    }
+    #[inline]
+    fn enter_fn_decl(&mut self, node: FnDecl, ast: &Ast) {
+        (**self).enter_fn_decl(node, ast)
+    }
+    #[inline]
+    fn leave_fn_decl(&mut self, node: FnDecl, ast: &Ast) {
+        (**self).leave_fn_decl(node, ast)
+    }
+    #[inline]
+    fn enter_class_decl(&mut self, node: ClassDecl, ast: &Ast) {
+        (**self).enter_class_decl(node, ast)
+    }
+    #[inline]
+    fn leave_class_decl(&mut self, node: ClassDecl, ast: &Ast) {
+        (**self).leave_class_decl(node, ast)
+    }
+    #[inline]
+    fn enter_var_decl(&mut self, node: VarDecl, ast: &Ast) {
+        (**self).enter_var_decl(node, ast)
+    }
+    #[inline]
+    fn leave_var_decl(&mut self, node: VarDecl, ast: &Ast) {
+        (**self).leave_var_decl(node, ast)
+    }
+    #[inline]
+    fn enter_var_declarator(&mut self, node: VarDeclarator, ast: &Ast) {
+        (**self).enter_var_declarator(node, ast)
+    }
+    #[inline]
+    fn leave_var_declarator(&mut self, node: VarDeclarator, ast: &Ast) {
+        (**self).leave_var_declarator(node, ast)
+    }
+    #[inline]
+    fn enter_using_decl(&mut self, node: UsingDecl, ast: &Ast) {
+        (**self).enter_using_decl(node, ast)
+    }
+    #[inline]
+    fn leave_using_decl(&mut self, node: UsingDecl, ast: &Ast) {
+        (**self).leave_using_decl(node, ast)
+    }
+    #[inline]
+    fn enter_this_expr(&mut self, node: ThisExpr, ast: &Ast) {
+        (**self).enter_this_expr(node, ast)
+    }
+    #[inline]
+    fn leave_this_expr(&mut self, node: ThisExpr, ast: &Ast) {
+        (**self).leave_this_expr(node, ast)
+    }
+    #[inline]
+    fn enter_array_lit(&mut self, node: ArrayLit, ast: &Ast) {
+        (**self).enter_array_lit(node, ast)
+    }
+    #[inline]
+    fn leave_array_lit(&mut self, node: ArrayLit, ast: &Ast) {
+        (**self).leave_array_lit(node, ast)
+    }
+    #[inline]
+    fn enter_object_lit(&mut self, node: ObjectLit, ast: &Ast) {
+        (**self).enter_object_lit(node, ast)
+    }
+    #[inline]
+    fn leave_object_lit(&mut self, node: ObjectLit, ast: &Ast) {
+        (**self).leave_object_lit(node, ast)
+    }
+    #[inline]
+    fn enter_spread_element(&mut self, node: SpreadElement, ast: &Ast) {
+        (**self).enter_spread_element(node, ast)
+    }
+    #[inline]
+    fn leave_spread_element(&mut self, node: SpreadElement, ast: &Ast) {
+        (**self).leave_spread_element(node, ast)
+    }
+    #[inline]
+    fn enter_unary_expr(&mut self, node: UnaryExpr, ast: &Ast) {
+        (**self).enter_unary_expr(node, ast)
+    }
+    #[inline]
+    fn leave_unary_expr(&mut self, node: UnaryExpr, ast: &Ast) {
+        (**self).leave_unary_expr(node, ast)
+    }
+    #[inline]
+    fn enter_update_expr(&mut self, node: UpdateExpr, ast: &Ast) {
+        (**self).enter_update_expr(node, ast)
+    }
+    #[inline]
+    fn leave_update_expr(&mut self, node: UpdateExpr, ast: &Ast) {
+        (**self).leave_update_expr(node, ast)
+    }
+    #[inline]
+    fn enter_bin_expr(&mut self, node: BinExpr, ast: &Ast) {
+        (**self).enter_bin_expr(node, ast)
+    }
+    #[inline]
+    fn leave_bin_expr(&mut self, node: BinExpr, ast: &Ast) {
+        (**self).leave_bin_expr(node, ast)
+    }
+    #[inline]
+    fn enter_fn_expr(&mut self, node: FnExpr, ast: &Ast) {
+        (**self).enter_fn_expr(node, ast)
+    }
+    #[inline]
+    fn leave_fn_expr(&mut self, node: FnExpr, ast: &Ast) {
+        (**self).leave_fn_expr(node, ast)
+    }
+    #[inline]
+    fn enter_class_expr(&mut self, node: ClassExpr, ast: &Ast) {
+        (**self).enter_class_expr(node, ast)
+    }
+    #[inline]
+    fn leave_class_expr(&mut self, node: ClassExpr, ast: &Ast) {
+        (**self).leave_class_expr(node, ast)
+    }
+    #[inline]
+    fn enter_assign_expr(&mut self, node: AssignExpr, ast: &Ast) {
+        (**self).enter_assign_expr(node, ast)
+    }
+    #[inline]
+    fn leave_assign_expr(&mut self, node: AssignExpr, ast: &Ast) {
+        (**self).leave_assign_expr(node, ast)
+    }
+    #[inline]
+    fn enter_member_expr(&mut self, node: MemberExpr, ast: &Ast) {
+        (**self).enter_member_expr(node, ast)
+    }
+    #[inline]
+    fn leave_member_expr(&mut self, node: MemberExpr, ast: &Ast) {
+        (**self).leave_member_expr(node, ast)
+    }
+    #[inline]
+    fn enter_super_prop_expr(&mut self, node: SuperPropExpr, ast: &Ast) {
+        (**self).enter_super_prop_expr(node, ast)
+    }
+    #[inline]
+    fn leave_super_prop_expr(&mut self, node: SuperPropExpr, ast: &Ast) {
+        (**self).leave_super_prop_expr(node, ast)
+    }
+    #[inline]
+    fn enter_cond_expr(&mut self, node: CondExpr, ast: &Ast) {
+        (**self).enter_cond_expr(node, ast)
+    }
+    #[inline]
+    fn leave_cond_expr(&mut self, node: CondExpr, ast: &Ast) {
+        (**self).leave_cond_expr(node, ast)
+    }
+    #[inline]
+    fn enter_call_expr(&mut self, node: CallExpr, ast: &Ast) {
+        (**self).enter_call_expr(node, ast)
+    }
+    #[inline]
+    fn leave_call_expr(&mut self, node: CallExpr, ast: &Ast) {
+        (**self).leave_call_expr(node, ast)
+    }
+    #[inline]
+    fn enter_new_expr(&mut self, node: NewExpr, ast: &Ast) {
+        (**self).enter_new_expr(node, ast)
+    }
+    #[inline]
+    fn leave_new_expr(&mut self, node: NewExpr, ast: &Ast) {
+        (**self).leave_new_expr(node, ast)
+    }
+    #[inline]
+    fn enter_seq_expr(&mut self, node: SeqExpr, ast: &Ast) {
+        (**self).enter_seq_expr(node, ast)
+    }
+    #[inline]
+    fn leave_seq_expr(&mut self, node: SeqExpr, ast: &Ast) {
+        (**self).leave_seq_expr(node, ast)
+    }
+    #[inline]
+    fn enter_arrow_expr(&mut self, node: ArrowExpr, ast: &Ast) {
+        (**self).enter_arrow_expr(node, ast)
+    }
+    #[inline]
+    fn leave_arrow_expr(&mut self, node: ArrowExpr, ast: &Ast) {
+        (**self).leave_arrow_expr(node, ast)
+    }
+    #[inline]
+    fn enter_yield_expr(&mut self, node: YieldExpr, ast: &Ast) {
+        (**self).enter_yield_expr(node, ast)
+    }
+    #[inline]
+    fn leave_yield_expr(&mut self, node: YieldExpr, ast: &Ast) {
+        (**self).leave_yield_expr(node, ast)
+    }
+    #[inline]
+    fn enter_meta_prop_expr(&mut self, node: MetaPropExpr, ast: &Ast) {
+        (**self).enter_meta_prop_expr(node, ast)
+    }
+    #[inline]
+    fn leave_meta_prop_expr(&mut self, node: MetaPropExpr, ast: &Ast) {
+        (**self).leave_meta_prop_expr(node, ast)
+    }
+    #[inline]
+    fn enter_await_expr(&mut self, node: AwaitExpr, ast: &Ast) {
+        (**self).enter_await_expr(node, ast)
+    }
+    #[inline]
+    fn leave_await_expr(&mut self, node: AwaitExpr, ast: &Ast) {
+        (**self).leave_await_expr(node, ast)
+    }
+    #[inline]
+    fn enter_tpl(&mut self, node: Tpl, ast: &Ast) {
+        (**self).enter_tpl(node, ast)
+    }
+    #[inline]
+    fn leave_tpl(&mut self, node: Tpl, ast: &Ast) {
+        (**self).leave_tpl(node, ast)
+    }
+    #[inline]
+    fn enter_tagged_tpl(&mut self, node: TaggedTpl, ast: &Ast) {
+        (**self).enter_tagged_tpl(node, ast)
+    }
+    #[inline]
+    fn leave_tagged_tpl(&mut self, node: TaggedTpl, ast: &Ast) {
+        (**self).leave_tagged_tpl(node, ast)
+    }
+    #[inline]
+    fn enter_tpl_element(&mut self, node: TplElement, ast: &Ast) {
+        (**self).enter_tpl_element(node, ast)
+    }
+    #[inline]
+    fn leave_tpl_element(&mut self, node: TplElement, ast: &Ast) {
+        (**self).leave_tpl_element(node, ast)
+    }
+    #[inline]
+    fn enter_paren_expr(&mut self, node: ParenExpr, ast: &Ast) {
+        (**self).enter_paren_expr(node, ast)
+    }
+    #[inline]
+    fn leave_paren_expr(&mut self, node: ParenExpr, ast: &Ast) {
+        (**self).leave_paren_expr(node, ast)
+    }
+    #[inline]
+    fn enter_super(&mut self, node: Super, ast: &Ast) {
+        (**self).enter_super(node, ast)
+    }
+    #[inline]
+    fn leave_super(&mut self, node: Super, ast: &Ast) {
+        (**self).leave_super(node, ast)
+    }
+    #[inline]
+    fn enter_import(&mut self, node: Import, ast: &Ast) {
+        (**self).enter_import(node, ast)
+    }
+    #[inline]
+    fn leave_import(&mut self, node: Import, ast: &Ast) {
+        (**self).leave_import(node, ast)
+    }
+    #[inline]
+    fn enter_expr_or_spread(&mut self, node: ExprOrSpread, ast: &Ast) {
+        (**self).enter_expr_or_spread(node, ast)
+    }
+    #[inline]
+    fn leave_expr_or_spread(&mut self, node: ExprOrSpread, ast: &Ast) {
+        (**self).leave_expr_or_spread(node, ast)
+    }
+    #[inline]
+    fn enter_spread_dot_3_token(&mut self, node: SpreadDot3Token, ast: &Ast) {
+        (**self).enter_spread_dot_3_token(node, ast)
+    }
+    #[inline]
+    fn leave_spread_dot_3_token(&mut self, node: SpreadDot3Token, ast: &Ast) {
+        (**self).leave_spread_dot_3_token(node, ast)
+    }
+    #[inline]
+    fn enter_opt_chain_expr(&mut self, node: OptChainExpr, ast: &Ast) {
+        (**self).enter_opt_chain_expr(node, ast)
+    }
+    #[inline]
+    fn leave_opt_chain_expr(&mut self, node: OptChainExpr, ast: &Ast) {
+        (**self).leave_opt_chain_expr(node, ast)
+    }
+    #[inline]
+    fn enter_opt_call(&mut self, node: OptCall, ast: &Ast) {
+        (**self).enter_opt_call(node, ast)
+    }
+    #[inline]
+    fn leave_opt_call(&mut self, node: OptCall, ast: &Ast) {
+        (**self).leave_opt_call(node, ast)
+    }
+    #[inline]
+    fn enter_invalid(&mut self, node: Invalid, ast: &Ast) {
+        (**self).enter_invalid(node, ast)
+    }
+    #[inline]
+    fn leave_invalid(&mut self, node: Invalid, ast: &Ast) {
+        (**self).leave_invalid(node, ast)
+    }
+    #[inline]
+    fn enter_function(&mut self, node: Function, ast: &Ast) {
+        (**self).enter_function(node, ast)
+    }
+    #[inline]
+    fn leave_function(&mut self, node: Function, ast: &Ast) {
+        (**self).leave_function(node, ast)
+    }
+    #[inline]
+    fn enter_param(&mut self, node: Param, ast: &Ast) {
+        (**self).enter_param(node, ast)
+    }
+    #[inline]
+    fn leave_param(&mut self, node: Param, ast: &Ast) {
+        (**self).leave_param(node, ast)
+    }
+    #[inline]
+    fn enter_class(&mut self, node: Class, ast: &Ast) {
+        (**self).enter_class(node, ast)
+    }
+    #[inline]
+    fn leave_class(&mut self, node: Class, ast: &Ast) {
+        (**self).leave_class(node, ast)
+    }
+    #[inline]
+    fn enter_class_prop(&mut self, node: ClassProp, ast: &Ast) {
+        (**self).enter_class_prop(node, ast)
+    }
+    #[inline]
+    fn leave_class_prop(&mut self, node: ClassProp, ast: &Ast) {
+        (**self).leave_class_prop(node, ast)
+    }
+    #[inline]
+    fn enter_private_prop(&mut self, node: PrivateProp, ast: &Ast) {
+        (**self).enter_private_prop(node, ast)
+    }
+    #[inline]
+    fn leave_private_prop(&mut self, node: PrivateProp, ast: &Ast) {
+        (**self).leave_private_prop(node, ast)
+    }
+    #[inline]
+    fn enter_class_method(&mut self, node: ClassMethod, ast: &Ast) {
+        (**self).enter_class_method(node, ast)
+    }
+    #[inline]
+    fn leave_class_method(&mut self, node: ClassMethod, ast: &Ast) {
+        (**self).leave_class_method(node, ast)
+    }
+    #[inline]
+    fn enter_private_method(&mut self, node: PrivateMethod, ast: &Ast) {
+        (**self).enter_private_method(node, ast)
+    }
+    #[inline]
+    fn leave_private_method(&mut self, node: PrivateMethod, ast: &Ast) {
+        (**self).leave_private_method(node, ast)
+    }
+    #[inline]
+    fn enter_constructor(&mut self, node: Constructor, ast: &Ast) {
+        (**self).enter_constructor(node, ast)
+    }
+    #[inline]
+    fn leave_constructor(&mut self, node: Constructor, ast: &Ast) {
+        (**self).leave_constructor(node, ast)
+    }
+    #[inline]
+    fn enter_decorator(&mut self, node: Decorator, ast: &Ast) {
+        (**self).enter_decorator(node, ast)
+    }
+    #[inline]
+    fn leave_decorator(&mut self, node: Decorator, ast: &Ast) {
+        (**self).leave_decorator(node, ast)
+    }
+    #[inline]
+    fn enter_static_block(&mut self, node: StaticBlock, ast: &Ast) {
+        (**self).enter_static_block(node, ast)
+    }
+    #[inline]
+    fn leave_static_block(&mut self, node: StaticBlock, ast: &Ast) {
+        (**self).leave_static_block(node, ast)
+    }
+    #[inline]
+    fn enter_auto_accessor(&mut self, node: AutoAccessor, ast: &Ast) {
+        (**self).enter_auto_accessor(node, ast)
+    }
+    #[inline]
+    fn leave_auto_accessor(&mut self, node: AutoAccessor, ast: &Ast) {
+        (**self).leave_auto_accessor(node, ast)
+    }
+    #[inline]
+    fn enter_key_value_prop(&mut self, node: KeyValueProp, ast: &Ast) {
+        (**self).enter_key_value_prop(node, ast)
+    }
+    #[inline]
+    fn leave_key_value_prop(&mut self, node: KeyValueProp, ast: &Ast) {
+        (**self).leave_key_value_prop(node, ast)
+    }
+    #[inline]
+    fn enter_assign_prop(&mut self, node: AssignProp, ast: &Ast) {
+        (**self).enter_assign_prop(node, ast)
+    }
+    #[inline]
+    fn leave_assign_prop(&mut self, node: AssignProp, ast: &Ast) {
+        (**self).leave_assign_prop(node, ast)
+    }
+    #[inline]
+    fn enter_getter_prop(&mut self, node: GetterProp, ast: &Ast) {
+        (**self).enter_getter_prop(node, ast)
+    }
+    #[inline]
+    fn leave_getter_prop(&mut self, node: GetterProp, ast: &Ast) {
+        (**self).leave_getter_prop(node, ast)
+    }
+    #[inline]
+    fn enter_setter_prop(&mut self, node: SetterProp, ast: &Ast) {
+        (**self).enter_setter_prop(node, ast)
+    }
+    #[inline]
+    fn leave_setter_prop(&mut self, node: SetterProp, ast: &Ast) {
+        (**self).leave_setter_prop(node, ast)
+    }
+    #[inline]
+    fn enter_method_prop(&mut self, node: MethodProp, ast: &Ast) {
+        (**self).enter_method_prop(node, ast)
+    }
+    #[inline]
+    fn leave_method_prop(&mut self, node: MethodProp, ast: &Ast) {
+        (**self).leave_method_prop(node, ast)
+    }
+    #[inline]
+    fn enter_computed_prop_name(&mut self, node: ComputedPropName, ast: &Ast) {
+        (**self).enter_computed_prop_name(node, ast)
+    }
+    #[inline]
+    fn leave_computed_prop_name(&mut self, node: ComputedPropName, ast: &Ast) {
+        (**self).leave_computed_prop_name(node, ast)
+    }
+    #[inline]
+    fn enter_array_pat(&mut self, node: ArrayPat, ast: &Ast) {
+        (**self).enter_array_pat(node, ast)
+    }
+    #[inline]
+    fn leave_array_pat(&mut self, node: ArrayPat, ast: &Ast) {
+        (**self).leave_array_pat(node, ast)
+    }
+    #[inline]
+    fn enter_object_pat(&mut self, node: ObjectPat, ast: &Ast) {
+        (**self).enter_object_pat(node, ast)
+    }
+    #[inline]
+    fn leave_object_pat(&mut self, node: ObjectPat, ast: &Ast) {
+        (**self).leave_object_pat(node, ast)
+    }
+    #[inline]
+    fn enter_assign_pat(&mut self, node: AssignPat, ast: &Ast) {
+        (**self).enter_assign_pat(node, ast)
+    }
+    #[inline]
+    fn leave_assign_pat(&mut self, node: AssignPat, ast: &Ast) {
+        (**self).leave_assign_pat(node, ast)
+    }
+    #[inline]
+    fn enter_rest_pat(&mut self, node: RestPat, ast: &Ast) {
+        (**self).enter_rest_pat(node, ast)
+    }
+    #[inline]
+    fn leave_rest_pat(&mut self, node: RestPat, ast: &Ast) {
+        (**self).leave_rest_pat(node, ast)
+    }
+    #[inline]
+    fn enter_key_value_pat_prop(&mut self, node: KeyValuePatProp, ast: &Ast) {
+        (**self).enter_key_value_pat_prop(node, ast)
+    }
+    #[inline]
+    fn leave_key_value_pat_prop(&mut self, node: KeyValuePatProp, ast: &Ast) {
+        (**self).leave_key_value_pat_prop(node, ast)
+    }
+    #[inline]
+    fn enter_assign_pat_prop(&mut self, node: AssignPatProp, ast: &Ast) {
+        (**self).enter_assign_pat_prop(node, ast)
+    }
+    #[inline]
+    fn leave_assign_pat_prop(&mut self, node: AssignPatProp, ast: &Ast) {
+        (**self).leave_assign_pat_prop(node, ast)
+    }
+    #[inline]
+    fn enter_ident(&mut self, node: Ident, ast: &Ast) {
+        (**self).enter_ident(node, ast)
+    }
+    #[inline]
+    fn leave_ident(&mut self, node: Ident, ast: &Ast) {
+        (**self).leave_ident(node, ast)
+    }
+    #[inline]
+    fn enter_ident_name(&mut self, node: IdentName, ast: &Ast) {
+        (**self).enter_ident_name(node, ast)
+    }
+    #[inline]
+    fn leave_ident_name(&mut self, node: IdentName, ast: &Ast) {
+        (**self).leave_ident_name(node, ast)
+    }
+    #[inline]
+    fn enter_private_name(&mut self, node: PrivateName, ast: &Ast) {
+        (**self).enter_private_name(node, ast)
+    }
+    #[inline]
+    fn leave_private_name(&mut self, node: PrivateName, ast: &Ast) {
+        (**self).leave_private_name(node, ast)
+    }
+    #[inline]
+    fn enter_binding_ident(&mut self, node: BindingIdent, ast: &Ast) {
+        (**self).enter_binding_ident(node, ast)
+    }
+    #[inline]
+    fn leave_binding_ident(&mut self, node: BindingIdent, ast: &Ast) {
+        (**self).leave_binding_ident(node, ast)
+    }
+    #[inline]
+    fn enter_str(&mut self, node: Str, ast: &Ast) {
+        (**self).enter_str(node, ast)
+    }
+    #[inline]
+    fn leave_str(&mut self, node: Str, ast: &Ast) {
+        (**self).leave_str(node, ast)
+    }
+    #[inline]
+    fn enter_bool(&mut self, node: Bool, ast: &Ast) {
+        (**self).enter_bool(node, ast)
+    }
+    #[inline]
+    fn leave_bool(&mut self, node: Bool, ast: &Ast) {
+        (**self).leave_bool(node, ast)
+    }
+    #[inline]
+    fn enter_null(&mut self, node: Null, ast: &Ast) {
+        (**self).enter_null(node, ast)
+    }
+    #[inline]
+    fn leave_null(&mut self, node: Null, ast: &Ast) {
+        (**self).leave_null(node, ast)
+    }
+    #[inline]
+    fn enter_number(&mut self, node: Number, ast: &Ast) {
+        (**self).enter_number(node, ast)
+    }
+    #[inline]
+    fn leave_number(&mut self, node: Number, ast: &Ast) {
+        (**self).leave_number(node, ast)
+    }
+    #[inline]
+    fn enter_big_int(&mut self, node: BigInt, ast: &Ast) {
+        (**self).enter_big_int(node, ast)
+    }
+    #[inline]
+    fn leave_big_int(&mut self, node: BigInt, ast: &Ast) {
+        (**self).leave_big_int(node, ast)
+    }
+    #[inline]
+    fn enter_regex(&mut self, node: Regex, ast: &Ast) {
+        (**self).enter_regex(node, ast)
+    }
+    #[inline]
+    fn leave_regex(&mut self, node: Regex, ast: &Ast) {
+        (**self).leave_regex(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_member_expr(&mut self, node: JSXMemberExpr, ast: &Ast) {
+        (**self).enter_jsx_member_expr(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_member_expr(&mut self, node: JSXMemberExpr, ast: &Ast) {
+        (**self).leave_jsx_member_expr(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_namespaced_name(&mut self, node: JSXNamespacedName, ast: &Ast) {
+        (**self).enter_jsx_namespaced_name(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_namespaced_name(&mut self, node: JSXNamespacedName, ast: &Ast) {
+        (**self).leave_jsx_namespaced_name(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_empty_expr(&mut self, node: JSXEmptyExpr, ast: &Ast) {
+        (**self).enter_jsx_empty_expr(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_empty_expr(&mut self, node: JSXEmptyExpr, ast: &Ast) {
+        (**self).leave_jsx_empty_expr(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_expr_container(&mut self, node: JSXExprContainer, ast: &Ast) {
+        (**self).enter_jsx_expr_container(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_expr_container(&mut self, node: JSXExprContainer, ast: &Ast) {
+        (**self).leave_jsx_expr_container(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_spread_child(&mut self, node: JSXSpreadChild, ast: &Ast) {
+        (**self).enter_jsx_spread_child(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_spread_child(&mut self, node: JSXSpreadChild, ast: &Ast) {
+        (**self).leave_jsx_spread_child(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_opening_element(&mut self, node: JSXOpeningElement, ast: &Ast) {
+        (**self).enter_jsx_opening_element(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_opening_element(&mut self, node: JSXOpeningElement, ast: &Ast) {
+        (**self).leave_jsx_opening_element(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_closing_element(&mut self, node: JSXClosingElement, ast: &Ast) {
+        (**self).enter_jsx_closing_element(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_closing_element(&mut self, node: JSXClosingElement, ast: &Ast) {
+        (**self).leave_jsx_closing_element(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_attr(&mut self, node: JSXAttr, ast: &Ast) {
+        (**self).enter_jsx_attr(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_attr(&mut self, node: JSXAttr, ast: &Ast) {
+        (**self).leave_jsx_attr(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_text(&mut self, node: JSXText, ast: &Ast) {
+        (**self).enter_jsx_text(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_text(&mut self, node: JSXText, ast: &Ast) {
+        (**self).leave_jsx_text(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_element(&mut self, node: JSXElement, ast: &Ast) {
+        (**self).enter_jsx_element(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_element(&mut self, node: JSXElement, ast: &Ast) {
+        (**self).leave_jsx_element(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_fragment(&mut self, node: JSXFragment, ast: &Ast) {
+        (**self).enter_jsx_fragment(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_fragment(&mut self, node: JSXFragment, ast: &Ast) {
+        (**self).leave_jsx_fragment(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_opening_fragment(&mut self, node: JSXOpeningFragment, ast: &Ast) {
+        (**self).enter_jsx_opening_fragment(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_opening_fragment(&mut self, node: JSXOpeningFragment, ast: &Ast) {
+        (**self).leave_jsx_opening_fragment(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_closing_fragment(&mut self, node: JSXClosingFragment, ast: &Ast) {
+        (**self).enter_jsx_closing_fragment(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_closing_fragment(&mut self, node: JSXClosingFragment, ast: &Ast) {
+        (**self).leave_jsx_closing_fragment(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_ann(&mut self, node: TsTypeAnn, ast: &Ast) {
+        (**self).enter_ts_type_ann(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_ann(&mut self, node: TsTypeAnn, ast: &Ast) {
+        (**self).leave_ts_type_ann(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_param_decl(&mut self, node: TsTypeParamDecl, ast: &Ast) {
+        (**self).enter_ts_type_param_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_param_decl(&mut self, node: TsTypeParamDecl, ast: &Ast) {
+        (**self).leave_ts_type_param_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_param(&mut self, node: TsTypeParam, ast: &Ast) {
+        (**self).enter_ts_type_param(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_param(&mut self, node: TsTypeParam, ast: &Ast) {
+        (**self).leave_ts_type_param(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_param_instantiation(&mut self, node: TsTypeParamInstantiation, ast: &Ast) {
+        (**self).enter_ts_type_param_instantiation(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_param_instantiation(&mut self, node: TsTypeParamInstantiation, ast: &Ast) {
+        (**self).leave_ts_type_param_instantiation(node, ast)
+    }
+    #[inline]
+    fn enter_ts_param_prop(&mut self, node: TsParamProp, ast: &Ast) {
+        (**self).enter_ts_param_prop(node, ast)
+    }
+    #[inline]
+    fn leave_ts_param_prop(&mut self, node: TsParamProp, ast: &Ast) {
+        (**self).leave_ts_param_prop(node, ast)
+    }
+    #[inline]
+    fn enter_ts_qualified_name(&mut self, node: TsQualifiedName, ast: &Ast) {
+        (**self).enter_ts_qualified_name(node, ast)
+    }
+    #[inline]
+    fn leave_ts_qualified_name(&mut self, node: TsQualifiedName, ast: &Ast) {
+        (**self).leave_ts_qualified_name(node, ast)
+    }
+    #[inline]
+    fn enter_ts_call_signature_decl(&mut self, node: TsCallSignatureDecl, ast: &Ast) {
+        (**self).enter_ts_call_signature_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_call_signature_decl(&mut self, node: TsCallSignatureDecl, ast: &Ast) {
+        (**self).leave_ts_call_signature_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_construct_signature_decl(&mut self, node: TsConstructSignatureDecl, ast: &Ast) {
+        (**self).enter_ts_construct_signature_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_construct_signature_decl(&mut self, node: TsConstructSignatureDecl, ast: &Ast) {
+        (**self).leave_ts_construct_signature_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_property_signature(&mut self, node: TsPropertySignature, ast: &Ast) {
+        (**self).enter_ts_property_signature(node, ast)
+    }
+    #[inline]
+    fn leave_ts_property_signature(&mut self, node: TsPropertySignature, ast: &Ast) {
+        (**self).leave_ts_property_signature(node, ast)
+    }
+    #[inline]
+    fn enter_ts_getter_signature(&mut self, node: TsGetterSignature, ast: &Ast) {
+        (**self).enter_ts_getter_signature(node, ast)
+    }
+    #[inline]
+    fn leave_ts_getter_signature(&mut self, node: TsGetterSignature, ast: &Ast) {
+        (**self).leave_ts_getter_signature(node, ast)
+    }
+    #[inline]
+    fn enter_ts_setter_signature(&mut self, node: TsSetterSignature, ast: &Ast) {
+        (**self).enter_ts_setter_signature(node, ast)
+    }
+    #[inline]
+    fn leave_ts_setter_signature(&mut self, node: TsSetterSignature, ast: &Ast) {
+        (**self).leave_ts_setter_signature(node, ast)
+    }
+    #[inline]
+    fn enter_ts_method_signature(&mut self, node: TsMethodSignature, ast: &Ast) {
+        (**self).enter_ts_method_signature(node, ast)
+    }
+    #[inline]
+    fn leave_ts_method_signature(&mut self, node: TsMethodSignature, ast: &Ast) {
+        (**self).leave_ts_method_signature(node, ast)
+    }
+    #[inline]
+    fn enter_ts_index_signature(&mut self, node: TsIndexSignature, ast: &Ast) {
+        (**self).enter_ts_index_signature(node, ast)
+    }
+    #[inline]
+    fn leave_ts_index_signature(&mut self, node: TsIndexSignature, ast: &Ast) {
+        (**self).leave_ts_index_signature(node, ast)
+    }
+    #[inline]
+    fn enter_ts_keyword_type(&mut self, node: TsKeywordType, ast: &Ast) {
+        (**self).enter_ts_keyword_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_keyword_type(&mut self, node: TsKeywordType, ast: &Ast) {
+        (**self).leave_ts_keyword_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_this_type(&mut self, node: TsThisType, ast: &Ast) {
+        (**self).enter_ts_this_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_this_type(&mut self, node: TsThisType, ast: &Ast) {
+        (**self).leave_ts_this_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_fn_type(&mut self, node: TsFnType, ast: &Ast) {
+        (**self).enter_ts_fn_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_fn_type(&mut self, node: TsFnType, ast: &Ast) {
+        (**self).leave_ts_fn_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_constructor_type(&mut self, node: TsConstructorType, ast: &Ast) {
+        (**self).enter_ts_constructor_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_constructor_type(&mut self, node: TsConstructorType, ast: &Ast) {
+        (**self).leave_ts_constructor_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_ref(&mut self, node: TsTypeRef, ast: &Ast) {
+        (**self).enter_ts_type_ref(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_ref(&mut self, node: TsTypeRef, ast: &Ast) {
+        (**self).leave_ts_type_ref(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_predicate(&mut self, node: TsTypePredicate, ast: &Ast) {
+        (**self).enter_ts_type_predicate(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_predicate(&mut self, node: TsTypePredicate, ast: &Ast) {
+        (**self).leave_ts_type_predicate(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_query(&mut self, node: TsTypeQuery, ast: &Ast) {
+        (**self).enter_ts_type_query(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_query(&mut self, node: TsTypeQuery, ast: &Ast) {
+        (**self).leave_ts_type_query(node, ast)
+    }
+    #[inline]
+    fn enter_ts_import_call_options(&mut self, node: TsImportCallOptions, ast: &Ast) {
+        (**self).enter_ts_import_call_options(node, ast)
+    }
+    #[inline]
+    fn leave_ts_import_call_options(&mut self, node: TsImportCallOptions, ast: &Ast) {
+        (**self).leave_ts_import_call_options(node, ast)
+    }
+    #[inline]
+    fn enter_ts_import_type(&mut self, node: TsImportType, ast: &Ast) {
+        (**self).enter_ts_import_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_import_type(&mut self, node: TsImportType, ast: &Ast) {
+        (**self).leave_ts_import_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_lit(&mut self, node: TsTypeLit, ast: &Ast) {
+        (**self).enter_ts_type_lit(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_lit(&mut self, node: TsTypeLit, ast: &Ast) {
+        (**self).leave_ts_type_lit(node, ast)
+    }
+    #[inline]
+    fn enter_ts_array_type(&mut self, node: TsArrayType, ast: &Ast) {
+        (**self).enter_ts_array_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_array_type(&mut self, node: TsArrayType, ast: &Ast) {
+        (**self).leave_ts_array_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_tuple_type(&mut self, node: TsTupleType, ast: &Ast) {
+        (**self).enter_ts_tuple_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_tuple_type(&mut self, node: TsTupleType, ast: &Ast) {
+        (**self).leave_ts_tuple_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_tuple_element(&mut self, node: TsTupleElement, ast: &Ast) {
+        (**self).enter_ts_tuple_element(node, ast)
+    }
+    #[inline]
+    fn leave_ts_tuple_element(&mut self, node: TsTupleElement, ast: &Ast) {
+        (**self).leave_ts_tuple_element(node, ast)
+    }
+    #[inline]
+    fn enter_ts_optional_type(&mut self, node: TsOptionalType, ast: &Ast) {
+        (**self).enter_ts_optional_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_optional_type(&mut self, node: TsOptionalType, ast: &Ast) {
+        (**self).leave_ts_optional_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_rest_type(&mut self, node: TsRestType, ast: &Ast) {
+        (**self).enter_ts_rest_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_rest_type(&mut self, node: TsRestType, ast: &Ast) {
+        (**self).leave_ts_rest_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_union_type(&mut self, node: TsUnionType, ast: &Ast) {
+        (**self).enter_ts_union_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_union_type(&mut self, node: TsUnionType, ast: &Ast) {
+        (**self).leave_ts_union_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_intersection_type(&mut self, node: TsIntersectionType, ast: &Ast) {
+        (**self).enter_ts_intersection_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_intersection_type(&mut self, node: TsIntersectionType, ast: &Ast) {
+        (**self).leave_ts_intersection_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_conditional_type(&mut self, node: TsConditionalType, ast: &Ast) {
+        (**self).enter_ts_conditional_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_conditional_type(&mut self, node: TsConditionalType, ast: &Ast) {
+        (**self).leave_ts_conditional_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_infer_type(&mut self, node: TsInferType, ast: &Ast) {
+        (**self).enter_ts_infer_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_infer_type(&mut self, node: TsInferType, ast: &Ast) {
+        (**self).leave_ts_infer_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_parenthesized_type(&mut self, node: TsParenthesizedType, ast: &Ast) {
+        (**self).enter_ts_parenthesized_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_parenthesized_type(&mut self, node: TsParenthesizedType, ast: &Ast) {
+        (**self).leave_ts_parenthesized_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_operator(&mut self, node: TsTypeOperator, ast: &Ast) {
+        (**self).enter_ts_type_operator(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_operator(&mut self, node: TsTypeOperator, ast: &Ast) {
+        (**self).leave_ts_type_operator(node, ast)
+    }
+    #[inline]
+    fn enter_ts_indexed_access_type(&mut self, node: TsIndexedAccessType, ast: &Ast) {
+        (**self).enter_ts_indexed_access_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_indexed_access_type(&mut self, node: TsIndexedAccessType, ast: &Ast) {
+        (**self).leave_ts_indexed_access_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_mapped_type(&mut self, node: TsMappedType, ast: &Ast) {
+        (**self).enter_ts_mapped_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_mapped_type(&mut self, node: TsMappedType, ast: &Ast) {
+        (**self).leave_ts_mapped_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_lit_type(&mut self, node: TsLitType, ast: &Ast) {
+        (**self).enter_ts_lit_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_lit_type(&mut self, node: TsLitType, ast: &Ast) {
+        (**self).leave_ts_lit_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_tpl_lit_type(&mut self, node: TsTplLitType, ast: &Ast) {
+        (**self).enter_ts_tpl_lit_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_tpl_lit_type(&mut self, node: TsTplLitType, ast: &Ast) {
+        (**self).leave_ts_tpl_lit_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_interface_decl(&mut self, node: TsInterfaceDecl, ast: &Ast) {
+        (**self).enter_ts_interface_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_interface_decl(&mut self, node: TsInterfaceDecl, ast: &Ast) {
+        (**self).leave_ts_interface_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_interface_body(&mut self, node: TsInterfaceBody, ast: &Ast) {
+        (**self).enter_ts_interface_body(node, ast)
+    }
+    #[inline]
+    fn leave_ts_interface_body(&mut self, node: TsInterfaceBody, ast: &Ast) {
+        (**self).leave_ts_interface_body(node, ast)
+    }
+    #[inline]
+    fn enter_ts_expr_with_type_args(&mut self, node: TsExprWithTypeArgs, ast: &Ast) {
+        (**self).enter_ts_expr_with_type_args(node, ast)
+    }
+    #[inline]
+    fn leave_ts_expr_with_type_args(&mut self, node: TsExprWithTypeArgs, ast: &Ast) {
+        (**self).leave_ts_expr_with_type_args(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl, ast: &Ast) {
+        (**self).enter_ts_type_alias_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl, ast: &Ast) {
+        (**self).leave_ts_type_alias_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_enum_decl(&mut self, node: TsEnumDecl, ast: &Ast) {
+        (**self).enter_ts_enum_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_enum_decl(&mut self, node: TsEnumDecl, ast: &Ast) {
+        (**self).leave_ts_enum_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_enum_member(&mut self, node: TsEnumMember, ast: &Ast) {
+        (**self).enter_ts_enum_member(node, ast)
+    }
+    #[inline]
+    fn leave_ts_enum_member(&mut self, node: TsEnumMember, ast: &Ast) {
+        (**self).leave_ts_enum_member(node, ast)
+    }
+    #[inline]
+    fn enter_ts_module_decl(&mut self, node: TsModuleDecl, ast: &Ast) {
+        (**self).enter_ts_module_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_module_decl(&mut self, node: TsModuleDecl, ast: &Ast) {
+        (**self).leave_ts_module_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_module_block(&mut self, node: TsModuleBlock, ast: &Ast) {
+        (**self).enter_ts_module_block(node, ast)
+    }
+    #[inline]
+    fn leave_ts_module_block(&mut self, node: TsModuleBlock, ast: &Ast) {
+        (**self).leave_ts_module_block(node, ast)
+    }
+    #[inline]
+    fn enter_ts_namespace_decl(&mut self, node: TsNamespaceDecl, ast: &Ast) {
+        (**self).enter_ts_namespace_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_namespace_decl(&mut self, node: TsNamespaceDecl, ast: &Ast) {
+        (**self).leave_ts_namespace_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_import_equals_decl(&mut self, node: TsImportEqualsDecl, ast: &Ast) {
+        (**self).enter_ts_import_equals_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_import_equals_decl(&mut self, node: TsImportEqualsDecl, ast: &Ast) {
+        (**self).leave_ts_import_equals_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_external_module_ref(&mut self, node: TsExternalModuleRef, ast: &Ast) {
+        (**self).enter_ts_external_module_ref(node, ast)
+    }
+    #[inline]
+    fn leave_ts_external_module_ref(&mut self, node: TsExternalModuleRef, ast: &Ast) {
+        (**self).leave_ts_external_module_ref(node, ast)
+    }
+    #[inline]
+    fn enter_ts_export_assignment(&mut self, node: TsExportAssignment, ast: &Ast) {
+        (**self).enter_ts_export_assignment(node, ast)
+    }
+    #[inline]
+    fn leave_ts_export_assignment(&mut self, node: TsExportAssignment, ast: &Ast) {
+        (**self).leave_ts_export_assignment(node, ast)
+    }
+    #[inline]
+    fn enter_ts_namespace_export_decl(&mut self, node: TsNamespaceExportDecl, ast: &Ast) {
+        (**self).enter_ts_namespace_export_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_namespace_export_decl(&mut self, node: TsNamespaceExportDecl, ast: &Ast) {
+        (**self).leave_ts_namespace_export_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_as_expr(&mut self, node: TsAsExpr, ast: &Ast) {
+        (**self).enter_ts_as_expr(node, ast)
+    }
+    #[inline]
+    fn leave_ts_as_expr(&mut self, node: TsAsExpr, ast: &Ast) {
+        (**self).leave_ts_as_expr(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_assertion(&mut self, node: TsTypeAssertion, ast: &Ast) {
+        (**self).enter_ts_type_assertion(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_assertion(&mut self, node: TsTypeAssertion, ast: &Ast) {
+        (**self).leave_ts_type_assertion(node, ast)
+    }
+    #[inline]
+    fn enter_ts_non_null_expr(&mut self, node: TsNonNullExpr, ast: &Ast) {
+        (**self).enter_ts_non_null_expr(node, ast)
+    }
+    #[inline]
+    fn leave_ts_non_null_expr(&mut self, node: TsNonNullExpr, ast: &Ast) {
+        (**self).leave_ts_non_null_expr(node, ast)
+    }
+    #[inline]
+    fn enter_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr, ast: &Ast) {
+        (**self).enter_ts_satisfies_expr(node, ast)
+    }
+    #[inline]
+    fn leave_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr, ast: &Ast) {
+        (**self).leave_ts_satisfies_expr(node, ast)
+    }
+    #[inline]
+    fn enter_ts_const_assertion(&mut self, node: TsConstAssertion, ast: &Ast) {
+        (**self).enter_ts_const_assertion(node, ast)
+    }
+    #[inline]
+    fn leave_ts_const_assertion(&mut self, node: TsConstAssertion, ast: &Ast) {
+        (**self).leave_ts_const_assertion(node, ast)
+    }
+    #[inline]
+    fn enter_ts_instantiation(&mut self, node: TsInstantiation, ast: &Ast) {
+        (**self).enter_ts_instantiation(node, ast)
+    }
+    #[inline]
+    fn leave_ts_instantiation(&mut self, node: TsInstantiation, ast: &Ast) {
+        (**self).leave_ts_instantiation(node, ast)
+    }
+}
+macro_rules! impl_visit_hooks_for_tuple {
+    ($($T:ident $idx:tt),+) => {
+        impl < $($T : VisitHooks),+ > VisitHooks for ($($T,)+) { #[inline] fn
+        enter_node(& mut self, node : NodeId, ast : & Ast) { $(self. $idx
+        .enter_node(node, ast);)+ } #[inline] fn leave_node(& mut self, node : NodeId,
+        ast : & Ast) { $(self. $idx .leave_node(node, ast);)+ } #[inline] fn
+        enter_module(& mut self, node : Module, ast : & Ast) { $(self. $idx
+        .enter_module(node, ast);)+ } #[inline] fn leave_module(& mut self, node :
+        Module, ast : & Ast) { $(self. $idx .leave_module(node, ast);)+ } #[inline] fn
+        enter_script(& mut self, node : Script, ast : & Ast) { $(self. $idx
+        .enter_script(node, ast);)+ } #[inline] fn leave_script(& mut self, node :
+        Script, ast : & Ast) { $(self. $idx .leave_script(node, ast);)+ } #[inline] fn
+        enter_import_decl(& mut self, node : ImportDecl, ast : & Ast) { $(self. $idx
+        .enter_import_decl(node, ast);)+ } #[inline] fn leave_import_decl(& mut self,
+        node : ImportDecl, ast : & Ast) { $(self. $idx .leave_import_decl(node, ast);)+ }
+        #[inline] fn enter_import_named_specifier(& mut self, node :
+        ImportNamedSpecifier, ast : & Ast) { $(self. $idx
+        .enter_import_named_specifier(node, ast);)+ } #[inline] fn
+        leave_import_named_specifier(& mut self, node : ImportNamedSpecifier, ast : &
+        Ast) { $(self. $idx .leave_import_named_specifier(node, ast);)+ } #[inline] fn
+        enter_import_default_specifier(& mut self, node : ImportDefaultSpecifier, ast : &
+        Ast) { $(self. $idx .enter_import_default_specifier(node, ast);)+ } #[inline] fn
+        leave_import_default_specifier(& mut self, node : ImportDefaultSpecifier, ast : &
+        Ast) { $(self. $idx .leave_import_default_specifier(node, ast);)+ } #[inline] fn
+        enter_import_star_as_specifier(& mut self, node : ImportStarAsSpecifier, ast : &
+        Ast) { $(self. $idx .enter_import_star_as_specifier(node, ast);)+ } #[inline] fn
+        leave_import_star_as_specifier(& mut self, node : ImportStarAsSpecifier, ast : &
+        Ast) { $(self. $idx .leave_import_star_as_specifier(node, ast);)+ } #[inline] fn
+        enter_export_decl(& mut self, node : ExportDecl, ast : & Ast) { $(self. $idx
+        .enter_export_decl(node, ast);)+ } #[inline] fn leave_export_decl(& mut self,
+        node : ExportDecl, ast : & Ast) { $(self. $idx .leave_export_decl(node, ast);)+ }
+        #[inline] fn enter_named_export(& mut self, node : NamedExport, ast : & Ast) {
+        $(self. $idx .enter_named_export(node, ast);)+ } #[inline] fn
+        leave_named_export(& mut self, node : NamedExport, ast : & Ast) { $(self. $idx
+        .leave_named_export(node, ast);)+ } #[inline] fn
+        enter_export_namespace_specifier(& mut self, node : ExportNamespaceSpecifier, ast
+        : & Ast) { $(self. $idx .enter_export_namespace_specifier(node, ast);)+ }
+        #[inline] fn leave_export_namespace_specifier(& mut self, node :
+        ExportNamespaceSpecifier, ast : & Ast) { $(self. $idx
+        .leave_export_namespace_specifier(node, ast);)+ } #[inline] fn
+        enter_export_default_specifier(& mut self, node : ExportDefaultSpecifier, ast : &
+        Ast) { $(self. $idx .enter_export_default_specifier(node, ast);)+ } #[inline] fn
+        leave_export_default_specifier(& mut self, node : ExportDefaultSpecifier, ast : &
+        Ast) { $(self. $idx .leave_export_default_specifier(node, ast);)+ } #[inline] fn
+        enter_export_named_specifier(& mut self, node : ExportNamedSpecifier, ast : &
+        Ast) { $(self. $idx .enter_export_named_specifier(node, ast);)+ } #[inline] fn
+        leave_export_named_specifier(& mut self, node : ExportNamedSpecifier, ast : &
+        Ast) { $(self. $idx .leave_export_named_specifier(node, ast);)+ } #[inline] fn
+        enter_export_default_decl(& mut self, node : ExportDefaultDecl, ast : & Ast) {
+        $(self. $idx .enter_export_default_decl(node, ast);)+ } #[inline] fn
+        leave_export_default_decl(& mut self, node : ExportDefaultDecl, ast : & Ast) {
+        $(self. $idx .leave_export_default_decl(node, ast);)+ } #[inline] fn
+        enter_export_default_expr(& mut self, node : ExportDefaultExpr, ast : & Ast) {
+        $(self. $idx .enter_export_default_expr(node, ast);)+ } #[inline] fn
+        leave_export_default_expr(& mut self, node : ExportDefaultExpr, ast : & Ast) {
+        $(self. $idx .leave_export_default_expr(node, ast);)+ } #[inline] fn
+        enter_export_all(& mut self, node : ExportAll, ast : & Ast) { $(self. $idx
+        .enter_export_all(node, ast);)+ } #[inline] fn leave_export_all(& mut self, node
+        : ExportAll, ast : & Ast) { $(self. $idx .leave_export_all(node, ast);)+ }
+        #[inline] fn enter_block_stmt(& mut self, node : BlockStmt, ast : & Ast) { $(self
+        . $idx .enter_block_stmt(node, ast);)+ } #[inline] fn leave_block_stmt(& mut
+        self, node : BlockStmt, ast : & Ast) { $(self. $idx .leave_block_stmt(node,
+        ast);)+ } #[inline] fn enter_expr_stmt(& mut self, node : ExprStmt, ast : & Ast)
+        { $(self. $idx .enter_expr_stmt(node, ast);)+ } #[inline] fn leave_expr_stmt(&
+        mut self, node : ExprStmt, ast : & Ast) { $(self. $idx .leave_expr_stmt(node,
+        ast);)+ } #[inline] fn enter_empty_stmt(& mut self, node : EmptyStmt, ast : &
+        Ast) { $(self. $idx .enter_empty_stmt(node, ast);)+ } #[inline] fn
+        leave_empty_stmt(& mut self, node : EmptyStmt, ast : & Ast) { $(self. $idx
+        .leave_empty_stmt(node, ast);)+ } #[inline] fn enter_debugger_stmt(& mut self,
+        node : DebuggerStmt, ast : & Ast) { $(self. $idx .enter_debugger_stmt(node,
+        ast);)+ } #[inline] fn leave_debugger_stmt(& mut self, node : DebuggerStmt, ast :
+        & Ast) { $(self. $idx .leave_debugger_stmt(node, ast);)+ } #[inline] fn
+        enter_with_stmt(& mut self, node : WithStmt, ast : & Ast) { $(self. $idx
+        .enter_with_stmt(node, ast);)+ } #[inline] fn leave_with_stmt(& mut self, node :
+        WithStmt, ast : & Ast) { $(self. $idx .leave_with_stmt(node, ast);)+ } #[inline]
+        fn enter_return_stmt(& mut self, node : ReturnStmt, ast : & Ast) { $(self. $idx
+        .enter_return_stmt(node, ast);)+ } #[inline] fn leave_return_stmt(& mut self,
+        node : ReturnStmt, ast : & Ast) { $(self. $idx .leave_return_stmt(node, ast);)+ }
+        #[inline] fn enter_labeled_stmt(& mut self, node : LabeledStmt, ast : & Ast) {
+        $(self. $idx .enter_labeled_stmt(node, ast);)+ } #[inline] fn
+        leave_labeled_stmt(& mut self, node : LabeledStmt, ast : & Ast) { $(self. $idx
+        .leave_labeled_stmt(node, ast);)+ } #[inline] fn enter_break_stmt(& mut self,
+        node : BreakStmt, ast : & Ast) { $(self. $idx .enter_break_stmt(node, ast);)+ }
+        #[inline] fn leave_break_stmt(& mut self, node : BreakStmt, ast : & Ast) { $(self
+        . $idx .leave_break_stmt(node, ast);)+ } #[inline] fn enter_continue_stmt(& mut
+        self, node : ContinueStmt, ast : & Ast) { $(self. $idx .enter_continue_stmt(node,
+        ast);)+ } #[inline] fn leave_continue_stmt(& mut self, node : ContinueStmt, ast :
+        & Ast) { $(self. $idx .leave_continue_stmt(node, ast);)+ } #[inline] fn
+        enter_if_stmt(& mut self, node : IfStmt, ast : & Ast) { $(self. $idx
+        .enter_if_stmt(node, ast);)+ } #[inline] fn leave_if_stmt(& mut self, node :
+        IfStmt, ast : & Ast) { $(self. $idx .leave_if_stmt(node, ast);)+ } #[inline] fn
+        enter_switch_stmt(& mut self, node : SwitchStmt, ast : & Ast) { $(self. $idx
+        .enter_switch_stmt(node, ast);)+ } #[inline] fn leave_switch_stmt(& mut self,
+        node : SwitchStmt, ast : & Ast) { $(self. $idx .leave_switch_stmt(node, ast);)+ }
+        #[inline] fn enter_throw_stmt(& mut self, node : ThrowStmt, ast : & Ast) { $(self
+        . $idx .enter_throw_stmt(node, ast);)+ } #[inline] fn leave_throw_stmt(& mut
+        self, node : ThrowStmt, ast : & Ast) { $(self. $idx .leave_throw_stmt(node,
+        ast);)+ } #[inline] fn enter_try_stmt(& mut self, node : TryStmt, ast : & Ast) {
+        $(self. $idx .enter_try_stmt(node, ast);)+ } #[inline] fn leave_try_stmt(& mut
+        self, node : TryStmt, ast : & Ast) { $(self. $idx .leave_try_stmt(node, ast);)+ }
+        #[inline] fn enter_while_stmt(& mut self, node : WhileStmt, ast : & Ast) { $(self
+        . $idx .enter_while_stmt(node, ast);)+ } #[inline] fn leave_while_stmt(& mut
+        self, node : WhileStmt, ast : & Ast) { $(self. $idx .leave_while_stmt(node,
+        ast);)+ } #[inline] fn enter_do_while_stmt(& mut self, node : DoWhileStmt, ast :
+        & Ast) { $(self. $idx .enter_do_while_stmt(node, ast);)+ } #[inline] fn
+        leave_do_while_stmt(& mut self, node : DoWhileStmt, ast : & Ast) { $(self. $idx
+        .leave_do_while_stmt(node, ast);)+ } #[inline] fn enter_for_stmt(& mut self, node
+        : ForStmt, ast : & Ast) { $(self. $idx .enter_for_stmt(node, ast);)+ } #[inline]
+        fn leave_for_stmt(& mut self, node : ForStmt, ast : & Ast) { $(self. $idx
+        .leave_for_stmt(node, ast);)+ } #[inline] fn enter_for_in_stmt(& mut self, node :
+        ForInStmt, ast : & Ast) { $(self. $idx .enter_for_in_stmt(node, ast);)+ }
+        #[inline] fn leave_for_in_stmt(& mut self, node : ForInStmt, ast : & Ast) {
+        $(self. $idx .leave_for_in_stmt(node, ast);)+ } #[inline] fn enter_for_of_stmt(&
+        mut self, node : ForOfStmt, ast : & Ast) { $(self. $idx .enter_for_of_stmt(node,
+        ast);)+ } #[inline] fn leave_for_of_stmt(& mut self, node : ForOfStmt, ast : &
+        Ast) { $(self. $idx .leave_for_of_stmt(node, ast);)+ } #[inline] fn
+        enter_switch_case(& mut self, node : SwitchCase, ast : & Ast) { $(self. $idx
+        .enter_switch_case(node, ast);)+ } #[inline] fn leave_switch_case(& mut self,
+        node : SwitchCase, ast : & Ast) { $(self. $idx .leave_switch_case(node, ast);)+ }
+        #[inline] fn enter_catch_clause(& mut self, node : CatchClause, ast : & Ast) {
+        $(self. $idx .enter_catch_clause(node, ast);)+ } #[inline] fn
+        leave_catch_clause(& mut self, node : CatchClause, ast : & Ast) { $(self. $idx
+        .leave_catch_clause(node, ast);)+ } #[inline] fn enter_fn_decl(& mut self, node :
+        FnDecl, ast : & Ast) { $(self. $idx .enter_fn_decl(node, ast);)+ } #[inline] fn
+        leave_fn_decl(& mut self, node : FnDecl, ast : & Ast) { $(self. $idx
+        .leave_fn_decl(node, ast);)+ } #[inline] fn enter_class_decl(& mut self, node :
+        ClassDecl, ast : & Ast) { $(self. $idx .enter_class_decl(node, ast);)+ }
+        #[inline] fn leave_class_decl(& mut self, node : ClassDecl, ast : & Ast) { $(self
+        . $idx .leave_class_decl(node, ast);)+ } #[inline] fn enter_var_decl(& mut self,
+        node : VarDecl, ast : & Ast) { $(self. $idx .enter_var_decl(node, ast);)+ }
+        #[inline] fn leave_var_decl(& mut self, node : VarDecl, ast : & Ast) { $(self.
+        $idx .leave_var_decl(node, ast);)+ } #[inline] fn enter_var_declarator(& mut
+        self, node : VarDeclarator, ast : & Ast) { $(self. $idx
+        .enter_var_declarator(node, ast);)+ } #[inline] fn leave_var_declarator(& mut
+        self, node : VarDeclarator, ast : & Ast) { $(self. $idx
+        .leave_var_declarator(node, ast);)+ } #[inline] fn enter_using_decl(& mut self,
+        node : UsingDecl, ast : & Ast) { $(self. $idx .enter_using_decl(node, ast);)+ }
+        #[inline] fn leave_using_decl(& mut self, node : UsingDecl, ast : & Ast) { $(self
+        . $idx .leave_using_decl(node, ast);)+ } #[inline] fn enter_this_expr(& mut self,
+        node : ThisExpr, ast : & Ast) { $(self. $idx .enter_this_expr(node, ast);)+ }
+        #[inline] fn leave_this_expr(& mut self, node : ThisExpr, ast : & Ast) { $(self.
+        $idx .leave_this_expr(node, ast);)+ } #[inline] fn enter_array_lit(& mut self,
+        node : ArrayLit, ast : & Ast) { $(self. $idx .enter_array_lit(node, ast);)+ }
+        #[inline] fn leave_array_lit(& mut self, node : ArrayLit, ast : & Ast) { $(self.
+        $idx .leave_array_lit(node, ast);)+ } #[inline] fn enter_object_lit(& mut self,
+        node : ObjectLit, ast : & Ast) { $(self. $idx .enter_object_lit(node, ast);)+ }
+        #[inline] fn leave_object_lit(& mut self, node : ObjectLit, ast : & Ast) { $(self
+        . $idx .leave_object_lit(node, ast);)+ } #[inline] fn enter_spread_element(& mut
+        self, node : SpreadElement, ast : & Ast) { $(self. $idx
+        .enter_spread_element(node, ast);)+ } #[inline] fn leave_spread_element(& mut
+        self, node : SpreadElement, ast : & Ast) { $(self. $idx
+        .leave_spread_element(node, ast);)+ } #[inline] fn enter_unary_expr(& mut self,
+        node : UnaryExpr, ast : & Ast) { $(self. $idx .enter_unary_expr(node, ast);)+ }
+        #[inline] fn leave_unary_expr(& mut self, node : UnaryExpr, ast : & Ast) { $(self
+        . $idx .leave_unary_expr(node, ast);)+ } #[inline] fn enter_update_expr(& mut
+        self, node : UpdateExpr, ast : & Ast) { $(self. $idx .enter_update_expr(node,
+        ast);)+ } #[inline] fn leave_update_expr(& mut self, node : UpdateExpr, ast : &
+        Ast) { $(self. $idx .leave_update_expr(node, ast);)+ } #[inline] fn
+        enter_bin_expr(& mut self, node : BinExpr, ast : & Ast) { $(self. $idx
+        .enter_bin_expr(node, ast);)+ } #[inline] fn leave_bin_expr(& mut self, node :
+        BinExpr, ast : & Ast) { $(self. $idx .leave_bin_expr(node, ast);)+ } #[inline] fn
+        enter_fn_expr(& mut self, node : FnExpr, ast : & Ast) { $(self. $idx
+        .enter_fn_expr(node, ast);)+ } #[inline] fn leave_fn_expr(& mut self, node :
+        FnExpr, ast : & Ast) { $(self. $idx .leave_fn_expr(node, ast);)+ } #[inline] fn
+        enter_class_expr(& mut self, node : ClassExpr, ast : & Ast) { $(self. $idx
+        .enter_class_expr(node, ast);)+ } #[inline] fn leave_class_expr(& mut self, node
+        : ClassExpr, ast : & Ast) { $(self. $idx .leave_class_expr(node, ast);)+ }
+        #[inline] fn enter_assign_expr(& mut self, node : AssignExpr, ast : & Ast) {
+        $(self. $idx .enter_assign_expr(node, ast);)+ } #[inline] fn leave_assign_expr(&
+        mut self, node : AssignExpr, ast : & Ast) { $(self. $idx .leave_assign_expr(node,
+        ast);)+ } #[inline] fn enter_member_expr(& mut self, node : MemberExpr, ast : &
+        Ast) { $(self. $idx .enter_member_expr(node, ast);)+ } #[inline] fn
+        leave_member_expr(& mut self, node : MemberExpr, ast : & Ast) { $(self. $idx
+        .leave_member_expr(node, ast);)+ } #[inline] fn enter_super_prop_expr(& mut self,
+        node : SuperPropExpr, ast : & Ast) { $(self. $idx .enter_super_prop_expr(node,
+        ast);)+ } #[inline] fn leave_super_prop_expr(& mut self, node : SuperPropExpr,
+        ast : & Ast) { $(self. $idx .leave_super_prop_expr(node, ast);)+ } #[inline] fn
+        enter_cond_expr(& mut self, node : CondExpr, ast : & Ast) { $(self. $idx
+        .enter_cond_expr(node, ast);)+ } #[inline] fn leave_cond_expr(& mut self, node :
+        CondExpr, ast : & Ast) { $(self. $idx .leave_cond_expr(node, ast);)+ } #[inline]
+        fn enter_call_expr(& mut self, node : CallExpr, ast : & Ast) { $(self. $idx
+        .enter_call_expr(node, ast);)+ } #[inline] fn leave_call_expr(& mut self, node :
+        CallExpr, ast : & Ast) { $(self. $idx .leave_call_expr(node, ast);)+ } #[inline]
+        fn enter_new_expr(& mut self, node : NewExpr, ast : & Ast) { $(self. $idx
+        .enter_new_expr(node, ast);)+ } #[inline] fn leave_new_expr(& mut self, node :
+        NewExpr, ast : & Ast) { $(self. $idx .leave_new_expr(node, ast);)+ } #[inline] fn
+        enter_seq_expr(& mut self, node : SeqExpr, ast : & Ast) { $(self. $idx
+        .enter_seq_expr(node, ast);)+ } #[inline] fn leave_seq_expr(& mut self, node :
+        SeqExpr, ast : & Ast) { $(self. $idx .leave_seq_expr(node, ast);)+ } #[inline] fn
+        enter_arrow_expr(& mut self, node : ArrowExpr, ast : & Ast) { $(self. $idx
+        .enter_arrow_expr(node, ast);)+ } #[inline] fn leave_arrow_expr(& mut self, node
+        : ArrowExpr, ast : & Ast) { $(self. $idx .leave_arrow_expr(node, ast);)+ }
+        #[inline] fn enter_yield_expr(& mut self, node : YieldExpr, ast : & Ast) { $(self
+        . $idx .enter_yield_expr(node, ast);)+ } #[inline] fn leave_yield_expr(& mut
+        self, node : YieldExpr, ast : & Ast) { $(self. $idx .leave_yield_expr(node,
+        ast);)+ } #[inline] fn enter_meta_prop_expr(& mut self, node : MetaPropExpr, ast
+        : & Ast) { $(self. $idx .enter_meta_prop_expr(node, ast);)+ } #[inline] fn
+        leave_meta_prop_expr(& mut self, node : MetaPropExpr, ast : & Ast) { $(self. $idx
+        .leave_meta_prop_expr(node, ast);)+ } #[inline] fn enter_await_expr(& mut self,
+        node : AwaitExpr, ast : & Ast) { $(self. $idx .enter_await_expr(node, ast);)+ }
+        #[inline] fn leave_await_expr(& mut self, node : AwaitExpr, ast : & Ast) { $(self
+        . $idx .leave_await_expr(node, ast);)+ } #[inline] fn enter_tpl(& mut self, node
+        : Tpl, ast : & Ast) { $(self. $idx .enter_tpl(node, ast);)+ } #[inline] fn
+        leave_tpl(& mut self, node : Tpl, ast : & Ast) { $(self. $idx .leave_tpl(node,
+        ast);)+ } #[inline] fn enter_tagged_tpl(& mut self, node : TaggedTpl, ast : &
+        Ast) { $(self. $idx .enter_tagged_tpl(node, ast);)+ } #[inline] fn
+        leave_tagged_tpl(& mut self, node : TaggedTpl, ast : & Ast) { $(self. $idx
+        .leave_tagged_tpl(node, ast);)+ } #[inline] fn enter_tpl_element(& mut self, node
+        : TplElement, ast : & Ast) { $(self. $idx .enter_tpl_element(node, ast);)+ }
+        #[inline] fn leave_tpl_element(& mut self, node : TplElement, ast : & Ast) {
+        $(self. $idx .leave_tpl_element(node, ast);)+ } #[inline] fn enter_paren_expr(&
+        mut self, node : ParenExpr, ast : & Ast) { $(self. $idx .enter_paren_expr(node,
+        ast);)+ } #[inline] fn leave_paren_expr(& mut self, node : ParenExpr, ast : &
+        Ast) { $(self. $idx .leave_paren_expr(node, ast);)+ } #[inline] fn enter_super(&
+        mut self, node : Super, ast : & Ast) { $(self. $idx .enter_super(node, ast);)+ }
+        #[inline] fn leave_super(& mut self, node : Super, ast : & Ast) { $(self. $idx
+        .leave_super(node, ast);)+ } #[inline] fn enter_import(& mut self, node : Import,
+        ast : & Ast) { $(self. $idx .enter_import(node, ast);)+ } #[inline] fn
+        leave_import(& mut self, node : Import, ast : & Ast) { $(self. $idx
+        .leave_import(node, ast);)+ } #[inline] fn enter_expr_or_spread(& mut self, node
+        : ExprOrSpread, ast : & Ast) { $(self. $idx .enter_expr_or_spread(node, ast);)+ }
+        #[inline] fn leave_expr_or_spread(& mut self, node : ExprOrSpread, ast : & Ast) {
+        $(self. $idx .leave_expr_or_spread(node, ast);)+ } #[inline] fn
+        enter_spread_dot_3_token(& mut self, node : SpreadDot3Token, ast : & Ast) {
+        $(self. $idx .enter_spread_dot_3_token(node, ast);)+ } #[inline] fn
+        leave_spread_dot_3_token(& mut self, node : SpreadDot3Token, ast : & Ast) {
+        $(self. $idx .leave_spread_dot_3_token(node, ast);)+ } #[inline] fn
+        enter_opt_chain_expr(& mut self, node : OptChainExpr, ast : & Ast) { $(self. $idx
+        .enter_opt_chain_expr(node, ast);)+ } #[inline] fn leave_opt_chain_expr(& mut
+        self, node : OptChainExpr, ast : & Ast) { $(self. $idx
+        .leave_opt_chain_expr(node, ast);)+ } #[inline] fn enter_opt_call(& mut self,
+        node : OptCall, ast : & Ast) { $(self. $idx .enter_opt_call(node, ast);)+ }
+        #[inline] fn leave_opt_call(& mut self, node : OptCall, ast : & Ast) { $(self.
+        $idx .leave_opt_call(node, ast);)+ } #[inline] fn enter_invalid(& mut self, node
+        : Invalid, ast : & Ast) { $(self. $idx .enter_invalid(node, ast);)+ } #[inline]
+        fn leave_invalid(& mut self, node : Invalid, ast : & Ast) { $(self. $idx
+        .leave_invalid(node, ast);)+ } #[inline] fn enter_function(& mut self, node :
+        Function, ast : & Ast) { $(self. $idx .enter_function(node, ast);)+ } #[inline]
+        fn leave_function(& mut self, node : Function, ast : & Ast) { $(self. $idx
+        .leave_function(node, ast);)+ } #[inline] fn enter_param(& mut self, node :
+        Param, ast : & Ast) { $(self. $idx .enter_param(node, ast);)+ } #[inline] fn
+        leave_param(& mut self, node : Param, ast : & Ast) { $(self. $idx
+        .leave_param(node, ast);)+ } #[inline] fn enter_class(& mut self, node : Class,
+        ast : & Ast) { $(self. $idx .enter_class(node, ast);)+ } #[inline] fn
+        leave_class(& mut self, node : Class, ast : & Ast) { $(self. $idx
+        .leave_class(node, ast);)+ } #[inline] fn enter_class_prop(& mut self, node :
+        ClassProp, ast : & Ast) { $(self. $idx .enter_class_prop(node, ast);)+ }
+        #[inline] fn leave_class_prop(& mut self, node : ClassProp, ast : & Ast) { $(self
+        . $idx .leave_class_prop(node, ast);)+ } #[inline] fn enter_private_prop(& mut
+        self, node : PrivateProp, ast : & Ast) { $(self. $idx .enter_private_prop(node,
+        ast);)+ } #[inline] fn leave_private_prop(& mut self, node : PrivateProp, ast : &
+        Ast) { $(self. $idx .leave_private_prop(node, ast);)+ } #[inline] fn
+        enter_class_method(& mut self, node : ClassMethod, ast : & Ast) { $(self. $idx
+        .enter_class_method(node, ast);)+ } #[inline] fn leave_class_method(& mut self,
+        node : ClassMethod, ast : & Ast) { $(self. $idx .leave_class_method(node, ast);)+
+        } #[inline] fn enter_private_method(& mut self, node : PrivateMethod, ast : &
+        Ast) { $(self. $idx .enter_private_method(node, ast);)+ } #[inline] fn
+        leave_private_method(& mut self, node : PrivateMethod, ast : & Ast) { $(self.
+        $idx .leave_private_method(node, ast);)+ } #[inline] fn enter_constructor(& mut
+        self, node : Constructor, ast : & Ast) { $(self. $idx .enter_constructor(node,
+        ast);)+ } #[inline] fn leave_constructor(& mut self, node : Constructor, ast : &
+        Ast) { $(self. $idx .leave_constructor(node, ast);)+ } #[inline] fn
+        enter_decorator(& mut self, node : Decorator, ast : & Ast) { $(self. $idx
+        .enter_decorator(node, ast);)+ } #[inline] fn leave_decorator(& mut self, node :
+        Decorator, ast : & Ast) { $(self. $idx .leave_decorator(node, ast);)+ } #[inline]
+        fn enter_static_block(& mut self, node : StaticBlock, ast : & Ast) { $(self. $idx
+        .enter_static_block(node, ast);)+ } #[inline] fn leave_static_block(& mut self,
+        node : StaticBlock, ast : & Ast) { $(self. $idx .leave_static_block(node, ast);)+
+        } #[inline] fn enter_auto_accessor(& mut self, node : AutoAccessor, ast : & Ast)
+        { $(self. $idx .enter_auto_accessor(node, ast);)+ } #[inline] fn
+        leave_auto_accessor(& mut self, node : AutoAccessor, ast : & Ast) { $(self. $idx
+        .leave_auto_accessor(node, ast);)+ } #[inline] fn enter_key_value_prop(& mut
+        self, node : KeyValueProp, ast : & Ast) { $(self. $idx
+        .enter_key_value_prop(node, ast);)+ } #[inline] fn leave_key_value_prop(& mut
+        self, node : KeyValueProp, ast : & Ast) { $(self. $idx
+        .leave_key_value_prop(node, ast);)+ } #[inline] fn enter_assign_prop(& mut self,
+        node : AssignProp, ast : & Ast) { $(self. $idx .enter_assign_prop(node, ast);)+ }
+        #[inline] fn leave_assign_prop(& mut self, node : AssignProp, ast : & Ast) {
+        $(self. $idx .leave_assign_prop(node, ast);)+ } #[inline] fn enter_getter_prop(&
+        mut self, node : GetterProp, ast : & Ast) { $(self. $idx .enter_getter_prop(node,
+        ast);)+ } #[inline] fn leave_getter_prop(& mut self, node : GetterProp, ast : &
+        Ast) { $(self. $idx .leave_getter_prop(node, ast);)+ } #[inline] fn
+        enter_setter_prop(& mut self, node : SetterProp, ast : & Ast) { $(self. $idx
+        .enter_setter_prop(node, ast);)+ } #[inline] fn leave_setter_prop(& mut self,
+        node : SetterProp, ast : & Ast) { $(self. $idx .leave_setter_prop(node, ast);)+ }
+        #[inline] fn enter_method_prop(& mut self, node : MethodProp, ast : & Ast) {
+        $(self. $idx .enter_method_prop(node, ast);)+ } #[inline] fn leave_method_prop(&
+        mut self, node : MethodProp, ast : & Ast) { $(self. $idx .leave_method_prop(node,
+        ast);)+ } #[inline] fn enter_computed_prop_name(& mut self, node :
+        ComputedPropName, ast : & Ast) { $(self. $idx .enter_computed_prop_name(node,
+        ast);)+ } #[inline] fn leave_computed_prop_name(& mut self, node :
+        ComputedPropName, ast : & Ast) { $(self. $idx .leave_computed_prop_name(node,
+        ast);)+ } #[inline] fn enter_array_pat(& mut self, node : ArrayPat, ast : & Ast)
+        { $(self. $idx .enter_array_pat(node, ast);)+ } #[inline] fn leave_array_pat(&
+        mut self, node : ArrayPat, ast : & Ast) { $(self. $idx .leave_array_pat(node,
+        ast);)+ } #[inline] fn enter_object_pat(& mut self, node : ObjectPat, ast : &
+        Ast) { $(self. $idx .enter_object_pat(node, ast);)+ } #[inline] fn
+        leave_object_pat(& mut self, node : ObjectPat, ast : & Ast) { $(self. $idx
+        .leave_object_pat(node, ast);)+ } #[inline] fn enter_assign_pat(& mut self, node
+        : AssignPat, ast : & Ast) { $(self. $idx .enter_assign_pat(node, ast);)+ }
+        #[inline] fn leave_assign_pat(& mut self, node : AssignPat, ast : & Ast) { $(self
+        . $idx .leave_assign_pat(node, ast);)+ } #[inline] fn enter_rest_pat(& mut self,
+        node : RestPat, ast : & Ast) { $(self. $idx .enter_rest_pat(node, ast);)+ }
+        #[inline] fn leave_rest_pat(& mut self, node : RestPat, ast : & Ast) { $(self.
+        $idx .leave_rest_pat(node, ast);)+ } #[inline] fn enter_key_value_pat_prop(& mut
+        self, node : KeyValuePatProp, ast : & Ast) { $(self. $idx
+        .enter_key_value_pat_prop(node, ast);)+ } #[inline] fn leave_key_value_pat_prop(&
+        mut self, node : KeyValuePatProp, ast : & Ast) { $(self. $idx
+        .leave_key_value_pat_prop(node, ast);)+ } #[inline] fn enter_assign_pat_prop(&
+        mut self, node : AssignPatProp, ast : & Ast) { $(self. $idx
+        .enter_assign_pat_prop(node, ast);)+ } #[inline] fn leave_assign_pat_prop(& mut
+        self, node : AssignPatProp, ast : & Ast) { $(self. $idx
+        .leave_assign_pat_prop(node, ast);)+ } #[inline] fn enter_ident(& mut self, node
+        : Ident, ast : & Ast) { $(self. $idx .enter_ident(node, ast);)+ } #[inline] fn
+        leave_ident(& mut self, node : Ident, ast : & Ast) { $(self. $idx
+        .leave_ident(node, ast);)+ } #[inline] fn enter_ident_name(& mut self, node :
+        IdentName, ast : & Ast) { $(self. $idx .enter_ident_name(node, ast);)+ }
+        #[inline] fn leave_ident_name(& mut self, node : IdentName, ast : & Ast) { $(self
+        . $idx .leave_ident_name(node, ast);)+ } #[inline] fn enter_private_name(& mut
+        self, node : PrivateName, ast : & Ast) { $(self. $idx .enter_private_name(node,
+        ast);)+ } #[inline] fn leave_private_name(& mut self, node : PrivateName, ast : &
+        Ast) { $(self. $idx .leave_private_name(node, ast);)+ } #[inline] fn
+        enter_binding_ident(& mut self, node : BindingIdent, ast : & Ast) { $(self. $idx
+        .enter_binding_ident(node, ast);)+ } #[inline] fn leave_binding_ident(& mut self,
+        node : BindingIdent, ast : & Ast) { $(self. $idx .leave_binding_ident(node,
+        ast);)+ } #[inline] fn enter_str(& mut self, node : Str, ast : & Ast) { $(self.
+        $idx .enter_str(node, ast);)+ } #[inline] fn leave_str(& mut self, node : Str,
+        ast : & Ast) { $(self. $idx .leave_str(node, ast);)+ } #[inline] fn enter_bool(&
+        mut self, node : Bool, ast : & Ast) { $(self. $idx .enter_bool(node, ast);)+ }
+        #[inline] fn leave_bool(& mut self, node : Bool, ast : & Ast) { $(self. $idx
+        .leave_bool(node, ast);)+ } #[inline] fn enter_null(& mut self, node : Null, ast
+        : & Ast) { $(self. $idx .enter_null(node, ast);)+ } #[inline] fn leave_null(& mut
+        self, node : Null, ast : & Ast) { $(self. $idx .leave_null(node, ast);)+ }
+        #[inline] fn enter_number(& mut self, node : Number, ast : & Ast) { $(self. $idx
+        .enter_number(node, ast);)+ } #[inline] fn leave_number(& mut self, node :
+        Number, ast : & Ast) { $(self. $idx .leave_number(node, ast);)+ } #[inline] fn
+        enter_big_int(& mut self, node : BigInt, ast : & Ast) { $(self. $idx
+        .enter_big_int(node, ast);)+ } #[inline] fn leave_big_int(& mut self, node :
+        BigInt, ast : & Ast) { $(self. $idx .leave_big_int(node, ast);)+ } #[inline] fn
+        enter_regex(& mut self, node : Regex, ast : & Ast) { $(self. $idx
+        .enter_regex(node, ast);)+ } #[inline] fn leave_regex(& mut self, node : Regex,
+        ast : & Ast) { $(self. $idx .leave_regex(node, ast);)+ } #[inline] fn
+        enter_jsx_member_expr(& mut self, node : JSXMemberExpr, ast : & Ast) { $(self.
+        $idx .enter_jsx_member_expr(node, ast);)+ } #[inline] fn leave_jsx_member_expr(&
+        mut self, node : JSXMemberExpr, ast : & Ast) { $(self. $idx
+        .leave_jsx_member_expr(node, ast);)+ } #[inline] fn enter_jsx_namespaced_name(&
+        mut self, node : JSXNamespacedName, ast : & Ast) { $(self. $idx
+        .enter_jsx_namespaced_name(node, ast);)+ } #[inline] fn
+        leave_jsx_namespaced_name(& mut self, node : JSXNamespacedName, ast : & Ast) {
+        $(self. $idx .leave_jsx_namespaced_name(node, ast);)+ } #[inline] fn
+        enter_jsx_empty_expr(& mut self, node : JSXEmptyExpr, ast : & Ast) { $(self. $idx
+        .enter_jsx_empty_expr(node, ast);)+ } #[inline] fn leave_jsx_empty_expr(& mut
+        self, node : JSXEmptyExpr, ast : & Ast) { $(self. $idx
+        .leave_jsx_empty_expr(node, ast);)+ } #[inline] fn enter_jsx_expr_container(& mut
+        self, node : JSXExprContainer, ast : & Ast) { $(self. $idx
+        .enter_jsx_expr_container(node, ast);)+ } #[inline] fn leave_jsx_expr_container(&
+        mut self, node : JSXExprContainer, ast : & Ast) { $(self. $idx
+        .leave_jsx_expr_container(node, ast);)+ } #[inline] fn enter_jsx_spread_child(&
+        mut self, node : JSXSpreadChild, ast : & Ast) { $(self. $idx
+        .enter_jsx_spread_child(node, ast);)+ } #[inline] fn leave_jsx_spread_child(& mut
+        self, node : JSXSpreadChild, ast : & Ast) { $(self. $idx
+        .leave_jsx_spread_child(node, ast);)+ } #[inline] fn enter_jsx_opening_element(&
+        mut self, node : JSXOpeningElement, ast : & Ast) { $(self. $idx
+        .enter_jsx_opening_element(node, ast);)+ } #[inline] fn
+        leave_jsx_opening_element(& mut self, node : JSXOpeningElement, ast : & Ast) {
+        $(self. $idx .leave_jsx_opening_element(node, ast);)+ } #[inline] fn
+        enter_jsx_closing_element(& mut self, node : JSXClosingElement, ast : & Ast) {
+        $(self. $idx .enter_jsx_closing_element(node, ast);)+ } #[inline] fn
+        leave_jsx_closing_element(& mut self, node : JSXClosingElement, ast : & Ast) {
+        $(self. $idx .leave_jsx_closing_element(node, ast);)+ } #[inline] fn
+        enter_jsx_attr(& mut self, node : JSXAttr, ast : & Ast) { $(self. $idx
+        .enter_jsx_attr(node, ast);)+ } #[inline] fn leave_jsx_attr(& mut self, node :
+        JSXAttr, ast : & Ast) { $(self. $idx .leave_jsx_attr(node, ast);)+ } #[inline] fn
+        enter_jsx_text(& mut self, node : JSXText, ast : & Ast) { $(self. $idx
+        .enter_jsx_text(node, ast);)+ } #[inline] fn leave_jsx_text(& mut self, node :
+        JSXText, ast : & Ast) { $(self. $idx .leave_jsx_text(node, ast);)+ } #[inline] fn
+        enter_jsx_element(& mut self, node : JSXElement, ast : & Ast) { $(self. $idx
+        .enter_jsx_element(node, ast);)+ } #[inline] fn leave_jsx_element(& mut self,
+        node : JSXElement, ast : & Ast) { $(self. $idx .leave_jsx_element(node, ast);)+ }
+        #[inline] fn enter_jsx_fragment(& mut self, node : JSXFragment, ast : & Ast) {
+        $(self. $idx .enter_jsx_fragment(node, ast);)+ } #[inline] fn
+        leave_jsx_fragment(& mut self, node : JSXFragment, ast : & Ast) { $(self. $idx
+        .leave_jsx_fragment(node, ast);)+ } #[inline] fn enter_jsx_opening_fragment(& mut
+        self, node : JSXOpeningFragment, ast : & Ast) { $(self. $idx
+        .enter_jsx_opening_fragment(node, ast);)+ } #[inline] fn
+        leave_jsx_opening_fragment(& mut self, node : JSXOpeningFragment, ast : & Ast) {
+        $(self. $idx .leave_jsx_opening_fragment(node, ast);)+ } #[inline] fn
+        enter_jsx_closing_fragment(& mut self, node : JSXClosingFragment, ast : & Ast) {
+        $(self. $idx .enter_jsx_closing_fragment(node, ast);)+ } #[inline] fn
+        leave_jsx_closing_fragment(& mut self, node : JSXClosingFragment, ast : & Ast) {
+        $(self. $idx .leave_jsx_closing_fragment(node, ast);)+ } #[inline] fn
+        enter_ts_type_ann(& mut self, node : TsTypeAnn, ast : & Ast) { $(self. $idx
+        .enter_ts_type_ann(node, ast);)+ } #[inline] fn leave_ts_type_ann(& mut self,
+        node : TsTypeAnn, ast : & Ast) { $(self. $idx .leave_ts_type_ann(node, ast);)+ }
+        #[inline] fn enter_ts_type_param_decl(& mut self, node : TsTypeParamDecl, ast : &
+        Ast) { $(self. $idx .enter_ts_type_param_decl(node, ast);)+ } #[inline] fn
+        leave_ts_type_param_decl(& mut self, node : TsTypeParamDecl, ast : & Ast) {
+        $(self. $idx .leave_ts_type_param_decl(node, ast);)+ } #[inline] fn
+        enter_ts_type_param(& mut self, node : TsTypeParam, ast : & Ast) { $(self. $idx
+        .enter_ts_type_param(node, ast);)+ } #[inline] fn leave_ts_type_param(& mut self,
+        node : TsTypeParam, ast : & Ast) { $(self. $idx .leave_ts_type_param(node,
+        ast);)+ } #[inline] fn enter_ts_type_param_instantiation(& mut self, node :
+        TsTypeParamInstantiation, ast : & Ast) { $(self. $idx
+        .enter_ts_type_param_instantiation(node, ast);)+ } #[inline] fn
+        leave_ts_type_param_instantiation(& mut self, node : TsTypeParamInstantiation,
+        ast : & Ast) { $(self. $idx .leave_ts_type_param_instantiation(node, ast);)+ }
+        #[inline] fn enter_ts_param_prop(& mut self, node : TsParamProp, ast : & Ast) {
+        $(self. $idx .enter_ts_param_prop(node, ast);)+ } #[inline] fn
+        leave_ts_param_prop(& mut self, node : TsParamProp, ast : & Ast) { $(self. $idx
+        .leave_ts_param_prop(node, ast);)+ } #[inline] fn enter_ts_qualified_name(& mut
+        self, node : TsQualifiedName, ast : & Ast) { $(self. $idx
+        .enter_ts_qualified_name(node, ast);)+ } #[inline] fn leave_ts_qualified_name(&
+        mut self, node : TsQualifiedName, ast : & Ast) { $(self. $idx
+        .leave_ts_qualified_name(node, ast);)+ } #[inline] fn
+        enter_ts_call_signature_decl(& mut self, node : TsCallSignatureDecl, ast : & Ast)
+        { $(self. $idx .enter_ts_call_signature_decl(node, ast);)+ } #[inline] fn
+        leave_ts_call_signature_decl(& mut self, node : TsCallSignatureDecl, ast : & Ast)
+        { $(self. $idx .leave_ts_call_signature_decl(node, ast);)+ } #[inline] fn
+        enter_ts_construct_signature_decl(& mut self, node : TsConstructSignatureDecl,
+        ast : & Ast) { $(self. $idx .enter_ts_construct_signature_decl(node, ast);)+ }
+        #[inline] fn leave_ts_construct_signature_decl(& mut self, node :
+        TsConstructSignatureDecl, ast : & Ast) { $(self. $idx
+        .leave_ts_construct_signature_decl(node, ast);)+ } #[inline] fn
+        enter_ts_property_signature(& mut self, node : TsPropertySignature, ast : & Ast)
+        { $(self. $idx .enter_ts_property_signature(node, ast);)+ } #[inline] fn
+        leave_ts_property_signature(& mut self, node : TsPropertySignature, ast : & Ast)
+        { $(self. $idx .leave_ts_property_signature(node, ast);)+ } #[inline] fn
+        enter_ts_getter_signature(& mut self, node : TsGetterSignature, ast : & Ast) {
+        $(self. $idx .enter_ts_getter_signature(node, ast);)+ } #[inline] fn
+        leave_ts_getter_signature(& mut self, node : TsGetterSignature, ast : & Ast) {
+        $(self. $idx .leave_ts_getter_signature(node, ast);)+ } #[inline] fn
+        enter_ts_setter_signature(& mut self, node : TsSetterSignature, ast : & Ast) {
+        $(self. $idx .enter_ts_setter_signature(node, ast);)+ } #[inline] fn
+        leave_ts_setter_signature(& mut self, node : TsSetterSignature, ast : & Ast) {
+        $(self. $idx .leave_ts_setter_signature(node, ast);)+ } #[inline] fn
+        enter_ts_method_signature(& mut self, node : TsMethodSignature, ast : & Ast) {
+        $(self. $idx .enter_ts_method_signature(node, ast);)+ } #[inline] fn
+        leave_ts_method_signature(& mut self, node : TsMethodSignature, ast : & Ast) {
+        $(self. $idx .leave_ts_method_signature(node, ast);)+ } #[inline] fn
+        enter_ts_index_signature(& mut self, node : TsIndexSignature, ast : & Ast) {
+        $(self. $idx .enter_ts_index_signature(node, ast);)+ } #[inline] fn
+        leave_ts_index_signature(& mut self, node : TsIndexSignature, ast : & Ast) {
+        $(self. $idx .leave_ts_index_signature(node, ast);)+ } #[inline] fn
+        enter_ts_keyword_type(& mut self, node : TsKeywordType, ast : & Ast) { $(self.
+        $idx .enter_ts_keyword_type(node, ast);)+ } #[inline] fn leave_ts_keyword_type(&
+        mut self, node : TsKeywordType, ast : & Ast) { $(self. $idx
+        .leave_ts_keyword_type(node, ast);)+ } #[inline] fn enter_ts_this_type(& mut
+        self, node : TsThisType, ast : & Ast) { $(self. $idx .enter_ts_this_type(node,
+        ast);)+ } #[inline] fn leave_ts_this_type(& mut self, node : TsThisType, ast : &
+        Ast) { $(self. $idx .leave_ts_this_type(node, ast);)+ } #[inline] fn
+        enter_ts_fn_type(& mut self, node : TsFnType, ast : & Ast) { $(self. $idx
+        .enter_ts_fn_type(node, ast);)+ } #[inline] fn leave_ts_fn_type(& mut self, node
+        : TsFnType, ast : & Ast) { $(self. $idx .leave_ts_fn_type(node, ast);)+ }
+        #[inline] fn enter_ts_constructor_type(& mut self, node : TsConstructorType, ast
+        : & Ast) { $(self. $idx .enter_ts_constructor_type(node, ast);)+ } #[inline] fn
+        leave_ts_constructor_type(& mut self, node : TsConstructorType, ast : & Ast) {
+        $(self. $idx .leave_ts_constructor_type(node, ast);)+ } #[inline] fn
+        enter_ts_type_ref(& mut self, node : TsTypeRef, ast : & Ast) { $(self. $idx
+        .enter_ts_type_ref(node, ast);)+ } #[inline] fn leave_ts_type_ref(& mut self,
+        node : TsTypeRef, ast : & Ast) { $(self. $idx .leave_ts_type_ref(node, ast);)+ }
+        #[inline] fn enter_ts_type_predicate(& mut self, node : TsTypePredicate, ast : &
+        Ast) { $(self. $idx .enter_ts_type_predicate(node, ast);)+ } #[inline] fn
+        leave_ts_type_predicate(& mut self, node : TsTypePredicate, ast : & Ast) { $(self
+        . $idx .leave_ts_type_predicate(node, ast);)+ } #[inline] fn
+        enter_ts_type_query(& mut self, node : TsTypeQuery, ast : & Ast) { $(self. $idx
+        .enter_ts_type_query(node, ast);)+ } #[inline] fn leave_ts_type_query(& mut self,
+        node : TsTypeQuery, ast : & Ast) { $(self. $idx .leave_ts_type_query(node,
+        ast);)+ } #[inline] fn enter_ts_import_call_options(& mut self, node :
+        TsImportCallOptions, ast : & Ast) { $(self. $idx
+        .enter_ts_import_call_options(node, ast);)+ } #[inline] fn
+        leave_ts_import_call_options(& mut self, node : TsImportCallOptions, ast : & Ast)
+        { $(self. $idx .leave_ts_import_call_options(node, ast);)+ } #[inline] fn
+        enter_ts_import_type(& mut self, node : TsImportType, ast : & Ast) { $(self. $idx
+        .enter_ts_import_type(node, ast);)+ } #[inline] fn leave_ts_import_type(& mut
+        self, node : TsImportType, ast : & Ast) { $(self. $idx
+        .leave_ts_import_type(node, ast);)+ } #[inline] fn enter_ts_type_lit(& mut self,
+        node : TsTypeLit, ast : & Ast) { $(self. $idx .enter_ts_type_lit(node, ast);)+ }
+        #[inline] fn leave_ts_type_lit(& mut self, node : TsTypeLit, ast : & Ast) {
+        $(self. $idx .leave_ts_type_lit(node, ast);)+ } #[inline] fn
+        enter_ts_array_type(& mut self, node : TsArrayType, ast : & Ast) { $(self. $idx
+        .enter_ts_array_type(node, ast);)+ } #[inline] fn leave_ts_array_type(& mut self,
+        node : TsArrayType, ast : & Ast) { $(self. $idx .leave_ts_array_type(node,
+        ast);)+ } #[inline] fn enter_ts_tuple_type(& mut self, node : TsTupleType, ast :
+        & Ast) { $(self. $idx .enter_ts_tuple_type(node, ast);)+ } #[inline] fn
+        leave_ts_tuple_type(& mut self, node : TsTupleType, ast : & Ast) { $(self. $idx
+        .leave_ts_tuple_type(node, ast);)+ } #[inline] fn enter_ts_tuple_element(& mut
+        self, node : TsTupleElement, ast : & Ast) { $(self. $idx
+        .enter_ts_tuple_element(node, ast);)+ } #[inline] fn leave_ts_tuple_element(& mut
+        self, node : TsTupleElement, ast : & Ast) { $(self. $idx
+        .leave_ts_tuple_element(node, ast);)+ } #[inline] fn enter_ts_optional_type(& mut
+        self, node : TsOptionalType, ast : & Ast) { $(self. $idx
+        .enter_ts_optional_type(node, ast);)+ } #[inline] fn leave_ts_optional_type(& mut
+        self, node : TsOptionalType, ast : & Ast) { $(self. $idx
+        .leave_ts_optional_type(node, ast);)+ } #[inline] fn enter_ts_rest_type(& mut
+        self, node : TsRestType, ast : & Ast) { $(self. $idx .enter_ts_rest_type(node,
+        ast);)+ } #[inline] fn leave_ts_rest_type(& mut self, node : TsRestType, ast : &
+        Ast) { $(self. $idx .leave_ts_rest_type(node, ast);)+ } #[inline] fn
+        enter_ts_union_type(& mut self, node : TsUnionType, ast : & Ast) { $(self. $idx
+        .enter_ts_union_type(node, ast);)+ } #[inline] fn leave_ts_union_type(& mut self,
+        node : TsUnionType, ast : & Ast) { $(self. $idx .leave_ts_union_type(node,
+        ast);)+ } #[inline] fn enter_ts_intersection_type(& mut self, node :
+        TsIntersectionType, ast : & Ast) { $(self. $idx .enter_ts_intersection_type(node,
+        ast);)+ } #[inline] fn leave_ts_intersection_type(& mut self, node :
+        TsIntersectionType, ast : & Ast) { $(self. $idx .leave_ts_intersection_type(node,
+        ast);)+ } #[inline] fn enter_ts_conditional_type(& mut self, node :
+        TsConditionalType, ast : & Ast) { $(self. $idx .enter_ts_conditional_type(node,
+        ast);)+ } #[inline] fn leave_ts_conditional_type(& mut self, node :
+        TsConditionalType, ast : & Ast) { $(self. $idx .leave_ts_conditional_type(node,
+        ast);)+ } #[inline] fn enter_ts_infer_type(& mut self, node : TsInferType, ast :
+        & Ast) { $(self. $idx .enter_ts_infer_type(node, ast);)+ } #[inline] fn
+        leave_ts_infer_type(& mut self, node : TsInferType, ast : & Ast) { $(self. $idx
+        .leave_ts_infer_type(node, ast);)+ } #[inline] fn enter_ts_parenthesized_type(&
+        mut self, node : TsParenthesizedType, ast : & Ast) { $(self. $idx
+        .enter_ts_parenthesized_type(node, ast);)+ } #[inline] fn
+        leave_ts_parenthesized_type(& mut self, node : TsParenthesizedType, ast : & Ast)
+        { $(self. $idx .leave_ts_parenthesized_type(node, ast);)+ } #[inline] fn
+        enter_ts_type_operator(& mut self, node : TsTypeOperator, ast : & Ast) { $(self.
+        $idx .enter_ts_type_operator(node, ast);)+ } #[inline] fn
+        leave_ts_type_operator(& mut self, node : TsTypeOperator, ast : & Ast) { $(self.
+        $idx .leave_ts_type_operator(node, ast);)+ } #[inline] fn
+        enter_ts_indexed_access_type(& mut self, node : TsIndexedAccessType, ast : & Ast)
+        { $(self. $idx .enter_ts_indexed_access_type(node, ast);)+ } #[inline] fn
+        leave_ts_indexed_access_type(& mut self, node : TsIndexedAccessType, ast : & Ast)
+        { $(self. $idx .leave_ts_indexed_access_type(node, ast);)+ } #[inline] fn
+        enter_ts_mapped_type(& mut self, node : TsMappedType, ast : & Ast) { $(self. $idx
+        .enter_ts_mapped_type(node, ast);)+ } #[inline] fn leave_ts_mapped_type(& mut
+        self, node : TsMappedType, ast : & Ast) { $(self. $idx
+        .leave_ts_mapped_type(node, ast);)+ } #[inline] fn enter_ts_lit_type(& mut self,
+        node : TsLitType, ast : & Ast) { $(self. $idx .enter_ts_lit_type(node, ast);)+ }
+        #[inline] fn leave_ts_lit_type(& mut self, node : TsLitType, ast : & Ast) {
+        $(self. $idx .leave_ts_lit_type(node, ast);)+ } #[inline] fn
+        enter_ts_tpl_lit_type(& mut self, node : TsTplLitType, ast : & Ast) { $(self.
+        $idx .enter_ts_tpl_lit_type(node, ast);)+ } #[inline] fn leave_ts_tpl_lit_type(&
+        mut self, node : TsTplLitType, ast : & Ast) { $(self. $idx
+        .leave_ts_tpl_lit_type(node, ast);)+ } #[inline] fn enter_ts_interface_decl(& mut
+        self, node : TsInterfaceDecl, ast : & Ast) { $(self. $idx
+        .enter_ts_interface_decl(node, ast);)+ } #[inline] fn leave_ts_interface_decl(&
+        mut self, node : TsInterfaceDecl, ast : & Ast) { $(self. $idx
+        .leave_ts_interface_decl(node, ast);)+ } #[inline] fn enter_ts_interface_body(&
+        mut self, node : TsInterfaceBody, ast : & Ast) { $(self. $idx
+        .enter_ts_interface_body(node, ast);)+ } #[inline] fn leave_ts_interface_body(&
+        mut self, node : TsInterfaceBody, ast : & Ast) { $(self. $idx
+        .leave_ts_interface_body(node, ast);)+ } #[inline] fn
+        enter_ts_expr_with_type_args(& mut self, node : TsExprWithTypeArgs, ast : & Ast)
+        { $(self. $idx .enter_ts_expr_with_type_args(node, ast);)+ } #[inline] fn
+        leave_ts_expr_with_type_args(& mut self, node : TsExprWithTypeArgs, ast : & Ast)
+        { $(self. $idx .leave_ts_expr_with_type_args(node, ast);)+ } #[inline] fn
+        enter_ts_type_alias_decl(& mut self, node : TsTypeAliasDecl, ast : & Ast) {
+        $(self. $idx .enter_ts_type_alias_decl(node, ast);)+ } #[inline] fn
+        leave_ts_type_alias_decl(& mut self, node : TsTypeAliasDecl, ast : & Ast) {
+        $(self. $idx .leave_ts_type_alias_decl(node, ast);)+ } #[inline] fn
+        enter_ts_enum_decl(& mut self, node : TsEnumDecl, ast : & Ast) { $(self. $idx
+        .enter_ts_enum_decl(node, ast);)+ } #[inline] fn leave_ts_enum_decl(& mut self,
+        node : TsEnumDecl, ast : & Ast) { $(self. $idx .leave_ts_enum_decl(node, ast);)+
+        } #[inline] fn enter_ts_enum_member(& mut self, node : TsEnumMember, ast : & Ast)
+        { $(self. $idx .enter_ts_enum_member(node, ast);)+ } #[inline] fn
+        leave_ts_enum_member(& mut self, node : TsEnumMember, ast : & Ast) { $(self. $idx
+        .leave_ts_enum_member(node, ast);)+ } #[inline] fn enter_ts_module_decl(& mut
+        self, node : TsModuleDecl, ast : & Ast) { $(self. $idx
+        .enter_ts_module_decl(node, ast);)+ } #[inline] fn leave_ts_module_decl(& mut
+        self, node : TsModuleDecl, ast : & Ast) { $(self. $idx
+        .leave_ts_module_decl(node, ast);)+ } #[inline] fn enter_ts_module_block(& mut
+        self, node : TsModuleBlock, ast : & Ast) { $(self. $idx
+        .enter_ts_module_block(node, ast);)+ } #[inline] fn leave_ts_module_block(& mut
+        self, node : TsModuleBlock, ast : & Ast) { $(self. $idx
+        .leave_ts_module_block(node, ast);)+ } #[inline] fn enter_ts_namespace_decl(& mut
+        self, node : TsNamespaceDecl, ast : & Ast) { $(self. $idx
+        .enter_ts_namespace_decl(node, ast);)+ } #[inline] fn leave_ts_namespace_decl(&
+        mut self, node : TsNamespaceDecl, ast : & Ast) { $(self. $idx
+        .leave_ts_namespace_decl(node, ast);)+ } #[inline] fn
+        enter_ts_import_equals_decl(& mut self, node : TsImportEqualsDecl, ast : & Ast) {
+        $(self. $idx .enter_ts_import_equals_decl(node, ast);)+ } #[inline] fn
+        leave_ts_import_equals_decl(& mut self, node : TsImportEqualsDecl, ast : & Ast) {
+        $(self. $idx .leave_ts_import_equals_decl(node, ast);)+ } #[inline] fn
+        enter_ts_external_module_ref(& mut self, node : TsExternalModuleRef, ast : & Ast)
+        { $(self. $idx .enter_ts_external_module_ref(node, ast);)+ } #[inline] fn
+        leave_ts_external_module_ref(& mut self, node : TsExternalModuleRef, ast : & Ast)
+        { $(self. $idx .leave_ts_external_module_ref(node, ast);)+ } #[inline] fn
+        enter_ts_export_assignment(& mut self, node : TsExportAssignment, ast : & Ast) {
+        $(self. $idx .enter_ts_export_assignment(node, ast);)+ } #[inline] fn
+        leave_ts_export_assignment(& mut self, node : TsExportAssignment, ast : & Ast) {
+        $(self. $idx .leave_ts_export_assignment(node, ast);)+ } #[inline] fn
+        enter_ts_namespace_export_decl(& mut self, node : TsNamespaceExportDecl, ast : &
+        Ast) { $(self. $idx .enter_ts_namespace_export_decl(node, ast);)+ } #[inline] fn
+        leave_ts_namespace_export_decl(& mut self, node : TsNamespaceExportDecl, ast : &
+        Ast) { $(self. $idx .leave_ts_namespace_export_decl(node, ast);)+ } #[inline] fn
+        enter_ts_as_expr(& mut self, node : TsAsExpr, ast : & Ast) { $(self. $idx
+        .enter_ts_as_expr(node, ast);)+ } #[inline] fn leave_ts_as_expr(& mut self, node
+        : TsAsExpr, ast : & Ast) { $(self. $idx .leave_ts_as_expr(node, ast);)+ }
+        #[inline] fn enter_ts_type_assertion(& mut self, node : TsTypeAssertion, ast : &
+        Ast) { $(self. $idx .enter_ts_type_assertion(node, ast);)+ } #[inline] fn
+        leave_ts_type_assertion(& mut self, node : TsTypeAssertion, ast : & Ast) { $(self
+        . $idx .leave_ts_type_assertion(node, ast);)+ } #[inline] fn
+        enter_ts_non_null_expr(& mut self, node : TsNonNullExpr, ast : & Ast) { $(self.
+        $idx .enter_ts_non_null_expr(node, ast);)+ } #[inline] fn
+        leave_ts_non_null_expr(& mut self, node : TsNonNullExpr, ast : & Ast) { $(self.
+        $idx .leave_ts_non_null_expr(node, ast);)+ } #[inline] fn
+        enter_ts_satisfies_expr(& mut self, node : TsSatisfiesExpr, ast : & Ast) { $(self
+        . $idx .enter_ts_satisfies_expr(node, ast);)+ } #[inline] fn
+        leave_ts_satisfies_expr(& mut self, node : TsSatisfiesExpr, ast : & Ast) { $(self
+        . $idx .leave_ts_satisfies_expr(node, ast);)+ } #[inline] fn
+        enter_ts_const_assertion(& mut self, node : TsConstAssertion, ast : & Ast) {
+        $(self. $idx .enter_ts_const_assertion(node, ast);)+ } #[inline] fn
+        leave_ts_const_assertion(& mut self, node : TsConstAssertion, ast : & Ast) {
+        $(self. $idx .leave_ts_const_assertion(node, ast);)+ } #[inline] fn
+        enter_ts_instantiation(& mut self, node : TsInstantiation, ast : & Ast) { $(self.
+        $idx .enter_ts_instantiation(node, ast);)+ } #[inline] fn
+        leave_ts_instantiation(& mut self, node : TsInstantiation, ast : & Ast) { $(self.
+        $idx .leave_ts_instantiation(node, ast);)+ } }
+    };
+}
+impl_visit_hooks_for_tuple!(A 0, B 1);
+impl_visit_hooks_for_tuple!(A 0, B 1, C 2);
+impl_visit_hooks_for_tuple!(A 0, B 1, C 2, D 3);
+impl_visit_hooks_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_visit_hooks_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_visit_hooks_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_visit_hooks_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl<V: ?Sized + VisitHooks> Visit for HooksVisitor<'_, V> {
+    fn ast(&self) -> &Ast {
+        self.ast
+    }
+    #[inline]
+    fn visit_module(&mut self, node: Module) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_module(node, self.ast);
+        <Module as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_module(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_script(&mut self, node: Script) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_script(node, self.ast);
+        <Script as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_script(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_import_decl(&mut self, node: ImportDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_import_decl(node, self.ast);
+        <ImportDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_import_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_import_named_specifier(&mut self, node: ImportNamedSpecifier) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_import_named_specifier(node, self.ast);
+        <ImportNamedSpecifier as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_import_named_specifier(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_import_default_specifier(&mut self, node: ImportDefaultSpecifier) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_import_default_specifier(node, self.ast);
+        <ImportDefaultSpecifier as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_import_default_specifier(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_import_star_as_specifier(&mut self, node: ImportStarAsSpecifier) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_import_star_as_specifier(node, self.ast);
+        <ImportStarAsSpecifier as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_import_star_as_specifier(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_export_decl(&mut self, node: ExportDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_export_decl(node, self.ast);
+        <ExportDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_export_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_named_export(&mut self, node: NamedExport) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_named_export(node, self.ast);
+        <NamedExport as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_named_export(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_export_namespace_specifier(&mut self, node: ExportNamespaceSpecifier) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_export_namespace_specifier(node, self.ast);
+        <ExportNamespaceSpecifier as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_export_namespace_specifier(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_export_default_specifier(&mut self, node: ExportDefaultSpecifier) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_export_default_specifier(node, self.ast);
+        <ExportDefaultSpecifier as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_export_default_specifier(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_export_named_specifier(&mut self, node: ExportNamedSpecifier) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_export_named_specifier(node, self.ast);
+        <ExportNamedSpecifier as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_export_named_specifier(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_export_default_decl(&mut self, node: ExportDefaultDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_export_default_decl(node, self.ast);
+        <ExportDefaultDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_export_default_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_export_default_expr(&mut self, node: ExportDefaultExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_export_default_expr(node, self.ast);
+        <ExportDefaultExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_export_default_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_export_all(&mut self, node: ExportAll) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_export_all(node, self.ast);
+        <ExportAll as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_export_all(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_block_stmt(&mut self, node: BlockStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_block_stmt(node, self.ast);
+        <BlockStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_block_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_expr_stmt(&mut self, node: ExprStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_expr_stmt(node, self.ast);
+        <ExprStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_expr_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_empty_stmt(&mut self, node: EmptyStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_empty_stmt(node, self.ast);
+        <EmptyStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_empty_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_debugger_stmt(&mut self, node: DebuggerStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_debugger_stmt(node, self.ast);
+        <DebuggerStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_debugger_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_with_stmt(&mut self, node: WithStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_with_stmt(node, self.ast);
+        <WithStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_with_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_return_stmt(&mut self, node: ReturnStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_return_stmt(node, self.ast);
+        <ReturnStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_return_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_labeled_stmt(&mut self, node: LabeledStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_labeled_stmt(node, self.ast);
+        <LabeledStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_labeled_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_break_stmt(&mut self, node: BreakStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_break_stmt(node, self.ast);
+        <BreakStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_break_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_continue_stmt(&mut self, node: ContinueStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_continue_stmt(node, self.ast);
+        <ContinueStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_continue_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_if_stmt(&mut self, node: IfStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_if_stmt(node, self.ast);
+        <IfStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_if_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_switch_stmt(&mut self, node: SwitchStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_switch_stmt(node, self.ast);
+        <SwitchStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_switch_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_throw_stmt(&mut self, node: ThrowStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_throw_stmt(node, self.ast);
+        <ThrowStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_throw_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_try_stmt(&mut self, node: TryStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_try_stmt(node, self.ast);
+        <TryStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_try_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_while_stmt(&mut self, node: WhileStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_while_stmt(node, self.ast);
+        <WhileStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_while_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_do_while_stmt(&mut self, node: DoWhileStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_do_while_stmt(node, self.ast);
+        <DoWhileStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_do_while_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_for_stmt(&mut self, node: ForStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_for_stmt(node, self.ast);
+        <ForStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_for_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_for_in_stmt(&mut self, node: ForInStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_for_in_stmt(node, self.ast);
+        <ForInStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_for_in_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_for_of_stmt(&mut self, node: ForOfStmt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_for_of_stmt(node, self.ast);
+        <ForOfStmt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_for_of_stmt(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_switch_case(&mut self, node: SwitchCase) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_switch_case(node, self.ast);
+        <SwitchCase as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_switch_case(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_catch_clause(&mut self, node: CatchClause) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_catch_clause(node, self.ast);
+        <CatchClause as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_catch_clause(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_fn_decl(&mut self, node: FnDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_fn_decl(node, self.ast);
+        <FnDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_fn_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_class_decl(&mut self, node: ClassDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_class_decl(node, self.ast);
+        <ClassDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_class_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_var_decl(&mut self, node: VarDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_var_decl(node, self.ast);
+        <VarDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_var_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_var_declarator(&mut self, node: VarDeclarator) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_var_declarator(node, self.ast);
+        <VarDeclarator as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_var_declarator(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_using_decl(&mut self, node: UsingDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_using_decl(node, self.ast);
+        <UsingDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_using_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_this_expr(&mut self, node: ThisExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_this_expr(node, self.ast);
+        <ThisExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_this_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_array_lit(&mut self, node: ArrayLit) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_array_lit(node, self.ast);
+        <ArrayLit as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_array_lit(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_object_lit(&mut self, node: ObjectLit) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_object_lit(node, self.ast);
+        <ObjectLit as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_object_lit(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_spread_element(&mut self, node: SpreadElement) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_spread_element(node, self.ast);
+        <SpreadElement as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_spread_element(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_unary_expr(&mut self, node: UnaryExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_unary_expr(node, self.ast);
+        <UnaryExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_unary_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_update_expr(&mut self, node: UpdateExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_update_expr(node, self.ast);
+        <UpdateExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_update_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_bin_expr(&mut self, node: BinExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_bin_expr(node, self.ast);
+        <BinExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_bin_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_fn_expr(&mut self, node: FnExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_fn_expr(node, self.ast);
+        <FnExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_fn_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_class_expr(&mut self, node: ClassExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_class_expr(node, self.ast);
+        <ClassExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_class_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_assign_expr(&mut self, node: AssignExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_assign_expr(node, self.ast);
+        <AssignExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_assign_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_member_expr(&mut self, node: MemberExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_member_expr(node, self.ast);
+        <MemberExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_member_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_super_prop_expr(&mut self, node: SuperPropExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_super_prop_expr(node, self.ast);
+        <SuperPropExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_super_prop_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_cond_expr(&mut self, node: CondExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_cond_expr(node, self.ast);
+        <CondExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_cond_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_call_expr(&mut self, node: CallExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_call_expr(node, self.ast);
+        <CallExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_call_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_new_expr(&mut self, node: NewExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_new_expr(node, self.ast);
+        <NewExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_new_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_seq_expr(&mut self, node: SeqExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_seq_expr(node, self.ast);
+        <SeqExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_seq_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_arrow_expr(&mut self, node: ArrowExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_arrow_expr(node, self.ast);
+        <ArrowExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_arrow_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_yield_expr(&mut self, node: YieldExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_yield_expr(node, self.ast);
+        <YieldExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_yield_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_meta_prop_expr(&mut self, node: MetaPropExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_meta_prop_expr(node, self.ast);
+        <MetaPropExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_meta_prop_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_await_expr(&mut self, node: AwaitExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_await_expr(node, self.ast);
+        <AwaitExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_await_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_tpl(&mut self, node: Tpl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_tpl(node, self.ast);
+        <Tpl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_tpl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_tagged_tpl(&mut self, node: TaggedTpl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_tagged_tpl(node, self.ast);
+        <TaggedTpl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_tagged_tpl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_tpl_element(&mut self, node: TplElement) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_tpl_element(node, self.ast);
+        <TplElement as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_tpl_element(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_paren_expr(&mut self, node: ParenExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_paren_expr(node, self.ast);
+        <ParenExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_paren_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_super(&mut self, node: Super) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_super(node, self.ast);
+        <Super as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_super(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_import(&mut self, node: Import) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_import(node, self.ast);
+        <Import as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_import(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_expr_or_spread(&mut self, node: ExprOrSpread) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_expr_or_spread(node, self.ast);
+        <ExprOrSpread as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_expr_or_spread(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_spread_dot_3_token(&mut self, node: SpreadDot3Token) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_spread_dot_3_token(node, self.ast);
+        <SpreadDot3Token as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_spread_dot_3_token(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_opt_chain_expr(&mut self, node: OptChainExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_opt_chain_expr(node, self.ast);
+        <OptChainExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_opt_chain_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_opt_call(&mut self, node: OptCall) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_opt_call(node, self.ast);
+        <OptCall as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_opt_call(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_invalid(&mut self, node: Invalid) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_invalid(node, self.ast);
+        <Invalid as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_invalid(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_function(&mut self, node: Function) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_function(node, self.ast);
+        <Function as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_function(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_param(&mut self, node: Param) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_param(node, self.ast);
+        <Param as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_param(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_class(&mut self, node: Class) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_class(node, self.ast);
+        <Class as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_class(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_class_prop(&mut self, node: ClassProp) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_class_prop(node, self.ast);
+        <ClassProp as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_class_prop(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_private_prop(&mut self, node: PrivateProp) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_private_prop(node, self.ast);
+        <PrivateProp as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_private_prop(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_class_method(&mut self, node: ClassMethod) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_class_method(node, self.ast);
+        <ClassMethod as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_class_method(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_private_method(&mut self, node: PrivateMethod) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_private_method(node, self.ast);
+        <PrivateMethod as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_private_method(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_constructor(&mut self, node: Constructor) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_constructor(node, self.ast);
+        <Constructor as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_constructor(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_decorator(&mut self, node: Decorator) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_decorator(node, self.ast);
+        <Decorator as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_decorator(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_static_block(&mut self, node: StaticBlock) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_static_block(node, self.ast);
+        <StaticBlock as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_static_block(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_auto_accessor(&mut self, node: AutoAccessor) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_auto_accessor(node, self.ast);
+        <AutoAccessor as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_auto_accessor(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_key_value_prop(&mut self, node: KeyValueProp) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_key_value_prop(node, self.ast);
+        <KeyValueProp as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_key_value_prop(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_assign_prop(&mut self, node: AssignProp) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_assign_prop(node, self.ast);
+        <AssignProp as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_assign_prop(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_getter_prop(&mut self, node: GetterProp) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_getter_prop(node, self.ast);
+        <GetterProp as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_getter_prop(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_setter_prop(&mut self, node: SetterProp) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_setter_prop(node, self.ast);
+        <SetterProp as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_setter_prop(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_method_prop(&mut self, node: MethodProp) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_method_prop(node, self.ast);
+        <MethodProp as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_method_prop(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_computed_prop_name(&mut self, node: ComputedPropName) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_computed_prop_name(node, self.ast);
+        <ComputedPropName as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_computed_prop_name(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_array_pat(&mut self, node: ArrayPat) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_array_pat(node, self.ast);
+        <ArrayPat as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_array_pat(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_object_pat(&mut self, node: ObjectPat) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_object_pat(node, self.ast);
+        <ObjectPat as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_object_pat(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_assign_pat(&mut self, node: AssignPat) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_assign_pat(node, self.ast);
+        <AssignPat as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_assign_pat(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_rest_pat(&mut self, node: RestPat) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_rest_pat(node, self.ast);
+        <RestPat as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_rest_pat(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_key_value_pat_prop(&mut self, node: KeyValuePatProp) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_key_value_pat_prop(node, self.ast);
+        <KeyValuePatProp as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_key_value_pat_prop(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_assign_pat_prop(&mut self, node: AssignPatProp) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_assign_pat_prop(node, self.ast);
+        <AssignPatProp as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_assign_pat_prop(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ident(&mut self, node: Ident) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ident(node, self.ast);
+        <Ident as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ident(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ident_name(&mut self, node: IdentName) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ident_name(node, self.ast);
+        <IdentName as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ident_name(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_private_name(&mut self, node: PrivateName) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_private_name(node, self.ast);
+        <PrivateName as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_private_name(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_binding_ident(&mut self, node: BindingIdent) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_binding_ident(node, self.ast);
+        <BindingIdent as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_binding_ident(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_str(&mut self, node: Str) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_str(node, self.ast);
+        <Str as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_str(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_bool(&mut self, node: Bool) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_bool(node, self.ast);
+        <Bool as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_bool(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_null(&mut self, node: Null) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_null(node, self.ast);
+        <Null as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_null(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_number(&mut self, node: Number) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_number(node, self.ast);
+        <Number as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_number(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_big_int(&mut self, node: BigInt) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_big_int(node, self.ast);
+        <BigInt as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_big_int(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_regex(&mut self, node: Regex) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_regex(node, self.ast);
+        <Regex as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_regex(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_member_expr(&mut self, node: JSXMemberExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_member_expr(node, self.ast);
+        <JSXMemberExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_member_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_namespaced_name(&mut self, node: JSXNamespacedName) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_namespaced_name(node, self.ast);
+        <JSXNamespacedName as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_namespaced_name(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_empty_expr(&mut self, node: JSXEmptyExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_empty_expr(node, self.ast);
+        <JSXEmptyExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_empty_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_expr_container(&mut self, node: JSXExprContainer) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_expr_container(node, self.ast);
+        <JSXExprContainer as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_expr_container(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_spread_child(&mut self, node: JSXSpreadChild) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_spread_child(node, self.ast);
+        <JSXSpreadChild as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_spread_child(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_opening_element(&mut self, node: JSXOpeningElement) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_opening_element(node, self.ast);
+        <JSXOpeningElement as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_opening_element(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_closing_element(&mut self, node: JSXClosingElement) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_closing_element(node, self.ast);
+        <JSXClosingElement as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_closing_element(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_attr(&mut self, node: JSXAttr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_attr(node, self.ast);
+        <JSXAttr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_attr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_text(&mut self, node: JSXText) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_text(node, self.ast);
+        <JSXText as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_text(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_element(&mut self, node: JSXElement) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_element(node, self.ast);
+        <JSXElement as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_element(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_fragment(&mut self, node: JSXFragment) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_fragment(node, self.ast);
+        <JSXFragment as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_fragment(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_opening_fragment(&mut self, node: JSXOpeningFragment) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_opening_fragment(node, self.ast);
+        <JSXOpeningFragment as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_opening_fragment(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_jsx_closing_fragment(&mut self, node: JSXClosingFragment) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_jsx_closing_fragment(node, self.ast);
+        <JSXClosingFragment as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_jsx_closing_fragment(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_ann(&mut self, node: TsTypeAnn) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_ann(node, self.ast);
+        <TsTypeAnn as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_ann(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_param_decl(&mut self, node: TsTypeParamDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_param_decl(node, self.ast);
+        <TsTypeParamDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_param_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_param(&mut self, node: TsTypeParam) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_param(node, self.ast);
+        <TsTypeParam as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_param(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_param_instantiation(&mut self, node: TsTypeParamInstantiation) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_param_instantiation(node, self.ast);
+        <TsTypeParamInstantiation as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_param_instantiation(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_param_prop(&mut self, node: TsParamProp) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_param_prop(node, self.ast);
+        <TsParamProp as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_param_prop(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_qualified_name(&mut self, node: TsQualifiedName) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_qualified_name(node, self.ast);
+        <TsQualifiedName as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_qualified_name(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_call_signature_decl(&mut self, node: TsCallSignatureDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_call_signature_decl(node, self.ast);
+        <TsCallSignatureDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_call_signature_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_construct_signature_decl(&mut self, node: TsConstructSignatureDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_construct_signature_decl(node, self.ast);
+        <TsConstructSignatureDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_construct_signature_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_property_signature(&mut self, node: TsPropertySignature) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_property_signature(node, self.ast);
+        <TsPropertySignature as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_property_signature(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_getter_signature(&mut self, node: TsGetterSignature) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_getter_signature(node, self.ast);
+        <TsGetterSignature as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_getter_signature(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_setter_signature(&mut self, node: TsSetterSignature) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_setter_signature(node, self.ast);
+        <TsSetterSignature as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_setter_signature(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_method_signature(&mut self, node: TsMethodSignature) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_method_signature(node, self.ast);
+        <TsMethodSignature as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_method_signature(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_index_signature(&mut self, node: TsIndexSignature) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_index_signature(node, self.ast);
+        <TsIndexSignature as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_index_signature(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_keyword_type(&mut self, node: TsKeywordType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_keyword_type(node, self.ast);
+        <TsKeywordType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_keyword_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_this_type(&mut self, node: TsThisType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_this_type(node, self.ast);
+        <TsThisType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_this_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_fn_type(&mut self, node: TsFnType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_fn_type(node, self.ast);
+        <TsFnType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_fn_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_constructor_type(&mut self, node: TsConstructorType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_constructor_type(node, self.ast);
+        <TsConstructorType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_constructor_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_ref(&mut self, node: TsTypeRef) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_ref(node, self.ast);
+        <TsTypeRef as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_ref(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_predicate(&mut self, node: TsTypePredicate) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_predicate(node, self.ast);
+        <TsTypePredicate as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_predicate(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_query(&mut self, node: TsTypeQuery) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_query(node, self.ast);
+        <TsTypeQuery as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_query(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_import_call_options(&mut self, node: TsImportCallOptions) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_import_call_options(node, self.ast);
+        <TsImportCallOptions as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_import_call_options(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_import_type(&mut self, node: TsImportType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_import_type(node, self.ast);
+        <TsImportType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_import_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_lit(&mut self, node: TsTypeLit) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_lit(node, self.ast);
+        <TsTypeLit as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_lit(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_array_type(&mut self, node: TsArrayType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_array_type(node, self.ast);
+        <TsArrayType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_array_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_tuple_type(&mut self, node: TsTupleType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_tuple_type(node, self.ast);
+        <TsTupleType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_tuple_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_tuple_element(&mut self, node: TsTupleElement) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_tuple_element(node, self.ast);
+        <TsTupleElement as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_tuple_element(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_optional_type(&mut self, node: TsOptionalType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_optional_type(node, self.ast);
+        <TsOptionalType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_optional_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_rest_type(&mut self, node: TsRestType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_rest_type(node, self.ast);
+        <TsRestType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_rest_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_union_type(&mut self, node: TsUnionType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_union_type(node, self.ast);
+        <TsUnionType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_union_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_intersection_type(&mut self, node: TsIntersectionType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_intersection_type(node, self.ast);
+        <TsIntersectionType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_intersection_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_conditional_type(&mut self, node: TsConditionalType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_conditional_type(node, self.ast);
+        <TsConditionalType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_conditional_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_infer_type(&mut self, node: TsInferType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_infer_type(node, self.ast);
+        <TsInferType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_infer_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_parenthesized_type(&mut self, node: TsParenthesizedType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_parenthesized_type(node, self.ast);
+        <TsParenthesizedType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_parenthesized_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_operator(&mut self, node: TsTypeOperator) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_operator(node, self.ast);
+        <TsTypeOperator as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_operator(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_indexed_access_type(&mut self, node: TsIndexedAccessType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_indexed_access_type(node, self.ast);
+        <TsIndexedAccessType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_indexed_access_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_mapped_type(&mut self, node: TsMappedType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_mapped_type(node, self.ast);
+        <TsMappedType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_mapped_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_lit_type(&mut self, node: TsLitType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_lit_type(node, self.ast);
+        <TsLitType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_lit_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_tpl_lit_type(&mut self, node: TsTplLitType) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_tpl_lit_type(node, self.ast);
+        <TsTplLitType as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_tpl_lit_type(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_interface_decl(&mut self, node: TsInterfaceDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_interface_decl(node, self.ast);
+        <TsInterfaceDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_interface_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_interface_body(&mut self, node: TsInterfaceBody) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_interface_body(node, self.ast);
+        <TsInterfaceBody as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_interface_body(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_expr_with_type_args(&mut self, node: TsExprWithTypeArgs) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_expr_with_type_args(node, self.ast);
+        <TsExprWithTypeArgs as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_expr_with_type_args(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_alias_decl(node, self.ast);
+        <TsTypeAliasDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_alias_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_enum_decl(&mut self, node: TsEnumDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_enum_decl(node, self.ast);
+        <TsEnumDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_enum_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_enum_member(&mut self, node: TsEnumMember) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_enum_member(node, self.ast);
+        <TsEnumMember as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_enum_member(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_module_decl(&mut self, node: TsModuleDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_module_decl(node, self.ast);
+        <TsModuleDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_module_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_module_block(&mut self, node: TsModuleBlock) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_module_block(node, self.ast);
+        <TsModuleBlock as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_module_block(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_namespace_decl(&mut self, node: TsNamespaceDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_namespace_decl(node, self.ast);
+        <TsNamespaceDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_namespace_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_import_equals_decl(&mut self, node: TsImportEqualsDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_import_equals_decl(node, self.ast);
+        <TsImportEqualsDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_import_equals_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_external_module_ref(&mut self, node: TsExternalModuleRef) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_external_module_ref(node, self.ast);
+        <TsExternalModuleRef as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_external_module_ref(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_export_assignment(&mut self, node: TsExportAssignment) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_export_assignment(node, self.ast);
+        <TsExportAssignment as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_export_assignment(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_namespace_export_decl(&mut self, node: TsNamespaceExportDecl) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_namespace_export_decl(node, self.ast);
+        <TsNamespaceExportDecl as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_namespace_export_decl(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_as_expr(&mut self, node: TsAsExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_as_expr(node, self.ast);
+        <TsAsExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_as_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_type_assertion(&mut self, node: TsTypeAssertion) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_type_assertion(node, self.ast);
+        <TsTypeAssertion as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_type_assertion(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_non_null_expr(&mut self, node: TsNonNullExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_non_null_expr(node, self.ast);
+        <TsNonNullExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_non_null_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_satisfies_expr(node, self.ast);
+        <TsSatisfiesExpr as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_satisfies_expr(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_const_assertion(&mut self, node: TsConstAssertion) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_const_assertion(node, self.ast);
+        <TsConstAssertion as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_const_assertion(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+    #[inline]
+    fn visit_ts_instantiation(&mut self, node: TsInstantiation) {
+        self.hooks.enter_node(node.node_id(), self.ast);
+        self.hooks.enter_ts_instantiation(node, self.ast);
+        <TsInstantiation as VisitWith<Self>>::visit_children_with(node, self);
+        self.hooks.leave_ts_instantiation(node, self.ast);
+        self.hooks.leave_node(node.node_id(), self.ast);
+    }
+}
+/// Calls the hooks on entering `node_id`, like [HooksVisitor].
+pub(crate) fn enter_hooks<V: ?Sized + VisitHooks>(hooks: &mut V, node_id: NodeId, ast: &Ast) {
+    hooks.enter_node(node_id, ast);
+    match ast.nodes.kind(node_id) {
+        NodeKind::Module => {
+            hooks.enter_module(unsafe { Module::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Script => {
+            hooks.enter_script(unsafe { Script::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::ImportDecl => hooks.enter_import_decl(
+            unsafe { ImportDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ImportNamedSpecifier => hooks.enter_import_named_specifier(
+            unsafe { ImportNamedSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ImportDefaultSpecifier => hooks.enter_import_default_specifier(
+            unsafe { ImportDefaultSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ImportStarAsSpecifier => hooks.enter_import_star_as_specifier(
+            unsafe { ImportStarAsSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportDecl => hooks.enter_export_decl(
+            unsafe { ExportDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::NamedExport => hooks.enter_named_export(
+            unsafe { NamedExport::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportNamespaceSpecifier => hooks.enter_export_namespace_specifier(
+            unsafe { ExportNamespaceSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportDefaultSpecifier => hooks.enter_export_default_specifier(
+            unsafe { ExportDefaultSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportNamedSpecifier => hooks.enter_export_named_specifier(
+            unsafe { ExportNamedSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportDefaultDecl => hooks.enter_export_default_decl(
+            unsafe { ExportDefaultDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportDefaultExpr => hooks.enter_export_default_expr(
+            unsafe { ExportDefaultExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportAll => hooks.enter_export_all(
+            unsafe { ExportAll::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::BlockStmt => hooks.enter_block_stmt(
+            unsafe { BlockStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExprStmt => hooks.enter_expr_stmt(
+            unsafe { ExprStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::EmptyStmt => hooks.enter_empty_stmt(
+            unsafe { EmptyStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::DebuggerStmt => hooks.enter_debugger_stmt(
+            unsafe { DebuggerStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::WithStmt => hooks.enter_with_stmt(
+            unsafe { WithStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ReturnStmt => hooks.enter_return_stmt(
+            unsafe { ReturnStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::LabeledStmt => hooks.enter_labeled_stmt(
+            unsafe { LabeledStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::BreakStmt => hooks.enter_break_stmt(
+            unsafe { BreakStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ContinueStmt => hooks.enter_continue_stmt(
+            unsafe { ContinueStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::IfStmt => {
+            hooks.enter_if_stmt(unsafe { IfStmt::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::SwitchStmt => hooks.enter_switch_stmt(
+            unsafe { SwitchStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ThrowStmt => hooks.enter_throw_stmt(
+            unsafe { ThrowStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TryStmt => hooks.enter_try_stmt(
+            unsafe { TryStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::WhileStmt => hooks.enter_while_stmt(
+            unsafe { WhileStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::DoWhileStmt => hooks.enter_do_while_stmt(
+            unsafe { DoWhileStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ForStmt => hooks.enter_for_stmt(
+            unsafe { ForStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ForInStmt => hooks.enter_for_in_stmt(
+            unsafe { ForInStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ForOfStmt => hooks.enter_for_of_stmt(
+            unsafe { ForOfStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SwitchCase => hooks.enter_switch_case(
+            unsafe { SwitchCase::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::CatchClause => hooks.enter_catch_clause(
+            unsafe { CatchClause::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::FnDecl => {
+            hooks.enter_fn_decl(unsafe { FnDecl::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::ClassDecl => hooks.enter_class_decl(
+            unsafe { ClassDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::VarDecl => hooks.enter_var_decl(
+            unsafe { VarDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::VarDeclarator => hooks.enter_var_declarator(
+            unsafe { VarDeclarator::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::UsingDecl => hooks.enter_using_decl(
+            unsafe { UsingDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ThisExpr => hooks.enter_this_expr(
+            unsafe { ThisExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ArrayLit => hooks.enter_array_lit(
+            unsafe { ArrayLit::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ObjectLit => hooks.enter_object_lit(
+            unsafe { ObjectLit::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SpreadElement => hooks.enter_spread_element(
+            unsafe { SpreadElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::UnaryExpr => hooks.enter_unary_expr(
+            unsafe { UnaryExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::UpdateExpr => hooks.enter_update_expr(
+            unsafe { UpdateExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::BinExpr => hooks.enter_bin_expr(
+            unsafe { BinExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::FnExpr => {
+            hooks.enter_fn_expr(unsafe { FnExpr::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::ClassExpr => hooks.enter_class_expr(
+            unsafe { ClassExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AssignExpr => hooks.enter_assign_expr(
+            unsafe { AssignExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::MemberExpr => hooks.enter_member_expr(
+            unsafe { MemberExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SuperPropExpr => hooks.enter_super_prop_expr(
+            unsafe { SuperPropExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::CondExpr => hooks.enter_cond_expr(
+            unsafe { CondExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::CallExpr => hooks.enter_call_expr(
+            unsafe { CallExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::NewExpr => hooks.enter_new_expr(
+            unsafe { NewExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SeqExpr => hooks.enter_seq_expr(
+            unsafe { SeqExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ArrowExpr => hooks.enter_arrow_expr(
+            unsafe { ArrowExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::YieldExpr => hooks.enter_yield_expr(
+            unsafe { YieldExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::MetaPropExpr => hooks.enter_meta_prop_expr(
+            unsafe { MetaPropExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AwaitExpr => hooks.enter_await_expr(
+            unsafe { AwaitExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Tpl => hooks.enter_tpl(unsafe { Tpl::from_node_id_unchecked(node_id, ast) }, ast),
+        NodeKind::TaggedTpl => hooks.enter_tagged_tpl(
+            unsafe { TaggedTpl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TplElement => hooks.enter_tpl_element(
+            unsafe { TplElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ParenExpr => hooks.enter_paren_expr(
+            unsafe { ParenExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Super => {
+            hooks.enter_super(unsafe { Super::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Import => {
+            hooks.enter_import(unsafe { Import::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::ExprOrSpread => hooks.enter_expr_or_spread(
+            unsafe { ExprOrSpread::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SpreadDot3Token => hooks.enter_spread_dot_3_token(
+            unsafe { SpreadDot3Token::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::OptChainExpr => hooks.enter_opt_chain_expr(
+            unsafe { OptChainExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::OptCall => hooks.enter_opt_call(
+            unsafe { OptCall::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Invalid => hooks.enter_invalid(
+            unsafe { Invalid::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Function => hooks.enter_function(
+            unsafe { Function::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Param => {
+            hooks.enter_param(unsafe { Param::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Class => {
+            hooks.enter_class(unsafe { Class::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::ClassProp => hooks.enter_class_prop(
+            unsafe { ClassProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::PrivateProp => hooks.enter_private_prop(
+            unsafe { PrivateProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ClassMethod => hooks.enter_class_method(
+            unsafe { ClassMethod::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::PrivateMethod => hooks.enter_private_method(
+            unsafe { PrivateMethod::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Constructor => hooks.enter_constructor(
+            unsafe { Constructor::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Decorator => hooks.enter_decorator(
+            unsafe { Decorator::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::StaticBlock => hooks.enter_static_block(
+            unsafe { StaticBlock::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AutoAccessor => hooks.enter_auto_accessor(
+            unsafe { AutoAccessor::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::KeyValueProp => hooks.enter_key_value_prop(
+            unsafe { KeyValueProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AssignProp => hooks.enter_assign_prop(
+            unsafe { AssignProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::GetterProp => hooks.enter_getter_prop(
+            unsafe { GetterProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SetterProp => hooks.enter_setter_prop(
+            unsafe { SetterProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::MethodProp => hooks.enter_method_prop(
+            unsafe { MethodProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ComputedPropName => hooks.enter_computed_prop_name(
+            unsafe { ComputedPropName::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ArrayPat => hooks.enter_array_pat(
+            unsafe { ArrayPat::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ObjectPat => hooks.enter_object_pat(
+            unsafe { ObjectPat::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AssignPat => hooks.enter_assign_pat(
+            unsafe { AssignPat::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::RestPat => hooks.enter_rest_pat(
+            unsafe { RestPat::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::KeyValuePatProp => hooks.enter_key_value_pat_prop(
+            unsafe { KeyValuePatProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AssignPatProp => hooks.enter_assign_pat_prop(
+            unsafe { AssignPatProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Ident => {
+            hooks.enter_ident(unsafe { Ident::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::IdentName => hooks.enter_ident_name(
+            unsafe { IdentName::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::PrivateName => hooks.enter_private_name(
+            unsafe { PrivateName::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::BindingIdent => hooks.enter_binding_ident(
+            unsafe { BindingIdent::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Str => hooks.enter_str(unsafe { Str::from_node_id_unchecked(node_id, ast) }, ast),
+        NodeKind::Bool => {
+            hooks.enter_bool(unsafe { Bool::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Null => {
+            hooks.enter_null(unsafe { Null::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Number => {
+            hooks.enter_number(unsafe { Number::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::BigInt => {
+            hooks.enter_big_int(unsafe { BigInt::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Regex => {
+            hooks.enter_regex(unsafe { Regex::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::JSXMemberExpr => hooks.enter_jsx_member_expr(
+            unsafe { JSXMemberExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXNamespacedName => hooks.enter_jsx_namespaced_name(
+            unsafe { JSXNamespacedName::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXEmptyExpr => hooks.enter_jsx_empty_expr(
+            unsafe { JSXEmptyExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXExprContainer => hooks.enter_jsx_expr_container(
+            unsafe { JSXExprContainer::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXSpreadChild => hooks.enter_jsx_spread_child(
+            unsafe { JSXSpreadChild::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXOpeningElement => hooks.enter_jsx_opening_element(
+            unsafe { JSXOpeningElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXClosingElement => hooks.enter_jsx_closing_element(
+            unsafe { JSXClosingElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXAttr => hooks.enter_jsx_attr(
+            unsafe { JSXAttr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXText => hooks.enter_jsx_text(
+            unsafe { JSXText::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXElement => hooks.enter_jsx_element(
+            unsafe { JSXElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXFragment => hooks.enter_jsx_fragment(
+            unsafe { JSXFragment::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXOpeningFragment => hooks.enter_jsx_opening_fragment(
+            unsafe { JSXOpeningFragment::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXClosingFragment => hooks.enter_jsx_closing_fragment(
+            unsafe { JSXClosingFragment::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeAnn => hooks.enter_ts_type_ann(
+            unsafe { TsTypeAnn::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeParamDecl => hooks.enter_ts_type_param_decl(
+            unsafe { TsTypeParamDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeParam => hooks.enter_ts_type_param(
+            unsafe { TsTypeParam::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeParamInstantiation => hooks.enter_ts_type_param_instantiation(
+            unsafe { TsTypeParamInstantiation::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsParamProp => hooks.enter_ts_param_prop(
+            unsafe { TsParamProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsQualifiedName => hooks.enter_ts_qualified_name(
+            unsafe { TsQualifiedName::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsCallSignatureDecl => hooks.enter_ts_call_signature_decl(
+            unsafe { TsCallSignatureDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsConstructSignatureDecl => hooks.enter_ts_construct_signature_decl(
+            unsafe { TsConstructSignatureDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsPropertySignature => hooks.enter_ts_property_signature(
+            unsafe { TsPropertySignature::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsGetterSignature => hooks.enter_ts_getter_signature(
+            unsafe { TsGetterSignature::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsSetterSignature => hooks.enter_ts_setter_signature(
+            unsafe { TsSetterSignature::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsMethodSignature => hooks.enter_ts_method_signature(
+            unsafe { TsMethodSignature::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsIndexSignature => hooks.enter_ts_index_signature(
+            unsafe { TsIndexSignature::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsKeywordType => hooks.enter_ts_keyword_type(
+            unsafe { TsKeywordType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsThisType => hooks.enter_ts_this_type(
+            unsafe { TsThisType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsFnType => hooks.enter_ts_fn_type(
+            unsafe { TsFnType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsConstructorType => hooks.enter_ts_constructor_type(
+            unsafe { TsConstructorType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeRef => hooks.enter_ts_type_ref(
+            unsafe { TsTypeRef::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypePredicate => hooks.enter_ts_type_predicate(
+            unsafe { TsTypePredicate::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeQuery => hooks.enter_ts_type_query(
+            unsafe { TsTypeQuery::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsImportCallOptions => hooks.enter_ts_import_call_options(
+            unsafe { TsImportCallOptions::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsImportType => hooks.enter_ts_import_type(
+            unsafe { TsImportType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeLit => hooks.enter_ts_type_lit(
+            unsafe { TsTypeLit::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsArrayType => hooks.enter_ts_array_type(
+            unsafe { TsArrayType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTupleType => hooks.enter_ts_tuple_type(
+            unsafe { TsTupleType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTupleElement => hooks.enter_ts_tuple_element(
+            unsafe { TsTupleElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsOptionalType => hooks.enter_ts_optional_type(
+            unsafe { TsOptionalType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsRestType => hooks.enter_ts_rest_type(
+            unsafe { TsRestType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsUnionType => hooks.enter_ts_union_type(
+            unsafe { TsUnionType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsIntersectionType => hooks.enter_ts_intersection_type(
+            unsafe { TsIntersectionType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsConditionalType => hooks.enter_ts_conditional_type(
+            unsafe { TsConditionalType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsInferType => hooks.enter_ts_infer_type(
+            unsafe { TsInferType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsParenthesizedType => hooks.enter_ts_parenthesized_type(
+            unsafe { TsParenthesizedType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeOperator => hooks.enter_ts_type_operator(
+            unsafe { TsTypeOperator::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsIndexedAccessType => hooks.enter_ts_indexed_access_type(
+            unsafe { TsIndexedAccessType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsMappedType => hooks.enter_ts_mapped_type(
+            unsafe { TsMappedType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsLitType => hooks.enter_ts_lit_type(
+            unsafe { TsLitType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTplLitType => hooks.enter_ts_tpl_lit_type(
+            unsafe { TsTplLitType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsInterfaceDecl => hooks.enter_ts_interface_decl(
+            unsafe { TsInterfaceDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsInterfaceBody => hooks.enter_ts_interface_body(
+            unsafe { TsInterfaceBody::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsExprWithTypeArgs => hooks.enter_ts_expr_with_type_args(
+            unsafe { TsExprWithTypeArgs::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeAliasDecl => hooks.enter_ts_type_alias_decl(
+            unsafe { TsTypeAliasDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsEnumDecl => hooks.enter_ts_enum_decl(
+            unsafe { TsEnumDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsEnumMember => hooks.enter_ts_enum_member(
+            unsafe { TsEnumMember::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsModuleDecl => hooks.enter_ts_module_decl(
+            unsafe { TsModuleDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsModuleBlock => hooks.enter_ts_module_block(
+            unsafe { TsModuleBlock::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsNamespaceDecl => hooks.enter_ts_namespace_decl(
+            unsafe { TsNamespaceDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsImportEqualsDecl => hooks.enter_ts_import_equals_decl(
+            unsafe { TsImportEqualsDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsExternalModuleRef => hooks.enter_ts_external_module_ref(
+            unsafe { TsExternalModuleRef::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsExportAssignment => hooks.enter_ts_export_assignment(
+            unsafe { TsExportAssignment::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsNamespaceExportDecl => hooks.enter_ts_namespace_export_decl(
+            unsafe { TsNamespaceExportDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsAsExpr => hooks.enter_ts_as_expr(
+            unsafe { TsAsExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeAssertion => hooks.enter_ts_type_assertion(
+            unsafe { TsTypeAssertion::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsNonNullExpr => hooks.enter_ts_non_null_expr(
+            unsafe { TsNonNullExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsSatisfiesExpr => hooks.enter_ts_satisfies_expr(
+            unsafe { TsSatisfiesExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsConstAssertion => hooks.enter_ts_const_assertion(
+            unsafe { TsConstAssertion::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsInstantiation => hooks.enter_ts_instantiation(
+            unsafe { TsInstantiation::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        _ => {}
+    }
+}
+/// Calls the hooks on leaving `node_id`, like [HooksVisitor].
+pub(crate) fn leave_hooks<V: ?Sized + VisitHooks>(hooks: &mut V, node_id: NodeId, ast: &Ast) {
+    match ast.nodes.kind(node_id) {
+        NodeKind::Module => {
+            hooks.leave_module(unsafe { Module::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Script => {
+            hooks.leave_script(unsafe { Script::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::ImportDecl => hooks.leave_import_decl(
+            unsafe { ImportDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ImportNamedSpecifier => hooks.leave_import_named_specifier(
+            unsafe { ImportNamedSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ImportDefaultSpecifier => hooks.leave_import_default_specifier(
+            unsafe { ImportDefaultSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ImportStarAsSpecifier => hooks.leave_import_star_as_specifier(
+            unsafe { ImportStarAsSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportDecl => hooks.leave_export_decl(
+            unsafe { ExportDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::NamedExport => hooks.leave_named_export(
+            unsafe { NamedExport::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportNamespaceSpecifier => hooks.leave_export_namespace_specifier(
+            unsafe { ExportNamespaceSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportDefaultSpecifier => hooks.leave_export_default_specifier(
+            unsafe { ExportDefaultSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportNamedSpecifier => hooks.leave_export_named_specifier(
+            unsafe { ExportNamedSpecifier::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportDefaultDecl => hooks.leave_export_default_decl(
+            unsafe { ExportDefaultDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportDefaultExpr => hooks.leave_export_default_expr(
+            unsafe { ExportDefaultExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExportAll => hooks.leave_export_all(
+            unsafe { ExportAll::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::BlockStmt => hooks.leave_block_stmt(
+            unsafe { BlockStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ExprStmt => hooks.leave_expr_stmt(
+            unsafe { ExprStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::EmptyStmt => hooks.leave_empty_stmt(
+            unsafe { EmptyStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::DebuggerStmt => hooks.leave_debugger_stmt(
+            unsafe { DebuggerStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::WithStmt => hooks.leave_with_stmt(
+            unsafe { WithStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ReturnStmt => hooks.leave_return_stmt(
+            unsafe { ReturnStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::LabeledStmt => hooks.leave_labeled_stmt(
+            unsafe { LabeledStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::BreakStmt => hooks.leave_break_stmt(
+            unsafe { BreakStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ContinueStmt => hooks.leave_continue_stmt(
+            unsafe { ContinueStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::IfStmt => {
+            hooks.leave_if_stmt(unsafe { IfStmt::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::SwitchStmt => hooks.leave_switch_stmt(
+            unsafe { SwitchStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ThrowStmt => hooks.leave_throw_stmt(
+            unsafe { ThrowStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TryStmt => hooks.leave_try_stmt(
+            unsafe { TryStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::WhileStmt => hooks.leave_while_stmt(
+            unsafe { WhileStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::DoWhileStmt => hooks.leave_do_while_stmt(
+            unsafe { DoWhileStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ForStmt => hooks.leave_for_stmt(
+            unsafe { ForStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ForInStmt => hooks.leave_for_in_stmt(
+            unsafe { ForInStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ForOfStmt => hooks.leave_for_of_stmt(
+            unsafe { ForOfStmt::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SwitchCase => hooks.leave_switch_case(
+            unsafe { SwitchCase::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::CatchClause => hooks.leave_catch_clause(
+            unsafe { CatchClause::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::FnDecl => {
+            hooks.leave_fn_decl(unsafe { FnDecl::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::ClassDecl => hooks.leave_class_decl(
+            unsafe { ClassDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::VarDecl => hooks.leave_var_decl(
+            unsafe { VarDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::VarDeclarator => hooks.leave_var_declarator(
+            unsafe { VarDeclarator::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::UsingDecl => hooks.leave_using_decl(
+            unsafe { UsingDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ThisExpr => hooks.leave_this_expr(
+            unsafe { ThisExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ArrayLit => hooks.leave_array_lit(
+            unsafe { ArrayLit::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ObjectLit => hooks.leave_object_lit(
+            unsafe { ObjectLit::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SpreadElement => hooks.leave_spread_element(
+            unsafe { SpreadElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::UnaryExpr => hooks.leave_unary_expr(
+            unsafe { UnaryExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::UpdateExpr => hooks.leave_update_expr(
+            unsafe { UpdateExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::BinExpr => hooks.leave_bin_expr(
+            unsafe { BinExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::FnExpr => {
+            hooks.leave_fn_expr(unsafe { FnExpr::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::ClassExpr => hooks.leave_class_expr(
+            unsafe { ClassExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AssignExpr => hooks.leave_assign_expr(
+            unsafe { AssignExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::MemberExpr => hooks.leave_member_expr(
+            unsafe { MemberExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SuperPropExpr => hooks.leave_super_prop_expr(
+            unsafe { SuperPropExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::CondExpr => hooks.leave_cond_expr(
+            unsafe { CondExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::CallExpr => hooks.leave_call_expr(
+            unsafe { CallExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::NewExpr => hooks.leave_new_expr(
+            unsafe { NewExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SeqExpr => hooks.leave_seq_expr(
+            unsafe { SeqExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ArrowExpr => hooks.leave_arrow_expr(
+            unsafe { ArrowExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::YieldExpr => hooks.leave_yield_expr(
+            unsafe { YieldExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::MetaPropExpr => hooks.leave_meta_prop_expr(
+            unsafe { MetaPropExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AwaitExpr => hooks.leave_await_expr(
+            unsafe { AwaitExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Tpl => hooks.leave_tpl(unsafe { Tpl::from_node_id_unchecked(node_id, ast) }, ast),
+        NodeKind::TaggedTpl => hooks.leave_tagged_tpl(
+            unsafe { TaggedTpl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TplElement => hooks.leave_tpl_element(
+            unsafe { TplElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ParenExpr => hooks.leave_paren_expr(
+            unsafe { ParenExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Super => {
+            hooks.leave_super(unsafe { Super::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Import => {
+            hooks.leave_import(unsafe { Import::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::ExprOrSpread => hooks.leave_expr_or_spread(
+            unsafe { ExprOrSpread::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SpreadDot3Token => hooks.leave_spread_dot_3_token(
+            unsafe { SpreadDot3Token::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::OptChainExpr => hooks.leave_opt_chain_expr(
+            unsafe { OptChainExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::OptCall => hooks.leave_opt_call(
+            unsafe { OptCall::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Invalid => hooks.leave_invalid(
+            unsafe { Invalid::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Function => hooks.leave_function(
+            unsafe { Function::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Param => {
+            hooks.leave_param(unsafe { Param::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Class => {
+            hooks.leave_class(unsafe { Class::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::ClassProp => hooks.leave_class_prop(
+            unsafe { ClassProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::PrivateProp => hooks.leave_private_prop(
+            unsafe { PrivateProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ClassMethod => hooks.leave_class_method(
+            unsafe { ClassMethod::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::PrivateMethod => hooks.leave_private_method(
+            unsafe { PrivateMethod::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Constructor => hooks.leave_constructor(
+            unsafe { Constructor::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Decorator => hooks.leave_decorator(
+            unsafe { Decorator::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::StaticBlock => hooks.leave_static_block(
+            unsafe { StaticBlock::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AutoAccessor => hooks.leave_auto_accessor(
+            unsafe { AutoAccessor::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::KeyValueProp => hooks.leave_key_value_prop(
+            unsafe { KeyValueProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AssignProp => hooks.leave_assign_prop(
+            unsafe { AssignProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::GetterProp => hooks.leave_getter_prop(
+            unsafe { GetterProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::SetterProp => hooks.leave_setter_prop(
+            unsafe { SetterProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::MethodProp => hooks.leave_method_prop(
+            unsafe { MethodProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ComputedPropName => hooks.leave_computed_prop_name(
+            unsafe { ComputedPropName::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ArrayPat => hooks.leave_array_pat(
+            unsafe { ArrayPat::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::ObjectPat => hooks.leave_object_pat(
+            unsafe { ObjectPat::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AssignPat => hooks.leave_assign_pat(
+            unsafe { AssignPat::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::RestPat => hooks.leave_rest_pat(
+            unsafe { RestPat::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::KeyValuePatProp => hooks.leave_key_value_pat_prop(
+            unsafe { KeyValuePatProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::AssignPatProp => hooks.leave_assign_pat_prop(
+            unsafe { AssignPatProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Ident => {
+            hooks.leave_ident(unsafe { Ident::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::IdentName => hooks.leave_ident_name(
+            unsafe { IdentName::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::PrivateName => hooks.leave_private_name(
+            unsafe { PrivateName::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::BindingIdent => hooks.leave_binding_ident(
+            unsafe { BindingIdent::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::Str => hooks.leave_str(unsafe { Str::from_node_id_unchecked(node_id, ast) }, ast),
+        NodeKind::Bool => {
+            hooks.leave_bool(unsafe { Bool::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Null => {
+            hooks.leave_null(unsafe { Null::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Number => {
+            hooks.leave_number(unsafe { Number::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::BigInt => {
+            hooks.leave_big_int(unsafe { BigInt::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::Regex => {
+            hooks.leave_regex(unsafe { Regex::from_node_id_unchecked(node_id, ast) }, ast)
+        }
+        NodeKind::JSXMemberExpr => hooks.leave_jsx_member_expr(
+            unsafe { JSXMemberExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXNamespacedName => hooks.leave_jsx_namespaced_name(
+            unsafe { JSXNamespacedName::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXEmptyExpr => hooks.leave_jsx_empty_expr(
+            unsafe { JSXEmptyExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXExprContainer => hooks.leave_jsx_expr_container(
+            unsafe { JSXExprContainer::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXSpreadChild => hooks.leave_jsx_spread_child(
+            unsafe { JSXSpreadChild::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXOpeningElement => hooks.leave_jsx_opening_element(
+            unsafe { JSXOpeningElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXClosingElement => hooks.leave_jsx_closing_element(
+            unsafe { JSXClosingElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXAttr => hooks.leave_jsx_attr(
+            unsafe { JSXAttr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXText => hooks.leave_jsx_text(
+            unsafe { JSXText::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXElement => hooks.leave_jsx_element(
+            unsafe { JSXElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXFragment => hooks.leave_jsx_fragment(
+            unsafe { JSXFragment::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXOpeningFragment => hooks.leave_jsx_opening_fragment(
+            unsafe { JSXOpeningFragment::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::JSXClosingFragment => hooks.leave_jsx_closing_fragment(
+            unsafe { JSXClosingFragment::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeAnn => hooks.leave_ts_type_ann(
+            unsafe { TsTypeAnn::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeParamDecl => hooks.leave_ts_type_param_decl(
+            unsafe { TsTypeParamDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeParam => hooks.leave_ts_type_param(
+            unsafe { TsTypeParam::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeParamInstantiation => hooks.leave_ts_type_param_instantiation(
+            unsafe { TsTypeParamInstantiation::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsParamProp => hooks.leave_ts_param_prop(
+            unsafe { TsParamProp::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsQualifiedName => hooks.leave_ts_qualified_name(
+            unsafe { TsQualifiedName::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsCallSignatureDecl => hooks.leave_ts_call_signature_decl(
+            unsafe { TsCallSignatureDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsConstructSignatureDecl => hooks.leave_ts_construct_signature_decl(
+            unsafe { TsConstructSignatureDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsPropertySignature => hooks.leave_ts_property_signature(
+            unsafe { TsPropertySignature::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsGetterSignature => hooks.leave_ts_getter_signature(
+            unsafe { TsGetterSignature::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsSetterSignature => hooks.leave_ts_setter_signature(
+            unsafe { TsSetterSignature::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsMethodSignature => hooks.leave_ts_method_signature(
+            unsafe { TsMethodSignature::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsIndexSignature => hooks.leave_ts_index_signature(
+            unsafe { TsIndexSignature::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsKeywordType => hooks.leave_ts_keyword_type(
+            unsafe { TsKeywordType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsThisType => hooks.leave_ts_this_type(
+            unsafe { TsThisType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsFnType => hooks.leave_ts_fn_type(
+            unsafe { TsFnType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsConstructorType => hooks.leave_ts_constructor_type(
+            unsafe { TsConstructorType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeRef => hooks.leave_ts_type_ref(
+            unsafe { TsTypeRef::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypePredicate => hooks.leave_ts_type_predicate(
+            unsafe { TsTypePredicate::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeQuery => hooks.leave_ts_type_query(
+            unsafe { TsTypeQuery::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsImportCallOptions => hooks.leave_ts_import_call_options(
+            unsafe { TsImportCallOptions::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsImportType => hooks.leave_ts_import_type(
+            unsafe { TsImportType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeLit => hooks.leave_ts_type_lit(
+            unsafe { TsTypeLit::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsArrayType => hooks.leave_ts_array_type(
+            unsafe { TsArrayType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTupleType => hooks.leave_ts_tuple_type(
+            unsafe { TsTupleType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTupleElement => hooks.leave_ts_tuple_element(
+            unsafe { TsTupleElement::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsOptionalType => hooks.leave_ts_optional_type(
+            unsafe { TsOptionalType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsRestType => hooks.leave_ts_rest_type(
+            unsafe { TsRestType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsUnionType => hooks.leave_ts_union_type(
+            unsafe { TsUnionType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsIntersectionType => hooks.leave_ts_intersection_type(
+            unsafe { TsIntersectionType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsConditionalType => hooks.leave_ts_conditional_type(
+            unsafe { TsConditionalType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsInferType => hooks.leave_ts_infer_type(
+            unsafe { TsInferType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsParenthesizedType => hooks.leave_ts_parenthesized_type(
+            unsafe { TsParenthesizedType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeOperator => hooks.leave_ts_type_operator(
+            unsafe { TsTypeOperator::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsIndexedAccessType => hooks.leave_ts_indexed_access_type(
+            unsafe { TsIndexedAccessType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsMappedType => hooks.leave_ts_mapped_type(
+            unsafe { TsMappedType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsLitType => hooks.leave_ts_lit_type(
+            unsafe { TsLitType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTplLitType => hooks.leave_ts_tpl_lit_type(
+            unsafe { TsTplLitType::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsInterfaceDecl => hooks.leave_ts_interface_decl(
+            unsafe { TsInterfaceDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsInterfaceBody => hooks.leave_ts_interface_body(
+            unsafe { TsInterfaceBody::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsExprWithTypeArgs => hooks.leave_ts_expr_with_type_args(
+            unsafe { TsExprWithTypeArgs::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeAliasDecl => hooks.leave_ts_type_alias_decl(
+            unsafe { TsTypeAliasDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsEnumDecl => hooks.leave_ts_enum_decl(
+            unsafe { TsEnumDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsEnumMember => hooks.leave_ts_enum_member(
+            unsafe { TsEnumMember::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsModuleDecl => hooks.leave_ts_module_decl(
+            unsafe { TsModuleDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsModuleBlock => hooks.leave_ts_module_block(
+            unsafe { TsModuleBlock::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsNamespaceDecl => hooks.leave_ts_namespace_decl(
+            unsafe { TsNamespaceDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsImportEqualsDecl => hooks.leave_ts_import_equals_decl(
+            unsafe { TsImportEqualsDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsExternalModuleRef => hooks.leave_ts_external_module_ref(
+            unsafe { TsExternalModuleRef::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsExportAssignment => hooks.leave_ts_export_assignment(
+            unsafe { TsExportAssignment::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsNamespaceExportDecl => hooks.leave_ts_namespace_export_decl(
+            unsafe { TsNamespaceExportDecl::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsAsExpr => hooks.leave_ts_as_expr(
+            unsafe { TsAsExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsTypeAssertion => hooks.leave_ts_type_assertion(
+            unsafe { TsTypeAssertion::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsNonNullExpr => hooks.leave_ts_non_null_expr(
+            unsafe { TsNonNullExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsSatisfiesExpr => hooks.leave_ts_satisfies_expr(
+            unsafe { TsSatisfiesExpr::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsConstAssertion => hooks.leave_ts_const_assertion(
+            unsafe { TsConstAssertion::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        NodeKind::TsInstantiation => hooks.leave_ts_instantiation(
+            unsafe { TsInstantiation::from_node_id_unchecked(node_id, ast) },
+            ast,
+        ),
+        _ => {}
+    }
+    hooks.leave_node(node_id, ast);
+}
+pub trait VisitMutHooks {
+    #[inline]
+    fn enter_node(&mut self, node_id: NodeId, ast: &mut Ast) {}
+    #[inline]
+    fn leave_node(&mut self, node_id: NodeId, ast: &mut Ast) {}
+    #[inline]
+    fn enter_module(&mut self, node: Module, ast: &mut Ast) {}
+    #[inline]
+    fn leave_module(&mut self, node: Module, ast: &mut Ast) {}
+    #[inline]
+    fn enter_script(&mut self, node: Script, ast: &mut Ast) {}
+    #[inline]
+    fn leave_script(&mut self, node: Script, ast: &mut Ast) {}
+    #[inline]
+    fn enter_import_decl(&mut self, node: ImportDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_import_decl(&mut self, node: ImportDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_import_named_specifier(&mut self, node: ImportNamedSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn leave_import_named_specifier(&mut self, node: ImportNamedSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn enter_import_default_specifier(&mut self, node: ImportDefaultSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn leave_import_default_specifier(&mut self, node: ImportDefaultSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn enter_import_star_as_specifier(&mut self, node: ImportStarAsSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn leave_import_star_as_specifier(&mut self, node: ImportStarAsSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn enter_export_decl(&mut self, node: ExportDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_export_decl(&mut self, node: ExportDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_named_export(&mut self, node: NamedExport, ast: &mut Ast) {}
+    #[inline]
+    fn leave_named_export(&mut self, node: NamedExport, ast: &mut Ast) {}
+    #[inline]
+    fn enter_export_namespace_specifier(&mut self, node: ExportNamespaceSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn leave_export_namespace_specifier(&mut self, node: ExportNamespaceSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn enter_export_default_specifier(&mut self, node: ExportDefaultSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn leave_export_default_specifier(&mut self, node: ExportDefaultSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn enter_export_named_specifier(&mut self, node: ExportNamedSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn leave_export_named_specifier(&mut self, node: ExportNamedSpecifier, ast: &mut Ast) {}
+    #[inline]
+    fn enter_export_default_decl(&mut self, node: ExportDefaultDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_export_default_decl(&mut self, node: ExportDefaultDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_export_default_expr(&mut self, node: ExportDefaultExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_export_default_expr(&mut self, node: ExportDefaultExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_export_all(&mut self, node: ExportAll, ast: &mut Ast) {}
+    #[inline]
+    fn leave_export_all(&mut self, node: ExportAll, ast: &mut Ast) {}
+    #[inline]
+    fn enter_block_stmt(&mut self, node: BlockStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_block_stmt(&mut self, node: BlockStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_expr_stmt(&mut self, node: ExprStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_expr_stmt(&mut self, node: ExprStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_empty_stmt(&mut self, node: EmptyStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_empty_stmt(&mut self, node: EmptyStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_debugger_stmt(&mut self, node: DebuggerStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_debugger_stmt(&mut self, node: DebuggerStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_with_stmt(&mut self, node: WithStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_with_stmt(&mut self, node: WithStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_return_stmt(&mut self, node: ReturnStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_return_stmt(&mut self, node: ReturnStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_labeled_stmt(&mut self, node: LabeledStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_labeled_stmt(&mut self, node: LabeledStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_break_stmt(&mut self, node: BreakStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_break_stmt(&mut self, node: BreakStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_continue_stmt(&mut self, node: ContinueStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_continue_stmt(&mut self, node: ContinueStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_if_stmt(&mut self, node: IfStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_if_stmt(&mut self, node: IfStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_switch_stmt(&mut self, node: SwitchStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_switch_stmt(&mut self, node: SwitchStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_throw_stmt(&mut self, node: ThrowStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_throw_stmt(&mut self, node: ThrowStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_try_stmt(&mut self, node: TryStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_try_stmt(&mut self, node: TryStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_while_stmt(&mut self, node: WhileStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_while_stmt(&mut self, node: WhileStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_do_while_stmt(&mut self, node: DoWhileStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_do_while_stmt(&mut self, node: DoWhileStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_for_stmt(&mut self, node: ForStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_for_stmt(&mut self, node: ForStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_for_in_stmt(&mut self, node: ForInStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_for_in_stmt(&mut self, node: ForInStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_for_of_stmt(&mut self, node: ForOfStmt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_for_of_stmt(&mut self, node: ForOfStmt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_switch_case(&mut self, node: SwitchCase, ast: &mut Ast) {}
+    #[inline]
+    fn leave_switch_case(&mut self, node: SwitchCase, ast: &mut Ast) {}
+    #[inline]
+    fn enter_catch_clause(&mut self, node: CatchClause, ast: &mut Ast) {}
+    #[inline]
+    fn leave_catch_clause(&mut self, node: CatchClause, ast: &mut Ast) {}
+    #[inline]
+    fn enter_fn_decl(&mut self, node: FnDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_fn_decl(&mut self, node: FnDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_class_decl(&mut self, node: ClassDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_class_decl(&mut self, node: ClassDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_var_decl(&mut self, node: VarDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_var_decl(&mut self, node: VarDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_var_declarator(&mut self, node: VarDeclarator, ast: &mut Ast) {}
+    #[inline]
+    fn leave_var_declarator(&mut self, node: VarDeclarator, ast: &mut Ast) {}
+    #[inline]
+    fn enter_using_decl(&mut self, node: UsingDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_using_decl(&mut self, node: UsingDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_this_expr(&mut self, node: ThisExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_this_expr(&mut self, node: ThisExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_array_lit(&mut self, node: ArrayLit, ast: &mut Ast) {}
+    #[inline]
+    fn leave_array_lit(&mut self, node: ArrayLit, ast: &mut Ast) {}
+    #[inline]
+    fn enter_object_lit(&mut self, node: ObjectLit, ast: &mut Ast) {}
+    #[inline]
+    fn leave_object_lit(&mut self, node: ObjectLit, ast: &mut Ast) {}
+    #[inline]
+    fn enter_spread_element(&mut self, node: SpreadElement, ast: &mut Ast) {}
+    #[inline]
+    fn leave_spread_element(&mut self, node: SpreadElement, ast: &mut Ast) {}
+    #[inline]
+    fn enter_unary_expr(&mut self, node: UnaryExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_unary_expr(&mut self, node: UnaryExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_update_expr(&mut self, node: UpdateExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_update_expr(&mut self, node: UpdateExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_bin_expr(&mut self, node: BinExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_bin_expr(&mut self, node: BinExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_fn_expr(&mut self, node: FnExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_fn_expr(&mut self, node: FnExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_class_expr(&mut self, node: ClassExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_class_expr(&mut self, node: ClassExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_assign_expr(&mut self, node: AssignExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_assign_expr(&mut self, node: AssignExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_member_expr(&mut self, node: MemberExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_member_expr(&mut self, node: MemberExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_super_prop_expr(&mut self, node: SuperPropExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_super_prop_expr(&mut self, node: SuperPropExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_cond_expr(&mut self, node: CondExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_cond_expr(&mut self, node: CondExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_call_expr(&mut self, node: CallExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_call_expr(&mut self, node: CallExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_new_expr(&mut self, node: NewExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_new_expr(&mut self, node: NewExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_seq_expr(&mut self, node: SeqExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_seq_expr(&mut self, node: SeqExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_arrow_expr(&mut self, node: ArrowExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_arrow_expr(&mut self, node: ArrowExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_yield_expr(&mut self, node: YieldExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_yield_expr(&mut self, node: YieldExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_meta_prop_expr(&mut self, node: MetaPropExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_meta_prop_expr(&mut self, node: MetaPropExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_await_expr(&mut self, node: AwaitExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_await_expr(&mut self, node: AwaitExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_tpl(&mut self, node: Tpl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_tpl(&mut self, node: Tpl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_tagged_tpl(&mut self, node: TaggedTpl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_tagged_tpl(&mut self, node: TaggedTpl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_tpl_element(&mut self, node: TplElement, ast: &mut Ast) {}
+    #[inline]
+    fn leave_tpl_element(&mut self, node: TplElement, ast: &mut Ast) {}
+    #[inline]
+    fn enter_paren_expr(&mut self, node: ParenExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_paren_expr(&mut self, node: ParenExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_super(&mut self, node: Super, ast: &mut Ast) {}
+    #[inline]
+    fn leave_super(&mut self, node: Super, ast: &mut Ast) {}
+    #[inline]
+    fn enter_import(&mut self, node: Import, ast: &mut Ast) {}
+    #[inline]
+    fn leave_import(&mut self, node: Import, ast: &mut Ast) {}
+    #[inline]
+    fn enter_expr_or_spread(&mut self, node: ExprOrSpread, ast: &mut Ast) {}
+    #[inline]
+    fn leave_expr_or_spread(&mut self, node: ExprOrSpread, ast: &mut Ast) {}
+    #[inline]
+    fn enter_spread_dot_3_token(&mut self, node: SpreadDot3Token, ast: &mut Ast) {}
+    #[inline]
+    fn leave_spread_dot_3_token(&mut self, node: SpreadDot3Token, ast: &mut Ast) {}
+    #[inline]
+    fn enter_opt_chain_expr(&mut self, node: OptChainExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_opt_chain_expr(&mut self, node: OptChainExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_opt_call(&mut self, node: OptCall, ast: &mut Ast) {}
+    #[inline]
+    fn leave_opt_call(&mut self, node: OptCall, ast: &mut Ast) {}
+    #[inline]
+    fn enter_invalid(&mut self, node: Invalid, ast: &mut Ast) {}
+    #[inline]
+    fn leave_invalid(&mut self, node: Invalid, ast: &mut Ast) {}
+    #[inline]
+    fn enter_function(&mut self, node: Function, ast: &mut Ast) {}
+    #[inline]
+    fn leave_function(&mut self, node: Function, ast: &mut Ast) {}
+    #[inline]
+    fn enter_param(&mut self, node: Param, ast: &mut Ast) {}
+    #[inline]
+    fn leave_param(&mut self, node: Param, ast: &mut Ast) {}
+    #[inline]
+    fn enter_class(&mut self, node: Class, ast: &mut Ast) {}
+    #[inline]
+    fn leave_class(&mut self, node: Class, ast: &mut Ast) {}
+    #[inline]
+    fn enter_class_prop(&mut self, node: ClassProp, ast: &mut Ast) {}
+    #[inline]
+    fn leave_class_prop(&mut self, node: ClassProp, ast: &mut Ast) {}
+    #[inline]
+    fn enter_private_prop(&mut self, node: PrivateProp, ast: &mut Ast) {}
+    #[inline]
+    fn leave_private_prop(&mut self, node: PrivateProp, ast: &mut Ast) {}
+    #[inline]
+    fn enter_class_method(&mut self, node: ClassMethod, ast: &mut Ast) {}
+    #[inline]
+    fn leave_class_method(&mut self, node: ClassMethod, ast: &mut Ast) {}
+    #[inline]
+    fn enter_private_method(&mut self, node: PrivateMethod, ast: &mut Ast) {}
+    #[inline]
+    fn leave_private_method(&mut self, node: PrivateMethod, ast: &mut Ast) {}
+    #[inline]
+    fn enter_constructor(&mut self, node: Constructor, ast: &mut Ast) {}
+    #[inline]
+    fn leave_constructor(&mut self, node: Constructor, ast: &mut Ast) {}
+    #[inline]
+    fn enter_decorator(&mut self, node: Decorator, ast: &mut Ast) {}
+    #[inline]
+    fn leave_decorator(&mut self, node: Decorator, ast: &mut Ast) {}
+    #[inline]
+    fn enter_static_block(&mut self, node: StaticBlock, ast: &mut Ast) {}
+    #[inline]
+    fn leave_static_block(&mut self, node: StaticBlock, ast: &mut Ast) {}
+    #[inline]
+    fn enter_auto_accessor(&mut self, node: AutoAccessor, ast: &mut Ast) {}
+    #[inline]
+    fn leave_auto_accessor(&mut self, node: AutoAccessor, ast: &mut Ast) {}
+    #[inline]
+    fn enter_key_value_prop(&mut self, node: KeyValueProp, ast: &mut Ast) {}
+    #[inline]
+    fn leave_key_value_prop(&mut self, node: KeyValueProp, ast: &mut Ast) {}
+    #[inline]
+    fn enter_assign_prop(&mut self, node: AssignProp, ast: &mut Ast) {}
+    #[inline]
+    fn leave_assign_prop(&mut self, node: AssignProp, ast: &mut Ast) {}
+    #[inline]
+    fn enter_getter_prop(&mut self, node: GetterProp, ast: &mut Ast) {}
+    #[inline]
+    fn leave_getter_prop(&mut self, node: GetterProp, ast: &mut Ast) {}
+    #[inline]
+    fn enter_setter_prop(&mut self, node: SetterProp, ast: &mut Ast) {}
+    #[inline]
+    fn leave_setter_prop(&mut self, node: SetterProp, ast: &mut Ast) {}
+    #[inline]
+    fn enter_method_prop(&mut self, node: MethodProp, ast: &mut Ast) {}
+    #[inline]
+    fn leave_method_prop(&mut self, node: MethodProp, ast: &mut Ast) {}
+    #[inline]
+    fn enter_computed_prop_name(&mut self, node: ComputedPropName, ast: &mut Ast) {}
+    #[inline]
+    fn leave_computed_prop_name(&mut self, node: ComputedPropName, ast: &mut Ast) {}
+    #[inline]
+    fn enter_array_pat(&mut self, node: ArrayPat, ast: &mut Ast) {}
+    #[inline]
+    fn leave_array_pat(&mut self, node: ArrayPat, ast: &mut Ast) {}
+    #[inline]
+    fn enter_object_pat(&mut self, node: ObjectPat, ast: &mut Ast) {}
+    #[inline]
+    fn leave_object_pat(&mut self, node: ObjectPat, ast: &mut Ast) {}
+    #[inline]
+    fn enter_assign_pat(&mut self, node: AssignPat, ast: &mut Ast) {}
+    #[inline]
+    fn leave_assign_pat(&mut self, node: AssignPat, ast: &mut Ast) {}
+    #[inline]
+    fn enter_rest_pat(&mut self, node: RestPat, ast: &mut Ast) {}
+    #[inline]
+    fn leave_rest_pat(&mut self, node: RestPat, ast: &mut Ast) {}
+    #[inline]
+    fn enter_key_value_pat_prop(&mut self, node: KeyValuePatProp, ast: &mut Ast) {}
+    #[inline]
+    fn leave_key_value_pat_prop(&mut self, node: KeyValuePatProp, ast: &mut Ast) {}
+    #[inline]
+    fn enter_assign_pat_prop(&mut self, node: AssignPatProp, ast: &mut Ast) {}
+    #[inline]
+    fn leave_assign_pat_prop(&mut self, node: AssignPatProp, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ident(&mut self, node: Ident, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ident(&mut self, node: Ident, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ident_name(&mut self, node: IdentName, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ident_name(&mut self, node: IdentName, ast: &mut Ast) {}
+    #[inline]
+    fn enter_private_name(&mut self, node: PrivateName, ast: &mut Ast) {}
+    #[inline]
+    fn leave_private_name(&mut self, node: PrivateName, ast: &mut Ast) {}
+    #[inline]
+    fn enter_binding_ident(&mut self, node: BindingIdent, ast: &mut Ast) {}
+    #[inline]
+    fn leave_binding_ident(&mut self, node: BindingIdent, ast: &mut Ast) {}
+    #[inline]
+    fn enter_str(&mut self, node: Str, ast: &mut Ast) {}
+    #[inline]
+    fn leave_str(&mut self, node: Str, ast: &mut Ast) {}
+    #[inline]
+    fn enter_bool(&mut self, node: Bool, ast: &mut Ast) {}
+    #[inline]
+    fn leave_bool(&mut self, node: Bool, ast: &mut Ast) {}
+    #[inline]
+    fn enter_null(&mut self, node: Null, ast: &mut Ast) {}
+    #[inline]
+    fn leave_null(&mut self, node: Null, ast: &mut Ast) {}
+    #[inline]
+    fn enter_number(&mut self, node: Number, ast: &mut Ast) {}
+    #[inline]
+    fn leave_number(&mut self, node: Number, ast: &mut Ast) {}
+    #[inline]
+    fn enter_big_int(&mut self, node: BigInt, ast: &mut Ast) {}
+    #[inline]
+    fn leave_big_int(&mut self, node: BigInt, ast: &mut Ast) {}
+    #[inline]
+    fn enter_regex(&mut self, node: Regex, ast: &mut Ast) {}
+    #[inline]
+    fn leave_regex(&mut self, node: Regex, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_member_expr(&mut self, node: JSXMemberExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_member_expr(&mut self, node: JSXMemberExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_namespaced_name(&mut self, node: JSXNamespacedName, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_namespaced_name(&mut self, node: JSXNamespacedName, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_empty_expr(&mut self, node: JSXEmptyExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_empty_expr(&mut self, node: JSXEmptyExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_expr_container(&mut self, node: JSXExprContainer, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_expr_container(&mut self, node: JSXExprContainer, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_spread_child(&mut self, node: JSXSpreadChild, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_spread_child(&mut self, node: JSXSpreadChild, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_opening_element(&mut self, node: JSXOpeningElement, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_opening_element(&mut self, node: JSXOpeningElement, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_closing_element(&mut self, node: JSXClosingElement, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_closing_element(&mut self, node: JSXClosingElement, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_attr(&mut self, node: JSXAttr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_attr(&mut self, node: JSXAttr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_text(&mut self, node: JSXText, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_text(&mut self, node: JSXText, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_element(&mut self, node: JSXElement, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_element(&mut self, node: JSXElement, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_fragment(&mut self, node: JSXFragment, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_fragment(&mut self, node: JSXFragment, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_opening_fragment(&mut self, node: JSXOpeningFragment, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_opening_fragment(&mut self, node: JSXOpeningFragment, ast: &mut Ast) {}
+    #[inline]
+    fn enter_jsx_closing_fragment(&mut self, node: JSXClosingFragment, ast: &mut Ast) {}
+    #[inline]
+    fn leave_jsx_closing_fragment(&mut self, node: JSXClosingFragment, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_ann(&mut self, node: TsTypeAnn, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_type_ann(&mut self, node: TsTypeAnn, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_param_decl(&mut self, node: TsTypeParamDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_type_param_decl(&mut self, node: TsTypeParamDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_param(&mut self, node: TsTypeParam, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_type_param(&mut self, node: TsTypeParam, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_param_instantiation(&mut self, node: TsTypeParamInstantiation, ast: &mut Ast) {
+    }
+    #[inline]
+    fn leave_ts_type_param_instantiation(&mut self, node: TsTypeParamInstantiation, ast: &mut Ast) {
+    }
+    #[inline]
+    fn enter_ts_param_prop(&mut self, node: TsParamProp, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_param_prop(&mut self, node: TsParamProp, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_qualified_name(&mut self, node: TsQualifiedName, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_qualified_name(&mut self, node: TsQualifiedName, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_call_signature_decl(&mut self, node: TsCallSignatureDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_call_signature_decl(&mut self, node: TsCallSignatureDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_construct_signature_decl(&mut self, node: TsConstructSignatureDecl, ast: &mut Ast) {
+    }
+    #[inline]
+    fn leave_ts_construct_signature_decl(&mut self, node: TsConstructSignatureDecl, ast: &mut Ast) {
+    }
+    #[inline]
+    fn enter_ts_property_signature(&mut self, node: TsPropertySignature, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_property_signature(&mut self, node: TsPropertySignature, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_getter_signature(&mut self, node: TsGetterSignature, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_getter_signature(&mut self, node: TsGetterSignature, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_setter_signature(&mut self, node: TsSetterSignature, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_setter_signature(&mut self, node: TsSetterSignature, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_method_signature(&mut self, node: TsMethodSignature, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_method_signature(&mut self, node: TsMethodSignature, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_index_signature(&mut self, node: TsIndexSignature, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_index_signature(&mut self, node: TsIndexSignature, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_keyword_type(&mut self, node: TsKeywordType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_keyword_type(&mut self, node: TsKeywordType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_this_type(&mut self, node: TsThisType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_this_type(&mut self, node: TsThisType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_fn_type(&mut self, node: TsFnType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_fn_type(&mut self, node: TsFnType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_constructor_type(&mut self, node: TsConstructorType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_constructor_type(&mut self, node: TsConstructorType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_ref(&mut self, node: TsTypeRef, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_type_ref(&mut self, node: TsTypeRef, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_predicate(&mut self, node: TsTypePredicate, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_type_predicate(&mut self, node: TsTypePredicate, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_query(&mut self, node: TsTypeQuery, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_type_query(&mut self, node: TsTypeQuery, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_import_call_options(&mut self, node: TsImportCallOptions, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_import_call_options(&mut self, node: TsImportCallOptions, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_import_type(&mut self, node: TsImportType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_import_type(&mut self, node: TsImportType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_lit(&mut self, node: TsTypeLit, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_type_lit(&mut self, node: TsTypeLit, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_array_type(&mut self, node: TsArrayType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_array_type(&mut self, node: TsArrayType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_tuple_type(&mut self, node: TsTupleType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_tuple_type(&mut self, node: TsTupleType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_tuple_element(&mut self, node: TsTupleElement, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_tuple_element(&mut self, node: TsTupleElement, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_optional_type(&mut self, node: TsOptionalType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_optional_type(&mut self, node: TsOptionalType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_rest_type(&mut self, node: TsRestType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_rest_type(&mut self, node: TsRestType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_union_type(&mut self, node: TsUnionType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_union_type(&mut self, node: TsUnionType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_intersection_type(&mut self, node: TsIntersectionType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_intersection_type(&mut self, node: TsIntersectionType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_conditional_type(&mut self, node: TsConditionalType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_conditional_type(&mut self, node: TsConditionalType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_infer_type(&mut self, node: TsInferType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_infer_type(&mut self, node: TsInferType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_parenthesized_type(&mut self, node: TsParenthesizedType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_parenthesized_type(&mut self, node: TsParenthesizedType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_operator(&mut self, node: TsTypeOperator, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_type_operator(&mut self, node: TsTypeOperator, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_indexed_access_type(&mut self, node: TsIndexedAccessType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_indexed_access_type(&mut self, node: TsIndexedAccessType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_mapped_type(&mut self, node: TsMappedType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_mapped_type(&mut self, node: TsMappedType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_lit_type(&mut self, node: TsLitType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_lit_type(&mut self, node: TsLitType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_tpl_lit_type(&mut self, node: TsTplLitType, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_tpl_lit_type(&mut self, node: TsTplLitType, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_interface_decl(&mut self, node: TsInterfaceDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_interface_decl(&mut self, node: TsInterfaceDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_interface_body(&mut self, node: TsInterfaceBody, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_interface_body(&mut self, node: TsInterfaceBody, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_expr_with_type_args(&mut self, node: TsExprWithTypeArgs, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_expr_with_type_args(&mut self, node: TsExprWithTypeArgs, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_enum_decl(&mut self, node: TsEnumDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_enum_decl(&mut self, node: TsEnumDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_enum_member(&mut self, node: TsEnumMember, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_enum_member(&mut self, node: TsEnumMember, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_module_decl(&mut self, node: TsModuleDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_module_decl(&mut self, node: TsModuleDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_module_block(&mut self, node: TsModuleBlock, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_module_block(&mut self, node: TsModuleBlock, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_namespace_decl(&mut self, node: TsNamespaceDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_namespace_decl(&mut self, node: TsNamespaceDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_import_equals_decl(&mut self, node: TsImportEqualsDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_import_equals_decl(&mut self, node: TsImportEqualsDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_external_module_ref(&mut self, node: TsExternalModuleRef, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_external_module_ref(&mut self, node: TsExternalModuleRef, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_export_assignment(&mut self, node: TsExportAssignment, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_export_assignment(&mut self, node: TsExportAssignment, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_namespace_export_decl(&mut self, node: TsNamespaceExportDecl, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_namespace_export_decl(&mut self, node: TsNamespaceExportDecl, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_as_expr(&mut self, node: TsAsExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_as_expr(&mut self, node: TsAsExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_type_assertion(&mut self, node: TsTypeAssertion, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_type_assertion(&mut self, node: TsTypeAssertion, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_non_null_expr(&mut self, node: TsNonNullExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_non_null_expr(&mut self, node: TsNonNullExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_const_assertion(&mut self, node: TsConstAssertion, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_const_assertion(&mut self, node: TsConstAssertion, ast: &mut Ast) {}
+    #[inline]
+    fn enter_ts_instantiation(&mut self, node: TsInstantiation, ast: &mut Ast) {}
+    #[inline]
+    fn leave_ts_instantiation(&mut self, node: TsInstantiation, ast: &mut Ast) {}
+}
+impl<V: ?Sized + VisitMutHooks> VisitMutHooks for &mut V {
+    #[inline]
+    fn enter_node(&mut self, node: NodeId, ast: &mut Ast) {
+        (**self).enter_node(node, ast)
+    }
+    #[inline]
+    fn leave_node(&mut self, node: NodeId, ast: &mut Ast) {
+        (**self).leave_node(node, ast)
+    }
+    #[inline]
+    fn enter_module(&mut self, node: Module, ast: &mut Ast) {
+        (**self).enter_module(node, ast)
+    }
+    #[inline]
+    fn leave_module(&mut self, node: Module, ast: &mut Ast) {
+        (**self).leave_module(node, ast)
+    }
+    #[inline]
+    fn enter_script(&mut self, node: Script, ast: &mut Ast) {
+        (**self).enter_script(node, ast)
+    }
+    #[inline]
+    fn leave_script(&mut self, node: Script, ast: &mut Ast) {
+        (**self).leave_script(node, ast)
+    }
+    #[inline]
+    fn enter_import_decl(&mut self, node: ImportDecl, ast: &mut Ast) {
+        (**self).enter_import_decl(node, ast)
+    }
+    #[inline]
+    fn leave_import_decl(&mut self, node: ImportDecl, ast: &mut Ast) {
+        (**self).leave_import_decl(node, ast)
+    }
+    #[inline]
+    fn enter_import_named_specifier(&mut self, node: ImportNamedSpecifier, ast: &mut Ast) {
+        (**self).enter_import_named_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_import_named_specifier(&mut self, node: ImportNamedSpecifier, ast: &mut Ast) {
+        (**self).leave_import_named_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_import_default_specifier(&mut self, node: ImportDefaultSpecifier, ast: &mut Ast) {
+        (**self).enter_import_default_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_import_default_specifier(&mut self, node: ImportDefaultSpecifier, ast: &mut Ast) {
+        (**self).leave_import_default_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_import_star_as_specifier(&mut self, node: ImportStarAsSpecifier, ast: &mut Ast) {
+        (**self).enter_import_star_as_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_import_star_as_specifier(&mut self, node: ImportStarAsSpecifier, ast: &mut Ast) {
+        (**self).leave_import_star_as_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_export_decl(&mut self, node: ExportDecl, ast: &mut Ast) {
+        (**self).enter_export_decl(node, ast)
+    }
+    #[inline]
+    fn leave_export_decl(&mut self, node: ExportDecl, ast: &mut Ast) {
+        (**self).leave_export_decl(node, ast)
+    }
+    #[inline]
+    fn enter_named_export(&mut self, node: NamedExport, ast: &mut Ast) {
+        (**self).enter_named_export(node, ast)
+    }
+    #[inline]
+    fn leave_named_export(&mut self, node: NamedExport, ast: &mut Ast) {
+        (**self).leave_named_export(node, ast)
+    }
+    #[inline]
+    fn enter_export_namespace_specifier(&mut self, node: ExportNamespaceSpecifier, ast: &mut Ast) {
+        (**self).enter_export_namespace_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_export_namespace_specifier(&mut self, node: ExportNamespaceSpecifier, ast: &mut Ast) {
+        (**self).leave_export_namespace_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_export_default_specifier(&mut self, node: ExportDefaultSpecifier, ast: &mut Ast) {
+        (**self).enter_export_default_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_export_default_specifier(&mut self, node: ExportDefaultSpecifier, ast: &mut Ast) {
+        (**self).leave_export_default_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_export_named_specifier(&mut self, node: ExportNamedSpecifier, ast: &mut Ast) {
+        (**self).enter_export_named_specifier(node, ast)
+    }
+    #[inline]
+    fn leave_export_named_specifier(&mut self, node: ExportNamedSpecifier, ast: &mut Ast) {
+        (**self).leave_export_named_specifier(node, ast)
+    }
+    #[inline]
+    fn enter_export_default_decl(&mut self, node: ExportDefaultDecl, ast: &mut Ast) {
+        (**self).enter_export_default_decl(node, ast)
+    }
+    #[inline]
+    fn leave_export_default_decl(&mut self, node: ExportDefaultDecl, ast: &mut Ast) {
+        (**self).leave_export_default_decl(node, ast)
+    }
+    #[inline]
+    fn enter_export_default_expr(&mut self, node: ExportDefaultExpr, ast: &mut Ast) {
+        (**self).enter_export_default_expr(node, ast)
+    }
+    #[inline]
+    fn leave_export_default_expr(&mut self, node: ExportDefaultExpr, ast: &mut Ast) {
+        (**self).leave_export_default_expr(node, ast)
+    }
+    #[inline]
+    fn enter_export_all(&mut self, node: ExportAll, ast: &mut Ast) {
+        (**self).enter_export_all(node, ast)
+    }
+    #[inline]
+    fn leave_export_all(&mut self, node: ExportAll, ast: &mut Ast) {
+        (**self).leave_export_all(node, ast)
+    }
+    #[inline]
+    fn enter_block_stmt(&mut self, node: BlockStmt, ast: &mut Ast) {
+        (**self).enter_block_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_block_stmt(&mut self, node: BlockStmt, ast: &mut Ast) {
+        (**self).leave_block_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_expr_stmt(&mut self, node: ExprStmt, ast: &mut Ast) {
+        (**self).enter_expr_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_expr_stmt(&mut self, node: ExprStmt, ast: &mut Ast) {
+        (**self).leave_expr_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_empty_stmt(&mut self, node: EmptyStmt, ast: &mut Ast) {
+        (**self).enter_empty_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_empty_stmt(&mut self, node: EmptyStmt, ast: &mut Ast) {
+        (**self).leave_empty_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_debugger_stmt(&mut self, node: DebuggerStmt, ast: &mut Ast) {
+        (**self).enter_debugger_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_debugger_stmt(&mut self, node: DebuggerStmt, ast: &mut Ast) {
+        (**self).leave_debugger_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_with_stmt(&mut self, node: WithStmt, ast: &mut Ast) {
+        (**self).enter_with_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_with_stmt(&mut self, node: WithStmt, ast: &mut Ast) {
+        (**self).leave_with_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_return_stmt(&mut self, node: ReturnStmt, ast: &mut Ast) {
+        (**self).enter_return_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_return_stmt(&mut self, node: ReturnStmt, ast: &mut Ast) {
+        (**self).leave_return_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_labeled_stmt(&mut self, node: LabeledStmt, ast: &mut Ast) {
+        (**self).enter_labeled_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_labeled_stmt(&mut self, node: LabeledStmt, ast: &mut Ast) {
+        (**self).leave_labeled_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_break_stmt(&mut self, node: BreakStmt, ast: &mut Ast) {
+        (**self).enter_break_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_break_stmt(&mut self, node: BreakStmt, ast: &mut Ast) {
+        (**self).leave_break_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_continue_stmt(&mut self, node: ContinueStmt, ast: &mut Ast) {
+        (**self).enter_continue_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_continue_stmt(&mut self, node: ContinueStmt, ast: &mut Ast) {
+        (**self).leave_continue_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_if_stmt(&mut self, node: IfStmt, ast: &mut Ast) {
+        (**self).enter_if_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_if_stmt(&mut self, node: IfStmt, ast: &mut Ast) {
+        (**self).leave_if_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_switch_stmt(&mut self, node: SwitchStmt, ast: &mut Ast) {
+        (**self).enter_switch_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_switch_stmt(&mut self, node: SwitchStmt, ast: &mut Ast) {
+        (**self).leave_switch_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_throw_stmt(&mut self, node: ThrowStmt, ast: &mut Ast) {
+        (**self).enter_throw_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_throw_stmt(&mut self, node: ThrowStmt, ast: &mut Ast) {
+        (**self).leave_throw_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_try_stmt(&mut self, node: TryStmt, ast: &mut Ast) {
+        (**self).enter_try_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_try_stmt(&mut self, node: TryStmt, ast: &mut Ast) {
+        (**self).leave_try_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_while_stmt(&mut self, node: WhileStmt, ast: &mut Ast) {
+        (**self).enter_while_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_while_stmt(&mut self, node: WhileStmt, ast: &mut Ast) {
+        (**self).leave_while_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_do_while_stmt(&mut self, node: DoWhileStmt, ast: &mut Ast) {
+        (**self).enter_do_while_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_do_while_stmt(&mut self, node: DoWhileStmt, ast: &mut Ast) {
+        (**self).leave_do_while_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_for_stmt(&mut self, node: ForStmt, ast: &mut Ast) {
+        (**self).enter_for_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_for_stmt(&mut self, node: ForStmt, ast: &mut Ast) {
+        (**self).leave_for_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_for_in_stmt(&mut self, node: ForInStmt, ast: &mut Ast) {
+        (**self).enter_for_in_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_for_in_stmt(&mut self, node: ForInStmt, ast: &mut Ast) {
+        (**self).leave_for_in_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_for_of_stmt(&mut self, node: ForOfStmt, ast: &mut Ast) {
+        (**self).enter_for_of_stmt(node, ast)
+    }
+    #[inline]
+    fn leave_for_of_stmt(&mut self, node: ForOfStmt, ast: &mut Ast) {
+        (**self).leave_for_of_stmt(node, ast)
+    }
+    #[inline]
+    fn enter_switch_case(&mut self, node: SwitchCase, ast: &mut Ast) {
+        (**self).enter_switch_case(node, ast)
+    }
+    #[inline]
+    fn leave_switch_case(&mut self, node: SwitchCase, ast: &mut Ast) {
+        (**self).leave_switch_case(node, ast)
+    }
+    #[inline]
+    fn enter_catch_clause(&mut self, node: CatchClause, ast: &mut Ast) {
+        (**self).enter_catch_clause(node, ast)
+    }
+    #[inline]
+    fn leave_catch_clause(&mut self, node: CatchClause, ast: &mut Ast) {
+        (**self).leave_catch_clause(node, ast)
+    }
+    #[inline]
+    fn enter_fn_decl(&mut self, node: FnDecl, ast: &mut Ast) {
+        (**self).enter_fn_decl(node, ast)
+    }
+    #[inline]
+    fn leave_fn_decl(&mut self, node: FnDecl, ast: &mut Ast) {
+        (**self).leave_fn_decl(node, ast)
+    }
+    #[inline]
+    fn enter_class_decl(&mut self, node: ClassDecl, ast: &mut Ast) {
+        (**self).enter_class_decl(node, ast)
+    }
+    #[inline]
+    fn leave_class_decl(&mut self, node: ClassDecl, ast: &mut Ast) {
+        (**self).leave_class_decl(node, ast)
+    }
+    #[inline]
+    fn enter_var_decl(&mut self, node: VarDecl, ast: &mut Ast) {
+        (**self).enter_var_decl(node, ast)
+    }
+    #[inline]
+    fn leave_var_decl(&mut self, node: VarDecl, ast: &mut Ast) {
+        (**self).leave_var_decl(node, ast)
+    }
+    #[inline]
+    fn enter_var_declarator(&mut self, node: VarDeclarator, ast: &mut Ast) {
+        (**self).enter_var_declarator(node, ast)
+    }
+    #[inline]
+    fn leave_var_declarator(&mut self, node: VarDeclarator, ast: &mut Ast) {
+        (**self).leave_var_declarator(node, ast)
+    }
+    #[inline]
+    fn enter_using_decl(&mut self, node: UsingDecl, ast: &mut Ast) {
+        (**self).enter_using_decl(node, ast)
+    }
+    #[inline]
+    fn leave_using_decl(&mut self, node: UsingDecl, ast: &mut Ast) {
+        (**self).leave_using_decl(node, ast)
+    }
+    #[inline]
+    fn enter_this_expr(&mut self, node: ThisExpr, ast: &mut Ast) {
+        (**self).enter_this_expr(node, ast)
+    }
+    #[inline]
+    fn leave_this_expr(&mut self, node: ThisExpr, ast: &mut Ast) {
+        (**self).leave_this_expr(node, ast)
+    }
+    #[inline]
+    fn enter_array_lit(&mut self, node: ArrayLit, ast: &mut Ast) {
+        (**self).enter_array_lit(node, ast)
+    }
+    #[inline]
+    fn leave_array_lit(&mut self, node: ArrayLit, ast: &mut Ast) {
+        (**self).leave_array_lit(node, ast)
+    }
+    #[inline]
+    fn enter_object_lit(&mut self, node: ObjectLit, ast: &mut Ast) {
+        (**self).enter_object_lit(node, ast)
+    }
+    #[inline]
+    fn leave_object_lit(&mut self, node: ObjectLit, ast: &mut Ast) {
+        (**self).leave_object_lit(node, ast)
+    }
+    #[inline]
+    fn enter_spread_element(&mut self, node: SpreadElement, ast: &mut Ast) {
+        (**self).enter_spread_element(node, ast)
+    }
+    #[inline]
+    fn leave_spread_element(&mut self, node: SpreadElement, ast: &mut Ast) {
+        (**self).leave_spread_element(node, ast)
+    }
+    #[inline]
+    fn enter_unary_expr(&mut self, node: UnaryExpr, ast: &mut Ast) {
+        (**self).enter_unary_expr(node, ast)
+    }
+    #[inline]
+    fn leave_unary_expr(&mut self, node: UnaryExpr, ast: &mut Ast) {
+        (**self).leave_unary_expr(node, ast)
+    }
+    #[inline]
+    fn enter_update_expr(&mut self, node: UpdateExpr, ast: &mut Ast) {
+        (**self).enter_update_expr(node, ast)
+    }
+    #[inline]
+    fn leave_update_expr(&mut self, node: UpdateExpr, ast: &mut Ast) {
+        (**self).leave_update_expr(node, ast)
+    }
+    #[inline]
+    fn enter_bin_expr(&mut self, node: BinExpr, ast: &mut Ast) {
+        (**self).enter_bin_expr(node, ast)
+    }
+    #[inline]
+    fn leave_bin_expr(&mut self, node: BinExpr, ast: &mut Ast) {
+        (**self).leave_bin_expr(node, ast)
+    }
+    #[inline]
+    fn enter_fn_expr(&mut self, node: FnExpr, ast: &mut Ast) {
+        (**self).enter_fn_expr(node, ast)
+    }
+    #[inline]
+    fn leave_fn_expr(&mut self, node: FnExpr, ast: &mut Ast) {
+        (**self).leave_fn_expr(node, ast)
+    }
+    #[inline]
+    fn enter_class_expr(&mut self, node: ClassExpr, ast: &mut Ast) {
+        (**self).enter_class_expr(node, ast)
+    }
+    #[inline]
+    fn leave_class_expr(&mut self, node: ClassExpr, ast: &mut Ast) {
+        (**self).leave_class_expr(node, ast)
+    }
+    #[inline]
+    fn enter_assign_expr(&mut self, node: AssignExpr, ast: &mut Ast) {
+        (**self).enter_assign_expr(node, ast)
+    }
+    #[inline]
+    fn leave_assign_expr(&mut self, node: AssignExpr, ast: &mut Ast) {
+        (**self).leave_assign_expr(node, ast)
+    }
+    #[inline]
+    fn enter_member_expr(&mut self, node: MemberExpr, ast: &mut Ast) {
+        (**self).enter_member_expr(node, ast)
+    }
+    #[inline]
+    fn leave_member_expr(&mut self, node: MemberExpr, ast: &mut Ast) {
+        (**self).leave_member_expr(node, ast)
+    }
+    #[inline]
+    fn enter_super_prop_expr(&mut self, node: SuperPropExpr, ast: &mut Ast) {
+        (**self).enter_super_prop_expr(node, ast)
+    }
+    #[inline]
+    fn leave_super_prop_expr(&mut self, node: SuperPropExpr, ast: &mut Ast) {
+        (**self).leave_super_prop_expr(node, ast)
+    }
+    #[inline]
+    fn enter_cond_expr(&mut self, node: CondExpr, ast: &mut Ast) {
+        (**self).enter_cond_expr(node, ast)
+    }
+    #[inline]
+    fn leave_cond_expr(&mut self, node: CondExpr, ast: &mut Ast) {
+        (**self).leave_cond_expr(node, ast)
+    }
+    #[inline]
+    fn enter_call_expr(&mut self, node: CallExpr, ast: &mut Ast) {
+        (**self).enter_call_expr(node, ast)
+    }
+    #[inline]
+    fn leave_call_expr(&mut self, node: CallExpr, ast: &mut Ast) {
+        (**self).leave_call_expr(node, ast)
+    }
+    #[inline]
+    fn enter_new_expr(&mut self, node: NewExpr, ast: &mut Ast) {
+        (**self).enter_new_expr(node, ast)
+    }
+    #[inline]
+    fn leave_new_expr(&mut self, node: NewExpr, ast: &mut Ast) {
+        (**self).leave_new_expr(node, ast)
+    }
+    #[inline]
+    fn enter_seq_expr(&mut self, node: SeqExpr, ast: &mut Ast) {
+        (**self).enter_seq_expr(node, ast)
+    }
+    #[inline]
+    fn leave_seq_expr(&mut self, node: SeqExpr, ast: &mut Ast) {
+        (**self).leave_seq_expr(node, ast)
+    }
+    #[inline]
+    fn enter_arrow_expr(&mut self, node: ArrowExpr, ast: &mut Ast) {
+        (**self).enter_arrow_expr(node, ast)
+    }
+    #[inline]
+    fn leave_arrow_expr(&mut self, node: ArrowExpr, ast: &mut Ast) {
+        (**self).leave_arrow_expr(node, ast)
+    }
+    #[inline]
+    fn enter_yield_expr(&mut self, node: YieldExpr, ast: &mut Ast) {
+        (**self).enter_yield_expr(node, ast)
+    }
+    #[inline]
+    fn leave_yield_expr(&mut self, node: YieldExpr, ast: &mut Ast) {
+        (**self).leave_yield_expr(node, ast)
+    }
+    #[inline]
+    fn enter_meta_prop_expr(&mut self, node: MetaPropExpr, ast: &mut Ast) {
+        (**self).enter_meta_prop_expr(node, ast)
+    }
+    #[inline]
+    fn leave_meta_prop_expr(&mut self, node: MetaPropExpr, ast: &mut Ast) {
+        (**self).leave_meta_prop_expr(node, ast)
+    }
+    #[inline]
+    fn enter_await_expr(&mut self, node: AwaitExpr, ast: &mut Ast) {
+        (**self).enter_await_expr(node, ast)
+    }
+    #[inline]
+    fn leave_await_expr(&mut self, node: AwaitExpr, ast: &mut Ast) {
+        (**self).leave_await_expr(node, ast)
+    }
+    #[inline]
+    fn enter_tpl(&mut self, node: Tpl, ast: &mut Ast) {
+        (**self).enter_tpl(node, ast)
+    }
+    #[inline]
+    fn leave_tpl(&mut self, node: Tpl, ast: &mut Ast) {
+        (**self).leave_tpl(node, ast)
+    }
+    #[inline]
+    fn enter_tagged_tpl(&mut self, node: TaggedTpl, ast: &mut Ast) {
+        (**self).enter_tagged_tpl(node, ast)
+    }
+    #[inline]
+    fn leave_tagged_tpl(&mut self, node: TaggedTpl, ast: &mut Ast) {
+        (**self).leave_tagged_tpl(node, ast)
+    }
+    #[inline]
+    fn enter_tpl_element(&mut self, node: TplElement, ast: &mut Ast) {
+        (**self).enter_tpl_element(node, ast)
+    }
+    #[inline]
+    fn leave_tpl_element(&mut self, node: TplElement, ast: &mut Ast) {
+        (**self).leave_tpl_element(node, ast)
+    }
+    #[inline]
+    fn enter_paren_expr(&mut self, node: ParenExpr, ast: &mut Ast) {
+        (**self).enter_paren_expr(node, ast)
+    }
+    #[inline]
+    fn leave_paren_expr(&mut self, node: ParenExpr, ast: &mut Ast) {
+        (**self).leave_paren_expr(node, ast)
+    }
+    #[inline]
+    fn enter_super(&mut self, node: Super, ast: &mut Ast) {
+        (**self).enter_super(node, ast)
+    }
+    #[inline]
+    fn leave_super(&mut self, node: Super, ast: &mut Ast) {
+        (**self).leave_super(node, ast)
+    }
+    #[inline]
+    fn enter_import(&mut self, node: Import, ast: &mut Ast) {
+        (**self).enter_import(node, ast)
+    }
+    #[inline]
+    fn leave_import(&mut self, node: Import, ast: &mut Ast) {
+        (**self).leave_import(node, ast)
+    }
+    #[inline]
+    fn enter_expr_or_spread(&mut self, node: ExprOrSpread, ast: &mut Ast) {
+        (**self).enter_expr_or_spread(node, ast)
+    }
+    #[inline]
+    fn leave_expr_or_spread(&mut self, node: ExprOrSpread, ast: &mut Ast) {
+        (**self).leave_expr_or_spread(node, ast)
+    }
+    #[inline]
+    fn enter_spread_dot_3_token(&mut self, node: SpreadDot3Token, ast: &mut Ast) {
+        (**self).enter_spread_dot_3_token(node, ast)
+    }
+    #[inline]
+    fn leave_spread_dot_3_token(&mut self, node: SpreadDot3Token, ast: &mut Ast) {
+        (**self).leave_spread_dot_3_token(node, ast)
+    }
+    #[inline]
+    fn enter_opt_chain_expr(&mut self, node: OptChainExpr, ast: &mut Ast) {
+        (**self).enter_opt_chain_expr(node, ast)
+    }
+    #[inline]
+    fn leave_opt_chain_expr(&mut self, node: OptChainExpr, ast: &mut Ast) {
+        (**self).leave_opt_chain_expr(node, ast)
+    }
+    #[inline]
+    fn enter_opt_call(&mut self, node: OptCall, ast: &mut Ast) {
+        (**self).enter_opt_call(node, ast)
+    }
+    #[inline]
+    fn leave_opt_call(&mut self, node: OptCall, ast: &mut Ast) {
+        (**self).leave_opt_call(node, ast)
+    }
+    #[inline]
+    fn enter_invalid(&mut self, node: Invalid, ast: &mut Ast) {
+        (**self).enter_invalid(node, ast)
+    }
+    #[inline]
+    fn leave_invalid(&mut self, node: Invalid, ast: &mut Ast) {
+        (**self).leave_invalid(node, ast)
+    }
+    #[inline]
+    fn enter_function(&mut self, node: Function, ast: &mut Ast) {
+        (**self).enter_function(node, ast)
+    }
+    #[inline]
+    fn leave_function(&mut self, node: Function, ast: &mut Ast) {
+        (**self).leave_function(node, ast)
+    }
+    #[inline]
+    fn enter_param(&mut self, node: Param, ast: &mut Ast) {
+        (**self).enter_param(node, ast)
+    }
+    #[inline]
+    fn leave_param(&mut self, node: Param, ast: &mut Ast) {
+        (**self).leave_param(node, ast)
+    }
+    #[inline]
+    fn enter_class(&mut self, node: Class, ast: &mut Ast) {
+        (**self).enter_class(node, ast)
+    }
+    #[inline]
+    fn leave_class(&mut self, node: Class, ast: &mut Ast) {
+        (**self).leave_class(node, ast)
+    }
+    #[inline]
+    fn enter_class_prop(&mut self, node: ClassProp, ast: &mut Ast) {
+        (**self).enter_class_prop(node, ast)
+    }
+    #[inline]
+    fn leave_class_prop(&mut self, node: ClassProp, ast: &mut Ast) {
+        (**self).leave_class_prop(node, ast)
+    }
+    #[inline]
+    fn enter_private_prop(&mut self, node: PrivateProp, ast: &mut Ast) {
+        (**self).enter_private_prop(node, ast)
+    }
+    #[inline]
+    fn leave_private_prop(&mut self, node: PrivateProp, ast: &mut Ast) {
+        (**self).leave_private_prop(node, ast)
+    }
+    #[inline]
+    fn enter_class_method(&mut self, node: ClassMethod, ast: &mut Ast) {
+        (**self).enter_class_method(node, ast)
+    }
+    #[inline]
+    fn leave_class_method(&mut self, node: ClassMethod, ast: &mut Ast) {
+        (**self).leave_class_method(node, ast)
+    }
+    #[inline]
+    fn enter_private_method(&mut self, node: PrivateMethod, ast: &mut Ast) {
+        (**self).enter_private_method(node, ast)
+    }
+    #[inline]
+    fn leave_private_method(&mut self, node: PrivateMethod, ast: &mut Ast) {
+        (**self).leave_private_method(node, ast)
+    }
+    #[inline]
+    fn enter_constructor(&mut self, node: Constructor, ast: &mut Ast) {
+        (**self).enter_constructor(node, ast)
+    }
+    #[inline]
+    fn leave_constructor(&mut self, node: Constructor, ast: &mut Ast) {
+        (**self).leave_constructor(node, ast)
+    }
+    #[inline]
+    fn enter_decorator(&mut self, node: Decorator, ast: &mut Ast) {
+        (**self).enter_decorator(node, ast)
+    }
+    #[inline]
+    fn leave_decorator(&mut self, node: Decorator, ast: &mut Ast) {
+        (**self).leave_decorator(node, ast)
+    }
+    #[inline]
+    fn enter_static_block(&mut self, node: StaticBlock, ast: &mut Ast) {
+        (**self).enter_static_block(node, ast)
+    }
+    #[inline]
+    fn leave_static_block(&mut self, node: StaticBlock, ast: &mut Ast) {
+        (**self).leave_static_block(node, ast)
+    }
+    #[inline]
+    fn enter_auto_accessor(&mut self, node: AutoAccessor, ast: &mut Ast) {
+        (**self).enter_auto_accessor(node, ast)
+    }
+    #[inline]
+    fn leave_auto_accessor(&mut self, node: AutoAccessor, ast: &mut Ast) {
+        (**self).leave_auto_accessor(node, ast)
+    }
+    #[inline]
+    fn enter_key_value_prop(&mut self, node: KeyValueProp, ast: &mut Ast) {
+        (**self).enter_key_value_prop(node, ast)
+    }
+    #[inline]
+    fn leave_key_value_prop(&mut self, node: KeyValueProp, ast: &mut Ast) {
+        (**self).leave_key_value_prop(node, ast)
+    }
+    #[inline]
+    fn enter_assign_prop(&mut self, node: AssignProp, ast: &mut Ast) {
+        (**self).enter_assign_prop(node, ast)
+    }
+    #[inline]
+    fn leave_assign_prop(&mut self, node: AssignProp, ast: &mut Ast) {
+        (**self).leave_assign_prop(node, ast)
+    }
+    #[inline]
+    fn enter_getter_prop(&mut self, node: GetterProp, ast: &mut Ast) {
+        (**self).enter_getter_prop(node, ast)
+    }
+    #[inline]
+    fn leave_getter_prop(&mut self, node: GetterProp, ast: &mut Ast) {
+        (**self).leave_getter_prop(node, ast)
+    }
+    #[inline]
+    fn enter_setter_prop(&mut self, node: SetterProp, ast: &mut Ast) {
+        (**self).enter_setter_prop(node, ast)
+    }
+    #[inline]
+    fn leave_setter_prop(&mut self, node: SetterProp, ast: &mut Ast) {
+        (**self).leave_setter_prop(node, ast)
+    }
+    #[inline]
+    fn enter_method_prop(&mut self, node: MethodProp, ast: &mut Ast) {
+        (**self).enter_method_prop(node, ast)
+    }
+    #[inline]
+    fn leave_method_prop(&mut self, node: MethodProp, ast: &mut Ast) {
+        (**self).leave_method_prop(node, ast)
+    }
+    #[inline]
+    fn enter_computed_prop_name(&mut self, node: ComputedPropName, ast: &mut Ast) {
+        (**self).enter_computed_prop_name(node, ast)
+    }
+    #[inline]
+    fn leave_computed_prop_name(&mut self, node: ComputedPropName, ast: &mut Ast) {
+        (**self).leave_computed_prop_name(node, ast)
+    }
+    #[inline]
+    fn enter_array_pat(&mut self, node: ArrayPat, ast: &mut Ast) {
+        (**self).enter_array_pat(node, ast)
+    }
+    #[inline]
+    fn leave_array_pat(&mut self, node: ArrayPat, ast: &mut Ast) {
+        (**self).leave_array_pat(node, ast)
+    }
+    #[inline]
+    fn enter_object_pat(&mut self, node: ObjectPat, ast: &mut Ast) {
+        (**self).enter_object_pat(node, ast)
+    }
+    #[inline]
+    fn leave_object_pat(&mut self, node: ObjectPat, ast: &mut Ast) {
+        (**self).leave_object_pat(node, ast)
+    }
+    #[inline]
+    fn enter_assign_pat(&mut self, node: AssignPat, ast: &mut Ast) {
+        (**self).enter_assign_pat(node, ast)
+    }
+    #[inline]
+    fn leave_assign_pat(&mut self, node: AssignPat, ast: &mut Ast) {
+        (**self).leave_assign_pat(node, ast)
+    }
+    #[inline]
+    fn enter_rest_pat(&mut self, node: RestPat, ast: &mut Ast) {
+        (**self).enter_rest_pat(node, ast)
+    }
+    #[inline]
+    fn leave_rest_pat(&mut self, node: RestPat, ast: &mut Ast) {
+        (**self).leave_rest_pat(node, ast)
+    }
+    #[inline]
+    fn enter_key_value_pat_prop(&mut self, node: KeyValuePatProp, ast: &mut Ast) {
+        (**self).enter_key_value_pat_prop(node, ast)
+    }
+    #[inline]
+    fn leave_key_value_pat_prop(&mut self, node: KeyValuePatProp, ast: &mut Ast) {
+        (**self).leave_key_value_pat_prop(node, ast)
+    }
+    #[inline]
+    fn enter_assign_pat_prop(&mut self, node: AssignPatProp, ast: &mut Ast) {
+        (**self).enter_assign_pat_prop(node, ast)
+    }
+    #[inline]
+    fn leave_assign_pat_prop(&mut self, node: AssignPatProp, ast: &mut Ast) {
+        (**self).leave_assign_pat_prop(node, ast)
+    }
+    #[inline]
+    fn enter_ident(&mut self, node: Ident, ast: &mut Ast) {
+        (**self).enter_ident(node, ast)
+    }
+    #[inline]
+    fn leave_ident(&mut self, node: Ident, ast: &mut Ast) {
+        (**self).leave_ident(node, ast)
+    }
+    #[inline]
+    fn enter_ident_name(&mut self, node: IdentName, ast: &mut Ast) {
+        (**self).enter_ident_name(node, ast)
+    }
+    #[inline]
+    fn leave_ident_name(&mut self, node: IdentName, ast: &mut Ast) {
+        (**self).leave_ident_name(node, ast)
+    }
+    #[inline]
+    fn enter_private_name(&mut self, node: PrivateName, ast: &mut Ast) {
+        (**self).enter_private_name(node, ast)
+    }
+    #[inline]
+    fn leave_private_name(&mut self, node: PrivateName, ast: &mut Ast) {
+        (**self).leave_private_name(node, ast)
+    }
+    #[inline]
+    fn enter_binding_ident(&mut self, node: BindingIdent, ast: &mut Ast) {
+        (**self).enter_binding_ident(node, ast)
+    }
+    #[inline]
+    fn leave_binding_ident(&mut self, node: BindingIdent, ast: &mut Ast) {
+        (**self).leave_binding_ident(node, ast)
+    }
+    #[inline]
+    fn enter_str(&mut self, node: Str, ast: &mut Ast) {
+        (**self).enter_str(node, ast)
+    }
+    #[inline]
+    fn leave_str(&mut self, node: Str, ast: &mut Ast) {
+        (**self).leave_str(node, ast)
+    }
+    #[inline]
+    fn enter_bool(&mut self, node: Bool, ast: &mut Ast) {
+        (**self).enter_bool(node, ast)
+    }
+    #[inline]
+    fn leave_bool(&mut self, node: Bool, ast: &mut Ast) {
+        (**self).leave_bool(node, ast)
+    }
+    #[inline]
+    fn enter_null(&mut self, node: Null, ast: &mut Ast) {
+        (**self).enter_null(node, ast)
+    }
+    #[inline]
+    fn leave_null(&mut self, node: Null, ast: &mut Ast) {
+        (**self).leave_null(node, ast)
+    }
+    #[inline]
+    fn enter_number(&mut self, node: Number, ast: &mut Ast) {
+        (**self).enter_number(node, ast)
+    }
+    #[inline]
+    fn leave_number(&mut self, node: Number, ast: &mut Ast) {
+        (**self).leave_number(node, ast)
+    }
+    #[inline]
+    fn enter_big_int(&mut self, node: BigInt, ast: &mut Ast) {
+        (**self).enter_big_int(node, ast)
+    }
+    #[inline]
+    fn leave_big_int(&mut self, node: BigInt, ast: &mut Ast) {
+        (**self).leave_big_int(node, ast)
+    }
+    #[inline]
+    fn enter_regex(&mut self, node: Regex, ast: &mut Ast) {
+        (**self).enter_regex(node, ast)
+    }
+    #[inline]
+    fn leave_regex(&mut self, node: Regex, ast: &mut Ast) {
+        (**self).leave_regex(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_member_expr(&mut self, node: JSXMemberExpr, ast: &mut Ast) {
+        (**self).enter_jsx_member_expr(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_member_expr(&mut self, node: JSXMemberExpr, ast: &mut Ast) {
+        (**self).leave_jsx_member_expr(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_namespaced_name(&mut self, node: JSXNamespacedName, ast: &mut Ast) {
+        (**self).enter_jsx_namespaced_name(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_namespaced_name(&mut self, node: JSXNamespacedName, ast: &mut Ast) {
+        (**self).leave_jsx_namespaced_name(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_empty_expr(&mut self, node: JSXEmptyExpr, ast: &mut Ast) {
+        (**self).enter_jsx_empty_expr(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_empty_expr(&mut self, node: JSXEmptyExpr, ast: &mut Ast) {
+        (**self).leave_jsx_empty_expr(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_expr_container(&mut self, node: JSXExprContainer, ast: &mut Ast) {
+        (**self).enter_jsx_expr_container(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_expr_container(&mut self, node: JSXExprContainer, ast: &mut Ast) {
+        (**self).leave_jsx_expr_container(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_spread_child(&mut self, node: JSXSpreadChild, ast: &mut Ast) {
+        (**self).enter_jsx_spread_child(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_spread_child(&mut self, node: JSXSpreadChild, ast: &mut Ast) {
+        (**self).leave_jsx_spread_child(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_opening_element(&mut self, node: JSXOpeningElement, ast: &mut Ast) {
+        (**self).enter_jsx_opening_element(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_opening_element(&mut self, node: JSXOpeningElement, ast: &mut Ast) {
+        (**self).leave_jsx_opening_element(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_closing_element(&mut self, node: JSXClosingElement, ast: &mut Ast) {
+        (**self).enter_jsx_closing_element(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_closing_element(&mut self, node: JSXClosingElement, ast: &mut Ast) {
+        (**self).leave_jsx_closing_element(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_attr(&mut self, node: JSXAttr, ast: &mut Ast) {
+        (**self).enter_jsx_attr(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_attr(&mut self, node: JSXAttr, ast: &mut Ast) {
+        (**self).leave_jsx_attr(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_text(&mut self, node: JSXText, ast: &mut Ast) {
+        (**self).enter_jsx_text(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_text(&mut self, node: JSXText, ast: &mut Ast) {
+        (**self).leave_jsx_text(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_element(&mut self, node: JSXElement, ast: &mut Ast) {
+        (**self).enter_jsx_element(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_element(&mut self, node: JSXElement, ast: &mut Ast) {
+        (**self).leave_jsx_element(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_fragment(&mut self, node: JSXFragment, ast: &mut Ast) {
+        (**self).enter_jsx_fragment(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_fragment(&mut self, node: JSXFragment, ast: &mut Ast) {
+        (**self).leave_jsx_fragment(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_opening_fragment(&mut self, node: JSXOpeningFragment, ast: &mut Ast) {
+        (**self).enter_jsx_opening_fragment(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_opening_fragment(&mut self, node: JSXOpeningFragment, ast: &mut Ast) {
+        (**self).leave_jsx_opening_fragment(node, ast)
+    }
+    #[inline]
+    fn enter_jsx_closing_fragment(&mut self, node: JSXClosingFragment, ast: &mut Ast) {
+        (**self).enter_jsx_closing_fragment(node, ast)
+    }
+    #[inline]
+    fn leave_jsx_closing_fragment(&mut self, node: JSXClosingFragment, ast: &mut Ast) {
+        (**self).leave_jsx_closing_fragment(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_ann(&mut self, node: TsTypeAnn, ast: &mut Ast) {
+        (**self).enter_ts_type_ann(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_ann(&mut self, node: TsTypeAnn, ast: &mut Ast) {
+        (**self).leave_ts_type_ann(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_param_decl(&mut self, node: TsTypeParamDecl, ast: &mut Ast) {
+        (**self).enter_ts_type_param_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_param_decl(&mut self, node: TsTypeParamDecl, ast: &mut Ast) {
+        (**self).leave_ts_type_param_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_param(&mut self, node: TsTypeParam, ast: &mut Ast) {
+        (**self).enter_ts_type_param(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_param(&mut self, node: TsTypeParam, ast: &mut Ast) {
+        (**self).leave_ts_type_param(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_param_instantiation(&mut self, node: TsTypeParamInstantiation, ast: &mut Ast) {
+        (**self).enter_ts_type_param_instantiation(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_param_instantiation(&mut self, node: TsTypeParamInstantiation, ast: &mut Ast) {
+        (**self).leave_ts_type_param_instantiation(node, ast)
+    }
+    #[inline]
+    fn enter_ts_param_prop(&mut self, node: TsParamProp, ast: &mut Ast) {
+        (**self).enter_ts_param_prop(node, ast)
+    }
+    #[inline]
+    fn leave_ts_param_prop(&mut self, node: TsParamProp, ast: &mut Ast) {
+        (**self).leave_ts_param_prop(node, ast)
+    }
+    #[inline]
+    fn enter_ts_qualified_name(&mut self, node: TsQualifiedName, ast: &mut Ast) {
+        (**self).enter_ts_qualified_name(node, ast)
+    }
+    #[inline]
+    fn leave_ts_qualified_name(&mut self, node: TsQualifiedName, ast: &mut Ast) {
+        (**self).leave_ts_qualified_name(node, ast)
+    }
+    #[inline]
+    fn enter_ts_call_signature_decl(&mut self, node: TsCallSignatureDecl, ast: &mut Ast) {
+        (**self).enter_ts_call_signature_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_call_signature_decl(&mut self, node: TsCallSignatureDecl, ast: &mut Ast) {
+        (**self).leave_ts_call_signature_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_construct_signature_decl(&mut self, node: TsConstructSignatureDecl, ast: &mut Ast) {
+        (**self).enter_ts_construct_signature_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_construct_signature_decl(&mut self, node: TsConstructSignatureDecl, ast: &mut Ast) {
+        (**self).leave_ts_construct_signature_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_property_signature(&mut self, node: TsPropertySignature, ast: &mut Ast) {
+        (**self).enter_ts_property_signature(node, ast)
+    }
+    #[inline]
+    fn leave_ts_property_signature(&mut self, node: TsPropertySignature, ast: &mut Ast) {
+        (**self).leave_ts_property_signature(node, ast)
+    }
+    #[inline]
+    fn enter_ts_getter_signature(&mut self, node: TsGetterSignature, ast: &mut Ast) {
+        (**self).enter_ts_getter_signature(node, ast)
+    }
+    #[inline]
+    fn leave_ts_getter_signature(&mut self, node: TsGetterSignature, ast: &mut Ast) {
+        (**self).leave_ts_getter_signature(node, ast)
+    }
+    #[inline]
+    fn enter_ts_setter_signature(&mut self, node: TsSetterSignature, ast: &mut Ast) {
+        (**self).enter_ts_setter_signature(node, ast)
+    }
+    #[inline]
+    fn leave_ts_setter_signature(&mut self, node: TsSetterSignature, ast: &mut Ast) {
+        (**self).leave_ts_setter_signature(node, ast)
+    }
+    #[inline]
+    fn enter_ts_method_signature(&mut self, node: TsMethodSignature, ast: &mut Ast) {
+        (**self).enter_ts_method_signature(node, ast)
+    }
+    #[inline]
+    fn leave_ts_method_signature(&mut self, node: TsMethodSignature, ast: &mut Ast) {
+        (**self).leave_ts_method_signature(node, ast)
+    }
+    #[inline]
+    fn enter_ts_index_signature(&mut self, node: TsIndexSignature, ast: &mut Ast) {
+        (**self).enter_ts_index_signature(node, ast)
+    }
+    #[inline]
+    fn leave_ts_index_signature(&mut self, node: TsIndexSignature, ast: &mut Ast) {
+        (**self).leave_ts_index_signature(node, ast)
+    }
+    #[inline]
+    fn enter_ts_keyword_type(&mut self, node: TsKeywordType, ast: &mut Ast) {
+        (**self).enter_ts_keyword_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_keyword_type(&mut self, node: TsKeywordType, ast: &mut Ast) {
+        (**self).leave_ts_keyword_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_this_type(&mut self, node: TsThisType, ast: &mut Ast) {
+        (**self).enter_ts_this_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_this_type(&mut self, node: TsThisType, ast: &mut Ast) {
+        (**self).leave_ts_this_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_fn_type(&mut self, node: TsFnType, ast: &mut Ast) {
+        (**self).enter_ts_fn_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_fn_type(&mut self, node: TsFnType, ast: &mut Ast) {
+        (**self).leave_ts_fn_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_constructor_type(&mut self, node: TsConstructorType, ast: &mut Ast) {
+        (**self).enter_ts_constructor_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_constructor_type(&mut self, node: TsConstructorType, ast: &mut Ast) {
+        (**self).leave_ts_constructor_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_ref(&mut self, node: TsTypeRef, ast: &mut Ast) {
+        (**self).enter_ts_type_ref(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_ref(&mut self, node: TsTypeRef, ast: &mut Ast) {
+        (**self).leave_ts_type_ref(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_predicate(&mut self, node: TsTypePredicate, ast: &mut Ast) {
+        (**self).enter_ts_type_predicate(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_predicate(&mut self, node: TsTypePredicate, ast: &mut Ast) {
+        (**self).leave_ts_type_predicate(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_query(&mut self, node: TsTypeQuery, ast: &mut Ast) {
+        (**self).enter_ts_type_query(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_query(&mut self, node: TsTypeQuery, ast: &mut Ast) {
+        (**self).leave_ts_type_query(node, ast)
+    }
+    #[inline]
+    fn enter_ts_import_call_options(&mut self, node: TsImportCallOptions, ast: &mut Ast) {
+        (**self).enter_ts_import_call_options(node, ast)
+    }
+    #[inline]
+    fn leave_ts_import_call_options(&mut self, node: TsImportCallOptions, ast: &mut Ast) {
+        (**self).leave_ts_import_call_options(node, ast)
+    }
+    #[inline]
+    fn enter_ts_import_type(&mut self, node: TsImportType, ast: &mut Ast) {
+        (**self).enter_ts_import_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_import_type(&mut self, node: TsImportType, ast: &mut Ast) {
+        (**self).leave_ts_import_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_lit(&mut self, node: TsTypeLit, ast: &mut Ast) {
+        (**self).enter_ts_type_lit(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_lit(&mut self, node: TsTypeLit, ast: &mut Ast) {
+        (**self).leave_ts_type_lit(node, ast)
+    }
+    #[inline]
+    fn enter_ts_array_type(&mut self, node: TsArrayType, ast: &mut Ast) {
+        (**self).enter_ts_array_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_array_type(&mut self, node: TsArrayType, ast: &mut Ast) {
+        (**self).leave_ts_array_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_tuple_type(&mut self, node: TsTupleType, ast: &mut Ast) {
+        (**self).enter_ts_tuple_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_tuple_type(&mut self, node: TsTupleType, ast: &mut Ast) {
+        (**self).leave_ts_tuple_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_tuple_element(&mut self, node: TsTupleElement, ast: &mut Ast) {
+        (**self).enter_ts_tuple_element(node, ast)
+    }
+    #[inline]
+    fn leave_ts_tuple_element(&mut self, node: TsTupleElement, ast: &mut Ast) {
+        (**self).leave_ts_tuple_element(node, ast)
+    }
+    #[inline]
+    fn enter_ts_optional_type(&mut self, node: TsOptionalType, ast: &mut Ast) {
+        (**self).enter_ts_optional_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_optional_type(&mut self, node: TsOptionalType, ast: &mut Ast) {
+        (**self).leave_ts_optional_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_rest_type(&mut self, node: TsRestType, ast: &mut Ast) {
+        (**self).enter_ts_rest_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_rest_type(&mut self, node: TsRestType, ast: &mut Ast) {
+        (**self).leave_ts_rest_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_union_type(&mut self, node: TsUnionType, ast: &mut Ast) {
+        (**self).enter_ts_union_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_union_type(&mut self, node: TsUnionType, ast: &mut Ast) {
+        (**self).leave_ts_union_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_intersection_type(&mut self, node: TsIntersectionType, ast: &mut Ast) {
+        (**self).enter_ts_intersection_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_intersection_type(&mut self, node: TsIntersectionType, ast: &mut Ast) {
+        (**self).leave_ts_intersection_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_conditional_type(&mut self, node: TsConditionalType, ast: &mut Ast) {
+        (**self).enter_ts_conditional_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_conditional_type(&mut self, node: TsConditionalType, ast: &mut Ast) {
+        (**self).leave_ts_conditional_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_infer_type(&mut self, node: TsInferType, ast: &mut Ast) {
+        (**self).enter_ts_infer_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_infer_type(&mut self, node: TsInferType, ast: &mut Ast) {
+        (**self).leave_ts_infer_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_parenthesized_type(&mut self, node: TsParenthesizedType, ast: &mut Ast) {
+        (**self).enter_ts_parenthesized_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_parenthesized_type(&mut self, node: TsParenthesizedType, ast: &mut Ast) {
+        (**self).leave_ts_parenthesized_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_operator(&mut self, node: TsTypeOperator, ast: &mut Ast) {
+        (**self).enter_ts_type_operator(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_operator(&mut self, node: TsTypeOperator, ast: &mut Ast) {
+        (**self).leave_ts_type_operator(node, ast)
+    }
+    #[inline]
+    fn enter_ts_indexed_access_type(&mut self, node: TsIndexedAccessType, ast: &mut Ast) {
+        (**self).enter_ts_indexed_access_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_indexed_access_type(&mut self, node: TsIndexedAccessType, ast: &mut Ast) {
+        (**self).leave_ts_indexed_access_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_mapped_type(&mut self, node: TsMappedType, ast: &mut Ast) {
+        (**self).enter_ts_mapped_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_mapped_type(&mut self, node: TsMappedType, ast: &mut Ast) {
+        (**self).leave_ts_mapped_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_lit_type(&mut self, node: TsLitType, ast: &mut Ast) {
+        (**self).enter_ts_lit_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_lit_type(&mut self, node: TsLitType, ast: &mut Ast) {
+        (**self).leave_ts_lit_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_tpl_lit_type(&mut self, node: TsTplLitType, ast: &mut Ast) {
+        (**self).enter_ts_tpl_lit_type(node, ast)
+    }
+    #[inline]
+    fn leave_ts_tpl_lit_type(&mut self, node: TsTplLitType, ast: &mut Ast) {
+        (**self).leave_ts_tpl_lit_type(node, ast)
+    }
+    #[inline]
+    fn enter_ts_interface_decl(&mut self, node: TsInterfaceDecl, ast: &mut Ast) {
+        (**self).enter_ts_interface_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_interface_decl(&mut self, node: TsInterfaceDecl, ast: &mut Ast) {
+        (**self).leave_ts_interface_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_interface_body(&mut self, node: TsInterfaceBody, ast: &mut Ast) {
+        (**self).enter_ts_interface_body(node, ast)
+    }
+    #[inline]
+    fn leave_ts_interface_body(&mut self, node: TsInterfaceBody, ast: &mut Ast) {
+        (**self).leave_ts_interface_body(node, ast)
+    }
+    #[inline]
+    fn enter_ts_expr_with_type_args(&mut self, node: TsExprWithTypeArgs, ast: &mut Ast) {
+        (**self).enter_ts_expr_with_type_args(node, ast)
+    }
+    #[inline]
+    fn leave_ts_expr_with_type_args(&mut self, node: TsExprWithTypeArgs, ast: &mut Ast) {
+        (**self).leave_ts_expr_with_type_args(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl, ast: &mut Ast) {
+        (**self).enter_ts_type_alias_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl, ast: &mut Ast) {
+        (**self).leave_ts_type_alias_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_enum_decl(&mut self, node: TsEnumDecl, ast: &mut Ast) {
+        (**self).enter_ts_enum_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_enum_decl(&mut self, node: TsEnumDecl, ast: &mut Ast) {
+        (**self).leave_ts_enum_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_enum_member(&mut self, node: TsEnumMember, ast: &mut Ast) {
+        (**self).enter_ts_enum_member(node, ast)
+    }
+    #[inline]
+    fn leave_ts_enum_member(&mut self, node: TsEnumMember, ast: &mut Ast) {
+        (**self).leave_ts_enum_member(node, ast)
+    }
+    #[inline]
+    fn enter_ts_module_decl(&mut self, node: TsModuleDecl, ast: &mut Ast) {
+        (**self).enter_ts_module_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_module_decl(&mut self, node: TsModuleDecl, ast: &mut Ast) {
+        (**self).leave_ts_module_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_module_block(&mut self, node: TsModuleBlock, ast: &mut Ast) {
+        (**self).enter_ts_module_block(node, ast)
+    }
+    #[inline]
+    fn leave_ts_module_block(&mut self, node: TsModuleBlock, ast: &mut Ast) {
+        (**self).leave_ts_module_block(node, ast)
+    }
+    #[inline]
+    fn enter_ts_namespace_decl(&mut self, node: TsNamespaceDecl, ast: &mut Ast) {
+        (**self).enter_ts_namespace_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_namespace_decl(&mut self, node: TsNamespaceDecl, ast: &mut Ast) {
+        (**self).leave_ts_namespace_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_import_equals_decl(&mut self, node: TsImportEqualsDecl, ast: &mut Ast) {
+        (**self).enter_ts_import_equals_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_import_equals_decl(&mut self, node: TsImportEqualsDecl, ast: &mut Ast) {
+        (**self).leave_ts_import_equals_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_external_module_ref(&mut self, node: TsExternalModuleRef, ast: &mut Ast) {
+        (**self).enter_ts_external_module_ref(node, ast)
+    }
+    #[inline]
+    fn leave_ts_external_module_ref(&mut self, node: TsExternalModuleRef, ast: &mut Ast) {
+        (**self).leave_ts_external_module_ref(node, ast)
+    }
+    #[inline]
+    fn enter_ts_export_assignment(&mut self, node: TsExportAssignment, ast: &mut Ast) {
+        (**self).enter_ts_export_assignment(node, ast)
+    }
+    #[inline]
+    fn leave_ts_export_assignment(&mut self, node: TsExportAssignment, ast: &mut Ast) {
+        (**self).leave_ts_export_assignment(node, ast)
+    }
+    #[inline]
+    fn enter_ts_namespace_export_decl(&mut self, node: TsNamespaceExportDecl, ast: &mut Ast) {
+        (**self).enter_ts_namespace_export_decl(node, ast)
+    }
+    #[inline]
+    fn leave_ts_namespace_export_decl(&mut self, node: TsNamespaceExportDecl, ast: &mut Ast) {
+        (**self).leave_ts_namespace_export_decl(node, ast)
+    }
+    #[inline]
+    fn enter_ts_as_expr(&mut self, node: TsAsExpr, ast: &mut Ast) {
+        (**self).enter_ts_as_expr(node, ast)
+    }
+    #[inline]
+    fn leave_ts_as_expr(&mut self, node: TsAsExpr, ast: &mut Ast) {
+        (**self).leave_ts_as_expr(node, ast)
+    }
+    #[inline]
+    fn enter_ts_type_assertion(&mut self, node: TsTypeAssertion, ast: &mut Ast) {
+        (**self).enter_ts_type_assertion(node, ast)
+    }
+    #[inline]
+    fn leave_ts_type_assertion(&mut self, node: TsTypeAssertion, ast: &mut Ast) {
+        (**self).leave_ts_type_assertion(node, ast)
+    }
+    #[inline]
+    fn enter_ts_non_null_expr(&mut self, node: TsNonNullExpr, ast: &mut Ast) {
+        (**self).enter_ts_non_null_expr(node, ast)
+    }
+    #[inline]
+    fn leave_ts_non_null_expr(&mut self, node: TsNonNullExpr, ast: &mut Ast) {
+        (**self).leave_ts_non_null_expr(node, ast)
+    }
+    #[inline]
+    fn enter_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr, ast: &mut Ast) {
+        (**self).enter_ts_satisfies_expr(node, ast)
+    }
+    #[inline]
+    fn leave_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr, ast: &mut Ast) {
+        (**self).leave_ts_satisfies_expr(node, ast)
+    }
+    #[inline]
+    fn enter_ts_const_assertion(&mut self, node: TsConstAssertion, ast: &mut Ast) {
+        (**self).enter_ts_const_assertion(node, ast)
+    }
+    #[inline]
+    fn leave_ts_const_assertion(&mut self, node: TsConstAssertion, ast: &mut Ast) {
+        (**self).leave_ts_const_assertion(node, ast)
+    }
+    #[inline]
+    fn enter_ts_instantiation(&mut self, node: TsInstantiation, ast: &mut Ast) {
+        (**self).enter_ts_instantiation(node, ast)
+    }
+    #[inline]
+    fn leave_ts_instantiation(&mut self, node: TsInstantiation, ast: &mut Ast) {
+        (**self).leave_ts_instantiation(node, ast)
+    }
+}
+/// Calls the hooks on entering `node_id`, like [enter_hooks].
+pub(crate) fn enter_mut_hooks<V: ?Sized + VisitMutHooks>(
+    hooks: &mut V,
+    node_id: NodeId,
+    ast: &mut Ast,
+) {
+    hooks.enter_node(node_id, ast);
+    match ast.nodes.kind(node_id) {
+        NodeKind::Module => {
+            let node = unsafe { Module::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_module(node, ast)
+        }
+        NodeKind::Script => {
+            let node = unsafe { Script::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_script(node, ast)
+        }
+        NodeKind::ImportDecl => {
+            let node = unsafe { ImportDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_import_decl(node, ast)
+        }
+        NodeKind::ImportNamedSpecifier => {
+            let node = unsafe { ImportNamedSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_import_named_specifier(node, ast)
+        }
+        NodeKind::ImportDefaultSpecifier => {
+            let node = unsafe { ImportDefaultSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_import_default_specifier(node, ast)
+        }
+        NodeKind::ImportStarAsSpecifier => {
+            let node = unsafe { ImportStarAsSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_import_star_as_specifier(node, ast)
+        }
+        NodeKind::ExportDecl => {
+            let node = unsafe { ExportDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_export_decl(node, ast)
+        }
+        NodeKind::NamedExport => {
+            let node = unsafe { NamedExport::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_named_export(node, ast)
+        }
+        NodeKind::ExportNamespaceSpecifier => {
+            let node = unsafe { ExportNamespaceSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_export_namespace_specifier(node, ast)
+        }
+        NodeKind::ExportDefaultSpecifier => {
+            let node = unsafe { ExportDefaultSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_export_default_specifier(node, ast)
+        }
+        NodeKind::ExportNamedSpecifier => {
+            let node = unsafe { ExportNamedSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_export_named_specifier(node, ast)
+        }
+        NodeKind::ExportDefaultDecl => {
+            let node = unsafe { ExportDefaultDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_export_default_decl(node, ast)
+        }
+        NodeKind::ExportDefaultExpr => {
+            let node = unsafe { ExportDefaultExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_export_default_expr(node, ast)
+        }
+        NodeKind::ExportAll => {
+            let node = unsafe { ExportAll::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_export_all(node, ast)
+        }
+        NodeKind::BlockStmt => {
+            let node = unsafe { BlockStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_block_stmt(node, ast)
+        }
+        NodeKind::ExprStmt => {
+            let node = unsafe { ExprStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_expr_stmt(node, ast)
+        }
+        NodeKind::EmptyStmt => {
+            let node = unsafe { EmptyStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_empty_stmt(node, ast)
+        }
+        NodeKind::DebuggerStmt => {
+            let node = unsafe { DebuggerStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_debugger_stmt(node, ast)
+        }
+        NodeKind::WithStmt => {
+            let node = unsafe { WithStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_with_stmt(node, ast)
+        }
+        NodeKind::ReturnStmt => {
+            let node = unsafe { ReturnStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_return_stmt(node, ast)
+        }
+        NodeKind::LabeledStmt => {
+            let node = unsafe { LabeledStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_labeled_stmt(node, ast)
+        }
+        NodeKind::BreakStmt => {
+            let node = unsafe { BreakStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_break_stmt(node, ast)
+        }
+        NodeKind::ContinueStmt => {
+            let node = unsafe { ContinueStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_continue_stmt(node, ast)
+        }
+        NodeKind::IfStmt => {
+            let node = unsafe { IfStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_if_stmt(node, ast)
+        }
+        NodeKind::SwitchStmt => {
+            let node = unsafe { SwitchStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_switch_stmt(node, ast)
+        }
+        NodeKind::ThrowStmt => {
+            let node = unsafe { ThrowStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_throw_stmt(node, ast)
+        }
+        NodeKind::TryStmt => {
+            let node = unsafe { TryStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_try_stmt(node, ast)
+        }
+        NodeKind::WhileStmt => {
+            let node = unsafe { WhileStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_while_stmt(node, ast)
+        }
+        NodeKind::DoWhileStmt => {
+            let node = unsafe { DoWhileStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_do_while_stmt(node, ast)
+        }
+        NodeKind::ForStmt => {
+            let node = unsafe { ForStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_for_stmt(node, ast)
+        }
+        NodeKind::ForInStmt => {
+            let node = unsafe { ForInStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_for_in_stmt(node, ast)
+        }
+        NodeKind::ForOfStmt => {
+            let node = unsafe { ForOfStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_for_of_stmt(node, ast)
+        }
+        NodeKind::SwitchCase => {
+            let node = unsafe { SwitchCase::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_switch_case(node, ast)
+        }
+        NodeKind::CatchClause => {
+            let node = unsafe { CatchClause::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_catch_clause(node, ast)
+        }
+        NodeKind::FnDecl => {
+            let node = unsafe { FnDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_fn_decl(node, ast)
+        }
+        NodeKind::ClassDecl => {
+            let node = unsafe { ClassDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_class_decl(node, ast)
+        }
+        NodeKind::VarDecl => {
+            let node = unsafe { VarDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_var_decl(node, ast)
+        }
+        NodeKind::VarDeclarator => {
+            let node = unsafe { VarDeclarator::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_var_declarator(node, ast)
+        }
+        NodeKind::UsingDecl => {
+            let node = unsafe { UsingDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_using_decl(node, ast)
+        }
+        NodeKind::ThisExpr => {
+            let node = unsafe { ThisExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_this_expr(node, ast)
+        }
+        NodeKind::ArrayLit => {
+            let node = unsafe { ArrayLit::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_array_lit(node, ast)
+        }
+        NodeKind::ObjectLit => {
+            let node = unsafe { ObjectLit::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_object_lit(node, ast)
+        }
+        NodeKind::SpreadElement => {
+            let node = unsafe { SpreadElement::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_spread_element(node, ast)
+        }
+        NodeKind::UnaryExpr => {
+            let node = unsafe { UnaryExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_unary_expr(node, ast)
+        }
+        NodeKind::UpdateExpr => {
+            let node = unsafe { UpdateExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_update_expr(node, ast)
+        }
+        NodeKind::BinExpr => {
+            let node = unsafe { BinExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_bin_expr(node, ast)
+        }
+        NodeKind::FnExpr => {
+            let node = unsafe { FnExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_fn_expr(node, ast)
+        }
+        NodeKind::ClassExpr => {
+            let node = unsafe { ClassExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_class_expr(node, ast)
+        }
+        NodeKind::AssignExpr => {
+            let node = unsafe { AssignExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_assign_expr(node, ast)
+        }
+        NodeKind::MemberExpr => {
+            let node = unsafe { MemberExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_member_expr(node, ast)
+        }
+        NodeKind::SuperPropExpr => {
+            let node = unsafe { SuperPropExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_super_prop_expr(node, ast)
+        }
+        NodeKind::CondExpr => {
+            let node = unsafe { CondExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_cond_expr(node, ast)
+        }
+        NodeKind::CallExpr => {
+            let node = unsafe { CallExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_call_expr(node, ast)
+        }
+        NodeKind::NewExpr => {
+            let node = unsafe { NewExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_new_expr(node, ast)
+        }
+        NodeKind::SeqExpr => {
+            let node = unsafe { SeqExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_seq_expr(node, ast)
+        }
+        NodeKind::ArrowExpr => {
+            let node = unsafe { ArrowExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_arrow_expr(node, ast)
+        }
+        NodeKind::YieldExpr => {
+            let node = unsafe { YieldExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_yield_expr(node, ast)
+        }
+        NodeKind::MetaPropExpr => {
+            let node = unsafe { MetaPropExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_meta_prop_expr(node, ast)
+        }
+        NodeKind::AwaitExpr => {
+            let node = unsafe { AwaitExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_await_expr(node, ast)
+        }
+        NodeKind::Tpl => {
+            let node = unsafe { Tpl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_tpl(node, ast)
+        }
+        NodeKind::TaggedTpl => {
+            let node = unsafe { TaggedTpl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_tagged_tpl(node, ast)
+        }
+        NodeKind::TplElement => {
+            let node = unsafe { TplElement::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_tpl_element(node, ast)
+        }
+        NodeKind::ParenExpr => {
+            let node = unsafe { ParenExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_paren_expr(node, ast)
+        }
+        NodeKind::Super => {
+            let node = unsafe { Super::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_super(node, ast)
+        }
+        NodeKind::Import => {
+            let node = unsafe { Import::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_import(node, ast)
+        }
+        NodeKind::ExprOrSpread => {
+            let node = unsafe { ExprOrSpread::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_expr_or_spread(node, ast)
+        }
+        NodeKind::SpreadDot3Token => {
+            let node = unsafe { SpreadDot3Token::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_spread_dot_3_token(node, ast)
+        }
+        NodeKind::OptChainExpr => {
+            let node = unsafe { OptChainExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_opt_chain_expr(node, ast)
+        }
+        NodeKind::OptCall => {
+            let node = unsafe { OptCall::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_opt_call(node, ast)
+        }
+        NodeKind::Invalid => {
+            let node = unsafe { Invalid::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_invalid(node, ast)
+        }
+        NodeKind::Function => {
+            let node = unsafe { Function::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_function(node, ast)
+        }
+        NodeKind::Param => {
+            let node = unsafe { Param::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_param(node, ast)
+        }
+        NodeKind::Class => {
+            let node = unsafe { Class::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_class(node, ast)
+        }
+        NodeKind::ClassProp => {
+            let node = unsafe { ClassProp::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_class_prop(node, ast)
+        }
+        NodeKind::PrivateProp => {
+            let node = unsafe { PrivateProp::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_private_prop(node, ast)
+        }
+        NodeKind::ClassMethod => {
+            let node = unsafe { ClassMethod::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_class_method(node, ast)
+        }
+        NodeKind::PrivateMethod => {
+            let node = unsafe { PrivateMethod::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_private_method(node, ast)
+        }
+        NodeKind::Constructor => {
+            let node = unsafe { Constructor::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_constructor(node, ast)
+        }
+        NodeKind::Decorator => {
+            let node = unsafe { Decorator::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_decorator(node, ast)
+        }
+        NodeKind::StaticBlock => {
+            let node = unsafe { StaticBlock::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_static_block(node, ast)
+        }
+        NodeKind::AutoAccessor => {
+            let node = unsafe { AutoAccessor::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_auto_accessor(node, ast)
+        }
+        NodeKind::KeyValueProp => {
+            let node = unsafe { KeyValueProp::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_key_value_prop(node, ast)
+        }
+        NodeKind::AssignProp => {
+            let node = unsafe { AssignProp::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_assign_prop(node, ast)
+        }
+        NodeKind::GetterProp => {
+            let node = unsafe { GetterProp::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_getter_prop(node, ast)
+        }
+        NodeKind::SetterProp => {
+            let node = unsafe { SetterProp::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_setter_prop(node, ast)
+        }
+        NodeKind::MethodProp => {
+            let node = unsafe { MethodProp::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_method_prop(node, ast)
+        }
+        NodeKind::ComputedPropName => {
+            let node = unsafe { ComputedPropName::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_computed_prop_name(node, ast)
+        }
+        NodeKind::ArrayPat => {
+            let node = unsafe { ArrayPat::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_array_pat(node, ast)
+        }
+        NodeKind::ObjectPat => {
+            let node = unsafe { ObjectPat::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_object_pat(node, ast)
+        }
+        NodeKind::AssignPat => {
+            let node = unsafe { AssignPat::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_assign_pat(node, ast)
+        }
+        NodeKind::RestPat => {
+            let node = unsafe { RestPat::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_rest_pat(node, ast)
+        }
+        NodeKind::KeyValuePatProp => {
+            let node = unsafe { KeyValuePatProp::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_key_value_pat_prop(node, ast)
+        }
+        NodeKind::AssignPatProp => {
+            let node = unsafe { AssignPatProp::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_assign_pat_prop(node, ast)
+        }
+        NodeKind::Ident => {
+            let node = unsafe { Ident::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ident(node, ast)
+        }
+        NodeKind::IdentName => {
+            let node = unsafe { IdentName::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ident_name(node, ast)
+        }
+        NodeKind::PrivateName => {
+            let node = unsafe { PrivateName::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_private_name(node, ast)
+        }
+        NodeKind::BindingIdent => {
+            let node = unsafe { BindingIdent::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_binding_ident(node, ast)
+        }
+        NodeKind::Str => {
+            let node = unsafe { Str::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_str(node, ast)
+        }
+        NodeKind::Bool => {
+            let node = unsafe { Bool::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_bool(node, ast)
+        }
+        NodeKind::Null => {
+            let node = unsafe { Null::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_null(node, ast)
+        }
+        NodeKind::Number => {
+            let node = unsafe { Number::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_number(node, ast)
+        }
+        NodeKind::BigInt => {
+            let node = unsafe { BigInt::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_big_int(node, ast)
+        }
+        NodeKind::Regex => {
+            let node = unsafe { Regex::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_regex(node, ast)
+        }
+        NodeKind::JSXMemberExpr => {
+            let node = unsafe { JSXMemberExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_member_expr(node, ast)
+        }
+        NodeKind::JSXNamespacedName => {
+            let node = unsafe { JSXNamespacedName::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_namespaced_name(node, ast)
+        }
+        NodeKind::JSXEmptyExpr => {
+            let node = unsafe { JSXEmptyExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_empty_expr(node, ast)
+        }
+        NodeKind::JSXExprContainer => {
+            let node = unsafe { JSXExprContainer::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_expr_container(node, ast)
+        }
+        NodeKind::JSXSpreadChild => {
+            let node = unsafe { JSXSpreadChild::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_spread_child(node, ast)
+        }
+        NodeKind::JSXOpeningElement => {
+            let node = unsafe { JSXOpeningElement::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_opening_element(node, ast)
+        }
+        NodeKind::JSXClosingElement => {
+            let node = unsafe { JSXClosingElement::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_closing_element(node, ast)
+        }
+        NodeKind::JSXAttr => {
+            let node = unsafe { JSXAttr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_attr(node, ast)
+        }
+        NodeKind::JSXText => {
+            let node = unsafe { JSXText::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_text(node, ast)
+        }
+        NodeKind::JSXElement => {
+            let node = unsafe { JSXElement::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_element(node, ast)
+        }
+        NodeKind::JSXFragment => {
+            let node = unsafe { JSXFragment::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_fragment(node, ast)
+        }
+        NodeKind::JSXOpeningFragment => {
+            let node = unsafe { JSXOpeningFragment::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_opening_fragment(node, ast)
+        }
+        NodeKind::JSXClosingFragment => {
+            let node = unsafe { JSXClosingFragment::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_jsx_closing_fragment(node, ast)
+        }
+        NodeKind::TsTypeAnn => {
+            let node = unsafe { TsTypeAnn::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_ann(node, ast)
+        }
+        NodeKind::TsTypeParamDecl => {
+            let node = unsafe { TsTypeParamDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_param_decl(node, ast)
+        }
+        NodeKind::TsTypeParam => {
+            let node = unsafe { TsTypeParam::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_param(node, ast)
+        }
+        NodeKind::TsTypeParamInstantiation => {
+            let node = unsafe { TsTypeParamInstantiation::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_param_instantiation(node, ast)
+        }
+        NodeKind::TsParamProp => {
+            let node = unsafe { TsParamProp::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_param_prop(node, ast)
+        }
+        NodeKind::TsQualifiedName => {
+            let node = unsafe { TsQualifiedName::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_qualified_name(node, ast)
+        }
+        NodeKind::TsCallSignatureDecl => {
+            let node = unsafe { TsCallSignatureDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_call_signature_decl(node, ast)
+        }
+        NodeKind::TsConstructSignatureDecl => {
+            let node = unsafe { TsConstructSignatureDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_construct_signature_decl(node, ast)
+        }
+        NodeKind::TsPropertySignature => {
+            let node = unsafe { TsPropertySignature::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_property_signature(node, ast)
+        }
+        NodeKind::TsGetterSignature => {
+            let node = unsafe { TsGetterSignature::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_getter_signature(node, ast)
+        }
+        NodeKind::TsSetterSignature => {
+            let node = unsafe { TsSetterSignature::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_setter_signature(node, ast)
+        }
+        NodeKind::TsMethodSignature => {
+            let node = unsafe { TsMethodSignature::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_method_signature(node, ast)
+        }
+        NodeKind::TsIndexSignature => {
+            let node = unsafe { TsIndexSignature::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_index_signature(node, ast)
+        }
+        NodeKind::TsKeywordType => {
+            let node = unsafe { TsKeywordType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_keyword_type(node, ast)
+        }
+        NodeKind::TsThisType => {
+            let node = unsafe { TsThisType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_this_type(node, ast)
+        }
+        NodeKind::TsFnType => {
+            let node = unsafe { TsFnType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_fn_type(node, ast)
+        }
+        NodeKind::TsConstructorType => {
+            let node = unsafe { TsConstructorType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_constructor_type(node, ast)
+        }
+        NodeKind::TsTypeRef => {
+            let node = unsafe { TsTypeRef::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_ref(node, ast)
+        }
+        NodeKind::TsTypePredicate => {
+            let node = unsafe { TsTypePredicate::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_predicate(node, ast)
+        }
+        NodeKind::TsTypeQuery => {
+            let node = unsafe { TsTypeQuery::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_query(node, ast)
+        }
+        NodeKind::TsImportCallOptions => {
+            let node = unsafe { TsImportCallOptions::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_import_call_options(node, ast)
+        }
+        NodeKind::TsImportType => {
+            let node = unsafe { TsImportType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_import_type(node, ast)
+        }
+        NodeKind::TsTypeLit => {
+            let node = unsafe { TsTypeLit::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_lit(node, ast)
+        }
+        NodeKind::TsArrayType => {
+            let node = unsafe { TsArrayType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_array_type(node, ast)
+        }
+        NodeKind::TsTupleType => {
+            let node = unsafe { TsTupleType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_tuple_type(node, ast)
+        }
+        NodeKind::TsTupleElement => {
+            let node = unsafe { TsTupleElement::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_tuple_element(node, ast)
+        }
+        NodeKind::TsOptionalType => {
+            let node = unsafe { TsOptionalType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_optional_type(node, ast)
+        }
+        NodeKind::TsRestType => {
+            let node = unsafe { TsRestType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_rest_type(node, ast)
+        }
+        NodeKind::TsUnionType => {
+            let node = unsafe { TsUnionType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_union_type(node, ast)
+        }
+        NodeKind::TsIntersectionType => {
+            let node = unsafe { TsIntersectionType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_intersection_type(node, ast)
+        }
+        NodeKind::TsConditionalType => {
+            let node = unsafe { TsConditionalType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_conditional_type(node, ast)
+        }
+        NodeKind::TsInferType => {
+            let node = unsafe { TsInferType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_infer_type(node, ast)
+        }
+        NodeKind::TsParenthesizedType => {
+            let node = unsafe { TsParenthesizedType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_parenthesized_type(node, ast)
+        }
+        NodeKind::TsTypeOperator => {
+            let node = unsafe { TsTypeOperator::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_operator(node, ast)
+        }
+        NodeKind::TsIndexedAccessType => {
+            let node = unsafe { TsIndexedAccessType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_indexed_access_type(node, ast)
+        }
+        NodeKind::TsMappedType => {
+            let node = unsafe { TsMappedType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_mapped_type(node, ast)
+        }
+        NodeKind::TsLitType => {
+            let node = unsafe { TsLitType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_lit_type(node, ast)
+        }
+        NodeKind::TsTplLitType => {
+            let node = unsafe { TsTplLitType::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_tpl_lit_type(node, ast)
+        }
+        NodeKind::TsInterfaceDecl => {
+            let node = unsafe { TsInterfaceDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_interface_decl(node, ast)
+        }
+        NodeKind::TsInterfaceBody => {
+            let node = unsafe { TsInterfaceBody::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_interface_body(node, ast)
+        }
+        NodeKind::TsExprWithTypeArgs => {
+            let node = unsafe { TsExprWithTypeArgs::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_expr_with_type_args(node, ast)
+        }
+        NodeKind::TsTypeAliasDecl => {
+            let node = unsafe { TsTypeAliasDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_alias_decl(node, ast)
+        }
+        NodeKind::TsEnumDecl => {
+            let node = unsafe { TsEnumDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_enum_decl(node, ast)
+        }
+        NodeKind::TsEnumMember => {
+            let node = unsafe { TsEnumMember::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_enum_member(node, ast)
+        }
+        NodeKind::TsModuleDecl => {
+            let node = unsafe { TsModuleDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_module_decl(node, ast)
+        }
+        NodeKind::TsModuleBlock => {
+            let node = unsafe { TsModuleBlock::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_module_block(node, ast)
+        }
+        NodeKind::TsNamespaceDecl => {
+            let node = unsafe { TsNamespaceDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_namespace_decl(node, ast)
+        }
+        NodeKind::TsImportEqualsDecl => {
+            let node = unsafe { TsImportEqualsDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_import_equals_decl(node, ast)
+        }
+        NodeKind::TsExternalModuleRef => {
+            let node = unsafe { TsExternalModuleRef::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_external_module_ref(node, ast)
+        }
+        NodeKind::TsExportAssignment => {
+            let node = unsafe { TsExportAssignment::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_export_assignment(node, ast)
+        }
+        NodeKind::TsNamespaceExportDecl => {
+            let node = unsafe { TsNamespaceExportDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_namespace_export_decl(node, ast)
+        }
+        NodeKind::TsAsExpr => {
+            let node = unsafe { TsAsExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_as_expr(node, ast)
+        }
+        NodeKind::TsTypeAssertion => {
+            let node = unsafe { TsTypeAssertion::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_type_assertion(node, ast)
+        }
+        NodeKind::TsNonNullExpr => {
+            let node = unsafe { TsNonNullExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_non_null_expr(node, ast)
+        }
+        NodeKind::TsSatisfiesExpr => {
+            let node = unsafe { TsSatisfiesExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_satisfies_expr(node, ast)
+        }
+        NodeKind::TsConstAssertion => {
+            let node = unsafe { TsConstAssertion::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_const_assertion(node, ast)
+        }
+        NodeKind::TsInstantiation => {
+            let node = unsafe { TsInstantiation::from_node_id_unchecked(node_id, ast) };
+            hooks.enter_ts_instantiation(node, ast)
+        }
+        _ => {}
+    }
+}
+/// Calls the hooks on leaving `node_id`, like [leave_hooks].
+pub(crate) fn leave_mut_hooks<V: ?Sized + VisitMutHooks>(
+    hooks: &mut V,
+    node_id: NodeId,
+    ast: &mut Ast,
+) {
+    match ast.nodes.kind(node_id) {
+        NodeKind::Module => {
+            let node = unsafe { Module::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_module(node, ast)
+        }
+        NodeKind::Script => {
+            let node = unsafe { Script::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_script(node, ast)
+        }
+        NodeKind::ImportDecl => {
+            let node = unsafe { ImportDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_import_decl(node, ast)
+        }
+        NodeKind::ImportNamedSpecifier => {
+            let node = unsafe { ImportNamedSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_import_named_specifier(node, ast)
+        }
+        NodeKind::ImportDefaultSpecifier => {
+            let node = unsafe { ImportDefaultSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_import_default_specifier(node, ast)
+        }
+        NodeKind::ImportStarAsSpecifier => {
+            let node = unsafe { ImportStarAsSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_import_star_as_specifier(node, ast)
+        }
+        NodeKind::ExportDecl => {
+            let node = unsafe { ExportDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_export_decl(node, ast)
+        }
+        NodeKind::NamedExport => {
+            let node = unsafe { NamedExport::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_named_export(node, ast)
+        }
+        NodeKind::ExportNamespaceSpecifier => {
+            let node = unsafe { ExportNamespaceSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_export_namespace_specifier(node, ast)
+        }
+        NodeKind::ExportDefaultSpecifier => {
+            let node = unsafe { ExportDefaultSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_export_default_specifier(node, ast)
+        }
+        NodeKind::ExportNamedSpecifier => {
+            let node = unsafe { ExportNamedSpecifier::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_export_named_specifier(node, ast)
+        }
+        NodeKind::ExportDefaultDecl => {
+            let node = unsafe { ExportDefaultDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_export_default_decl(node, ast)
+        }
+        NodeKind::ExportDefaultExpr => {
+            let node = unsafe { ExportDefaultExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_export_default_expr(node, ast)
+        }
+        NodeKind::ExportAll => {
+            let node = unsafe { ExportAll::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_export_all(node, ast)
+        }
+        NodeKind::BlockStmt => {
+            let node = unsafe { BlockStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_block_stmt(node, ast)
+        }
+        NodeKind::ExprStmt => {
+            let node = unsafe { ExprStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_expr_stmt(node, ast)
+        }
+        NodeKind::EmptyStmt => {
+            let node = unsafe { EmptyStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_empty_stmt(node, ast)
+        }
+        NodeKind::DebuggerStmt => {
+            let node = unsafe { DebuggerStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_debugger_stmt(node, ast)
+        }
+        NodeKind::WithStmt => {
+            let node = unsafe { WithStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_with_stmt(node, ast)
+        }
+        NodeKind::ReturnStmt => {
+            let node = unsafe { ReturnStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_return_stmt(node, ast)
+        }
+        NodeKind::LabeledStmt => {
+            let node = unsafe { LabeledStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_labeled_stmt(node, ast)
+        }
+        NodeKind::BreakStmt => {
+            let node = unsafe { BreakStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_break_stmt(node, ast)
+        }
+        NodeKind::ContinueStmt => {
+            let node = unsafe { ContinueStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_continue_stmt(node, ast)
+        }
+        NodeKind::IfStmt => {
+            let node = unsafe { IfStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_if_stmt(node, ast)
+        }
+        NodeKind::SwitchStmt => {
+            let node = unsafe { SwitchStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_switch_stmt(node, ast)
+        }
+        NodeKind::ThrowStmt => {
+            let node = unsafe { ThrowStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_throw_stmt(node, ast)
+        }
+        NodeKind::TryStmt => {
+            let node = unsafe { TryStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_try_stmt(node, ast)
+        }
+        NodeKind::WhileStmt => {
+            let node = unsafe { WhileStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_while_stmt(node, ast)
+        }
+        NodeKind::DoWhileStmt => {
+            let node = unsafe { DoWhileStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_do_while_stmt(node, ast)
+        }
+        NodeKind::ForStmt => {
+            let node = unsafe { ForStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_for_stmt(node, ast)
+        }
+        NodeKind::ForInStmt => {
+            let node = unsafe { ForInStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_for_in_stmt(node, ast)
+        }
+        NodeKind::ForOfStmt => {
+            let node = unsafe { ForOfStmt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_for_of_stmt(node, ast)
+        }
+        NodeKind::SwitchCase => {
+            let node = unsafe { SwitchCase::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_switch_case(node, ast)
+        }
+        NodeKind::CatchClause => {
+            let node = unsafe { CatchClause::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_catch_clause(node, ast)
+        }
+        NodeKind::FnDecl => {
+            let node = unsafe { FnDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_fn_decl(node, ast)
+        }
+        NodeKind::ClassDecl => {
+            let node = unsafe { ClassDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_class_decl(node, ast)
+        }
+        NodeKind::VarDecl => {
+            let node = unsafe { VarDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_var_decl(node, ast)
+        }
+        NodeKind::VarDeclarator => {
+            let node = unsafe { VarDeclarator::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_var_declarator(node, ast)
+        }
+        NodeKind::UsingDecl => {
+            let node = unsafe { UsingDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_using_decl(node, ast)
+        }
+        NodeKind::ThisExpr => {
+            let node = unsafe { ThisExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_this_expr(node, ast)
+        }
+        NodeKind::ArrayLit => {
+            let node = unsafe { ArrayLit::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_array_lit(node, ast)
+        }
+        NodeKind::ObjectLit => {
+            let node = unsafe { ObjectLit::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_object_lit(node, ast)
+        }
+        NodeKind::SpreadElement => {
+            let node = unsafe { SpreadElement::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_spread_element(node, ast)
+        }
+        NodeKind::UnaryExpr => {
+            let node = unsafe { UnaryExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_unary_expr(node, ast)
+        }
+        NodeKind::UpdateExpr => {
+            let node = unsafe { UpdateExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_update_expr(node, ast)
+        }
+        NodeKind::BinExpr => {
+            let node = unsafe { BinExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_bin_expr(node, ast)
+        }
+        NodeKind::FnExpr => {
+            let node = unsafe { FnExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_fn_expr(node, ast)
+        }
+        NodeKind::ClassExpr => {
+            let node = unsafe { ClassExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_class_expr(node, ast)
+        }
+        NodeKind::AssignExpr => {
+            let node = unsafe { AssignExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_assign_expr(node, ast)
+        }
+        NodeKind::MemberExpr => {
+            let node = unsafe { MemberExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_member_expr(node, ast)
+        }
+        NodeKind::SuperPropExpr => {
+            let node = unsafe { SuperPropExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_super_prop_expr(node, ast)
+        }
+        NodeKind::CondExpr => {
+            let node = unsafe { CondExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_cond_expr(node, ast)
+        }
+        NodeKind::CallExpr => {
+            let node = unsafe { CallExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_call_expr(node, ast)
+        }
+        NodeKind::NewExpr => {
+            let node = unsafe { NewExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_new_expr(node, ast)
+        }
+        NodeKind::SeqExpr => {
+            let node = unsafe { SeqExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_seq_expr(node, ast)
+        }
+        NodeKind::ArrowExpr => {
+            let node = unsafe { ArrowExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_arrow_expr(node, ast)
+        }
+        NodeKind::YieldExpr => {
+            let node = unsafe { YieldExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_yield_expr(node, ast)
+        }
+        NodeKind::MetaPropExpr => {
+            let node = unsafe { MetaPropExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_meta_prop_expr(node, ast)
+        }
+        NodeKind::AwaitExpr => {
+            let node = unsafe { AwaitExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_await_expr(node, ast)
+        }
+        NodeKind::Tpl => {
+            let node = unsafe { Tpl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_tpl(node, ast)
+        }
+        NodeKind::TaggedTpl => {
+            let node = unsafe { TaggedTpl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_tagged_tpl(node, ast)
+        }
+        NodeKind::TplElement => {
+            let node = unsafe { TplElement::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_tpl_element(node, ast)
+        }
+        NodeKind::ParenExpr => {
+            let node = unsafe { ParenExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_paren_expr(node, ast)
+        }
+        NodeKind::Super => {
+            let node = unsafe { Super::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_super(node, ast)
+        }
+        NodeKind::Import => {
+            let node = unsafe { Import::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_import(node, ast)
+        }
+        NodeKind::ExprOrSpread => {
+            let node = unsafe { ExprOrSpread::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_expr_or_spread(node, ast)
+        }
+        NodeKind::SpreadDot3Token => {
+            let node = unsafe { SpreadDot3Token::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_spread_dot_3_token(node, ast)
+        }
+        NodeKind::OptChainExpr => {
+            let node = unsafe { OptChainExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_opt_chain_expr(node, ast)
+        }
+        NodeKind::OptCall => {
+            let node = unsafe { OptCall::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_opt_call(node, ast)
+        }
+        NodeKind::Invalid => {
+            let node = unsafe { Invalid::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_invalid(node, ast)
+        }
+        NodeKind::Function => {
+            let node = unsafe { Function::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_function(node, ast)
+        }
+        NodeKind::Param => {
+            let node = unsafe { Param::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_param(node, ast)
+        }
+        NodeKind::Class => {
+            let node = unsafe { Class::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_class(node, ast)
+        }
+        NodeKind::ClassProp => {
+            let node = unsafe { ClassProp::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_class_prop(node, ast)
+        }
+        NodeKind::PrivateProp => {
+            let node = unsafe { PrivateProp::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_private_prop(node, ast)
+        }
+        NodeKind::ClassMethod => {
+            let node = unsafe { ClassMethod::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_class_method(node, ast)
+        }
+        NodeKind::PrivateMethod => {
+            let node = unsafe { PrivateMethod::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_private_method(node, ast)
+        }
+        NodeKind::Constructor => {
+            let node = unsafe { Constructor::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_constructor(node, ast)
+        }
+        NodeKind::Decorator => {
+            let node = unsafe { Decorator::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_decorator(node, ast)
+        }
+        NodeKind::StaticBlock => {
+            let node = unsafe { StaticBlock::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_static_block(node, ast)
+        }
+        NodeKind::AutoAccessor => {
+            let node = unsafe { AutoAccessor::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_auto_accessor(node, ast)
+        }
+        NodeKind::KeyValueProp => {
+            let node = unsafe { KeyValueProp::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_key_value_prop(node, ast)
+        }
+        NodeKind::AssignProp => {
+            let node = unsafe { AssignProp::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_assign_prop(node, ast)
+        }
+        NodeKind::GetterProp => {
+            let node = unsafe { GetterProp::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_getter_prop(node, ast)
+        }
+        NodeKind::SetterProp => {
+            let node = unsafe { SetterProp::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_setter_prop(node, ast)
+        }
+        NodeKind::MethodProp => {
+            let node = unsafe { MethodProp::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_method_prop(node, ast)
+        }
+        NodeKind::ComputedPropName => {
+            let node = unsafe { ComputedPropName::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_computed_prop_name(node, ast)
+        }
+        NodeKind::ArrayPat => {
+            let node = unsafe { ArrayPat::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_array_pat(node, ast)
+        }
+        NodeKind::ObjectPat => {
+            let node = unsafe { ObjectPat::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_object_pat(node, ast)
+        }
+        NodeKind::AssignPat => {
+            let node = unsafe { AssignPat::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_assign_pat(node, ast)
+        }
+        NodeKind::RestPat => {
+            let node = unsafe { RestPat::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_rest_pat(node, ast)
+        }
+        NodeKind::KeyValuePatProp => {
+            let node = unsafe { KeyValuePatProp::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_key_value_pat_prop(node, ast)
+        }
+        NodeKind::AssignPatProp => {
+            let node = unsafe { AssignPatProp::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_assign_pat_prop(node, ast)
+        }
+        NodeKind::Ident => {
+            let node = unsafe { Ident::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ident(node, ast)
+        }
+        NodeKind::IdentName => {
+            let node = unsafe { IdentName::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ident_name(node, ast)
+        }
+        NodeKind::PrivateName => {
+            let node = unsafe { PrivateName::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_private_name(node, ast)
+        }
+        NodeKind::BindingIdent => {
+            let node = unsafe { BindingIdent::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_binding_ident(node, ast)
+        }
+        NodeKind::Str => {
+            let node = unsafe { Str::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_str(node, ast)
+        }
+        NodeKind::Bool => {
+            let node = unsafe { Bool::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_bool(node, ast)
+        }
+        NodeKind::Null => {
+            let node = unsafe { Null::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_null(node, ast)
+        }
+        NodeKind::Number => {
+            let node = unsafe { Number::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_number(node, ast)
+        }
+        NodeKind::BigInt => {
+            let node = unsafe { BigInt::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_big_int(node, ast)
+        }
+        NodeKind::Regex => {
+            let node = unsafe { Regex::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_regex(node, ast)
+        }
+        NodeKind::JSXMemberExpr => {
+            let node = unsafe { JSXMemberExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_member_expr(node, ast)
+        }
+        NodeKind::JSXNamespacedName => {
+            let node = unsafe { JSXNamespacedName::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_namespaced_name(node, ast)
+        }
+        NodeKind::JSXEmptyExpr => {
+            let node = unsafe { JSXEmptyExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_empty_expr(node, ast)
+        }
+        NodeKind::JSXExprContainer => {
+            let node = unsafe { JSXExprContainer::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_expr_container(node, ast)
+        }
+        NodeKind::JSXSpreadChild => {
+            let node = unsafe { JSXSpreadChild::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_spread_child(node, ast)
+        }
+        NodeKind::JSXOpeningElement => {
+            let node = unsafe { JSXOpeningElement::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_opening_element(node, ast)
+        }
+        NodeKind::JSXClosingElement => {
+            let node = unsafe { JSXClosingElement::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_closing_element(node, ast)
+        }
+        NodeKind::JSXAttr => {
+            let node = unsafe { JSXAttr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_attr(node, ast)
+        }
+        NodeKind::JSXText => {
+            let node = unsafe { JSXText::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_text(node, ast)
+        }
+        NodeKind::JSXElement => {
+            let node = unsafe { JSXElement::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_element(node, ast)
+        }
+        NodeKind::JSXFragment => {
+            let node = unsafe { JSXFragment::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_fragment(node, ast)
+        }
+        NodeKind::JSXOpeningFragment => {
+            let node = unsafe { JSXOpeningFragment::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_opening_fragment(node, ast)
+        }
+        NodeKind::JSXClosingFragment => {
+            let node = unsafe { JSXClosingFragment::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_jsx_closing_fragment(node, ast)
+        }
+        NodeKind::TsTypeAnn => {
+            let node = unsafe { TsTypeAnn::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_ann(node, ast)
+        }
+        NodeKind::TsTypeParamDecl => {
+            let node = unsafe { TsTypeParamDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_param_decl(node, ast)
+        }
+        NodeKind::TsTypeParam => {
+            let node = unsafe { TsTypeParam::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_param(node, ast)
+        }
+        NodeKind::TsTypeParamInstantiation => {
+            let node = unsafe { TsTypeParamInstantiation::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_param_instantiation(node, ast)
+        }
+        NodeKind::TsParamProp => {
+            let node = unsafe { TsParamProp::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_param_prop(node, ast)
+        }
+        NodeKind::TsQualifiedName => {
+            let node = unsafe { TsQualifiedName::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_qualified_name(node, ast)
+        }
+        NodeKind::TsCallSignatureDecl => {
+            let node = unsafe { TsCallSignatureDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_call_signature_decl(node, ast)
+        }
+        NodeKind::TsConstructSignatureDecl => {
+            let node = unsafe { TsConstructSignatureDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_construct_signature_decl(node, ast)
+        }
+        NodeKind::TsPropertySignature => {
+            let node = unsafe { TsPropertySignature::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_property_signature(node, ast)
+        }
+        NodeKind::TsGetterSignature => {
+            let node = unsafe { TsGetterSignature::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_getter_signature(node, ast)
+        }
+        NodeKind::TsSetterSignature => {
+            let node = unsafe { TsSetterSignature::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_setter_signature(node, ast)
+        }
+        NodeKind::TsMethodSignature => {
+            let node = unsafe { TsMethodSignature::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_method_signature(node, ast)
+        }
+        NodeKind::TsIndexSignature => {
+            let node = unsafe { TsIndexSignature::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_index_signature(node, ast)
+        }
+        NodeKind::TsKeywordType => {
+            let node = unsafe { TsKeywordType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_keyword_type(node, ast)
+        }
+        NodeKind::TsThisType => {
+            let node = unsafe { TsThisType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_this_type(node, ast)
+        }
+        NodeKind::TsFnType => {
+            let node = unsafe { TsFnType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_fn_type(node, ast)
+        }
+        NodeKind::TsConstructorType => {
+            let node = unsafe { TsConstructorType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_constructor_type(node, ast)
+        }
+        NodeKind::TsTypeRef => {
+            let node = unsafe { TsTypeRef::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_ref(node, ast)
+        }
+        NodeKind::TsTypePredicate => {
+            let node = unsafe { TsTypePredicate::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_predicate(node, ast)
+        }
+        NodeKind::TsTypeQuery => {
+            let node = unsafe { TsTypeQuery::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_query(node, ast)
+        }
+        NodeKind::TsImportCallOptions => {
+            let node = unsafe { TsImportCallOptions::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_import_call_options(node, ast)
+        }
+        NodeKind::TsImportType => {
+            let node = unsafe { TsImportType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_import_type(node, ast)
+        }
+        NodeKind::TsTypeLit => {
+            let node = unsafe { TsTypeLit::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_lit(node, ast)
+        }
+        NodeKind::TsArrayType => {
+            let node = unsafe { TsArrayType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_array_type(node, ast)
+        }
+        NodeKind::TsTupleType => {
+            let node = unsafe { TsTupleType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_tuple_type(node, ast)
+        }
+        NodeKind::TsTupleElement => {
+            let node = unsafe { TsTupleElement::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_tuple_element(node, ast)
+        }
+        NodeKind::TsOptionalType => {
+            let node = unsafe { TsOptionalType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_optional_type(node, ast)
+        }
+        NodeKind::TsRestType => {
+            let node = unsafe { TsRestType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_rest_type(node, ast)
+        }
+        NodeKind::TsUnionType => {
+            let node = unsafe { TsUnionType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_union_type(node, ast)
+        }
+        NodeKind::TsIntersectionType => {
+            let node = unsafe { TsIntersectionType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_intersection_type(node, ast)
+        }
+        NodeKind::TsConditionalType => {
+            let node = unsafe { TsConditionalType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_conditional_type(node, ast)
+        }
+        NodeKind::TsInferType => {
+            let node = unsafe { TsInferType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_infer_type(node, ast)
+        }
+        NodeKind::TsParenthesizedType => {
+            let node = unsafe { TsParenthesizedType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_parenthesized_type(node, ast)
+        }
+        NodeKind::TsTypeOperator => {
+            let node = unsafe { TsTypeOperator::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_operator(node, ast)
+        }
+        NodeKind::TsIndexedAccessType => {
+            let node = unsafe { TsIndexedAccessType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_indexed_access_type(node, ast)
+        }
+        NodeKind::TsMappedType => {
+            let node = unsafe { TsMappedType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_mapped_type(node, ast)
+        }
+        NodeKind::TsLitType => {
+            let node = unsafe { TsLitType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_lit_type(node, ast)
+        }
+        NodeKind::TsTplLitType => {
+            let node = unsafe { TsTplLitType::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_tpl_lit_type(node, ast)
+        }
+        NodeKind::TsInterfaceDecl => {
+            let node = unsafe { TsInterfaceDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_interface_decl(node, ast)
+        }
+        NodeKind::TsInterfaceBody => {
+            let node = unsafe { TsInterfaceBody::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_interface_body(node, ast)
+        }
+        NodeKind::TsExprWithTypeArgs => {
+            let node = unsafe { TsExprWithTypeArgs::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_expr_with_type_args(node, ast)
+        }
+        NodeKind::TsTypeAliasDecl => {
+            let node = unsafe { TsTypeAliasDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_alias_decl(node, ast)
+        }
+        NodeKind::TsEnumDecl => {
+            let node = unsafe { TsEnumDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_enum_decl(node, ast)
+        }
+        NodeKind::TsEnumMember => {
+            let node = unsafe { TsEnumMember::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_enum_member(node, ast)
+        }
+        NodeKind::TsModuleDecl => {
+            let node = unsafe { TsModuleDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_module_decl(node, ast)
+        }
+        NodeKind::TsModuleBlock => {
+            let node = unsafe { TsModuleBlock::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_module_block(node, ast)
+        }
+        NodeKind::TsNamespaceDecl => {
+            let node = unsafe { TsNamespaceDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_namespace_decl(node, ast)
+        }
+        NodeKind::TsImportEqualsDecl => {
+            let node = unsafe { TsImportEqualsDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_import_equals_decl(node, ast)
+        }
+        NodeKind::TsExternalModuleRef => {
+            let node = unsafe { TsExternalModuleRef::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_external_module_ref(node, ast)
+        }
+        NodeKind::TsExportAssignment => {
+            let node = unsafe { TsExportAssignment::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_export_assignment(node, ast)
+        }
+        NodeKind::TsNamespaceExportDecl => {
+            let node = unsafe { TsNamespaceExportDecl::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_namespace_export_decl(node, ast)
+        }
+        NodeKind::TsAsExpr => {
+            let node = unsafe { TsAsExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_as_expr(node, ast)
+        }
+        NodeKind::TsTypeAssertion => {
+            let node = unsafe { TsTypeAssertion::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_type_assertion(node, ast)
+        }
+        NodeKind::TsNonNullExpr => {
+            let node = unsafe { TsNonNullExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_non_null_expr(node, ast)
+        }
+        NodeKind::TsSatisfiesExpr => {
+            let node = unsafe { TsSatisfiesExpr::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_satisfies_expr(node, ast)
+        }
+        NodeKind::TsConstAssertion => {
+            let node = unsafe { TsConstAssertion::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_const_assertion(node, ast)
+        }
+        NodeKind::TsInstantiation => {
+            let node = unsafe { TsInstantiation::from_node_id_unchecked(node_id, ast) };
+            hooks.leave_ts_instantiation(node, ast)
+        }
+        _ => {}
+    }
+    hooks.leave_node(node_id, ast);
+}
@@ -0,0 +1,6456 @@
+#![allow(unused, clippy::useless_conversion, clippy::single_match)]
+use crate::*;
+use swc_core::common::Span;
+pub trait VisitWithPath {
+    fn ast(&self) -> &Ast;
+    #[inline]
+    fn enter_node(&mut self, node_id: NodeId, path: &AstNodePath) {}
+    #[inline]
+    fn leave_node(&mut self, node_id: NodeId, path: &AstNodePath) {}
+    #[inline]
+    fn visit_program(&mut self, node: Program, path: &mut AstNodePath) {
+        <Program as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_module(&mut self, node: Module, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Module as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_script(&mut self, node: Script, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Script as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_module_item(&mut self, node: ModuleItem, path: &mut AstNodePath) {
+        <ModuleItem as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_module_decl(&mut self, node: ModuleDecl, path: &mut AstNodePath) {
+        <ModuleDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_import_decl(&mut self, node: ImportDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ImportDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_import_specifier(&mut self, node: ImportSpecifier, path: &mut AstNodePath) {
+        <ImportSpecifier as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_import_named_specifier(&mut self, node: ImportNamedSpecifier, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ImportNamedSpecifier as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_import_default_specifier(
+        &mut self,
+        node: ImportDefaultSpecifier,
+        path: &mut AstNodePath,
+    ) {
+        self.enter_node(node.node_id(), path);
+        <ImportDefaultSpecifier as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_import_star_as_specifier(
+        &mut self,
+        node: ImportStarAsSpecifier,
+        path: &mut AstNodePath,
+    ) {
+        self.enter_node(node.node_id(), path);
+        <ImportStarAsSpecifier as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_export_decl(&mut self, node: ExportDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ExportDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_named_export(&mut self, node: NamedExport, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <NamedExport as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_export_specifier(&mut self, node: ExportSpecifier, path: &mut AstNodePath) {
+        <ExportSpecifier as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_export_namespace_specifier(
+        &mut self,
+        node: ExportNamespaceSpecifier,
+        path: &mut AstNodePath,
+    ) {
+        self.enter_node(node.node_id(), path);
+        <ExportNamespaceSpecifier as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_module_export_name(&mut self, node: ModuleExportName, path: &mut AstNodePath) {
+        <ModuleExportName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_export_default_specifier(
+        &mut self,
+        node: ExportDefaultSpecifier,
+        path: &mut AstNodePath,
+    ) {
+        self.enter_node(node.node_id(), path);
+        <ExportDefaultSpecifier as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_export_named_specifier(&mut self, node: ExportNamedSpecifier, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ExportNamedSpecifier as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_export_default_decl(&mut self, node: ExportDefaultDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ExportDefaultDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_default_decl(&mut self, node: DefaultDecl, path: &mut AstNodePath) {
+        <DefaultDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_export_default_expr(&mut self, node: ExportDefaultExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ExportDefaultExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_export_all(&mut self, node: ExportAll, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ExportAll as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_block_stmt(&mut self, node: BlockStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <BlockStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_stmt(&mut self, node: Stmt, path: &mut AstNodePath) {
+        <Stmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_expr_stmt(&mut self, node: ExprStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ExprStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_empty_stmt(&mut self, node: EmptyStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <EmptyStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_debugger_stmt(&mut self, node: DebuggerStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <DebuggerStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_with_stmt(&mut self, node: WithStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <WithStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_return_stmt(&mut self, node: ReturnStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ReturnStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_labeled_stmt(&mut self, node: LabeledStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <LabeledStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_break_stmt(&mut self, node: BreakStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <BreakStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_continue_stmt(&mut self, node: ContinueStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ContinueStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_if_stmt(&mut self, node: IfStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <IfStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_switch_stmt(&mut self, node: SwitchStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <SwitchStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_throw_stmt(&mut self, node: ThrowStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ThrowStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_try_stmt(&mut self, node: TryStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TryStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_while_stmt(&mut self, node: WhileStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <WhileStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_do_while_stmt(&mut self, node: DoWhileStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <DoWhileStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_for_stmt(&mut self, node: ForStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ForStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_for_in_stmt(&mut self, node: ForInStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ForInStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_for_of_stmt(&mut self, node: ForOfStmt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ForOfStmt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_switch_case(&mut self, node: SwitchCase, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <SwitchCase as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_catch_clause(&mut self, node: CatchClause, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <CatchClause as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_for_head(&mut self, node: ForHead, path: &mut AstNodePath) {
+        <ForHead as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_var_decl_or_expr(&mut self, node: VarDeclOrExpr, path: &mut AstNodePath) {
+        <VarDeclOrExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_decl(&mut self, node: Decl, path: &mut AstNodePath) {
+        <Decl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_fn_decl(&mut self, node: FnDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <FnDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_class_decl(&mut self, node: ClassDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ClassDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_var_decl(&mut self, node: VarDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <VarDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_var_declarator(&mut self, node: VarDeclarator, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <VarDeclarator as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_using_decl(&mut self, node: UsingDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <UsingDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_expr(&mut self, node: Expr, path: &mut AstNodePath) {
+        <Expr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_this_expr(&mut self, node: ThisExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ThisExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_array_lit(&mut self, node: ArrayLit, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ArrayLit as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_object_lit(&mut self, node: ObjectLit, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ObjectLit as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_prop_or_spread(&mut self, node: PropOrSpread, path: &mut AstNodePath) {
+        <PropOrSpread as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_spread_element(&mut self, node: SpreadElement, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <SpreadElement as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_unary_expr(&mut self, node: UnaryExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <UnaryExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_update_expr(&mut self, node: UpdateExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <UpdateExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_bin_expr(&mut self, node: BinExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <BinExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_fn_expr(&mut self, node: FnExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <FnExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_class_expr(&mut self, node: ClassExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ClassExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_assign_expr(&mut self, node: AssignExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <AssignExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_member_expr(&mut self, node: MemberExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <MemberExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_member_prop(&mut self, node: MemberProp, path: &mut AstNodePath) {
+        <MemberProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_super_prop_expr(&mut self, node: SuperPropExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <SuperPropExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_super_prop(&mut self, node: SuperProp, path: &mut AstNodePath) {
+        <SuperProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_cond_expr(&mut self, node: CondExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <CondExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_call_expr(&mut self, node: CallExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <CallExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_new_expr(&mut self, node: NewExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <NewExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_seq_expr(&mut self, node: SeqExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <SeqExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_arrow_expr(&mut self, node: ArrowExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ArrowExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_yield_expr(&mut self, node: YieldExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <YieldExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_meta_prop_expr(&mut self, node: MetaPropExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <MetaPropExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_await_expr(&mut self, node: AwaitExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <AwaitExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_tpl(&mut self, node: Tpl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Tpl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_tagged_tpl(&mut self, node: TaggedTpl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TaggedTpl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_tpl_element(&mut self, node: TplElement, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TplElement as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_paren_expr(&mut self, node: ParenExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ParenExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_callee(&mut self, node: Callee, path: &mut AstNodePath) {
+        <Callee as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_super(&mut self, node: Super, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Super as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_import(&mut self, node: Import, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Import as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_expr_or_spread(&mut self, node: ExprOrSpread, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ExprOrSpread as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_spread_dot_3_token(&mut self, node: SpreadDot3Token, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <SpreadDot3Token as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_block_stmt_or_expr(&mut self, node: BlockStmtOrExpr, path: &mut AstNodePath) {
+        <BlockStmtOrExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_assign_target(&mut self, node: AssignTarget, path: &mut AstNodePath) {
+        <AssignTarget as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_assign_target_pat(&mut self, node: AssignTargetPat, path: &mut AstNodePath) {
+        <AssignTargetPat as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_simple_assign_target(&mut self, node: SimpleAssignTarget, path: &mut AstNodePath) {
+        <SimpleAssignTarget as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_opt_chain_expr(&mut self, node: OptChainExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <OptChainExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_opt_chain_base(&mut self, node: OptChainBase, path: &mut AstNodePath) {
+        <OptChainBase as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_opt_call(&mut self, node: OptCall, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <OptCall as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_invalid(&mut self, node: Invalid, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Invalid as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_function(&mut self, node: Function, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Function as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_param(&mut self, node: Param, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Param as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_param_or_ts_param_prop(&mut self, node: ParamOrTsParamProp, path: &mut AstNodePath) {
+        <ParamOrTsParamProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_class(&mut self, node: Class, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Class as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_class_member(&mut self, node: ClassMember, path: &mut AstNodePath) {
+        <ClassMember as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_class_prop(&mut self, node: ClassProp, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ClassProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_private_prop(&mut self, node: PrivateProp, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <PrivateProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_class_method(&mut self, node: ClassMethod, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ClassMethod as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_private_method(&mut self, node: PrivateMethod, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <PrivateMethod as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_constructor(&mut self, node: Constructor, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Constructor as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_decorator(&mut self, node: Decorator, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Decorator as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_static_block(&mut self, node: StaticBlock, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <StaticBlock as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_key(&mut self, node: Key, path: &mut AstNodePath) {
+        <Key as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_auto_accessor(&mut self, node: AutoAccessor, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <AutoAccessor as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_prop(&mut self, node: Prop, path: &mut AstNodePath) {
+        <Prop as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_key_value_prop(&mut self, node: KeyValueProp, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <KeyValueProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_assign_prop(&mut self, node: AssignProp, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <AssignProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_getter_prop(&mut self, node: GetterProp, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <GetterProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_setter_prop(&mut self, node: SetterProp, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <SetterProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_method_prop(&mut self, node: MethodProp, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <MethodProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_prop_name(&mut self, node: PropName, path: &mut AstNodePath) {
+        <PropName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_computed_prop_name(&mut self, node: ComputedPropName, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ComputedPropName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_pat(&mut self, node: Pat, path: &mut AstNodePath) {
+        <Pat as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_array_pat(&mut self, node: ArrayPat, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ArrayPat as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_object_pat(&mut self, node: ObjectPat, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <ObjectPat as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_assign_pat(&mut self, node: AssignPat, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <AssignPat as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_rest_pat(&mut self, node: RestPat, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <RestPat as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_object_pat_prop(&mut self, node: ObjectPatProp, path: &mut AstNodePath) {
+        <ObjectPatProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_key_value_pat_prop(&mut self, node: KeyValuePatProp, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <KeyValuePatProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_assign_pat_prop(&mut self, node: AssignPatProp, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <AssignPatProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ident(&mut self, node: Ident, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Ident as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ident_name(&mut self, node: IdentName, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <IdentName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_private_name(&mut self, node: PrivateName, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <PrivateName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_binding_ident(&mut self, node: BindingIdent, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <BindingIdent as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_lit(&mut self, node: Lit, path: &mut AstNodePath) {
+        <Lit as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_str(&mut self, node: Str, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Str as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_bool(&mut self, node: Bool, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Bool as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_null(&mut self, node: Null, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Null as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_number(&mut self, node: Number, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Number as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_big_int(&mut self, node: BigInt, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <BigInt as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_regex(&mut self, node: Regex, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <Regex as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_object(&mut self, node: JSXObject, path: &mut AstNodePath) {
+        <JSXObject as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_jsx_member_expr(&mut self, node: JSXMemberExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXMemberExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_namespaced_name(&mut self, node: JSXNamespacedName, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXNamespacedName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_empty_expr(&mut self, node: JSXEmptyExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXEmptyExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_expr_container(&mut self, node: JSXExprContainer, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXExprContainer as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_expr(&mut self, node: JSXExpr, path: &mut AstNodePath) {
+        <JSXExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_jsx_spread_child(&mut self, node: JSXSpreadChild, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXSpreadChild as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_element_name(&mut self, node: JSXElementName, path: &mut AstNodePath) {
+        <JSXElementName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_jsx_opening_element(&mut self, node: JSXOpeningElement, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXOpeningElement as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_attr_or_spread(&mut self, node: JSXAttrOrSpread, path: &mut AstNodePath) {
+        <JSXAttrOrSpread as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_jsx_closing_element(&mut self, node: JSXClosingElement, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXClosingElement as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_attr(&mut self, node: JSXAttr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXAttr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_attr_name(&mut self, node: JSXAttrName, path: &mut AstNodePath) {
+        <JSXAttrName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_jsx_attr_value(&mut self, node: JSXAttrValue, path: &mut AstNodePath) {
+        <JSXAttrValue as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_jsx_text(&mut self, node: JSXText, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXText as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_element(&mut self, node: JSXElement, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXElement as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_element_child(&mut self, node: JSXElementChild, path: &mut AstNodePath) {
+        <JSXElementChild as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_jsx_fragment(&mut self, node: JSXFragment, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXFragment as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_opening_fragment(&mut self, node: JSXOpeningFragment, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXOpeningFragment as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_jsx_closing_fragment(&mut self, node: JSXClosingFragment, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <JSXClosingFragment as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_ann(&mut self, node: TsTypeAnn, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTypeAnn as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_param_decl(&mut self, node: TsTypeParamDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTypeParamDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_param(&mut self, node: TsTypeParam, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTypeParam as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_param_instantiation(
+        &mut self,
+        node: TsTypeParamInstantiation,
+        path: &mut AstNodePath,
+    ) {
+        self.enter_node(node.node_id(), path);
+        <TsTypeParamInstantiation as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_param_prop(&mut self, node: TsParamProp, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsParamProp as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_param_prop_param(&mut self, node: TsParamPropParam, path: &mut AstNodePath) {
+        <TsParamPropParam as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_qualified_name(&mut self, node: TsQualifiedName, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsQualifiedName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_entity_name(&mut self, node: TsEntityName, path: &mut AstNodePath) {
+        <TsEntityName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_type_element(&mut self, node: TsTypeElement, path: &mut AstNodePath) {
+        <TsTypeElement as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_call_signature_decl(&mut self, node: TsCallSignatureDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsCallSignatureDecl as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_construct_signature_decl(
+        &mut self,
+        node: TsConstructSignatureDecl,
+        path: &mut AstNodePath,
+    ) {
+        self.enter_node(node.node_id(), path);
+        <TsConstructSignatureDecl as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_property_signature(&mut self, node: TsPropertySignature, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsPropertySignature as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_getter_signature(&mut self, node: TsGetterSignature, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsGetterSignature as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_setter_signature(&mut self, node: TsSetterSignature, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsSetterSignature as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_method_signature(&mut self, node: TsMethodSignature, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsMethodSignature as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_index_signature(&mut self, node: TsIndexSignature, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsIndexSignature as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type(&mut self, node: TsType, path: &mut AstNodePath) {
+        <TsType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_fn_or_constructor_type(
+        &mut self,
+        node: TsFnOrConstructorType,
+        path: &mut AstNodePath,
+    ) {
+        <TsFnOrConstructorType as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_ts_keyword_type(&mut self, node: TsKeywordType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsKeywordType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_this_type(&mut self, node: TsThisType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsThisType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_fn_param(&mut self, node: TsFnParam, path: &mut AstNodePath) {
+        <TsFnParam as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_fn_type(&mut self, node: TsFnType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsFnType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_constructor_type(&mut self, node: TsConstructorType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsConstructorType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_ref(&mut self, node: TsTypeRef, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTypeRef as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_predicate(&mut self, node: TsTypePredicate, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTypePredicate as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_this_type_or_ident(&mut self, node: TsThisTypeOrIdent, path: &mut AstNodePath) {
+        <TsThisTypeOrIdent as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_type_query(&mut self, node: TsTypeQuery, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTypeQuery as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_query_expr(&mut self, node: TsTypeQueryExpr, path: &mut AstNodePath) {
+        <TsTypeQueryExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_import_call_options(&mut self, node: TsImportCallOptions, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsImportCallOptions as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_import_type(&mut self, node: TsImportType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsImportType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_lit(&mut self, node: TsTypeLit, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTypeLit as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_array_type(&mut self, node: TsArrayType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsArrayType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_tuple_type(&mut self, node: TsTupleType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTupleType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_tuple_element(&mut self, node: TsTupleElement, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTupleElement as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_optional_type(&mut self, node: TsOptionalType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsOptionalType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_rest_type(&mut self, node: TsRestType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsRestType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_union_or_intersection_type(
+        &mut self,
+        node: TsUnionOrIntersectionType,
+        path: &mut AstNodePath,
+    ) {
+        <TsUnionOrIntersectionType as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_ts_union_type(&mut self, node: TsUnionType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsUnionType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_intersection_type(&mut self, node: TsIntersectionType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsIntersectionType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_conditional_type(&mut self, node: TsConditionalType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsConditionalType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_infer_type(&mut self, node: TsInferType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsInferType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_parenthesized_type(&mut self, node: TsParenthesizedType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsParenthesizedType as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_operator(&mut self, node: TsTypeOperator, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTypeOperator as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_indexed_access_type(&mut self, node: TsIndexedAccessType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsIndexedAccessType as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_mapped_type(&mut self, node: TsMappedType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsMappedType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_lit_type(&mut self, node: TsLitType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsLitType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_lit(&mut self, node: TsLit, path: &mut AstNodePath) {
+        <TsLit as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_tpl_lit_type(&mut self, node: TsTplLitType, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTplLitType as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_interface_decl(&mut self, node: TsInterfaceDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsInterfaceDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_interface_body(&mut self, node: TsInterfaceBody, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsInterfaceBody as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_expr_with_type_args(&mut self, node: TsExprWithTypeArgs, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsExprWithTypeArgs as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_alias_decl(&mut self, node: TsTypeAliasDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTypeAliasDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_enum_decl(&mut self, node: TsEnumDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsEnumDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_enum_member(&mut self, node: TsEnumMember, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsEnumMember as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_enum_member_id(&mut self, node: TsEnumMemberId, path: &mut AstNodePath) {
+        <TsEnumMemberId as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_module_decl(&mut self, node: TsModuleDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsModuleDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_namespace_body(&mut self, node: TsNamespaceBody, path: &mut AstNodePath) {
+        <TsNamespaceBody as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_module_block(&mut self, node: TsModuleBlock, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsModuleBlock as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_namespace_decl(&mut self, node: TsNamespaceDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsNamespaceDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_module_name(&mut self, node: TsModuleName, path: &mut AstNodePath) {
+        <TsModuleName as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_import_equals_decl(&mut self, node: TsImportEqualsDecl, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsImportEqualsDecl as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_module_ref(&mut self, node: TsModuleRef, path: &mut AstNodePath) {
+        <TsModuleRef as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_external_module_ref(&mut self, node: TsExternalModuleRef, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsExternalModuleRef as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_export_assignment(&mut self, node: TsExportAssignment, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsExportAssignment as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_namespace_export_decl(
+        &mut self,
+        node: TsNamespaceExportDecl,
+        path: &mut AstNodePath,
+    ) {
+        self.enter_node(node.node_id(), path);
+        <TsNamespaceExportDecl as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        );
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_as_expr(&mut self, node: TsAsExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsAsExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_type_assertion(&mut self, node: TsTypeAssertion, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsTypeAssertion as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_non_null_expr(&mut self, node: TsNonNullExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsNonNullExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_satisfies_expr(&mut self, node: TsSatisfiesExpr, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsSatisfiesExpr as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_const_assertion(&mut self, node: TsConstAssertion, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsConstAssertion as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_ts_instantiation(&mut self, node: TsInstantiation, path: &mut AstNodePath) {
+        self.enter_node(node.node_id(), path);
+        <TsInstantiation as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+        self.leave_node(node.node_id(), path);
+    }
+    #[inline]
+    fn visit_module_items(&mut self, node: TypedSubRange<ModuleItem>, path: &mut AstNodePath) {
+        <TypedSubRange<ModuleItem> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_stmts(&mut self, node: TypedSubRange<Stmt>, path: &mut AstNodePath) {
+        <TypedSubRange<Stmt> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_import_specifiers(
+        &mut self,
+        node: TypedSubRange<ImportSpecifier>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<ImportSpecifier> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_object_lit(&mut self, node: Option<ObjectLit>, path: &mut AstNodePath) {
+        <Option<ObjectLit> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_opt_module_export_name(
+        &mut self,
+        node: Option<ModuleExportName>,
+        path: &mut AstNodePath,
+    ) {
+        <Option<ModuleExportName> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_export_specifiers(
+        &mut self,
+        node: TypedSubRange<ExportSpecifier>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<ExportSpecifier> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_str(&mut self, node: Option<Str>, path: &mut AstNodePath) {
+        <Option<Str> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_opt_expr(&mut self, node: Option<Expr>, path: &mut AstNodePath) {
+        <Option<Expr> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_opt_ident(&mut self, node: Option<Ident>, path: &mut AstNodePath) {
+        <Option<Ident> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_opt_stmt(&mut self, node: Option<Stmt>, path: &mut AstNodePath) {
+        <Option<Stmt> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_switch_cases(&mut self, node: TypedSubRange<SwitchCase>, path: &mut AstNodePath) {
+        <TypedSubRange<SwitchCase> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_catch_clause(&mut self, node: Option<CatchClause>, path: &mut AstNodePath) {
+        <Option<CatchClause> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_opt_block_stmt(&mut self, node: Option<BlockStmt>, path: &mut AstNodePath) {
+        <Option<BlockStmt> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_opt_var_decl_or_expr(&mut self, node: Option<VarDeclOrExpr>, path: &mut AstNodePath) {
+        <Option<VarDeclOrExpr> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_pat(&mut self, node: Option<Pat>, path: &mut AstNodePath) {
+        <Option<Pat> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_var_declarators(
+        &mut self,
+        node: TypedSubRange<VarDeclarator>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<VarDeclarator> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_expr_or_spread(&mut self, node: Option<ExprOrSpread>, path: &mut AstNodePath) {
+        <Option<ExprOrSpread> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_vec_expr_or_spreads(
+        &mut self,
+        node: TypedSubRange<Option<ExprOrSpread>>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<Option<ExprOrSpread>> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_prop_or_spreads(&mut self, node: TypedSubRange<PropOrSpread>, path: &mut AstNodePath) {
+        <TypedSubRange<PropOrSpread> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_expr_or_spreads(&mut self, node: TypedSubRange<ExprOrSpread>, path: &mut AstNodePath) {
+        <TypedSubRange<ExprOrSpread> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_ts_type_param_instantiation(
+        &mut self,
+        node: Option<TsTypeParamInstantiation>,
+        path: &mut AstNodePath,
+    ) {
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_expr_or_spreads(
+        &mut self,
+        node: Option<TypedSubRange<ExprOrSpread>>,
+        path: &mut AstNodePath,
+    ) {
+        <Option<TypedSubRange<ExprOrSpread>> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_exprs(&mut self, node: TypedSubRange<Expr>, path: &mut AstNodePath) {
+        <TypedSubRange<Expr> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_pats(&mut self, node: TypedSubRange<Pat>, path: &mut AstNodePath) {
+        <TypedSubRange<Pat> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_opt_ts_type_param_decl(
+        &mut self,
+        node: Option<TsTypeParamDecl>,
+        path: &mut AstNodePath,
+    ) {
+        <Option<TsTypeParamDecl> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_ts_type_ann(&mut self, node: Option<TsTypeAnn>, path: &mut AstNodePath) {
+        <Option<TsTypeAnn> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_tpl_elements(&mut self, node: TypedSubRange<TplElement>, path: &mut AstNodePath) {
+        <TypedSubRange<TplElement> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_spread_dot_3_token(
+        &mut self,
+        node: Option<SpreadDot3Token>,
+        path: &mut AstNodePath,
+    ) {
+        <Option<SpreadDot3Token> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_params(&mut self, node: TypedSubRange<Param>, path: &mut AstNodePath) {
+        <TypedSubRange<Param> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_decorators(&mut self, node: TypedSubRange<Decorator>, path: &mut AstNodePath) {
+        <TypedSubRange<Decorator> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_class_members(&mut self, node: TypedSubRange<ClassMember>, path: &mut AstNodePath) {
+        <TypedSubRange<ClassMember> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_ts_expr_with_type_argss(
+        &mut self,
+        node: TypedSubRange<TsExprWithTypeArgs>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<TsExprWithTypeArgs> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_accessibility(&mut self, node: Option<Accessibility>, path: &mut AstNodePath) {
+        <Option<Accessibility> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_param_or_ts_param_props(
+        &mut self,
+        node: TypedSubRange<ParamOrTsParamProp>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<ParamOrTsParamProp> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_vec_pats(&mut self, node: TypedSubRange<Option<Pat>>, path: &mut AstNodePath) {
+        <TypedSubRange<Option<Pat>> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_object_pat_props(
+        &mut self,
+        node: TypedSubRange<ObjectPatProp>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<ObjectPatProp> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_jsx_attr_or_spreads(
+        &mut self,
+        node: TypedSubRange<JSXAttrOrSpread>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<JSXAttrOrSpread> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_jsx_attr_value(&mut self, node: Option<JSXAttrValue>, path: &mut AstNodePath) {
+        <Option<JSXAttrValue> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_jsx_element_childs(
+        &mut self,
+        node: TypedSubRange<JSXElementChild>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<JSXElementChild> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_jsx_closing_element(
+        &mut self,
+        node: Option<JSXClosingElement>,
+        path: &mut AstNodePath,
+    ) {
+        <Option<JSXClosingElement> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_ts_type_params(&mut self, node: TypedSubRange<TsTypeParam>, path: &mut AstNodePath) {
+        <TypedSubRange<TsTypeParam> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_ts_type(&mut self, node: Option<TsType>, path: &mut AstNodePath) {
+        <Option<TsType> as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+    }
+    #[inline]
+    fn visit_ts_types(&mut self, node: TypedSubRange<TsType>, path: &mut AstNodePath) {
+        <TypedSubRange<TsType> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_ts_fn_params(&mut self, node: TypedSubRange<TsFnParam>, path: &mut AstNodePath) {
+        <TypedSubRange<TsFnParam> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_ts_entity_name(&mut self, node: Option<TsEntityName>, path: &mut AstNodePath) {
+        <Option<TsEntityName> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_ts_import_call_options(
+        &mut self,
+        node: Option<TsImportCallOptions>,
+        path: &mut AstNodePath,
+    ) {
+        <Option<TsImportCallOptions> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_ts_type_elements(
+        &mut self,
+        node: TypedSubRange<TsTypeElement>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<TsTypeElement> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_ts_tuple_elements(
+        &mut self,
+        node: TypedSubRange<TsTupleElement>,
+        path: &mut AstNodePath,
+    ) {
+        <TypedSubRange<TsTupleElement> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_true_plus_minus(&mut self, node: Option<TruePlusMinus>, path: &mut AstNodePath) {
+        <Option<TruePlusMinus> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_ts_enum_members(&mut self, node: TypedSubRange<TsEnumMember>, path: &mut AstNodePath) {
+        <TypedSubRange<TsEnumMember> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+    #[inline]
+    fn visit_opt_ts_namespace_body(
+        &mut self,
+        node: Option<TsNamespaceBody>,
+        path: &mut AstNodePath,
+    ) {
+        <Option<TsNamespaceBody> as VisitWithPathWith<Self>>::visit_children_with_path(
+            node, self, path,
+        )
+    }
+}
+pub trait VisitWithPathWith<V: ?Sized + VisitWithPath> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath);
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath);
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Program {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_program(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Module(it) => {
+                <Module as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Script(it) => {
+                <Script as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Module {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_module(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Module);
+        path.set_field(0u8);
+        let field_value = self.body(visitor.ast());
+        <TypedSubRange<ModuleItem> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.shebang(visitor.ast());
+        <OptionalUtf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Script {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_script(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Script);
+        path.set_field(0u8);
+        let field_value = self.body(visitor.ast());
+        <TypedSubRange<Stmt> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.shebang(visitor.ast());
+        <OptionalUtf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ModuleItem {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_module_item(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::ModuleDecl(it) => {
+                <ModuleDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Stmt(it) => <Stmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ModuleDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_module_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Import(it) => {
+                <ImportDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::ExportDecl(it) => {
+                <ExportDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::ExportNamed(it) => {
+                <NamedExport as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::ExportDefaultDecl(it) => {
+                <ExportDefaultDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::ExportDefaultExpr(it) => {
+                <ExportDefaultExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::ExportAll(it) => {
+                <ExportAll as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsImportEquals(it) => {
+                <TsImportEqualsDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsExportAssignment(it) => {
+                <TsExportAssignment as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsNamespaceExport(it) => {
+                <TsNamespaceExportDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ImportDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_import_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ImportDecl);
+        path.set_field(0u8);
+        let field_value = self.specifiers(visitor.ast());
+        <TypedSubRange<ImportSpecifier> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.src(visitor.ast());
+        <Str as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_only(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.with(visitor.ast());
+        <Option<ObjectLit> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.phase(visitor.ast());
+        <ImportPhase as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ImportSpecifier {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_import_specifier(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Named(it) => {
+                <ImportNamedSpecifier as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Default(it) => {
+                <ImportDefaultSpecifier as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Namespace(it) => {
+                <ImportStarAsSpecifier as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ImportNamedSpecifier {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_import_named_specifier(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ImportNamedSpecifier);
+        path.set_field(0u8);
+        let field_value = self.local(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.imported(visitor.ast());
+        <Option<ModuleExportName> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.is_type_only(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ImportDefaultSpecifier {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_import_default_specifier(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ImportDefaultSpecifier);
+        path.set_field(0u8);
+        let field_value = self.local(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ImportStarAsSpecifier {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_import_star_as_specifier(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ImportStarAsSpecifier);
+        path.set_field(0u8);
+        let field_value = self.local(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ExportDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_export_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ExportDecl);
+        path.set_field(0u8);
+        let field_value = self.decl(visitor.ast());
+        <Decl as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for NamedExport {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_named_export(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::NamedExport);
+        path.set_field(0u8);
+        let field_value = self.specifiers(visitor.ast());
+        <TypedSubRange<ExportSpecifier> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.src(visitor.ast());
+        <Option<Str> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_only(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.with(visitor.ast());
+        <Option<ObjectLit> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ExportSpecifier {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_export_specifier(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Namespace(it) => {
+                <ExportNamespaceSpecifier as VisitWithPathWith<V>>::visit_with_path(
+                    it, visitor, path,
+                )
+            }
+            Self::Default(it) => {
+                <ExportDefaultSpecifier as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Named(it) => {
+                <ExportNamedSpecifier as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ExportNamespaceSpecifier {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_export_namespace_specifier(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ExportNamespaceSpecifier);
+        path.set_field(0u8);
+        let field_value = self.name(visitor.ast());
+        <ModuleExportName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ModuleExportName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_module_export_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => <Ident as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Str(it) => <Str as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ExportDefaultSpecifier {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_export_default_specifier(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ExportDefaultSpecifier);
+        path.set_field(0u8);
+        let field_value = self.exported(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ExportNamedSpecifier {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_export_named_specifier(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ExportNamedSpecifier);
+        path.set_field(0u8);
+        let field_value = self.orig(visitor.ast());
+        <ModuleExportName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.exported(visitor.ast());
+        <Option<ModuleExportName> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.is_type_only(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ExportDefaultDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_export_default_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ExportDefaultDecl);
+        path.set_field(0u8);
+        let field_value = self.decl(visitor.ast());
+        <DefaultDecl as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for DefaultDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_default_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Class(it) => {
+                <ClassExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Fn(it) => <FnExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::TsInterfaceDecl(it) => {
+                <TsInterfaceDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ExportDefaultExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_export_default_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ExportDefaultExpr);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ExportAll {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_export_all(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ExportAll);
+        path.set_field(0u8);
+        let field_value = self.src(visitor.ast());
+        <Str as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_only(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.with(visitor.ast());
+        <Option<ObjectLit> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for BlockStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_block_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::BlockStmt);
+        path.set_field(0u8);
+        let field_value = self.stmts(visitor.ast());
+        <TypedSubRange<Stmt> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Stmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Block(it) => {
+                <BlockStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Empty(it) => {
+                <EmptyStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Debugger(it) => {
+                <DebuggerStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::With(it) => {
+                <WithStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Return(it) => {
+                <ReturnStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Labeled(it) => {
+                <LabeledStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Break(it) => {
+                <BreakStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Continue(it) => {
+                <ContinueStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::If(it) => <IfStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Switch(it) => {
+                <SwitchStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Throw(it) => {
+                <ThrowStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Try(it) => <TryStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::While(it) => {
+                <WhileStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::DoWhile(it) => {
+                <DoWhileStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::For(it) => <ForStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::ForIn(it) => {
+                <ForInStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::ForOf(it) => {
+                <ForOfStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Decl(it) => <Decl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Expr(it) => {
+                <ExprStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ExprStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_expr_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ExprStmt);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for EmptyStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_empty_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for DebuggerStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_debugger_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for WithStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_with_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::WithStmt);
+        path.set_field(0u8);
+        let field_value = self.obj(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ReturnStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_return_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ReturnStmt);
+        path.set_field(0u8);
+        let field_value = self.arg(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for LabeledStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_labeled_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::LabeledStmt);
+        path.set_field(0u8);
+        let field_value = self.label(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for BreakStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_break_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::BreakStmt);
+        path.set_field(0u8);
+        let field_value = self.label(visitor.ast());
+        <Option<Ident> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ContinueStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_continue_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ContinueStmt);
+        path.set_field(0u8);
+        let field_value = self.label(visitor.ast());
+        <Option<Ident> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for IfStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_if_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::IfStmt);
+        path.set_field(0u8);
+        let field_value = self.test(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.cons(visitor.ast());
+        <Stmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.alt(visitor.ast());
+        <Option<Stmt> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for SwitchStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_switch_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::SwitchStmt);
+        path.set_field(0u8);
+        let field_value = self.discriminant(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.cases(visitor.ast());
+        <TypedSubRange<SwitchCase> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ThrowStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_throw_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ThrowStmt);
+        path.set_field(0u8);
+        let field_value = self.arg(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TryStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_try_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TryStmt);
+        path.set_field(0u8);
+        let field_value = self.block(visitor.ast());
+        <BlockStmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.handler(visitor.ast());
+        <Option<CatchClause> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.finalizer(visitor.ast());
+        <Option<BlockStmt> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for WhileStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_while_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::WhileStmt);
+        path.set_field(0u8);
+        let field_value = self.test(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for DoWhileStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_do_while_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::DoWhileStmt);
+        path.set_field(0u8);
+        let field_value = self.test(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ForStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_for_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ForStmt);
+        path.set_field(0u8);
+        let field_value = self.init(visitor.ast());
+        <Option<VarDeclOrExpr> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.test(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.update(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ForInStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_for_in_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ForInStmt);
+        path.set_field(0u8);
+        let field_value = self.left(visitor.ast());
+        <ForHead as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.right(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ForOfStmt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_for_of_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ForOfStmt);
+        path.set_field(0u8);
+        let field_value = self.is_await(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.left(visitor.ast());
+        <ForHead as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.right(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.body(visitor.ast());
+        <Stmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for SwitchCase {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_switch_case(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::SwitchCase);
+        path.set_field(0u8);
+        let field_value = self.test(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.cons(visitor.ast());
+        <TypedSubRange<Stmt> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for CatchClause {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_catch_clause(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::CatchClause);
+        path.set_field(0u8);
+        let field_value = self.param(visitor.ast());
+        <Option<Pat> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.body(visitor.ast());
+        <BlockStmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ForHead {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_for_head(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::VarDecl(it) => {
+                <VarDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::UsingDecl(it) => {
+                <UsingDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Pat(it) => <Pat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for VarDeclOrExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_var_decl_or_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::VarDecl(it) => {
+                <VarDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Expr(it) => <Expr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Decl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Class(it) => {
+                <ClassDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Fn(it) => <FnDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Var(it) => <VarDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Using(it) => {
+                <UsingDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsInterface(it) => {
+                <TsInterfaceDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsTypeAlias(it) => {
+                <TsTypeAliasDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsEnum(it) => {
+                <TsEnumDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsModule(it) => {
+                <TsModuleDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for FnDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_fn_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::FnDecl);
+        path.set_field(0u8);
+        let field_value = self.ident(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.function(visitor.ast());
+        <Function as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ClassDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_class_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ClassDecl);
+        path.set_field(0u8);
+        let field_value = self.ident(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.class(visitor.ast());
+        <Class as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for VarDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_var_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::VarDecl);
+        path.set_field(0u8);
+        let field_value = self.kind(visitor.ast());
+        <VarDeclKind as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.decls(visitor.ast());
+        <TypedSubRange<VarDeclarator> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for VarDeclarator {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_var_declarator(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::VarDeclarator);
+        path.set_field(0u8);
+        let field_value = self.name(visitor.ast());
+        <Pat as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.init(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.definite(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for UsingDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_using_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::UsingDecl);
+        path.set_field(0u8);
+        let field_value = self.is_await(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.decls(visitor.ast());
+        <TypedSubRange<VarDeclarator> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Expr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::This(it) => {
+                <ThisExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Array(it) => {
+                <ArrayLit as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Object(it) => {
+                <ObjectLit as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Fn(it) => <FnExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Unary(it) => {
+                <UnaryExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Update(it) => {
+                <UpdateExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Bin(it) => <BinExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Assign(it) => {
+                <AssignExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Member(it) => {
+                <MemberExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::SuperProp(it) => {
+                <SuperPropExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Cond(it) => {
+                <CondExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Call(it) => {
+                <CallExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::New(it) => <NewExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Seq(it) => <SeqExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Ident(it) => <Ident as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Lit(it) => <Lit as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Tpl(it) => <Tpl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::TaggedTpl(it) => {
+                <TaggedTpl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Arrow(it) => {
+                <ArrowExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Class(it) => {
+                <ClassExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Yield(it) => {
+                <YieldExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::MetaProp(it) => {
+                <MetaPropExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Await(it) => {
+                <AwaitExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Paren(it) => {
+                <ParenExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXMember(it) => {
+                <JSXMemberExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXNamespacedName(it) => {
+                <JSXNamespacedName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXEmpty(it) => {
+                <JSXEmptyExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXElement(it) => {
+                <JSXElement as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXFragment(it) => {
+                <JSXFragment as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsTypeAssertion(it) => {
+                <TsTypeAssertion as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsConstAssertion(it) => {
+                <TsConstAssertion as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsNonNull(it) => {
+                <TsNonNullExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsAs(it) => {
+                <TsAsExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsInstantiation(it) => {
+                <TsInstantiation as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsSatisfies(it) => {
+                <TsSatisfiesExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::PrivateName(it) => {
+                <PrivateName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::OptChain(it) => {
+                <OptChainExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Invalid(it) => {
+                <Invalid as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ThisExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_this_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ArrayLit {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_array_lit(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ArrayLit);
+        path.set_field(0u8);
+        let field_value = self.elems(visitor.ast());
+        <TypedSubRange<Option<ExprOrSpread>> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ObjectLit {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_object_lit(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ObjectLit);
+        path.set_field(0u8);
+        let field_value = self.props(visitor.ast());
+        <TypedSubRange<PropOrSpread> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for PropOrSpread {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_prop_or_spread(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::SpreadElement(it) => {
+                <SpreadElement as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Prop(it) => <Prop as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for SpreadElement {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_spread_element(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::SpreadElement);
+        path.set_field(0u8);
+        let field_value = self.dot_3_token(visitor.ast());
+        <Span as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for UnaryExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_unary_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::UnaryExpr);
+        path.set_field(0u8);
+        let field_value = self.op(visitor.ast());
+        <UnaryOp as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.arg(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for UpdateExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_update_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::UpdateExpr);
+        path.set_field(0u8);
+        let field_value = self.op(visitor.ast());
+        <UpdateOp as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.prefix(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.arg(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for BinExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_bin_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::BinExpr);
+        path.set_field(0u8);
+        let field_value = self.op(visitor.ast());
+        <BinaryOp as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.left(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.right(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for FnExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_fn_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::FnExpr);
+        path.set_field(0u8);
+        let field_value = self.ident(visitor.ast());
+        <Option<Ident> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.function(visitor.ast());
+        <Function as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ClassExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_class_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ClassExpr);
+        path.set_field(0u8);
+        let field_value = self.ident(visitor.ast());
+        <Option<Ident> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.class(visitor.ast());
+        <Class as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for AssignExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_assign_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::AssignExpr);
+        path.set_field(0u8);
+        let field_value = self.op(visitor.ast());
+        <AssignOp as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.left(visitor.ast());
+        <AssignTarget as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.right(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for MemberExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_member_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::MemberExpr);
+        path.set_field(0u8);
+        let field_value = self.obj(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.prop(visitor.ast());
+        <MemberProp as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for MemberProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_member_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => {
+                <IdentName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::PrivateName(it) => {
+                <PrivateName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Computed(it) => {
+                <ComputedPropName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for SuperPropExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_super_prop_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::SuperPropExpr);
+        path.set_field(0u8);
+        let field_value = self.obj(visitor.ast());
+        <Super as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.prop(visitor.ast());
+        <SuperProp as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for SuperProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_super_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => {
+                <IdentName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Computed(it) => {
+                <ComputedPropName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for CondExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_cond_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::CondExpr);
+        path.set_field(0u8);
+        let field_value = self.test(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.cons(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.alt(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for CallExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_call_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::CallExpr);
+        path.set_field(0u8);
+        let field_value = self.callee(visitor.ast());
+        <Callee as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.args(visitor.ast());
+        <TypedSubRange<ExprOrSpread> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for NewExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_new_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::NewExpr);
+        path.set_field(0u8);
+        let field_value = self.callee(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.args(visitor.ast());
+        <Option<TypedSubRange<ExprOrSpread>> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for SeqExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_seq_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::SeqExpr);
+        path.set_field(0u8);
+        let field_value = self.exprs(visitor.ast());
+        <TypedSubRange<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ArrowExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_arrow_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ArrowExpr);
+        path.set_field(0u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<Pat> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.body(visitor.ast());
+        <BlockStmtOrExpr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.is_async(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_generator(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(5u8);
+        let field_value = self.return_type(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for YieldExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_yield_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::YieldExpr);
+        path.set_field(0u8);
+        let field_value = self.arg(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.delegate(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for MetaPropExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_meta_prop_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::MetaPropExpr);
+        path.set_field(0u8);
+        let field_value = self.kind(visitor.ast());
+        <MetaPropKind as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for AwaitExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_await_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::AwaitExpr);
+        path.set_field(0u8);
+        let field_value = self.arg(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Tpl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_tpl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Tpl);
+        path.set_field(0u8);
+        let field_value = self.exprs(visitor.ast());
+        <TypedSubRange<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.quasis(visitor.ast());
+        <TypedSubRange<TplElement> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TaggedTpl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_tagged_tpl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TaggedTpl);
+        path.set_field(0u8);
+        let field_value = self.tag(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.tpl(visitor.ast());
+        <Tpl as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TplElement {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_tpl_element(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TplElement);
+        path.set_field(0u8);
+        let field_value = self.tail(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.cooked(visitor.ast());
+        <OptionalWtf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.raw(visitor.ast());
+        <Utf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ParenExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_paren_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ParenExpr);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Callee {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_callee(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Super(it) => <Super as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Import(it) => {
+                <Import as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Expr(it) => <Expr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Super {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_super(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Import {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_import(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Import);
+        path.set_field(0u8);
+        let field_value = self.phase(visitor.ast());
+        <ImportPhase as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ExprOrSpread {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_expr_or_spread(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ExprOrSpread);
+        path.set_field(0u8);
+        let field_value = self.spread(visitor.ast());
+        <Option<SpreadDot3Token> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for SpreadDot3Token {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_spread_dot_3_token(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for BlockStmtOrExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_block_stmt_or_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::BlockStmt(it) => {
+                <BlockStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Expr(it) => <Expr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for AssignTarget {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_assign_target(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Simple(it) => {
+                <SimpleAssignTarget as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Pat(it) => {
+                <AssignTargetPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for AssignTargetPat {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_assign_target_pat(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Array(it) => {
+                <ArrayPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Object(it) => {
+                <ObjectPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Invalid(it) => {
+                <Invalid as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for SimpleAssignTarget {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_simple_assign_target(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => {
+                <BindingIdent as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Member(it) => {
+                <MemberExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::SuperProp(it) => {
+                <SuperPropExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Paren(it) => {
+                <ParenExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::OptChain(it) => {
+                <OptChainExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsAs(it) => {
+                <TsAsExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsSatisfies(it) => {
+                <TsSatisfiesExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsNonNull(it) => {
+                <TsNonNullExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsTypeAssertion(it) => {
+                <TsTypeAssertion as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsInstantiation(it) => {
+                <TsInstantiation as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Invalid(it) => {
+                <Invalid as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for OptChainExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_chain_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::OptChainExpr);
+        path.set_field(0u8);
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.base(visitor.ast());
+        <OptChainBase as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for OptChainBase {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_chain_base(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Member(it) => {
+                <MemberExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Call(it) => <OptCall as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for OptCall {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_call(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::OptCall);
+        path.set_field(0u8);
+        let field_value = self.callee(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.args(visitor.ast());
+        <TypedSubRange<ExprOrSpread> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Invalid {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_invalid(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Function {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_function(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Function);
+        path.set_field(0u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<Param> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.body(visitor.ast());
+        <Option<BlockStmt> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_generator(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.is_async(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(5u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(6u8);
+        let field_value = self.return_type(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Param {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_param(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Param);
+        path.set_field(0u8);
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.pat(visitor.ast());
+        <Pat as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ParamOrTsParamProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_param_or_ts_param_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::TsParamProp(it) => {
+                <TsParamProp as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Param(it) => <Param as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Class {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_class(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Class);
+        path.set_field(0u8);
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.body(visitor.ast());
+        <TypedSubRange<ClassMember> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.super_class(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(5u8);
+        let field_value = self.super_type_params(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(6u8);
+        let field_value = self.implements(visitor.ast());
+        <TypedSubRange<TsExprWithTypeArgs> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ClassMember {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_class_member(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Constructor(it) => {
+                <Constructor as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Method(it) => {
+                <ClassMethod as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::PrivateMethod(it) => {
+                <PrivateMethod as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::ClassProp(it) => {
+                <ClassProp as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::PrivateProp(it) => {
+                <PrivateProp as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Empty(it) => {
+                <EmptyStmt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::StaticBlock(it) => {
+                <StaticBlock as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::AutoAccessor(it) => {
+                <AutoAccessor as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsIndexSignature(it) => {
+                <TsIndexSignature as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ClassProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_class_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ClassProp);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.value(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(5u8);
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(6u8);
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(7u8);
+        let field_value = self.is_optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(8u8);
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(9u8);
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(10u8);
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(11u8);
+        let field_value = self.definite(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for PrivateProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_private_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::PrivateProp);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <PrivateName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.value(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(5u8);
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(6u8);
+        let field_value = self.is_optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(7u8);
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(8u8);
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(9u8);
+        let field_value = self.definite(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ClassMethod {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_class_method(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ClassMethod);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.function(visitor.ast());
+        <Function as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.kind(visitor.ast());
+        <MethodKind as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(5u8);
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(6u8);
+        let field_value = self.is_optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(7u8);
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for PrivateMethod {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_private_method(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::PrivateMethod);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <PrivateName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.function(visitor.ast());
+        <Function as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.kind(visitor.ast());
+        <MethodKind as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(5u8);
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(6u8);
+        let field_value = self.is_optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(7u8);
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Constructor {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_constructor(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Constructor);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<ParamOrTsParamProp> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.body(visitor.ast());
+        <Option<BlockStmt> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(4u8);
+        let field_value = self.is_optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Decorator {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_decorator(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Decorator);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for StaticBlock {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_static_block(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::StaticBlock);
+        path.set_field(0u8);
+        let field_value = self.body(visitor.ast());
+        <BlockStmt as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Key {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_key(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Private(it) => {
+                <PrivateName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Public(it) => {
+                <PropName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for AutoAccessor {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_auto_accessor(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::AutoAccessor);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <Key as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.value(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(5u8);
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(6u8);
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(7u8);
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(8u8);
+        let field_value = self.definite(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Prop {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Shorthand(it) => {
+                <Ident as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::KeyValue(it) => {
+                <KeyValueProp as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Assign(it) => {
+                <AssignProp as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Getter(it) => {
+                <GetterProp as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Setter(it) => {
+                <SetterProp as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Method(it) => {
+                <MethodProp as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for KeyValueProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_key_value_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::KeyValueProp);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.value(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for AssignProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_assign_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::AssignProp);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.value(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for GetterProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_getter_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::GetterProp);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.body(visitor.ast());
+        <Option<BlockStmt> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for SetterProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_setter_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::SetterProp);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.this_param(visitor.ast());
+        <Option<Pat> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.param(visitor.ast());
+        <Pat as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.body(visitor.ast());
+        <Option<BlockStmt> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for MethodProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_method_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::MethodProp);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.function(visitor.ast());
+        <Function as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for PropName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_prop_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => {
+                <IdentName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Str(it) => <Str as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Num(it) => <Number as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Computed(it) => {
+                <ComputedPropName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::BigInt(it) => {
+                <BigInt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ComputedPropName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_computed_prop_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ComputedPropName);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Pat {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_pat(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => {
+                <BindingIdent as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Array(it) => {
+                <ArrayPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Rest(it) => <RestPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Object(it) => {
+                <ObjectPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Assign(it) => {
+                <AssignPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Invalid(it) => {
+                <Invalid as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Expr(it) => <Expr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ArrayPat {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_array_pat(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ArrayPat);
+        path.set_field(0u8);
+        let field_value = self.elems(visitor.ast());
+        <TypedSubRange<Option<Pat>> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ObjectPat {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_object_pat(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::ObjectPat);
+        path.set_field(0u8);
+        let field_value = self.props(visitor.ast());
+        <TypedSubRange<ObjectPatProp> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for AssignPat {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_assign_pat(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::AssignPat);
+        path.set_field(0u8);
+        let field_value = self.left(visitor.ast());
+        <Pat as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.right(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for RestPat {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_rest_pat(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::RestPat);
+        path.set_field(0u8);
+        let field_value = self.dot_3_token(visitor.ast());
+        <Span as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.arg(visitor.ast());
+        <Pat as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for ObjectPatProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_object_pat_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::KeyValue(it) => {
+                <KeyValuePatProp as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Assign(it) => {
+                <AssignPatProp as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Rest(it) => <RestPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for KeyValuePatProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_key_value_pat_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::KeyValuePatProp);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <PropName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.value(visitor.ast());
+        <Pat as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for AssignPatProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_assign_pat_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::AssignPatProp);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <BindingIdent as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.value(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Ident {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ident(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Ident);
+        path.set_field(0u8);
+        let field_value = self.sym(visitor.ast());
+        <Utf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for IdentName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ident_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::IdentName);
+        path.set_field(0u8);
+        let field_value = self.sym(visitor.ast());
+        <Utf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for PrivateName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_private_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::PrivateName);
+        path.set_field(0u8);
+        let field_value = self.name(visitor.ast());
+        <Utf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for BindingIdent {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_binding_ident(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::BindingIdent);
+        path.set_field(0u8);
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Lit {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_lit(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Str(it) => <Str as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Bool(it) => <Bool as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Null(it) => <Null as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Num(it) => <Number as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::BigInt(it) => {
+                <BigInt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Regex(it) => <Regex as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Str {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_str(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Str);
+        path.set_field(0u8);
+        let field_value = self.value(visitor.ast());
+        <Wtf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.raw(visitor.ast());
+        <OptionalUtf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Bool {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_bool(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Bool);
+        path.set_field(0u8);
+        let field_value = self.value(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Null {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_null(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Number {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_number(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Number);
+        path.set_field(0u8);
+        let field_value = self.value(visitor.ast());
+        <f64 as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.raw(visitor.ast());
+        <OptionalUtf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for BigInt {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_big_int(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::BigInt);
+        path.set_field(0u8);
+        let field_value = self.value(visitor.ast());
+        <BigIntId as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.raw(visitor.ast());
+        <OptionalUtf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Regex {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_regex(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::Regex);
+        path.set_field(0u8);
+        let field_value = self.exp(visitor.ast());
+        <Utf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.flags(visitor.ast());
+        <Utf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXObject {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_object(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::JSXMemberExpr(it) => {
+                <JSXMemberExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Ident(it) => <Ident as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXMemberExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_member_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::JSXMemberExpr);
+        path.set_field(0u8);
+        let field_value = self.obj(visitor.ast());
+        <JSXObject as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.prop(visitor.ast());
+        <IdentName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXNamespacedName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_namespaced_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::JSXNamespacedName);
+        path.set_field(0u8);
+        let field_value = self.ns(visitor.ast());
+        <IdentName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.name(visitor.ast());
+        <IdentName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXEmptyExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_empty_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXExprContainer {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_expr_container(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::JSXExprContainer);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <JSXExpr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::JSXEmptyExpr(it) => {
+                <JSXEmptyExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Expr(it) => <Expr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXSpreadChild {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_spread_child(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::JSXSpreadChild);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXElementName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_element_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => <Ident as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::JSXMemberExpr(it) => {
+                <JSXMemberExpr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXNamespacedName(it) => {
+                <JSXNamespacedName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXOpeningElement {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_opening_element(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::JSXOpeningElement);
+        path.set_field(0u8);
+        let field_value = self.name(visitor.ast());
+        <JSXElementName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.attrs(visitor.ast());
+        <TypedSubRange<JSXAttrOrSpread> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.self_closing(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXAttrOrSpread {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_attr_or_spread(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::JSXAttr(it) => {
+                <JSXAttr as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::SpreadElement(it) => {
+                <SpreadElement as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXClosingElement {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_closing_element(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::JSXClosingElement);
+        path.set_field(0u8);
+        let field_value = self.name(visitor.ast());
+        <JSXElementName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXAttr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_attr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::JSXAttr);
+        path.set_field(0u8);
+        let field_value = self.name(visitor.ast());
+        <JSXAttrName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.value(visitor.ast());
+        <Option<JSXAttrValue> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXAttrName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_attr_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => {
+                <IdentName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXNamespacedName(it) => {
+                <JSXNamespacedName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXAttrValue {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_attr_value(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Str(it) => <Str as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::JSXExprContainer(it) => {
+                <JSXExprContainer as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXElement(it) => {
+                <JSXElement as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXFragment(it) => {
+                <JSXFragment as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXText {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_text(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::JSXText);
+        path.set_field(0u8);
+        let field_value = self.value(visitor.ast());
+        <Utf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.raw(visitor.ast());
+        <Utf8Ref as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXElement {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_element(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::JSXElement);
+        path.set_field(0u8);
+        let field_value = self.opening(visitor.ast());
+        <JSXOpeningElement as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.children(visitor.ast());
+        <TypedSubRange<JSXElementChild> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.closing(visitor.ast());
+        <Option<JSXClosingElement> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXElementChild {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_element_child(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::JSXText(it) => {
+                <JSXText as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXExprContainer(it) => {
+                <JSXExprContainer as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXSpreadChild(it) => {
+                <JSXSpreadChild as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXElement(it) => {
+                <JSXElement as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::JSXFragment(it) => {
+                <JSXFragment as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXFragment {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_fragment(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::JSXFragment);
+        path.set_field(0u8);
+        let field_value = self.opening(visitor.ast());
+        <JSXOpeningFragment as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.children(visitor.ast());
+        <TypedSubRange<JSXElementChild> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.closing(visitor.ast());
+        <JSXClosingFragment as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXOpeningFragment {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_opening_fragment(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for JSXClosingFragment {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_closing_fragment(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeAnn {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_ann(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypeAnn);
+        path.set_field(0u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeParamDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_param_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypeParamDecl);
+        path.set_field(0u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsTypeParam> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeParam {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_param(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypeParam);
+        path.set_field(0u8);
+        let field_value = self.name(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.is_in(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.is_out(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_const(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.constraint(visitor.ast());
+        <Option<TsType> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(5u8);
+        let field_value = self.default(visitor.ast());
+        <Option<TsType> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeParamInstantiation {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_param_instantiation(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypeParamInstantiation);
+        path.set_field(0u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsType> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsParamProp {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_param_prop(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsParamProp);
+        path.set_field(0u8);
+        let field_value = self.decorators(visitor.ast());
+        <TypedSubRange<Decorator> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.accessibility(visitor.ast());
+        <Option<Accessibility> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.is_override(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.param(visitor.ast());
+        <TsParamPropParam as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsParamPropParam {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_param_prop_param(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => {
+                <BindingIdent as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Assign(it) => {
+                <AssignPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsQualifiedName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_qualified_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsQualifiedName);
+        path.set_field(0u8);
+        let field_value = self.left(visitor.ast());
+        <TsEntityName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.right(visitor.ast());
+        <IdentName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsEntityName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_entity_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::TsQualifiedName(it) => {
+                <TsQualifiedName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Ident(it) => <Ident as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeElement {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_element(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::TsCallSignatureDecl(it) => {
+                <TsCallSignatureDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsConstructSignatureDecl(it) => <TsConstructSignatureDecl as VisitWithPathWith<
+                V,
+            >>::visit_with_path(it, visitor, path),
+            Self::TsPropertySignature(it) => {
+                <TsPropertySignature as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsGetterSignature(it) => {
+                <TsGetterSignature as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsSetterSignature(it) => {
+                <TsSetterSignature as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsMethodSignature(it) => {
+                <TsMethodSignature as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsIndexSignature(it) => {
+                <TsIndexSignature as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsCallSignatureDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_call_signature_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsCallSignatureDecl);
+        path.set_field(0u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsConstructSignatureDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_construct_signature_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsConstructSignatureDecl);
+        path.set_field(0u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsPropertySignature {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_property_signature(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsPropertySignature);
+        path.set_field(0u8);
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.key(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.computed(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsGetterSignature {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_getter_signature(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsGetterSignature);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.computed(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsSetterSignature {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_setter_signature(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsSetterSignature);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.computed(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.param(visitor.ast());
+        <TsFnParam as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsMethodSignature {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_method_signature(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsMethodSignature);
+        path.set_field(0u8);
+        let field_value = self.key(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.computed(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.optional(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(4u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(5u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsIndexSignature {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_index_signature(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsIndexSignature);
+        path.set_field(0u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_static(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::TsKeywordType(it) => {
+                <TsKeywordType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsThisType(it) => {
+                <TsThisType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsFnOrConstructorType(it) => {
+                <TsFnOrConstructorType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsTypeRef(it) => {
+                <TsTypeRef as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsTypeQuery(it) => {
+                <TsTypeQuery as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsTypeLit(it) => {
+                <TsTypeLit as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsArrayType(it) => {
+                <TsArrayType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsTupleType(it) => {
+                <TsTupleType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsOptionalType(it) => {
+                <TsOptionalType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsRestType(it) => {
+                <TsRestType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsUnionOrIntersectionType(it) => {
+                <TsUnionOrIntersectionType as VisitWithPathWith<V>>::visit_with_path(
+                    it, visitor, path,
+                )
+            }
+            Self::TsConditionalType(it) => {
+                <TsConditionalType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsInferType(it) => {
+                <TsInferType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsParenthesizedType(it) => {
+                <TsParenthesizedType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsTypeOperator(it) => {
+                <TsTypeOperator as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsIndexedAccessType(it) => {
+                <TsIndexedAccessType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsMappedType(it) => {
+                <TsMappedType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsLitType(it) => {
+                <TsLitType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsTypePredicate(it) => {
+                <TsTypePredicate as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsImportType(it) => {
+                <TsImportType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsFnOrConstructorType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_fn_or_constructor_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::TsFnType(it) => {
+                <TsFnType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsConstructorType(it) => {
+                <TsConstructorType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsKeywordType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_keyword_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsKeywordType);
+        path.set_field(0u8);
+        let field_value = self.kind(visitor.ast());
+        <TsKeywordTypeKind as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsThisType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_this_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {}
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsFnParam {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_fn_param(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => {
+                <BindingIdent as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Array(it) => {
+                <ArrayPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Rest(it) => <RestPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Object(it) => {
+                <ObjectPat as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsFnType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_fn_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsFnType);
+        path.set_field(0u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsTypeAnn as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsConstructorType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_constructor_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsConstructorType);
+        path.set_field(0u8);
+        let field_value = self.params(visitor.ast());
+        <TypedSubRange<TsFnParam> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(2u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsTypeAnn as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.is_abstract(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeRef {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_ref(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypeRef);
+        path.set_field(0u8);
+        let field_value = self.type_name(visitor.ast());
+        <TsEntityName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypePredicate {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_predicate(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypePredicate);
+        path.set_field(0u8);
+        let field_value = self.asserts(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.param_name(visitor.ast());
+        <TsThisTypeOrIdent as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsTypeAnn> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsThisTypeOrIdent {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_this_type_or_ident(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::TsThisType(it) => {
+                <TsThisType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Ident(it) => <Ident as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeQuery {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_query(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypeQuery);
+        path.set_field(0u8);
+        let field_value = self.expr_name(visitor.ast());
+        <TsTypeQueryExpr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeQueryExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_query_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::TsEntityName(it) => {
+                <TsEntityName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Import(it) => {
+                <TsImportType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsImportCallOptions {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_import_call_options(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsImportCallOptions);
+        path.set_field(0u8);
+        let field_value = self.with(visitor.ast());
+        <ObjectLit as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsImportType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_import_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsImportType);
+        path.set_field(0u8);
+        let field_value = self.arg(visitor.ast());
+        <Str as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.qualifier(visitor.ast());
+        <Option<TsEntityName> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(3u8);
+        let field_value = self.attributes(visitor.ast());
+        <Option<TsImportCallOptions> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeLit {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_lit(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypeLit);
+        path.set_field(0u8);
+        let field_value = self.members(visitor.ast());
+        <TypedSubRange<TsTypeElement> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsArrayType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_array_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsArrayType);
+        path.set_field(0u8);
+        let field_value = self.elem_type(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTupleType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_tuple_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTupleType);
+        path.set_field(0u8);
+        let field_value = self.elem_types(visitor.ast());
+        <TypedSubRange<TsTupleElement> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTupleElement {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_tuple_element(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTupleElement);
+        path.set_field(0u8);
+        let field_value = self.label(visitor.ast());
+        <Option<Pat> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.ty(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsOptionalType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_optional_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsOptionalType);
+        path.set_field(0u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsRestType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_rest_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsRestType);
+        path.set_field(0u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsUnionOrIntersectionType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_union_or_intersection_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::TsUnionType(it) => {
+                <TsUnionType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsIntersectionType(it) => {
+                <TsIntersectionType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsUnionType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_union_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsUnionType);
+        path.set_field(0u8);
+        let field_value = self.types(visitor.ast());
+        <TypedSubRange<TsType> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsIntersectionType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_intersection_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsIntersectionType);
+        path.set_field(0u8);
+        let field_value = self.types(visitor.ast());
+        <TypedSubRange<TsType> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsConditionalType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_conditional_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsConditionalType);
+        path.set_field(0u8);
+        let field_value = self.check_type(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.extends_type(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.true_type(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.false_type(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsInferType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_infer_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsInferType);
+        path.set_field(0u8);
+        let field_value = self.type_param(visitor.ast());
+        <TsTypeParam as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsParenthesizedType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_parenthesized_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsParenthesizedType);
+        path.set_field(0u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeOperator {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_operator(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypeOperator);
+        path.set_field(0u8);
+        let field_value = self.op(visitor.ast());
+        <TsTypeOperatorOp as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsIndexedAccessType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_indexed_access_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsIndexedAccessType);
+        path.set_field(0u8);
+        let field_value = self.readonly(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.obj_type(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.index_type(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsMappedType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_mapped_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsMappedType);
+        path.set_field(0u8);
+        let field_value = self.readonly(visitor.ast());
+        <Option<TruePlusMinus> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.type_param(visitor.ast());
+        <TsTypeParam as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.name_type(visitor.ast());
+        <Option<TsType> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.optional(visitor.ast());
+        <Option<TruePlusMinus> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(4u8);
+        let field_value = self.type_ann(visitor.ast());
+        <Option<TsType> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsLitType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_lit_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsLitType);
+        path.set_field(0u8);
+        let field_value = self.lit(visitor.ast());
+        <TsLit as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsLit {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_lit(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Number(it) => {
+                <Number as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Str(it) => <Str as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Bool(it) => <Bool as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::BigInt(it) => {
+                <BigInt as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::Tpl(it) => {
+                <TsTplLitType as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTplLitType {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_tpl_lit_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTplLitType);
+        path.set_field(0u8);
+        let field_value = self.types(visitor.ast());
+        <TypedSubRange<TsType> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(1u8);
+        let field_value = self.quasis(visitor.ast());
+        <TypedSubRange<TplElement> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsInterfaceDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_interface_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsInterfaceDecl);
+        path.set_field(0u8);
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(3u8);
+        let field_value = self.extends(visitor.ast());
+        <TypedSubRange<TsExprWithTypeArgs> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(4u8);
+        let field_value = self.body(visitor.ast());
+        <TsInterfaceBody as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsInterfaceBody {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_interface_body(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsInterfaceBody);
+        path.set_field(0u8);
+        let field_value = self.body(visitor.ast());
+        <TypedSubRange<TsTypeElement> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsExprWithTypeArgs {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_expr_with_type_args(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsExprWithTypeArgs);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_args(visitor.ast());
+        <Option<TsTypeParamInstantiation> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeAliasDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_alias_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypeAliasDecl);
+        path.set_field(0u8);
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.type_params(visitor.ast());
+        <Option<TsTypeParamDecl> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.set_field(3u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsEnumDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_enum_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsEnumDecl);
+        path.set_field(0u8);
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.is_const(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.members(visitor.ast());
+        <TypedSubRange<TsEnumMember> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsEnumMember {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_enum_member(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsEnumMember);
+        path.set_field(0u8);
+        let field_value = self.id(visitor.ast());
+        <TsEnumMemberId as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.init(visitor.ast());
+        <Option<Expr> as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsEnumMemberId {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_enum_member_id(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => <Ident as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Str(it) => <Str as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsModuleDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_module_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsModuleDecl);
+        path.set_field(0u8);
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.global(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.namespace(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.id(visitor.ast());
+        <TsModuleName as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(4u8);
+        let field_value = self.body(visitor.ast());
+        <Option<TsNamespaceBody> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsNamespaceBody {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_namespace_body(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::TsModuleBlock(it) => {
+                <TsModuleBlock as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsNamespaceDecl(it) => {
+                <TsNamespaceDecl as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsModuleBlock {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_module_block(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsModuleBlock);
+        path.set_field(0u8);
+        let field_value = self.body(visitor.ast());
+        <TypedSubRange<ModuleItem> as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsNamespaceDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_namespace_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsNamespaceDecl);
+        path.set_field(0u8);
+        let field_value = self.declare(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.global(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.body(visitor.ast());
+        <TsNamespaceBody as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsModuleName {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_module_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::Ident(it) => <Ident as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+            Self::Str(it) => <Str as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsImportEqualsDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_import_equals_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsImportEqualsDecl);
+        path.set_field(0u8);
+        let field_value = self.is_export(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.is_type_only(visitor.ast());
+        <bool as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(2u8);
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(3u8);
+        let field_value = self.module_ref(visitor.ast());
+        <TsModuleRef as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsModuleRef {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_module_ref(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Self::TsEntityName(it) => {
+                <TsEntityName as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+            Self::TsExternalModuleRef(it) => {
+                <TsExternalModuleRef as VisitWithPathWith<V>>::visit_with_path(it, visitor, path)
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsExternalModuleRef {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_external_module_ref(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsExternalModuleRef);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Str as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsExportAssignment {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_export_assignment(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsExportAssignment);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsNamespaceExportDecl {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_namespace_export_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsNamespaceExportDecl);
+        path.set_field(0u8);
+        let field_value = self.id(visitor.ast());
+        <Ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsAsExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_as_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsAsExpr);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsTypeAssertion {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_assertion(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsTypeAssertion);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsNonNullExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_non_null_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsNonNullExpr);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsSatisfiesExpr {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_satisfies_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsSatisfiesExpr);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_ann(visitor.ast());
+        <TsType as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsConstAssertion {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_const_assertion(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsConstAssertion);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TsInstantiation {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_instantiation(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        path.push(self.node_id(), NodeKind::TsInstantiation);
+        path.set_field(0u8);
+        let field_value = self.expr(visitor.ast());
+        <Expr as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+        path.set_field(1u8);
+        let field_value = self.type_args(visitor.ast());
+        <TsTypeParamInstantiation as VisitWithPathWith<V>>::visit_with_path(
+            field_value,
+            visitor,
+            path,
+        );
+        path.pop();
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<ModuleItem> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_module_items(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<Stmt> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_stmts(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<ImportSpecifier> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_import_specifiers(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<ObjectLit> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_object_lit(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<ModuleExportName> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_module_export_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<ExportSpecifier> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_export_specifiers(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<Str> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_str(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<Expr> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<Ident> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_ident(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<Stmt> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<SwitchCase> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_switch_cases(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<CatchClause> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_catch_clause(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<BlockStmt> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_block_stmt(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<VarDeclOrExpr> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_var_decl_or_expr(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<Pat> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_pat(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<VarDeclarator> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_var_declarators(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<ExprOrSpread> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_expr_or_spread(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<Option<ExprOrSpread>> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_vec_expr_or_spreads(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<PropOrSpread> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_prop_or_spreads(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<ExprOrSpread> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_expr_or_spreads(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<TsTypeParamInstantiation> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_ts_type_param_instantiation(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<TypedSubRange<ExprOrSpread>> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_expr_or_spreads(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<Expr> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_exprs(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<Pat> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_pats(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<TsTypeParamDecl> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_ts_type_param_decl(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<TsTypeAnn> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_ts_type_ann(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<TplElement> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_tpl_elements(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<SpreadDot3Token> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_spread_dot_3_token(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<Param> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_params(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<Decorator> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_decorators(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<ClassMember> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_class_members(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<TsExprWithTypeArgs> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_expr_with_type_argss(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<Accessibility> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_accessibility(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<ParamOrTsParamProp> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_param_or_ts_param_props(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<Option<Pat>> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_vec_pats(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<ObjectPatProp> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_object_pat_props(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<JSXAttrOrSpread> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_attr_or_spreads(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<JSXAttrValue> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_jsx_attr_value(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<JSXElementChild> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_jsx_element_childs(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<JSXClosingElement> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_jsx_closing_element(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<TsTypeParam> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_params(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<TsType> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_ts_type(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<TsType> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_types(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<TsFnParam> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_fn_params(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<TsEntityName> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_ts_entity_name(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<TsImportCallOptions> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_ts_import_call_options(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<TsTypeElement> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_type_elements(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<TsTupleElement> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_tuple_elements(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<TruePlusMinus> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_true_plus_minus(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for TypedSubRange<TsEnumMember> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_ts_enum_members(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        for (index, child_idx) in self.iter().enumerate() {
+            path.set_index(Some(index as u32));
+            let child = visitor.ast().get_node_in_sub_range(child_idx);
+            child.visit_with_path(visitor, path);
+        }
+        path.set_index(None);
+    }
+}
+impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for Option<TsNamespaceBody> {
+    fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        <V as VisitWithPath>::visit_opt_ts_namespace_body(visitor, self, path)
+    }
+    fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+        match self {
+            Some(it) => it.visit_with_path(visitor, path),
+            None => {}
+        }
+    }
+}
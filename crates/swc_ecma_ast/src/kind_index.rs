@@ -0,0 +1,102 @@
+//! Queries of the nodes of a kind, which scan [Ast::nodes] instead of
+//! visiting the tree.
+
+use crate::{Ast, NodeId, NodeIdTrait, NodeKind, NodeSchema, StructNode};
+
+impl Ast {
+    /// Returns the nodes of `T`, in the order they were added to the arena.
+    ///
+    /// The whole arena is scanned, so it includes the nodes which are no
+    /// longer reachable from the root, e.g. the ones replaced by a transform.
+    /// Use [KindIndex] to only get the reachable ones.
+    ///
+    /// Only node structs can be queried, since the kinds of an enum like
+    /// [crate::Expr] are also used outside of its positions.
+    pub fn nodes_of_kind<T: StructNode>(&self) -> impl Iterator<Item = T> + '_ {
+        self.nodes
+            .kinds()
+            .zip(self.nodes.indices())
+            .filter(|&(kind, _)| kind == T::KIND)
+            .map(|(_, node_id)| unsafe { T::from_node_id_unchecked(node_id, self) })
+    }
+}
+
+/// The nodes reachable from a root, grouped by their kinds.
+///
+/// The index isn't updated when the AST is modified, it should be built again.
+pub struct KindIndex {
+    /// The nodes of each kind in DFS pre-order, indexed by
+    /// [NodeKind::schema_index].
+    nodes: Vec<Vec<NodeId>>,
+}
+
+impl KindIndex {
+    pub fn new<N: NodeIdTrait>(root: N, ast: &Ast) -> Self {
+        let mut nodes = vec![Vec::new(); NodeSchema::all().len()];
+        let mut visited = vec![false; ast.node_count()];
+
+        let mut stack = vec![root.node_id()];
+        let mut children = Vec::new();
+        while let Some(node_id) = stack.pop() {
+            // A node may be shared by several parents.
+            if std::mem::replace(&mut visited[node_id.index()], true) {
+                continue;
+            }
+            nodes[ast.nodes.kind(node_id).schema_index()].push(node_id);
+
+            children.extend(ast.children(node_id));
+            stack.extend(children.drain(..).rev());
+        }
+
+        Self { nodes }
+    }
+
+    /// Returns the reachable nodes of `kind`, in DFS pre-order.
+    #[inline]
+    pub fn get(&self, kind: NodeKind) -> &[NodeId] {
+        &self.nodes[kind.schema_index()]
+    }
+
+    /// Returns the reachable nodes of `T`, in DFS pre-order.
+    ///
+    /// Like [Ast::nodes_of_kind], only node structs can be queried.
+    pub fn nodes<'a, T: StructNode>(&'a self, ast: &'a Ast) -> impl Iterator<Item = T> + 'a {
+        self.get(T::KIND)
+            .iter()
+            .map(|&node_id| unsafe { T::from_node_id_unchecked(node_id, ast) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::DUMMY_SP;
+
+    use super::*;
+    use crate::{Expr, Ident, ParenExpr, StringAllocator};
+
+    #[test]
+    fn test_orphaned_nodes() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let sym = ast.add_utf8("a");
+        let a = ast.expr_ident(DUMMY_SP, sym, false);
+        let paren = ast.expr_paren_expr(DUMMY_SP, a);
+        let Expr::Paren(paren) = paren else {
+            unreachable!()
+        };
+        // `(a)` is replaced by `a`, as `remove_paren` does.
+        let root = ast.expr_stmt(DUMMY_SP, paren.expr(&ast));
+
+        assert_eq!(ast.nodes_of_kind::<ParenExpr>().count(), 1);
+        assert_eq!(ast.nodes_of_kind::<Ident>().count(), 1);
+
+        let index = KindIndex::new(root, &ast);
+        assert_eq!(index.nodes::<ParenExpr>(&ast).count(), 0);
+        assert_eq!(index.get(NodeKind::Ident).len(), 1);
+        assert_eq!(
+            index.nodes::<Ident>(&ast).next(),
+            ast.nodes_of_kind().next()
+        );
+    }
+}
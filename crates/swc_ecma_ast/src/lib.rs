@@ -1,18 +1,37 @@
 mod assert_layout;
 mod ast;
 mod atom;
+mod bytes;
+mod comments;
 mod common;
+mod compact;
 mod derive;
+mod frozen;
+mod kind_index;
 mod node_id;
+mod nodes;
+mod path;
+mod schema;
+mod stack;
+mod validate;
+mod view;
 mod visit;
+mod walk;
 
 mod generated {
     mod ast_builder;
     mod ast_clone_in;
+    mod ast_clone_into;
+    mod ast_dump;
+    mod ast_eq_ignore_span;
     pub(crate) mod ast_extra_compact;
     mod ast_node_id;
     mod ast_property;
+    pub(crate) mod ast_schema;
     pub(crate) mod ast_visitor;
+    pub(crate) mod ast_visitor_control;
+    pub(crate) mod ast_visitor_hooks;
+    pub(crate) mod ast_visitor_path;
 }
 
 use std::{marker::PhantomData, rc::Rc};
@@ -25,20 +44,35 @@ use oxc_index::IndexVec;
 
 pub use ast::*;
 pub use atom::*;
+pub use bytes::{AstDecodeError, AstEncodeError};
+pub use comments::{Comment, CommentPlacement, CommentsIter};
 pub use common::*;
+pub use compact::NodeIdRemap;
 pub use derive::*;
+pub use frozen::FrozenAst;
 pub use generated::ast_visitor::*;
+pub use generated::ast_visitor_control::*;
+pub use generated::ast_visitor_hooks::*;
+pub use generated::ast_visitor_path::*;
+pub use kind_index::KindIndex;
 pub use node_id::{
-    BigIntId, ExtraDataCompact, ExtraDataId, NodeId, NodeIdTrait, OptionalNodeId, SubRange,
-    TypedSubRange,
+    BigIntId, ExtraDataCompact, ExtraDataId, NodeExtraDataId, NodeId, NodeIdTrait, OptionalNodeId,
+    StructNode, SubRange, SubRangeEditor, TypedSubRange,
 };
+pub use path::{AstNodePath, AstNodePathBuilder, AstPathEntry};
+pub use schema::{Children, EnumType, FieldLocation, FieldSchema, FieldType, NodeSchema, NodeType};
+pub use stack::maybe_grow;
+pub use validate::AstValidationError;
+pub use view::{AstView, FieldValue, ListView};
+pub use visit::{HooksVisitor, VisitFlow};
+pub use walk::{Walk, WalkEvent};
 
-use crate::node_id::OptionalSubRange;
+use crate::{comments::AstComments, node_id::OptionalSubRange, nodes::Nodes};
 
 /// AST context that stores everything about the flattening AST.
 pub struct Ast {
     /// Flattening AST nodes.
-    nodes: IndexVec<NodeId, AstNode>,
+    nodes: Nodes,
 
     /// Flattening AST fields.
     extra_data: IndexVec<ExtraDataId, ExtraData>,
@@ -49,6 +83,9 @@ pub struct Ast {
 
     /// The AST doesn't directly store strings (UTF-8 and WTF-8).
     string_allocator: Rc<StringAllocator>,
+
+    /// Comments attached to the nodes.
+    comments: AstComments,
 }
 
 /// A 24-bit unsigned integer stored as 3 bytes.
@@ -78,7 +115,7 @@ impl From<U24> for u32 {
 }
 
 /// Untyped AST node
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 pub struct AstNode {
     span: Span,
     kind: NodeKind,
@@ -177,6 +214,19 @@ pub union ExtraData {
     jsx_attr_or_spread: JSXAttrOrSpread,
     jsx_attr_value: JSXAttrValue,
     jsx_element_child: JSXElementChild,
+    ts_param_prop_param: TsParamPropParam,
+    ts_entity_name: TsEntityName,
+    ts_type_element: TsTypeElement,
+    ts_fn_or_constructor_type: TsFnOrConstructorType,
+    ts_fn_param: TsFnParam,
+    ts_this_type_or_ident: TsThisTypeOrIdent,
+    ts_type_query_expr: TsTypeQueryExpr,
+    ts_union_or_intersection_type: TsUnionOrIntersectionType,
+    ts_lit: TsLit,
+    ts_enum_member_id: TsEnumMemberId,
+    ts_namespace_body: TsNamespaceBody,
+    ts_module_name: TsModuleName,
+    ts_module_ref: TsModuleRef,
 
     optional_expr: Option<Expr>,
     optional_pat: Option<Pat>,
@@ -184,6 +234,8 @@ pub union ExtraData {
     optional_module_export_name: Option<ModuleExportName>,
     optional_var_decl_or_expr: Option<VarDeclOrExpr>,
     optional_jsx_attr_value: Option<JSXAttrValue>,
+    optional_ts_entity_name: Option<TsEntityName>,
+    optional_ts_namespace_body: Option<TsNamespaceBody>,
 
     /// Any other data (usually enum) that can be representated within 8 bytes
     other: u64,
@@ -383,7 +435,7 @@ pub enum NodeKind {
     TsNamespaceDecl,
     TsImportEqualsDecl,
     TsExternalModuleRef,
-    TsExportAssginment,
+    TsExportAssignment,
     TsNamespaceExportDecl,
     TsAsExpr,
     TsTypeAssertion,
@@ -398,10 +450,11 @@ impl Ast {
     pub fn new(source_len: usize, string_allocator: Rc<StringAllocator>) -> Self {
         let empirical_capacity = (source_len as f64 * 0.15) as usize;
         Self {
-            nodes: IndexVec::with_capacity(empirical_capacity),
+            nodes: Nodes::with_capacity(empirical_capacity),
             extra_data: IndexVec::with_capacity(empirical_capacity * 2),
             bigint: IndexVec::new(),
             string_allocator,
+            comments: AstComments::default(),
         }
     }
 
@@ -499,28 +552,55 @@ impl Ast {
 }
 
 impl Ast {
-    /// Get a reference to a node in the arena without boundary check.
+    /// Get a node in the arena without boundary check.
     ///
     /// # Safety
     /// 1. The node_id must be valid.
-    pub unsafe fn get_node_unchecked(&self, node_id: NodeId) -> &AstNode {
-        debug_assert!(node_id.index() < self.nodes.len());
-        unsafe { self.nodes.as_raw_slice().get_unchecked(node_id.index()) }
+    #[inline]
+    pub unsafe fn get_node_unchecked(&self, node_id: NodeId) -> AstNode {
+        unsafe { self.nodes.get_unchecked(node_id) }
     }
 
-    /// Get a mutable reference to a node in the arena without boundary check.
-    ///
-    /// # Safety
-    /// 1. The node_id must be valid.
-    pub(crate) unsafe fn get_node_unchecked_mut(&mut self, node_id: NodeId) -> &mut AstNode {
-        unsafe {
-            self.nodes
-                .as_raw_slice_mut()
-                .get_unchecked_mut(node_id.index())
-        }
+    /// Get a node in the arena, or `None` if `node_id` is out of bounds.
+    #[inline]
+    pub fn get_node(&self, node_id: NodeId) -> Option<AstNode> {
+        self.nodes.get(node_id)
     }
 
     pub fn node_count(&self) -> usize {
         self.nodes.len()
     }
+
+    /// Returns the current size of the arena, to drop everything added after
+    /// it with [Ast::truncate].
+    #[inline]
+    pub fn checkpoint(&self) -> AstCheckpoint {
+        AstCheckpoint {
+            nodes: self.nodes.len(),
+            extra_data: self.extra_data.len(),
+            bigint: self.bigint.len(),
+        }
+    }
+
+    /// Drops the nodes, lists and big integers added after `checkpoint`, and
+    /// the comments attached to the dropped nodes.
+    ///
+    /// # Safety
+    /// Nothing which is kept may refer to what's dropped, neither the nodes
+    /// added before `checkpoint` nor the ids held by the caller.
+    pub unsafe fn truncate(&mut self, checkpoint: AstCheckpoint) {
+        debug_assert!(checkpoint.nodes <= self.nodes.len());
+        self.nodes.truncate(checkpoint.nodes);
+        self.extra_data.truncate(checkpoint.extra_data);
+        self.bigint.truncate(checkpoint.bigint);
+        self.comments.truncate(checkpoint.nodes);
+    }
+}
+
+/// The size of an [Ast] at some point, see [Ast::checkpoint].
+#[derive(Debug, Clone, Copy)]
+pub struct AstCheckpoint {
+    nodes: usize,
+    extra_data: usize,
+    bigint: usize,
 }
@@ -1,10 +1,12 @@
 mod extra_data;
 mod node;
 mod sub_range;
+mod sub_range_edit;
 
 pub use extra_data::*;
 pub use node::*;
 pub use sub_range::*;
+pub use sub_range_edit::*;
 
 #[macro_export]
 macro_rules! define_optional_index_type {
@@ -1,4 +1,4 @@
-use crate::Ast;
+use crate::{Ast, NodeKind};
 
 pub trait NodeIdTrait {
     /// Get node id from node
@@ -10,6 +10,32 @@ pub trait NodeIdTrait {
     /// # Safety
     /// 1. The caller should ensure that `node.kind` is corresponding to `Self`
     unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self;
+
+    /// Returns `true` if a node of `kind` can be constructed as `Self`
+    fn is_kind(kind: NodeKind) -> bool;
+
+    /// Construct typed ast node, or return `None` if `node.kind` is not
+    /// corresponding to `Self`
+    #[inline]
+    fn try_from_node_id(id: NodeId, ast: &Ast) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if Self::is_kind(ast.nodes.kind(id)) {
+            Some(unsafe { Self::from_node_id_unchecked(id, ast) })
+        } else {
+            None
+        }
+    }
+}
+
+/// A node struct, e.g. [crate::Ident], whose nodes are all of one kind.
+///
+/// Enums like [crate::Expr] don't implement it: a node of one of their kinds
+/// isn't necessarily in their position, e.g. a binding [crate::Ident] isn't an
+/// expression.
+pub trait StructNode: NodeIdTrait {
+    const KIND: NodeKind;
 }
 
 oxc_index::define_index_type! {
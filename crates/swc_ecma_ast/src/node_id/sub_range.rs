@@ -242,7 +242,7 @@ impl<T: ExtraDataCompact> DoubleEndedIterator for TypedSubRangeIterator<T> {
 /// It's type safe and can be used to access nodes in the AST context.
 #[derive(Debug, Clone, Copy)]
 pub struct NodeExtraDataId<T> {
-    inner: ExtraDataId,
+    pub(crate) inner: ExtraDataId,
     _phantom: PhantomData<T>,
 }
 
@@ -252,8 +252,8 @@ impl Ast {
         unsafe { T::from_extra_data(self.extra_data[id.inner], self) }
     }
 
-    pub fn get_raw_node_in_sub_range<T>(&self, id: NodeExtraDataId<T>) -> &AstNode {
+    pub fn get_raw_node_in_sub_range<T>(&self, id: NodeExtraDataId<T>) -> AstNode {
         let node_id = unsafe { self.extra_data[id.inner].node };
-        &self.nodes[node_id]
+        self.nodes.get(node_id).unwrap()
     }
 }
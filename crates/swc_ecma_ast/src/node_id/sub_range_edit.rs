@@ -0,0 +1,258 @@
+use std::ops::Range;
+
+use crate::{
+    Ast, ExtraData,
+    node_id::{ExtraDataCompact, TypedSubRange},
+};
+
+/// Collects the edits of a [TypedSubRange] and commits them as a new range.
+///
+/// The indices always refer to the elements of the original range, so the
+/// edits can be made while iterating it. Elements inserted at the same
+/// position keep the order of the calls.
+///
+/// [Ast::extra_data] is append-only, so the edited elements are written once
+/// on [SubRangeEditor::commit]. The original elements are left in place,
+/// since other nodes may still refer to them.
+///
+/// ```ignore
+/// let mut editor = block.stmts(&ast).edit();
+/// editor.insert_before(0, use_strict);
+/// editor.remove(2);
+/// let stmts = editor.commit(&mut ast);
+/// block.set_stmts(&mut ast, stmts);
+/// ```
+pub struct SubRangeEditor<T> {
+    range: TypedSubRange<T>,
+    /// The state of each original element, empty until one is removed or
+    /// replaced.
+    slots: Vec<Slot<T>>,
+    /// The inserted elements with the index of the original element they are
+    /// inserted before. The index is the length when inserted at the end.
+    inserted: Vec<(usize, T)>,
+}
+
+enum Slot<T> {
+    Keep,
+    Remove,
+    Replace(T),
+}
+
+impl<T: ExtraDataCompact> TypedSubRange<T> {
+    /// Starts editing the elements of this range, see [SubRangeEditor].
+    #[inline]
+    pub fn edit(self) -> SubRangeEditor<T> {
+        SubRangeEditor {
+            range: self,
+            slots: Vec::new(),
+            inserted: Vec::new(),
+        }
+    }
+}
+
+impl<T: ExtraDataCompact> SubRangeEditor<T> {
+    /// Returns the length of the original range.
+    #[inline]
+    pub fn original_len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Inserts `node` before the original element at `index`, or at the end
+    /// if `index` is the length.
+    pub fn insert_before(&mut self, index: usize, node: T) {
+        assert!(
+            index <= self.range.len(),
+            "Insertion index {} is larger than the length {}",
+            index,
+            self.range.len()
+        );
+        self.inserted.push((index, node));
+    }
+
+    /// Inserts `node` after the original element at `index`.
+    #[inline]
+    pub fn insert_after(&mut self, index: usize, node: T) {
+        self.insert_before(index + 1, node);
+    }
+
+    #[inline]
+    pub fn prepend(&mut self, node: T) {
+        self.insert_before(0, node);
+    }
+
+    #[inline]
+    pub fn push(&mut self, node: T) {
+        self.insert_before(self.range.len(), node);
+    }
+
+    /// Removes the original element at `index`.
+    #[inline]
+    pub fn remove(&mut self, index: usize) {
+        *self.slot_mut(index) = Slot::Remove;
+    }
+
+    /// Replaces the original element at `index` with `node`.
+    #[inline]
+    pub fn replace(&mut self, index: usize, node: T) {
+        *self.slot_mut(index) = Slot::Replace(node);
+    }
+
+    /// Removes the original elements in `range` and inserts `nodes` in their
+    /// place.
+    pub fn splice<I: IntoIterator<Item = T>>(&mut self, range: Range<usize>, nodes: I) {
+        assert!(
+            range.start <= range.end && range.end <= self.range.len(),
+            "Splice range {:?} is out of the length {}",
+            range,
+            self.range.len()
+        );
+        for index in range.clone() {
+            self.remove(index);
+        }
+        self.inserted
+            .extend(nodes.into_iter().map(|node| (range.start, node)));
+    }
+
+    /// Only keeps the original elements for which `f` returns `true`. The
+    /// replaced elements are passed instead of the original ones, and the
+    /// removed and inserted elements are skipped.
+    pub fn retain(&mut self, ast: &Ast, mut f: impl FnMut(&T) -> bool) {
+        for (index, id) in self.range.iter().enumerate() {
+            let keep = match self.slots.get(index) {
+                Some(Slot::Remove) => continue,
+                Some(Slot::Replace(node)) => f(node),
+                Some(Slot::Keep) | None => f(&ast.get_node_in_sub_range(id)),
+            };
+            if !keep {
+                self.remove(index);
+            }
+        }
+    }
+
+    /// Writes the edited elements and returns the new range.
+    ///
+    /// The elements are always copied, even if the range is unchanged, so the
+    /// new range never shares [Ast::extra_data] with the original one, and
+    /// [TypedSubRange::replace_slot] on one of them leaves the other as is.
+    pub fn commit(mut self, ast: &mut Ast) -> TypedSubRange<T> {
+        // Sort by the position only, so the order of the calls is kept.
+        self.inserted.sort_by_key(|(index, _)| *index);
+
+        let len = self.range.len();
+        let mut data = Vec::<ExtraData>::with_capacity(len + self.inserted.len());
+        let mut inserted = self.inserted.into_iter().peekable();
+        let mut slots = self.slots.into_iter();
+        for (index, id) in self.range.iter().enumerate() {
+            while let Some((_, node)) = inserted.next_if(|(at, _)| *at == index) {
+                data.push(node.to_extra_data());
+            }
+            match slots.next() {
+                Some(Slot::Remove) => {}
+                Some(Slot::Replace(node)) => data.push(node.to_extra_data()),
+                Some(Slot::Keep) | None => data.push(ast.extra_data[id.inner]),
+            }
+        }
+        data.extend(inserted.map(|(_, node)| node.to_extra_data()));
+
+        let range = ast.add_typed_sub_range(data);
+        // SAFETY: The elements are all of type `T`.
+        unsafe { range.inner.cast_to_typed() }
+    }
+
+    fn slot_mut(&mut self, index: usize) -> &mut Slot<T> {
+        assert!(
+            index < self.range.len(),
+            "Index {} is out of the length {}",
+            index,
+            self.range.len()
+        );
+        if self.slots.is_empty() {
+            self.slots.resize_with(self.range.len(), || Slot::Keep);
+        }
+        &mut self.slots[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::DUMMY_SP;
+
+    use super::*;
+    use crate::{Expr, StringAllocator};
+
+    fn ident(ast: &mut Ast, sym: &str) -> Expr {
+        let sym = ast.add_utf8(sym);
+        ast.expr_ident(DUMMY_SP, sym, false)
+    }
+
+    fn names(ast: &Ast, range: TypedSubRange<Expr>) -> Vec<&str> {
+        range
+            .iter()
+            .map(|id| match ast.get_node_in_sub_range(id) {
+                Expr::Ident(ident) => ast.get_utf8(ident.sym(ast)),
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_edit() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let exprs = ["a", "b", "c", "d"].map(|sym| ident(&mut ast, sym));
+        let range = ast.add_typed_sub_range(exprs);
+        let x = ident(&mut ast, "x");
+        let y = ident(&mut ast, "y");
+        let z = ident(&mut ast, "z");
+
+        let mut editor = range.edit();
+        editor.insert_after(0, x);
+        editor.insert_before(1, y);
+        editor.replace(1, z);
+        editor.remove(2);
+        editor.prepend(z);
+        editor.push(x);
+        editor.retain(&ast, |expr| *expr != exprs[3]);
+        let edited = editor.commit(&mut ast);
+
+        assert_eq!(names(&ast, edited), ["z", "a", "x", "y", "z", "x"]);
+        assert_eq!(names(&ast, range), ["a", "b", "c", "d"]);
+
+        let mut editor = range.edit();
+        editor.splice(1..3, [y, z]);
+        let edited = editor.commit(&mut ast);
+        assert_eq!(names(&ast, edited), ["a", "y", "z", "d"]);
+    }
+
+    #[test]
+    fn test_commit_copies() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let exprs = ["a", "b", "c", "d"].map(|sym| ident(&mut ast, sym));
+        let x = ident(&mut ast, "x");
+        let range = ast.add_typed_sub_range(exprs);
+
+        let unchanged = range.edit().commit(&mut ast);
+        assert_ne!(unchanged.start, range.start);
+
+        let mut editor = range.edit();
+        editor.remove(0);
+        editor.remove(3);
+        let trimmed = editor.commit(&mut ast);
+        assert_eq!(names(&ast, trimmed), ["b", "c"]);
+
+        let mut editor = trimmed.edit();
+        editor.push(x);
+        let appended = editor.commit(&mut ast);
+        assert_eq!(names(&ast, appended), ["b", "c", "x"]);
+
+        for edited in [unchanged, trimmed, appended] {
+            let first = edited.get(0).unwrap();
+            edited.replace_slot(&mut ast, first, x);
+        }
+        assert_eq!(names(&ast, range), ["a", "b", "c", "d"]);
+        assert_eq!(names(&ast, unchanged), ["x", "b", "c", "d"]);
+        assert_eq!(names(&ast, trimmed), ["x", "c"]);
+        assert_eq!(names(&ast, appended), ["x", "c", "x"]);
+    }
+}
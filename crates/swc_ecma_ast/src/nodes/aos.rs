@@ -0,0 +1,130 @@
+use oxc_index::IndexVec;
+use swc_core::common::Span;
+
+use crate::{AstNode, NodeData, NodeId, NodeKind, U24};
+
+pub(crate) struct Nodes {
+    nodes: IndexVec<NodeId, AstNode>,
+}
+
+impl Nodes {
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: IndexVec::with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, node: AstNode) -> NodeId {
+        self.nodes.push(node)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.nodes.truncate(len);
+    }
+
+    #[inline]
+    pub fn get(&self, node_id: NodeId) -> Option<AstNode> {
+        self.nodes.get(node_id).copied()
+    }
+
+    #[inline]
+    pub fn set(&mut self, node_id: NodeId, node: AstNode) {
+        self.nodes[node_id] = node;
+    }
+
+    #[inline]
+    pub fn kind(&self, node_id: NodeId) -> NodeKind {
+        self.nodes[node_id].kind
+    }
+
+    #[inline]
+    pub fn indices(&self) -> impl Iterator<Item = NodeId> + use<> {
+        self.nodes.indices()
+    }
+
+    #[inline]
+    pub fn kinds(&self) -> impl Iterator<Item = NodeKind> + '_ {
+        self.nodes.iter().map(|node| node.kind)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = AstNode> + '_ {
+        self.nodes.iter().copied()
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, node_id: NodeId) -> AstNode {
+        debug_assert!(node_id.index() < self.nodes.len());
+        unsafe { *self.nodes.as_raw_slice().get_unchecked(node_id.index()) }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut(&mut self, node_id: NodeId) -> &mut AstNode {
+        debug_assert!(node_id.index() < self.nodes.len());
+        unsafe {
+            self.nodes
+                .as_raw_slice_mut()
+                .get_unchecked_mut(node_id.index())
+        }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn span_unchecked(&self, node_id: NodeId) -> Span {
+        unsafe { self.get_unchecked(node_id).span }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn set_span_unchecked(&mut self, node_id: NodeId, span: Span) {
+        unsafe { self.get_unchecked_mut(node_id).span = span }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn inline_data_unchecked(&self, node_id: NodeId) -> U24 {
+        unsafe { self.get_unchecked(node_id).inline_data }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn set_inline_data_unchecked(&mut self, node_id: NodeId, inline_data: U24) {
+        unsafe { self.get_unchecked_mut(node_id).inline_data = inline_data }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn data_unchecked(&self, node_id: NodeId) -> NodeData {
+        unsafe { self.get_unchecked(node_id).data }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn data_unchecked_mut(&mut self, node_id: NodeId) -> &mut NodeData {
+        unsafe { &mut self.get_unchecked_mut(node_id).data }
+    }
+}
+
+impl FromIterator<AstNode> for Nodes {
+    fn from_iter<I: IntoIterator<Item = AstNode>>(iter: I) -> Self {
+        Self {
+            nodes: iter.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Storage of the [AstNode]s.
+//!
+//! By default the nodes are stored as an array of structs. With the `soa`
+//! feature, the kinds, spans and data are stored in separate arrays instead,
+//! so the passes which only read the kinds or the spans touch less memory.
+//! Both layouts have the same API, and the nodes are read by value.
+
+#[cfg(not(feature = "soa"))]
+mod aos;
+#[cfg(feature = "soa")]
+mod soa;
+
+#[cfg(not(feature = "soa"))]
+pub(crate) use aos::Nodes;
+#[cfg(feature = "soa")]
+pub(crate) use soa::Nodes;
@@ -0,0 +1,173 @@
+use oxc_index::IndexVec;
+use swc_core::common::Span;
+
+use crate::{AstNode, NodeData, NodeId, NodeKind, U24};
+
+/// The fields of [AstNode] in separate arrays, which always have the same
+/// length.
+pub(crate) struct Nodes {
+    kinds: IndexVec<NodeId, NodeKind>,
+    spans: IndexVec<NodeId, Span>,
+    inline_data: IndexVec<NodeId, U24>,
+    data: IndexVec<NodeId, NodeData>,
+}
+
+impl Nodes {
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            kinds: IndexVec::with_capacity(capacity),
+            spans: IndexVec::with_capacity(capacity),
+            inline_data: IndexVec::with_capacity(capacity),
+            data: IndexVec::with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, node: AstNode) -> NodeId {
+        self.spans.push(node.span);
+        self.inline_data.push(node.inline_data);
+        self.data.push(node.data);
+        self.kinds.push(node.kind)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.kinds.len()
+    }
+
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.kinds.truncate(len);
+        self.spans.truncate(len);
+        self.inline_data.truncate(len);
+        self.data.truncate(len);
+    }
+
+    #[inline]
+    pub fn get(&self, node_id: NodeId) -> Option<AstNode> {
+        (node_id.index() < self.len()).then(|| unsafe { self.get_unchecked(node_id) })
+    }
+
+    #[inline]
+    pub fn set(&mut self, node_id: NodeId, node: AstNode) {
+        self.kinds[node_id] = node.kind;
+        self.spans[node_id] = node.span;
+        self.inline_data[node_id] = node.inline_data;
+        self.data[node_id] = node.data;
+    }
+
+    #[inline]
+    pub fn kind(&self, node_id: NodeId) -> NodeKind {
+        self.kinds[node_id]
+    }
+
+    #[inline]
+    pub fn indices(&self) -> impl Iterator<Item = NodeId> + use<> {
+        self.kinds.indices()
+    }
+
+    #[inline]
+    pub fn kinds(&self) -> impl Iterator<Item = NodeKind> + '_ {
+        self.kinds.iter().copied()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = AstNode> + '_ {
+        self.indices()
+            .map(|node_id| unsafe { self.get_unchecked(node_id) })
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn get_unchecked(&self, node_id: NodeId) -> AstNode {
+        debug_assert!(node_id.index() < self.len());
+        unsafe {
+            AstNode {
+                span: self.span_unchecked(node_id),
+                kind: *self.kinds.as_raw_slice().get_unchecked(node_id.index()),
+                inline_data: self.inline_data_unchecked(node_id),
+                data: self.data_unchecked(node_id),
+            }
+        }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn span_unchecked(&self, node_id: NodeId) -> Span {
+        debug_assert!(node_id.index() < self.len());
+        unsafe { *self.spans.as_raw_slice().get_unchecked(node_id.index()) }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn set_span_unchecked(&mut self, node_id: NodeId, span: Span) {
+        debug_assert!(node_id.index() < self.len());
+        unsafe {
+            *self
+                .spans
+                .as_raw_slice_mut()
+                .get_unchecked_mut(node_id.index()) = span
+        }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn inline_data_unchecked(&self, node_id: NodeId) -> U24 {
+        debug_assert!(node_id.index() < self.len());
+        unsafe {
+            *self
+                .inline_data
+                .as_raw_slice()
+                .get_unchecked(node_id.index())
+        }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn set_inline_data_unchecked(&mut self, node_id: NodeId, inline_data: U24) {
+        debug_assert!(node_id.index() < self.len());
+        unsafe {
+            *self
+                .inline_data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(node_id.index()) = inline_data
+        }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn data_unchecked(&self, node_id: NodeId) -> NodeData {
+        debug_assert!(node_id.index() < self.len());
+        unsafe { *self.data.as_raw_slice().get_unchecked(node_id.index()) }
+    }
+
+    /// # Safety
+    /// The node_id must be valid.
+    #[inline]
+    pub unsafe fn data_unchecked_mut(&mut self, node_id: NodeId) -> &mut NodeData {
+        debug_assert!(node_id.index() < self.len());
+        unsafe {
+            self.data
+                .as_raw_slice_mut()
+                .get_unchecked_mut(node_id.index())
+        }
+    }
+}
+
+impl FromIterator<AstNode> for Nodes {
+    fn from_iter<I: IntoIterator<Item = AstNode>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut nodes = Self::with_capacity(iter.size_hint().0);
+        for node in iter {
+            nodes.push(node);
+        }
+        nodes
+    }
+}
@@ -0,0 +1,272 @@
+//! The ancestors of the node visited by [crate::VisitWithPath].
+
+use crate::{Ast, FieldSchema, NodeId, NodeIdTrait, NodeKind};
+
+/// An ancestor of the visited node, and the field of it the traversal went
+/// through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AstPathEntry {
+    pub node: NodeId,
+    pub kind: NodeKind,
+    /// The index of the field in [NodeKind::schema].
+    field: u8,
+    /// The index of the element, if the field is a list.
+    pub index: Option<u32>,
+}
+
+impl AstPathEntry {
+    #[inline]
+    pub fn field(&self) -> &'static FieldSchema {
+        &self.kind.schema().fields[self.field as usize]
+    }
+
+    #[inline]
+    pub fn field_name(&self) -> &'static str {
+        self.field().name
+    }
+
+    /// Returns the ancestor as a typed node, or `None` if it's of another
+    /// type.
+    #[inline]
+    pub fn node_as<N: NodeIdTrait>(&self, ast: &Ast) -> Option<N> {
+        N::try_from_node_id(self.node, ast)
+    }
+}
+
+/// The chain of ancestors from the root of the traversal to the parent of the
+/// visited node.
+#[derive(Debug, Clone, Default)]
+pub struct AstNodePath {
+    entries: Vec<AstPathEntry>,
+}
+
+impl AstNodePath {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ancestors, starting with the root.
+    #[inline]
+    pub fn entries(&self) -> &[AstPathEntry] {
+        &self.entries
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    pub fn parent(&self) -> Option<&AstPathEntry> {
+        self.entries.last()
+    }
+
+    /// Returns the ancestors, starting with the parent.
+    #[inline]
+    pub fn ancestors(&self) -> impl Iterator<Item = &AstPathEntry> {
+        self.entries.iter().rev()
+    }
+
+    /// Returns `true` if the visited node is the `field` of a `kind` node,
+    /// e.g. the `callee` of a `CallExpr`.
+    #[inline]
+    pub fn is_field_of(&self, kind: NodeKind, field: &str) -> bool {
+        self.parent()
+            .is_some_and(|parent| parent.kind == kind && parent.field_name() == field)
+    }
+
+    #[inline]
+    pub(crate) fn push(&mut self, node: NodeId, kind: NodeKind) {
+        self.entries.push(AstPathEntry {
+            node,
+            kind,
+            field: 0,
+            index: None,
+        });
+    }
+
+    #[inline]
+    pub(crate) fn pop(&mut self) {
+        self.entries.pop();
+    }
+
+    #[inline]
+    pub(crate) fn set_field(&mut self, field: u8) {
+        self.entries.last_mut().unwrap().field = field;
+    }
+
+    #[inline]
+    pub(crate) fn set_index(&mut self, index: Option<u32>) {
+        // The root may be a list, which has no parent.
+        if let Some(entry) = self.entries.last_mut() {
+            entry.index = index;
+        }
+    }
+}
+
+/// Builds the [AstNodePath] of the traversals generated in other crates.
+///
+/// The kind of a pushed node is read from the [Ast], and a field missing from
+/// the schema of the parent, or set without a parent, is ignored, so every
+/// entry names a field of its node.
+#[doc(hidden)]
+#[derive(Debug, Clone, Default)]
+pub struct AstNodePathBuilder {
+    path: AstNodePath,
+}
+
+impl AstNodePathBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn path(&self) -> &AstNodePath {
+        &self.path
+    }
+
+    /// # Panics
+    /// Panics if `node` isn't in `ast`.
+    #[inline]
+    pub fn push(&mut self, ast: &Ast, node: NodeId) {
+        let kind = ast.get_node(node).expect("the node is in the ast").kind();
+        self.path.push(node, kind);
+    }
+
+    #[inline]
+    pub fn pop(&mut self) {
+        self.path.pop();
+    }
+
+    #[inline]
+    pub fn set_field(&mut self, field: u8) {
+        let has_field = self
+            .path
+            .parent()
+            .is_some_and(|parent| (field as usize) < parent.kind.schema().fields.len());
+        if has_field {
+            self.path.set_field(field);
+        }
+    }
+
+    #[inline]
+    pub fn set_index(&mut self, index: Option<u32>) {
+        self.path.set_index(index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::DUMMY_SP;
+
+    use super::*;
+    use crate::{CallExpr, Expr, Ident, StringAllocator, VisitWithPath, VisitWithPathWith};
+
+    type Entry = (NodeKind, &'static str, Option<u32>);
+
+    struct Collector<'a> {
+        ast: &'a Ast,
+        /// The name, whether it's a callee, and the path of the identifiers.
+        idents: Vec<(&'a str, bool, Vec<Entry>)>,
+    }
+
+    impl VisitWithPath for Collector<'_> {
+        fn ast(&self) -> &Ast {
+            self.ast
+        }
+
+        fn visit_ident(&mut self, node: Ident, path: &mut AstNodePath) {
+            let entries = path
+                .entries()
+                .iter()
+                .map(|entry| (entry.kind, entry.field_name(), entry.index))
+                .collect();
+            self.idents.push((
+                self.ast.get_utf8(node.sym(self.ast)),
+                path.is_field_of(NodeKind::CallExpr, "callee"),
+                entries,
+            ));
+        }
+    }
+
+    #[test]
+    fn test_visit_with_path() {
+        // `f(a, b)`
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let f = ast.add_utf8("f");
+        let callee = ast.callee_expr_ident(DUMMY_SP, f, false);
+        let args = ["a", "b"].map(|sym| {
+            let sym = ast.add_utf8(sym);
+            let expr = ast.expr_ident(DUMMY_SP, sym, false);
+            ast.expr_or_spread(DUMMY_SP, None, expr)
+        });
+        let args = ast.add_typed_sub_range(args);
+        let root = ast.expr_call_expr(DUMMY_SP, callee, args, None);
+
+        let mut collector = Collector {
+            ast: &ast,
+            idents: Vec::new(),
+        };
+        let mut path = AstNodePath::new();
+        root.visit_with_path(&mut collector, &mut path);
+        assert!(path.is_empty());
+
+        let [f, a, b] = &collector.idents[..] else {
+            panic!()
+        };
+        assert_eq!(*f, ("f", true, vec![(NodeKind::CallExpr, "callee", None)]));
+        assert_eq!(
+            *b,
+            (
+                "b",
+                false,
+                vec![
+                    (NodeKind::CallExpr, "args", Some(1)),
+                    (NodeKind::ExprOrSpread, "expr", None)
+                ]
+            )
+        );
+        assert_eq!(a.2[0], (NodeKind::CallExpr, "args", Some(0)));
+
+        let Expr::Call(call) = root else {
+            unreachable!()
+        };
+        let entry = AstPathEntry {
+            node: call.node_id(),
+            kind: NodeKind::CallExpr,
+            field: 0,
+            index: None,
+        };
+        assert_eq!(entry.node_as::<CallExpr>(&ast), Some(call));
+        assert_eq!(entry.node_as::<Ident>(&ast), None);
+    }
+
+    #[test]
+    fn test_path_builder() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let f = ast.add_utf8("f");
+        let callee = ast.callee_expr_ident(DUMMY_SP, f, false);
+        let args = ast.add_typed_sub_range([]);
+        let root = ast.expr_call_expr(DUMMY_SP, callee, args, None);
+
+        let mut builder = AstNodePathBuilder::new();
+        builder.set_field(0);
+        builder.push(&ast, root.node_id());
+        assert_eq!(builder.path().parent().unwrap().kind, NodeKind::CallExpr);
+        builder.set_field(1);
+        builder.set_field(u8::MAX);
+        assert_eq!(builder.path().parent().unwrap().field_name(), "args");
+
+        builder.push(&ast, callee.node_id());
+        assert_eq!(builder.path().parent().unwrap().kind, NodeKind::Ident);
+    }
+}
@@ -0,0 +1,389 @@
+//! A static description of how the fields of every node kind are stored,
+//! generated by `ast_tools` into `generated/ast_schema.rs`.
+//!
+//! It's used by the code that has to handle the untyped [AstNode] and
+//! [ExtraData] without knowing the kinds, e.g. [Ast::to_bytes] and
+//! [Ast::children].
+
+use std::{ops::Range, slice};
+
+use crate::{
+    Ast, AstNode, ExtraData, ExtraDataCompact, ExtraDataId, NodeId, NodeIdTrait, NodeKind,
+    OptionalNodeId, node_id::SubRange,
+};
+
+pub(crate) use crate::generated::ast_schema::NODE_SCHEMAS;
+
+impl NodeKind {
+    /// Returns how the fields of the kind are stored.
+    #[inline]
+    pub fn schema(self) -> &'static NodeSchema {
+        &NODE_SCHEMAS[self.schema_index()]
+    }
+}
+
+/// The fields of a node kind.
+#[derive(Debug)]
+pub struct NodeSchema {
+    pub kind: NodeKind,
+    pub name: &'static str,
+    pub fields: &'static [FieldSchema],
+}
+
+impl NodeSchema {
+    /// Returns the schemas of all node kinds.
+    #[inline]
+    pub fn all() -> &'static [NodeSchema] {
+        NODE_SCHEMAS
+    }
+
+    /// Returns `true` if [crate::NodeData] is the start of the fields in
+    /// [Ast::extra_data].
+    pub fn has_extra_data(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|field| matches!(field.location, FieldLocation::ExtraData(_)))
+    }
+}
+
+#[derive(Debug)]
+pub struct FieldSchema {
+    pub name: &'static str,
+    pub ty: FieldType,
+    pub location: FieldLocation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldLocation {
+    /// The field is packed into the 7 bytes of inline storage: bytes 0-3 are
+    /// `NodeData::inline_data` and bytes 4-6 are `AstNode::inline_data`.
+    Inline { offset: u8, size: u8 },
+    /// The field is the `n`-th [ExtraData] from `NodeData::extra_data_start`.
+    ExtraData(u32),
+}
+
+impl FieldLocation {
+    /// Reads the raw bits of an inline field.
+    ///
+    /// # Safety
+    /// The node must have inline fields, so that `NodeData` is initialized.
+    pub(crate) unsafe fn read_inline(node: &AstNode, offset: u8, size: u8) -> u32 {
+        let bits =
+            unsafe { node.data.inline_data } as u64 | (u32::from(node.inline_data) as u64) << 32;
+        ((bits >> (offset * 8)) & ((1u64 << (size * 8)) - 1)) as u32
+    }
+
+    /// Writes the raw bits of an inline field.
+    ///
+    /// # Safety
+    /// The node must have inline fields, so that `NodeData` is initialized.
+    pub(crate) unsafe fn write_inline(node: &mut AstNode, offset: u8, size: u8, value: u32) {
+        let bits =
+            unsafe { node.data.inline_data } as u64 | (u32::from(node.inline_data) as u64) << 32;
+        let mask = ((1u64 << (size * 8)) - 1) << (offset * 8);
+        let bits = (bits & !mask) | ((value as u64) << (offset * 8) & mask);
+        node.data.inline_data = bits as u32;
+        node.inline_data = ((bits >> 32) as u32).into();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    /// A node, stored by the [NodeId] of the struct.
+    Node(&'static NodeType),
+    OptionalNode(&'static NodeType),
+    /// A [crate::TypedSubRange] of the elements.
+    List(&'static FieldType),
+    OptionalList(&'static FieldType),
+    Enum(&'static EnumType),
+    OptionalEnum(&'static EnumType),
+    Span,
+    Bool,
+    Number,
+    Utf8,
+    OptionalUtf8,
+    Wtf8,
+    OptionalWtf8,
+    BigInt,
+}
+
+/// A struct or an enum of structs, which is stored by the [crate::NodeId] of
+/// the struct.
+#[derive(Debug)]
+pub struct NodeType {
+    pub name: &'static str,
+    /// The kinds of the structs that can be stored.
+    pub kinds: &'static [NodeKind],
+    pub(crate) codec: NodeCodec,
+    /// The codec of `Option<T>`, which is `None` if the AST never uses it.
+    pub(crate) optional_codec: Option<NodeCodec>,
+}
+
+impl PartialEq for NodeType {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for NodeType {}
+
+/// A `#[repr(u8)]` enum like [crate::BinaryOp], whose discriminants are the
+/// indexes of the variants.
+#[derive(Debug, PartialEq, Eq)]
+pub struct EnumType {
+    pub name: &'static str,
+    pub variants: &'static [&'static str],
+}
+
+/// Converts the [ExtraData] of a [NodeType] from and to a node id, as enums of
+/// structs are stored by value.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NodeCodec {
+    /// # Safety
+    /// The data must store the type of the codec.
+    pub read: unsafe fn(ExtraData, &Ast) -> OptionalNodeId,
+    /// # Safety
+    /// The node must be of the kinds of the type of the codec, or `None` for
+    /// the optional codec.
+    pub write: unsafe fn(OptionalNodeId, &Ast) -> ExtraData,
+    /// Returns the raw id stored in the data without decoding it, or
+    /// `u32::MAX` for `None`, so that the id can be checked before the data is
+    /// decoded.
+    ///
+    /// # Safety
+    /// The data must have been written by a codec of a type of the same size.
+    pub raw: unsafe fn(ExtraData) -> u32,
+    /// Returns `true` if the data is the one `write` stores for `node_id`,
+    /// e.g. an [crate::Expr] stores the variant of the kind besides the id.
+    ///
+    /// # Panics
+    /// Panics if `node_id` is out of bounds.
+    pub check: fn(ExtraData, NodeId, &Ast) -> bool,
+}
+
+impl NodeCodec {
+    pub const fn new<T: NodeIdTrait + ExtraDataCompact>() -> Self {
+        Self {
+            read: read_node::<T>,
+            write: write_node::<T>,
+            raw: raw_node::<T>,
+            check: check_node::<T>,
+        }
+    }
+
+    pub const fn new_optional<T: NodeIdTrait>() -> Self
+    where
+        Option<T>: ExtraDataCompact,
+    {
+        Self {
+            read: read_optional_node::<T>,
+            write: write_optional_node::<T>,
+            raw: raw_optional_node::<T>,
+            check: check_optional_node::<T>,
+        }
+    }
+}
+
+unsafe fn read_node<T: NodeIdTrait + ExtraDataCompact>(
+    data: ExtraData,
+    ast: &Ast,
+) -> OptionalNodeId {
+    unsafe { T::from_extra_data(data, ast) }.node_id().into()
+}
+
+unsafe fn write_node<T: NodeIdTrait + ExtraDataCompact>(
+    node_id: OptionalNodeId,
+    ast: &Ast,
+) -> ExtraData {
+    let node_id = node_id.to_option().unwrap();
+    unsafe { T::from_node_id_unchecked(node_id, ast) }.to_extra_data()
+}
+
+/// Reads the `u32` at `index` in the data.
+///
+/// # Safety
+/// The 4 bytes must be initialized.
+#[inline]
+unsafe fn read_u32(data: &ExtraData, index: usize) -> u32 {
+    unsafe { (data as *const ExtraData).cast::<u32>().add(index).read() }
+}
+
+/// Returns the number of `u32` a node of type `T` is stored in. An enum of the
+/// size of [ExtraData] is stored by value, as its variant and then the id of
+/// the struct, see `assert_layout.rs`. Structs and the larger enums are stored
+/// by their id.
+const fn stored_len<T>() -> usize {
+    if size_of::<T>() == size_of::<ExtraData>() {
+        2
+    } else {
+        1
+    }
+}
+
+/// Returns `true` if `a` and `b` store the same node of type `T`.
+///
+/// # Safety
+/// The data must have been written by a codec of a type stored in as many
+/// bytes as `T`.
+#[inline]
+unsafe fn same_node<T>(a: ExtraData, b: ExtraData) -> bool {
+    (0..stored_len::<T>()).all(|index| unsafe { read_u32(&a, index) == read_u32(&b, index) })
+}
+
+unsafe fn raw_node<T: NodeIdTrait>(data: ExtraData) -> u32 {
+    unsafe { read_u32(&data, stored_len::<T>() - 1) }
+}
+
+fn check_node<T: NodeIdTrait + ExtraDataCompact>(
+    data: ExtraData,
+    node_id: NodeId,
+    ast: &Ast,
+) -> bool {
+    T::try_from_node_id(node_id, ast)
+        .is_some_and(|expected| unsafe { same_node::<T>(expected.to_extra_data(), data) })
+}
+
+unsafe fn read_optional_node<T: NodeIdTrait>(data: ExtraData, ast: &Ast) -> OptionalNodeId
+where
+    Option<T>: ExtraDataCompact,
+{
+    unsafe { <Option<T>>::from_extra_data(data, ast) }
+        .map(|node| node.node_id())
+        .into()
+}
+
+unsafe fn raw_optional_node<T: NodeIdTrait>(data: ExtraData) -> u32
+where
+    Option<T>: ExtraDataCompact,
+{
+    if stored_len::<T>() == 1 {
+        return unsafe { read_u32(&data, 0) };
+    }
+    // `None` is an enum with a variant out of range, without an id.
+    let none = None::<T>.to_extra_data();
+    if unsafe { read_u32(&data, 0) == read_u32(&none, 0) } {
+        u32::MAX
+    } else {
+        unsafe { raw_node::<T>(data) }
+    }
+}
+
+fn check_optional_node<T: NodeIdTrait>(data: ExtraData, node_id: NodeId, ast: &Ast) -> bool
+where
+    Option<T>: ExtraDataCompact,
+{
+    T::try_from_node_id(node_id, ast)
+        .is_some_and(|expected| unsafe { same_node::<T>(Some(expected).to_extra_data(), data) })
+}
+
+unsafe fn write_optional_node<T: NodeIdTrait>(node_id: OptionalNodeId, ast: &Ast) -> ExtraData
+where
+    Option<T>: ExtraDataCompact,
+{
+    node_id
+        .map(|node_id| unsafe { T::from_node_id_unchecked(node_id, ast) })
+        .to_extra_data()
+}
+
+impl Ast {
+    /// Returns the nodes referenced by the fields of a node, in the order of
+    /// the fields.
+    ///
+    /// # Panics
+    /// Panics if `node_id` is out of bounds.
+    pub fn children(&self, node_id: NodeId) -> Children<'_> {
+        let node = self.nodes.get(node_id).expect("node_id out of bounds");
+        Children {
+            ast: self,
+            node,
+            fields: node.kind.schema().fields.iter(),
+            list: None,
+        }
+    }
+
+    /// Returns the node referenced by `data`, if `ty` is a node.
+    ///
+    /// # Safety
+    /// `data` must store `ty`.
+    pub(crate) unsafe fn read_child(&self, data: ExtraData, ty: FieldType) -> Option<NodeId> {
+        let child = match ty {
+            FieldType::Node(node_type) => unsafe { (node_type.codec.read)(data, self) },
+            FieldType::OptionalNode(node_type) => unsafe {
+                (node_type.optional_codec.unwrap().read)(data, self)
+            },
+            _ => return None,
+        };
+        child.to_option()
+    }
+}
+
+/// The iterator returned by [Ast::children].
+pub struct Children<'a> {
+    ast: &'a Ast,
+    node: AstNode,
+    fields: slice::Iter<'static, FieldSchema>,
+    /// The rest of the list being iterated, and the type of its elements.
+    list: Option<(Range<usize>, FieldType)>,
+}
+
+impl Iterator for Children<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((range, element)) = &mut self.list {
+                match range.next() {
+                    Some(index) => {
+                        let data = self.ast.extra_data[ExtraDataId::from_usize(index)];
+                        // SAFETY: The elements of the list are of `element`.
+                        match unsafe { self.ast.read_child(data, *element) } {
+                            Some(child) => return Some(child),
+                            None => continue,
+                        }
+                    }
+                    None => self.list = None,
+                }
+            }
+
+            let field = self.fields.next()?;
+            let index = match field.location {
+                FieldLocation::Inline { offset, size } => {
+                    if let FieldType::Node(_) | FieldType::OptionalNode(_) = field.ty {
+                        // SAFETY: The node has inline fields.
+                        let raw = unsafe { FieldLocation::read_inline(&self.node, offset, size) };
+                        if let Some(child) = OptionalNodeId::from_raw(raw).to_option() {
+                            return Some(child);
+                        }
+                    }
+                    continue;
+                }
+                FieldLocation::ExtraData(offset) => {
+                    (unsafe { self.node.data.extra_data_start }) + offset as usize
+                }
+            };
+            let data = self.ast.extra_data[index];
+            let (range, element) = match field.ty {
+                FieldType::List(element) => (unsafe { data.sub_range }, *element),
+                FieldType::OptionalList(element) => {
+                    let range = unsafe { data.optional_sub_range };
+                    if range.end.raw() == u32::MAX {
+                        continue;
+                    }
+                    let range = SubRange {
+                        start: range.start,
+                        end: range.end,
+                    };
+                    (range, *element)
+                }
+                ty => {
+                    // SAFETY: The field is stored as described by the schema.
+                    match unsafe { self.ast.read_child(data, ty) } {
+                        Some(child) => return Some(child),
+                        None => continue,
+                    }
+                }
+            };
+            self.list = Some((range.start.index()..range.end.index(), element));
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Stack growth for the recursive passes over the AST.
+//!
+//! The parser, the printers and the passes implemented with [crate::Visit] and
+//! [crate::VisitMut] recurse into the children of a node, so a deeply nested
+//! input, e.g. a generated array literal nested 100,000 times, would overflow
+//! the stack of the thread. The generated traversals don't grow the stack
+//! themselves, which would probe it at every node of every visitor.
+
+/// The stack left below which a new stack segment is allocated.
+///
+/// It must hold the frames between two calls of [maybe_grow], which are the
+/// largest in unoptimized builds.
+const RED_ZONE: usize = 256 * 1024;
+
+/// The size of the stack segments allocated by [maybe_grow].
+const STACK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Runs `f` on a new stack segment if the stack of the current one is
+/// running out.
+///
+/// Call it on the way down every recursion that the input controls, e.g.
+/// once per nested expression.
+#[inline]
+pub fn maybe_grow<R>(f: impl FnOnce() -> R) -> R {
+    stacker::maybe_grow(RED_ZONE, STACK_SIZE, f)
+}
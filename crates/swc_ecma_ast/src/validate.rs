@@ -0,0 +1,334 @@
+//! Checks of the invariants that the unsafe getters and setters rely on, to
+//! find the transforms corrupting the AST.
+
+use std::fmt;
+
+use oxc_index::Idx;
+
+use crate::{
+    Ast, ExtraData, ExtraDataId, NodeId, NodeIdTrait, Span,
+    schema::{FieldLocation, FieldType, NodeType},
+};
+
+/// The error of [Ast::validate].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AstValidationError {
+    /// The invalid node, or the parent of the invalid reference.
+    pub node: NodeId,
+    /// The invalid field, or `None` if the node itself is invalid.
+    pub field: Option<&'static str>,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for AstValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.field {
+            Some(field) => write!(
+                f,
+                "invalid field `{field}` of node {}: {}",
+                self.node.index(),
+                self.reason
+            ),
+            None => write!(f, "invalid node {}: {}", self.node.index(), self.reason),
+        }
+    }
+}
+
+impl std::error::Error for AstValidationError {}
+
+impl Ast {
+    /// Checks the nodes reachable from `root`:
+    ///
+    /// - The nodes referenced by the fields are in bounds, and of the kinds
+    ///   the schema allows.
+    /// - The lists and the fields in [Ast::extra_data] are in bounds.
+    /// - The enums, strings and bigints are valid, or the `None` sentinels.
+    /// - The spans don't end before they start.
+    /// - No node is referenced by two fields, so that a node has one parent.
+    /// - The comments are attached to nodes in bounds, and their texts are
+    ///   in bounds.
+    ///
+    /// It's meant to be run in debug builds and tests, after a transform.
+    pub fn validate<N: NodeIdTrait>(&self, root: N) -> Result<(), AstValidationError> {
+        let root = root.node_id();
+        if root.index() >= self.nodes.len() {
+            return Err(AstValidationError {
+                node: root,
+                field: None,
+                reason: "node out of bounds",
+            });
+        }
+
+        let mut visited = vec![false; self.nodes.len()];
+        visited[root.index()] = true;
+        let mut stack = vec![root];
+        let mut children = Vec::new();
+        while let Some(node_id) = stack.pop() {
+            let node = self.nodes.get(node_id).unwrap();
+            if !valid_span(node.span) {
+                return Err(AstValidationError {
+                    node: node_id,
+                    field: None,
+                    reason: "span ends before it starts",
+                });
+            }
+
+            let schema = node.kind.schema();
+            for field in schema.fields {
+                let result = match field.location {
+                    FieldLocation::Inline { offset, size } => {
+                        let raw = unsafe { FieldLocation::read_inline(&node, offset, size) };
+                        self.check_inline(field.ty, raw, &mut children)
+                    }
+                    FieldLocation::ExtraData(offset) => {
+                        let start = unsafe { node.data.extra_data_start };
+                        match self.extra_data.get(start + offset as usize) {
+                            Some(&data) => self.check_extra_data(field.ty, data, &mut children),
+                            None => Err("extra data out of bounds"),
+                        }
+                    }
+                };
+                let error = |reason| AstValidationError {
+                    node: node_id,
+                    field: Some(field.name),
+                    reason,
+                };
+                result.map_err(error)?;
+
+                for &child in &children {
+                    if std::mem::replace(&mut visited[child.index()], true) {
+                        return Err(error("node shared by two parents"));
+                    }
+                }
+                stack.extend(children.drain(..).rev());
+            }
+        }
+
+        for (node, _, comment) in self.comments() {
+            let reason = if node.index() >= self.nodes.len() {
+                "comment attached to a node out of bounds"
+            } else if comment.text.index() >= self.string_allocator.utf8_len() {
+                "comment text out of bounds"
+            } else {
+                continue;
+            };
+            return Err(AstValidationError {
+                node,
+                field: None,
+                reason,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn check_inline(
+        &self,
+        ty: FieldType,
+        raw: u32,
+        children: &mut Vec<NodeId>,
+    ) -> Result<(), &'static str> {
+        match ty {
+            FieldType::Node(node_type) => {
+                children.push(self.check_node_ref(node_type, raw)?);
+            }
+            FieldType::OptionalNode(node_type) => {
+                if raw != u32::MAX {
+                    children.push(self.check_node_ref(node_type, raw)?);
+                }
+            }
+            FieldType::Enum(enum_type) if raw as usize >= enum_type.variants.len() => {
+                return Err("invalid enum variant");
+            }
+            FieldType::Bool if raw > 1 => return Err("invalid bool"),
+            FieldType::BigInt if raw as usize >= self.bigint.len() => {
+                return Err("bigint out of bounds");
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn check_extra_data(
+        &self,
+        ty: FieldType,
+        data: ExtraData,
+        children: &mut Vec<NodeId>,
+    ) -> Result<(), &'static str> {
+        // The sentinel of the `None` strings.
+        let optional = |index: usize| index == u32::MAX as usize;
+        let valid = unsafe {
+            match ty {
+                // The id is checked before the data is decoded, which may
+                // hold an invalid variant.
+                FieldType::Node(node_type) => {
+                    let node_id = self.check_node_ref(node_type, (node_type.codec.raw)(data))?;
+                    children.push(node_id);
+                    (node_type.codec.check)(data, node_id, self)
+                }
+                FieldType::OptionalNode(node_type) => {
+                    let codec = node_type.optional_codec.unwrap();
+                    match (codec.raw)(data) {
+                        u32::MAX => true,
+                        raw => {
+                            let node_id = self.check_node_ref(node_type, raw)?;
+                            children.push(node_id);
+                            (codec.check)(data, node_id, self)
+                        }
+                    }
+                }
+                FieldType::List(element) => {
+                    let range = data.sub_range;
+                    return self.check_list(range.start, range.end, *element, children);
+                }
+                FieldType::OptionalList(element) => {
+                    let range = data.optional_sub_range;
+                    if range.end.raw() == u32::MAX {
+                        return Ok(());
+                    }
+                    return self.check_list(range.start, range.end, *element, children);
+                }
+                FieldType::Enum(enum_type) => data.other < enum_type.variants.len() as u64,
+                FieldType::OptionalEnum(enum_type) => {
+                    data.other == u64::MAX || data.other < enum_type.variants.len() as u64
+                }
+                FieldType::Span => valid_span(data.span),
+                FieldType::Bool | FieldType::Number => true,
+                FieldType::Utf8 => data.utf8.index() < self.string_allocator.utf8_len(),
+                FieldType::OptionalUtf8 => {
+                    let index = data.optional_utf8.index();
+                    optional(index) || index < self.string_allocator.utf8_len()
+                }
+                FieldType::Wtf8 => data.wtf8.index() < self.string_allocator.wtf8_len(),
+                FieldType::OptionalWtf8 => {
+                    let index = data.optional_wtf8.index();
+                    optional(index) || index < self.string_allocator.wtf8_len()
+                }
+                FieldType::BigInt => data.bigint.index() < self.bigint.len(),
+            }
+        };
+        match valid {
+            true => Ok(()),
+            false => Err(match ty {
+                FieldType::Node(_) | FieldType::OptionalNode(_) => "unexpected node kind",
+                FieldType::Enum(_) | FieldType::OptionalEnum(_) => "invalid enum variant",
+                FieldType::Span => "span ends before it starts",
+                FieldType::BigInt => "bigint out of bounds",
+                _ => "string out of bounds",
+            }),
+        }
+    }
+
+    fn check_list(
+        &self,
+        start: ExtraDataId,
+        end: ExtraDataId,
+        element: FieldType,
+        children: &mut Vec<NodeId>,
+    ) -> Result<(), &'static str> {
+        if start > end || end.index() > self.extra_data.len() {
+            return Err("list out of bounds");
+        }
+        for data in &self.extra_data.as_raw_slice()[start.index()..end.index()] {
+            self.check_extra_data(element, *data, children)?;
+        }
+        Ok(())
+    }
+
+    fn check_node_ref(&self, node_type: &NodeType, raw: u32) -> Result<NodeId, &'static str> {
+        if raw as usize >= self.nodes.len() {
+            return Err("node out of bounds");
+        }
+        let node_id = NodeId::from_raw(raw);
+        if !node_type.kinds.contains(&self.nodes.kind(node_id)) {
+            return Err("unexpected node kind");
+        }
+        Ok(node_id)
+    }
+}
+
+#[inline]
+fn valid_span(span: Span) -> bool {
+    span.lo <= span.hi
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::{BytePos, DUMMY_SP};
+
+    use super::*;
+    use crate::{Expr, GetSpan, Ident, SetSpan, StringAllocator};
+
+    #[test]
+    fn test_validate() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let sym = ast.add_utf8("a");
+        let a = ast.expr_ident(DUMMY_SP, sym, false);
+        let root = ast.expr_paren_expr(DUMMY_SP, a);
+        let Expr::Paren(paren) = root else {
+            unreachable!()
+        };
+        assert_eq!(ast.validate(root), Ok(()));
+
+        // A node referenced twice.
+        let bin = ast.expr_bin_expr(DUMMY_SP, crate::BinaryOp::Add, a, a);
+        let error = ast.validate(bin).unwrap_err();
+        assert_eq!(error.field, Some("right"));
+        assert_eq!(error.reason, "node shared by two parents");
+
+        // A node of the wrong kind, as an unchecked conversion would store.
+        let this = ast.expr_this_expr(DUMMY_SP);
+        let ident = unsafe { Ident::from_node_id_unchecked(this.node_id(), &ast) };
+        paren.set_expr(&mut ast, Expr::Ident(ident));
+        assert_eq!(
+            ast.validate(root).unwrap_err().reason,
+            "unexpected node kind"
+        );
+
+        paren.set_expr(&mut ast, a);
+        let mut span = a.span(&ast);
+        span.lo = BytePos(2);
+        span.hi = BytePos(1);
+        a.clone().set_span(&mut ast, span);
+        let error = ast.validate(root).unwrap_err();
+        assert_eq!(error.node, a.node_id());
+        assert_eq!(error.field, None);
+    }
+
+    #[test]
+    fn test_validate_corrupt_enum() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let sym = ast.add_utf8("a");
+        let a = ast.expr_ident(DUMMY_SP, sym, false);
+        let b = ast.expr_this_expr(DUMMY_SP);
+        let bin = ast.expr_bin_expr(DUMMY_SP, crate::BinaryOp::Add, a, b);
+        assert_eq!(ast.validate(bin), Ok(()));
+
+        let node = ast.nodes.get(bin.node_id()).unwrap();
+        let field = node
+            .kind
+            .schema()
+            .fields
+            .iter()
+            .find(|field| field.name == "left")
+            .unwrap();
+        let FieldLocation::ExtraData(offset) = field.location else {
+            unreachable!()
+        };
+        let index = unsafe { node.data.extra_data_start } + offset as usize;
+
+        // The validator must not decode these as an `Expr`.
+        let raw = |variant: u32, node: u32| ExtraData {
+            other: (node as u64) << 32 | variant as u64,
+        };
+        ast.extra_data[index] = raw(u32::MAX - 1, a.node_id().raw());
+        let error = ast.validate(bin).unwrap_err();
+        assert_eq!(error.field, Some("left"));
+        assert_eq!(error.reason, "unexpected node kind");
+
+        ast.extra_data[index] = raw(0, 100);
+        assert_eq!(ast.validate(bin).unwrap_err().reason, "node out of bounds");
+    }
+}
@@ -0,0 +1,241 @@
+use std::ops::Range;
+
+use oxc_index::Idx;
+use swc_core::{atoms::wtf8::Wtf8, common::BytePos};
+
+use crate::{
+    AstDecodeError, BigIntValue, Comment, CommentPlacement, NodeId, NodeKind, OptionalNodeId, Span,
+    Utf8Ref,
+    bytes::Payload,
+    schema::{FieldLocation, FieldType},
+};
+
+/// A read-only view of an AST encoded by [crate::Ast::to_bytes], which reads
+/// the nodes, the extra data and the strings in place.
+///
+/// Unlike [crate::Ast::from_bytes], nothing is copied out of the bytes, so a
+/// file mapped into memory can be inspected without decoding it, e.g. to look
+/// for the imports of a cached module. The bytes are validated against the
+/// schema as by [crate::Ast::from_bytes], and the fields are read by their
+/// types from the schema.
+///
+/// ```ignore
+/// let bytes = unsafe { memmap2::Mmap::map(&file)? };
+/// let view = AstView::new(&bytes)?;
+/// for node_id in view.node_ids() {
+///     if view.kind(node_id) == NodeKind::ImportDecl {
+///         let Some(FieldValue::Node(Some(src))) = view.field(node_id, "src") else {
+///             unreachable!()
+///         };
+///         println!("{:?}", view.field(src, "value"));
+///     }
+/// }
+/// ```
+pub struct AstView<'a> {
+    payload: Payload<'a>,
+}
+
+/// The value of a field read by [AstView::field].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue<'a> {
+    /// A node or an optional node.
+    Node(Option<NodeId>),
+    /// A list or an optional list.
+    List(Option<ListView<'a>>),
+    /// The name of the variant of an enum or an optional enum.
+    Enum(Option<&'static str>),
+    Span(Span),
+    Bool(bool),
+    Number(f64),
+    /// A UTF-8 string or an optional one, borrowed from the bytes.
+    Utf8(Option<&'a str>),
+    /// A WTF-8 string or an optional one, borrowed from the bytes.
+    Wtf8(Option<&'a Wtf8>),
+    BigInt(BigIntValue),
+}
+
+/// The elements of a list read by [AstView::field].
+#[derive(Clone)]
+pub struct ListView<'a> {
+    view: &'a AstView<'a>,
+    element: FieldType,
+    range: Range<usize>,
+}
+
+impl<'a> AstView<'a> {
+    /// Validates `bytes` encoded by [crate::Ast::to_bytes] and views them.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, AstDecodeError> {
+        let (payload, _) = Payload::read(bytes)?;
+        Ok(Self { payload })
+    }
+
+    /// Returns the number of nodes, which are numbered from `0`.
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.payload.node_count()
+    }
+
+    /// Returns the ids of all nodes.
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.node_count() as u32).map(NodeId::from_raw)
+    }
+
+    /// # Panics
+    /// Panics if `node_id` is out of bounds.
+    pub fn kind(&self, node_id: NodeId) -> NodeKind {
+        self.payload.node(node_id.index()).kind
+    }
+
+    /// # Panics
+    /// Panics if `node_id` is out of bounds.
+    pub fn span(&self, node_id: NodeId) -> Span {
+        self.payload.node(node_id.index()).span
+    }
+
+    /// Returns the field `name` of a node, or `None` if the kind of the node
+    /// has no such field.
+    ///
+    /// # Panics
+    /// Panics if `node_id` is out of bounds.
+    pub fn field(&self, node_id: NodeId, name: &str) -> Option<FieldValue<'_>> {
+        let node = self.payload.node(node_id.index());
+        let field = node
+            .kind
+            .schema()
+            .fields
+            .iter()
+            .find(|field| field.name == name)?;
+        let value = match field.location {
+            FieldLocation::Inline { offset, size } => {
+                // SAFETY: The node has inline fields.
+                let raw = unsafe { FieldLocation::read_inline(&node, offset, size) };
+                match field.ty {
+                    FieldType::Node(_) | FieldType::OptionalNode(_) => {
+                        FieldValue::Node(OptionalNodeId::from_raw(raw).to_option())
+                    }
+                    FieldType::Bool => FieldValue::Bool(raw != 0),
+                    FieldType::Enum(enum_type) => {
+                        FieldValue::Enum(Some(enum_type.variants[raw as usize]))
+                    }
+                    _ => self.value(field.ty, raw as u64),
+                }
+            }
+            FieldLocation::ExtraData(offset) => {
+                let start = unsafe { node.data.extra_data_start };
+                let index = start.index() + offset as usize;
+                self.value(field.ty, self.payload.extra_data(index))
+            }
+        };
+        Some(value)
+    }
+
+    /// Returns the nodes referenced by the fields of a node, in the order of
+    /// [crate::Ast::children].
+    ///
+    /// # Panics
+    /// Panics if `node_id` is out of bounds.
+    pub fn children(&self, node_id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.kind(node_id)
+            .schema()
+            .fields
+            .iter()
+            .flat_map(move |field| {
+                let (node, list) = match self.field(node_id, field.name) {
+                    Some(FieldValue::Node(node)) => (node, None),
+                    Some(FieldValue::List(list)) => (None, list),
+                    _ => (None, None),
+                };
+                node.into_iter()
+                    .chain(
+                        list.into_iter()
+                            .flatten()
+                            .filter_map(|element| match element {
+                                FieldValue::Node(node) => node,
+                                _ => None,
+                            }),
+                    )
+            })
+    }
+
+    /// Returns the comments, sorted by the nodes they are attached to.
+    pub fn comments(&self) -> impl Iterator<Item = (NodeId, CommentPlacement, Comment)> + '_ {
+        self.payload.comments()
+    }
+
+    /// Returns the text of a comment.
+    ///
+    /// # Panics
+    /// Panics if `id` is out of bounds.
+    pub fn get_utf8(&self, id: Utf8Ref) -> &'a str {
+        self.payload.utf8[id.index()]
+    }
+
+    /// Reads an encoded value of [crate::ExtraData], see [crate::Ast::to_bytes].
+    fn value(&self, ty: FieldType, value: u64) -> FieldValue<'_> {
+        let (low, high) = (value as u32, (value >> 32) as u32);
+        let optional = |value: u32| (value != u32::MAX).then_some(value as usize);
+        match ty {
+            FieldType::Node(_) | FieldType::OptionalNode(_) => {
+                FieldValue::Node(OptionalNodeId::from_raw(low).to_option())
+            }
+            FieldType::List(element) => FieldValue::List(Some(ListView {
+                view: self,
+                element: *element,
+                range: low as usize..high as usize,
+            })),
+            FieldType::OptionalList(element) => {
+                FieldValue::List(optional(high).map(|high| ListView {
+                    view: self,
+                    element: *element,
+                    range: low as usize..high,
+                }))
+            }
+            FieldType::Enum(enum_type) => FieldValue::Enum(Some(enum_type.variants[low as usize])),
+            FieldType::OptionalEnum(enum_type) => {
+                FieldValue::Enum((value != u64::MAX).then(|| enum_type.variants[value as usize]))
+            }
+            FieldType::Span => FieldValue::Span(Span::new(BytePos(low), BytePos(high))),
+            FieldType::Bool => FieldValue::Bool(value == 1),
+            FieldType::Number => FieldValue::Number(f64::from_bits(value)),
+            FieldType::Utf8 | FieldType::OptionalUtf8 => {
+                FieldValue::Utf8(optional(low).map(|index| self.payload.utf8[index]))
+            }
+            FieldType::Wtf8 | FieldType::OptionalWtf8 => {
+                FieldValue::Wtf8(optional(low).map(|index| self.payload.wtf8[index]))
+            }
+            FieldType::BigInt => FieldValue::BigInt(BigIntValue::from_signed_bytes_le(
+                self.payload.bigints[low as usize],
+            )),
+        }
+    }
+}
+
+impl<'a> Iterator for ListView<'a> {
+    type Item = FieldValue<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.range.next()?;
+        Some(
+            self.view
+                .value(self.element, self.view.payload.extra_data(index)),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ListView<'_> {}
+
+impl std::fmt::Debug for ListView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl PartialEq for ListView<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.clone().eq(other.clone())
+    }
+}
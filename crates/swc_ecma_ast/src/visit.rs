@@ -1,10 +1,51 @@
+use std::ops::ControlFlow;
+
 use crate::{
-    AssignOp, BigIntId, BinaryOp, ImportPhase, MetaPropKind, MethodKind, OptionalUtf8Ref,
-    OptionalWtf8Ref, UnaryOp, UpdateOp, Utf8Ref, VarDeclKind, Wtf8Ref,
+    Accessibility, AssignOp, BigIntId, BinaryOp, ImportPhase, MetaPropKind, MethodKind,
+    OptionalUtf8Ref, OptionalWtf8Ref, TruePlusMinus, TsKeywordTypeKind, TsTypeOperatorOp, UnaryOp,
+    UpdateOp, Utf8Ref, VarDeclKind, Wtf8Ref,
 };
 use swc_core::common::Span;
 
-use crate::{Visit, VisitMut, VisitMutWith, VisitWith};
+use crate::{
+    Ast, AstNodePath, Visit, VisitControl, VisitControlWith, VisitHooks, VisitMut, VisitMutWith,
+    VisitWith, VisitWithPath, VisitWithPathWith,
+};
+
+/// What [VisitControl::enter_node] lets the traversal do next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisitFlow<B> {
+    /// Visits the children of the node.
+    Continue,
+    /// Skips the children of the node, and continues with its siblings.
+    SkipChildren,
+    /// Stops the whole traversal with the value.
+    Break(B),
+}
+
+/// The [Visit] calling the [VisitHooks] of each node, see [Ast::visit_all].
+pub struct HooksVisitor<'a, V: ?Sized> {
+    pub(crate) ast: &'a Ast,
+    pub(crate) hooks: &'a mut V,
+}
+
+impl Ast {
+    /// Visits `root` once, calling the hooks of `visitors` on entering and
+    /// leaving each node.
+    ///
+    /// Several visitors are combined as a tuple, e.g. `(&mut a, &mut b)`, to
+    /// share one traversal. Their hooks are called in the order of the tuple.
+    pub fn visit_all<'a, N, V>(&'a self, root: N, visitors: &'a mut V)
+    where
+        N: VisitWith<HooksVisitor<'a, V>>,
+        V: ?Sized + VisitHooks,
+    {
+        root.visit_with(&mut HooksVisitor {
+            ast: self,
+            hooks: visitors,
+        });
+    }
+}
 
 macro_rules! dummy_visit_mut_impl {
     ($ident:ident) => {
@@ -16,6 +57,26 @@ macro_rules! dummy_visit_mut_impl {
             fn visit_children_with(self, _visitor: &mut V) {}
         }
 
+        impl<V: ?Sized + VisitControl> VisitControlWith<V> for $ident {
+            #[inline]
+            fn visit_with_control(self, _visitor: &mut V) -> ControlFlow<V::Break> {
+                ControlFlow::Continue(())
+            }
+
+            #[inline]
+            fn visit_children_with_control(self, _visitor: &mut V) -> ControlFlow<V::Break> {
+                ControlFlow::Continue(())
+            }
+        }
+
+        impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for $ident {
+            #[inline]
+            fn visit_with_path(self, _visitor: &mut V, _path: &mut AstNodePath) {}
+
+            #[inline]
+            fn visit_children_with_path(self, _visitor: &mut V, _path: &mut AstNodePath) {}
+        }
+
         impl<V: ?Sized + VisitMut> VisitMutWith<V> for $ident {
             #[inline]
             fn visit_mut_with(self, _visitor: &mut V) -> Self {
@@ -46,3 +107,128 @@ dummy_visit_mut_impl!(BinaryOp);
 dummy_visit_mut_impl!(AssignOp);
 dummy_visit_mut_impl!(MetaPropKind);
 dummy_visit_mut_impl!(MethodKind);
+dummy_visit_mut_impl!(Accessibility);
+dummy_visit_mut_impl!(TruePlusMinus);
+dummy_visit_mut_impl!(TsKeywordTypeKind);
+dummy_visit_mut_impl!(TsTypeOperatorOp);
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::DUMMY_SP;
+
+    use super::*;
+    use crate::{Ast, BinExpr, BinaryOp, Expr, Ident, NodeId, NodeIdTrait, StringAllocator};
+
+    struct IdentFinder<'a> {
+        ast: &'a Ast,
+        skip: Option<NodeId>,
+        visited: Vec<&'a str>,
+    }
+
+    impl VisitControl for IdentFinder<'_> {
+        type Break = Ident;
+
+        fn ast(&self) -> &Ast {
+            self.ast
+        }
+
+        fn enter_node(&mut self, node_id: NodeId) -> VisitFlow<Ident> {
+            if Some(node_id) == self.skip {
+                VisitFlow::SkipChildren
+            } else {
+                VisitFlow::Continue
+            }
+        }
+
+        fn visit_ident(&mut self, node: Ident) -> ControlFlow<Ident> {
+            let sym = self.ast.get_utf8(node.sym(self.ast));
+            self.visited.push(sym);
+            if sym == "b" {
+                ControlFlow::Break(node)
+            } else {
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    #[test]
+    fn test_visit_control() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let [a, b, c] = ["a", "b", "c"].map(|sym| {
+            let sym = ast.add_utf8(sym);
+            ast.expr_ident(DUMMY_SP, sym, false)
+        });
+        // `(a + b) + c`
+        let left = ast.expr_bin_expr(DUMMY_SP, BinaryOp::Add, a, b);
+        let root = ast.expr_bin_expr(DUMMY_SP, BinaryOp::Add, left, c);
+
+        let mut finder = IdentFinder {
+            ast: &ast,
+            skip: None,
+            visited: Vec::new(),
+        };
+        let Expr::Ident(found) = b else {
+            unreachable!()
+        };
+        assert_eq!(
+            root.visit_with_control(&mut finder),
+            ControlFlow::Break(found)
+        );
+        assert_eq!(finder.visited, ["a", "b"]);
+
+        let mut finder = IdentFinder {
+            ast: &ast,
+            skip: Some(left.node_id()),
+            visited: Vec::new(),
+        };
+        assert_eq!(
+            root.visit_with_control(&mut finder),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(finder.visited, ["c"]);
+    }
+
+    #[derive(Default)]
+    struct Events(Vec<String>);
+
+    impl VisitHooks for Events {
+        fn enter_bin_expr(&mut self, _: BinExpr, _: &Ast) {
+            self.0.push("enter bin".into());
+        }
+
+        fn leave_bin_expr(&mut self, _: BinExpr, _: &Ast) {
+            self.0.push("leave bin".into());
+        }
+
+        fn enter_ident(&mut self, node: Ident, ast: &Ast) {
+            self.0.push(ast.get_utf8(node.sym(ast)).into());
+        }
+    }
+
+    #[derive(Default)]
+    struct NodeCounter(usize);
+
+    impl VisitHooks for NodeCounter {
+        fn enter_node(&mut self, _: NodeId, _: &Ast) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    fn test_visit_all() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let [a, b] = ["a", "b"].map(|sym| {
+            let sym = ast.add_utf8(sym);
+            ast.expr_ident(DUMMY_SP, sym, false)
+        });
+        let root = ast.expr_bin_expr(DUMMY_SP, BinaryOp::Add, a, b);
+
+        let mut events = Events::default();
+        let mut counter = NodeCounter::default();
+        ast.visit_all(root, &mut (&mut events, &mut counter));
+        assert_eq!(events.0, ["enter bin", "a", "b", "leave bin"]);
+        assert_eq!(counter.0, 3);
+    }
+}
@@ -0,0 +1,361 @@
+//! Traversals driven by an explicit stack instead of recursion, so that deep
+//! ASTs, e.g. thousands of chained binary operators in a minified bundle,
+//! don't overflow the stack.
+//!
+//! The nodes are visited in the order of [crate::Visit], with the children
+//! read from [Ast::children].
+//!
+//! The `visit_*` methods of [crate::Visit] and [crate::VisitMut] visit the
+//! children themselves, so their implementors recurse. The ones which must
+//! handle deep input call [crate::maybe_grow] in their `visit_expr` and
+//! alike. The same passes written as [VisitHooks] or [VisitMutHooks], whose
+//! methods are split into entering and leaving a node, are driven by
+//! [Ast::walk_hooks] and [Ast::walk_mut_hooks] without recursion instead.
+
+use std::ops::ControlFlow;
+
+use crate::{
+    Ast, NodeId, NodeIdTrait, VisitFlow, VisitHooks, VisitMutHooks,
+    generated::ast_visitor_hooks::{enter_hooks, enter_mut_hooks, leave_hooks, leave_mut_hooks},
+};
+
+/// A node entered before its children, or left after them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalkEvent {
+    Enter(NodeId),
+    Leave(NodeId),
+}
+
+/// The pending events of a traversal.
+struct WalkStack {
+    stack: Vec<WalkEvent>,
+    /// The last entered node, whose children aren't pushed yet.
+    entered: Option<NodeId>,
+}
+
+impl WalkStack {
+    fn new(root: NodeId) -> Self {
+        Self {
+            stack: vec![WalkEvent::Enter(root)],
+            entered: None,
+        }
+    }
+
+    fn next(&mut self, ast: &Ast) -> Option<WalkEvent> {
+        if let Some(node_id) = self.entered.take() {
+            self.stack.push(WalkEvent::Leave(node_id));
+            let len = self.stack.len();
+            self.stack
+                .extend(ast.children(node_id).map(WalkEvent::Enter));
+            self.stack[len..].reverse();
+        }
+
+        let event = self.stack.pop()?;
+        if let WalkEvent::Enter(node_id) = event {
+            self.entered = Some(node_id);
+        }
+        Some(event)
+    }
+
+    fn skip_children(&mut self) {
+        if let Some(node_id) = self.entered.take() {
+            self.stack.push(WalkEvent::Leave(node_id));
+        }
+    }
+}
+
+/// The iterator returned by [Ast::walk].
+pub struct Walk<'a> {
+    ast: &'a Ast,
+    stack: WalkStack,
+}
+
+impl Walk<'_> {
+    /// Skips the children of the node just entered, so that the next event
+    /// is leaving it.
+    pub fn skip_children(&mut self) {
+        self.stack.skip_children();
+    }
+}
+
+impl Iterator for Walk<'_> {
+    type Item = WalkEvent;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.stack.next(self.ast)
+    }
+}
+
+impl Ast {
+    /// Returns the events of a pre and post-order traversal of `root`.
+    pub fn walk<N: NodeIdTrait>(&self, root: N) -> Walk<'_> {
+        Walk {
+            ast: self,
+            stack: WalkStack::new(root.node_id()),
+        }
+    }
+
+    /// Calls the hooks of `visitors` on entering and leaving each node, like
+    /// [Ast::visit_all] but without recursion.
+    pub fn walk_hooks<N: NodeIdTrait, V: ?Sized + VisitHooks>(&self, root: N, visitors: &mut V) {
+        for event in self.walk(root) {
+            match event {
+                WalkEvent::Enter(node_id) => enter_hooks(visitors, node_id, self),
+                WalkEvent::Leave(node_id) => leave_hooks(visitors, node_id, self),
+            }
+        }
+    }
+
+    /// Calls the hooks of `visitors` on entering and leaving each node, with
+    /// the [Ast] to edit.
+    ///
+    /// The children of a node are read after entering it, like in
+    /// [Ast::walk_mut].
+    pub fn walk_mut_hooks<N: NodeIdTrait, V: ?Sized + VisitMutHooks>(
+        &mut self,
+        root: N,
+        visitors: &mut V,
+    ) {
+        let mut stack = WalkStack::new(root.node_id());
+        while let Some(event) = stack.next(self) {
+            match event {
+                WalkEvent::Enter(node_id) => enter_mut_hooks(visitors, node_id, self),
+                WalkEvent::Leave(node_id) => leave_mut_hooks(visitors, node_id, self),
+            }
+        }
+    }
+
+    /// Calls `f` on entering and leaving each node, with the [Ast] to edit.
+    ///
+    /// The children of a node are read after entering it, so the nodes set as
+    /// its fields by `f` are visited instead of the replaced ones. The flow
+    /// returned on leaving a node is only checked for [VisitFlow::Break].
+    pub fn walk_mut<N, B>(
+        &mut self,
+        root: N,
+        mut f: impl FnMut(&mut Ast, WalkEvent) -> VisitFlow<B>,
+    ) -> ControlFlow<B>
+    where
+        N: NodeIdTrait,
+    {
+        let mut stack = WalkStack::new(root.node_id());
+        while let Some(event) = stack.next(self) {
+            match f(self, event) {
+                VisitFlow::Continue => {}
+                VisitFlow::SkipChildren => stack.skip_children(),
+                VisitFlow::Break(value) => return ControlFlow::Break(value),
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::DUMMY_SP;
+
+    use super::*;
+    use crate::{
+        BinExpr, BinaryOp, Expr, Ident, NodeKind, StringAllocator, Utf8Ref, Visit, VisitWith,
+    };
+
+    /// The depth of the ASTs, which overflows the stack of a recursive
+    /// traversal.
+    const DEPTH: usize = 100_000;
+
+    /// Returns `a + a + ... + a`, whose left operands are nested `depth` times.
+    fn chain(ast: &mut Ast, depth: usize) -> Expr {
+        let sym = ast.add_utf8("a");
+        let mut expr = ast.expr_ident(DUMMY_SP, sym, false);
+        for _ in 0..depth {
+            let right = ast.expr_ident(DUMMY_SP, sym, false);
+            expr = ast.expr_bin_expr(DUMMY_SP, BinaryOp::Add, expr, right);
+        }
+        expr
+    }
+
+    struct Recorder<'a> {
+        ast: &'a Ast,
+        events: Vec<WalkEvent>,
+    }
+
+    impl Visit for Recorder<'_> {
+        fn ast(&self) -> &Ast {
+            self.ast
+        }
+
+        fn enter_node(&mut self, node_id: NodeId) {
+            self.events.push(WalkEvent::Enter(node_id));
+        }
+
+        fn leave_node(&mut self, node_id: NodeId) {
+            self.events.push(WalkEvent::Leave(node_id));
+        }
+    }
+
+    #[derive(Default)]
+    struct Counter {
+        bin_exprs: usize,
+        idents: usize,
+        depth: usize,
+        max_depth: usize,
+    }
+
+    impl VisitHooks for Counter {
+        fn enter_node(&mut self, _: NodeId, _: &Ast) {
+            self.depth += 1;
+            self.max_depth = self.max_depth.max(self.depth);
+        }
+
+        fn leave_node(&mut self, _: NodeId, _: &Ast) {
+            self.depth -= 1;
+        }
+
+        fn enter_bin_expr(&mut self, _: BinExpr, _: &Ast) {
+            self.bin_exprs += 1;
+        }
+
+        fn leave_ident(&mut self, _: Ident, _: &Ast) {
+            self.idents += 1;
+        }
+    }
+
+    #[test]
+    fn test_walk_order() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let root = chain(&mut ast, 3);
+
+        let mut recorder = Recorder {
+            ast: &ast,
+            events: Vec::new(),
+        };
+        root.visit_with(&mut recorder);
+        let events = ast.walk(root).collect::<Vec<_>>();
+        assert_eq!(events, recorder.events);
+
+        let mut walk = ast.walk(root);
+        let first = walk.next();
+        walk.skip_children();
+        assert_eq!(first, Some(WalkEvent::Enter(root.node_id())));
+        assert_eq!(walk.next(), Some(WalkEvent::Leave(root.node_id())));
+        assert_eq!(walk.next(), None);
+    }
+
+    #[test]
+    fn test_walk_deep() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let root = chain(&mut ast, DEPTH);
+
+        let mut enters = 0;
+        let mut depth = 0usize;
+        for event in ast.walk(root) {
+            match event {
+                WalkEvent::Enter(_) => {
+                    enters += 1;
+                    depth += 1;
+                }
+                WalkEvent::Leave(_) => depth -= 1,
+            }
+        }
+        assert_eq!(enters, ast.node_count());
+        assert_eq!(depth, 0);
+
+        let mut counter = Counter::default();
+        ast.walk_hooks(root, &mut counter);
+        assert_eq!(counter.bin_exprs, DEPTH);
+        assert_eq!(counter.idents, DEPTH + 1);
+        assert_eq!(counter.max_depth, DEPTH + 1);
+    }
+
+    #[test]
+    fn test_walk_mut_deep() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let root = chain(&mut ast, DEPTH);
+
+        // Replaces every `+` with `-`, stopping at the innermost operator.
+        let flow = ast.walk_mut(root, |ast, event| {
+            let WalkEvent::Enter(node_id) = event else {
+                return VisitFlow::Continue;
+            };
+            if ast.get_node(node_id).unwrap().kind() != NodeKind::BinExpr {
+                return VisitFlow::Continue;
+            }
+            let bin = BinExpr::from_node_id(node_id, ast);
+            if let Expr::Ident(_) = bin.left(ast) {
+                return VisitFlow::<()>::Break(());
+            }
+            bin.set_op(ast, BinaryOp::Sub);
+            VisitFlow::Continue
+        });
+        assert_eq!(flow, ControlFlow::Break(()));
+
+        let ops = ast
+            .walk(root)
+            .filter_map(|event| match event {
+                WalkEvent::Enter(node_id) => BinExpr::try_from_node_id(node_id, &ast),
+                WalkEvent::Leave(_) => None,
+            })
+            .map(|bin| bin.op(&ast))
+            .collect::<Vec<_>>();
+        assert_eq!(ops.len(), DEPTH);
+        assert!(ops[..DEPTH - 1].iter().all(|op| *op == BinaryOp::Sub));
+        assert_eq!(ops[DEPTH - 1], BinaryOp::Add);
+
+        // Skipping the children of the root leaves the rest untouched.
+        let mut entered = 0;
+        let flow = ast.walk_mut(root, |_, event| {
+            if let WalkEvent::Enter(_) = event {
+                entered += 1;
+            }
+            VisitFlow::<()>::SkipChildren
+        });
+        assert_eq!(flow, ControlFlow::Continue(()));
+        assert_eq!(entered, 1);
+    }
+
+    /// Renames the right operands to `b` and replaces `+` with `-`.
+    struct Renamer {
+        b: Utf8Ref,
+        renamed: usize,
+    }
+
+    impl VisitMutHooks for Renamer {
+        fn enter_bin_expr(&mut self, node: BinExpr, ast: &mut Ast) {
+            node.set_op(ast, BinaryOp::Sub);
+            let right = ast.expr_ident(DUMMY_SP, self.b, false);
+            node.set_right(ast, right);
+        }
+
+        fn leave_ident(&mut self, node: Ident, ast: &mut Ast) {
+            if node.sym(ast) == self.b {
+                self.renamed += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_walk_mut_hooks_deep() {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let root = chain(&mut ast, DEPTH);
+
+        // The replaced right operands are visited instead of the old ones.
+        let b = ast.add_utf8("b");
+        let mut renamer = Renamer { b, renamed: 0 };
+        ast.walk_mut_hooks(root, &mut renamer);
+        assert_eq!(renamer.renamed, DEPTH);
+
+        let mut counter = Counter::default();
+        ast.walk_hooks(root, &mut counter);
+        assert_eq!(counter.bin_exprs, DEPTH);
+        assert!(ast.walk(root).all(|event| {
+            match event {
+                WalkEvent::Enter(node_id) => BinExpr::try_from_node_id(node_id, &ast)
+                    .is_none_or(|bin| bin.op(&ast) == BinaryOp::Sub),
+                WalkEvent::Leave(_) => true,
+            }
+        }));
+    }
+}
@@ -108,6 +108,21 @@ impl AstCompat<'_> {
             experimental::ModuleDecl::ExportAll(export_all) => {
                 legacy::ModuleDecl::ExportAll(self.compat_export_all(export_all))
             }
+            experimental::ModuleDecl::TsImportEquals(d) => {
+                legacy::ModuleDecl::TsImportEquals(Box::new(self.compat_ts_import_equals_decl(d)))
+            }
+            experimental::ModuleDecl::TsExportAssignment(e) => {
+                legacy::ModuleDecl::TsExportAssignment(legacy::TsExportAssignment {
+                    span: e.span(self.ast),
+                    expr: self.compat_expr(e.expr(self.ast)),
+                })
+            }
+            experimental::ModuleDecl::TsNamespaceExport(e) => {
+                legacy::ModuleDecl::TsNamespaceExport(legacy::TsNamespaceExportDecl {
+                    span: e.span(self.ast),
+                    id: self.compat_ident(e.id(self.ast)),
+                })
+            }
         }
     }
 
@@ -171,6 +186,9 @@ impl AstCompat<'_> {
                     legacy::DefaultDecl::Class(self.compat_class_expr(cls))
                 }
                 experimental::DefaultDecl::Fn(f) => legacy::DefaultDecl::Fn(self.compat_fn_expr(f)),
+                experimental::DefaultDecl::TsInterfaceDecl(d) => {
+                    legacy::DefaultDecl::TsInterfaceDecl(Box::new(self.compat_ts_interface_decl(d)))
+                }
             },
         }
     }
@@ -469,7 +487,9 @@ impl AstCompat<'_> {
                     experimental::Callee::Expr(e) => legacy::Callee::Expr(self.compat_expr(e)),
                 },
                 args: self.compat_type_sub_range(c.args(self.ast), Self::compat_expr_or_spread),
-                type_args: None,
+                type_args: c
+                    .type_args(self.ast)
+                    .map(|t| Box::new(self.compat_ts_type_param_instantiation(t))),
             }),
             experimental::Expr::New(n) => legacy::Expr::New(legacy::NewExpr {
                 span: n.span(self.ast),
@@ -478,7 +498,9 @@ impl AstCompat<'_> {
                 args: n
                     .args(self.ast)
                     .map(|args| self.compat_type_sub_range(args, Self::compat_expr_or_spread)),
-                type_args: None,
+                type_args: n
+                    .type_args(self.ast)
+                    .map(|t| Box::new(self.compat_ts_type_param_instantiation(t))),
             }),
             experimental::Expr::Seq(s) => legacy::Expr::Seq(legacy::SeqExpr {
                 span: s.span(self.ast),
@@ -496,7 +518,9 @@ impl AstCompat<'_> {
                 ctxt: Default::default(),
                 tag: self.compat_expr(tt.tag(self.ast)),
                 tpl: Box::new(self.compat_tpl(tt.tpl(self.ast))),
-                type_params: None,
+                type_params: tt
+                    .type_params(self.ast)
+                    .map(|t| Box::new(self.compat_ts_type_param_instantiation(t))),
             }),
             experimental::Expr::Arrow(a) => legacy::Expr::Arrow(legacy::ArrowExpr {
                 span: a.span(self.ast),
@@ -512,8 +536,12 @@ impl AstCompat<'_> {
                 }),
                 is_async: a.is_async(self.ast),
                 is_generator: a.is_generator(self.ast),
-                type_params: None,
-                return_type: None,
+                type_params: a
+                    .type_params(self.ast)
+                    .map(|t| Box::new(self.compat_ts_type_param_decl(t))),
+                return_type: a
+                    .return_type(self.ast)
+                    .map(|t| Box::new(self.compat_ts_type_ann(t))),
             }),
             experimental::Expr::Class(c) => legacy::Expr::Class(self.compat_class_expr(c)),
             experimental::Expr::Yield(y) => legacy::Expr::Yield(legacy::YieldExpr {
@@ -560,7 +588,9 @@ impl AstCompat<'_> {
                                 c.args(self.ast),
                                 Self::compat_expr_or_spread,
                             ),
-                            type_args: None,
+                            type_args: c
+                                .type_args(self.ast)
+                                .map(|t| Box::new(self.compat_ts_type_param_instantiation(t))),
                         })
                     }
                 }),
@@ -580,6 +610,25 @@ impl AstCompat<'_> {
             experimental::Expr::JSXFragment(j) => {
                 legacy::Expr::JSXFragment(self.compat_jsx_fragment(j))
             }
+            experimental::Expr::TsTypeAssertion(t) => {
+                legacy::Expr::TsTypeAssertion(self.compat_ts_type_assertion(t))
+            }
+            experimental::Expr::TsConstAssertion(t) => {
+                legacy::Expr::TsConstAssertion(legacy::TsConstAssertion {
+                    span: t.span(self.ast),
+                    expr: self.compat_expr(t.expr(self.ast)),
+                })
+            }
+            experimental::Expr::TsNonNull(t) => {
+                legacy::Expr::TsNonNull(self.compat_ts_non_null_expr(t))
+            }
+            experimental::Expr::TsAs(t) => legacy::Expr::TsAs(self.compat_ts_as_expr(t)),
+            experimental::Expr::TsInstantiation(t) => {
+                legacy::Expr::TsInstantiation(self.compat_ts_instantiation(t))
+            }
+            experimental::Expr::TsSatisfies(t) => {
+                legacy::Expr::TsSatisfies(self.compat_ts_satisfies_expr(t))
+            }
             experimental::Expr::Invalid(i) => legacy::Expr::Invalid(legacy::Invalid {
                 span: i.span(self.ast),
             }),
@@ -768,7 +817,9 @@ impl AstCompat<'_> {
             experimental::Prop::Getter(g) => legacy::Prop::Getter(legacy::GetterProp {
                 span: g.span(self.ast),
                 key: self.compat_prop_name(g.key(self.ast)),
-                type_ann: None,
+                type_ann: g
+                    .type_ann(self.ast)
+                    .map(|t| Box::new(self.compat_ts_type_ann(t))),
                 body: g.body(self.ast).map(|b| self.compat_block_stmt(b)),
             }),
             experimental::Prop::Setter(s) => legacy::Prop::Setter(legacy::SetterProp {
@@ -845,8 +896,12 @@ impl AstCompat<'_> {
             body: f.body(self.ast).map(|b| self.compat_block_stmt(b)),
             is_generator: f.is_generator(self.ast),
             is_async: f.is_async(self.ast),
-            type_params: None,
-            return_type: None,
+            type_params: f
+                .type_params(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_param_decl(t))),
+            return_type: f
+                .return_type(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_ann(t))),
         }
     }
 
@@ -873,9 +928,14 @@ impl AstCompat<'_> {
             body: self.compat_type_sub_range(c.body(self.ast), Self::compat_class_member),
             super_class: c.super_class(self.ast).map(|e| self.compat_expr(e)),
             is_abstract: c.is_abstract(self.ast),
-            type_params: None,
-            super_type_params: None,
-            implements: Default::default(),
+            type_params: c
+                .type_params(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_param_decl(t))),
+            super_type_params: c
+                .super_type_params(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_param_instantiation(t))),
+            implements: self
+                .compat_type_sub_range(c.implements(self.ast), Self::compat_ts_expr_with_type_args),
         }
     }
 
@@ -891,8 +951,8 @@ impl AstCompat<'_> {
                         Self::compat_param_or_ts_param_prop,
                     ),
                     body: k.body(self.ast).map(|b| self.compat_block_stmt(b)),
-                    accessibility: None,
-                    is_optional: false,
+                    accessibility: self.compat_opt_accessibility(k.accessibility(self.ast)),
+                    is_optional: k.is_optional(self.ast),
                 })
             }
             experimental::ClassMember::Method(me) => {
@@ -906,10 +966,10 @@ impl AstCompat<'_> {
                         experimental::MethodKind::Setter => legacy::MethodKind::Setter,
                     },
                     is_static: me.is_static(self.ast),
-                    accessibility: None,
-                    is_abstract: false,
-                    is_optional: false,
-                    is_override: false,
+                    accessibility: self.compat_opt_accessibility(me.accessibility(self.ast)),
+                    is_abstract: me.is_abstract(self.ast),
+                    is_optional: me.is_optional(self.ast),
+                    is_override: me.is_override(self.ast),
                 })
             }
             experimental::ClassMember::PrivateMethod(pm) => {
@@ -926,10 +986,10 @@ impl AstCompat<'_> {
                         experimental::MethodKind::Setter => legacy::MethodKind::Setter,
                     },
                     is_static: pm.is_static(self.ast),
-                    accessibility: None,
-                    is_abstract: false,
-                    is_optional: false,
-                    is_override: false,
+                    accessibility: self.compat_opt_accessibility(pm.accessibility(self.ast)),
+                    is_abstract: pm.is_abstract(self.ast),
+                    is_optional: pm.is_optional(self.ast),
+                    is_override: pm.is_override(self.ast),
                 })
             }
             experimental::ClassMember::ClassProp(cp) => {
@@ -937,17 +997,19 @@ impl AstCompat<'_> {
                     span: cp.span(self.ast),
                     key: self.compat_prop_name(cp.key(self.ast)),
                     value: cp.value(self.ast).map(|e| self.compat_expr(e)),
-                    type_ann: None,
+                    type_ann: cp
+                        .type_ann(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_ann(t))),
                     is_static: cp.is_static(self.ast),
                     decorators: self
                         .compat_type_sub_range(cp.decorators(self.ast), Self::compat_decorator),
-                    accessibility: None,
-                    is_abstract: false,
-                    is_optional: false,
-                    is_override: false,
-                    readonly: false,
-                    declare: false,
-                    definite: false,
+                    accessibility: self.compat_opt_accessibility(cp.accessibility(self.ast)),
+                    is_abstract: cp.is_abstract(self.ast),
+                    is_optional: cp.is_optional(self.ast),
+                    is_override: cp.is_override(self.ast),
+                    readonly: cp.readonly(self.ast),
+                    declare: cp.declare(self.ast),
+                    definite: cp.definite(self.ast),
                 })
             }
             experimental::ClassMember::PrivateProp(pp) => {
@@ -959,15 +1021,17 @@ impl AstCompat<'_> {
                         name: self.compat_utf8_ref(pp.key(self.ast).name(self.ast)),
                     },
                     value: pp.value(self.ast).map(|e| self.compat_expr(e)),
-                    type_ann: None,
+                    type_ann: pp
+                        .type_ann(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_ann(t))),
                     is_static: pp.is_static(self.ast),
                     decorators: self
                         .compat_type_sub_range(pp.decorators(self.ast), Self::compat_decorator),
-                    accessibility: None,
-                    is_optional: false,
-                    is_override: false,
-                    readonly: false,
-                    definite: false,
+                    accessibility: self.compat_opt_accessibility(pp.accessibility(self.ast)),
+                    is_optional: pp.is_optional(self.ast),
+                    is_override: pp.is_override(self.ast),
+                    readonly: pp.readonly(self.ast),
+                    definite: pp.definite(self.ast),
                 })
             }
             experimental::ClassMember::Empty(e) => {
@@ -994,16 +1058,21 @@ impl AstCompat<'_> {
                         }
                     },
                     value: a.value(self.ast).map(|e| self.compat_expr(e)),
-                    type_ann: None,
+                    type_ann: a
+                        .type_ann(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_ann(t))),
                     is_static: a.is_static(self.ast),
                     decorators: self
                         .compat_type_sub_range(a.decorators(self.ast), Self::compat_decorator),
-                    accessibility: None,
-                    is_abstract: false,
-                    is_override: false,
-                    definite: false,
+                    accessibility: self.compat_opt_accessibility(a.accessibility(self.ast)),
+                    is_abstract: a.is_abstract(self.ast),
+                    is_override: a.is_override(self.ast),
+                    definite: a.definite(self.ast),
                 })
             }
+            experimental::ClassMember::TsIndexSignature(s) => {
+                legacy::ClassMember::TsIndexSignature(self.compat_ts_index_signature(s))
+            }
         }
     }
 
@@ -1013,6 +1082,9 @@ impl AstCompat<'_> {
         p: experimental::ParamOrTsParamProp,
     ) -> legacy::ParamOrTsParamProp {
         match p {
+            experimental::ParamOrTsParamProp::TsParamProp(pp) => {
+                legacy::ParamOrTsParamProp::TsParamProp(self.compat_ts_param_prop(pp))
+            }
             experimental::ParamOrTsParamProp::Param(pp) => {
                 legacy::ParamOrTsParamProp::Param(self.compat_param(pp))
             }
@@ -1040,6 +1112,18 @@ impl AstCompat<'_> {
                 is_await: u.is_await(self.ast),
                 decls: self.compat_type_sub_range(u.decls(self.ast), Self::compat_var_declarator),
             })),
+            experimental::Decl::TsInterface(d) => {
+                legacy::Decl::TsInterface(Box::new(self.compat_ts_interface_decl(d)))
+            }
+            experimental::Decl::TsTypeAlias(d) => {
+                legacy::Decl::TsTypeAlias(Box::new(self.compat_ts_type_alias_decl(d)))
+            }
+            experimental::Decl::TsEnum(d) => {
+                legacy::Decl::TsEnum(Box::new(self.compat_ts_enum_decl(d)))
+            }
+            experimental::Decl::TsModule(d) => {
+                legacy::Decl::TsModule(Box::new(self.compat_ts_module_decl(d)))
+            }
         }
     }
 
@@ -1048,41 +1132,17 @@ impl AstCompat<'_> {
             span: d.span(self.ast),
             name: self.compat_pat(d.name(self.ast)),
             init: d.init(self.ast).map(|e| self.compat_expr(e)),
-            definite: false,
+            definite: d.definite(self.ast),
         }
     }
 
     fn compat_pat(&mut self, p: experimental::Pat) -> legacy::Pat {
         match p {
-            experimental::Pat::Ident(b) => legacy::Pat::Ident(legacy::BindingIdent {
-                id: self.compat_ident(b.id(self.ast)),
-                type_ann: None,
-            }),
-            experimental::Pat::Array(a) => legacy::Pat::Array(legacy::ArrayPat {
-                span: a.span(self.ast),
-                elems: self.compat_type_sub_range(a.elems(self.ast), |this, p| {
-                    p.map(|p| this.compat_pat(p))
-                }),
-                optional: a.optional(self.ast),
-                type_ann: None,
-            }),
-            experimental::Pat::Rest(r) => legacy::Pat::Rest(legacy::RestPat {
-                span: r.span(self.ast),
-                dot3_token: r.dot_3_token(self.ast),
-                arg: Box::new(self.compat_pat(r.arg(self.ast))),
-                type_ann: None,
-            }),
-            experimental::Pat::Object(o) => legacy::Pat::Object(legacy::ObjectPat {
-                span: o.span(self.ast),
-                props: self.compat_type_sub_range(o.props(self.ast), Self::compat_object_pat_prop),
-                optional: o.optional(self.ast),
-                type_ann: None,
-            }),
-            experimental::Pat::Assign(a) => legacy::Pat::Assign(legacy::AssignPat {
-                span: a.span(self.ast),
-                left: Box::new(self.compat_pat(a.left(self.ast))),
-                right: self.compat_expr(a.right(self.ast)),
-            }),
+            experimental::Pat::Ident(b) => legacy::Pat::Ident(self.compat_binding_ident(b)),
+            experimental::Pat::Array(a) => legacy::Pat::Array(self.compat_array_pat(a)),
+            experimental::Pat::Rest(r) => legacy::Pat::Rest(self.compat_rest_pat(r)),
+            experimental::Pat::Object(o) => legacy::Pat::Object(self.compat_object_pat(o)),
+            experimental::Pat::Assign(a) => legacy::Pat::Assign(self.compat_assign_pat(a)),
             experimental::Pat::Invalid(i) => legacy::Pat::Invalid(legacy::Invalid {
                 span: i.span(self.ast),
             }),
@@ -1090,6 +1150,57 @@ impl AstCompat<'_> {
         }
     }
 
+    fn compat_binding_ident(&mut self, b: experimental::BindingIdent) -> legacy::BindingIdent {
+        legacy::BindingIdent {
+            id: self.compat_ident(b.id(self.ast)),
+            type_ann: b
+                .type_ann(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_ann(t))),
+        }
+    }
+
+    fn compat_array_pat(&mut self, a: experimental::ArrayPat) -> legacy::ArrayPat {
+        legacy::ArrayPat {
+            span: a.span(self.ast),
+            elems: self
+                .compat_type_sub_range(a.elems(self.ast), |this, p| p.map(|p| this.compat_pat(p))),
+            optional: a.optional(self.ast),
+            type_ann: a
+                .type_ann(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_ann(t))),
+        }
+    }
+
+    fn compat_rest_pat(&mut self, r: experimental::RestPat) -> legacy::RestPat {
+        legacy::RestPat {
+            span: r.span(self.ast),
+            dot3_token: r.dot_3_token(self.ast),
+            arg: Box::new(self.compat_pat(r.arg(self.ast))),
+            type_ann: r
+                .type_ann(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_ann(t))),
+        }
+    }
+
+    fn compat_object_pat(&mut self, o: experimental::ObjectPat) -> legacy::ObjectPat {
+        legacy::ObjectPat {
+            span: o.span(self.ast),
+            props: self.compat_type_sub_range(o.props(self.ast), Self::compat_object_pat_prop),
+            optional: o.optional(self.ast),
+            type_ann: o
+                .type_ann(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_ann(t))),
+        }
+    }
+
+    fn compat_assign_pat(&mut self, a: experimental::AssignPat) -> legacy::AssignPat {
+        legacy::AssignPat {
+            span: a.span(self.ast),
+            left: Box::new(self.compat_pat(a.left(self.ast))),
+            right: self.compat_expr(a.right(self.ast)),
+        }
+    }
+
     fn compat_object_pat_prop(&mut self, p: experimental::ObjectPatProp) -> legacy::ObjectPatProp {
         match p {
             experimental::ObjectPatProp::KeyValue(kv) => {
@@ -1101,19 +1212,13 @@ impl AstCompat<'_> {
             experimental::ObjectPatProp::Assign(ap) => {
                 legacy::ObjectPatProp::Assign(legacy::AssignPatProp {
                     span: ap.span(self.ast),
-                    key: legacy::BindingIdent {
-                        id: self.compat_ident(ap.key(self.ast).id(self.ast)),
-                        type_ann: None,
-                    },
+                    key: self.compat_binding_ident(ap.key(self.ast)),
                     value: ap.value(self.ast).map(|e| self.compat_expr(e)),
                 })
             }
-            experimental::ObjectPatProp::Rest(r) => legacy::ObjectPatProp::Rest(legacy::RestPat {
-                span: r.span(self.ast),
-                dot3_token: r.dot_3_token(self.ast),
-                arg: Box::new(self.compat_pat(r.arg(self.ast))),
-                type_ann: None,
-            }),
+            experimental::ObjectPatProp::Rest(r) => {
+                legacy::ObjectPatProp::Rest(self.compat_rest_pat(r))
+            }
         }
     }
     fn compat_expr_or_spread(&mut self, e: experimental::ExprOrSpread) -> legacy::ExprOrSpread {
@@ -1193,10 +1298,7 @@ impl AstCompat<'_> {
         match t {
             experimental::AssignTarget::Simple(s) => legacy::AssignTarget::Simple(match s {
                 experimental::SimpleAssignTarget::Ident(b) => {
-                    legacy::SimpleAssignTarget::Ident(legacy::BindingIdent {
-                        id: self.compat_ident(b.id(self.ast)),
-                        type_ann: None,
-                    })
+                    legacy::SimpleAssignTarget::Ident(self.compat_binding_ident(b))
                 }
                 experimental::SimpleAssignTarget::Member(m) => {
                     legacy::SimpleAssignTarget::Member(legacy::MemberExpr {
@@ -1254,12 +1356,29 @@ impl AstCompat<'_> {
                                         c.args(self.ast),
                                         Self::compat_expr_or_spread,
                                     ),
-                                    type_args: None,
+                                    type_args: c.type_args(self.ast).map(|t| {
+                                        Box::new(self.compat_ts_type_param_instantiation(t))
+                                    }),
                                 })
                             }
                         }),
                     })
                 }
+                experimental::SimpleAssignTarget::TsAs(t) => {
+                    legacy::SimpleAssignTarget::TsAs(self.compat_ts_as_expr(t))
+                }
+                experimental::SimpleAssignTarget::TsSatisfies(t) => {
+                    legacy::SimpleAssignTarget::TsSatisfies(self.compat_ts_satisfies_expr(t))
+                }
+                experimental::SimpleAssignTarget::TsNonNull(t) => {
+                    legacy::SimpleAssignTarget::TsNonNull(self.compat_ts_non_null_expr(t))
+                }
+                experimental::SimpleAssignTarget::TsTypeAssertion(t) => {
+                    legacy::SimpleAssignTarget::TsTypeAssertion(self.compat_ts_type_assertion(t))
+                }
+                experimental::SimpleAssignTarget::TsInstantiation(t) => {
+                    legacy::SimpleAssignTarget::TsInstantiation(self.compat_ts_instantiation(t))
+                }
                 experimental::SimpleAssignTarget::Invalid(i) => {
                     legacy::SimpleAssignTarget::Invalid(legacy::Invalid {
                         span: i.span(self.ast),
@@ -1274,7 +1393,9 @@ impl AstCompat<'_> {
                             pat.map(|pat| this.compat_pat(pat))
                         }),
                         optional: false,
-                        type_ann: None,
+                        type_ann: a
+                            .type_ann(self.ast)
+                            .map(|t| Box::new(self.compat_ts_type_ann(t))),
                     })
                 }
                 experimental::AssignTargetPat::Object(o) => {
@@ -1283,7 +1404,9 @@ impl AstCompat<'_> {
                         props: self
                             .compat_type_sub_range(o.props(self.ast), Self::compat_object_pat_prop),
                         optional: false,
-                        type_ann: None,
+                        type_ann: o
+                            .type_ann(self.ast)
+                            .map(|t| Box::new(self.compat_ts_type_ann(t))),
                     })
                 }
                 experimental::AssignTargetPat::Invalid(i) => {
@@ -1444,7 +1567,9 @@ impl AstCompat<'_> {
             name: self.compat_jsx_element_name(o.name(self.ast)),
             attrs: self.compat_type_sub_range(o.attrs(self.ast), Self::compat_jsx_attr_or_spread),
             self_closing: o.self_closing(self.ast),
-            type_args: None,
+            type_args: o
+                .type_args(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_param_instantiation(t))),
         }
     }
 
@@ -1544,6 +1669,675 @@ impl AstCompat<'_> {
         }
     }
 
+    // -------------------------------------------------------------------------------
+    // TypeScript compatibility helpers
+
+    fn compat_opt_accessibility(
+        &mut self,
+        a: Option<experimental::Accessibility>,
+    ) -> Option<legacy::Accessibility> {
+        a.map(|a| match a {
+            experimental::Accessibility::Public => legacy::Accessibility::Public,
+            experimental::Accessibility::Protected => legacy::Accessibility::Protected,
+            experimental::Accessibility::Private => legacy::Accessibility::Private,
+        })
+    }
+
+    fn compat_opt_true_plus_minus(
+        &mut self,
+        t: Option<experimental::TruePlusMinus>,
+    ) -> Option<legacy::TruePlusMinus> {
+        t.map(|t| match t {
+            experimental::TruePlusMinus::True => legacy::TruePlusMinus::True,
+            experimental::TruePlusMinus::Plus => legacy::TruePlusMinus::Plus,
+            experimental::TruePlusMinus::Minus => legacy::TruePlusMinus::Minus,
+        })
+    }
+
+    fn compat_ts_type_ann(&mut self, t: experimental::TsTypeAnn) -> legacy::TsTypeAnn {
+        legacy::TsTypeAnn {
+            span: t.span(self.ast),
+            type_ann: self.compat_ts_type(t.type_ann(self.ast)),
+        }
+    }
+
+    fn compat_ts_type_param_decl(
+        &mut self,
+        t: experimental::TsTypeParamDecl,
+    ) -> legacy::TsTypeParamDecl {
+        legacy::TsTypeParamDecl {
+            span: t.span(self.ast),
+            params: self.compat_type_sub_range(t.params(self.ast), Self::compat_ts_type_param),
+        }
+    }
+
+    fn compat_ts_type_param(&mut self, t: experimental::TsTypeParam) -> legacy::TsTypeParam {
+        legacy::TsTypeParam {
+            span: t.span(self.ast),
+            name: self.compat_ident(t.name(self.ast)),
+            is_in: t.is_in(self.ast),
+            is_out: t.is_out(self.ast),
+            is_const: t.is_const(self.ast),
+            constraint: t.constraint(self.ast).map(|t| self.compat_ts_type(t)),
+            default: t.default(self.ast).map(|t| self.compat_ts_type(t)),
+        }
+    }
+
+    fn compat_ts_type_param_instantiation(
+        &mut self,
+        t: experimental::TsTypeParamInstantiation,
+    ) -> legacy::TsTypeParamInstantiation {
+        legacy::TsTypeParamInstantiation {
+            span: t.span(self.ast),
+            params: self.compat_type_sub_range(t.params(self.ast), Self::compat_ts_type),
+        }
+    }
+
+    fn compat_ts_param_prop(&mut self, p: experimental::TsParamProp) -> legacy::TsParamProp {
+        legacy::TsParamProp {
+            span: p.span(self.ast),
+            decorators: self.compat_type_sub_range(p.decorators(self.ast), Self::compat_decorator),
+            accessibility: self.compat_opt_accessibility(p.accessibility(self.ast)),
+            is_override: p.is_override(self.ast),
+            readonly: p.readonly(self.ast),
+            param: match p.param(self.ast) {
+                experimental::TsParamPropParam::Ident(b) => {
+                    legacy::TsParamPropParam::Ident(self.compat_binding_ident(b))
+                }
+                experimental::TsParamPropParam::Assign(a) => {
+                    legacy::TsParamPropParam::Assign(self.compat_assign_pat(a))
+                }
+            },
+        }
+    }
+
+    fn compat_ts_entity_name(&mut self, n: experimental::TsEntityName) -> legacy::TsEntityName {
+        match n {
+            experimental::TsEntityName::TsQualifiedName(q) => {
+                legacy::TsEntityName::TsQualifiedName(Box::new(legacy::TsQualifiedName {
+                    span: q.span(self.ast),
+                    left: self.compat_ts_entity_name(q.left(self.ast)),
+                    right: self.compat_ident_name(q.right(self.ast)),
+                }))
+            }
+            experimental::TsEntityName::Ident(i) => {
+                legacy::TsEntityName::Ident(self.compat_ident(i))
+            }
+        }
+    }
+
+    fn compat_ts_type_element(&mut self, e: experimental::TsTypeElement) -> legacy::TsTypeElement {
+        match e {
+            experimental::TsTypeElement::TsCallSignatureDecl(d) => {
+                legacy::TsTypeElement::TsCallSignatureDecl(legacy::TsCallSignatureDecl {
+                    span: d.span(self.ast),
+                    params: self
+                        .compat_type_sub_range(d.params(self.ast), Self::compat_ts_fn_param),
+                    type_ann: d
+                        .type_ann(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_ann(t))),
+                    type_params: d
+                        .type_params(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_param_decl(t))),
+                })
+            }
+            experimental::TsTypeElement::TsConstructSignatureDecl(d) => {
+                legacy::TsTypeElement::TsConstructSignatureDecl(legacy::TsConstructSignatureDecl {
+                    span: d.span(self.ast),
+                    params: self
+                        .compat_type_sub_range(d.params(self.ast), Self::compat_ts_fn_param),
+                    type_ann: d
+                        .type_ann(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_ann(t))),
+                    type_params: d
+                        .type_params(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_param_decl(t))),
+                })
+            }
+            experimental::TsTypeElement::TsPropertySignature(p) => {
+                legacy::TsTypeElement::TsPropertySignature(legacy::TsPropertySignature {
+                    span: p.span(self.ast),
+                    readonly: p.readonly(self.ast),
+                    key: self.compat_expr(p.key(self.ast)),
+                    computed: p.computed(self.ast),
+                    optional: p.optional(self.ast),
+                    type_ann: p
+                        .type_ann(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_ann(t))),
+                })
+            }
+            experimental::TsTypeElement::TsGetterSignature(g) => {
+                legacy::TsTypeElement::TsGetterSignature(legacy::TsGetterSignature {
+                    span: g.span(self.ast),
+                    key: self.compat_expr(g.key(self.ast)),
+                    computed: g.computed(self.ast),
+                    type_ann: g
+                        .type_ann(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_ann(t))),
+                })
+            }
+            experimental::TsTypeElement::TsSetterSignature(s) => {
+                legacy::TsTypeElement::TsSetterSignature(legacy::TsSetterSignature {
+                    span: s.span(self.ast),
+                    key: self.compat_expr(s.key(self.ast)),
+                    computed: s.computed(self.ast),
+                    param: self.compat_ts_fn_param(s.param(self.ast)),
+                })
+            }
+            experimental::TsTypeElement::TsMethodSignature(m) => {
+                legacy::TsTypeElement::TsMethodSignature(legacy::TsMethodSignature {
+                    span: m.span(self.ast),
+                    key: self.compat_expr(m.key(self.ast)),
+                    computed: m.computed(self.ast),
+                    optional: m.optional(self.ast),
+                    params: self
+                        .compat_type_sub_range(m.params(self.ast), Self::compat_ts_fn_param),
+                    type_ann: m
+                        .type_ann(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_ann(t))),
+                    type_params: m
+                        .type_params(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_param_decl(t))),
+                })
+            }
+            experimental::TsTypeElement::TsIndexSignature(s) => {
+                legacy::TsTypeElement::TsIndexSignature(self.compat_ts_index_signature(s))
+            }
+        }
+    }
+
+    fn compat_ts_index_signature(
+        &mut self,
+        s: experimental::TsIndexSignature,
+    ) -> legacy::TsIndexSignature {
+        legacy::TsIndexSignature {
+            params: self.compat_type_sub_range(s.params(self.ast), Self::compat_ts_fn_param),
+            type_ann: s
+                .type_ann(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_ann(t))),
+            readonly: s.readonly(self.ast),
+            is_static: s.is_static(self.ast),
+            span: s.span(self.ast),
+        }
+    }
+
+    fn compat_ts_fn_param(&mut self, p: experimental::TsFnParam) -> legacy::TsFnParam {
+        match p {
+            experimental::TsFnParam::Ident(b) => {
+                legacy::TsFnParam::Ident(self.compat_binding_ident(b))
+            }
+            experimental::TsFnParam::Array(a) => legacy::TsFnParam::Array(self.compat_array_pat(a)),
+            experimental::TsFnParam::Rest(r) => legacy::TsFnParam::Rest(self.compat_rest_pat(r)),
+            experimental::TsFnParam::Object(o) => {
+                legacy::TsFnParam::Object(self.compat_object_pat(o))
+            }
+        }
+    }
+
+    fn compat_ts_type(&mut self, t: experimental::TsType) -> Box<legacy::TsType> {
+        Box::new(match t {
+            experimental::TsType::TsKeywordType(k) => {
+                legacy::TsType::TsKeywordType(legacy::TsKeywordType {
+                    span: k.span(self.ast),
+                    kind: match k.kind(self.ast) {
+                        experimental::TsKeywordTypeKind::TsAnyKeyword => {
+                            legacy::TsKeywordTypeKind::TsAnyKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsUnknownKeyword => {
+                            legacy::TsKeywordTypeKind::TsUnknownKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsNumberKeyword => {
+                            legacy::TsKeywordTypeKind::TsNumberKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsObjectKeyword => {
+                            legacy::TsKeywordTypeKind::TsObjectKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsBooleanKeyword => {
+                            legacy::TsKeywordTypeKind::TsBooleanKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsBigIntKeyword => {
+                            legacy::TsKeywordTypeKind::TsBigIntKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsStringKeyword => {
+                            legacy::TsKeywordTypeKind::TsStringKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsSymbolKeyword => {
+                            legacy::TsKeywordTypeKind::TsSymbolKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsVoidKeyword => {
+                            legacy::TsKeywordTypeKind::TsVoidKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsUndefinedKeyword => {
+                            legacy::TsKeywordTypeKind::TsUndefinedKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsNullKeyword => {
+                            legacy::TsKeywordTypeKind::TsNullKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsNeverKeyword => {
+                            legacy::TsKeywordTypeKind::TsNeverKeyword
+                        }
+                        experimental::TsKeywordTypeKind::TsIntrinsicKeyword => {
+                            legacy::TsKeywordTypeKind::TsIntrinsicKeyword
+                        }
+                    },
+                })
+            }
+            experimental::TsType::TsThisType(t) => {
+                legacy::TsType::TsThisType(self.compat_ts_this_type(t))
+            }
+            experimental::TsType::TsFnOrConstructorType(f) => {
+                legacy::TsType::TsFnOrConstructorType(match f {
+                    experimental::TsFnOrConstructorType::TsFnType(f) => {
+                        legacy::TsFnOrConstructorType::TsFnType(legacy::TsFnType {
+                            span: f.span(self.ast),
+                            params: self.compat_type_sub_range(
+                                f.params(self.ast),
+                                Self::compat_ts_fn_param,
+                            ),
+                            type_params: f
+                                .type_params(self.ast)
+                                .map(|t| Box::new(self.compat_ts_type_param_decl(t))),
+                            type_ann: Box::new(self.compat_ts_type_ann(f.type_ann(self.ast))),
+                        })
+                    }
+                    experimental::TsFnOrConstructorType::TsConstructorType(c) => {
+                        legacy::TsFnOrConstructorType::TsConstructorType(
+                            legacy::TsConstructorType {
+                                span: c.span(self.ast),
+                                params: self.compat_type_sub_range(
+                                    c.params(self.ast),
+                                    Self::compat_ts_fn_param,
+                                ),
+                                type_params: c
+                                    .type_params(self.ast)
+                                    .map(|t| Box::new(self.compat_ts_type_param_decl(t))),
+                                type_ann: Box::new(self.compat_ts_type_ann(c.type_ann(self.ast))),
+                                is_abstract: c.is_abstract(self.ast),
+                            },
+                        )
+                    }
+                })
+            }
+            experimental::TsType::TsTypeRef(r) => legacy::TsType::TsTypeRef(legacy::TsTypeRef {
+                span: r.span(self.ast),
+                type_name: self.compat_ts_entity_name(r.type_name(self.ast)),
+                type_params: r
+                    .type_params(self.ast)
+                    .map(|t| Box::new(self.compat_ts_type_param_instantiation(t))),
+            }),
+            experimental::TsType::TsTypeQuery(q) => {
+                legacy::TsType::TsTypeQuery(legacy::TsTypeQuery {
+                    span: q.span(self.ast),
+                    expr_name: match q.expr_name(self.ast) {
+                        experimental::TsTypeQueryExpr::TsEntityName(n) => {
+                            legacy::TsTypeQueryExpr::TsEntityName(self.compat_ts_entity_name(n))
+                        }
+                        experimental::TsTypeQueryExpr::Import(i) => {
+                            legacy::TsTypeQueryExpr::Import(self.compat_ts_import_type(i))
+                        }
+                    },
+                    type_args: q
+                        .type_args(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_param_instantiation(t))),
+                })
+            }
+            experimental::TsType::TsTypeLit(l) => legacy::TsType::TsTypeLit(legacy::TsTypeLit {
+                span: l.span(self.ast),
+                members: self
+                    .compat_type_sub_range(l.members(self.ast), Self::compat_ts_type_element),
+            }),
+            experimental::TsType::TsArrayType(a) => {
+                legacy::TsType::TsArrayType(legacy::TsArrayType {
+                    span: a.span(self.ast),
+                    elem_type: self.compat_ts_type(a.elem_type(self.ast)),
+                })
+            }
+            experimental::TsType::TsTupleType(t) => {
+                legacy::TsType::TsTupleType(legacy::TsTupleType {
+                    span: t.span(self.ast),
+                    elem_types: self.compat_type_sub_range(t.elem_types(self.ast), |this, e| {
+                        legacy::TsTupleElement {
+                            span: e.span(this.ast),
+                            label: e.label(this.ast).map(|p| this.compat_pat(p)),
+                            ty: this.compat_ts_type(e.ty(this.ast)),
+                        }
+                    }),
+                })
+            }
+            experimental::TsType::TsOptionalType(o) => {
+                legacy::TsType::TsOptionalType(legacy::TsOptionalType {
+                    span: o.span(self.ast),
+                    type_ann: self.compat_ts_type(o.type_ann(self.ast)),
+                })
+            }
+            experimental::TsType::TsRestType(r) => legacy::TsType::TsRestType(legacy::TsRestType {
+                span: r.span(self.ast),
+                type_ann: self.compat_ts_type(r.type_ann(self.ast)),
+            }),
+            experimental::TsType::TsUnionOrIntersectionType(u) => {
+                legacy::TsType::TsUnionOrIntersectionType(match u {
+                    experimental::TsUnionOrIntersectionType::TsUnionType(u) => {
+                        legacy::TsUnionOrIntersectionType::TsUnionType(legacy::TsUnionType {
+                            span: u.span(self.ast),
+                            types: self
+                                .compat_type_sub_range(u.types(self.ast), Self::compat_ts_type),
+                        })
+                    }
+                    experimental::TsUnionOrIntersectionType::TsIntersectionType(i) => {
+                        legacy::TsUnionOrIntersectionType::TsIntersectionType(
+                            legacy::TsIntersectionType {
+                                span: i.span(self.ast),
+                                types: self
+                                    .compat_type_sub_range(i.types(self.ast), Self::compat_ts_type),
+                            },
+                        )
+                    }
+                })
+            }
+            experimental::TsType::TsConditionalType(c) => {
+                legacy::TsType::TsConditionalType(legacy::TsConditionalType {
+                    span: c.span(self.ast),
+                    check_type: self.compat_ts_type(c.check_type(self.ast)),
+                    extends_type: self.compat_ts_type(c.extends_type(self.ast)),
+                    true_type: self.compat_ts_type(c.true_type(self.ast)),
+                    false_type: self.compat_ts_type(c.false_type(self.ast)),
+                })
+            }
+            experimental::TsType::TsInferType(i) => {
+                legacy::TsType::TsInferType(legacy::TsInferType {
+                    span: i.span(self.ast),
+                    type_param: self.compat_ts_type_param(i.type_param(self.ast)),
+                })
+            }
+            experimental::TsType::TsParenthesizedType(p) => {
+                legacy::TsType::TsParenthesizedType(legacy::TsParenthesizedType {
+                    span: p.span(self.ast),
+                    type_ann: self.compat_ts_type(p.type_ann(self.ast)),
+                })
+            }
+            experimental::TsType::TsTypeOperator(o) => {
+                legacy::TsType::TsTypeOperator(legacy::TsTypeOperator {
+                    span: o.span(self.ast),
+                    op: match o.op(self.ast) {
+                        experimental::TsTypeOperatorOp::KeyOf => legacy::TsTypeOperatorOp::KeyOf,
+                        experimental::TsTypeOperatorOp::Unique => legacy::TsTypeOperatorOp::Unique,
+                        experimental::TsTypeOperatorOp::ReadOnly => {
+                            legacy::TsTypeOperatorOp::ReadOnly
+                        }
+                    },
+                    type_ann: self.compat_ts_type(o.type_ann(self.ast)),
+                })
+            }
+            experimental::TsType::TsIndexedAccessType(i) => {
+                legacy::TsType::TsIndexedAccessType(legacy::TsIndexedAccessType {
+                    span: i.span(self.ast),
+                    readonly: i.readonly(self.ast),
+                    obj_type: self.compat_ts_type(i.obj_type(self.ast)),
+                    index_type: self.compat_ts_type(i.index_type(self.ast)),
+                })
+            }
+            experimental::TsType::TsMappedType(m) => {
+                legacy::TsType::TsMappedType(legacy::TsMappedType {
+                    span: m.span(self.ast),
+                    readonly: self.compat_opt_true_plus_minus(m.readonly(self.ast)),
+                    type_param: self.compat_ts_type_param(m.type_param(self.ast)),
+                    name_type: m.name_type(self.ast).map(|t| self.compat_ts_type(t)),
+                    optional: self.compat_opt_true_plus_minus(m.optional(self.ast)),
+                    type_ann: m.type_ann(self.ast).map(|t| self.compat_ts_type(t)),
+                })
+            }
+            experimental::TsType::TsLitType(l) => legacy::TsType::TsLitType(legacy::TsLitType {
+                span: l.span(self.ast),
+                lit: match l.lit(self.ast) {
+                    experimental::TsLit::Number(n) => legacy::TsLit::Number(legacy::Number {
+                        span: n.span(self.ast),
+                        value: n.value(self.ast),
+                        raw: self.compat_opt_utf8_ref(n.raw(self.ast)),
+                    }),
+                    experimental::TsLit::Str(s) => legacy::TsLit::Str(self.compat_str(s)),
+                    experimental::TsLit::Bool(b) => legacy::TsLit::Bool(legacy::Bool {
+                        span: b.span(self.ast),
+                        value: b.value(self.ast),
+                    }),
+                    experimental::TsLit::BigInt(b) => legacy::TsLit::BigInt(legacy::BigInt {
+                        span: b.span(self.ast),
+                        value: Box::new(self.compat_big_int(b.value(self.ast))),
+                        raw: self.compat_opt_utf8_ref(b.raw(self.ast)),
+                    }),
+                    experimental::TsLit::Tpl(t) => legacy::TsLit::Tpl(legacy::TsTplLitType {
+                        span: t.span(self.ast),
+                        types: self.compat_type_sub_range(t.types(self.ast), Self::compat_ts_type),
+                        quasis: self
+                            .compat_type_sub_range(t.quasis(self.ast), Self::compat_tpl_element),
+                    }),
+                },
+            }),
+            experimental::TsType::TsTypePredicate(p) => {
+                legacy::TsType::TsTypePredicate(legacy::TsTypePredicate {
+                    span: p.span(self.ast),
+                    asserts: p.asserts(self.ast),
+                    param_name: match p.param_name(self.ast) {
+                        experimental::TsThisTypeOrIdent::TsThisType(t) => {
+                            legacy::TsThisTypeOrIdent::TsThisType(self.compat_ts_this_type(t))
+                        }
+                        experimental::TsThisTypeOrIdent::Ident(i) => {
+                            legacy::TsThisTypeOrIdent::Ident(self.compat_ident(i))
+                        }
+                    },
+                    type_ann: p
+                        .type_ann(self.ast)
+                        .map(|t| Box::new(self.compat_ts_type_ann(t))),
+                })
+            }
+            experimental::TsType::TsImportType(i) => {
+                legacy::TsType::TsImportType(self.compat_ts_import_type(i))
+            }
+        })
+    }
+
+    fn compat_ts_this_type(&mut self, t: experimental::TsThisType) -> legacy::TsThisType {
+        legacy::TsThisType {
+            span: t.span(self.ast),
+        }
+    }
+
+    fn compat_ts_import_type(&mut self, i: experimental::TsImportType) -> legacy::TsImportType {
+        legacy::TsImportType {
+            span: i.span(self.ast),
+            arg: self.compat_str(i.arg(self.ast)),
+            qualifier: i.qualifier(self.ast).map(|q| self.compat_ts_entity_name(q)),
+            type_args: i
+                .type_args(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_param_instantiation(t))),
+            attributes: i.attributes(self.ast).map(|a| legacy::TsImportCallOptions {
+                span: a.span(self.ast),
+                with: Box::new(self.compat_object_lit(a.with(self.ast))),
+            }),
+        }
+    }
+
+    fn compat_ts_interface_decl(
+        &mut self,
+        d: experimental::TsInterfaceDecl,
+    ) -> legacy::TsInterfaceDecl {
+        let body = d.body(self.ast);
+        legacy::TsInterfaceDecl {
+            span: d.span(self.ast),
+            id: self.compat_ident(d.id(self.ast)),
+            declare: d.declare(self.ast),
+            type_params: d
+                .type_params(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_param_decl(t))),
+            extends: self
+                .compat_type_sub_range(d.extends(self.ast), Self::compat_ts_expr_with_type_args),
+            body: legacy::TsInterfaceBody {
+                span: body.span(self.ast),
+                body: self.compat_type_sub_range(body.body(self.ast), Self::compat_ts_type_element),
+            },
+        }
+    }
+
+    fn compat_ts_expr_with_type_args(
+        &mut self,
+        e: experimental::TsExprWithTypeArgs,
+    ) -> legacy::TsExprWithTypeArgs {
+        legacy::TsExprWithTypeArgs {
+            span: e.span(self.ast),
+            expr: self.compat_expr(e.expr(self.ast)),
+            type_args: e
+                .type_args(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_param_instantiation(t))),
+        }
+    }
+
+    fn compat_ts_type_alias_decl(
+        &mut self,
+        d: experimental::TsTypeAliasDecl,
+    ) -> legacy::TsTypeAliasDecl {
+        legacy::TsTypeAliasDecl {
+            span: d.span(self.ast),
+            declare: d.declare(self.ast),
+            id: self.compat_ident(d.id(self.ast)),
+            type_params: d
+                .type_params(self.ast)
+                .map(|t| Box::new(self.compat_ts_type_param_decl(t))),
+            type_ann: self.compat_ts_type(d.type_ann(self.ast)),
+        }
+    }
+
+    fn compat_ts_enum_decl(&mut self, d: experimental::TsEnumDecl) -> legacy::TsEnumDecl {
+        legacy::TsEnumDecl {
+            span: d.span(self.ast),
+            declare: d.declare(self.ast),
+            is_const: d.is_const(self.ast),
+            id: self.compat_ident(d.id(self.ast)),
+            members: self.compat_type_sub_range(d.members(self.ast), |this, m| {
+                legacy::TsEnumMember {
+                    span: m.span(this.ast),
+                    id: match m.id(this.ast) {
+                        experimental::TsEnumMemberId::Ident(i) => {
+                            legacy::TsEnumMemberId::Ident(this.compat_ident(i))
+                        }
+                        experimental::TsEnumMemberId::Str(s) => {
+                            legacy::TsEnumMemberId::Str(this.compat_str(s))
+                        }
+                    },
+                    init: m.init(this.ast).map(|e| this.compat_expr(e)),
+                }
+            }),
+        }
+    }
+
+    fn compat_ts_module_decl(&mut self, d: experimental::TsModuleDecl) -> legacy::TsModuleDecl {
+        legacy::TsModuleDecl {
+            span: d.span(self.ast),
+            declare: d.declare(self.ast),
+            global: d.global(self.ast),
+            namespace: d.namespace(self.ast),
+            id: match d.id(self.ast) {
+                experimental::TsModuleName::Ident(i) => {
+                    legacy::TsModuleName::Ident(self.compat_ident(i))
+                }
+                experimental::TsModuleName::Str(s) => legacy::TsModuleName::Str(self.compat_str(s)),
+            },
+            body: d.body(self.ast).map(|b| self.compat_ts_namespace_body(b)),
+        }
+    }
+
+    fn compat_ts_namespace_body(
+        &mut self,
+        b: experimental::TsNamespaceBody,
+    ) -> legacy::TsNamespaceBody {
+        match b {
+            experimental::TsNamespaceBody::TsModuleBlock(b) => {
+                legacy::TsNamespaceBody::TsModuleBlock(legacy::TsModuleBlock {
+                    span: b.span(self.ast),
+                    body: self.compat_type_sub_range(b.body(self.ast), Self::compat_module_item),
+                })
+            }
+            experimental::TsNamespaceBody::TsNamespaceDecl(d) => {
+                legacy::TsNamespaceBody::TsNamespaceDecl(legacy::TsNamespaceDecl {
+                    span: d.span(self.ast),
+                    declare: d.declare(self.ast),
+                    global: d.global(self.ast),
+                    id: self.compat_ident(d.id(self.ast)),
+                    body: Box::new(self.compat_ts_namespace_body(d.body(self.ast))),
+                })
+            }
+        }
+    }
+
+    fn compat_ts_import_equals_decl(
+        &mut self,
+        d: experimental::TsImportEqualsDecl,
+    ) -> legacy::TsImportEqualsDecl {
+        legacy::TsImportEqualsDecl {
+            span: d.span(self.ast),
+            is_export: d.is_export(self.ast),
+            is_type_only: d.is_type_only(self.ast),
+            id: self.compat_ident(d.id(self.ast)),
+            module_ref: match d.module_ref(self.ast) {
+                experimental::TsModuleRef::TsEntityName(n) => {
+                    legacy::TsModuleRef::TsEntityName(self.compat_ts_entity_name(n))
+                }
+                experimental::TsModuleRef::TsExternalModuleRef(r) => {
+                    legacy::TsModuleRef::TsExternalModuleRef(legacy::TsExternalModuleRef {
+                        span: r.span(self.ast),
+                        expr: self.compat_str(r.expr(self.ast)),
+                    })
+                }
+            },
+        }
+    }
+
+    fn compat_ts_as_expr(&mut self, e: experimental::TsAsExpr) -> legacy::TsAsExpr {
+        legacy::TsAsExpr {
+            span: e.span(self.ast),
+            expr: self.compat_expr(e.expr(self.ast)),
+            type_ann: self.compat_ts_type(e.type_ann(self.ast)),
+        }
+    }
+
+    fn compat_ts_satisfies_expr(
+        &mut self,
+        e: experimental::TsSatisfiesExpr,
+    ) -> legacy::TsSatisfiesExpr {
+        legacy::TsSatisfiesExpr {
+            span: e.span(self.ast),
+            expr: self.compat_expr(e.expr(self.ast)),
+            type_ann: self.compat_ts_type(e.type_ann(self.ast)),
+        }
+    }
+
+    fn compat_ts_type_assertion(
+        &mut self,
+        e: experimental::TsTypeAssertion,
+    ) -> legacy::TsTypeAssertion {
+        legacy::TsTypeAssertion {
+            span: e.span(self.ast),
+            expr: self.compat_expr(e.expr(self.ast)),
+            type_ann: self.compat_ts_type(e.type_ann(self.ast)),
+        }
+    }
+
+    fn compat_ts_non_null_expr(&mut self, e: experimental::TsNonNullExpr) -> legacy::TsNonNullExpr {
+        legacy::TsNonNullExpr {
+            span: e.span(self.ast),
+            expr: self.compat_expr(e.expr(self.ast)),
+        }
+    }
+
+    fn compat_ts_instantiation(
+        &mut self,
+        e: experimental::TsInstantiation,
+    ) -> legacy::TsInstantiation {
+        legacy::TsInstantiation {
+            span: e.span(self.ast),
+            expr: self.compat_expr(e.expr(self.ast)),
+            type_args: Box::new(self.compat_ts_type_param_instantiation(e.type_args(self.ast))),
+        }
+    }
+
     // ===============================================================================
 
     fn compat_utf8_ref(&mut self, utf8_ref: Utf8Ref) -> Atom {
@@ -0,0 +1,284 @@
+use std::io;
+
+use swc_experimental_ecma_ast::*;
+
+use crate::{Emitter, Result, WriteJs, unsupported, util::Precedence};
+
+impl<W: WriteJs> Emitter<'_, W> {
+    pub(crate) fn emit_fn_expr(&mut self, f: FnExpr) -> Result {
+        self.emit_function(f.span(self.ast), f.ident(self.ast), f.function(self.ast))
+    }
+
+    pub(crate) fn emit_function(
+        &mut self,
+        span: Span,
+        ident: Option<Ident>,
+        function: Function,
+    ) -> Result {
+        if function.is_async(self.ast) {
+            self.wr.write_keyword(Some(span), "async")?;
+            self.wr.write_space()?;
+        }
+        self.wr.write_keyword(Some(span), "function")?;
+        if function.is_generator(self.ast) {
+            self.wr.write_operator(None, "*")?;
+        }
+        if let Some(ident) = ident {
+            if function.is_generator(self.ast) {
+                self.formatting_space()?;
+            } else {
+                self.wr.write_space()?;
+            }
+            self.emit_ident(ident)?;
+        }
+        self.emit_fn_params_and_body(function)
+    }
+
+    /// Emits the `async` and `*` in front of the name of a method.
+    pub(crate) fn emit_method_prefix(&mut self, function: Function) -> Result {
+        if function.is_async(self.ast) {
+            self.wr.write_keyword(None, "async")?;
+            self.wr.write_space()?;
+        }
+        if function.is_generator(self.ast) {
+            self.wr.write_operator(None, "*")?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn emit_fn_params_and_body(&mut self, function: Function) -> Result {
+        self.wr.write_punct(None, "(")?;
+        self.emit_comma_list(function.params(self.ast), |e, param| {
+            e.emit_pat(param.pat(e.ast))
+        })?;
+        self.wr.write_punct(None, ")")?;
+        self.formatting_space()?;
+        self.emit_fn_body(function.body(self.ast))
+    }
+
+    pub(crate) fn emit_fn_body(&mut self, body: Option<BlockStmt>) -> Result {
+        match body {
+            Some(body) => self.emit_block_stmt(body),
+            None => {
+                self.wr.write_punct(None, "{")?;
+                self.wr.write_punct(None, "}")
+            }
+        }
+    }
+
+    pub(crate) fn emit_class_expr(&mut self, class: ClassExpr) -> Result {
+        self.emit_class(
+            class.span(self.ast),
+            class.ident(self.ast),
+            class.class(self.ast),
+        )
+    }
+
+    pub(crate) fn emit_class(&mut self, span: Span, ident: Option<Ident>, class: Class) -> Result {
+        self.emit_decorators(class.decorators(self.ast))?;
+        self.wr.write_keyword(Some(span), "class")?;
+        if let Some(ident) = ident {
+            self.wr.write_space()?;
+            self.emit_ident(ident)?;
+        }
+        if let Some(super_class) = class.super_class(self.ast) {
+            self.wr.write_space()?;
+            self.wr.write_keyword(None, "extends")?;
+            self.wr.write_space()?;
+            self.emit_expr(super_class, Precedence::New)?;
+        }
+        self.formatting_space()?;
+        self.wr.write_punct(None, "{")?;
+
+        let mut is_empty = true;
+        for member in class.body(self.ast).iter() {
+            let member = self.ast.get_node_in_sub_range(member);
+            if self.is_erased_class_member(member)? {
+                continue;
+            }
+            if is_empty {
+                is_empty = false;
+                self.wr.increase_indent()?;
+            }
+            self.formatting_newline()?;
+            self.emit_class_member(member)?;
+        }
+        if !is_empty {
+            self.wr.decrease_indent()?;
+            self.formatting_newline()?;
+        }
+        self.wr.write_punct(None, "}")
+    }
+
+    /// Returns `true` if `member` only exists in the type system and is left out.
+    fn is_erased_class_member(&self, member: ClassMember) -> io::Result<bool> {
+        Ok(match member {
+            ClassMember::Constructor(c) => {
+                for param in c.params(self.ast).iter() {
+                    if let ParamOrTsParamProp::TsParamProp(..) =
+                        self.ast.get_node_in_sub_range(param)
+                    {
+                        return Err(unsupported("A TypeScript parameter property"));
+                    }
+                }
+                c.body(self.ast).is_none()
+            }
+            ClassMember::Method(m) => {
+                m.is_abstract(self.ast) || m.function(self.ast).body(self.ast).is_none()
+            }
+            ClassMember::PrivateMethod(m) => {
+                m.is_abstract(self.ast) || m.function(self.ast).body(self.ast).is_none()
+            }
+            ClassMember::ClassProp(prop) => prop.declare(self.ast) || prop.is_abstract(self.ast),
+            ClassMember::AutoAccessor(accessor) => accessor.is_abstract(self.ast),
+            ClassMember::TsIndexSignature(..) => true,
+            ClassMember::PrivateProp(..)
+            | ClassMember::Empty(..)
+            | ClassMember::StaticBlock(..) => false,
+        })
+    }
+
+    fn emit_class_member(&mut self, member: ClassMember) -> Result {
+        match member {
+            ClassMember::Constructor(c) => {
+                self.emit_prop_name(c.key(self.ast))?;
+                self.wr.write_punct(None, "(")?;
+                self.emit_comma_list(c.params(self.ast), |e, param| match param {
+                    ParamOrTsParamProp::Param(param) => e.emit_pat(param.pat(e.ast)),
+                    ParamOrTsParamProp::TsParamProp(..) => unreachable!(),
+                })?;
+                self.wr.write_punct(None, ")")?;
+                self.formatting_space()?;
+                self.emit_fn_body(c.body(self.ast))
+            }
+            ClassMember::Method(m) => {
+                let function = m.function(self.ast);
+                self.emit_decorators(function.decorators(self.ast))?;
+                self.emit_method_head(m.is_static(self.ast), m.kind(self.ast), function)?;
+                self.emit_prop_name(m.key(self.ast))?;
+                self.emit_fn_params_and_body(function)
+            }
+            ClassMember::PrivateMethod(m) => {
+                let function = m.function(self.ast);
+                self.emit_decorators(function.decorators(self.ast))?;
+                self.emit_method_head(m.is_static(self.ast), m.kind(self.ast), function)?;
+                self.emit_private_name(m.key(self.ast))?;
+                self.emit_fn_params_and_body(function)
+            }
+            ClassMember::ClassProp(prop) => {
+                self.emit_decorators(prop.decorators(self.ast))?;
+                self.emit_static(prop.is_static(self.ast))?;
+                self.emit_prop_name(prop.key(self.ast))?;
+                self.emit_class_field_value(prop.span(self.ast), prop.value(self.ast))
+            }
+            ClassMember::PrivateProp(prop) => {
+                self.emit_decorators(prop.decorators(self.ast))?;
+                self.emit_static(prop.is_static(self.ast))?;
+                self.emit_private_name(prop.key(self.ast))?;
+                self.emit_class_field_value(prop.span(self.ast), prop.value(self.ast))
+            }
+            ClassMember::Empty(empty) => self.wr.write_semi(Some(empty.span(self.ast))),
+            ClassMember::StaticBlock(block) => {
+                self.wr
+                    .write_keyword(Some(block.span(self.ast)), "static")?;
+                self.formatting_space()?;
+                self.emit_block_stmt(block.body(self.ast))
+            }
+            ClassMember::AutoAccessor(accessor) => {
+                self.emit_decorators(accessor.decorators(self.ast))?;
+                self.emit_static(accessor.is_static(self.ast))?;
+                self.wr
+                    .write_keyword(Some(accessor.span(self.ast)), "accessor")?;
+                self.wr.write_space()?;
+                match accessor.key(self.ast) {
+                    Key::Private(name) => self.emit_private_name(name)?,
+                    Key::Public(name) => self.emit_prop_name(name)?,
+                }
+                self.emit_class_field_value(accessor.span(self.ast), accessor.value(self.ast))
+            }
+            ClassMember::TsIndexSignature(..) => Ok(()),
+        }
+    }
+
+    fn emit_static(&mut self, is_static: bool) -> Result {
+        if is_static {
+            self.wr.write_keyword(None, "static")?;
+            self.wr.write_space()?;
+        }
+        Ok(())
+    }
+
+    fn emit_method_head(
+        &mut self,
+        is_static: bool,
+        kind: MethodKind,
+        function: Function,
+    ) -> Result {
+        self.emit_static(is_static)?;
+        match kind {
+            MethodKind::Method => self.emit_method_prefix(function),
+            MethodKind::Getter => {
+                self.wr.write_keyword(None, "get")?;
+                self.wr.write_space()
+            }
+            MethodKind::Setter => {
+                self.wr.write_keyword(None, "set")?;
+                self.wr.write_space()
+            }
+        }
+    }
+
+    fn emit_class_field_value(&mut self, span: Span, value: Option<Expr>) -> Result {
+        if let Some(value) = value {
+            self.formatting_space()?;
+            self.wr.write_operator(None, "=")?;
+            self.formatting_space()?;
+            self.emit_expr(value, Precedence::Assign)?;
+        }
+        // Fields always end with a semicolon, the next member could continue
+        // the initializer otherwise.
+        self.wr.write_semi(Some(span))
+    }
+
+    pub(crate) fn emit_decorators(&mut self, decorators: TypedSubRange<Decorator>) -> Result {
+        for decorator in decorators.iter() {
+            let decorator = self.ast.get_node_in_sub_range(decorator);
+            self.wr.write_punct(Some(decorator.span(self.ast)), "@")?;
+            let expr = decorator.expr(self.ast);
+            if is_simple_decorator(self.ast, expr) {
+                self.emit_expr(expr, Precedence::Call)?;
+            } else {
+                self.emit_paren_expr(expr)?;
+            }
+            self.formatting_newline()?;
+            if self.cfg.minify {
+                self.wr.write_space()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if `expr` can follow the `@` of a decorator without
+/// parentheses, which is a member chain with an optional call at the end.
+fn is_simple_decorator(ast: &Ast, expr: Expr) -> bool {
+    fn is_member_chain(ast: &Ast, expr: Expr) -> bool {
+        match expr {
+            Expr::Ident(..) => true,
+            Expr::Member(member) => {
+                !matches!(member.prop(ast), MemberProp::Computed(..))
+                    && is_member_chain(ast, member.obj(ast))
+            }
+            _ => false,
+        }
+    }
+
+    match expr {
+        Expr::Call(call) => match call.callee(ast) {
+            Callee::Expr(callee) => is_member_chain(ast, callee),
+            _ => false,
+        },
+        Expr::Paren(..) => true,
+        _ => is_member_chain(ast, expr),
+    }
+}
@@ -0,0 +1,128 @@
+use std::io;
+
+use swc_experimental_ecma_ast::*;
+
+use crate::{
+    Emitter, Result, WriteJs, unsupported,
+    util::{Precedence, contains_in_operator},
+};
+
+impl<W: WriteJs> Emitter<'_, W> {
+    /// Returns `true` if `decl` only exists in the type system and is left out.
+    pub(crate) fn is_erased_decl(&self, decl: Decl) -> io::Result<bool> {
+        Ok(match decl {
+            Decl::Class(c) => c.declare(self.ast),
+            Decl::Fn(f) => f.declare(self.ast) || f.function(self.ast).body(self.ast).is_none(),
+            Decl::Var(v) => v.declare(self.ast),
+            Decl::Using(..) => false,
+            Decl::TsInterface(..) | Decl::TsTypeAlias(..) => true,
+            Decl::TsEnum(e) => {
+                if !e.declare(self.ast) {
+                    return Err(unsupported("A TypeScript enum"));
+                }
+                true
+            }
+            Decl::TsModule(m) => {
+                if !m.declare(self.ast) && self.has_values(m.body(self.ast))? {
+                    return Err(unsupported("A TypeScript namespace"));
+                }
+                true
+            }
+        })
+    }
+
+    /// Returns `true` if anything in the namespace body would be printed.
+    fn has_values(&self, mut body: Option<TsNamespaceBody>) -> io::Result<bool> {
+        loop {
+            match body {
+                Some(TsNamespaceBody::TsModuleBlock(block)) => {
+                    for item in block.body(self.ast).iter() {
+                        let item = self.ast.get_node_in_sub_range(item);
+                        if !self.is_erased_module_item(item)? {
+                            return Ok(true);
+                        }
+                    }
+                    return Ok(false);
+                }
+                Some(TsNamespaceBody::TsNamespaceDecl(decl)) => body = Some(decl.body(self.ast)),
+                None => return Ok(false),
+            }
+        }
+    }
+
+    pub(crate) fn emit_decl(&mut self, decl: Decl) -> Result {
+        match decl {
+            Decl::Class(decl) => self.emit_class(
+                decl.span(self.ast),
+                Some(decl.ident(self.ast)),
+                decl.class(self.ast),
+            ),
+            Decl::Fn(decl) => self.emit_function(
+                decl.span(self.ast),
+                Some(decl.ident(self.ast)),
+                decl.function(self.ast),
+            ),
+            Decl::Var(decl) => self.emit_var_decl(decl, false),
+            Decl::Using(decl) => self.emit_using_decl(decl, false),
+            Decl::TsInterface(..)
+            | Decl::TsTypeAlias(..)
+            | Decl::TsEnum(..)
+            | Decl::TsModule(..) => Ok(()),
+        }
+    }
+
+    /// Emits a variable declaration. The declaration in the head of a `for`
+    /// statement has no semicolon.
+    pub(crate) fn emit_var_decl(&mut self, decl: VarDecl, in_for_head: bool) -> Result {
+        let span = decl.span(self.ast);
+        let kind = match decl.kind(self.ast) {
+            VarDeclKind::Var => "var",
+            VarDeclKind::Let => "let",
+            VarDeclKind::Const => "const",
+        };
+        self.wr.write_keyword(Some(span), kind)?;
+        self.wr.write_space()?;
+        self.emit_var_declarators(decl.decls(self.ast), in_for_head)?;
+        if !in_for_head {
+            self.wr.write_semi(Some(span))?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn emit_using_decl(&mut self, decl: UsingDecl, in_for_head: bool) -> Result {
+        let span = decl.span(self.ast);
+        if decl.is_await(self.ast) {
+            self.wr.write_keyword(Some(span), "await")?;
+            self.wr.write_space()?;
+        }
+        self.wr.write_keyword(Some(span), "using")?;
+        self.wr.write_space()?;
+        self.emit_var_declarators(decl.decls(self.ast), in_for_head)?;
+        if !in_for_head {
+            self.wr.write_semi(Some(span))?;
+        }
+        Ok(())
+    }
+
+    fn emit_var_declarators(
+        &mut self,
+        decls: TypedSubRange<VarDeclarator>,
+        in_for_head: bool,
+    ) -> Result {
+        self.emit_comma_list(decls, |e, decl| {
+            e.emit_pat(decl.name(e.ast))?;
+            if let Some(init) = decl.init(e.ast) {
+                e.formatting_space()?;
+                e.wr.write_operator(None, "=")?;
+                e.formatting_space()?;
+                // `for (var a = b in c;;)` would be parsed as a `for-in` statement.
+                if in_for_head && contains_in_operator(e.ast, init) {
+                    e.emit_paren_expr(init)?;
+                } else {
+                    e.emit_expr(init, Precedence::Assign)?;
+                }
+            }
+            Ok(())
+        })
+    }
+}
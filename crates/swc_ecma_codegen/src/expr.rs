@@ -0,0 +1,724 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{
+    Emitter, Result, WriteJs, unsupported,
+    util::{Precedence, StartsWith, contains_call},
+};
+
+impl<W: WriteJs> Emitter<'_, W> {
+    /// Emits `expr` in a position which requires at least the precedence `min`,
+    /// wrapping it in parentheses if it binds weaker.
+    pub(crate) fn emit_expr(&mut self, expr: Expr, min: Precedence) -> Result {
+        if Precedence::of_expr(self.ast, expr) < min {
+            self.emit_paren_expr(expr)
+        } else {
+            self.emit_expr_inner(expr)
+        }
+    }
+
+    pub(crate) fn emit_paren_expr(&mut self, expr: Expr) -> Result {
+        self.wr.write_punct(None, "(")?;
+        self.emit_expr_inner(expr)?;
+        self.wr.write_punct(None, ")")
+    }
+
+    fn emit_expr_inner(&mut self, expr: Expr) -> Result {
+        maybe_grow(|| match expr {
+            Expr::This(this) => self.wr.write_keyword(Some(this.span(self.ast)), "this"),
+            Expr::Array(array) => self.emit_array_lit(array),
+            Expr::Object(object) => self.emit_object_lit(object),
+            Expr::Fn(f) => self.emit_fn_expr(f),
+            Expr::Unary(unary) => self.emit_unary_expr(unary),
+            Expr::Update(update) => self.emit_update_expr(update),
+            Expr::Bin(bin) => self.emit_bin_expr(bin),
+            Expr::Assign(assign) => self.emit_assign_expr(assign),
+            Expr::Member(member) => self.emit_member_expr(member, false),
+            Expr::SuperProp(super_prop) => self.emit_super_prop_expr(super_prop),
+            Expr::Cond(cond) => self.emit_cond_expr(cond),
+            Expr::Call(call) => self.emit_call_expr(call),
+            Expr::New(new) => self.emit_new_expr(new),
+            Expr::Seq(seq) => self.emit_comma_list(seq.exprs(self.ast), |e, expr| {
+                e.emit_expr(expr, Precedence::Assign)
+            }),
+            Expr::Ident(ident) => self.emit_ident(ident),
+            Expr::Lit(lit) => self.emit_lit(lit),
+            Expr::Tpl(tpl) => self.emit_tpl(tpl),
+            Expr::TaggedTpl(tagged) => {
+                self.emit_callee(tagged.tag(self.ast), false)?;
+                self.emit_tpl(tagged.tpl(self.ast))
+            }
+            Expr::Arrow(arrow) => self.emit_arrow_expr(arrow),
+            Expr::Class(class) => self.emit_class_expr(class),
+            Expr::Yield(yield_expr) => self.emit_yield_expr(yield_expr),
+            Expr::MetaProp(meta_prop) => {
+                let span = meta_prop.span(self.ast);
+                match meta_prop.kind(self.ast) {
+                    MetaPropKind::NewTarget => {
+                        self.wr.write_keyword(Some(span), "new")?;
+                        self.wr.write_punct(None, ".")?;
+                        self.wr.write_keyword(None, "target")
+                    }
+                    MetaPropKind::ImportMeta => {
+                        self.wr.write_keyword(Some(span), "import")?;
+                        self.wr.write_punct(None, ".")?;
+                        self.wr.write_keyword(None, "meta")
+                    }
+                }
+            }
+            Expr::Await(await_expr) => {
+                self.wr
+                    .write_keyword(Some(await_expr.span(self.ast)), "await")?;
+                self.wr.write_space()?;
+                self.emit_expr(await_expr.arg(self.ast), Precedence::Unary)
+            }
+            Expr::Paren(paren) => {
+                self.wr.write_punct(Some(paren.span(self.ast)), "(")?;
+                self.emit_expr(paren.expr(self.ast), Precedence::Seq)?;
+                self.wr.write_punct(None, ")")
+            }
+            Expr::JSXMember(member) => self.emit_jsx_member_expr(member),
+            Expr::JSXNamespacedName(name) => self.emit_jsx_namespaced_name(name),
+            Expr::JSXEmpty(..) => Ok(()),
+            Expr::JSXElement(element) => self.emit_jsx_element(element),
+            Expr::JSXFragment(fragment) => self.emit_jsx_fragment(fragment),
+            // Types are not printed, the operand keeps the precedence of the
+            // whole expression.
+            Expr::TsTypeAssertion(e) => self.emit_expr_inner(e.expr(self.ast)),
+            Expr::TsConstAssertion(e) => self.emit_expr_inner(e.expr(self.ast)),
+            Expr::TsNonNull(e) => self.emit_expr_inner(e.expr(self.ast)),
+            Expr::TsAs(e) => self.emit_expr_inner(e.expr(self.ast)),
+            Expr::TsInstantiation(e) => self.emit_expr_inner(e.expr(self.ast)),
+            Expr::TsSatisfies(e) => self.emit_expr_inner(e.expr(self.ast)),
+            Expr::PrivateName(name) => self.emit_private_name(name),
+            Expr::OptChain(chain) => self.emit_opt_chain_expr(chain),
+            Expr::Invalid(..) => Err(unsupported("An invalid expression")),
+        })
+    }
+
+    pub(crate) fn emit_ident(&mut self, ident: Ident) -> Result {
+        self.emit_sym(ident.span(self.ast), ident.sym(self.ast))
+    }
+
+    pub(crate) fn emit_ident_name(&mut self, ident: IdentName) -> Result {
+        self.emit_sym(ident.span(self.ast), ident.sym(self.ast))
+    }
+
+    pub(crate) fn emit_private_name(&mut self, name: PrivateName) -> Result {
+        let span = name.span(self.ast);
+        self.wr.write_punct(Some(span), "#")?;
+        self.emit_sym(span, name.name(self.ast))
+    }
+
+    fn emit_sym(&mut self, span: Span, sym: Utf8Ref) -> Result {
+        let sym = self.ast.get_utf8(sym);
+        if !self.cfg.ascii_only || sym.is_ascii() {
+            return self.wr.write_symbol(span, sym);
+        }
+
+        let mut escaped = String::with_capacity(sym.len() * 2);
+        for c in sym.chars() {
+            if c.is_ascii() {
+                escaped.push(c);
+            } else if c > '\u{ffff}' && self.cfg.target < EsVersion::Es2015 {
+                // Surrogate pairs are only valid in strings.
+                return Err(unsupported(
+                    "An identifier with astral characters escaped before ES2015",
+                ));
+            } else {
+                self.push_unicode_escape(&mut escaped, c as u32);
+            }
+        }
+        self.wr.write_symbol(span, &escaped)
+    }
+
+    fn emit_array_lit(&mut self, array: ArrayLit) -> Result {
+        self.wr.write_punct(Some(array.span(self.ast)), "[")?;
+        let elems = array.elems(self.ast);
+        self.emit_comma_list(elems, |e, elem| match elem {
+            Some(elem) => e.emit_expr_or_spread(elem),
+            None => Ok(()),
+        })?;
+        // A trailing hole needs a comma of its own.
+        if let Some(last) = elems.last()
+            && self.ast.get_node_in_sub_range(last).is_none()
+        {
+            self.wr.write_punct(None, ",")?;
+        }
+        self.wr.write_punct(None, "]")
+    }
+
+    fn emit_object_lit(&mut self, object: ObjectLit) -> Result {
+        let span = object.span(self.ast);
+        let props = object.props(self.ast);
+        self.wr.write_punct(Some(span), "{")?;
+        if !props.is_empty() {
+            self.wr.increase_indent()?;
+            self.formatting_newline()?;
+            for (i, prop) in props.iter().enumerate() {
+                if i > 0 {
+                    self.wr.write_punct(None, ",")?;
+                    self.formatting_newline()?;
+                }
+                match self.ast.get_node_in_sub_range(prop) {
+                    PropOrSpread::SpreadElement(spread) => self.emit_spread_element(spread)?,
+                    PropOrSpread::Prop(prop) => self.emit_prop(prop)?,
+                }
+            }
+            self.wr.decrease_indent()?;
+            self.formatting_newline()?;
+        }
+        self.wr.write_punct(None, "}")
+    }
+
+    pub(crate) fn emit_spread_element(&mut self, spread: SpreadElement) -> Result {
+        self.wr
+            .write_punct(Some(spread.dot_3_token(self.ast)), "...")?;
+        self.emit_expr(spread.expr(self.ast), Precedence::Assign)
+    }
+
+    fn emit_prop(&mut self, prop: Prop) -> Result {
+        match prop {
+            Prop::Shorthand(ident) => self.emit_ident(ident),
+            Prop::KeyValue(prop) => {
+                self.emit_prop_name(prop.key(self.ast))?;
+                self.wr.write_punct(None, ":")?;
+                self.formatting_space()?;
+                self.emit_expr(prop.value(self.ast), Precedence::Assign)
+            }
+            Prop::Assign(prop) => {
+                self.emit_ident(prop.key(self.ast))?;
+                self.formatting_space()?;
+                self.wr.write_operator(None, "=")?;
+                self.formatting_space()?;
+                self.emit_expr(prop.value(self.ast), Precedence::Assign)
+            }
+            Prop::Getter(prop) => {
+                self.wr.write_keyword(Some(prop.span(self.ast)), "get")?;
+                self.wr.write_space()?;
+                self.emit_prop_name(prop.key(self.ast))?;
+                self.wr.write_punct(None, "(")?;
+                self.wr.write_punct(None, ")")?;
+                self.formatting_space()?;
+                self.emit_fn_body(prop.body(self.ast))
+            }
+            Prop::Setter(prop) => {
+                self.wr.write_keyword(Some(prop.span(self.ast)), "set")?;
+                self.wr.write_space()?;
+                self.emit_prop_name(prop.key(self.ast))?;
+                self.wr.write_punct(None, "(")?;
+                self.emit_pat(prop.param(self.ast))?;
+                self.wr.write_punct(None, ")")?;
+                self.formatting_space()?;
+                self.emit_fn_body(prop.body(self.ast))
+            }
+            Prop::Method(prop) => {
+                let function = prop.function(self.ast);
+                self.emit_method_prefix(function)?;
+                self.emit_prop_name(prop.key(self.ast))?;
+                self.emit_fn_params_and_body(function)
+            }
+        }
+    }
+
+    fn emit_unary_expr(&mut self, unary: UnaryExpr) -> Result {
+        let span = unary.span(self.ast);
+        let op = unary.op(self.ast);
+        let arg = unary.arg(self.ast);
+        match op {
+            UnaryOp::TypeOf | UnaryOp::Void | UnaryOp::Delete => {
+                self.wr.write_keyword(Some(span), unary_op_str(op))?;
+                self.wr.write_space()?;
+            }
+            _ => {
+                self.wr.write_operator(Some(span), unary_op_str(op))?;
+                // `- -a` and `+ +a` must not be merged into `--a` and `++a`.
+                let merges = match op {
+                    UnaryOp::Plus => StartsWith::expr(self.ast, arg) == StartsWith::Plus,
+                    UnaryOp::Minus => StartsWith::expr(self.ast, arg) == StartsWith::Minus,
+                    _ => false,
+                };
+                if merges {
+                    self.wr.write_space()?;
+                }
+            }
+        }
+        self.emit_expr(arg, Precedence::Unary)
+    }
+
+    fn emit_update_expr(&mut self, update: UpdateExpr) -> Result {
+        let span = update.span(self.ast);
+        let op = match update.op(self.ast) {
+            UpdateOp::PlusPlus => "++",
+            UpdateOp::MinusMinus => "--",
+        };
+        if update.prefix(self.ast) {
+            self.wr.write_operator(Some(span), op)?;
+            self.emit_expr(update.arg(self.ast), Precedence::New)
+        } else {
+            self.emit_expr(update.arg(self.ast), Precedence::New)?;
+            self.wr.write_operator(None, op)
+        }
+    }
+
+    fn emit_bin_expr(&mut self, bin: BinExpr) -> Result {
+        // Chains like `a + b + c` nest to the left, and may be too deep to
+        // recurse into, so the left operands which need no parentheses are
+        // unrolled into a list of the outer expressions.
+        let mut outer = Vec::new();
+        let mut bin = bin;
+        let mut left = bin.left(self.ast);
+        while let Expr::Bin(inner) = left {
+            if self.needs_left_paren(bin.op(self.ast), left) {
+                break;
+            }
+            outer.push(bin);
+            bin = inner;
+            left = bin.left(self.ast);
+        }
+
+        let paren = self.needs_left_paren(bin.op(self.ast), left);
+        self.emit_operand(left, paren)?;
+        self.emit_bin_op_and_right(bin)?;
+        for bin in outer.into_iter().rev() {
+            self.emit_bin_op_and_right(bin)?;
+        }
+        Ok(())
+    }
+
+    fn needs_left_paren(&self, op: BinaryOp, left: Expr) -> bool {
+        let left_prec = Precedence::of_expr(self.ast, left);
+        let paren = if op == BinaryOp::Exp {
+            // `-a ** b` is a syntax error.
+            left_prec <= Precedence::Unary
+        } else {
+            left_prec < Precedence::of_bin_op(op)
+        };
+        paren || self.mixes_coalescing(op, left)
+    }
+
+    fn emit_bin_op_and_right(&mut self, bin: BinExpr) -> Result {
+        let op = bin.op(self.ast);
+        let right = bin.right(self.ast);
+        let prec = Precedence::of_bin_op(op);
+
+        let right_prec = Precedence::of_expr(self.ast, right);
+        let right_paren = if op == BinaryOp::Exp {
+            // `**` is right-associative.
+            right_prec < prec
+        } else {
+            right_prec <= prec
+        };
+
+        let op_str = bin_op_str(op);
+        let is_keyword = matches!(op, BinaryOp::In | BinaryOp::InstanceOf);
+        // The AST has no position for the operator, and the span of the
+        // expression starts at the left operand.
+        if is_keyword {
+            self.wr.write_space()?;
+            self.wr.write_keyword(None, op_str)?;
+            self.wr.write_space()?;
+        } else {
+            self.formatting_space()?;
+            self.wr.write_operator(None, op_str)?;
+            if self.cfg.minify && self.needs_space_after_bin_op(op, right) {
+                self.wr.write_space()?;
+            } else {
+                self.formatting_space()?;
+            }
+        }
+
+        self.emit_operand(right, right_paren || self.mixes_coalescing(op, right))
+    }
+
+    fn emit_operand(&mut self, expr: Expr, paren: bool) -> Result {
+        if paren {
+            self.emit_paren_expr(expr)
+        } else {
+            self.emit_expr_inner(expr)
+        }
+    }
+
+    /// `??` can't be mixed with `||` and `&&` without parentheses.
+    fn mixes_coalescing(&self, op: BinaryOp, operand: Expr) -> bool {
+        let Expr::Bin(operand) = operand else {
+            return false;
+        };
+        let is_logical = |op| matches!(op, BinaryOp::LogicalOr | BinaryOp::LogicalAnd);
+        let operand_op = operand.op(self.ast);
+        (op == BinaryOp::NullishCoalescing && is_logical(operand_op))
+            || (is_logical(op) && operand_op == BinaryOp::NullishCoalescing)
+    }
+
+    /// Returns `true` if the right operand would merge with the operator when
+    /// printed without whitespace.
+    fn needs_space_after_bin_op(&self, op: BinaryOp, right: Expr) -> bool {
+        match op {
+            BinaryOp::Add => StartsWith::expr(self.ast, right) == StartsWith::Plus,
+            BinaryOp::Sub => StartsWith::expr(self.ast, right) == StartsWith::Minus,
+            // `a//re/` starts a line comment.
+            BinaryOp::Div => StartsWith::expr(self.ast, right) == StartsWith::Regex,
+            // `a<!--b` starts an HTML comment.
+            BinaryOp::Lt => matches!(
+                right,
+                Expr::Unary(unary) if unary.op(self.ast) == UnaryOp::Bang
+                    && matches!(unary.arg(self.ast), Expr::Update(update)
+                        if update.prefix(self.ast) && update.op(self.ast) == UpdateOp::MinusMinus)
+            ),
+            _ => false,
+        }
+    }
+
+    fn emit_assign_expr(&mut self, assign: AssignExpr) -> Result {
+        match assign.left(self.ast) {
+            AssignTarget::Simple(target) => self.emit_simple_assign_target(target)?,
+            AssignTarget::Pat(AssignTargetPat::Array(pat)) => self.emit_array_pat(pat)?,
+            AssignTarget::Pat(AssignTargetPat::Object(pat)) => self.emit_object_pat(pat)?,
+            AssignTarget::Pat(AssignTargetPat::Invalid(..)) => {
+                return Err(unsupported("An invalid assignment target"));
+            }
+        }
+        self.formatting_space()?;
+        self.wr
+            .write_operator(None, assign_op_str(assign.op(self.ast)))?;
+        self.formatting_space()?;
+        self.emit_expr(assign.right(self.ast), Precedence::Assign)
+    }
+
+    pub(crate) fn emit_simple_assign_target(&mut self, target: SimpleAssignTarget) -> Result {
+        match target {
+            SimpleAssignTarget::Ident(ident) => self.emit_ident(ident.id(self.ast)),
+            SimpleAssignTarget::Member(member) => self.emit_member_expr(member, false),
+            SimpleAssignTarget::SuperProp(super_prop) => self.emit_super_prop_expr(super_prop),
+            SimpleAssignTarget::Paren(paren) => self.emit_expr_inner(Expr::Paren(paren)),
+            SimpleAssignTarget::OptChain(chain) => self.emit_opt_chain_expr(chain),
+            SimpleAssignTarget::TsAs(e) => self.emit_expr(e.expr(self.ast), Precedence::New),
+            SimpleAssignTarget::TsSatisfies(e) => self.emit_expr(e.expr(self.ast), Precedence::New),
+            SimpleAssignTarget::TsNonNull(e) => self.emit_expr(e.expr(self.ast), Precedence::New),
+            SimpleAssignTarget::TsTypeAssertion(e) => {
+                self.emit_expr(e.expr(self.ast), Precedence::New)
+            }
+            SimpleAssignTarget::TsInstantiation(e) => {
+                self.emit_expr(e.expr(self.ast), Precedence::New)
+            }
+            SimpleAssignTarget::Invalid(..) => Err(unsupported("An invalid assignment target")),
+        }
+    }
+
+    fn emit_cond_expr(&mut self, cond: CondExpr) -> Result {
+        self.emit_expr(cond.test(self.ast), Precedence::LogicalOr)?;
+        self.formatting_space()?;
+        self.wr.write_punct(Some(cond.span(self.ast)), "?")?;
+        self.formatting_space()?;
+        self.emit_expr(cond.cons(self.ast), Precedence::Assign)?;
+        self.formatting_space()?;
+        self.wr.write_punct(None, ":")?;
+        self.formatting_space()?;
+        self.emit_expr(cond.alt(self.ast), Precedence::Assign)
+    }
+
+    /// Emits the object of a member expression, the callee of a call or the tag
+    /// of a tagged template.
+    ///
+    /// An optional chain is only continued by the members of the chain itself,
+    /// `(a?.b).c` must keep its parentheses otherwise.
+    fn emit_callee(&mut self, callee: Expr, in_chain: bool) -> Result {
+        let paren = Precedence::of_expr(self.ast, callee) < Precedence::Call
+            || (!in_chain && matches!(callee, Expr::OptChain(..)));
+        if paren {
+            return self.emit_paren_expr(callee);
+        }
+
+        // `1.toString()` would be parsed as a number with a fraction.
+        if let Expr::Lit(Lit::Num(num)) = callee {
+            let num = self.num_to_str(num);
+            if num.bytes().all(|c| c.is_ascii_digit() || c == b'_') {
+                return self.emit_paren_expr(callee);
+            }
+        }
+
+        self.emit_expr_inner(callee)
+    }
+
+    pub(crate) fn emit_member_expr(&mut self, member: MemberExpr, in_chain: bool) -> Result {
+        self.emit_callee(member.obj(self.ast), in_chain)?;
+        self.emit_member_prop(member.prop(self.ast), false)
+    }
+
+    fn emit_member_prop(&mut self, prop: MemberProp, optional: bool) -> Result {
+        if optional {
+            self.wr.write_punct(None, "?.")?;
+        }
+        match prop {
+            MemberProp::Ident(ident) => {
+                let sym = self.ast.get_utf8(ident.sym(self.ast));
+                if self.cfg.target == EsVersion::Es3 && ident.is_reserved(self.ast) {
+                    // Reserved words can't be used as property names in ES3.
+                    let span = ident.span(self.ast);
+                    self.wr.write_punct(None, "[")?;
+                    self.emit_quoted_str(span, sym.into())?;
+                    self.wr.write_punct(None, "]")
+                } else {
+                    if !optional {
+                        self.wr.write_punct(None, ".")?;
+                    }
+                    self.emit_ident_name(ident)
+                }
+            }
+            MemberProp::PrivateName(name) => {
+                if !optional {
+                    self.wr.write_punct(None, ".")?;
+                }
+                self.emit_private_name(name)
+            }
+            MemberProp::Computed(computed) => self.emit_computed_prop_name(computed),
+        }
+    }
+
+    pub(crate) fn emit_computed_prop_name(&mut self, computed: ComputedPropName) -> Result {
+        self.wr.write_punct(Some(computed.span(self.ast)), "[")?;
+        self.emit_expr(computed.expr(self.ast), Precedence::Assign)?;
+        self.wr.write_punct(None, "]")
+    }
+
+    fn emit_super_prop_expr(&mut self, super_prop: SuperPropExpr) -> Result {
+        self.wr
+            .write_keyword(Some(super_prop.obj(self.ast).span(self.ast)), "super")?;
+        match super_prop.prop(self.ast) {
+            SuperProp::Ident(ident) => self.emit_member_prop(MemberProp::Ident(ident), false),
+            SuperProp::Computed(computed) => self.emit_computed_prop_name(computed),
+        }
+    }
+
+    fn emit_call_expr(&mut self, call: CallExpr) -> Result {
+        match call.callee(self.ast) {
+            Callee::Super(callee) => self
+                .wr
+                .write_keyword(Some(callee.span(self.ast)), "super")?,
+            Callee::Import(callee) => {
+                self.wr
+                    .write_keyword(Some(callee.span(self.ast)), "import")?;
+                match callee.phase(self.ast) {
+                    ImportPhase::Evaluation => {}
+                    ImportPhase::Source => {
+                        self.wr.write_punct(None, ".")?;
+                        self.wr.write_keyword(None, "source")?;
+                    }
+                    ImportPhase::Defer => {
+                        self.wr.write_punct(None, ".")?;
+                        self.wr.write_keyword(None, "defer")?;
+                    }
+                }
+            }
+            Callee::Expr(callee) => self.emit_callee(callee, false)?,
+        }
+        self.emit_args(call.args(self.ast))
+    }
+
+    fn emit_new_expr(&mut self, new: NewExpr) -> Result {
+        self.wr.write_keyword(Some(new.span(self.ast)), "new")?;
+        self.wr.write_space()?;
+        // The arguments of a call in the callee would be taken by `new`.
+        let callee = new.callee(self.ast);
+        if Precedence::of_expr(self.ast, callee) < Precedence::Call
+            || contains_call(self.ast, callee)
+        {
+            self.emit_paren_expr(callee)?;
+        } else {
+            self.emit_callee(callee, false)?;
+        }
+        match new.args(self.ast) {
+            Some(args) => self.emit_args(args),
+            None => Ok(()),
+        }
+    }
+
+    fn emit_args(&mut self, args: TypedSubRange<ExprOrSpread>) -> Result {
+        self.wr.write_punct(None, "(")?;
+        self.emit_comma_list(args, Self::emit_expr_or_spread)?;
+        self.wr.write_punct(None, ")")
+    }
+
+    fn emit_expr_or_spread(&mut self, arg: ExprOrSpread) -> Result {
+        if let Some(spread) = arg.spread(self.ast) {
+            self.wr.write_punct(Some(spread.span(self.ast)), "...")?;
+        }
+        self.emit_expr(arg.expr(self.ast), Precedence::Assign)
+    }
+
+    fn emit_opt_chain_expr(&mut self, chain: OptChainExpr) -> Result {
+        let optional = chain.optional(self.ast);
+        match chain.base(self.ast) {
+            OptChainBase::Member(member) => {
+                self.emit_callee(member.obj(self.ast), true)?;
+                self.emit_member_prop(member.prop(self.ast), optional)
+            }
+            OptChainBase::Call(call) => {
+                self.emit_callee(call.callee(self.ast), true)?;
+                if optional {
+                    self.wr.write_punct(None, "?.")?;
+                }
+                self.emit_args(call.args(self.ast))
+            }
+        }
+    }
+
+    fn emit_tpl(&mut self, tpl: Tpl) -> Result {
+        self.wr.write_punct(Some(tpl.span(self.ast)), "`")?;
+        let exprs = tpl.exprs(self.ast);
+        for (i, quasi) in tpl.quasis(self.ast).iter().enumerate() {
+            let quasi = self.ast.get_node_in_sub_range(quasi);
+            self.wr
+                .write_lit(quasi.span(self.ast), self.ast.get_utf8(quasi.raw(self.ast)))?;
+            if let Some(expr) = exprs.get_node(self.ast, i) {
+                self.wr.write_punct(None, "${")?;
+                self.emit_expr(expr, Precedence::Seq)?;
+                self.wr.write_punct(None, "}")?;
+            }
+        }
+        self.wr.write_punct(None, "`")
+    }
+
+    fn emit_arrow_expr(&mut self, arrow: ArrowExpr) -> Result {
+        let span = arrow.span(self.ast);
+        if arrow.is_async(self.ast) {
+            self.wr.write_keyword(Some(span), "async")?;
+            self.formatting_space()?;
+        }
+        self.wr.write_punct(Some(span), "(")?;
+        self.emit_comma_list(arrow.params(self.ast), Self::emit_pat)?;
+        self.wr.write_punct(None, ")")?;
+        self.formatting_space()?;
+        self.wr.write_punct(None, "=>")?;
+        self.formatting_space()?;
+        match arrow.body(self.ast) {
+            BlockStmtOrExpr::BlockStmt(block) => self.emit_block_stmt(block),
+            BlockStmtOrExpr::Expr(body) => {
+                if StartsWith::expr(self.ast, body) == StartsWith::Brace {
+                    self.emit_paren_expr(body)
+                } else {
+                    self.emit_expr(body, Precedence::Assign)
+                }
+            }
+        }
+    }
+
+    fn emit_yield_expr(&mut self, yield_expr: YieldExpr) -> Result {
+        self.wr
+            .write_keyword(Some(yield_expr.span(self.ast)), "yield")?;
+        if yield_expr.delegate(self.ast) {
+            self.wr.write_operator(None, "*")?;
+        }
+        if let Some(arg) = yield_expr.arg(self.ast) {
+            if yield_expr.delegate(self.ast) {
+                self.formatting_space()?;
+            } else {
+                self.wr.write_space()?;
+            }
+            self.emit_expr(arg, Precedence::Assign)?;
+        }
+        Ok(())
+    }
+}
+
+fn unary_op_str(op: UnaryOp) -> &'static str {
+    match op {
+        UnaryOp::Minus => "-",
+        UnaryOp::Plus => "+",
+        UnaryOp::Bang => "!",
+        UnaryOp::Tilde => "~",
+        UnaryOp::TypeOf => "typeof",
+        UnaryOp::Void => "void",
+        UnaryOp::Delete => "delete",
+    }
+}
+
+fn bin_op_str(op: BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::EqEq => "==",
+        BinaryOp::NotEq => "!=",
+        BinaryOp::EqEqEq => "===",
+        BinaryOp::NotEqEq => "!==",
+        BinaryOp::Lt => "<",
+        BinaryOp::LtEq => "<=",
+        BinaryOp::Gt => ">",
+        BinaryOp::GtEq => ">=",
+        BinaryOp::LShift => "<<",
+        BinaryOp::RShift => ">>",
+        BinaryOp::ZeroFillRShift => ">>>",
+        BinaryOp::Add => "+",
+        BinaryOp::Sub => "-",
+        BinaryOp::Mul => "*",
+        BinaryOp::Div => "/",
+        BinaryOp::Mod => "%",
+        BinaryOp::BitOr => "|",
+        BinaryOp::BitXor => "^",
+        BinaryOp::BitAnd => "&",
+        BinaryOp::LogicalOr => "||",
+        BinaryOp::LogicalAnd => "&&",
+        BinaryOp::In => "in",
+        BinaryOp::InstanceOf => "instanceof",
+        BinaryOp::Exp => "**",
+        BinaryOp::NullishCoalescing => "??",
+    }
+}
+
+fn assign_op_str(op: AssignOp) -> &'static str {
+    match op {
+        AssignOp::Assign => "=",
+        AssignOp::AddAssign => "+=",
+        AssignOp::SubAssign => "-=",
+        AssignOp::MulAssign => "*=",
+        AssignOp::DivAssign => "/=",
+        AssignOp::ModAssign => "%=",
+        AssignOp::LShiftAssign => "<<=",
+        AssignOp::RShiftAssign => ">>=",
+        AssignOp::ZeroFillRShiftAssign => ">>>=",
+        AssignOp::BitOrAssign => "|=",
+        AssignOp::BitXorAssign => "^=",
+        AssignOp::BitAndAssign => "&=",
+        AssignOp::ExpAssign => "**=",
+        AssignOp::AndAssign => "&&=",
+        AssignOp::OrAssign => "||=",
+        AssignOp::NullishAssign => "??=",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::DUMMY_SP;
+    use swc_experimental_ecma_ast::{Ast, EsVersion, Program, StringAllocator};
+
+    use crate::{Config, to_code};
+
+    /// Returns the script `sym;`.
+    fn script(sym: &str) -> (Ast, Program) {
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let sym = ast.add_utf8(sym);
+        let expr = ast.expr_ident(DUMMY_SP, sym, false);
+        let stmt = ast.stmt_expr_stmt(DUMMY_SP, expr);
+        let body = ast.add_typed_sub_range([stmt]);
+        let shebang = ast.add_optional_utf8(None);
+        let root = ast.program_script(DUMMY_SP, body, shebang);
+        (ast, root)
+    }
+
+    #[test]
+    fn test_ascii_only_ident() {
+        let es5 = Config::default()
+            .with_ascii_only(true)
+            .with_target(EsVersion::Es5);
+        let es2015 = es5.with_target(EsVersion::Es2015);
+
+        let (ast, root) = script("café");
+        assert_eq!(to_code(&ast, root, es5).unwrap(), "caf\\u00e9;\n");
+        assert_eq!(to_code(&ast, root, es2015).unwrap(), "caf\\u00e9;\n");
+
+        // `\u{...}` escapes are ES2015, and surrogate pairs aren't valid in
+        // identifiers.
+        let (ast, root) = script("a𝑥");
+        assert_eq!(to_code(&ast, root, es2015).unwrap(), "a\\u{1d465};\n");
+        assert!(to_code(&ast, root, es5).is_err());
+    }
+}
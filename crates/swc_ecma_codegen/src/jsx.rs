@@ -0,0 +1,135 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{Emitter, Result, WriteJs, util::Precedence};
+
+impl<W: WriteJs> Emitter<'_, W> {
+    pub(crate) fn emit_jsx_element(&mut self, element: JSXElement) -> Result {
+        let opening = element.opening(self.ast);
+        self.wr.write_punct(Some(opening.span(self.ast)), "<")?;
+        self.emit_jsx_element_name(opening.name(self.ast))?;
+        for attr in opening.attrs(self.ast).iter() {
+            self.wr.write_space()?;
+            match self.ast.get_node_in_sub_range(attr) {
+                JSXAttrOrSpread::JSXAttr(attr) => self.emit_jsx_attr(attr)?,
+                JSXAttrOrSpread::SpreadElement(spread) => {
+                    self.wr.write_punct(None, "{")?;
+                    self.emit_spread_element(spread)?;
+                    self.wr.write_punct(None, "}")?;
+                }
+            }
+        }
+        if opening.self_closing(self.ast) {
+            self.formatting_space()?;
+            return self.wr.write_punct(None, "/>");
+        }
+        self.wr.write_punct(None, ">")?;
+
+        self.emit_jsx_children(element.children(self.ast))?;
+
+        if let Some(closing) = element.closing(self.ast) {
+            self.wr.write_punct(Some(closing.span(self.ast)), "</")?;
+            self.emit_jsx_element_name(closing.name(self.ast))?;
+            self.wr.write_punct(None, ">")?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn emit_jsx_fragment(&mut self, fragment: JSXFragment) -> Result {
+        self.wr
+            .write_punct(Some(fragment.opening(self.ast).span(self.ast)), "<>")?;
+        self.emit_jsx_children(fragment.children(self.ast))?;
+        self.wr
+            .write_punct(Some(fragment.closing(self.ast).span(self.ast)), "</>")
+    }
+
+    fn emit_jsx_children(&mut self, children: TypedSubRange<JSXElementChild>) -> Result {
+        for child in children.iter() {
+            match self.ast.get_node_in_sub_range(child) {
+                JSXElementChild::JSXText(text) => self
+                    .wr
+                    .write_lit(text.span(self.ast), self.ast.get_utf8(text.raw(self.ast)))?,
+                JSXElementChild::JSXExprContainer(container) => {
+                    self.emit_jsx_expr_container(container)?
+                }
+                JSXElementChild::JSXSpreadChild(spread) => {
+                    self.wr.write_punct(Some(spread.span(self.ast)), "{")?;
+                    self.wr.write_punct(None, "...")?;
+                    self.emit_expr(spread.expr(self.ast), Precedence::Assign)?;
+                    self.wr.write_punct(None, "}")?;
+                }
+                JSXElementChild::JSXElement(element) => self.emit_jsx_element(element)?,
+                JSXElementChild::JSXFragment(fragment) => self.emit_jsx_fragment(fragment)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn emit_jsx_expr_container(&mut self, container: JSXExprContainer) -> Result {
+        self.wr.write_punct(Some(container.span(self.ast)), "{")?;
+        match container.expr(self.ast) {
+            JSXExpr::JSXEmptyExpr(..) => {}
+            JSXExpr::Expr(expr) => self.emit_expr(expr, Precedence::Assign)?,
+        }
+        self.wr.write_punct(None, "}")
+    }
+
+    fn emit_jsx_attr(&mut self, attr: JSXAttr) -> Result {
+        match attr.name(self.ast) {
+            JSXAttrName::Ident(ident) => self.emit_ident_name(ident)?,
+            JSXAttrName::JSXNamespacedName(name) => self.emit_jsx_namespaced_name(name)?,
+        }
+        let Some(value) = attr.value(self.ast) else {
+            return Ok(());
+        };
+        self.wr.write_punct(None, "=")?;
+        match value {
+            JSXAttrValue::Str(s) => self.emit_jsx_attr_str(s),
+            JSXAttrValue::JSXExprContainer(container) => self.emit_jsx_expr_container(container),
+            JSXAttrValue::JSXElement(element) => self.emit_jsx_element(element),
+            JSXAttrValue::JSXFragment(fragment) => self.emit_jsx_fragment(fragment),
+        }
+    }
+
+    /// JSX attribute strings have no escapes, so a value which contains both
+    /// quotes is printed as an expression.
+    fn emit_jsx_attr_str(&mut self, s: Str) -> Result {
+        let span = s.span(self.ast);
+        if let Some(raw) = self.ast.get_optional_utf8(s.raw(self.ast)) {
+            return self.wr.write_lit(span, raw);
+        }
+
+        let value = self.ast.get_wtf8(s.value(self.ast));
+        match value.as_str() {
+            Some(value) if !value.contains('"') => self.wr.write_lit(span, &format!("\"{value}\"")),
+            Some(value) if !value.contains('\'') => self.wr.write_lit(span, &format!("'{value}'")),
+            _ => {
+                self.wr.write_punct(None, "{")?;
+                self.emit_quoted_str(span, value)?;
+                self.wr.write_punct(None, "}")
+            }
+        }
+    }
+
+    fn emit_jsx_element_name(&mut self, name: JSXElementName) -> Result {
+        match name {
+            JSXElementName::Ident(ident) => self.emit_ident(ident),
+            JSXElementName::JSXMemberExpr(member) => self.emit_jsx_member_expr(member),
+            JSXElementName::JSXNamespacedName(name) => self.emit_jsx_namespaced_name(name),
+        }
+    }
+
+    pub(crate) fn emit_jsx_member_expr(&mut self, member: JSXMemberExpr) -> Result {
+        match member.obj(self.ast) {
+            JSXObject::JSXMemberExpr(obj) => self.emit_jsx_member_expr(obj)?,
+            JSXObject::Ident(ident) => self.emit_ident(ident)?,
+        }
+        self.wr.write_punct(None, ".")?;
+        self.emit_ident_name(member.prop(self.ast))
+    }
+
+    pub(crate) fn emit_jsx_namespaced_name(&mut self, name: JSXNamespacedName) -> Result {
+        self.emit_ident_name(name.ns(self.ast))?;
+        self.wr.write_punct(None, ":")?;
+        self.emit_ident_name(name.name(self.ast))
+    }
+}
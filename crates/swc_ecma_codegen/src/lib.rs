@@ -0,0 +1,191 @@
+//! Code generator for the flattening AST.
+//!
+//! The [`Emitter`] walks the nodes of an [`Ast`] directly and prints ECMAScript
+//! and JSX. Parentheses are derived from the operator precedence, so trees which
+//! were built or transformed without [`ParenExpr`] nodes print correctly.
+//!
+//! TypeScript syntax is not printed. Types, type-only declarations and modifiers
+//! are left out, while TypeScript constructs with runtime semantics (enums,
+//! namespaces, parameter properties, ...) are reported as errors. Use
+//! `TsSyntax::strip_types` to parse TypeScript for this emitter.
+//!
+//! [`ParenExpr`]: swc_experimental_ecma_ast::ParenExpr
+
+use std::io;
+
+use swc_experimental_ecma_ast::*;
+
+pub use crate::{
+    source_map::{SourceMap, SourceMapBuilder},
+    text_writer::{JsWriter, WriteJs},
+};
+
+mod class;
+mod decl;
+mod expr;
+mod jsx;
+mod lit;
+mod module_decl;
+mod pat;
+pub mod source_map;
+mod stmt;
+pub mod text_writer;
+mod util;
+
+pub type Result = io::Result<()>;
+
+#[derive(Debug, Default, Clone, Copy)]
+#[non_exhaustive]
+pub struct Config {
+    /// The version of ECMAScript the output must be valid for.
+    ///
+    /// Raw literals which can't be parsed by the target, such as `\u{...}`
+    /// escapes before ES2015, are printed from their values instead.
+    pub target: EsVersion,
+
+    /// Omits whitespace which isn't required.
+    pub minify: bool,
+
+    /// Escapes all non-ASCII characters in strings and identifiers.
+    pub ascii_only: bool,
+}
+
+impl Config {
+    pub fn with_target(mut self, target: EsVersion) -> Self {
+        self.target = target;
+        self
+    }
+
+    pub fn with_minify(mut self, minify: bool) -> Self {
+        self.minify = minify;
+        self
+    }
+
+    pub fn with_ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+}
+
+pub struct Emitter<'a, W: WriteJs> {
+    pub cfg: Config,
+    pub ast: &'a Ast,
+    pub wr: W,
+}
+
+impl<'a, W: WriteJs> Emitter<'a, W> {
+    pub fn new(cfg: Config, ast: &'a Ast, wr: W) -> Self {
+        Self { cfg, ast, wr }
+    }
+
+    pub fn emit_program(&mut self, program: Program) -> Result {
+        match program {
+            Program::Module(module) => self.emit_module(module),
+            Program::Script(script) => self.emit_script(script),
+        }
+    }
+
+    pub fn emit_module(&mut self, module: Module) -> Result {
+        self.emit_shebang(module.span(self.ast), module.shebang(self.ast))?;
+        for item in module.body(self.ast).iter() {
+            let item = self.ast.get_node_in_sub_range(item);
+            if self.is_erased_module_item(item)? {
+                continue;
+            }
+            self.emit_module_item(item)?;
+            self.formatting_newline()?;
+        }
+        Ok(())
+    }
+
+    pub fn emit_script(&mut self, script: Script) -> Result {
+        self.emit_shebang(script.span(self.ast), script.shebang(self.ast))?;
+        self.emit_stmts(script.body(self.ast))
+    }
+
+    fn emit_shebang(&mut self, span: Span, shebang: OptionalUtf8Ref) -> Result {
+        if let Some(shebang) = self.ast.get_optional_utf8(shebang) {
+            self.wr.write_punct(Some(span), "#!")?;
+            self.wr.write_lit(span, shebang)?;
+            self.wr.write_line()?;
+        }
+        Ok(())
+    }
+
+    fn emit_module_item(&mut self, item: ModuleItem) -> Result {
+        match item {
+            ModuleItem::ModuleDecl(decl) => self.emit_module_decl(decl),
+            ModuleItem::Stmt(stmt) => self.emit_stmt(stmt),
+        }
+    }
+
+    /// Writes a space which is only needed for readability.
+    #[inline]
+    fn formatting_space(&mut self) -> Result {
+        if !self.cfg.minify {
+            self.wr.write_space()?;
+        }
+        Ok(())
+    }
+
+    /// Writes a line break which is only needed for readability.
+    #[inline]
+    fn formatting_newline(&mut self) -> Result {
+        if !self.cfg.minify {
+            self.wr.write_line()?;
+        }
+        Ok(())
+    }
+
+    /// Emits `list` separated by commas.
+    fn emit_comma_list<T: ExtraDataCompact>(
+        &mut self,
+        list: TypedSubRange<T>,
+        mut emit: impl FnMut(&mut Self, T) -> Result,
+    ) -> Result {
+        for (i, item) in list.iter().enumerate() {
+            if i > 0 {
+                self.wr.write_punct(None, ",")?;
+                self.formatting_space()?;
+            }
+            let item = self.ast.get_node_in_sub_range(item);
+            emit(self, item)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints `program` with the default writer.
+pub fn to_code(ast: &Ast, program: Program, cfg: Config) -> io::Result<String> {
+    let mut buf = Vec::new();
+    Emitter::new(cfg, ast, JsWriter::new("\n", &mut buf, None)).emit_program(program)?;
+    // The emitter escapes everything which isn't valid UTF-8.
+    Ok(String::from_utf8(buf).unwrap())
+}
+
+/// Prints `program` with a source map to `source`, the code named
+/// `source_name` which `ast` was parsed from.
+///
+/// Use [`SourceMap::compose`] to map the output further back through the
+/// source map of `source`.
+pub fn to_code_with_source_map(
+    ast: &Ast,
+    program: Program,
+    cfg: Config,
+    source_name: &str,
+    source: &str,
+) -> io::Result<(String, SourceMap)> {
+    let mut buf = Vec::new();
+    let mut srcmap = SourceMapBuilder::new();
+    Emitter::new(cfg, ast, JsWriter::new("\n", &mut buf, Some(&mut srcmap)))
+        .emit_program(program)?;
+    let code = String::from_utf8(buf).unwrap();
+    Ok((code, srcmap.build(source_name, source)))
+}
+
+fn unsupported(what: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("{what} can't be printed as JavaScript"),
+    )
+}
@@ -0,0 +1,160 @@
+use std::{borrow::Cow, fmt::Write};
+
+use swc_core::atoms::wtf8::Wtf8;
+use swc_experimental_ecma_ast::*;
+
+use crate::{Emitter, Result, WriteJs};
+
+impl<'a, W: WriteJs> Emitter<'a, W> {
+    pub(crate) fn emit_lit(&mut self, lit: Lit) -> Result {
+        match lit {
+            Lit::Str(s) => self.emit_str_lit(s),
+            Lit::Bool(b) => {
+                let value = if b.value(self.ast) { "true" } else { "false" };
+                self.wr.write_keyword(Some(b.span(self.ast)), value)
+            }
+            Lit::Null(null) => self.wr.write_keyword(Some(null.span(self.ast)), "null"),
+            Lit::Num(num) => {
+                let value = self.num_to_str(num);
+                self.wr.write_lit(num.span(self.ast), &value)
+            }
+            Lit::BigInt(bigint) => self.emit_bigint_lit(bigint),
+            Lit::Regex(regex) => {
+                let span = regex.span(self.ast);
+                self.wr.write_punct(Some(span), "/")?;
+                self.wr
+                    .write_lit(span, self.ast.get_utf8(regex.exp(self.ast)))?;
+                self.wr.write_punct(None, "/")?;
+                self.wr
+                    .write_lit(span, self.ast.get_utf8(regex.flags(self.ast)))
+            }
+        }
+    }
+
+    /// Emits a string literal, from [`Str::raw`] if the target can parse it.
+    pub(crate) fn emit_str_lit(&mut self, s: Str) -> Result {
+        let span = s.span(self.ast);
+        if let Some(raw) = self.ast.get_optional_utf8(s.raw(self.ast))
+            && self.can_emit_raw_str(raw)
+        {
+            return self.wr.write_lit(span, raw);
+        }
+        self.emit_quoted_str(span, self.ast.get_wtf8(s.value(self.ast)))
+    }
+
+    fn can_emit_raw_str(&self, raw: &str) -> bool {
+        (!self.cfg.ascii_only || raw.is_ascii())
+            && (self.cfg.target >= EsVersion::Es2015 || !raw.contains("\\u{"))
+            // Line and paragraph separators are allowed in strings since ES2019.
+            && (self.cfg.target >= EsVersion::Es2019 || !raw.contains(['\u{2028}', '\u{2029}']))
+    }
+
+    /// Emits `value` as a string literal, using the quote which needs fewer
+    /// escapes.
+    pub(crate) fn emit_quoted_str(&mut self, span: Span, value: &Wtf8) -> Result {
+        let (mut single, mut double) = (0, 0);
+        for c in value.code_points() {
+            match c.to_u32() {
+                0x27 => single += 1,
+                0x22 => double += 1,
+                _ => {}
+            }
+        }
+        let quote = if double > single { '\'' } else { '"' };
+
+        let mut buf = String::with_capacity(value.len() + 2);
+        buf.push(quote);
+        let mut iter = value.code_points().peekable();
+        while let Some(c) = iter.next() {
+            let c = c.to_u32();
+            match c {
+                0x00 => {
+                    // `\0` followed by a digit would be a legacy octal escape.
+                    let next_is_digit = iter
+                        .peek()
+                        .and_then(|next| next.to_char())
+                        .is_some_and(|next| next.is_ascii_digit());
+                    buf.push_str(if next_is_digit { "\\x00" } else { "\\0" });
+                }
+                0x08 => buf.push_str("\\b"),
+                0x09 => buf.push_str("\\t"),
+                0x0a => buf.push_str("\\n"),
+                0x0b => buf.push_str("\\v"),
+                0x0c => buf.push_str("\\f"),
+                0x0d => buf.push_str("\\r"),
+                0x5c => buf.push_str("\\\\"),
+                _ if c == quote as u32 => {
+                    buf.push('\\');
+                    buf.push(quote);
+                }
+                0x00..=0x1f | 0x7f => write!(buf, "\\x{c:02x}").unwrap(),
+                // Lone surrogates can't be represented in UTF-8.
+                0x2028 | 0x2029 | 0xd800..=0xdfff => write!(buf, "\\u{c:04x}").unwrap(),
+                0x80.. if self.cfg.ascii_only => self.push_unicode_escape(&mut buf, c),
+                _ => buf.push(char::from_u32(c).unwrap()),
+            }
+        }
+        buf.push(quote);
+
+        self.wr.write_lit(span, &buf)
+    }
+
+    pub(crate) fn push_unicode_escape(&self, buf: &mut String, c: u32) {
+        if c <= 0xffff {
+            write!(buf, "\\u{c:04x}").unwrap();
+        } else if self.cfg.target >= EsVersion::Es2015 {
+            write!(buf, "\\u{{{c:x}}}").unwrap();
+        } else {
+            let c = c - 0x10000;
+            let (high, low) = (0xd800 + (c >> 10), 0xdc00 + (c & 0x3ff));
+            write!(buf, "\\u{high:04x}\\u{low:04x}").unwrap();
+        }
+    }
+
+    /// Returns the text of a number literal, which is [`Number::raw`] if the
+    /// target can parse it.
+    pub(crate) fn num_to_str(&self, num: Number) -> Cow<'a, str> {
+        let ast = self.ast;
+        if let Some(raw) = ast.get_optional_utf8(num.raw(ast)) {
+            let is_es2015_radix = raw.len() > 1
+                && raw.as_bytes()[0] == b'0'
+                && matches!(raw.as_bytes()[1], b'b' | b'B' | b'o' | b'O');
+            if (self.cfg.target >= EsVersion::Es2021 || !raw.contains('_'))
+                && (self.cfg.target >= EsVersion::Es2015 || !is_es2015_radix)
+            {
+                return Cow::Borrowed(raw);
+            }
+        }
+
+        let value = num.value(self.ast);
+        if value.is_nan() {
+            return Cow::Borrowed("NaN");
+        }
+        let sign = if value.is_sign_negative() { "-" } else { "" };
+        let value = value.abs();
+        if value.is_infinite() {
+            return Cow::Owned(format!("{sign}Infinity"));
+        }
+
+        // Both forms are the shortest digits which round-trip, pick the
+        // shorter notation.
+        let decimal = format!("{sign}{value}");
+        let exponential = format!("{sign}{value:e}");
+        Cow::Owned(if exponential.len() < decimal.len() {
+            exponential
+        } else {
+            decimal
+        })
+    }
+
+    pub(crate) fn emit_bigint_lit(&mut self, bigint: BigInt) -> Result {
+        let span = bigint.span(self.ast);
+        if let Some(raw) = self.ast.get_optional_utf8(bigint.raw(self.ast))
+            && (self.cfg.target >= EsVersion::Es2021 || !raw.contains('_'))
+        {
+            return self.wr.write_lit(span, raw);
+        }
+        let value = self.ast.get_big_int(bigint.value(self.ast));
+        self.wr.write_lit(span, &format!("{value}n"))
+    }
+}
@@ -0,0 +1,265 @@
+use std::io;
+
+use swc_experimental_ecma_ast::*;
+
+use crate::{
+    Emitter, Result, WriteJs, unsupported,
+    util::{Precedence, StartsWith},
+};
+
+impl<W: WriteJs> Emitter<'_, W> {
+    /// Returns `true` if `item` only exists in the type system and is left out.
+    pub(crate) fn is_erased_module_item(&self, item: ModuleItem) -> io::Result<bool> {
+        match item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => self.is_erased_decl(decl),
+            ModuleItem::Stmt(..) => Ok(false),
+            ModuleItem::ModuleDecl(decl) => self.is_erased_module_decl(decl),
+        }
+    }
+
+    fn is_erased_module_decl(&self, decl: ModuleDecl) -> io::Result<bool> {
+        Ok(match decl {
+            ModuleDecl::Import(import) => import.type_only(self.ast),
+            ModuleDecl::ExportDecl(export) => self.is_erased_decl(export.decl(self.ast))?,
+            ModuleDecl::ExportNamed(export) => export.type_only(self.ast),
+            ModuleDecl::ExportDefaultDecl(export) => match export.decl(self.ast) {
+                DefaultDecl::Class(..) => false,
+                DefaultDecl::Fn(f) => f.function(self.ast).body(self.ast).is_none(),
+                DefaultDecl::TsInterfaceDecl(..) => true,
+            },
+            ModuleDecl::ExportDefaultExpr(..) => false,
+            ModuleDecl::ExportAll(export) => export.type_only(self.ast),
+            ModuleDecl::TsImportEquals(import) => {
+                if !import.is_type_only(self.ast) {
+                    return Err(unsupported("A TypeScript import equals declaration"));
+                }
+                true
+            }
+            ModuleDecl::TsExportAssignment(..) => {
+                return Err(unsupported("A TypeScript export assignment"));
+            }
+            ModuleDecl::TsNamespaceExport(..) => true,
+        })
+    }
+
+    pub(crate) fn emit_module_decl(&mut self, decl: ModuleDecl) -> Result {
+        match decl {
+            ModuleDecl::Import(import) => self.emit_import_decl(import),
+            ModuleDecl::ExportDecl(export) => {
+                self.wr
+                    .write_keyword(Some(export.span(self.ast)), "export")?;
+                self.wr.write_space()?;
+                self.emit_decl(export.decl(self.ast))
+            }
+            ModuleDecl::ExportNamed(export) => self.emit_named_export(export),
+            ModuleDecl::ExportDefaultDecl(export) => {
+                self.emit_export_default(export.span(self.ast))?;
+                match export.decl(self.ast) {
+                    DefaultDecl::Class(class) => self.emit_class_expr(class),
+                    DefaultDecl::Fn(f) => self.emit_fn_expr(f),
+                    DefaultDecl::TsInterfaceDecl(..) => Ok(()),
+                }
+            }
+            ModuleDecl::ExportDefaultExpr(export) => {
+                let span = export.span(self.ast);
+                self.emit_export_default(span)?;
+                // `export default function () {}` would be a declaration.
+                let expr = export.expr(self.ast);
+                match StartsWith::expr(self.ast, expr) {
+                    StartsWith::Function | StartsWith::Class => self.emit_paren_expr(expr)?,
+                    _ => self.emit_expr(expr, Precedence::Assign)?,
+                }
+                self.wr.write_semi(Some(span))
+            }
+            ModuleDecl::ExportAll(export) => {
+                let span = export.span(self.ast);
+                self.wr.write_keyword(Some(span), "export")?;
+                self.formatting_space()?;
+                self.wr.write_operator(None, "*")?;
+                self.emit_from(export.src(self.ast), export.with(self.ast))?;
+                self.wr.write_semi(Some(span))
+            }
+            ModuleDecl::TsImportEquals(..)
+            | ModuleDecl::TsExportAssignment(..)
+            | ModuleDecl::TsNamespaceExport(..) => Ok(()),
+        }
+    }
+
+    fn emit_export_default(&mut self, span: Span) -> Result {
+        self.wr.write_keyword(Some(span), "export")?;
+        self.wr.write_space()?;
+        self.wr.write_keyword(None, "default")?;
+        self.wr.write_space()
+    }
+
+    fn emit_import_decl(&mut self, import: ImportDecl) -> Result {
+        let span = import.span(self.ast);
+        self.wr.write_keyword(Some(span), "import")?;
+        match import.phase(self.ast) {
+            ImportPhase::Evaluation => {}
+            ImportPhase::Source => {
+                self.wr.write_space()?;
+                self.wr.write_keyword(None, "source")?;
+            }
+            ImportPhase::Defer => {
+                self.wr.write_space()?;
+                self.wr.write_keyword(None, "defer")?;
+            }
+        }
+
+        let specifiers = import.specifiers(self.ast);
+        if specifiers.is_empty() {
+            self.formatting_space()?;
+            self.emit_str_lit(import.src(self.ast))?;
+            self.emit_with(import.with(self.ast))?;
+            return self.wr.write_semi(Some(span));
+        }
+
+        let mut default = None;
+        let mut namespace = None;
+        let mut named = Vec::new();
+        for specifier in specifiers.iter() {
+            match self.ast.get_node_in_sub_range(specifier) {
+                ImportSpecifier::Default(specifier) => default = Some(specifier),
+                ImportSpecifier::Namespace(specifier) => namespace = Some(specifier),
+                ImportSpecifier::Named(specifier) => {
+                    if !specifier.is_type_only(self.ast) {
+                        named.push(specifier);
+                    }
+                }
+            }
+        }
+
+        self.wr.write_space()?;
+        if let Some(default) = default {
+            self.emit_ident(default.local(self.ast))?;
+            if namespace.is_some() || !named.is_empty() {
+                self.wr.write_punct(None, ",")?;
+                self.formatting_space()?;
+            }
+        }
+        if let Some(namespace) = namespace {
+            self.wr
+                .write_operator(Some(namespace.span(self.ast)), "*")?;
+            self.formatting_space()?;
+            self.wr.write_keyword(None, "as")?;
+            self.wr.write_space()?;
+            self.emit_ident(namespace.local(self.ast))?;
+        } else if default.is_none() || !named.is_empty() {
+            let has_named = !named.is_empty();
+            self.wr.write_punct(None, "{")?;
+            for (i, specifier) in named.into_iter().enumerate() {
+                if i > 0 {
+                    self.wr.write_punct(None, ",")?;
+                }
+                self.formatting_space()?;
+                if let Some(imported) = specifier.imported(self.ast) {
+                    self.emit_module_export_name(imported)?;
+                    self.wr.write_space()?;
+                    self.wr.write_keyword(None, "as")?;
+                    self.wr.write_space()?;
+                }
+                self.emit_ident(specifier.local(self.ast))?;
+            }
+            if has_named {
+                self.formatting_space()?;
+            }
+            self.wr.write_punct(None, "}")?;
+        }
+
+        self.emit_from(import.src(self.ast), import.with(self.ast))?;
+        self.wr.write_semi(Some(span))
+    }
+
+    fn emit_named_export(&mut self, export: NamedExport) -> Result {
+        let span = export.span(self.ast);
+        self.wr.write_keyword(Some(span), "export")?;
+
+        let mut default = None;
+        let mut namespace = None;
+        let mut named = Vec::new();
+        for specifier in export.specifiers(self.ast).iter() {
+            match self.ast.get_node_in_sub_range(specifier) {
+                ExportSpecifier::Default(specifier) => default = Some(specifier),
+                ExportSpecifier::Namespace(specifier) => namespace = Some(specifier),
+                ExportSpecifier::Named(specifier) => {
+                    if !specifier.is_type_only(self.ast) {
+                        named.push(specifier);
+                    }
+                }
+            }
+        }
+
+        if let Some(default) = default {
+            self.wr.write_space()?;
+            self.emit_ident(default.exported(self.ast))?;
+            if namespace.is_some() || !named.is_empty() {
+                self.wr.write_punct(None, ",")?;
+            }
+        }
+        if let Some(namespace) = namespace {
+            self.formatting_space()?;
+            self.wr
+                .write_operator(Some(namespace.span(self.ast)), "*")?;
+            self.formatting_space()?;
+            self.wr.write_keyword(None, "as")?;
+            self.wr.write_space()?;
+            self.emit_module_export_name(namespace.name(self.ast))?;
+            if !named.is_empty() {
+                self.wr.write_punct(None, ",")?;
+            }
+        }
+        if (default.is_none() && namespace.is_none()) || !named.is_empty() {
+            let has_named = !named.is_empty();
+            self.formatting_space()?;
+            self.wr.write_punct(None, "{")?;
+            for (i, specifier) in named.into_iter().enumerate() {
+                if i > 0 {
+                    self.wr.write_punct(None, ",")?;
+                }
+                self.formatting_space()?;
+                self.emit_module_export_name(specifier.orig(self.ast))?;
+                if let Some(exported) = specifier.exported(self.ast) {
+                    self.wr.write_space()?;
+                    self.wr.write_keyword(None, "as")?;
+                    self.wr.write_space()?;
+                    self.emit_module_export_name(exported)?;
+                }
+            }
+            if has_named {
+                self.formatting_space()?;
+            }
+            self.wr.write_punct(None, "}")?;
+        }
+
+        if let Some(src) = export.src(self.ast) {
+            self.emit_from(src, export.with(self.ast))?;
+        }
+        self.wr.write_semi(Some(span))
+    }
+
+    fn emit_from(&mut self, src: Str, with: Option<ObjectLit>) -> Result {
+        self.wr.write_space()?;
+        self.wr.write_keyword(None, "from")?;
+        self.formatting_space()?;
+        self.emit_str_lit(src)?;
+        self.emit_with(with)
+    }
+
+    fn emit_with(&mut self, with: Option<ObjectLit>) -> Result {
+        if let Some(with) = with {
+            self.formatting_space()?;
+            self.wr.write_keyword(None, "with")?;
+            self.formatting_space()?;
+            self.emit_expr(Expr::Object(with), Precedence::Primary)?;
+        }
+        Ok(())
+    }
+
+    fn emit_module_export_name(&mut self, name: ModuleExportName) -> Result {
+        match name {
+            ModuleExportName::Ident(ident) => self.emit_ident(ident),
+            ModuleExportName::Str(s) => self.emit_str_lit(s),
+        }
+    }
+}
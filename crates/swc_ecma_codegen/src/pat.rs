@@ -0,0 +1,98 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{Emitter, Result, WriteJs, unsupported, util::Precedence};
+
+impl<W: WriteJs> Emitter<'_, W> {
+    pub(crate) fn emit_pat(&mut self, pat: Pat) -> Result {
+        match pat {
+            Pat::Ident(ident) => self.emit_ident(ident.id(self.ast)),
+            Pat::Array(pat) => self.emit_array_pat(pat),
+            Pat::Rest(pat) => self.emit_rest_pat(pat),
+            Pat::Object(pat) => self.emit_object_pat(pat),
+            Pat::Assign(pat) => {
+                self.emit_pat(pat.left(self.ast))?;
+                self.formatting_space()?;
+                self.wr.write_operator(None, "=")?;
+                self.formatting_space()?;
+                self.emit_expr(pat.right(self.ast), Precedence::Assign)
+            }
+            Pat::Invalid(..) => Err(unsupported("An invalid pattern")),
+            Pat::Expr(expr) => self.emit_expr(expr, Precedence::New),
+        }
+    }
+
+    pub(crate) fn emit_array_pat(&mut self, pat: ArrayPat) -> Result {
+        self.wr.write_punct(Some(pat.span(self.ast)), "[")?;
+        let elems = pat.elems(self.ast);
+        self.emit_comma_list(elems, |e, elem| match elem {
+            Some(elem) => e.emit_pat(elem),
+            None => Ok(()),
+        })?;
+        if let Some(last) = elems.last()
+            && self.ast.get_node_in_sub_range(last).is_none()
+        {
+            self.wr.write_punct(None, ",")?;
+        }
+        self.wr.write_punct(None, "]")
+    }
+
+    pub(crate) fn emit_object_pat(&mut self, pat: ObjectPat) -> Result {
+        self.wr.write_punct(Some(pat.span(self.ast)), "{")?;
+        let props = pat.props(self.ast);
+        if !props.is_empty() {
+            self.formatting_space()?;
+            self.emit_comma_list(props, Self::emit_object_pat_prop)?;
+            self.formatting_space()?;
+        }
+        self.wr.write_punct(None, "}")
+    }
+
+    fn emit_object_pat_prop(&mut self, prop: ObjectPatProp) -> Result {
+        match prop {
+            ObjectPatProp::KeyValue(prop) => {
+                self.emit_prop_name(prop.key(self.ast))?;
+                self.wr.write_punct(None, ":")?;
+                self.formatting_space()?;
+                self.emit_pat(prop.value(self.ast))
+            }
+            ObjectPatProp::Assign(prop) => {
+                self.emit_ident(prop.key(self.ast).id(self.ast))?;
+                if let Some(value) = prop.value(self.ast) {
+                    self.formatting_space()?;
+                    self.wr.write_operator(None, "=")?;
+                    self.formatting_space()?;
+                    self.emit_expr(value, Precedence::Assign)?;
+                }
+                Ok(())
+            }
+            ObjectPatProp::Rest(pat) => self.emit_rest_pat(pat),
+        }
+    }
+
+    fn emit_rest_pat(&mut self, pat: RestPat) -> Result {
+        self.wr
+            .write_punct(Some(pat.dot_3_token(self.ast)), "...")?;
+        self.emit_pat(pat.arg(self.ast))
+    }
+
+    pub(crate) fn emit_prop_name(&mut self, name: PropName) -> Result {
+        match name {
+            PropName::Ident(ident) => {
+                if self.cfg.target == EsVersion::Es3 && ident.is_reserved(self.ast) {
+                    // Reserved words can't be used as property names in ES3.
+                    let sym = self.ast.get_utf8(ident.sym(self.ast));
+                    self.emit_quoted_str(ident.span(self.ast), sym.into())
+                } else {
+                    self.emit_ident_name(ident)
+                }
+            }
+            PropName::Str(s) => self.emit_str_lit(s),
+            PropName::Num(num) => {
+                let value = self.num_to_str(num);
+                self.wr.write_lit(num.span(self.ast), &value)
+            }
+            PropName::Computed(computed) => self.emit_computed_prop_name(computed),
+            PropName::BigInt(bigint) => self.emit_bigint_lit(bigint),
+        }
+    }
+}
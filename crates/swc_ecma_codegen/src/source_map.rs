@@ -0,0 +1,586 @@
+//! Source maps (revision 3) for the output of the [`Emitter`](crate::Emitter).
+//!
+//! Lines and columns are zero-based. Columns are counted in UTF-16 code units,
+//! which is what browsers and most tools expect.
+
+use std::{hash::BuildHasherDefault, io};
+
+use indexmap::IndexSet;
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+use swc_core::{atoms::Atom, common::BytePos};
+
+type FxIndexSet<T> = IndexSet<T, BuildHasherDefault<FxHasher>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+/// A position in the output and the position in the source it was printed
+/// from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub generated: LineCol,
+    pub original: BytePos,
+    /// The name of the identifier printed at this position.
+    pub name: Option<Atom>,
+}
+
+/// Collects the [`Mapping`]s of a [`JsWriter`](crate::JsWriter).
+#[derive(Debug, Default)]
+pub struct SourceMapBuilder {
+    mappings: Vec<Mapping>,
+}
+
+impl SourceMapBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn mappings(&self) -> &[Mapping] {
+        &self.mappings
+    }
+
+    /// Adds a mapping. Mappings must be added in the order of the output.
+    pub fn add_mapping(&mut self, generated: LineCol, original: BytePos, name: Option<&str>) {
+        debug_assert!(
+            self.mappings
+                .last()
+                .is_none_or(|last| last.generated <= generated)
+        );
+        // Only the first token printed at a position can be mapped.
+        if self
+            .mappings
+            .last()
+            .is_some_and(|last| last.generated == generated)
+        {
+            return;
+        }
+        self.mappings.push(Mapping {
+            generated,
+            original,
+            name: name.map(Atom::from),
+        });
+    }
+
+    /// Builds a source map for an output printed from `source`, the code named
+    /// `source_name` which the [`Ast`](swc_experimental_ecma_ast::Ast) was
+    /// parsed from.
+    ///
+    /// Mappings to positions outside of `source` are left out.
+    pub fn build(&self, source_name: &str, source: &str) -> SourceMap {
+        let lines = LineIndex::new(source);
+        let mut names = FxIndexSet::default();
+        let mut tokens = Vec::with_capacity(self.mappings.len());
+        for mapping in &self.mappings {
+            let Some(original) = lines.line_col(source, mapping.original) else {
+                continue;
+            };
+            let name_id = mapping
+                .name
+                .as_ref()
+                .map(|name| names.insert_full(name.clone()).0 as u32);
+            tokens.push(Token {
+                dst_line: mapping.generated.line,
+                dst_col: mapping.generated.col,
+                src_id: 0,
+                src_line: original.line,
+                src_col: original.col,
+                name_id,
+            });
+        }
+
+        SourceMap {
+            file: None,
+            source_root: None,
+            sources: vec![source_name.to_string()],
+            sources_content: vec![Some(source.to_string())],
+            names: names.into_iter().map(|name| name.to_string()).collect(),
+            tokens,
+        }
+    }
+}
+
+/// The start of each line in a source.
+struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    /// Splits `source` at the line terminators of JavaScript, like the
+    /// positions of the ESTree output.
+    fn new(source: &str) -> Self {
+        let mut line_starts = vec![0];
+        let mut chars = source.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                // `\r\n` is a single line terminator.
+                '\r' if matches!(chars.peek(), Some((_, '\n'))) => {}
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(i + c.len_utf8()),
+                _ => {}
+            }
+        }
+        Self { line_starts }
+    }
+
+    fn line_col(&self, source: &str, pos: BytePos) -> Option<LineCol> {
+        // Positions are one-based, `BytePos(0)` is a dummy.
+        let pos = (pos.0 as usize).checked_sub(1)?;
+        let line = self.line_starts.partition_point(|&start| start <= pos) - 1;
+        let col = utf16_len(source.get(self.line_starts[line]..pos)?);
+        Some(LineCol {
+            line: line as u32,
+            col,
+        })
+    }
+}
+
+/// A decoded mapping of a [`SourceMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub dst_line: u32,
+    pub dst_col: u32,
+    /// The index into [`SourceMap::sources`].
+    pub src_id: u32,
+    pub src_line: u32,
+    pub src_col: u32,
+    /// The index into [`SourceMap::names`].
+    pub name_id: Option<u32>,
+}
+
+/// A source map with decoded mappings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    file: Option<String>,
+    source_root: Option<String>,
+    sources: Vec<String>,
+    sources_content: Vec<Option<String>>,
+    names: Vec<String>,
+    /// Sorted by the generated position.
+    tokens: Vec<Token>,
+}
+
+impl SourceMap {
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn set_file(&mut self, file: Option<String>) {
+        self.file = file;
+    }
+
+    pub fn source_root(&self) -> Option<&str> {
+        self.source_root.as_deref()
+    }
+
+    pub fn sources(&self) -> &[String] {
+        &self.sources
+    }
+
+    pub fn sources_content(&self) -> &[Option<String>] {
+        &self.sources_content
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    /// Returns the token which covers the generated position, which is the
+    /// last token on `line` starting at or before `col`.
+    pub fn lookup_token(&self, line: u32, col: u32) -> Option<&Token> {
+        let i = self
+            .tokens
+            .partition_point(|token| (token.dst_line, token.dst_col) <= (line, col));
+        let token = self.tokens.get(i.checked_sub(1)?)?;
+        (token.dst_line == line).then_some(token)
+    }
+
+    /// Maps this source map through `input`, the source map of the code this
+    /// map points to. The result maps the output of this map to the sources
+    /// of `input`.
+    ///
+    /// Tokens which point to a position not covered by `input` are dropped.
+    /// Names are taken from `input` if it has any for a position.
+    pub fn compose(&self, input: &SourceMap) -> SourceMap {
+        let mut names = FxIndexSet::default();
+        let mut tokens = Vec::with_capacity(self.tokens.len());
+        for token in &self.tokens {
+            let Some(original) = input.lookup_token(token.src_line, token.src_col) else {
+                continue;
+            };
+            let name = match original.name_id {
+                Some(id) => input.names.get(id as usize),
+                None => token.name_id.and_then(|id| self.names.get(id as usize)),
+            };
+            tokens.push(Token {
+                dst_line: token.dst_line,
+                dst_col: token.dst_col,
+                src_id: original.src_id,
+                src_line: original.src_line,
+                src_col: original.src_col,
+                name_id: name.map(|name| names.insert_full(name.as_str()).0 as u32),
+            });
+        }
+
+        SourceMap {
+            file: self.file.clone(),
+            source_root: input.source_root.clone(),
+            sources: input.sources.clone(),
+            sources_content: input.sources_content.clone(),
+            names: names.into_iter().map(str::to_string).collect(),
+            tokens,
+        }
+    }
+
+    /// Parses a source map from JSON. Index maps (`sections`) are not
+    /// supported.
+    pub fn from_json(json: &str) -> io::Result<SourceMap> {
+        let raw: RawSourceMap = serde_json::from_str(json)?;
+        if raw.version != 3 {
+            return Err(invalid_data(format!(
+                "unsupported source map version {}",
+                raw.version
+            )));
+        }
+
+        let mut sources_content = raw.sources_content;
+        sources_content.resize(raw.sources.len(), None);
+        let map = SourceMap {
+            file: raw.file,
+            source_root: raw.source_root,
+            sources: raw
+                .sources
+                .into_iter()
+                .map(Option::unwrap_or_default)
+                .collect(),
+            sources_content,
+            names: raw.names,
+            tokens: decode_mappings(&raw.mappings)?,
+        };
+
+        let out_of_range = map.tokens.iter().any(|token| {
+            token.src_id as usize >= map.sources.len()
+                || token
+                    .name_id
+                    .is_some_and(|id| id as usize >= map.names.len())
+        });
+        if out_of_range {
+            return Err(invalid_data("source or name index out of range".into()));
+        }
+        Ok(map)
+    }
+
+    pub fn to_json(&self) -> String {
+        let raw = RawSourceMap {
+            version: 3,
+            file: self.file.clone(),
+            source_root: self.source_root.clone(),
+            sources: self.sources.iter().cloned().map(Some).collect(),
+            sources_content: if self.sources_content.iter().all(Option::is_none) {
+                Vec::new()
+            } else {
+                self.sources_content.clone()
+            },
+            names: self.names.clone(),
+            mappings: encode_mappings(&self.tokens),
+        };
+        serde_json::to_string(&raw).unwrap()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSourceMap {
+    version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    file: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source_root: Option<String>,
+    sources: Vec<Option<String>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    sources_content: Vec<Option<String>>,
+    #[serde(default)]
+    names: Vec<String>,
+    mappings: String,
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn encode_mappings(tokens: &[Token]) -> String {
+    let mut out = String::with_capacity(tokens.len() * 6);
+    let mut line = 0;
+    let mut prev_dst_col = 0;
+    let mut prev_src_id = 0;
+    let mut prev_src_line = 0;
+    let mut prev_src_col = 0;
+    let mut prev_name_id = 0;
+    let mut first_in_line = true;
+    for token in tokens {
+        while line < token.dst_line {
+            out.push(';');
+            line += 1;
+            prev_dst_col = 0;
+            first_in_line = true;
+        }
+        if !first_in_line {
+            out.push(',');
+        }
+        first_in_line = false;
+
+        encode_vlq(&mut out, token.dst_col as i64 - prev_dst_col as i64);
+        encode_vlq(&mut out, token.src_id as i64 - prev_src_id as i64);
+        encode_vlq(&mut out, token.src_line as i64 - prev_src_line as i64);
+        encode_vlq(&mut out, token.src_col as i64 - prev_src_col as i64);
+        prev_dst_col = token.dst_col;
+        prev_src_id = token.src_id;
+        prev_src_line = token.src_line;
+        prev_src_col = token.src_col;
+        if let Some(name_id) = token.name_id {
+            encode_vlq(&mut out, name_id as i64 - prev_name_id as i64);
+            prev_name_id = name_id;
+        }
+    }
+    out
+}
+
+fn decode_mappings(mappings: &str) -> io::Result<Vec<Token>> {
+    let invalid = || invalid_data("invalid source map mappings".into());
+    let field = |value: i64| u32::try_from(value).map_err(|_| invalid());
+
+    let mut tokens = Vec::new();
+    let mut src_id = 0;
+    let mut src_line = 0;
+    let mut src_col = 0;
+    let mut name_id = 0;
+    for (dst_line, line) in mappings.split(';').enumerate() {
+        let mut dst_col = 0;
+        for segment in line.split(',').filter(|segment| !segment.is_empty()) {
+            let mut bytes = segment.bytes();
+            let mut values = [0; 5];
+            let mut len = 0;
+            while bytes.len() > 0 {
+                if len == values.len() {
+                    return Err(invalid());
+                }
+                values[len] = decode_vlq(&mut bytes).ok_or_else(invalid)?;
+                len += 1;
+            }
+
+            dst_col = field(dst_col as i64 + values[0])?;
+            match len {
+                // A segment without a source.
+                1 => continue,
+                4 | 5 => {}
+                _ => return Err(invalid()),
+            }
+            src_id = field(src_id as i64 + values[1])?;
+            src_line = field(src_line as i64 + values[2])?;
+            src_col = field(src_col as i64 + values[3])?;
+            let token_name_id = if len == 5 {
+                name_id = field(name_id as i64 + values[4])?;
+                Some(name_id)
+            } else {
+                None
+            };
+            tokens.push(Token {
+                dst_line: dst_line as u32,
+                dst_col,
+                src_id,
+                src_line,
+                src_col,
+                name_id: token_name_id,
+            });
+        }
+    }
+    tokens.sort_by_key(|token| (token.dst_line, token.dst_col));
+    Ok(tokens)
+}
+
+fn encode_vlq(out: &mut String, value: i64) {
+    let mut vlq = if value < 0 {
+        ((-value) << 1) | 1
+    } else {
+        value << 1
+    };
+    loop {
+        let mut digit = vlq & 0b11111;
+        vlq >>= 5;
+        if vlq > 0 {
+            // More digits follow.
+            digit |= 0b100000;
+        }
+        out.push(BASE64[digit as usize] as char);
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+fn decode_vlq(bytes: &mut impl Iterator<Item = u8>) -> Option<i64> {
+    let mut vlq: i64 = 0;
+    let mut shift = 0;
+    loop {
+        let digit = match bytes.next()? {
+            b @ b'A'..=b'Z' => b - b'A',
+            b @ b'a'..=b'z' => b - b'a' + 26,
+            b @ b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        } as i64;
+        if shift > 32 {
+            return None;
+        }
+        vlq |= (digit & 0b11111) << shift;
+        shift += 5;
+        if digit & 0b100000 == 0 {
+            break;
+        }
+    }
+    let value = vlq >> 1;
+    Some(if vlq & 1 == 1 { -value } else { value })
+}
+
+/// Returns the length of `s` in UTF-16 code units.
+pub(crate) fn utf16_len(s: &str) -> u32 {
+    if s.is_ascii() {
+        s.len() as u32
+    } else {
+        s.encode_utf16().count() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::Span;
+    use swc_experimental_ecma_ast::{Ast, BinaryOp, StringAllocator};
+
+    use super::*;
+    use crate::{Config, to_code_with_source_map};
+
+    #[test]
+    fn test_vlq_roundtrip() {
+        for value in [0, 1, -1, 15, 16, -16, 31, 32, 1000, -1000, i32::MAX as i64] {
+            let mut out = String::new();
+            encode_vlq(&mut out, value);
+            assert_eq!(decode_vlq(&mut out.bytes()), Some(value), "{out}");
+        }
+
+        let mut out = String::new();
+        encode_vlq(&mut out, 16);
+        assert_eq!(out, "gB");
+    }
+
+    #[test]
+    fn test_line_index() {
+        let source = "a\nb\r\nc\rd\u{2028}e";
+        let index = LineIndex::new(source);
+        let line_col = |c: char| {
+            let pos = BytePos(source.find(c).unwrap() as u32 + 1);
+            let LineCol { line, col } = index.line_col(source, pos).unwrap();
+            (line, col)
+        };
+        assert_eq!(line_col('a'), (0, 0));
+        assert_eq!(line_col('b'), (1, 0));
+        assert_eq!(line_col('c'), (2, 0));
+        assert_eq!(line_col('d'), (3, 0));
+        assert_eq!(line_col('e'), (4, 0));
+    }
+
+    #[test]
+    fn test_bin_op_mapping() {
+        // `a + b;`
+        let source = "a + b;";
+        let span = |lo: u32, hi: u32| Span::new(BytePos(lo + 1), BytePos(hi + 1));
+        let mut ast = Ast::new(source.len(), Rc::new(StringAllocator::default()));
+        let [a, b] = ["a", "b"].map(|sym| ast.add_utf8(sym));
+        let a = ast.expr_ident(span(0, 1), a, false);
+        let b = ast.expr_ident(span(4, 5), b, false);
+        let expr = ast.expr_bin_expr(span(0, 5), BinaryOp::Add, a, b);
+        let stmt = ast.stmt_expr_stmt(span(0, 6), expr);
+        let body = ast.add_typed_sub_range([stmt]);
+        let shebang = ast.add_optional_utf8(None);
+        let root = ast.program_script(span(0, 6), body, shebang);
+
+        let (code, map) =
+            to_code_with_source_map(&ast, root, Config::default(), "a.js", source).unwrap();
+        assert_eq!(code, "a + b;\n");
+        // The operator isn't mapped to the start of the left operand.
+        let cols = map
+            .tokens()
+            .iter()
+            .map(|token| (token.dst_col, token.src_col))
+            .collect::<Vec<_>>();
+        assert!(!cols.contains(&(2, 0)), "{cols:?}");
+        assert!(cols.contains(&(4, 4)), "{cols:?}");
+    }
+
+    #[test]
+    fn test_mappings_roundtrip() {
+        let mappings = "AAAAA,SAASC;;IACRC,GAAA";
+        let tokens = decode_mappings(mappings).unwrap();
+        assert_eq!(tokens.len(), 4);
+        assert_eq!(encode_mappings(&tokens), mappings);
+    }
+
+    #[test]
+    fn test_compose() {
+        // `b.js` is generated from `a.js` with every line indented by 2.
+        let input = SourceMap {
+            sources: vec!["a.js".into()],
+            sources_content: vec![None],
+            names: vec!["foo".into()],
+            tokens: vec![Token {
+                dst_line: 0,
+                dst_col: 2,
+                src_id: 0,
+                src_line: 0,
+                src_col: 0,
+                name_id: Some(0),
+            }],
+            ..Default::default()
+        };
+        let map = SourceMapBuilder {
+            mappings: vec![
+                Mapping {
+                    generated: LineCol { line: 0, col: 0 },
+                    original: BytePos(1),
+                    name: None,
+                },
+                Mapping {
+                    generated: LineCol { line: 1, col: 0 },
+                    original: BytePos(5),
+                    name: Some("bar".into()),
+                },
+            ],
+        }
+        .build("b.js", "  foo");
+
+        let composed = map.compose(&input);
+        assert_eq!(composed.sources(), ["a.js"]);
+        assert_eq!(composed.names(), ["foo"]);
+        assert_eq!(
+            composed.tokens(),
+            [Token {
+                dst_line: 1,
+                dst_col: 0,
+                src_id: 0,
+                src_line: 0,
+                src_col: 0,
+                name_id: Some(0),
+            }]
+        );
+    }
+}
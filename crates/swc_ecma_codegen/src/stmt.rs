@@ -0,0 +1,351 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{
+    Emitter, Result, WriteJs,
+    util::{Precedence, StartsWith, contains_in_operator, ends_with_dangling_if},
+};
+
+impl<W: WriteJs> Emitter<'_, W> {
+    pub fn emit_stmt(&mut self, stmt: Stmt) -> Result {
+        maybe_grow(|| match stmt {
+            Stmt::Block(block) => self.emit_block_stmt(block),
+            Stmt::Empty(empty) => self.wr.write_semi(Some(empty.span(self.ast))),
+            Stmt::Debugger(debugger) => {
+                let span = debugger.span(self.ast);
+                self.wr.write_keyword(Some(span), "debugger")?;
+                self.wr.write_semi(Some(span))
+            }
+            Stmt::With(with) => self.emit_with_stmt(with),
+            Stmt::Return(ret) => self.emit_return_stmt(ret),
+            Stmt::Labeled(labeled) => self.emit_labeled_stmt(labeled),
+            Stmt::Break(stmt) => {
+                self.emit_jump_stmt(stmt.span(self.ast), "break", stmt.label(self.ast))
+            }
+            Stmt::Continue(stmt) => {
+                self.emit_jump_stmt(stmt.span(self.ast), "continue", stmt.label(self.ast))
+            }
+            Stmt::If(stmt) => self.emit_if_stmt(stmt),
+            Stmt::Switch(stmt) => self.emit_switch_stmt(stmt),
+            Stmt::Throw(stmt) => self.emit_throw_stmt(stmt),
+            Stmt::Try(stmt) => self.emit_try_stmt(stmt),
+            Stmt::While(stmt) => self.emit_while_stmt(stmt),
+            Stmt::DoWhile(stmt) => self.emit_do_while_stmt(stmt),
+            Stmt::For(stmt) => self.emit_for_stmt(stmt),
+            Stmt::ForIn(stmt) => self.emit_for_in_stmt(stmt),
+            Stmt::ForOf(stmt) => self.emit_for_of_stmt(stmt),
+            Stmt::Decl(decl) => {
+                if self.is_erased_decl(decl)? {
+                    // A type-only declaration as the body of a statement.
+                    return self.wr.write_semi(None);
+                }
+                self.emit_decl(decl)
+            }
+            Stmt::Expr(stmt) => self.emit_expr_stmt(stmt),
+        })
+    }
+
+    /// Emits the statements of a block, one per line.
+    pub(crate) fn emit_stmts(&mut self, stmts: TypedSubRange<Stmt>) -> Result {
+        for stmt in stmts.iter() {
+            let stmt = self.ast.get_node_in_sub_range(stmt);
+            if let Stmt::Decl(decl) = stmt
+                && self.is_erased_decl(decl)?
+            {
+                continue;
+            }
+            self.emit_stmt(stmt)?;
+            self.formatting_newline()?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn emit_block_stmt(&mut self, block: BlockStmt) -> Result {
+        let span = block.span(self.ast);
+        let stmts = block.stmts(self.ast);
+        self.wr.write_punct(Some(span), "{")?;
+        if !stmts.is_empty() {
+            self.wr.increase_indent()?;
+            self.formatting_newline()?;
+            self.emit_stmts(stmts)?;
+            self.wr.decrease_indent()?;
+        }
+        self.wr.write_punct(None, "}")
+    }
+
+    /// Emits the body of a compound statement after its head.
+    fn emit_body(&mut self, body: Stmt) -> Result {
+        self.formatting_space()?;
+        self.emit_stmt(body)
+    }
+
+    fn emit_expr_stmt(&mut self, stmt: ExprStmt) -> Result {
+        let span = stmt.span(self.ast);
+        let expr = stmt.expr(self.ast);
+        match StartsWith::expr(self.ast, expr) {
+            StartsWith::Brace | StartsWith::Function | StartsWith::Class | StartsWith::Let => {
+                self.emit_paren_expr(expr)?
+            }
+            // A directive starts at its string. Other strings, e.g. the one of
+            // `("use strict");` once the parentheses are removed, keep them so
+            // that they don't become directives.
+            _ if matches!(expr, Expr::Lit(Lit::Str(s)) if s.span(self.ast).lo != span.lo) => {
+                self.emit_paren_expr(expr)?
+            }
+            _ => self.emit_expr(expr, Precedence::Seq)?,
+        }
+        self.wr.write_semi(Some(span))
+    }
+
+    fn emit_with_stmt(&mut self, stmt: WithStmt) -> Result {
+        self.wr.write_keyword(Some(stmt.span(self.ast)), "with")?;
+        self.formatting_space()?;
+        self.emit_paren_expr(stmt.obj(self.ast))?;
+        self.emit_body(stmt.body(self.ast))
+    }
+
+    fn emit_return_stmt(&mut self, stmt: ReturnStmt) -> Result {
+        let span = stmt.span(self.ast);
+        self.wr.write_keyword(Some(span), "return")?;
+        if let Some(arg) = stmt.arg(self.ast) {
+            // The argument is always printed on the same line, as a line break
+            // after `return` would end the statement.
+            self.wr.write_space()?;
+            self.emit_expr(arg, Precedence::Seq)?;
+        }
+        self.wr.write_semi(Some(span))
+    }
+
+    fn emit_labeled_stmt(&mut self, stmt: LabeledStmt) -> Result {
+        self.emit_ident(stmt.label(self.ast))?;
+        self.wr.write_punct(None, ":")?;
+        self.emit_body(stmt.body(self.ast))
+    }
+
+    fn emit_jump_stmt(
+        &mut self,
+        span: Span,
+        keyword: &'static str,
+        label: Option<Ident>,
+    ) -> Result {
+        self.wr.write_keyword(Some(span), keyword)?;
+        if let Some(label) = label {
+            self.wr.write_space()?;
+            self.emit_ident(label)?;
+        }
+        self.wr.write_semi(Some(span))
+    }
+
+    fn emit_if_stmt(&mut self, stmt: IfStmt) -> Result {
+        self.wr.write_keyword(Some(stmt.span(self.ast)), "if")?;
+        self.formatting_space()?;
+        self.emit_paren_expr(stmt.test(self.ast))?;
+
+        let cons = stmt.cons(self.ast);
+        let Some(alt) = stmt.alt(self.ast) else {
+            return self.emit_body(cons);
+        };
+
+        // `if (a) if (b) c; else d;` binds the `else` to the inner statement.
+        let wrap_cons = ends_with_dangling_if(self.ast, cons);
+        self.formatting_space()?;
+        if wrap_cons {
+            self.wr.write_punct(None, "{")?;
+            self.emit_stmt(cons)?;
+            self.wr.write_punct(None, "}")?;
+        } else {
+            self.emit_stmt(cons)?;
+        }
+        // Every statement ends with `;` or `}`.
+        self.formatting_space()?;
+        self.wr.write_keyword(None, "else")?;
+        match alt {
+            // `else` must be separated from anything that could be a part of a name.
+            Stmt::Block(..) | Stmt::Empty(..) => self.formatting_space()?,
+            _ => self.wr.write_space()?,
+        }
+        self.emit_stmt(alt)
+    }
+
+    fn emit_switch_stmt(&mut self, stmt: SwitchStmt) -> Result {
+        let span = stmt.span(self.ast);
+        self.wr.write_keyword(Some(span), "switch")?;
+        self.formatting_space()?;
+        self.emit_paren_expr(stmt.discriminant(self.ast))?;
+        self.formatting_space()?;
+        self.wr.write_punct(None, "{")?;
+        self.wr.increase_indent()?;
+        self.formatting_newline()?;
+        for case in stmt.cases(self.ast).iter() {
+            let case = self.ast.get_node_in_sub_range(case);
+            let case_span = case.span(self.ast);
+            match case.test(self.ast) {
+                Some(test) => {
+                    self.wr.write_keyword(Some(case_span), "case")?;
+                    self.wr.write_space()?;
+                    self.emit_expr(test, Precedence::Seq)?;
+                }
+                None => self.wr.write_keyword(Some(case_span), "default")?,
+            }
+            self.wr.write_punct(None, ":")?;
+            self.wr.increase_indent()?;
+            self.formatting_newline()?;
+            self.emit_stmts(case.cons(self.ast))?;
+            self.wr.decrease_indent()?;
+        }
+        self.wr.decrease_indent()?;
+        self.wr.write_punct(None, "}")
+    }
+
+    fn emit_throw_stmt(&mut self, stmt: ThrowStmt) -> Result {
+        let span = stmt.span(self.ast);
+        self.wr.write_keyword(Some(span), "throw")?;
+        self.wr.write_space()?;
+        self.emit_expr(stmt.arg(self.ast), Precedence::Seq)?;
+        self.wr.write_semi(Some(span))
+    }
+
+    fn emit_try_stmt(&mut self, stmt: TryStmt) -> Result {
+        self.wr.write_keyword(Some(stmt.span(self.ast)), "try")?;
+        self.formatting_space()?;
+        self.emit_block_stmt(stmt.block(self.ast))?;
+
+        if let Some(handler) = stmt.handler(self.ast) {
+            self.formatting_space()?;
+            self.wr
+                .write_keyword(Some(handler.span(self.ast)), "catch")?;
+            self.formatting_space()?;
+            if let Some(param) = handler.param(self.ast) {
+                self.wr.write_punct(None, "(")?;
+                self.emit_pat(param)?;
+                self.wr.write_punct(None, ")")?;
+                self.formatting_space()?;
+            }
+            self.emit_block_stmt(handler.body(self.ast))?;
+        }
+
+        if let Some(finalizer) = stmt.finalizer(self.ast) {
+            self.formatting_space()?;
+            self.wr.write_keyword(None, "finally")?;
+            self.formatting_space()?;
+            self.emit_block_stmt(finalizer)?;
+        }
+        Ok(())
+    }
+
+    fn emit_while_stmt(&mut self, stmt: WhileStmt) -> Result {
+        self.wr.write_keyword(Some(stmt.span(self.ast)), "while")?;
+        self.formatting_space()?;
+        self.emit_paren_expr(stmt.test(self.ast))?;
+        self.emit_body(stmt.body(self.ast))
+    }
+
+    fn emit_do_while_stmt(&mut self, stmt: DoWhileStmt) -> Result {
+        let span = stmt.span(self.ast);
+        self.wr.write_keyword(Some(span), "do")?;
+        let body = stmt.body(self.ast);
+        if matches!(body, Stmt::Block(..)) {
+            self.formatting_space()?;
+        } else {
+            self.wr.write_space()?;
+        }
+        self.emit_stmt(body)?;
+        self.formatting_space()?;
+        self.wr.write_keyword(None, "while")?;
+        self.formatting_space()?;
+        self.emit_paren_expr(stmt.test(self.ast))?;
+        self.wr.write_semi(Some(span))
+    }
+
+    fn emit_for_stmt(&mut self, stmt: ForStmt) -> Result {
+        self.wr.write_keyword(Some(stmt.span(self.ast)), "for")?;
+        self.formatting_space()?;
+        self.wr.write_punct(None, "(")?;
+        match stmt.init(self.ast) {
+            Some(VarDeclOrExpr::VarDecl(decl)) => self.emit_var_decl(decl, true)?,
+            Some(VarDeclOrExpr::Expr(init)) => {
+                if contains_in_operator(self.ast, init)
+                    || StartsWith::expr(self.ast, init) == StartsWith::Let
+                {
+                    self.emit_paren_expr(init)?;
+                } else {
+                    self.emit_expr(init, Precedence::Seq)?;
+                }
+            }
+            None => {}
+        }
+        self.wr.write_semi(None)?;
+        if let Some(test) = stmt.test(self.ast) {
+            self.formatting_space()?;
+            self.emit_expr(test, Precedence::Seq)?;
+        }
+        self.wr.write_semi(None)?;
+        if let Some(update) = stmt.update(self.ast) {
+            self.formatting_space()?;
+            self.emit_expr(update, Precedence::Seq)?;
+        }
+        self.wr.write_punct(None, ")")?;
+        self.emit_body(stmt.body(self.ast))
+    }
+
+    fn emit_for_in_stmt(&mut self, stmt: ForInStmt) -> Result {
+        self.wr.write_keyword(Some(stmt.span(self.ast)), "for")?;
+        self.formatting_space()?;
+        self.wr.write_punct(None, "(")?;
+        self.emit_for_head(stmt.left(self.ast), false)?;
+        self.wr.write_space()?;
+        self.wr.write_keyword(None, "in")?;
+        self.wr.write_space()?;
+        self.emit_expr(stmt.right(self.ast), Precedence::Seq)?;
+        self.wr.write_punct(None, ")")?;
+        self.emit_body(stmt.body(self.ast))
+    }
+
+    fn emit_for_of_stmt(&mut self, stmt: ForOfStmt) -> Result {
+        self.wr.write_keyword(Some(stmt.span(self.ast)), "for")?;
+        if stmt.is_await(self.ast) {
+            self.wr.write_space()?;
+            self.wr.write_keyword(None, "await")?;
+        }
+        self.formatting_space()?;
+        self.wr.write_punct(None, "(")?;
+        self.emit_for_head(stmt.left(self.ast), true)?;
+        self.wr.write_space()?;
+        self.wr.write_keyword(None, "of")?;
+        self.wr.write_space()?;
+        self.emit_expr(stmt.right(self.ast), Precedence::Assign)?;
+        self.wr.write_punct(None, ")")?;
+        self.emit_body(stmt.body(self.ast))
+    }
+
+    fn emit_for_head(&mut self, head: ForHead, is_for_of: bool) -> Result {
+        match head {
+            ForHead::VarDecl(decl) => self.emit_var_decl(decl, true),
+            ForHead::UsingDecl(decl) => self.emit_using_decl(decl, true),
+            ForHead::Pat(pat) => {
+                // `for (let of x)` and `for (async of x)` start other productions.
+                let ident = match pat {
+                    Pat::Ident(ident) => Some(ident.id(self.ast)),
+                    Pat::Expr(Expr::Ident(ident)) => Some(ident),
+                    _ => None,
+                };
+                let needs_paren = match ident {
+                    Some(ident) => {
+                        let sym = self.ast.get_utf8(ident.sym(self.ast));
+                        sym == "let" || (is_for_of && sym == "async")
+                    }
+                    None => match pat {
+                        Pat::Expr(expr) => StartsWith::expr(self.ast, expr) == StartsWith::Let,
+                        _ => false,
+                    },
+                };
+                if needs_paren {
+                    self.wr.write_punct(None, "(")?;
+                    self.emit_pat(pat)?;
+                    self.wr.write_punct(None, ")")
+                } else {
+                    self.emit_pat(pat)
+                }
+            }
+        }
+    }
+}
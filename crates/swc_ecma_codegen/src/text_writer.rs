@@ -0,0 +1,206 @@
+use std::io::{self, Write};
+
+use swc_experimental_ecma_ast::Span;
+
+use crate::{
+    Result,
+    source_map::{LineCol, SourceMapBuilder, utf16_len},
+};
+
+/// The output of an [`Emitter`](crate::Emitter).
+///
+/// The emitter passes the span of the node each token belongs to, so that a
+/// writer can relate the output to the original source.
+pub trait WriteJs {
+    fn increase_indent(&mut self) -> Result;
+
+    fn decrease_indent(&mut self) -> Result;
+
+    fn write_line(&mut self) -> Result;
+
+    fn write_space(&mut self) -> Result;
+
+    fn write_semi(&mut self, span: Option<Span>) -> Result;
+
+    fn write_keyword(&mut self, span: Option<Span>, s: &'static str) -> Result;
+
+    fn write_operator(&mut self, span: Option<Span>, s: &str) -> Result;
+
+    fn write_punct(&mut self, span: Option<Span>, s: &'static str) -> Result;
+
+    /// Writes an identifier, a private name or a property name.
+    fn write_symbol(&mut self, span: Span, s: &str) -> Result;
+
+    /// Writes a literal. Template and JSX text may contain line breaks.
+    fn write_lit(&mut self, span: Span, s: &str) -> Result;
+}
+
+impl<W: WriteJs + ?Sized> WriteJs for &mut W {
+    #[inline]
+    fn increase_indent(&mut self) -> Result {
+        (**self).increase_indent()
+    }
+
+    #[inline]
+    fn decrease_indent(&mut self) -> Result {
+        (**self).decrease_indent()
+    }
+
+    #[inline]
+    fn write_line(&mut self) -> Result {
+        (**self).write_line()
+    }
+
+    #[inline]
+    fn write_space(&mut self) -> Result {
+        (**self).write_space()
+    }
+
+    #[inline]
+    fn write_semi(&mut self, span: Option<Span>) -> Result {
+        (**self).write_semi(span)
+    }
+
+    #[inline]
+    fn write_keyword(&mut self, span: Option<Span>, s: &'static str) -> Result {
+        (**self).write_keyword(span, s)
+    }
+
+    #[inline]
+    fn write_operator(&mut self, span: Option<Span>, s: &str) -> Result {
+        (**self).write_operator(span, s)
+    }
+
+    #[inline]
+    fn write_punct(&mut self, span: Option<Span>, s: &'static str) -> Result {
+        (**self).write_punct(span, s)
+    }
+
+    #[inline]
+    fn write_symbol(&mut self, span: Span, s: &str) -> Result {
+        (**self).write_symbol(span, s)
+    }
+
+    #[inline]
+    fn write_lit(&mut self, span: Span, s: &str) -> Result {
+        (**self).write_lit(span, s)
+    }
+}
+
+/// A [`WriteJs`] which writes the code to an [`io::Write`], and optionally
+/// collects the mappings for a source map.
+pub struct JsWriter<'a, W: Write> {
+    indent: usize,
+    line_start: bool,
+    new_line: &'a str,
+    line: u32,
+    col: u32,
+    srcmap: Option<&'a mut SourceMapBuilder>,
+    wr: W,
+}
+
+impl<'a, W: Write> JsWriter<'a, W> {
+    pub fn new(new_line: &'a str, wr: W, srcmap: Option<&'a mut SourceMapBuilder>) -> Self {
+        Self {
+            indent: 0,
+            line_start: true,
+            new_line,
+            line: 0,
+            col: 0,
+            srcmap,
+            wr,
+        }
+    }
+
+    fn write_indent(&mut self) -> io::Result<()> {
+        if self.line_start {
+            for _ in 0..self.indent {
+                self.wr.write_all(b"    ")?;
+            }
+            self.col += self.indent as u32 * 4;
+            self.line_start = false;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, span: Option<Span>, name: Option<&str>, s: &str) -> Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        self.write_indent()?;
+        if let Some(srcmap) = &mut self.srcmap
+            && let Some(span) = span
+            && !span.is_dummy()
+        {
+            let generated = LineCol {
+                line: self.line,
+                col: self.col,
+            };
+            srcmap.add_mapping(generated, span.lo, name);
+        }
+        self.wr.write_all(s.as_bytes())?;
+        self.update_pos(s);
+        Ok(())
+    }
+
+    fn update_pos(&mut self, s: &str) {
+        if self.srcmap.is_none() {
+            return;
+        }
+        match s.rfind('\n') {
+            Some(i) => {
+                self.line += s.matches('\n').count() as u32;
+                self.col = utf16_len(&s[i + 1..]);
+            }
+            None => self.col += utf16_len(s),
+        }
+    }
+}
+
+impl<W: Write> WriteJs for JsWriter<'_, W> {
+    fn increase_indent(&mut self) -> Result {
+        self.indent += 1;
+        Ok(())
+    }
+
+    fn decrease_indent(&mut self) -> Result {
+        self.indent -= 1;
+        Ok(())
+    }
+
+    fn write_line(&mut self) -> Result {
+        self.wr.write_all(self.new_line.as_bytes())?;
+        self.update_pos(self.new_line);
+        self.line_start = true;
+        Ok(())
+    }
+
+    fn write_space(&mut self) -> Result {
+        self.write(None, None, " ")
+    }
+
+    fn write_semi(&mut self, _span: Option<Span>) -> Result {
+        // The span is the one of the whole statement, which starts elsewhere.
+        self.write(None, None, ";")
+    }
+
+    fn write_keyword(&mut self, span: Option<Span>, s: &'static str) -> Result {
+        self.write(span, None, s)
+    }
+
+    fn write_operator(&mut self, span: Option<Span>, s: &str) -> Result {
+        self.write(span, None, s)
+    }
+
+    fn write_punct(&mut self, span: Option<Span>, s: &'static str) -> Result {
+        self.write(span, None, s)
+    }
+
+    fn write_symbol(&mut self, span: Span, s: &str) -> Result {
+        self.write(Some(span), Some(s), s)
+    }
+
+    fn write_lit(&mut self, span: Span, s: &str) -> Result {
+        self.write(Some(span), None, s)
+    }
+}
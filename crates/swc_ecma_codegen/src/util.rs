@@ -0,0 +1,272 @@
+use std::ops::ControlFlow;
+
+use swc_experimental_ecma_ast::*;
+
+/// Binding power of an expression. An operand is wrapped in parentheses if it
+/// binds weaker than its position requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Precedence {
+    Seq,
+    /// Assignments, arrow functions, `yield` and spread arguments.
+    Assign,
+    Cond,
+    /// `||` and `??`
+    LogicalOr,
+    LogicalAnd,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Equality,
+    Relational,
+    Shift,
+    Additive,
+    Multiplicative,
+    Exponential,
+    /// Prefix operators and `await`.
+    Unary,
+    Postfix,
+    /// `new` without arguments.
+    New,
+    /// Calls, member accesses and `new` with arguments.
+    Call,
+    Primary,
+}
+
+impl Precedence {
+    pub(crate) fn of_bin_op(op: BinaryOp) -> Self {
+        match op {
+            BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => Precedence::LogicalOr,
+            BinaryOp::LogicalAnd => Precedence::LogicalAnd,
+            BinaryOp::BitOr => Precedence::BitOr,
+            BinaryOp::BitXor => Precedence::BitXor,
+            BinaryOp::BitAnd => Precedence::BitAnd,
+            BinaryOp::EqEq | BinaryOp::NotEq | BinaryOp::EqEqEq | BinaryOp::NotEqEq => {
+                Precedence::Equality
+            }
+            BinaryOp::Lt
+            | BinaryOp::LtEq
+            | BinaryOp::Gt
+            | BinaryOp::GtEq
+            | BinaryOp::In
+            | BinaryOp::InstanceOf => Precedence::Relational,
+            BinaryOp::LShift | BinaryOp::RShift | BinaryOp::ZeroFillRShift => Precedence::Shift,
+            BinaryOp::Add | BinaryOp::Sub => Precedence::Additive,
+            BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => Precedence::Multiplicative,
+            BinaryOp::Exp => Precedence::Exponential,
+        }
+    }
+
+    pub(crate) fn of_expr(ast: &Ast, expr: Expr) -> Self {
+        match expr {
+            Expr::Seq(..) => Precedence::Seq,
+            Expr::Assign(..) | Expr::Arrow(..) | Expr::Yield(..) => Precedence::Assign,
+            Expr::Cond(..) => Precedence::Cond,
+            Expr::Bin(bin) => Self::of_bin_op(bin.op(ast)),
+            Expr::Unary(..) | Expr::Await(..) => Precedence::Unary,
+            Expr::Update(update) => {
+                if update.prefix(ast) {
+                    Precedence::Unary
+                } else {
+                    Precedence::Postfix
+                }
+            }
+            Expr::New(new) => {
+                if new.args(ast).is_some() {
+                    Precedence::Call
+                } else {
+                    Precedence::New
+                }
+            }
+            Expr::Member(..)
+            | Expr::SuperProp(..)
+            | Expr::Call(..)
+            | Expr::TaggedTpl(..)
+            | Expr::OptChain(..) => Precedence::Call,
+            // Negative numbers only come from transforms and are printed as `-x`.
+            Expr::Lit(Lit::Num(num)) if num.value(ast).is_sign_negative() => Precedence::Unary,
+            Expr::TsTypeAssertion(e) => Self::of_expr(ast, e.expr(ast)),
+            Expr::TsConstAssertion(e) => Self::of_expr(ast, e.expr(ast)),
+            Expr::TsNonNull(e) => Self::of_expr(ast, e.expr(ast)),
+            Expr::TsAs(e) => Self::of_expr(ast, e.expr(ast)),
+            Expr::TsInstantiation(e) => Self::of_expr(ast, e.expr(ast)),
+            Expr::TsSatisfies(e) => Self::of_expr(ast, e.expr(ast)),
+            _ => Precedence::Primary,
+        }
+    }
+}
+
+/// The first token of an expression, as far as it matters for the places
+/// where some tokens start a different production.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StartsWith {
+    /// `{`, which starts a block or an object pattern.
+    Brace,
+    /// `function` or `async function`
+    Function,
+    Class,
+    /// The identifier `let`, which may start a lexical declaration.
+    Let,
+    /// `+` or `++`, which merges with a preceding `+`.
+    Plus,
+    /// `-`, `--` or a negative number, which merges with a preceding `-`.
+    Minus,
+    /// A regular expression, which starts a comment after a `/`.
+    Regex,
+    Other,
+}
+
+impl StartsWith {
+    /// Returns what `expr` starts with if it is printed without parentheses.
+    pub(crate) fn expr(ast: &Ast, mut expr: Expr) -> Self {
+        // The leftmost subexpressions are followed in a loop, as chains like
+        // `a + b + c` may nest too deep to recurse into.
+        loop {
+            expr = match expr {
+                Expr::Object(..) => return StartsWith::Brace,
+                Expr::Fn(..) => return StartsWith::Function,
+                Expr::Class(..) => return StartsWith::Class,
+                Expr::Ident(ident) => return Self::ident(ast, ident),
+                Expr::Bin(bin) => bin.left(ast),
+                Expr::Seq(seq) => match seq.exprs(ast).first() {
+                    Some(first) => ast.get_node_in_sub_range(first),
+                    None => return StartsWith::Other,
+                },
+                Expr::Cond(cond) => cond.test(ast),
+                Expr::Assign(assign) => match assign.left(ast) {
+                    AssignTarget::Pat(AssignTargetPat::Object(..)) => return StartsWith::Brace,
+                    AssignTarget::Simple(target) => match target {
+                        SimpleAssignTarget::Ident(ident) => return Self::ident(ast, ident.id(ast)),
+                        SimpleAssignTarget::Member(member) => member.obj(ast),
+                        SimpleAssignTarget::OptChain(chain) => {
+                            Self::opt_chain_base(ast, chain.base(ast))
+                        }
+                        SimpleAssignTarget::TsAs(e) => e.expr(ast),
+                        SimpleAssignTarget::TsSatisfies(e) => e.expr(ast),
+                        SimpleAssignTarget::TsNonNull(e) => e.expr(ast),
+                        SimpleAssignTarget::TsTypeAssertion(e) => e.expr(ast),
+                        SimpleAssignTarget::TsInstantiation(e) => e.expr(ast),
+                        _ => return StartsWith::Other,
+                    },
+                    _ => return StartsWith::Other,
+                },
+                Expr::Unary(unary) => {
+                    return match unary.op(ast) {
+                        UnaryOp::Plus => StartsWith::Plus,
+                        UnaryOp::Minus => StartsWith::Minus,
+                        _ => StartsWith::Other,
+                    };
+                }
+                Expr::Update(update) if update.prefix(ast) => {
+                    return match update.op(ast) {
+                        UpdateOp::PlusPlus => StartsWith::Plus,
+                        UpdateOp::MinusMinus => StartsWith::Minus,
+                    };
+                }
+                Expr::Update(update) => update.arg(ast),
+                Expr::Lit(Lit::Num(num)) if num.value(ast).is_sign_negative() => {
+                    return StartsWith::Minus;
+                }
+                Expr::Lit(Lit::Regex(..)) => return StartsWith::Regex,
+                Expr::Member(member) => member.obj(ast),
+                Expr::Call(call) => match call.callee(ast) {
+                    Callee::Expr(callee) => callee,
+                    _ => return StartsWith::Other,
+                },
+                Expr::TaggedTpl(tpl) => tpl.tag(ast),
+                Expr::OptChain(chain) => Self::opt_chain_base(ast, chain.base(ast)),
+                Expr::TsTypeAssertion(e) => e.expr(ast),
+                Expr::TsConstAssertion(e) => e.expr(ast),
+                Expr::TsNonNull(e) => e.expr(ast),
+                Expr::TsAs(e) => e.expr(ast),
+                Expr::TsInstantiation(e) => e.expr(ast),
+                Expr::TsSatisfies(e) => e.expr(ast),
+                _ => return StartsWith::Other,
+            };
+        }
+    }
+
+    /// Returns the leftmost subexpression of `base`.
+    fn opt_chain_base(ast: &Ast, base: OptChainBase) -> Expr {
+        match base {
+            OptChainBase::Member(member) => member.obj(ast),
+            OptChainBase::Call(call) => call.callee(ast),
+        }
+    }
+
+    fn ident(ast: &Ast, ident: Ident) -> Self {
+        if ast.get_utf8(ident.sym(ast)) == "let" {
+            StartsWith::Let
+        } else {
+            StartsWith::Other
+        }
+    }
+}
+
+/// Returns `true` if `expr` contains an `in` operator which would be parsed as
+/// the `in` of a `for (... in ...)` statement when printed in its head.
+pub(crate) fn contains_in_operator(ast: &Ast, expr: Expr) -> bool {
+    struct InFinder<'a> {
+        ast: &'a Ast,
+    }
+
+    impl VisitControl for InFinder<'_> {
+        type Break = ();
+
+        fn ast(&self) -> &Ast {
+            self.ast
+        }
+
+        fn visit_bin_expr(&mut self, node: BinExpr) -> ControlFlow<()> {
+            if node.op(self.ast) == BinaryOp::In {
+                return ControlFlow::Break(());
+            }
+            node.visit_children_with_control(self)
+        }
+
+        // The operator is allowed again inside of parentheses and brackets.
+        fn visit_paren_expr(&mut self, _: ParenExpr) -> ControlFlow<()> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_function(&mut self, _: Function) -> ControlFlow<()> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_class(&mut self, _: Class) -> ControlFlow<()> {
+            ControlFlow::Continue(())
+        }
+    }
+
+    expr.visit_with_control(&mut InFinder { ast }).is_break()
+}
+
+/// Returns `true` if `stmt` ends with an `if` statement without `else`, which
+/// would take the `else` of an enclosing `if` statement.
+pub(crate) fn ends_with_dangling_if(ast: &Ast, stmt: Stmt) -> bool {
+    match stmt {
+        Stmt::If(stmt) => match stmt.alt(ast) {
+            Some(alt) => ends_with_dangling_if(ast, alt),
+            None => true,
+        },
+        Stmt::Labeled(stmt) => ends_with_dangling_if(ast, stmt.body(ast)),
+        Stmt::With(stmt) => ends_with_dangling_if(ast, stmt.body(ast)),
+        Stmt::While(stmt) => ends_with_dangling_if(ast, stmt.body(ast)),
+        Stmt::For(stmt) => ends_with_dangling_if(ast, stmt.body(ast)),
+        Stmt::ForIn(stmt) => ends_with_dangling_if(ast, stmt.body(ast)),
+        Stmt::ForOf(stmt) => ends_with_dangling_if(ast, stmt.body(ast)),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `expr` contains a call in the callee position of a `new`
+/// expression, where the arguments would be taken by the `new` instead.
+pub(crate) fn contains_call(ast: &Ast, expr: Expr) -> bool {
+    match expr {
+        Expr::Call(..) | Expr::OptChain(..) => true,
+        Expr::Member(member) => contains_call(ast, member.obj(ast)),
+        Expr::TaggedTpl(tpl) => contains_call(ast, tpl.tag(ast)),
+        Expr::TsNonNull(e) => contains_call(ast, e.expr(ast)),
+        Expr::TsInstantiation(e) => contains_call(ast, e.expr(ast)),
+        _ => false,
+    }
+}
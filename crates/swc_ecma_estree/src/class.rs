@@ -0,0 +1,212 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{Result, Serializer, json::byte_pos, unsupported};
+
+impl Serializer<'_> {
+    pub(crate) fn serialize_class(
+        &mut self,
+        ty: &str,
+        span: Span,
+        ident: Option<Ident>,
+        class: Class,
+    ) -> Result {
+        let super_class = class.super_class(self.ast);
+        self.start_node(ty, span);
+        self.serialize_decorators(class.decorators(self.ast))?;
+        self.key("id");
+        self.opt(ident, |s, ident| {
+            s.serialize_ident(ident);
+            Ok(())
+        })?;
+        self.key("superClass");
+        self.opt(super_class, Self::serialize_expr)?;
+
+        // The body starts at the brace after the heritage.
+        let head_end = match (super_class, ident) {
+            (Some(super_class), _) => super_class.span(self.ast).hi,
+            (None, Some(ident)) => ident.span(self.ast).hi,
+            (None, None) => span.lo,
+        };
+        let body_start = self.find_token(byte_pos(head_end), b'{');
+        self.key("body");
+        self.start_node_at("ClassBody", body_start, byte_pos(span.hi));
+        self.key("body");
+        let ast = self.ast;
+        let members = class
+            .body(ast)
+            .iter()
+            .map(|member| ast.get_node_in_sub_range(member))
+            .filter(|member| !matches!(member, ClassMember::Empty(..)));
+        self.array(members, Self::serialize_class_member)?;
+        self.end_node();
+
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_class_member(&mut self, member: ClassMember) -> Result {
+        match member {
+            ClassMember::Constructor(constructor) => self.serialize_constructor(constructor),
+            ClassMember::Method(method) => {
+                let key = method.key(self.ast);
+                let function = method.function(self.ast);
+                self.start_node("MethodDefinition", method.span(self.ast));
+                self.serialize_decorators(function.decorators(self.ast))?;
+                self.key("static");
+                self.bool_value(method.is_static(self.ast));
+                self.key("computed");
+                self.bool_value(matches!(key, PropName::Computed(..)));
+                self.key("key");
+                self.serialize_prop_name(key)?;
+                self.key("kind");
+                self.str_value(method_kind_str(method.kind(self.ast)));
+                self.key("value");
+                self.serialize_method_function(key.span(self.ast), function)?;
+                self.end_node();
+                Ok(())
+            }
+            ClassMember::PrivateMethod(method) => {
+                let key = method.key(self.ast);
+                let function = method.function(self.ast);
+                self.start_node("MethodDefinition", method.span(self.ast));
+                self.serialize_decorators(function.decorators(self.ast))?;
+                self.key("static");
+                self.bool_value(method.is_static(self.ast));
+                self.key("computed");
+                self.bool_value(false);
+                self.key("key");
+                self.serialize_private_name(key);
+                self.key("kind");
+                self.str_value(method_kind_str(method.kind(self.ast)));
+                self.key("value");
+                self.serialize_method_function(key.span(self.ast), function)?;
+                self.end_node();
+                Ok(())
+            }
+            ClassMember::ClassProp(prop) => {
+                let key = prop.key(self.ast);
+                self.start_node("PropertyDefinition", prop.span(self.ast));
+                self.serialize_decorators(prop.decorators(self.ast))?;
+                self.key("static");
+                self.bool_value(prop.is_static(self.ast));
+                self.key("computed");
+                self.bool_value(matches!(key, PropName::Computed(..)));
+                self.key("key");
+                self.serialize_prop_name(key)?;
+                self.key("value");
+                self.opt(prop.value(self.ast), Self::serialize_expr)?;
+                self.end_node();
+                Ok(())
+            }
+            ClassMember::PrivateProp(prop) => {
+                self.start_node("PropertyDefinition", prop.span(self.ast));
+                self.serialize_decorators(prop.decorators(self.ast))?;
+                self.key("static");
+                self.bool_value(prop.is_static(self.ast));
+                self.key("computed");
+                self.bool_value(false);
+                self.key("key");
+                self.serialize_private_name(prop.key(self.ast));
+                self.key("value");
+                self.opt(prop.value(self.ast), Self::serialize_expr)?;
+                self.end_node();
+                Ok(())
+            }
+            ClassMember::Empty(..) => Ok(()),
+            ClassMember::StaticBlock(block) => {
+                self.start_node("StaticBlock", block.span(self.ast));
+                self.key("body");
+                self.list(block.body(self.ast).stmts(self.ast), Self::serialize_stmt)?;
+                self.end_node();
+                Ok(())
+            }
+            ClassMember::AutoAccessor(accessor) => {
+                let key = accessor.key(self.ast);
+                self.start_node("AccessorProperty", accessor.span(self.ast));
+                self.serialize_decorators(accessor.decorators(self.ast))?;
+                self.key("static");
+                self.bool_value(accessor.is_static(self.ast));
+                self.key("computed");
+                self.bool_value(matches!(key, Key::Public(PropName::Computed(..))));
+                self.key("key");
+                match key {
+                    Key::Private(name) => self.serialize_private_name(name),
+                    Key::Public(key) => self.serialize_prop_name(key)?,
+                }
+                self.key("value");
+                self.opt(accessor.value(self.ast), Self::serialize_expr)?;
+                self.end_node();
+                Ok(())
+            }
+            ClassMember::TsIndexSignature(..) => Err(unsupported("TsIndexSignature")),
+        }
+    }
+
+    fn serialize_constructor(&mut self, constructor: Constructor) -> Result {
+        let key = constructor.key(self.ast);
+        let Some(body) = constructor.body(self.ast) else {
+            return Err(unsupported("A constructor without a body"));
+        };
+        self.start_node("MethodDefinition", constructor.span(self.ast));
+        self.key("static");
+        self.bool_value(false);
+        self.key("computed");
+        self.bool_value(false);
+        self.key("key");
+        self.serialize_prop_name(key)?;
+        self.key("kind");
+        self.str_value("constructor");
+
+        // The function is implied by the constructor in the AST.
+        self.key("value");
+        let start = self.find_token(byte_pos(key.span(self.ast).hi), b'(');
+        self.start_node_at(
+            "FunctionExpression",
+            start,
+            byte_pos(body.span(self.ast).hi),
+        );
+        self.key("id");
+        self.null_value();
+        self.key("expression");
+        self.bool_value(false);
+        self.key("generator");
+        self.bool_value(false);
+        self.key("async");
+        self.bool_value(false);
+        self.key("params");
+        self.list(constructor.params(self.ast), |s, param| match param {
+            ParamOrTsParamProp::Param(param) => s.serialize_pat(param.pat(s.ast)),
+            ParamOrTsParamProp::TsParamProp(..) => Err(unsupported("TsParamProp")),
+        })?;
+        self.key("body");
+        self.serialize_function_body(body)?;
+        self.end_node();
+
+        self.end_node();
+        Ok(())
+    }
+
+    /// Serializes `decorators`, which are left out if there are none as they
+    /// aren't part of the ESTree specification yet.
+    pub(crate) fn serialize_decorators(&mut self, decorators: TypedSubRange<Decorator>) -> Result {
+        if decorators.is_empty() {
+            return Ok(());
+        }
+        self.key("decorators");
+        self.list(decorators, |s, decorator| {
+            s.start_node("Decorator", decorator.span(s.ast));
+            s.key("expression");
+            s.serialize_expr(decorator.expr(s.ast))?;
+            s.end_node();
+            Ok(())
+        })
+    }
+}
+
+fn method_kind_str(kind: MethodKind) -> &'static str {
+    match kind {
+        MethodKind::Method => "method",
+        MethodKind::Getter => "get",
+        MethodKind::Setter => "set",
+    }
+}
@@ -0,0 +1,153 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{Result, Serializer, json::byte_pos, unsupported};
+
+impl Serializer<'_> {
+    pub(crate) fn serialize_decl(&mut self, decl: Decl) -> Result {
+        match decl {
+            Decl::Class(decl) => self.serialize_class(
+                "ClassDeclaration",
+                decl.span(self.ast),
+                Some(decl.ident(self.ast)),
+                decl.class(self.ast),
+            ),
+            Decl::Fn(decl) => self.serialize_function(
+                "FunctionDeclaration",
+                decl.function(self.ast).span(self.ast),
+                Some(decl.ident(self.ast)),
+                decl.function(self.ast),
+            ),
+            Decl::Var(decl) => self.serialize_var_decl(decl),
+            Decl::Using(decl) => self.serialize_using_decl(decl),
+            Decl::TsInterface(..) => Err(unsupported("TsInterfaceDecl")),
+            Decl::TsTypeAlias(..) => Err(unsupported("TsTypeAliasDecl")),
+            Decl::TsEnum(..) => Err(unsupported("TsEnumDecl")),
+            Decl::TsModule(..) => Err(unsupported("TsModuleDecl")),
+        }
+    }
+
+    pub(crate) fn serialize_var_decl(&mut self, decl: VarDecl) -> Result {
+        let kind = match decl.kind(self.ast) {
+            VarDeclKind::Var => "var",
+            VarDeclKind::Let => "let",
+            VarDeclKind::Const => "const",
+        };
+        self.serialize_var_declarators(decl.span(self.ast), kind, decl.decls(self.ast))
+    }
+
+    pub(crate) fn serialize_using_decl(&mut self, decl: UsingDecl) -> Result {
+        let kind = if decl.is_await(self.ast) {
+            "await using"
+        } else {
+            "using"
+        };
+        self.serialize_var_declarators(decl.span(self.ast), kind, decl.decls(self.ast))
+    }
+
+    fn serialize_var_declarators(
+        &mut self,
+        span: Span,
+        kind: &str,
+        decls: TypedSubRange<VarDeclarator>,
+    ) -> Result {
+        self.start_node("VariableDeclaration", span);
+        self.key("declarations");
+        self.list(decls, |s, decl| {
+            s.start_node("VariableDeclarator", decl.span(s.ast));
+            s.key("id");
+            s.serialize_pat(decl.name(s.ast))?;
+            s.key("init");
+            s.opt(decl.init(s.ast), Self::serialize_expr)?;
+            s.end_node();
+            Ok(())
+        })?;
+        self.key("kind");
+        self.str_value(kind);
+        self.end_node();
+        Ok(())
+    }
+
+    /// Serializes a function declaration or expression.
+    pub(crate) fn serialize_function(
+        &mut self,
+        ty: &str,
+        span: Span,
+        ident: Option<Ident>,
+        function: Function,
+    ) -> Result {
+        self.start_node(ty, span);
+        self.key("id");
+        self.opt(ident, |s, ident| {
+            s.serialize_ident(ident);
+            Ok(())
+        })?;
+        self.serialize_function_fields(function)?;
+        self.end_node();
+        Ok(())
+    }
+
+    /// Serializes the function of a method, which starts at the parameters in
+    /// ESTree.
+    pub(crate) fn serialize_method_function(
+        &mut self,
+        key_span: Span,
+        function: Function,
+    ) -> Result {
+        let start = self.find_token(byte_pos(key_span.hi), b'(');
+        let end = byte_pos(function.span(self.ast).hi);
+        self.start_node_at("FunctionExpression", start, end);
+        self.key("id");
+        self.null_value();
+        self.serialize_function_fields(function)?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_function_fields(&mut self, function: Function) -> Result {
+        self.key("expression");
+        self.bool_value(false);
+        self.key("generator");
+        self.bool_value(function.is_generator(self.ast));
+        self.key("async");
+        self.bool_value(function.is_async(self.ast));
+        self.key("params");
+        self.list(function.params(self.ast), |s, param| {
+            s.serialize_pat(param.pat(s.ast))
+        })?;
+        self.key("body");
+        match function.body(self.ast) {
+            Some(body) => self.serialize_function_body(body),
+            None => Err(unsupported("A function without a body")),
+        }
+    }
+
+    /// Serializes the function of a getter or a setter, which is implied by
+    /// the property in the AST.
+    pub(crate) fn serialize_accessor_function(
+        &mut self,
+        key_span: Span,
+        param: Option<Pat>,
+        body: Option<BlockStmt>,
+    ) -> Result {
+        let Some(body) = body else {
+            return Err(unsupported("An accessor without a body"));
+        };
+        let start = self.find_token(byte_pos(key_span.hi), b'(');
+        let end = byte_pos(body.span(self.ast).hi);
+        self.start_node_at("FunctionExpression", start, end);
+        self.key("id");
+        self.null_value();
+        self.key("expression");
+        self.bool_value(false);
+        self.key("generator");
+        self.bool_value(false);
+        self.key("async");
+        self.bool_value(false);
+        self.key("params");
+        self.array(param, Self::serialize_pat)?;
+        self.key("body");
+        self.serialize_function_body(body)?;
+        self.end_node();
+        Ok(())
+    }
+}
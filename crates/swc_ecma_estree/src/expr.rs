@@ -0,0 +1,635 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{Result, Serializer, json::byte_pos, unsupported};
+
+impl Serializer<'_> {
+    pub fn serialize_expr(&mut self, expr: Expr) -> Result {
+        maybe_grow(|| match expr {
+            Expr::This(this) => {
+                self.start_node("ThisExpression", this.span(self.ast));
+                self.end_node();
+                Ok(())
+            }
+            Expr::Array(array) => {
+                self.start_node("ArrayExpression", array.span(self.ast));
+                self.key("elements");
+                self.list(array.elems(self.ast), |s, elem| {
+                    s.opt(elem, Self::serialize_expr_or_spread)
+                })?;
+                self.end_node();
+                Ok(())
+            }
+            Expr::Object(object) => self.serialize_object_lit(object),
+            Expr::Fn(f) => self.serialize_function(
+                "FunctionExpression",
+                f.span(self.ast),
+                f.ident(self.ast),
+                f.function(self.ast),
+            ),
+            Expr::Unary(unary) => {
+                self.start_node("UnaryExpression", unary.span(self.ast));
+                self.key("operator");
+                self.str_value(unary_op_str(unary.op(self.ast)));
+                self.key("prefix");
+                self.bool_value(true);
+                self.key("argument");
+                self.serialize_expr(unary.arg(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Expr::Update(update) => {
+                self.start_node("UpdateExpression", update.span(self.ast));
+                self.key("operator");
+                self.str_value(match update.op(self.ast) {
+                    UpdateOp::PlusPlus => "++",
+                    UpdateOp::MinusMinus => "--",
+                });
+                self.key("prefix");
+                self.bool_value(update.prefix(self.ast));
+                self.key("argument");
+                self.serialize_expr(update.arg(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Expr::Bin(bin) => self.serialize_bin_expr(bin),
+            Expr::Assign(assign) => {
+                self.start_node("AssignmentExpression", assign.span(self.ast));
+                self.key("operator");
+                self.str_value(assign_op_str(assign.op(self.ast)));
+                self.key("left");
+                self.serialize_assign_target(assign.left(self.ast))?;
+                self.key("right");
+                self.serialize_expr(assign.right(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Expr::Member(member) => self.serialize_member_expr(member, false, false),
+            Expr::SuperProp(super_prop) => self.serialize_super_prop_expr(super_prop),
+            Expr::Cond(cond) => {
+                self.start_node("ConditionalExpression", cond.span(self.ast));
+                self.key("test");
+                self.serialize_expr(cond.test(self.ast))?;
+                self.key("consequent");
+                self.serialize_expr(cond.cons(self.ast))?;
+                self.key("alternate");
+                self.serialize_expr(cond.alt(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Expr::Call(call) => self.serialize_call_expr(call),
+            Expr::New(new) => {
+                self.start_node("NewExpression", new.span(self.ast));
+                self.key("callee");
+                self.serialize_expr(new.callee(self.ast))?;
+                self.key("arguments");
+                match new.args(self.ast) {
+                    Some(args) => self.list(args, Self::serialize_expr_or_spread)?,
+                    None => self.array(None, Self::serialize_expr_or_spread)?,
+                }
+                self.end_node();
+                Ok(())
+            }
+            Expr::Seq(seq) => {
+                self.start_node("SequenceExpression", seq.span(self.ast));
+                self.key("expressions");
+                self.list(seq.exprs(self.ast), Self::serialize_expr)?;
+                self.end_node();
+                Ok(())
+            }
+            Expr::Ident(ident) => {
+                self.serialize_ident(ident);
+                Ok(())
+            }
+            Expr::Lit(lit) => {
+                self.serialize_lit(lit);
+                Ok(())
+            }
+            Expr::Tpl(tpl) => self.serialize_tpl(tpl),
+            Expr::TaggedTpl(tagged) => {
+                self.start_node("TaggedTemplateExpression", tagged.span(self.ast));
+                self.key("tag");
+                self.serialize_expr(tagged.tag(self.ast))?;
+                self.key("quasi");
+                self.serialize_tpl(tagged.tpl(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Expr::Arrow(arrow) => self.serialize_arrow_expr(arrow),
+            Expr::Class(class) => self.serialize_class(
+                "ClassExpression",
+                class.span(self.ast),
+                class.ident(self.ast),
+                class.class(self.ast),
+            ),
+            Expr::Yield(yield_expr) => {
+                self.start_node("YieldExpression", yield_expr.span(self.ast));
+                self.key("delegate");
+                self.bool_value(yield_expr.delegate(self.ast));
+                self.key("argument");
+                self.opt(yield_expr.arg(self.ast), Self::serialize_expr)?;
+                self.end_node();
+                Ok(())
+            }
+            Expr::MetaProp(meta_prop) => {
+                let span = meta_prop.span(self.ast);
+                let (meta, property) = match meta_prop.kind(self.ast) {
+                    MetaPropKind::NewTarget => ("new", "target"),
+                    MetaPropKind::ImportMeta => ("import", "meta"),
+                };
+                // The parts of a meta property have no nodes in the AST.
+                let (start, end) = (byte_pos(span.lo), byte_pos(span.hi));
+                self.start_node("MetaProperty", span);
+                self.key("meta");
+                self.start_node_at("Identifier", start, start + meta.len());
+                self.key("name");
+                self.str_value(meta);
+                self.end_node();
+                self.key("property");
+                self.start_node_at("Identifier", end - property.len(), end);
+                self.key("name");
+                self.str_value(property);
+                self.end_node();
+                self.end_node();
+                Ok(())
+            }
+            Expr::Await(await_expr) => {
+                self.start_node("AwaitExpression", await_expr.span(self.ast));
+                self.key("argument");
+                self.serialize_expr(await_expr.arg(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            // Parentheses are not represented in ESTree.
+            Expr::Paren(paren) => self.serialize_expr(paren.expr(self.ast)),
+            Expr::JSXMember(member) => self.serialize_jsx_member_expr(member),
+            Expr::JSXNamespacedName(name) => {
+                self.serialize_jsx_namespaced_name(name);
+                Ok(())
+            }
+            Expr::JSXEmpty(empty) => {
+                self.start_node("JSXEmptyExpression", empty.span(self.ast));
+                self.end_node();
+                Ok(())
+            }
+            Expr::JSXElement(element) => self.serialize_jsx_element(element),
+            Expr::JSXFragment(fragment) => self.serialize_jsx_fragment(fragment),
+            Expr::TsTypeAssertion(..) => Err(unsupported("TsTypeAssertion")),
+            Expr::TsConstAssertion(..) => Err(unsupported("TsConstAssertion")),
+            Expr::TsNonNull(..) => Err(unsupported("TsNonNullExpr")),
+            Expr::TsAs(..) => Err(unsupported("TsAsExpr")),
+            Expr::TsInstantiation(..) => Err(unsupported("TsInstantiation")),
+            Expr::TsSatisfies(..) => Err(unsupported("TsSatisfiesExpr")),
+            Expr::PrivateName(name) => {
+                self.serialize_private_name(name);
+                Ok(())
+            }
+            Expr::OptChain(chain) => {
+                self.start_node("ChainExpression", chain.span(self.ast));
+                self.key("expression");
+                self.serialize_opt_chain_expr(chain)?;
+                self.end_node();
+                Ok(())
+            }
+            Expr::Invalid(..) => Err(unsupported("Invalid")),
+        })
+    }
+
+    /// Serializes the object of a member expression or the callee of a call.
+    /// An optional chain continues into these positions without another
+    /// `ChainExpression`.
+    fn serialize_callee(&mut self, expr: Expr, in_chain: bool) -> Result {
+        match expr {
+            Expr::OptChain(chain) if in_chain => self.serialize_opt_chain_expr(chain),
+            _ => self.serialize_expr(expr),
+        }
+    }
+
+    fn serialize_opt_chain_expr(&mut self, chain: OptChainExpr) -> Result {
+        let optional = chain.optional(self.ast);
+        match chain.base(self.ast) {
+            OptChainBase::Member(member) => self.serialize_member_expr(member, optional, true),
+            OptChainBase::Call(call) => {
+                self.start_node("CallExpression", call.span(self.ast));
+                self.key("callee");
+                self.serialize_callee(call.callee(self.ast), true)?;
+                self.key("arguments");
+                self.list(call.args(self.ast), Self::serialize_expr_or_spread)?;
+                self.key("optional");
+                self.bool_value(optional);
+                self.end_node();
+                Ok(())
+            }
+        }
+    }
+
+    fn serialize_member_expr(
+        &mut self,
+        member: MemberExpr,
+        optional: bool,
+        in_chain: bool,
+    ) -> Result {
+        let prop = member.prop(self.ast);
+        self.start_node("MemberExpression", member.span(self.ast));
+        self.key("object");
+        self.serialize_callee(member.obj(self.ast), in_chain)?;
+        self.key("property");
+        match prop {
+            MemberProp::Ident(ident) => self.serialize_ident_name(ident),
+            MemberProp::PrivateName(name) => self.serialize_private_name(name),
+            MemberProp::Computed(computed) => self.serialize_expr(computed.expr(self.ast))?,
+        }
+        self.key("computed");
+        self.bool_value(matches!(prop, MemberProp::Computed(..)));
+        self.key("optional");
+        self.bool_value(optional);
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_super_prop_expr(&mut self, super_prop: SuperPropExpr) -> Result {
+        let prop = super_prop.prop(self.ast);
+        self.start_node("MemberExpression", super_prop.span(self.ast));
+        self.key("object");
+        self.start_node("Super", super_prop.obj(self.ast).span(self.ast));
+        self.end_node();
+        self.key("property");
+        match prop {
+            SuperProp::Ident(ident) => self.serialize_ident_name(ident),
+            SuperProp::Computed(computed) => self.serialize_expr(computed.expr(self.ast))?,
+        }
+        self.key("computed");
+        self.bool_value(matches!(prop, SuperProp::Computed(..)));
+        self.key("optional");
+        self.bool_value(false);
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_call_expr(&mut self, call: CallExpr) -> Result {
+        let span = call.span(self.ast);
+        let args = call.args(self.ast);
+        let callee = match call.callee(self.ast) {
+            Callee::Import(import) => return self.serialize_import_call(span, import, args),
+            callee => callee,
+        };
+
+        self.start_node("CallExpression", span);
+        self.key("callee");
+        match callee {
+            Callee::Super(callee) => {
+                self.start_node("Super", callee.span(self.ast));
+                self.end_node();
+            }
+            Callee::Expr(callee) => self.serialize_expr(callee)?,
+            Callee::Import(..) => unreachable!(),
+        }
+        self.key("arguments");
+        self.list(args, Self::serialize_expr_or_spread)?;
+        self.key("optional");
+        self.bool_value(false);
+        self.end_node();
+        Ok(())
+    }
+
+    /// Serializes a dynamic import, which is not a call in ESTree.
+    fn serialize_import_call(
+        &mut self,
+        span: Span,
+        import: Import,
+        args: TypedSubRange<ExprOrSpread>,
+    ) -> Result {
+        let Some(source) = args.get_node(self.ast, 0) else {
+            return Err(unsupported("An import call without arguments"));
+        };
+        self.start_node("ImportExpression", span);
+        self.key("source");
+        self.serialize_expr(source.expr(self.ast))?;
+        self.key("options");
+        self.opt(args.get_node(self.ast, 1), |s, options| {
+            s.serialize_expr(options.expr(s.ast))
+        })?;
+        match import.phase(self.ast) {
+            ImportPhase::Evaluation => {}
+            ImportPhase::Source => {
+                self.key("phase");
+                self.str_value("source");
+            }
+            ImportPhase::Defer => {
+                self.key("phase");
+                self.str_value("defer");
+            }
+        }
+        self.end_node();
+        Ok(())
+    }
+
+    pub(crate) fn serialize_expr_or_spread(&mut self, arg: ExprOrSpread) -> Result {
+        let expr = arg.expr(self.ast);
+        match arg.spread(self.ast) {
+            Some(spread) => self.serialize_spread(spread.span(self.ast), expr),
+            None => self.serialize_expr(expr),
+        }
+    }
+
+    /// Serializes a spread element, whose span in the AST may only cover the
+    /// `...` token.
+    pub(crate) fn serialize_spread(&mut self, dot_3_token: Span, expr: Expr) -> Result {
+        self.start_node_at(
+            "SpreadElement",
+            byte_pos(dot_3_token.lo),
+            byte_pos(expr.span(self.ast).hi),
+        );
+        self.key("argument");
+        self.serialize_expr(expr)?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_arrow_expr(&mut self, arrow: ArrowExpr) -> Result {
+        let body = arrow.body(self.ast);
+        self.start_node("ArrowFunctionExpression", arrow.span(self.ast));
+        self.key("id");
+        self.null_value();
+        self.key("expression");
+        self.bool_value(matches!(body, BlockStmtOrExpr::Expr(..)));
+        self.key("generator");
+        self.bool_value(arrow.is_generator(self.ast));
+        self.key("async");
+        self.bool_value(arrow.is_async(self.ast));
+        self.key("params");
+        self.list(arrow.params(self.ast), Self::serialize_pat)?;
+        self.key("body");
+        match body {
+            BlockStmtOrExpr::BlockStmt(block) => self.serialize_function_body(block)?,
+            BlockStmtOrExpr::Expr(expr) => self.serialize_expr(expr)?,
+        }
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_assign_target(&mut self, target: AssignTarget) -> Result {
+        match target {
+            AssignTarget::Simple(target) => match target {
+                SimpleAssignTarget::Ident(ident) => {
+                    self.serialize_ident(ident.id(self.ast));
+                    Ok(())
+                }
+                SimpleAssignTarget::Member(member) => {
+                    self.serialize_member_expr(member, false, false)
+                }
+                SimpleAssignTarget::SuperProp(super_prop) => {
+                    self.serialize_super_prop_expr(super_prop)
+                }
+                SimpleAssignTarget::Paren(paren) => self.serialize_expr(paren.expr(self.ast)),
+                SimpleAssignTarget::OptChain(chain) => self.serialize_expr(Expr::OptChain(chain)),
+                SimpleAssignTarget::TsAs(..) => Err(unsupported("TsAsExpr")),
+                SimpleAssignTarget::TsSatisfies(..) => Err(unsupported("TsSatisfiesExpr")),
+                SimpleAssignTarget::TsNonNull(..) => Err(unsupported("TsNonNullExpr")),
+                SimpleAssignTarget::TsTypeAssertion(..) => Err(unsupported("TsTypeAssertion")),
+                SimpleAssignTarget::TsInstantiation(..) => Err(unsupported("TsInstantiation")),
+                SimpleAssignTarget::Invalid(..) => Err(unsupported("Invalid")),
+            },
+            AssignTarget::Pat(AssignTargetPat::Array(array)) => self.serialize_array_pat(array),
+            AssignTarget::Pat(AssignTargetPat::Object(object)) => self.serialize_object_pat(object),
+            AssignTarget::Pat(AssignTargetPat::Invalid(..)) => Err(unsupported("Invalid")),
+        }
+    }
+
+    pub(crate) fn serialize_object_lit(&mut self, object: ObjectLit) -> Result {
+        self.start_node("ObjectExpression", object.span(self.ast));
+        self.key("properties");
+        self.list(object.props(self.ast), |s, prop| match prop {
+            PropOrSpread::SpreadElement(spread) => {
+                s.serialize_spread(spread.dot_3_token(s.ast), spread.expr(s.ast))
+            }
+            PropOrSpread::Prop(prop) => s.serialize_prop(prop),
+        })?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_prop(&mut self, prop: Prop) -> Result {
+        match prop {
+            Prop::Shorthand(ident) => {
+                self.start_node("Property", ident.span(self.ast));
+                self.key("method");
+                self.bool_value(false);
+                self.key("shorthand");
+                self.bool_value(true);
+                self.key("computed");
+                self.bool_value(false);
+                self.key("key");
+                self.serialize_ident(ident);
+                self.key("kind");
+                self.str_value("init");
+                self.key("value");
+                self.serialize_ident(ident);
+            }
+            Prop::KeyValue(prop) => {
+                self.start_node("Property", prop.span(self.ast));
+                self.serialize_property_fields("init", false, false, prop.key(self.ast))?;
+                self.key("value");
+                self.serialize_expr(prop.value(self.ast))?;
+            }
+            Prop::Assign(prop) => {
+                let span = prop.span(self.ast);
+                let ident = prop.key(self.ast);
+                self.start_node("Property", span);
+                self.key("method");
+                self.bool_value(false);
+                self.key("shorthand");
+                self.bool_value(true);
+                self.key("computed");
+                self.bool_value(false);
+                self.key("key");
+                self.serialize_ident(ident);
+                self.key("kind");
+                self.str_value("init");
+                self.key("value");
+                self.serialize_shorthand_value(span, ident, Some(prop.value(self.ast)))?;
+            }
+            Prop::Getter(prop) => {
+                let key = prop.key(self.ast);
+                self.start_node("Property", prop.span(self.ast));
+                self.serialize_property_fields("get", false, false, key)?;
+                self.key("value");
+                self.serialize_accessor_function(key.span(self.ast), None, prop.body(self.ast))?;
+            }
+            Prop::Setter(prop) => {
+                let key = prop.key(self.ast);
+                self.start_node("Property", prop.span(self.ast));
+                self.serialize_property_fields("set", false, false, key)?;
+                self.key("value");
+                self.serialize_accessor_function(
+                    key.span(self.ast),
+                    Some(prop.param(self.ast)),
+                    prop.body(self.ast),
+                )?;
+            }
+            Prop::Method(prop) => {
+                // The span of the method may only cover the key, while the
+                // function starts at the modifiers.
+                let key = prop.key(self.ast);
+                let function = prop.function(self.ast);
+                self.start_node("Property", function.span(self.ast));
+                self.serialize_property_fields("init", true, false, key)?;
+                self.key("value");
+                self.serialize_method_function(key.span(self.ast), function)?;
+            }
+        }
+        self.end_node();
+        Ok(())
+    }
+
+    /// Serializes the fields of a `Property` before its value.
+    pub(crate) fn serialize_property_fields(
+        &mut self,
+        kind: &str,
+        method: bool,
+        shorthand: bool,
+        key: PropName,
+    ) -> Result {
+        self.key("method");
+        self.bool_value(method);
+        self.key("shorthand");
+        self.bool_value(shorthand);
+        self.key("computed");
+        self.bool_value(matches!(key, PropName::Computed(..)));
+        self.key("key");
+        self.serialize_prop_name(key)?;
+        self.key("kind");
+        self.str_value(kind);
+        Ok(())
+    }
+
+    pub(crate) fn serialize_prop_name(&mut self, key: PropName) -> Result {
+        match key {
+            PropName::Ident(ident) => self.serialize_ident_name(ident),
+            PropName::Str(s) => self.serialize_lit(Lit::Str(s)),
+            PropName::Num(num) => self.serialize_lit(Lit::Num(num)),
+            PropName::BigInt(bigint) => self.serialize_lit(Lit::BigInt(bigint)),
+            PropName::Computed(computed) => return self.serialize_expr(computed.expr(self.ast)),
+        }
+        Ok(())
+    }
+
+    fn serialize_tpl(&mut self, tpl: Tpl) -> Result {
+        self.start_node("TemplateLiteral", tpl.span(self.ast));
+        self.key("expressions");
+        self.list(tpl.exprs(self.ast), Self::serialize_expr)?;
+        self.key("quasis");
+        self.list(tpl.quasis(self.ast), |s, quasi| {
+            s.start_node("TemplateElement", quasi.span(s.ast));
+            s.key("value");
+            // Line terminators in the raw text are normalized to `\n`.
+            let raw = s.ast.get_utf8(quasi.raw(s.ast));
+            let raw = raw.replace("\r\n", "\n").replace('\r', "\n");
+            s.buf.push_str("{\"raw\":");
+            s.str_value(&raw);
+            s.buf.push_str(",\"cooked\":");
+            match s.ast.get_optional_wtf8(quasi.cooked(s.ast)) {
+                Some(cooked) => s.wtf8_value(cooked),
+                None => s.null_value(),
+            }
+            s.buf.push('}');
+            s.key("tail");
+            s.bool_value(quasi.tail(s.ast));
+            s.end_node();
+            Ok(())
+        })?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_bin_expr(&mut self, bin: BinExpr) -> Result {
+        // Chains like `a + b + c` nest to the left, and may be too deep to
+        // recurse into, so the left operands are started in a loop.
+        let mut outer = Vec::new();
+        let mut left = Expr::Bin(bin);
+        while let Expr::Bin(bin) = left {
+            let ty = match bin.op(self.ast) {
+                BinaryOp::LogicalOr | BinaryOp::LogicalAnd | BinaryOp::NullishCoalescing => {
+                    "LogicalExpression"
+                }
+                _ => "BinaryExpression",
+            };
+            self.start_node(ty, bin.span(self.ast));
+            self.key("left");
+            outer.push(bin);
+            left = bin.left(self.ast);
+        }
+
+        self.serialize_expr(left)?;
+        for bin in outer.into_iter().rev() {
+            self.key("operator");
+            self.str_value(bin_op_str(bin.op(self.ast)));
+            self.key("right");
+            self.serialize_expr(bin.right(self.ast))?;
+            self.end_node();
+        }
+        Ok(())
+    }
+}
+
+fn unary_op_str(op: UnaryOp) -> &'static str {
+    match op {
+        UnaryOp::Minus => "-",
+        UnaryOp::Plus => "+",
+        UnaryOp::Bang => "!",
+        UnaryOp::Tilde => "~",
+        UnaryOp::TypeOf => "typeof",
+        UnaryOp::Void => "void",
+        UnaryOp::Delete => "delete",
+    }
+}
+
+fn bin_op_str(op: BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::EqEq => "==",
+        BinaryOp::NotEq => "!=",
+        BinaryOp::EqEqEq => "===",
+        BinaryOp::NotEqEq => "!==",
+        BinaryOp::Lt => "<",
+        BinaryOp::LtEq => "<=",
+        BinaryOp::Gt => ">",
+        BinaryOp::GtEq => ">=",
+        BinaryOp::LShift => "<<",
+        BinaryOp::RShift => ">>",
+        BinaryOp::ZeroFillRShift => ">>>",
+        BinaryOp::Add => "+",
+        BinaryOp::Sub => "-",
+        BinaryOp::Mul => "*",
+        BinaryOp::Div => "/",
+        BinaryOp::Mod => "%",
+        BinaryOp::BitOr => "|",
+        BinaryOp::BitXor => "^",
+        BinaryOp::BitAnd => "&",
+        BinaryOp::LogicalOr => "||",
+        BinaryOp::LogicalAnd => "&&",
+        BinaryOp::In => "in",
+        BinaryOp::InstanceOf => "instanceof",
+        BinaryOp::Exp => "**",
+        BinaryOp::NullishCoalescing => "??",
+    }
+}
+
+fn assign_op_str(op: AssignOp) -> &'static str {
+    match op {
+        AssignOp::Assign => "=",
+        AssignOp::AddAssign => "+=",
+        AssignOp::SubAssign => "-=",
+        AssignOp::MulAssign => "*=",
+        AssignOp::DivAssign => "/=",
+        AssignOp::ModAssign => "%=",
+        AssignOp::LShiftAssign => "<<=",
+        AssignOp::RShiftAssign => ">>=",
+        AssignOp::ZeroFillRShiftAssign => ">>>=",
+        AssignOp::BitOrAssign => "|=",
+        AssignOp::BitXorAssign => "^=",
+        AssignOp::BitAndAssign => "&=",
+        AssignOp::ExpAssign => "**=",
+        AssignOp::AndAssign => "&&=",
+        AssignOp::OrAssign => "||=",
+        AssignOp::NullishAssign => "??=",
+    }
+}
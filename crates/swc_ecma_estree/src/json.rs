@@ -0,0 +1,247 @@
+use std::fmt::Write;
+
+use swc_core::{atoms::wtf8::Wtf8, common::BytePos};
+use swc_experimental_ecma_ast::*;
+
+use crate::{Result, Serializer};
+
+/// Converts byte offsets in the source to the UTF-16 offsets and lines used by
+/// ESTree.
+pub(crate) struct Positions {
+    /// The UTF-16 offset of every byte offset, or `None` for ASCII sources
+    /// where they are the same.
+    utf16: Option<Vec<u32>>,
+    /// The UTF-16 offsets where lines start.
+    line_starts: Vec<u32>,
+}
+
+impl Positions {
+    pub(crate) fn new(source: &str) -> Self {
+        let mut utf16 = if source.is_ascii() {
+            None
+        } else {
+            Some(Vec::with_capacity(source.len() + 1))
+        };
+        let mut line_starts = vec![0];
+        let mut offset = 0;
+        let mut chars = source.chars().peekable();
+        while let Some(c) = chars.next() {
+            if let Some(utf16) = &mut utf16 {
+                utf16.extend(std::iter::repeat_n(offset, c.len_utf8()));
+            }
+            offset += c.len_utf16() as u32;
+            match c {
+                // `\r\n` is a single line terminator.
+                '\r' if chars.peek() == Some(&'\n') => {}
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(offset),
+                _ => {}
+            }
+        }
+        if let Some(utf16) = &mut utf16 {
+            utf16.push(offset);
+        }
+        Self { utf16, line_starts }
+    }
+
+    fn offset(&self, pos: usize) -> u32 {
+        match &self.utf16 {
+            Some(utf16) => utf16[pos.min(utf16.len() - 1)],
+            None => pos as u32,
+        }
+    }
+
+    /// Returns the one-based line and the column of a UTF-16 offset.
+    fn line_col(&self, offset: u32) -> (usize, u32) {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        (line, offset - self.line_starts[line - 1])
+    }
+}
+
+/// Returns the byte offset of a position from the parser, which starts at 1.
+pub(crate) fn byte_pos(pos: BytePos) -> usize {
+    pos.0.saturating_sub(1) as usize
+}
+
+impl<'a> Serializer<'a> {
+    /// Starts a node object covering `span`.
+    pub(crate) fn start_node(&mut self, ty: &str, span: Span) {
+        self.start_node_at(ty, byte_pos(span.lo), byte_pos(span.hi));
+    }
+
+    /// Starts a node object covering the bytes `start..end` of the source.
+    pub(crate) fn start_node_at(&mut self, ty: &str, start: usize, end: usize) {
+        let start = self.positions.offset(start.min(self.source.len()));
+        let end = self.positions.offset(end.min(self.source.len()));
+
+        self.buf.push_str("{\"type\":");
+        self.str_value(ty);
+        write!(self.buf, ",\"start\":{start},\"end\":{end}").unwrap();
+        if self.cfg.loc {
+            let (start_line, start_col) = self.positions.line_col(start);
+            let (end_line, end_col) = self.positions.line_col(end);
+            write!(
+                self.buf,
+                ",\"loc\":{{\"start\":{{\"line\":{start_line},\"column\":{start_col}}},\"end\":{{\"line\":{end_line},\"column\":{end_col}}}}}"
+            )
+            .unwrap();
+        }
+        if self.cfg.range {
+            write!(self.buf, ",\"range\":[{start},{end}]").unwrap();
+        }
+    }
+
+    pub(crate) fn end_node(&mut self) {
+        self.buf.push('}');
+    }
+
+    /// Writes the key of the next property of the current node.
+    pub(crate) fn key(&mut self, key: &str) {
+        self.buf.push_str(",\"");
+        self.buf.push_str(key);
+        self.buf.push_str("\":");
+    }
+
+    pub(crate) fn null_value(&mut self) {
+        self.buf.push_str("null");
+    }
+
+    pub(crate) fn bool_value(&mut self, value: bool) {
+        self.buf.push_str(if value { "true" } else { "false" });
+    }
+
+    /// Writes a number like `JSON.stringify`, which writes non-finite numbers
+    /// as `null`.
+    pub(crate) fn num_value(&mut self, value: f64) {
+        if !value.is_finite() {
+            return self.null_value();
+        }
+        if value == 0.0 {
+            // Including `-0`.
+            return self.buf.push('0');
+        }
+        let decimal = format!("{value}");
+        let exponential = format!("{value:e}");
+        self.buf.push_str(if exponential.len() < decimal.len() {
+            &exponential
+        } else {
+            &decimal
+        });
+    }
+
+    pub(crate) fn str_value(&mut self, value: &str) {
+        self.buf.push('"');
+        for c in value.chars() {
+            self.push_escaped(c as u32);
+        }
+        self.buf.push('"');
+    }
+
+    /// Writes a string which may contain lone surrogates, which are escaped.
+    pub(crate) fn wtf8_value(&mut self, value: &Wtf8) {
+        self.buf.push('"');
+        for c in value.code_points() {
+            self.push_escaped(c.to_u32());
+        }
+        self.buf.push('"');
+    }
+
+    fn push_escaped(&mut self, c: u32) {
+        match c {
+            0x08 => self.buf.push_str("\\b"),
+            0x09 => self.buf.push_str("\\t"),
+            0x0a => self.buf.push_str("\\n"),
+            0x0c => self.buf.push_str("\\f"),
+            0x0d => self.buf.push_str("\\r"),
+            0x22 => self.buf.push_str("\\\""),
+            0x5c => self.buf.push_str("\\\\"),
+            0x00..=0x1f | 0xd800..=0xdfff => write!(self.buf, "\\u{c:04x}").unwrap(),
+            _ => self.buf.push(char::from_u32(c).unwrap()),
+        }
+    }
+
+    /// Writes an array of `items`.
+    pub(crate) fn array<T>(
+        &mut self,
+        items: impl IntoIterator<Item = T>,
+        mut serialize: impl FnMut(&mut Self, T) -> Result,
+    ) -> Result {
+        self.buf.push('[');
+        for (i, item) in items.into_iter().enumerate() {
+            if i > 0 {
+                self.buf.push(',');
+            }
+            serialize(self, item)?;
+        }
+        self.buf.push(']');
+        Ok(())
+    }
+
+    /// Writes an array of the nodes in `list`.
+    pub(crate) fn list<T: ExtraDataCompact>(
+        &mut self,
+        list: TypedSubRange<T>,
+        serialize: impl FnMut(&mut Self, T) -> Result,
+    ) -> Result {
+        let ast = self.ast;
+        self.array(
+            list.iter().map(|item| ast.get_node_in_sub_range(item)),
+            serialize,
+        )
+    }
+
+    /// Writes `value`, or `null` if there is none.
+    pub(crate) fn opt<T>(
+        &mut self,
+        value: Option<T>,
+        serialize: impl FnOnce(&mut Self, T) -> Result,
+    ) -> Result {
+        match value {
+            Some(value) => serialize(self, value),
+            None => {
+                self.null_value();
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the source text of `span`.
+    pub(crate) fn source_text(&self, span: Span) -> &'a str {
+        let start = byte_pos(span.lo).min(self.source.len());
+        let end = byte_pos(span.hi).clamp(start, self.source.len());
+        &self.source[start..end]
+    }
+
+    /// Returns the byte offset of the first `token` at or after `from`, which
+    /// isn't in a comment. Spans of a few nodes don't include their
+    /// punctuation, which is needed to match the positions of ESTree.
+    pub(crate) fn find_token(&self, from: usize, token: u8) -> usize {
+        let bytes = self.source.as_bytes();
+        let mut pos = from;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b if b == token => return pos,
+                b'/' if bytes.get(pos + 1) == Some(&b'/') => {
+                    pos += bytes[pos..]
+                        .iter()
+                        .position(|&b| b == b'\n' || b == b'\r')
+                        .unwrap_or(bytes.len() - pos);
+                }
+                b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                    pos += self.source[pos + 2..]
+                        .find("*/")
+                        .map_or(bytes.len() - pos, |end| end + 4);
+                }
+                _ => pos += 1,
+            }
+        }
+        bytes.len()
+    }
+
+    /// Returns the byte offset of the last `token` before `before`.
+    pub(crate) fn rfind_token(&self, before: usize, token: u8) -> usize {
+        self.source.as_bytes()[..before.min(self.source.len())]
+            .iter()
+            .rposition(|&b| b == token)
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,186 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{Result, Serializer, json::byte_pos};
+
+impl Serializer<'_> {
+    pub(crate) fn serialize_jsx_element(&mut self, element: JSXElement) -> Result {
+        let opening = element.opening(self.ast);
+        self.start_node("JSXElement", element.span(self.ast));
+
+        self.key("openingElement");
+        self.start_node("JSXOpeningElement", opening.span(self.ast));
+        self.key("attributes");
+        self.list(opening.attrs(self.ast), |s, attr| match attr {
+            JSXAttrOrSpread::JSXAttr(attr) => s.serialize_jsx_attr(attr),
+            JSXAttrOrSpread::SpreadElement(spread) => {
+                // The span of the spread doesn't include the braces.
+                let expr = spread.expr(s.ast);
+                let start = s.rfind_token(byte_pos(spread.dot_3_token(s.ast).lo), b'{');
+                let end = s.find_token(byte_pos(expr.span(s.ast).hi), b'}') + 1;
+                s.start_node_at("JSXSpreadAttribute", start, end);
+                s.key("argument");
+                s.serialize_expr(expr)?;
+                s.end_node();
+                Ok(())
+            }
+        })?;
+        self.key("name");
+        self.serialize_jsx_element_name(opening.name(self.ast))?;
+        self.key("selfClosing");
+        self.bool_value(opening.self_closing(self.ast));
+        self.end_node();
+
+        self.key("closingElement");
+        self.opt(element.closing(self.ast), |s, closing| {
+            s.start_node("JSXClosingElement", closing.span(s.ast));
+            s.key("name");
+            s.serialize_jsx_element_name(closing.name(s.ast))?;
+            s.end_node();
+            Ok(())
+        })?;
+        self.key("children");
+        self.serialize_jsx_children(element.children(self.ast))?;
+        self.end_node();
+        Ok(())
+    }
+
+    pub(crate) fn serialize_jsx_fragment(&mut self, fragment: JSXFragment) -> Result {
+        self.start_node("JSXFragment", fragment.span(self.ast));
+        self.key("openingFragment");
+        self.start_node(
+            "JSXOpeningFragment",
+            fragment.opening(self.ast).span(self.ast),
+        );
+        self.key("attributes");
+        self.buf.push_str("[]");
+        self.key("selfClosing");
+        self.bool_value(false);
+        self.end_node();
+        self.key("closingFragment");
+        self.start_node(
+            "JSXClosingFragment",
+            fragment.closing(self.ast).span(self.ast),
+        );
+        self.end_node();
+        self.key("children");
+        self.serialize_jsx_children(fragment.children(self.ast))?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_jsx_children(&mut self, children: TypedSubRange<JSXElementChild>) -> Result {
+        self.list(children, |s, child| match child {
+            JSXElementChild::JSXText(text) => {
+                s.start_node("JSXText", text.span(s.ast));
+                s.key("value");
+                s.str_value(s.ast.get_utf8(text.value(s.ast)));
+                s.key("raw");
+                s.str_value(s.ast.get_utf8(text.raw(s.ast)));
+                s.end_node();
+                Ok(())
+            }
+            JSXElementChild::JSXExprContainer(container) => {
+                s.serialize_jsx_expr_container(container)
+            }
+            JSXElementChild::JSXSpreadChild(spread) => {
+                s.start_node("JSXSpreadChild", spread.span(s.ast));
+                s.key("expression");
+                s.serialize_expr(spread.expr(s.ast))?;
+                s.end_node();
+                Ok(())
+            }
+            JSXElementChild::JSXElement(element) => s.serialize_jsx_element(element),
+            JSXElementChild::JSXFragment(fragment) => s.serialize_jsx_fragment(fragment),
+        })
+    }
+
+    fn serialize_jsx_expr_container(&mut self, container: JSXExprContainer) -> Result {
+        let span = container.span(self.ast);
+        self.start_node("JSXExpressionContainer", span);
+        self.key("expression");
+        match container.expr(self.ast) {
+            // The empty expression is everything between the braces.
+            JSXExpr::JSXEmptyExpr(..) => {
+                self.start_node_at(
+                    "JSXEmptyExpression",
+                    byte_pos(span.lo) + 1,
+                    byte_pos(span.hi).saturating_sub(1),
+                );
+                self.end_node();
+            }
+            JSXExpr::Expr(expr) => self.serialize_expr(expr)?,
+        }
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_jsx_attr(&mut self, attr: JSXAttr) -> Result {
+        self.start_node("JSXAttribute", attr.span(self.ast));
+        self.key("name");
+        match attr.name(self.ast) {
+            JSXAttrName::Ident(ident) => {
+                self.serialize_jsx_ident(ident.span(self.ast), ident.sym(self.ast))
+            }
+            JSXAttrName::JSXNamespacedName(name) => self.serialize_jsx_namespaced_name(name),
+        }
+        self.key("value");
+        self.opt(attr.value(self.ast), |s, value| match value {
+            JSXAttrValue::Str(str) => {
+                s.serialize_lit(Lit::Str(str));
+                Ok(())
+            }
+            JSXAttrValue::JSXExprContainer(container) => s.serialize_jsx_expr_container(container),
+            JSXAttrValue::JSXElement(element) => s.serialize_jsx_element(element),
+            JSXAttrValue::JSXFragment(fragment) => s.serialize_jsx_fragment(fragment),
+        })?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_jsx_element_name(&mut self, name: JSXElementName) -> Result {
+        match name {
+            JSXElementName::Ident(ident) => {
+                self.serialize_jsx_ident(ident.span(self.ast), ident.sym(self.ast));
+                Ok(())
+            }
+            JSXElementName::JSXMemberExpr(member) => self.serialize_jsx_member_expr(member),
+            JSXElementName::JSXNamespacedName(name) => {
+                self.serialize_jsx_namespaced_name(name);
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) fn serialize_jsx_member_expr(&mut self, member: JSXMemberExpr) -> Result {
+        self.start_node("JSXMemberExpression", member.span(self.ast));
+        self.key("object");
+        match member.obj(self.ast) {
+            JSXObject::JSXMemberExpr(obj) => self.serialize_jsx_member_expr(obj)?,
+            JSXObject::Ident(ident) => {
+                self.serialize_jsx_ident(ident.span(self.ast), ident.sym(self.ast))
+            }
+        }
+        self.key("property");
+        let prop = member.prop(self.ast);
+        self.serialize_jsx_ident(prop.span(self.ast), prop.sym(self.ast));
+        self.end_node();
+        Ok(())
+    }
+
+    pub(crate) fn serialize_jsx_namespaced_name(&mut self, name: JSXNamespacedName) {
+        let (ns, local) = (name.ns(self.ast), name.name(self.ast));
+        self.start_node("JSXNamespacedName", name.span(self.ast));
+        self.key("namespace");
+        self.serialize_jsx_ident(ns.span(self.ast), ns.sym(self.ast));
+        self.key("name");
+        self.serialize_jsx_ident(local.span(self.ast), local.sym(self.ast));
+        self.end_node();
+    }
+
+    fn serialize_jsx_ident(&mut self, span: Span, sym: Utf8Ref) {
+        self.start_node("JSXIdentifier", span);
+        self.key("name");
+        self.str_value(self.ast.get_utf8(sym));
+        self.end_node();
+    }
+}
@@ -0,0 +1,147 @@
+//! [ESTree](https://github.com/estree/estree) serialization for the flattening
+//! AST.
+//!
+//! The [`Serializer`] walks the nodes of an [`Ast`] and writes JSON directly,
+//! in the shape produced by [acorn](https://github.com/acornjs/acorn) and
+//! acorn-jsx. Like acorn, `start` and `end` are offsets in UTF-16 code units,
+//! and parenthesized expressions are serialized as their inner expression.
+//!
+//! TypeScript syntax is not part of ESTree. Type annotations are left out,
+//! while other TypeScript nodes are reported as errors.
+
+use std::fmt;
+
+use swc_experimental_ecma_ast::*;
+
+use crate::json::Positions;
+
+mod class;
+mod decl;
+mod expr;
+mod json;
+mod jsx;
+mod lit;
+mod module_decl;
+mod pat;
+mod stmt;
+
+pub type Result = std::result::Result<(), Error>;
+
+#[derive(Debug, Default, Clone, Copy)]
+#[non_exhaustive]
+pub struct Config {
+    /// Adds `loc` with one-based lines and zero-based columns to every node.
+    pub loc: bool,
+
+    /// Adds `range: [start, end]` to every node.
+    pub range: bool,
+}
+
+impl Config {
+    pub fn with_loc(mut self, loc: bool) -> Self {
+        self.loc = loc;
+        self
+    }
+
+    pub fn with_range(mut self, range: bool) -> Self {
+        self.range = range;
+        self
+    }
+}
+
+/// A node which has no ESTree representation.
+#[derive(Debug, Clone)]
+pub struct Error {
+    node: &'static str,
+}
+
+impl Error {
+    pub fn node(&self) -> &'static str {
+        self.node
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} can't be serialized as ESTree", self.node)
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn unsupported(node: &'static str) -> Error {
+    Error { node }
+}
+
+pub struct Serializer<'a> {
+    pub cfg: Config,
+    pub ast: &'a Ast,
+    /// The code the [`Ast`] was parsed from.
+    source: &'a str,
+    positions: Positions,
+    buf: String,
+}
+
+impl<'a> Serializer<'a> {
+    /// Creates a serializer for an [`Ast`] parsed from `source`, which is
+    /// needed to compute the positions of the nodes.
+    pub fn new(cfg: Config, ast: &'a Ast, source: &'a str) -> Self {
+        Self {
+            cfg,
+            ast,
+            source,
+            positions: Positions::new(source),
+            buf: String::with_capacity(source.len() * 8),
+        }
+    }
+
+    /// Returns the JSON written so far.
+    pub fn into_json(self) -> String {
+        self.buf
+    }
+
+    pub fn serialize_program(&mut self, program: Program) -> Result {
+        // The program covers the whole source, including leading and trailing
+        // comments.
+        self.start_node_at("Program", 0, self.source.len());
+        match program {
+            Program::Module(module) => {
+                self.key("body");
+                let body = module.body(self.ast);
+                let mut in_prologue = true;
+                self.list(body, |s, item| match item {
+                    ModuleItem::ModuleDecl(decl) => {
+                        in_prologue = false;
+                        s.serialize_module_decl(decl)
+                    }
+                    ModuleItem::Stmt(stmt) => {
+                        in_prologue &= s.is_directive(stmt);
+                        s.serialize_stmt_in_prologue(stmt, in_prologue)
+                    }
+                })?;
+                self.key("sourceType");
+                self.str_value("module");
+            }
+            Program::Script(script) => {
+                self.key("body");
+                self.serialize_stmts_with_directives(script.body(self.ast))?;
+                self.key("sourceType");
+                self.str_value("script");
+            }
+        }
+        self.end_node();
+        Ok(())
+    }
+}
+
+/// Serializes `program` parsed from `source`.
+pub fn to_estree_json(
+    ast: &Ast,
+    program: Program,
+    source: &str,
+    cfg: Config,
+) -> std::result::Result<String, Error> {
+    let mut serializer = Serializer::new(cfg, ast, source);
+    serializer.serialize_program(program)?;
+    Ok(serializer.into_json())
+}
@@ -0,0 +1,41 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::Serializer;
+
+impl Serializer<'_> {
+    /// Serializes a literal. Like acorn, `raw` is the text in the source.
+    pub(crate) fn serialize_lit(&mut self, lit: Lit) {
+        let span = lit.span(self.ast);
+        self.start_node("Literal", span);
+        self.key("value");
+        match lit {
+            Lit::Str(s) => self.wtf8_value(self.ast.get_wtf8(s.value(self.ast))),
+            Lit::Bool(b) => self.bool_value(b.value(self.ast)),
+            Lit::Null(..) => self.null_value(),
+            Lit::Num(num) => self.num_value(num.value(self.ast)),
+            // JSON has no values for these, the literal has its own fields
+            // instead.
+            Lit::BigInt(..) | Lit::Regex(..) => self.null_value(),
+        }
+        self.key("raw");
+        let raw = self.source_text(span);
+        self.str_value(raw);
+        match lit {
+            Lit::BigInt(..) => {
+                let digits = raw.trim_end_matches('n').replace('_', "");
+                self.key("bigint");
+                self.str_value(&digits);
+            }
+            Lit::Regex(regex) => {
+                self.key("regex");
+                self.buf.push_str("{\"pattern\":");
+                self.str_value(self.ast.get_utf8(regex.exp(self.ast)));
+                self.buf.push_str(",\"flags\":");
+                self.str_value(self.ast.get_utf8(regex.flags(self.ast)));
+                self.buf.push('}');
+            }
+            _ => {}
+        }
+        self.end_node();
+    }
+}
@@ -0,0 +1,199 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{Result, Serializer, unsupported};
+
+impl Serializer<'_> {
+    pub fn serialize_module_decl(&mut self, decl: ModuleDecl) -> Result {
+        match decl {
+            ModuleDecl::Import(import) => self.serialize_import_decl(import),
+            ModuleDecl::ExportDecl(export) => {
+                self.start_node("ExportNamedDeclaration", export.span(self.ast));
+                self.key("declaration");
+                self.serialize_decl(export.decl(self.ast))?;
+                self.key("specifiers");
+                self.buf.push_str("[]");
+                self.key("source");
+                self.null_value();
+                self.key("attributes");
+                self.buf.push_str("[]");
+                self.end_node();
+                Ok(())
+            }
+            ModuleDecl::ExportNamed(export) => self.serialize_named_export(export),
+            ModuleDecl::ExportDefaultDecl(export) => {
+                self.start_node("ExportDefaultDeclaration", export.span(self.ast));
+                self.key("declaration");
+                match export.decl(self.ast) {
+                    DefaultDecl::Class(class) => self.serialize_class(
+                        "ClassDeclaration",
+                        class.span(self.ast),
+                        class.ident(self.ast),
+                        class.class(self.ast),
+                    )?,
+                    DefaultDecl::Fn(f) => self.serialize_function(
+                        "FunctionDeclaration",
+                        f.span(self.ast),
+                        f.ident(self.ast),
+                        f.function(self.ast),
+                    )?,
+                    DefaultDecl::TsInterfaceDecl(..) => {
+                        return Err(unsupported("TsInterfaceDecl"));
+                    }
+                }
+                self.end_node();
+                Ok(())
+            }
+            ModuleDecl::ExportDefaultExpr(export) => {
+                self.start_node("ExportDefaultDeclaration", export.span(self.ast));
+                self.key("declaration");
+                self.serialize_expr(export.expr(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            ModuleDecl::ExportAll(export) => {
+                self.start_node("ExportAllDeclaration", export.span(self.ast));
+                self.key("exported");
+                self.null_value();
+                self.key("source");
+                self.serialize_lit(Lit::Str(export.src(self.ast)));
+                self.serialize_attributes(export.with(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            ModuleDecl::TsImportEquals(..) => Err(unsupported("TsImportEqualsDecl")),
+            ModuleDecl::TsExportAssignment(..) => Err(unsupported("TsExportAssignment")),
+            ModuleDecl::TsNamespaceExport(..) => Err(unsupported("TsNamespaceExportDecl")),
+        }
+    }
+
+    fn serialize_import_decl(&mut self, import: ImportDecl) -> Result {
+        if import.type_only(self.ast) {
+            return Err(unsupported("A type-only import"));
+        }
+        self.start_node("ImportDeclaration", import.span(self.ast));
+        self.key("specifiers");
+        self.list(import.specifiers(self.ast), |s, specifier| {
+            match specifier {
+                ImportSpecifier::Named(specifier) => {
+                    let local = specifier.local(s.ast);
+                    s.start_node("ImportSpecifier", specifier.span(s.ast));
+                    s.key("imported");
+                    match specifier.imported(s.ast) {
+                        Some(imported) => s.serialize_module_export_name(imported),
+                        None => s.serialize_ident(local),
+                    }
+                    s.key("local");
+                    s.serialize_ident(local);
+                }
+                ImportSpecifier::Default(specifier) => {
+                    s.start_node("ImportDefaultSpecifier", specifier.span(s.ast));
+                    s.key("local");
+                    s.serialize_ident(specifier.local(s.ast));
+                }
+                ImportSpecifier::Namespace(specifier) => {
+                    s.start_node("ImportNamespaceSpecifier", specifier.span(s.ast));
+                    s.key("local");
+                    s.serialize_ident(specifier.local(s.ast));
+                }
+            }
+            s.end_node();
+            Ok(())
+        })?;
+        self.key("source");
+        self.serialize_lit(Lit::Str(import.src(self.ast)));
+        match import.phase(self.ast) {
+            ImportPhase::Evaluation => {}
+            ImportPhase::Source => {
+                self.key("phase");
+                self.str_value("source");
+            }
+            ImportPhase::Defer => {
+                self.key("phase");
+                self.str_value("defer");
+            }
+        }
+        self.serialize_attributes(import.with(self.ast))?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_named_export(&mut self, export: NamedExport) -> Result {
+        if export.type_only(self.ast) {
+            return Err(unsupported("A type-only export"));
+        }
+        let span = export.span(self.ast);
+        let specifiers = export.specifiers(self.ast);
+        let src = export.src(self.ast);
+
+        // `export * as ns from "mod"` is an `ExportAllDeclaration`.
+        if let Some(ExportSpecifier::Namespace(specifier)) = specifiers.get_node(self.ast, 0)
+            && let Some(src) = src
+        {
+            self.start_node("ExportAllDeclaration", span);
+            self.key("exported");
+            self.serialize_module_export_name(specifier.name(self.ast));
+            self.key("source");
+            self.serialize_lit(Lit::Str(src));
+            self.serialize_attributes(export.with(self.ast))?;
+            self.end_node();
+            return Ok(());
+        }
+
+        self.start_node("ExportNamedDeclaration", span);
+        self.key("declaration");
+        self.null_value();
+        self.key("specifiers");
+        self.list(specifiers, |s, specifier| match specifier {
+            ExportSpecifier::Named(specifier) => {
+                let orig = specifier.orig(s.ast);
+                s.start_node("ExportSpecifier", specifier.span(s.ast));
+                s.key("local");
+                s.serialize_module_export_name(orig);
+                s.key("exported");
+                s.serialize_module_export_name(specifier.exported(s.ast).unwrap_or(orig));
+                s.end_node();
+                Ok(())
+            }
+            ExportSpecifier::Namespace(..) => Err(unsupported("ExportNamespaceSpecifier")),
+            ExportSpecifier::Default(..) => Err(unsupported("ExportDefaultSpecifier")),
+        })?;
+        self.key("source");
+        self.opt(src, |s, src| {
+            s.serialize_lit(Lit::Str(src));
+            Ok(())
+        })?;
+        self.serialize_attributes(export.with(self.ast))?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_module_export_name(&mut self, name: ModuleExportName) {
+        match name {
+            ModuleExportName::Ident(ident) => self.serialize_ident(ident),
+            ModuleExportName::Str(s) => self.serialize_lit(Lit::Str(s)),
+        }
+    }
+
+    /// Serializes the import attributes of `with { ... }`.
+    fn serialize_attributes(&mut self, with: Option<ObjectLit>) -> Result {
+        self.key("attributes");
+        let Some(with) = with else {
+            self.buf.push_str("[]");
+            return Ok(());
+        };
+        self.list(with.props(self.ast), |s, prop| {
+            let PropOrSpread::Prop(Prop::KeyValue(prop)) = prop else {
+                return Err(unsupported(
+                    "An import attribute which isn't a key-value pair",
+                ));
+            };
+            s.start_node("ImportAttribute", prop.span(s.ast));
+            s.key("key");
+            s.serialize_prop_name(prop.key(s.ast))?;
+            s.key("value");
+            s.serialize_expr(prop.value(s.ast))?;
+            s.end_node();
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,133 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{Result, Serializer, json::byte_pos, unsupported};
+
+impl Serializer<'_> {
+    pub fn serialize_pat(&mut self, pat: Pat) -> Result {
+        match pat {
+            Pat::Ident(ident) => {
+                self.serialize_ident(ident.id(self.ast));
+                Ok(())
+            }
+            Pat::Array(array) => self.serialize_array_pat(array),
+            Pat::Rest(rest) => self.serialize_rest_pat(rest),
+            Pat::Object(object) => self.serialize_object_pat(object),
+            Pat::Assign(assign) => {
+                self.start_node("AssignmentPattern", assign.span(self.ast));
+                self.key("left");
+                self.serialize_pat(assign.left(self.ast))?;
+                self.key("right");
+                self.serialize_expr(assign.right(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Pat::Invalid(..) => Err(unsupported("Invalid")),
+            Pat::Expr(expr) => self.serialize_expr(expr),
+        }
+    }
+
+    pub(crate) fn serialize_ident(&mut self, ident: Ident) {
+        self.start_node("Identifier", ident.span(self.ast));
+        self.key("name");
+        self.str_value(self.ast.get_utf8(ident.sym(self.ast)));
+        self.end_node();
+    }
+
+    pub(crate) fn serialize_ident_name(&mut self, ident: IdentName) {
+        self.start_node("Identifier", ident.span(self.ast));
+        self.key("name");
+        self.str_value(self.ast.get_utf8(ident.sym(self.ast)));
+        self.end_node();
+    }
+
+    pub(crate) fn serialize_private_name(&mut self, name: PrivateName) {
+        self.start_node("PrivateIdentifier", name.span(self.ast));
+        self.key("name");
+        self.str_value(self.ast.get_utf8(name.name(self.ast)));
+        self.end_node();
+    }
+
+    pub(crate) fn serialize_array_pat(&mut self, array: ArrayPat) -> Result {
+        self.start_node("ArrayPattern", array.span(self.ast));
+        self.key("elements");
+        self.list(array.elems(self.ast), |s, elem| {
+            s.opt(elem, Self::serialize_pat)
+        })?;
+        self.end_node();
+        Ok(())
+    }
+
+    pub(crate) fn serialize_object_pat(&mut self, object: ObjectPat) -> Result {
+        self.start_node("ObjectPattern", object.span(self.ast));
+        self.key("properties");
+        self.list(object.props(self.ast), |s, prop| match prop {
+            ObjectPatProp::KeyValue(prop) => {
+                let key = prop.key(s.ast);
+                s.start_node("Property", prop.span(s.ast));
+                s.serialize_property_fields("init", false, false, key)?;
+                s.key("value");
+                s.serialize_pat(prop.value(s.ast))?;
+                s.end_node();
+                Ok(())
+            }
+            ObjectPatProp::Assign(prop) => {
+                let span = prop.span(s.ast);
+                let ident = prop.key(s.ast).id(s.ast);
+                s.start_node("Property", span);
+                s.key("method");
+                s.bool_value(false);
+                s.key("shorthand");
+                s.bool_value(true);
+                s.key("computed");
+                s.bool_value(false);
+                s.key("key");
+                s.serialize_ident(ident);
+                s.key("kind");
+                s.str_value("init");
+                s.key("value");
+                s.serialize_shorthand_value(span, ident, prop.value(s.ast))?;
+                s.end_node();
+                Ok(())
+            }
+            ObjectPatProp::Rest(rest) => s.serialize_rest_pat(rest),
+        })?;
+        self.end_node();
+        Ok(())
+    }
+
+    /// Serializes the value of a shorthand property, which is an assignment
+    /// pattern if it has a default value.
+    pub(crate) fn serialize_shorthand_value(
+        &mut self,
+        span: Span,
+        ident: Ident,
+        value: Option<Expr>,
+    ) -> Result {
+        match value {
+            Some(value) => {
+                self.start_node("AssignmentPattern", span);
+                self.key("left");
+                self.serialize_ident(ident);
+                self.key("right");
+                self.serialize_expr(value)?;
+                self.end_node();
+            }
+            None => self.serialize_ident(ident),
+        }
+        Ok(())
+    }
+
+    fn serialize_rest_pat(&mut self, rest: RestPat) -> Result {
+        // The span of a rest pattern doesn't always include the argument.
+        let arg = rest.arg(self.ast);
+        self.start_node_at(
+            "RestElement",
+            byte_pos(rest.dot_3_token(self.ast).lo),
+            byte_pos(arg.span(self.ast).hi),
+        );
+        self.key("argument");
+        self.serialize_pat(arg)?;
+        self.end_node();
+        Ok(())
+    }
+}
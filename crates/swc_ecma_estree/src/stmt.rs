@@ -0,0 +1,256 @@
+use swc_experimental_ecma_ast::*;
+
+use crate::{Result, Serializer};
+
+impl Serializer<'_> {
+    pub fn serialize_stmt(&mut self, stmt: Stmt) -> Result {
+        maybe_grow(|| match stmt {
+            Stmt::Block(block) => self.serialize_block_stmt(block),
+            Stmt::Empty(empty) => {
+                self.start_node("EmptyStatement", empty.span(self.ast));
+                self.end_node();
+                Ok(())
+            }
+            Stmt::Debugger(debugger) => {
+                self.start_node("DebuggerStatement", debugger.span(self.ast));
+                self.end_node();
+                Ok(())
+            }
+            Stmt::With(with) => {
+                self.start_node("WithStatement", with.span(self.ast));
+                self.key("object");
+                self.serialize_expr(with.obj(self.ast))?;
+                self.key("body");
+                self.serialize_stmt(with.body(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Stmt::Return(ret) => {
+                self.start_node("ReturnStatement", ret.span(self.ast));
+                self.key("argument");
+                self.opt(ret.arg(self.ast), Self::serialize_expr)?;
+                self.end_node();
+                Ok(())
+            }
+            Stmt::Labeled(labeled) => {
+                self.start_node("LabeledStatement", labeled.span(self.ast));
+                self.key("body");
+                self.serialize_stmt(labeled.body(self.ast))?;
+                self.key("label");
+                self.serialize_ident(labeled.label(self.ast));
+                self.end_node();
+                Ok(())
+            }
+            Stmt::Break(stmt) => self.serialize_jump_stmt(
+                "BreakStatement",
+                stmt.span(self.ast),
+                stmt.label(self.ast),
+            ),
+            Stmt::Continue(stmt) => self.serialize_jump_stmt(
+                "ContinueStatement",
+                stmt.span(self.ast),
+                stmt.label(self.ast),
+            ),
+            Stmt::If(stmt) => {
+                self.start_node("IfStatement", stmt.span(self.ast));
+                self.key("test");
+                self.serialize_expr(stmt.test(self.ast))?;
+                self.key("consequent");
+                self.serialize_stmt(stmt.cons(self.ast))?;
+                self.key("alternate");
+                self.opt(stmt.alt(self.ast), Self::serialize_stmt)?;
+                self.end_node();
+                Ok(())
+            }
+            Stmt::Switch(stmt) => self.serialize_switch_stmt(stmt),
+            Stmt::Throw(stmt) => {
+                self.start_node("ThrowStatement", stmt.span(self.ast));
+                self.key("argument");
+                self.serialize_expr(stmt.arg(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Stmt::Try(stmt) => self.serialize_try_stmt(stmt),
+            Stmt::While(stmt) => {
+                self.start_node("WhileStatement", stmt.span(self.ast));
+                self.key("test");
+                self.serialize_expr(stmt.test(self.ast))?;
+                self.key("body");
+                self.serialize_stmt(stmt.body(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Stmt::DoWhile(stmt) => {
+                self.start_node("DoWhileStatement", stmt.span(self.ast));
+                self.key("body");
+                self.serialize_stmt(stmt.body(self.ast))?;
+                self.key("test");
+                self.serialize_expr(stmt.test(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Stmt::For(stmt) => self.serialize_for_stmt(stmt),
+            Stmt::ForIn(stmt) => {
+                self.start_node("ForInStatement", stmt.span(self.ast));
+                self.key("left");
+                self.serialize_for_head(stmt.left(self.ast))?;
+                self.key("right");
+                self.serialize_expr(stmt.right(self.ast))?;
+                self.key("body");
+                self.serialize_stmt(stmt.body(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Stmt::ForOf(stmt) => {
+                self.start_node("ForOfStatement", stmt.span(self.ast));
+                self.key("await");
+                self.bool_value(stmt.is_await(self.ast));
+                self.key("left");
+                self.serialize_for_head(stmt.left(self.ast))?;
+                self.key("right");
+                self.serialize_expr(stmt.right(self.ast))?;
+                self.key("body");
+                self.serialize_stmt(stmt.body(self.ast))?;
+                self.end_node();
+                Ok(())
+            }
+            Stmt::Decl(decl) => self.serialize_decl(decl),
+            Stmt::Expr(stmt) => self.serialize_expr_stmt(stmt, false),
+        })
+    }
+
+    /// Returns `true` if `stmt` is a directive when it's in a directive
+    /// prologue. Parenthesized strings aren't directives.
+    pub(crate) fn is_directive(&self, stmt: Stmt) -> bool {
+        match stmt {
+            Stmt::Expr(stmt) => matches!(stmt.expr(self.ast), Expr::Lit(Lit::Str(..))),
+            _ => false,
+        }
+    }
+
+    /// Serializes a statement, which is a directive if it's in the directive
+    /// prologue.
+    pub(crate) fn serialize_stmt_in_prologue(&mut self, stmt: Stmt, in_prologue: bool) -> Result {
+        match stmt {
+            Stmt::Expr(expr_stmt) if in_prologue => self.serialize_expr_stmt(expr_stmt, true),
+            _ => self.serialize_stmt(stmt),
+        }
+    }
+
+    /// Serializes the statements of a script or a function body, which start
+    /// with a directive prologue.
+    pub(crate) fn serialize_stmts_with_directives(&mut self, stmts: TypedSubRange<Stmt>) -> Result {
+        let mut in_prologue = true;
+        self.list(stmts, |s, stmt| {
+            in_prologue &= s.is_directive(stmt);
+            s.serialize_stmt_in_prologue(stmt, in_prologue)
+        })
+    }
+
+    fn serialize_expr_stmt(&mut self, stmt: ExprStmt, is_directive: bool) -> Result {
+        let expr = stmt.expr(self.ast);
+        self.start_node("ExpressionStatement", stmt.span(self.ast));
+        self.key("expression");
+        self.serialize_expr(expr)?;
+        if is_directive {
+            // The directive is the raw text between the quotes.
+            let raw = self.source_text(expr.span(self.ast));
+            let directive = raw.get(1..raw.len().saturating_sub(1)).unwrap_or_default();
+            self.key("directive");
+            self.str_value(directive);
+        }
+        self.end_node();
+        Ok(())
+    }
+
+    pub(crate) fn serialize_block_stmt(&mut self, block: BlockStmt) -> Result {
+        self.start_node("BlockStatement", block.span(self.ast));
+        self.key("body");
+        self.list(block.stmts(self.ast), Self::serialize_stmt)?;
+        self.end_node();
+        Ok(())
+    }
+
+    /// Serializes the body of a function, which may have directives.
+    pub(crate) fn serialize_function_body(&mut self, body: BlockStmt) -> Result {
+        self.start_node("BlockStatement", body.span(self.ast));
+        self.key("body");
+        self.serialize_stmts_with_directives(body.stmts(self.ast))?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_jump_stmt(&mut self, ty: &str, span: Span, label: Option<Ident>) -> Result {
+        self.start_node(ty, span);
+        self.key("label");
+        self.opt(label, |s, label| {
+            s.serialize_ident(label);
+            Ok(())
+        })?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_switch_stmt(&mut self, stmt: SwitchStmt) -> Result {
+        self.start_node("SwitchStatement", stmt.span(self.ast));
+        self.key("discriminant");
+        self.serialize_expr(stmt.discriminant(self.ast))?;
+        self.key("cases");
+        self.list(stmt.cases(self.ast), |s, case| {
+            s.start_node("SwitchCase", case.span(s.ast));
+            s.key("consequent");
+            s.list(case.cons(s.ast), Self::serialize_stmt)?;
+            s.key("test");
+            s.opt(case.test(s.ast), Self::serialize_expr)?;
+            s.end_node();
+            Ok(())
+        })?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_try_stmt(&mut self, stmt: TryStmt) -> Result {
+        self.start_node("TryStatement", stmt.span(self.ast));
+        self.key("block");
+        self.serialize_block_stmt(stmt.block(self.ast))?;
+        self.key("handler");
+        self.opt(stmt.handler(self.ast), |s, handler| {
+            s.start_node("CatchClause", handler.span(s.ast));
+            s.key("param");
+            s.opt(handler.param(s.ast), Self::serialize_pat)?;
+            s.key("body");
+            s.serialize_block_stmt(handler.body(s.ast))?;
+            s.end_node();
+            Ok(())
+        })?;
+        self.key("finalizer");
+        self.opt(stmt.finalizer(self.ast), Self::serialize_block_stmt)?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_for_stmt(&mut self, stmt: ForStmt) -> Result {
+        self.start_node("ForStatement", stmt.span(self.ast));
+        self.key("init");
+        self.opt(stmt.init(self.ast), |s, init| match init {
+            VarDeclOrExpr::VarDecl(decl) => s.serialize_var_decl(decl),
+            VarDeclOrExpr::Expr(expr) => s.serialize_expr(expr),
+        })?;
+        self.key("test");
+        self.opt(stmt.test(self.ast), Self::serialize_expr)?;
+        self.key("update");
+        self.opt(stmt.update(self.ast), Self::serialize_expr)?;
+        self.key("body");
+        self.serialize_stmt(stmt.body(self.ast))?;
+        self.end_node();
+        Ok(())
+    }
+
+    fn serialize_for_head(&mut self, head: ForHead) -> Result {
+        match head {
+            ForHead::VarDecl(decl) => self.serialize_var_decl(decl),
+            ForHead::UsingDecl(decl) => self.serialize_using_decl(decl),
+            ForHead::Pat(pat) => self.serialize_pat(pat),
+        }
+    }
+}
@@ -292,6 +292,9 @@ pub enum SyntaxError {
     ReservedTypeAssertion,
     ReservedArrowTypeParam,
     EmptyTypeArgumentList,
+    /// A TypeScript construct with runtime semantics, found while types are
+    /// stripped on parse.
+    TsNotErasable(&'static str),
 }
 
 impl SyntaxError {
@@ -749,6 +752,9 @@ impl SyntaxError {
                 .into(),
             SyntaxError::InvalidAssignTarget => "Invalid assignment target".into(),
             SyntaxError::EmptyTypeArgumentList => "Type argument list cannot be empty.".into(),
+            SyntaxError::TsNotErasable(what) => {
+                format!("TypeScript {what} is not supported in strip-only mode").into()
+            }
         }
     }
 }
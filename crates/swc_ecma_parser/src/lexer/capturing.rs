@@ -129,6 +129,16 @@ impl<I: Tokens> Tokens for Capturing<I> {
         self.inner.take_script_module_errors()
     }
 
+    fn take_comments(
+        &mut self,
+    ) -> Vec<(
+        swc_experimental_ecma_ast::CommentPlacement,
+        swc_core::common::BytePos,
+        swc_experimental_ecma_ast::Comment,
+    )> {
+        self.inner.take_comments()
+    }
+
     fn update_token_flags(&mut self, f: impl FnOnce(&mut TokenFlags)) {
         self.inner.update_token_flags(f);
     }
@@ -125,6 +125,9 @@ fn remove_underscore(s: &str, has_underscore: bool) -> Cow<'_, str> {
 pub struct Lexer<'a> {
     comments: Option<&'a dyn Comments>,
     /// [Some] if comment comment parsing is enabled. Otherwise [None]
+    ///
+    /// Without `comments`, the comments are kept here until the parser takes
+    /// them for the AST.
     comments_buffer: Option<CommentsBuffer>,
 
     pub ctx: Context,
@@ -230,6 +233,15 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Collects the comments into the AST returned by the parser instead of
+    /// the [Comments] passed to [Lexer::new], see
+    /// [swc_experimental_ecma_ast::Ast::leading_comments].
+    pub fn with_ast_comments(mut self) -> Self {
+        self.comments = None;
+        self.comments_buffer.get_or_insert_with(CommentsBuffer::new);
+        self
+    }
+
     /// babel: `getTokenFromCode`
     fn read_token(&mut self) -> LexResult<Token> {
         self.token_flags = TokenFlags::empty();
@@ -1188,23 +1200,26 @@ impl<'a> Lexer<'a> {
     #[cold]
     #[inline(never)]
     fn consume_pending_comments(&mut self) {
-        if let Some(comments) = self.comments() {
-            let last = self.state.prev_hi;
-            let start_pos = self.start_pos();
-            let comments_buffer = self.comments_buffer_mut().unwrap();
-
-            // if the file had no tokens and no shebang, then treat any
-            // comments in the leading comments buffer as leading.
-            // Otherwise treat them as trailing.
-            let kind = if last == start_pos {
-                BufferedCommentKind::Leading
-            } else {
-                BufferedCommentKind::Trailing
-            };
-            // move the pending to the leading or trailing
-            comments_buffer.pending_to_comment(kind, last);
+        let last = self.state.prev_hi;
+        let start_pos = self.start_pos();
+        let comments = self.comments();
+        let Some(comments_buffer) = self.comments_buffer_mut() else {
+            return;
+        };
+
+        // if the file had no tokens and no shebang, then treat any
+        // comments in the leading comments buffer as leading.
+        // Otherwise treat them as trailing.
+        let kind = if last == start_pos {
+            BufferedCommentKind::Leading
+        } else {
+            BufferedCommentKind::Trailing
+        };
+        // move the pending to the leading or trailing
+        comments_buffer.pending_to_comment(kind, last);
 
-            // now fill the user's passed in comments
+        // now fill the user's passed in comments, or keep them for the AST
+        if let Some(comments) = comments {
             for comment in comments_buffer.take_comments() {
                 match comment.kind {
                     BufferedCommentKind::Leading => {
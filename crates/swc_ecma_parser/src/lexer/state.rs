@@ -1,7 +1,7 @@
 use std::{mem::take, rc::Rc};
 
 use swc_core::atoms::wtf8::CodePoint;
-use swc_experimental_ecma_ast::{EsVersion, Span, StringAllocator};
+use swc_experimental_ecma_ast::{Comment, CommentPlacement, EsVersion, Span, StringAllocator};
 // use swc_core::atoms::wtf8::CodePoint;
 use swc_core::common::BytePos;
 
@@ -140,6 +140,33 @@ impl crate::input::Tokens for Lexer<'_> {
         take(&mut self.module_errors)
     }
 
+    fn take_comments(&mut self) -> Vec<(CommentPlacement, BytePos, Comment)> {
+        // The comments are passed to the user's `Comments` instead.
+        if self.comments.is_some() {
+            return Vec::new();
+        }
+        let string_allocator = self._string_allocator();
+        let Some(comments_buffer) = self.comments_buffer.as_mut() else {
+            return Vec::new();
+        };
+        comments_buffer
+            .take_comments()
+            .map(|comment| {
+                let placement = match comment.kind {
+                    BufferedCommentKind::Leading => CommentPlacement::Leading,
+                    BufferedCommentKind::Trailing => CommentPlacement::Trailing,
+                };
+                let text = string_allocator.add_utf8(&comment.comment.text);
+                let ast_comment = Comment {
+                    kind: comment.comment.kind,
+                    span: comment.comment.span,
+                    text,
+                };
+                (placement, comment.pos, ast_comment)
+            })
+            .collect()
+    }
+
     #[inline]
     fn end_pos(&self) -> BytePos {
         self.input.end_pos()
@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use swc_core::common::{BytePos, Span};
-use swc_experimental_ecma_ast::{EsVersion, StringAllocator};
+use swc_experimental_ecma_ast::{Comment, CommentPlacement, EsVersion, StringAllocator};
 
 use crate::lexer::{MaybeSubUtf8, MaybeSubWtf8};
 use crate::{
@@ -56,6 +56,12 @@ pub trait Tokens: Clone {
     /// If the program was parsed as a script, this contains the module
     /// errors should the program be identified as a module in the future.
     fn take_script_module_errors(&mut self) -> Vec<Error>;
+
+    /// Takes the comments collected for the AST, see
+    /// [crate::Lexer::with_ast_comments].
+    fn take_comments(&mut self) -> Vec<(CommentPlacement, BytePos, Comment)> {
+        Vec::new()
+    }
     fn update_token_flags(&mut self, f: impl FnOnce(&mut TokenFlags));
     fn token_flags(&self) -> TokenFlags;
 
@@ -16,7 +16,7 @@ use crate::{
 
 struct MakeMethodArgs {
     start: BytePos,
-    // accessibility: Option<Accessibility>,
+    accessibility: Option<Accessibility>,
     is_abstract: bool,
     static_token: Option<Span>,
     decorators: TypedSubRange<Decorator>,
@@ -43,27 +43,19 @@ impl<I: Tokens> Parser<I> {
     }
 
     fn parse_maybe_decorator_args(&mut self, expr: Expr) -> PResult<Expr> {
-        // let type_args = if self.input().syntax().typescript() && self.input().is(Token::Lt) {
-        //     let ret = self.parse_ts_type_args()?;
-        //     self.assert_and_bump(Token::Gt);
-        //     Some(ret)
-        // } else {
-        //     None
-        // };
-
-        // if type_args.is_none() && !self.input().is(Token::LParen) {
-        //     return Ok(expr);
-        // }
-
-        // let args = self.parse_args(false)?;
-        // Ok(CallExpr {
-        //     span: self.span(expr.span_lo()),
-        //     callee: Callee::Expr(expr),
-        //     args,
-        //     ..Default::default()
-        // }
-        // .into())
-        Ok(expr)
+        let type_args = self.parse_ts_explicit_type_args()?.flatten();
+
+        if type_args.is_none() && !self.input().is(Token::LParen) {
+            return Ok(expr);
+        }
+
+        let args = self.parse_args(false)?;
+        Ok(self.ast.expr_call_expr(
+            self.span(expr.span_lo(&self.ast)),
+            Callee::Expr(expr),
+            args,
+            type_args,
+        ))
     }
 
     pub(crate) fn parse_decorators(
@@ -130,43 +122,24 @@ impl<I: Tokens> Parser<I> {
         Ok(self.ast.decorator(self.span(start), expr))
     }
 
-    // pub(crate) fn parse_access_modifier(&mut self) -> PResult<Option<Accessibility>> {
-    //     Ok(self
-    //         .parse_ts_modifier(&["public", "protected", "private", "in", "out"], false)?
-    //         .and_then(|s| match s {
-    //             "public" => Some(Accessibility::Public),
-    //             "protected" => Some(Accessibility::Protected),
-    //             "private" => Some(Accessibility::Private),
-    //             other => {
-    //                 self.emit_err(self.input().prev_span(), SyntaxError::TS1274(other.into()));
-    //                 None
-    //             }
-    //         }))
-    // }
-
-    // fn parse_super_class(&mut self) -> PResult<(Expr, Option<Box<TsTypeParamInstantiation>>)> {
-    fn parse_super_class(&mut self) -> PResult<Expr> {
+    fn parse_super_class(&mut self) -> PResult<(Expr, Option<TsTypeParamInstantiation>)> {
         let super_class = self.parse_lhs_expr()?;
-        Ok(super_class)
-        // match super_class {
-        //     Expr::TsInstantiation(TsInstantiation {
-        //         expr, type_args, ..
-        //     }) => Ok((expr, Some(type_args))),
-        //     _ => {
-        //         We still need to parse TS type arguments,
-        //         because in some cases "super class" returned by `parse_lhs_expr`
-        //         may not include `TsExprWithTypeArgs`
-        //         but it's a super class with type params, for example, in JSX.
-        //         if self.syntax().typescript() && self.input().is(Token::Lt) {
-        //             let ret = self.parse_ts_type_args()?;
-        //             self.assert_and_bump(Token::Gt);
-        //             Ok((super_class, Some(ret)))
-        //         } else {
-        //             Ok((super_class, None))
-        //         }
-        //         Ok(super_class)
-        //     }
-        // }
+        match super_class {
+            Expr::TsInstantiation(instantiation) => Ok((
+                instantiation.expr(&self.ast),
+                Some(instantiation.type_args(&self.ast)),
+            )),
+            _ => {
+                // We still need to parse TS type arguments,
+                // because in some cases "super class" returned by `parse_lhs_expr`
+                // may not include `TsExprWithTypeArgs`
+                // but it's a super class with type params, for example, in JSX.
+                if let Some(ret) = self.parse_ts_explicit_type_args()? {
+                    return Ok((super_class, ret));
+                }
+                Ok((super_class, None))
+            }
+        }
     }
 
     fn is_class_method(&mut self) -> bool {
@@ -213,21 +186,7 @@ impl<I: Tokens> Parser<I> {
     {
         trace_cur!(self, parse_fn_args_body);
         let f = |p: &mut Self| {
-            // let type_params = if p.syntax().typescript() {
-            //     p.in_type(|p| {
-            //         trace_cur!(p, parse_fn_args_body__type_params);
-
-            //         Ok(
-            //             if p.input().is(Token::Lt) || p.input().is(Token::JSXTagStart) {
-            //                 Some(p.parse_ts_type_params(false, true)?)
-            //             } else {
-            //                 None
-            //             },
-            //         )
-            //     })?
-            // } else {
-            //     None
-            // };
+            let type_params = p.try_parse_ts_fn_type_params()?;
 
             expect!(p, Token::LParen);
 
@@ -252,12 +211,7 @@ impl<I: Tokens> Parser<I> {
             expect!(p, Token::RParen);
 
             // typescript extension
-            // let return_type = if p.syntax().typescript() && p.input().is(Token::Colon) {
-            //     p.parse_ts_type_or_type_predicate_ann(Token::Colon)
-            //         .map(Some)?
-            // } else {
-            //     None
-            // };
+            let return_type = p.try_parse_ts_return_type()?;
 
             let body: Option<_> = p.parse_fn_block_body(
                 is_async,
@@ -290,6 +244,8 @@ impl<I: Tokens> Parser<I> {
                 body,
                 is_generator,
                 is_async,
+                type_params,
+                return_type,
             ))
         };
 
@@ -473,12 +429,12 @@ impl<I: Tokens> Parser<I> {
         parse_args: F,
         MakeMethodArgs {
             start,
-            // accessibility,
+            accessibility,
             is_abstract,
             static_token,
             decorators,
-            is_optional: _is_optional,
-            is_override: _is_override,
+            is_optional,
+            is_override,
             key,
             kind,
             is_async,
@@ -510,13 +466,21 @@ impl<I: Tokens> Parser<I> {
         match key {
             Key::Private(key) => {
                 let span = self.span(start);
-                // if accessibility.is_some() {
-                //     self.emit_err(span.with_hi(key.span_hi()), SyntaxError::TS18010);
-                // }
+                if accessibility.is_some() {
+                    self.emit_err(span.with_hi(key.span_hi(&self.ast)), SyntaxError::TS18010);
+                }
 
-                Ok(self
-                    .ast
-                    .class_member_private_method(span, key, function, kind, is_static))
+                Ok(self.ast.class_member_private_method(
+                    span,
+                    key,
+                    function,
+                    kind,
+                    is_static,
+                    accessibility,
+                    is_abstract,
+                    is_optional,
+                    is_override,
+                ))
             }
             Key::Public(key) => {
                 let span = self.span(start);
@@ -524,9 +488,17 @@ impl<I: Tokens> Parser<I> {
                     self.emit_err(span, SyntaxError::TS1245)
                 }
 
-                Ok(self
-                    .ast
-                    .class_member_class_method(span, key, function, kind, is_static))
+                Ok(self.ast.class_member_class_method(
+                    span,
+                    key,
+                    function,
+                    kind,
+                    is_static,
+                    accessibility,
+                    is_abstract,
+                    is_optional,
+                    is_override,
+                ))
             }
             #[cfg(swc_ast_unknown)]
             _ => unreachable!(),
@@ -655,15 +627,15 @@ impl<I: Tokens> Parser<I> {
         &mut self,
         start: BytePos,
         decorators: TypedSubRange<Decorator>,
-        // accessibility: Option<Accessibility>,
+        accessibility: Option<Accessibility>,
         key: Key,
         is_static: bool,
         accessor_token: Option<Span>,
-        _is_optional: bool,
-        _readonly: bool,
+        is_optional: bool,
+        readonly: bool,
         declare: bool,
         is_abstract: bool,
-        _is_override: bool,
+        is_override: bool,
     ) -> PResult<ClassMember> {
         if is_constructor(&self.ast, key) {
             syntax_error!(
@@ -686,10 +658,10 @@ impl<I: Tokens> Parser<I> {
                 )
             }
         }
-        // let definite =
-        //     self.input().syntax().typescript() && !is_optional && self.input_mut().eat(Token::Bang);
+        let definite =
+            self.input().syntax().typescript() && !is_optional && self.input_mut().eat(Token::Bang);
 
-        // let type_ann = self.try_parse_ts_type_ann()?;
+        let type_ann = self.try_parse_ts_type_ann_stripped()?;
 
         self.do_inside_of_context(Context::IncludeInExpr.union(Context::InClassField), |p| {
             let value = if p.input().is(Token::Eq) {
@@ -708,24 +680,35 @@ impl<I: Tokens> Parser<I> {
                     p.span(start),
                     key,
                     value,
+                    type_ann,
                     is_static,
                     decorators,
+                    accessibility,
+                    is_abstract,
+                    is_override,
+                    definite,
                 ));
             }
 
             Ok(match key {
                 Key::Private(key) => {
-                    // let span = p.span(start);
-                    // if accessibility.is_some() {
-                    //     p.emit_err(span.with_hi(key.span_hi()), SyntaxError::TS18010);
-                    // }
+                    let span = p.span(start);
+                    if accessibility.is_some() {
+                        p.emit_err(span.with_hi(key.span_hi(&p.ast)), SyntaxError::TS18010);
+                    }
 
                     p.ast.class_member_private_prop(
-                        p.span(start),
+                        span,
                         key,
                         value,
+                        type_ann,
                         is_static,
                         decorators,
+                        accessibility,
+                        is_optional,
+                        is_override,
+                        readonly,
+                        definite,
                     )
                 }
                 Key::Public(key) => {
@@ -734,8 +717,21 @@ impl<I: Tokens> Parser<I> {
                         p.emit_err(span, SyntaxError::TS1267)
                     }
 
-                    p.ast
-                        .class_member_class_prop(span, key, value, is_static, decorators)
+                    p.ast.class_member_class_prop(
+                        span,
+                        key,
+                        value,
+                        type_ann,
+                        is_static,
+                        decorators,
+                        accessibility,
+                        is_abstract,
+                        is_optional,
+                        is_override,
+                        readonly,
+                        declare,
+                        definite,
+                    )
                 }
                 #[cfg(swc_ast_unknown)]
                 _ => unreachable!(),
@@ -759,81 +755,83 @@ impl<I: Tokens> Parser<I> {
         &mut self,
         start: BytePos,
         declare_token: Option<Span>,
-        // accessibility: Option<Accessibility>,
+        accessibility: Option<Accessibility>,
         static_token: Option<Span>,
         accessor_token: Option<Span>,
         decorators: TypedSubRange<Decorator>,
     ) -> PResult<ClassMember> {
-        let is_static = static_token.is_some();
+        let mut is_static = static_token.is_some();
 
-        let is_abstract = false;
-        let is_override = false;
-        let readonly = None;
-        let modifier_span = None;
+        let mut is_abstract = false;
+        let mut is_override = false;
+        let mut readonly = None;
+        let mut modifier_span = None;
         let declare = declare_token.is_some();
-        // while let Some(modifier) = if self.input().syntax().typescript() {
-        //     self.parse_ts_modifier(&["abstract", "readonly", "override", "static"], true)?
-        // } else {
-        //     None
-        // } {
-        //     modifier_span = Some(self.input().prev_span());
-        //     match modifier {
-        //         "abstract" => {
-        //             if is_abstract {
-        //                 self.emit_err(
-        //                     self.input().prev_span(),
-        //                     SyntaxError::TS1030(atom!("abstract")),
-        //                 );
-        //             } else if is_override {
-        //                 self.emit_err(
-        //                     self.input().prev_span(),
-        //                     SyntaxError::TS1029(atom!("abstract"), atom!("override")),
-        //                 );
-        //             }
-        //             is_abstract = true;
-        //         }
-        //         "override" => {
-        //             if is_override {
-        //                 self.emit_err(
-        //                     self.input().prev_span(),
-        //                     SyntaxError::TS1030(atom!("override")),
-        //                 );
-        //             } else if readonly.is_some() {
-        //                 self.emit_err(
-        //                     self.input().prev_span(),
-        //                     SyntaxError::TS1029(atom!("override"), atom!("readonly")),
-        //                 );
-        //             } else if declare {
-        //                 self.emit_err(
-        //                     self.input().prev_span(),
-        //                     SyntaxError::TS1243(atom!("override"), atom!("declare")),
-        //                 );
-        //             } else if !self.ctx().contains(Context::HasSuperClass) {
-        //                 self.emit_err(self.input().prev_span(), SyntaxError::TS4112);
-        //             }
-        //             is_override = true;
-        //         }
-        //         "readonly" => {
-        //             let readonly_span = self.input().prev_span();
-        //             if readonly.is_some() {
-        //                 self.emit_err(readonly_span, SyntaxError::TS1030(atom!("readonly")));
-        //             } else {
-        //                 readonly = Some(readonly_span);
-        //             }
-        //         }
-        //         "static" => {
-        //             if is_override {
-        //                 self.emit_err(
-        //                     self.input().prev_span(),
-        //                     SyntaxError::TS1029(atom!("static"), atom!("override")),
-        //                 );
-        //             }
-
-        //             is_static = true;
-        //         }
-        //         _ => {}
-        //     }
-        // }
+        loop {
+            let Some(modifier) =
+                self.try_parse_ts_modifier(&["abstract", "readonly", "override", "static"], true)?
+            else {
+                break;
+            };
+
+            modifier_span = Some(self.input().prev_span());
+            match modifier {
+                "abstract" => {
+                    if is_abstract {
+                        self.emit_err(
+                            self.input().prev_span(),
+                            SyntaxError::TS1030(atom!("abstract")),
+                        );
+                    } else if is_override {
+                        self.emit_err(
+                            self.input().prev_span(),
+                            SyntaxError::TS1029(atom!("abstract"), atom!("override")),
+                        );
+                    }
+                    is_abstract = true;
+                }
+                "override" => {
+                    if is_override {
+                        self.emit_err(
+                            self.input().prev_span(),
+                            SyntaxError::TS1030(atom!("override")),
+                        );
+                    } else if readonly.is_some() {
+                        self.emit_err(
+                            self.input().prev_span(),
+                            SyntaxError::TS1029(atom!("override"), atom!("readonly")),
+                        );
+                    } else if declare {
+                        self.emit_err(
+                            self.input().prev_span(),
+                            SyntaxError::TS1243(atom!("override"), atom!("declare")),
+                        );
+                    } else if !self.ctx().contains(Context::HasSuperClass) {
+                        self.emit_err(self.input().prev_span(), SyntaxError::TS4112);
+                    }
+                    is_override = true;
+                }
+                "readonly" => {
+                    let readonly_span = self.input().prev_span();
+                    if readonly.is_some() {
+                        self.emit_err(readonly_span, SyntaxError::TS1030(atom!("readonly")));
+                    } else {
+                        readonly = Some(readonly_span);
+                    }
+                }
+                "static" => {
+                    if is_override {
+                        self.emit_err(
+                            self.input().prev_span(),
+                            SyntaxError::TS1029(atom!("static"), atom!("override")),
+                        );
+                    }
+
+                    is_static = true;
+                }
+                _ => {}
+            }
+        }
 
         let accessor_token = accessor_token.or_else(|| {
             if self.syntax().auto_accessors() && readonly.is_none() {
@@ -854,9 +852,9 @@ impl<I: Tokens> Parser<I> {
             if let Some(span) = declare_token {
                 self.emit_err(span, SyntaxError::TS1184);
             }
-            // if accessibility.is_some() {
-            //     self.emit_err(self.input().cur_span(), SyntaxError::TS1184);
-            // }
+            if accessibility.is_some() {
+                self.emit_err(self.input().cur_span(), SyntaxError::TS1184);
+            }
             return self.parse_static_block(start);
         }
         if self.input().is(Token::Static) && peek!(self).is_some_and(|cur| cur == Token::LBrace) {
@@ -871,16 +869,13 @@ impl<I: Tokens> Parser<I> {
             return self.parse_static_block(start);
         }
 
-        // if self.input().syntax().typescript()
-        //     && !is_abstract
-        //     && !is_override
-        //     && accessibility.is_none()
-        // {
-        //     let idx = self.try_parse_ts_index_signature(start, readonly.is_some(), is_static)?;
-        //     if let Some(idx) = idx {
-        //         return Ok(idx.into());
-        //     }
-        // }
+        if !is_abstract && !is_override && accessibility.is_none() {
+            let idx =
+                self.try_parse_ts_class_index_signature(start, readonly.is_some(), is_static)?;
+            if let Some(idx) = idx {
+                return Ok(ClassMember::TsIndexSignature(idx));
+            }
+        }
 
         if self.input_mut().eat(Token::Asterisk) {
             // generator method
@@ -899,7 +894,7 @@ impl<I: Tokens> Parser<I> {
                     decorators,
                     is_async: false,
                     is_generator: true,
-                    // accessibility,
+                    accessibility,
                     is_abstract,
                     is_override,
                     is_optional: false,
@@ -941,36 +936,13 @@ impl<I: Tokens> Parser<I> {
                     self.emit_err(self.span(start), SyntaxError::TS1089(atom!("override")));
                 }
 
-                if self.syntax().typescript() && self.input().is(Token::Lt) {
-                    let start = self.cur_pos();
-                    if peek!(self).is_some_and(|cur| cur == Token::Lt) {
-                        self.assert_and_bump(Token::Lt);
-                        let start2 = self.cur_pos();
-                        self.assert_and_bump(Token::Gt);
-
-                        self.emit_err(self.span(start), SyntaxError::TS1098);
-                        self.emit_err(self.span(start2), SyntaxError::TS1092);
-                    } else {
-                        // let type_params = self.try_parse_ts_type_params(false, true)?;
-
-                        // if let Some(type_params) = type_params {
-                        //     for param in type_params.params {
-                        //         self.emit_err(param.span(), SyntaxError::TS1092);
-                        //     }
-                        // }
-                    }
-                }
+                self.report_ts_constructor_type_params()?;
 
                 expect!(self, Token::LParen);
                 let params = self.parse_constructor_params()?;
                 expect!(self, Token::RParen);
 
-                // if self.syntax().typescript() && self.input().is(Token::Colon) {
-                //     let start = self.cur_pos();
-                //     let type_ann = self.parse_ts_type_ann(true, start)?;
-
-                //     self.emit_err(type_ann.type_ann.span(), SyntaxError::TS1093);
-                // }
+                self.report_ts_constructor_return_type()?;
 
                 let body: Option<_> = self.parse_fn_block_body(
                     false,
@@ -979,36 +951,37 @@ impl<I: Tokens> Parser<I> {
                     params.is_simple_parameter_list(&self.ast),
                 )?;
 
-                // if body.is_none() {
-                //     for param in params.iter() {
-                //         if param.is_ts_param_prop() {
-                //             self.emit_err(param.span(&self.ast), SyntaxError::TS2369)
-                //         }
-                //     }
-                // }
-
-                // if self.syntax().typescript() && body.is_none() {
-                //     // Declare constructors cannot have assignment pattern in parameters
-                //     for param in &params {
-                //         // TODO: Search deeply for assignment pattern using a Visitor
-
-                //         let span = match *param {
-                //             ParamOrTsParamProp::Param(ref param) => match param.pat {
-                //                 Pat::Assign(ref p) => Some(p.span()),
-                //                 _ => None,
-                //             },
-                //             ParamOrTsParamProp::TsParamProp(TsParamProp {
-                //                 param: TsParamPropParam::Assign(ref p),
-                //                 ..
-                //             }) => Some(p.span()),
-                //             _ => None,
-                //         };
-
-                //         if let Some(span) = span {
-                //             self.emit_err(span, SyntaxError::TS2371)
-                //         }
-                //     }
-                // }
+                if body.is_none() {
+                    for param in params.iter() {
+                        let param = self.ast.get_node_in_sub_range(param);
+                        if let ParamOrTsParamProp::TsParamProp(prop) = param {
+                            self.emit_err(prop.span(&self.ast), SyntaxError::TS2369)
+                        }
+                    }
+                }
+
+                if self.syntax().typescript() && body.is_none() {
+                    // Declare constructors cannot have assignment pattern in parameters
+                    for param in params.iter() {
+                        // TODO: Search deeply for assignment pattern using a Visitor
+
+                        let param = self.ast.get_node_in_sub_range(param);
+                        let span = match param {
+                            ParamOrTsParamProp::Param(param) => match param.pat(&self.ast) {
+                                Pat::Assign(p) => Some(p.span(&self.ast)),
+                                _ => None,
+                            },
+                            ParamOrTsParamProp::TsParamProp(prop) => match prop.param(&self.ast) {
+                                TsParamPropParam::Assign(p) => Some(p.span(&self.ast)),
+                                _ => None,
+                            },
+                        };
+
+                        if let Some(span) = span {
+                            self.emit_err(span, SyntaxError::TS2371)
+                        }
+                    }
+                }
 
                 if let Some(static_token) = static_token {
                     self.emit_err(static_token, SyntaxError::TS1089(atom!("static")))
@@ -1025,16 +998,21 @@ impl<I: Tokens> Parser<I> {
                     _ => unreachable!("is_constructor() returns false for PrivateName"),
                 };
 
-                return Ok(self
-                    .ast
-                    .class_member_constructor(self.span(start), key, params, body));
+                return Ok(self.ast.class_member_constructor(
+                    self.span(start),
+                    key,
+                    params,
+                    body,
+                    accessibility,
+                    is_optional,
+                ));
             } else {
                 return self.make_method(
                     Self::parse_formal_params,
                     MakeMethodArgs {
                         start,
                         is_optional,
-                        // accessibility,
+                        accessibility,
                         decorators,
                         is_abstract,
                         is_override,
@@ -1070,7 +1048,7 @@ impl<I: Tokens> Parser<I> {
             return self.make_property(
                 start,
                 decorators,
-                // accessibility,
+                accessibility,
                 key,
                 is_static,
                 accessor_token,
@@ -1089,15 +1067,13 @@ impl<I: Tokens> Parser<I> {
         {
             // handle async foo(){}
 
-            // if self.input().syntax().typescript()
-            //     && self.parse_ts_modifier(&["override"], false)?.is_some()
-            // {
-            //     is_override = true;
-            //     self.emit_err(
-            //         self.input().prev_span(),
-            //         SyntaxError::TS1029(atom!("override"), atom!("async")),
-            //     );
-            // }
+            if self.try_parse_ts_modifier(&["override"], false)?.is_some() {
+                is_override = true;
+                self.emit_err(
+                    self.input().prev_span(),
+                    SyntaxError::TS1029(atom!("override"), atom!("async")),
+                );
+            }
 
             let is_generator = self.input_mut().eat(Token::Asterisk);
             let key = self.parse_class_prop_name()?;
@@ -1118,7 +1094,7 @@ impl<I: Tokens> Parser<I> {
                     static_token,
                     key,
                     is_abstract,
-                    // accessibility,
+                    accessibility,
                     is_optional,
                     is_override,
                     decorators,
@@ -1166,7 +1142,7 @@ impl<I: Tokens> Parser<I> {
                         is_generator: false,
                         is_optional,
                         is_override,
-                        // accessibility,
+                        accessibility,
                         static_token,
                         key,
                         kind: MethodKind::Getter,
@@ -1206,7 +1182,7 @@ impl<I: Tokens> Parser<I> {
                         is_override,
                         is_async: false,
                         is_generator: false,
-                        // accessibility,
+                        accessibility,
                         static_token,
                         key,
                         kind: MethodKind::Setter,
@@ -1225,11 +1201,7 @@ impl<I: Tokens> Parser<I> {
         let start = self.cur_pos();
         let decorators = self.parse_decorators(false)?;
         let declare = self.syntax().typescript() && self.input_mut().eat(Token::Declare);
-        // let accessibility = if self.input().syntax().typescript() {
-        //     self.parse_access_modifier()?
-        // } else {
-        //     None
-        // };
+        let accessibility = self.try_parse_ts_access_modifier()?;
         // Allow `private declare`.
         let declare = declare || self.syntax().typescript() && self.input_mut().eat(Token::Declare);
 
@@ -1244,7 +1216,7 @@ impl<I: Tokens> Parser<I> {
                     Self::parse_unique_formal_params,
                     MakeMethodArgs {
                         start,
-                        // accessibility,
+                        accessibility,
                         decorators,
                         is_abstract: false,
                         is_optional,
@@ -1268,7 +1240,7 @@ impl<I: Tokens> Parser<I> {
                 return self.make_property(
                     start,
                     decorators,
-                    // accessibility,
+                    accessibility,
                     key,
                     false,
                     None,
@@ -1316,7 +1288,7 @@ impl<I: Tokens> Parser<I> {
                     Self::parse_unique_formal_params,
                     MakeMethodArgs {
                         start,
-                        // accessibility,
+                        accessibility,
                         decorators,
                         is_abstract: false,
                         is_optional,
@@ -1341,7 +1313,7 @@ impl<I: Tokens> Parser<I> {
                 return self.make_property(
                     start,
                     decorators,
-                    // accessibility,
+                    accessibility,
                     key,
                     is_static,
                     None,
@@ -1365,7 +1337,7 @@ impl<I: Tokens> Parser<I> {
                     Self::parse_unique_formal_params,
                     MakeMethodArgs {
                         start,
-                        // accessibility,
+                        accessibility,
                         decorators,
                         is_abstract: false,
                         is_optional,
@@ -1394,7 +1366,7 @@ impl<I: Tokens> Parser<I> {
                     return self.make_property(
                         start,
                         decorators,
-                        // accessibility,
+                        accessibility,
                         key,
                         false,
                         accessor_token,
@@ -1413,14 +1385,14 @@ impl<I: Tokens> Parser<I> {
         self.parse_class_member_with_is_static(
             start,
             declare_token,
-            // accessibility,
+            accessibility,
             static_token,
             accessor_token,
             decorators,
         )
     }
 
-    fn parse_class_body(&mut self) -> PResult<TypedSubRange<ClassMember>> {
+    fn parse_class_body(&mut self, is_abstract: bool) -> PResult<TypedSubRange<ClassMember>> {
         self.scratch_start(|p, elems| {
             let mut has_constructor_with_body = false;
             while !p.input().is(Token::RBrace) {
@@ -1431,18 +1403,22 @@ impl<I: Tokens> Parser<I> {
                     elems.push(p, member);
                     continue;
                 }
+                let checkpoint = p.ast.checkpoint();
                 let elem =
                     p.do_inside_of_context(Context::AllowDirectSuper, Self::parse_class_member)?;
 
                 if !p.ctx().contains(Context::InDeclare)
                     && let ClassMember::Constructor(constructor) = elem
+                    && constructor.body(&p.ast).is_some()
                 {
-                    if constructor.body(&p.ast).is_some() && has_constructor_with_body {
+                    if has_constructor_with_body {
                         p.emit_err(constructor.span(&p.ast), SyntaxError::DuplicateConstructor);
                     }
                     has_constructor_with_body = true;
                 }
-                elems.push(p, elem);
+                if !p.is_erased_class_member(elem, is_abstract, checkpoint) {
+                    elems.push(p, elem);
+                }
             }
             Ok(())
         })
@@ -1459,27 +1435,31 @@ impl<I: Tokens> Parser<I> {
         T: OutputType,
     {
         let (ident, class) = self.do_inside_of_context(Context::InClass, |p| {
-            p.parse_class_inner(start, class_start, decorators, T::IS_IDENT_REQUIRED)
+            p.parse_class_inner(
+                start,
+                class_start,
+                decorators,
+                is_abstract,
+                T::IS_IDENT_REQUIRED,
+            )
         })?;
 
         if is_abstract {
-            class.set_is_abstract(&mut self.ast, true);
+            let is_abstract = self.strip_ts_flag(true);
+            class.set_is_abstract(&mut self.ast, is_abstract);
         } else {
-            // for member in class.body(&self.ast).iter(&self.ast) {
-            //     match member {
-            //         ClassMember::ClassProp(ClassProp {
-            //             is_abstract: true,
-            //             span,
-            //             ..
-            //         })
-            //         | ClassMember::Method(ClassMethod {
-            //             span,
-            //             is_abstract: true,
-            //             ..
-            //         }) => self.emit_err(*span, SyntaxError::TS1244),
-            //         _ => (),
-            //     }
-            // }
+            for member in class.body(&self.ast).iter() {
+                let span = match self.ast.get_node_in_sub_range(member) {
+                    ClassMember::ClassProp(prop) if prop.is_abstract(&self.ast) => {
+                        prop.span(&self.ast)
+                    }
+                    ClassMember::Method(method) if method.is_abstract(&self.ast) => {
+                        method.span(&self.ast)
+                    }
+                    _ => continue,
+                };
+                self.emit_err(span, SyntaxError::TS1244);
+            }
         }
 
         let span = self.span(start);
@@ -1495,6 +1475,7 @@ impl<I: Tokens> Parser<I> {
         _start: BytePos,
         class_start: BytePos,
         decorators: TypedSubRange<Decorator>,
+        is_abstract: bool,
         is_ident_required: bool,
     ) -> PResult<(Option<Ident>, Class)> {
         self.strict_mode(|p| {
@@ -1507,75 +1488,55 @@ impl<I: Tokens> Parser<I> {
                 p.emit_err(span, SyntaxError::TS2414);
             }
 
-            // let type_params = if p.input().syntax().typescript() {
-            //     p.try_parse_ts_type_params(true, true)?
-            // } else {
-            //     None
-            // };
-
-            // let (mut super_class, mut super_type_params) = if p.input_mut().eat(Token::Extends) {
-            let super_class = if p.input_mut().eat(Token::Extends) {
-                // let (super_class, super_type_params) = p.parse_super_class()?;
-                let super_class = p.parse_super_class()?;
+            let type_params = p.try_parse_ts_class_type_params()?;
 
-                // if p.syntax().typescript() && p.input_mut().eat(Token::Comma) {
-                //     let exprs = p.parse_ts_heritage_clause()?;
+            let (mut super_class, mut super_type_params) = if p.input_mut().eat(Token::Extends) {
+                let (super_class, super_type_params) = p.parse_super_class()?;
 
-                //     for e in &exprs {
-                //         p.emit_err(e.span(), SyntaxError::TS1174);
-                //     }
-                // }
+                p.report_ts_extra_super_classes()?;
 
-                // (Some(super_class), super_type_params)
-                Some(super_class)
+                (Some(super_class), super_type_params)
             } else {
-                // (None, None)
-                None
+                (None, None)
             };
 
             // Handle TS1172
             if p.input_mut().eat(Token::Extends) {
                 p.emit_err(p.input().prev_span(), SyntaxError::TS1172);
 
+                let checkpoint = p.ast.checkpoint();
                 p.parse_super_class()?;
+                // SAFETY: The super class is only parsed to be reported.
+                unsafe { p.ast.truncate(checkpoint) };
             };
 
-            // let implements =
-            //     if p.input().syntax().typescript() && p.input_mut().eat(Token::Implements) {
-            //         p.parse_ts_heritage_clause()?
-            //     } else {
-            //         Vec::with_capacity(4)
-            //     };
+            let implements = p.parse_ts_implements()?;
 
-            // {
-            //     // Handle TS1175
-            //     if p.input().syntax().typescript() && p.input_mut().eat(Token::Implements) {
-            //         p.emit_err(p.input().prev_span(), SyntaxError::TS1175);
-
-            //         p.parse_ts_heritage_clause()?;
-            //     }
-            // }
+            // Handle TS1175
+            p.report_ts_extra_implements()?;
 
             // Handle TS1173
-            // if p.input().syntax().typescript() && p.input_mut().eat(Token::Extends) {
-            //     p.emit_err(p.input().prev_span(), SyntaxError::TS1173);
+            if p.input().syntax().typescript() && p.input_mut().eat(Token::Extends) {
+                p.emit_err(p.input().prev_span(), SyntaxError::TS1173);
 
-            //     let (sc, type_params) = p.parse_super_class()?;
+                let (sc, type_params) = p.parse_super_class()?;
 
-            //     if super_class.is_none() {
-            //         super_class = Some(sc);
-            //         if type_params.is_some() {
-            //             super_type_params = type_params;
-            //         }
-            //     }
-            // }
+                if super_class.is_none() {
+                    super_class = Some(sc);
+                    if type_params.is_some() {
+                        super_type_params = type_params;
+                    }
+                }
+            }
 
             expect!(p, Token::LBrace);
 
             let body = if super_class.is_some() {
-                p.do_inside_of_context(Context::HasSuperClass, Self::parse_class_body)?
+                p.do_inside_of_context(Context::HasSuperClass, |p| p.parse_class_body(is_abstract))?
             } else {
-                p.do_outside_of_context(Context::HasSuperClass, Self::parse_class_body)?
+                p.do_outside_of_context(Context::HasSuperClass, |p| {
+                    p.parse_class_body(is_abstract)
+                })?
             };
 
             if p.input().cur() == Token::Eof {
@@ -1589,7 +1550,16 @@ impl<I: Tokens> Parser<I> {
             }
 
             let span = p.span(class_start);
-            let class = p.ast.class(span, decorators, body, super_class, false);
+            let class = p.ast.class(
+                span,
+                decorators,
+                body,
+                super_class,
+                false,
+                type_params,
+                super_type_params,
+                implements,
+            );
             Ok((ident, class))
         })
     }
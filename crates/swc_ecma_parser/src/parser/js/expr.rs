@@ -14,7 +14,6 @@ use crate::{
 
 pub(crate) enum AssignTargetOrSpread {
     ExprOrSpread(ExprOrSpread),
-    #[allow(unused)]
     Pat(Pat),
 }
 
@@ -103,16 +102,13 @@ impl<I: Tokens> Parser<I> {
     pub(crate) fn parse_assignment_expr(&mut self) -> PResult<Expr> {
         trace_cur!(self, parse_assignment_expr);
 
-        // if self.input().syntax().typescript() && self.input().is(Token::JSXTagStart) {
-        //     // Note: When the JSX plugin is on, type assertions (`<T> x`) aren't valid
-        //     // syntax.
-        //     let res = self.try_parse_ts(|p| p.parse_assignment_expr_base().map(Some));
-        //     if let Some(res) = res {
-        //         return Ok(res);
-        //     }
-        // }
+        if let Some(expr) = self.try_parse_ts_assignment_at_jsx_tag() {
+            return Ok(expr);
+        }
 
-        self.parse_assignment_expr_base()
+        // Every nested expression (array elements, parens, arguments,
+        // conditional branches) comes back through here.
+        maybe_grow(|| self.parse_assignment_expr_base())
     }
 
     /// Parse an assignment expression. This includes applications of
@@ -120,39 +116,11 @@ impl<I: Tokens> Parser<I> {
     ///
     /// `parseMaybeAssign`
     #[cfg_attr(feature = "tracing-spans", tracing::instrument(skip_all))]
-    fn parse_assignment_expr_base(&mut self) -> PResult<Expr> {
+    pub(crate) fn parse_assignment_expr_base(&mut self) -> PResult<Expr> {
         trace_cur!(self, parse_assignment_expr_base);
-        // let start = self.input().cur_span();
-
-        // if self.input().syntax().typescript()
-        //     && (self.input().cur() == Token::Lt || self.input().cur() == Token::JSXTagStart)
-        //     && (peek!(self).is_some_and(|peek| peek.is_word() || peek == Token::JSXName))
-        // {
-        //     let res = self.do_outside_of_context(Context::WillExpectColonForCond, |p| {
-        //         p.try_parse_ts(|p| {
-        //             let type_parameters = p.parse_ts_type_params(false, true)?;
-        //             let mut arrow = p.parse_assignment_expr_base()?;
-        //             match *arrow {
-        //                 Expr::Arrow(ArrowExpr {
-        //                     ref mut span,
-        //                     ref mut type_params,
-        //                     ..
-        //                 }) => {
-        //                     *span = Span::new_with_checked(type_parameters.span.lo, span.hi);
-        //                     *type_params = Some(type_parameters);
-        //                 }
-        //                 _ => unexpected!(p, "("),
-        //             }
-        //             Ok(Some(arrow))
-        //         })
-        //     });
-        //     if let Some(res) = res {
-        //         if self.input().syntax().disallow_ambiguous_jsx_like() {
-        //             self.emit_err(start, SyntaxError::ReservedArrowTypeParam);
-        //         }
-        //         return Ok(res);
-        //     }
-        // }
+        if let Some(expr) = self.try_parse_ts_generic_arrow() {
+            return Ok(expr);
+        }
 
         if self.ctx().contains(Context::InGenerator) && self.input().is(Token::Yield) {
             return self.parse_yield_expr();
@@ -189,29 +157,24 @@ impl<I: Tokens> Parser<I> {
         self.finish_assignment_expr(start, cond)
     }
 
-    pub(super) fn parse_unary_expr(&mut self) -> PResult<Expr> {
+    pub(crate) fn parse_unary_expr(&mut self) -> PResult<Expr> {
+        // Prefix operators and `await` nest through here.
+        maybe_grow(|| self.parse_unary_expr_inner())
+    }
+
+    fn parse_unary_expr_inner(&mut self) -> PResult<Expr> {
         trace_cur!(self, parse_unary_expr);
 
         let token_and_span = self.input().get_cur();
         let start = token_and_span.span.lo;
         let cur = token_and_span.token;
 
-        if cur == Token::Lt && self.input().syntax().typescript() && !self.input().syntax().jsx() {
-            // self.bump(); // consume `<`
-            // return if self.input_mut().eat(Token::Const) {
-            //     self.expect(Token::Gt)?;
-            //     let expr = self.parse_unary_expr()?;
-            //     Ok(TsConstAssertion {
-            //         span: self.span(start),
-            //         expr,
-            //     }
-            //     .into())
-            // } else {
-            //     self.parse_ts_type_assertion(start)
-            //         .map(Expr::from)
-            //         .map(Box::new)
-            // };
-        } else if cur == Token::Lt
+        if cur == Token::Lt
+            && let Some(expr) = self.try_parse_ts_type_assertion(start)?
+        {
+            return Ok(expr);
+        }
+        if cur == Token::Lt
             && self.input().syntax().jsx()
             && self.input_mut().peek().is_some_and(|peek| {
                 peek.is_word() || peek == Token::Gt || peek.should_rescan_into_gt_in_jsx()
@@ -404,37 +367,22 @@ impl<I: Tokens> Parser<I> {
         let callee = self.parse_new_expr()?;
         return_if_arrow!(self, callee);
 
-        // let type_args = None;
-        // let type_args = if self.input().syntax().typescript() && {
-        //     let cur = self.input().cur();
-        //     cur == Token::Lt || cur == Token::LShift
-        // } {
-        //     self.try_parse_ts(|p| {
-        //         let type_args = p.parse_ts_type_args()?;
-        //         p.assert_and_bump(Token::Gt);
-        //         if p.input().is(Token::LParen) {
-        //             Ok(Some(type_args))
-        //         } else {
-        //             Ok(None)
-        //         }
-        //     })
-        // } else {
-        //     None
-        // };
+        let type_args = self.try_parse_ts_call_type_args();
 
         if let Expr::New(new) = callee
             && new.args(&self.ast).is_none()
         {
             // If this is parsed using 'NewExpression' rule, just return it.
             // Because it's not left-recursive.
-            // if type_args.is_some() {
-            //     // This fails with `expected (`
-            //     expect!(self, Token::LParen);
-            // }
+            if type_args.is_some() {
+                // This fails with `expected (`
+                expect!(self, Token::LParen);
+            }
             debug_assert!(
                 self.input().cur() != Token::LParen,
                 "parse_new_expr() should eat paren if it exists"
             );
+            new.set_type_args(&mut self.ast, type_args.flatten());
             return Ok(Expr::New(new));
         }
         // 'CallExpr' rule contains 'MemberExpr (...)',
@@ -452,21 +400,26 @@ impl<I: Tokens> Parser<I> {
                 _ => (Callee::Expr(callee), false),
             };
             let args = self.parse_args(is_import)?;
+            let type_args = type_args.flatten();
 
             let call_expr = match callee {
-                Callee::Expr(e) if unwrap_ts_non_null(e).is_opt_chain() => {
-                    let base = self.ast.opt_chain_base_opt_call(self.span(start), e, args);
+                Callee::Expr(e) if unwrap_ts_non_null(&self.ast, e).is_opt_chain() => {
+                    let base =
+                        self.ast
+                            .opt_chain_base_opt_call(self.span(start), e, args, type_args);
                     self.ast.expr_opt_chain_expr(self.span(start), false, base)
                 }
-                _ => self.ast.expr_call_expr(self.span(start), callee, args),
+                _ => self
+                    .ast
+                    .expr_call_expr(self.span(start), callee, args, type_args),
             };
 
             return self.parse_subscripts(Callee::Expr(call_expr), false, false);
         }
-        // if type_args.is_some() {
-        //     // This fails
-        //     expect!(self, Token::LParen);
-        // }
+        if type_args.is_some() {
+            // This fails
+            expect!(self, Token::LParen);
+        }
 
         // This is parsed using production 'NewExpression', which contains
         // 'MemberExpression'
@@ -512,13 +465,6 @@ impl<I: Tokens> Parser<I> {
         Ok(self.ast.expr_array_lit(span, elems))
     }
 
-    #[allow(unused)]
-    fn at_possible_async(&mut self, expr: Expr) -> bool {
-        // TODO(kdy1): !this.state.containsEsc &&
-        self.state().potential_arrow_start == Some(expr.span_lo(&self.ast))
-            && expr.is_ident_ref_to(&self.ast, "async")
-    }
-
     fn parse_yield_expr(&mut self) -> PResult<Expr> {
         let start = self.input().cur_pos();
         self.assert_and_bump(Token::Yield);
@@ -585,10 +531,10 @@ impl<I: Tokens> Parser<I> {
         Ok((exprs, quasis))
     }
 
-    fn parse_tagged_tpl(
+    pub(crate) fn parse_tagged_tpl(
         &mut self,
         tag: Expr,
-        // type_params: Option<Box<TsTypeParamInstantiation>>,
+        type_params: Option<TsTypeParamInstantiation>,
     ) -> PResult<TaggedTpl> {
         let tagged_tpl_start = tag.span_lo(&self.ast);
         trace_cur!(self, parse_tagged_tpl);
@@ -606,7 +552,7 @@ impl<I: Tokens> Parser<I> {
             self.emit_err(span, SyntaxError::TaggedTplInOptChain);
         }
 
-        Ok(self.ast.tagged_tpl(span, tag, tpl))
+        Ok(self.ast.tagged_tpl(span, tag, type_params, tpl))
     }
 
     pub(super) fn parse_no_substitution_template_literal(
@@ -643,7 +589,7 @@ impl<I: Tokens> Parser<I> {
         Ok(self.ast.tpl(self.span(start), exprs, quasis))
     }
 
-    fn parse_template_head(&mut self, is_tagged_tpl: bool) -> PResult<TplElement> {
+    pub(crate) fn parse_template_head(&mut self, is_tagged_tpl: bool) -> PResult<TplElement> {
         let cur = self.input().cur();
         let token_span = self.input.cur_span();
         debug_assert!(matches!(cur, Token::TemplateHead));
@@ -732,93 +678,6 @@ impl<I: Tokens> Parser<I> {
         Ok(self.ast.tpl_element(span, tail, cooked, raw))
     }
 
-    // fn parse_tpl_ty_elements(&mut self) -> PResult<(Vec<Box<TsType>>, Vec<TplElement>)> {
-    //     trace_cur!(self, parse_tpl_elements);
-
-    //     let mut tys = Vec::new();
-    //     let cur_elem = self.parse_template_head(false)?;
-    //     let mut is_tail = cur_elem.tail(self.ast);
-    //     let mut quasis = vec![cur_elem];
-
-    //     while !is_tail {
-    //         tys.push(self.parse_ts_type()?);
-    //         let elem = self.parse_tpl_element(false)?;
-    //         is_tail = elem.tail(&self.ast);
-    //         quasis.push(elem);
-    //     }
-    //     Ok((tys, quasis))
-    // }
-
-    // fn parse_no_substitution_template_ty(&mut self) -> PResult<TsTplLitType> {
-    //     let start = self.input.cur_pos();
-    //     let cur = self.input.cur();
-    //     debug_assert!(matches!(cur, Token::NoSubstitutionTemplateLiteral));
-
-    //     let (cooked, raw) = cur.take_template(self.input_mut());
-    //     let (raw, cooked) = match cooked {
-    //         Ok(cooked) => (raw, Some(cooked)),
-    //         Err(_) => (raw, None),
-    //     };
-    //     self.bump();
-    //     let pos = self.input.prev_span().hi;
-    //     debug_assert!(start.0 <= pos.0);
-    //     let span = Span::new_with_checked(start, pos);
-    //     Ok(TsTplLitType {
-    //         span,
-    //         types: vec![],
-    //         quasis: vec![TplElement {
-    //             span: {
-    //                 debug_assert!(start.0 <= pos.0 - 2);
-    //                 // `____`
-    //                 // `start.0 + 1` means skip the first backtick
-    //                 // `pos.0 - 1` means skip the last backtick
-    //                 Span::new_with_checked(
-    //                     BytePos::from_u32(start.0 + 1),
-    //                     BytePos::from_u32(pos.0 - 1),
-    //                 )
-    //             },
-    //             tail: true,
-    //             raw,
-    //             cooked,
-    //         }],
-    //     })
-    // }
-
-    // fn parse_tpl_ty(&mut self) -> PResult<TsTplLitType> {
-    //     trace_cur!(self, parse_tpl_ty);
-    //     debug_assert!(matches!(self.input.cur(), Token::TemplateHead));
-
-    //     let start = self.cur_pos();
-
-    //     let (types, quasis) = self.parse_tpl_ty_elements()?;
-
-    //     let _ = self.input.cur();
-
-    //     Ok(TsTplLitType {
-    //         span: self.span(start),
-    //         types,
-    //         quasis,
-    //     })
-    // }
-
-    // pub(super) fn parse_tagged_tpl_ty(&mut self) -> PResult<TsLitType> {
-    //     let start = self.cur_pos();
-    //     debug_assert!(self.input().syntax().typescript());
-    //     trace_cur!(self, parse_tagged_tpl);
-    //     let tpl_ty = if self.input_mut().is(Token::NoSubstitutionTemplateLiteral) {
-    //         self.parse_no_substitution_template_ty()
-    //     } else {
-    //         self.parse_tpl_ty()
-    //     };
-    //     tpl_ty.map(|tpl_ty| {
-    //         let lit = TsLit::Tpl(tpl_ty);
-    //         TsLitType {
-    //             span: self.span(start),
-    //             lit,
-    //         }
-    //     })
-    // }
-
     pub(crate) fn parse_str_lit(&mut self) -> Str {
         debug_assert!(self.input().cur() == Token::Str);
         let token_and_span = self.input().get_cur();
@@ -1036,105 +895,11 @@ impl<I: Tokens> Parser<I> {
     ) -> PResult<(Expr, bool)> {
         trace_cur!(self, parse_subscript);
 
-        // if self.input().syntax().typescript() {
-        //     if !self.input().had_line_break_before_cur() && self.input().is(Token::Bang) {
-        //         self.assert_and_bump(Token::Bang);
-
-        //         let expr = Box::new(Expr::TsNonNull(TsNonNullExpr {
-        //             span: self.span(start),
-        //             expr: callee,
-        //         }));
-
-        //         return Ok((expr, true));
-        //     }
-
-        //     if self.input().is(Token::Lt) {
-        //         // tsTryParseAndCatch is expensive, so avoid if not necessary.
-        //         // There are number of things we are going to "maybe" parse, like type arguments
-        //         // on tagged template expressions. If any of them fail, walk it back and
-        //         // continue.
-
-        //         let result = None;
-        //         let result = self.do_inside_of_context(Context::ShouldNotLexLtOrGtAsType, |p| {
-        //             p.try_parse_ts(|p| {
-        //                 if !no_call && p.at_possible_async(&callee) {
-        //                     // Almost certainly this is a generic async function `async <T>() =>
-        //                     // ... But it might be a call with a
-        //                     // type argument `async<T>();`
-        //                     let async_arrow_fn = p.try_parse_ts_generic_async_arrow_fn(start)?;
-        //                     if let Some(async_arrow_fn) = async_arrow_fn {
-        //                         return Ok(Some((async_arrow_fn.into(), true)));
-        //                     }
-        //                 }
-
-        //                 let type_args = p.parse_ts_type_args()?;
-        //                 p.assert_and_bump(Token::Gt);
-        //                 let cur = p.input().cur();
-
-        //                 if !no_call && cur == Token::LParen {
-        //                     // possibleAsync always false here, because we would have handled it
-        //                     // above. (won't be any undefined arguments)
-        //                     let args = p.parse_args(false)?;
-
-        //                     let expr = if callee.is_opt_chain() {
-        //                         Expr::OptChain(OptChainExpr {
-        //                             span: p.span(start),
-        //                             base: Box::new(OptChainBase::Call(OptCall {
-        //                                 span: p.span(start),
-        //                                 callee: callee.take(),
-        //                                 type_args: Some(type_args),
-        //                                 args,
-        //                                 ..Default::default()
-        //                             })),
-        //                             optional: false,
-        //                         })
-        //                     } else {
-        //                         Expr::Call(CallExpr {
-        //                             span: p.span(start),
-        //                             callee: Callee::Expr(callee.take()),
-        //                             type_args: Some(type_args),
-        //                             args,
-        //                             ..Default::default()
-        //                         })
-        //                     };
-
-        //                     Ok(Some((Box::new(expr), true)))
-        //                 } else if matches!(
-        //                     cur,
-        //                     Token::NoSubstitutionTemplateLiteral
-        //                         | Token::TemplateHead
-        //                         | Token::BackQuote
-        //                 ) {
-        //                     p.parse_tagged_tpl(callee.take(), Some(type_args))
-        //                         .map(|expr| (expr.into(), true))
-        //                         .map(Some)
-        //                 } else if matches!(cur, Token::Eq | Token::As | Token::Satisfies) {
-        //                     let expr = Expr::TsInstantiation(TsInstantiation {
-        //                         span: p.span(start),
-        //                         expr: callee.take(),
-        //                         type_args,
-        //                     });
-        //                     Ok(Some((Box::new(expr), false)))
-        //                 } else if no_call {
-        //                     unexpected!(p, "`")
-        //                 } else {
-        //                     unexpected!(p, "( or `")
-        //                 }
-        //             })
-        //         });
-
-        //         if let Some(expr) = result {
-        //             return Ok(expr);
-        //         }
-        //     }
-        // }
-
-        // let ts_instantiation = None;
-        // let ts_instantiation = if self.syntax().typescript() && self.input().is(Token::Lt) {
-        //     self.try_parse_ts_type_args()
-        // } else {
-        //     None
-        // };
+        if let Some(ret) = self.try_parse_ts_subscript(start, callee, no_call)? {
+            return Ok(ret);
+        }
+
+        let ts_instantiation = self.parse_ts_instantiation_args()?;
 
         let question_dot_token = if self.input().is(Token::QuestionMark)
             && peek!(self).is_some_and(|peek| peek == Token::Dot)
@@ -1165,14 +930,9 @@ impl<I: Tokens> Parser<I> {
                 prop,
             );
 
-            // let type_args = None;
-            // let type_args = if self.syntax().typescript() && self.input().is(Token::Lt) {
-            //     self.try_parse_ts_type_args()
-            // } else {
-            //     None
-            // };
+            let type_args = self.parse_ts_instantiation_args()?;
 
-            let is_opt_chain = unwrap_ts_non_null(callee).is_opt_chain();
+            let is_opt_chain = unwrap_ts_non_null(&self.ast, callee).is_opt_chain();
             let expr = self
                 .ast
                 .member_expr(span, callee, MemberProp::Computed(prop));
@@ -1183,43 +943,36 @@ impl<I: Tokens> Parser<I> {
                 Expr::Member(expr)
             };
 
-            // let expr = if let Some(type_args) = type_args {
-            //     Expr::TsInstantiation(TsInstantiation {
-            //         expr: Box::new(expr),
-            //         type_args,
-            //         span: self.span(start),
-            //     })
-            // } else {
-            //     expr
-            // };
+            let expr = if let Some(type_args) = type_args {
+                self.ast
+                    .expr_ts_instantiation(self.span(start), expr, type_args)
+            } else {
+                expr
+            };
             return Ok((expr, true));
         }
 
-        // let type_args = None;
-        // let type_args = if self.syntax().typescript() && self.input().is(Token::Lt) && question_dot
-        // {
-        //     let ret = self.parse_ts_type_args()?;
-        //     self.assert_and_bump(Token::Gt);
-        //     Some(ret)
-        // } else {
-        //     None
-        // };
-
-        // if (self.input.is(Token::LParen) && (!no_call || question_dot)) || type_args.is_some() {
-        if self.input.is(Token::LParen) && (!no_call || question_dot) {
+        let type_args = if question_dot {
+            self.parse_ts_explicit_type_args()?
+        } else {
+            None
+        };
+
+        if (self.input.is(Token::LParen) && (!no_call || question_dot)) || type_args.is_some() {
             let args = self.parse_args(false)?;
 
             let span = self.span(start);
-            return if question_dot || unwrap_ts_non_null(callee).is_opt_chain() {
-                let base = self
-                    .ast
-                    .opt_chain_base_opt_call(self.span(start), callee, args);
+            return if question_dot || unwrap_ts_non_null(&self.ast, callee).is_opt_chain() {
+                let type_args = type_args.flatten();
+                let base =
+                    self.ast
+                        .opt_chain_base_opt_call(self.span(start), callee, args, type_args);
                 let expr = self.ast.opt_chain_expr(span, question_dot, base);
                 Ok((Expr::OptChain(expr), true))
             } else {
                 let expr = self
                     .ast
-                    .call_expr(self.span(start), Callee::Expr(callee), args);
+                    .call_expr(self.span(start), Callee::Expr(callee), args, None);
                 Ok((Expr::Call(expr), true))
             };
         }
@@ -1238,15 +991,12 @@ impl<I: Tokens> Parser<I> {
             debug_assert_eq!(callee.span_lo(&self.ast), span.lo());
             debug_assert_eq!(prop.span_hi(&self.ast), span.hi());
 
-            // let type_args = None;
-            // let type_args = if self.syntax().typescript() && self.input().is(Token::Lt) {
-            //     self.try_parse_ts_type_args()
-            // } else {
-            //     None
-            // };
+            let type_args = self.parse_ts_instantiation_args()?;
 
             let expr = self.ast.member_expr(span, callee, prop);
-            let expr = if unwrap_ts_non_null(expr.obj(&self.ast)).is_opt_chain() || question_dot {
+            let expr = if unwrap_ts_non_null(&self.ast, expr.obj(&self.ast)).is_opt_chain()
+                || question_dot
+            {
                 self.ast.expr_opt_chain_expr(
                     self.span(start),
                     question_dot,
@@ -1256,30 +1006,22 @@ impl<I: Tokens> Parser<I> {
                 Expr::Member(expr)
             };
 
-            // let expr = if let Some(type_args) = type_args {
-            //     Expr::TsInstantiation(TsInstantiation {
-            //         expr: Box::new(expr),
-            //         type_args,
-            //         span: self.span(start),
-            //     })
-            // } else {
-            //     expr
-            // };
+            let expr = if let Some(type_args) = type_args {
+                self.ast
+                    .expr_ts_instantiation(self.span(start), expr, type_args)
+            } else {
+                expr
+            };
 
             return Ok((expr, true));
         }
 
-        let expr = callee;
-        // let expr = if let Some(type_args) = ts_instantiation {
-        //     TsInstantiation {
-        //         expr: callee,
-        //         type_args,
-        //         span: self.span(start),
-        //     }
-        //     .into()
-        // } else {
-        //     callee
-        // };
+        let expr = if let Some(type_args) = ts_instantiation {
+            self.ast
+                .expr_ts_instantiation(self.span(start), callee, type_args)
+        } else {
+            callee
+        };
 
         // MemberExpression[?Yield, ?Await] TemplateLiteral[?Yield, ?Await, +Tagged]
         let cur = self.input().cur();
@@ -1288,7 +1030,7 @@ impl<I: Tokens> Parser<I> {
             Token::TemplateHead | Token::NoSubstitutionTemplateLiteral | Token::BackQuote
         ) {
             let tpl = self.do_outside_of_context(Context::WillExpectColonForCond, |p| {
-                p.parse_tagged_tpl(expr)
+                p.parse_tagged_tpl(expr, None)
             })?;
             return Ok((Expr::TaggedTpl(tpl), true));
         }
@@ -1332,7 +1074,7 @@ impl<I: Tokens> Parser<I> {
                 let args = self.parse_args(false)?;
                 Ok(self
                     .ast
-                    .expr_call_expr(self.span(start), Callee::Super(lhs), args))
+                    .expr_call_expr(self.span(start), Callee::Super(lhs), args, None))
             }
             Token::Dot => {
                 self.bump();
@@ -1400,7 +1142,7 @@ impl<I: Tokens> Parser<I> {
 
             let expr = self
                 .ast
-                .expr_call_expr(self.span(start), Callee::Import(lhs), args);
+                .expr_call_expr(self.span(start), Callee::Import(lhs), args, None);
             return Ok(expr);
         }
 
@@ -1502,32 +1244,14 @@ impl<I: Tokens> Parser<I> {
                 }
             }
 
-            // let type_args = None;
-            // let type_args = if self.input().syntax().typescript() && {
-            //     let cur = self.input().cur();
-            //     cur == Token::Lt || cur == Token::LShift
-            // } {
-            //     self.try_parse_ts(|p| {
-            //         let args = p.do_outside_of_context(
-            //             Context::ShouldNotLexLtOrGtAsType,
-            //             Self::parse_ts_type_args,
-            //         )?;
-            //         p.assert_and_bump(Token::Gt);
-            //         if !p.input().is(Token::LParen) {
-            //             let span = p.input().cur_span();
-            //             let cur = p.input_mut().dump_cur();
-            //             syntax_error!(p, span, SyntaxError::Expected('('.to_string(), cur))
-            //         }
-            //         Ok(Some(args))
-            //     })
-            // } else {
-            //     None
-            // };
+            let type_args = self.try_parse_ts_new_type_args()?;
 
             if !is_new_expr || self.input().is(Token::LParen) {
                 // Parsed with 'MemberExpression' production.
                 let args = self.parse_args(false)?;
-                let new_expr = self.ast.expr_new_expr(self.span(start), callee, Some(args));
+                let new_expr =
+                    self.ast
+                        .expr_new_expr(self.span(start), callee, Some(args), type_args);
 
                 // We should parse subscripts for MemberExpression.
                 // Because it's left recursive.
@@ -1536,7 +1260,9 @@ impl<I: Tokens> Parser<I> {
 
             // Parsed with 'NewExpression' production.
 
-            return Ok(self.ast.expr_new_expr(self.span(start), callee, None));
+            return Ok(self
+                .ast
+                .expr_new_expr(self.span(start), callee, None, type_args));
         }
 
         if self.input_mut().eat(Token::Super) {
@@ -1548,23 +1274,14 @@ impl<I: Tokens> Parser<I> {
         let obj = self.parse_primary_expr()?;
         return_if_arrow!(self, obj);
 
-        // let type_args = None;
-        // let type_args = if self.syntax().typescript() && self.input().is(Token::Lt) {
-        //     self.try_parse_ts_type_args()
-        // } else {
-        //     None
-        // };
-        // let obj = if let Some(type_args) = type_args {
-        //     trace_cur!(self, parse_member_expr_or_new_expr__with_type_args);
-        //     TsInstantiation {
-        //         expr: obj,
-        //         type_args,
-        //         span: self.span(start),
-        //     }
-        //     .into()
-        // } else {
-        //     obj
-        // };
+        let type_args = self.parse_ts_instantiation_args()?;
+        let obj = if let Some(type_args) = type_args {
+            trace_cur!(self, parse_member_expr_or_new_expr__with_type_args);
+            self.ast
+                .expr_ts_instantiation(self.span(start), obj, type_args)
+        } else {
+            obj
+        };
 
         self.parse_subscripts(Callee::Expr(obj), true, false)
     }
@@ -1650,48 +1367,9 @@ impl<I: Tokens> Parser<I> {
         left: Expr,
         min_prec: u8,
     ) -> PResult<(Expr, Option<u8>)> {
-        #[allow(unused)]
-        const PREC_OF_IN: u8 = 7;
-
-        // if self.input().syntax().typescript() && !self.input().had_line_break_before_cur() {
-        //     if PREC_OF_IN > min_prec && self.input().is(Token::As) {
-        //         let start = left.span_lo(&self.ast);
-        //         let expr = left;
-        //         let node = if peek!(self).is_some_and(|cur| cur == Token::Const) {
-        //             self.bump(); // as
-        //             self.bump(); // const
-        //             TsConstAssertion {
-        //                 span: self.span(start),
-        //                 expr,
-        //             }
-        //             .into()
-        //         } else {
-        //             let type_ann = self.next_then_parse_ts_type()?;
-        //             TsAsExpr {
-        //                 span: self.span(start),
-        //                 expr,
-        //                 type_ann,
-        //             }
-        //             .into()
-        //         };
-
-        //         return self.parse_bin_op_recursively_inner(node, min_prec);
-        //     } else if self.input().is(Token::Satisfies) {
-        //         let start = left.span_lo(&self.ast);
-        //         let expr = left;
-        //         let node = {
-        //             let type_ann = self.next_then_parse_ts_type()?;
-        //             TsSatisfiesExpr {
-        //                 span: self.span(start),
-        //                 expr,
-        //                 type_ann,
-        //             }
-        //             .into()
-        //         };
-
-        //         return self.parse_bin_op_recursively_inner(node, min_prec);
-        //     }
-        // }
+        if let Some(node) = self.try_parse_ts_as_or_satisfies(left, min_prec)? {
+            return self.parse_bin_op_recursively_inner(node, min_prec);
+        }
 
         // Return left on eof
         let cur = self.input().cur();
@@ -1745,7 +1423,8 @@ impl<I: Tokens> Parser<I> {
             )
         }
 
-        let right = {
+        // Right associative operators nest to the right through here.
+        let right = maybe_grow(|| -> PResult<Expr> {
             let left_of_right = self.parse_unary_expr()?;
             self.parse_bin_op_recursively(
                 left_of_right,
@@ -1755,8 +1434,8 @@ impl<I: Tokens> Parser<I> {
                 } else {
                     op.precedence()
                 },
-            )?
-        };
+            )
+        })?;
         /* this check is for all ?? operators
          * a ?? b && c for this example
          * b && c => This is considered as a logical expression in the ast tree
@@ -1889,12 +1568,12 @@ impl<I: Tokens> Parser<I> {
 
             let start = self.cur_pos();
             self.state_mut().potential_arrow_start = Some(start);
-            // let modifier_start = start;
+            let modifier_start = start;
 
-            // let has_modifier = self.eat_any_ts_modifier()?;
-            // let pat_start = self.cur_pos();
+            let has_modifier = self.eat_any_ts_modifier()?;
+            let pat_start = self.cur_pos();
 
-            let arg = {
+            let mut arg = {
                 if self.input().syntax().typescript()
                     && (self.is_ident_ref()
                         || (self.input().is(Token::DotDotDot) && self.peek_is_ident_ref()))
@@ -1919,146 +1598,119 @@ impl<I: Tokens> Parser<I> {
                         expr
                     };
 
-                    self.ast.expr_or_spread(expr.span(&self.ast), None, expr)
+                    match spread {
+                        Some(spread_span) => {
+                            let spread = self.ast.spread_dot_3_token(spread_span);
+                            self.ast.expr_or_spread(
+                                expr.span(&self.ast).with_lo(spread_span.lo()),
+                                Some(spread),
+                                expr,
+                            )
+                        }
+                        None => self.ast.expr_or_spread(expr.span(&self.ast), None, expr),
+                    }
                 } else {
                     self.allow_in_expr(|p| p.parse_expr_or_spread())?
                 }
             };
 
-            // let optional = if self.input().syntax().typescript() {
-            //     if self.input().is(Token::QuestionMark) {
-            //         if peek!(self).is_some_and(|peek| {
-            //             matches!(
-            //                 peek,
-            //                 Token::Comma | Token::Eq | Token::RParen | Token::Colon
-            //             )
-            //         }) {
-            //             self.assert_and_bump(Token::QuestionMark);
-            //             if arg.spread.is_some() {
-            //                 self.emit_err(self.input().prev_span(), SyntaxError::TS1047);
-            //             }
-            //             match *arg.expr {
-            //                 Expr::Ident(..) => {}
-            //                 _ => {
-            //                     syntax_error!(
-            //                         self,
-            //                         arg.span(&self.ast),
-            //                         SyntaxError::TsBindingPatCannotBeOptional
-            //                     )
-            //                 }
-            //             }
-            //             true
-            //         } else if let ExprOrSpread::Expr(test) = arg {
-            //             expect!(self, Token::QuestionMark);
-
-            //             let cons = self.do_inside_of_context(
-            //                 Context::InCondExpr
-            //                     .union(Context::WillExpectColonForCond)
-            //                     .union(Context::IncludeInExpr),
-            //                 Self::parse_assignment_expr,
-            //             )?;
-            //             expect!(self, Token::Colon);
-
-            //             let alt = self.do_inside_of_context(Context::InCondExpr, |p| {
-            //                 p.do_outside_of_context(
-            //                     Context::WillExpectColonForCond,
-            //                     Self::parse_assignment_expr,
-            //                 )
-            //             })?;
-
-            //             arg = ExprOrSpread::Expr(self.ast.expr_cond_expr(
-            //                 Span::new_with_checked(start, alt.span_hi(&self.ast)),
-            //                 test,
-            //                 cons,
-            //                 alt,
-            //             ));
-
-            //             false
-            //         } else {
-            //             false
-            //         }
-            //     } else {
-            //         false
-            //     }
-            // } else {
-            //     false
-            // };
-
-            // if optional || (self.input().syntax().typescript() && self.input().is(Token::Colon)) {
-            //     // TODO: `async(...args?: any[]) : any => {}`
-            //     //
-            //     // if self.input().syntax().typescript() && optional && arg.spread.is_some() {
-            //     //     self.emit_err(self.input().prev_span(), SyntaxError::TS1047)
-            //     // }
-
-            //     let mut pat = self.reparse_expr_as_pat(PatType::BindingPat, arg.expr)?;
-            //     if optional {
-            //         match pat {
-            //             Pat::Ident(ref mut i) => i.id(&self.ast).set_optional(&mut self.ast, true),
-            //             _ => unreachable!(),
-            //         }
-            //     }
-            //     if let Some(spread) = arg.as_spread_element() {
-            //         pat = self.ast.pat_rest_pat(self.span(pat_start), span, arg);
-            //     }
-            //     match pat {
-            //         Pat::Ident(BindingIdent {
-            //             id: Ident { ref mut span, .. },
-            //             ref mut type_ann,
-            //             ..
-            //         })
-            //         | Pat::Array(ArrayPat {
-            //             ref mut type_ann,
-            //             ref mut span,
-            //             ..
-            //         })
-            //         | Pat::Object(ObjectPat {
-            //             ref mut type_ann,
-            //             ref mut span,
-            //             ..
-            //         })
-            //         | Pat::Rest(RestPat {
-            //             ref mut type_ann,
-            //             ref mut span,
-            //             ..
-            //         }) => {
-            //             let new_type_ann = self.try_parse_ts_type_ann()?;
-            //             if new_type_ann.is_some() {
-            //                 *span = Span::new_with_checked(pat_start, self.input().prev_span().hi);
-            //             }
-            //             *type_ann = new_type_ann;
-            //         }
-            //         Pat::Expr(ref expr) => unreachable!("invalid pattern: Expr({:?})", expr),
-            //         Pat::Assign(..) | Pat::Invalid(..) => {
-            //             // We don't have to panic here.
-            //             // See: https://github.com/swc-project/swc/issues/1170
-            //             //
-            //             // Also, as an exact error is added to the errors while
-            //             // creating `Invalid`, we don't have to emit a new
-            //             // error.
-            //         }
-            //         #[cfg(swc_ast_unknown)]
-            //         _ => unreachable!(),
-            //     }
-
-            //     if self.input_mut().eat(Token::Eq) {
-            //         let right = self.parse_assignment_expr()?;
-            //         pat = self.ast.pat_assign_pat(self.span(pat_start), pat, right);
-            //     }
-
-            //     if has_modifier {
-            //         self.emit_err(self.span(modifier_start), SyntaxError::TS2369);
-            //     }
-
-            //     items.push(AssignTargetOrSpread::Pat(pat))
-            // } else {
-            //     if has_modifier {
-            //         self.emit_err(self.span(modifier_start), SyntaxError::TS2369);
-            //     }
-
-            //     items.push(AssignTargetOrSpread::ExprOrSpread(arg));
-            // }
-            items.push(AssignTargetOrSpread::ExprOrSpread(arg));
+            let optional = if self.input().syntax().typescript() {
+                if self.input().is(Token::QuestionMark) {
+                    if peek!(self).is_some_and(|peek| {
+                        matches!(
+                            peek,
+                            Token::Comma | Token::Eq | Token::RParen | Token::Colon
+                        )
+                    }) {
+                        self.assert_and_bump(Token::QuestionMark);
+                        if arg.spread(&self.ast).is_some() {
+                            self.emit_err(self.input().prev_span(), SyntaxError::TS1047);
+                        }
+                        match arg.expr(&self.ast) {
+                            Expr::Ident(..) => {}
+                            _ => {
+                                syntax_error!(
+                                    self,
+                                    arg.span(&self.ast),
+                                    SyntaxError::TsBindingPatCannotBeOptional
+                                )
+                            }
+                        }
+                        true
+                    } else if arg.spread(&self.ast).is_none() {
+                        expect!(self, Token::QuestionMark);
+                        let test = arg.expr(&self.ast);
+
+                        let cons = self.do_inside_of_context(
+                            Context::InCondExpr
+                                .union(Context::WillExpectColonForCond)
+                                .union(Context::IncludeInExpr),
+                            Self::parse_assignment_expr,
+                        )?;
+                        expect!(self, Token::Colon);
+
+                        let alt = self.do_inside_of_context(Context::InCondExpr, |p| {
+                            p.do_outside_of_context(
+                                Context::WillExpectColonForCond,
+                                Self::parse_assignment_expr,
+                            )
+                        })?;
+
+                        let span = Span::new_with_checked(start, alt.span_hi(&self.ast));
+                        let cond = self.ast.expr_cond_expr(span, test, cons, alt);
+                        arg = self.ast.expr_or_spread(span, None, cond);
+
+                        false
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            } else {
+                false
+            };
+
+            if optional || (self.input().syntax().typescript() && self.input().is(Token::Colon)) {
+                // TODO: `async(...args?: any[]) : any => {}`
+                //
+                // if self.input().syntax().typescript() && optional && arg.spread.is_some() {
+                //     self.emit_err(self.input().prev_span(), SyntaxError::TS1047)
+                // }
+
+                let expr = arg.expr(&self.ast);
+                let mut pat = self.reparse_expr_as_pat(PatType::BindingPat, expr)?;
+                if self.strip_ts_flag(optional) {
+                    match pat {
+                        Pat::Ident(i) => i.id(&self.ast).set_optional(&mut self.ast, true),
+                        _ => unreachable!(),
+                    }
+                }
+                if let Some(spread) = arg.spread(&self.ast) {
+                    let dot3_token = spread.span(&self.ast);
+                    pat = self
+                        .ast
+                        .pat_rest_pat(self.span(pat_start), dot3_token, pat, None);
+                }
+                self.parse_ts_type_ann_of_arrow_param(pat, pat_start)?;
+
+                if self.input_mut().eat(Token::Eq) {
+                    let right = self.parse_assignment_expr()?;
+                    pat = self.ast.pat_assign_pat(self.span(pat_start), pat, right);
+                }
+
+                if has_modifier {
+                    self.emit_err(self.span(modifier_start), SyntaxError::TS2369);
+                }
+
+                items.push(AssignTargetOrSpread::Pat(pat))
+            } else {
+                if has_modifier {
+                    self.emit_err(self.span(modifier_start), SyntaxError::TS2369);
+                }
+
+                items.push(AssignTargetOrSpread::ExprOrSpread(arg));
+            }
 
             // https://github.com/swc-project/swc/issues/433
             if self.input_mut().eat(Token::Arrow) && {
@@ -2087,7 +1739,7 @@ impl<I: Tokens> Parser<I> {
 
                 let arrow = self
                     .ast
-                    .expr_arrow_expr(span, params, body, is_async, false);
+                    .expr_arrow_expr(span, params, body, is_async, false, None, None);
                 let expr = self.ast.expr_or_spread(span, None, arrow);
                 items.push(AssignTargetOrSpread::ExprOrSpread(expr));
             }
@@ -2131,72 +1783,17 @@ impl<I: Tokens> Parser<I> {
             .iter()
             .any(|item| matches!(item, AssignTargetOrSpread::Pat(..)));
 
-        // let will_expect_colon_for_cond = self.ctx().contains(Context::WillExpectColonForCond);
         // This is slow path. We handle arrow in conditional expression.
-        // if self.syntax().typescript()
-        //     && self.ctx().contains(Context::InCondExpr)
-        //     && self.input().is(Token::Colon)
-        // {
-        //     // TODO: Remove clone
-        //     let items_ref = &paren_items;
-        //     if let Some(expr) = self.try_parse_ts(|p| {
-        //         let return_type = p.parse_ts_type_or_type_predicate_ann(Token::Colon)?;
-
-        //         expect!(p, Token::Arrow);
-
-        //         let params: Vec<Pat> =
-        //             p.parse_paren_items_as_params(items_ref.clone(), trailing_comma)?;
-
-        //         let body: Box<BlockStmtOrExpr> = p.parse_fn_block_or_expr_body(
-        //             async_span.is_some(),
-        //             false,
-        //             true,
-        //             params.is_simple_parameter_list(),
-        //         )?;
-
-        //         if will_expect_colon_for_cond && !p.input().is(Token::Colon) {
-        //             trace_cur!(p, parse_arrow_in_cond__fail);
-        //             unexpected!(p, "fail")
-        //         }
-
-        //         Ok(Some(
-        //             ArrowExpr {
-        //                 span: p.span(expr_start),
-        //                 is_async: async_span.is_some(),
-        //                 is_generator: false,
-        //                 params,
-        //                 body,
-        //                 return_type: Some(return_type),
-        //                 ..Default::default()
-        //             }
-        //             .into(),
-        //         ))
-        //     }) {
-        //         return Ok(expr);
-        //     }
-        // }
-
-        // let return_type = None;
-        // let return_type = if !self.ctx().contains(Context::WillExpectColonForCond)
-        //     && self.input().syntax().typescript()
-        //     && self.input().is(Token::Colon)
-        // {
-        //     self.try_parse_ts(|p| {
-        //         let return_type = p.parse_ts_type_or_type_predicate_ann(Token::Colon)?;
-
-        //         if !p.input().is(Token::Arrow) {
-        //             unexpected!(p, "fail")
-        //         }
-
-        //         Ok(Some(return_type))
-        //     })
-        // } else {
-        //     None
-        // };
+        if let Some(expr) =
+            self.try_parse_ts_arrow_in_cond(expr_start, async_span, &paren_items, trailing_comma)
+        {
+            return Ok(expr);
+        }
+
+        let return_type = self.try_parse_ts_arrow_return_type();
 
         // we parse arrow function at here, to handle it efficiently.
-        // if has_pattern || return_type.is_some() || self.input().is(Token::Arrow) {
-        if has_pattern || self.input().is(Token::Arrow) {
+        if has_pattern || return_type.is_some() || self.input().is(Token::Arrow) {
             if self.input().had_line_break_before_cur() {
                 syntax_error!(
                     self,
@@ -2225,6 +1822,8 @@ impl<I: Tokens> Parser<I> {
                 body,
                 async_span.is_some(),
                 false,
+                None,
+                return_type.flatten(),
             );
             if arrow_expr.body(&self.ast).is_block_stmt() && self.input().cur().is_bin_op() {
                 // ) is required
@@ -2279,6 +1878,7 @@ impl<I: Tokens> Parser<I> {
                 self.span(async_span.lo()),
                 callee,
                 expr_or_spreads,
+                None,
             ));
         }
 
@@ -2396,26 +1996,12 @@ impl<I: Tokens> Parser<I> {
                     }
 
                     let ident = p.parse_binding_ident(false)?;
-                    // if p.input().syntax().typescript()
-                    //     && self
-                    //         .ast
-                    //         .get_atom(ident.id(&self.ast).sym(&self.ast))
-                    //         .as_str()
-                    //         == "as"
-                    //     && !p.input().is(Token::Arrow)
-                    // {
-                    //     // async as type
-                    //     let type_ann = p.in_type(Self::parse_ts_type)?;
-                    //     return Ok(TsAsExpr {
-                    //         span: p.span(start),
-                    //         expr: Box::new(id.into()),
-                    //         type_ann,
-                    //     }
-                    //     .into());
-                    // }
+                    if let Some(expr) = p.try_parse_ts_async_as(start, id, ident)? {
+                        return Ok(expr);
+                    }
 
                     // async a => body
-                    let arg = Pat::Ident(p.ast.binding_ident(id.span(&p.ast), ident));
+                    let arg = Pat::Ident(p.ast.binding_ident(id.span(&p.ast), ident, None));
                     let params = p.scratch_start(|p, params| {
                         params.push(p, arg);
                         Ok(())
@@ -2429,9 +2015,15 @@ impl<I: Tokens> Parser<I> {
                         params.is_simple_parameter_list(&p.ast),
                     )?;
 
-                    return Ok(p
-                        .ast
-                        .expr_arrow_expr(p.span(start), params, body, true, false));
+                    return Ok(p.ast.expr_arrow_expr(
+                        p.span(start),
+                        params,
+                        body,
+                        true,
+                        false,
+                        None,
+                        None,
+                    ));
                 } else if p.input_mut().eat(Token::Arrow) {
                     if p.ctx().contains(Context::Strict) && id.is_reserved_in_strict_bind(&p.ast) {
                         p.emit_strict_mode_err(
@@ -2441,7 +2033,7 @@ impl<I: Tokens> Parser<I> {
                     }
 
                     let params = p.scratch_start(|p, params| {
-                        let pat = Pat::Ident(p.ast.binding_ident(id.span(&p.ast), id));
+                        let pat = Pat::Ident(p.ast.binding_ident(id.span(&p.ast), id, None));
                         params.push(p, pat);
                         Ok(())
                     })?;
@@ -2453,9 +2045,15 @@ impl<I: Tokens> Parser<I> {
                         params.is_simple_parameter_list(&p.ast),
                     )?;
 
-                    return Ok(p
-                        .ast
-                        .expr_arrow_expr(p.span(start), params, body, false, false));
+                    return Ok(p.ast.expr_arrow_expr(
+                        p.span(start),
+                        params,
+                        body,
+                        false,
+                        false,
+                        None,
+                        None,
+                    ));
                 }
             }
 
@@ -2541,19 +2139,9 @@ impl<I: Tokens> Parser<I> {
             return Some(self.parse_async_fn_expr());
         }
 
-        // if can_be_arrow
-        //     && self.input().syntax().typescript()
-        //     && peek!(self).is_some_and(|peek| peek == Token::Lt)
-        // {
-        //     // try parsing `async<T>() => {}`
-        //     if let Some(res) = self.try_parse_ts(|p| {
-        //         let start = p.cur_pos();
-        //         p.assert_and_bump(Token::Async);
-        //         p.try_parse_ts_generic_async_arrow_fn(start)
-        //     }) {
-        //         return Some(Ok(res.into()));
-        //     }
-        // }
+        if can_be_arrow && let Some(arrow) = self.try_parse_ts_generic_async_arrow() {
+            return Some(Ok(arrow));
+        }
 
         if can_be_arrow
             && peek!(self).is_some_and(|peek| peek == Token::LParen)
@@ -2605,10 +2193,10 @@ impl<I: Tokens> Parser<I> {
     }
 }
 
-fn unwrap_ts_non_null(expr: Expr) -> Expr {
-    // while let Expr::TsNonNull(ts_non_null) = expr {
-    //     expr = &ts_non_null.expr;
-    // }
+fn unwrap_ts_non_null(ast: &Ast, mut expr: Expr) -> Expr {
+    while let Expr::TsNonNull(ts_non_null) = expr {
+        expr = ts_non_null.expr(ast);
+    }
 
     expr
 }
@@ -1,5 +1,5 @@
 mod class_and_fn;
-mod expr;
+pub(crate) mod expr;
 mod ident;
 mod module_item;
 mod object;
@@ -335,81 +335,9 @@ impl<I: Tokens> Parser<I> {
             return Err(self.eof_error());
         }
 
-        // let after_export_start = self.cur_pos();
-
-        // "export declare" is equivalent to just "export".
-        // let declare = self.input().syntax().typescript() && self.input_mut().eat(Token::Declare);
-
-        // if declare {
-        //     // TODO: Remove
-        //     if let Some(decl) = self.try_parse_ts_declare(after_export_start, decorators.clone())? {
-        //         return Ok(ExportDecl {
-        //             span: self.span(start),
-        //             decl,
-        //         }
-        //         .into());
-        //     }
-        // }
-
-        // if self.input().syntax().typescript() {
-        //     let cur = self.input().cur();
-        //     if cur.is_word() {
-        //         let sym = cur.take_word(self.input()).unwrap();
-        //         // TODO: remove clone
-        //         if let Some(decl) = self.try_parse_ts_export_decl(decorators.clone(), sym) {
-        //             return Ok(ExportDecl {
-        //                 span: self.span(start),
-        //                 decl,
-        //             }
-        //             .into());
-        //         }
-        //     }
-
-        //     if self.input_mut().eat(Token::Import) {
-        //         let is_type_only =
-        //             self.input().is(Token::Type) && peek!(self).is_some_and(|p| p.is_word());
-
-        //         if is_type_only {
-        //             self.assert_and_bump(Token::Type);
-        //         }
-
-        //         let id = self.parse_ident_name()?;
-
-        //         // export import A = B
-        //         return self
-        //             .parse_ts_import_equals_decl(
-        //                 start,
-        //                 id.into(),
-        //                 /* is_export */ true,
-        //                 is_type_only,
-        //             )
-        //             .map(From::from);
-        //     }
-
-        //     if self.input_mut().eat(Token::Eq) {
-        //         // `export = x;`
-        //         let expr = self.parse_expr()?;
-        //         self.expect_general_semi()?;
-        //         return Ok(TsExportAssignment {
-        //             span: self.span(start),
-        //             expr,
-        //         }
-        //         .into());
-        //     }
-
-        //     if self.input_mut().eat(Token::As) {
-        //         // `export as namespace A;`
-        //         // See `parseNamespaceExportDeclaration` in TypeScript's own parser
-        //         expect!(self, Token::Namespace);
-        //         let id = self.parse_ident(false, false)?;
-        //         self.expect_general_semi()?;
-        //         return Ok(TsNamespaceExportDecl {
-        //             span: self.span(start),
-        //             id,
-        //         }
-        //         .into());
-        //     }
-        // }
+        if let Some(decl) = self.try_parse_ts_export(start, decorators)? {
+            return Ok(decl);
+        }
 
         let ns_export_specifier_start = self.cur_pos();
 
@@ -430,42 +358,8 @@ impl<I: Tokens> Parser<I> {
                 decorators = after_decorators;
             }
 
-            if self.input().syntax().typescript() {
-                if self.input().is(Token::Abstract)
-                    && peek!(self).is_some_and(|cur| cur == Token::Class)
-                    && !self.input_mut().has_linebreak_between_cur_and_peeked()
-                {
-                    let class_start = self.cur_pos();
-                    self.assert_and_bump(Token::Abstract);
-                    let cur = self.input().cur();
-                    if cur == Token::Error {
-                        let err = self.input_mut().expect_error_token_and_bump();
-                        return Err(err);
-                    }
-
-                    return self
-                        .parse_default_class(start, class_start, decorators, true)
-                        .map(ModuleDecl::ExportDefaultDecl);
-                }
-                if self.input().is(Token::Abstract)
-                    && peek!(self).is_some_and(|cur| cur == Token::Interface)
-                {
-                    self.emit_err(self.input().cur_span(), SyntaxError::TS1242);
-                    self.assert_and_bump(Token::Abstract);
-                }
-
-                // if self.input().is(Token::Interface) {
-                //     let interface_start = self.cur_pos();
-                //     self.assert_and_bump(Token::Interface);
-                //     let decl = self
-                //         .parse_ts_interface_decl(interface_start)
-                //         .map(DefaultDecl::from)?;
-                //     return Ok(ExportDefaultDecl {
-                //         span: self.span(start),
-                //         decl,
-                //     }
-                //     .into());
-                // }
+            if let Some(decl) = self.try_parse_ts_export_default(start, decorators)? {
+                return Ok(decl);
             }
 
             if self.input().is(Token::Class) {
@@ -510,6 +404,12 @@ impl<I: Tokens> Parser<I> {
             decorators = after_decorators;
         }
 
+        if !type_only && let Some(decl) = self.try_parse_ts_const_enum(self.cur_pos())? {
+            return Ok(self
+                .ast
+                .module_decl_export_decl(self.span(start), Decl::TsEnum(decl)));
+        }
+
         let decl = if !type_only && self.input().is(Token::Class) {
             let class_start = self.cur_pos();
             self.parse_class_decl(start, class_start, decorators, false)?
@@ -521,24 +421,6 @@ impl<I: Tokens> Parser<I> {
             self.parse_async_fn_decl(decorators)?
         } else if !type_only && self.input().is(Token::Function) {
             self.parse_fn_decl(decorators)?
-        // } else if !type_only
-        //     && self.input().syntax().typescript()
-        //     && self.input().is(Token::Const)
-        //     && peek!(self).is_some_and(|cur| cur == Token::Enum)
-        // {
-        //     let enum_start = self.cur_pos();
-        //     self.assert_and_bump(Token::Const);
-        //     self.assert_and_bump(Token::Enum);
-        //     return self
-        //         .parse_ts_enum_decl(enum_start, /* is_const */ true)
-        //         .map(Decl::from)
-        //         .map(|decl| {
-        //             ExportDecl {
-        //                 span: self.span(start),
-        //                 decl,
-        //             }
-        //             .into()
-        //         });
         } else if !type_only
             && (self.input().is(Token::Var)
                 || self.input().is(Token::Const)
@@ -642,7 +524,9 @@ impl<I: Tokens> Parser<I> {
 
                 while !p.input().is(Token::RBrace) {
                     let specifier = p.parse_named_export_specifier(type_only)?;
-                    specifiers.push(p, ExportSpecifier::Named(specifier));
+                    if !(p.syntax().strip_types() && specifier.is_type_only(&p.ast)) {
+                        specifiers.push(p, ExportSpecifier::Named(specifier));
+                    }
 
                     if p.input().is(Token::RBrace) {
                         break;
@@ -797,6 +681,7 @@ impl<I: Tokens> Parser<I> {
 
         let mut type_only = false;
         let mut phase = ImportPhase::Evaluation;
+        let mut import_equals = None;
         let specifiers = self.scratch_start(|p, specifiers| {
             'import_maybe_ident: {
                 if p.is_ident_ref() {
@@ -828,12 +713,10 @@ impl<I: Tokens> Parser<I> {
                         }
                     }
 
-                    // if p.input().syntax().typescript() && p.input().is(Token::Eq) {
-                    //     return p
-                    //         .parse_ts_import_equals_decl(start, local, false, type_only)
-                    //         .map(ModuleDecl::from)
-                    //         .map(ModuleItem::from);
-                    // }
+                    if let Some(decl) = p.try_parse_ts_import_equals(start, local, type_only)? {
+                        import_equals = Some(decl);
+                        return Ok(());
+                    }
 
                     if is_source || is_defer {
                         let new_phase = if is_source {
@@ -889,7 +772,13 @@ impl<I: Tokens> Parser<I> {
                 } else if phase == ImportPhase::Evaluation && p.input_mut().eat(Token::LBrace) {
                     while !p.input().is(Token::RBrace) {
                         let specifier = p.parse_import_specifier(type_only)?;
-                        specifiers.push(p, specifier);
+                        let is_type_only = matches!(
+                            specifier,
+                            ImportSpecifier::Named(named) if named.is_type_only(&p.ast)
+                        );
+                        if !(p.syntax().strip_types() && is_type_only) {
+                            specifiers.push(p, specifier);
+                        }
 
                         if p.input().is(Token::RBrace) {
                             break;
@@ -904,6 +793,10 @@ impl<I: Tokens> Parser<I> {
             Ok(())
         })?;
 
+        if let Some(decl) = import_equals {
+            return Ok(ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(decl)));
+        }
+
         let src = {
             expect!(self, Token::From);
             if self.input().cur() == Token::Str {
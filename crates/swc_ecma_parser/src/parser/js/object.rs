@@ -55,7 +55,7 @@ impl<I: Tokens> Parser<I> {
 
             return Ok(self
                 .ast
-                .object_pat_prop_rest_pat(self.span(start), dot3_token, arg));
+                .object_pat_prop_rest_pat(self.span(start), dot3_token, arg, None));
         }
 
         let key = self.parse_prop_name()?;
@@ -92,7 +92,9 @@ impl<I: Tokens> Parser<I> {
         let key_ident = self
             .ast
             .ident(key.span(&self.ast), key.sym(&self.ast), false);
-        let key_ident = self.ast.binding_ident(key_ident.span(&self.ast), key_ident);
+        let key_ident = self
+            .ast
+            .binding_ident(key_ident.span(&self.ast), key_ident, None);
         Ok(self
             .ast
             .object_pat_prop_assign_pat_prop(self.span(start), key_ident, value))
@@ -133,7 +135,7 @@ impl<I: Tokens> Parser<I> {
         let optional = (self.input().syntax().dts() || self.ctx().contains(Context::InDeclare))
             && self.input_mut().eat(Token::QuestionMark);
 
-        Ok(self.ast.pat_object_pat(span, props, optional))
+        Ok(self.ast.pat_object_pat(span, props, optional, None))
     }
 
     pub(super) fn parse_object_pat(&mut self) -> PResult<Pat> {
@@ -346,8 +348,12 @@ impl<I: Tokens> Parser<I> {
                                 }
 
                                 let body = function.body(&p.ast);
-                                p.ast
-                                    .prop_or_spread_prop_getter_prop(p.span(start), key, body)
+                                p.ast.prop_or_spread_prop_getter_prop(
+                                    p.span(start),
+                                    key,
+                                    None,
+                                    body,
+                                )
                             });
                     }
 
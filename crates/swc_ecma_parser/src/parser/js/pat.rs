@@ -132,7 +132,7 @@ impl<I: Tokens> Parser<I> {
                         return Ok(Pat::Expr(expr));
                     }
                     Expr::Ident(i) => {
-                        let i = self.ast.binding_ident(i.span(&self.ast), i);
+                        let i = self.ast.binding_ident(i.span(&self.ast), i, None);
                         return Ok(Pat::Ident(i));
                     }
                     _ => {
@@ -159,17 +159,23 @@ impl<I: Tokens> Parser<I> {
                 | Expr::Fn(..)
                 | Expr::Class(..)
                 | Expr::Paren(..)
-                // | Expr::TsAs(..) 
-                | Expr::Tpl(..)=> {
-                    if !expr.is_valid_simple_assignment_target(&self.ast, self.ctx().contains(Context::Strict))
-                    {
+                | Expr::Tpl(..)
+                | Expr::TsAs(..)
+                | Expr::TsNonNull(..)
+                | Expr::TsTypeAssertion(..)
+                | Expr::TsInstantiation(..)
+                | Expr::TsSatisfies(..) => {
+                    if !expr.is_valid_simple_assignment_target(
+                        &self.ast,
+                        self.ctx().contains(Context::Strict),
+                    ) {
                         self.emit_err(span, SyntaxError::NotSimpleAssign)
                     }
                     match expr {
                         Expr::Ident(i) => {
-                            let i = self.ast.binding_ident(i.span(&self.ast), i);
-                            return Ok(Pat::Ident(i))
-                        },
+                            let i = self.ast.binding_ident(i.span(&self.ast), i, None);
+                            return Ok(Pat::Ident(i));
+                        }
                         _ => {
                             return Ok(Pat::Expr(expr));
                         }
@@ -208,6 +214,11 @@ impl<I: Tokens> Parser<I> {
                             SimpleAssignTarget::OptChain(opt_chain_expr) => {
                                 Expr::OptChain(opt_chain_expr)
                             }
+                            SimpleAssignTarget::TsAs(e) => Expr::TsAs(e),
+                            SimpleAssignTarget::TsSatisfies(e) => Expr::TsSatisfies(e),
+                            SimpleAssignTarget::TsNonNull(e) => Expr::TsNonNull(e),
+                            SimpleAssignTarget::TsTypeAssertion(e) => Expr::TsTypeAssertion(e),
+                            SimpleAssignTarget::TsInstantiation(e) => Expr::TsInstantiation(e),
                             SimpleAssignTarget::Invalid(invalid) => Expr::Invalid(invalid),
                         };
                         self.reparse_expr_as_pat(pat_ty, left)?
@@ -236,7 +247,8 @@ impl<I: Tokens> Parser<I> {
                         let prop = match prop {
                             PropOrSpread::Prop(prop) => match prop {
                                 Prop::Shorthand(id) => {
-                                    let binding_ident = p.ast.binding_ident(id.span(&p.ast), id);
+                                    let binding_ident =
+                                        p.ast.binding_ident(id.span(&p.ast), id, None);
                                     p.ast.object_pat_prop_assign_pat_prop(
                                         id.span(&p.ast),
                                         binding_ident,
@@ -256,7 +268,7 @@ impl<I: Tokens> Parser<I> {
                                 }
                                 Prop::Assign(assign_prop) => {
                                     let id = assign_prop.key(&p.ast);
-                                    let key = p.ast.binding_ident(id.span(&p.ast), id);
+                                    let key = p.ast.binding_ident(id.span(&p.ast), id, None);
                                     let value = assign_prop.value(&p.ast);
                                     p.ast
                                         .object_pat_prop_assign_pat_prop(span, key, Some(value))
@@ -278,7 +290,7 @@ impl<I: Tokens> Parser<I> {
                                 let element_pat_ty = pat_ty.element();
                                 let pat = if let PatType::BindingElement = element_pat_ty {
                                     if let Expr::Ident(i) = expr {
-                                        Pat::Ident(p.ast.binding_ident(i.span(&p.ast), i))
+                                        Pat::Ident(p.ast.binding_ident(i.span(&p.ast), i, None))
                                     } else {
                                         p.emit_err(span, SyntaxError::DotsWithoutIdentifier);
                                         p.ast.pat_invalid(span)
@@ -289,7 +301,7 @@ impl<I: Tokens> Parser<I> {
                                 if let Pat::Assign(_) = pat {
                                     p.emit_err(span, SyntaxError::TS1048)
                                 };
-                                p.ast.object_pat_prop_rest_pat(span, dot_3_token, pat)
+                                p.ast.object_pat_prop_rest_pat(span, dot_3_token, pat, None)
                             }
                             #[cfg(swc_ast_unknown)]
                             _ => unreachable!(),
@@ -301,15 +313,19 @@ impl<I: Tokens> Parser<I> {
                     Ok(())
                 })?;
 
-                Ok(self.ast.pat_object_pat(object_span, props, false))
+                Ok(self.ast.pat_object_pat(object_span, props, false, None))
             }
-            Expr::Ident(ident) => Ok(Pat::Ident(
-                self.ast.binding_ident(ident.span(&self.ast), ident),
-            )),
+            Expr::Ident(ident) => Ok(Pat::Ident(self.ast.binding_ident(
+                ident.span(&self.ast),
+                ident,
+                None,
+            ))),
             Expr::Array(array) => {
                 let mut exprs = array.elems(&self.ast);
                 if exprs.is_empty() {
-                    return Ok(self.ast.pat_array_pat(span, TypedSubRange::empty(), false));
+                    return Ok(self
+                        .ast
+                        .pat_array_pat(span, TypedSubRange::empty(), false, None));
                 }
                 // Trailing comma may exist. We should remove those commas.
                 let count_of_trailing_comma = exprs
@@ -371,7 +387,9 @@ impl<I: Tokens> Parser<I> {
                                     let spread_span = spread.span(&self.ast);
 
                                     Some(self.reparse_expr_as_pat(pat_ty.element(), expr).map(
-                                        |pat| self.ast.pat_rest_pat(expr_span, spread_span, pat),
+                                        |pat| {
+                                            self.ast.pat_rest_pat(expr_span, spread_span, pat, None)
+                                        },
                                     )?)
                                 }
                                 None => {
@@ -387,7 +405,7 @@ impl<I: Tokens> Parser<I> {
                 }
 
                 let params = self.ast.add_typed_sub_range(params);
-                Ok(self.ast.pat_array_pat(span, params, false))
+                Ok(self.ast.pat_array_pat(span, params, false, None))
             }
 
             // Invalid patterns.
@@ -435,9 +453,11 @@ impl<I: Tokens> Parser<I> {
         let cur = self.input().cur();
         if cur.is_word() {
             let ident = self.parse_binding_ident(disallow_let)?;
-            Ok(Pat::Ident(
-                self.ast.binding_ident(ident.span(&self.ast), ident),
-            ))
+            Ok(Pat::Ident(self.ast.binding_ident(
+                ident.span(&self.ast),
+                ident,
+                None,
+            )))
         } else if cur == Token::LBracket {
             self.parse_array_binding_pat()
         } else if cur == Token::LBrace {
@@ -479,7 +499,7 @@ impl<I: Tokens> Parser<I> {
                 let pat = self.parse_binding_pat_or_ident(false)?;
                 rest_span = self.span(start);
 
-                let pat = self.ast.pat_rest_pat(rest_span, dot3_token, pat);
+                let pat = self.ast.pat_rest_pat(rest_span, dot3_token, pat, None);
                 elems.push(Some(pat));
             } else {
                 elems.push(Some(self.parse_binding_element()?));
@@ -498,7 +518,9 @@ impl<I: Tokens> Parser<I> {
             && self.input_mut().eat(Token::QuestionMark);
 
         let elems = self.ast.add_typed_sub_range(elems);
-        Ok(self.ast.pat_array_pat(self.span(start), elems, optional))
+        Ok(self
+            .ast
+            .pat_array_pat(self.span(start), elems, optional, None))
     }
 
     /// spec: 'FormalParameter'
@@ -507,84 +529,11 @@ impl<I: Tokens> Parser<I> {
     fn parse_formal_param_pat(&mut self) -> PResult<Pat> {
         let start = self.cur_pos();
 
-        // let has_modifier = self.eat_any_ts_modifier()?;
+        let has_modifier = self.eat_any_ts_modifier()?;
 
-        // let pat_start = self.cur_pos();
+        let pat_start = self.cur_pos();
         let pat = self.parse_binding_element()?;
-        let opt = false;
-
-        // if self.input().syntax().typescript() {
-        //     if self.input_mut().eat(Token::QuestionMark) {
-        //         match pat {
-        //             Pat::Ident(BindingIdent {
-        //                 id:
-        //                     Ident {
-        //                         ref mut optional, ..
-        //                     },
-        //                 ..
-        //             })
-        //             | Pat::Array(ArrayPat {
-        //                 ref mut optional, ..
-        //             })
-        //             | Pat::Object(ObjectPat {
-        //                 ref mut optional, ..
-        //             }) => {
-        //                 *optional = true;
-        //                 opt = true;
-        //             }
-        //             _ if self.input().syntax().dts() || self.ctx().contains(Context::InDeclare) => {
-        //             }
-        //             _ => {
-        //                 syntax_error!(
-        //                     self,
-        //                     self.input().prev_span(),
-        //                     SyntaxError::TsBindingPatCannotBeOptional
-        //                 );
-        //             }
-        //         }
-        //     }
-
-        //     match pat {
-        //         Pat::Array(ArrayPat {
-        //             ref mut type_ann,
-        //             ref mut span,
-        //             ..
-        //         })
-        //         | Pat::Object(ObjectPat {
-        //             ref mut type_ann,
-        //             ref mut span,
-        //             ..
-        //         })
-        //         | Pat::Rest(RestPat {
-        //             ref mut type_ann,
-        //             ref mut span,
-        //             ..
-        //         }) => {
-        //             let new_type_ann = self.try_parse_ts_type_ann()?;
-        //             if new_type_ann.is_some() {
-        //                 *span = Span::new_with_checked(pat_start, self.input().prev_span().hi);
-        //             }
-        //             *type_ann = new_type_ann;
-        //         }
-
-        //         Pat::Ident(BindingIdent {
-        //             ref mut type_ann, ..
-        //         }) => {
-        //             let new_type_ann = self.try_parse_ts_type_ann()?;
-        //             *type_ann = new_type_ann;
-        //         }
-
-        //         Pat::Assign(AssignPat { ref mut span, .. }) => {
-        //             if (self.try_parse_ts_type_ann()?).is_some() {
-        //                 *span = Span::new_with_checked(pat_start, self.input().prev_span().hi);
-        //                 self.emit_err(*span, SyntaxError::TSTypeAnnotationAfterAssign);
-        //             }
-        //         }
-        //         Pat::Invalid(..) => {}
-        //         // _ => unreachable!("invalid syntax: Pat: {:?}", pat),
-        //         _ => unreachable!("invalid syntax: Pat: {:?}", "pat"),
-        //     }
-        // }
+        let (pat, opt) = self.parse_ts_formal_param_suffix(pat_start, pat)?;
 
         let pat = if self.input_mut().eat(Token::Eq) {
             // `=` cannot follow optional parameter.
@@ -602,10 +551,10 @@ impl<I: Tokens> Parser<I> {
             pat
         };
 
-        // if has_modifier {
-        //     self.emit_err(self.span(start), SyntaxError::TS2369);
-        //     return Ok(pat);
-        // }
+        if has_modifier {
+            self.emit_err(self.span(start), SyntaxError::TS2369);
+            return Ok(pat);
+        }
 
         Ok(pat)
     }
@@ -615,42 +564,33 @@ impl<I: Tokens> Parser<I> {
         param_start: BytePos,
         decorators: TypedSubRange<Decorator>,
     ) -> PResult<ParamOrTsParamProp> {
-        // let (accessibility, is_override, readonly) = if self.input().syntax().typescript() {
-        //     let accessibility = self.parse_access_modifier()?;
-        //     (
-        //         accessibility,
-        //         self.parse_ts_modifier(&["override"], false)?.is_some(),
-        //         self.parse_ts_modifier(&["readonly"], false)?.is_some(),
-        //     )
-        // } else {
-        //     (None, false, false)
-        // };
-        // if accessibility.is_none() && !is_override && !readonly {
-        //     let pat = self.parse_formal_param_pat()?;
-        //     Ok(ParamOrTsParamProp::Param(Param {
-        //         span: self.span(param_start),
-        //         decorators,
-        //         pat,
-        //     }))
-        // } else {
-        //     let param = match self.parse_formal_param_pat()? {
-        //         Pat::Ident(i) => TsParamPropParam::Ident(i),
-        //         Pat::Assign(a) => TsParamPropParam::Assign(a),
-        //         node => syntax_error!(self, node.span(), SyntaxError::TsInvalidParamPropPat),
-        //     };
-        //     Ok(ParamOrTsParamProp::TsParamProp(TsParamProp {
-        //         span: self.span(param_start),
-        //         accessibility,
-        //         is_override,
-        //         readonly,
-        //         decorators,
-        //         param,
-        //     }))
-        // }
-        let pat = self.parse_formal_param_pat()?;
-        Ok(self
-            .ast
-            .param_or_ts_param_prop_param(self.span(param_start), decorators, pat))
+        let accessibility = self.try_parse_ts_access_modifier()?;
+        let is_override = self.try_parse_ts_modifier(&["override"], false)?.is_some();
+        let readonly = self.try_parse_ts_modifier(&["readonly"], false)?.is_some();
+        if accessibility.is_none() && !is_override && !readonly {
+            let pat = self.parse_formal_param_pat()?;
+            Ok(self
+                .ast
+                .param_or_ts_param_prop_param(self.span(param_start), decorators, pat))
+        } else {
+            let param = match self.parse_formal_param_pat()? {
+                Pat::Ident(i) => TsParamPropParam::Ident(i),
+                Pat::Assign(a) => TsParamPropParam::Assign(a),
+                node => syntax_error!(
+                    self,
+                    node.span(&self.ast),
+                    SyntaxError::TsInvalidParamPropPat
+                ),
+            };
+            Ok(self.ast.param_or_ts_param_prop_ts_param_prop(
+                self.span(param_start),
+                decorators,
+                accessibility,
+                is_override,
+                readonly,
+                param,
+            ))
+        }
     }
 
     pub(crate) fn parse_constructor_params(
@@ -674,16 +614,14 @@ impl<I: Tokens> Parser<I> {
                     let dot3_token = p.span(pat_start);
 
                     let pat = p.parse_binding_pat_or_ident(false)?;
-                    // let type_ann =
-                    //     if p.input().syntax().typescript() && p.input().is(Token::Colon) {
-                    //         let cur_pos = p.cur_pos();
-                    //         Some(p.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?)
-                    //     } else {
-                    //         None
-                    //     };
+                    let type_ann = if p.input().syntax().typescript() {
+                        p.try_parse_ts_type_ann_stripped()?
+                    } else {
+                        None
+                    };
 
                     rest_span = p.span(pat_start);
-                    let pat = p.ast.pat_rest_pat(rest_span, dot3_token, pat);
+                    let pat = p.ast.pat_rest_pat(rest_span, dot3_token, pat, type_ann);
                     let param =
                         p.ast
                             .param_or_ts_param_prop_param(p.span(param_start), decorators, pat);
@@ -714,6 +652,7 @@ impl<I: Tokens> Parser<I> {
                 }
 
                 let param_start = p.cur_pos();
+                let checkpoint = p.ast.checkpoint();
                 let decorators = p.parse_decorators(false)?;
                 let pat_start = p.cur_pos();
 
@@ -728,17 +667,14 @@ impl<I: Tokens> Parser<I> {
                         pat = p.ast.pat_assign_pat(p.span(pat_start), pat, right);
                     }
 
-                    // let type_ann =
-                    //     if p.input().syntax().typescript() && p.input().is(Token::Colon) {
-                    //         let cur_pos = p.cur_pos();
-                    //         let ty = p.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?;
-                    //         Some(ty)
-                    //     } else {
-                    //         None
-                    //     };
+                    let type_ann = if p.input().syntax().typescript() {
+                        p.try_parse_ts_type_ann_stripped()?
+                    } else {
+                        None
+                    };
 
                     rest_span = p.span(pat_start);
-                    let pat = p.ast.pat_rest_pat(rest_span, dot3_token, pat);
+                    let pat = p.ast.pat_rest_pat(rest_span, dot3_token, pat, type_ann);
 
                     if p.syntax().typescript() && p.input_mut().eat(Token::QuestionMark) {
                         p.emit_err(p.input().prev_span(), SyntaxError::TS1047);
@@ -751,8 +687,17 @@ impl<I: Tokens> Parser<I> {
                 };
                 let is_rest = matches!(pat, Pat::Rest(_));
 
-                let param = p.ast.param(p.span(param_start), decorators, pat);
-                params.push(p, param);
+                // `this` parameters only exist in the type system.
+                let is_erased = p.syntax().strip_types()
+                    && matches!(pat, Pat::Ident(i) if p.ast.get_utf8(i.id(&p.ast).sym(&p.ast)) == "this");
+                if is_erased {
+                    // SAFETY: The parameter isn't added to the list, and it's
+                    // the last thing parsed.
+                    unsafe { p.ast.truncate(checkpoint) };
+                } else {
+                    let param = p.ast.param(p.span(param_start), decorators, pat);
+                    params.push(p, param);
+                }
 
                 if !p.input().is(Token::RParen) {
                     expect!(p, Token::Comma);
@@ -771,7 +716,7 @@ impl<I: Tokens> Parser<I> {
         self.parse_formal_params()
     }
 
-    pub(super) fn parse_paren_items_as_params(
+    pub(crate) fn parse_paren_items_as_params(
         &mut self,
         mut exprs: Vec<AssignTargetOrSpread>,
         trailing_comma: Option<Span>,
@@ -822,7 +767,7 @@ impl<I: Tokens> Parser<I> {
                             let expr_span = expr.span(&p.ast);
                             let dot3_span = dot3_token.span(&p.ast);
                             p.reparse_expr_as_pat(pat_ty, expr)
-                                .map(|pat| p.ast.pat_rest_pat(expr_span, dot3_span, pat))?
+                                .map(|pat| p.ast.pat_rest_pat(expr_span, dot3_span, pat, None))?
                         }
                         None => p.reparse_expr_as_pat(pat_ty, expr_or_spread.expr(&p.ast))?,
                     }
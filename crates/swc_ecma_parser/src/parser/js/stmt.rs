@@ -10,6 +10,7 @@ use crate::{
     parser::{
         Parser,
         js::pat::PatType,
+        strip::Erasable,
         util::{FromStmt, ScratchIndex},
     },
 };
@@ -101,36 +102,26 @@ impl<I: Tokens> Parser<I> {
 
         let name = self.parse_binding_pat_or_ident(is_let_or_const)?;
 
-        // let definite = if self.input().syntax().typescript() {
-        //     match name {
-        //         Pat::Ident(..) => self.input_mut().eat(Token::Bang),
-        //         _ => false,
-        //     }
-        // } else {
-        //     false
-        // };
+        let definite = if self.input().syntax().typescript() {
+            match name {
+                Pat::Ident(..) => self.input_mut().eat(Token::Bang),
+                _ => false,
+            }
+        } else {
+            false
+        };
 
         // Typescript extension
-        // if self.input().syntax().typescript() && self.input().is(Token::Colon) {
-        //     let type_annotation = self.try_parse_ts_type_ann()?;
-        //     match name {
-        //         Pat::Array(ArrayPat {
-        //             ref mut type_ann, ..
-        //         })
-        //         | Pat::Ident(BindingIdent {
-        //             ref mut type_ann, ..
-        //         })
-        //         | Pat::Object(ObjectPat {
-        //             ref mut type_ann, ..
-        //         })
-        //         | Pat::Rest(RestPat {
-        //             ref mut type_ann, ..
-        //         }) => {
-        //             *type_ann = type_annotation;
-        //         }
-        //         _ => unreachable!("invalid syntax: Pat: {:?}", name),
-        //     }
-        // }
+        if self.input().syntax().typescript() && self.input().is(Token::Colon) {
+            let type_annotation = self.try_parse_ts_type_ann_stripped()?;
+            match name {
+                Pat::Array(a) => a.set_type_ann(&mut self.ast, type_annotation),
+                Pat::Ident(i) => i.set_type_ann(&mut self.ast, type_annotation),
+                Pat::Object(o) => o.set_type_ann(&mut self.ast, type_annotation),
+                Pat::Rest(r) => r.set_type_ann(&mut self.ast, type_annotation),
+                _ => unreachable!("invalid syntax: Pat: {:?}", name),
+            }
+        }
 
         //FIXME: This is wrong. Should check in/of only on first looself.
         let cur = self.input().cur();
@@ -169,7 +160,10 @@ impl<I: Tokens> Parser<I> {
             None
         };
 
-        Ok(self.ast.var_declarator(self.span(start), name, init))
+        let definite = self.strip_ts_flag(definite);
+        Ok(self
+            .ast
+            .var_declarator(self.span(start), name, init, definite))
     }
 
     pub(crate) fn parse_var_stmt(&mut self, for_loop: bool) -> PResult<VarDecl> {
@@ -188,42 +182,15 @@ impl<I: Tokens> Parser<I> {
         let var_span = self.span(start);
         let should_include_in = kind != VarDeclKind::Var || !for_loop;
 
-        // if self.syntax().typescript() && for_loop {
-        //     let cur = self.input().cur();
-        //     let res: PResult<bool> = if cur == Token::In || cur == Token::Of {
-        //         self.ts_look_ahead(|p| {
-        //             //
-        //             if !p.input_mut().eat(Token::Of) && !p.input_mut().eat(Token::In) {
-        //                 return Ok(false);
-        //             }
-
-        //             p.parse_assignment_expr()?;
-        //             expect!(p, Token::RParen);
-
-        //             Ok(true)
-        //         })
-        //     } else {
-        //         Ok(false)
-        //     };
-
-        //     match res {
-        //         Ok(true) => {
-        //             let pos = var_span.hi();
-        //             let span = Span::new_with_checked(pos, pos);
-        //             self.emit_err(span, SyntaxError::TS1123);
-
-        //             return Ok(Box::new(VarDecl {
-        //                 span: self.span(start),
-        //                 kind,
-        //                 declare: false,
-        //                 decls: Vec::new(),
-        //                 ..Default::default()
-        //             }));
-        //         }
-        //         Err(..) => {}
-        //         _ => {}
-        //     }
-        // }
+        if for_loop && self.ts_at_for_in_of_head() {
+            let pos = var_span.hi();
+            let span = Span::new_with_checked(pos, pos);
+            self.emit_err(span, SyntaxError::TS1123);
+
+            return Ok(self
+                .ast
+                .var_decl(self.span(start), kind, false, TypedSubRange::empty()));
+        }
 
         let decls = self.scratch_start(|p, decls| {
             loop {
@@ -372,21 +339,20 @@ impl<I: Tokens> Parser<I> {
                         );
                     }
 
-                    // if self.syntax().typescript() {
-                    //     let type_ann = match decl.decls[0].name {
-                    //         Pat::Ident(ref v) => Some(&v.type_ann),
-                    //         Pat::Array(ref v) => Some(&v.type_ann),
-                    //         Pat::Rest(ref v) => Some(&v.type_ann),
-                    //         Pat::Object(ref v) => Some(&v.type_ann),
-                    //         _ => None,
-                    //     };
-
-                    //     if let Some(type_ann) = type_ann {
-                    //         if type_ann.is_some() {
-                    //             self.emit_err(decl.decls[0].name.span(), SyntaxError::TS2483);
-                    //         }
-                    //     }
-                    // }
+                    if self.syntax().typescript() {
+                        let name = decl.name(&self.ast);
+                        let has_type_ann = match name {
+                            Pat::Ident(v) => v.type_ann(&self.ast).is_some(),
+                            Pat::Array(v) => v.type_ann(&self.ast).is_some(),
+                            Pat::Rest(v) => v.type_ann(&self.ast).is_some(),
+                            Pat::Object(v) => v.type_ann(&self.ast).is_some(),
+                            _ => false,
+                        };
+
+                        if has_type_ann {
+                            self.emit_err(name.span(&self.ast), SyntaxError::TS2483);
+                        }
+                    }
                 }
 
                 return self.parse_for_each_head(ForHead::VarDecl(decl));
@@ -433,10 +399,10 @@ impl<I: Tokens> Parser<I> {
 
         if is_using_decl {
             let name = self.parse_binding_ident(false)?;
-            let name = self.ast.binding_ident(self.span(start), name);
-            let decl = self
-                .ast
-                .var_declarator(self.span(start), Pat::Ident(name), Some(init));
+            let name = self.ast.binding_ident(self.span(start), name, None);
+            let decl =
+                self.ast
+                    .var_declarator(self.span(start), Pat::Ident(name), Some(init), false);
 
             let decls = self.scratch_start(|p, decls| {
                 decls.push(p, decl);
@@ -638,28 +604,16 @@ impl<I: Tokens> Parser<I> {
         if self.input_mut().eat(Token::LParen) {
             let pat = self.parse_binding_pat_or_ident(false)?;
 
-            // let type_ann_start = self.cur_pos();
-            // if self.syntax().typescript() && self.input_mut().eat(Token::Colon) {
-            //     let ty = self.do_inside_of_context(Context::InType, Self::parse_ts_type)?;
-            //     // self.emit_err(ty.span(), SyntaxError::TS1196);
-
-            //     match &mut pat {
-            //         Pat::Ident(BindingIdent { type_ann, .. })
-            //         | Pat::Array(ArrayPat { type_ann, .. })
-            //         | Pat::Rest(RestPat { type_ann, .. })
-            //         | Pat::Object(ObjectPat { type_ann, .. }) => {
-            //             *type_ann = Some(Box::new(TsTypeAnn {
-            //                 span: self.span(type_ann_start),
-            //                 type_ann: ty,
-            //             }));
-            //         }
-            //         Pat::Assign(..) => {}
-            //         Pat::Invalid(_) => {}
-            //         Pat::Expr(_) => {}
-            //         #[cfg(swc_ast_unknown)]
-            //         _ => unreachable!(),
-            //     }
-            // }
+            if let Some(type_ann) = self.try_parse_ts_catch_param_type_ann()? {
+                let type_ann = Some(type_ann);
+                match pat {
+                    Pat::Ident(i) => i.set_type_ann(&mut self.ast, type_ann),
+                    Pat::Array(a) => a.set_type_ann(&mut self.ast, type_ann),
+                    Pat::Rest(r) => r.set_type_ann(&mut self.ast, type_ann),
+                    Pat::Object(o) => o.set_type_ann(&mut self.ast, type_ann),
+                    Pat::Assign(..) | Pat::Invalid(_) | Pat::Expr(_) => {}
+                }
+            }
             expect!(self, Token::RParen);
             Ok(Some(pat))
         } else {
@@ -898,9 +852,12 @@ impl<I: Tokens> Parser<I> {
             return handle_import_export(self, decorators);
         }
 
-        self.do_outside_of_context(Context::WillExpectColonForCond, |p| {
-            p.do_inside_of_context(Context::AllowUsingDecl, |p| {
-                p.parse_stmt_internal(start, include_decl, decorators)
+        // Nested blocks and statement bodies recurse through here.
+        maybe_grow(|| {
+            self.do_outside_of_context(Context::WillExpectColonForCond, |p| {
+                p.do_inside_of_context(Context::AllowUsingDecl, |p| {
+                    p.parse_stmt_internal(start, include_decl, decorators)
+                })
             })
         })
         .map(Type::from_stmt)
@@ -915,18 +872,9 @@ impl<I: Tokens> Parser<I> {
     ) -> PResult<Stmt> {
         trace_cur!(self, parse_stmt_internal);
 
-        // let is_typescript = self.input().syntax().typescript();
-        // if is_typescript
-        //     && self.input().is(Token::Const)
-        //     && peek!(self).is_some_and(|peek| peek == Token::Enum)
-        // {
-        //     self.assert_and_bump(Token::Const);
-        //     self.assert_and_bump(Token::Enum);
-        //     return self
-        //         .parse_ts_enum_decl(start, true)
-        //         .map(Decl::from)
-        //         .map(Stmt::from);
-        // }
+        if let Some(decl) = self.try_parse_ts_const_enum(start)? {
+            return Ok(Stmt::Decl(Decl::TsEnum(decl)));
+        }
 
         let top_level = self.ctx().contains(Context::TopLevel);
 
@@ -1064,31 +1012,13 @@ impl<I: Tokens> Parser<I> {
             if let Some(v) = v {
                 return Ok(Stmt::Decl(Decl::Using(v)));
             }
-        // } else if cur == Token::Interface
-        //     && is_typescript
-        //     && peek!(self).is_some_and(|peek| peek.is_word())
-        //     && !self.input_mut().has_linebreak_between_cur_and_peeked()
-        // {
-        //     let start = self.input().cur_pos();
-        //     self.bump();
-        //     return Ok(self.parse_ts_interface_decl(start)?.into());
-        // } else if cur == Token::Type
-        //     && is_typescript
-        //     && peek!(self).is_some_and(|peek| peek.is_word())
-        //     && !self.input_mut().has_linebreak_between_cur_and_peeked()
-        // {
-        //     let start = self.input().cur_pos();
-        //     self.bump();
-        //     return Ok(self.parse_ts_type_alias_decl(start)?.into());
-        // } else if cur == Token::Enum
-        //     && is_typescript
-        //     && peek!(self).is_some_and(|peek| peek.is_word())
-        //     && !self.input_mut().has_linebreak_between_cur_and_peeked()
-        // {
-        //     let start = self.input().cur_pos();
-        //     self.bump();
-        //     return Ok(self.parse_ts_enum_decl(start, false)?.into());
-        } else if cur == Token::LBrace {
+        }
+
+        if let Some(decl) = self.try_parse_ts_decl_stmt()? {
+            return Ok(Stmt::Decl(decl));
+        }
+
+        if cur == Token::LBrace {
             return self
                 .do_inside_of_context(Context::AllowUsingDecl, |p| p.parse_block(false))
                 .map(Stmt::Block);
@@ -1135,29 +1065,14 @@ impl<I: Tokens> Parser<I> {
                 return Ok(self.ast.stmt_expr_stmt(self.span(start), expr));
             }
 
-            // if self.input().syntax().typescript() {
-            //     if let Some(decl) = self.parse_ts_expr_stmt(decorators, ident.clone())? {
-            //         return Ok(decl.into());
-            //     }
-            // }
+            if let Some(decl) = self.try_parse_ts_expr_stmt(decorators, *ident)? {
+                return Ok(Stmt::Decl(decl));
+            }
         }
 
-        // if self.syntax().typescript() {
-        //     if let Expr::Ident(ref i) = expr {
-        //         match &*i.sym {
-        //             "public" | "static" | "abstract" => {
-        //                 if self.input_mut().eat(Token::Interface) {
-        //                     self.emit_err(i.span, SyntaxError::TS2427);
-        //                     return self
-        //                         .parse_ts_interface_decl(start)
-        //                         .map(Decl::from)
-        //                         .map(Stmt::from);
-        //                 }
-        //             }
-        //             _ => {}
-        //         }
-        //     }
-        // }
+        if let Some(decl) = self.try_parse_ts_interface_after_modifier(start, expr)? {
+            return Ok(Stmt::Decl(decl));
+        }
 
         if self.eat_general_semi() {
             Ok(self.ast.stmt_expr_stmt(self.span(start), expr))
@@ -1186,7 +1101,7 @@ impl<I: Tokens> Parser<I> {
         self.parse_block_body(allow_directives, end, handle_import_export)
     }
 
-    pub(crate) fn parse_block_body<Type: FromStmt>(
+    pub(crate) fn parse_block_body<Type: FromStmt + Erasable>(
         &mut self,
         allow_directives: bool,
         end: Option<Token>,
@@ -1219,8 +1134,11 @@ impl<I: Tokens> Parser<I> {
                     }
                 };
                 while is_stmt_start(p) {
+                    let checkpoint = p.ast.checkpoint();
                     let stmt = p.parse_stmt_like(true, &handle_import_export)?;
-                    stmts.push(p, stmt);
+                    if !p.is_erased(stmt, checkpoint) {
+                        stmts.push(p, stmt);
+                    }
                 }
                 Ok(())
             };
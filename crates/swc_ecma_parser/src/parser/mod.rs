@@ -11,7 +11,7 @@ use crate::{
     Context, ParseRet, Syntax,
     error::SyntaxError,
     input::Buffer,
-    lexer::{MaybeSubUtf8, MaybeSubWtf8, Token, TokenAndSpan, source::StringSource},
+    lexer::{MaybeSubUtf8, MaybeSubWtf8, Token, source::StringSource},
     parser::{
         input::Tokens,
         state::{State, WithState},
@@ -21,6 +21,8 @@ use crate::{
 };
 
 use crate::error::Error;
+#[cfg(feature = "typescript")]
+use crate::lexer::TokenAndSpan;
 
 #[macro_use]
 mod macros;
@@ -28,13 +30,13 @@ pub mod input;
 mod js;
 mod jsx;
 mod state;
-// #[cfg(feature = "typescript")]
-// mod typescript;
+mod strip;
+mod ts;
 pub(crate) mod util;
 
 pub type PResult<T> = Result<T, crate::error::Error>;
 
-#[allow(unused)]
+#[cfg(feature = "typescript")]
 pub struct ParserCheckpoint<I: Tokens> {
     lexer: I::Checkpoint,
     buffer_prev_span: Span,
@@ -73,7 +75,7 @@ impl<I: Tokens> Parser<I> {
         &mut self.state
     }
 
-    #[allow(unused)]
+    #[cfg(feature = "typescript")]
     fn checkpoint_save(&self) -> ParserCheckpoint<I> {
         ParserCheckpoint {
             lexer: self.input.iter.checkpoint_save(),
@@ -83,7 +85,7 @@ impl<I: Tokens> Parser<I> {
         }
     }
 
-    #[allow(unused)]
+    #[cfg(feature = "typescript")]
     fn checkpoint_load(&mut self, checkpoint: ParserCheckpoint<I>) {
         self.input.iter.checkpoint_load(checkpoint.lexer);
         self.input.cur = checkpoint.buffer_cur;
@@ -204,6 +206,7 @@ impl<I: Tokens> Parser<I> {
         debug_assert!(self.input().cur() == Token::Eof);
         self.input_mut().bump();
 
+        self.attach_comments(ret);
         let errors = self.take_errors();
         Ok(ParseRet {
             ast: self.ast,
@@ -231,6 +234,7 @@ impl<I: Tokens> Parser<I> {
         debug_assert!(self.input().cur() == Token::Eof);
         self.input_mut().bump();
 
+        self.attach_comments(ret);
         let errors = self.take_errors();
         Ok(ParseRet {
             ast: self.ast,
@@ -259,6 +263,7 @@ impl<I: Tokens> Parser<I> {
         debug_assert!(self.input().cur() == Token::Eof);
         self.input_mut().bump();
 
+        self.attach_comments(ret);
         let errors = self.take_errors();
         Ok(ParseRet {
             ast: self.ast,
@@ -319,6 +324,7 @@ impl<I: Tokens> Parser<I> {
         debug_assert!(self.input().cur() == Token::Eof);
         self.input_mut().bump();
 
+        self.attach_comments(ret);
         let errors = self.take_errors();
         Ok(ParseRet {
             ast: self.ast,
@@ -346,6 +352,7 @@ impl<I: Tokens> Parser<I> {
         debug_assert!(self.input().cur() == Token::Eof);
         self.input_mut().bump();
 
+        self.attach_comments(ret);
         let errors = self.take_errors();
         Ok(ParseRet {
             ast: self.ast,
@@ -355,12 +362,22 @@ impl<I: Tokens> Parser<I> {
         })
     }
 
+    /// Attaches the comments collected by the lexer to the nodes, see
+    /// [crate::Lexer::with_ast_comments].
+    fn attach_comments<N: NodeIdTrait>(&mut self, root: N) {
+        let comments = self.input.iter.take_comments();
+        if !comments.is_empty() {
+            self.ast.attach_comments(root, comments);
+        }
+    }
+
     pub fn parse_expr(mut self) -> PResult<ParseRet<Expr, I>> {
         // This allow to parse `import.meta`
         let ctx = self.ctx();
         self.set_ctx(ctx.union(Context::CanBeModule));
 
         let expr = self.parse_expr_inner()?;
+        self.attach_comments(expr);
         let errors = self.take_errors();
         Ok(ParseRet {
             ast: self.ast,
@@ -583,44 +600,47 @@ impl<I: Tokens> Parser<I> {
         self.bump();
     }
 
-    pub fn check_assign_target(&mut self, expr: Expr, _deny_call: bool) {
+    pub fn check_assign_target(&mut self, expr: Expr, deny_call: bool) {
         if !expr.is_valid_simple_assignment_target(&self.ast, self.ctx().contains(Context::Strict))
         {
             self.emit_err(expr.span(&self.ast), SyntaxError::TS2406);
         }
 
         // We follow behavior of tsc
-        // if self.input().syntax().typescript() && self.syntax().early_errors() {
-        //     let is_eval_or_arguments = match expr {
-        //         Expr::Ident(i) => i.is_reserved_in_strict_bind(&self.ast),
-        //         _ => false,
-        //     };
-
-        //     if is_eval_or_arguments {
-        //         self.emit_strict_mode_err(expr.span(&self.ast), SyntaxError::TS1100);
-        //     }
-
-        //     fn should_deny(e: &Expr, deny_call: bool) -> bool {
-        //         match e {
-        //             Expr::Lit(..) => false,
-        //             Expr::Call(..) => deny_call,
-        //             Expr::Bin(..) => false,
-        //             Expr::Paren(ref p) => should_deny(&p.expr, deny_call),
-
-        //             _ => true,
-        //         }
-        //     }
-
-        //     // It is an early Reference Error if LeftHandSideExpression is neither
-        //     // an ObjectLiteral nor an ArrayLiteral and
-        //     // IsValidSimpleAssignmentTarget of LeftHandSideExpression is false.
-        //     if !is_eval_or_arguments
-        //         && !expr.is_valid_simple_assignment_target(self.ctx().contains(Context::Strict))
-        //         && should_deny(expr, deny_call)
-        //     {
-        //         self.emit_err(expr.span(&self.ast), SyntaxError::TS2406);
-        //     }
-        // }
+        if self.input().syntax().typescript() && self.syntax().early_errors() {
+            let is_eval_or_arguments = match expr {
+                Expr::Ident(i) => i.is_reserved_in_strict_bind(&self.ast),
+                _ => false,
+            };
+
+            if is_eval_or_arguments {
+                self.emit_strict_mode_err(expr.span(&self.ast), SyntaxError::TS1100);
+            }
+
+            fn should_deny(ast: &Ast, e: Expr, deny_call: bool) -> bool {
+                match e {
+                    Expr::Lit(..) => false,
+                    Expr::Call(..) => deny_call,
+                    Expr::Bin(..) => false,
+                    Expr::Paren(p) => should_deny(ast, p.expr(ast), deny_call),
+
+                    _ => true,
+                }
+            }
+
+            // It is an early Reference Error if LeftHandSideExpression is neither
+            // an ObjectLiteral nor an ArrayLiteral and
+            // IsValidSimpleAssignmentTarget of LeftHandSideExpression is false.
+            if !is_eval_or_arguments
+                && !expr.is_valid_simple_assignment_target(
+                    &self.ast,
+                    self.ctx().contains(Context::Strict),
+                )
+                && should_deny(&self.ast, expr, deny_call)
+            {
+                self.emit_err(expr.span(&self.ast), SyntaxError::TS2406);
+            }
+        }
     }
 
     #[inline]
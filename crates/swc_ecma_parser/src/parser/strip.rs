@@ -0,0 +1,284 @@
+//! Type erasure for [`TsSyntax::strip_types`](crate::TsSyntax::strip_types).
+//!
+//! Types are still parsed and checked as usual, but the arena is truncated
+//! right after each of them, so they're never attached to the JavaScript nodes
+//! that own them. Declarations which only exist in the type system are dropped
+//! from the enclosing list the same way, so no TypeScript node is left in the
+//! arena.
+
+use swc_experimental_ecma_ast::*;
+
+#[cfg(feature = "typescript")]
+use crate::PResult;
+use crate::{Context, Parser, error::SyntaxError, input::Tokens};
+
+/// An item of a statement list which might be erased when types are stripped.
+pub(crate) trait Erasable: Copy {
+    /// Returns `true` if the item has no runtime semantics.
+    ///
+    /// Items with runtime semantics TypeScript can't erase are reported and
+    /// erased as well.
+    fn is_erasable<I: Tokens>(self, p: &mut Parser<I>) -> bool;
+}
+
+impl Erasable for Stmt {
+    fn is_erasable<I: Tokens>(self, p: &mut Parser<I>) -> bool {
+        match self {
+            Stmt::Decl(decl) => p.is_erasable_decl(decl),
+            _ => false,
+        }
+    }
+}
+
+impl Erasable for ModuleItem {
+    fn is_erasable<I: Tokens>(self, p: &mut Parser<I>) -> bool {
+        match self {
+            ModuleItem::Stmt(stmt) => stmt.is_erasable(p),
+            ModuleItem::ModuleDecl(decl) => p.is_erasable_module_decl(decl),
+        }
+    }
+}
+
+impl<I: Tokens> Parser<I> {
+    /// Parses a TypeScript node with `op`, which is dropped if types are
+    /// stripped.
+    ///
+    /// Everything `op` adds to the arena is removed again when types are
+    /// stripped, so `op` must not attach what it parses to an existing node.
+    #[cfg(feature = "typescript")]
+    pub(crate) fn parse_stripped<T>(
+        &mut self,
+        op: impl FnOnce(&mut Self) -> PResult<Option<T>>,
+    ) -> PResult<Option<T>> {
+        if !self.syntax().strip_types() {
+            return op(self);
+        }
+
+        let checkpoint = self.ast.checkpoint();
+        let ret = op(self);
+        // SAFETY: Nothing `op` parsed is returned.
+        unsafe { self.ast.truncate(checkpoint) };
+        ret.map(|_| None)
+    }
+
+    /// Clears a TypeScript-only flag (`x?`, `x!`) if types are stripped.
+    #[inline(always)]
+    pub(crate) fn strip_ts_flag(&self, flag: bool) -> bool {
+        flag && !self.syntax().strip_types()
+    }
+
+    /// Wraps `expr` into `x!` or `<const>x` with `wrap`, unless types are
+    /// stripped.
+    #[cfg(feature = "typescript")]
+    pub(crate) fn wrap_ts_expr(
+        &mut self,
+        expr: Expr,
+        wrap: impl FnOnce(&mut Ast, Expr) -> Expr,
+    ) -> Expr {
+        if self.syntax().strip_types() {
+            expr
+        } else {
+            wrap(&mut self.ast, expr)
+        }
+    }
+
+    /// Returns `true` if `item` should be left out of the list being parsed,
+    /// and drops it from the arena along with everything else parsed since
+    /// `checkpoint`.
+    ///
+    /// Nothing is erased inside of `declare` declarations, because the whole
+    /// declaration is erased.
+    pub(crate) fn is_erased<T: Erasable>(&mut self, item: T, checkpoint: AstCheckpoint) -> bool {
+        let erased = self.syntax().strip_types()
+            && !self.ctx().contains(Context::InDeclare)
+            && item.is_erasable(self);
+        if erased {
+            // SAFETY: The item isn't added to the list, and it's the last
+            // thing parsed.
+            unsafe { self.ast.truncate(checkpoint) };
+        }
+        erased
+    }
+
+    /// Returns `true` if `member` should be left out of the class body, and
+    /// drops it from the arena along with everything else parsed since
+    /// `checkpoint`. The TypeScript modifiers of the members which are kept
+    /// are stripped.
+    ///
+    /// Abstract members of a non-abstract class are kept, so that they are
+    /// reported.
+    pub(crate) fn is_erased_class_member(
+        &mut self,
+        member: ClassMember,
+        is_abstract: bool,
+        checkpoint: AstCheckpoint,
+    ) -> bool {
+        if !self.syntax().strip_types() || self.ctx().contains(Context::InDeclare) {
+            return false;
+        }
+
+        let erased = self.is_erasable_class_member(member, is_abstract);
+        if erased {
+            // SAFETY: The member isn't added to the class body, and it's the
+            // last thing parsed.
+            unsafe { self.ast.truncate(checkpoint) };
+        }
+        erased
+    }
+
+    fn is_erasable_class_member(&mut self, member: ClassMember, is_abstract: bool) -> bool {
+        match member {
+            ClassMember::Constructor(c) => {
+                for param in c.params(&self.ast).iter() {
+                    if let ParamOrTsParamProp::TsParamProp(prop) =
+                        self.ast.get_node_in_sub_range(param)
+                    {
+                        self.emit_err(
+                            prop.span(&self.ast),
+                            SyntaxError::TsNotErasable("parameter property"),
+                        );
+                    }
+                }
+                if c.body(&self.ast).is_none() {
+                    return true;
+                }
+                c.set_accessibility(&mut self.ast, None);
+                c.set_is_optional(&mut self.ast, false);
+            }
+            ClassMember::Method(m) => {
+                if m.is_abstract(&self.ast) {
+                    return is_abstract;
+                }
+                if m.function(&self.ast).body(&self.ast).is_none() {
+                    return true;
+                }
+                m.set_accessibility(&mut self.ast, None);
+                m.set_is_optional(&mut self.ast, false);
+                m.set_is_override(&mut self.ast, false);
+            }
+            ClassMember::PrivateMethod(m) => {
+                if m.is_abstract(&self.ast) {
+                    return is_abstract;
+                }
+                if m.function(&self.ast).body(&self.ast).is_none() {
+                    return true;
+                }
+                m.set_accessibility(&mut self.ast, None);
+                m.set_is_optional(&mut self.ast, false);
+                m.set_is_override(&mut self.ast, false);
+            }
+            ClassMember::ClassProp(prop) => {
+                if prop.declare(&self.ast) {
+                    return true;
+                }
+                if prop.is_abstract(&self.ast) {
+                    return is_abstract;
+                }
+                prop.set_accessibility(&mut self.ast, None);
+                prop.set_is_optional(&mut self.ast, false);
+                prop.set_is_override(&mut self.ast, false);
+                prop.set_readonly(&mut self.ast, false);
+                prop.set_definite(&mut self.ast, false);
+            }
+            ClassMember::PrivateProp(prop) => {
+                prop.set_accessibility(&mut self.ast, None);
+                prop.set_is_optional(&mut self.ast, false);
+                prop.set_is_override(&mut self.ast, false);
+                prop.set_readonly(&mut self.ast, false);
+                prop.set_definite(&mut self.ast, false);
+            }
+            ClassMember::AutoAccessor(accessor) => {
+                if accessor.is_abstract(&self.ast) {
+                    return is_abstract;
+                }
+                accessor.set_accessibility(&mut self.ast, None);
+                accessor.set_is_override(&mut self.ast, false);
+                accessor.set_definite(&mut self.ast, false);
+            }
+            ClassMember::TsIndexSignature(..) => return true,
+            ClassMember::Empty(..) | ClassMember::StaticBlock(..) => {}
+            #[cfg(swc_ast_unknown)]
+            _ => unreachable!(),
+        }
+
+        false
+    }
+
+    fn is_erasable_decl(&mut self, decl: Decl) -> bool {
+        match decl {
+            Decl::Class(c) => c.declare(&self.ast),
+            Decl::Fn(f) => f.declare(&self.ast) || f.function(&self.ast).body(&self.ast).is_none(),
+            Decl::Var(v) => v.declare(&self.ast),
+            Decl::Using(..) => false,
+            Decl::TsInterface(..) | Decl::TsTypeAlias(..) => true,
+            Decl::TsEnum(e) => {
+                if !e.declare(&self.ast) {
+                    self.emit_err(e.span(&self.ast), SyntaxError::TsNotErasable("enum"));
+                }
+                true
+            }
+            Decl::TsModule(m) => {
+                if !m.declare(&self.ast) && has_values(&self.ast, m.body(&self.ast)) {
+                    self.emit_err(
+                        m.span(&self.ast),
+                        SyntaxError::TsNotErasable("namespace declaration"),
+                    );
+                }
+                true
+            }
+            #[cfg(swc_ast_unknown)]
+            _ => unreachable!(),
+        }
+    }
+
+    fn is_erasable_module_decl(&mut self, decl: ModuleDecl) -> bool {
+        match decl {
+            ModuleDecl::Import(import) => import.type_only(&self.ast),
+            ModuleDecl::ExportDecl(export) => self.is_erasable_decl(export.decl(&self.ast)),
+            ModuleDecl::ExportNamed(export) => export.type_only(&self.ast),
+            ModuleDecl::ExportDefaultDecl(export) => match export.decl(&self.ast) {
+                DefaultDecl::Class(..) => false,
+                DefaultDecl::Fn(f) => f.function(&self.ast).body(&self.ast).is_none(),
+                DefaultDecl::TsInterfaceDecl(..) => true,
+                #[cfg(swc_ast_unknown)]
+                _ => unreachable!(),
+            },
+            ModuleDecl::ExportDefaultExpr(..) => false,
+            ModuleDecl::ExportAll(export) => export.type_only(&self.ast),
+            ModuleDecl::TsImportEquals(import) => {
+                if !import.is_type_only(&self.ast) {
+                    self.emit_err(
+                        import.span(&self.ast),
+                        SyntaxError::TsNotErasable("import equals declaration"),
+                    );
+                }
+                true
+            }
+            ModuleDecl::TsExportAssignment(export) => {
+                self.emit_err(
+                    export.span(&self.ast),
+                    SyntaxError::TsNotErasable("export assignment"),
+                );
+                true
+            }
+            ModuleDecl::TsNamespaceExport(..) => true,
+            #[cfg(swc_ast_unknown)]
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Returns `true` if anything in the namespace body is left after erasure.
+///
+/// The items of the body have already been erased while it was parsed.
+fn has_values(ast: &Ast, mut body: Option<TsNamespaceBody>) -> bool {
+    loop {
+        match body {
+            Some(TsNamespaceBody::TsModuleBlock(block)) => return !block.body(ast).is_empty(),
+            Some(TsNamespaceBody::TsNamespaceDecl(decl)) => body = Some(decl.body(ast)),
+            None => return false,
+            #[cfg(swc_ast_unknown)]
+            _ => unreachable!(),
+        }
+    }
+}
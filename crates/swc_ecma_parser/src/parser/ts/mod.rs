@@ -1,2962 +1,1125 @@
-use std::{fmt::Write, mem};
+//! TypeScript support of the parser.
+//!
+//! The TypeScript parser in `parse` is only compiled with the `typescript`
+//! feature. The JavaScript parser reaches it only through the hooks below,
+//! which parse the TypeScript syntax at the current token if there's any. When
+//! the feature is disabled, [crate::Syntax::typescript] is always `false` and
+//! the hooks parse nothing instead.
 
-use either::Either;
-use swc_core::atoms::{Atom, Wtf8Atom, atom};
 use swc_core::common::{BytePos, Span};
-use swc_ecma_ast::*;
-
-use crate::{
-    Context, PResult, Parser, error::SyntaxError, input::Tokens, lexer::Token,
-    parser::util::IsSimpleParameterList,
-};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum ParsingContext {
-    EnumMembers,
-    HeritageClauseElement,
-    TupleElementTypes,
-    TypeMembers,
-    TypeParametersOrArguments,
-}
-
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum UnionOrIntersection {
-    Union,
-    Intersection,
-}
+use swc_experimental_ecma_ast::*;
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum SignatureParsingMode {
-    TSCallSignatureDeclaration,
-    TSConstructSignatureDeclaration,
-}
+#[cfg(feature = "typescript")]
+use crate::{Context, error::SyntaxError, lexer::Token, parser::util::IsSimpleParameterList};
+use crate::{PResult, Parser, input::Tokens, parser::js::expr::AssignTargetOrSpread};
 
-/// Mark as declare
-fn make_decl_declare(mut decl: Decl) -> Decl {
-    match decl {
-        Decl::Class(ref mut c) => c.declare = true,
-        Decl::Fn(ref mut f) => f.declare = true,
-        Decl::Var(ref mut v) => v.declare = true,
-        Decl::TsInterface(ref mut i) => i.declare = true,
-        Decl::TsTypeAlias(ref mut a) => a.declare = true,
-        Decl::TsEnum(ref mut e) => e.declare = true,
-        Decl::TsModule(ref mut m) => m.declare = true,
-        Decl::Using(..) => unreachable!("Using is not a valid declaration for `declare` keyword"),
-        #[cfg(swc_ast_unknown)]
-        _ => unreachable!(),
-    }
-
-    decl
-}
+#[cfg(feature = "typescript")]
+mod parse;
 
+#[cfg(feature = "typescript")]
 impl<I: Tokens> Parser<I> {
-    /// `tsParseList`
-    fn parse_ts_list<T, F>(&mut self, kind: ParsingContext, mut parse_element: F) -> PResult<Vec<T>>
-    where
-        F: FnMut(&mut Self) -> PResult<T>,
-    {
-        debug_assert!(self.input().syntax().typescript());
-        let mut buf = Vec::with_capacity(8);
-        while !self.is_ts_list_terminator(kind) {
-            // Skipping "parseListElement" from the TS source since that's just for error
-            // handling.
-            buf.push(parse_element(self)?);
+    /// Parses `<T>(x) => x` at the `<` of a JSX tag.
+    pub(crate) fn try_parse_ts_assignment_at_jsx_tag(&mut self) -> Option<Expr> {
+        if !self.input().syntax().typescript() || !self.input().is(Token::JSXTagStart) {
+            return None;
         }
-        Ok(buf)
+        // Note: When the JSX plugin is on, type assertions (`<T> x`) aren't valid
+        // syntax.
+        self.try_parse_ts(|p| maybe_grow(|| p.parse_assignment_expr_base()).map(Some))
     }
 
-    /// `tsTryParse`
-    pub(super) fn try_parse_ts_bool<F>(&mut self, op: F) -> PResult<bool>
-    where
-        F: FnOnce(&mut Self) -> PResult<Option<bool>>,
-    {
-        if !self.input().syntax().typescript() {
-            return Ok(false);
-        }
-
-        let prev_ignore_error = self.input().get_ctx().contains(Context::IgnoreError);
-        let checkpoint = self.checkpoint_save();
-        self.set_ctx(self.ctx() | Context::IgnoreError);
-        let res = op(self);
-        match res {
-            Ok(Some(res)) if res => {
-                let mut ctx = self.ctx();
-                ctx.set(Context::IgnoreError, prev_ignore_error);
-                self.input_mut().set_ctx(ctx);
-                Ok(res)
-            }
-            _ => {
-                self.checkpoint_load(checkpoint);
-                Ok(false)
-            }
+    /// Parses an arrow function with type parameters, `<T>(x: T) => x`.
+    pub(crate) fn try_parse_ts_generic_arrow(&mut self) -> Option<Expr> {
+        let start = self.input().cur_span();
+        if !self.input().syntax().typescript()
+            || !(self.input().cur() == Token::Lt || self.input().cur() == Token::JSXTagStart)
+            || !peek!(self).is_some_and(|peek| peek.is_word() || peek == Token::JSXName)
+        {
+            return None;
         }
-    }
-
-    /// `tsParseDelimitedList`
-    fn parse_ts_delimited_list_inner<T, F>(
-        &mut self,
-        kind: ParsingContext,
-        mut parse_element: F,
-    ) -> PResult<Vec<T>>
-    where
-        F: FnMut(&mut Self) -> PResult<(BytePos, T)>,
-    {
-        debug_assert!(self.input().syntax().typescript());
-        let mut buf = Vec::new();
-        loop {
-            trace_cur!(self, parse_ts_delimited_list_inner__element);
-
-            if self.is_ts_list_terminator(kind) {
-                break;
-            }
-
-            let (_, element) = parse_element(self)?;
-            buf.push(element);
-
-            if self.input_mut().eat(Token::Comma) {
-                continue;
-            }
 
-            if self.is_ts_list_terminator(kind) {
-                break;
-            }
-
-            if kind == ParsingContext::EnumMembers {
-                let expect = Token::Comma;
-                let cur = self.input().cur();
-                let cur = cur.to_string(self.input().get_token_value());
-                self.emit_err(
-                    self.input().cur_span(),
-                    SyntaxError::Expected(format!("{expect:?}"), cur),
-                );
-                continue;
-            }
-            // This will fail with an error about a missing comma
-            expect!(self, Token::Comma);
+        let res = self.do_outside_of_context(Context::WillExpectColonForCond, |p| {
+            p.try_parse_ts(|p| {
+                let type_params_start = p.cur_pos();
+                let type_parameters =
+                    p.parse_stripped(|p| p.parse_ts_type_params(false, true).map(Some))?;
+                let arrow = p.parse_assignment_expr_base()?;
+                match arrow {
+                    Expr::Arrow(mut arrow) => {
+                        let span = Span::new_with_checked(type_params_start, arrow.span_hi(&p.ast));
+                        arrow.set_span(&mut p.ast, span);
+                        arrow.set_type_params(&mut p.ast, type_parameters);
+                    }
+                    _ => unexpected!(p, "("),
+                }
+                Ok(Some(arrow))
+            })
+        });
+        if res.is_some() && self.input().syntax().disallow_ambiguous_jsx_like() {
+            self.emit_err(start, SyntaxError::ReservedArrowTypeParam);
         }
-
-        Ok(buf)
-    }
-
-    /// In no lexer context
-    pub(crate) fn ts_in_no_context<T, F>(&mut self, op: F) -> PResult<T>
-    where
-        F: FnOnce(&mut Self) -> PResult<T>,
-    {
-        debug_assert!(self.input().syntax().typescript());
-        trace_cur!(self, ts_in_no_context__before);
-        let res = op(self);
-        trace_cur!(self, ts_in_no_context__after);
         res
     }
 
-    /// `tsIsListTerminator`
-    fn is_ts_list_terminator(&mut self, kind: ParsingContext) -> bool {
-        debug_assert!(self.input().syntax().typescript());
-        let cur = self.input().cur();
-        match kind {
-            ParsingContext::EnumMembers | ParsingContext::TypeMembers => cur == Token::RBrace,
-            ParsingContext::HeritageClauseElement => {
-                matches!(cur, Token::LBrace | Token::Implements | Token::Extends)
-            }
-            ParsingContext::TupleElementTypes => cur == Token::RBracket,
-            ParsingContext::TypeParametersOrArguments => cur == Token::Gt,
+    /// Parses `<T>x` or `<const>x` at `<`.
+    pub(crate) fn try_parse_ts_type_assertion(&mut self, start: BytePos) -> PResult<Option<Expr>> {
+        if !self.input().syntax().typescript() || self.input().syntax().jsx() {
+            return Ok(None);
+        }
+        self.bump(); // consume `<`
+        if self.input_mut().eat(Token::Const) {
+            expect!(self, Token::Gt);
+            let expr = self.parse_unary_expr()?;
+            let span = self.span(start);
+            Ok(Some(self.wrap_ts_expr(expr, |ast, expr| {
+                ast.expr_ts_const_assertion(span, expr)
+            })))
+        } else {
+            self.parse_ts_type_assertion(start).map(Some)
         }
     }
 
-    /// `tsNextTokenCanFollowModifier`
-    pub(super) fn ts_next_token_can_follow_modifier(&mut self) -> bool {
-        debug_assert!(self.input().syntax().typescript());
-        // Note: TypeScript's implementation is much more complicated because
-        // more things are considered modifiers there.
-        // This implementation only handles modifiers not handled by @babel/parser
-        // itself. And "static". TODO: Would be nice to avoid lookahead. Want a
-        // hasLineBreakUpNext() method...
-        self.bump();
-
+    /// Parses the type arguments of a call, `f<T>()`.
+    pub(crate) fn try_parse_ts_call_type_args(
+        &mut self,
+    ) -> Option<Option<TsTypeParamInstantiation>> {
         let cur = self.input().cur();
-        !self.input().had_line_break_before_cur()
-            && matches!(
-                cur,
-                Token::LBracket
-                    | Token::LBrace
-                    | Token::Asterisk
-                    | Token::DotDotDot
-                    | Token::Hash
-                    | Token::Str
-                    | Token::Num
-                    | Token::BigInt
-            )
-            || cur.is_word()
-    }
-
-    /// `tsTryParse`
-    pub(crate) fn try_parse_ts<T, F>(&mut self, op: F) -> Option<T>
-    where
-        F: FnOnce(&mut Self) -> PResult<Option<T>>,
-    {
-        if !self.input().syntax().typescript() {
+        if !self.input().syntax().typescript() || !(cur == Token::Lt || cur == Token::LShift) {
             return None;
         }
-        debug_tracing!(self, "try_parse_ts");
-
-        trace_cur!(self, try_parse_ts);
-
-        let prev_ignore_error = self.input().get_ctx().contains(Context::IgnoreError);
-        let checkpoint = self.checkpoint_save();
-        self.set_ctx(self.ctx() | Context::IgnoreError);
-        let res = op(self);
-        match res {
-            Ok(Some(res)) => {
-                trace_cur!(self, try_parse_ts__success_value);
-                let mut ctx = self.ctx();
-                ctx.set(Context::IgnoreError, prev_ignore_error);
-                self.input_mut().set_ctx(ctx);
-                Some(res)
-            }
-            Ok(None) => {
-                trace_cur!(self, try_parse_ts__success_no_value);
-                self.checkpoint_load(checkpoint);
-                None
-            }
-            Err(..) => {
-                trace_cur!(self, try_parse_ts__fail);
-                self.checkpoint_load(checkpoint);
-                None
+        self.try_parse_ts(|p| {
+            let type_args = p.parse_stripped(|p| {
+                let type_args = p.parse_ts_type_args()?;
+                p.assert_and_bump(Token::Gt);
+                Ok(Some(type_args))
+            })?;
+            if p.input().is(Token::LParen) {
+                Ok(Some(type_args))
+            } else {
+                Ok(None)
             }
-        }
-    }
-
-    /// `tsParseTypeMemberSemicolon`
-    fn parse_ts_type_member_semicolon(&mut self) -> PResult<()> {
-        debug_assert!(self.input().syntax().typescript());
-
-        if !self.input_mut().eat(Token::Comma) {
-            self.expect_general_semi()
-        } else {
-            Ok(())
-        }
+        })
     }
 
-    /// `tsIsStartOfConstructSignature`
-    fn is_ts_start_of_construct_signature(&mut self) -> bool {
-        debug_assert!(self.input().syntax().typescript());
-
-        self.bump();
-        let cur = self.input().cur();
-        matches!(cur, Token::LParen | Token::Lt)
+    fn at_possible_async(&mut self, expr: Expr) -> bool {
+        // TODO(kdy1): !this.state.containsEsc &&
+        self.state().potential_arrow_start == Some(expr.span_lo(&self.ast))
+            && expr.is_ident_ref_to(&self.ast, "async")
     }
 
-    /// `tsParseDelimitedList`
-    fn parse_ts_delimited_list<T, F>(
+    /// Parses the TypeScript subscripts of `callee`: `x!`, and `x<T>` followed
+    /// by a call, a tagged template or the end of the expression.
+    pub(crate) fn try_parse_ts_subscript(
         &mut self,
-        kind: ParsingContext,
-        mut parse_element: F,
-    ) -> PResult<Vec<T>>
-    where
-        F: FnMut(&mut Self) -> PResult<T>,
-    {
-        self.parse_ts_delimited_list_inner(kind, |p| {
-            let start = p.input().cur_pos();
-            Ok((start, parse_element(p)?))
-        })
-    }
+        start: BytePos,
+        callee: Expr,
+        no_call: bool,
+    ) -> PResult<Option<(Expr, bool)>> {
+        if !self.input().syntax().typescript() {
+            return Ok(None);
+        }
 
-    /// `tsParseUnionOrIntersectionType`
-    fn parse_ts_union_or_intersection_type<F>(
-        &mut self,
-        kind: UnionOrIntersection,
-        mut parse_constituent_type: F,
-        operator: Token,
-    ) -> PResult<Box<TsType>>
-    where
-        F: FnMut(&mut Self) -> PResult<Box<TsType>>,
-    {
-        trace_cur!(self, parse_ts_union_or_intersection_type);
+        if !self.input().had_line_break_before_cur() && self.input().is(Token::Bang) {
+            self.assert_and_bump(Token::Bang);
+
+            let span = self.span(start);
+            let expr = self.wrap_ts_expr(callee, |ast, expr| ast.expr_ts_non_null_expr(span, expr));
 
-        debug_assert!(self.input().syntax().typescript());
+            return Ok(Some((expr, true)));
+        }
 
-        let start = self.input().cur_pos(); // include the leading operator in the start
-        self.input_mut().eat(operator);
-        trace_cur!(self, parse_ts_union_or_intersection_type__first_type);
+        if !self.input().is(Token::Lt) {
+            return Ok(None);
+        }
 
-        let ty = parse_constituent_type(self)?;
-        trace_cur!(self, parse_ts_union_or_intersection_type__after_first);
+        // tsTryParseAndCatch is expensive, so avoid if not necessary.
+        // There are number of things we are going to "maybe" parse, like type arguments
+        // on tagged template expressions. If any of them fail, walk it back and
+        // continue.
+
+        let result = self.do_inside_of_context(Context::ShouldNotLexLtOrGtAsType, |p| {
+            p.try_parse_ts(|p| {
+                if !no_call && p.at_possible_async(callee) {
+                    // Almost certainly this is a generic async function `async <T>() =>
+                    // ... But it might be a call with a
+                    // type argument `async<T>();`
+                    let async_arrow_fn = p.try_parse_ts_generic_async_arrow_fn(start)?;
+                    if let Some(async_arrow_fn) = async_arrow_fn {
+                        return Ok(Some((Expr::Arrow(async_arrow_fn), true)));
+                    }
+                }
 
-        if self.input().is(operator) {
-            let mut types = vec![ty];
+                let type_args = p.parse_stripped(|p| {
+                    let type_args = p.parse_ts_type_args()?;
+                    p.assert_and_bump(Token::Gt);
+                    Ok(Some(type_args))
+                })?;
+                let cur = p.input().cur();
 
-            while self.input_mut().eat(operator) {
-                trace_cur!(self, parse_ts_union_or_intersection_type__constituent);
+                if !no_call && cur == Token::LParen {
+                    // possibleAsync always false here, because we would have handled it
+                    // above. (won't be any undefined arguments)
+                    let args = p.parse_args(false)?;
 
-                types.push(parse_constituent_type(self)?);
-            }
+                    let expr = if callee.is_opt_chain() {
+                        let base =
+                            p.ast
+                                .opt_chain_base_opt_call(p.span(start), callee, args, type_args);
+                        p.ast.expr_opt_chain_expr(p.span(start), false, base)
+                    } else {
+                        p.ast
+                            .expr_call_expr(p.span(start), Callee::Expr(callee), args, type_args)
+                    };
 
-            return Ok(Box::new(TsType::TsUnionOrIntersectionType(match kind {
-                UnionOrIntersection::Union => TsUnionOrIntersectionType::TsUnionType(TsUnionType {
-                    span: self.span(start),
-                    types,
-                }),
-                UnionOrIntersection::Intersection => {
-                    TsUnionOrIntersectionType::TsIntersectionType(TsIntersectionType {
-                        span: self.span(start),
-                        types,
-                    })
+                    Ok(Some((expr, true)))
+                } else if matches!(
+                    cur,
+                    Token::NoSubstitutionTemplateLiteral | Token::TemplateHead | Token::BackQuote
+                ) {
+                    p.parse_tagged_tpl(callee, type_args)
+                        .map(|expr| (Expr::TaggedTpl(expr), true))
+                        .map(Some)
+                } else if matches!(cur, Token::Eq | Token::As | Token::Satisfies) {
+                    let expr = match type_args {
+                        Some(type_args) => {
+                            p.ast
+                                .expr_ts_instantiation(p.span(start), callee, type_args)
+                        }
+                        None => callee,
+                    };
+                    Ok(Some((expr, false)))
+                } else if no_call {
+                    unexpected!(p, "`")
+                } else {
+                    unexpected!(p, "( or `")
                 }
-            })));
-        }
-        Ok(ty)
+            })
+        });
+        Ok(result)
     }
 
-    pub(crate) fn eat_any_ts_modifier(&mut self) -> PResult<bool> {
-        if self.syntax().typescript()
-            && {
-                let cur = self.input().cur();
-                matches!(
-                    cur,
-                    Token::Public | Token::Protected | Token::Private | Token::Readonly
-                )
-            }
-            && peek!(self)
-                .is_some_and(|t| t.is_word() || matches!(t, Token::LBrace | Token::LBracket))
-        {
-            let _ = self.parse_ts_modifier(&["public", "protected", "private", "readonly"], false);
-            Ok(true)
+    /// Parses `<T>` after a member or a primary expression, which makes it an
+    /// instantiation expression.
+    pub(crate) fn parse_ts_instantiation_args(
+        &mut self,
+    ) -> PResult<Option<TsTypeParamInstantiation>> {
+        if self.syntax().typescript() && self.input().is(Token::Lt) {
+            self.parse_stripped(|p| Ok(p.try_parse_ts_type_args()))
         } else {
-            Ok(false)
+            Ok(None)
         }
     }
 
-    /// Parses a modifier matching one the given modifier names.
-    ///
-    /// `tsParseModifier`
-    pub(crate) fn parse_ts_modifier(
+    /// Parses `<T>` which must be followed by arguments. The inner option is
+    /// `None` if types are stripped.
+    pub(crate) fn parse_ts_explicit_type_args(
         &mut self,
-        allowed_modifiers: &[&'static str],
-        stop_on_start_of_class_static_blocks: bool,
-    ) -> PResult<Option<&'static str>> {
-        debug_assert!(self.input().syntax().typescript());
-        let pos = {
-            let cur = self.input().cur();
-            let modifier = if cur == Token::Ident {
-                cur.clone().take_unknown_ident_ref(self.input()).clone()
-            } else if cur.is_known_ident() {
-                cur.take_known_ident()
-            } else if cur == Token::In {
-                atom!("in")
-            } else if cur == Token::Const {
-                atom!("const")
-            } else if cur == Token::Error {
-                let err = self.input_mut().expect_error_token_and_bump();
-                return Err(err);
-            } else if cur == Token::Eof {
-                return Err(self.eof_error());
-            } else {
-                return Ok(None);
-            };
-            // TODO: compare atom rather than string.
-            allowed_modifiers
-                .iter()
-                .position(|s| **s == *modifier.as_str())
-        };
-        if let Some(pos) = pos {
-            if stop_on_start_of_class_static_blocks
-                && self.input().is(Token::Static)
-                && peek!(self).is_some_and(|peek| peek == Token::LBrace)
-            {
-                return Ok(None);
-            }
-            if self.try_parse_ts_bool(|p| Ok(Some(p.ts_next_token_can_follow_modifier())))? {
-                return Ok(Some(allowed_modifiers[pos]));
-            }
+    ) -> PResult<Option<Option<TsTypeParamInstantiation>>> {
+        if !self.syntax().typescript() || !self.input().is(Token::Lt) {
+            return Ok(None);
         }
-        Ok(None)
+        self.parse_stripped(|p| {
+            let ret = p.parse_ts_type_args()?;
+            p.assert_and_bump(Token::Gt);
+            Ok(Some(ret))
+        })
+        .map(Some)
     }
 
-    fn parse_ts_bracketed_list<T, F>(
+    /// Parses the type arguments of `new C<T>()`.
+    pub(crate) fn try_parse_ts_new_type_args(
         &mut self,
-        kind: ParsingContext,
-        parse_element: F,
-        bracket: bool,
-        skip_first_token: bool,
-    ) -> PResult<Vec<T>>
-    where
-        F: FnMut(&mut Self) -> PResult<T>,
-    {
-        debug_assert!(self.input().syntax().typescript());
-        if !skip_first_token {
-            if bracket {
-                expect!(self, Token::LBracket);
-            } else {
-                expect!(self, Token::Lt);
-            }
-        }
-        let result = self.parse_ts_delimited_list(kind, parse_element)?;
-        if bracket {
-            expect!(self, Token::RBracket);
-        } else {
-            expect!(self, Token::Gt);
+    ) -> PResult<Option<TsTypeParamInstantiation>> {
+        let cur = self.input().cur();
+        if !self.input().syntax().typescript() || !(cur == Token::Lt || cur == Token::LShift) {
+            return Ok(None);
         }
-        Ok(result)
-    }
-
-    /// `tsParseThisTypeNode`
-    fn parse_ts_this_type_node(&mut self) -> PResult<TsThisType> {
-        debug_assert!(self.input().syntax().typescript());
-        expect!(self, Token::This);
-        Ok(TsThisType {
-            span: self.input().prev_span(),
+        self.parse_stripped(|p| {
+            Ok(p.try_parse_ts(|p| {
+                let args = p.do_outside_of_context(
+                    Context::ShouldNotLexLtOrGtAsType,
+                    Self::parse_ts_type_args,
+                )?;
+                p.assert_and_bump(Token::Gt);
+                if !p.input().is(Token::LParen) {
+                    let span = p.input().cur_span();
+                    let cur = p.input_mut().dump_cur();
+                    syntax_error!(p, span, SyntaxError::Expected('('.to_string(), cur))
+                }
+                Ok(Some(args))
+            }))
         })
     }
 
-    /// `tsParseEntityName`
-    fn parse_ts_entity_name(&mut self, allow_reserved_words: bool) -> PResult<TsEntityName> {
-        debug_assert!(self.input().syntax().typescript());
-        trace_cur!(self, parse_ts_entity_name);
-        let start = self.input().cur_pos();
-        let init = self.parse_ident_name()?;
-        if &*init.sym == "void" {
-            let dot_start = self.input().cur_pos();
-            let dot_span = self.span(dot_start);
-            self.emit_err(dot_span, SyntaxError::TS1005)
-        }
-        let mut entity = TsEntityName::Ident(init.into());
-        while self.input_mut().eat(Token::Dot) {
-            let dot_start = self.input().cur_pos();
-            let cur = self.input().cur();
-            if cur != Token::Hash && !cur.is_word() {
-                self.emit_err(
-                    Span::new_with_checked(dot_start, dot_start),
-                    SyntaxError::TS1003,
-                );
-                return Ok(entity);
-            }
-            let left = entity;
-            let right = if allow_reserved_words {
-                self.parse_ident_name()?
-            } else {
-                self.parse_ident(false, false)?.into()
-            };
-            let span = self.span(start);
-            entity = TsEntityName::TsQualifiedName(Box::new(TsQualifiedName { span, left, right }));
+    /// Parses `left as T`, `left as const` or `left satisfies T`.
+    pub(crate) fn try_parse_ts_as_or_satisfies(
+        &mut self,
+        left: Expr,
+        min_prec: u8,
+    ) -> PResult<Option<Expr>> {
+        const PREC_OF_IN: u8 = 7;
+
+        if !self.input().syntax().typescript() || self.input().had_line_break_before_cur() {
+            return Ok(None);
         }
-        Ok(entity)
-    }
 
-    pub(crate) fn ts_look_ahead<T, F>(&mut self, op: F) -> T
-    where
-        F: FnOnce(&mut Self) -> T,
-    {
-        debug_assert!(self.input().syntax().typescript());
-        let checkpoint = self.checkpoint_save();
-        self.set_ctx(self.ctx() | Context::IgnoreError);
-        let ret = op(self);
-        self.checkpoint_load(checkpoint);
-        ret
+        let start = left.span_lo(&self.ast);
+        let expr = left;
+        let node = if PREC_OF_IN > min_prec && self.input().is(Token::As) {
+            if peek!(self).is_some_and(|cur| cur == Token::Const) {
+                self.bump(); // as
+                self.bump(); // const
+                let span = self.span(start);
+                self.wrap_ts_expr(expr, |ast, expr| ast.expr_ts_const_assertion(span, expr))
+            } else {
+                match self.parse_stripped(|p| p.next_then_parse_ts_type().map(Some))? {
+                    Some(type_ann) => self.ast.expr_ts_as_expr(self.span(start), expr, type_ann),
+                    None => expr,
+                }
+            }
+        } else if self.input().is(Token::Satisfies) {
+            match self.parse_stripped(|p| p.next_then_parse_ts_type().map(Some))? {
+                Some(type_ann) => self
+                    .ast
+                    .expr_ts_satisfies_expr(self.span(start), expr, type_ann),
+                None => expr,
+            }
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(node))
     }
 
-    /// `tsParseTypeArguments`
-    pub(crate) fn parse_ts_type_args(&mut self) -> PResult<Box<TsTypeParamInstantiation>> {
-        trace_cur!(self, parse_ts_type_args);
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.input().cur_pos();
-        let params = self.in_type(|p| {
-            // Temporarily remove a JSX parsing context, which makes us scan different
-            // tokens.
-            p.ts_in_no_context(|p| {
-                if p.input().is(Token::LShift) {
-                    p.input_mut().cut_lshift();
-                } else {
-                    expect!(p, Token::Lt);
+    /// Parses the type annotation of a parameter of an arrow function, which
+    /// was parsed as an expression.
+    pub(crate) fn parse_ts_type_ann_of_arrow_param(
+        &mut self,
+        mut pat: Pat,
+        pat_start: BytePos,
+    ) -> PResult<()> {
+        match pat {
+            Pat::Ident(i) => {
+                let new_type_ann = self.parse_stripped(Self::try_parse_ts_type_ann)?;
+                if new_type_ann.is_some() {
+                    let mut id = i.id(&self.ast);
+                    let span = Span::new_with_checked(pat_start, self.input().prev_span().hi);
+                    id.set_span(&mut self.ast, span);
                 }
-                p.parse_ts_delimited_list(ParsingContext::TypeParametersOrArguments, |p| {
-                    trace_cur!(p, parse_ts_type_args__arg);
-
-                    p.parse_ts_type()
-                })
-            })
-        })?;
-        // This reads the next token after the `>` too, so do this in the enclosing
-        // context. But be sure not to parse a regex in the jsx expression
-        // `<C<number> />`, so set exprAllowed = false
-        self.expect_without_advance(Token::Gt)?;
-        let span = Span::new_with_checked(start, self.input().cur_span().hi);
-
-        // Report grammar error for empty type argument list like `I<>`.
-        if params.is_empty() {
-            self.emit_err(span, SyntaxError::EmptyTypeArgumentList);
+                i.set_type_ann(&mut self.ast, new_type_ann);
+            }
+            Pat::Array(a) => {
+                let new_type_ann = self.parse_stripped(Self::try_parse_ts_type_ann)?;
+                if new_type_ann.is_some() {
+                    let span = Span::new_with_checked(pat_start, self.input().prev_span().hi);
+                    pat.set_span(&mut self.ast, span);
+                }
+                a.set_type_ann(&mut self.ast, new_type_ann);
+            }
+            Pat::Object(o) => {
+                let new_type_ann = self.parse_stripped(Self::try_parse_ts_type_ann)?;
+                if new_type_ann.is_some() {
+                    let span = Span::new_with_checked(pat_start, self.input().prev_span().hi);
+                    pat.set_span(&mut self.ast, span);
+                }
+                o.set_type_ann(&mut self.ast, new_type_ann);
+            }
+            Pat::Rest(r) => {
+                let new_type_ann = self.parse_stripped(Self::try_parse_ts_type_ann)?;
+                if new_type_ann.is_some() {
+                    let span = Span::new_with_checked(pat_start, self.input().prev_span().hi);
+                    pat.set_span(&mut self.ast, span);
+                }
+                r.set_type_ann(&mut self.ast, new_type_ann);
+            }
+            Pat::Expr(..) => unreachable!("invalid pattern: Expr"),
+            Pat::Assign(..) | Pat::Invalid(..) => {
+                // We don't have to panic here.
+                // See: https://github.com/swc-project/swc/issues/1170
+                //
+                // Also, as an exact error is added to the errors while
+                // creating `Invalid`, we don't have to emit a new
+                // error.
+            }
         }
-
-        Ok(Box::new(TsTypeParamInstantiation { span, params }))
+        Ok(())
     }
 
-    /// `tsParseTypeReference`
-    fn parse_ts_type_ref(&mut self) -> PResult<TsTypeRef> {
-        trace_cur!(self, parse_ts_type_ref);
-        debug_assert!(self.input().syntax().typescript());
+    /// Parses an arrow function with a return type in the consequent of a
+    /// conditional expression, `a ? (b): c => d : e`.
+    pub(crate) fn try_parse_ts_arrow_in_cond(
+        &mut self,
+        expr_start: BytePos,
+        async_span: Option<Span>,
+        paren_items: &[AssignTargetOrSpread],
+        trailing_comma: Option<Span>,
+    ) -> Option<Expr> {
+        if !self.syntax().typescript()
+            || !self.ctx().contains(Context::InCondExpr)
+            || !self.input().is(Token::Colon)
+        {
+            return None;
+        }
 
-        let start = self.input().cur_pos();
+        let will_expect_colon_for_cond = self.ctx().contains(Context::WillExpectColonForCond);
+        self.try_parse_ts(|p| {
+            let return_type = p.parse_stripped(|p| {
+                p.parse_ts_type_or_type_predicate_ann(Token::Colon)
+                    .map(Some)
+            })?;
 
-        let has_modifier = self.eat_any_ts_modifier()?;
+            expect!(p, Token::Arrow);
 
-        let type_name = self.parse_ts_entity_name(/* allow_reserved_words */ true)?;
-        trace_cur!(self, parse_ts_type_ref__type_args);
-        let type_params = if !self.input().had_line_break_before_cur()
-            && (self.input().is(Token::Lt) || self.input().is(Token::LShift))
-        {
-            let ret = self.do_outside_of_context(
-                Context::ShouldNotLexLtOrGtAsType,
-                Self::parse_ts_type_args,
+            let items = paren_items
+                .iter()
+                .map(|item| item.clone_in(&mut p.ast))
+                .collect();
+            let params = p.parse_paren_items_as_params(items, trailing_comma)?;
+
+            let body: BlockStmtOrExpr = p.parse_fn_block_or_expr_body(
+                async_span.is_some(),
+                false,
+                true,
+                params.is_simple_parameter_list(&p.ast),
             )?;
-            self.assert_and_bump(Token::Gt);
-            Some(ret)
-        } else {
-            None
-        };
 
-        if has_modifier {
-            self.emit_err(self.span(start), SyntaxError::TS2369);
-        }
+            if will_expect_colon_for_cond && !p.input().is(Token::Colon) {
+                trace_cur!(p, parse_arrow_in_cond__fail);
+                unexpected!(p, "fail")
+            }
 
-        Ok(TsTypeRef {
-            span: self.span(start),
-            type_name,
-            type_params,
+            Ok(Some(p.ast.expr_arrow_expr(
+                p.span(expr_start),
+                params,
+                body,
+                async_span.is_some(),
+                false,
+                None,
+                return_type,
+            )))
         })
     }
 
-    #[cfg_attr(
-        feature = "tracing-spans",
-        tracing::instrument(level = "debug", skip_all)
-    )]
-    pub(crate) fn parse_ts_type_ann(
-        &mut self,
-        eat_colon: bool,
-        start: BytePos,
-    ) -> PResult<Box<TsTypeAnn>> {
-        trace_cur!(self, parse_ts_type_ann);
+    /// Parses the return type of an arrow function, `(a): T => a`.
+    pub(crate) fn try_parse_ts_arrow_return_type(&mut self) -> Option<Option<TsTypeAnn>> {
+        if self.ctx().contains(Context::WillExpectColonForCond)
+            || !self.input().syntax().typescript()
+            || !self.input().is(Token::Colon)
+        {
+            return None;
+        }
 
-        debug_assert!(self.input().syntax().typescript());
+        self.try_parse_ts(|p| {
+            let return_type = p.parse_stripped(|p| {
+                p.parse_ts_type_or_type_predicate_ann(Token::Colon)
+                    .map(Some)
+            })?;
 
-        self.in_type(|p| {
-            if eat_colon {
-                p.assert_and_bump(Token::Colon);
+            if !p.input().is(Token::Arrow) {
+                unexpected!(p, "fail")
             }
 
-            trace_cur!(p, parse_ts_type_ann__after_colon);
-
-            let type_ann = p.parse_ts_type()?;
-
-            Ok(Box::new(TsTypeAnn {
-                span: p.span(start),
-                type_ann,
-            }))
+            Ok(Some(return_type))
         })
     }
 
-    /// `tsParseThisTypePredicate`
-    fn parse_ts_this_type_predicate(
+    /// Parses `async as T`, where `ident` is the identifier after `async`.
+    pub(crate) fn try_parse_ts_async_as(
         &mut self,
         start: BytePos,
-        has_asserts_keyword: bool,
-        lhs: TsThisType,
-    ) -> PResult<TsTypePredicate> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let param_name = TsThisTypeOrIdent::TsThisType(lhs);
-        let type_ann = if self.input_mut().eat(Token::Is) {
-            let cur_pos = self.input().cur_pos();
-            Some(self.parse_ts_type_ann(false, cur_pos)?)
-        } else {
-            None
-        };
+        id: Ident,
+        ident: Ident,
+    ) -> PResult<Option<Expr>> {
+        if !self.input().syntax().typescript()
+            || self.ast.get_utf8(ident.sym(&self.ast)) != "as"
+            || self.input().is(Token::Arrow)
+        {
+            return Ok(None);
+        }
 
-        Ok(TsTypePredicate {
-            span: self.span(start),
-            asserts: has_asserts_keyword,
-            param_name,
-            type_ann,
-        })
+        let expr = Expr::Ident(id);
+        let expr = match self.parse_stripped(|p| p.in_type(Self::parse_ts_type).map(Some))? {
+            Some(type_ann) => self.ast.expr_ts_as_expr(self.span(start), expr, type_ann),
+            None => expr,
+        };
+        Ok(Some(expr))
     }
 
-    /// `tsEatThenParseType`
-    fn eat_then_parse_ts_type(&mut self, token_to_eat: Token) -> PResult<Option<Box<TsType>>> {
-        if !cfg!(feature = "typescript") {
-            return Ok(Default::default());
+    /// Parses `async<T>() => {}` at `async`.
+    pub(crate) fn try_parse_ts_generic_async_arrow(&mut self) -> Option<Expr> {
+        if !self.input().syntax().typescript() || !peek!(self).is_some_and(|peek| peek == Token::Lt)
+        {
+            return None;
         }
 
-        self.in_type(|p| {
-            if !p.input_mut().eat(token_to_eat) {
-                return Ok(None);
-            }
-
-            p.parse_ts_type().map(Some)
+        self.try_parse_ts(|p| {
+            let start = p.cur_pos();
+            p.assert_and_bump(Token::Async);
+            p.try_parse_ts_generic_async_arrow_fn(start)
         })
+        .map(Expr::Arrow)
+    }
+    /// Parses `: T`, unless types are stripped.
+    pub(crate) fn try_parse_ts_type_ann_stripped(&mut self) -> PResult<Option<TsTypeAnn>> {
+        self.parse_stripped(Self::try_parse_ts_type_ann)
     }
 
-    /// `tsExpectThenParseType`
-    fn expect_then_parse_ts_type(
+    /// Parses one of `allowed_modifiers`.
+    pub(crate) fn try_parse_ts_modifier(
         &mut self,
-        token: Token,
-        token_str: &'static str,
-    ) -> PResult<Box<TsType>> {
-        debug_assert!(self.input().syntax().typescript());
-
-        self.in_type(|p| {
-            if !p.input_mut().eat(token) {
-                let got = format!("{:?}", p.input().cur());
-                syntax_error!(
-                    p,
-                    p.input().cur_span(),
-                    SyntaxError::Unexpected {
-                        got,
-                        expected: token_str
-                    }
-                );
-            }
-
-            p.parse_ts_type()
-        })
+        allowed_modifiers: &[&'static str],
+        stop_on_start_of_class_static_blocks: bool,
+    ) -> PResult<Option<&'static str>> {
+        if !self.input().syntax().typescript() {
+            return Ok(None);
+        }
+        self.parse_ts_modifier(allowed_modifiers, stop_on_start_of_class_static_blocks)
     }
 
-    /// `tsParseMappedTypeParameter`
-    fn parse_ts_mapped_type_param(&mut self) -> PResult<TsTypeParam> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.input().cur_pos();
-        let name = self.parse_ident_name()?;
-        let constraint = Some(self.expect_then_parse_ts_type(Token::In, "in")?);
-
-        Ok(TsTypeParam {
-            span: self.span(start),
-            name: name.into(),
-            is_in: false,
-            is_out: false,
-            is_const: false,
-            constraint,
-            default: None,
-        })
+    /// Parses `public`, `protected` or `private`.
+    pub(crate) fn try_parse_ts_access_modifier(&mut self) -> PResult<Option<Accessibility>> {
+        if !self.input().syntax().typescript() {
+            return Ok(None);
+        }
+        self.parse_access_modifier()
     }
 
-    /// `tsParseTypeParameter`
-    fn parse_ts_type_param(
+    /// Parses the optional marker and the type annotation of a formal
+    /// parameter.
+    ///
+    /// Returns the parameter and whether it's optional.
+    pub(crate) fn parse_ts_formal_param_suffix(
         &mut self,
-        permit_in_out: bool,
-        permit_const: bool,
-    ) -> PResult<TsTypeParam> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let mut is_in = false;
-        let mut is_out = false;
-        let mut is_const = false;
-
-        let start = self.input().cur_pos();
-
-        while let Some(modifer) = self.parse_ts_modifier(
-            &[
-                "public",
-                "private",
-                "protected",
-                "readonly",
-                "abstract",
-                "const",
-                "override",
-                "in",
-                "out",
-            ],
-            false,
-        )? {
-            match modifer {
-                "const" => {
-                    is_const = true;
-                    if !permit_const {
-                        self.emit_err(
-                            self.input().prev_span(),
-                            SyntaxError::TS1277(atom!("const")),
-                        );
-                    }
-                }
-                "in" => {
-                    if !permit_in_out {
-                        self.emit_err(self.input().prev_span(), SyntaxError::TS1274(atom!("in")));
-                    } else if is_in {
-                        self.emit_err(self.input().prev_span(), SyntaxError::TS1030(atom!("in")));
-                    } else if is_out {
-                        self.emit_err(
-                            self.input().prev_span(),
-                            SyntaxError::TS1029(atom!("in"), atom!("out")),
-                        );
-                    }
-                    is_in = true;
-                }
-                "out" => {
-                    if !permit_in_out {
-                        self.emit_err(self.input().prev_span(), SyntaxError::TS1274(atom!("out")));
-                    } else if is_out {
-                        self.emit_err(self.input().prev_span(), SyntaxError::TS1030(atom!("out")));
-                    }
-                    is_out = true;
-                }
-                other => self.emit_err(self.input().prev_span(), SyntaxError::TS1273(other.into())),
-            };
+        pat_start: BytePos,
+        pat: Pat,
+    ) -> PResult<(Pat, bool)> {
+        if !self.input().syntax().typescript() {
+            return Ok((pat, false));
         }
-
-        let name = self.in_type(Self::parse_ident_name)?.into();
-        let constraint = self.eat_then_parse_ts_type(Token::Extends)?;
-        let default = self.eat_then_parse_ts_type(Token::Eq)?;
-
-        Ok(TsTypeParam {
-            span: self.span(start),
-            name,
-            is_in,
-            is_out,
-            is_const,
-            constraint,
-            default,
-        })
+        self.parse_formal_param_pat_ts(pat_start, pat)
     }
 
-    /// `tsParseTypeParameter`
-    pub(crate) fn parse_ts_type_params(
-        &mut self,
-        permit_in_out: bool,
-        permit_const: bool,
-    ) -> PResult<Box<TsTypeParamDecl>> {
+    /// Parses the type parameters of a function, `function f<T>() {}`.
+    pub(crate) fn try_parse_ts_fn_type_params(&mut self) -> PResult<Option<TsTypeParamDecl>> {
+        if !self.syntax().typescript() {
+            return Ok(None);
+        }
         self.in_type(|p| {
-            p.ts_in_no_context(|p| {
-                let start = p.input().cur_pos();
-                let cur = p.input().cur();
-                if cur != Token::Lt && cur != Token::JSXTagStart {
-                    unexpected!(p, "< (jsx tag start)")
-                }
-                p.bump();
-
-                let params = p.parse_ts_bracketed_list(
-                    ParsingContext::TypeParametersOrArguments,
-                    |p| p.parse_ts_type_param(permit_in_out, permit_const), // bracket
-                    false,
-                    // skip_first_token
-                    true,
-                )?;
+            trace_cur!(p, parse_fn_args_body__type_params);
 
-                Ok(Box::new(TsTypeParamDecl {
-                    span: p.span(start),
-                    params,
-                }))
-            })
+            Ok(
+                if p.input().is(Token::Lt) || p.input().is(Token::JSXTagStart) {
+                    p.parse_stripped(|p| p.parse_ts_type_params(false, true).map(Some))?
+                } else {
+                    None
+                },
+            )
         })
     }
 
-    /// `tsTryParseTypeParameters`
-    pub(crate) fn try_parse_ts_type_params(
-        &mut self,
-        permit_in_out: bool,
-        permit_const: bool,
-    ) -> PResult<Option<Box<TsTypeParamDecl>>> {
-        if !cfg!(feature = "typescript") {
+    /// Parses the return type of a function, `function f(): T {}`.
+    pub(crate) fn try_parse_ts_return_type(&mut self) -> PResult<Option<TsTypeAnn>> {
+        if !self.syntax().typescript() || !self.input().is(Token::Colon) {
             return Ok(None);
         }
+        self.parse_stripped(|p| {
+            p.parse_ts_type_or_type_predicate_ann(Token::Colon)
+                .map(Some)
+        })
+    }
 
-        if self.input().cur() == Token::Lt {
-            return self
-                .parse_ts_type_params(permit_in_out, permit_const)
-                .map(Some);
+    /// Parses the type parameters of a class, `class C<T> {}`.
+    pub(crate) fn try_parse_ts_class_type_params(&mut self) -> PResult<Option<TsTypeParamDecl>> {
+        if !self.input().syntax().typescript() {
+            return Ok(None);
         }
+        self.parse_stripped(|p| p.try_parse_ts_type_params(true, true))
+    }
 
-        Ok(None)
+    /// Parses `implements I, J` of a class.
+    pub(crate) fn parse_ts_implements(&mut self) -> PResult<TypedSubRange<TsExprWithTypeArgs>> {
+        if !self.input().syntax().typescript() || !self.input_mut().eat(Token::Implements) {
+            return Ok(TypedSubRange::empty());
+        }
+        Ok(self
+            .parse_stripped(|p| p.parse_ts_heritage_clause().map(Some))?
+            .unwrap_or_else(TypedSubRange::empty))
     }
 
-    /// `tsParseTypeOrTypePredicateAnnotation`
-    pub(crate) fn parse_ts_type_or_type_predicate_ann(
-        &mut self,
-        return_token: Token,
-    ) -> PResult<Box<TsTypeAnn>> {
-        debug_assert!(self.input().syntax().typescript());
+    /// Reports the super classes after the first one, `class C extends A, B {}`.
+    pub(crate) fn report_ts_extra_super_classes(&mut self) -> PResult<()> {
+        if !self.syntax().typescript() || !self.input_mut().eat(Token::Comma) {
+            return Ok(());
+        }
+        let checkpoint = self.ast.checkpoint();
+        let exprs = self.parse_ts_heritage_clause()?;
 
-        self.in_type(|p| {
-            let return_token_start = p.input().cur_pos();
-            if !p.input_mut().eat(return_token) {
-                let cur = format!("{:?}", p.input().cur());
-                let span = p.input().cur_span();
-                syntax_error!(
-                    p,
-                    span,
-                    SyntaxError::Expected(format!("{return_token:?}"), cur)
-                )
-            }
+        for e in exprs.iter() {
+            let e = self.ast.get_node_in_sub_range(e);
+            self.emit_err(e.span(&self.ast), SyntaxError::TS1174);
+        }
+        // SAFETY: The clause is only parsed to be reported.
+        unsafe { self.ast.truncate(checkpoint) };
+        Ok(())
+    }
 
-            let type_pred_start = p.input().cur_pos();
-            let has_type_pred_asserts = p.input().cur() == Token::Asserts && {
-                let ctx = p.ctx();
-                peek!(p).is_some_and(|peek| {
-                    if peek.is_word() {
-                        !peek.is_reserved(ctx)
-                    } else {
-                        false
-                    }
-                })
-            };
-
-            if has_type_pred_asserts {
-                p.assert_and_bump(Token::Asserts);
-            }
-
-            let has_type_pred_is = p.is_ident_ref()
-                && peek!(p).is_some_and(|peek| peek == Token::Is)
-                && !p.input_mut().has_linebreak_between_cur_and_peeked();
-            let is_type_predicate = has_type_pred_asserts || has_type_pred_is;
-            if !is_type_predicate {
-                return p.parse_ts_type_ann(
-                    // eat_colon
-                    false,
-                    return_token_start,
-                );
-            }
+    /// Reports a second `implements` clause of a class.
+    pub(crate) fn report_ts_extra_implements(&mut self) -> PResult<()> {
+        if !self.input().syntax().typescript() || !self.input_mut().eat(Token::Implements) {
+            return Ok(());
+        }
+        self.emit_err(self.input().prev_span(), SyntaxError::TS1175);
 
-            let type_pred_var = p.parse_ident_name()?;
-            let type_ann = if has_type_pred_is {
-                p.assert_and_bump(Token::Is);
-                let pos = p.input().cur_pos();
-                Some(p.parse_ts_type_ann(false, pos)?)
-            } else {
-                None
-            };
-
-            let node = Box::new(TsType::TsTypePredicate(TsTypePredicate {
-                span: p.span(type_pred_start),
-                asserts: has_type_pred_asserts,
-                param_name: TsThisTypeOrIdent::Ident(type_pred_var.into()),
-                type_ann,
-            }));
-
-            Ok(Box::new(TsTypeAnn {
-                span: p.span(return_token_start),
-                type_ann: node,
-            }))
-        })
+        let checkpoint = self.ast.checkpoint();
+        self.parse_ts_heritage_clause()?;
+        // SAFETY: The clause is only parsed to be reported.
+        unsafe { self.ast.truncate(checkpoint) };
+        Ok(())
     }
 
-    fn is_start_of_expr(&mut self) -> bool {
-        self.is_start_of_left_hand_side_expr() || {
-            let cur = self.input().cur();
-            matches!(
-                cur,
-                Token::Plus
-                    | Token::Minus
-                    | Token::Tilde
-                    | Token::Bang
-                    | Token::Delete
-                    | Token::TypeOf
-                    | Token::Void
-                    | Token::PlusPlus
-                    | Token::MinusMinus
-                    | Token::Lt
-                    | Token::Await
-                    | Token::Yield
-            ) || (cur == Token::Hash && peek!(self).is_some_and(|peek| peek.is_word()))
+    /// Parses an index signature in a class body, `[key: string]: T`.
+    pub(crate) fn try_parse_ts_class_index_signature(
+        &mut self,
+        start: BytePos,
+        readonly: bool,
+        is_static: bool,
+    ) -> PResult<Option<TsIndexSignature>> {
+        if !self.input().syntax().typescript() {
+            return Ok(None);
         }
+        self.try_parse_ts_index_signature(start, readonly, is_static)
     }
 
-    #[cfg_attr(
-        feature = "tracing-spans",
-        tracing::instrument(level = "debug", skip_all)
-    )]
-    pub(super) fn try_parse_ts_type_args(&mut self) -> Option<Box<TsTypeParamInstantiation>> {
-        trace_cur!(self, try_parse_ts_type_args);
-        debug_assert!(self.input().syntax().typescript());
+    /// Reports the type parameters of a constructor.
+    pub(crate) fn report_ts_constructor_type_params(&mut self) -> PResult<()> {
+        if !self.syntax().typescript() || !self.input().is(Token::Lt) {
+            return Ok(());
+        }
+        let start = self.cur_pos();
+        if peek!(self).is_some_and(|cur| cur == Token::Lt) {
+            self.assert_and_bump(Token::Lt);
+            let start2 = self.cur_pos();
+            self.assert_and_bump(Token::Gt);
 
-        self.try_parse_ts(|p| {
-            let type_args = p.parse_ts_type_args()?;
-            p.assert_and_bump(Token::Gt);
-            let cur = p.input().cur();
-            if matches!(
-                cur,
-                Token::Lt
-                    | Token::Gt
-                    | Token::Eq
-                    | Token::RShift
-                    | Token::GtEq
-                    | Token::Plus
-                    | Token::Minus
-                    | Token::LParen
-                    | Token::NoSubstitutionTemplateLiteral
-                    | Token::TemplateHead
-                    | Token::BackQuote
-            )
-            // these should be type
-            // arguments in function
-            // call or template, not
-            // instantiation
-            // expression
-            {
-                Ok(None)
-            } else if p.input().had_line_break_before_cur()
-                || p.input().cur().is_bin_op()
-                || !p.is_start_of_expr()
-            {
-                Ok(Some(type_args))
-            } else {
-                Ok(None)
+            self.emit_err(self.span(start), SyntaxError::TS1098);
+            self.emit_err(self.span(start2), SyntaxError::TS1092);
+        } else {
+            let checkpoint = self.ast.checkpoint();
+            let type_params = self.try_parse_ts_type_params(false, true)?;
+
+            if let Some(type_params) = type_params {
+                for param in type_params.params(&self.ast).iter() {
+                    let param = self.ast.get_node_in_sub_range(param);
+                    self.emit_err(param.span(&self.ast), SyntaxError::TS1092);
+                }
             }
-        })
+            // SAFETY: The type parameters are only parsed to be reported.
+            unsafe { self.ast.truncate(checkpoint) };
+        }
+        Ok(())
     }
 
-    /// `tsTryParseType`
-    fn try_parse_ts_type(&mut self) -> PResult<Option<Box<TsType>>> {
-        if !cfg!(feature = "typescript") {
-            return Ok(None);
+    /// Reports the return type of a constructor.
+    pub(crate) fn report_ts_constructor_return_type(&mut self) -> PResult<()> {
+        if !self.syntax().typescript() || !self.input().is(Token::Colon) {
+            return Ok(());
         }
+        let start = self.cur_pos();
+        let checkpoint = self.ast.checkpoint();
+        let type_ann = self.parse_ts_type_ann(true, start)?;
 
-        self.eat_then_parse_ts_type(Token::Colon)
+        self.emit_err(
+            type_ann.type_ann(&self.ast).span(&self.ast),
+            SyntaxError::TS1093,
+        );
+        // SAFETY: The return type is only parsed to be reported.
+        unsafe { self.ast.truncate(checkpoint) };
+        Ok(())
     }
 
-    /// `tsTryParseTypeAnnotation`
-    #[cfg_attr(
-        feature = "tracing-spans",
-        tracing::instrument(level = "debug", skip_all)
-    )]
-    pub(crate) fn try_parse_ts_type_ann(&mut self) -> PResult<Option<Box<TsTypeAnn>>> {
-        if !cfg!(feature = "typescript") {
-            return Ok(None);
+    /// Returns `true` if a declaration without bindings is followed by `in` or
+    /// `of` in the head of a for loop, `for (let in x)`.
+    pub(crate) fn ts_at_for_in_of_head(&mut self) -> bool {
+        if !self.syntax().typescript() {
+            return false;
         }
-
-        if self.input().is(Token::Colon) {
-            let pos = self.cur_pos();
-            return self.parse_ts_type_ann(/* eat_colon */ true, pos).map(Some);
+        let cur = self.input().cur();
+        if cur != Token::In && cur != Token::Of {
+            return false;
         }
+        let res: PResult<bool> = self.ts_look_ahead(|p| {
+            //
+            if !p.input_mut().eat(Token::Of) && !p.input_mut().eat(Token::In) {
+                return Ok(false);
+            }
 
-        Ok(None)
-    }
-
-    /// `tsNextThenParseType`
-    pub(super) fn next_then_parse_ts_type(&mut self) -> PResult<Box<TsType>> {
-        debug_assert!(self.input().syntax().typescript());
+            p.parse_assignment_expr()?;
+            expect!(p, Token::RParen);
 
-        let result = self.in_type(|p| {
-            p.bump();
-            p.parse_ts_type()
+            Ok(true)
         });
+        matches!(res, Ok(true))
+    }
 
-        if !self.ctx().contains(Context::InType) && {
-            let cur = self.input().cur();
-            cur == Token::Lt || cur == Token::Gt
-        } {
-            self.input_mut().merge_lt_gt();
+    /// Parses the type annotation of a catch clause parameter, `catch (e: T)`.
+    pub(crate) fn try_parse_ts_catch_param_type_ann(&mut self) -> PResult<Option<TsTypeAnn>> {
+        let type_ann_start = self.cur_pos();
+        if !self.syntax().typescript() || !self.input_mut().eat(Token::Colon) {
+            return Ok(None);
         }
+        self.parse_stripped(|p| {
+            let ty = p.do_inside_of_context(Context::InType, Self::parse_ts_type)?;
+            // p.emit_err(ty.span(), SyntaxError::TS1196);
 
-        result
+            Ok(Some(p.ast.ts_type_ann(p.span(type_ann_start), ty)))
+        })
     }
 
-    /// `tsParseEnumMember`
-    fn parse_ts_enum_member(&mut self) -> PResult<TsEnumMember> {
-        debug_assert!(self.input().syntax().typescript());
+    /// Parses `const enum E {}` at `const`.
+    pub(crate) fn try_parse_ts_const_enum(
+        &mut self,
+        start: BytePos,
+    ) -> PResult<Option<TsEnumDecl>> {
+        if !self.input().syntax().typescript()
+            || !self.input().is(Token::Const)
+            || !peek!(self).is_some_and(|peek| peek == Token::Enum)
+        {
+            return Ok(None);
+        }
+        self.assert_and_bump(Token::Const);
+        self.assert_and_bump(Token::Enum);
+        self.parse_ts_enum_decl(start, true).map(Some)
+    }
 
-        let start = self.cur_pos();
-        // TypeScript allows computed property names with literal expressions in enums.
-        // Non-literal computed properties (like ["a" + "b"]) are rejected.
-        // We normalize literal computed properties (["\t"] → "\t") to keep AST simple.
-        // See https://github.com/swc-project/swc/issues/11160
+    /// Parses an interface, a type alias or an enum declaration.
+    pub(crate) fn try_parse_ts_decl_stmt(&mut self) -> PResult<Option<Decl>> {
+        if !self.input().syntax().typescript() {
+            return Ok(None);
+        }
         let cur = self.input().cur();
-        let id = if cur == Token::Str {
-            TsEnumMemberId::Str(self.parse_str_lit())
-        } else if cur == Token::Num {
-            let (value, raw) = self.input_mut().expect_number_token_and_bump();
-            let mut new_raw = String::new();
-
-            new_raw.push('"');
-            new_raw.push_str(raw.as_str());
-            new_raw.push('"');
-
-            let span = self.span(start);
-
-            // Recover from error
-            self.emit_err(span, SyntaxError::TS2452);
-
-            TsEnumMemberId::Str(Str {
-                span,
-                value: value.to_string().into(),
-                raw: Some(new_raw.into()),
-            })
-        } else if cur == Token::LBracket {
-            self.assert_and_bump(Token::LBracket);
-            let expr = self.parse_expr()?;
-            self.assert_and_bump(Token::RBracket);
-            let bracket_span = self.span(start);
-
-            match *expr {
-                Expr::Lit(Lit::Str(str_lit)) => {
-                    // String literal: ["\t"] → "\t"
-                    TsEnumMemberId::Str(str_lit)
-                }
-                Expr::Tpl(mut tpl) if tpl.exprs.is_empty() => {
-                    // Template literal without substitution: [`hello`] → "hello"
-
-                    let tpl = mem::take(tpl.quasis.first_mut().unwrap());
-
-                    let span = tpl.span;
-                    let value = tpl.cooked.unwrap();
-
-                    TsEnumMemberId::Str(Str {
-                        span,
-                        value,
-                        raw: None,
-                    })
-                }
-                _ => {
-                    // Non-literal expression: report error
-                    self.emit_err(bracket_span, SyntaxError::TS1164);
-                    TsEnumMemberId::Ident(Ident::new_no_ctxt(atom!(""), bracket_span))
-                }
-            }
-        } else if cur == Token::Error {
-            let err = self.input_mut().expect_error_token_and_bump();
-            return Err(err);
-        } else {
-            self.parse_ident_name()
-                .map(Ident::from)
-                .map(TsEnumMemberId::from)?
-        };
+        if !matches!(cur, Token::Interface | Token::Type | Token::Enum)
+            || !peek!(self).is_some_and(|peek| peek.is_word())
+            || self.input_mut().has_linebreak_between_cur_and_peeked()
+        {
+            return Ok(None);
+        }
 
-        let init = if self.input_mut().eat(Token::Eq) {
-            Some(self.parse_assignment_expr()?)
-        } else if self.input().cur() == Token::Comma || self.input().cur() == Token::RBrace {
-            None
-        } else {
-            let start = self.cur_pos();
-            self.bump();
-            self.input_mut().store(Token::Comma);
-            self.emit_err(Span::new_with_checked(start, start), SyntaxError::TS1005);
-            None
+        let start = self.input().cur_pos();
+        self.bump();
+        let decl = match cur {
+            Token::Interface => Decl::TsInterface(self.parse_ts_interface_decl(start)?),
+            Token::Type => Decl::TsTypeAlias(self.parse_ts_type_alias_decl(start)?),
+            _ => Decl::TsEnum(self.parse_ts_enum_decl(start, false)?),
         };
+        Ok(Some(decl))
+    }
 
-        Ok(TsEnumMember {
-            span: self.span(start),
-            id,
-            init,
-        })
+    /// Parses a declaration which starts with a contextual keyword, `ident`,
+    /// e.g. `declare`, `namespace` or `abstract`.
+    pub(crate) fn try_parse_ts_expr_stmt(
+        &mut self,
+        decorators: TypedSubRange<Decorator>,
+        ident: Ident,
+    ) -> PResult<Option<Decl>> {
+        if !self.input().syntax().typescript() {
+            return Ok(None);
+        }
+        self.parse_ts_expr_stmt(decorators, ident)
     }
 
-    /// `tsParseEnumDeclaration`
-    pub(crate) fn parse_ts_enum_decl(
+    /// Reports and parses an interface after a modifier of a class member,
+    /// `public interface I {}`.
+    pub(crate) fn try_parse_ts_interface_after_modifier(
         &mut self,
         start: BytePos,
-        is_const: bool,
-    ) -> PResult<Box<TsEnumDecl>> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let id = self.parse_ident_name()?;
-        expect!(self, Token::LBrace);
-        let members =
-            self.parse_ts_delimited_list(ParsingContext::EnumMembers, Self::parse_ts_enum_member)?;
-        expect!(self, Token::RBrace);
-
-        Ok(Box::new(TsEnumDecl {
-            span: self.span(start),
-            declare: false,
-            is_const,
-            id: id.into(),
-            members,
-        }))
-    }
-
-    /// `tsTryParseTypeOrTypePredicateAnnotation`
-    ///
-    /// Used for parsing return types.
-    fn try_parse_ts_type_or_type_predicate_ann(&mut self) -> PResult<Option<Box<TsTypeAnn>>> {
-        if !cfg!(feature = "typescript") {
+        expr: Expr,
+    ) -> PResult<Option<Decl>> {
+        if !self.syntax().typescript() {
             return Ok(None);
         }
-
-        if self.input().is(Token::Colon) {
-            self.parse_ts_type_or_type_predicate_ann(Token::Colon)
-                .map(Some)
-        } else {
-            Ok(None)
+        let Expr::Ident(i) = expr else {
+            return Ok(None);
+        };
+        match self.ast.get_utf8(i.sym(&self.ast)) {
+            "public" | "static" | "abstract" => {
+                if self.input_mut().eat(Token::Interface) {
+                    self.emit_err(i.span(&self.ast), SyntaxError::TS2427);
+                    return self
+                        .parse_ts_interface_decl(start)
+                        .map(|decl| Some(Decl::TsInterface(decl)));
+                }
+            }
+            _ => {}
         }
+        Ok(None)
     }
 
-    /// `tsParseTemplateLiteralType`
-    fn parse_ts_tpl_lit_type(&mut self) -> PResult<TsTplLitType> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
+    /// Parses the TypeScript forms of `export` after the keyword, e.g.
+    /// `export declare`, `export import A = B`, `export = x` and
+    /// `export as namespace A`.
+    pub(crate) fn try_parse_ts_export(
+        &mut self,
+        start: BytePos,
+        decorators: TypedSubRange<Decorator>,
+    ) -> PResult<Option<ModuleDecl>> {
+        if !self.input().syntax().typescript() {
+            return Ok(None);
+        }
 
-        self.assert_and_bump(Token::BackQuote);
+        let after_export_start = self.cur_pos();
 
-        let (types, quasis) = self.parse_ts_tpl_type_elements()?;
+        // "export declare" is equivalent to just "export".
+        if self.input_mut().eat(Token::Declare) {
+            // TODO: Remove
+            if let Some(decl) = self.try_parse_ts_declare(after_export_start, decorators)? {
+                return Ok(Some(
+                    self.ast.module_decl_export_decl(self.span(start), decl),
+                ));
+            }
+        }
 
-        expect!(self, Token::BackQuote);
+        let cur = self.input().cur();
+        if cur.is_word()
+            && let Some(decl) = self.try_parse_ts_export_decl(decorators)
+        {
+            return Ok(Some(
+                self.ast.module_decl_export_decl(self.span(start), decl),
+            ));
+        }
 
-        Ok(TsTplLitType {
-            span: self.span(start),
-            types,
-            quasis,
-        })
-    }
+        if self.input_mut().eat(Token::Import) {
+            let is_type_only =
+                self.input().is(Token::Type) && peek!(self).is_some_and(|p| p.is_word());
 
-    fn parse_ts_tpl_type_elements(&mut self) -> PResult<(Vec<Box<TsType>>, Vec<TplElement>)> {
-        if !cfg!(feature = "typescript") {
-            return Ok(Default::default());
-        }
+            if is_type_only {
+                self.assert_and_bump(Token::Type);
+            }
 
-        trace_cur!(self, parse_tpl_elements);
+            let id = self.parse_ident_name().map(|(span, sym)| {
+                let sym = self.to_utf8_ref(sym);
+                self.ast.ident(span, sym, false)
+            })?;
 
-        let mut types = Vec::new();
+            // export import A = B
+            return self
+                .parse_ts_import_equals_decl(start, id, /* is_export */ true, is_type_only)
+                .map(|decl| Some(ModuleDecl::TsImportEquals(decl)));
+        }
 
-        let cur_elem = self.parse_tpl_element(false)?;
-        let mut is_tail = cur_elem.tail;
-        let mut quasis = vec![cur_elem];
+        if self.input_mut().eat(Token::Eq) {
+            // `export = x;`
+            let expr = self.allow_in_expr(Self::parse_expr_inner)?;
+            self.expect_general_semi()?;
+            return Ok(Some(
+                self.ast
+                    .module_decl_ts_export_assignment(self.span(start), expr),
+            ));
+        }
 
-        while !is_tail {
-            expect!(self, Token::DollarLBrace);
-            types.push(self.parse_ts_type()?);
-            expect!(self, Token::RBrace);
-            let elem = self.parse_tpl_element(false)?;
-            is_tail = elem.tail;
-            quasis.push(elem);
+        if self.input_mut().eat(Token::As) {
+            // `export as namespace A;`
+            // See `parseNamespaceExportDeclaration` in TypeScript's own parser
+            expect!(self, Token::Namespace);
+            let id = self.parse_ident(false, false)?;
+            self.expect_general_semi()?;
+            return Ok(Some(
+                self.ast
+                    .module_decl_ts_namespace_export_decl(self.span(start), id),
+            ));
         }
 
-        Ok((types, quasis))
+        Ok(None)
     }
 
-    /// `tsParseLiteralTypeNode`
-    fn parse_ts_lit_type_node(&mut self) -> PResult<TsLitType> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
+    /// Parses `export default abstract class {}` and
+    /// `export default interface I {}` after `default`.
+    pub(crate) fn try_parse_ts_export_default(
+        &mut self,
+        start: BytePos,
+        decorators: TypedSubRange<Decorator>,
+    ) -> PResult<Option<ModuleDecl>> {
+        if !self.input().syntax().typescript() {
+            return Ok(None);
+        }
 
-        let lit = if self.input().is(Token::BackQuote) {
-            let tpl = self.parse_ts_tpl_lit_type()?;
-            TsLit::Tpl(tpl)
-        } else {
-            match self.parse_lit()? {
-                Lit::BigInt(n) => TsLit::BigInt(n),
-                Lit::Bool(n) => TsLit::Bool(n),
-                Lit::Num(n) => TsLit::Number(n),
-                Lit::Str(n) => TsLit::Str(n),
-                _ => unreachable!(),
+        if self.input().is(Token::Abstract)
+            && peek!(self).is_some_and(|cur| cur == Token::Class)
+            && !self.input_mut().has_linebreak_between_cur_and_peeked()
+        {
+            let class_start = self.cur_pos();
+            self.assert_and_bump(Token::Abstract);
+            let cur = self.input().cur();
+            if cur == Token::Error {
+                let err = self.input_mut().expect_error_token_and_bump();
+                return Err(err);
             }
-        };
 
-        Ok(TsLitType {
-            span: self.span(start),
-            lit,
-        })
-    }
+            return self
+                .parse_default_class(start, class_start, decorators, true)
+                .map(|decl| Some(ModuleDecl::ExportDefaultDecl(decl)));
+        }
+        if self.input().is(Token::Abstract)
+            && peek!(self).is_some_and(|cur| cur == Token::Interface)
+        {
+            self.emit_err(self.input().cur_span(), SyntaxError::TS1242);
+            self.assert_and_bump(Token::Abstract);
+        }
 
-    /// `tsParseHeritageClause`
-    pub(crate) fn parse_ts_heritage_clause(&mut self) -> PResult<Vec<TsExprWithTypeArgs>> {
-        debug_assert!(self.input().syntax().typescript());
+        if self.input().is(Token::Interface) {
+            let interface_start = self.cur_pos();
+            self.assert_and_bump(Token::Interface);
+            let decl = self.parse_ts_interface_decl(interface_start)?;
+            return Ok(Some(self.ast.module_decl_export_default_decl(
+                self.span(start),
+                DefaultDecl::TsInterfaceDecl(decl),
+            )));
+        }
 
-        self.parse_ts_delimited_list(
-            ParsingContext::HeritageClauseElement,
-            Self::parse_ts_heritage_clause_element,
-        )
+        Ok(None)
     }
 
-    fn parse_ts_heritage_clause_element(&mut self) -> PResult<TsExprWithTypeArgs> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-        // Note: TS uses parseLeftHandSideExpressionOrHigher,
-        // then has grammar errors later if it's not an EntityName.
-
-        let ident = self.parse_ident_name()?.into();
-        let expr = self.parse_subscripts(Callee::Expr(ident), true, true)?;
-        if !matches!(
-            &*expr,
-            Expr::Ident(..) | Expr::Member(..) | Expr::TsInstantiation(..)
-        ) {
-            self.emit_err(self.span(start), SyntaxError::TS2499);
+    /// Parses `import A = B` after `A`.
+    pub(crate) fn try_parse_ts_import_equals(
+        &mut self,
+        start: BytePos,
+        id: Ident,
+        is_type_only: bool,
+    ) -> PResult<Option<TsImportEqualsDecl>> {
+        if !self.input().syntax().typescript() || !self.input().is(Token::Eq) {
+            return Ok(None);
         }
+        self.parse_ts_import_equals_decl(start, id, false, is_type_only)
+            .map(Some)
+    }
 
-        match *expr {
-            Expr::TsInstantiation(v) => Ok(TsExprWithTypeArgs {
-                span: v.span,
-                expr: v.expr,
-                type_args: Some(v.type_args),
-            }),
-            _ => {
-                let type_args = if self.input().is(Token::Lt) {
-                    let ret = self.parse_ts_type_args()?;
-                    self.assert_and_bump(Token::Gt);
-                    Some(ret)
-                } else {
-                    None
-                };
-
-                Ok(TsExprWithTypeArgs {
-                    span: self.span(start),
-                    expr,
-                    type_args,
-                })
-            }
+    /// Parses the type arguments of a JSX element, `<C<T> />`.
+    pub(crate) fn try_parse_ts_jsx_type_args(
+        &mut self,
+    ) -> PResult<Option<TsTypeParamInstantiation>> {
+        if !self.input().syntax().typescript() || !self.input().is(Token::Lt) {
+            return Ok(None);
         }
+        self.parse_stripped(|p| {
+            Ok(p.try_parse_ts(|p| {
+                let ret = p.parse_ts_type_args()?;
+                p.assert_and_bump(Token::Gt);
+                Ok(Some(ret))
+            }))
+        })
     }
+}
 
-    /// `tsSkipParameterStart`
-    fn skip_ts_parameter_start(&mut self) -> PResult<bool> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let _ = self.eat_any_ts_modifier()?;
-
-        let cur = self.input().cur();
-
-        if cur == Token::Void {
-            Ok(false)
-        } else if cur.is_word() || cur == Token::This {
-            self.bump();
-            Ok(true)
-        } else if (cur == Token::LBrace || cur == Token::LBracket)
-            && self.parse_binding_pat_or_ident(false).is_ok()
-        {
-            Ok(true)
-        } else {
-            Ok(false)
-        }
+/// The hooks without the TypeScript parser, which parse nothing.
+#[cfg(not(feature = "typescript"))]
+impl<I: Tokens> Parser<I> {
+    pub(crate) fn try_parse_ts_assignment_at_jsx_tag(&mut self) -> Option<Expr> {
+        None
     }
 
-    /// `tsIsUnambiguouslyStartOfFunctionType`
-    fn is_ts_unambiguously_start_of_fn_type(&mut self) -> PResult<bool> {
-        debug_assert!(self.input().syntax().typescript());
+    pub(crate) fn try_parse_ts_generic_arrow(&mut self) -> Option<Expr> {
+        None
+    }
 
-        self.assert_and_bump(Token::LParen);
+    pub(crate) fn try_parse_ts_type_assertion(&mut self, _: BytePos) -> PResult<Option<Expr>> {
+        Ok(None)
+    }
 
-        let cur = self.input().cur();
-        if cur == Token::RParen || cur == Token::DotDotDot {
-            // ( )
-            // ( ...
-            return Ok(true);
-        }
-        if self.skip_ts_parameter_start()? {
-            let cur = self.input().cur();
-            if matches!(
-                cur,
-                Token::Colon | Token::Comma | Token::Eq | Token::QuestionMark
-            ) {
-                // ( xxx :
-                // ( xxx ,
-                // ( xxx ?
-                // ( xxx =
-                return Ok(true);
-            }
-            if self.input_mut().eat(Token::RParen) && self.input().cur() == Token::Arrow {
-                // ( xxx ) =>
-                return Ok(true);
-            }
-        }
-        Ok(false)
+    pub(crate) fn try_parse_ts_call_type_args(
+        &mut self,
+    ) -> Option<Option<TsTypeParamInstantiation>> {
+        None
     }
 
-    fn is_ts_start_of_fn_type(&mut self) -> bool {
-        debug_assert!(self.input().syntax().typescript());
+    pub(crate) fn try_parse_ts_subscript(
+        &mut self,
+        _: BytePos,
+        _: Expr,
+        _: bool,
+    ) -> PResult<Option<(Expr, bool)>> {
+        Ok(None)
+    }
 
-        if self.input().cur() == Token::Lt {
-            return true;
-        }
+    pub(crate) fn parse_ts_instantiation_args(
+        &mut self,
+    ) -> PResult<Option<TsTypeParamInstantiation>> {
+        Ok(None)
+    }
 
-        self.input().cur() == Token::LParen
-            && self
-                .ts_look_ahead(Self::is_ts_unambiguously_start_of_fn_type)
-                .unwrap_or_default()
+    pub(crate) fn parse_ts_explicit_type_args(
+        &mut self,
+    ) -> PResult<Option<Option<TsTypeParamInstantiation>>> {
+        Ok(None)
     }
 
-    /// `tsIsUnambiguouslyIndexSignature`
-    fn is_ts_unambiguously_index_signature(&mut self) -> bool {
-        debug_assert!(self.input().syntax().typescript());
+    pub(crate) fn try_parse_ts_new_type_args(
+        &mut self,
+    ) -> PResult<Option<TsTypeParamInstantiation>> {
+        Ok(None)
+    }
 
-        // Note: babel's comment is wrong
-        self.assert_and_bump(Token::LBracket); // Skip '['
+    pub(crate) fn try_parse_ts_as_or_satisfies(&mut self, _: Expr, _: u8) -> PResult<Option<Expr>> {
+        Ok(None)
+    }
 
-        // ',' is for error recovery
-        self.eat_ident_ref() && {
-            let cur = self.input().cur();
-            cur == Token::Comma || cur == Token::Colon
-        }
+    pub(crate) fn parse_ts_type_ann_of_arrow_param(&mut self, _: Pat, _: BytePos) -> PResult<()> {
+        Ok(())
     }
 
-    /// `tsTryParseIndexSignature`
-    pub(crate) fn try_parse_ts_index_signature(
+    pub(crate) fn try_parse_ts_arrow_in_cond(
         &mut self,
-        index_signature_start: BytePos,
-        readonly: bool,
-        is_static: bool,
-    ) -> PResult<Option<TsIndexSignature>> {
-        if !cfg!(feature = "typescript") {
-            return Ok(Default::default());
-        }
-
-        if !(self.input().cur() == Token::LBracket
-            && self.ts_look_ahead(Self::is_ts_unambiguously_index_signature))
-        {
-            return Ok(None);
-        }
+        _: BytePos,
+        _: Option<Span>,
+        _: &[AssignTargetOrSpread],
+        _: Option<Span>,
+    ) -> Option<Expr> {
+        None
+    }
 
-        expect!(self, Token::LBracket);
+    pub(crate) fn try_parse_ts_arrow_return_type(&mut self) -> Option<Option<TsTypeAnn>> {
+        None
+    }
 
-        let ident_start = self.cur_pos();
-        let mut id = self.parse_ident_name().map(BindingIdent::from)?;
-        let type_ann_start = self.cur_pos();
+    pub(crate) fn try_parse_ts_async_as(
+        &mut self,
+        _: BytePos,
+        _: Ident,
+        _: Ident,
+    ) -> PResult<Option<Expr>> {
+        Ok(None)
+    }
 
-        if self.input_mut().eat(Token::Comma) {
-            self.emit_err(id.span, SyntaxError::TS1096);
-        } else {
-            expect!(self, Token::Colon);
-        }
+    pub(crate) fn try_parse_ts_generic_async_arrow(&mut self) -> Option<Expr> {
+        None
+    }
 
-        let type_ann = self.parse_ts_type_ann(/* eat_colon */ false, type_ann_start)?;
-        id.span = self.span(ident_start);
-        id.type_ann = Some(type_ann);
+    pub(crate) fn eat_any_ts_modifier(&mut self) -> PResult<bool> {
+        Ok(false)
+    }
 
-        expect!(self, Token::RBracket);
+    pub(crate) fn try_parse_ts_type_ann_stripped(&mut self) -> PResult<Option<TsTypeAnn>> {
+        Ok(None)
+    }
 
-        let params = vec![TsFnParam::Ident(id)];
+    pub(crate) fn try_parse_ts_modifier(
+        &mut self,
+        _: &[&'static str],
+        _: bool,
+    ) -> PResult<Option<&'static str>> {
+        Ok(None)
+    }
 
-        let ty = self.try_parse_ts_type_ann()?;
-        let type_ann = ty;
+    pub(crate) fn try_parse_ts_access_modifier(&mut self) -> PResult<Option<Accessibility>> {
+        Ok(None)
+    }
 
-        self.parse_ts_type_member_semicolon()?;
+    pub(crate) fn parse_ts_formal_param_suffix(
+        &mut self,
+        _: BytePos,
+        pat: Pat,
+    ) -> PResult<(Pat, bool)> {
+        Ok((pat, false))
+    }
 
-        Ok(Some(TsIndexSignature {
-            span: self.span(index_signature_start),
-            readonly,
-            is_static,
-            params,
-            type_ann,
-        }))
+    pub(crate) fn try_parse_ts_fn_type_params(&mut self) -> PResult<Option<TsTypeParamDecl>> {
+        Ok(None)
     }
 
-    /// `tsIsExternalModuleReference`
-    fn is_ts_external_module_ref(&mut self) -> bool {
-        debug_assert!(self.input().syntax().typescript());
-        self.input().is(Token::Require) && peek!(self).is_some_and(|t| t == Token::LParen)
+    pub(crate) fn try_parse_ts_return_type(&mut self) -> PResult<Option<TsTypeAnn>> {
+        Ok(None)
     }
 
-    /// `tsParseModuleReference`
-    fn parse_ts_module_ref(&mut self) -> PResult<TsModuleRef> {
-        debug_assert!(self.input().syntax().typescript());
+    pub(crate) fn try_parse_ts_class_type_params(&mut self) -> PResult<Option<TsTypeParamDecl>> {
+        Ok(None)
+    }
 
-        if self.is_ts_external_module_ref() {
-            self.parse_ts_external_module_ref().map(From::from)
-        } else {
-            self.parse_ts_entity_name(/* allow_reserved_words */ false)
-                .map(From::from)
-        }
+    pub(crate) fn parse_ts_implements(&mut self) -> PResult<TypedSubRange<TsExprWithTypeArgs>> {
+        Ok(TypedSubRange::empty())
     }
 
-    /// `tsParseExternalModuleReference`
-    fn parse_ts_external_module_ref(&mut self) -> PResult<TsExternalModuleRef> {
-        debug_assert!(self.input().syntax().typescript());
+    pub(crate) fn report_ts_extra_super_classes(&mut self) -> PResult<()> {
+        Ok(())
+    }
 
-        let start = self.cur_pos();
-        expect!(self, Token::Require);
-        expect!(self, Token::LParen);
-        let cur = self.input().cur();
-        if cur == Token::Error {
-            let err = self.input_mut().expect_error_token_and_bump();
-            return Err(err);
-        } else if cur != Token::Str {
-            unexpected!(self, "a string literal")
-        }
-        let expr = self.parse_str_lit();
-        expect!(self, Token::RParen);
-        Ok(TsExternalModuleRef {
-            span: self.span(start),
-            expr,
-        })
+    pub(crate) fn report_ts_extra_implements(&mut self) -> PResult<()> {
+        Ok(())
     }
 
-    /// `tsParseImportEqualsDeclaration`
-    pub(crate) fn parse_ts_import_equals_decl(
+    pub(crate) fn try_parse_ts_class_index_signature(
         &mut self,
-        start: BytePos,
-        id: Ident,
-        is_export: bool,
-        is_type_only: bool,
-    ) -> PResult<Box<TsImportEqualsDecl>> {
-        debug_assert!(self.input().syntax().typescript());
-
-        expect!(self, Token::Eq);
-        let module_ref = self.parse_ts_module_ref()?;
-        self.expect_general_semi()?;
+        _: BytePos,
+        _: bool,
+        _: bool,
+    ) -> PResult<Option<TsIndexSignature>> {
+        Ok(None)
+    }
 
-        Ok(Box::new(TsImportEqualsDecl {
-            span: self.span(start),
-            id,
-            is_export,
-            is_type_only,
-            module_ref,
-        }))
+    pub(crate) fn report_ts_constructor_type_params(&mut self) -> PResult<()> {
+        Ok(())
     }
 
-    /// `tsParseBindingListForSignature`
-    ///
-    /// Eats ')` at the end but does not eat `(` at start.
-    fn parse_ts_binding_list_for_signature(&mut self) -> PResult<Vec<TsFnParam>> {
-        if !cfg!(feature = "typescript") {
-            return Ok(Default::default());
-        }
+    pub(crate) fn report_ts_constructor_return_type(&mut self) -> PResult<()> {
+        Ok(())
+    }
 
-        debug_assert!(self.input().syntax().typescript());
-
-        let params = self.parse_formal_params()?;
-        let mut list = Vec::with_capacity(4);
-
-        for param in params {
-            let item = match param.pat {
-                Pat::Ident(pat) => TsFnParam::Ident(pat),
-                Pat::Array(pat) => TsFnParam::Array(pat),
-                Pat::Object(pat) => TsFnParam::Object(pat),
-                Pat::Rest(pat) => TsFnParam::Rest(pat),
-                _ => unexpected!(
-                    self,
-                    "an identifier, [ for an array pattern, { for an object patter or ... for a \
-                     rest pattern"
-                ),
-            };
-            list.push(item);
-        }
-        expect!(self, Token::RParen);
-        Ok(list)
+    pub(crate) fn ts_at_for_in_of_head(&mut self) -> bool {
+        false
     }
 
-    /// `tsIsStartOfMappedType`
-    fn is_ts_start_of_mapped_type(&mut self) -> bool {
-        debug_assert!(self.input().syntax().typescript());
+    pub(crate) fn try_parse_ts_catch_param_type_ann(&mut self) -> PResult<Option<TsTypeAnn>> {
+        Ok(None)
+    }
 
-        self.bump();
-        if self.input_mut().eat(Token::Plus) || self.input_mut().eat(Token::Minus) {
-            return self.input().is(Token::Readonly);
-        }
-
-        self.input_mut().eat(Token::Readonly);
-
-        if !self.input().is(Token::LBracket) {
-            return false;
-        }
-        self.bump();
-        if !self.is_ident_ref() {
-            return false;
-        }
-        self.bump();
-
-        self.input().is(Token::In)
-    }
-
-    /// `tsParseSignatureMember`
-    fn parse_ts_signature_member(
-        &mut self,
-        kind: SignatureParsingMode,
-    ) -> PResult<Either<TsCallSignatureDecl, TsConstructSignatureDecl>> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-
-        if kind == SignatureParsingMode::TSConstructSignatureDeclaration {
-            expect!(self, Token::New);
-        }
-
-        // ----- inlined self.tsFillSignature(tt.colon, node);
-        let type_params = self.try_parse_ts_type_params(false, true)?;
-        expect!(self, Token::LParen);
-        let params = self.parse_ts_binding_list_for_signature()?;
-        let type_ann = if self.input().is(Token::Colon) {
-            Some(self.parse_ts_type_or_type_predicate_ann(Token::Colon)?)
-        } else {
-            None
-        };
-        // -----
-
-        self.parse_ts_type_member_semicolon()?;
-
-        match kind {
-            SignatureParsingMode::TSCallSignatureDeclaration => {
-                Ok(Either::Left(TsCallSignatureDecl {
-                    span: self.span(start),
-                    params,
-                    type_ann,
-                    type_params,
-                }))
-            }
-            SignatureParsingMode::TSConstructSignatureDeclaration => {
-                Ok(Either::Right(TsConstructSignatureDecl {
-                    span: self.span(start),
-                    params,
-                    type_ann,
-                    type_params,
-                }))
-            }
-        }
-    }
-
-    fn try_parse_ts_tuple_element_name(&mut self) -> Option<Pat> {
-        if !cfg!(feature = "typescript") {
-            return Default::default();
-        }
-
-        self.try_parse_ts(|p| {
-            let start = p.cur_pos();
-
-            let rest = if p.input_mut().eat(Token::DotDotDot) {
-                Some(p.input().prev_span())
-            } else {
-                None
-            };
-
-            let mut ident = p.parse_ident_name().map(Ident::from)?;
-            if p.input_mut().eat(Token::QuestionMark) {
-                ident.optional = true;
-                ident.span = ident.span.with_hi(p.input().prev_span().hi);
-            }
-            expect!(p, Token::Colon);
-
-            Ok(Some(if let Some(dot3_token) = rest {
-                RestPat {
-                    span: p.span(start),
-                    dot3_token,
-                    arg: ident.into(),
-                    type_ann: None,
-                }
-                .into()
-            } else {
-                ident.into()
-            }))
-        })
-    }
-
-    /// `tsParseTupleElementType`
-    fn parse_ts_tuple_element_type(&mut self) -> PResult<TsTupleElement> {
-        debug_assert!(self.input().syntax().typescript());
-
-        // parses `...TsType[]`
-        let start = self.cur_pos();
-
-        let label = self.try_parse_ts_tuple_element_name();
-
-        if self.input_mut().eat(Token::DotDotDot) {
-            let type_ann = self.parse_ts_type()?;
-            return Ok(TsTupleElement {
-                span: self.span(start),
-                label,
-                ty: Box::new(TsType::TsRestType(TsRestType {
-                    span: self.span(start),
-                    type_ann,
-                })),
-            });
-        }
-
-        let ty = self.parse_ts_type()?;
-        // parses `TsType?`
-        if self.input_mut().eat(Token::QuestionMark) {
-            let type_ann = ty;
-            return Ok(TsTupleElement {
-                span: self.span(start),
-                label,
-                ty: Box::new(TsType::TsOptionalType(TsOptionalType {
-                    span: self.span(start),
-                    type_ann,
-                })),
-            });
-        }
-
-        Ok(TsTupleElement {
-            span: self.span(start),
-            label,
-            ty,
-        })
-    }
-
-    /// `tsParseTupleType`
-    fn parse_ts_tuple_type(&mut self) -> PResult<TsTupleType> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-        let elems = self.parse_ts_bracketed_list(
-            ParsingContext::TupleElementTypes,
-            Self::parse_ts_tuple_element_type,
-            /* bracket */ true,
-            /* skipFirstToken */ false,
-        )?;
-
-        // Validate the elementTypes to ensure:
-        //   No mandatory elements may follow optional elements
-        //   If there's a rest element, it must be at the end of the tuple
-
-        let mut seen_optional_element = false;
-
-        for elem in elems.iter() {
-            match *elem.ty {
-                TsType::TsRestType(..) => {}
-                TsType::TsOptionalType(..) => {
-                    seen_optional_element = true;
-                }
-                _ if seen_optional_element => {
-                    syntax_error!(self, self.span(start), SyntaxError::TsRequiredAfterOptional)
-                }
-                _ => {}
-            }
-        }
-
-        Ok(TsTupleType {
-            span: self.span(start),
-            elem_types: elems,
-        })
-    }
-
-    /// `tsParseMappedType`
-    fn parse_ts_mapped_type(&mut self) -> PResult<TsMappedType> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-        expect!(self, Token::LBrace);
-        let mut readonly = None;
-        let cur = self.input().cur();
-        if cur == Token::Plus || cur == Token::Minus {
-            readonly = Some(if cur == Token::Plus {
-                TruePlusMinus::Plus
-            } else {
-                TruePlusMinus::Minus
-            });
-            self.bump();
-            expect!(self, Token::Readonly)
-        } else if self.input_mut().eat(Token::Readonly) {
-            readonly = Some(TruePlusMinus::True);
-        }
-
-        expect!(self, Token::LBracket);
-        let type_param = self.parse_ts_mapped_type_param()?;
-        let name_type = if self.input_mut().eat(Token::As) {
-            Some(self.parse_ts_type()?)
-        } else {
-            None
-        };
-        expect!(self, Token::RBracket);
-
-        let mut optional = None;
-        let cur = self.input().cur();
-        if cur == Token::Plus || cur == Token::Minus {
-            optional = Some(if cur == Token::Plus {
-                TruePlusMinus::Plus
-            } else {
-                TruePlusMinus::Minus
-            });
-            self.bump(); // +, -
-            expect!(self, Token::QuestionMark);
-        } else if self.input_mut().eat(Token::QuestionMark) {
-            optional = Some(TruePlusMinus::True);
-        }
-
-        let type_ann = self.try_parse_ts_type()?;
-        self.expect_general_semi()?;
-        expect!(self, Token::RBrace);
-
-        Ok(TsMappedType {
-            span: self.span(start),
-            readonly,
-            optional,
-            type_param,
-            name_type,
-            type_ann,
-        })
-    }
-
-    /// `tsParseParenthesizedType`
-    fn parse_ts_parenthesized_type(&mut self) -> PResult<TsParenthesizedType> {
-        debug_assert!(self.input().syntax().typescript());
-        trace_cur!(self, parse_ts_parenthesized_type);
-
-        let start = self.cur_pos();
-        expect!(self, Token::LParen);
-        let type_ann = self.parse_ts_type()?;
-        expect!(self, Token::RParen);
-        Ok(TsParenthesizedType {
-            span: self.span(start),
-            type_ann,
-        })
-    }
-
-    /// `tsParseTypeAliasDeclaration`
-    pub(crate) fn parse_ts_type_alias_decl(
-        &mut self,
-        start: BytePos,
-    ) -> PResult<Box<TsTypeAliasDecl>> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let id = self.parse_ident_name()?;
-        let type_params = self.try_parse_ts_type_params(true, false)?;
-        let type_ann = self.expect_then_parse_ts_type(Token::Eq, "=")?;
-        self.expect_general_semi()?;
-        Ok(Box::new(TsTypeAliasDecl {
-            declare: false,
-            span: self.span(start),
-            id: id.into(),
-            type_params,
-            type_ann,
-        }))
-    }
-
-    /// `tsParseFunctionOrConstructorType`
-    fn parse_ts_fn_or_constructor_type(
-        &mut self,
-        is_fn_type: bool,
-    ) -> PResult<TsFnOrConstructorType> {
-        trace_cur!(self, parse_ts_fn_or_constructor_type);
-
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-        let is_abstract = if !is_fn_type {
-            self.input_mut().eat(Token::Abstract)
-        } else {
-            false
-        };
-        if !is_fn_type {
-            expect!(self, Token::New);
-        }
-
-        // ----- inlined `self.tsFillSignature(tt.arrow, node)`
-        let type_params = self.try_parse_ts_type_params(false, true)?;
-        expect!(self, Token::LParen);
-        let params = self.parse_ts_binding_list_for_signature()?;
-        let type_ann = self.parse_ts_type_or_type_predicate_ann(Token::Arrow)?;
-        // ----- end
-
-        Ok(if is_fn_type {
-            TsFnOrConstructorType::TsFnType(TsFnType {
-                span: self.span(start),
-                type_params,
-                params,
-                type_ann,
-            })
-        } else {
-            TsFnOrConstructorType::TsConstructorType(TsConstructorType {
-                span: self.span(start),
-                type_params,
-                params,
-                type_ann,
-                is_abstract,
-            })
-        })
-    }
-
-    /// `tsParseUnionTypeOrHigher`
-    fn parse_ts_union_type_or_higher(&mut self) -> PResult<Box<TsType>> {
-        trace_cur!(self, parse_ts_union_type_or_higher);
-        debug_assert!(self.input().syntax().typescript());
-
-        self.parse_ts_union_or_intersection_type(
-            UnionOrIntersection::Union,
-            Self::parse_ts_intersection_type_or_higher,
-            Token::Pipe,
-        )
-    }
-
-    /// `tsParseIntersectionTypeOrHigher`
-    fn parse_ts_intersection_type_or_higher(&mut self) -> PResult<Box<TsType>> {
-        trace_cur!(self, parse_ts_intersection_type_or_higher);
-
-        debug_assert!(self.input().syntax().typescript());
-
-        self.parse_ts_union_or_intersection_type(
-            UnionOrIntersection::Intersection,
-            Self::parse_ts_type_operator_or_higher,
-            Token::Ampersand,
-        )
-    }
-
-    /// `tsParseTypeOperatorOrHigher`
-    fn parse_ts_type_operator_or_higher(&mut self) -> PResult<Box<TsType>> {
-        trace_cur!(self, parse_ts_type_operator_or_higher);
-        debug_assert!(self.input().syntax().typescript());
-
-        let operator = if self.input().is(Token::Keyof) {
-            Some(TsTypeOperatorOp::KeyOf)
-        } else if self.input().is(Token::Unique) {
-            Some(TsTypeOperatorOp::Unique)
-        } else if self.input().is(Token::Readonly) {
-            Some(TsTypeOperatorOp::ReadOnly)
-        } else {
-            None
-        };
-
-        match operator {
-            Some(operator) => self
-                .parse_ts_type_operator(operator)
-                .map(TsType::from)
-                .map(Box::new),
-            None => {
-                trace_cur!(self, parse_ts_type_operator_or_higher__not_operator);
-
-                if self.input().is(Token::Infer) {
-                    self.parse_ts_infer_type().map(TsType::from).map(Box::new)
-                } else {
-                    let readonly = self.parse_ts_modifier(&["readonly"], false)?.is_some();
-                    self.parse_ts_array_type_or_higher(readonly)
-                }
-            }
-        }
-    }
-
-    /// `tsParseTypeOperator`
-    fn parse_ts_type_operator(&mut self, op: TsTypeOperatorOp) -> PResult<TsTypeOperator> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-        match op {
-            TsTypeOperatorOp::Unique => expect!(self, Token::Unique),
-            TsTypeOperatorOp::KeyOf => expect!(self, Token::Keyof),
-            TsTypeOperatorOp::ReadOnly => expect!(self, Token::Readonly),
-            #[cfg(swc_ast_unknown)]
-            _ => unreachable!(),
-        }
-
-        let type_ann = self.parse_ts_type_operator_or_higher()?;
-        Ok(TsTypeOperator {
-            span: self.span(start),
-            op,
-            type_ann,
-        })
-    }
-
-    /// `tsParseInferType`
-    fn parse_ts_infer_type(&mut self) -> PResult<TsInferType> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-        expect!(self, Token::Infer);
-        let type_param_name = self.parse_ident_name()?;
-        let constraint = self.try_parse_ts(|p| {
-            expect!(p, Token::Extends);
-            let constraint = p.parse_ts_non_conditional_type();
-            if p.ctx().contains(Context::DisallowConditionalTypes)
-                || !p.input().is(Token::QuestionMark)
-            {
-                constraint.map(Some)
-            } else {
-                Ok(None)
-            }
-        });
-        let type_param = TsTypeParam {
-            span: type_param_name.span(),
-            name: type_param_name.into(),
-            is_in: false,
-            is_out: false,
-            is_const: false,
-            constraint,
-            default: None,
-        };
-        Ok(TsInferType {
-            span: self.span(start),
-            type_param,
-        })
-    }
-
-    /// `tsParseNonConditionalType`
-    fn parse_ts_non_conditional_type(&mut self) -> PResult<Box<TsType>> {
-        trace_cur!(self, parse_ts_non_conditional_type);
-
-        debug_assert!(self.input().syntax().typescript());
-
-        if self.is_ts_start_of_fn_type() {
-            return self
-                .parse_ts_fn_or_constructor_type(true)
-                .map(TsType::from)
-                .map(Box::new);
-        }
-        if (self.input().is(Token::Abstract) && peek!(self).is_some_and(|cur| cur == Token::New))
-            || self.input().is(Token::New)
-        {
-            // As in `new () => Date`
-            return self
-                .parse_ts_fn_or_constructor_type(false)
-                .map(TsType::from)
-                .map(Box::new);
-        }
-
-        self.parse_ts_union_type_or_higher()
-    }
-
-    /// `tsParseArrayTypeOrHigher`
-    fn parse_ts_array_type_or_higher(&mut self, readonly: bool) -> PResult<Box<TsType>> {
-        trace_cur!(self, parse_ts_array_type_or_higher);
-        debug_assert!(self.input().syntax().typescript());
-
-        let mut ty = self.parse_ts_non_array_type()?;
-
-        while !self.input().had_line_break_before_cur() && self.input_mut().eat(Token::LBracket) {
-            if self.input_mut().eat(Token::RBracket) {
-                ty = Box::new(TsType::TsArrayType(TsArrayType {
-                    span: self.span(ty.span_lo()),
-                    elem_type: ty,
-                }));
-            } else {
-                let index_type = self.parse_ts_type()?;
-                expect!(self, Token::RBracket);
-                ty = Box::new(TsType::TsIndexedAccessType(TsIndexedAccessType {
-                    span: self.span(ty.span_lo()),
-                    readonly,
-                    obj_type: ty,
-                    index_type,
-                }))
-            }
-        }
-
-        Ok(ty)
-    }
-
-    /// Be sure to be in a type context before calling self.
-    ///
-    /// `tsParseType`
-    pub(crate) fn parse_ts_type(&mut self) -> PResult<Box<TsType>> {
-        trace_cur!(self, parse_ts_type);
-
-        debug_assert!(self.input().syntax().typescript());
-
-        // Need to set `state.inType` so that we don't parse JSX in a type context.
-        debug_assert!(self.ctx().contains(Context::InType));
-
-        let start = self.cur_pos();
-
-        self.do_outside_of_context(Context::DisallowConditionalTypes, |p| {
-            let ty = p.parse_ts_non_conditional_type()?;
-            if p.input().had_line_break_before_cur() || !p.input_mut().eat(Token::Extends) {
-                return Ok(ty);
-            }
-
-            let check_type = ty;
-            let extends_type = p.do_inside_of_context(
-                Context::DisallowConditionalTypes,
-                Self::parse_ts_non_conditional_type,
-            )?;
-
-            expect!(p, Token::QuestionMark);
-
-            let true_type = p.parse_ts_type()?;
-
-            expect!(p, Token::Colon);
-
-            let false_type = p.parse_ts_type()?;
-
-            Ok(Box::new(TsType::TsConditionalType(TsConditionalType {
-                span: p.span(start),
-                check_type,
-                extends_type,
-                true_type,
-                false_type,
-            })))
-        })
-    }
-
-    /// `parsePropertyName` in babel.
-    ///
-    /// Returns `(computed, key)`.
-    fn parse_ts_property_name(&mut self) -> PResult<(bool, Box<Expr>)> {
-        let (computed, key) = if self.input_mut().eat(Token::LBracket) {
-            let key = self.parse_assignment_expr()?;
-            expect!(self, Token::RBracket);
-            (true, key)
-        } else {
-            self.do_inside_of_context(Context::InPropertyName, |p| {
-                // We check if it's valid for it to be a private name when we push it.
-                let cur = p.input().cur();
-
-                let key = if cur == Token::Num || cur == Token::Str {
-                    p.parse_new_expr()
-                } else if cur == Token::Error {
-                    let err = p.input_mut().expect_error_token_and_bump();
-                    return Err(err);
-                } else {
-                    p.parse_maybe_private_name().map(|e| match e {
-                        Either::Left(e) => {
-                            p.emit_err(e.span(), SyntaxError::PrivateNameInInterface);
-
-                            e.into()
-                        }
-                        Either::Right(e) => e.into(),
-                    })
-                };
-                key.map(|key| (false, key))
-            })?
-        };
-
-        Ok((computed, key))
-    }
-
-    /// `tsParsePropertyOrMethodSignature`
-    fn parse_ts_property_or_method_signature(
-        &mut self,
-        start: BytePos,
-        readonly: bool,
-    ) -> PResult<Either<TsPropertySignature, TsMethodSignature>> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let (computed, key) = self.parse_ts_property_name()?;
-
-        let optional = self.input_mut().eat(Token::QuestionMark);
-
-        let cur = self.input().cur();
-        if matches!(cur, Token::LParen | Token::Lt) {
-            if readonly {
-                syntax_error!(self, SyntaxError::ReadOnlyMethod);
-            }
-
-            let type_params = self.try_parse_ts_type_params(false, true)?;
-            expect!(self, Token::LParen);
-            let params = self.parse_ts_binding_list_for_signature()?;
-            let type_ann = if self.input().is(Token::Colon) {
-                self.parse_ts_type_or_type_predicate_ann(Token::Colon)
-                    .map(Some)?
-            } else {
-                None
-            };
-            // -----
-
-            self.parse_ts_type_member_semicolon()?;
-            Ok(Either::Right(TsMethodSignature {
-                span: self.span(start),
-                computed,
-                key,
-                optional,
-                type_params,
-                params,
-                type_ann,
-            }))
-        } else {
-            let type_ann = self.try_parse_ts_type_ann()?;
-
-            self.parse_ts_type_member_semicolon()?;
-            Ok(Either::Left(TsPropertySignature {
-                span: self.span(start),
-                computed,
-                readonly,
-                key,
-                optional,
-                type_ann,
-            }))
-        }
-    }
-
-    /// `tsParseTypeMember`
-    fn parse_ts_type_member(&mut self) -> PResult<TsTypeElement> {
-        debug_assert!(self.input().syntax().typescript());
-
-        fn into_type_elem(
-            e: Either<TsCallSignatureDecl, TsConstructSignatureDecl>,
-        ) -> TsTypeElement {
-            match e {
-                Either::Left(e) => e.into(),
-                Either::Right(e) => e.into(),
-            }
-        }
-        let cur = self.input().cur();
-        if cur == Token::LParen || cur == Token::Lt {
-            return self
-                .parse_ts_signature_member(SignatureParsingMode::TSCallSignatureDeclaration)
-                .map(into_type_elem);
-        }
-        if self.input().is(Token::New)
-            && self.ts_look_ahead(Self::is_ts_start_of_construct_signature)
-        {
-            return self
-                .parse_ts_signature_member(SignatureParsingMode::TSConstructSignatureDeclaration)
-                .map(into_type_elem);
-        }
-        // Instead of fullStart, we create a node here.
-        let start = self.cur_pos();
-        let readonly = self.parse_ts_modifier(&["readonly"], false)?.is_some();
-
-        let idx = self.try_parse_ts_index_signature(start, readonly, false)?;
-        if let Some(idx) = idx {
-            return Ok(idx.into());
-        }
-
-        if let Some(v) = self.try_parse_ts(|p| {
-            let start = p.input().cur_pos();
-
-            if readonly {
-                syntax_error!(p, SyntaxError::GetterSetterCannotBeReadonly)
-            }
-
-            let is_get = if p.input_mut().eat(Token::Get) {
-                true
-            } else {
-                expect!(p, Token::Set);
-                false
-            };
-
-            let (computed, key) = p.parse_ts_property_name()?;
-
-            if is_get {
-                expect!(p, Token::LParen);
-                expect!(p, Token::RParen);
-                let type_ann = p.try_parse_ts_type_ann()?;
-
-                p.parse_ts_type_member_semicolon()?;
-
-                Ok(Some(TsTypeElement::TsGetterSignature(TsGetterSignature {
-                    span: p.span(start),
-                    key,
-                    computed,
-                    type_ann,
-                })))
-            } else {
-                expect!(p, Token::LParen);
-                let params = p.parse_ts_binding_list_for_signature()?;
-                if params.is_empty() {
-                    syntax_error!(p, SyntaxError::SetterParamRequired)
-                }
-                let param = params.into_iter().next().unwrap();
-
-                p.parse_ts_type_member_semicolon()?;
-
-                Ok(Some(TsTypeElement::TsSetterSignature(TsSetterSignature {
-                    span: p.span(start),
-                    key,
-                    computed,
-                    param,
-                })))
-            }
-        }) {
-            return Ok(v);
-        }
-
-        self.parse_ts_property_or_method_signature(start, readonly)
-            .map(|e| match e {
-                Either::Left(e) => e.into(),
-                Either::Right(e) => e.into(),
-            })
-    }
-
-    /// `tsParseObjectTypeMembers`
-    fn parse_ts_object_type_members(&mut self) -> PResult<Vec<TsTypeElement>> {
-        debug_assert!(self.input().syntax().typescript());
-
-        expect!(self, Token::LBrace);
-        let members =
-            self.parse_ts_list(ParsingContext::TypeMembers, |p| p.parse_ts_type_member())?;
-        expect!(self, Token::RBrace);
-        Ok(members)
-    }
-
-    /// `tsParseTypeLiteral`
-    fn parse_ts_type_lit(&mut self) -> PResult<TsTypeLit> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-        let members = self.parse_ts_object_type_members()?;
-        Ok(TsTypeLit {
-            span: self.span(start),
-            members,
-        })
-    }
-
-    /// `tsParseInterfaceDeclaration`
-    pub(crate) fn parse_ts_interface_decl(
-        &mut self,
-        start: BytePos,
-    ) -> PResult<Box<TsInterfaceDecl>> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let id = self.parse_ident_name()?;
-        match &*id.sym {
-            "string" | "null" | "number" | "object" | "any" | "unknown" | "boolean" | "bigint"
-            | "symbol" | "void" | "never" | "intrinsic" => {
-                self.emit_err(id.span, SyntaxError::TS2427);
-            }
-            _ => {}
-        }
-
-        let type_params = self.try_parse_ts_type_params(true, false)?;
-
-        let extends = if self.input_mut().eat(Token::Extends) {
-            self.parse_ts_heritage_clause()?
-        } else {
-            Vec::new()
-        };
-
-        // Recover from
-        //
-        //     interface I extends A extends B {}
-        if self.input().is(Token::Extends) {
-            self.emit_err(self.input().cur_span(), SyntaxError::TS1172);
-
-            while self.input().cur() != Token::Eof && !self.input().is(Token::LBrace) {
-                self.bump();
-            }
-        }
-
-        let body_start = self.cur_pos();
-        let body = self.in_type(Self::parse_ts_object_type_members)?;
-        let body = TsInterfaceBody {
-            span: self.span(body_start),
-            body,
-        };
-        Ok(Box::new(TsInterfaceDecl {
-            span: self.span(start),
-            declare: false,
-            id: id.into(),
-            type_params,
-            extends,
-            body,
-        }))
-    }
-
-    /// `tsParseTypeAssertion`
-    pub(crate) fn parse_ts_type_assertion(&mut self, start: BytePos) -> PResult<TsTypeAssertion> {
-        debug_assert!(self.input().syntax().typescript());
-
-        if self.input().syntax().disallow_ambiguous_jsx_like() {
-            self.emit_err(self.span(start), SyntaxError::ReservedTypeAssertion);
-        }
-
-        // Not actually necessary to set state.inType because we never reach here if JSX
-        // plugin is enabled, but need `tsInType` to satisfy the assertion in
-        // `tsParseType`.
-        let type_ann = self.in_type(Self::parse_ts_type)?;
-        expect!(self, Token::Gt);
-        let expr = self.parse_unary_expr()?;
-        Ok(TsTypeAssertion {
-            span: self.span(start),
-            type_ann,
-            expr,
-        })
-    }
-
-    /// `tsParseImportType`
-    fn parse_ts_import_type(&mut self) -> PResult<TsImportType> {
-        let start = self.cur_pos();
-        self.assert_and_bump(Token::Import);
-
-        expect!(self, Token::LParen);
-
-        let cur = self.input().cur();
-
-        let arg = if cur == Token::Str {
-            self.parse_str_lit()
-        } else if cur == Token::Error {
-            let err = self.input_mut().expect_error_token_and_bump();
-            return Err(err);
-        } else {
-            let arg_span = self.input().cur_span();
-            self.bump();
-            self.emit_err(arg_span, SyntaxError::TS1141);
-            Str {
-                span: arg_span,
-                value: Wtf8Atom::default(),
-                raw: Some(atom!("\"\"")),
-            }
-        };
-
-        // the "assert" keyword is deprecated and this syntax is niche, so
-        // don't support it
-        let attributes = if self.input_mut().eat(Token::Comma)
-            && self.input().syntax().import_attributes()
-            && self.input().is(Token::LBrace)
-        {
-            Some(self.parse_ts_call_options()?)
-        } else {
-            None
-        };
-
-        expect!(self, Token::RParen);
-
-        let qualifier = if self.input_mut().eat(Token::Dot) {
-            self.parse_ts_entity_name(false).map(Some)?
-        } else {
-            None
-        };
-
-        let type_args = if self.input().is(Token::Lt) {
-            let ret = self.do_outside_of_context(
-                Context::ShouldNotLexLtOrGtAsType,
-                Self::parse_ts_type_args,
-            )?;
-            self.assert_and_bump(Token::Gt);
-            Some(ret)
-        } else {
-            None
-        };
-
-        Ok(TsImportType {
-            span: self.span(start),
-            arg,
-            qualifier,
-            type_args,
-            attributes,
-        })
-    }
-
-    fn parse_ts_call_options(&mut self) -> PResult<TsImportCallOptions> {
-        debug_assert!(self.input().syntax().typescript());
-        let start = self.cur_pos();
-        self.assert_and_bump(Token::LBrace);
-
-        expect!(self, Token::With);
-        expect!(self, Token::Colon);
-
-        let value = match self.parse_object_expr()? {
-            Expr::Object(v) => v,
-            _ => unreachable!(),
-        };
-        self.input_mut().eat(Token::Comma);
-        expect!(self, Token::RBrace);
-        Ok(TsImportCallOptions {
-            span: self.span(start),
-            with: Box::new(value),
-        })
-    }
-
-    /// `tsParseTypeQuery`
-    fn parse_ts_type_query(&mut self) -> PResult<TsTypeQuery> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-        expect!(self, Token::TypeOf);
-        let expr_name = if self.input().is(Token::Import) {
-            self.parse_ts_import_type().map(From::from)?
-        } else {
-            self.parse_ts_entity_name(true).map(From::from)?
-        };
-
-        let type_args = if !self.input().had_line_break_before_cur() && self.input().is(Token::Lt) {
-            let ret = self.do_outside_of_context(
-                Context::ShouldNotLexLtOrGtAsType,
-                Self::parse_ts_type_args,
-            )?;
-            self.assert_and_bump(Token::Gt);
-            Some(ret)
-        } else {
-            None
-        };
-
-        Ok(TsTypeQuery {
-            span: self.span(start),
-            expr_name,
-            type_args,
-        })
-    }
-
-    /// `tsParseModuleBlock`
-    fn parse_ts_module_block(&mut self) -> PResult<TsModuleBlock> {
-        trace_cur!(self, parse_ts_module_block);
-
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-        expect!(self, Token::LBrace);
-        let body = self.do_inside_of_context(Context::TsModuleBlock, |p| {
-            p.do_outside_of_context(Context::TopLevel, |p| {
-                p.parse_module_item_block_body(false, Some(Token::RBrace))
-            })
-        })?;
-
-        Ok(TsModuleBlock {
-            span: self.span(start),
-            body,
-        })
+    pub(crate) fn try_parse_ts_const_enum(&mut self, _: BytePos) -> PResult<Option<TsEnumDecl>> {
+        Ok(None)
     }
 
-    /// `tsParseModuleOrNamespaceDeclaration`
-    fn parse_ts_module_or_ns_decl(
-        &mut self,
-        start: BytePos,
-        namespace: bool,
-    ) -> PResult<Box<TsModuleDecl>> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let id = self.parse_ident_name()?;
-        let body: TsNamespaceBody = if self.input_mut().eat(Token::Dot) {
-            let inner_start = self.cur_pos();
-            let inner = self.parse_ts_module_or_ns_decl(inner_start, namespace)?;
-            let inner = TsNamespaceDecl {
-                span: inner.span,
-                id: match inner.id {
-                    TsModuleName::Ident(i) => i,
-                    _ => unreachable!(),
-                },
-                body: Box::new(inner.body.unwrap()),
-                declare: inner.declare,
-                global: inner.global,
-            };
-            inner.into()
-        } else {
-            self.parse_ts_module_block().map(From::from)?
-        };
-
-        Ok(Box::new(TsModuleDecl {
-            span: self.span(start),
-            declare: false,
-            id: TsModuleName::Ident(id.into()),
-            body: Some(body),
-            global: false,
-            namespace,
-        }))
+    pub(crate) fn try_parse_ts_decl_stmt(&mut self) -> PResult<Option<Decl>> {
+        Ok(None)
     }
 
-    /// `tsParseAmbientExternalModuleDeclaration`
-    fn parse_ts_ambient_external_module_decl(
+    pub(crate) fn try_parse_ts_expr_stmt(
         &mut self,
-        start: BytePos,
-    ) -> PResult<Box<TsModuleDecl>> {
-        debug_assert!(self.input().syntax().typescript());
-
-        let (global, id) = if self.input().is(Token::Global) {
-            let id = self.parse_ident_name()?;
-            (true, TsModuleName::Ident(id.into()))
-        } else if self.input().cur() == Token::Str {
-            let id = TsModuleName::Str(self.parse_str_lit());
-            (false, id)
-        } else {
-            unexpected!(self, "global or a string literal");
-        };
-
-        let body = if self.input().is(Token::LBrace) {
-            Some(self.parse_ts_module_block().map(TsNamespaceBody::from)?)
-        } else {
-            self.expect_general_semi()?;
-            None
-        };
-
-        Ok(Box::new(TsModuleDecl {
-            span: self.span(start),
-            declare: false,
-            id,
-            global,
-            body,
-            namespace: false,
-        }))
-    }
-
-    /// `tsParseNonArrayType`
-    fn parse_ts_non_array_type(&mut self) -> PResult<Box<TsType>> {
-        if !cfg!(feature = "typescript") {
-            unreachable!()
-        }
-        trace_cur!(self, parse_ts_non_array_type);
-        debug_assert!(self.input().syntax().typescript());
-
-        let start = self.cur_pos();
-
-        let cur = self.input().cur();
-        if cur.is_known_ident()
-            || matches!(
-                cur,
-                Token::Ident
-                    | Token::Void
-                    | Token::Yield
-                    | Token::Null
-                    | Token::Await
-                    | Token::Break
-            )
-        {
-            if self.input().is(Token::Asserts)
-                && peek!(self).is_some_and(|peek| peek == Token::This)
-            {
-                self.bump();
-                let this_keyword = self.parse_ts_this_type_node()?;
-                return self
-                    .parse_ts_this_type_predicate(start, true, this_keyword)
-                    .map(TsType::from)
-                    .map(Box::new);
-            }
-            let kind = if self.input().is(Token::Void) {
-                Some(TsKeywordTypeKind::TsVoidKeyword)
-            } else if self.input().is(Token::Null) {
-                Some(TsKeywordTypeKind::TsNullKeyword)
-            } else if self.input().is(Token::Any) {
-                Some(TsKeywordTypeKind::TsAnyKeyword)
-            } else if self.input().is(Token::Boolean) {
-                Some(TsKeywordTypeKind::TsBooleanKeyword)
-            } else if self.input().is(Token::Bigint) {
-                Some(TsKeywordTypeKind::TsBigIntKeyword)
-            } else if self.input().is(Token::Never) {
-                Some(TsKeywordTypeKind::TsNeverKeyword)
-            } else if self.input().is(Token::Number) {
-                Some(TsKeywordTypeKind::TsNumberKeyword)
-            } else if self.input().is(Token::Object) {
-                Some(TsKeywordTypeKind::TsObjectKeyword)
-            } else if self.input().is(Token::String) {
-                Some(TsKeywordTypeKind::TsStringKeyword)
-            } else if self.input().is(Token::Symbol) {
-                Some(TsKeywordTypeKind::TsSymbolKeyword)
-            } else if self.input().is(Token::Unknown) {
-                Some(TsKeywordTypeKind::TsUnknownKeyword)
-            } else if self.input().is(Token::Undefined) {
-                Some(TsKeywordTypeKind::TsUndefinedKeyword)
-            } else if self.input().is(Token::Intrinsic) {
-                Some(TsKeywordTypeKind::TsIntrinsicKeyword)
-            } else {
-                None
-            };
-
-            let peeked_is_dot = peek!(self).is_some_and(|cur| cur == Token::Dot);
-
-            match kind {
-                Some(kind) if !peeked_is_dot => {
-                    self.bump();
-                    return Ok(Box::new(TsType::TsKeywordType(TsKeywordType {
-                        span: self.span(start),
-                        kind,
-                    })));
-                }
-                _ => {
-                    return self.parse_ts_type_ref().map(TsType::from).map(Box::new);
-                }
-            }
-        } else if matches!(
-            cur,
-            Token::BigInt | Token::Str | Token::Num | Token::True | Token::False | Token::BackQuote
-        ) {
-            return self
-                .parse_ts_lit_type_node()
-                .map(TsType::from)
-                .map(Box::new);
-        } else if cur == Token::NoSubstitutionTemplateLiteral || cur == Token::TemplateHead {
-            return self.parse_tagged_tpl_ty().map(TsType::from).map(Box::new);
-        } else if cur == Token::Minus {
-            let start = self.cur_pos();
-
-            self.bump();
-
-            let cur = self.input().cur();
-            if !(cur == Token::Num || cur == Token::BigInt) {
-                unexpected!(self, "numeric literal or bigint literal")
-            }
-
-            let lit = self.parse_lit()?;
-            let lit = match lit {
-                Lit::Num(Number { span, value, raw }) => {
-                    let mut new_raw = String::from("-");
-
-                    match raw {
-                        Some(raw) => {
-                            new_raw.push_str(&raw);
-                        }
-                        _ => {
-                            write!(new_raw, "{value}").unwrap();
-                        }
-                    };
-
-                    TsLit::Number(Number {
-                        span,
-                        value: -value,
-                        raw: Some(new_raw.into()),
-                    })
-                }
-                Lit::BigInt(BigInt { span, value, raw }) => {
-                    let mut new_raw = String::from("-");
-
-                    match raw {
-                        Some(raw) => {
-                            new_raw.push_str(&raw);
-                        }
-                        _ => {
-                            write!(new_raw, "{value}").unwrap();
-                        }
-                    };
-
-                    TsLit::BigInt(BigInt {
-                        span,
-                        value: Box::new(-*value),
-                        raw: Some(new_raw.into()),
-                    })
-                }
-                _ => unreachable!(),
-            };
-
-            return Ok(Box::new(TsType::TsLitType(TsLitType {
-                span: self.span(start),
-                lit,
-            })));
-        } else if cur == Token::Import {
-            return self.parse_ts_import_type().map(TsType::from).map(Box::new);
-        } else if cur == Token::This {
-            let start = self.cur_pos();
-            let this_keyword = self.parse_ts_this_type_node()?;
-            return if !self.input().had_line_break_before_cur() && self.input().is(Token::Is) {
-                self.parse_ts_this_type_predicate(start, false, this_keyword)
-                    .map(TsType::from)
-                    .map(Box::new)
-            } else {
-                Ok(Box::new(TsType::TsThisType(this_keyword)))
-            };
-        } else if cur == Token::TypeOf {
-            return self.parse_ts_type_query().map(TsType::from).map(Box::new);
-        } else if cur == Token::LBrace {
-            return if self.ts_look_ahead(Self::is_ts_start_of_mapped_type) {
-                self.parse_ts_mapped_type().map(TsType::from).map(Box::new)
-            } else {
-                self.parse_ts_type_lit().map(TsType::from).map(Box::new)
-            };
-        } else if cur == Token::LBracket {
-            return self.parse_ts_tuple_type().map(TsType::from).map(Box::new);
-        } else if cur == Token::LParen {
-            return self
-                .parse_ts_parenthesized_type()
-                .map(TsType::from)
-                .map(Box::new);
-        }
-
-        //   switch (self.state.type) {
-        //   }
-
-        unexpected!(
-            self,
-            "an identifier, void, yield, null, await, break, a string literal, a numeric literal, \
-             true, false, `, -, import, this, typeof, {, [, ("
-        )
+        _: TypedSubRange<Decorator>,
+        _: Ident,
+    ) -> PResult<Option<Decl>> {
+        Ok(None)
     }
 
-    /// `tsParseExpressionStatement`
-    pub(crate) fn parse_ts_expr_stmt(
+    pub(crate) fn try_parse_ts_interface_after_modifier(
         &mut self,
-        decorators: Vec<Decorator>,
-        expr: Ident,
+        _: BytePos,
+        _: Expr,
     ) -> PResult<Option<Decl>> {
-        if !cfg!(feature = "typescript") {
-            return Ok(Default::default());
-        }
-
-        let start = expr.span_lo();
-
-        match &*expr.sym {
-            "declare" => {
-                let decl = self.try_parse_ts_declare(start, decorators)?;
-                if let Some(decl) = decl {
-                    Ok(Some(make_decl_declare(decl)))
-                } else {
-                    Ok(None)
-                }
-            }
-            "global" => {
-                // `global { }` (with no `declare`) may appear inside an ambient module
-                // declaration.
-                // Would like to use tsParseAmbientExternalModuleDeclaration here, but already
-                // ran past "global".
-                if self.input().is(Token::LBrace) {
-                    let global = true;
-                    let id = TsModuleName::Ident(expr);
-                    let body = self
-                        .parse_ts_module_block()
-                        .map(TsNamespaceBody::from)
-                        .map(Some)?;
-                    Ok(Some(
-                        TsModuleDecl {
-                            span: self.span(start),
-                            global,
-                            declare: false,
-                            namespace: false,
-                            id,
-                            body,
-                        }
-                        .into(),
-                    ))
-                } else {
-                    Ok(None)
-                }
-            }
-            _ => self.parse_ts_decl(start, decorators, expr.sym, /* next */ false),
-        }
+        Ok(None)
     }
 
-    /// `tsTryParseDeclare`
-    pub(crate) fn try_parse_ts_declare(
+    pub(crate) fn try_parse_ts_export(
         &mut self,
-        start: BytePos,
-        decorators: Vec<Decorator>,
-    ) -> PResult<Option<Decl>> {
-        if !self.syntax().typescript() {
-            return Ok(None);
-        }
-
-        if self
-            .ctx()
-            .contains(Context::InDeclare | Context::TsModuleBlock)
-        {
-            let span_of_declare = self.span(start);
-            self.emit_err(span_of_declare, SyntaxError::TS1038);
-        }
-
-        let declare_start = start;
-        self.do_inside_of_context(Context::InDeclare, |p| {
-            if p.input().is(Token::Function) {
-                return p
-                    .parse_fn_decl(decorators)
-                    .map(|decl| match decl {
-                        Decl::Fn(f) => FnDecl {
-                            declare: true,
-                            function: Box::new(Function {
-                                span: Span {
-                                    lo: declare_start,
-                                    ..f.function.span
-                                },
-                                ..*f.function
-                            }),
-                            ..f
-                        }
-                        .into(),
-                        _ => decl,
-                    })
-                    .map(Some);
-            }
-
-            if p.input().is(Token::Class) {
-                return p
-                    .parse_class_decl(start, start, decorators, false)
-                    .map(|decl| match decl {
-                        Decl::Class(c) => ClassDecl {
-                            declare: true,
-                            class: Box::new(Class {
-                                span: Span {
-                                    lo: declare_start,
-                                    ..c.class.span
-                                },
-                                ..*c.class
-                            }),
-                            ..c
-                        }
-                        .into(),
-                        _ => decl,
-                    })
-                    .map(Some);
-            }
-
-            if p.input().is(Token::Const) && peek!(p).is_some_and(|peek| peek == Token::Enum) {
-                p.assert_and_bump(Token::Const);
-                p.assert_and_bump(Token::Enum);
-
-                return p
-                    .parse_ts_enum_decl(start, /* is_const */ true)
-                    .map(|decl| TsEnumDecl {
-                        declare: true,
-                        span: Span {
-                            lo: declare_start,
-                            ..decl.span
-                        },
-                        ..*decl
-                    })
-                    .map(Box::new)
-                    .map(From::from)
-                    .map(Some);
-            }
-
-            let cur = p.input().cur();
-            if matches!(cur, Token::Const | Token::Var | Token::Let) {
-                return p
-                    .parse_var_stmt(false)
-                    .map(|decl| VarDecl {
-                        declare: true,
-                        span: Span {
-                            lo: declare_start,
-                            ..decl.span
-                        },
-                        ..*decl
-                    })
-                    .map(Box::new)
-                    .map(From::from)
-                    .map(Some);
-            }
-
-            if p.input().is(Token::Global) {
-                return p
-                    .parse_ts_ambient_external_module_decl(start)
-                    .map(Decl::from)
-                    .map(make_decl_declare)
-                    .map(Some);
-            } else if p.input().cur().is_word() {
-                let value = p.input_mut().cur().take_word(p.input_mut()).unwrap();
-                return p
-                    .parse_ts_decl(start, decorators, value, /* next */ true)
-                    .map(|v| v.map(make_decl_declare));
-            }
-
-            Ok(None)
-        })
+        _: BytePos,
+        _: TypedSubRange<Decorator>,
+    ) -> PResult<Option<ModuleDecl>> {
+        Ok(None)
     }
 
-    /// `tsTryParseExportDeclaration`
-    ///
-    /// Note: this won't be called unless the keyword is allowed in
-    /// `shouldParseExportDeclaration`.
-    pub(crate) fn try_parse_ts_export_decl(
+    pub(crate) fn try_parse_ts_export_default(
         &mut self,
-        decorators: Vec<Decorator>,
-        value: Atom,
-    ) -> Option<Decl> {
-        if !cfg!(feature = "typescript") {
-            return None;
-        }
-
-        self.try_parse_ts(|p| {
-            let start = p.cur_pos();
-            let opt = p.parse_ts_decl(start, decorators, value, true)?;
-            Ok(opt)
-        })
+        _: BytePos,
+        _: TypedSubRange<Decorator>,
+    ) -> PResult<Option<ModuleDecl>> {
+        Ok(None)
     }
 
-    /// Common to tsTryParseDeclare, tsTryParseExportDeclaration, and
-    /// tsParseExpressionStatement.
-    ///
-    /// `tsParseDeclaration`
-    fn parse_ts_decl(
+    pub(crate) fn try_parse_ts_import_equals(
         &mut self,
-        start: BytePos,
-        decorators: Vec<Decorator>,
-        value: Atom,
-        next: bool,
-    ) -> PResult<Option<Decl>> {
-        if !cfg!(feature = "typescript") {
-            return Ok(Default::default());
-        }
-
-        match &*value {
-            "abstract" => {
-                if next
-                    || (self.input().is(Token::Class) && !self.input().had_line_break_before_cur())
-                {
-                    if next {
-                        self.bump();
-                    }
-                    return Ok(Some(self.parse_class_decl(start, start, decorators, true)?));
-                }
-            }
-
-            "enum" => {
-                if next || self.is_ident_ref() {
-                    if next {
-                        self.bump();
-                    }
-                    return self
-                        .parse_ts_enum_decl(start, /* is_const */ false)
-                        .map(From::from)
-                        .map(Some);
-                }
-            }
-
-            "interface" => {
-                if next || (self.is_ident_ref()) {
-                    if next {
-                        self.bump();
-                    }
-
-                    return self
-                        .parse_ts_interface_decl(start)
-                        .map(From::from)
-                        .map(Some);
-                }
-            }
-
-            "module" if !self.input().had_line_break_before_cur() => {
-                if next {
-                    self.bump();
-                }
-
-                let cur = self.input().cur();
-                if cur == Token::Str {
-                    return self
-                        .parse_ts_ambient_external_module_decl(start)
-                        .map(From::from)
-                        .map(Some);
-                } else if cur == Token::Error {
-                    let err = self.input_mut().expect_error_token_and_bump();
-                    return Err(err);
-                } else if cur == Token::Eof {
-                    return Err(self.eof_error());
-                } else if next || self.is_ident_ref() {
-                    return self
-                        .parse_ts_module_or_ns_decl(start, false)
-                        .map(From::from)
-                        .map(Some);
-                }
-            }
-
-            "namespace" => {
-                if next || self.is_ident_ref() {
-                    if next {
-                        self.bump();
-                    }
-                    return self
-                        .parse_ts_module_or_ns_decl(start, true)
-                        .map(From::from)
-                        .map(Some);
-                }
-            }
-
-            "type" => {
-                if next || (!self.input().had_line_break_before_cur() && self.is_ident_ref()) {
-                    if next {
-                        self.bump();
-                    }
-                    return self
-                        .parse_ts_type_alias_decl(start)
-                        .map(From::from)
-                        .map(Some);
-                }
-            }
-
-            _ => {}
-        }
-
+        _: BytePos,
+        _: Ident,
+        _: bool,
+    ) -> PResult<Option<TsImportEqualsDecl>> {
         Ok(None)
     }
 
-    /// `tsTryParseGenericAsyncArrowFunction`
-    pub(crate) fn try_parse_ts_generic_async_arrow_fn(
+    pub(crate) fn try_parse_ts_jsx_type_args(
         &mut self,
-        start: BytePos,
-    ) -> PResult<Option<ArrowExpr>> {
-        if !cfg!(feature = "typescript") {
-            return Ok(Default::default());
-        }
-
-        let cur = self.input().cur();
-        let res = if cur == Token::Lt || cur == Token::JSXTagStart {
-            self.try_parse_ts(|p| {
-                let type_params = p.parse_ts_type_params(false, false)?;
-                // Don't use overloaded parseFunctionParams which would look for "<" again.
-                expect!(p, Token::LParen);
-                let params: Vec<Pat> = p
-                    .parse_formal_params()?
-                    .into_iter()
-                    .map(|p| p.pat)
-                    .collect();
-                expect!(p, Token::RParen);
-                let return_type = p.try_parse_ts_type_or_type_predicate_ann()?;
-                expect!(p, Token::Arrow);
-
-                Ok(Some((type_params, params, return_type)))
-            })
-        } else {
-            None
-        };
-
-        let (type_params, params, return_type) = match res {
-            Some(v) => v,
-            None => return Ok(None),
-        };
-
-        self.do_inside_of_context(Context::InAsync, |p| {
-            p.do_outside_of_context(Context::InGenerator, |p| {
-                let is_generator = false;
-                let is_async = true;
-                let body = p.parse_fn_block_or_expr_body(
-                    true,
-                    false,
-                    true,
-                    params.is_simple_parameter_list(),
-                )?;
-                Ok(Some(ArrowExpr {
-                    span: p.span(start),
-                    body,
-                    is_async,
-                    is_generator,
-                    type_params: Some(type_params),
-                    params,
-                    return_type,
-                    ..Default::default()
-                }))
-            })
-        })
+    ) -> PResult<Option<TsTypeParamInstantiation>> {
+        Ok(None)
     }
 }
@@ -35,6 +35,7 @@ impl IsSimpleParameterList for TypedSubRange<ParamOrTsParamProp> {
         self.iter().all(|param| {
             let param = ast.get_node_in_sub_range(param);
             match param {
+                ParamOrTsParamProp::TsParamProp(..) => true,
                 ParamOrTsParamProp::Param(param) => matches!(param.pat(ast), Pat::Ident(_)),
             }
         })
@@ -125,16 +126,14 @@ impl ExprExt for Expr {
             | Expr::JSXElement(..)
             | Expr::JSXFragment(..) => false,
 
-            // // typescript
-            // Expr::TsNonNull(TsNonNullExpr { ref expr, .. })
-            // | Expr::TsTypeAssertion(TsTypeAssertion { ref expr, .. })
-            // | Expr::TsAs(TsAsExpr { ref expr, .. })
-            // | Expr::TsInstantiation(TsInstantiation { ref expr, .. })
-            // | Expr::TsSatisfies(TsSatisfiesExpr { ref expr, .. }) => {
-            //     expr.is_valid_simple_assignment_target(strict)
-            // }
+            // typescript
+            Expr::TsNonNull(e) => e.expr(ast).is_valid_simple_assignment_target(ast, strict),
+            Expr::TsTypeAssertion(e) => e.expr(ast).is_valid_simple_assignment_target(ast, strict),
+            Expr::TsAs(e) => e.expr(ast).is_valid_simple_assignment_target(ast, strict),
+            Expr::TsInstantiation(e) => e.expr(ast).is_valid_simple_assignment_target(ast, strict),
+            Expr::TsSatisfies(e) => e.expr(ast).is_valid_simple_assignment_target(ast, strict),
 
-            // Expr::TsConstAssertion(..) => false,
+            Expr::TsConstAssertion(..) => false,
             Expr::Invalid(..) => false,
             #[cfg(swc_ast_unknown)]
             _ => unreachable!(),
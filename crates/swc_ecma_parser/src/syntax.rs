@@ -5,7 +5,6 @@ pub enum Syntax {
     /// This variant requires the cargo feature `typescript` to be enabled.
     #[cfg(feature = "typescript")]
     #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
-    #[serde(rename = "typescript")]
     Typescript(TsSyntax),
 }
 
@@ -139,6 +138,14 @@ impl Syntax {
         }
     }
 
+    pub fn strip_types(self) -> bool {
+        match self {
+            #[cfg(feature = "typescript")]
+            Syntax::Typescript(t) => t.strip_types,
+            _ => false,
+        }
+    }
+
     pub fn explicit_resource_management(&self) -> bool {
         match self {
             Syntax::Es(EsSyntax {
@@ -179,10 +186,21 @@ pub struct TsSyntax {
     /// `<X>()=>{}` type arguments)
     /// see: https://babeljs.io/docs/en/babel-plugin-transform-typescript#disallowambiguousjsxlike
     pub disallow_ambiguous_jsx_like: bool,
+
+    /// Erase types while parsing, like Node's `--experimental-strip-types`.
+    ///
+    /// Type annotations, interfaces, type aliases, `declare` declarations,
+    /// `as`/`satisfies`/non-null expressions and type arguments are parsed
+    /// and checked, then dropped from the arena, so the parsed program only
+    /// holds JavaScript nodes.
+    /// Constructs that are not erasable (enums, namespaces with values,
+    /// parameter properties, `import =` and `export =`) are reported as errors.
+    pub strip_types: bool,
 }
 
 impl TsSyntax {
-    fn _into_flags(self) -> SyntaxFlags {
+    #[cfg(feature = "typescript")]
+    fn into_flags(self) -> SyntaxFlags {
         let mut flags = SyntaxFlags::TS
             .union(SyntaxFlags::AUTO_ACCESSORS)
             .union(SyntaxFlags::IMPORT_ATTRIBUTES)
@@ -208,6 +226,9 @@ impl TsSyntax {
         if self.disallow_ambiguous_jsx_like {
             flags |= SyntaxFlags::DISALLOW_AMBIGUOUS_JSX_LIKE;
         }
+        if self.strip_types {
+            flags |= SyntaxFlags::STRIP_TYPES;
+        }
         flags
     }
 }
@@ -363,6 +384,20 @@ impl SyntaxFlags {
     pub const fn explicit_resource_management(&self) -> bool {
         self.contains(SyntaxFlags::EXPLICIT_RESOURCE_MANAGEMENT)
     }
+
+    /// Should we erase types instead of building TypeScript nodes?
+    #[cfg(not(feature = "typescript"))]
+    #[inline(always)]
+    pub const fn strip_types(&self) -> bool {
+        false
+    }
+
+    /// Should we erase types instead of building TypeScript nodes?
+    #[cfg(feature = "typescript")]
+    #[inline(always)]
+    pub const fn strip_types(&self) -> bool {
+        self.contains(SyntaxFlags::STRIP_TYPES)
+    }
 }
 
 bitflags::bitflags! {
@@ -383,5 +418,6 @@ bitflags::bitflags! {
         const DISALLOW_AMBIGUOUS_JSX_LIKE = 1 << 12;
         const TS = 1 << 13;
         const NO_PAREN = 1 << 14;
+        const STRIP_TYPES = 1 << 15;
     }
 }
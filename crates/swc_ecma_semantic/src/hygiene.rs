@@ -0,0 +1,425 @@
+//! Hygiene of generated identifiers.
+//!
+//! [Ident] doesn't store a syntax context, so the marks applied by transforms
+//! are kept in [Hygiene], a side table keyed by the node id of the identifier.
+//! The resolver treats identifiers with the same name but different marks as
+//! different variables, like `private_ident!` of swc, and [Hygiene::rename]
+//! gives them different names before the program is printed.
+//!
+//! The marks follow the node ids, so they aren't carried over by
+//! [CloneIn::clone_in], [CloneInto] or [Ast::compact]. Clone marked nodes with
+//! [Hygiene::clone_ident] or [Hygiene::clone_in], and call [Hygiene::remap]
+//! after compacting. The marks of an [Ast::import_subtree] are dropped, as
+//! they belong to the [Hygiene] of the other AST.
+
+use std::num::NonZeroU32;
+
+use oxc_index::Idx;
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_experimental_ecma_ast::*;
+
+use crate::{ScopeId, resolver::resolver_with_hygiene};
+
+/// A mark applied to the identifiers generated by a transform.
+///
+/// Identifiers of the source have no mark.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Mark(NonZeroU32);
+
+impl Idx for Mark {
+    const MAX: usize = u32::MAX as usize - 1;
+
+    unsafe fn from_usize_unchecked(idx: usize) -> Self {
+        unsafe { Self(NonZeroU32::new_unchecked(idx as u32 + 1)) }
+    }
+
+    fn index(self) -> usize {
+        self.0.get() as usize - 1
+    }
+}
+
+/// The name of a binding, as compared by the resolver.
+pub(crate) type Id = (Utf8Ref, Option<Mark>);
+
+/// The marks of identifiers.
+#[derive(Debug, Default)]
+pub struct Hygiene {
+    mark_count: usize,
+    marks: FxHashMap<NodeId, Mark>,
+}
+
+impl Hygiene {
+    /// Allocates a new mark, which is different from all other marks of this
+    /// table.
+    pub fn new_mark(&mut self) -> Mark {
+        let mark = Mark::from_usize(self.mark_count);
+        self.mark_count += 1;
+        mark
+    }
+
+    /// Applies `mark` to `ident`, replacing its previous mark.
+    #[inline]
+    pub fn apply_mark(&mut self, ident: Ident, mark: Mark) {
+        self.marks.insert(ident.node_id(), mark);
+    }
+
+    #[inline]
+    pub fn mark(&self, ident: Ident) -> Option<Mark> {
+        self.marks.get(&ident.node_id()).copied()
+    }
+
+    /// Creates an identifier with a fresh mark, which never resolves to a
+    /// binding of the source even if it has the same name.
+    pub fn private_ident(&mut self, ast: &mut Ast, span: Span, sym: &str) -> Ident {
+        let sym = ast.add_utf8(sym);
+        let ident = ast.ident(span, sym, false);
+        let mark = self.new_mark();
+        self.apply_mark(ident, mark);
+        ident
+    }
+
+    /// Creates another reference to the variable of `ident`, with its name
+    /// and mark.
+    pub fn clone_ident(&mut self, ast: &mut Ast, ident: Ident) -> Ident {
+        let cloned = ident.clone_in(ast);
+        if let Some(mark) = self.mark(ident) {
+            self.apply_mark(cloned, mark);
+        }
+        cloned
+    }
+
+    /// Clones `node` with [CloneIn::clone_in], and applies the marks of its
+    /// identifiers to the identifiers of the clone.
+    pub fn clone_in<N: CloneIn<Cloned = N> + NodeIdTrait + Copy>(
+        &mut self,
+        ast: &mut Ast,
+        node: N,
+    ) -> N {
+        let cloned = node.clone_in(ast);
+        if self.marks.is_empty() {
+            return cloned;
+        }
+
+        // The clone has the shape of `node`, so the walks enter the copies of
+        // the nodes in the same order.
+        let entered = |event| match event {
+            WalkEvent::Enter(node_id) => Some(node_id),
+            WalkEvent::Leave(_) => None,
+        };
+        let originals = ast.walk(node).filter_map(entered);
+        let copies = ast.walk(cloned).filter_map(entered);
+        for (original, copy) in originals.zip(copies) {
+            if let Some(&mark) = self.marks.get(&original) {
+                self.marks.insert(copy, mark);
+            }
+        }
+        cloned
+    }
+
+    /// Updates the node ids of the marks after [Ast::compact], dropping the
+    /// marks of the removed identifiers.
+    pub fn remap(&mut self, remap: &NodeIdRemap) {
+        self.marks = self
+            .marks
+            .drain()
+            .filter_map(|(old, mark)| Some((remap.get(old)?, mark)))
+            .collect();
+    }
+
+    pub(crate) fn id(&self, ident: Ident, ast: &Ast) -> Id {
+        (ident.sym(ast), self.mark(ident))
+    }
+
+    /// Renames the marked variables of `root` which have the same name as
+    /// another variable, so that the printed program resolves like `root`
+    /// does with the marks.
+    ///
+    /// A renamed variable gets the first of `x1`, `x2`, ... which isn't the
+    /// name of an identifier of the program. The identifiers of the source are
+    /// never renamed, and shorthand properties and specifiers of renamed
+    /// variables are expanded to keep their keys.
+    pub fn rename(&self, root: Program, ast: &mut Ast) {
+        let semantic = resolver_with_hygiene(root, ast, self);
+        let (idents, shorthands) = collect_idents(root, ast);
+
+        // A variable is a name with a mark, declared in a scope.
+        let variable = |ident: Ident, ast: &Ast| {
+            let (sym, mark) = self.id(ident, ast);
+            (sym, mark, semantic.node_scope(ident))
+        };
+        let mut variables: FxHashMap<Utf8Ref, FxHashSet<(Option<Mark>, ScopeId)>> =
+            FxHashMap::default();
+        for &ident in &idents {
+            let (sym, mark, scope) = variable(ident, ast);
+            variables.entry(sym).or_default().insert((mark, scope));
+        }
+
+        let mut used = variables.keys().copied().collect::<FxHashSet<_>>();
+        let mut renamed = FxHashMap::default();
+        for &ident in &idents {
+            let key = variable(ident, ast);
+            let (sym, mark, _) = key;
+            if mark.is_none() || variables[&sym].len() == 1 {
+                continue;
+            }
+            let new_sym = *renamed.entry(key).or_insert_with(|| {
+                let name = ast.get_utf8(sym).to_string();
+                let new_sym = (1..)
+                    .map(|n| ast.add_utf8(&format!("{name}{n}")))
+                    .find(|new_sym| !used.contains(new_sym))
+                    .unwrap();
+                used.insert(new_sym);
+                new_sym
+            });
+            ident.set_sym(ast, new_sym);
+        }
+
+        for shorthand in shorthands {
+            shorthand.expand(ast);
+        }
+    }
+}
+
+/// A shorthand whose key is the name of its variable.
+enum Shorthand {
+    /// `{ x }`
+    Prop(
+        TypedSubRange<PropOrSpread>,
+        NodeExtraDataId<PropOrSpread>,
+        Ident,
+        Utf8Ref,
+    ),
+    /// `{ x = 1 } = {}`
+    PatProp(
+        TypedSubRange<ObjectPatProp>,
+        NodeExtraDataId<ObjectPatProp>,
+        AssignPatProp,
+        Utf8Ref,
+    ),
+    /// `import { x } from "a"`
+    Import(ImportNamedSpecifier, Utf8Ref),
+    /// `export { x }`
+    Export(ExportNamedSpecifier, Ident, Utf8Ref),
+}
+
+impl Shorthand {
+    /// Adds the original name as the key, if the variable was renamed.
+    fn expand(self, ast: &mut Ast) {
+        match self {
+            Shorthand::Prop(props, at, ident, key) => {
+                if ident.sym(ast) == key {
+                    return;
+                }
+                let span = ident.span(ast);
+                let key = ast.prop_name_ident_name(span, key);
+                let prop = ast.prop_or_spread_prop_key_value_prop(span, key, Expr::Ident(ident));
+                props.replace_slot(ast, at, prop);
+            }
+            Shorthand::PatProp(props, at, prop, key) => {
+                let id = prop.key(ast);
+                if id.id(ast).sym(ast) == key {
+                    return;
+                }
+                let span = prop.span(ast);
+                let key = ast.prop_name_ident_name(id.span(ast), key);
+                let value = match prop.value(ast) {
+                    Some(default) => ast.pat_assign_pat(span, Pat::Ident(id), default),
+                    None => Pat::Ident(id),
+                };
+                let prop = ast.object_pat_prop_key_value_pat_prop(span, key, value);
+                props.replace_slot(ast, at, prop);
+            }
+            Shorthand::Import(specifier, imported) => {
+                let local = specifier.local(ast);
+                if local.sym(ast) == imported {
+                    return;
+                }
+                let imported = ast.module_export_name_ident(local.span(ast), imported, false);
+                specifier.set_imported(ast, Some(imported));
+            }
+            Shorthand::Export(specifier, orig, exported) => {
+                if orig.sym(ast) == exported {
+                    return;
+                }
+                let exported = ast.module_export_name_ident(orig.span(ast), exported, false);
+                specifier.set_exported(ast, Some(exported));
+            }
+        }
+    }
+}
+
+/// Collects the identifiers of `root` in the order they are visited, and the
+/// shorthands which have to be expanded if their variable is renamed.
+///
+/// It walks `root` without recursion, as the renamed programs may be deeply
+/// nested.
+fn collect_idents(root: Program, ast: &Ast) -> (Vec<Ident>, Vec<Shorthand>) {
+    let mut idents = Vec::new();
+    let mut shorthands = Vec::new();
+    for event in ast.walk(root) {
+        let WalkEvent::Enter(node_id) = event else {
+            continue;
+        };
+
+        if let Some(ident) = Ident::try_from_node_id(node_id, ast) {
+            idents.push(ident);
+        } else if let Some(node) = ObjectLit::try_from_node_id(node_id, ast) {
+            let props = node.props(ast);
+            for at in props.iter() {
+                if let PropOrSpread::Prop(Prop::Shorthand(ident)) = ast.get_node_in_sub_range(at) {
+                    let key = ident.sym(ast);
+                    shorthands.push(Shorthand::Prop(props, at, ident, key));
+                }
+            }
+        } else if let Some(node) = ObjectPat::try_from_node_id(node_id, ast) {
+            let props = node.props(ast);
+            for at in props.iter() {
+                if let ObjectPatProp::Assign(prop) = ast.get_node_in_sub_range(at) {
+                    let key = prop.key(ast).id(ast).sym(ast);
+                    shorthands.push(Shorthand::PatProp(props, at, prop, key));
+                }
+            }
+        } else if let Some(node) = ImportNamedSpecifier::try_from_node_id(node_id, ast) {
+            if node.imported(ast).is_none() {
+                let imported = node.local(ast).sym(ast);
+                shorthands.push(Shorthand::Import(node, imported));
+            }
+        } else if let Some(node) = ExportNamedSpecifier::try_from_node_id(node_id, ast)
+            && let (ModuleExportName::Ident(orig), None) = (node.orig(ast), node.exported(ast))
+        {
+            let exported = orig.sym(ast);
+            shorthands.push(Shorthand::Export(node, orig, exported));
+        }
+    }
+    (idents, shorthands)
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::common::DUMMY_SP;
+    use swc_experimental_ecma_codegen::{Config, to_code};
+    use swc_experimental_ecma_parser::{Lexer, Parser, StringSource, Syntax};
+
+    use super::*;
+
+    fn parse(code: &str) -> (Program, Ast) {
+        let lexer = Lexer::new(
+            Syntax::default(),
+            Default::default(),
+            StringSource::new(code),
+            None,
+        );
+        let ret = Parser::new_from(lexer).parse_module().unwrap();
+        (Program::Module(ret.root), ret.ast)
+    }
+
+    /// Returns the identifiers of `root`, in the order they are visited.
+    fn idents(root: Program, ast: &Ast) -> Vec<Ident> {
+        collect_idents(root, ast).0
+    }
+
+    fn idents_of<N: NodeIdTrait>(node: N, ast: &Ast) -> Vec<Ident> {
+        ast.walk(node)
+            .filter_map(|event| match event {
+                WalkEvent::Enter(node_id) => Ident::try_from_node_id(node_id, ast),
+                WalkEvent::Leave(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rename() {
+        let code = "let x = 1, x1 = 2;\n{\n    let x = x;\n    f(x, x1, { x });\n}\n";
+        let (root, mut ast) = parse(code);
+        let idents = idents(root, &ast);
+
+        // The `x` of the block is generated, but its initializer is the `x` of
+        // the source.
+        let mut hygiene = Hygiene::default();
+        let mark = hygiene.new_mark();
+        for index in [2, 5, 7] {
+            hygiene.apply_mark(idents[index], mark);
+        }
+        let semantic = resolver_with_hygiene(root, &ast, &hygiene);
+        assert_ne!(
+            semantic.node_scope(idents[2]),
+            semantic.node_scope(idents[0])
+        );
+        assert_eq!(
+            semantic.node_scope(idents[3]),
+            semantic.node_scope(idents[0])
+        );
+
+        hygiene.rename(root, &mut ast);
+        let code = to_code(&ast, root, Config::default()).unwrap();
+        assert_eq!(
+            code,
+            "let x = 1, x1 = 2;\n{\n    let x2 = x;\n    f(x2, x1, {\n        x: x2\n    });\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_rename_pat_shorthand() {
+        let code = "{\n    let { x = 1 } = o;\n    f(x);\n}\nx;\n";
+        let (root, mut ast) = parse(code);
+        let idents = idents(root, &ast);
+
+        let mut hygiene = Hygiene::default();
+        let mark = hygiene.new_mark();
+        for index in [0, 3] {
+            hygiene.apply_mark(idents[index], mark);
+        }
+        hygiene.rename(root, &mut ast);
+        let code = to_code(&ast, root, Config::default()).unwrap();
+        assert_eq!(code, "{\n    let { x: x1 = 1 } = o;\n    f(x1);\n}\nx;\n");
+    }
+
+    #[test]
+    fn test_clone_marks() {
+        let (root, mut ast) = parse("let x = 1;\nf(x + y);\n");
+        let idents = idents(root, &ast);
+
+        let mut hygiene = Hygiene::default();
+        let mark = hygiene.new_mark();
+        hygiene.apply_mark(idents[2], mark);
+
+        let ident = hygiene.clone_ident(&mut ast, idents[2]);
+        assert_ne!(ident, idents[2]);
+        assert_eq!(hygiene.mark(ident), Some(mark));
+
+        let call = ast
+            .walk(root)
+            .find_map(|event| match event {
+                WalkEvent::Enter(node_id) => CallExpr::try_from_node_id(node_id, &ast),
+                WalkEvent::Leave(_) => None,
+            })
+            .unwrap();
+        let cloned = hygiene.clone_in(&mut ast, call);
+        let cloned = idents_of(cloned, &ast);
+        assert_eq!(cloned.len(), 3);
+        assert_eq!(hygiene.mark(cloned[0]), None);
+        assert_eq!(hygiene.mark(cloned[1]), Some(mark));
+        assert_eq!(hygiene.mark(cloned[2]), None);
+    }
+
+    #[test]
+    fn test_remap_marks() {
+        let (root, mut ast) = parse("f(x);\n");
+        let idents = idents(root, &ast);
+
+        let mut hygiene = Hygiene::default();
+        let mark = hygiene.new_mark();
+        hygiene.apply_mark(idents[1], mark);
+
+        // The private identifier isn't reachable from the root.
+        let private = hygiene.private_ident(&mut ast, DUMMY_SP, "x");
+        let (root, remap) = ast.compact(root);
+        hygiene.remap(&remap);
+
+        let idents = self::idents(root, &ast);
+        assert_eq!(hygiene.mark(idents[0]), None);
+        assert_eq!(hygiene.mark(idents[1]), Some(mark));
+        assert_eq!(hygiene.marks.len(), 1);
+        assert!(remap.get(private.node_id()).is_none());
+    }
+}
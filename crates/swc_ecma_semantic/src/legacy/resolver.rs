@@ -4,9 +4,11 @@ use oxc_index::IndexVec;
 use rustc_hash::{FxHashMap, FxHashSet};
 use swc_experimental_ecma_ast::*;
 use swc_experimental_ecma_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
-// use swc_ecma_utils::{find_pat_ids, stack_size::maybe_grow_default};
 
-use crate::legacy::{scope::ScopeId, utils::find_pat_ids};
+use crate::{
+    hygiene::{Hygiene, Id},
+    legacy::{scope::ScopeId, utils::find_pat_ids},
+};
 
 use super::scope::{DeclKind, IdentType, ScopeKind};
 
@@ -125,6 +127,28 @@ const LOG: bool = false && cfg!(debug_assertions);
 /// both of them have the same name, so the `(Atom, SyntaxContext)` pair will
 /// be also identical.
 pub fn resolver<'ast, N: Copy + VisitWith<Resolver<'ast>>>(root: N, ast: &'ast Ast) -> Semantic {
+    resolve(root, ast, None)
+}
+
+/// Same as [resolver], but identifiers with different marks in `hygiene` are
+/// different variables even if they have the same name.
+///
+/// A private identifier created by [Hygiene::private_ident] never resolves to
+/// a binding of the source, and a reference to it only resolves to the
+/// binding with the same mark.
+pub fn resolver_with_hygiene<'ast, N: Copy + VisitWith<Resolver<'ast>>>(
+    root: N,
+    ast: &'ast Ast,
+    hygiene: &'ast Hygiene,
+) -> Semantic {
+    resolve(root, ast, Some(hygiene))
+}
+
+fn resolve<'ast, N: Copy + VisitWith<Resolver<'ast>>>(
+    root: N,
+    ast: &'ast Ast,
+    hygiene: Option<&'ast Hygiene>,
+) -> Semantic {
     let node_count = ast.node_count();
 
     let top_level_scope = Scope::new(ScopeKind::Fn, None);
@@ -144,6 +168,7 @@ pub fn resolver<'ast, N: Copy + VisitWith<Resolver<'ast>>>(root: N, ast: &'ast A
 
     let mut resolver = Resolver {
         ast,
+        hygiene,
         symbol_scopes,
         block_scopes,
         scopes,
@@ -187,6 +212,12 @@ impl Semantic {
         self.symbol_scopes[block.node_id()].unwrap_or(self.unresolved_scope_id)
     }
 
+    /// Updates the node ids of the tables after [Ast::compact].
+    pub fn remap(&mut self, remap: &NodeIdRemap) {
+        self.symbol_scopes = remap.remap_table(&self.symbol_scopes);
+        self.block_scopes = remap.remap_table(&self.block_scopes);
+    }
+
     #[inline]
     pub fn top_level_scope_id(&self) -> ScopeId {
         self.top_level_scope_id
@@ -207,10 +238,10 @@ struct Scope {
     kind: ScopeKind,
 
     /// All declarations in the scope
-    declared_symbols: FxHashMap<Utf8Ref, DeclKind>,
+    declared_symbols: FxHashMap<Id, DeclKind>,
 
     /// All types declared in the scope
-    declared_types: FxHashSet<Utf8Ref>,
+    declared_types: FxHashSet<Id>,
 }
 
 impl Scope {
@@ -232,6 +263,7 @@ impl Scope {
 pub struct Resolver<'ast> {
     // Changed
     ast: &'ast Ast,
+    hygiene: Option<&'ast Hygiene>,
     symbol_scopes: IndexVec<NodeId, Option<ScopeId>>,
     block_scopes: IndexVec<NodeId, Option<ScopeId>>,
 
@@ -249,7 +281,14 @@ pub struct Resolver<'ast> {
 }
 
 impl<'ast> Resolver<'ast> {
-    fn is_declared(&self, symbol: Utf8Ref, start_scope: ScopeId) -> Option<DeclKind> {
+    fn id(&self, ident: Ident) -> Id {
+        match self.hygiene {
+            Some(hygiene) => hygiene.id(ident, self.ast),
+            None => (ident.sym(self.ast), None),
+        }
+    }
+
+    fn is_declared(&self, symbol: Id, start_scope: ScopeId) -> Option<DeclKind> {
         let mut scope = Some(start_scope);
         while let Some(cur_scope) = scope {
             let cur_scope = &self.scopes[cur_scope];
@@ -298,11 +337,11 @@ impl<'ast> Resolver<'ast> {
     }
 
     /// Returns a [Mark] for an identifier reference.
-    fn mark_for_ref(&self, sym: Utf8Ref) -> Option<ScopeId> {
+    fn mark_for_ref(&self, sym: Id) -> Option<ScopeId> {
         self.mark_for_ref_inner(sym, false)
     }
 
-    fn mark_for_ref_inner(&self, sym: Utf8Ref, stop_an_fn_scope: bool) -> Option<ScopeId> {
+    fn mark_for_ref_inner(&self, sym: Id, stop_an_fn_scope: bool) -> Option<ScopeId> {
         // if self.config.handle_types && self.in_type {
         //     let mut mark = self.current.mark;
         //     let mut scope = Some(&self.current);
@@ -332,8 +371,7 @@ impl<'ast> Resolver<'ast> {
         while let Some(cur) = scope {
             let cur_scope = &self.scopes[cur];
             if cur_scope.declared_symbols.contains_key(&sym) {
-                let sym = self.ast.get_utf8(sym);
-                return match sym {
+                return match self.ast.get_utf8(sym.0) {
                     // https://tc39.es/ecma262/multipage/global-object.html#sec-value-properties-of-the-global-object-infinity
                     // non configurable global value
                     "undefined" | "NaN" | "Infinity"
@@ -363,14 +401,11 @@ impl<'ast> Resolver<'ast> {
             return;
         }
 
+        let sym = self.id(id);
         if self.in_type {
-            self.scopes[self.current]
-                .declared_types
-                .insert(id.sym(self.ast));
+            self.scopes[self.current].declared_types.insert(sym);
         } else {
-            self.scopes[self.current]
-                .declared_symbols
-                .insert(id.sym(self.ast), kind);
+            self.scopes[self.current].declared_symbols.insert(sym, kind);
         }
 
         let scope_id = self.current;
@@ -565,9 +600,10 @@ impl<'ast> Visit for Resolver<'ast> {
                     });
 
                 for id in params.iter() {
+                    let id = child.id(*id);
                     child.scopes[child.current]
                         .declared_symbols
-                        .insert(*id, DeclKind::Param);
+                        .insert(id, DeclKind::Param);
                 }
             }
             e.params(self.ast).visit_with(child);
@@ -802,7 +838,7 @@ impl<'ast> Visit for Resolver<'ast> {
                     .filter(|p| {
                         let p = self.ast.get_node_in_sub_range(*p);
                         match p {
-                            // ParamOrTsParamProp::TsParamProp(_) => false,
+                            ParamOrTsParamProp::TsParamProp(_) => false,
                             ParamOrTsParamProp::Param(p) => !p.pat(self.ast).is_rest(),
                         }
                     })
@@ -811,9 +847,10 @@ impl<'ast> Visit for Resolver<'ast> {
                     });
 
                 for id in params.iter() {
+                    let id = child.id(*id);
                     child.scopes[child.current]
                         .declared_symbols
-                        .insert(*id, DeclKind::Param);
+                        .insert(id, DeclKind::Param);
                 }
             }
             c.params(self.ast).visit_with(child);
@@ -912,8 +949,7 @@ impl<'ast> Visit for Resolver<'ast> {
 
         let old = self.ident_type;
         self.ident_type = IdentType::Ref;
-        // maybe_grow_default(|| expr.visit_children_with(self));
-        expr.visit_children_with(self);
+        maybe_grow(|| expr.visit_children_with(self));
         self.ident_type = old;
     }
 
@@ -1017,9 +1053,10 @@ impl<'ast> Visit for Resolver<'ast> {
                 });
 
             for id in params.iter() {
+                let id = self.id(*id);
                 self.scopes[self.current]
                     .declared_symbols
-                    .insert(*id, DeclKind::Param);
+                    .insert(id, DeclKind::Param);
             }
         }
         self.ident_type = IdentType::Binding;
@@ -1098,7 +1135,7 @@ impl<'ast> Visit for Resolver<'ast> {
                 //     debug!("IdentRef (type = {}) {}{:?}", self.in_type, sym, ctxt);
                 // }
 
-                if let Some(scope_id) = self.mark_for_ref(i.sym(self.ast)) {
+                if let Some(scope_id) = self.mark_for_ref(self.id(i)) {
                     // if cfg!(debug_assertions) && LOG {
                     //     debug!("\t -> {:?}", ctxt);
                     // }
@@ -1272,7 +1309,7 @@ impl<'ast> Visit for Resolver<'ast> {
     }
 
     fn visit_pat(&mut self, p: Pat) {
-        p.visit_children_with(self);
+        maybe_grow(|| p.visit_children_with(self));
     }
 
     fn visit_private_method(&mut self, m: PrivateMethod) {
@@ -1342,6 +1379,10 @@ impl<'ast> Visit for Resolver<'ast> {
         self.leave_node(n.node_id());
     }
 
+    fn visit_stmt(&mut self, s: Stmt) {
+        maybe_grow(|| s.visit_children_with(self));
+    }
+
     fn visit_stmts(&mut self, stmts: TypedSubRange<Stmt>) {
         // let _span = if LOG {
         //     Some(span!(Level::ERROR, "visit_stmts").entered())
@@ -1774,14 +1815,14 @@ struct Hoister<'resolver, 'ast> {
 
     in_catch_body: bool,
 
-    excluded_from_catch: FxHashSet<Utf8Ref>,
-    catch_param_decls: FxHashSet<Utf8Ref>,
+    excluded_from_catch: FxHashSet<Id>,
+    catch_param_decls: FxHashSet<Id>,
 }
 
 impl<'resolver, 'ast> Hoister<'resolver, 'ast> {
     fn add_pat_id(&mut self, id: BindingIdent) {
         let id = id.id(self.ast());
-        let sym = id.sym(self.resolver.ast);
+        let sym = self.resolver.id(id);
         if self.in_catch_body {
             // If we have a binding, it's different variable.
             if self.resolver.mark_for_ref_inner(sym, true).is_some()
@@ -1869,7 +1910,8 @@ impl<'resolver, 'ast> Visit for Hoister<'resolver, 'ast> {
         find_pat_ids(self.resolver.ast, c.param(self.resolver.ast), &mut params);
 
         let orig = self.catch_param_decls.clone();
-        self.catch_param_decls.extend(params);
+        self.catch_param_decls
+            .extend(params.into_iter().map(|id| self.resolver.id(id)));
 
         self.in_catch_body = true;
         c.body(self.resolver.ast).visit_with(self);
@@ -2003,7 +2045,7 @@ impl<'resolver, 'ast> Visit for Hoister<'resolver, 'ast> {
 
         if self
             .catch_param_decls
-            .contains(&node.ident(self.ast()).sym(self.ast()))
+            .contains(&self.resolver.id(node.ident(self.ast())))
         {
             return;
         }
@@ -2016,7 +2058,7 @@ impl<'resolver, 'ast> Visit for Hoister<'resolver, 'ast> {
             // If we are in nested block, and variable named `foo` is lexically declared or
             // a parameter, we should ignore function foo while handling upper scopes.
             if let Some(DeclKind::Lexical | DeclKind::Param) = self.resolver.is_declared(
-                node.ident(self.ast()).sym(self.ast()),
+                self.resolver.id(node.ident(self.ast())),
                 self.resolver.current,
             ) {
                 return;
@@ -2078,7 +2120,7 @@ impl<'resolver, 'ast> Visit for Hoister<'resolver, 'ast> {
                 self.add_pat_id(i);
             }
 
-            _ => node.visit_children_with(self),
+            _ => maybe_grow(|| node.visit_children_with(self)),
         }
     }
 
@@ -2088,6 +2130,10 @@ impl<'resolver, 'ast> Visit for Hoister<'resolver, 'ast> {
     #[inline]
     fn visit_setter_prop(&mut self, _: SetterProp) {}
 
+    fn visit_stmt(&mut self, s: Stmt) {
+        maybe_grow(|| s.visit_children_with(self));
+    }
+
     fn visit_switch_stmt(&mut self, s: SwitchStmt) {
         s.discriminant(self.ast()).visit_with(self);
 
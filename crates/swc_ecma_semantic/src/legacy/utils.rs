@@ -1,16 +1,16 @@
 use rustc_hash::FxHashSet;
-use swc_experimental_ecma_ast::{Ast, Expr, Ident, PropName, Utf8Ref};
+use swc_experimental_ecma_ast::{Ast, Expr, Ident, PropName};
 use swc_experimental_ecma_visit::{Visit, VisitWith};
 
 pub struct DestructuringFinder<'ast> {
     ast: &'ast Ast,
-    found: &'ast mut Vec<Utf8Ref>,
+    found: &'ast mut Vec<Ident>,
 }
 
 pub fn find_pat_ids<'ast, N: VisitWith<DestructuringFinder<'ast>>>(
     ast: &'ast Ast,
     node: N,
-    found: &'ast mut Vec<Utf8Ref>,
+    found: &'ast mut Vec<Ident>,
 ) {
     let mut v = DestructuringFinder { ast, found };
     node.visit_with(&mut v);
@@ -25,7 +25,7 @@ impl<'ast> Visit for DestructuringFinder<'ast> {
     fn visit_expr(&mut self, _: Expr) {}
 
     fn visit_ident(&mut self, i: Ident) {
-        self.found.push(i.sym(self.ast));
+        self.found.push(i);
     }
 
     // fn visit_jsx_member_expr(&mut self, n: &JSXMemberExpr) {
@@ -6,6 +6,10 @@ use rustc_hash::FxHashMap;
 use swc_experimental_ecma_ast::*;
 use swc_experimental_ecma_visit::{Visit, VisitWith};
 
+mod hygiene;
 mod legacy;
+mod parent_map;
 
+pub use hygiene::{Hygiene, Mark};
 pub use legacy::{ScopeId, resolver};
+pub use parent_map::{Ancestors, ParentMap};
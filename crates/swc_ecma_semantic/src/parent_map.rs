@@ -0,0 +1,157 @@
+use oxc_index::IndexVec;
+use swc_experimental_ecma_ast::*;
+
+/// The parent of every node reachable from a root, so that analyses can walk
+/// upward, e.g. to find the function enclosing an [Ident].
+///
+/// The parent is the nearest enclosing struct node, as enums like [Expr] are
+/// stored by value and don't have a [NodeId]. Nodes outside of the root have
+/// no parent, and neither does the root itself.
+///
+/// The map isn't updated by mutations of the AST, build it again afterwards.
+pub struct ParentMap {
+    parents: IndexVec<NodeId, OptionalNodeId>,
+}
+
+impl ParentMap {
+    pub fn new<N: NodeIdTrait>(root: N, ast: &Ast) -> Self {
+        let node_count = ast.node_count();
+        let mut parents = IndexVec::with_capacity(node_count);
+        parents.resize(node_count, OptionalNodeId::none());
+
+        // The walk has no recursion, so deeply nested programs can be mapped.
+        let mut stack: Vec<NodeId> = Vec::new();
+        for event in ast.walk(root) {
+            match event {
+                WalkEvent::Enter(node_id) => {
+                    parents[node_id] = stack.last().copied().into();
+                    stack.push(node_id);
+                }
+                WalkEvent::Leave(_) => {
+                    stack.pop();
+                }
+            }
+        }
+        Self { parents }
+    }
+
+    #[inline]
+    pub fn parent(&self, node: NodeId) -> Option<NodeId> {
+        self.parents.get(node)?.to_option()
+    }
+
+    /// Iterates the ancestors of `node` from its parent up to the root.
+    #[inline]
+    pub fn ancestors(&self, node: NodeId) -> Ancestors<'_> {
+        Ancestors {
+            map: self,
+            current: self.parent(node),
+        }
+    }
+
+    /// Returns the nearest ancestor of `node` that is a `T`, e.g.
+    /// `enclosing::<Function>(ident.node_id(), ast)`.
+    pub fn enclosing<T: NodeIdTrait>(&self, node: NodeId, ast: &Ast) -> Option<T> {
+        self.ancestors(node)
+            .find_map(|ancestor| T::try_from_node_id(ancestor, ast))
+    }
+}
+
+pub struct Ancestors<'a> {
+    map: &'a ParentMap,
+    current: Option<NodeId>,
+}
+
+impl Iterator for Ancestors<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?;
+        self.current = self.map.parent(node);
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_experimental_ecma_parser::{Lexer, Parser, StringSource, Syntax};
+
+    use super::*;
+
+    fn parse(code: &str) -> (Module, Ast) {
+        let lexer = Lexer::new(
+            Syntax::default(),
+            Default::default(),
+            StringSource::new(code),
+            None,
+        );
+        let ret = Parser::new_from(lexer).parse_module().unwrap();
+        (ret.root, ret.ast)
+    }
+
+    fn nodes_of<T: NodeIdTrait>(root: Module, ast: &Ast) -> Vec<T> {
+        ast.walk(root)
+            .filter_map(|event| match event {
+                WalkEvent::Enter(node_id) => T::try_from_node_id(node_id, ast),
+                WalkEvent::Leave(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_root() {
+        let (root, ast) = parse("a;\n");
+        let parents = ParentMap::new(root, &ast);
+        assert_eq!(parents.parent(root.node_id()), None);
+        assert_eq!(parents.ancestors(root.node_id()).count(), 0);
+    }
+
+    #[test]
+    fn test_list_elements() {
+        let (root, ast) = parse("a;\nb;\nc;\n");
+        let parents = ParentMap::new(root, &ast);
+
+        // The statements are elements of the body of the module.
+        let stmts = nodes_of::<ExprStmt>(root, &ast);
+        assert_eq!(stmts.len(), 3);
+        for stmt in stmts {
+            assert_eq!(parents.parent(stmt.node_id()), Some(root.node_id()));
+        }
+
+        let idents = nodes_of::<Ident>(root, &ast);
+        let ancestors = parents.ancestors(idents[2].node_id()).collect::<Vec<_>>();
+        assert_eq!(ancestors.len(), 2);
+        assert_eq!(ancestors[1], root.node_id());
+    }
+
+    #[test]
+    fn test_enclosing() {
+        let (root, ast) = parse("a;\nfunction f() {\n    return b;\n}\n");
+        let parents = ParentMap::new(root, &ast);
+        let idents = nodes_of::<Ident>(root, &ast);
+        let function = nodes_of::<Function>(root, &ast)[0];
+
+        // `a`, `f` and `b`.
+        assert_eq!(idents.len(), 3);
+        assert_eq!(
+            parents.enclosing::<Function>(idents[2].node_id(), &ast),
+            Some(function)
+        );
+        assert_eq!(
+            parents.enclosing::<ReturnStmt>(idents[2].node_id(), &ast),
+            nodes_of::<ReturnStmt>(root, &ast).first().copied()
+        );
+        assert_eq!(
+            parents.enclosing::<Function>(idents[0].node_id(), &ast),
+            None
+        );
+        assert_eq!(
+            parents.enclosing::<Function>(idents[1].node_id(), &ast),
+            None
+        );
+        assert_eq!(
+            parents.enclosing::<Module>(idents[0].node_id(), &ast),
+            Some(root)
+        );
+    }
+}
@@ -0,0 +1,5948 @@
+#![allow(unused, clippy::useless_conversion)]
+use crate::traverse::TraverseCtx;
+use swc_experimental_ecma_ast::*;
+pub trait Traverse {
+    #[inline]
+    fn enter_program(&mut self, node: &mut Program, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_program(&mut self, node: &mut Program, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_module(&mut self, node: &mut Module, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_module(&mut self, node: &mut Module, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_script(&mut self, node: &mut Script, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_script(&mut self, node: &mut Script, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_module_item(&mut self, node: &mut ModuleItem, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_module_item(&mut self, node: &mut ModuleItem, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_module_decl(&mut self, node: &mut ModuleDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_module_decl(&mut self, node: &mut ModuleDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_import_decl(&mut self, node: &mut ImportDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_import_decl(&mut self, node: &mut ImportDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_import_specifier(&mut self, node: &mut ImportSpecifier, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_import_specifier(&mut self, node: &mut ImportSpecifier, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_import_named_specifier(
+        &mut self,
+        node: &mut ImportNamedSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_import_named_specifier(
+        &mut self,
+        node: &mut ImportNamedSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_import_default_specifier(
+        &mut self,
+        node: &mut ImportDefaultSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_import_default_specifier(
+        &mut self,
+        node: &mut ImportDefaultSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_import_star_as_specifier(
+        &mut self,
+        node: &mut ImportStarAsSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_import_star_as_specifier(
+        &mut self,
+        node: &mut ImportStarAsSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_export_decl(&mut self, node: &mut ExportDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_export_decl(&mut self, node: &mut ExportDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_named_export(&mut self, node: &mut NamedExport, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_named_export(&mut self, node: &mut NamedExport, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_export_specifier(&mut self, node: &mut ExportSpecifier, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_export_specifier(&mut self, node: &mut ExportSpecifier, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_export_namespace_specifier(
+        &mut self,
+        node: &mut ExportNamespaceSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_export_namespace_specifier(
+        &mut self,
+        node: &mut ExportNamespaceSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_module_export_name(&mut self, node: &mut ModuleExportName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_module_export_name(&mut self, node: &mut ModuleExportName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_export_default_specifier(
+        &mut self,
+        node: &mut ExportDefaultSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_export_default_specifier(
+        &mut self,
+        node: &mut ExportDefaultSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_export_named_specifier(
+        &mut self,
+        node: &mut ExportNamedSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_export_named_specifier(
+        &mut self,
+        node: &mut ExportNamedSpecifier,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_export_default_decl(&mut self, node: &mut ExportDefaultDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_export_default_decl(&mut self, node: &mut ExportDefaultDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_default_decl(&mut self, node: &mut DefaultDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_default_decl(&mut self, node: &mut DefaultDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_export_default_expr(&mut self, node: &mut ExportDefaultExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_export_default_expr(&mut self, node: &mut ExportDefaultExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_export_all(&mut self, node: &mut ExportAll, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_export_all(&mut self, node: &mut ExportAll, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_block_stmt(&mut self, node: &mut BlockStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_block_stmt(&mut self, node: &mut BlockStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_stmt(&mut self, node: &mut Stmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_stmt(&mut self, node: &mut Stmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_expr_stmt(&mut self, node: &mut ExprStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_expr_stmt(&mut self, node: &mut ExprStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_empty_stmt(&mut self, node: &mut EmptyStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_empty_stmt(&mut self, node: &mut EmptyStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_debugger_stmt(&mut self, node: &mut DebuggerStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_debugger_stmt(&mut self, node: &mut DebuggerStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_with_stmt(&mut self, node: &mut WithStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_with_stmt(&mut self, node: &mut WithStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_return_stmt(&mut self, node: &mut ReturnStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_return_stmt(&mut self, node: &mut ReturnStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_labeled_stmt(&mut self, node: &mut LabeledStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_labeled_stmt(&mut self, node: &mut LabeledStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_break_stmt(&mut self, node: &mut BreakStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_break_stmt(&mut self, node: &mut BreakStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_continue_stmt(&mut self, node: &mut ContinueStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_continue_stmt(&mut self, node: &mut ContinueStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_if_stmt(&mut self, node: &mut IfStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_if_stmt(&mut self, node: &mut IfStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_switch_stmt(&mut self, node: &mut SwitchStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_switch_stmt(&mut self, node: &mut SwitchStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_throw_stmt(&mut self, node: &mut ThrowStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_throw_stmt(&mut self, node: &mut ThrowStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_try_stmt(&mut self, node: &mut TryStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_try_stmt(&mut self, node: &mut TryStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_while_stmt(&mut self, node: &mut WhileStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_while_stmt(&mut self, node: &mut WhileStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_do_while_stmt(&mut self, node: &mut DoWhileStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_do_while_stmt(&mut self, node: &mut DoWhileStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_for_stmt(&mut self, node: &mut ForStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_for_stmt(&mut self, node: &mut ForStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_for_in_stmt(&mut self, node: &mut ForInStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_for_in_stmt(&mut self, node: &mut ForInStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_for_of_stmt(&mut self, node: &mut ForOfStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_for_of_stmt(&mut self, node: &mut ForOfStmt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_switch_case(&mut self, node: &mut SwitchCase, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_switch_case(&mut self, node: &mut SwitchCase, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_catch_clause(&mut self, node: &mut CatchClause, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_catch_clause(&mut self, node: &mut CatchClause, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_for_head(&mut self, node: &mut ForHead, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_for_head(&mut self, node: &mut ForHead, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_var_decl_or_expr(&mut self, node: &mut VarDeclOrExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_var_decl_or_expr(&mut self, node: &mut VarDeclOrExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_decl(&mut self, node: &mut Decl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_decl(&mut self, node: &mut Decl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_fn_decl(&mut self, node: &mut FnDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_fn_decl(&mut self, node: &mut FnDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_class_decl(&mut self, node: &mut ClassDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_class_decl(&mut self, node: &mut ClassDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_var_decl(&mut self, node: &mut VarDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_var_decl(&mut self, node: &mut VarDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_var_declarator(&mut self, node: &mut VarDeclarator, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_var_declarator(&mut self, node: &mut VarDeclarator, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_using_decl(&mut self, node: &mut UsingDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_using_decl(&mut self, node: &mut UsingDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_expr(&mut self, node: &mut Expr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_expr(&mut self, node: &mut Expr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_this_expr(&mut self, node: &mut ThisExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_this_expr(&mut self, node: &mut ThisExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_array_lit(&mut self, node: &mut ArrayLit, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_array_lit(&mut self, node: &mut ArrayLit, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_object_lit(&mut self, node: &mut ObjectLit, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_object_lit(&mut self, node: &mut ObjectLit, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_prop_or_spread(&mut self, node: &mut PropOrSpread, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_prop_or_spread(&mut self, node: &mut PropOrSpread, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_spread_element(&mut self, node: &mut SpreadElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_spread_element(&mut self, node: &mut SpreadElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_unary_expr(&mut self, node: &mut UnaryExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_unary_expr(&mut self, node: &mut UnaryExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_update_expr(&mut self, node: &mut UpdateExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_update_expr(&mut self, node: &mut UpdateExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_bin_expr(&mut self, node: &mut BinExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_bin_expr(&mut self, node: &mut BinExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_fn_expr(&mut self, node: &mut FnExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_fn_expr(&mut self, node: &mut FnExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_class_expr(&mut self, node: &mut ClassExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_class_expr(&mut self, node: &mut ClassExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_assign_expr(&mut self, node: &mut AssignExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_assign_expr(&mut self, node: &mut AssignExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_member_expr(&mut self, node: &mut MemberExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_member_expr(&mut self, node: &mut MemberExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_member_prop(&mut self, node: &mut MemberProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_member_prop(&mut self, node: &mut MemberProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_super_prop_expr(&mut self, node: &mut SuperPropExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_super_prop_expr(&mut self, node: &mut SuperPropExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_super_prop(&mut self, node: &mut SuperProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_super_prop(&mut self, node: &mut SuperProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_cond_expr(&mut self, node: &mut CondExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_cond_expr(&mut self, node: &mut CondExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_call_expr(&mut self, node: &mut CallExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_call_expr(&mut self, node: &mut CallExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_new_expr(&mut self, node: &mut NewExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_new_expr(&mut self, node: &mut NewExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_seq_expr(&mut self, node: &mut SeqExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_seq_expr(&mut self, node: &mut SeqExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_arrow_expr(&mut self, node: &mut ArrowExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_arrow_expr(&mut self, node: &mut ArrowExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_yield_expr(&mut self, node: &mut YieldExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_yield_expr(&mut self, node: &mut YieldExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_meta_prop_expr(&mut self, node: &mut MetaPropExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_meta_prop_expr(&mut self, node: &mut MetaPropExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_await_expr(&mut self, node: &mut AwaitExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_await_expr(&mut self, node: &mut AwaitExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_tpl(&mut self, node: &mut Tpl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_tpl(&mut self, node: &mut Tpl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_tagged_tpl(&mut self, node: &mut TaggedTpl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_tagged_tpl(&mut self, node: &mut TaggedTpl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_tpl_element(&mut self, node: &mut TplElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_tpl_element(&mut self, node: &mut TplElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_paren_expr(&mut self, node: &mut ParenExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_paren_expr(&mut self, node: &mut ParenExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_callee(&mut self, node: &mut Callee, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_callee(&mut self, node: &mut Callee, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_super(&mut self, node: &mut Super, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_super(&mut self, node: &mut Super, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_import(&mut self, node: &mut Import, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_import(&mut self, node: &mut Import, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_expr_or_spread(&mut self, node: &mut ExprOrSpread, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_expr_or_spread(&mut self, node: &mut ExprOrSpread, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_spread_dot_3_token(&mut self, node: &mut SpreadDot3Token, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_spread_dot_3_token(&mut self, node: &mut SpreadDot3Token, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_block_stmt_or_expr(&mut self, node: &mut BlockStmtOrExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_block_stmt_or_expr(&mut self, node: &mut BlockStmtOrExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_assign_target(&mut self, node: &mut AssignTarget, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_assign_target(&mut self, node: &mut AssignTarget, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_assign_target_pat(&mut self, node: &mut AssignTargetPat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_assign_target_pat(&mut self, node: &mut AssignTargetPat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_simple_assign_target(&mut self, node: &mut SimpleAssignTarget, ctx: &mut TraverseCtx) {
+    }
+    #[inline]
+    fn exit_simple_assign_target(&mut self, node: &mut SimpleAssignTarget, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_opt_chain_expr(&mut self, node: &mut OptChainExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_opt_chain_expr(&mut self, node: &mut OptChainExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_opt_chain_base(&mut self, node: &mut OptChainBase, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_opt_chain_base(&mut self, node: &mut OptChainBase, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_opt_call(&mut self, node: &mut OptCall, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_opt_call(&mut self, node: &mut OptCall, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_invalid(&mut self, node: &mut Invalid, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_invalid(&mut self, node: &mut Invalid, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_function(&mut self, node: &mut Function, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_function(&mut self, node: &mut Function, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_param(&mut self, node: &mut Param, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_param(&mut self, node: &mut Param, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_param_or_ts_param_prop(
+        &mut self,
+        node: &mut ParamOrTsParamProp,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_param_or_ts_param_prop(
+        &mut self,
+        node: &mut ParamOrTsParamProp,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_class(&mut self, node: &mut Class, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_class(&mut self, node: &mut Class, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_class_member(&mut self, node: &mut ClassMember, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_class_member(&mut self, node: &mut ClassMember, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_class_prop(&mut self, node: &mut ClassProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_class_prop(&mut self, node: &mut ClassProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_private_prop(&mut self, node: &mut PrivateProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_private_prop(&mut self, node: &mut PrivateProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_class_method(&mut self, node: &mut ClassMethod, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_class_method(&mut self, node: &mut ClassMethod, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_private_method(&mut self, node: &mut PrivateMethod, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_private_method(&mut self, node: &mut PrivateMethod, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_constructor(&mut self, node: &mut Constructor, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_constructor(&mut self, node: &mut Constructor, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_decorator(&mut self, node: &mut Decorator, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_decorator(&mut self, node: &mut Decorator, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_static_block(&mut self, node: &mut StaticBlock, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_static_block(&mut self, node: &mut StaticBlock, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_key(&mut self, node: &mut Key, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_key(&mut self, node: &mut Key, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_auto_accessor(&mut self, node: &mut AutoAccessor, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_auto_accessor(&mut self, node: &mut AutoAccessor, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_prop(&mut self, node: &mut Prop, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_prop(&mut self, node: &mut Prop, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_key_value_prop(&mut self, node: &mut KeyValueProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_key_value_prop(&mut self, node: &mut KeyValueProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_assign_prop(&mut self, node: &mut AssignProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_assign_prop(&mut self, node: &mut AssignProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_getter_prop(&mut self, node: &mut GetterProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_getter_prop(&mut self, node: &mut GetterProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_setter_prop(&mut self, node: &mut SetterProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_setter_prop(&mut self, node: &mut SetterProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_method_prop(&mut self, node: &mut MethodProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_method_prop(&mut self, node: &mut MethodProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_prop_name(&mut self, node: &mut PropName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_prop_name(&mut self, node: &mut PropName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_computed_prop_name(&mut self, node: &mut ComputedPropName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_computed_prop_name(&mut self, node: &mut ComputedPropName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_pat(&mut self, node: &mut Pat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_pat(&mut self, node: &mut Pat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_array_pat(&mut self, node: &mut ArrayPat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_array_pat(&mut self, node: &mut ArrayPat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_object_pat(&mut self, node: &mut ObjectPat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_object_pat(&mut self, node: &mut ObjectPat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_assign_pat(&mut self, node: &mut AssignPat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_assign_pat(&mut self, node: &mut AssignPat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_rest_pat(&mut self, node: &mut RestPat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_rest_pat(&mut self, node: &mut RestPat, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_object_pat_prop(&mut self, node: &mut ObjectPatProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_object_pat_prop(&mut self, node: &mut ObjectPatProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_key_value_pat_prop(&mut self, node: &mut KeyValuePatProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_key_value_pat_prop(&mut self, node: &mut KeyValuePatProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_assign_pat_prop(&mut self, node: &mut AssignPatProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_assign_pat_prop(&mut self, node: &mut AssignPatProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ident(&mut self, node: &mut Ident, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ident(&mut self, node: &mut Ident, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ident_name(&mut self, node: &mut IdentName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ident_name(&mut self, node: &mut IdentName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_private_name(&mut self, node: &mut PrivateName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_private_name(&mut self, node: &mut PrivateName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_binding_ident(&mut self, node: &mut BindingIdent, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_binding_ident(&mut self, node: &mut BindingIdent, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_lit(&mut self, node: &mut Lit, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_lit(&mut self, node: &mut Lit, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_str(&mut self, node: &mut Str, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_str(&mut self, node: &mut Str, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_bool(&mut self, node: &mut Bool, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_bool(&mut self, node: &mut Bool, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_null(&mut self, node: &mut Null, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_null(&mut self, node: &mut Null, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_number(&mut self, node: &mut Number, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_number(&mut self, node: &mut Number, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_big_int(&mut self, node: &mut BigInt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_big_int(&mut self, node: &mut BigInt, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_regex(&mut self, node: &mut Regex, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_regex(&mut self, node: &mut Regex, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_object(&mut self, node: &mut JSXObject, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_object(&mut self, node: &mut JSXObject, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_member_expr(&mut self, node: &mut JSXMemberExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_member_expr(&mut self, node: &mut JSXMemberExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_namespaced_name(&mut self, node: &mut JSXNamespacedName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_namespaced_name(&mut self, node: &mut JSXNamespacedName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_empty_expr(&mut self, node: &mut JSXEmptyExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_empty_expr(&mut self, node: &mut JSXEmptyExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_expr_container(&mut self, node: &mut JSXExprContainer, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_expr_container(&mut self, node: &mut JSXExprContainer, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_expr(&mut self, node: &mut JSXExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_expr(&mut self, node: &mut JSXExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_spread_child(&mut self, node: &mut JSXSpreadChild, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_spread_child(&mut self, node: &mut JSXSpreadChild, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_element_name(&mut self, node: &mut JSXElementName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_element_name(&mut self, node: &mut JSXElementName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_opening_element(&mut self, node: &mut JSXOpeningElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_opening_element(&mut self, node: &mut JSXOpeningElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_attr_or_spread(&mut self, node: &mut JSXAttrOrSpread, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_attr_or_spread(&mut self, node: &mut JSXAttrOrSpread, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_closing_element(&mut self, node: &mut JSXClosingElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_closing_element(&mut self, node: &mut JSXClosingElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_attr(&mut self, node: &mut JSXAttr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_attr(&mut self, node: &mut JSXAttr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_attr_name(&mut self, node: &mut JSXAttrName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_attr_name(&mut self, node: &mut JSXAttrName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_attr_value(&mut self, node: &mut JSXAttrValue, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_attr_value(&mut self, node: &mut JSXAttrValue, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_text(&mut self, node: &mut JSXText, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_text(&mut self, node: &mut JSXText, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_element(&mut self, node: &mut JSXElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_element(&mut self, node: &mut JSXElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_element_child(&mut self, node: &mut JSXElementChild, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_element_child(&mut self, node: &mut JSXElementChild, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_fragment(&mut self, node: &mut JSXFragment, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_jsx_fragment(&mut self, node: &mut JSXFragment, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_opening_fragment(&mut self, node: &mut JSXOpeningFragment, ctx: &mut TraverseCtx) {
+    }
+    #[inline]
+    fn exit_jsx_opening_fragment(&mut self, node: &mut JSXOpeningFragment, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_jsx_closing_fragment(&mut self, node: &mut JSXClosingFragment, ctx: &mut TraverseCtx) {
+    }
+    #[inline]
+    fn exit_jsx_closing_fragment(&mut self, node: &mut JSXClosingFragment, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_ann(&mut self, node: &mut TsTypeAnn, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_ann(&mut self, node: &mut TsTypeAnn, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_param_decl(&mut self, node: &mut TsTypeParamDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_param_decl(&mut self, node: &mut TsTypeParamDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_param(&mut self, node: &mut TsTypeParam, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_param(&mut self, node: &mut TsTypeParam, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_param_instantiation(
+        &mut self,
+        node: &mut TsTypeParamInstantiation,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_type_param_instantiation(
+        &mut self,
+        node: &mut TsTypeParamInstantiation,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_param_prop(&mut self, node: &mut TsParamProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_param_prop(&mut self, node: &mut TsParamProp, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_param_prop_param(&mut self, node: &mut TsParamPropParam, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_param_prop_param(&mut self, node: &mut TsParamPropParam, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_qualified_name(&mut self, node: &mut TsQualifiedName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_qualified_name(&mut self, node: &mut TsQualifiedName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_entity_name(&mut self, node: &mut TsEntityName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_entity_name(&mut self, node: &mut TsEntityName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_element(&mut self, node: &mut TsTypeElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_element(&mut self, node: &mut TsTypeElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_call_signature_decl(
+        &mut self,
+        node: &mut TsCallSignatureDecl,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_call_signature_decl(
+        &mut self,
+        node: &mut TsCallSignatureDecl,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_construct_signature_decl(
+        &mut self,
+        node: &mut TsConstructSignatureDecl,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_construct_signature_decl(
+        &mut self,
+        node: &mut TsConstructSignatureDecl,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_property_signature(
+        &mut self,
+        node: &mut TsPropertySignature,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_property_signature(
+        &mut self,
+        node: &mut TsPropertySignature,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_getter_signature(&mut self, node: &mut TsGetterSignature, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_getter_signature(&mut self, node: &mut TsGetterSignature, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_setter_signature(&mut self, node: &mut TsSetterSignature, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_setter_signature(&mut self, node: &mut TsSetterSignature, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_method_signature(&mut self, node: &mut TsMethodSignature, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_method_signature(&mut self, node: &mut TsMethodSignature, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_index_signature(&mut self, node: &mut TsIndexSignature, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_index_signature(&mut self, node: &mut TsIndexSignature, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type(&mut self, node: &mut TsType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type(&mut self, node: &mut TsType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_fn_or_constructor_type(
+        &mut self,
+        node: &mut TsFnOrConstructorType,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_fn_or_constructor_type(
+        &mut self,
+        node: &mut TsFnOrConstructorType,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_keyword_type(&mut self, node: &mut TsKeywordType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_keyword_type(&mut self, node: &mut TsKeywordType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_this_type(&mut self, node: &mut TsThisType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_this_type(&mut self, node: &mut TsThisType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_fn_param(&mut self, node: &mut TsFnParam, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_fn_param(&mut self, node: &mut TsFnParam, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_fn_type(&mut self, node: &mut TsFnType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_fn_type(&mut self, node: &mut TsFnType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_constructor_type(&mut self, node: &mut TsConstructorType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_constructor_type(&mut self, node: &mut TsConstructorType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_ref(&mut self, node: &mut TsTypeRef, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_ref(&mut self, node: &mut TsTypeRef, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_predicate(&mut self, node: &mut TsTypePredicate, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_predicate(&mut self, node: &mut TsTypePredicate, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_this_type_or_ident(&mut self, node: &mut TsThisTypeOrIdent, ctx: &mut TraverseCtx) {
+    }
+    #[inline]
+    fn exit_ts_this_type_or_ident(&mut self, node: &mut TsThisTypeOrIdent, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_query(&mut self, node: &mut TsTypeQuery, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_query(&mut self, node: &mut TsTypeQuery, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_query_expr(&mut self, node: &mut TsTypeQueryExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_query_expr(&mut self, node: &mut TsTypeQueryExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_import_call_options(
+        &mut self,
+        node: &mut TsImportCallOptions,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_import_call_options(
+        &mut self,
+        node: &mut TsImportCallOptions,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_import_type(&mut self, node: &mut TsImportType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_import_type(&mut self, node: &mut TsImportType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_lit(&mut self, node: &mut TsTypeLit, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_lit(&mut self, node: &mut TsTypeLit, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_array_type(&mut self, node: &mut TsArrayType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_array_type(&mut self, node: &mut TsArrayType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_tuple_type(&mut self, node: &mut TsTupleType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_tuple_type(&mut self, node: &mut TsTupleType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_tuple_element(&mut self, node: &mut TsTupleElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_tuple_element(&mut self, node: &mut TsTupleElement, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_optional_type(&mut self, node: &mut TsOptionalType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_optional_type(&mut self, node: &mut TsOptionalType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_rest_type(&mut self, node: &mut TsRestType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_rest_type(&mut self, node: &mut TsRestType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_union_or_intersection_type(
+        &mut self,
+        node: &mut TsUnionOrIntersectionType,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_union_or_intersection_type(
+        &mut self,
+        node: &mut TsUnionOrIntersectionType,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_union_type(&mut self, node: &mut TsUnionType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_union_type(&mut self, node: &mut TsUnionType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_intersection_type(&mut self, node: &mut TsIntersectionType, ctx: &mut TraverseCtx) {
+    }
+    #[inline]
+    fn exit_ts_intersection_type(&mut self, node: &mut TsIntersectionType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_conditional_type(&mut self, node: &mut TsConditionalType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_conditional_type(&mut self, node: &mut TsConditionalType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_infer_type(&mut self, node: &mut TsInferType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_infer_type(&mut self, node: &mut TsInferType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_parenthesized_type(
+        &mut self,
+        node: &mut TsParenthesizedType,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_parenthesized_type(
+        &mut self,
+        node: &mut TsParenthesizedType,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_type_operator(&mut self, node: &mut TsTypeOperator, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_operator(&mut self, node: &mut TsTypeOperator, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_indexed_access_type(
+        &mut self,
+        node: &mut TsIndexedAccessType,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_indexed_access_type(
+        &mut self,
+        node: &mut TsIndexedAccessType,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_mapped_type(&mut self, node: &mut TsMappedType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_mapped_type(&mut self, node: &mut TsMappedType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_lit_type(&mut self, node: &mut TsLitType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_lit_type(&mut self, node: &mut TsLitType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_lit(&mut self, node: &mut TsLit, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_lit(&mut self, node: &mut TsLit, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_tpl_lit_type(&mut self, node: &mut TsTplLitType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_tpl_lit_type(&mut self, node: &mut TsTplLitType, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_interface_decl(&mut self, node: &mut TsInterfaceDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_interface_decl(&mut self, node: &mut TsInterfaceDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_interface_body(&mut self, node: &mut TsInterfaceBody, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_interface_body(&mut self, node: &mut TsInterfaceBody, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_expr_with_type_args(
+        &mut self,
+        node: &mut TsExprWithTypeArgs,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_expr_with_type_args(
+        &mut self,
+        node: &mut TsExprWithTypeArgs,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_type_alias_decl(&mut self, node: &mut TsTypeAliasDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_alias_decl(&mut self, node: &mut TsTypeAliasDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_enum_decl(&mut self, node: &mut TsEnumDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_enum_decl(&mut self, node: &mut TsEnumDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_enum_member(&mut self, node: &mut TsEnumMember, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_enum_member(&mut self, node: &mut TsEnumMember, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_enum_member_id(&mut self, node: &mut TsEnumMemberId, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_enum_member_id(&mut self, node: &mut TsEnumMemberId, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_module_decl(&mut self, node: &mut TsModuleDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_module_decl(&mut self, node: &mut TsModuleDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_namespace_body(&mut self, node: &mut TsNamespaceBody, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_namespace_body(&mut self, node: &mut TsNamespaceBody, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_module_block(&mut self, node: &mut TsModuleBlock, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_module_block(&mut self, node: &mut TsModuleBlock, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_namespace_decl(&mut self, node: &mut TsNamespaceDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_namespace_decl(&mut self, node: &mut TsNamespaceDecl, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_module_name(&mut self, node: &mut TsModuleName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_module_name(&mut self, node: &mut TsModuleName, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_import_equals_decl(
+        &mut self,
+        node: &mut TsImportEqualsDecl,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_import_equals_decl(&mut self, node: &mut TsImportEqualsDecl, ctx: &mut TraverseCtx) {
+    }
+    #[inline]
+    fn enter_ts_module_ref(&mut self, node: &mut TsModuleRef, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_module_ref(&mut self, node: &mut TsModuleRef, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_external_module_ref(
+        &mut self,
+        node: &mut TsExternalModuleRef,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_external_module_ref(
+        &mut self,
+        node: &mut TsExternalModuleRef,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_export_assignment(&mut self, node: &mut TsExportAssignment, ctx: &mut TraverseCtx) {
+    }
+    #[inline]
+    fn exit_ts_export_assignment(&mut self, node: &mut TsExportAssignment, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_namespace_export_decl(
+        &mut self,
+        node: &mut TsNamespaceExportDecl,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_namespace_export_decl(
+        &mut self,
+        node: &mut TsNamespaceExportDecl,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_as_expr(&mut self, node: &mut TsAsExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_as_expr(&mut self, node: &mut TsAsExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_assertion(&mut self, node: &mut TsTypeAssertion, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_type_assertion(&mut self, node: &mut TsTypeAssertion, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_non_null_expr(&mut self, node: &mut TsNonNullExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_non_null_expr(&mut self, node: &mut TsNonNullExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_satisfies_expr(&mut self, node: &mut TsSatisfiesExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_satisfies_expr(&mut self, node: &mut TsSatisfiesExpr, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_const_assertion(&mut self, node: &mut TsConstAssertion, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_const_assertion(&mut self, node: &mut TsConstAssertion, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_instantiation(&mut self, node: &mut TsInstantiation, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_instantiation(&mut self, node: &mut TsInstantiation, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_module_items(&mut self, node: &mut TypedSubRange<ModuleItem>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_module_items(&mut self, node: &mut TypedSubRange<ModuleItem>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_stmts(&mut self, node: &mut TypedSubRange<Stmt>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_stmts(&mut self, node: &mut TypedSubRange<Stmt>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_import_specifiers(
+        &mut self,
+        node: &mut TypedSubRange<ImportSpecifier>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_import_specifiers(
+        &mut self,
+        node: &mut TypedSubRange<ImportSpecifier>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_export_specifiers(
+        &mut self,
+        node: &mut TypedSubRange<ExportSpecifier>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_export_specifiers(
+        &mut self,
+        node: &mut TypedSubRange<ExportSpecifier>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_switch_cases(&mut self, node: &mut TypedSubRange<SwitchCase>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_switch_cases(&mut self, node: &mut TypedSubRange<SwitchCase>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_var_declarators(
+        &mut self,
+        node: &mut TypedSubRange<VarDeclarator>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_var_declarators(
+        &mut self,
+        node: &mut TypedSubRange<VarDeclarator>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_opt_vec_expr_or_spreads(
+        &mut self,
+        node: &mut TypedSubRange<Option<ExprOrSpread>>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_opt_vec_expr_or_spreads(
+        &mut self,
+        node: &mut TypedSubRange<Option<ExprOrSpread>>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_prop_or_spreads(
+        &mut self,
+        node: &mut TypedSubRange<PropOrSpread>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_prop_or_spreads(
+        &mut self,
+        node: &mut TypedSubRange<PropOrSpread>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_expr_or_spreads(
+        &mut self,
+        node: &mut TypedSubRange<ExprOrSpread>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_expr_or_spreads(
+        &mut self,
+        node: &mut TypedSubRange<ExprOrSpread>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_exprs(&mut self, node: &mut TypedSubRange<Expr>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_exprs(&mut self, node: &mut TypedSubRange<Expr>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_pats(&mut self, node: &mut TypedSubRange<Pat>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_pats(&mut self, node: &mut TypedSubRange<Pat>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_tpl_elements(&mut self, node: &mut TypedSubRange<TplElement>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_tpl_elements(&mut self, node: &mut TypedSubRange<TplElement>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_params(&mut self, node: &mut TypedSubRange<Param>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_params(&mut self, node: &mut TypedSubRange<Param>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_decorators(&mut self, node: &mut TypedSubRange<Decorator>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_decorators(&mut self, node: &mut TypedSubRange<Decorator>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_class_members(
+        &mut self,
+        node: &mut TypedSubRange<ClassMember>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_class_members(&mut self, node: &mut TypedSubRange<ClassMember>, ctx: &mut TraverseCtx) {
+    }
+    #[inline]
+    fn enter_ts_expr_with_type_argss(
+        &mut self,
+        node: &mut TypedSubRange<TsExprWithTypeArgs>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_expr_with_type_argss(
+        &mut self,
+        node: &mut TypedSubRange<TsExprWithTypeArgs>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_param_or_ts_param_props(
+        &mut self,
+        node: &mut TypedSubRange<ParamOrTsParamProp>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_param_or_ts_param_props(
+        &mut self,
+        node: &mut TypedSubRange<ParamOrTsParamProp>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_opt_vec_pats(&mut self, node: &mut TypedSubRange<Option<Pat>>, ctx: &mut TraverseCtx) {
+    }
+    #[inline]
+    fn exit_opt_vec_pats(&mut self, node: &mut TypedSubRange<Option<Pat>>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_object_pat_props(
+        &mut self,
+        node: &mut TypedSubRange<ObjectPatProp>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_object_pat_props(
+        &mut self,
+        node: &mut TypedSubRange<ObjectPatProp>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_jsx_attr_or_spreads(
+        &mut self,
+        node: &mut TypedSubRange<JSXAttrOrSpread>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_jsx_attr_or_spreads(
+        &mut self,
+        node: &mut TypedSubRange<JSXAttrOrSpread>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_jsx_element_childs(
+        &mut self,
+        node: &mut TypedSubRange<JSXElementChild>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_jsx_element_childs(
+        &mut self,
+        node: &mut TypedSubRange<JSXElementChild>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_type_params(
+        &mut self,
+        node: &mut TypedSubRange<TsTypeParam>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_type_params(
+        &mut self,
+        node: &mut TypedSubRange<TsTypeParam>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_types(&mut self, node: &mut TypedSubRange<TsType>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_types(&mut self, node: &mut TypedSubRange<TsType>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_fn_params(&mut self, node: &mut TypedSubRange<TsFnParam>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn exit_ts_fn_params(&mut self, node: &mut TypedSubRange<TsFnParam>, ctx: &mut TraverseCtx) {}
+    #[inline]
+    fn enter_ts_type_elements(
+        &mut self,
+        node: &mut TypedSubRange<TsTypeElement>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_type_elements(
+        &mut self,
+        node: &mut TypedSubRange<TsTypeElement>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_tuple_elements(
+        &mut self,
+        node: &mut TypedSubRange<TsTupleElement>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_tuple_elements(
+        &mut self,
+        node: &mut TypedSubRange<TsTupleElement>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn enter_ts_enum_members(
+        &mut self,
+        node: &mut TypedSubRange<TsEnumMember>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+    #[inline]
+    fn exit_ts_enum_members(
+        &mut self,
+        node: &mut TypedSubRange<TsEnumMember>,
+        ctx: &mut TraverseCtx,
+    ) {
+    }
+}
+pub trait TraverseWith: Sized {
+    /// Calls the hooks of `traverser` on this node and its
+    /// descendants, and returns the node replacing it.
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self;
+}
+impl TraverseWith for Program {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_program(&mut node, ctx);
+        node = match node {
+            Self::Module(it) => {
+                Self::Module(<Module as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Script(it) => {
+                Self::Script(<Script as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_program(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Module {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_module(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_module(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Script {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_script(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_script(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ModuleItem {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_module_item(&mut node, ctx);
+        node = match node {
+            Self::ModuleDecl(it) => Self::ModuleDecl(<ModuleDecl as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Stmt(it) => Self::Stmt(<Stmt as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_module_item(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ModuleDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_module_decl(&mut node, ctx);
+        node = match node {
+            Self::Import(it) => Self::Import(<ImportDecl as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::ExportDecl(it) => Self::ExportDecl(<ExportDecl as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::ExportNamed(it) => Self::ExportNamed(
+                <NamedExport as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::ExportDefaultDecl(it) => Self::ExportDefaultDecl(
+                <ExportDefaultDecl as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::ExportDefaultExpr(it) => Self::ExportDefaultExpr(
+                <ExportDefaultExpr as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::ExportAll(it) => Self::ExportAll(<ExportAll as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsImportEquals(it) => Self::TsImportEquals(
+                <TsImportEqualsDecl as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsExportAssignment(it) => Self::TsExportAssignment(
+                <TsExportAssignment as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsNamespaceExport(it) => Self::TsNamespaceExport(
+                <TsNamespaceExportDecl as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_module_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ImportDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_import_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.specifiers(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_specifiers(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.src(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_src(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.with(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_with(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_import_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ImportSpecifier {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_import_specifier(&mut node, ctx);
+        node = match node {
+            Self::Named(it) => Self::Named(<ImportNamedSpecifier as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Default(it) => Self::Default(
+                <ImportDefaultSpecifier as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Namespace(it) => Self::Namespace(
+                <ImportStarAsSpecifier as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_import_specifier(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ImportNamedSpecifier {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_import_named_specifier(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.local(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_local(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.imported(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_imported(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_import_named_specifier(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ImportDefaultSpecifier {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_import_default_specifier(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.local(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_local(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_import_default_specifier(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ImportStarAsSpecifier {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_import_star_as_specifier(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.local(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_local(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_import_star_as_specifier(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ExportDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_export_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.decl(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decl(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_export_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for NamedExport {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_named_export(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.specifiers(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_specifiers(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.src(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_src(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.with(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_with(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_named_export(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ExportSpecifier {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_export_specifier(&mut node, ctx);
+        node = match node {
+            Self::Namespace(it) => Self::Namespace(
+                <ExportNamespaceSpecifier as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Default(it) => Self::Default(
+                <ExportDefaultSpecifier as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Named(it) => Self::Named(<ExportNamedSpecifier as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+        };
+        traverser.exit_export_specifier(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ExportNamespaceSpecifier {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_export_namespace_specifier(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.name(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_name(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_export_namespace_specifier(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ModuleExportName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_module_export_name(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => {
+                Self::Ident(<Ident as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Str(it) => Self::Str(<Str as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_module_export_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ExportDefaultSpecifier {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_export_default_specifier(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.exported(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_exported(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_export_default_specifier(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ExportNamedSpecifier {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_export_named_specifier(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.orig(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_orig(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.exported(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_exported(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_export_named_specifier(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ExportDefaultDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_export_default_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.decl(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decl(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_export_default_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for DefaultDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_default_decl(&mut node, ctx);
+        node = match node {
+            Self::Class(it) => Self::Class(<ClassExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Fn(it) => Self::Fn(<FnExpr as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::TsInterfaceDecl(it) => Self::TsInterfaceDecl(
+                <TsInterfaceDecl as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_default_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ExportDefaultExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_export_default_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_export_default_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ExportAll {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_export_all(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.src(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_src(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.with(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_with(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_export_all(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for BlockStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_block_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.stmts(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_stmts(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_block_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Stmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_stmt(&mut node, ctx);
+        node = match node {
+            Self::Block(it) => Self::Block(<BlockStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Empty(it) => Self::Empty(<EmptyStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Debugger(it) => Self::Debugger(<DebuggerStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::With(it) => Self::With(<WithStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Return(it) => Self::Return(<ReturnStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Labeled(it) => Self::Labeled(<LabeledStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Break(it) => Self::Break(<BreakStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Continue(it) => Self::Continue(<ContinueStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::If(it) => Self::If(<IfStmt as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Switch(it) => Self::Switch(<SwitchStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Throw(it) => Self::Throw(<ThrowStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Try(it) => {
+                Self::Try(<TryStmt as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::While(it) => Self::While(<WhileStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::DoWhile(it) => Self::DoWhile(<DoWhileStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::For(it) => {
+                Self::For(<ForStmt as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::ForIn(it) => Self::ForIn(<ForInStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::ForOf(it) => Self::ForOf(<ForOfStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Decl(it) => Self::Decl(<Decl as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Expr(it) => Self::Expr(<ExprStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+        };
+        traverser.exit_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ExprStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_expr_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_expr_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for EmptyStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_empty_stmt(&mut node, ctx);
+        traverser.exit_empty_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for DebuggerStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_debugger_stmt(&mut node, ctx);
+        traverser.exit_debugger_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for WithStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_with_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.obj(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_obj(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_with_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ReturnStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_return_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.arg(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_arg(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_return_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for LabeledStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_labeled_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.label(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_label(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_labeled_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for BreakStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_break_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.label(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_label(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_break_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ContinueStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_continue_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.label(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_label(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_continue_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for IfStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_if_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.test(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_test(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.cons(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_cons(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.alt(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_alt(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_if_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for SwitchStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_switch_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.discriminant(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_discriminant(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.cases(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_cases(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_switch_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ThrowStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_throw_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.arg(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_arg(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_throw_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TryStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_try_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.block(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_block(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.handler(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_handler(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.finalizer(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_finalizer(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_try_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for WhileStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_while_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.test(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_test(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_while_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for DoWhileStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_do_while_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.test(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_test(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_do_while_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ForStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_for_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.init(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_init(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.test(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_test(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.update(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_update(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_for_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ForInStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_for_in_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.left(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_left(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.right(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_right(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_for_in_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ForOfStmt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_for_of_stmt(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.left(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_left(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.right(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_right(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_for_of_stmt(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for SwitchCase {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_switch_case(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.test(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_test(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.cons(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_cons(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_switch_case(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for CatchClause {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_catch_clause(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.param(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_param(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_catch_clause(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ForHead {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_for_head(&mut node, ctx);
+        node = match node {
+            Self::VarDecl(it) => {
+                Self::VarDecl(<VarDecl as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::UsingDecl(it) => Self::UsingDecl(<UsingDecl as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Pat(it) => Self::Pat(<Pat as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_for_head(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for VarDeclOrExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_var_decl_or_expr(&mut node, ctx);
+        node = match node {
+            Self::VarDecl(it) => {
+                Self::VarDecl(<VarDecl as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Expr(it) => Self::Expr(<Expr as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_var_decl_or_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Decl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_decl(&mut node, ctx);
+        node = match node {
+            Self::Class(it) => Self::Class(<ClassDecl as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Fn(it) => Self::Fn(<FnDecl as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Var(it) => {
+                Self::Var(<VarDecl as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Using(it) => Self::Using(<UsingDecl as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsInterface(it) => Self::TsInterface(
+                <TsInterfaceDecl as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsTypeAlias(it) => Self::TsTypeAlias(
+                <TsTypeAliasDecl as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsEnum(it) => Self::TsEnum(<TsEnumDecl as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsModule(it) => Self::TsModule(<TsModuleDecl as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+        };
+        traverser.exit_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for FnDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_fn_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.ident(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_ident(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.function(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_function(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_fn_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ClassDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_class_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.ident(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_ident(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.class(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_class(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_class_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for VarDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_var_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.decls(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decls(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_var_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for VarDeclarator {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_var_declarator(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.name(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_name(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.init(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_init(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_var_declarator(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for UsingDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_using_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.decls(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decls(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_using_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Expr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_expr(&mut node, ctx);
+        node = match node {
+            Self::This(it) => Self::This(<ThisExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Array(it) => Self::Array(<ArrayLit as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Object(it) => Self::Object(<ObjectLit as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Fn(it) => Self::Fn(<FnExpr as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Unary(it) => Self::Unary(<UnaryExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Update(it) => Self::Update(<UpdateExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Bin(it) => {
+                Self::Bin(<BinExpr as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Assign(it) => Self::Assign(<AssignExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Member(it) => Self::Member(<MemberExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::SuperProp(it) => Self::SuperProp(<SuperPropExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Cond(it) => Self::Cond(<CondExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Call(it) => Self::Call(<CallExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::New(it) => {
+                Self::New(<NewExpr as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Seq(it) => {
+                Self::Seq(<SeqExpr as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Ident(it) => {
+                Self::Ident(<Ident as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Lit(it) => Self::Lit(<Lit as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Tpl(it) => Self::Tpl(<Tpl as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::TaggedTpl(it) => Self::TaggedTpl(<TaggedTpl as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Arrow(it) => Self::Arrow(<ArrowExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Class(it) => Self::Class(<ClassExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Yield(it) => Self::Yield(<YieldExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::MetaProp(it) => Self::MetaProp(<MetaPropExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Await(it) => Self::Await(<AwaitExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Paren(it) => Self::Paren(<ParenExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::JSXMember(it) => Self::JSXMember(<JSXMemberExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::JSXNamespacedName(it) => Self::JSXNamespacedName(
+                <JSXNamespacedName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::JSXEmpty(it) => Self::JSXEmpty(<JSXEmptyExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::JSXElement(it) => Self::JSXElement(<JSXElement as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::JSXFragment(it) => Self::JSXFragment(
+                <JSXFragment as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsTypeAssertion(it) => Self::TsTypeAssertion(
+                <TsTypeAssertion as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsConstAssertion(it) => Self::TsConstAssertion(
+                <TsConstAssertion as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsNonNull(it) => Self::TsNonNull(<TsNonNullExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsAs(it) => Self::TsAs(<TsAsExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsInstantiation(it) => Self::TsInstantiation(
+                <TsInstantiation as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsSatisfies(it) => Self::TsSatisfies(
+                <TsSatisfiesExpr as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::PrivateName(it) => Self::PrivateName(
+                <PrivateName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::OptChain(it) => Self::OptChain(<OptChainExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Invalid(it) => {
+                Self::Invalid(<Invalid as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ThisExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_this_expr(&mut node, ctx);
+        traverser.exit_this_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ArrayLit {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_array_lit(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.elems(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_elems(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_array_lit(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ObjectLit {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_object_lit(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.props(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_props(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_object_lit(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for PropOrSpread {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_prop_or_spread(&mut node, ctx);
+        node = match node {
+            Self::SpreadElement(it) => Self::SpreadElement(
+                <SpreadElement as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Prop(it) => Self::Prop(<Prop as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_prop_or_spread(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for SpreadElement {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_spread_element(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_spread_element(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for UnaryExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_unary_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.arg(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_arg(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_unary_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for UpdateExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_update_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.arg(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_arg(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_update_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for BinExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_bin_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.left(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_left(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.right(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_right(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_bin_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for FnExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_fn_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.ident(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_ident(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.function(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_function(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_fn_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ClassExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_class_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.ident(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_ident(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.class(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_class(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_class_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for AssignExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_assign_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.left(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_left(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.right(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_right(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_assign_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for MemberExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_member_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.obj(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_obj(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.prop(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_prop(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_member_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for MemberProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_member_prop(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => Self::Ident(<IdentName as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::PrivateName(it) => Self::PrivateName(
+                <PrivateName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Computed(it) => Self::Computed(
+                <ComputedPropName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_member_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for SuperPropExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_super_prop_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.obj(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_obj(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.prop(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_prop(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_super_prop_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for SuperProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_super_prop(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => Self::Ident(<IdentName as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Computed(it) => Self::Computed(
+                <ComputedPropName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_super_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for CondExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_cond_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.test(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_test(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.cons(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_cons(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.alt(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_alt(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_cond_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for CallExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_call_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.callee(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_callee(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_args(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_args(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_call_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for NewExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_new_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.callee(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_callee(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_args(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_args(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_new_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for SeqExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_seq_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.exprs(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_exprs(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_seq_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ArrowExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_arrow_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(5u8);
+        let field_value = node.return_type(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_return_type(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_arrow_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for YieldExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_yield_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.arg(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_arg(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_yield_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for MetaPropExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_meta_prop_expr(&mut node, ctx);
+        traverser.exit_meta_prop_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for AwaitExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_await_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.arg(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_arg(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_await_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Tpl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_tpl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.exprs(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_exprs(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.quasis(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_quasis(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_tpl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TaggedTpl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_tagged_tpl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.tag(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_tag(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.tpl(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_tpl(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_tagged_tpl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TplElement {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_tpl_element(&mut node, ctx);
+        traverser.exit_tpl_element(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ParenExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_paren_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_paren_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Callee {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_callee(&mut node, ctx);
+        node = match node {
+            Self::Super(it) => {
+                Self::Super(<Super as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Import(it) => {
+                Self::Import(<Import as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Expr(it) => Self::Expr(<Expr as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_callee(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Super {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_super(&mut node, ctx);
+        traverser.exit_super(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Import {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_import(&mut node, ctx);
+        traverser.exit_import(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ExprOrSpread {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_expr_or_spread(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.spread(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_spread(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_expr_or_spread(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for SpreadDot3Token {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_spread_dot_3_token(&mut node, ctx);
+        traverser.exit_spread_dot_3_token(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for BlockStmtOrExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_block_stmt_or_expr(&mut node, ctx);
+        node = match node {
+            Self::BlockStmt(it) => Self::BlockStmt(<BlockStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Expr(it) => Self::Expr(<Expr as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_block_stmt_or_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for AssignTarget {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_assign_target(&mut node, ctx);
+        node = match node {
+            Self::Simple(it) => Self::Simple(<SimpleAssignTarget as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Pat(it) => Self::Pat(<AssignTargetPat as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+        };
+        traverser.exit_assign_target(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for AssignTargetPat {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_assign_target_pat(&mut node, ctx);
+        node = match node {
+            Self::Array(it) => Self::Array(<ArrayPat as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Object(it) => Self::Object(<ObjectPat as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Invalid(it) => {
+                Self::Invalid(<Invalid as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_assign_target_pat(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for SimpleAssignTarget {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_simple_assign_target(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => Self::Ident(<BindingIdent as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Member(it) => Self::Member(<MemberExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::SuperProp(it) => Self::SuperProp(<SuperPropExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Paren(it) => Self::Paren(<ParenExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::OptChain(it) => Self::OptChain(<OptChainExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsAs(it) => Self::TsAs(<TsAsExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsSatisfies(it) => Self::TsSatisfies(
+                <TsSatisfiesExpr as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsNonNull(it) => Self::TsNonNull(<TsNonNullExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsTypeAssertion(it) => Self::TsTypeAssertion(
+                <TsTypeAssertion as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsInstantiation(it) => Self::TsInstantiation(
+                <TsInstantiation as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Invalid(it) => {
+                Self::Invalid(<Invalid as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_simple_assign_target(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for OptChainExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_opt_chain_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.base(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_base(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_opt_chain_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for OptChainBase {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_opt_chain_base(&mut node, ctx);
+        node = match node {
+            Self::Member(it) => Self::Member(<MemberExpr as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Call(it) => {
+                Self::Call(<OptCall as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_opt_chain_base(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for OptCall {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_opt_call(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.callee(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_callee(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_args(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_args(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_opt_call(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Invalid {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_invalid(&mut node, ctx);
+        traverser.exit_invalid(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Function {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_function(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.decorators(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decorators(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.set_field(5u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(6u8);
+        let field_value = node.return_type(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_return_type(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_function(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Param {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_param(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.decorators(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decorators(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.pat(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_pat(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_param(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ParamOrTsParamProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_param_or_ts_param_prop(&mut node, ctx);
+        node = match node {
+            Self::TsParamProp(it) => Self::TsParamProp(
+                <TsParamProp as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Param(it) => {
+                Self::Param(<Param as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_param_or_ts_param_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Class {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_class(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.decorators(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decorators(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.super_class(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_super_class(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(5u8);
+        let field_value = node.super_type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_super_type_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(6u8);
+        let field_value = node.implements(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_implements(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_class(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ClassMember {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_class_member(&mut node, ctx);
+        node = match node {
+            Self::Constructor(it) => Self::Constructor(
+                <Constructor as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Method(it) => Self::Method(<ClassMethod as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::PrivateMethod(it) => Self::PrivateMethod(
+                <PrivateMethod as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::ClassProp(it) => Self::ClassProp(<ClassProp as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::PrivateProp(it) => Self::PrivateProp(
+                <PrivateProp as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Empty(it) => Self::Empty(<EmptyStmt as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::StaticBlock(it) => Self::StaticBlock(
+                <StaticBlock as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::AutoAccessor(it) => Self::AutoAccessor(
+                <AutoAccessor as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsIndexSignature(it) => Self::TsIndexSignature(
+                <TsIndexSignature as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_class_member(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ClassProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_class_prop(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.value(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_value(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.decorators(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decorators(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_class_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for PrivateProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_private_prop(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.value(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_value(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.decorators(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decorators(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_private_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ClassMethod {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_class_method(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.function(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_function(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_class_method(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for PrivateMethod {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_private_method(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.function(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_function(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_private_method(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Constructor {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_constructor(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_constructor(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Decorator {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_decorator(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_decorator(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for StaticBlock {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_static_block(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_static_block(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Key {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_key(&mut node, ctx);
+        node = match node {
+            Self::Private(it) => Self::Private(<PrivateName as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Public(it) => Self::Public(<PropName as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+        };
+        traverser.exit_key(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for AutoAccessor {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_auto_accessor(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.value(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_value(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.decorators(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decorators(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_auto_accessor(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Prop {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_prop(&mut node, ctx);
+        node = match node {
+            Self::Shorthand(it) => {
+                Self::Shorthand(<Ident as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::KeyValue(it) => Self::KeyValue(<KeyValueProp as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Assign(it) => Self::Assign(<AssignProp as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Getter(it) => Self::Getter(<GetterProp as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Setter(it) => Self::Setter(<SetterProp as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Method(it) => Self::Method(<MethodProp as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+        };
+        traverser.exit_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for KeyValueProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_key_value_prop(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.value(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_value(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_key_value_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for AssignProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_assign_prop(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.value(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_value(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_assign_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for GetterProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_getter_prop(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_getter_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for SetterProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_setter_prop(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.this_param(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_this_param(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.param(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_param(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_setter_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for MethodProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_method_prop(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.function(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_function(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_method_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for PropName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_prop_name(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => Self::Ident(<IdentName as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Str(it) => Self::Str(<Str as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Num(it) => Self::Num(<Number as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Computed(it) => Self::Computed(
+                <ComputedPropName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::BigInt(it) => {
+                Self::BigInt(<BigInt as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_prop_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ComputedPropName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_computed_prop_name(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_computed_prop_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Pat {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_pat(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => Self::Ident(<BindingIdent as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Array(it) => Self::Array(<ArrayPat as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Rest(it) => {
+                Self::Rest(<RestPat as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Object(it) => Self::Object(<ObjectPat as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Assign(it) => Self::Assign(<AssignPat as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Invalid(it) => {
+                Self::Invalid(<Invalid as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Expr(it) => Self::Expr(<Expr as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_pat(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ArrayPat {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_array_pat(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.elems(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_elems(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_array_pat(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ObjectPat {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_object_pat(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.props(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_props(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_object_pat(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for AssignPat {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_assign_pat(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.left(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_left(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.right(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_right(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_assign_pat(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for RestPat {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_rest_pat(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.arg(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_arg(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_rest_pat(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for ObjectPatProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_object_pat_prop(&mut node, ctx);
+        node = match node {
+            Self::KeyValue(it) => Self::KeyValue(<KeyValuePatProp as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Assign(it) => Self::Assign(<AssignPatProp as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Rest(it) => {
+                Self::Rest(<RestPat as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_object_pat_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for KeyValuePatProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_key_value_pat_prop(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.value(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_value(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_key_value_pat_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for AssignPatProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_assign_pat_prop(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.value(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_value(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_assign_pat_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Ident {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ident(&mut node, ctx);
+        traverser.exit_ident(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for IdentName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ident_name(&mut node, ctx);
+        traverser.exit_ident_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for PrivateName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_private_name(&mut node, ctx);
+        traverser.exit_private_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for BindingIdent {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_binding_ident(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.id(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_id(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_binding_ident(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Lit {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_lit(&mut node, ctx);
+        node = match node {
+            Self::Str(it) => Self::Str(<Str as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Bool(it) => Self::Bool(<Bool as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Null(it) => Self::Null(<Null as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Num(it) => Self::Num(<Number as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::BigInt(it) => {
+                Self::BigInt(<BigInt as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Regex(it) => {
+                Self::Regex(<Regex as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_lit(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Str {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_str(&mut node, ctx);
+        traverser.exit_str(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Bool {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_bool(&mut node, ctx);
+        traverser.exit_bool(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Null {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_null(&mut node, ctx);
+        traverser.exit_null(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Number {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_number(&mut node, ctx);
+        traverser.exit_number(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for BigInt {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_big_int(&mut node, ctx);
+        traverser.exit_big_int(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Regex {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_regex(&mut node, ctx);
+        traverser.exit_regex(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXObject {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_object(&mut node, ctx);
+        node = match node {
+            Self::JSXMemberExpr(it) => Self::JSXMemberExpr(
+                <JSXMemberExpr as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Ident(it) => {
+                Self::Ident(<Ident as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_jsx_object(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXMemberExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_member_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.obj(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_obj(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.prop(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_prop(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_jsx_member_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXNamespacedName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_namespaced_name(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.ns(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_ns(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.name(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_name(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_jsx_namespaced_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXEmptyExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_empty_expr(&mut node, ctx);
+        traverser.exit_jsx_empty_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXExprContainer {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_expr_container(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_jsx_expr_container(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_expr(&mut node, ctx);
+        node = match node {
+            Self::JSXEmptyExpr(it) => Self::JSXEmptyExpr(
+                <JSXEmptyExpr as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Expr(it) => Self::Expr(<Expr as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_jsx_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXSpreadChild {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_spread_child(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_jsx_spread_child(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXElementName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_element_name(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => {
+                Self::Ident(<Ident as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::JSXMemberExpr(it) => Self::JSXMemberExpr(
+                <JSXMemberExpr as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::JSXNamespacedName(it) => Self::JSXNamespacedName(
+                <JSXNamespacedName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_jsx_element_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXOpeningElement {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_opening_element(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.name(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_name(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.attrs(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_attrs(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.type_args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_args(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_jsx_opening_element(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXAttrOrSpread {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_attr_or_spread(&mut node, ctx);
+        node = match node {
+            Self::JSXAttr(it) => {
+                Self::JSXAttr(<JSXAttr as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::SpreadElement(it) => Self::SpreadElement(
+                <SpreadElement as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_jsx_attr_or_spread(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXClosingElement {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_closing_element(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.name(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_name(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_jsx_closing_element(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXAttr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_attr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.name(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_name(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.value(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_value(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_jsx_attr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXAttrName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_attr_name(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => Self::Ident(<IdentName as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::JSXNamespacedName(it) => Self::JSXNamespacedName(
+                <JSXNamespacedName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_jsx_attr_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXAttrValue {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_attr_value(&mut node, ctx);
+        node = match node {
+            Self::Str(it) => Self::Str(<Str as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::JSXExprContainer(it) => Self::JSXExprContainer(
+                <JSXExprContainer as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::JSXElement(it) => Self::JSXElement(<JSXElement as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::JSXFragment(it) => Self::JSXFragment(
+                <JSXFragment as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_jsx_attr_value(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXText {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_text(&mut node, ctx);
+        traverser.exit_jsx_text(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXElement {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_element(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.opening(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_opening(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.children(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_children(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.closing(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_closing(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_jsx_element(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXElementChild {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_element_child(&mut node, ctx);
+        node = match node {
+            Self::JSXText(it) => {
+                Self::JSXText(<JSXText as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::JSXExprContainer(it) => Self::JSXExprContainer(
+                <JSXExprContainer as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::JSXSpreadChild(it) => Self::JSXSpreadChild(
+                <JSXSpreadChild as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::JSXElement(it) => Self::JSXElement(<JSXElement as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::JSXFragment(it) => Self::JSXFragment(
+                <JSXFragment as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_jsx_element_child(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXFragment {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_fragment(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.opening(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_opening(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.children(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_children(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.closing(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_closing(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_jsx_fragment(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXOpeningFragment {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_opening_fragment(&mut node, ctx);
+        traverser.exit_jsx_opening_fragment(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for JSXClosingFragment {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_closing_fragment(&mut node, ctx);
+        traverser.exit_jsx_closing_fragment(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeAnn {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_ann(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_ann(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeParamDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_param_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_param_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeParam {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_param(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.name(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_name(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.constraint(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_constraint(ctx.ast, new_node);
+        ctx.ancestors.set_field(5u8);
+        let field_value = node.default(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_default(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_param(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeParamInstantiation {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_param_instantiation(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_param_instantiation(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsParamProp {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_param_prop(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.decorators(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_decorators(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.param(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_param(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_param_prop(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsParamPropParam {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_param_prop_param(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => Self::Ident(<BindingIdent as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Assign(it) => Self::Assign(<AssignPat as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+        };
+        traverser.exit_ts_param_prop_param(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsQualifiedName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_qualified_name(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.left(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_left(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.right(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_right(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_qualified_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsEntityName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_entity_name(&mut node, ctx);
+        node = match node {
+            Self::TsQualifiedName(it) => Self::TsQualifiedName(
+                <TsQualifiedName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Ident(it) => {
+                Self::Ident(<Ident as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_ts_entity_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeElement {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_element(&mut node, ctx);
+        node = match node {
+            Self::TsCallSignatureDecl(it) => Self::TsCallSignatureDecl(
+                <TsCallSignatureDecl as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsConstructSignatureDecl(it) => Self::TsConstructSignatureDecl(
+                <TsConstructSignatureDecl as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsPropertySignature(it) => Self::TsPropertySignature(
+                <TsPropertySignature as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsGetterSignature(it) => Self::TsGetterSignature(
+                <TsGetterSignature as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsSetterSignature(it) => Self::TsSetterSignature(
+                <TsSetterSignature as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsMethodSignature(it) => Self::TsMethodSignature(
+                <TsMethodSignature as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsIndexSignature(it) => Self::TsIndexSignature(
+                <TsIndexSignature as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_ts_type_element(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsCallSignatureDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_call_signature_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_call_signature_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsConstructSignatureDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_construct_signature_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_construct_signature_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsPropertySignature {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_property_signature(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_property_signature(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsGetterSignature {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_getter_signature(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_getter_signature(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsSetterSignature {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_setter_signature(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.param(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_param(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_setter_signature(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsMethodSignature {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_method_signature(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.key(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_key(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.set_field(5u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_method_signature(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsIndexSignature {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_index_signature(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_index_signature(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type(&mut node, ctx);
+        node = match node {
+            Self::TsKeywordType(it) => Self::TsKeywordType(
+                <TsKeywordType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsThisType(it) => Self::TsThisType(<TsThisType as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsFnOrConstructorType(it) => Self::TsFnOrConstructorType(
+                <TsFnOrConstructorType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsTypeRef(it) => Self::TsTypeRef(<TsTypeRef as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsTypeQuery(it) => Self::TsTypeQuery(
+                <TsTypeQuery as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsTypeLit(it) => Self::TsTypeLit(<TsTypeLit as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsArrayType(it) => Self::TsArrayType(
+                <TsArrayType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsTupleType(it) => Self::TsTupleType(
+                <TsTupleType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsOptionalType(it) => Self::TsOptionalType(
+                <TsOptionalType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsRestType(it) => Self::TsRestType(<TsRestType as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsUnionOrIntersectionType(it) => Self::TsUnionOrIntersectionType(
+                <TsUnionOrIntersectionType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsConditionalType(it) => Self::TsConditionalType(
+                <TsConditionalType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsInferType(it) => Self::TsInferType(
+                <TsInferType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsParenthesizedType(it) => Self::TsParenthesizedType(
+                <TsParenthesizedType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsTypeOperator(it) => Self::TsTypeOperator(
+                <TsTypeOperator as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsIndexedAccessType(it) => Self::TsIndexedAccessType(
+                <TsIndexedAccessType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsMappedType(it) => Self::TsMappedType(
+                <TsMappedType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsLitType(it) => Self::TsLitType(<TsLitType as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsTypePredicate(it) => Self::TsTypePredicate(
+                <TsTypePredicate as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsImportType(it) => Self::TsImportType(
+                <TsImportType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_ts_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsFnOrConstructorType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_fn_or_constructor_type(&mut node, ctx);
+        node = match node {
+            Self::TsFnType(it) => Self::TsFnType(<TsFnType as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::TsConstructorType(it) => Self::TsConstructorType(
+                <TsConstructorType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_ts_fn_or_constructor_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsKeywordType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_keyword_type(&mut node, ctx);
+        traverser.exit_ts_keyword_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsThisType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_this_type(&mut node, ctx);
+        traverser.exit_ts_this_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsFnParam {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_fn_param(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => Self::Ident(<BindingIdent as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Array(it) => Self::Array(<ArrayPat as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Rest(it) => {
+                Self::Rest(<RestPat as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Object(it) => Self::Object(<ObjectPat as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+        };
+        traverser.exit_ts_fn_param(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsFnType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_fn_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_fn_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsConstructorType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_constructor_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_constructor_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeRef {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_ref(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.type_name(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_name(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_ref(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypePredicate {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_predicate(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.param_name(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_param_name(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_predicate(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsThisTypeOrIdent {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_this_type_or_ident(&mut node, ctx);
+        node = match node {
+            Self::TsThisType(it) => Self::TsThisType(<TsThisType as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+            Self::Ident(it) => {
+                Self::Ident(<Ident as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+        };
+        traverser.exit_ts_this_type_or_ident(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeQuery {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_query(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr_name(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr_name(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_args(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_query(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeQueryExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_query_expr(&mut node, ctx);
+        node = match node {
+            Self::TsEntityName(it) => Self::TsEntityName(
+                <TsEntityName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::Import(it) => Self::Import(<TsImportType as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+        };
+        traverser.exit_ts_type_query_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsImportCallOptions {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_import_call_options(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.with(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_with(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_import_call_options(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsImportType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_import_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.arg(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_arg(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.qualifier(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_qualifier(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_args(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.attributes(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_attributes(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_import_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeLit {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_lit(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.members(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_members(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_lit(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsArrayType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_array_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.elem_type(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_elem_type(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_array_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTupleType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_tuple_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.elem_types(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_elem_types(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_tuple_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTupleElement {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_tuple_element(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.label(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_label(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.ty(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_ty(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_tuple_element(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsOptionalType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_optional_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_optional_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsRestType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_rest_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_rest_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsUnionOrIntersectionType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_union_or_intersection_type(&mut node, ctx);
+        node = match node {
+            Self::TsUnionType(it) => Self::TsUnionType(
+                <TsUnionType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsIntersectionType(it) => Self::TsIntersectionType(
+                <TsIntersectionType as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_ts_union_or_intersection_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsUnionType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_union_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.types(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_types(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_union_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsIntersectionType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_intersection_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.types(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_types(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_intersection_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsConditionalType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_conditional_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.check_type(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_check_type(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.extends_type(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_extends_type(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.true_type(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_true_type(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.false_type(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_false_type(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_conditional_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsInferType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_infer_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.type_param(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_param(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_infer_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsParenthesizedType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_parenthesized_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_parenthesized_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeOperator {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_operator(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_operator(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsIndexedAccessType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_indexed_access_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.obj_type(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_obj_type(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.index_type(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_index_type(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_indexed_access_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsMappedType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_mapped_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_param(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_param(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.name_type(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_name_type(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_mapped_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsLitType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_lit_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.lit(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_lit(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_lit_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsLit {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_lit(&mut node, ctx);
+        node = match node {
+            Self::Number(it) => {
+                Self::Number(<Number as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Str(it) => Self::Str(<Str as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::Bool(it) => Self::Bool(<Bool as TraverseWith>::traverse_with(it, traverser, ctx)),
+            Self::BigInt(it) => {
+                Self::BigInt(<BigInt as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Tpl(it) => Self::Tpl(<TsTplLitType as TraverseWith>::traverse_with(
+                it, traverser, ctx,
+            )),
+        };
+        traverser.exit_ts_lit(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTplLitType {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_tpl_lit_type(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.types(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_types(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.quasis(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_quasis(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_tpl_lit_type(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsInterfaceDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_interface_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.id(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_id(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.extends(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_extends(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_interface_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsInterfaceBody {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_interface_body(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_interface_body(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsExprWithTypeArgs {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_expr_with_type_args(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_args(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_expr_with_type_args(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeAliasDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_alias_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.id(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_id(ctx.ast, new_node);
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.type_params(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_params(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_alias_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsEnumDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_enum_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.id(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_id(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.members(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_members(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_enum_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsEnumMember {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_enum_member(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.id(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_id(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.init(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_init(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_enum_member(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsEnumMemberId {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_enum_member_id(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => {
+                Self::Ident(<Ident as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Str(it) => Self::Str(<Str as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_ts_enum_member_id(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsModuleDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_module_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.id(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_id(ctx.ast, new_node);
+        ctx.ancestors.set_field(4u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_module_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsNamespaceBody {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_namespace_body(&mut node, ctx);
+        node = match node {
+            Self::TsModuleBlock(it) => Self::TsModuleBlock(
+                <TsModuleBlock as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsNamespaceDecl(it) => Self::TsNamespaceDecl(
+                <TsNamespaceDecl as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_ts_namespace_body(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsModuleBlock {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_module_block(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_module_block(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsNamespaceDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_namespace_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.id(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_id(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.body(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_body(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_namespace_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsModuleName {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_module_name(&mut node, ctx);
+        node = match node {
+            Self::Ident(it) => {
+                Self::Ident(<Ident as TraverseWith>::traverse_with(it, traverser, ctx))
+            }
+            Self::Str(it) => Self::Str(<Str as TraverseWith>::traverse_with(it, traverser, ctx)),
+        };
+        traverser.exit_ts_module_name(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsImportEqualsDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_import_equals_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(2u8);
+        let field_value = node.id(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_id(ctx.ast, new_node);
+        ctx.ancestors.set_field(3u8);
+        let field_value = node.module_ref(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_module_ref(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_import_equals_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsModuleRef {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_module_ref(&mut node, ctx);
+        node = match node {
+            Self::TsEntityName(it) => Self::TsEntityName(
+                <TsEntityName as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+            Self::TsExternalModuleRef(it) => Self::TsExternalModuleRef(
+                <TsExternalModuleRef as TraverseWith>::traverse_with(it, traverser, ctx),
+            ),
+        };
+        traverser.exit_ts_module_ref(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsExternalModuleRef {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_external_module_ref(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_external_module_ref(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsExportAssignment {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_export_assignment(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_export_assignment(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsNamespaceExportDecl {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_namespace_export_decl(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.id(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_id(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_namespace_export_decl(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsAsExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_as_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_as_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsTypeAssertion {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_assertion(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_type_assertion(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsNonNullExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_non_null_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_non_null_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsSatisfiesExpr {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_satisfies_expr(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_ann(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_ann(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_satisfies_expr(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsConstAssertion {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_const_assertion(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_const_assertion(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TsInstantiation {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_instantiation(&mut node, ctx);
+        ctx.ancestors.push(ctx.ast, node.node_id());
+        ctx.ancestors.set_field(0u8);
+        let field_value = node.expr(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_expr(ctx.ast, new_node);
+        ctx.ancestors.set_field(1u8);
+        let field_value = node.type_args(ctx.ast);
+        let new_node = field_value.traverse_with(traverser, ctx);
+        node.set_type_args(ctx.ast, new_node);
+        ctx.ancestors.pop();
+        traverser.exit_ts_instantiation(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<ModuleItem> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_module_items(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_module_items(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<Stmt> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_stmts(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_stmts(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<ImportSpecifier> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_import_specifiers(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_import_specifiers(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<ObjectLit> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for Option<ModuleExportName> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<ExportSpecifier> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_export_specifiers(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_export_specifiers(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<Str> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for Option<Expr> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for Option<Ident> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for Option<Stmt> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<SwitchCase> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_switch_cases(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_switch_cases(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<CatchClause> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for Option<BlockStmt> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for Option<VarDeclOrExpr> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for Option<Pat> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<VarDeclarator> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_var_declarators(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_var_declarators(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<ExprOrSpread> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<Option<ExprOrSpread>> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_opt_vec_expr_or_spreads(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_opt_vec_expr_or_spreads(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<PropOrSpread> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_prop_or_spreads(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_prop_or_spreads(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<ExprOrSpread> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_expr_or_spreads(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_expr_or_spreads(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<TsTypeParamInstantiation> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for Option<TypedSubRange<ExprOrSpread>> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<Expr> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_exprs(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_exprs(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<Pat> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_pats(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_pats(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<TsTypeParamDecl> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for Option<TsTypeAnn> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<TplElement> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_tpl_elements(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_tpl_elements(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<SpreadDot3Token> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<Param> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_params(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_params(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<Decorator> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_decorators(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_decorators(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<ClassMember> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_class_members(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_class_members(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<TsExprWithTypeArgs> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_expr_with_type_argss(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_ts_expr_with_type_argss(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<ParamOrTsParamProp> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_param_or_ts_param_props(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_param_or_ts_param_props(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<Option<Pat>> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_opt_vec_pats(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_opt_vec_pats(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<ObjectPatProp> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_object_pat_props(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_object_pat_props(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<JSXAttrOrSpread> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_attr_or_spreads(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_jsx_attr_or_spreads(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<JSXAttrValue> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<JSXElementChild> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_jsx_element_childs(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_jsx_element_childs(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<JSXClosingElement> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<TsTypeParam> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_params(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_ts_type_params(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<TsType> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<TsType> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_types(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_ts_types(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<TsFnParam> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_fn_params(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_ts_fn_params(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<TsEntityName> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for Option<TsImportCallOptions> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<TsTypeElement> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_type_elements(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_ts_type_elements(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<TsTupleElement> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_tuple_elements(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_ts_tuple_elements(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for TypedSubRange<TsEnumMember> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        traverser.enter_ts_enum_members(&mut node, ctx);
+        for (index, child_idx) in node.iter().enumerate() {
+            ctx.ancestors.set_index(Some(index as u32));
+            let child = ctx.ast.get_node_in_sub_range(child_idx);
+            let new_child = child.traverse_with(traverser, ctx);
+            node.replace_slot(ctx.ast, child_idx, new_child);
+        }
+        ctx.ancestors.set_index(None);
+        traverser.exit_ts_enum_members(&mut node, ctx);
+        node
+    }
+}
+impl TraverseWith for Option<TsNamespaceBody> {
+    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+        let mut node = self;
+        node = node.map(|it| it.traverse_with(traverser, ctx));
+        node
+    }
+}
@@ -1 +1,6 @@
+mod generated {
+    pub(crate) mod traverse;
+}
+
 pub mod remove_paren;
+pub mod traverse;
@@ -4,7 +4,8 @@ use indexmap::IndexMap;
 use rustc_hash::FxHasher;
 use swc_core::common::comments::Comments;
 use swc_experimental_ecma_ast::{
-    Ast, Expr, GetSpan, SimpleAssignTarget, Span, VisitMut, VisitMutWith,
+    Ast, Expr, GetSpan, NodeIdTrait, Pat, SimpleAssignTarget, Span, Stmt, VisitMut, VisitMutWith,
+    maybe_grow,
 };
 
 pub fn remove_paren<'ast, N: VisitMutWith<ParenRemover<'ast>>>(
@@ -35,18 +36,40 @@ pub struct ParenRemover<'a> {
     span_map: IndexMap<Span, Span, BuildHasherDefault<FxHasher>>,
 }
 
+impl ParenRemover<'_> {
+    /// Moves the comments of the [Ast] attached to the paren expression to the
+    /// expression replacing it.
+    fn move_comments<P: NodeIdTrait + Copy, I: NodeIdTrait + Copy>(&mut self, paren: P, inner: I) {
+        let leading = self.ast.take_leading_comments(paren);
+        let trailing = self.ast.take_trailing_comments(paren);
+        if leading.is_empty() && trailing.is_empty() {
+            return;
+        }
+
+        // The comments of the paren surround the ones of the inner expression.
+        let inner_leading = self.ast.take_leading_comments(inner);
+        for comment in leading.into_iter().chain(inner_leading) {
+            self.ast.add_leading_comment(inner, comment);
+        }
+        for comment in trailing {
+            self.ast.add_trailing_comment(inner, comment);
+        }
+    }
+}
+
 impl VisitMut for ParenRemover<'_> {
     fn ast(&mut self) -> &mut Ast {
         self.ast
     }
 
     fn visit_mut_expr(&mut self, node: Expr) -> Expr {
-        let node = node.visit_mut_children_with(self);
+        let node = maybe_grow(|| node.visit_mut_children_with(self));
         if let Expr::Paren(expr) = node {
             let paren_span = expr.span(self.ast);
             let inner_expr = expr.expr(self.ast);
             let expr_span = inner_expr.span(self.ast);
             self.span_map.insert(expr_span, paren_span);
+            self.move_comments(expr, inner_expr);
             return inner_expr;
         }
         node
@@ -60,8 +83,17 @@ impl VisitMut for ParenRemover<'_> {
             let expr_span = inner_expr.span(self.ast);
             let target = SimpleAssignTarget::try_from_expr(self.ast, inner_expr).unwrap();
             self.span_map.insert(expr_span, paren_expr);
+            self.move_comments(expr, target);
             return target;
         }
         node
     }
+
+    fn visit_mut_pat(&mut self, node: Pat) -> Pat {
+        maybe_grow(|| node.visit_mut_children_with(self))
+    }
+
+    fn visit_mut_stmt(&mut self, node: Stmt) -> Stmt {
+        maybe_grow(|| node.visit_mut_children_with(self))
+    }
 }
@@ -0,0 +1,138 @@
+//! A traversal for writing transforms, whose hooks may replace the nodes they
+//! enter or exit.
+//!
+//! Unlike [swc_experimental_ecma_ast::VisitMut], the [Traverse] doesn't own
+//! the [Ast]. The hooks receive a [TraverseCtx] with the [Ast], the
+//! ancestors of the node and the [Semantic] of the program instead.
+
+use swc_experimental_ecma_ast::{Ast, AstNodePath, AstNodePathBuilder, AstPathEntry};
+use swc_experimental_ecma_semantic::resolver::Semantic;
+
+pub use crate::generated::traverse::{Traverse, TraverseWith};
+
+/// The state shared by the hooks of a [Traverse].
+pub struct TraverseCtx<'a> {
+    pub ast: &'a mut Ast,
+    pub semantic: &'a Semantic,
+    pub(crate) ancestors: AstNodePathBuilder,
+}
+
+impl<'a> TraverseCtx<'a> {
+    pub fn new(ast: &'a mut Ast, semantic: &'a Semantic) -> Self {
+        Self {
+            ast,
+            semantic,
+            ancestors: AstNodePathBuilder::new(),
+        }
+    }
+
+    /// Returns the ancestors of the node, from the root of the traversal.
+    #[inline]
+    pub fn ancestors(&self) -> &AstNodePath {
+        self.ancestors.path()
+    }
+
+    #[inline]
+    pub fn parent(&self) -> Option<&AstPathEntry> {
+        self.ancestors.path().parent()
+    }
+}
+
+/// Traverses `root` with `traverser`, and returns the node replacing it.
+///
+/// The nodes created by the hooks have no scope in `semantic`.
+pub fn traverse_mut<N: TraverseWith, T: ?Sized + Traverse>(
+    traverser: &mut T,
+    root: N,
+    ast: &mut Ast,
+    semantic: &Semantic,
+) -> N {
+    let mut ctx = TraverseCtx::new(ast, semantic);
+    root.traverse_with(traverser, &mut ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use swc_core::common::DUMMY_SP;
+    use swc_experimental_ecma_ast::{
+        BinaryOp, Expr, GetSpan, Ident, Lit, Program, Stmt, StringAllocator,
+    };
+    use swc_experimental_ecma_semantic::resolver::resolver;
+
+    use super::*;
+
+    /// Folds the additions of numbers, and records the identifiers.
+    #[derive(Default)]
+    struct Folder {
+        /// The name of the identifiers, whether they're unresolved, and the
+        /// field of their parent.
+        idents: Vec<(String, bool, &'static str)>,
+    }
+
+    impl Traverse for Folder {
+        fn enter_ident(&mut self, node: &mut Ident, ctx: &mut TraverseCtx) {
+            let unresolved = ctx.semantic.node_scope(*node) == ctx.semantic.unresolved_scope_id();
+            let field = ctx.parent().map_or("", |parent| parent.field_name());
+            let name = ctx.ast.get_utf8(node.sym(ctx.ast)).to_string();
+            self.idents.push((name, unresolved, field));
+        }
+
+        fn exit_expr(&mut self, node: &mut Expr, ctx: &mut TraverseCtx) {
+            let Expr::Bin(bin) = *node else {
+                return;
+            };
+            if bin.op(ctx.ast) != BinaryOp::Add {
+                return;
+            }
+            let (Expr::Lit(Lit::Num(left)), Expr::Lit(Lit::Num(right))) =
+                (bin.left(ctx.ast), bin.right(ctx.ast))
+            else {
+                return;
+            };
+            let value = left.value(ctx.ast) + right.value(ctx.ast);
+            let span = bin.span(ctx.ast);
+            let raw = ctx.ast.add_optional_utf8(None);
+            *node = ctx.ast.expr_lit_number(span, value, raw);
+        }
+    }
+
+    #[test]
+    fn test_traverse_mut() {
+        // `x * (1 + 2);`
+        let mut ast = Ast::new(0, Rc::new(StringAllocator::default()));
+        let x = ast.add_utf8("x");
+        let x = ast.expr_ident(DUMMY_SP, x, false);
+        let numbers = [1.0, 2.0].map(|value| {
+            let raw = ast.add_optional_utf8(None);
+            ast.expr_lit_number(DUMMY_SP, value, raw)
+        });
+        let sum = ast.expr_bin_expr(DUMMY_SP, BinaryOp::Add, numbers[0], numbers[1]);
+        let product = ast.expr_bin_expr(DUMMY_SP, BinaryOp::Mul, x, sum);
+        let stmt = ast.stmt_expr_stmt(DUMMY_SP, product);
+        let body = ast.add_typed_sub_range([stmt]);
+        let shebang = ast.add_optional_utf8(None);
+        let root = ast.program_script(DUMMY_SP, body, shebang);
+        let semantic = resolver(root, &ast);
+
+        let mut folder = Folder::default();
+        let new_root = traverse_mut(&mut folder, root, &mut ast, &semantic);
+        assert_eq!(new_root, root);
+        assert_eq!(folder.idents, [("x".to_string(), true, "left")]);
+
+        let Program::Script(script) = root else {
+            unreachable!()
+        };
+        let Some(Stmt::Expr(stmt)) = script.body(&ast).get_node(&ast, 0) else {
+            unreachable!()
+        };
+        let Expr::Bin(product) = stmt.expr(&ast) else {
+            unreachable!()
+        };
+        let Expr::Lit(Lit::Num(sum)) = product.right(&ast) else {
+            panic!("`1 + 2` isn't folded")
+        };
+        assert_eq!(sum.value(&ast), 3.0);
+    }
+}
@@ -287,6 +287,13 @@ impl ExprExt for Expr {
             | Expr::JSXElement(..)
             | Expr::JSXFragment(..) => true,
 
+            Expr::TsAs(e) => e.expr(ctx.ast).may_have_side_effects(ctx),
+            Expr::TsSatisfies(e) => e.expr(ctx.ast).may_have_side_effects(ctx),
+            Expr::TsNonNull(e) => e.expr(ctx.ast).may_have_side_effects(ctx),
+            Expr::TsTypeAssertion(e) => e.expr(ctx.ast).may_have_side_effects(ctx),
+            Expr::TsConstAssertion(e) => e.expr(ctx.ast).may_have_side_effects(ctx),
+            Expr::TsInstantiation(e) => e.expr(ctx.ast).may_have_side_effects(ctx),
+
             Expr::Invalid(..) => true,
         }
     }
@@ -1 +1,5 @@
-pub use swc_experimental_ecma_ast::{Visit, VisitMut, VisitMutWith, VisitWith};
+pub use swc_experimental_ecma_ast::{
+    AstNodePath, AstPathEntry, HooksVisitor, Visit, VisitControl, VisitControlWith, VisitFlow,
+    VisitHooks, VisitMut, VisitMutHooks, VisitMutWith, VisitWith, VisitWithPath, VisitWithPathWith,
+    Walk, WalkEvent,
+};
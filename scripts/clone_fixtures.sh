@@ -25,3 +25,4 @@ clone_repo() {
 
 clone_repo "tc39/test262" "tasks/testsuite/fixtures/test262" "079b13f85da120049919365612163e4579678e89" "test262"
 clone_repo "tc39/test262-parser-tests" "tasks/testsuite/fixtures/test262-parser-tests" "0e808c74fbec780646434cad17bb22dc52461003" "test262-parser"
+clone_repo "swc-project/swc" "tasks/testsuite/fixtures/swc" "41b770d8450a22a2c85a39a474f7865436dad114" "swc"
@@ -0,0 +1,52 @@
+// Generates the reference ESTree of the test262-parser-tests fixtures with
+// acorn, for the `estree` runner of the testsuite.
+//
+// Usage: npm ci && node scripts/generate_estree_fixtures.mjs
+//
+// acorn is pinned by package-lock.json, so that the references only change
+// with an update of the lockfile.
+
+import { mkdirSync, readdirSync, readFileSync, writeFileSync } from "node:fs";
+import { dirname, join } from "node:path";
+import { fileURLToPath } from "node:url";
+import { parse } from "acorn";
+
+const fixtures = join(dirname(fileURLToPath(import.meta.url)), "../tasks/testsuite/fixtures");
+
+// JSON has no representation for these values, their literals have `regex` and
+// `bigint` fields instead.
+const replacer = (_key, value) =>
+  typeof value === "bigint" || value instanceof RegExp ? null : value;
+
+let written = 0;
+let rejected = 0;
+for (const dir of ["pass", "pass-explicit"]) {
+  const input = join(fixtures, "test262-parser-tests", dir);
+  const output = join(fixtures, "estree", "test262-parser-tests", dir);
+  mkdirSync(output, { recursive: true });
+
+  for (const name of readdirSync(input)) {
+    if (!name.endsWith(".js")) continue;
+    const code = readFileSync(join(input, name), "utf8");
+    const reference = join(output, name.replace(/\.js$/, ".json"));
+    let ast;
+    try {
+      ast = parse(code, {
+        ecmaVersion: "latest",
+        sourceType: name.endsWith(".module.js") ? "module" : "script",
+        locations: true,
+        ranges: true,
+      });
+    } catch {
+      // Cases acorn rejects have a `null` reference, so that they can be told
+      // apart from the missing ones.
+      writeFileSync(reference, "null");
+      rejected++;
+      continue;
+    }
+    writeFileSync(reference, JSON.stringify(ast, replacer));
+    written++;
+  }
+}
+
+console.log(`Generated ${written} ESTree fixtures, acorn rejected ${rejected} cases`);
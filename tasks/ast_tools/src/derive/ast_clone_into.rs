@@ -0,0 +1,89 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    AST_CRATE_PATH,
+    output::{RawOutput, RustOutput, output_path},
+    schema::{AstEnum, AstStruct, AstType, Schema},
+    util::safe_ident,
+};
+
+pub fn ast_clone_into(schema: &Schema) -> RawOutput {
+    let mut impls = TokenStream::new();
+    for ty in schema.types.iter() {
+        match ty {
+            AstType::Struct(ast_struct) => impls.extend(generate_clone_into_for_struct(ast_struct)),
+            AstType::Enum(ast_enum) => impls.extend(generate_clone_into_for_enum(ast_enum)),
+            _ => continue,
+        };
+    }
+
+    let output = quote! {
+        #![allow(unused)]
+        use crate::{Ast, CloneInto, GetSpan};
+        use crate::{ast::*, node_id::*};
+
+        #impls
+    };
+
+    RustOutput {
+        path: output_path(AST_CRATE_PATH, "ast_clone_into"),
+        tokens: output,
+    }
+    .into()
+}
+
+fn generate_clone_into_for_struct(ast: &AstStruct) -> TokenStream {
+    let name = format_ident!("{}", ast.name);
+
+    let mut fields_clone_into = TokenStream::new();
+    let mut fields_params = Vec::new();
+    fields_clone_into.extend(quote! { let span = self.span(from); });
+    fields_params.push(quote!(span));
+    for field in ast.fields.iter() {
+        let field_ident = safe_ident(&field.name.to_case(Case::Snake));
+        fields_clone_into.extend(quote! {
+            let #field_ident = self.#field_ident(from).clone_into_ast(from, to);
+        });
+        fields_params.push(quote!(#field_ident));
+    }
+
+    let fn_name = safe_ident(&ast.name.to_case(Case::Snake));
+    quote! {
+        impl CloneInto for #name {
+            type Cloned = #name;
+
+            #[inline]
+            fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+                #fields_clone_into
+                to.#fn_name( #(#fields_params),* )
+            }
+        }
+    }
+}
+
+fn generate_clone_into_for_enum(ast: &AstEnum) -> TokenStream {
+    let name = format_ident!("{}", ast.name);
+
+    let mut arms = TokenStream::new();
+    for variant in ast.variants.iter() {
+        let variant_ident = format_ident!("{}", variant.name);
+        arms.extend(quote! {
+            Self::#variant_ident(it) => Self::#variant_ident(it.clone_into_ast(from, to)),
+        });
+    }
+
+    quote! {
+        impl CloneInto for #name {
+            type Cloned = #name;
+
+            #[inline]
+            fn clone_into_ast(&self, from: &Ast, to: &mut Ast) -> Self::Cloned {
+                match self {
+                    #arms
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    AST_CRATE_PATH,
+    output::{RawOutput, RustOutput, output_path},
+    schema::{AstEnum, AstStruct, AstType, Schema},
+    util::safe_ident,
+};
+
+pub fn ast_dump(schema: &Schema) -> RawOutput {
+    let mut impls = TokenStream::new();
+    for ty in schema.types.iter() {
+        match ty {
+            AstType::Struct(ast_struct) => impls.extend(generate_dump_for_struct(ast_struct)),
+            AstType::Enum(ast_enum) => impls.extend(generate_dump_for_enum(ast_enum)),
+            _ => continue,
+        };
+    }
+
+    let output = quote! {
+        #![allow(unused)]
+        use crate::{Ast, Dump, Dumper, GetSpan};
+        use crate::{ast::*, node_id::*};
+
+        #impls
+    };
+
+    RustOutput {
+        path: output_path(AST_CRATE_PATH, "ast_dump"),
+        tokens: output,
+    }
+    .into()
+}
+
+fn generate_dump_for_struct(ast: &AstStruct) -> TokenStream {
+    let name = format_ident!("{}", ast.name);
+    let name_str = &ast.name;
+
+    let mut fields_dump = TokenStream::new();
+    for field in ast.fields.iter() {
+        let field_name = field.name.to_case(Case::Snake);
+        let field_ident = safe_ident(&field_name);
+        fields_dump.extend(quote! {
+            d.field(#field_name, &self.#field_ident(ast));
+        });
+    }
+
+    quote! {
+        impl Dump for #name {
+            fn dump(&self, d: &mut Dumper) {
+                let ast = d.ast();
+                d.node(#name_str, self.span(ast), |d| {
+                    #fields_dump
+                });
+            }
+        }
+    }
+}
+
+fn generate_dump_for_enum(ast: &AstEnum) -> TokenStream {
+    let name = format_ident!("{}", ast.name);
+
+    let mut arms = TokenStream::new();
+    for variant in ast.variants.iter() {
+        let variant_ident = format_ident!("{}", variant.name);
+        arms.extend(quote! {
+            Self::#variant_ident(it) => it.dump(d),
+        });
+    }
+
+    quote! {
+        impl Dump for #name {
+            #[inline]
+            fn dump(&self, d: &mut Dumper) {
+                match self {
+                    #arms
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    AST_CRATE_PATH,
+    output::{RawOutput, RustOutput, output_path},
+    schema::{AstEnum, AstStruct, AstType, Schema},
+    util::safe_ident,
+};
+
+pub fn ast_eq_ignore_span(schema: &Schema) -> RawOutput {
+    let mut impls = TokenStream::new();
+    for ty in schema.types.iter() {
+        match ty {
+            AstType::Struct(ast_struct) => {
+                impls.extend(generate_eq_ignore_span_for_struct(ast_struct))
+            }
+            AstType::Enum(ast_enum) => impls.extend(generate_eq_ignore_span_for_enum(ast_enum)),
+            _ => continue,
+        };
+    }
+
+    let output = quote! {
+        #![allow(unused)]
+        use std::hash::{Hash, Hasher};
+        use crate::{Ast, EqIgnoreSpan, HashIgnoreSpan};
+        use crate::{ast::*, node_id::*};
+
+        #impls
+    };
+
+    RustOutput {
+        path: output_path(AST_CRATE_PATH, "ast_eq_ignore_span"),
+        tokens: output,
+    }
+    .into()
+}
+
+fn generate_eq_ignore_span_for_struct(ast: &AstStruct) -> TokenStream {
+    let name = format_ident!("{}", ast.name);
+
+    let mut fields_eq = Vec::new();
+    let mut fields_hash = TokenStream::new();
+    for field in ast.fields.iter() {
+        let field_ident = safe_ident(&field.name.to_case(Case::Snake));
+        fields_eq.push(quote! {
+            self.#field_ident(ast).eq_ignore_span(ast, &other.#field_ident(other_ast), other_ast)
+        });
+        fields_hash.extend(quote! {
+            self.#field_ident(ast).hash_ignore_span(ast, state);
+        });
+    }
+    if fields_eq.is_empty() {
+        fields_eq.push(quote!(true));
+    }
+
+    quote! {
+        impl EqIgnoreSpan for #name {
+            fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+                #(#fields_eq)&&*
+            }
+        }
+
+        impl HashIgnoreSpan for #name {
+            fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+                #fields_hash
+            }
+        }
+    }
+}
+
+fn generate_eq_ignore_span_for_enum(ast: &AstEnum) -> TokenStream {
+    let name = format_ident!("{}", ast.name);
+
+    let mut eq_arms = TokenStream::new();
+    let mut hash_arms = TokenStream::new();
+    for variant in ast.variants.iter() {
+        let variant_ident = format_ident!("{}", variant.name);
+        eq_arms.extend(quote! {
+            (Self::#variant_ident(a), Self::#variant_ident(b)) => a.eq_ignore_span(ast, b, other_ast),
+        });
+        hash_arms.extend(quote! {
+            Self::#variant_ident(it) => it.hash_ignore_span(ast, state),
+        });
+    }
+
+    quote! {
+        impl EqIgnoreSpan for #name {
+            fn eq_ignore_span(&self, ast: &Ast, other: &Self, other_ast: &Ast) -> bool {
+                match (self, other) {
+                    #eq_arms
+                    _ => false,
+                }
+            }
+        }
+
+        impl HashIgnoreSpan for #name {
+            fn hash_ignore_span<H: Hasher>(&self, ast: &Ast, state: &mut H) {
+                std::mem::discriminant(self).hash(state);
+                match self {
+                    #hash_arms
+                }
+            }
+        }
+    }
+}
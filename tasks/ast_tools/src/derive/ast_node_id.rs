@@ -46,7 +46,7 @@ fn generate_node_id_for_struct(ast: &AstStruct, _schema: &Schema) -> TokenStream
 
             #[inline]
             fn from_node_id(node_id: NodeId, ast: &Ast) -> Self {
-                assert!(ast.nodes[node_id].kind == NodeKind::#name);
+                assert!(ast.nodes.kind(node_id) == NodeKind::#name);
                 Self(node_id)
             }
 
@@ -54,6 +54,15 @@ fn generate_node_id_for_struct(ast: &AstStruct, _schema: &Schema) -> TokenStream
             unsafe fn from_node_id_unchecked(node_id: NodeId, _ast: &Ast) -> Self {
                 Self(node_id)
             }
+
+            #[inline]
+            fn is_kind(kind: NodeKind) -> bool {
+                kind == NodeKind::#name
+            }
+        }
+
+        impl StructNode for #name {
+            const KIND: NodeKind = NodeKind::#name;
         }
     }
 }
@@ -70,8 +79,10 @@ fn generate_node_id_for_enum(ast: &AstEnum, schema: &Schema) -> TokenStream {
     }
 
     let mut from_node_id_arms = TokenStream::new();
+    let mut kinds = Vec::new();
     for (_, element) in flat_enum_type(ast, schema) {
         let kind = element.kind;
+        kinds.push(kind.clone());
         let body = element.path.iter().rev().fold(
             quote!({ #kind::from_node_id(id, ast) }),
             |acc, construcotr| quote!(#construcotr(#acc)),
@@ -92,7 +103,7 @@ fn generate_node_id_for_enum(ast: &AstEnum, schema: &Schema) -> TokenStream {
 
             #[inline]
             fn from_node_id(id: NodeId, ast: &Ast) -> Self {
-                match &ast.nodes[id].kind {
+                match ast.nodes.kind(id) {
                     #from_node_id_arms
                     _ => unreachable!(),
                 }
@@ -102,6 +113,11 @@ fn generate_node_id_for_enum(ast: &AstEnum, schema: &Schema) -> TokenStream {
             unsafe fn from_node_id_unchecked(id: NodeId, ast: &Ast) -> Self {
                 Self::from_node_id(id, ast)
             }
+
+            #[inline]
+            fn is_kind(kind: NodeKind) -> bool {
+                matches!(kind, #(NodeKind::#kinds)|*)
+            }
         }
     }
 }
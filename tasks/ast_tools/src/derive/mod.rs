@@ -1,2 +1,5 @@
 pub(crate) mod ast_clone_in;
+pub(crate) mod ast_clone_into;
+pub(crate) mod ast_dump;
+pub(crate) mod ast_eq_ignore_span;
 pub(crate) mod ast_node_id;
@@ -35,7 +35,7 @@ pub fn ast_builder(schema: &Schema) -> RawOutput {
     }
 
     let output = quote! {
-            #![allow(unused, clippy::useless_conversion, clippy::identity_op)]
+            #![allow(unused, clippy::useless_conversion, clippy::identity_op, clippy::too_many_arguments)]
             use swc_core::common::Span;
             use crate::*;
 
@@ -89,8 +89,8 @@ pub fn ast_extra_compact(schema: &Schema) -> RawOutput {
             }
             AstType::Option(ast_option) => {
                 let inner_type = &schema.types[ast_option.inner_type_id];
-                // Option<TypedSubRange<T>> is implemented manually
-                if matches!(inner_type, AstType::Vec(_)) {
+                // Option<TypedSubRange<T>> and Option<Primitive> are implemented manually
+                if matches!(inner_type, AstType::Vec(_) | AstType::Primitive(_)) {
                     continue;
                 }
 
@@ -0,0 +1,200 @@
+use std::collections::BTreeSet;
+
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    AST_CRATE_PATH,
+    output::{RawOutput, RustOutput, output_path},
+    schema::{AstStruct, AstType, Schema},
+    util::{calculate_inline_layout, flat_enum_type},
+};
+
+pub fn ast_schema(schema: &Schema) -> RawOutput {
+    let mut types = TokenStream::new();
+    let mut node_schemas = TokenStream::new();
+    let mut kind_arms = TokenStream::new();
+
+    let optional_inner_types = schema
+        .types
+        .iter()
+        .filter_map(|ty| match ty {
+            AstType::Option(ast_option) => Some(ast_option.inner_type_id),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>();
+
+    let mut struct_index = 0usize;
+    for ty in schema.types.iter() {
+        let (kinds, name, type_id) = match ty {
+            AstType::Struct(ast_struct) => {
+                let kind = format_ident!("{}", ast_struct.name);
+                node_schemas.extend(generate_node_schema(ast_struct, schema));
+                kind_arms.extend(quote!( NodeKind::#kind => #struct_index, ));
+                struct_index += 1;
+                (vec![kind], &ast_struct.name, ast_struct.type_id)
+            }
+            AstType::Enum(ast_enum) => {
+                let kinds = flat_enum_type(ast_enum, schema)
+                    .into_values()
+                    .map(|element| element.kind)
+                    .collect();
+                (kinds, &ast_enum.name, ast_enum.type_id)
+            }
+            _ => continue,
+        };
+
+        let ident = format_ident!("{}", name);
+        let const_name = type_const_name(name);
+        let optional_codec = if optional_inner_types.contains(&type_id) {
+            quote!(Some(NodeCodec::new_optional::<#ident>()))
+        } else {
+            quote!(None)
+        };
+        types.extend(quote! {
+            const #const_name: NodeType = NodeType {
+                name: #name,
+                kinds: &[#(NodeKind::#kinds),*],
+                codec: NodeCodec::new::<#ident>(),
+                optional_codec: #optional_codec,
+            };
+        });
+    }
+
+    let mut repr_enums = schema.repr_variants.iter().collect::<Vec<_>>();
+    repr_enums.sort();
+    for (name, variants) in repr_enums {
+        let const_name = type_const_name(name);
+        types.extend(quote! {
+            const #const_name: EnumType = EnumType {
+                name: #name,
+                variants: &[#(#variants),*],
+            };
+        });
+    }
+
+    let output = quote! {
+        #![allow(dead_code, unreachable_patterns)]
+        use crate::{*, schema::*};
+
+        #types
+
+        pub(crate) static NODE_SCHEMAS: &[NodeSchema] = &[#node_schemas];
+
+        impl NodeKind {
+            /// Returns the index of the schema of the kind in [NODE_SCHEMAS].
+            pub(crate) fn schema_index(self) -> usize {
+                match self {
+                    #kind_arms
+                    // Kinds that no struct is declared for
+                    _ => unreachable!("{self:?} has no schema"),
+                }
+            }
+        }
+    };
+
+    RustOutput {
+        path: output_path(AST_CRATE_PATH, "ast_schema"),
+        tokens: output,
+    }
+    .into()
+}
+
+fn generate_node_schema(ast: &AstStruct, schema: &Schema) -> TokenStream {
+    let kind = format_ident!("{}", ast.name);
+    let name = &ast.name;
+
+    // Mirrors the layout used by the generated getters, see `ast_property`.
+    let layout = calculate_inline_layout(ast, schema);
+    let mut extra_offset = 0u32;
+    let mut fields = TokenStream::new();
+    for (field_idx, field) in ast.fields.iter().enumerate() {
+        let inline = layout.as_ref().and_then(|layout| {
+            layout
+                .fields
+                .iter()
+                .find(|(idx, _, _)| *idx == field_idx)
+                .map(|&(_, offset, size)| (offset as u8, size as u8))
+        });
+        let location = match inline {
+            Some((offset, size)) => quote!(FieldLocation::Inline {
+                offset: #offset,
+                size: #size
+            }),
+            None => {
+                let offset = extra_offset;
+                extra_offset += 1;
+                quote!(FieldLocation::ExtraData(#offset))
+            }
+        };
+
+        let field_name = &field.name;
+        let ty = field_type(&schema.types[field.type_id], schema);
+        fields.extend(quote! {
+            FieldSchema {
+                name: #field_name,
+                ty: #ty,
+                location: #location,
+            },
+        });
+    }
+
+    quote! {
+        NodeSchema {
+            kind: NodeKind::#kind,
+            name: #name,
+            fields: &[#fields],
+        },
+    }
+}
+
+fn field_type(ty: &AstType, schema: &Schema) -> TokenStream {
+    match ty {
+        AstType::Struct(_) | AstType::Enum(_) => {
+            let const_name = type_const_name(ty.name());
+            quote!(FieldType::Node(&#const_name))
+        }
+        AstType::Vec(ast_vec) => {
+            let inner = field_type(&schema.types[ast_vec.inner_type_id], schema);
+            quote!(FieldType::List(&#inner))
+        }
+        AstType::Option(ast_option) => {
+            let inner_ty = &schema.types[ast_option.inner_type_id];
+            match inner_ty {
+                AstType::Struct(_) | AstType::Enum(_) => {
+                    let const_name = type_const_name(inner_ty.name());
+                    quote!(FieldType::OptionalNode(&#const_name))
+                }
+                AstType::Vec(ast_vec) => {
+                    let inner = field_type(&schema.types[ast_vec.inner_type_id], schema);
+                    quote!(FieldType::OptionalList(&#inner))
+                }
+                AstType::Primitive(prim) if schema.repr_variants.contains_key(prim.name) => {
+                    let const_name = type_const_name(prim.name);
+                    quote!(FieldType::OptionalEnum(&#const_name))
+                }
+                _ => panic!("Unsupported optional type {}", ast_option.name),
+            }
+        }
+        AstType::Primitive(prim) => match prim.name {
+            "Span" => quote!(FieldType::Span),
+            "bool" => quote!(FieldType::Bool),
+            "f64" => quote!(FieldType::Number),
+            "Utf8Ref" => quote!(FieldType::Utf8),
+            "OptionalUtf8Ref" => quote!(FieldType::OptionalUtf8),
+            "Wtf8Ref" => quote!(FieldType::Wtf8),
+            "OptionalWtf8Ref" => quote!(FieldType::OptionalWtf8),
+            "BigIntId" => quote!(FieldType::BigInt),
+            name if schema.repr_variants.contains_key(name) => {
+                let const_name = type_const_name(name);
+                quote!(FieldType::Enum(&#const_name))
+            }
+            name => panic!("Unsupported primitive {name}"),
+        },
+    }
+}
+
+fn type_const_name(name: &str) -> syn::Ident {
+    format_ident!("{}", name.to_case(Case::UpperSnake))
+}
@@ -0,0 +1,166 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    AST_CRATE_PATH,
+    output::{RawOutput, RustOutput, output_path},
+    schema::{AstType, Schema},
+    util::safe_ident,
+};
+
+/// Generates `VisitControl`, a variant of `Visit` whose methods return
+/// [std::ops::ControlFlow], so that a traversal can be stopped early.
+pub fn ast_visitor_control(schema: &Schema) -> RawOutput {
+    let mut visit_functions = TokenStream::new();
+    let mut visit_with_impls = TokenStream::new();
+
+    visit_functions.extend(quote! {
+        type Break;
+
+        fn ast(&self) -> &Ast;
+
+        #[inline]
+        fn enter_node(&mut self, node_id: NodeId) -> VisitFlow<Self::Break> {
+            VisitFlow::Continue
+        }
+        #[inline]
+        fn leave_node(&mut self, node_id: NodeId) -> ControlFlow<Self::Break> {
+            ControlFlow::Continue(())
+        }
+    });
+
+    for ty in schema.types.iter() {
+        let (fn_name, visit_children) = match ty {
+            AstType::Struct(ast) => {
+                let fn_name = format_ident!("visit_{}", &ast.name.to_case(Case::Snake));
+
+                let mut visit_children = TokenStream::new();
+                for field in ast.fields.iter() {
+                    let field_ty = &schema.types[field.type_id];
+                    let field_ty_ident = field_ty.repr_ident(schema);
+                    let getter_name = safe_ident(&field.name.to_case(Case::Snake));
+                    visit_children.extend(quote! {
+                        let field_value = self.#getter_name(visitor.ast());
+                        <#field_ty_ident as VisitControlWith<V>>::visit_with_control(field_value, visitor)?;
+                    });
+                }
+                (fn_name, quote!( #visit_children ControlFlow::Continue(()) ))
+            }
+            AstType::Enum(ast) => {
+                let fn_name = format_ident!("visit_{}", &ast.name.to_case(Case::Snake));
+
+                let mut arms = TokenStream::new();
+                for variant in ast.variants.iter() {
+                    let Some(variant_type_id) = variant.type_id else {
+                        continue;
+                    };
+                    let variant_ty_ident = schema.types[variant_type_id].repr_ident(schema);
+                    let variant_name = format_ident!("{}", variant.name);
+                    arms.extend(quote! {
+                        Self::#variant_name(it) => <#variant_ty_ident as VisitControlWith<V>>::visit_with_control(it, visitor),
+                    });
+                }
+                (fn_name, quote!( match self { #arms } ))
+            }
+            AstType::Option(ast) => {
+                let inner_type = &schema.types[ast.inner_type_id];
+                let fn_name = match inner_type {
+                    AstType::Vec(vec) => {
+                        let inner_type = &schema.types[vec.inner_type_id];
+                        format_ident!("visit_opt_{}s", &inner_type.name().to_case(Case::Snake))
+                    }
+                    _ => format_ident!("visit_opt_{}", &inner_type.name().to_case(Case::Snake)),
+                };
+                let visit_children = quote! {
+                    match self {
+                        Some(it) => it.visit_with_control(visitor),
+                        None => ControlFlow::Continue(()),
+                    }
+                };
+                (fn_name, visit_children)
+            }
+            AstType::Vec(ast) => {
+                let inner_type = &schema.types[ast.inner_type_id];
+                let fn_name = match inner_type {
+                    AstType::Option(opt) => {
+                        let inner_type = &schema.types[opt.inner_type_id];
+                        format_ident!("visit_opt_vec_{}s", &inner_type.name().to_case(Case::Snake))
+                    }
+                    _ => format_ident!("visit_{}s", &inner_type.name().to_case(Case::Snake)),
+                };
+                let visit_children = quote! {
+                    for child_idx in self.iter() {
+                        let child = visitor.ast().get_node_in_sub_range(child_idx);
+                        child.visit_with_control(visitor)?;
+                    }
+                    ControlFlow::Continue(())
+                };
+                (fn_name, visit_children)
+            }
+            _ => continue,
+        };
+        let ty_ident = ty.repr_ident(schema);
+
+        // Only the nodes declared as structs are entered, like `Visit`.
+        let visit_function = match ty {
+            AstType::Struct(_) => quote! {
+                #[inline]
+                fn #fn_name(&mut self, node: #ty_ident) -> ControlFlow<Self::Break> {
+                    match self.enter_node(node.node_id()) {
+                        VisitFlow::Continue => {
+                            <#ty_ident as VisitControlWith<Self>>::visit_children_with_control(node, self)?
+                        }
+                        VisitFlow::SkipChildren => {}
+                        VisitFlow::Break(value) => return ControlFlow::Break(value),
+                    }
+                    self.leave_node(node.node_id())
+                }
+            },
+            _ => quote! {
+                #[inline]
+                fn #fn_name(&mut self, node: #ty_ident) -> ControlFlow<Self::Break> {
+                    <#ty_ident as VisitControlWith<Self>>::visit_children_with_control(node, self)
+                }
+            },
+        };
+        visit_functions.extend(visit_function);
+
+        visit_with_impls.extend(quote! {
+            impl<V: ?Sized + VisitControl> VisitControlWith<V> for #ty_ident {
+                fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+                    <V as VisitControl>::#fn_name(visitor, self)
+                }
+
+                fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break> {
+                    #visit_children
+                }
+            }
+        });
+    }
+
+    let output = quote! {
+            #![allow(unused, clippy::useless_conversion, clippy::single_match)]
+            use std::ops::ControlFlow;
+
+            use crate::*;
+            use swc_core::common::Span;
+
+            pub trait VisitControl {
+                #visit_functions
+            }
+
+            pub trait VisitControlWith<V: ?Sized + VisitControl> {
+                fn visit_with_control(self, visitor: &mut V) -> ControlFlow<V::Break>;
+                fn visit_children_with_control(self, visitor: &mut V) -> ControlFlow<V::Break>;
+            }
+
+            #visit_with_impls
+    };
+
+    RustOutput {
+        path: output_path(AST_CRATE_PATH, "ast_visitor_control"),
+        tokens: output,
+    }
+    .into()
+}
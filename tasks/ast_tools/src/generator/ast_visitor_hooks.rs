@@ -0,0 +1,233 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    AST_CRATE_PATH,
+    output::{RawOutput, RustOutput, output_path},
+    schema::{AstType, Schema},
+};
+
+/// The largest tuple of visitors implementing `VisitHooks`.
+const MAX_TUPLE_LEN: usize = 8;
+
+/// Generates `VisitHooks`, whose methods are called on entering and leaving
+/// the nodes of one traversal, so that several visitors can share it, and
+/// `VisitMutHooks`, their counterpart with the [Ast] to edit.
+pub fn ast_visitor_hooks(schema: &Schema) -> RawOutput {
+    let mut hook_functions = TokenStream::new();
+    let mut ref_mut_functions = TokenStream::new();
+    let mut tuple_functions = TokenStream::new();
+    let mut visit_functions = TokenStream::new();
+    let mut enter_arms = TokenStream::new();
+    let mut leave_arms = TokenStream::new();
+    let mut mut_hook_functions = TokenStream::new();
+    let mut mut_ref_mut_functions = TokenStream::new();
+    let mut enter_mut_arms = TokenStream::new();
+    let mut leave_mut_arms = TokenStream::new();
+
+    hook_functions.extend(quote! {
+        #[inline]
+        fn enter_node(&mut self, node_id: NodeId, ast: &Ast) {}
+        #[inline]
+        fn leave_node(&mut self, node_id: NodeId, ast: &Ast) {}
+    });
+    mut_hook_functions.extend(quote! {
+        #[inline]
+        fn enter_node(&mut self, node_id: NodeId, ast: &mut Ast) {}
+        #[inline]
+        fn leave_node(&mut self, node_id: NodeId, ast: &mut Ast) {}
+    });
+    for (hook, ty) in [
+        (format_ident!("enter_node"), quote!(NodeId)),
+        (format_ident!("leave_node"), quote!(NodeId)),
+    ] {
+        ref_mut_functions.extend(quote! {
+            #[inline]
+            fn #hook(&mut self, node: #ty, ast: &Ast) {
+                (**self).#hook(node, ast)
+            }
+        });
+        tuple_functions.extend(quote! {
+            #[inline]
+            fn #hook(&mut self, node: #ty, ast: &Ast) {
+                $(self.$idx.#hook(node, ast);)+
+            }
+        });
+        mut_ref_mut_functions.extend(quote! {
+            #[inline]
+            fn #hook(&mut self, node: #ty, ast: &mut Ast) {
+                (**self).#hook(node, ast)
+            }
+        });
+    }
+
+    for ty in schema.types.iter() {
+        let AstType::Struct(ast) = ty else {
+            continue;
+        };
+        let name = ast.name.to_case(Case::Snake);
+        let enter = format_ident!("enter_{}", name);
+        let leave = format_ident!("leave_{}", name);
+        let fn_name = format_ident!("visit_{}", name);
+        let ty_ident = ty.repr_ident(schema);
+
+        for hook in [&enter, &leave] {
+            hook_functions.extend(quote! {
+                #[inline]
+                fn #hook(&mut self, node: #ty_ident, ast: &Ast) {}
+            });
+            ref_mut_functions.extend(quote! {
+                #[inline]
+                fn #hook(&mut self, node: #ty_ident, ast: &Ast) {
+                    (**self).#hook(node, ast)
+                }
+            });
+            tuple_functions.extend(quote! {
+                #[inline]
+                fn #hook(&mut self, node: #ty_ident, ast: &Ast) {
+                    $(self.$idx.#hook(node, ast);)+
+                }
+            });
+            mut_hook_functions.extend(quote! {
+                #[inline]
+                fn #hook(&mut self, node: #ty_ident, ast: &mut Ast) {}
+            });
+            mut_ref_mut_functions.extend(quote! {
+                #[inline]
+                fn #hook(&mut self, node: #ty_ident, ast: &mut Ast) {
+                    (**self).#hook(node, ast)
+                }
+            });
+        }
+
+        let node_kind = format_ident!("{}", ast.name);
+        for (arms, hook) in [(&mut enter_arms, &enter), (&mut leave_arms, &leave)] {
+            arms.extend(quote! {
+                NodeKind::#node_kind => {
+                    hooks.#hook(unsafe { #ty_ident::from_node_id_unchecked(node_id, ast) }, ast)
+                }
+            });
+        }
+        for (arms, hook) in [(&mut enter_mut_arms, &enter), (&mut leave_mut_arms, &leave)] {
+            arms.extend(quote! {
+                NodeKind::#node_kind => {
+                    let node = unsafe { #ty_ident::from_node_id_unchecked(node_id, ast) };
+                    hooks.#hook(node, ast)
+                }
+            });
+        }
+
+        visit_functions.extend(quote! {
+            #[inline]
+            fn #fn_name(&mut self, node: #ty_ident) {
+                self.hooks.enter_node(node.node_id(), self.ast);
+                self.hooks.#enter(node, self.ast);
+                <#ty_ident as VisitWith<Self>>::visit_children_with(node, self);
+                self.hooks.#leave(node, self.ast);
+                self.hooks.leave_node(node.node_id(), self.ast);
+            }
+        });
+    }
+
+    let mut tuple_impls = TokenStream::new();
+    for len in 2..=MAX_TUPLE_LEN {
+        let elements = (0..len).map(|idx| {
+            let ty = format_ident!("{}", (b'A' + idx as u8) as char);
+            let idx = syn::Index::from(idx);
+            quote!(#ty #idx)
+        });
+        tuple_impls.extend(quote! {
+            impl_visit_hooks_for_tuple!(#(#elements),*);
+        });
+    }
+
+    let output = quote! {
+            #![allow(unused, clippy::useless_conversion, clippy::single_match)]
+            use crate::*;
+
+            pub trait VisitHooks {
+                #hook_functions
+            }
+
+            impl<V: ?Sized + VisitHooks> VisitHooks for &mut V {
+                #ref_mut_functions
+            }
+
+            macro_rules! impl_visit_hooks_for_tuple {
+                ($($T:ident $idx:tt),+) => {
+                    impl<$($T: VisitHooks),+> VisitHooks for ($($T,)+) {
+                        #tuple_functions
+                    }
+                };
+            }
+
+            #tuple_impls
+
+            impl<V: ?Sized + VisitHooks> Visit for HooksVisitor<'_, V> {
+                fn ast(&self) -> &Ast {
+                    self.ast
+                }
+
+                #visit_functions
+            }
+
+            /// Calls the hooks on entering `node_id`, like [HooksVisitor].
+            pub(crate) fn enter_hooks<V: ?Sized + VisitHooks>(hooks: &mut V, node_id: NodeId, ast: &Ast) {
+                hooks.enter_node(node_id, ast);
+                // SAFETY: The kind of the node is checked.
+                match ast.nodes.kind(node_id) {
+                    #enter_arms
+                    // Kinds without a node struct have no hooks.
+                    _ => {}
+                }
+            }
+
+            /// Calls the hooks on leaving `node_id`, like [HooksVisitor].
+            pub(crate) fn leave_hooks<V: ?Sized + VisitHooks>(hooks: &mut V, node_id: NodeId, ast: &Ast) {
+                // SAFETY: The kind of the node is checked.
+                match ast.nodes.kind(node_id) {
+                    #leave_arms
+                    // Kinds without a node struct have no hooks.
+                    _ => {}
+                }
+                hooks.leave_node(node_id, ast);
+            }
+
+            pub trait VisitMutHooks {
+                #mut_hook_functions
+            }
+
+            impl<V: ?Sized + VisitMutHooks> VisitMutHooks for &mut V {
+                #mut_ref_mut_functions
+            }
+
+            /// Calls the hooks on entering `node_id`, like [enter_hooks].
+            pub(crate) fn enter_mut_hooks<V: ?Sized + VisitMutHooks>(hooks: &mut V, node_id: NodeId, ast: &mut Ast) {
+                hooks.enter_node(node_id, ast);
+                // SAFETY: The kind of the node is checked.
+                match ast.nodes.kind(node_id) {
+                    #enter_mut_arms
+                    // Kinds without a node struct have no hooks.
+                    _ => {}
+                }
+            }
+
+            /// Calls the hooks on leaving `node_id`, like [leave_hooks].
+            pub(crate) fn leave_mut_hooks<V: ?Sized + VisitMutHooks>(hooks: &mut V, node_id: NodeId, ast: &mut Ast) {
+                // SAFETY: The kind of the node is checked.
+                match ast.nodes.kind(node_id) {
+                    #leave_mut_arms
+                    // Kinds without a node struct have no hooks.
+                    _ => {}
+                }
+                hooks.leave_node(node_id, ast);
+            }
+    };
+
+    RustOutput {
+        path: output_path(AST_CRATE_PATH, "ast_visitor_hooks"),
+        tokens: output,
+    }
+    .into()
+}
@@ -0,0 +1,165 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    AST_CRATE_PATH,
+    output::{RawOutput, RustOutput, output_path},
+    schema::{AstType, Schema},
+    util::safe_ident,
+};
+
+/// Generates `VisitWithPath`, a variant of `Visit` whose methods receive the
+/// `AstNodePath` of the ancestors of the visited node.
+pub fn ast_visitor_path(schema: &Schema) -> RawOutput {
+    let mut visit_functions = TokenStream::new();
+    let mut visit_with_impls = TokenStream::new();
+
+    visit_functions.extend(quote! {
+        fn ast(&self) -> &Ast;
+
+        #[inline]
+        fn enter_node(&mut self, node_id: NodeId, path: &AstNodePath) {}
+        #[inline]
+        fn leave_node(&mut self, node_id: NodeId, path: &AstNodePath) {}
+    });
+
+    for ty in schema.types.iter() {
+        let (fn_name, visit_children) = match ty {
+            AstType::Struct(ast) => {
+                let fn_name = format_ident!("visit_{}", &ast.name.to_case(Case::Snake));
+                let node_kind = format_ident!("{}", ast.name);
+
+                let mut visit_fields = TokenStream::new();
+                for (field_idx, field) in ast.fields.iter().enumerate() {
+                    let field_ty = &schema.types[field.type_id];
+                    let field_ty_ident = field_ty.repr_ident(schema);
+                    let getter_name = safe_ident(&field.name.to_case(Case::Snake));
+                    let field_idx = field_idx as u8;
+                    visit_fields.extend(quote! {
+                        path.set_field(#field_idx);
+                        let field_value = self.#getter_name(visitor.ast());
+                        <#field_ty_ident as VisitWithPathWith<V>>::visit_with_path(field_value, visitor, path);
+                    });
+                }
+
+                let visit_children = match ast.fields.is_empty() {
+                    true => TokenStream::new(),
+                    false => quote! {
+                        path.push(self.node_id(), NodeKind::#node_kind);
+                        #visit_fields
+                        path.pop();
+                    },
+                };
+                (fn_name, visit_children)
+            }
+            AstType::Enum(ast) => {
+                let fn_name = format_ident!("visit_{}", &ast.name.to_case(Case::Snake));
+
+                let mut arms = TokenStream::new();
+                for variant in ast.variants.iter() {
+                    let Some(variant_type_id) = variant.type_id else {
+                        continue;
+                    };
+                    let variant_ty_ident = schema.types[variant_type_id].repr_ident(schema);
+                    let variant_name = format_ident!("{}", variant.name);
+                    arms.extend(quote! {
+                        Self::#variant_name(it) => <#variant_ty_ident as VisitWithPathWith<V>>::visit_with_path(it, visitor, path),
+                    });
+                }
+                (fn_name, quote!( match self { #arms } ))
+            }
+            AstType::Option(ast) => {
+                let inner_type = &schema.types[ast.inner_type_id];
+                let fn_name = match inner_type {
+                    AstType::Vec(vec) => {
+                        let inner_type = &schema.types[vec.inner_type_id];
+                        format_ident!("visit_opt_{}s", &inner_type.name().to_case(Case::Snake))
+                    }
+                    _ => format_ident!("visit_opt_{}", &inner_type.name().to_case(Case::Snake)),
+                };
+                let visit_children = quote! {
+                    match self {
+                        Some(it) => it.visit_with_path(visitor, path),
+                        None => {}
+                    }
+                };
+                (fn_name, visit_children)
+            }
+            AstType::Vec(ast) => {
+                let inner_type = &schema.types[ast.inner_type_id];
+                let fn_name = match inner_type {
+                    AstType::Option(opt) => {
+                        let inner_type = &schema.types[opt.inner_type_id];
+                        format_ident!("visit_opt_vec_{}s", &inner_type.name().to_case(Case::Snake))
+                    }
+                    _ => format_ident!("visit_{}s", &inner_type.name().to_case(Case::Snake)),
+                };
+                let visit_children = quote! {
+                    for (index, child_idx) in self.iter().enumerate() {
+                        path.set_index(Some(index as u32));
+                        let child = visitor.ast().get_node_in_sub_range(child_idx);
+                        child.visit_with_path(visitor, path);
+                    }
+                    path.set_index(None);
+                };
+                (fn_name, visit_children)
+            }
+            _ => continue,
+        };
+        let ty_ident = ty.repr_ident(schema);
+
+        let visit_function = match ty {
+            AstType::Struct(_) => quote! {
+                #[inline]
+                fn #fn_name(&mut self, node: #ty_ident, path: &mut AstNodePath) {
+                    self.enter_node(node.node_id(), path);
+                    <#ty_ident as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path);
+                    self.leave_node(node.node_id(), path);
+                }
+            },
+            _ => quote! {
+                #[inline]
+                fn #fn_name(&mut self, node: #ty_ident, path: &mut AstNodePath) {
+                    <#ty_ident as VisitWithPathWith<Self>>::visit_children_with_path(node, self, path)
+                }
+            },
+        };
+        visit_functions.extend(visit_function);
+
+        visit_with_impls.extend(quote! {
+            impl<V: ?Sized + VisitWithPath> VisitWithPathWith<V> for #ty_ident {
+                fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+                    <V as VisitWithPath>::#fn_name(visitor, self, path)
+                }
+
+                fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath) {
+                    #visit_children
+                }
+            }
+        });
+    }
+
+    let output = quote! {
+            #![allow(unused, clippy::useless_conversion, clippy::single_match)]
+            use crate::*;
+            use swc_core::common::Span;
+
+            pub trait VisitWithPath {
+                #visit_functions
+            }
+
+            pub trait VisitWithPathWith<V: ?Sized + VisitWithPath> {
+                fn visit_with_path(self, visitor: &mut V, path: &mut AstNodePath);
+                fn visit_children_with_path(self, visitor: &mut V, path: &mut AstNodePath);
+            }
+
+            #visit_with_impls
+    };
+
+    RustOutput {
+        path: output_path(AST_CRATE_PATH, "ast_visitor_path"),
+        tokens: output,
+    }
+    .into()
+}
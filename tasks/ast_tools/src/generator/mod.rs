@@ -1,4 +1,9 @@
 pub(crate) mod ast_builder;
 pub(crate) mod ast_extra_compact;
 pub(crate) mod ast_property;
+pub(crate) mod ast_schema;
 pub(crate) mod ast_visitor;
+pub(crate) mod ast_visitor_control;
+pub(crate) mod ast_visitor_hooks;
+pub(crate) mod ast_visitor_path;
+pub(crate) mod traverse;
@@ -0,0 +1,172 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{
+    TRANSFORMS_BASE_CRATE_PATH,
+    output::{RawOutput, RustOutput, output_path},
+    schema::{AstType, Schema, TypeId},
+    util::safe_ident,
+};
+
+/// Generates `Traverse`, whose `enter_*` and `exit_*` methods may replace the
+/// visited node and receive a `TraverseCtx` with the mutable `Ast`.
+pub fn traverse(schema: &Schema) -> RawOutput {
+    let mut traverse_functions = TokenStream::new();
+    let mut traverse_with_impls = TokenStream::new();
+
+    for (type_id, ty) in schema.types.iter_enumerated() {
+        if !is_traversable(type_id, schema) {
+            continue;
+        }
+        let ty_ident = ty.repr_ident(schema);
+
+        let (hook_name, traverse_children) = match ty {
+            AstType::Struct(ast) => {
+                let mut traverse_fields = TokenStream::new();
+                for (field_idx, field) in ast.fields.iter().enumerate() {
+                    if !is_traversable(field.type_id, schema) {
+                        continue;
+                    }
+                    let getter_name = safe_ident(&field.name.to_case(Case::Snake));
+                    let setter_name = format_ident!("set_{}", &field.name);
+                    let field_idx = field_idx as u8;
+                    traverse_fields.extend(quote! {
+                        ctx.ancestors.set_field(#field_idx);
+                        let field_value = node.#getter_name(ctx.ast);
+                        let new_node = field_value.traverse_with(traverser, ctx);
+                        node.#setter_name(ctx.ast, new_node);
+                    });
+                }
+
+                let traverse_children = match traverse_fields.is_empty() {
+                    true => TokenStream::new(),
+                    false => quote! {
+                        ctx.ancestors.push(ctx.ast, node.node_id());
+                        #traverse_fields
+                        ctx.ancestors.pop();
+                    },
+                };
+                (Some(ast.name.to_case(Case::Snake)), traverse_children)
+            }
+            AstType::Enum(ast) => {
+                let mut arms = TokenStream::new();
+                for variant in ast.variants.iter() {
+                    let Some(variant_type_id) = variant.type_id else {
+                        continue;
+                    };
+                    let variant_ty_ident = schema.types[variant_type_id].repr_ident(schema);
+                    let variant_name = format_ident!("{}", variant.name);
+                    arms.extend(quote! {
+                        Self::#variant_name(it) => {
+                            Self::#variant_name(<#variant_ty_ident as TraverseWith>::traverse_with(it, traverser, ctx))
+                        }
+                    });
+                }
+                let traverse_children = quote! {
+                    node = match node {
+                        #arms
+                    };
+                };
+                (Some(ast.name.to_case(Case::Snake)), traverse_children)
+            }
+            AstType::Option(_) => {
+                let traverse_children = quote! {
+                    node = node.map(|it| it.traverse_with(traverser, ctx));
+                };
+                (None, traverse_children)
+            }
+            AstType::Vec(ast) => {
+                let inner_type = &schema.types[ast.inner_type_id];
+                let hook_name = match inner_type {
+                    AstType::Option(opt) => {
+                        let inner_type = &schema.types[opt.inner_type_id];
+                        format!("opt_vec_{}s", inner_type.name().to_case(Case::Snake))
+                    }
+                    _ => format!("{}s", inner_type.name().to_case(Case::Snake)),
+                };
+                let traverse_children = quote! {
+                    for (index, child_idx) in node.iter().enumerate() {
+                        ctx.ancestors.set_index(Some(index as u32));
+                        let child = ctx.ast.get_node_in_sub_range(child_idx);
+                        let new_child = child.traverse_with(traverser, ctx);
+                        node.replace_slot(ctx.ast, child_idx, new_child);
+                    }
+                    ctx.ancestors.set_index(None);
+                };
+                (Some(hook_name), traverse_children)
+            }
+            AstType::Primitive(_) => continue,
+        };
+
+        // Options have no hooks, like the nodes they may hold.
+        let Some(hook_name) = hook_name else {
+            traverse_with_impls.extend(quote! {
+                impl TraverseWith for #ty_ident {
+                    fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+                        let mut node = self;
+                        #traverse_children
+                        node
+                    }
+                }
+            });
+            continue;
+        };
+
+        let enter = format_ident!("enter_{}", hook_name);
+        let exit = format_ident!("exit_{}", hook_name);
+        traverse_functions.extend(quote! {
+            #[inline]
+            fn #enter(&mut self, node: &mut #ty_ident, ctx: &mut TraverseCtx) {}
+            #[inline]
+            fn #exit(&mut self, node: &mut #ty_ident, ctx: &mut TraverseCtx) {}
+        });
+
+        traverse_with_impls.extend(quote! {
+            impl TraverseWith for #ty_ident {
+                fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self {
+                    let mut node = self;
+                    traverser.#enter(&mut node, ctx);
+                    #traverse_children
+                    traverser.#exit(&mut node, ctx);
+                    node
+                }
+            }
+        });
+    }
+
+    let output = quote! {
+            #![allow(unused, clippy::useless_conversion)]
+            use swc_experimental_ecma_ast::*;
+
+            use crate::traverse::TraverseCtx;
+
+            pub trait Traverse {
+                #traverse_functions
+            }
+
+            pub trait TraverseWith: Sized {
+                /// Calls the hooks of `traverser` on this node and its
+                /// descendants, and returns the node replacing it.
+                fn traverse_with<T: ?Sized + Traverse>(self, traverser: &mut T, ctx: &mut TraverseCtx) -> Self;
+            }
+
+            #traverse_with_impls
+    };
+
+    RustOutput {
+        path: output_path(TRANSFORMS_BASE_CRATE_PATH, "traverse"),
+        tokens: output,
+    }
+    .into()
+}
+
+/// Returns `true` if values of the type may hold nodes.
+fn is_traversable(type_id: TypeId, schema: &Schema) -> bool {
+    match &schema.types[type_id] {
+        AstType::Struct(_) | AstType::Enum(_) => true,
+        AstType::Option(ast) => is_traversable(ast.inner_type_id, schema),
+        AstType::Vec(ast) => is_traversable(ast.inner_type_id, schema),
+        AstType::Primitive(_) => false,
+    }
+}
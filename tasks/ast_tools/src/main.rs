@@ -1,8 +1,13 @@
 use crate::{
-    derive::{ast_clone_in::ast_clone_in, ast_node_id::ast_node_id},
+    derive::{
+        ast_clone_in::ast_clone_in, ast_clone_into::ast_clone_into, ast_dump::ast_dump,
+        ast_eq_ignore_span::ast_eq_ignore_span, ast_node_id::ast_node_id,
+    },
     generator::{
         ast_builder::ast_builder, ast_extra_compact::ast_extra_compact, ast_property::ast_property,
-        ast_visitor::ast_visitor,
+        ast_schema::ast_schema, ast_visitor::ast_visitor, ast_visitor_control::ast_visitor_control,
+        ast_visitor_hooks::ast_visitor_hooks, ast_visitor_path::ast_visitor_path,
+        traverse::traverse,
     },
     parse::parse_files,
 };
@@ -28,10 +33,11 @@ const SOURCE_PATHS: &[&str] = &[
     "crates/swc_ecma_ast/src/ast/lit.rs",
     "crates/swc_ecma_ast/src/ast/jsx.rs",
     "crates/swc_ecma_ast/src/ast/operator.rs",
-    // "crates/swc_ecma_ast/src/ast/typescript.rs",
+    "crates/swc_ecma_ast/src/ast/typescript.rs",
 ];
 
 const AST_CRATE_PATH: &str = "swc_ecma_ast";
+const TRANSFORMS_BASE_CRATE_PATH: &str = "swc_ecma_transforms_base";
 
 fn main() {
     let schema = parse_files(SOURCE_PATHS);
@@ -48,9 +54,33 @@ fn main() {
     let ast_clone_in = ast_clone_in(&schema);
     ast_clone_in.write_to_file().unwrap();
 
+    let ast_clone_into = ast_clone_into(&schema);
+    ast_clone_into.write_to_file().unwrap();
+
+    let ast_dump = ast_dump(&schema);
+    ast_dump.write_to_file().unwrap();
+
+    let ast_eq_ignore_span = ast_eq_ignore_span(&schema);
+    ast_eq_ignore_span.write_to_file().unwrap();
+
     let ast_visitor = ast_visitor(&schema);
     ast_visitor.write_to_file().unwrap();
 
+    let ast_visitor_control = ast_visitor_control(&schema);
+    ast_visitor_control.write_to_file().unwrap();
+
+    let ast_visitor_path = ast_visitor_path(&schema);
+    ast_visitor_path.write_to_file().unwrap();
+
+    let ast_visitor_hooks = ast_visitor_hooks(&schema);
+    ast_visitor_hooks.write_to_file().unwrap();
+
+    let traverse = traverse(&schema);
+    traverse.write_to_file().unwrap();
+
     let ast_extra_data_compact = ast_extra_compact(&schema);
     ast_extra_data_compact.write_to_file().unwrap();
+
+    let ast_schema = ast_schema(&schema);
+    ast_schema.write_to_file().unwrap();
 }
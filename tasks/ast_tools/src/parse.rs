@@ -45,6 +45,7 @@ pub fn parse_files(file_paths: &[&str]) -> Schema {
     let mut type_names = IndexSet::new();
     let mut prototypes = Vec::new();
     let mut repr_sizes = HashMap::new();
+    let mut repr_variants = HashMap::new();
 
     for source in file_paths {
         let content =
@@ -71,6 +72,16 @@ pub fn parse_files(file_paths: &[&str]) -> Schema {
                     // Collect #[repr(uN)] sizes for all enums (not just #[ast] enums)
                     if let Some(size) = parse_repr_size(&item.attrs) {
                         repr_sizes.insert(item.ident.to_string(), size);
+                        // The schema assumes the discriminants are the variant indexes
+                        assert!(
+                            item.variants.iter().all(|v| v.discriminant.is_none()),
+                            "{} has explicit discriminants",
+                            item.ident
+                        );
+                        repr_variants.insert(
+                            item.ident.to_string(),
+                            item.variants.iter().map(|v| v.ident.to_string()).collect(),
+                        );
                     }
 
                     // Filter enums with #[ast]
@@ -119,7 +130,11 @@ pub fn parse_files(file_paths: &[&str]) -> Schema {
     }
 
     types.extend(parser.extra_types);
-    Schema { types, repr_sizes }
+    Schema {
+        types,
+        repr_sizes,
+        repr_variants,
+    }
 }
 
 impl Parser {
@@ -186,6 +201,10 @@ impl Parser {
             "UpdateOp" => primitive("UpdateOp"),
             "MetaPropKind" => primitive("MetaPropKind"),
             "MethodKind" => primitive("MethodKind"),
+            "Accessibility" => primitive("Accessibility"),
+            "TruePlusMinus" => primitive("TruePlusMinus"),
+            "TsKeywordTypeKind" => primitive("TsKeywordTypeKind"),
+            "TsTypeOperatorOp" => primitive("TsTypeOperatorOp"),
             _ => panic!("Unknown primitive {name}"),
         };
         self.create_new_type(type_def)
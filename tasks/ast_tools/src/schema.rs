@@ -17,6 +17,8 @@ pub struct Schema {
     /// Mapping of enum names to their #[repr(uN)] sizes in bytes
     /// e.g., "UnaryOp" -> 1 for #[repr(u8)]
     pub repr_sizes: HashMap<String, usize>,
+    /// Mapping of enum names with #[repr(uN)] to their variant names
+    pub repr_variants: HashMap<String, Vec<String>>,
 }
 
 #[derive(Debug)]
@@ -219,11 +219,17 @@ pub fn safe_ident(name: &str) -> Ident {
     }
 }
 
+/// Enums nesting more than one enum do not fit into 8 bytes of `ExtraData`,
+/// so they are stored by their `NodeId` instead.
+fn is_stored_as_node(enum_name: &str) -> bool {
+    matches!(enum_name, "AssignTarget" | "ModuleItem" | "TsType")
+}
+
 pub fn map_field_type_to_extra_field(ast: &AstType, schema: &Schema) -> String {
     match ast {
         AstType::Struct(_) => "node".to_owned(),
         AstType::Enum(ast_enum) => {
-            if ast_enum.name == "AssignTarget" || ast_enum.name == "ModuleItem" {
+            if is_stored_as_node(&ast_enum.name) {
                 "node".to_owned()
             } else {
                 ast_enum.name.to_case(Case::Snake)
@@ -235,7 +241,7 @@ pub fn map_field_type_to_extra_field(ast: &AstType, schema: &Schema) -> String {
             match inner_ty {
                 AstType::Vec(_) => "optional_sub_range".to_owned(),
                 AstType::Enum(ast_enum) => {
-                    if ast_enum.name == "AssignTarget" || ast_enum.name == "ModuleItem" {
+                    if is_stored_as_node(&ast_enum.name) {
                         "optional_node".to_owned()
                     } else {
                         format!("optional_{}", ast_enum.name.to_case(Case::Snake))
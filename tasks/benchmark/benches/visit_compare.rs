@@ -150,6 +150,169 @@ fn bench_new_mut(b: &mut Bencher, src: &'static str) {
     });
 }
 
+fn bench_kind_scan(b: &mut Bencher, src: &'static str) {
+    use swc_experimental_ecma_ast::Ident;
+    use swc_experimental_ecma_parser::{Lexer, Parser, StringSource};
+
+    let input = StringSource::new(src);
+    let lexer = Lexer::new(
+        swc_experimental_ecma_parser::Syntax::Es(Default::default()),
+        Default::default(),
+        input,
+        None,
+    );
+    let parser = Parser::new_from(lexer);
+    let ret = parser.parse_module().unwrap();
+
+    b.iter(|| {
+        let count = ret.ast.nodes_of_kind::<Ident>().count();
+        std::hint::black_box(count);
+    });
+}
+
+fn bench_span_visit(b: &mut Bencher, src: &'static str) {
+    use swc_experimental_ecma_ast::{Ast, GetSpan};
+    use swc_experimental_ecma_parser::{Lexer, Parser, StringSource};
+    use swc_experimental_ecma_visit::VisitWith;
+
+    let input = StringSource::new(src);
+    let lexer = Lexer::new(
+        swc_experimental_ecma_parser::Syntax::Es(Default::default()),
+        Default::default(),
+        input,
+        None,
+    );
+    let parser = Parser::new_from(lexer);
+    let ret = parser.parse_module().unwrap();
+
+    struct SpanSum<'a> {
+        ast: &'a Ast,
+        sum: u32,
+    }
+
+    impl swc_experimental_ecma_visit::Visit for SpanSum<'_> {
+        fn ast(&self) -> &swc_experimental_ecma_ast::Ast {
+            self.ast
+        }
+
+        fn visit_ident(&mut self, node: swc_experimental_ecma_ast::Ident) {
+            let span = node.span(self.ast);
+            self.sum = self.sum.wrapping_add(span.hi.0 - span.lo.0);
+        }
+    }
+
+    b.iter(|| {
+        let mut visitor = SpanSum {
+            ast: &ret.ast,
+            sum: 0,
+        };
+        ret.root.visit_with(&mut visitor);
+        std::hint::black_box(visitor.sum);
+    });
+}
+
+/// Read-only analyses sharing one traversal in [bench_visit_all].
+mod analyses {
+    use swc_experimental_ecma_ast::*;
+
+    /// Collects the sources of the imports, re-exports and `require` calls.
+    #[derive(Default)]
+    pub struct Dependencies(pub Vec<Wtf8Ref>);
+
+    impl VisitHooks for Dependencies {
+        fn enter_import_decl(&mut self, node: ImportDecl, ast: &Ast) {
+            self.0.push(node.src(ast).value(ast));
+        }
+
+        fn enter_export_all(&mut self, node: ExportAll, ast: &Ast) {
+            self.0.push(node.src(ast).value(ast));
+        }
+
+        fn enter_named_export(&mut self, node: NamedExport, ast: &Ast) {
+            if let Some(src) = node.src(ast) {
+                self.0.push(src.value(ast));
+            }
+        }
+
+        fn enter_call_expr(&mut self, node: CallExpr, ast: &Ast) {
+            let Callee::Expr(Expr::Ident(callee)) = node.callee(ast) else {
+                return;
+            };
+            if ast.get_utf8(callee.sym(ast)) != "require" {
+                return;
+            }
+            if let Some(arg) = node.args(ast).first()
+                && let Expr::Lit(Lit::Str(src)) = ast.get_node_in_sub_range(arg).expr(ast)
+            {
+                self.0.push(src.value(ast));
+            }
+        }
+    }
+
+    #[derive(Default)]
+    pub struct Idents(pub usize);
+
+    impl VisitHooks for Idents {
+        fn enter_ident(&mut self, _: Ident, _: &Ast) {
+            self.0 += 1;
+        }
+    }
+
+    /// Counts the expressions which may have side effects.
+    #[derive(Default)]
+    pub struct SideEffects(pub usize);
+
+    impl VisitHooks for SideEffects {
+        fn enter_call_expr(&mut self, _: CallExpr, _: &Ast) {
+            self.0 += 1;
+        }
+
+        fn enter_new_expr(&mut self, _: NewExpr, _: &Ast) {
+            self.0 += 1;
+        }
+
+        fn enter_assign_expr(&mut self, _: AssignExpr, _: &Ast) {
+            self.0 += 1;
+        }
+
+        fn enter_update_expr(&mut self, _: UpdateExpr, _: &Ast) {
+            self.0 += 1;
+        }
+    }
+}
+
+fn bench_visit_all(b: &mut Bencher, src: &'static str, combined: bool) {
+    use analyses::*;
+    use swc_experimental_ecma_parser::{Lexer, Parser, StringSource};
+
+    let input = StringSource::new(src);
+    let lexer = Lexer::new(
+        swc_experimental_ecma_parser::Syntax::Es(Default::default()),
+        Default::default(),
+        input,
+        None,
+    );
+    let parser = Parser::new_from(lexer);
+    let ret = parser.parse_module().unwrap();
+
+    b.iter(|| {
+        let mut dependencies = Dependencies::default();
+        let mut idents = Idents::default();
+        let mut side_effects = SideEffects::default();
+        if combined {
+            ret.ast.visit_all(
+                ret.root,
+                &mut (&mut dependencies, &mut idents, &mut side_effects),
+            );
+        } else {
+            ret.ast.visit_all(ret.root, &mut dependencies);
+            ret.ast.visit_all(ret.root, &mut idents);
+            ret.ast.visit_all(ret.root, &mut side_effects);
+        }
+        std::hint::black_box((dependencies.0.len(), idents.0, side_effects.0));
+    });
+}
+
 fn bench_files(c: &mut Criterion) {
     let bench_cases = &[
         ("colors", include_str!("../files/colors.js")),
@@ -177,6 +340,20 @@ fn bench_files(c: &mut Criterion) {
         c.bench_function(&format!("{name}/visit_mut/new"), |b| {
             bench_new_mut(b, source)
         });
+
+        c.bench_function(&format!("{name}/kind_scan/new"), |b| {
+            bench_kind_scan(b, source)
+        });
+        c.bench_function(&format!("{name}/span_visit/new"), |b| {
+            bench_span_visit(b, source)
+        });
+
+        c.bench_function(&format!("{name}/visit_all/sequential"), |b| {
+            bench_visit_all(b, source, false)
+        });
+        c.bench_function(&format!("{name}/visit_all/combined"), |b| {
+            bench_visit_all(b, source, true)
+        });
     }
 }
 
@@ -0,0 +1,10 @@
+export default class Foo<T> extends Bar implements Baz {
+    private readonly x?: T;
+    static #count = 0;
+    constructor(public y: number) {
+        super();
+    }
+    get value(): T | undefined {
+        return this.x;
+    }
+}
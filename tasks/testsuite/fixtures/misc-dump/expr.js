@@ -0,0 +1,3 @@
+let a = 1, b = "str\u{1F600}";
+a += (-b) ** 2n;
+foo?.bar(...args, `x${a}y`);
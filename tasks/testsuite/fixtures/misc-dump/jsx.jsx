@@ -0,0 +1 @@
+const el = <div className="a" {...props}>hello {name}</div>;
@@ -0,0 +1 @@
+for (let x: number of y) {}
@@ -0,0 +1,5 @@
+// @strip_types
+
+const enum Flags {
+  None,
+}
@@ -0,0 +1,6 @@
+// @strip_types
+
+enum Direction {
+  Up,
+  Down,
+}
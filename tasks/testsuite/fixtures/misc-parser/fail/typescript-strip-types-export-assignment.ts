@@ -0,0 +1,4 @@
+// @strip_types
+
+const value = 1;
+export = value;
@@ -0,0 +1,5 @@
+// @strip_types
+
+export enum Color {
+  Red,
+}
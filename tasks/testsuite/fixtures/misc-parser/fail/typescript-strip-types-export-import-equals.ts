@@ -0,0 +1,3 @@
+// @strip_types
+
+export import Utils = Lib.Utils;
@@ -0,0 +1,3 @@
+// @strip_types
+
+import fs = require("fs");
@@ -0,0 +1,5 @@
+// @strip_types
+
+module Legacy {
+  export function f() {}
+}
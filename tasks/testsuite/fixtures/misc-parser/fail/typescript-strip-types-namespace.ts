@@ -0,0 +1,5 @@
+// @strip_types
+
+namespace Values {
+  export const answer = 42;
+}
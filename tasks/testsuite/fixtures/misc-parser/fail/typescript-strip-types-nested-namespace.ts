@@ -0,0 +1,5 @@
+// @strip_types
+
+namespace A.B {
+  export const c = 1;
+}
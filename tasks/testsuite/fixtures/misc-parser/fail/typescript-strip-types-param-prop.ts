@@ -0,0 +1,5 @@
+// @strip_types
+
+class Point {
+  constructor(public x: number) {}
+}
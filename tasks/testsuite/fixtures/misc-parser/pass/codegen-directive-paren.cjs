@@ -0,0 +1,11 @@
+("use strict");
+with (a) {}
+function f() {
+  ("use strict");
+  with (b) {}
+}
+function g() {
+  "use strict";
+  ("b");
+  ("c").length;
+}
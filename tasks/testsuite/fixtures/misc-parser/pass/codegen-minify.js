@@ -0,0 +1,10 @@
+x = a / /re/g;
+x = a / /re/.source;
+y = a - -b * c;
+y = a - --b.c;
+y = a - -1 * c;
+z = a + +b.c;
+z = a + ++b[c];
+z = a + +b ? c : d;
+w = - -a * b;
+w = + +a.b;
@@ -0,0 +1,7 @@
+(a + b) * c; a * (b + c); (a, b); x = (a, b); f((a, b)); (a = b).c; (a || b) && c; (a && b) ?? c;
+(function(){}).call(); (async () => {})(); (() => {}).x; (a ? b : c).d; new (a.b().c); (new a).b; (new a()).b;
+(-1) ** 2; (await x).y; typeof (a + b); (a => a) || b; x = (y = z); (a, b) ? c : d; a ? (b, c) : d;
+(`a`).b; (a++).b; -(-a); +(+a); (void 0)(); (yield_) ; (a.b)(); (a?.b)(); (a?.b).c; (a?.b.c)(); new (a?.b)();
+({}).x = 1; (function(){} ()); (class{} .x);
+label: (function(){}); if (a) (function(){}); x = a in b; for (var i = (a in b) ? 1 : 2;;) break;
+for (let x = (() => a in b);;) break; (a, b) => (c, d);
@@ -0,0 +1,37 @@
+"use strict";
+var a = (1, 2), b = (x = 3);
+(function () {})();
+(class {}).name;
+({}).toString();
+({ a } = b);
+let x = a ** -b, y = (-a) ** b, z = (a ?? b) || c, w = a ?? (b || c);
+1..toString(); 1.5.toFixed(); 0x10.toString();
+new (foo())(); new (foo.bar()); new foo.bar(); new (a().b)();
+a = b ? c : d ? e : f;
+(a ? b : c) ? d : e;
+for (var i = ("x" in o); i < 1; i++);
+for (let [q] of [[1]]) {}
+for (const k in o) if (k) break; else continue;
+if (a) { if (b) c(); } else d();
+label: for (;;) { break label; }
+async function* gen() { yield* other(); await x; for await (const v of y) {} }
+const f = async (a, ...rest) => ({ a });
+const g = x => x + 1;
+a?.b?.[c]?.(d); (a?.b).c; (a?.b)();
+`hello ${world} \n ${`nested ${x}`}`;
+tag`x${y}`;
+x = - -y; x = +(+y); x = - --y; x = a - -b; x = a + ++b; x = a++ + b;
+x = typeof void delete a.b;
+x = /re/g.test(s);
+x = "a b"; x = 'it\'s'; x = 1_000; x = 10n;
+class A extends (B, C) { static #p = 1; #m() { return this.#p; } get x() { return 1; } static { init(); } static async *gen() {} }
+let o2 = { get a() {}, set a(v) {}, async b() {}, *c() {}, [d]: 1, "e-f": 2, 3: 4, ...rest, g };
+switch (a) { case 1: b(); break; default: }
+try { a(); } catch { b(); } finally { c(); }
+try { a(); } catch (e) { b(e); }
+do x++; while (x < 10)
+while (1) ;
+throw new Error("x");
+x = (a, b) => { return a; };
+x = (yield_, await_) => 1;
+debugger;
@@ -0,0 +1,5 @@
+declare function foo(a: string): void;
+declare class Bar { method(): void; prop: string; }
+export declare namespace N { const x: number; }
+declare let v: number;
+export {};
@@ -0,0 +1,7 @@
+// @decorators
+@dec()
+class Decorated<T> {
+    @prop() x: T;
+    method(@param p: string, q?: number) {}
+    constructor(@inject() private readonly service: Service) {}
+}
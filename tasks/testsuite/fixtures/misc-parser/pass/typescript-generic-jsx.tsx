@@ -0,0 +1,3 @@
+const Comp = <T,>(props: { value: T }) => <div<string> className="x">{props.value as any}</div>;
+const el = <Generic<number> value={1} />;
+function f<T extends unknown>(x: T) { return <span>{x}</span>; }
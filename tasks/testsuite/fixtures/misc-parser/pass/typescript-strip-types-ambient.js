@@ -0,0 +1 @@
+// The erased typescript-strip-types-ambient.ts
@@ -0,0 +1,19 @@
+// @strip_types
+
+declare namespace Lib {
+  enum Kind {
+    A,
+  }
+  const version: string;
+}
+
+namespace Types {
+  export interface Point {
+    x: number;
+  }
+  export type Id = string;
+}
+
+declare module "pkg" {
+  export = Lib;
+}
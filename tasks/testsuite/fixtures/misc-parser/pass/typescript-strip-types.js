@@ -0,0 +1,36 @@
+// The erased typescript-strip-types.ts
+// @auto_accessors
+import { v } from "./u";
+export { v };
+function f(a, b, ...rest) { return a; }
+function over(a) {}
+let x;
+const y = (x);
+const z = x;
+const w = (y);
+const ca = [1];
+const inst = f;
+f(1);
+new Map();
+const arrow = (a) => a;
+const arrow2 = async (a) => {};
+class C extends Base {
+  a = 1;
+  c;
+  d;
+  constructor(x) { super(); }
+  n(a) { return a; }
+  o(a) {}
+  #p = 1;
+  accessor q = 1;
+}
+try {} catch (e) {}
+let [aa, bb] = [];
+let { cc } = { cc: 1 };
+tag`x`;
+a?.b.c;
+f?.(1);
+const gen = async (a) => a;
+const cond = x ? (a) => a : null;
+const cmp = aa < bb > cc;
+const lt = aa < (bb);
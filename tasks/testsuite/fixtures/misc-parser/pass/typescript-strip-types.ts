@@ -0,0 +1,58 @@
+// @strip_types
+import type { T } from "./t";
+import { type U, v } from "./u";
+export type { W } from "./w";
+export { type X, v };
+export type * from "./y";
+interface I { a: number }
+type A<T> = T[];
+declare const d: number;
+declare function df(): void;
+declare class DC {}
+declare namespace DN { const x: number; enum E {} }
+declare global { interface Window {} }
+declare module "m" { export = x; }
+declare enum DE { A }
+namespace Types { export interface J {} export type K = 1; }
+namespace Outer.Inner { type L = 2; }
+export interface EI {}
+export default interface DI {}
+function f<T>(this: Window, a: T, b?: number, ...rest: string[]): T { return a; }
+function over(a: string): void;
+function over(a: any) {}
+let x!: number;
+const y = (x as any) satisfies unknown;
+const z = x!;
+const w = <string>(y as any);
+const ca = [1] as const;
+const inst = f<number>;
+f<number>(1);
+new Map<string, number>();
+const arrow = <T,>(a: T): T => a;
+const arrow2 = async (a: number): Promise<void> => {};
+abstract class C<T> extends Base<T> implements I, J {
+  private readonly a: number = 1;
+  declare b: string;
+  c?: number;
+  d!: number;
+  abstract e: number;
+  static [k: string]: any;
+  constructor();
+  constructor(x?: number) { super(); }
+  abstract m(): void;
+  protected override n<T>(a: T): T { return a; }
+  o(): void;
+  o(a?: any) {}
+  #p: number = 1;
+  accessor q: number = 1;
+}
+try {} catch (e: unknown) {}
+let [aa, bb]: number[] = [];
+let { cc }: { cc: number } = { cc: 1 };
+tag<number>`x`;
+a?.b!.c;
+f?.<number>(1);
+const gen = async <T,>(a: T): Promise<T> => a;
+const cond = x ? (a): number => a : null;
+const cmp = aa < bb > cc;
+const lt = aa < (bb as number);
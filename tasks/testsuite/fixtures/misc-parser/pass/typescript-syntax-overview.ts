@@ -0,0 +1,82 @@
+import type { Foo } from "./foo";
+import fs = require("fs");
+import Bar = Foo.Bar;
+export type { Baz } from "./baz";
+
+interface Point<T extends number = number> extends Base, Other<string> {
+    readonly x: T;
+    y?: T;
+    [key: string]: unknown;
+    method<U>(a: U, ...rest: any[]): void;
+    new (x: number): Point<T>;
+    (call: string): boolean;
+    get prop(): string;
+    set prop(v: string);
+}
+
+type Mapped<T> = { readonly [K in keyof T]?: T[K] extends infer U extends string ? U : never };
+type Tpl = `prefix-${string}-${number}`;
+type Fn = (a: number, b?: string) => asserts a is number;
+type Ctor = abstract new () => object;
+type Tuple = [a: string, b?: number, ...rest: boolean[]];
+type Cond<T> = T extends (infer R)[] ? R : T extends Promise<infer P> ? P : never;
+type Q = typeof import("./mod").default;
+type U = unique symbol;
+type Acc = Foo["bar"][number];
+
+enum Color { Red, Green = "green", Blue = 1 << 2 }
+const enum CE { A = 1 }
+declare enum DE { X }
+
+namespace NS.Inner {
+    export const x: number = 1;
+    export function f(): void {}
+}
+declare module "ambient" {
+    export function g(a: string): number;
+}
+declare global {
+    interface Window { foo: string }
+}
+
+abstract class Animal<T> extends Base<T> implements I1, I2<string> {
+    private readonly name!: string;
+    protected static count?: number = 0;
+    declare field: number;
+    public abstract speak(): void;
+    override toString(): string { return ""; }
+    constructor(public a: number, private readonly b?: string) { super(); }
+    get value(): T { return null!; }
+    accessor acc = 1;
+    [key: string]: any;
+}
+
+function overload(a: string): string;
+function overload(a: number): number;
+function overload(a: any): any { return a; }
+
+function isString(x: unknown): x is string { return typeof x === "string"; }
+function assertIsDefined<T>(val: T): asserts val is NonNullable<T> {}
+
+const a = <T,>(x: T): T => x;
+const b = async <T>(x: T): Promise<T> => x;
+let c = x as const;
+let d = y satisfies Record<string, number>;
+let e = z!.foo!.bar;
+let f = <any>window;
+let g = fn<string>("a");
+let h = new Map<string, number>();
+let i = obj?.method<number>?.(1);
+let j: typeof c = c;
+let k = cond ? (x: number): string => "" : null;
+let l!: number;
+for (const [m, n] of entries as [string, number][]) {}
+export default interface DefaultIface { a: 1 }
+export = foo;
+export as namespace MyLib;
+export import Alias = NS.Inner;
+export declare const dc: number;
+export abstract class AC {}
+let tagged = tag<string>`hello ${world}`;
+label: for (;;) { break label; }
+try {} catch (e: unknown) {}
@@ -0,0 +1,110 @@
+use std::path::{Path, PathBuf};
+
+use swc_experimental_ecma_ast::NodeKind;
+
+use crate::{
+    cases::{Case, IsModule},
+    util::crate_root,
+};
+
+/// The number of nested expressions of the generated cases, which overflows
+/// the stack of a recursive traversal.
+pub const DEPTH: usize = 100_000;
+
+/// A generated source, as it's printed by the codegen.
+pub struct DeepCase {
+    path: PathBuf,
+    code: String,
+    /// The kind of the nested nodes, of which there are [DEPTH].
+    pub kind: NodeKind,
+    /// The ESTree type of the nested nodes, if they're serialized.
+    pub estree_type: Option<&'static str>,
+}
+
+impl DeepCase {
+    /// Generates the shapes that nest expressions [DEPTH] times: operator
+    /// chains nesting to the left like the string concatenations of minified
+    /// bundles or to the right, nested literals and parentheses, call and
+    /// member chains, and conditional chains.
+    pub fn generate() -> Vec<Self> {
+        let chain = |op: &str| format!("a{};\n", format!(" {op} a").repeat(DEPTH));
+        let nested =
+            |open: &str, close: &str| format!("{}a{};\n", open.repeat(DEPTH), close.repeat(DEPTH));
+        [
+            (
+                "binary-chain.js",
+                chain("+"),
+                NodeKind::BinExpr,
+                Some("BinaryExpression"),
+            ),
+            (
+                "logical-chain.js",
+                chain("&&"),
+                NodeKind::BinExpr,
+                Some("LogicalExpression"),
+            ),
+            (
+                "exponent-chain.js",
+                chain("**"),
+                NodeKind::BinExpr,
+                Some("BinaryExpression"),
+            ),
+            (
+                "nested-arrays.js",
+                nested("[", "]"),
+                NodeKind::ArrayLit,
+                Some("ArrayExpression"),
+            ),
+            (
+                "nested-parens.js",
+                nested("(", ")"),
+                NodeKind::ParenExpr,
+                None,
+            ),
+            (
+                "call-chain.js",
+                format!("a{};\n", "()".repeat(DEPTH)),
+                NodeKind::CallExpr,
+                Some("CallExpression"),
+            ),
+            (
+                "member-chain.js",
+                format!("a{};\n", ".a".repeat(DEPTH)),
+                NodeKind::MemberExpr,
+                Some("MemberExpression"),
+            ),
+            (
+                "conditional-chain.js",
+                format!("{}a;\n", "a ? a : ".repeat(DEPTH)),
+                NodeKind::CondExpr,
+                Some("ConditionalExpression"),
+            ),
+        ]
+        .into_iter()
+        .map(|(name, code, kind, estree_type)| DeepCase {
+            path: crate_root().join("generated").join(name),
+            code,
+            kind,
+            estree_type,
+        })
+        .collect()
+    }
+}
+
+impl Case for DeepCase {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn should_fail(&self) -> bool {
+        false
+    }
+
+    fn is_module(&self) -> IsModule {
+        IsModule::Script
+    }
+}
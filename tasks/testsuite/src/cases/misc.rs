@@ -3,7 +3,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use swc_experimental_ecma_parser::{EsSyntax, Syntax};
+use swc_experimental_ecma_parser::{EsSyntax, Syntax, TsSyntax};
 
 use crate::cases::{Case, fixtures};
 
@@ -11,6 +11,8 @@ pub struct MiscCase {
     path: PathBuf,
     code: String,
     should_fail: bool,
+    /// The errors recorded in `<file>.stderr` next to a failing case.
+    errors: Option<String>,
 }
 
 impl MiscCase {
@@ -34,22 +36,47 @@ impl MiscCase {
                 path,
                 code,
                 should_fail: false,
+                errors: None,
             });
         }
 
         for fail_case in fail_cases {
             let fail_case = fail_case.unwrap();
             let path = fail_case.path();
+            if path.extension().is_some_and(|ext| ext == "stderr") {
+                continue;
+            }
             let code = std::fs::read_to_string(&path).unwrap();
+            let file_name = path.file_name().unwrap().to_string_lossy();
+            let errors =
+                std::fs::read_to_string(path.with_file_name(format!("{file_name}.stderr"))).ok();
             cases.push(MiscCase {
                 path,
                 code,
                 should_fail: true,
+                errors,
             });
         }
 
         cases
     }
+
+    /// Reads the cases whose ASTs are dumped into snapshots.
+    pub fn read_dump() -> Vec<Self> {
+        read_dir(fixtures().join("misc-dump"))
+            .unwrap()
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                let code = std::fs::read_to_string(&path).unwrap();
+                MiscCase {
+                    path,
+                    code,
+                    should_fail: false,
+                    errors: None,
+                }
+            })
+            .collect()
+    }
 }
 
 impl Case for MiscCase {
@@ -69,6 +96,10 @@ impl Case for MiscCase {
         self.should_fail() && IGNORED_FAIL_TESTS.contains(&self.filename().as_str())
     }
 
+    fn expected_errors(&self) -> Option<&str> {
+        self.errors.as_deref()
+    }
+
     fn syntax(&self) -> Syntax {
         let ext = self.ext();
         match ext.as_str() {
@@ -89,9 +120,18 @@ impl Case for MiscCase {
                 if self.code.contains("// @allow_super_outside_method") {
                     es.allow_super_outside_method = true;
                 }
+                if self.code.contains("// @auto_accessors") {
+                    es.auto_accessors = true;
+                }
                 Syntax::Es(es)
             }
-            "ts" | "cts" | "mts" | "tsx" => Syntax::Es(EsSyntax::default()),
+            "ts" | "cts" | "mts" | "tsx" => Syntax::Typescript(TsSyntax {
+                tsx: ext == "tsx",
+                decorators: self.code.contains("// @decorators"),
+                dts: self.filename().ends_with(".d.ts"),
+                strip_types: self.code.contains("// @strip_types"),
+                ..Default::default()
+            }),
             _ => unreachable!(),
         }
     }
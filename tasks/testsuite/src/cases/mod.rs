@@ -4,15 +4,17 @@ use swc_experimental_ecma_parser::Syntax;
 
 use crate::util::crate_root;
 
+pub mod deep;
 pub mod misc;
 pub mod test262;
 pub mod test262_parser;
+pub mod typescript;
 
 pub enum IsModule {
     Module,
     Script,
     Unknown,
-    Skip,
+    Typescript,
 }
 
 pub trait Case: Sync {
@@ -24,12 +26,18 @@ pub trait Case: Sync {
         false
     }
 
+    /// The errors a failing case should report, one `Debug` per line, if
+    /// they are recorded.
+    fn expected_errors(&self) -> Option<&str> {
+        None
+    }
+
     fn is_module(&self) -> IsModule {
         match self.ext().as_str() {
             "cjs" => IsModule::Script,
             "mjs" => IsModule::Module,
             "js" | "jsx" => IsModule::Unknown,
-            "ts" | "tsx" => IsModule::Skip,
+            "ts" | "mts" | "cts" | "tsx" => IsModule::Unknown,
             _ => unreachable!(),
         }
     }
@@ -0,0 +1,112 @@
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use jwalk::WalkDir;
+use swc_experimental_ecma_parser::{Syntax, TsSyntax};
+
+use crate::cases::{Case, IsModule, fixtures};
+
+/// TypeScript fixtures from the upstream swc parser testsuite
+/// (`crates/swc_ecma_parser/tests`), cloned by `scripts/clone_fixtures.sh`.
+///
+/// - `typescript/` and `tsc/` are expected to parse, unless upstream recorded an
+///   error snapshot (`*.stderr` / `*.swc-stderr`) next to the input.
+/// - `typescript-errors/` is expected to fail.
+pub struct TypeScriptCase {
+    path: PathBuf,
+    code: String,
+    should_fail: bool,
+    /// `tsc/` cases are type-checker conformance tests, so early errors are
+    /// not reported for them.
+    no_early_errors: bool,
+}
+
+impl TypeScriptCase {
+    pub fn read() -> Vec<Self> {
+        let test_path = fixtures()
+            .join("swc")
+            .join("crates")
+            .join("swc_ecma_parser")
+            .join("tests");
+
+        let mut cases = Vec::new();
+        for (dir, expect_error) in [
+            ("typescript", false),
+            ("tsc", false),
+            ("typescript-errors", true),
+        ] {
+            for file in WalkDir::new(test_path.join(dir)).parallelism(if cfg!(miri) {
+                jwalk::Parallelism::Serial
+            } else {
+                jwalk::Parallelism::RayonDefaultPool {
+                    busy_timeout: Duration::from_secs(1),
+                }
+            }) {
+                let file = file.unwrap();
+                if !file.file_type().is_file() {
+                    continue;
+                }
+
+                let path = file.path();
+                let ext = path.extension().unwrap_or_default().to_string_lossy();
+                if !matches!(ext.as_ref(), "ts" | "mts" | "cts" | "tsx") {
+                    continue;
+                }
+
+                let code = read_to_string(&path).unwrap();
+                let should_fail = expect_error || has_error_snapshot(&path);
+                cases.push(Self {
+                    path,
+                    code,
+                    should_fail,
+                    no_early_errors: dir == "tsc",
+                });
+            }
+        }
+
+        cases
+    }
+}
+
+fn has_error_snapshot(path: &Path) -> bool {
+    let file_name = path.file_name().unwrap().to_string_lossy();
+    ["stderr", "swc-stderr"]
+        .iter()
+        .any(|ext| path.with_file_name(format!("{file_name}.{ext}")).exists())
+}
+
+impl Case for TypeScriptCase {
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn should_fail(&self) -> bool {
+        self.should_fail
+    }
+
+    fn is_module(&self) -> IsModule {
+        IsModule::Typescript
+    }
+
+    fn syntax(&self) -> Syntax {
+        let filename = self.filename();
+        let ext = self.ext();
+        Syntax::Typescript(TsSyntax {
+            tsx: ext == "tsx",
+            decorators: true,
+            dts: filename.ends_with(".d.ts")
+                || filename.ends_with(".d.mts")
+                || filename.ends_with(".d.cts"),
+            no_early_errors: self.no_early_errors,
+            disallow_ambiguous_jsx_like: ext == "mts" || ext == "cts",
+            ..Default::default()
+        })
+    }
+}
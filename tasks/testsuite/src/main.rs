@@ -7,12 +7,16 @@ use rayon::ThreadPoolBuilder;
 use crate::{
     cases::{
         Case,
+        deep::DeepCase,
         misc::MiscCase,
         test262,
         test262_parser::{self},
+        typescript::TypeScriptCase,
     },
     runner::{
-        parser::ParserRunner, semantic::SemanticRunner, transform_remove_paren::RemoveParenRunner,
+        bytes::BytesRunner, codegen::CodegenRunner, deep::DeepRunner, dump::DumpRunner,
+        estree::EstreeRunner, parser::ParserRunner, semantic::SemanticRunner,
+        transform_remove_paren::RemoveParenRunner, validate::ValidateRunner,
     },
     suite::TestResult,
     util::crate_root,
@@ -34,6 +38,12 @@ pub struct AppArgs {
 const PARSER_RUNNER: &str = "parser";
 const SEMANTIC_RUNNER: &str = "semantic";
 const REMOVE_PAREN_RUNNER: &str = "remove_paren";
+const CODEGEN_RUNNER: &str = "codegen";
+const ESTREE_RUNNER: &str = "estree";
+const BYTES_RUNNER: &str = "bytes";
+const DUMP_RUNNER: &str = "dump";
+const DEEP_RUNNER: &str = "deep";
+const VALIDATE_RUNNER: &str = "validate";
 
 pub fn main() {
     // Initialize args
@@ -73,10 +83,12 @@ fn test_normal(args: &AppArgs) {
 
     let misc_cases = filter(args, MiscCase::read());
     let test262_parser_cases = filter(args, test262_parser::Test262ParserCase::read());
+    let typescript_cases = filter(args, TypeScriptCase::read());
 
     if args.runners.is_empty() || args.runners.contains(PARSER_RUNNER) {
         results.extend(ParserRunner::run(args, &misc_cases));
         results.extend(ParserRunner::run(args, &test262_parser_cases));
+        results.extend(ParserRunner::run(args, &typescript_cases));
     }
 
     if args.runners.is_empty() || args.runners.contains(SEMANTIC_RUNNER) {
@@ -89,6 +101,37 @@ fn test_normal(args: &AppArgs) {
         results.extend(RemoveParenRunner::run(args, &test262_parser_cases));
     }
 
+    if args.runners.is_empty() || args.runners.contains(CODEGEN_RUNNER) {
+        results.extend(CodegenRunner::run(args, &misc_cases));
+        results.extend(CodegenRunner::run(args, &test262_parser_cases));
+    }
+
+    if args.runners.is_empty() || args.runners.contains(ESTREE_RUNNER) {
+        results.extend(EstreeRunner::run(args, &test262_parser_cases));
+    }
+
+    if args.runners.is_empty() || args.runners.contains(BYTES_RUNNER) {
+        results.extend(BytesRunner::run(args, &misc_cases));
+        results.extend(BytesRunner::run(args, &test262_parser_cases));
+        results.extend(BytesRunner::run(args, &typescript_cases));
+    }
+
+    if args.runners.is_empty() || args.runners.contains(DUMP_RUNNER) {
+        let dump_cases = filter(args, MiscCase::read_dump());
+        results.extend(DumpRunner::run(args, &dump_cases));
+    }
+
+    if args.runners.is_empty() || args.runners.contains(DEEP_RUNNER) {
+        let deep_cases = filter(args, DeepCase::generate());
+        results.extend(DeepRunner::run(args, &deep_cases));
+    }
+
+    if args.runners.is_empty() || args.runners.contains(VALIDATE_RUNNER) {
+        results.extend(ValidateRunner::run(args, &misc_cases));
+        results.extend(ValidateRunner::run(args, &test262_parser_cases));
+        results.extend(ValidateRunner::run(args, &typescript_cases));
+    }
+
     // Collect results
     let mut passed = 0;
     let mut failed = 0;
@@ -217,6 +260,15 @@ fn test_test262_snapshots(args: &AppArgs) {
         )
         .unwrap();
     }
+
+    if args.runners.is_empty() || args.runners.contains(CODEGEN_RUNNER) {
+        let results = CodegenRunner::run(args, &cases);
+        fs::write(
+            snapshot_dir.join("codegen_test262.snap"),
+            to_snapshot(&results),
+        )
+        .unwrap();
+    }
 }
 
 fn filter<T: Case>(args: &AppArgs, list: Vec<T>) -> Vec<T> {
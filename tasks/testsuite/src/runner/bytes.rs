@@ -0,0 +1,107 @@
+use std::{io::ErrorKind, rc::Rc};
+
+use colored::Colorize;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use swc_experimental_ecma_ast::{Ast, AstView, EqIgnoreSpan, StringAllocator};
+use swc_experimental_ecma_codegen::{Config, to_code};
+
+use crate::{
+    AppArgs,
+    cases::Case,
+    runner::{ParseResult, parse},
+    suite::TestResult,
+};
+
+/// Decodes the encoded AST of the cases, which should encode to the same bytes
+/// and print the same code again. Importing the program into an AST with
+/// other strings should print the same code too. The nodes read in place by
+/// [AstView] should be the decoded ones.
+pub struct BytesRunner;
+
+impl BytesRunner {
+    pub fn run<C: Case>(args: &AppArgs, cases: &[C]) -> Vec<TestResult> {
+        #[cfg(not(miri))]
+        let iter = cases.par_iter();
+
+        #[cfg(miri)]
+        let iter = cases.iter();
+
+        iter.filter_map(|case| {
+            if args.debug {
+                println!("[{}] {:?}", "Debug".green(), case.relative_path());
+            }
+
+            if case.should_ignore() {
+                return Some(TestResult::Ignored {
+                    path: case.relative_path().to_owned(),
+                });
+            }
+
+            let (root, ast) = match parse(case) {
+                ParseResult::Succ(ret) => ret,
+                _ => return None,
+            };
+
+            let result = ast
+                .to_bytes()
+                .map_err(|e| format!("Failed to encode: {e}"))
+                .and_then(|bytes| {
+                    let decoded =
+                        Ast::from_bytes(&bytes).map_err(|e| format!("Failed to decode: {e}"))?;
+                    Ok((bytes, decoded))
+                })
+                .and_then(|(bytes, decoded)| {
+                    if decoded.to_bytes().ok().as_ref() != Some(&bytes) {
+                        return Err("Re-encoded bytes differ".to_string());
+                    }
+
+                    let view = AstView::new(&bytes).map_err(|e| format!("Failed to view: {e}"))?;
+                    let viewed_differently = view.node_ids().any(|node_id| {
+                        let node = decoded.get_node(node_id).unwrap();
+                        view.kind(node_id) != node.kind()
+                            || view.span(node_id) != node.span()
+                            || !view.children(node_id).eq(decoded.children(node_id))
+                    });
+                    if viewed_differently {
+                        return Err("Viewed AST differs from the decoded one".to_string());
+                    }
+
+                    let mut imported = Ast::new(0, Rc::new(StringAllocator::default()));
+                    imported.add_utf8("__unrelated");
+                    let imported_root = imported.import_subtree(&ast, root);
+                    if !root.eq_ignore_span(&ast, &imported_root, &imported) {
+                        return Err("Imported AST is not equal to the original".to_string());
+                    }
+
+                    let expected = match to_code(&ast, root, Config::default()) {
+                        Ok(code) => code,
+                        // TypeScript which needs to be transformed.
+                        Err(e) if e.kind() == ErrorKind::InvalidInput => return Ok(()),
+                        Err(e) => return Err(format!("Failed to emit: {e}")),
+                    };
+                    let actual = to_code(&decoded, root, Config::default())
+                        .map_err(|e| format!("Failed to emit the decoded AST: {e}"))?;
+                    if actual != expected {
+                        return Err(format!("Decoded AST differs\n{expected}\n{actual}"));
+                    }
+                    let actual = to_code(&imported, imported_root, Config::default())
+                        .map_err(|e| format!("Failed to emit the imported AST: {e}"))?;
+                    if actual != expected {
+                        return Err(format!("Imported AST differs\n{expected}\n{actual}"));
+                    }
+                    Ok(())
+                });
+
+            Some(match result {
+                Ok(()) => TestResult::Passed {
+                    path: case.relative_path().to_owned(),
+                },
+                Err(error) => TestResult::Failed {
+                    path: case.relative_path().to_owned(),
+                    error,
+                },
+            })
+        })
+        .collect()
+    }
+}
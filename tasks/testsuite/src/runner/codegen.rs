@@ -0,0 +1,199 @@
+use std::io::ErrorKind;
+
+use colored::Colorize;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use swc_experimental_ecma_ast::{Ast, Program};
+use swc_experimental_ecma_codegen::{Config, SourceMap, to_code, to_code_with_source_map};
+use swc_experimental_ecma_transforms_base::remove_paren;
+
+use crate::{
+    AppArgs,
+    cases::Case,
+    runner::{ParseResult, parse, parse_code},
+    suite::TestResult,
+};
+
+pub struct CodegenRunner;
+
+impl CodegenRunner {
+    pub fn run<C: Case>(args: &AppArgs, cases: &[C]) -> Vec<TestResult> {
+        #[cfg(not(miri))]
+        let iter = cases.par_iter();
+
+        #[cfg(miri)]
+        let iter = cases.iter();
+
+        iter.filter_map(|case| {
+            if args.debug {
+                println!("[{}] {:?}", "Debug".green(), case.relative_path());
+            }
+
+            if case.should_ignore() {
+                return Some(TestResult::Ignored {
+                    path: case.relative_path().to_owned(),
+                });
+            }
+
+            let (root, mut ast) = match parse(case) {
+                ParseResult::Succ(ret) => ret,
+                _ => return None,
+            };
+
+            let source_name = case.filename();
+            let (code, map) = match to_code_with_source_map(
+                &ast,
+                root,
+                Config::default(),
+                &source_name,
+                case.code(),
+            ) {
+                Ok(ret) => ret,
+                // TypeScript which needs to be transformed is ignored, so that
+                // it shows up in the summary.
+                Err(e) if e.kind() == ErrorKind::InvalidInput => {
+                    if args.debug {
+                        println!("[{}] Ignored: {e}", "Debug".green());
+                    }
+                    return Some(TestResult::Ignored {
+                        path: case.relative_path().to_owned(),
+                    });
+                }
+                Err(e) => {
+                    return Some(TestResult::Failed {
+                        path: case.relative_path().to_owned(),
+                        error: format!("Failed to emit: {e}"),
+                    });
+                }
+            };
+
+            if let Err(error) = check_minified(case, &ast, root, &code) {
+                return Some(TestResult::Failed {
+                    path: case.relative_path().to_owned(),
+                    error,
+                });
+            }
+
+            // Without the parentheses of the source, the emitter has to insert
+            // them where they are needed.
+            remove_paren::remove_paren(root, &mut ast, None);
+            let result = check_source_map(case, &code, &map)
+                .and_then(|_| check_reprint(case, &code))
+                .and_then(|_| {
+                    let code = to_code(&ast, root, Config::default())
+                        .map_err(|e| format!("Failed to emit without parentheses: {e}"))?;
+                    check_reprint(case, &code)
+                });
+
+            Some(match result {
+                Ok(()) => TestResult::Passed {
+                    path: case.relative_path().to_owned(),
+                },
+                Err(error) => TestResult::Failed {
+                    path: case.relative_path().to_owned(),
+                    error,
+                },
+            })
+        })
+        .collect()
+    }
+}
+
+/// The output should parse without errors and print the same code again.
+fn check_reprint<C: Case>(case: &C, code: &str) -> Result<(), String> {
+    let (root, ast) = match parse_code(code, case.syntax(), case.is_module()) {
+        ParseResult::Succ(ret) => ret,
+        ParseResult::Fail(errors) => {
+            let errors = errors.iter().map(|e| e.kind()).collect::<Vec<_>>();
+            return Err(format!("Failed to reparse: {errors:?}\n{code}"));
+        }
+        ParseResult::Panic => return Err(format!("Panicked to reparse\n{code}")),
+    };
+    let reprinted = to_code(&ast, root, Config::default())
+        .map_err(|e| format!("Failed to emit the reparsed code: {e}"))?;
+    if reprinted != code {
+        return Err(format!("Output is not stable\n{code}\n{reprinted}"));
+    }
+    Ok(())
+}
+
+/// The minified output should parse to the same program as `code`.
+fn check_minified<C: Case>(case: &C, ast: &Ast, root: Program, code: &str) -> Result<(), String> {
+    let minified = to_code(ast, root, Config::default().with_minify(true))
+        .map_err(|e| format!("Failed to emit minified: {e}"))?;
+    let (root, ast) = match parse_code(&minified, case.syntax(), case.is_module()) {
+        ParseResult::Succ(ret) => ret,
+        ParseResult::Fail(errors) => {
+            let errors = errors.iter().map(|e| e.kind()).collect::<Vec<_>>();
+            return Err(format!(
+                "Failed to reparse minified: {errors:?}\n{minified}"
+            ));
+        }
+        ParseResult::Panic => return Err(format!("Panicked to reparse minified\n{minified}")),
+    };
+    let reprinted = to_code(&ast, root, Config::default())
+        .map_err(|e| format!("Failed to emit the reparsed minified code: {e}"))?;
+    if reprinted != code {
+        return Err(format!(
+            "Minified output changed the code\n{minified}\n{reprinted}"
+        ));
+    }
+    Ok(())
+}
+
+/// Every named token should point to the name in both the output and the
+/// source.
+fn check_source_map<C: Case>(case: &C, code: &str, map: &SourceMap) -> Result<(), String> {
+    let generated_lines = split_lines(code);
+    let original_lines = split_lines(case.code());
+    for token in map.tokens() {
+        let Some(name_id) = token.name_id else {
+            continue;
+        };
+        let name = &map.names()[name_id as usize];
+        let generated = text_at(&generated_lines, token.dst_line, token.dst_col);
+        let original = text_at(&original_lines, token.src_line, token.src_col);
+        // Private names are mapped to their `#`.
+        let original = original.strip_prefix('#').unwrap_or(original);
+        if !generated.starts_with(name.as_str()) || !original.starts_with(name.as_str()) {
+            return Err(format!(
+                "Bad mapping for `{name}` at {}:{}: {generated:?} <- {original:?}",
+                token.dst_line, token.dst_col
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Splits `code` at the line terminators of JavaScript, as the source map does.
+fn split_lines(code: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut chars = code.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\r' if matches!(chars.peek(), Some((_, '\n'))) => {}
+            '\n' | '\r' | '\u{2028}' | '\u{2029}' => {
+                lines.push(&code[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    lines.push(&code[start..]);
+    lines
+}
+
+/// Returns the rest of `lines[line]` from the UTF-16 column `col`.
+fn text_at<'a>(lines: &[&'a str], line: u32, col: u32) -> &'a str {
+    let Some(line) = lines.get(line as usize) else {
+        return "";
+    };
+    let mut units = 0;
+    for (i, c) in line.char_indices() {
+        if units >= col as usize {
+            return &line[i..];
+        }
+        units += c.len_utf16();
+    }
+    ""
+}
@@ -0,0 +1,130 @@
+use colored::Colorize;
+use swc_experimental_ecma_ast::{Ast, NodeId, NodeKind, Program, WalkEvent};
+use swc_experimental_ecma_codegen::{Config, to_code};
+use swc_experimental_ecma_estree::to_estree_json;
+use swc_experimental_ecma_semantic::{Hygiene, ParentMap, resolver::resolver};
+use swc_experimental_ecma_transforms_base::remove_paren::remove_paren;
+
+use crate::{
+    AppArgs,
+    cases::{
+        Case,
+        deep::{DEPTH, DeepCase},
+    },
+    runner::{ParseResult, parse},
+    suite::TestResult,
+};
+
+/// Parses, walks, prints, serializes, encodes and transforms the generated
+/// deep cases on the main thread, which would overflow its stack by
+/// recursing.
+pub struct DeepRunner;
+
+impl DeepRunner {
+    pub fn run(args: &AppArgs, cases: &[DeepCase]) -> Vec<TestResult> {
+        cases
+            .iter()
+            .map(|case| {
+                if args.debug {
+                    println!("[{}] {:?}", "Debug".green(), case.relative_path());
+                }
+
+                let result = match parse(case) {
+                    ParseResult::Succ((root, ast)) => check(case, root, ast),
+                    ParseResult::Fail(errors) => Err(format!("Failed to parse: {errors:?}")),
+                    ParseResult::Panic => {
+                        return TestResult::Panic {
+                            path: case.relative_path().to_owned(),
+                        };
+                    }
+                };
+                match result {
+                    Ok(()) => TestResult::Passed {
+                        path: case.relative_path().to_owned(),
+                    },
+                    Err(error) => TestResult::Failed {
+                        path: case.relative_path().to_owned(),
+                        error,
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the nodes of `kind` in the order they're entered.
+fn nodes_of_kind(ast: &Ast, root: Program, kind: NodeKind) -> Vec<NodeId> {
+    ast.walk(root)
+        .filter_map(|event| match event {
+            WalkEvent::Enter(node_id) => Some(node_id),
+            WalkEvent::Leave(_) => None,
+        })
+        .filter(|&node_id| {
+            ast.get_node(node_id)
+                .is_some_and(|node| node.kind() == kind)
+        })
+        .collect()
+}
+
+fn check(case: &DeepCase, root: Program, mut ast: Ast) -> Result<(), String> {
+    let nested = nodes_of_kind(&ast, root, case.kind);
+    if nested.len() != DEPTH {
+        return Err(format!("Walked {} nested nodes", nested.len()));
+    }
+
+    let code =
+        to_code(&ast, root, Config::default()).map_err(|e| format!("Failed to emit: {e}"))?;
+    if code != case.code() {
+        return Err("Printed code differs".to_string());
+    }
+
+    // serde_json can't read JSON this deep, so only the nodes are counted.
+    let json = to_estree_json(&ast, root, case.code(), Default::default())
+        .map_err(|e| format!("Failed to serialize: {e}"))?;
+    if let Some(estree_type) = case.estree_type {
+        let count = json.matches(&format!("\"type\":\"{estree_type}\"")).count();
+        if count != DEPTH {
+            return Err(format!("Serialized {count} nested nodes"));
+        }
+    }
+
+    let bytes = ast
+        .to_bytes()
+        .map_err(|e| format!("Failed to encode: {e}"))?;
+    let decoded = Ast::from_bytes(&bytes).map_err(|e| format!("Failed to decode: {e}"))?;
+    let code = to_code(&decoded, root, Config::default())
+        .map_err(|e| format!("Failed to emit the decoded AST: {e}"))?;
+    if code != case.code() {
+        return Err("Decoded AST differs".to_string());
+    }
+
+    // The passes implemented with `Visit` and `VisitMut`.
+    resolver(root, &ast);
+
+    let parents = ParentMap::new(root, &ast);
+    let innermost = *nested.last().unwrap();
+    let ancestors = parents
+        .ancestors(innermost)
+        .filter(|&node_id| {
+            ast.get_node(node_id)
+                .is_some_and(|node| node.kind() == case.kind)
+        })
+        .count();
+    if ancestors != DEPTH - 1 {
+        return Err(format!("Found {ancestors} nested ancestors"));
+    }
+
+    Hygiene::default().rename(root, &mut ast);
+    remove_paren(root, &mut ast, None);
+    let code =
+        to_code(&ast, root, Config::default()).map_err(|e| format!("Failed to emit: {e}"))?;
+    let expected = if case.kind == NodeKind::ParenExpr {
+        "a;\n"
+    } else {
+        case.code()
+    };
+    if code != expected {
+        return Err("Code differs after the passes".to_string());
+    }
+    Ok(())
+}
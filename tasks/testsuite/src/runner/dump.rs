@@ -0,0 +1,58 @@
+use colored::Colorize;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{
+    AppArgs,
+    cases::Case,
+    runner::{ParseResult, parse},
+    suite::TestResult,
+    util::crate_root,
+};
+
+/// Writes the dumped AST of the cases into `snapshots/dump`, so that changes
+/// of the AST show up in the diff.
+pub struct DumpRunner;
+
+impl DumpRunner {
+    pub fn run<C: Case>(args: &AppArgs, cases: &[C]) -> Vec<TestResult> {
+        #[cfg(not(miri))]
+        let iter = cases.par_iter();
+
+        #[cfg(miri)]
+        let iter = cases.iter();
+
+        let snapshot_dir = crate_root().join("snapshots").join("dump");
+        iter.map(|case| {
+            if args.debug {
+                println!("[{}] {:?}", "Debug".green(), case.relative_path());
+            }
+
+            let (root, ast) = match parse(case) {
+                ParseResult::Succ(ret) => ret,
+                ParseResult::Fail(errors) => {
+                    return TestResult::Failed {
+                        path: case.relative_path().to_owned(),
+                        error: format!("Failed to parse: {errors:?}"),
+                    };
+                }
+                ParseResult::Panic => {
+                    return TestResult::Panic {
+                        path: case.relative_path().to_owned(),
+                    };
+                }
+            };
+
+            let snapshot = snapshot_dir.join(format!("{}.snap", case.filename()));
+            match std::fs::write(snapshot, ast.dump(root)) {
+                Ok(()) => TestResult::Passed {
+                    path: case.relative_path().to_owned(),
+                },
+                Err(e) => TestResult::Failed {
+                    path: case.relative_path().to_owned(),
+                    error: format!("Failed to write the snapshot: {e}"),
+                },
+            }
+        })
+        .collect()
+    }
+}
@@ -0,0 +1,155 @@
+use std::path::Path;
+
+use colored::Colorize;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde_json::Value;
+use swc_experimental_ecma_estree::{Config, to_estree_json};
+
+use crate::{
+    AppArgs,
+    cases::{Case, fixtures},
+    runner::{ParseResult, parse},
+    suite::TestResult,
+};
+
+/// Compares the ESTree of the cases with the reference generated by acorn,
+/// see `scripts/generate_estree_fixtures.mjs`.
+pub struct EstreeRunner;
+
+impl EstreeRunner {
+    pub fn run<C: Case>(args: &AppArgs, cases: &[C]) -> Vec<TestResult> {
+        #[cfg(not(miri))]
+        let iter = cases.par_iter();
+
+        #[cfg(miri)]
+        let iter = cases.iter();
+
+        let references = fixtures().join("estree");
+        if !references.is_dir() {
+            let ignored = cases
+                .iter()
+                .filter(|case| !case.should_fail())
+                .map(|case| TestResult::Ignored {
+                    path: case.relative_path().to_owned(),
+                })
+                .collect::<Vec<_>>();
+            println!(
+                "[{}] No ESTree references in {}, {} cases are ignored, run `scripts/generate_estree_fixtures.mjs`",
+                "Warning".yellow(),
+                references.display(),
+                ignored.len()
+            );
+            return ignored;
+        }
+
+        iter.filter_map(|case| {
+            if args.debug {
+                println!("[{}] {:?}", "Debug".green(), case.relative_path());
+            }
+
+            if case.should_fail() {
+                return None;
+            }
+
+            if case.should_ignore() {
+                return Some(TestResult::Ignored {
+                    path: case.relative_path().to_owned(),
+                });
+            }
+
+            // Cases acorn rejects are ignored, so that they show up in the
+            // summary. References with lone surrogates in strings can't be
+            // read by serde_json.
+            let expected = match read_reference(&references, case) {
+                Ok(Some(expected)) => expected,
+                Ok(None) => {
+                    return Some(TestResult::Ignored {
+                        path: case.relative_path().to_owned(),
+                    });
+                }
+                Err(error) => {
+                    return Some(TestResult::Failed {
+                        path: case.relative_path().to_owned(),
+                        error,
+                    });
+                }
+            };
+
+            let (root, ast) = match parse(case) {
+                ParseResult::Succ(ret) => ret,
+                _ => return None,
+            };
+
+            let cfg = Config::default().with_loc(true).with_range(true);
+            let result = to_estree_json(&ast, root, case.code(), cfg)
+                .map_err(|e| format!("Failed to serialize: {e}"))
+                .and_then(|json| {
+                    serde_json::from_str::<Value>(&json)
+                        .map_err(|e| format!("Invalid JSON: {e}\n{json}"))
+                })
+                .and_then(|actual| {
+                    let mut path = String::new();
+                    compare(&mut path, &expected, &actual)
+                });
+
+            Some(match result {
+                Ok(()) => TestResult::Passed {
+                    path: case.relative_path().to_owned(),
+                },
+                Err(error) => TestResult::Failed {
+                    path: case.relative_path().to_owned(),
+                    error,
+                },
+            })
+        })
+        .collect()
+    }
+}
+
+/// Reads the reference of a case, which is `None` if acorn rejects the case or
+/// the reference can't be read.
+fn read_reference<C: Case>(references: &Path, case: &C) -> Result<Option<Value>, String> {
+    let reference = references
+        .join(case.path().strip_prefix(fixtures()).unwrap())
+        .with_extension("json");
+    let Ok(expected) = std::fs::read_to_string(reference) else {
+        return Err(
+            "No ESTree reference, run `scripts/generate_estree_fixtures.mjs` again".to_string(),
+        );
+    };
+    Ok(serde_json::from_str(&expected)
+        .ok()
+        .filter(|expected| !Value::is_null(expected)))
+}
+
+/// Returns the first difference between the trees, with its path.
+fn compare(path: &mut String, expected: &Value, actual: &Value) -> Result<(), String> {
+    let len = path.len();
+    match (expected, actual) {
+        // Numbers are compared by value, `1e21` and `1e+21` are the same.
+        (Value::Number(expected), Value::Number(actual))
+            if expected.as_f64() == actual.as_f64() => {}
+        (Value::Array(expected), Value::Array(actual)) if expected.len() == actual.len() => {
+            for (i, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                path.push_str(&format!("[{i}]"));
+                compare(path, expected, actual)?;
+                path.truncate(len);
+            }
+        }
+        (Value::Object(expected), Value::Object(actual)) => {
+            for key in expected.keys().chain(actual.keys()) {
+                path.push('.');
+                path.push_str(key);
+                match (expected.get(key), actual.get(key)) {
+                    (Some(expected), Some(actual)) => compare(path, expected, actual)?,
+                    (Some(_), None) => return Err(format!("Missing `{path}`")),
+                    (None, _) => return Err(format!("Unexpected `{path}`")),
+                }
+                path.truncate(len);
+            }
+        }
+        _ if expected == actual => {}
+        _ => return Err(format!("Expected {expected} at `{path}`, found {actual}")),
+    }
+    Ok(())
+}
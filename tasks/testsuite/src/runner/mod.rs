@@ -1,12 +1,17 @@
+pub mod bytes;
+pub mod codegen;
+pub mod deep;
+pub mod dump;
+pub mod estree;
 pub mod parser;
 pub mod semantic;
 pub mod transform_remove_paren;
+pub mod validate;
 
 use std::panic::{AssertUnwindSafe, catch_unwind};
 
-use swc_core::common::comments::SingleThreadedComments;
 use swc_experimental_ecma_ast::{Ast, Program};
-use swc_experimental_ecma_parser::{Lexer, Parser, StringSource, error::Error};
+use swc_experimental_ecma_parser::{Lexer, Parser, StringSource, Syntax, error::Error};
 
 use crate::cases::{Case, IsModule};
 
@@ -14,16 +19,17 @@ pub enum ParseResult {
     Succ((Program, Ast)),
     Fail(Vec<Error>),
     Panic,
-    Ignore,
 }
 
 pub fn parse<C: Case>(case: &C) -> ParseResult {
-    let syntax = case.syntax();
-    let input = StringSource::new(case.code());
-    let comments = SingleThreadedComments::default();
-    let lexer = Lexer::new(syntax, Default::default(), input, Some(&comments));
+    parse_code(case.code(), case.syntax(), case.is_module())
+}
+
+pub fn parse_code(code: &str, syntax: Syntax, is_module: IsModule) -> ParseResult {
+    let input = StringSource::new(code);
+    let lexer = Lexer::new(syntax, Default::default(), input, None).with_ast_comments();
     let parser = Parser::new_from(lexer);
-    let ret = match case.is_module() {
+    let ret = match is_module {
         IsModule::Script => catch_unwind(AssertUnwindSafe(|| {
             parser
                 .parse_script()
@@ -35,9 +41,11 @@ pub fn parse<C: Case>(case: &C) -> ParseResult {
                 .map(|ret| ret.map_root(Program::Module))
         })),
         IsModule::Unknown => catch_unwind(AssertUnwindSafe(|| parser.parse_program())),
-        IsModule::Skip => {
-            return ParseResult::Ignore;
-        }
+        IsModule::Typescript => catch_unwind(AssertUnwindSafe(|| {
+            parser
+                .parse_typescript_module()
+                .map(|ret| ret.map_root(Program::Module))
+        })),
     };
 
     match ret {
@@ -1,10 +1,15 @@
+use std::fs;
+
 use colored::Colorize;
 use rayon::prelude::*;
+use swc_experimental_ecma_ast::{Ast, EqIgnoreSpan, NodeId, Program};
+use swc_experimental_ecma_codegen::{Config, to_code};
+use swc_experimental_ecma_parser::{EsSyntax, Syntax, error::Error};
 
 use crate::{
     AppArgs,
     cases::Case,
-    runner::{ParseResult, parse},
+    runner::{ParseResult, parse, parse_code},
     suite::TestResult,
 };
 
@@ -30,9 +35,22 @@ impl ParserRunner {
             }
 
             match (case.should_fail(), parse(case)) {
-                (false, ParseResult::Succ(_)) => TestResult::Passed {
-                    path: case.relative_path().to_owned(),
-                },
+                (false, ParseResult::Succ((root, ast))) => {
+                    let result = if case.syntax().strip_types() {
+                        check_stripped(case, root, &ast)
+                    } else {
+                        Ok(())
+                    };
+                    match result {
+                        Ok(()) => TestResult::Passed {
+                            path: case.relative_path().to_owned(),
+                        },
+                        Err(error) => TestResult::Failed {
+                            path: case.relative_path().to_owned(),
+                            error,
+                        },
+                    }
+                }
                 (true, ParseResult::Succ(_)) => TestResult::Failed {
                     path: case.relative_path().to_owned(),
                     error: "Expected failure, but parsed successfully".to_string(),
@@ -41,17 +59,84 @@ impl ParserRunner {
                     path: case.relative_path().to_owned(),
                     error: format!("{:?}", errors),
                 },
-                (true, ParseResult::Fail(_)) => TestResult::Passed {
-                    path: case.relative_path().to_owned(),
+                (true, ParseResult::Fail(errors)) => match check_errors(case, &errors) {
+                    Ok(()) => TestResult::Passed {
+                        path: case.relative_path().to_owned(),
+                    },
+                    Err(error) => TestResult::Failed {
+                        path: case.relative_path().to_owned(),
+                        error,
+                    },
                 },
                 (_, ParseResult::Panic) => TestResult::Panic {
                     path: case.relative_path().to_owned(),
                 },
-                (_, ParseResult::Ignore) => TestResult::Ignored {
-                    path: case.relative_path().to_owned(),
-                },
             }
         })
         .collect()
     }
 }
+
+/// The errors should be the ones recorded for the case, if there are any.
+fn check_errors<C: Case>(case: &C, errors: &[Error]) -> Result<(), String> {
+    let Some(expected) = case.expected_errors() else {
+        return Ok(());
+    };
+    let errors = errors
+        .iter()
+        .map(|error| format!("{error:?}\n"))
+        .collect::<String>();
+    if errors == expected {
+        Ok(())
+    } else {
+        Err(format!("Expected errors\n{expected}but got\n{errors}"))
+    }
+}
+
+/// The program parsed with the types stripped should leave no TypeScript node
+/// in the arena, reachable or not, and should be the same as the JavaScript
+/// fixture next to it, which has the types erased by hand.
+fn check_stripped<C: Case>(case: &C, root: Program, ast: &Ast) -> Result<(), String> {
+    let mut kinds = (0..ast.node_count())
+        .filter_map(|index| ast.get_node(NodeId::from_usize(index)))
+        .map(|node| node.kind().schema().name)
+        .filter(|name| name.starts_with("Ts"))
+        .collect::<Vec<_>>();
+    if !kinds.is_empty() {
+        kinds.sort_unstable();
+        kinds.dedup();
+        return Err(format!("TypeScript nodes left after stripping: {kinds:?}"));
+    }
+
+    let js_path = case.relative_path().with_extension("js");
+    let Ok(js) = fs::read_to_string(case.path().with_extension("js")) else {
+        return Err(format!("Missing the erased fixture {}", js_path.display()));
+    };
+    let Syntax::Typescript(ts) = case.syntax() else {
+        unreachable!()
+    };
+    // What TypeScript always allows has to be enabled for JavaScript.
+    let syntax = Syntax::Es(EsSyntax {
+        jsx: ts.tsx,
+        decorators: ts.decorators,
+        auto_accessors: true,
+        ..Default::default()
+    });
+    let (js_root, js_ast) = match parse_code(&js, syntax, case.is_module()) {
+        ParseResult::Succ(ret) => ret,
+        ParseResult::Fail(errors) => {
+            let errors = errors.iter().map(|e| e.kind()).collect::<Vec<_>>();
+            return Err(format!("Failed to parse the erased fixture: {errors:?}"));
+        }
+        ParseResult::Panic => return Err("Panicked to parse the erased fixture".to_string()),
+    };
+    if !root.eq_ignore_span(ast, &js_root, &js_ast) {
+        let code = to_code(ast, root, Config::default())
+            .map_err(|e| format!("Failed to emit the stripped program: {e}"))?;
+        return Err(format!(
+            "Stripped program differs from {}\n{code}",
+            js_path.display()
+        ));
+    }
+    Ok(())
+}
@@ -1,6 +1,10 @@
 use colored::Colorize;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use swc_experimental_ecma_semantic::resolver::resolver;
+use swc_experimental_ecma_ast::{Ast, Ident, NodeId, NodeIdTrait, Program, Visit};
+use swc_experimental_ecma_semantic::{
+    Hygiene, ParentMap,
+    resolver::{Semantic, resolver, resolver_with_hygiene},
+};
 
 use crate::{
     AppArgs,
@@ -35,11 +39,95 @@ impl SemanticRunner {
                 _ => return None,
             };
 
-            let _semantic = resolver(root, &ast);
-            Some(TestResult::Passed {
-                path: case.path().to_owned(),
+            let semantic = resolver(root, &ast);
+            let result =
+                check_parent_map(root, &ast).and_then(|()| check_hygiene(root, &ast, &semantic));
+            Some(match result {
+                Ok(()) => TestResult::Passed {
+                    path: case.relative_path().to_owned(),
+                },
+                Err(error) => TestResult::Failed {
+                    path: case.relative_path().to_owned(),
+                    error,
+                },
             })
         })
         .collect()
     }
 }
+
+/// The ancestors of every node in the parent map should end at the root, and
+/// the children found by the schema should be the ones found by visiting.
+fn check_parent_map(root: Program, ast: &Ast) -> Result<(), String> {
+    let parent_map = ParentMap::new(root, ast);
+    let root = root.node_id();
+    for node_id in (0..ast.node_count()).map(NodeId::from_usize) {
+        let parent = parent_map.parent(node_id);
+        if parent.is_none() && node_id != root {
+            continue;
+        }
+        for child in ast.children(node_id) {
+            if parent_map.parent(child) != Some(node_id) {
+                let kind = unsafe { ast.get_node_unchecked(child) }.kind();
+                return Err(format!("{kind:?} is not a child of its parent"));
+            }
+        }
+        // The root has no parent.
+        let Some(parent) = parent else {
+            continue;
+        };
+        if !ast.children(parent).any(|child| child == node_id) {
+            let kind = unsafe { ast.get_node_unchecked(node_id) }.kind();
+            return Err(format!(
+                "{kind:?} is missing from the children of its parent"
+            ));
+        }
+        let enclosing = parent_map.enclosing::<Program>(node_id, ast);
+        if parent_map.ancestors(node_id).last() != Some(root)
+            || enclosing.map(|program| program.node_id()) != Some(root)
+        {
+            let kind = unsafe { ast.get_node_unchecked(node_id) }.kind();
+            return Err(format!("{kind:?} is not under the root"));
+        }
+    }
+    Ok(())
+}
+
+/// Applying the same mark to every identifier shouldn't change how they are
+/// resolved.
+fn check_hygiene(root: Program, ast: &Ast, semantic: &Semantic) -> Result<(), String> {
+    let mut collector = IdentCollector {
+        ast,
+        idents: Vec::new(),
+    };
+    collector.visit_program(root);
+
+    let mut hygiene = Hygiene::default();
+    let mark = hygiene.new_mark();
+    for &ident in &collector.idents {
+        hygiene.apply_mark(ident, mark);
+    }
+    let marked = resolver_with_hygiene(root, ast, &hygiene);
+    for &ident in &collector.idents {
+        if semantic.node_scope(ident) != marked.node_scope(ident) {
+            let sym = ast.get_utf8(ident.sym(ast));
+            return Err(format!("Marked `{sym}` is resolved differently"));
+        }
+    }
+    Ok(())
+}
+
+struct IdentCollector<'a> {
+    ast: &'a Ast,
+    idents: Vec<Ident>,
+}
+
+impl Visit for IdentCollector<'_> {
+    fn ast(&self) -> &Ast {
+        self.ast
+    }
+
+    fn visit_ident(&mut self, ident: Ident) {
+        self.idents.push(ident);
+    }
+}
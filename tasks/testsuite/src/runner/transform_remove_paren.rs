@@ -1,6 +1,14 @@
+use std::{
+    hash::{DefaultHasher, Hasher},
+    io::ErrorKind,
+};
+
 use colored::Colorize;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use swc_experimental_ecma_ast::{Ast, Visit};
+use swc_experimental_ecma_ast::{
+    Ast, EqIgnoreSpan, HashIgnoreSpan, KindIndex, NodeId, ParenExpr, Program, Visit,
+};
+use swc_experimental_ecma_codegen::{Config, to_code};
 use swc_experimental_ecma_transforms_base::remove_paren;
 
 use crate::{
@@ -48,15 +56,78 @@ impl RemoveParenRunner {
                     error: format!("ParenExpr is detected {}", collector.count),
                 });
             }
+            // The removed `ParenExpr`s are still in the arena, but not reachable.
+            let index = KindIndex::new(root, &ast);
+            if index.nodes::<ParenExpr>(&ast).next().is_some() {
+                return Some(TestResult::Failed {
+                    path: case.relative_path().to_owned(),
+                    error: "ParenExpr is detected by the kind index".to_string(),
+                });
+            }
 
-            Some(TestResult::Passed {
-                path: case.relative_path().to_owned(),
+            Some(match check_compact(root, ast) {
+                Ok(()) => TestResult::Passed {
+                    path: case.relative_path().to_owned(),
+                },
+                Err(error) => TestResult::Failed {
+                    path: case.relative_path().to_owned(),
+                    error,
+                },
             })
         })
         .collect()
     }
 }
 
+/// The removed `ParenExpr`s are garbage, compacting the AST should print the
+/// same code, keep the program structurally equal and leave nothing to remove
+/// for the next compaction.
+fn check_compact(root: Program, mut ast: Ast) -> Result<(), String> {
+    let bytes = ast
+        .to_bytes()
+        .map_err(|e| format!("Failed to encode: {e}"))?;
+    let original = Ast::from_bytes(&bytes).map_err(|e| format!("Failed to decode: {e}"))?;
+    let expected = match to_code(&ast, root, Config::default()) {
+        Ok(code) => Some(code),
+        // TypeScript which needs to be transformed.
+        Err(e) if e.kind() == ErrorKind::InvalidInput => None,
+        Err(e) => return Err(format!("Failed to emit: {e}")),
+    };
+    let original_root = root;
+    let (root, remap) = ast.compact(root);
+    let bytes = ast
+        .to_bytes()
+        .map_err(|e| format!("Failed to encode the compacted AST: {e}"))?;
+    Ast::from_bytes(&bytes).map_err(|e| format!("Failed to decode the compacted AST: {e}"))?;
+    if !root.eq_ignore_span(&ast, &original_root, &original)
+        || hash_ignore_span(root, &ast) != hash_ignore_span(original_root, &original)
+    {
+        return Err("Compacted AST is not equal to the original".to_string());
+    }
+    if let Some(expected) = expected {
+        let actual = to_code(&ast, root, Config::default())
+            .map_err(|e| format!("Failed to emit the compacted AST: {e}"))?;
+        if actual != expected {
+            return Err(format!("Compacted AST differs\n{expected}\n{actual}"));
+        }
+    }
+
+    let (_, remap_again) = ast.compact(root);
+    let is_identity = (0..remap.len())
+        .map(NodeId::from_usize)
+        .all(|id| remap_again.get(id) == Some(id));
+    if remap_again.len() != remap.len() || !is_identity {
+        return Err("Compaction is not stable".to_string());
+    }
+    Ok(())
+}
+
+fn hash_ignore_span(root: Program, ast: &Ast) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    root.hash_ignore_span(ast, &mut hasher);
+    hasher.finish()
+}
+
 struct ParenCollector<'a> {
     ast: &'a Ast,
     count: usize,
@@ -0,0 +1,86 @@
+use colored::Colorize;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use swc_experimental_ecma_ast::{Ast, Program};
+use swc_experimental_ecma_transforms_base::remove_paren;
+
+use crate::{
+    AppArgs,
+    cases::Case,
+    runner::{ParseResult, parse},
+    suite::TestResult,
+};
+
+/// Checks the invariants of the AST after parsing, and after every transform
+/// of it.
+pub struct ValidateRunner;
+
+impl ValidateRunner {
+    pub fn run<C: Case>(args: &AppArgs, cases: &[C]) -> Vec<TestResult> {
+        #[cfg(not(miri))]
+        let iter = cases.par_iter();
+
+        #[cfg(miri)]
+        let iter = cases.iter();
+
+        iter.filter_map(|case| {
+            if args.debug {
+                println!("[{}] {:?}", "Debug".green(), case.relative_path());
+            }
+
+            if case.should_ignore() {
+                return Some(TestResult::Ignored {
+                    path: case.relative_path().to_owned(),
+                });
+            }
+
+            let (root, ast) = match parse(case) {
+                ParseResult::Succ(ret) => ret,
+                _ => return None,
+            };
+
+            Some(match check_transforms(root, ast) {
+                Ok(()) => TestResult::Passed {
+                    path: case.relative_path().to_owned(),
+                },
+                Err(error) => TestResult::Failed {
+                    path: case.relative_path().to_owned(),
+                    error,
+                },
+            })
+        })
+        .collect()
+    }
+}
+
+fn check_transforms(root: Program, mut ast: Ast) -> Result<(), String> {
+    let validate = |stage: &str, root: Program, ast: &Ast| {
+        ast.validate(root)
+            .map_err(|e| format!("Invalid AST after {stage}: {e}"))
+    };
+
+    validate("parsing", root, &ast)?;
+
+    remove_paren::remove_paren(root, &mut ast, None);
+    validate("remove_paren", root, &ast)?;
+
+    // The comments are attached to reachable nodes, and kept by remove_paren.
+    let comment_count = ast.comments().len();
+    let (root, _) = ast.compact(root);
+    validate("compact", root, &ast)?;
+    if ast.comments().len() != comment_count {
+        return Err(format!(
+            "Lost {} comments after compact",
+            comment_count - ast.comments().len()
+        ));
+    }
+
+    let bytes = ast
+        .to_bytes()
+        .map_err(|e| format!("Failed to encode: {e}"))?;
+    let decoded = Ast::from_bytes(&bytes).map_err(|e| format!("Failed to decode: {e}"))?;
+    validate("from_bytes", root, &decoded)?;
+
+    let mut imported = Ast::new(0, Default::default());
+    let imported_root = imported.import_subtree(&ast, root);
+    validate("import_subtree", imported_root, &imported)
+}